@@ -9,5 +9,15 @@ pub mod permissioned_liquidator {
     declare_id!("4wbNjWbj3kPDbyKnSq8SXVEtAJw4uzE8mJ2QwuK1BCYZ");
 }
 
+/// The swap program `deposit_with_swap` is allowed to route a depositor's tokens through
+pub mod jupiter {
+    use anchor_lang::prelude::declare_id;
+    declare_id!("JUP6LkbZbjS1jKKwapdHNy74zcZ3tLUZoi5QNyVTaV4");
+}
+
 pub const FUEL_SHARE_PRECISION: u128 = 1_000_000_000_000_000_000; // expo -18
+/// Holding period over which a vault's fuel boost ramps up to its max, see `VaultConfig::get_fuel_boost_multiplier`
+pub const FUEL_BOOST_RAMP_PERIOD: i64 = 90 * ONE_DAY;
+/// A vault user stats fuel category at or above this is close enough to `u32::MAX` to be swept into its `FuelOverflow`
+pub const FUEL_OVERFLOW_SWEEP_THRESHOLD: u32 = u32::MAX / 10 * 9;
 pub const MAGIC_FUEL_START_TS: u32 = 123; // some arbitrary timestamp to identify VaultDepositors created after fuel distribution started.
@@ -1,19 +1,46 @@
 use anchor_lang::prelude::*;
 
+use crate::state::WithdrawRouteAccounts;
+
 pub trait InitializeUserCPI {
     fn drift_initialize_user(&self, name: [u8; 32], bump: u8) -> Result<()>;
 
     fn drift_initialize_user_stats(&self, name: [u8; 32], bump: u8) -> Result<()>;
 }
 
+pub trait InitializeEscrowUserCPI {
+    fn drift_initialize_escrow_user(&self) -> Result<()>;
+}
+
 pub trait DepositCPI {
     fn drift_deposit(&self, amount: u64) -> Result<()>;
 }
 
+pub trait WithdrawFromUserCPI {
+    fn drift_withdraw_from_user(&self, amount: u64) -> Result<()>;
+}
+
+pub trait DepositToUserCPI {
+    fn drift_deposit_to_user(&self, amount: u64) -> Result<()>;
+}
+
 pub trait WithdrawCPI {
     fn drift_withdraw(&self, amount: u64) -> Result<()>;
 }
 
+pub trait TransferDepositCPI {
+    fn drift_transfer_deposit(&self, amount: u64) -> Result<()>;
+}
+
+pub trait WithdrawRouteCPI<'info> {
+    fn drift_withdraw_route(
+        &self,
+        market_index: u16,
+        amount: u64,
+        route_accounts: &WithdrawRouteAccounts<'info>,
+    ) -> Result<()>;
+}
+
 pub trait UpdateUserDelegateCPI {
     fn drift_update_user_delegate(&self, delegate: Pubkey) -> Result<()>;
 }
@@ -22,6 +49,10 @@ pub trait UpdateUserReduceOnlyCPI {
     fn drift_update_user_reduce_only(&self, reduce_only: bool) -> Result<()>;
 }
 
+pub trait UpdateUserCustomMarginRatioCPI {
+    fn drift_update_user_custom_margin_ratio(&self, margin_ratio: u32) -> Result<()>;
+}
+
 pub trait UpdateUserMarginTradingEnabledCPI {
     fn drift_update_user_margin_trading_enabled(&self, enabled: bool) -> Result<()>;
 }
@@ -52,3 +83,9 @@ pub trait CancelRequestRemoveInsuranceFundStakeCPI {
 pub trait RemoveInsuranceFundStakeCPI {
     fn drift_remove_insurance_fund_stake(&self, market_index: u16) -> Result<()>;
 }
+
+pub trait FuelOverflowCPI {
+    fn drift_initialize_fuel_overflow(&self) -> Result<()>;
+
+    fn drift_sweep_fuel(&self) -> Result<()>;
+}
@@ -4,6 +4,24 @@ use drift::error::ErrorCode as DriftErrorCode;
 
 pub type VaultResult<T = ()> = std::result::Result<T, ErrorCode>;
 
+/// Status code returned by failure-tolerant view instructions when they succeed
+pub const STATUS_OK: u32 = 0;
+/// Status code for runtime errors that carry no custom error number, e.g. a missing account
+pub const STATUS_PROGRAM_ERROR: u32 = 1;
+
+/// Stable numeric status code for `error`, for failure-tolerant view instructions to return instead of failing.
+/// Vault errors map to their anchor error number (6000 + [`ErrorCode`] variant index), which never changes since
+/// variants are only ever appended. Anchor framework errors keep their own numbers.
+pub fn status_code(error: &anchor_lang::error::Error) -> u32 {
+    match error {
+        anchor_lang::error::Error::AnchorError(error) => error.error_code_number,
+        anchor_lang::error::Error::ProgramError(error) => match error.program_error {
+            ProgramError::Custom(code) => code,
+            _ => STATUS_PROGRAM_ERROR,
+        },
+    }
+}
+
 #[error_code]
 #[derive(PartialEq, Eq)]
 pub enum ErrorCode {
@@ -57,6 +75,158 @@ pub enum ErrorCode {
     InvalidTokenization,
     #[msg("InvalidFuelDistributionMode")]
     InvalidFuelDistributionMode,
+    #[msg("VaultHookMissing")]
+    VaultHookMissing,
+    #[msg("InvalidVaultHook")]
+    InvalidVaultHook,
+    #[msg("InvalidWithdrawRoute")]
+    InvalidWithdrawRoute,
+    #[msg("InvalidVaultTemplate")]
+    InvalidVaultTemplate,
+    #[msg("InvalidVaultPoll")]
+    InvalidVaultPoll,
+    #[msg("VaultWindingDown")]
+    VaultWindingDown,
+    #[msg("InvalidShareSnapshot")]
+    InvalidShareSnapshot,
+    #[msg("InvalidLossCarryforward")]
+    InvalidLossCarryforward,
+    #[msg("InvalidVaultDepositorCustodian")]
+    InvalidVaultDepositorCustodian,
+    #[msg("DuplicateIdempotencyKey")]
+    DuplicateIdempotencyKey,
+    #[msg("InvalidVaultDepositorIdempotency")]
+    InvalidVaultDepositorIdempotency,
+    #[msg("InvalidWithdrawSettlement")]
+    InvalidWithdrawSettlement,
+    #[msg("InvalidVaultProfile")]
+    InvalidVaultProfile,
+    #[msg("InvalidPerformancePeriod")]
+    InvalidPerformancePeriod,
+    #[msg("InvalidVaultInterface")]
+    InvalidVaultInterface,
+    #[msg("InvalidDustWithdraw")]
+    InvalidDustWithdraw,
+    #[msg("InvalidWithdrawStream")]
+    InvalidWithdrawStream,
+    #[msg("InvalidVaultFeatureFlags")]
+    InvalidVaultFeatureFlags,
+    #[msg("VaultFeatureDisabled")]
+    VaultFeatureDisabled,
+    #[msg("InvalidVaultRegistry")]
+    InvalidVaultRegistry,
+    #[msg("InvalidDepositMintMigration")]
+    InvalidDepositMintMigration,
+    #[msg("InvalidVaultParamChangeLog")]
+    InvalidVaultParamChangeLog,
+    #[msg("InvalidVaultDepositorCap")]
+    InvalidVaultDepositorCap,
+    #[msg("VaultDepositorCapReached")]
+    VaultDepositorCapReached,
+    #[msg("InvalidFeeHoliday")]
+    InvalidFeeHoliday,
+    #[msg("InvalidWithdrawReceipt")]
+    InvalidWithdrawReceipt,
+    #[msg("WithdrawCancelDeadlinePassed")]
+    WithdrawCancelDeadlinePassed,
+    #[msg("SettleCrankMissing")]
+    SettleCrankMissing,
+    #[msg("InvalidVaultDepositorVersion")]
+    InvalidVaultDepositorVersion,
+    #[msg("InvalidVaultDelegateProposal")]
+    InvalidVaultDelegateProposal,
+    #[msg("DelegateApprovalRequired")]
+    DelegateApprovalRequired,
+    #[msg("SpotMarketWithdrawPaused")]
+    SpotMarketWithdrawPaused,
+    #[msg("WithdrawNotQueued")]
+    WithdrawNotQueued,
+    #[msg("InvalidVaultFeeSplit")]
+    InvalidVaultFeeSplit,
+    #[msg("AutoRenewWithdrawNotEnabled")]
+    AutoRenewWithdrawNotEnabled,
+    #[msg("WithdrawRequestNotExpired")]
+    WithdrawRequestNotExpired,
+    #[msg("InvalidProtocolTvlCap")]
+    InvalidProtocolTvlCap,
+    #[msg("ProtocolTvlCapExceeded")]
+    ProtocolTvlCapExceeded,
+    #[msg("InvalidVaultRedeemSchedule")]
+    InvalidVaultRedeemSchedule,
+    #[msg("InvalidVaultDepositorBatch")]
+    InvalidVaultDepositorBatch,
+    #[msg("VaultDepositorBlacklisted")]
+    VaultDepositorBlacklisted,
+    #[msg("InvalidVaultManagerFeeClaim")]
+    InvalidVaultManagerFeeClaim,
+    #[msg("InvalidVaultWithdrawEscrow")]
+    InvalidVaultWithdrawEscrow,
+    #[msg("ZeroVaultEquity")]
+    ZeroVaultEquity,
+    #[msg("InvalidVaultShareClass")]
+    InvalidVaultShareClass,
+    #[msg("VaultShareClassLocked")]
+    VaultShareClassLocked,
+    #[msg("InvalidVaultDepositorThrottle")]
+    InvalidVaultDepositorThrottle,
+    #[msg("VaultDepositorThrottled")]
+    VaultDepositorThrottled,
+    #[msg("DelegateSelfTrade")]
+    DelegateSelfTrade,
+    #[msg("InvalidVaultWithdrawPriority")]
+    InvalidVaultWithdrawPriority,
+    #[msg("InvalidLiquidationAction")]
+    InvalidLiquidationAction,
+    #[msg("InvalidVaultCrystallization")]
+    InvalidVaultCrystallization,
+    #[msg("InvalidVaultBackstop")]
+    InvalidVaultBackstop,
+    #[msg("InvalidFuelOverflowSweep")]
+    InvalidFuelOverflowSweep,
+    #[msg("InvalidProfitShareRefund")]
+    InvalidProfitShareRefund,
+    #[msg("InvalidOmnibusLedger")]
+    InvalidOmnibusLedger,
+    #[msg("OracleTwapDeviationTooLarge")]
+    OracleTwapDeviationTooLarge,
+    #[msg("InvalidVaultGuard")]
+    InvalidVaultGuard,
+    #[msg("InvalidVaultManagerActivity")]
+    InvalidVaultManagerActivity,
+    #[msg("VaultStateChanged")]
+    VaultStateChanged,
+    #[msg("SimulationOnly")]
+    SimulationOnly,
+    #[msg("VaultDepositorHasCompanionAccounts")]
+    VaultDepositorHasCompanionAccounts,
+    #[msg("InvalidVaultWithdrawBlackout")]
+    InvalidVaultWithdrawBlackout,
+    #[msg("WithdrawBlackout")]
+    WithdrawBlackout,
+    #[msg("InvalidVaultLockup")]
+    InvalidVaultLockup,
+    #[msg("VaultDepositorLockedUp")]
+    VaultDepositorLockedUp,
+    #[msg("InvalidVaultDepositorWithdrawRequests")]
+    InvalidVaultDepositorWithdrawRequests,
+    #[msg("InvalidVaultAuditor")]
+    InvalidVaultAuditor,
+    #[msg("DepositTooSmall")]
+    DepositTooSmall,
+    #[msg("InvalidVaultDepositorAuthorityTransfer")]
+    InvalidVaultDepositorAuthorityTransfer,
+    #[msg("TokenMintMissing")]
+    TokenMintMissing,
+    #[msg("InvalidVaultProfitShareCadence")]
+    InvalidVaultProfitShareCadence,
+    #[msg("ProfitShareCadenceNotElapsed")]
+    ProfitShareCadenceNotElapsed,
+    #[msg("InvalidSwapProgram")]
+    InvalidSwapProgram,
+    #[msg("SwapOutputBelowMinimum")]
+    SwapOutputBelowMinimum,
+    #[msg("InvalidVaultConfig")]
+    InvalidVaultConfig,
 }
 
 impl From<DriftErrorCode> for ErrorCode {
@@ -64,3 +234,26 @@ impl From<DriftErrorCode> for ErrorCode {
         ErrorCode::DriftError
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use anchor_lang::prelude::ProgramError;
+
+    use crate::error::{status_code, ErrorCode, STATUS_PROGRAM_ERROR};
+
+    #[test]
+    fn test_status_codes_are_stable() {
+        // integrators branch on these, never reorder or remove variants
+        assert_eq!(u32::from(ErrorCode::Default), 6000);
+        assert_eq!(u32::from(ErrorCode::DriftError), 6014);
+        assert_eq!(u32::from(ErrorCode::ZeroVaultEquity), 6069);
+        assert_eq!(u32::from(ErrorCode::VaultShareClassLocked), 6071);
+
+        assert_eq!(status_code(&ErrorCode::ZeroVaultEquity.into()), 6069);
+        assert_eq!(status_code(&ProgramError::Custom(6071).into()), 6071);
+        assert_eq!(
+            status_code(&ProgramError::NotEnoughAccountKeys.into()),
+            STATUS_PROGRAM_ERROR
+        );
+    }
+}
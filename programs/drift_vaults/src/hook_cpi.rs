@@ -0,0 +1,12 @@
+use anchor_lang::prelude::*;
+
+use crate::state::VaultHookAction;
+
+pub trait VaultHookCPI<'info> {
+    fn vault_hook_invoke(
+        &self,
+        hook_program: &AccountInfo<'info>,
+        action: VaultHookAction,
+        amount: u64,
+    ) -> Result<()>;
+}
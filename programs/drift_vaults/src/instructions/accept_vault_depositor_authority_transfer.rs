@@ -0,0 +1,214 @@
+use anchor_lang::prelude::*;
+use drift::math::safe_math::SafeMath;
+
+use crate::constraints::is_vault_for_vault_depositor;
+use crate::error::ErrorCode;
+use crate::state::account_maps::find_companion_account;
+use crate::state::events::VaultDepositorAuthorityTransferRecord;
+use crate::state::{
+    Vault, VaultDepositor, VaultDepositorAuthorityTransfer, VaultDepositorBase,
+    VaultDepositorCompanionAccountsProvider, VaultDepositorFlag, VaultDepositorLoader,
+    VaultDepositorLockup, VaultDepositorShareClass, VaultDepositorThrottleProvider,
+    VaultDepositorThrottleState, VaultLockupProvider,
+};
+use crate::{validate, Size};
+
+/// Completes a [`VaultDepositorAuthorityTransfer`], signed by the new authority. The old vault depositor's position
+/// moves into a new vault depositor for the new authority along with its high water mark, so profit share isn't
+/// realized here and is charged when the new vault depositor next realizes profits, see
+/// [`VaultDepositor::transfer_authority`]. The old vault depositor is closed with its companion accounts, see
+/// [`VaultDepositorCompanionAccountsProvider`], returning their rent and the proposal's to the old authority, so the
+/// vault's depositor count doesn't grow. The old depositor's last deposit moves to the new depositor's lockup and
+/// throttle state, so moving the position doesn't lift either, and locked share class shares move to the new
+/// depositor's share class. This is also how depositors rotate a compromised key.
+pub fn accept_vault_depositor_authority_transfer<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, AcceptVaultDepositorAuthorityTransfer<'info>>,
+) -> Result<()> {
+    let clock = &Clock::get()?;
+
+    let mut vault = ctx.accounts.vault.load_mut()?;
+    if vault.permissioned {
+        validate!(
+            vault.manager == *ctx.accounts.payer.key,
+            ErrorCode::PermissionedVault,
+            "Vault depositor can only be created by vault manager"
+        )?;
+    }
+
+    let mut vault_depositor = ctx.accounts.vault_depositor.load_mut_migrated()?;
+
+    // the old vault depositor is closed below, only legacy depositors that were never counted add to the count
+    if !vault_depositor.has_flag(VaultDepositorFlag::Counted) {
+        vault.depositor_count = vault.depositor_count.safe_add(1)?;
+    }
+
+    // no depositor lockup, no deposit since the lockup was enabled
+    let last_deposit_ts = match ctx.vault_lockup(&vault, &vault_depositor.pubkey)? {
+        Some((_, Some(vault_depositor_lockup))) => {
+            let last_deposit_ts = vault_depositor_lockup.load()?.last_deposit_ts;
+            validate!(
+                ctx.accounts.new_vault_depositor_lockup.is_some(),
+                ErrorCode::InvalidVaultLockup,
+                "the new vault depositor lockup must be created to move the lockup"
+            )?;
+            last_deposit_ts
+        }
+        _ => 0,
+    };
+    if let Some(new_vault_depositor_lockup) = &ctx.accounts.new_vault_depositor_lockup {
+        let mut new_vault_depositor_lockup = new_vault_depositor_lockup.load_init()?;
+        new_vault_depositor_lockup.vault_depositor = ctx.accounts.new_vault_depositor.key();
+        new_vault_depositor_lockup.last_deposit_ts = last_deposit_ts;
+        new_vault_depositor_lockup.bump = ctx.bumps.new_vault_depositor_lockup;
+    }
+
+    // no depositor throttle state, no deposit since the throttle was enabled
+    let last_deposit_ts = match ctx.vault_depositor_throttle(&vault, &vault_depositor.pubkey)? {
+        Some((_, Some(vault_depositor_throttle_state))) => {
+            let last_deposit_ts = vault_depositor_throttle_state.load()?.last_deposit_ts;
+            validate!(
+                ctx.accounts.new_vault_depositor_throttle_state.is_some(),
+                ErrorCode::InvalidVaultDepositorThrottle,
+                "the new vault depositor throttle state must be created to move the throttle"
+            )?;
+            last_deposit_ts
+        }
+        _ => 0,
+    };
+    if let Some(new_vault_depositor_throttle_state) =
+        &ctx.accounts.new_vault_depositor_throttle_state
+    {
+        let mut new_vault_depositor_throttle_state =
+            new_vault_depositor_throttle_state.load_init()?;
+        new_vault_depositor_throttle_state.vault_depositor = ctx.accounts.new_vault_depositor.key();
+        new_vault_depositor_throttle_state.last_deposit_ts = last_deposit_ts;
+        new_vault_depositor_throttle_state.bump = ctx.bumps.new_vault_depositor_throttle_state;
+    }
+
+    // locked share class shares move on their current lock and terms, so rotating a key doesn't wait out the lock
+    let vault_depositor_share_class = find_companion_account(
+        ctx.remaining_accounts,
+        "VaultDepositorShareClass",
+        VaultDepositorShareClass::get_address(&vault_depositor.pubkey),
+    )?
+    .map(AccountLoader::<'info, VaultDepositorShareClass>::try_from)
+    .transpose()?;
+    if let Some(vault_depositor_share_class) = &vault_depositor_share_class {
+        validate!(
+            vault_depositor_share_class.load()?.get_vault_shares() == 0
+                || ctx.accounts.new_vault_depositor_share_class.is_some(),
+            ErrorCode::InvalidVaultShareClass,
+            "the new vault depositor share class must be created to move the locked shares"
+        )?;
+    }
+    if let Some(new_vault_depositor_share_class) = &ctx.accounts.new_vault_depositor_share_class {
+        let mut new_share_class = new_vault_depositor_share_class.load_init()?;
+        new_share_class.pubkey = new_vault_depositor_share_class.key();
+        new_share_class.vault_depositor = ctx.accounts.new_vault_depositor.key();
+        new_share_class.authority = ctx.accounts.new_authority.key();
+        new_share_class.vault_shares_base = vault.shares_base;
+        new_share_class.bump = ctx.bumps.new_vault_depositor_share_class;
+        if let Some(vault_depositor_share_class) = &vault_depositor_share_class {
+            vault_depositor_share_class
+                .load_mut()?
+                .transfer_authority(&mut new_share_class)?;
+        }
+    }
+
+    let mut new_vault_depositor = ctx.accounts.new_vault_depositor.load_init()?;
+    *new_vault_depositor = VaultDepositor::new(
+        vault.pubkey,
+        ctx.accounts.new_vault_depositor.key(),
+        ctx.accounts.new_authority.key(),
+        clock.unix_timestamp,
+    );
+    vault_depositor.transfer_authority(&mut new_vault_depositor)?;
+
+    emit!(VaultDepositorAuthorityTransferRecord {
+        ts: clock.unix_timestamp,
+        vault: vault.pubkey,
+        old_authority: vault_depositor.authority,
+        new_authority: new_vault_depositor.authority,
+        vault_depositor: new_vault_depositor.pubkey,
+        vault_shares: new_vault_depositor.get_vault_shares(),
+    });
+
+    drop(new_vault_depositor);
+    drop(vault_depositor);
+    drop(vault);
+
+    let authority = ctx.accounts.authority.to_account_info();
+    ctx.accounts
+        .vault_depositor_authority_transfer
+        .close(authority.clone())?;
+    ctx.close_companion_accounts(&ctx.accounts.vault_depositor.key(), &authority)?;
+    ctx.accounts.vault_depositor.close(authority)
+}
+
+#[derive(Accounts)]
+pub struct AcceptVaultDepositorAuthorityTransfer<'info> {
+    #[account(mut)]
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        mut,
+        seeds = [b"vault_depositor", vault.key().as_ref(), authority.key().as_ref()],
+        bump,
+        constraint = is_vault_for_vault_depositor(&vault_depositor, &vault)?,
+    )]
+    pub vault_depositor: AccountLoader<'info, VaultDepositor>,
+    /// CHECK: the vault depositor's current authority, receives the proposal's rent
+    #[account(
+        mut,
+        constraint = vault_depositor.load()?.authority == authority.key(),
+    )]
+    pub authority: AccountInfo<'info>,
+    #[account(
+        mut,
+        seeds = [b"vault_depositor_authority_transfer", vault_depositor.key().as_ref()],
+        bump = vault_depositor_authority_transfer.load()?.bump,
+        constraint = vault_depositor_authority_transfer.load()?.new_authority == new_authority.key(),
+    )]
+    pub vault_depositor_authority_transfer: AccountLoader<'info, VaultDepositorAuthorityTransfer>,
+    #[account(
+        init,
+        seeds = [b"vault_depositor", vault.key().as_ref(), new_authority.key().as_ref()],
+        space = VaultDepositor::SIZE,
+        bump,
+        payer = payer
+    )]
+    pub new_vault_depositor: AccountLoader<'info, VaultDepositor>,
+    /// required if the old vault depositor has a lockup on a vault with a [`VaultLockup`](crate::state::VaultLockup)
+    #[account(
+        init,
+        seeds = [b"vault_depositor_lockup", new_vault_depositor.key().as_ref()],
+        space = VaultDepositorLockup::SIZE,
+        bump,
+        payer = payer
+    )]
+    pub new_vault_depositor_lockup: Option<AccountLoader<'info, VaultDepositorLockup>>,
+    /// required if the old vault depositor has a throttle state on a vault with a
+    /// [`VaultDepositorThrottle`](crate::state::VaultDepositorThrottle)
+    #[account(
+        init,
+        seeds = [b"vault_depositor_throttle_state", new_vault_depositor.key().as_ref()],
+        space = VaultDepositorThrottleState::SIZE,
+        bump,
+        payer = payer
+    )]
+    pub new_vault_depositor_throttle_state:
+        Option<AccountLoader<'info, VaultDepositorThrottleState>>,
+    /// required if the old vault depositor holds locked [`VaultShareClass`](crate::state::VaultShareClass) shares
+    #[account(
+        init,
+        seeds = [b"vault_depositor_share_class", new_vault_depositor.key().as_ref()],
+        space = VaultDepositorShareClass::SIZE,
+        bump,
+        payer = payer
+    )]
+    pub new_vault_depositor_share_class: Option<AccountLoader<'info, VaultDepositorShareClass>>,
+    pub new_authority: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub rent: Sysvar<'info, Rent>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+use drift::instructions::optional_accounts::AccountMaps;
+use drift::state::user::User;
+
+use crate::constraints::{is_protocol_for_vault, is_user_for_vault, is_vault_protocol_for_vault};
+use crate::state::{get_vault_tvl, ProtocolTvlCap, Vault, VaultProtocol, VaultProtocolFlag};
+use crate::AccountMapProvider;
+
+/// Counts the vault's current equity towards the protocol's tvl cap. Deposits into the vault must pass the
+/// [`ProtocolTvlCap`] in remaining_accounts from here on.
+pub fn add_vault_to_protocol_tvl_cap<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, AddVaultToProtocolTvlCap<'info>>,
+) -> Result<()> {
+    let clock = &Clock::get()?;
+    let vault = ctx.accounts.vault.load()?;
+    let user = ctx.accounts.drift_user.load()?;
+
+    let AccountMaps {
+        perp_market_map,
+        spot_market_map,
+        mut oracle_map,
+    } = ctx.load_maps(clock.slot, None, false, false)?;
+
+    let vault_equity =
+        vault.calculate_equity(&user, &perp_market_map, &spot_market_map, &mut oracle_map)?;
+
+    let spot_market = spot_market_map.get_ref(&vault.spot_market_index)?;
+    let oracle = oracle_map.get_price_data(&spot_market.oracle_id())?;
+    let tvl = get_vault_tvl(vault_equity, &spot_market, oracle.price)?;
+
+    ctx.accounts
+        .protocol_tvl_cap
+        .load_mut()?
+        .add_vault(vault.pubkey, tvl)?;
+    ctx.accounts
+        .vault_protocol
+        .load_mut()?
+        .add_flag(VaultProtocolFlag::TvlCap);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct AddVaultToProtocolTvlCap<'info> {
+    #[account(
+        constraint = is_protocol_for_vault(&vault, &vault_protocol, &protocol)?
+    )]
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        mut,
+        constraint = is_vault_protocol_for_vault(&vault_protocol, &vault)?
+    )]
+    pub vault_protocol: AccountLoader<'info, VaultProtocol>,
+    #[account(
+        mut,
+        seeds = [b"protocol_tvl_cap", protocol.key().as_ref()],
+        bump = protocol_tvl_cap.load()?.bump,
+        has_one = protocol,
+    )]
+    pub protocol_tvl_cap: AccountLoader<'info, ProtocolTvlCap>,
+    pub protocol: Signer<'info>,
+    #[account(
+        constraint = is_user_for_vault(&vault, &drift_user.key())?
+    )]
+    pub drift_user: AccountLoader<'info, User>,
+}
@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{VaultDepositorShareSnapshot, VaultShareSnapshot, VaultShareStatement};
+
+/// Permissionless crank, appends the [`VaultDepositorShareSnapshot`]s in remaining accounts to the epoch's
+/// [`VaultShareStatement`] in ascending authority order and publishes the root once all depositors are in.
+pub fn append_vault_share_statement<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, AppendVaultShareStatement<'info>>,
+) -> Result<()> {
+    let clock = &Clock::get()?;
+
+    let snapshot = ctx.accounts.vault_share_snapshot.load()?;
+    let mut statement = ctx.accounts.vault_share_statement.load_mut()?;
+
+    for acct in ctx.remaining_accounts.iter() {
+        let depositor_snapshot = AccountLoader::<VaultDepositorShareSnapshot>::try_from(acct)?;
+        statement.append(
+            &snapshot,
+            &*depositor_snapshot.load()?,
+            clock.unix_timestamp,
+        )?;
+    }
+
+    if statement.try_publish(&snapshot, clock.unix_timestamp)? {
+        msg!(
+            "Published share statement epoch {} for {} depositors",
+            statement.epoch,
+            statement.num_leaves
+        );
+    }
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct AppendVaultShareStatement<'info> {
+    pub vault_share_snapshot: AccountLoader<'info, VaultShareSnapshot>,
+    #[account(
+        mut,
+        seeds = [b"vault_share_statement", vault_share_snapshot.key().as_ref()],
+        bump = vault_share_statement.load()?.bump,
+    )]
+    pub vault_share_statement: AccountLoader<'info, VaultShareStatement>,
+}
@@ -0,0 +1,92 @@
+use anchor_lang::prelude::*;
+use drift::instructions::optional_accounts::AccountMaps;
+use drift::state::user::User;
+
+use crate::constraints::{is_manager_for_vault, is_user_for_vault};
+use crate::error::ErrorCode;
+use crate::state::{
+    record_vault_param_change, Vault, VaultConfigProvider, VaultParam, VaultParamChangeLogProvider,
+    VaultProfitShareHistory, VaultProtocolProvider,
+};
+use crate::{validate, AccountMapProvider};
+
+/// Lowers the vault's `profit_share` like `update_vault` and refunds the difference on the profit share realized in
+/// the [`VaultProfitShareHistory`] lookback, out of the manager's shares. Remaining accounts are the oracles and
+/// markets for the vault's positions, then the vault's [`VaultProtocol`](crate::state::VaultProtocol) if it has one.
+pub fn apply_fee_reduction_refund<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, ApplyFeeReductionRefund<'info>>,
+    profit_share: u32,
+) -> Result<()> {
+    let clock = &Clock::get()?;
+    let mut vault = ctx.accounts.vault.load_mut()?;
+
+    validate!(!vault.in_liquidation(), ErrorCode::OngoingLiquidation)?;
+
+    let param_change_log = ctx.vault_param_change_log();
+    vault.validate_param_change_log(&param_change_log)?;
+
+    let vault_config = ctx.vault_config(&vault)?;
+
+    let mut vp = ctx.vault_protocol();
+    vault.validate_vault_protocol(&vp)?;
+    let mut vp = vp.as_mut().map(|vp| vp.load_mut()).transpose()?;
+
+    let user = ctx.accounts.drift_user.load()?;
+
+    let AccountMaps {
+        perp_market_map,
+        spot_market_map,
+        mut oracle_map,
+    } = ctx.load_maps(clock.slot, None, vp.is_some(), false)?;
+
+    let vault_equity =
+        vault.calculate_equity(&user, &perp_market_map, &spot_market_map, &mut oracle_map)?;
+
+    record_vault_param_change(
+        &vault,
+        &param_change_log,
+        ctx.accounts.manager.key(),
+        VaultParam::ProfitShare,
+        vault.profit_share,
+        profit_share,
+        clock.unix_timestamp,
+    )?;
+
+    let refund = ctx
+        .accounts
+        .vault_profit_share_history
+        .load_mut()?
+        .apply_fee_reduction_refund(
+            &mut vault,
+            &vault_config,
+            &mut vp,
+            profit_share,
+            vault_equity,
+            clock.unix_timestamp,
+        )?;
+
+    msg!("refunded {} of profit share to shareholders", refund);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ApplyFeeReductionRefund<'info> {
+    #[account(
+        mut,
+        constraint = is_manager_for_vault(&vault, &manager)?
+    )]
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        mut,
+        seeds = [b"vault_profit_share_history", vault.key().as_ref()],
+        bump = vault_profit_share_history.load()?.bump,
+    )]
+    pub vault_profit_share_history: AccountLoader<'info, VaultProfitShareHistory>,
+    #[account(
+        constraint = is_user_for_vault(&vault, &drift_user.key())?
+    )]
+    /// CHECK: checked in drift cpi
+    pub drift_user: AccountLoader<'info, User>,
+    pub manager: Signer<'info>,
+}
@@ -0,0 +1,17 @@
+use anchor_lang::prelude::*;
+
+use crate::state::VaultDepositorLoader;
+
+use super::{depositor_total_amount, LossCarryforward};
+
+pub fn apply_loss_carryforward<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, LossCarryforward<'info>>,
+) -> Result<()> {
+    let total_amount = depositor_total_amount(&ctx)?;
+
+    let mut vault_depositor = ctx.accounts.vault_depositor.load_mut_migrated()?;
+    let mut ledger = ctx.accounts.protocol_loss_ledger.load_mut()?;
+    ledger.apply_loss(&mut *vault_depositor, total_amount)?;
+
+    Ok(())
+}
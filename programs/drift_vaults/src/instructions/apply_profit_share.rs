@@ -1,3 +1,5 @@
+use std::cell::RefMut;
+
 use anchor_lang::prelude::*;
 use drift::instructions::optional_accounts::AccountMaps;
 use drift::program::Drift;
@@ -9,7 +11,11 @@ use crate::constraints::{
 };
 use crate::state::events::{VaultDepositorAction, VaultDepositorRecord, VaultDepositorV1Record};
 use crate::state::traits::VaultDepositorBase;
-use crate::state::{FuelOverflowProvider, Vault, VaultProtocolProvider};
+use crate::state::{
+    record_vault_depositor_action, FuelOverflowProvider, Vault, VaultConfigProvider,
+    VaultDepositorLoader, VaultDepositorRecordLogProvider, VaultProfitShareCadenceProvider,
+    VaultProtocol, VaultProtocolProvider,
+};
 use crate::AccountMapProvider;
 use crate::VaultDepositor;
 
@@ -19,7 +25,18 @@ pub fn apply_profit_share<'c: 'info, 'info>(
     let clock = &Clock::get()?;
 
     let mut vault = ctx.accounts.vault.load_mut()?;
-    let mut vault_depositor = ctx.accounts.vault_depositor.load_mut()?;
+    let vault_config = ctx.vault_config(&vault)?;
+    vault_config.validate_unrealized_profit_share()?;
+    let mut vault_depositor = ctx.accounts.vault_depositor.load_mut_migrated()?;
+
+    if let Some((vault_cadence, vault_depositor_cadence)) =
+        ctx.vault_profit_share_cadence(&vault, &vault_depositor.pubkey)?
+    {
+        vault_cadence.load()?.record_profit_share(
+            &mut *vault_depositor_cadence.load_mut()?,
+            clock.unix_timestamp,
+        )?;
+    }
 
     // backwards compatible: if last rem acct does not deserialize into [`VaultProtocol`] then it's a legacy vault.
     let mut vp = ctx.vault_protocol();
@@ -51,24 +68,103 @@ pub fn apply_profit_share<'c: 'info, 'info>(
     let spot_market = spot_market_map.get_ref(&spot_market_index)?;
     let oracle = oracle_map.get_price_data(&spot_market.oracle_id())?;
 
+    let vault_shares_before = vault_depositor.get_vault_shares();
     let (manager_profit_share, protocol_profit_share) = vault_depositor.apply_profit_share(
         vault_equity,
         &mut vault,
+        &vault_config,
         &mut vp,
         clock.unix_timestamp,
         &user_stats,
         &fuel_overflow,
     )?;
 
+    record_vault_depositor_action(
+        &ctx.vault_depositor_record_log(&vault.pubkey)?,
+        &vault,
+        &vault_depositor,
+        VaultDepositorAction::FeePayment,
+        manager_profit_share.saturating_add(protocol_profit_share),
+        vault_shares_before,
+        vault_equity,
+        clock.unix_timestamp,
+    )?;
+
     let vault_shares_before = vault_depositor.checked_vault_shares(&vault)?;
     let total_vault_shares_before = vault.total_shares;
     let user_vault_shares_before = vault.user_shares;
     let protocol_shares_before = vault.get_protocol_shares(&mut vp);
 
+    emit_profit_share_record(
+        clock.unix_timestamp,
+        &vault,
+        &mut vp,
+        &vault_depositor,
+        vault_equity,
+        vault_shares_before,
+        total_vault_shares_before,
+        user_vault_shares_before,
+        protocol_shares_before,
+        manager_profit_share,
+        protocol_profit_share,
+        oracle.price,
+    )?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ApplyProfitShare<'info> {
+    #[account(
+        mut,
+        constraint = is_manager_for_vault(&vault, &manager)? || is_delegate_for_vault(&vault, &manager)?
+    )]
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        mut,
+        constraint = is_vault_for_vault_depositor(&vault_depositor, &vault)?
+    )]
+    pub vault_depositor: AccountLoader<'info, VaultDepositor>,
+    pub manager: Signer<'info>,
+    #[account(
+        mut,
+        constraint = is_user_stats_for_vault(&vault, &drift_user_stats.key())?
+    )]
+    /// CHECK: checked in drift cpi
+    pub drift_user_stats: AccountLoader<'info, UserStats>,
+    #[account(
+        mut,
+        constraint = is_user_for_vault(&vault, &drift_user.key())?
+    )]
+    /// CHECK: checked in drift cpi
+    pub drift_user: AccountLoader<'info, User>,
+    /// CHECK: checked in drift cpi
+    pub drift_state: AccountInfo<'info>,
+    /// CHECK: checked in drift cpi
+    pub drift_signer: AccountInfo<'info>,
+    pub drift_program: Program<'info, Drift>,
+}
+
+/// Emits the [`VaultDepositorAction::FeePayment`] record of a depositor's profit share
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn emit_profit_share_record(
+    now: i64,
+    vault: &Vault,
+    vp: &mut Option<RefMut<VaultProtocol>>,
+    vault_depositor: &VaultDepositor,
+    vault_equity: u64,
+    vault_shares_before: u128,
+    total_vault_shares_before: u128,
+    user_vault_shares_before: u128,
+    protocol_shares_before: u128,
+    manager_profit_share: u64,
+    protocol_profit_share: u64,
+    oracle_price: i64,
+) -> Result<()> {
     match vp {
         None => {
             emit!(VaultDepositorRecord {
-                ts: clock.unix_timestamp,
+                ts: now,
                 vault: vault.pubkey,
                 depositor_authority: vault_depositor.get_authority(),
                 action: VaultDepositorAction::FeePayment,
@@ -84,12 +180,12 @@ pub fn apply_profit_share<'c: 'info, 'info>(
                 profit_share: manager_profit_share,
                 management_fee: 0,
                 management_fee_shares: 0,
-                deposit_oracle_price: oracle.price,
+                deposit_oracle_price: oracle_price,
             });
         }
         Some(_) => {
             emit!(VaultDepositorV1Record {
-                ts: clock.unix_timestamp,
+                ts: now,
                 vault: vault.pubkey,
                 depositor_authority: vault_depositor.get_authority(),
                 action: VaultDepositorAction::FeePayment,
@@ -109,43 +205,11 @@ pub fn apply_profit_share<'c: 'info, 'info>(
                 management_fee: 0,
                 management_fee_shares: 0,
                 protocol_shares_before,
-                protocol_shares_after: vault.get_protocol_shares(&mut vp),
-                deposit_oracle_price: oracle.price,
+                protocol_shares_after: vault.get_protocol_shares(vp),
+                deposit_oracle_price: oracle_price,
             });
         }
     }
 
     Ok(())
 }
-
-#[derive(Accounts)]
-pub struct ApplyProfitShare<'info> {
-    #[account(
-        mut,
-        constraint = is_manager_for_vault(&vault, &manager)? || is_delegate_for_vault(&vault, &manager)?
-    )]
-    pub vault: AccountLoader<'info, Vault>,
-    #[account(
-        mut,
-        constraint = is_vault_for_vault_depositor(&vault_depositor, &vault)?
-    )]
-    pub vault_depositor: AccountLoader<'info, VaultDepositor>,
-    pub manager: Signer<'info>,
-    #[account(
-        mut,
-        constraint = is_user_stats_for_vault(&vault, &drift_user_stats.key())?
-    )]
-    /// CHECK: checked in drift cpi
-    pub drift_user_stats: AccountLoader<'info, UserStats>,
-    #[account(
-        mut,
-        constraint = is_user_for_vault(&vault, &drift_user.key())?
-    )]
-    /// CHECK: checked in drift cpi
-    pub drift_user: AccountLoader<'info, User>,
-    /// CHECK: checked in drift cpi
-    pub drift_state: AccountInfo<'info>,
-    /// CHECK: checked in drift cpi
-    pub drift_signer: AccountInfo<'info>,
-    pub drift_program: Program<'info, Drift>,
-}
@@ -0,0 +1,194 @@
+use anchor_lang::prelude::*;
+use drift::instructions::optional_accounts::AccountMaps;
+use drift::math::safe_math::SafeMath;
+use drift::state::user::{FuelOverflowStatus, User, UserStats};
+
+use crate::constraints::{
+    is_delegate_for_vault, is_manager_for_vault, is_user_for_vault, is_user_stats_for_vault,
+};
+use crate::error::ErrorCode;
+use crate::state::events::{VaultDepositorAction, VaultDepositorBatchRecord};
+use crate::state::traits::VaultDepositorBase;
+use crate::state::{
+    merkle_root, settle_fuel_season_batch, FuelOverflowProvider, Vault, VaultConfigProvider,
+    VaultDepositor, VaultDepositorDelta, VaultDepositorLoader, VaultProfitShareCadenceProvider,
+    VaultProtocolProvider, MAX_DETAILED_BATCH_SIZE,
+};
+use crate::{validate, AccountMapProvider};
+
+use super::apply_profit_share::emit_profit_share_record;
+
+/// [`apply_profit_share`](super::apply_profit_share) for every writable [`VaultDepositor`] in remaining_accounts,
+/// passed after the market accounts. Emits one [`VaultDepositorBatchRecord`], plus a record per depositor if
+/// `emit_details` is set and the batch has at most [`MAX_DETAILED_BATCH_SIZE`] depositors.
+pub fn apply_profit_share_batch<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, ApplyProfitShareBatch<'info>>,
+    emit_details: bool,
+) -> Result<()> {
+    let clock = &Clock::get()?;
+
+    let mut vault = ctx.accounts.vault.load_mut()?;
+    let vault_config = ctx.vault_config(&vault)?;
+    vault_config.validate_unrealized_profit_share()?;
+
+    let vault_depositors: Vec<AccountLoader<'info, VaultDepositor>> = ctx
+        .remaining_accounts
+        .iter()
+        .filter_map(|acct| AccountLoader::<'info, VaultDepositor>::try_from(acct).ok())
+        .collect();
+
+    validate!(
+        !vault_depositors.is_empty(),
+        ErrorCode::InvalidVaultDepositorBatch,
+        "no vault depositors in remaining accounts"
+    )?;
+    validate!(
+        !emit_details || vault_depositors.len() <= MAX_DETAILED_BATCH_SIZE,
+        ErrorCode::InvalidVaultDepositorBatch,
+        "per depositor records are limited to batches of {}, got {}",
+        MAX_DETAILED_BATCH_SIZE,
+        vault_depositors.len()
+    )?;
+
+    // backwards compatible: if last rem acct does not deserialize into [`VaultProtocol`] then it's a legacy vault.
+    let mut vp = ctx.vault_protocol();
+    vault.validate_vault_protocol(&vp)?;
+    let mut vp = vp.as_mut().map(|vp| vp.load_mut()).transpose()?;
+
+    let user = ctx.accounts.drift_user.load()?;
+    let spot_market_index = vault.spot_market_index;
+
+    let user_stats = ctx.accounts.drift_user_stats.load()?;
+    let has_fuel_overflow = FuelOverflowStatus::exists(user_stats.fuel_overflow_status);
+    let fuel_overflow = ctx.fuel_overflow(vp.is_some(), has_fuel_overflow);
+    user_stats.validate_fuel_overflow(&fuel_overflow)?;
+    settle_fuel_season_batch(
+        &vault,
+        &vault_config,
+        &vault_depositors,
+        &fuel_overflow,
+        clock.unix_timestamp,
+    )?;
+
+    let AccountMaps {
+        perp_market_map,
+        spot_market_map,
+        mut oracle_map,
+    } = ctx.load_maps(
+        clock.slot,
+        Some(spot_market_index),
+        vp.is_some(),
+        has_fuel_overflow,
+    )?;
+
+    let vault_equity =
+        vault.calculate_equity(&user, &perp_market_map, &spot_market_map, &mut oracle_map)?;
+
+    let spot_market = spot_market_map.get_ref(&spot_market_index)?;
+    let oracle = oracle_map.get_price_data(&spot_market.oracle_id())?;
+
+    let mut record = VaultDepositorBatchRecord {
+        ts: clock.unix_timestamp,
+        vault: vault.pubkey,
+        action: VaultDepositorAction::FeePayment,
+        vault_equity_before: vault_equity,
+        ..VaultDepositorBatchRecord::default()
+    };
+    let mut leaves = Vec::with_capacity(vault_depositors.len());
+
+    for vault_depositor_loader in vault_depositors.iter() {
+        let mut vault_depositor = vault_depositor_loader.load_mut_migrated()?;
+        validate!(
+            vault_depositor.vault == vault.pubkey,
+            ErrorCode::InvalidVaultDepositorBatch,
+            "vault depositor {} is not for vault {}",
+            vault_depositor.pubkey,
+            vault.pubkey
+        )?;
+
+        if let Some((vault_cadence, vault_depositor_cadence)) =
+            ctx.vault_profit_share_cadence(&vault, &vault_depositor.pubkey)?
+        {
+            vault_cadence.load()?.record_profit_share(
+                &mut *vault_depositor_cadence.load_mut()?,
+                clock.unix_timestamp,
+            )?;
+        }
+
+        let vault_shares_before = vault_depositor.checked_vault_shares(&vault)?;
+        let total_vault_shares_before = vault.total_shares;
+        let user_vault_shares_before = vault.user_shares;
+        let protocol_shares_before = vault.get_protocol_shares(&mut vp);
+
+        let (manager_profit_share, protocol_profit_share) = vault_depositor.apply_profit_share(
+            vault_equity,
+            &mut vault,
+            &vault_config,
+            &mut vp,
+            clock.unix_timestamp,
+            &user_stats,
+            &fuel_overflow,
+        )?;
+
+        let vault_shares_after = vault_depositor.get_vault_shares();
+
+        if emit_details {
+            emit_profit_share_record(
+                clock.unix_timestamp,
+                &vault,
+                &mut vp,
+                &vault_depositor,
+                vault_equity,
+                vault_shares_before,
+                total_vault_shares_before,
+                user_vault_shares_before,
+                protocol_shares_before,
+                manager_profit_share,
+                protocol_profit_share,
+                oracle.price,
+            )?;
+        }
+
+        leaves.push(
+            VaultDepositorDelta {
+                authority: vault_depositor.get_authority(),
+                vault_shares_before,
+                vault_shares_after,
+                amount: manager_profit_share.safe_add(protocol_profit_share)?,
+            }
+            .leaf(),
+        );
+
+        record.depositor_count = record.depositor_count.safe_add(1)?;
+        record.vault_shares_before = record.vault_shares_before.safe_add(vault_shares_before)?;
+        record.vault_shares_after = record.vault_shares_after.safe_add(vault_shares_after)?;
+        record.manager_profit_share = record.manager_profit_share.safe_add(manager_profit_share)?;
+        record.protocol_profit_share = record
+            .protocol_profit_share
+            .safe_add(protocol_profit_share)?;
+    }
+
+    record.total_vault_shares_after = vault.total_shares;
+    record.deltas_root = merkle_root(&leaves);
+    emit!(record);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ApplyProfitShareBatch<'info> {
+    #[account(
+        mut,
+        constraint = is_manager_for_vault(&vault, &manager)? || is_delegate_for_vault(&vault, &manager)?
+    )]
+    pub vault: AccountLoader<'info, Vault>,
+    pub manager: Signer<'info>,
+    #[account(
+        constraint = is_user_stats_for_vault(&vault, &drift_user_stats.key())?
+    )]
+    pub drift_user_stats: AccountLoader<'info, UserStats>,
+    #[account(
+        constraint = is_user_for_vault(&vault, &drift_user.key())?
+    )]
+    pub drift_user: AccountLoader<'info, User>,
+}
@@ -3,6 +3,7 @@ use drift::instructions::optional_accounts::AccountMaps;
 use drift::state::user::User;
 
 use crate::constraints::{is_user_for_vault, is_vault_for_vault_depositor};
+use crate::state::VaultDepositorLoader;
 use crate::{AccountMapProvider, Vault, VaultDepositor, VaultProtocolProvider};
 
 pub fn apply_rebase<'c: 'info, 'info>(
@@ -11,7 +12,7 @@ pub fn apply_rebase<'c: 'info, 'info>(
     let clock = &Clock::get()?;
 
     let mut vault = ctx.accounts.vault.load_mut()?;
-    let mut vault_depositor = ctx.accounts.vault_depositor.load_mut()?;
+    let mut vault_depositor = ctx.accounts.vault_depositor.load_mut_migrated()?;
 
     // backwards compatible: if last rem acct does not deserialize into [`VaultProtocol`] then it's a legacy vault.
     let mut vp = ctx.vault_protocol();
@@ -30,7 +31,7 @@ pub fn apply_rebase<'c: 'info, 'info>(
     let vault_equity =
         vault.calculate_equity(&user, &perp_market_map, &spot_market_map, &mut oracle_map)?;
 
-    vault_depositor.apply_rebase(&mut vault, &mut vp, vault_equity)?;
+    vault_depositor.apply_rebase(&mut vault, &mut vp, vault_equity, clock.unix_timestamp)?;
 
     Ok(())
 }
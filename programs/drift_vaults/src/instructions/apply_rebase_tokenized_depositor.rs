@@ -33,7 +33,7 @@ pub fn apply_rebase_tokenized_depositor<'c: 'info, 'info>(
     ctx.accounts
         .tokenized_vault_depositor
         .load_mut()?
-        .apply_rebase(&mut vault, &mut vp, vault_equity)?;
+        .apply_rebase(&mut vault, &mut vp, vault_equity, clock.unix_timestamp)?;
 
     Ok(())
 }
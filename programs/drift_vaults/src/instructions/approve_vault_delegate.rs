@@ -0,0 +1,75 @@
+use anchor_lang::prelude::*;
+use drift::cpi::accounts::UpdateUser;
+use drift::program::Drift;
+use drift::state::user::User;
+
+use crate::constraints::{is_protocol_for_vault, is_user_for_vault, is_vault_protocol_for_vault};
+use crate::drift_cpi::UpdateUserDelegateCPI;
+use crate::state::{Vault, VaultDelegateProposal, VaultParamChangeLogProvider, VaultProtocol};
+use crate::{declare_vault_seeds, implement_update_user_delegate_cpi};
+
+use super::set_vault_delegate;
+
+/// Applies the manager's pending [`VaultDelegateProposal`], `delegate` must match the proposal.
+pub fn approve_vault_delegate<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, ApproveVaultDelegate<'info>>,
+    delegate: Pubkey,
+) -> Result<()> {
+    let mut vault = ctx.accounts.vault.load_mut()?;
+    let now = Clock::get()?.unix_timestamp;
+    let param_change_log = ctx.vault_param_change_log();
+    vault.validate_param_change_log(&param_change_log)?;
+
+    ctx.accounts
+        .vault_delegate_proposal
+        .load_mut()?
+        .approve(delegate)?;
+
+    set_vault_delegate(
+        &mut vault,
+        &param_change_log,
+        ctx.accounts.protocol.key(),
+        delegate,
+        now,
+    )?;
+
+    drop(vault);
+
+    ctx.drift_update_user_delegate(delegate)?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ApproveVaultDelegate<'info> {
+    #[account(
+        mut,
+        constraint = is_protocol_for_vault(&vault, &vault_protocol, &protocol)?
+    )]
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        constraint = is_vault_protocol_for_vault(&vault_protocol, &vault)?
+    )]
+    pub vault_protocol: AccountLoader<'info, VaultProtocol>,
+    #[account(
+        mut,
+        seeds = [b"vault_delegate_proposal", vault.key().as_ref()],
+        bump = vault_delegate_proposal.load()?.bump,
+    )]
+    pub vault_delegate_proposal: AccountLoader<'info, VaultDelegateProposal>,
+    pub protocol: Signer<'info>,
+    #[account(
+        mut,
+        constraint = is_user_for_vault(&vault, &drift_user.key())?
+    )]
+    /// CHECK: checked in drift cpi
+    pub drift_user: AccountLoader<'info, User>,
+    pub drift_program: Program<'info, Drift>,
+}
+
+impl<'info> UpdateUserDelegateCPI for Context<'_, '_, '_, 'info, ApproveVaultDelegate<'info>> {
+    fn drift_update_user_delegate(&self, delegate: Pubkey) -> Result<()> {
+        implement_update_user_delegate_cpi!(self, delegate);
+        Ok(())
+    }
+}
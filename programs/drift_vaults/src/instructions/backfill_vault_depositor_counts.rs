@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+
+use crate::error::ErrorCode;
+use crate::state::{Vault, VaultDepositor, VaultDepositorLoader};
+use crate::validate;
+
+/// Permissionless, counts every writable [`VaultDepositor`] in remaining_accounts created before
+/// `Vault::depositor_count` and `Vault::active_withdraw_request_count` were tracked, see
+/// [`VaultDepositor::backfill_counts`]. Depositors that are already counted are skipped, so the counters are exact
+/// once every older depositor of the vault has been passed.
+pub fn backfill_vault_depositor_counts<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, BackfillVaultDepositorCounts<'info>>,
+) -> Result<()> {
+    let mut vault = ctx.accounts.vault.load_mut()?;
+
+    let vault_depositors: Vec<AccountLoader<'info, VaultDepositor>> = ctx
+        .remaining_accounts
+        .iter()
+        .filter(|acct| acct.is_writable)
+        .filter_map(|acct| AccountLoader::<'info, VaultDepositor>::try_from(acct).ok())
+        .collect();
+
+    validate!(
+        !vault_depositors.is_empty(),
+        ErrorCode::InvalidVaultDepositorBatch,
+        "no vault depositors in remaining accounts"
+    )?;
+
+    let mut backfilled: u32 = 0;
+    for vault_depositor in vault_depositors.iter() {
+        if vault_depositor
+            .load_mut_migrated()?
+            .backfill_counts(&mut vault)?
+        {
+            backfilled += 1;
+        }
+    }
+
+    msg!(
+        "backfilled {} vault depositors, depositor_count: {}, active_withdraw_request_count: {}",
+        backfilled,
+        vault.depositor_count,
+        vault.active_withdraw_request_count
+    );
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct BackfillVaultDepositorCounts<'info> {
+    #[account(mut)]
+    pub vault: AccountLoader<'info, Vault>,
+}
@@ -0,0 +1,91 @@
+use anchor_lang::prelude::*;
+use drift::instructions::optional_accounts::AccountMaps;
+use drift::state::user::{FuelOverflowStatus, User, UserStats};
+
+use crate::constraints::{
+    is_authority_for_vault_depositor, is_user_for_vault, is_user_stats_for_vault,
+};
+use crate::error::ErrorCode;
+use crate::state::{
+    FuelOverflowProvider, Vault, VaultConfigProvider, VaultDepositor, VaultDepositorLoader,
+    VaultProtocolProvider, VaultWithdrawPriority,
+};
+use crate::{validate, AccountMapProvider};
+
+/// Pays `priority_fee` (PERCENTAGE_PRECISION) of the depositor's queued withdraw request to the remaining
+/// shareholders for it to mature sooner, see [`VaultWithdrawPriority`].
+pub fn boost_withdraw_request<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, BoostWithdrawRequest<'info>>,
+    priority_fee: u32,
+) -> Result<()> {
+    let clock = &Clock::get()?;
+    let mut vault = ctx.accounts.vault.load_mut()?;
+    validate!(!vault.in_liquidation(), ErrorCode::OngoingLiquidation)?;
+
+    let mut vault_depositor = ctx.accounts.vault_depositor.load_mut_migrated()?;
+    let vault_withdraw_priority = ctx.accounts.vault_withdraw_priority.load()?;
+
+    let vault_config = ctx.vault_config(&vault)?;
+
+    // backwards compatible: if last rem acct does not deserialize into [`VaultProtocol`] then it's a legacy vault.
+    let mut vp = ctx.vault_protocol();
+    vault.validate_vault_protocol(&vp)?;
+    let mut vp = vp.as_mut().map(|vp| vp.load_mut()).transpose()?;
+
+    let user = ctx.accounts.drift_user.load()?;
+
+    let user_stats = ctx.accounts.drift_user_stats.load()?;
+    let has_fuel_overflow = FuelOverflowStatus::exists(user_stats.fuel_overflow_status);
+    let fuel_overflow = ctx.fuel_overflow(vp.is_some(), has_fuel_overflow);
+    user_stats.validate_fuel_overflow(&fuel_overflow)?;
+
+    let AccountMaps {
+        perp_market_map,
+        spot_market_map,
+        mut oracle_map,
+    } = ctx.load_maps(clock.slot, None, vp.is_some(), has_fuel_overflow)?;
+
+    let vault_equity =
+        vault.calculate_equity(&user, &perp_market_map, &spot_market_map, &mut oracle_map)?;
+
+    vault_depositor.boost_withdraw_request(
+        &vault_withdraw_priority,
+        priority_fee,
+        vault_equity,
+        &mut vault,
+        &vault_config,
+        &mut vp,
+        clock.unix_timestamp,
+        &user_stats,
+        &fuel_overflow,
+    )?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct BoostWithdrawRequest<'info> {
+    #[account(mut)]
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        mut,
+        seeds = [b"vault_depositor", vault.key().as_ref(), authority.key().as_ref()],
+        bump,
+        constraint = is_authority_for_vault_depositor(&vault_depositor, &authority)?,
+    )]
+    pub vault_depositor: AccountLoader<'info, VaultDepositor>,
+    pub authority: Signer<'info>,
+    #[account(
+        seeds = [b"vault_withdraw_priority", vault.key().as_ref()],
+        bump = vault_withdraw_priority.load()?.bump,
+    )]
+    pub vault_withdraw_priority: AccountLoader<'info, VaultWithdrawPriority>,
+    #[account(
+        constraint = is_user_stats_for_vault(&vault, &drift_user_stats.key())?
+    )]
+    pub drift_user_stats: AccountLoader<'info, UserStats>,
+    #[account(
+        constraint = is_user_for_vault(&vault, &drift_user.key())?
+    )]
+    pub drift_user: AccountLoader<'info, User>,
+}
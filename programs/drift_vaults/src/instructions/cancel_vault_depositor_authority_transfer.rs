@@ -0,0 +1,29 @@
+use anchor_lang::prelude::*;
+
+use crate::constraints::is_authority_for_vault_depositor;
+use crate::state::{VaultDepositor, VaultDepositorAuthorityTransfer};
+
+/// Withdraws a pending authority transfer proposal, returning its rent to the authority.
+pub fn cancel_vault_depositor_authority_transfer<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, CancelVaultDepositorAuthorityTransfer<'info>>,
+) -> Result<()> {
+    ctx.accounts
+        .vault_depositor_authority_transfer
+        .close(ctx.accounts.authority.to_account_info())
+}
+
+#[derive(Accounts)]
+pub struct CancelVaultDepositorAuthorityTransfer<'info> {
+    #[account(
+        constraint = is_authority_for_vault_depositor(&vault_depositor, &authority)?,
+    )]
+    pub vault_depositor: AccountLoader<'info, VaultDepositor>,
+    #[account(
+        mut,
+        seeds = [b"vault_depositor_authority_transfer", vault_depositor.key().as_ref()],
+        bump = vault_depositor_authority_transfer.load()?.bump,
+    )]
+    pub vault_depositor_authority_transfer: AccountLoader<'info, VaultDepositorAuthorityTransfer>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+}
@@ -4,9 +4,15 @@ use drift::math::casting::Cast;
 use drift::state::user::{FuelOverflowStatus, User, UserStats};
 
 use crate::constraints::{
-    is_authority_for_vault_depositor, is_user_for_vault, is_user_stats_for_vault,
+    is_authority_or_custodian_for_vault_depositor, is_user_for_vault, is_user_stats_for_vault,
+};
+use crate::state::events::VaultDepositorAction;
+use crate::state::{
+    record_vault_depositor_action, ComputeUnitsHint, FuelOverflowProvider, VaultConfigProvider,
+    VaultDepositorBase, VaultDepositorLoader, VaultDepositorRecordLogProvider,
+    VaultWithdrawSettlementProvider, WithdrawReceiptProvider,
+    CANCEL_WITHDRAW_REQUEST_BASE_COMPUTE_UNITS,
 };
-use crate::state::FuelOverflowProvider;
 use crate::AccountMapProvider;
 use crate::{Vault, VaultDepositor, VaultProtocolProvider};
 
@@ -15,8 +21,9 @@ pub fn cancel_withdraw_request<'c: 'info, 'info>(
 ) -> Result<()> {
     let clock = &Clock::get()?;
     let mut vault = ctx.accounts.vault.load_mut()?;
-    let mut vault_depositor = ctx.accounts.vault_depositor.load_mut()?;
+    let mut vault_depositor = ctx.accounts.vault_depositor.load_mut_migrated()?;
 
+    let vault_config = ctx.vault_config(&vault)?;
     // backwards compatible: if last rem acct does not deserialize into [`VaultProtocol`] then it's a legacy vault.
     let mut vp = ctx.vault_protocol();
     vault.validate_vault_protocol(&vp)?;
@@ -37,20 +44,53 @@ pub fn cancel_withdraw_request<'c: 'info, 'info>(
 
     let vault_equity =
         vault.calculate_equity(&user, &perp_market_map, &spot_market_map, &mut oracle_map)?;
+    let compute_units_hint = ComputeUnitsHint::new(
+        CANCEL_WITHDRAW_REQUEST_BASE_COMPUTE_UNITS,
+        &perp_market_map,
+        &spot_market_map,
+    )?;
 
     let spot_market = spot_market_map.get_ref(&vault.spot_market_index)?;
     let oracle = oracle_map.get_price_data(&spot_market.oracle_id())?;
 
+    if let Some(receipt) = ctx.withdraw_receipt(&vault, &ctx.accounts.vault_depositor.key())? {
+        receipt
+            .load()?
+            .validate_cancel(&vault_depositor.last_withdraw_request, clock.unix_timestamp)?;
+    }
+
+    let withdraw_settlement = ctx.vault_withdraw_settlement(&vault)?;
+    let withdraw_settlement = withdraw_settlement
+        .as_ref()
+        .map(|settlement| settlement.load())
+        .transpose()?;
+
+    let vault_shares_before = vault_depositor.get_vault_shares();
     vault_depositor.cancel_withdraw_request(
         vault_equity.cast()?,
         &mut vault,
+        &vault_config,
         &mut vp,
         clock.unix_timestamp,
         &user_stats,
         &fuel_overflow,
         oracle.price,
+        withdraw_settlement.as_deref(),
     )?;
 
+    record_vault_depositor_action(
+        &ctx.vault_depositor_record_log(&vault.pubkey)?,
+        &vault,
+        &vault_depositor,
+        VaultDepositorAction::CancelWithdrawRequest,
+        0,
+        vault_shares_before,
+        vault_equity,
+        clock.unix_timestamp,
+    )?;
+
+    compute_units_hint.set_return_data()?;
+
     Ok(())
 }
 
@@ -60,11 +100,12 @@ pub struct CancelWithdrawRequest<'info> {
     pub vault: AccountLoader<'info, Vault>,
     #[account(
         mut,
-        seeds = [b"vault_depositor", vault.key().as_ref(), authority.key().as_ref()],
+        seeds = [b"vault_depositor", vault.key().as_ref(), vault_depositor.load()?.authority.as_ref()],
         bump,
-        constraint = is_authority_for_vault_depositor(&vault_depositor, &authority)?
+        constraint = is_authority_or_custodian_for_vault_depositor(&vault_depositor, &authority)?
     )]
     pub vault_depositor: AccountLoader<'info, VaultDepositor>,
+    /// the depositor authority or its custodian
     pub authority: Signer<'info>,
     #[account(
         constraint = is_user_stats_for_vault(&vault, &drift_user_stats.key())?
@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+use drift::state::user::User;
+
+use crate::constraints::is_user_for_vault;
+use crate::error::ErrorCode;
+use crate::state::{find_opposing_order, is_controlled_by_vault_operator, Vault};
+use crate::validate;
+
+/// Guard against the delegate trading the vault against the manager's or its own drift users. Remaining accounts
+/// are drift users controlled by the manager or delegate, fails with `DelegateSelfTrade` if any of them has an open
+/// order against one of the vault user's. Drift has no self-match prevention across users, so this can't stop the
+/// fill itself, it's for keepers and depositors to bundle with or monitor on.
+pub fn check_delegate_self_trade<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, CheckDelegateSelfTrade<'info>>,
+) -> Result<()> {
+    let vault = ctx.accounts.vault.load()?;
+    let vault_user = ctx.accounts.drift_user.load()?;
+
+    for account in ctx.remaining_accounts.iter() {
+        let counterparty = AccountLoader::<User>::try_from(account)?;
+        let counterparty = counterparty.load()?;
+
+        validate!(
+            account.key() != vault.user && is_controlled_by_vault_operator(&vault, &counterparty),
+            ErrorCode::Default,
+            "drift user {} is not controlled by the vault manager or delegate",
+            account.key()
+        )?;
+
+        if let Some(order) = find_opposing_order(&vault_user, &counterparty) {
+            msg!(
+                "vault order {} in {:?} market {} is opposed by drift user {}",
+                order.order_id,
+                order.market_type,
+                order.market_index,
+                account.key()
+            );
+            return Err(ErrorCode::DelegateSelfTrade.into());
+        }
+    }
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CheckDelegateSelfTrade<'info> {
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        constraint = is_user_for_vault(&vault, &drift_user.key())?
+    )]
+    pub drift_user: AccountLoader<'info, User>,
+}
@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+
+use crate::error::ErrorCode;
+use crate::state::Vault;
+use crate::validate;
+
+/// Fails unless the vault's [`Vault::state_hash`] is `expected_state_hash`. Keepers put it ahead of fee and withdraw
+/// cranks in the same transaction, so a crank priced against a vault that changed since (e.g. a depositor withdrew)
+/// fails here cheaply with a distinct error instead of part way through.
+pub fn check_vault_state_hash(
+    ctx: Context<CheckVaultStateHash>,
+    expected_state_hash: u64,
+) -> Result<()> {
+    let state_hash = ctx.accounts.vault.load()?.state_hash();
+
+    validate!(
+        state_hash == expected_state_hash,
+        ErrorCode::VaultStateChanged,
+        "vault state hash {} != expected {}",
+        state_hash,
+        expected_state_hash
+    )?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CheckVaultStateHash<'info> {
+    pub vault: AccountLoader<'info, Vault>,
+}
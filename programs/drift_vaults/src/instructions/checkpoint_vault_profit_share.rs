@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{Vault, VaultProfitShareHistory};
+
+/// Permissionless crank, rolls the [`VaultProfitShareHistory`] at most once every
+/// [`PROFIT_SHARE_CHECKPOINT_PERIOD`](crate::state::PROFIT_SHARE_CHECKPOINT_PERIOD).
+pub fn checkpoint_vault_profit_share<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, CheckpointVaultProfitShare<'info>>,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let vault = ctx.accounts.vault.load()?;
+
+    ctx.accounts
+        .vault_profit_share_history
+        .load_mut()?
+        .checkpoint(&vault, now)?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CheckpointVaultProfitShare<'info> {
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        mut,
+        seeds = [b"vault_profit_share_history", vault.key().as_ref()],
+        bump = vault_profit_share_history.load()?.bump,
+    )]
+    pub vault_profit_share_history: AccountLoader<'info, VaultProfitShareHistory>,
+}
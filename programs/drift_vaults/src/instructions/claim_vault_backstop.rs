@@ -0,0 +1,137 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{TokenAccount, TokenInterface};
+use drift::cpi::accounts::Deposit as DriftDeposit;
+use drift::instructions::optional_accounts::AccountMaps;
+use drift::program::Drift;
+use drift::state::user::User;
+
+use crate::constraints::{is_user_for_vault, is_user_stats_for_vault};
+use crate::drift_cpi::DepositCPI;
+use crate::error::ErrorCode;
+use crate::state::{TokenMintProvider, Vault, VaultBackstop, VaultProtocolProvider};
+use crate::token_cpi::{get_amount_after_transfer_fee, transfer_tokens, TokenTransferCPI};
+use crate::{declare_vault_seeds, implement_deposit, validate, AccountMapProvider};
+
+/// Tops up vault equity from the [`VaultBackstop`] when the share price fell more than the deductible below the
+/// pool's high water mark, and raises the high water mark otherwise. The top up is deposited without shares so it
+/// accrues to every shareholder. Permissionless, so the top up lands as soon as anyone cranks it after a loss rather
+/// than when the manager chooses to. Equity isn't final while the vault is being liquidated, so claims wait for the
+/// liquidation to end.
+pub fn claim_vault_backstop<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, ClaimVaultBackstop<'info>>,
+) -> Result<()> {
+    let clock = &Clock::get()?;
+    let vault = ctx.accounts.vault.load()?;
+    validate!(!vault.in_liquidation(), ErrorCode::OngoingLiquidation)?;
+
+    let vp = ctx.vault_protocol();
+    vault.validate_vault_protocol(&vp)?;
+
+    let user = ctx.accounts.drift_user.load()?;
+
+    let AccountMaps {
+        perp_market_map,
+        spot_market_map,
+        mut oracle_map,
+    } = ctx.load_maps(
+        clock.slot,
+        Some(vault.spot_market_index),
+        vp.is_some(),
+        false,
+    )?;
+
+    let vault_equity =
+        vault.calculate_equity(&user, &perp_market_map, &spot_market_map, &mut oracle_map)?;
+
+    let amount = ctx.accounts.vault_backstop.load_mut()?.claim(
+        &vault,
+        vault_equity,
+        clock.unix_timestamp,
+    )?;
+
+    drop(vault);
+    drop(user);
+
+    if amount == 0 {
+        msg!("no backstop claim");
+        return Ok(());
+    }
+
+    ctx.token_transfer(amount)?;
+
+    let mint = ctx.token_mint(&ctx.accounts.vault_token_account.mint)?;
+    let received_amount = get_amount_after_transfer_fee(mint.as_ref(), amount)?;
+    ctx.drift_deposit(received_amount)?;
+
+    msg!("backstop topped up vault equity by {}", received_amount);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ClaimVaultBackstop<'info> {
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        mut,
+        seeds = [b"vault_backstop", vault.key().as_ref()],
+        bump = vault_backstop.load()?.bump,
+    )]
+    pub vault_backstop: AccountLoader<'info, VaultBackstop>,
+    #[account(
+        mut,
+        seeds = [b"vault_backstop_token_account".as_ref(), vault.key().as_ref()],
+        bump,
+    )]
+    pub backstop_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        seeds = [b"vault_token_account".as_ref(), vault.key().as_ref()],
+        bump,
+    )]
+    pub vault_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        constraint = is_user_stats_for_vault(&vault, &drift_user_stats.key())?
+    )]
+    /// CHECK: checked in drift cpi
+    pub drift_user_stats: AccountInfo<'info>,
+    #[account(
+        mut,
+        constraint = is_user_for_vault(&vault, &drift_user.key())?
+    )]
+    /// CHECK: checked in drift cpi
+    pub drift_user: AccountLoader<'info, User>,
+    /// CHECK: checked in drift cpi
+    pub drift_state: AccountInfo<'info>,
+    #[account(
+        mut,
+        token::mint = vault_token_account.mint
+    )]
+    pub drift_spot_market_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub drift_program: Program<'info, Drift>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'c: 'info, 'info> TokenTransferCPI for Context<'_, '_, 'c, 'info, ClaimVaultBackstop<'info>> {
+    fn token_transfer(&self, amount: u64) -> Result<()> {
+        declare_vault_seeds!(self.accounts.vault, seeds);
+
+        transfer_tokens(
+            self.accounts.token_program.to_account_info(),
+            self.accounts.backstop_token_account.to_account_info(),
+            self.accounts.vault_token_account.to_account_info(),
+            self.accounts.vault.to_account_info(),
+            self.token_mint(&self.accounts.vault_token_account.mint)?
+                .as_ref(),
+            amount,
+            seeds,
+        )
+    }
+}
+
+impl<'info> DepositCPI for Context<'_, '_, '_, 'info, ClaimVaultBackstop<'info>> {
+    fn drift_deposit(&self, amount: u64) -> Result<()> {
+        implement_deposit!(self, amount);
+        Ok(())
+    }
+}
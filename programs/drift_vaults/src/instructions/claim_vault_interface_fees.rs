@@ -0,0 +1,112 @@
+use anchor_lang::prelude::*;
+use drift::instructions::optional_accounts::AccountMaps;
+use drift::math::insurance::{
+    if_shares_to_vault_amount as depositor_shares_to_vault_amount,
+    vault_amount_to_if_shares as vault_amount_to_depositor_shares,
+};
+use drift::math::safe_math::SafeMath;
+use drift::state::user::{FuelOverflowStatus, User, UserStats};
+
+use crate::constraints::{
+    is_authority_for_vault_depositor, is_user_for_vault, is_user_stats_for_vault,
+    is_vault_for_vault_depositor,
+};
+use crate::state::{
+    FuelOverflowProvider, Vault, VaultConfigProvider, VaultDepositor, VaultDepositorLoader,
+    VaultInterface, VaultProtocolProvider,
+};
+use crate::AccountMapProvider;
+
+/// Pays the interface's accrued fee by moving shares from the manager to the interface's vault depositor, capped by
+/// the manager's shares not already requested for withdraw.
+pub fn claim_vault_interface_fees<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, ClaimVaultInterfaceFees<'info>>,
+) -> Result<()> {
+    let clock = &Clock::get()?;
+    let mut vault = ctx.accounts.vault.load_mut()?;
+    let mut vault_depositor = ctx.accounts.vault_depositor.load_mut_migrated()?;
+    let mut vault_interface = ctx.accounts.vault_interface.load_mut()?;
+
+    let vault_config = ctx.vault_config(&vault)?;
+    let mut vp = ctx.vault_protocol();
+    vault.validate_vault_protocol(&vp)?;
+    let mut vp = vp.as_mut().map(|vp| vp.load_mut()).transpose()?;
+
+    let user = ctx.accounts.drift_user.load()?;
+
+    let user_stats = ctx.accounts.drift_user_stats.load()?;
+    let has_fuel_overflow = FuelOverflowStatus::exists(user_stats.fuel_overflow_status);
+    let fuel_overflow = ctx.fuel_overflow(vp.is_some(), has_fuel_overflow);
+    user_stats.validate_fuel_overflow(&fuel_overflow)?;
+
+    let AccountMaps {
+        perp_market_map,
+        spot_market_map,
+        mut oracle_map,
+    } = ctx.load_maps(clock.slot, None, vp.is_some(), has_fuel_overflow)?;
+
+    let vault_equity =
+        vault.calculate_equity(&user, &perp_market_map, &spot_market_map, &mut oracle_map)?;
+
+    vault_depositor.apply_rebase(&mut vault, &mut vp, vault_equity, clock.unix_timestamp)?;
+
+    let manager_shares_available = vault
+        .get_manager_shares(&mut vp)?
+        .saturating_sub(vault.last_manager_withdraw_request.shares);
+    let fee_shares = vault_amount_to_depositor_shares(
+        vault_interface.accrued_fee,
+        vault.total_shares,
+        vault_equity,
+    )?
+    .min(manager_shares_available);
+    let fee_paid = depositor_shares_to_vault_amount(fee_shares, vault.total_shares, vault_equity)?
+        .min(vault_interface.accrued_fee);
+
+    vault_depositor.with_fuel_sync(
+        clock.unix_timestamp,
+        &mut vault,
+        &vault_config,
+        &user_stats,
+        &fuel_overflow,
+        |vd, vault| vd.increase_vault_shares(fee_shares, vault),
+    )?;
+    vault.user_shares = vault.user_shares.safe_add(fee_shares)?;
+    vault_interface.claim(fee_paid)?;
+
+    msg!(
+        "interface {} claimed {} ({} shares), {} still accrued",
+        vault_interface.interface,
+        fee_paid,
+        fee_shares,
+        vault_interface.accrued_fee
+    );
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ClaimVaultInterfaceFees<'info> {
+    #[account(mut)]
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        mut,
+        seeds = [b"vault_interface", vault.key().as_ref(), interface.key().as_ref()],
+        bump = vault_interface.load()?.bump,
+    )]
+    pub vault_interface: AccountLoader<'info, VaultInterface>,
+    #[account(
+        mut,
+        constraint = is_vault_for_vault_depositor(&vault_depositor, &vault)?,
+        constraint = is_authority_for_vault_depositor(&vault_depositor, &interface)?,
+    )]
+    pub vault_depositor: AccountLoader<'info, VaultDepositor>,
+    pub interface: Signer<'info>,
+    #[account(
+        constraint = is_user_stats_for_vault(&vault, &drift_user_stats.key())?
+    )]
+    pub drift_user_stats: AccountLoader<'info, UserStats>,
+    #[account(
+        constraint = is_user_for_vault(&vault, &drift_user.key())?
+    )]
+    pub drift_user: AccountLoader<'info, User>,
+}
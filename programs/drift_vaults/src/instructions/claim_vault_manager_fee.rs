@@ -0,0 +1,75 @@
+use anchor_lang::prelude::*;
+use drift::instructions::optional_accounts::AccountMaps;
+use drift::state::user::User;
+
+use crate::constraints::{is_manager_for_vault, is_user_for_vault};
+use crate::state::{Vault, VaultConfigProvider, VaultManagerFeeClaim};
+use crate::{AccountMapProvider, VaultProtocolProvider};
+
+/// Accrues the management fee since the last claim into the [`VaultManagerFeeClaim`] and mints what the claim
+/// period's cap allows to the manager.
+pub fn claim_vault_manager_fee<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, ClaimVaultManagerFee<'info>>,
+) -> Result<()> {
+    let clock = &Clock::get()?;
+
+    let mut vault = ctx.accounts.vault.load_mut()?;
+    let mut vault_manager_fee_claim = ctx.accounts.vault_manager_fee_claim.load_mut()?;
+
+    let vault_config = ctx.vault_config(&vault)?;
+
+    // backwards compatible: if last rem acct does not deserialize into [`VaultProtocol`] then it's a legacy vault.
+    let mut vp = ctx.vault_protocol();
+    vault.validate_vault_protocol(&vp)?;
+    let mut vp = vp.as_mut().map(|vp| vp.load_mut()).transpose()?;
+
+    let user = ctx.accounts.drift_user.load()?;
+    let spot_market_index = vault.spot_market_index;
+
+    let AccountMaps {
+        perp_market_map,
+        spot_market_map,
+        mut oracle_map,
+    } = ctx.load_maps(clock.slot, Some(spot_market_index), vp.is_some(), false)?;
+
+    let vault_equity =
+        vault.calculate_equity(&user, &perp_market_map, &spot_market_map, &mut oracle_map)?;
+
+    vault.apply_fee(&vault_config, &mut vp, vault_equity, clock.unix_timestamp)?;
+
+    let accrued_shares =
+        vault_manager_fee_claim.accrue(&mut vault, vault_equity, clock.unix_timestamp)?;
+    let claimed_shares = vault_manager_fee_claim.claim(&mut vault, clock.unix_timestamp)?;
+
+    msg!(
+        "accrued {} claimed {} management fee shares, {} left to claim",
+        accrued_shares,
+        claimed_shares,
+        vault_manager_fee_claim.accrued_shares
+    );
+
+    // in case total_shares is pushed to level that warrants a rebase
+    vault.apply_rebase(&mut vp, vault_equity, clock.unix_timestamp)?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ClaimVaultManagerFee<'info> {
+    #[account(
+        mut,
+        constraint = is_manager_for_vault(&vault, &manager)?
+    )]
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        mut,
+        seeds = [b"vault_manager_fee_claim", vault.key().as_ref()],
+        bump = vault_manager_fee_claim.load()?.bump,
+    )]
+    pub vault_manager_fee_claim: AccountLoader<'info, VaultManagerFeeClaim>,
+    #[account(
+        constraint = is_user_for_vault(&vault, &drift_user.key())?
+    )]
+    pub drift_user: AccountLoader<'info, User>,
+    pub manager: Signer<'info>,
+}
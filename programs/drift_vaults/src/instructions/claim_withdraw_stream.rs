@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+use drift::math::safe_math::SafeMath;
+
+use crate::state::{VaultDepositorBase, VaultDepositorLoader, VaultWithdrawStreamProvider};
+
+use super::Withdraw;
+
+/// Withdraws the part of the depositor's [`VaultWithdrawStream`](crate::state::VaultWithdrawStream) released since
+/// the last claim, the rest stays requested. The stream must be writable in remaining_accounts after the market
+/// accounts.
+pub fn claim_withdraw_stream<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, Withdraw<'info>>,
+) -> Result<()> {
+    let clock = &Clock::get()?;
+
+    let vault = ctx.accounts.vault.clone();
+    let vault_depositor = ctx.accounts.vault_depositor.clone();
+    let vault_withdraw_stream = ctx.vault_withdraw_stream(&vault_depositor.key())?;
+
+    let (mut remaining, shares_base) = {
+        let mut vault_depositor = vault_depositor.load_mut_migrated()?;
+        let mut vault_withdraw_stream = vault_withdraw_stream.load_mut()?;
+
+        let tranche = vault_withdraw_stream.next_tranche(
+            &vault_depositor.last_withdraw_request,
+            vault_depositor.get_vault_shares_base(),
+            clock.unix_timestamp,
+        )?;
+        msg!(
+            "claiming stream tranche shares={} value={}",
+            tranche.shares,
+            tranche.value
+        );
+
+        // withdraw only the released tranche
+        vault_depositor.last_withdraw_request = tranche;
+        (
+            vault_withdraw_stream.claim(&tranche)?,
+            vault_depositor.get_vault_shares_base(),
+        )
+    };
+
+    super::withdraw(ctx)?;
+
+    if remaining.pending() {
+        let mut vault_depositor = vault_depositor.load_mut_migrated()?;
+        // a rebase during the withdraw ends the stream, the rest can still be withdrawn as a normal request
+        if vault_depositor.get_vault_shares_base() != shares_base {
+            let expo_diff = vault_depositor
+                .get_vault_shares_base()
+                .safe_sub(shares_base)?;
+            remaining.rebase(10_u128.pow(expo_diff))?;
+        }
+        vault_depositor.last_withdraw_request = remaining;
+
+        vault_depositor.increment_active_withdraw_request_count(&mut *vault.load_mut()?)?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,215 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Transfer};
+use anchor_spl::token::{Token, TokenAccount};
+use drift::cpi::accounts::Withdraw as DriftWithdraw;
+use drift::instructions::optional_accounts::AccountMaps;
+use drift::math::casting::Cast;
+use drift::math::safe_math::SafeMath;
+use drift::program::Drift;
+use drift::state::user::{User, UserStats};
+
+use crate::constraints::{is_user_for_vault, is_user_stats_for_vault, is_vault_protocol_for_vault};
+use crate::drift_cpi::WithdrawCPI;
+use crate::state::{
+    ProtocolFeeCollectStep, ProtocolTreasury, Vault, VaultConfigProvider,
+    VaultManagerActivityProvider, VaultProtocol,
+};
+use crate::token_cpi::TokenTransferCPI;
+use crate::{declare_vault_seeds, AccountMapProvider, WithdrawUnit};
+
+/// Permissionless, advances the protocol's fee collection for one vault: requests a withdraw of all protocol shares,
+/// or once the vault's redeem period is over withdraws them to the [`ProtocolTreasury`]. Vaults that can't advance
+/// are skipped rather than failing, so a keeper can batch many vaults in one transaction.
+pub fn collect_protocol_fees<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, CollectProtocolFees<'info>>,
+) -> Result<()> {
+    let clock = &Clock::get()?;
+    let mut vault = ctx.accounts.vault.load_mut()?;
+    let now = clock.unix_timestamp;
+
+    let step =
+        ProtocolTreasury::next_collect_step(&vault, &*ctx.accounts.vault_protocol.load()?, now)?;
+    match step {
+        ProtocolFeeCollectStep::Skip => {
+            msg!("no protocol shares to collect");
+            return Ok(());
+        }
+        ProtocolFeeCollectStep::Wait => {
+            msg!("protocol withdraw request in redeem period");
+            return Ok(());
+        }
+        ProtocolFeeCollectStep::Request | ProtocolFeeCollectStep::Withdraw => {}
+    }
+
+    let user = ctx.accounts.drift_user.load()?;
+    let spot_market_index = vault.spot_market_index;
+
+    let vault_config = ctx.vault_config(&vault)?;
+
+    let mut vp = Some(ctx.accounts.vault_protocol.load_mut()?);
+
+    let AccountMaps {
+        perp_market_map,
+        spot_market_map,
+        mut oracle_map,
+    } = ctx.load_maps(clock.slot, Some(spot_market_index), vp.is_some(), false)?;
+
+    let vault_equity =
+        vault.calculate_equity(&user, &perp_market_map, &spot_market_map, &mut oracle_map)?;
+
+    let spot_market = spot_market_map.get_ref(&spot_market_index)?;
+    let oracle = oracle_map.get_price_data(&spot_market.oracle_id())?;
+
+    if step == ProtocolFeeCollectStep::Request {
+        let protocol_shares = vault.get_protocol_shares(&mut vp);
+        vault.protocol_request_withdraw(
+            &vault_config,
+            &mut vp,
+            protocol_shares.cast()?,
+            WithdrawUnit::Shares,
+            vault_equity,
+            now,
+            oracle.price,
+        )?;
+        msg!("requested withdraw of {} protocol shares", protocol_shares);
+
+        return Ok(());
+    }
+
+    let protocol_withdraw_amount =
+        vault.protocol_withdraw(&vault_config, &mut vp, vault_equity, now, oracle.price)?;
+
+    drop(spot_market);
+    drop(vault);
+    drop(user);
+    drop(vp);
+
+    ctx.drift_withdraw(protocol_withdraw_amount)?;
+
+    ctx.token_transfer(protocol_withdraw_amount)?;
+
+    let mut protocol_treasury = ctx.accounts.protocol_treasury.load_mut()?;
+    protocol_treasury.total_collected = protocol_treasury
+        .total_collected
+        .safe_add(protocol_withdraw_amount)?;
+    msg!(
+        "collected {} protocol fees to {}",
+        protocol_withdraw_amount,
+        ctx.accounts.treasury_token_account.key()
+    );
+
+    ctx.accounts.vault.load()?.emit_health_record(
+        &*ctx.accounts.drift_user.load()?,
+        &perp_market_map,
+        &spot_market_map,
+        &mut oracle_map,
+        protocol_withdraw_amount,
+        ctx.last_manager_activity_ts(&ctx.accounts.vault.key())?,
+        clock.unix_timestamp,
+    )?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CollectProtocolFees<'info> {
+    #[account(mut)]
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        mut,
+        constraint = is_vault_protocol_for_vault(&vault_protocol, &vault)?
+    )]
+    pub vault_protocol: AccountLoader<'info, VaultProtocol>,
+    #[account(
+        mut,
+        seeds = [b"protocol_treasury", vault_protocol.load()?.protocol.as_ref()],
+        bump = protocol_treasury.load()?.bump,
+    )]
+    pub protocol_treasury: AccountLoader<'info, ProtocolTreasury>,
+    #[account(
+        mut,
+        seeds = [b"vault_token_account".as_ref(), vault.key().as_ref()],
+        bump,
+    )]
+    pub vault_token_account: Box<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        constraint = is_user_stats_for_vault(&vault, &drift_user_stats.key())?
+    )]
+    /// CHECK: checked in drift cpi
+    pub drift_user_stats: AccountLoader<'info, UserStats>,
+    #[account(
+        mut,
+        constraint = is_user_for_vault(&vault, &drift_user.key())?
+    )]
+    /// CHECK: checked in drift cpi
+    pub drift_user: AccountLoader<'info, User>,
+    /// CHECK: checked in drift cpi
+    pub drift_state: AccountInfo<'info>,
+    #[account(
+        mut,
+        token::mint = vault_token_account.mint
+    )]
+    pub drift_spot_market_vault: Box<Account<'info, TokenAccount>>,
+    /// CHECK: checked in drift cpi
+    pub drift_signer: AccountInfo<'info>,
+    #[account(
+        mut,
+        token::authority = protocol_treasury.load()?.treasury,
+        token::mint = vault_token_account.mint
+    )]
+    pub treasury_token_account: Box<Account<'info, TokenAccount>>,
+    pub drift_program: Program<'info, Drift>,
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> WithdrawCPI for Context<'_, '_, '_, 'info, CollectProtocolFees<'info>> {
+    fn drift_withdraw(&self, amount: u64) -> Result<()> {
+        declare_vault_seeds!(self.accounts.vault, seeds);
+        let spot_market_index = self.accounts.vault.load()?.spot_market_index;
+
+        let cpi_accounts = DriftWithdraw {
+            state: self.accounts.drift_state.to_account_info().clone(),
+            user: self.accounts.drift_user.to_account_info().clone(),
+            user_stats: self.accounts.drift_user_stats.to_account_info().clone(),
+            authority: self.accounts.vault.to_account_info().clone(),
+            spot_market_vault: self
+                .accounts
+                .drift_spot_market_vault
+                .to_account_info()
+                .clone(),
+            drift_signer: self.accounts.drift_signer.to_account_info().clone(),
+            user_token_account: self.accounts.vault_token_account.to_account_info().clone(),
+            token_program: self.accounts.token_program.to_account_info().clone(),
+        };
+
+        let drift_program = self.accounts.drift_program.to_account_info().clone();
+        let cpi_context = CpiContext::new_with_signer(drift_program, cpi_accounts, seeds)
+            .with_remaining_accounts(self.remaining_accounts.into());
+        drift::cpi::withdraw(cpi_context, spot_market_index, amount, false)?;
+
+        Ok(())
+    }
+}
+
+impl<'info> TokenTransferCPI for Context<'_, '_, '_, 'info, CollectProtocolFees<'info>> {
+    fn token_transfer(&self, amount: u64) -> Result<()> {
+        declare_vault_seeds!(self.accounts.vault, seeds);
+
+        let cpi_accounts = Transfer {
+            from: self.accounts.vault_token_account.to_account_info().clone(),
+            to: self
+                .accounts
+                .treasury_token_account
+                .to_account_info()
+                .clone(),
+            authority: self.accounts.vault.to_account_info().clone(),
+        };
+        let token_program = self.accounts.token_program.to_account_info().clone();
+        let cpi_context = CpiContext::new_with_signer(token_program, cpi_accounts, seeds);
+
+        token::transfer(cpi_context, amount)?;
+
+        Ok(())
+    }
+}
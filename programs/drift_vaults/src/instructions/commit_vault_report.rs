@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+use crate::constraints::is_manager_for_vault;
+use crate::state::{Vault, VaultReport};
+use crate::Size;
+
+/// Commits the hash of the manager's report for accounting `epoch`, once per epoch.
+pub fn commit_vault_report<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, CommitVaultReport<'info>>,
+    epoch: u32,
+    report_hash: [u8; 32],
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+
+    let mut vault_report = ctx.accounts.vault_report.load_init()?;
+    vault_report.bump = ctx.bumps.vault_report;
+    vault_report.commit(ctx.accounts.vault.key(), epoch, report_hash, now);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(epoch: u32)]
+pub struct CommitVaultReport<'info> {
+    #[account(
+        constraint = is_manager_for_vault(&vault, &manager)?,
+    )]
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        init,
+        seeds = [b"vault_report", vault.key().as_ref(), epoch.to_le_bytes().as_ref()],
+        space = VaultReport::SIZE,
+        bump,
+        payer = payer
+    )]
+    pub vault_report: AccountLoader<'info, VaultReport>,
+    pub manager: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub rent: Sysvar<'info, Rent>,
+    pub system_program: Program<'info, System>,
+}
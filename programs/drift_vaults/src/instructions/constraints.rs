@@ -1,10 +1,27 @@
 use anchor_lang::prelude::*;
 use anchor_spl::associated_token::get_associated_token_address;
 use drift::state::insurance_fund_stake::InsuranceFundStake;
+use drift::state::user::User;
 
-use crate::state::VaultProtocol;
+use crate::state::{VaultDepositorFlag, VaultProtocol};
 use crate::{TokenizedVaultDepositor, Vault, VaultDepositor};
 
+pub fn is_not_blacklisted_vault_depositor(
+    vault_depositor: &AccountLoader<VaultDepositor>,
+) -> Result<bool> {
+    if vault_depositor
+        .load()?
+        .has_flag(VaultDepositorFlag::Blacklisted)
+    {
+        let ec = crate::error::ErrorCode::VaultDepositorBlacklisted;
+        msg!("Error {} thrown at {}:{}", ec, file!(), line!());
+        msg!("VaultDepositor is blacklisted from depositing");
+        Err(anchor_lang::error::Error::from(ec))
+    } else {
+        Ok(true)
+    }
+}
+
 pub fn is_vault_for_vault_depositor(
     vault_depositor: &AccountLoader<VaultDepositor>,
     vault: &AccountLoader<Vault>,
@@ -19,6 +36,23 @@ pub fn is_authority_for_vault_depositor(
     Ok(vault_depositor.load()?.authority.eq(signer.key))
 }
 
+pub fn is_authority_or_custodian_for_vault_depositor(
+    vault_depositor: &AccountLoader<VaultDepositor>,
+    signer: &Signer,
+) -> Result<bool> {
+    let vault_depositor = vault_depositor.load()?;
+    Ok(vault_depositor.authority.eq(signer.key)
+        || (vault_depositor.custodian != Pubkey::default()
+            && vault_depositor.custodian.eq(signer.key)))
+}
+
+pub fn is_authority_for_drift_user(
+    drift_user: &AccountLoader<User>,
+    signer: &Signer,
+) -> Result<bool> {
+    Ok(drift_user.load()?.authority.eq(signer.key))
+}
+
 pub fn is_manager_for_vault(vault: &AccountLoader<Vault>, signer: &Signer) -> Result<bool> {
     Ok(vault.load()?.manager.eq(signer.key))
 }
@@ -47,6 +81,14 @@ pub fn is_delegate_for_vault(vault: &AccountLoader<Vault>, signer: &Signer) -> R
     Ok(vault.load()?.delegate.eq(signer.key))
 }
 
+pub fn is_liquidation_delegate_for_vault(
+    vault: &AccountLoader<Vault>,
+    signer: &Signer,
+) -> Result<bool> {
+    let vault = vault.load()?;
+    Ok(vault.in_liquidation() && vault.liquidation_delegate.eq(signer.key))
+}
+
 pub fn is_user_for_vault(vault: &AccountLoader<Vault>, user_key: &Pubkey) -> Result<bool> {
     Ok(vault.load()?.user.eq(user_key))
 }
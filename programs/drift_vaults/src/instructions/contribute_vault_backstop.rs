@@ -0,0 +1,162 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{TokenAccount, TokenInterface};
+use drift::cpi::accounts::Withdraw as DriftWithdraw;
+use drift::instructions::optional_accounts::AccountMaps;
+use drift::program::Drift;
+use drift::state::user::{User, UserStats};
+
+use crate::constraints::{is_user_for_vault, is_user_stats_for_vault};
+use crate::drift_cpi::WithdrawCPI;
+use crate::state::{
+    TokenMintProvider, Vault, VaultBackstop, VaultConfigProvider, VaultProtocolProvider,
+};
+use crate::token_cpi::{get_amount_after_transfer_fee, transfer_tokens, TokenTransferCPI};
+use crate::{declare_vault_seeds, AccountMapProvider};
+
+/// Permissionless, moves the [`VaultBackstop`] contributions owed out of the manager's shares into the pool.
+pub fn contribute_vault_backstop<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, ContributeVaultBackstop<'info>>,
+) -> Result<()> {
+    let clock = &Clock::get()?;
+    let mut vault = ctx.accounts.vault.load_mut()?;
+
+    let vault_config = ctx.vault_config(&vault)?;
+
+    let mut vp = ctx.vault_protocol();
+    vault.validate_vault_protocol(&vp)?;
+    let mut vp = vp.as_mut().map(|vp| vp.load_mut()).transpose()?;
+
+    let user = ctx.accounts.drift_user.load()?;
+    let spot_market_index = vault.spot_market_index;
+
+    let AccountMaps {
+        perp_market_map,
+        spot_market_map,
+        mut oracle_map,
+    } = ctx.load_maps(clock.slot, Some(spot_market_index), vp.is_some(), false)?;
+
+    let vault_equity =
+        vault.calculate_equity(&user, &perp_market_map, &spot_market_map, &mut oracle_map)?;
+
+    let amount = ctx.accounts.vault_backstop.load_mut()?.contribute(
+        &mut vault,
+        &vault_config,
+        &mut vp,
+        vault_equity,
+        clock.unix_timestamp,
+    )?;
+
+    drop(vault);
+    drop(user);
+    drop(vp);
+
+    if amount == 0 {
+        msg!("no backstop contribution owed");
+        return Ok(());
+    }
+
+    ctx.drift_withdraw(amount)?;
+
+    let mint = ctx.token_mint(&ctx.accounts.vault_token_account.mint)?;
+    let received_amount = get_amount_after_transfer_fee(mint.as_ref(), amount)?;
+    ctx.token_transfer(received_amount)?;
+
+    msg!("contributed {} to the backstop", amount);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ContributeVaultBackstop<'info> {
+    #[account(mut)]
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        mut,
+        seeds = [b"vault_backstop", vault.key().as_ref()],
+        bump = vault_backstop.load()?.bump,
+    )]
+    pub vault_backstop: AccountLoader<'info, VaultBackstop>,
+    #[account(
+        mut,
+        seeds = [b"vault_token_account".as_ref(), vault.key().as_ref()],
+        bump,
+    )]
+    pub vault_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        constraint = is_user_stats_for_vault(&vault, &drift_user_stats.key())?
+    )]
+    /// CHECK: checked in drift cpi
+    pub drift_user_stats: AccountLoader<'info, UserStats>,
+    #[account(
+        mut,
+        constraint = is_user_for_vault(&vault, &drift_user.key())?
+    )]
+    /// CHECK: checked in drift cpi
+    pub drift_user: AccountLoader<'info, User>,
+    /// CHECK: checked in drift cpi
+    pub drift_state: AccountInfo<'info>,
+    #[account(
+        mut,
+        token::mint = vault_token_account.mint
+    )]
+    pub drift_spot_market_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+    /// CHECK: checked in drift cpi
+    pub drift_signer: AccountInfo<'info>,
+    #[account(
+        mut,
+        seeds = [b"vault_backstop_token_account".as_ref(), vault.key().as_ref()],
+        bump,
+    )]
+    pub backstop_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub drift_program: Program<'info, Drift>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> WithdrawCPI for Context<'_, '_, '_, 'info, ContributeVaultBackstop<'info>> {
+    fn drift_withdraw(&self, amount: u64) -> Result<()> {
+        declare_vault_seeds!(self.accounts.vault, seeds);
+        let spot_market_index = self.accounts.vault.load()?.spot_market_index;
+
+        let cpi_accounts = DriftWithdraw {
+            state: self.accounts.drift_state.to_account_info().clone(),
+            user: self.accounts.drift_user.to_account_info().clone(),
+            user_stats: self.accounts.drift_user_stats.to_account_info().clone(),
+            authority: self.accounts.vault.to_account_info().clone(),
+            spot_market_vault: self
+                .accounts
+                .drift_spot_market_vault
+                .to_account_info()
+                .clone(),
+            drift_signer: self.accounts.drift_signer.to_account_info().clone(),
+            user_token_account: self.accounts.vault_token_account.to_account_info().clone(),
+            token_program: self.accounts.token_program.to_account_info().clone(),
+        };
+
+        let drift_program = self.accounts.drift_program.to_account_info().clone();
+        let cpi_context = CpiContext::new_with_signer(drift_program, cpi_accounts, seeds)
+            .with_remaining_accounts(self.remaining_accounts.into());
+        drift::cpi::withdraw(cpi_context, spot_market_index, amount, false)?;
+
+        Ok(())
+    }
+}
+
+impl<'c: 'info, 'info> TokenTransferCPI
+    for Context<'_, '_, 'c, 'info, ContributeVaultBackstop<'info>>
+{
+    fn token_transfer(&self, amount: u64) -> Result<()> {
+        declare_vault_seeds!(self.accounts.vault, seeds);
+
+        transfer_tokens(
+            self.accounts.token_program.to_account_info(),
+            self.accounts.vault_token_account.to_account_info(),
+            self.accounts.backstop_token_account.to_account_info(),
+            self.accounts.vault.to_account_info(),
+            self.token_mint(&self.accounts.vault_token_account.mint)?
+                .as_ref(),
+            amount,
+            seeds,
+        )
+    }
+}
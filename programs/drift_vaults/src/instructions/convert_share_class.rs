@@ -0,0 +1,164 @@
+use anchor_lang::prelude::*;
+use drift::instructions::optional_accounts::AccountMaps;
+use drift::state::user::{FuelOverflowStatus, User, UserStats};
+
+use crate::constraints::{
+    is_authority_for_vault_depositor, is_user_for_vault, is_user_stats_for_vault,
+};
+use crate::error::ErrorCode;
+use crate::state::traits::VaultDepositorBase;
+use crate::state::{
+    Vault, VaultConfigProvider, VaultDepositor, VaultDepositorLoader, VaultDepositorShareClass,
+    VaultShareClass, WithdrawUnit,
+};
+use crate::{validate, AccountMapProvider, FuelOverflowProvider, VaultProtocolProvider};
+
+/// Converts `amount` of the depositor's shares into the vault's locked [`VaultShareClass`], or back out of it once
+/// the lock has passed if `locked` is false. Profit share is charged on both sides at their current terms first, so
+/// the locked class's discount only applies to profits from the conversion on.
+pub fn convert_share_class<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, ConvertShareClass<'info>>,
+    amount: u64,
+    unit: WithdrawUnit,
+    locked: bool,
+) -> Result<()> {
+    let clock = &Clock::get()?;
+
+    let mut vault = ctx.accounts.vault.load_mut()?;
+    validate!(!vault.in_liquidation(), ErrorCode::OngoingLiquidation)?;
+
+    let mut vault_depositor = ctx.accounts.vault_depositor.load_mut_migrated()?;
+    let mut vault_share_class = ctx.accounts.vault_share_class.load_mut()?;
+    let mut vault_depositor_share_class = ctx.accounts.vault_depositor_share_class.load_mut()?;
+
+    if locked {
+        validate!(
+            !vault_depositor.last_withdraw_request.pending(),
+            ErrorCode::InvalidVaultShareClass,
+            "Cannot lock shares with a pending withdraw request"
+        )?;
+    } else {
+        vault_depositor_share_class.validate_unlocked(clock.unix_timestamp)?;
+    }
+
+    let vault_config = ctx.vault_config(&vault)?;
+
+    // backwards compatible: if last rem acct does not deserialize into [`VaultProtocol`] then it's a legacy vault.
+    let mut vp = ctx.vault_protocol();
+    vault.validate_vault_protocol(&vp)?;
+    let mut vp = vp.as_mut().map(|vp| vp.load_mut()).transpose()?;
+
+    let user = ctx.accounts.drift_user.load()?;
+
+    let user_stats = ctx.accounts.drift_user_stats.load()?;
+    let has_fuel_overflow = FuelOverflowStatus::exists(user_stats.fuel_overflow_status);
+    let fuel_overflow = ctx.fuel_overflow(vp.is_some(), has_fuel_overflow);
+    user_stats.validate_fuel_overflow(&fuel_overflow)?;
+
+    let spot_market_index = vault.spot_market_index;
+    let AccountMaps {
+        perp_market_map,
+        spot_market_map,
+        mut oracle_map,
+    } = ctx.load_maps(
+        clock.slot,
+        Some(spot_market_index),
+        vp.is_some(),
+        has_fuel_overflow,
+    )?;
+
+    let vault_equity =
+        vault.calculate_equity(&user, &perp_market_map, &spot_market_map, &mut oracle_map)?;
+
+    let spot_market = spot_market_map.get_ref(&spot_market_index)?;
+    let oracle = oracle_map.get_price_data(&spot_market.oracle_id())?;
+
+    let (shares_converted, _) = vault_depositor.with_fuel_sync(
+        clock.unix_timestamp,
+        &mut vault,
+        &vault_config,
+        &user_stats,
+        &fuel_overflow,
+        |vd, vault| {
+            if locked {
+                vd.transfer_shares(
+                    &mut *vault_depositor_share_class,
+                    vault,
+                    &vault_config,
+                    &mut vp,
+                    amount,
+                    unit,
+                    vault_equity,
+                    clock.unix_timestamp,
+                    oracle.price,
+                )
+            } else {
+                vault_depositor_share_class.transfer_shares(
+                    vd,
+                    vault,
+                    &vault_config,
+                    &mut vp,
+                    amount,
+                    unit,
+                    vault_equity,
+                    clock.unix_timestamp,
+                    oracle.price,
+                )
+            }
+        },
+    )?;
+
+    if locked {
+        vault_share_class.record_lock(
+            &mut vault_depositor_share_class,
+            &vault,
+            shares_converted,
+            clock.unix_timestamp,
+        )?;
+    } else {
+        vault_share_class.record_unlock(
+            &vault_depositor_share_class,
+            &vault,
+            shares_converted,
+            clock.unix_timestamp,
+        )?;
+    }
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ConvertShareClass<'info> {
+    #[account(mut)]
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        mut,
+        seeds = [b"vault_depositor", vault.key().as_ref(), authority.key().as_ref()],
+        bump,
+        constraint = is_authority_for_vault_depositor(&vault_depositor, &authority)?,
+    )]
+    pub vault_depositor: AccountLoader<'info, VaultDepositor>,
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"vault_share_class", vault.key().as_ref()],
+        bump = vault_share_class.load()?.bump,
+    )]
+    pub vault_share_class: AccountLoader<'info, VaultShareClass>,
+    #[account(
+        mut,
+        seeds = [b"vault_depositor_share_class", vault_depositor.key().as_ref()],
+        bump = vault_depositor_share_class.load()?.bump,
+    )]
+    pub vault_depositor_share_class: AccountLoader<'info, VaultDepositorShareClass>,
+    #[account(
+        constraint = is_user_for_vault(&vault, &drift_user.key())?
+    )]
+    /// CHECK: checked in drift cpi
+    pub drift_user: AccountLoader<'info, User>,
+    #[account(
+        constraint = is_user_stats_for_vault(&vault, &drift_user_stats.key())?
+    )]
+    /// CHECK: checked in drift cpi
+    pub drift_user_stats: AccountLoader<'info, UserStats>,
+}
@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+use drift::instructions::optional_accounts::AccountMaps;
+use drift::state::user::User;
+
+use crate::constraints::is_user_for_vault;
+use crate::state::{Vault, VaultConfigProvider, VaultFeeHoliday};
+use crate::{AccountMapProvider, VaultProtocolProvider};
+
+/// Permissionless, starts or ends the vault's scheduled fee holiday.
+pub fn crank_vault_fee_holiday<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, CrankVaultFeeHoliday<'info>>,
+) -> Result<()> {
+    let clock = &Clock::get()?;
+
+    let mut vault = ctx.accounts.vault.load_mut()?;
+    let mut vault_fee_holiday = ctx.accounts.vault_fee_holiday.load_mut()?;
+
+    let vault_config = ctx.vault_config(&vault)?;
+
+    // backwards compatible: if last rem acct does not deserialize into [`VaultProtocol`] then it's a legacy vault.
+    let mut vp = ctx.vault_protocol();
+    vault.validate_vault_protocol(&vp)?;
+    let mut vp = vp.as_mut().map(|vp| vp.load_mut()).transpose()?;
+
+    let user = ctx.accounts.drift_user.load()?;
+    let spot_market_index = vault.spot_market_index;
+
+    let AccountMaps {
+        perp_market_map,
+        spot_market_map,
+        mut oracle_map,
+    } = ctx.load_maps(clock.slot, Some(spot_market_index), vp.is_some(), false)?;
+
+    let vault_equity =
+        vault.calculate_equity(&user, &perp_market_map, &spot_market_map, &mut oracle_map)?;
+
+    vault.apply_fee(&vault_config, &mut vp, vault_equity, clock.unix_timestamp)?;
+
+    let action = vault_fee_holiday.crank(&mut vault, clock.unix_timestamp)?;
+    vault_fee_holiday.emit_record(action, clock.unix_timestamp);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CrankVaultFeeHoliday<'info> {
+    #[account(mut)]
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        mut,
+        seeds = [b"vault_fee_holiday", vault.key().as_ref()],
+        bump = vault_fee_holiday.load()?.bump,
+    )]
+    pub vault_fee_holiday: AccountLoader<'info, VaultFeeHoliday>,
+    #[account(
+        constraint = is_user_for_vault(&vault, &drift_user.key())?
+    )]
+    pub drift_user: AccountLoader<'info, User>,
+}
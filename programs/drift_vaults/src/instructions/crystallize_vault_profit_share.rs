@@ -0,0 +1,75 @@
+use anchor_lang::prelude::*;
+use drift::instructions::optional_accounts::AccountMaps;
+use drift::state::user::User;
+
+use crate::constraints::is_user_for_vault;
+use crate::state::{Vault, VaultConfigProvider, VaultCrystallization, VaultProtocolProvider};
+use crate::AccountMapProvider;
+
+/// Permissionless crank, crystallizes the manager's fund level profit share at most once every
+/// `crystallization_period` and emits a [`VaultCrystallizationRecord`](crate::state::events::VaultCrystallizationRecord).
+/// Remaining accounts are the oracles and markets for the vault's positions, the vault's
+/// [`VaultConfig`](crate::state::VaultConfig) if it has one, then the vault's [`VaultProtocol`] if it has one.
+///
+/// [`VaultProtocol`]: crate::state::VaultProtocol
+pub fn crystallize_vault_profit_share<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, CrystallizeVaultProfitShare<'info>>,
+) -> Result<()> {
+    let clock = &Clock::get()?;
+    let mut vault = ctx.accounts.vault.load_mut()?;
+
+    let vault_config = ctx.vault_config(&vault)?;
+
+    let mut vp = ctx.vault_protocol();
+    vault.validate_vault_protocol(&vp)?;
+    let mut vp = vp.as_mut().map(|vp| vp.load_mut()).transpose()?;
+
+    let user = ctx.accounts.drift_user.load()?;
+
+    let AccountMaps {
+        perp_market_map,
+        spot_market_map,
+        mut oracle_map,
+    } = ctx.load_maps(clock.slot, None, vp.is_some(), false)?;
+
+    let vault_equity =
+        vault.calculate_equity(&user, &perp_market_map, &spot_market_map, &mut oracle_map)?;
+
+    // charge on the share price net of the management fee
+    vault.apply_fee(&vault_config, &mut vp, vault_equity, clock.unix_timestamp)?;
+
+    let mut vault_crystallization = ctx.accounts.vault_crystallization.load_mut()?;
+    let record = vault_crystallization.crystallize(
+        &mut vault,
+        &vault_config,
+        vault_equity,
+        clock.unix_timestamp,
+    )?;
+
+    msg!(
+        "crystallized profit share {} ({} shares) at share price {}",
+        record.profit_share_paid,
+        record.profit_share_shares,
+        record.share_price
+    );
+    emit!(record);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CrystallizeVaultProfitShare<'info> {
+    #[account(mut)]
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        mut,
+        seeds = [b"vault_crystallization", vault.key().as_ref()],
+        bump = vault_crystallization.load()?.bump,
+    )]
+    pub vault_crystallization: AccountLoader<'info, VaultCrystallization>,
+    #[account(
+        constraint = is_user_for_vault(&vault, &drift_user.key())?
+    )]
+    /// CHECK: checked in drift cpi
+    pub drift_user: AccountLoader<'info, User>,
+}
@@ -1,29 +1,97 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use anchor_spl::token_interface::{TokenAccount, TokenInterface};
 use drift::cpi::accounts::Deposit as DriftDeposit;
 use drift::instructions::optional_accounts::AccountMaps;
+use drift::math::safe_math::SafeMath;
 use drift::program::Drift;
 use drift::state::user::{FuelOverflowStatus, User, UserStats};
 
 use crate::constraints::{
-    is_authority_for_vault_depositor, is_user_for_vault, is_user_stats_for_vault,
+    is_authority_for_vault_depositor, is_not_blacklisted_vault_depositor, is_user_for_vault,
+    is_user_stats_for_vault,
 };
 use crate::drift_cpi::DepositCPI;
 use crate::error::ErrorCode;
-use crate::state::{FuelOverflowProvider, Vault, VaultDepositor, VaultProtocolProvider};
-use crate::token_cpi::TokenTransferCPI;
-use crate::{declare_vault_seeds, implement_deposit, validate, AccountMapProvider};
+use crate::hook_cpi::VaultHookCPI;
+use crate::state::events::VaultDepositorAction;
+use crate::state::{
+    get_vault_tvl, record_vault_depositor_action, validate_deposit_hook, ComputeUnitsHint,
+    FlowPreview, FuelOverflowProvider, InstructionsSysvarProvider, ProtocolTvlCapProvider,
+    TokenMintProvider, Vault, VaultConfigProvider, VaultDepositor, VaultDepositorBase,
+    VaultDepositorLoader, VaultDepositorRecordLogProvider, VaultDepositorThrottleProvider,
+    VaultGuardProvider, VaultHook, VaultHookAction, VaultHookContext, VaultHookProvider,
+    VaultLockupProvider, VaultProtocolProvider, VaultWithdrawSettlementProvider,
+    WithdrawReceiptProvider, DEPOSIT_BASE_COMPUTE_UNITS,
+};
+use crate::token_cpi::{get_amount_after_transfer_fee, transfer_tokens, TokenTransferCPI};
+use crate::{
+    declare_vault_seeds, implement_deposit, implement_vault_hook_cpi, validate, AccountMapProvider,
+};
 
 pub fn deposit<'c: 'info, 'info>(
     ctx: Context<'_, '_, 'c, 'info, Deposit<'info>>,
     amount: u64,
+) -> Result<()> {
+    deposit_with_cancel_withdraw_request(ctx, amount, false)
+}
+
+/// [`deposit`] that, if `cancel_withdraw_request` is set, first cancels the depositor's pending withdraw request
+/// instead of failing with `WithdrawInProgress`.
+pub fn deposit_with_cancel_withdraw_request<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, Deposit<'info>>,
+    amount: u64,
+    cancel_withdraw_request: bool,
+) -> Result<()> {
+    process_deposit(ctx, amount, cancel_withdraw_request, false)
+}
+
+/// Simulation only [`deposit`], takes the same accounts and runs the same share, fee and fuel math but skips the
+/// hook, token and drift cpis. Always fails with `SimulationOnly` after writing the projected [`FlowPreview`] to
+/// return data, see [`FlowPreview::set_return_data_and_revert`].
+pub fn preview_deposit<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, Deposit<'info>>,
+    amount: u64,
+) -> Result<()> {
+    process_deposit(ctx, amount, false, true)
+}
+
+fn process_deposit<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, Deposit<'info>>,
+    amount: u64,
+    cancel_withdraw_request: bool,
+    simulate: bool,
 ) -> Result<()> {
     let clock = &Clock::get()?;
 
+    let vault_hook_program = {
+        let vault = ctx.accounts.vault.load()?;
+        let vault_hook_program = ctx.vault_hook_program(&vault)?;
+        validate_deposit_hook(&vault, vault_hook_program.as_ref())?;
+        // previews skip every cpi
+        vault_hook_program.filter(|_| !simulate)
+    };
+
+    if let Some(hook_program) = &vault_hook_program {
+        ctx.vault_hook_invoke(hook_program, VaultHookAction::PreDeposit, amount)?;
+    }
+
     let mut vault = ctx.accounts.vault.load_mut()?;
     validate!(!vault.in_liquidation(), ErrorCode::OngoingLiquidation)?;
+    vault.validate_settle_crank(&ctx.accounts.drift_user.key(), ctx.instructions_sysvar())?;
+
+    let mut vault_depositor = ctx.accounts.vault_depositor.load_mut_migrated()?;
+    let preview_base = simulate.then(|| (*vault, *vault_depositor));
 
-    let mut vault_depositor = ctx.accounts.vault_depositor.load_mut()?;
+    let throttle = ctx.vault_depositor_throttle(&vault, &vault_depositor.pubkey)?;
+    // no depositor throttle state, no deposit since the throttle was enabled
+    if let Some((throttle, Some(vault_depositor_throttle_state))) = &throttle {
+        throttle.load()?.validate_deposit(
+            &vault_depositor_throttle_state.load()?,
+            clock.unix_timestamp,
+        )?;
+    }
+
+    let vault_config = ctx.vault_config(&vault)?;
 
     // backwards compatible: if last rem acct does not deserialize into [`VaultProtocol`] then it's a legacy vault.
     let mut vp = ctx.vault_protocol();
@@ -49,8 +117,18 @@ pub fn deposit<'c: 'info, 'info>(
         has_fuel_overflow,
     )?;
 
-    let vault_equity =
-        vault.calculate_equity(&user, &perp_market_map, &spot_market_map, &mut oracle_map)?;
+    let vault_equity = vault.calculate_deposit_equity(
+        &vault_config,
+        &user,
+        &perp_market_map,
+        &spot_market_map,
+        &mut oracle_map,
+    )?;
+    let compute_units_hint = ComputeUnitsHint::new(
+        DEPOSIT_BASE_COMPUTE_UNITS,
+        &perp_market_map,
+        &spot_market_map,
+    )?;
 
     let deposit_room_remaining = vault.max_tokens.saturating_sub(vault_equity);
     let mut deposit_amount = amount;
@@ -64,13 +142,80 @@ pub fn deposit<'c: 'info, 'info>(
         deposit_amount = deposit_room_remaining;
     }
 
+    // Token-2022 transfer fees are withheld on the transfer into the vault token account and again on the drift
+    // deposit out of it, so shares are minted for what reaches drift
+    let mint = ctx.token_mint(&ctx.accounts.vault_token_account.mint)?;
+    let received_amount = get_amount_after_transfer_fee(mint.as_ref(), deposit_amount)?;
+    let deposited_amount = get_amount_after_transfer_fee(mint.as_ref(), received_amount)?;
+
     let spot_market = spot_market_map.get_ref(&spot_market_index)?;
     let oracle = oracle_map.get_price_data(&spot_market.oracle_id())?;
 
+    if let Some(vault_guard) = ctx.vault_guard(&vault)? {
+        let vault_guard = vault_guard.load()?;
+        vault_guard.validate_oracle_price(
+            oracle.price,
+            spot_market.historical_oracle_data.last_oracle_price_twap,
+        )?;
+        vault_guard.validate_deposit_value(deposit_amount, spot_market.decimals, oracle.price)?;
+    }
+
+    if let Some(protocol_tvl_cap) = ctx.protocol_tvl_cap(&vp)? {
+        let tvl_after_deposit = get_vault_tvl(
+            vault_equity.safe_add(deposit_amount)?,
+            &spot_market,
+            oracle.price,
+        )?;
+        protocol_tvl_cap
+            .load_mut()?
+            .validate_deposit(&vault.pubkey, tvl_after_deposit)?;
+    }
+
+    let record_log = ctx.vault_depositor_record_log(&vault.pubkey)?;
+
+    if cancel_withdraw_request && vault_depositor.last_withdraw_request.pending() {
+        if let Some(receipt) = ctx.withdraw_receipt(&vault, &ctx.accounts.vault_depositor.key())? {
+            receipt
+                .load()?
+                .validate_cancel(&vault_depositor.last_withdraw_request, clock.unix_timestamp)?;
+        }
+
+        let withdraw_settlement = ctx.vault_withdraw_settlement(&vault)?;
+        let withdraw_settlement = withdraw_settlement
+            .as_ref()
+            .map(|settlement| settlement.load())
+            .transpose()?;
+
+        let vault_shares_before = vault_depositor.get_vault_shares();
+        vault_depositor.cancel_withdraw_request(
+            vault_equity,
+            &mut vault,
+            &vault_config,
+            &mut vp,
+            clock.unix_timestamp,
+            &user_stats,
+            &fuel_overflow,
+            oracle.price,
+            withdraw_settlement.as_deref(),
+        )?;
+        record_vault_depositor_action(
+            &record_log,
+            &vault,
+            &vault_depositor,
+            VaultDepositorAction::CancelWithdrawRequest,
+            0,
+            vault_shares_before,
+            vault_equity,
+            clock.unix_timestamp,
+        )?;
+    }
+
+    let vault_shares_before = vault_depositor.get_vault_shares();
     vault_depositor.deposit(
-        deposit_amount,
+        deposited_amount,
         vault_equity,
         &mut vault,
+        &vault_config,
         &mut vp,
         clock.unix_timestamp,
         &user_stats,
@@ -78,6 +223,42 @@ pub fn deposit<'c: 'info, 'info>(
         oracle.price,
     )?;
 
+    if let Some((vault_before, vault_depositor_before)) = preview_base {
+        return FlowPreview::new(
+            deposited_amount,
+            &vault_before,
+            &vault_depositor_before,
+            &vault,
+            &vault_depositor,
+            compute_units_hint,
+        )?
+        .set_return_data_and_revert();
+    }
+
+    record_vault_depositor_action(
+        &record_log,
+        &vault,
+        &vault_depositor,
+        VaultDepositorAction::Deposit,
+        deposited_amount,
+        vault_shares_before,
+        vault_equity,
+        clock.unix_timestamp,
+    )?;
+
+    if let Some((_, vault_depositor_lockup)) = ctx.vault_lockup(&vault, &vault_depositor.pubkey)? {
+        // deposits into a locked up vault are always tracked
+        let vault_depositor_lockup = vault_depositor_lockup.ok_or(ErrorCode::InvalidVaultLockup)?;
+        vault_depositor_lockup.load_mut()?.last_deposit_ts = clock.unix_timestamp;
+    }
+
+    if let Some((_, vault_depositor_throttle_state)) = throttle {
+        // deposits into a throttled vault are always tracked
+        let vault_depositor_throttle_state =
+            vault_depositor_throttle_state.ok_or(ErrorCode::InvalidVaultDepositorThrottle)?;
+        vault_depositor_throttle_state.load_mut()?.last_deposit_ts = clock.unix_timestamp;
+    }
+
     drop(spot_market);
     drop(vault);
     drop(user);
@@ -86,7 +267,13 @@ pub fn deposit<'c: 'info, 'info>(
 
     ctx.token_transfer(deposit_amount)?;
 
-    ctx.drift_deposit(deposit_amount)?;
+    ctx.drift_deposit(received_amount)?;
+
+    if let Some(hook_program) = &vault_hook_program {
+        ctx.vault_hook_invoke(hook_program, VaultHookAction::PostDeposit, deposited_amount)?;
+    }
+
+    compute_units_hint.set_return_data()?;
 
     Ok(())
 }
@@ -99,7 +286,8 @@ pub struct Deposit<'info> {
         mut,
         seeds = [b"vault_depositor", vault.key().as_ref(), authority.key().as_ref()],
         bump,
-        constraint = is_authority_for_vault_depositor(&vault_depositor, &authority)?
+        constraint = is_authority_for_vault_depositor(&vault_depositor, &authority)?,
+        constraint = is_not_blacklisted_vault_depositor(&vault_depositor)?
     )]
     pub vault_depositor: AccountLoader<'info, VaultDepositor>,
     pub authority: Signer<'info>,
@@ -108,7 +296,7 @@ pub struct Deposit<'info> {
         seeds = [b"vault_token_account".as_ref(), vault.key().as_ref()],
         bump
     )]
-    pub vault_token_account: Box<Account<'info, TokenAccount>>,
+    pub vault_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
     #[account(
         mut,
         constraint = is_user_stats_for_vault(&vault, &drift_user_stats.key())?
@@ -127,30 +315,29 @@ pub struct Deposit<'info> {
         mut,
         token::mint = vault_token_account.mint
     )]
-    pub drift_spot_market_vault: Box<Account<'info, TokenAccount>>,
+    pub drift_spot_market_vault: Box<InterfaceAccount<'info, TokenAccount>>,
     #[account(
         mut,
         token::authority = authority,
         token::mint = vault_token_account.mint
     )]
-    pub user_token_account: Box<Account<'info, TokenAccount>>,
+    pub user_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
     pub drift_program: Program<'info, Drift>,
-    pub token_program: Program<'info, Token>,
+    pub token_program: Interface<'info, TokenInterface>,
 }
 
-impl<'info> TokenTransferCPI for Context<'_, '_, '_, 'info, Deposit<'info>> {
+impl<'c: 'info, 'info> TokenTransferCPI for Context<'_, '_, 'c, 'info, Deposit<'info>> {
     fn token_transfer(&self, amount: u64) -> Result<()> {
-        let cpi_accounts = Transfer {
-            from: self.accounts.user_token_account.to_account_info().clone(),
-            to: self.accounts.vault_token_account.to_account_info().clone(),
-            authority: self.accounts.authority.to_account_info().clone(),
-        };
-        let token_program = self.accounts.token_program.to_account_info().clone();
-        let cpi_context = CpiContext::new(token_program, cpi_accounts);
-
-        token::transfer(cpi_context, amount)?;
-
-        Ok(())
+        transfer_tokens(
+            self.accounts.token_program.to_account_info(),
+            self.accounts.user_token_account.to_account_info(),
+            self.accounts.vault_token_account.to_account_info(),
+            self.accounts.authority.to_account_info(),
+            self.token_mint(&self.accounts.vault_token_account.mint)?
+                .as_ref(),
+            amount,
+            &[],
+        )
     }
 }
 
@@ -160,3 +347,15 @@ impl<'info> DepositCPI for Context<'_, '_, '_, 'info, Deposit<'info>> {
         Ok(())
     }
 }
+
+impl<'info> VaultHookCPI<'info> for Context<'_, '_, '_, 'info, Deposit<'info>> {
+    fn vault_hook_invoke(
+        &self,
+        hook_program: &AccountInfo<'info>,
+        action: VaultHookAction,
+        amount: u64,
+    ) -> Result<()> {
+        implement_vault_hook_cpi!(self, hook_program, action, amount);
+        Ok(())
+    }
+}
@@ -0,0 +1,312 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use drift::cpi::accounts::{Deposit as DriftDeposit, Withdraw as DriftWithdraw};
+use drift::instructions::optional_accounts::AccountMaps;
+use drift::math::safe_math::SafeMath;
+use drift::program::Drift;
+use drift::state::user::{FuelOverflowStatus, User, UserStats};
+
+use crate::constraints::{
+    is_authority_for_drift_user, is_authority_for_vault_depositor,
+    is_not_blacklisted_vault_depositor, is_user_for_vault, is_user_stats_for_vault,
+};
+use crate::drift_cpi::{DepositCPI, WithdrawFromUserCPI};
+use crate::error::ErrorCode;
+use crate::hook_cpi::VaultHookCPI;
+use crate::state::{
+    get_vault_tvl, ComputeUnitsHint, FuelOverflowProvider, InstructionsSysvarProvider,
+    ProtocolTvlCapProvider, Vault, VaultConfigProvider, VaultDepositor, VaultDepositorLoader,
+    VaultDepositorThrottleProvider, VaultGuardProvider, VaultHook, VaultHookAction,
+    VaultHookContext, VaultHookProvider, VaultLockupProvider, VaultProtocolProvider,
+    DEPOSIT_BASE_COMPUTE_UNITS,
+};
+use crate::token_cpi::TokenTransferCPI;
+use crate::{
+    declare_vault_seeds, implement_deposit, implement_vault_hook_cpi, validate, AccountMapProvider,
+};
+
+/// Like [`deposit`](crate::instructions::deposit) but funds the deposit from the depositor's own drift user instead
+/// of their wallet. The amount is withdrawn reduce only, so it can't open a borrow, and passes through
+/// `user_token_account` within the instruction.
+pub fn deposit_from_drift_user<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, DepositFromDriftUser<'info>>,
+    amount: u64,
+) -> Result<()> {
+    let clock = &Clock::get()?;
+
+    let vault_hook_program = {
+        let vault = ctx.accounts.vault.load()?;
+        let vault_hook_program = ctx.vault_hook_program(&vault)?;
+        validate!(
+            !vault.vault_hook || vault_hook_program.is_some(),
+            ErrorCode::VaultHookMissing,
+            "VaultHook and its program must be in remaining accounts"
+        )?;
+        vault_hook_program
+    };
+
+    if let Some(hook_program) = &vault_hook_program {
+        ctx.vault_hook_invoke(hook_program, VaultHookAction::PreDeposit, amount)?;
+    }
+
+    let mut vault = ctx.accounts.vault.load_mut()?;
+    validate!(!vault.in_liquidation(), ErrorCode::OngoingLiquidation)?;
+    vault.validate_settle_crank(&ctx.accounts.drift_user.key(), ctx.instructions_sysvar())?;
+
+    let mut vault_depositor = ctx.accounts.vault_depositor.load_mut_migrated()?;
+
+    let throttle = ctx.vault_depositor_throttle(&vault, &vault_depositor.pubkey)?;
+    // no depositor throttle state, no deposit since the throttle was enabled
+    if let Some((throttle, Some(vault_depositor_throttle_state))) = &throttle {
+        throttle.load()?.validate_deposit(
+            &vault_depositor_throttle_state.load()?,
+            clock.unix_timestamp,
+        )?;
+    }
+
+    let vault_config = ctx.vault_config(&vault)?;
+
+    // backwards compatible: if last rem acct does not deserialize into [`VaultProtocol`] then it's a legacy vault.
+    let mut vp = ctx.vault_protocol();
+    vault.validate_vault_protocol(&vp)?;
+    let mut vp = vp.as_mut().map(|vp| vp.load_mut()).transpose()?;
+
+    let user = ctx.accounts.drift_user.load()?;
+    let spot_market_index = vault.spot_market_index;
+
+    let user_stats = ctx.accounts.drift_user_stats.load()?;
+    let has_fuel_overflow = FuelOverflowStatus::exists(user_stats.fuel_overflow_status);
+    let fuel_overflow = ctx.fuel_overflow(vp.is_some(), has_fuel_overflow);
+    user_stats.validate_fuel_overflow(&fuel_overflow)?;
+
+    let AccountMaps {
+        perp_market_map,
+        spot_market_map,
+        mut oracle_map,
+    } = ctx.load_maps(
+        clock.slot,
+        Some(spot_market_index),
+        vp.is_some(),
+        has_fuel_overflow,
+    )?;
+
+    let vault_equity = vault.calculate_deposit_equity(
+        &vault_config,
+        &user,
+        &perp_market_map,
+        &spot_market_map,
+        &mut oracle_map,
+    )?;
+    let compute_units_hint = ComputeUnitsHint::new(
+        DEPOSIT_BASE_COMPUTE_UNITS,
+        &perp_market_map,
+        &spot_market_map,
+    )?;
+
+    let deposit_room_remaining = vault.max_tokens.saturating_sub(vault_equity);
+    let mut deposit_amount = amount;
+    if vault.max_tokens > 0 && deposit_room_remaining < amount {
+        msg!(
+            "Deposting {}/{} to stay within vault max tokens {}",
+            deposit_room_remaining,
+            amount,
+            vault.max_tokens
+        );
+        deposit_amount = deposit_room_remaining;
+    }
+
+    let spot_market = spot_market_map.get_ref(&spot_market_index)?;
+    let oracle = oracle_map.get_price_data(&spot_market.oracle_id())?;
+
+    if let Some(vault_guard) = ctx.vault_guard(&vault)? {
+        let vault_guard = vault_guard.load()?;
+        vault_guard.validate_oracle_price(
+            oracle.price,
+            spot_market.historical_oracle_data.last_oracle_price_twap,
+        )?;
+        vault_guard.validate_deposit_value(deposit_amount, spot_market.decimals, oracle.price)?;
+    }
+
+    if let Some(protocol_tvl_cap) = ctx.protocol_tvl_cap(&vp)? {
+        let tvl_after_deposit = get_vault_tvl(
+            vault_equity.safe_add(deposit_amount)?,
+            &spot_market,
+            oracle.price,
+        )?;
+        protocol_tvl_cap
+            .load_mut()?
+            .validate_deposit(&vault.pubkey, tvl_after_deposit)?;
+    }
+
+    vault_depositor.deposit(
+        deposit_amount,
+        vault_equity,
+        &mut vault,
+        &vault_config,
+        &mut vp,
+        clock.unix_timestamp,
+        &user_stats,
+        &fuel_overflow,
+        oracle.price,
+    )?;
+
+    if let Some((_, vault_depositor_lockup)) = ctx.vault_lockup(&vault, &vault_depositor.pubkey)? {
+        // deposits into a locked up vault are always tracked
+        let vault_depositor_lockup = vault_depositor_lockup.ok_or(ErrorCode::InvalidVaultLockup)?;
+        vault_depositor_lockup.load_mut()?.last_deposit_ts = clock.unix_timestamp;
+    }
+
+    if let Some((_, vault_depositor_throttle_state)) = throttle {
+        // deposits into a throttled vault are always tracked
+        let vault_depositor_throttle_state =
+            vault_depositor_throttle_state.ok_or(ErrorCode::InvalidVaultDepositorThrottle)?;
+        vault_depositor_throttle_state.load_mut()?.last_deposit_ts = clock.unix_timestamp;
+    }
+
+    drop(spot_market);
+    drop(vault);
+    drop(user);
+    drop(user_stats);
+    drop(vp);
+
+    ctx.drift_withdraw_from_user(deposit_amount)?;
+
+    ctx.token_transfer(deposit_amount)?;
+
+    ctx.drift_deposit(deposit_amount)?;
+
+    if let Some(hook_program) = &vault_hook_program {
+        ctx.vault_hook_invoke(hook_program, VaultHookAction::PostDeposit, deposit_amount)?;
+    }
+
+    compute_units_hint.set_return_data()?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct DepositFromDriftUser<'info> {
+    #[account(mut)]
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        mut,
+        seeds = [b"vault_depositor", vault.key().as_ref(), authority.key().as_ref()],
+        bump,
+        constraint = is_authority_for_vault_depositor(&vault_depositor, &authority)?,
+        constraint = is_not_blacklisted_vault_depositor(&vault_depositor)?
+    )]
+    pub vault_depositor: AccountLoader<'info, VaultDepositor>,
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"vault_token_account".as_ref(), vault.key().as_ref()],
+        bump
+    )]
+    pub vault_token_account: Box<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        constraint = is_user_stats_for_vault(&vault, &drift_user_stats.key())?
+    )]
+    /// CHECK: checked in drift cpi
+    pub drift_user_stats: AccountLoader<'info, UserStats>,
+    #[account(
+        mut,
+        constraint = is_user_for_vault(&vault, &drift_user.key())?
+    )]
+    /// CHECK: checked in drift cpi
+    pub drift_user: AccountLoader<'info, User>,
+    /// CHECK: checked in drift cpi
+    pub drift_state: AccountInfo<'info>,
+    #[account(
+        mut,
+        token::mint = vault_token_account.mint
+    )]
+    pub drift_spot_market_vault: Box<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        token::authority = authority,
+        token::mint = vault_token_account.mint
+    )]
+    pub user_token_account: Box<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        constraint = is_authority_for_drift_user(&depositor_drift_user, &authority)?
+    )]
+    /// CHECK: checked in drift cpi
+    pub depositor_drift_user: AccountLoader<'info, User>,
+    #[account(mut)]
+    /// CHECK: checked in drift cpi
+    pub depositor_drift_user_stats: AccountLoader<'info, UserStats>,
+    /// CHECK: checked in drift cpi
+    pub drift_signer: AccountInfo<'info>,
+    pub drift_program: Program<'info, Drift>,
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> WithdrawFromUserCPI for Context<'_, '_, '_, 'info, DepositFromDriftUser<'info>> {
+    fn drift_withdraw_from_user(&self, amount: u64) -> Result<()> {
+        let spot_market_index = self.accounts.vault.load()?.spot_market_index;
+
+        let cpi_accounts = DriftWithdraw {
+            state: self.accounts.drift_state.to_account_info().clone(),
+            user: self.accounts.depositor_drift_user.to_account_info().clone(),
+            user_stats: self
+                .accounts
+                .depositor_drift_user_stats
+                .to_account_info()
+                .clone(),
+            authority: self.accounts.authority.to_account_info().clone(),
+            spot_market_vault: self
+                .accounts
+                .drift_spot_market_vault
+                .to_account_info()
+                .clone(),
+            drift_signer: self.accounts.drift_signer.to_account_info().clone(),
+            user_token_account: self.accounts.user_token_account.to_account_info().clone(),
+            token_program: self.accounts.token_program.to_account_info().clone(),
+        };
+
+        let drift_program = self.accounts.drift_program.to_account_info().clone();
+        // signed by the depositor, the vault has no authority over their drift user
+        let cpi_context = CpiContext::new(drift_program, cpi_accounts)
+            .with_remaining_accounts(self.remaining_accounts.into());
+        drift::cpi::withdraw(cpi_context, spot_market_index, amount, true)?;
+
+        Ok(())
+    }
+}
+
+impl<'info> TokenTransferCPI for Context<'_, '_, '_, 'info, DepositFromDriftUser<'info>> {
+    fn token_transfer(&self, amount: u64) -> Result<()> {
+        let cpi_accounts = Transfer {
+            from: self.accounts.user_token_account.to_account_info().clone(),
+            to: self.accounts.vault_token_account.to_account_info().clone(),
+            authority: self.accounts.authority.to_account_info().clone(),
+        };
+        let token_program = self.accounts.token_program.to_account_info().clone();
+        let cpi_context = CpiContext::new(token_program, cpi_accounts);
+
+        token::transfer(cpi_context, amount)?;
+
+        Ok(())
+    }
+}
+
+impl<'info> DepositCPI for Context<'_, '_, '_, 'info, DepositFromDriftUser<'info>> {
+    fn drift_deposit(&self, amount: u64) -> Result<()> {
+        implement_deposit!(self, amount);
+        Ok(())
+    }
+}
+
+impl<'info> VaultHookCPI<'info> for Context<'_, '_, '_, 'info, DepositFromDriftUser<'info>> {
+    fn vault_hook_invoke(
+        &self,
+        hook_program: &AccountInfo<'info>,
+        action: VaultHookAction,
+        amount: u64,
+    ) -> Result<()> {
+        implement_vault_hook_cpi!(self, hook_program, action, amount);
+        Ok(())
+    }
+}
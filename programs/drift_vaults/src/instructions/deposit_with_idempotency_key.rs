@@ -0,0 +1,22 @@
+use anchor_lang::prelude::*;
+
+use crate::state::VaultDepositorIdempotencyProvider;
+
+use super::Deposit;
+
+/// [`deposit`](super::deposit) that fails if `idempotency_key` was already executed for the vault depositor.
+/// The vault depositor's [`VaultDepositorIdempotency`](crate::state::VaultDepositorIdempotency) must be writable in
+/// remaining_accounts, after the market accounts.
+pub fn deposit_with_idempotency_key<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, Deposit<'info>>,
+    amount: u64,
+    idempotency_key: u64,
+) -> Result<()> {
+    let clock = &Clock::get()?;
+
+    ctx.vault_depositor_idempotency(&ctx.accounts.vault_depositor.key())?
+        .load_mut()?
+        .check_and_record(idempotency_key, clock.unix_timestamp)?;
+
+    super::deposit(ctx, amount)
+}
@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+use drift::math::safe_math::SafeMath;
+
+use crate::events::VaultDepositorAction;
+use crate::state::{record_interface_attribution, VaultFeature, VaultInterfaceProvider};
+
+use super::Deposit;
+
+/// [`deposit`](super::deposit) attributed to the front-end `interface`. If the manager registered a
+/// [`VaultInterface`](crate::state::VaultInterface) for it, it must be writable in remaining_accounts after the market
+/// accounts to accrue the interface's fee.
+pub fn deposit_with_interface<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, Deposit<'info>>,
+    amount: u64,
+    interface: Pubkey,
+) -> Result<()> {
+    let clock = &Clock::get()?;
+
+    ctx.accounts
+        .vault
+        .load()?
+        .validate_feature_enabled(VaultFeature::Interface)?;
+
+    let vault = ctx.accounts.vault.clone();
+    let authority = ctx.accounts.authority.key();
+    let vault_interface = ctx.vault_interface(&vault.key(), &interface);
+
+    // deposits can be capped by max_tokens, attribute what was actually deposited
+    let total_deposits_before = vault.load()?.total_deposits;
+
+    super::deposit(ctx, amount)?;
+
+    let vault = vault.load()?;
+    let deposit_amount = vault.total_deposits.safe_sub(total_deposits_before)?;
+
+    record_interface_attribution(
+        &vault,
+        authority,
+        interface,
+        vault_interface,
+        VaultDepositorAction::Deposit,
+        deposit_amount,
+        clock.unix_timestamp,
+    )
+}
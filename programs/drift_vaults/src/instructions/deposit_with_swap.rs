@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::program::invoke;
+
+use crate::state::{calculate_swap_amount_out, SwapAccountsProvider, VaultFeature};
+
+use super::Deposit;
+
+/// [`deposit`](super::deposit) of whatever a swap into the vault's deposit mint pays out to `user_token_account`.
+/// The swap program, followed by its `swap_accounts_len` accounts, must be in remaining_accounts after the market
+/// accounts, `swap_data` is passed to it as is. Fails if the swap pays out less than `min_amount_out`.
+pub fn deposit_with_swap<'c: 'info, 'info>(
+    mut ctx: Context<'_, '_, 'c, 'info, Deposit<'info>>,
+    min_amount_out: u64,
+    swap_accounts_len: u8,
+    swap_data: Vec<u8>,
+) -> Result<()> {
+    ctx.accounts
+        .vault
+        .load()?
+        .validate_feature_enabled(VaultFeature::Swaps)?;
+
+    let swap_accounts = ctx.swap_accounts(swap_accounts_len as usize)?;
+
+    let ix = Instruction {
+        program_id: swap_accounts.swap_program.key(),
+        accounts: swap_accounts
+            .accounts
+            .iter()
+            .map(|acct| AccountMeta {
+                pubkey: acct.key(),
+                is_signer: acct.is_signer,
+                is_writable: acct.is_writable,
+            })
+            .collect(),
+        data: swap_data,
+    };
+
+    let mut account_infos = swap_accounts.accounts;
+    account_infos.push(swap_accounts.swap_program);
+
+    let balance_before = ctx.accounts.user_token_account.amount;
+    invoke(&ix, &account_infos)?;
+    ctx.accounts.user_token_account.reload()?;
+    let amount_out = calculate_swap_amount_out(
+        balance_before,
+        ctx.accounts.user_token_account.amount,
+        min_amount_out,
+    )?;
+
+    super::deposit(ctx, amount_out)
+}
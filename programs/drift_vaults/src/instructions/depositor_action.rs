@@ -0,0 +1,117 @@
+use anchor_lang::prelude::*;
+
+use crate::constraints::is_not_blacklisted_vault_depositor;
+use crate::state::WithdrawUnit;
+
+use super::{
+    CancelWithdrawRequest, CancelWithdrawRequestBumps, Deposit, DepositBumps, RequestWithdraw,
+    RequestWithdrawBumps, UpdateCumulativeFuelAmount, UpdateCumulativeFuelAmountBumps, Withdraw,
+};
+
+#[derive(Clone, Copy, AnchorSerialize, AnchorDeserialize, PartialEq, Debug, Eq)]
+pub enum DepositorAction {
+    /// [`deposit`](super::deposit)
+    Deposit { amount: u64 },
+    /// [`request_withdraw`](super::request_withdraw)
+    Request {
+        withdraw_amount: u64,
+        withdraw_unit: WithdrawUnit,
+    },
+    /// [`cancel_withdraw_request`](super::cancel_withdraw_request)
+    Cancel,
+    /// [`withdraw`](super::withdraw)
+    Withdraw,
+    /// [`update_cumulative_fuel_amount`](super::update_cumulative_fuel_amount)
+    Fuel,
+}
+
+/// Routes a depositor's `action` to its instruction so wallets with instruction whitelists only need to allow this
+/// one. Takes the [`Withdraw`] accounts, a superset of every action's, and the action's usual remaining accounts.
+/// Only the depositor authority can route actions, custodians use the standalone instructions.
+pub fn depositor_action<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, Withdraw<'info>>,
+    action: DepositorAction,
+) -> Result<()> {
+    msg!("depositor action: {:?}", action);
+
+    match action {
+        DepositorAction::Deposit { amount } => {
+            is_not_blacklisted_vault_depositor(&ctx.accounts.vault_depositor)?;
+
+            let mut accounts = Deposit {
+                vault: ctx.accounts.vault.clone(),
+                vault_depositor: ctx.accounts.vault_depositor.clone(),
+                authority: ctx.accounts.authority.clone(),
+                vault_token_account: ctx.accounts.vault_token_account.clone(),
+                drift_user_stats: ctx.accounts.drift_user_stats.clone(),
+                drift_user: ctx.accounts.drift_user.clone(),
+                drift_state: ctx.accounts.drift_state.clone(),
+                drift_spot_market_vault: ctx.accounts.drift_spot_market_vault.clone(),
+                user_token_account: ctx.accounts.user_token_account.clone(),
+                drift_program: ctx.accounts.drift_program.clone(),
+                token_program: ctx.accounts.token_program.clone(),
+            };
+            let bumps = DepositBumps {
+                vault_depositor: ctx.bumps.vault_depositor,
+                vault_token_account: ctx.bumps.vault_token_account,
+            };
+            super::deposit(
+                Context::new(ctx.program_id, &mut accounts, ctx.remaining_accounts, bumps),
+                amount,
+            )
+        }
+        DepositorAction::Request {
+            withdraw_amount,
+            withdraw_unit,
+        } => {
+            let mut accounts = RequestWithdraw {
+                vault: ctx.accounts.vault.clone(),
+                vault_depositor: ctx.accounts.vault_depositor.clone(),
+                authority: ctx.accounts.authority.clone(),
+                drift_user_stats: ctx.accounts.drift_user_stats.clone(),
+                drift_user: ctx.accounts.drift_user.clone(),
+            };
+            let bumps = RequestWithdrawBumps {
+                vault_depositor: ctx.bumps.vault_depositor,
+            };
+            super::request_withdraw(
+                Context::new(ctx.program_id, &mut accounts, ctx.remaining_accounts, bumps),
+                withdraw_amount,
+                withdraw_unit,
+            )
+        }
+        DepositorAction::Cancel => {
+            let mut accounts = CancelWithdrawRequest {
+                vault: ctx.accounts.vault.clone(),
+                vault_depositor: ctx.accounts.vault_depositor.clone(),
+                authority: ctx.accounts.authority.clone(),
+                drift_user_stats: ctx.accounts.drift_user_stats.clone(),
+                drift_user: ctx.accounts.drift_user.clone(),
+            };
+            let bumps = CancelWithdrawRequestBumps {
+                vault_depositor: ctx.bumps.vault_depositor,
+            };
+            super::cancel_withdraw_request(Context::new(
+                ctx.program_id,
+                &mut accounts,
+                ctx.remaining_accounts,
+                bumps,
+            ))
+        }
+        DepositorAction::Withdraw => super::withdraw(ctx),
+        DepositorAction::Fuel => {
+            let mut accounts = UpdateCumulativeFuelAmount {
+                vault: ctx.accounts.vault.clone(),
+                vault_depositor: ctx.accounts.vault_depositor.clone(),
+                signer: ctx.accounts.authority.clone(),
+                drift_user_stats: ctx.accounts.drift_user_stats.clone(),
+            };
+            super::update_cumulative_fuel_amount(Context::new(
+                ctx.program_id,
+                &mut accounts,
+                ctx.remaining_accounts,
+                UpdateCumulativeFuelAmountBumps {},
+            ))
+        }
+    }
+}
@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+use drift::cpi::accounts::UpdateUser;
+use drift::instructions::optional_accounts::AccountMaps;
+use drift::program::Drift;
+use drift::state::user::User;
+
+use crate::constraints::is_user_for_vault;
+use crate::drift_cpi::UpdateUserReduceOnlyCPI;
+use crate::state::{Vault, VaultProfile};
+use crate::{declare_vault_seeds, implement_update_user_reduce_only_cpi, AccountMapProvider};
+
+/// Permissionless guard, puts the vault's drift user in reduce only if it holds positions its [`VaultProfile`]
+/// doesn't allow. Remaining accounts are the perp markets of the user's perp positions.
+pub fn enforce_vault_profile<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, EnforceVaultProfile<'info>>,
+) -> Result<()> {
+    let clock = &Clock::get()?;
+
+    let profile = VaultProfile::try_from(ctx.accounts.vault.load()?.profile)?;
+    if profile == VaultProfile::Unrestricted {
+        return Ok(());
+    }
+
+    let user = ctx.accounts.drift_user.load()?;
+    let AccountMaps {
+        perp_market_map, ..
+    } = ctx.load_maps(clock.slot, None, false, false)?;
+
+    if profile.is_satisfied_by(&user, &perp_market_map)? {
+        return Ok(());
+    }
+
+    msg!("vault breaches profile {:?}, setting reduce only", profile);
+    drop(user);
+
+    ctx.drift_update_user_reduce_only(true)?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct EnforceVaultProfile<'info> {
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        mut,
+        constraint = is_user_for_vault(&vault, &drift_user.key())?
+    )]
+    /// CHECK: checked in drift cpi
+    pub drift_user: AccountLoader<'info, User>,
+    pub drift_program: Program<'info, Drift>,
+}
+
+impl<'info> UpdateUserReduceOnlyCPI for Context<'_, '_, '_, 'info, EnforceVaultProfile<'info>> {
+    fn drift_update_user_reduce_only(&self, reduce_only: bool) -> Result<()> {
+        implement_update_user_reduce_only_cpi!(self, reduce_only);
+        Ok(())
+    }
+}
@@ -0,0 +1,201 @@
+use anchor_lang::prelude::*;
+use drift::cpi::accounts::TransferDeposit;
+use drift::instructions::optional_accounts::AccountMaps;
+use drift::math::safe_math::SafeMath;
+use drift::program::Drift;
+use drift::state::user::{FuelOverflowStatus, User, UserStats};
+
+use crate::constraints::{
+    is_authority_for_vault_depositor, is_user_for_vault, is_user_stats_for_vault,
+};
+use crate::drift_cpi::TransferDepositCPI;
+use crate::error::ErrorCode;
+use crate::state::{
+    validate_withdraw_not_paused, FuelOverflowProvider, InstructionsSysvarProvider, Vault,
+    VaultConfigProvider, VaultDepositor, VaultDepositorLoader, VaultDepositorWithdrawEscrow,
+    VaultGuardProvider, VaultProtocolProvider, VaultWithdrawEscrow,
+};
+use crate::{declare_vault_seeds, validate, AccountMapProvider};
+
+/// Settles the depositor's pending withdraw request now, at the lesser of its request and current value, and moves
+/// the proceeds into the vault's [`VaultWithdrawEscrow`] where they earn lend interest until the request matures.
+/// The escrowed amount plus interest is paid out by [`withdraw_from_escrow`](crate::instructions::withdraw_from_escrow).
+pub fn escrow_withdraw_request<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, EscrowWithdrawRequest<'info>>,
+) -> Result<()> {
+    let clock = &Clock::get()?;
+
+    let mut vault = ctx.accounts.vault.load_mut()?;
+    vault.validate_settle_crank(&ctx.accounts.drift_user.key(), ctx.instructions_sysvar())?;
+    let mut vault_depositor = ctx.accounts.vault_depositor.load_mut_migrated()?;
+
+    validate!(
+        vault_depositor.last_withdraw_request.pending(),
+        ErrorCode::InvalidVaultWithdrawEscrow,
+        "no withdraw request to escrow"
+    )?;
+
+    validate!(
+        vault.liquidation_delegate != vault_depositor.authority,
+        ErrorCode::InvalidVaultWithdrawEscrow,
+        "can't escrow a withdraw request while liquidating the vault"
+    )?;
+
+    let unlock_ts = vault_depositor
+        .last_withdraw_request
+        .ts
+        .safe_add(vault.redeem_period)?;
+
+    let vault_config = ctx.vault_config(&vault)?;
+    // backwards compatible: if last rem acct does not deserialize into [`VaultProtocol`] then it's a legacy vault.
+    let mut vp = ctx.vault_protocol();
+    vault.validate_vault_protocol(&vp)?;
+    let mut vp = vp.as_mut().map(|vp| vp.load_mut()).transpose()?;
+
+    let user = ctx.accounts.drift_user.load()?;
+    let escrow_user = ctx.accounts.escrow_drift_user.load()?;
+    let spot_market_index = vault.spot_market_index;
+
+    let user_stats = ctx.accounts.drift_user_stats.load()?;
+    let has_fuel_overflow = FuelOverflowStatus::exists(user_stats.fuel_overflow_status);
+    let fuel_overflow = ctx.fuel_overflow(vp.is_some(), has_fuel_overflow);
+    user_stats.validate_fuel_overflow(&fuel_overflow)?;
+
+    let AccountMaps {
+        perp_market_map,
+        spot_market_map,
+        mut oracle_map,
+    } = ctx.load_maps(
+        clock.slot,
+        Some(spot_market_index),
+        vp.is_some(),
+        has_fuel_overflow,
+    )?;
+
+    let vault_equity =
+        vault.calculate_equity(&user, &perp_market_map, &spot_market_map, &mut oracle_map)?;
+
+    let spot_market = spot_market_map.get_ref(&spot_market_index)?;
+    validate_withdraw_not_paused(&spot_market)?;
+    let oracle = oracle_map.get_price_data(&spot_market.oracle_id())?;
+
+    if let Some(vault_guard) = ctx.vault_guard(&vault)? {
+        vault_guard.load()?.validate_oracle_price(
+            oracle.price,
+            spot_market.historical_oracle_data.last_oracle_price_twap,
+        )?;
+    }
+    let escrow_balance = VaultWithdrawEscrow::get_escrow_balance(&escrow_user, &spot_market)?;
+
+    let (escrow_amount, _) = vault_depositor.settle_withdraw_request(
+        vault_equity,
+        &mut vault,
+        &vault_config,
+        &mut vp,
+        clock.unix_timestamp,
+        &user_stats,
+        &fuel_overflow,
+        oracle.price,
+    )?;
+
+    let escrow_shares = ctx
+        .accounts
+        .vault_withdraw_escrow
+        .load_mut()?
+        .deposit(escrow_amount, escrow_balance)?;
+    ctx.accounts
+        .vault_depositor_withdraw_escrow
+        .load_mut()?
+        .escrow(escrow_shares, escrow_amount, unlock_ts)?;
+
+    msg!(
+        "escrow_amount: {}, escrow_shares: {}, unlock_ts: {}",
+        escrow_amount,
+        escrow_shares,
+        unlock_ts
+    );
+
+    drop(spot_market);
+    drop(vault);
+    drop(user);
+    drop(escrow_user);
+    drop(user_stats);
+    drop(vp);
+
+    ctx.drift_transfer_deposit(escrow_amount)?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct EscrowWithdrawRequest<'info> {
+    #[account(mut)]
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        mut,
+        seeds = [b"vault_depositor", vault.key().as_ref(), authority.key().as_ref()],
+        bump,
+        constraint = is_authority_for_vault_depositor(&vault_depositor, &authority)?,
+    )]
+    pub vault_depositor: AccountLoader<'info, VaultDepositor>,
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"vault_withdraw_escrow", vault.key().as_ref()],
+        bump = vault_withdraw_escrow.load()?.bump,
+    )]
+    pub vault_withdraw_escrow: AccountLoader<'info, VaultWithdrawEscrow>,
+    #[account(
+        mut,
+        seeds = [b"vault_depositor_withdraw_escrow", vault_depositor.key().as_ref()],
+        bump = vault_depositor_withdraw_escrow.load()?.bump,
+    )]
+    pub vault_depositor_withdraw_escrow: AccountLoader<'info, VaultDepositorWithdrawEscrow>,
+    #[account(
+        mut,
+        constraint = is_user_stats_for_vault(&vault, &drift_user_stats.key())?
+    )]
+    /// CHECK: checked in drift cpi
+    pub drift_user_stats: AccountLoader<'info, UserStats>,
+    #[account(
+        mut,
+        constraint = is_user_for_vault(&vault, &drift_user.key())?
+    )]
+    /// CHECK: checked in drift cpi
+    pub drift_user: AccountLoader<'info, User>,
+    #[account(
+        mut,
+        constraint = vault_withdraw_escrow.load()?.escrow_user.eq(&escrow_drift_user.key())
+    )]
+    /// CHECK: checked in drift cpi
+    pub escrow_drift_user: AccountLoader<'info, User>,
+    /// CHECK: checked in drift cpi
+    pub drift_state: AccountInfo<'info>,
+    /// CHECK: checked in drift cpi
+    pub drift_spot_market_vault: AccountInfo<'info>,
+    pub drift_program: Program<'info, Drift>,
+}
+
+impl<'info> TransferDepositCPI for Context<'_, '_, '_, 'info, EscrowWithdrawRequest<'info>> {
+    fn drift_transfer_deposit(&self, amount: u64) -> Result<()> {
+        declare_vault_seeds!(self.accounts.vault, seeds);
+
+        let spot_market_index = self.accounts.vault.load()?.spot_market_index;
+
+        let cpi_accounts = TransferDeposit {
+            from_user: self.accounts.drift_user.to_account_info().clone(),
+            to_user: self.accounts.escrow_drift_user.to_account_info().clone(),
+            user_stats: self.accounts.drift_user_stats.to_account_info().clone(),
+            authority: self.accounts.vault.to_account_info().clone(),
+            state: self.accounts.drift_state.clone(),
+            spot_market_vault: self.accounts.drift_spot_market_vault.clone(),
+        };
+
+        let drift_program = self.accounts.drift_program.to_account_info().clone();
+        let cpi_context = CpiContext::new_with_signer(drift_program, cpi_accounts, seeds)
+            .with_remaining_accounts(self.remaining_accounts.into());
+        drift::cpi::transfer_deposit(cpi_context, spot_market_index, amount)?;
+
+        Ok(())
+    }
+}
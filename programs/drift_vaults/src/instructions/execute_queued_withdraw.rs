@@ -0,0 +1,153 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{TokenAccount, TokenInterface};
+use drift::cpi::accounts::{UpdateUser, Withdraw as DriftWithdraw};
+use drift::program::Drift;
+use drift::state::user::{User, UserStats};
+
+use crate::constraints::*;
+use crate::drift_cpi::{UpdateUserDelegateCPI, UpdateUserReduceOnlyCPI, WithdrawCPI};
+use crate::error::ErrorCode;
+use crate::instructions::{finish_withdraw, settle_withdraw};
+use crate::state::{TokenMintProvider, Vault, VaultDepositor, VaultDepositorFlag};
+use crate::token_cpi::{get_amount_after_transfer_fee, transfer_tokens, TokenTransferCPI};
+use crate::{
+    declare_vault_seeds, implement_update_user_delegate_cpi, implement_update_user_reduce_only_cpi,
+    implement_withdraw, validate,
+};
+
+/// Permissionless, pays out a depositor's [queued](super::queue_withdraw) withdraw request to the depositor once it
+/// matures and drift allows withdraws from the vault's spot market. Settles the request exactly like
+/// [`withdraw`](super::withdraw), only without the depositor's opt-in hook.
+pub fn execute_queued_withdraw<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, ExecuteQueuedWithdraw<'info>>,
+) -> Result<()> {
+    let clock = &Clock::get()?;
+
+    validate!(
+        ctx.accounts
+            .vault_depositor
+            .load()?
+            .has_flag(VaultDepositorFlag::QueuedWithdraw),
+        ErrorCode::WithdrawNotQueued,
+        "vault depositor did not queue its withdraw request"
+    )?;
+
+    let mut settled_withdraw = settle_withdraw(
+        &ctx,
+        &ctx.accounts.vault,
+        &ctx.accounts.vault_depositor,
+        &ctx.accounts.drift_user,
+        &ctx.accounts.drift_user_stats,
+        clock,
+        false,
+    )?;
+    let user_withdraw_amount = settled_withdraw.user_withdraw_amount;
+
+    // shares burned at zero vault equity pay out nothing
+    if user_withdraw_amount > 0 {
+        ctx.drift_withdraw(user_withdraw_amount)?;
+
+        let mint = ctx.token_mint(&ctx.accounts.vault_token_account.mint)?;
+        let received_amount = get_amount_after_transfer_fee(mint.as_ref(), user_withdraw_amount)?;
+        ctx.token_transfer(received_amount)?;
+    }
+
+    finish_withdraw(
+        &ctx,
+        &ctx.accounts.vault,
+        &ctx.accounts.drift_user,
+        &mut settled_withdraw,
+        clock,
+    )?;
+
+    settled_withdraw.compute_units_hint.set_return_data()?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ExecuteQueuedWithdraw<'info> {
+    #[account(mut)]
+    pub vault: AccountLoader<'info, Vault>,
+    pub keeper: Signer<'info>,
+    #[account(
+        mut,
+        constraint = is_vault_for_vault_depositor(&vault_depositor, &vault)?,
+    )]
+    pub vault_depositor: AccountLoader<'info, VaultDepositor>,
+    #[account(
+        mut,
+        seeds = [b"vault_token_account".as_ref(), vault.key().as_ref()],
+        bump,
+    )]
+    pub vault_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        constraint = is_user_stats_for_vault(&vault, &drift_user_stats.key())?
+    )]
+    /// CHECK: checked in drift cpi
+    pub drift_user_stats: AccountLoader<'info, UserStats>,
+    #[account(
+        mut,
+        constraint = is_user_for_vault(&vault, &drift_user.key())?
+    )]
+    /// CHECK: checked in drift cpi
+    pub drift_user: AccountLoader<'info, User>,
+    /// CHECK: checked in drift cpi
+    pub drift_state: AccountInfo<'info>,
+    #[account(
+        mut,
+        token::mint = vault_token_account.mint
+    )]
+    pub drift_spot_market_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+    /// CHECK: checked in drift cpi
+    pub drift_signer: AccountInfo<'info>,
+    #[account(
+        mut,
+        token::authority = vault_depositor.load()?.authority,
+        token::mint = vault_token_account.mint
+    )]
+    pub user_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub drift_program: Program<'info, Drift>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> WithdrawCPI for Context<'_, '_, '_, 'info, ExecuteQueuedWithdraw<'info>> {
+    fn drift_withdraw(&self, amount: u64) -> Result<()> {
+        implement_withdraw!(self, amount);
+        Ok(())
+    }
+}
+
+impl<'c: 'info, 'info> TokenTransferCPI
+    for Context<'_, '_, 'c, 'info, ExecuteQueuedWithdraw<'info>>
+{
+    fn token_transfer(&self, amount: u64) -> Result<()> {
+        declare_vault_seeds!(self.accounts.vault, seeds);
+
+        transfer_tokens(
+            self.accounts.token_program.to_account_info(),
+            self.accounts.vault_token_account.to_account_info(),
+            self.accounts.user_token_account.to_account_info(),
+            self.accounts.vault.to_account_info(),
+            self.token_mint(&self.accounts.vault_token_account.mint)?
+                .as_ref(),
+            amount,
+            seeds,
+        )
+    }
+}
+
+impl<'info> UpdateUserDelegateCPI for Context<'_, '_, '_, 'info, ExecuteQueuedWithdraw<'info>> {
+    fn drift_update_user_delegate(&self, delegate: Pubkey) -> Result<()> {
+        implement_update_user_delegate_cpi!(self, delegate);
+        Ok(())
+    }
+}
+
+impl<'info> UpdateUserReduceOnlyCPI for Context<'_, '_, '_, 'info, ExecuteQueuedWithdraw<'info>> {
+    fn drift_update_user_reduce_only(&self, reduce_only: bool) -> Result<()> {
+        implement_update_user_reduce_only_cpi!(self, reduce_only);
+        Ok(())
+    }
+}
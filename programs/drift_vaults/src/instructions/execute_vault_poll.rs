@@ -0,0 +1,72 @@
+use anchor_lang::prelude::*;
+use drift::cpi::accounts::UpdateUser;
+use drift::program::Drift;
+use drift::state::user::User;
+
+use crate::constraints::{is_protocol_for_vault, is_user_for_vault, is_vault_protocol_for_vault};
+use crate::drift_cpi::UpdateUserReduceOnlyCPI;
+use crate::state::{Vault, VaultPoll, VaultPollProposal, VaultProtocol};
+use crate::{declare_vault_seeds, implement_update_user_reduce_only_cpi};
+
+pub fn execute_vault_poll<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, ExecuteVaultPoll<'info>>,
+) -> Result<()> {
+    let mut vault_poll = ctx.accounts.vault_poll.load_mut()?;
+    let proposal = vault_poll.execute()?;
+
+    let mut vault = ctx.accounts.vault.load_mut()?;
+    match proposal {
+        VaultPollProposal::ReplaceManager => {
+            msg!(
+                "Poll {} replacing vault manager {} -> {}",
+                vault_poll.poll_id,
+                vault.manager,
+                vault_poll.new_manager
+            );
+            vault.manager = vault_poll.new_manager;
+        }
+        VaultPollProposal::WindDown => {
+            msg!("Poll {} winding down vault", vault_poll.poll_id);
+            vault.wind_down = true;
+            drop(vault);
+
+            ctx.drift_update_user_reduce_only(true)?;
+        }
+    }
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ExecuteVaultPoll<'info> {
+    #[account(
+        mut,
+        constraint = is_protocol_for_vault(&vault, &vault_protocol, &protocol)?
+    )]
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        constraint = is_vault_protocol_for_vault(&vault_protocol, &vault)?
+    )]
+    pub vault_protocol: AccountLoader<'info, VaultProtocol>,
+    #[account(
+        mut,
+        seeds = [b"vault_poll", vault.key().as_ref()],
+        bump = vault_poll.load()?.bump,
+    )]
+    pub vault_poll: AccountLoader<'info, VaultPoll>,
+    pub protocol: Signer<'info>,
+    #[account(
+        mut,
+        constraint = is_user_for_vault(&vault, &drift_user.key())?
+    )]
+    /// CHECK: checked in drift cpi
+    pub drift_user: AccountLoader<'info, User>,
+    pub drift_program: Program<'info, Drift>,
+}
+
+impl<'info> UpdateUserReduceOnlyCPI for Context<'_, '_, '_, 'info, ExecuteVaultPoll<'info>> {
+    fn drift_update_user_reduce_only(&self, reduce_only: bool) -> Result<()> {
+        implement_update_user_reduce_only_cpi!(self, reduce_only);
+        Ok(())
+    }
+}
@@ -8,7 +8,10 @@ use drift::state::user::{FuelOverflowStatus, User, UserStats};
 
 use crate::constraints::*;
 use crate::drift_cpi::WithdrawCPI;
-use crate::state::{FuelOverflowProvider, Vault, VaultDepositor};
+use crate::state::{
+    validate_withdraw_not_paused, FuelOverflowProvider, Vault, VaultConfigProvider, VaultDepositor,
+    VaultDepositorLoader, VaultGuardProvider,
+};
 use crate::token_cpi::TokenTransferCPI;
 use crate::VaultProtocolProvider;
 use crate::{declare_vault_seeds, AccountMapProvider};
@@ -18,8 +21,9 @@ pub fn force_withdraw<'c: 'info, 'info>(
 ) -> Result<()> {
     let clock = &Clock::get()?;
     let mut vault = ctx.accounts.vault.load_mut()?;
-    let mut vault_depositor = ctx.accounts.vault_depositor.load_mut()?;
+    let mut vault_depositor = ctx.accounts.vault_depositor.load_mut_migrated()?;
 
+    let vault_config = ctx.vault_config(&vault)?;
     // backwards compatible: if last rem acct does not deserialize into [`VaultProtocol`] then it's a legacy vault.
     let mut vp = ctx.vault_protocol();
     vault.validate_vault_protocol(&vp)?;
@@ -48,11 +52,20 @@ pub fn force_withdraw<'c: 'info, 'info>(
         vault.calculate_equity(&user, &perp_market_map, &spot_market_map, &mut oracle_map)?;
 
     let spot_market = spot_market_map.get_ref(&spot_market_index)?;
+    validate_withdraw_not_paused(&spot_market)?;
     let oracle = oracle_map.get_price_data(&spot_market.oracle_id())?;
 
+    if let Some(vault_guard) = ctx.vault_guard(&vault)? {
+        vault_guard.load()?.validate_oracle_price(
+            oracle.price,
+            spot_market.historical_oracle_data.last_oracle_price_twap,
+        )?;
+    }
+
     let (withdraw_amount, _) = vault_depositor.withdraw(
         vault_equity,
         &mut vault,
+        &vault_config,
         &mut vp,
         clock.unix_timestamp,
         &user_stats,
@@ -68,9 +81,12 @@ pub fn force_withdraw<'c: 'info, 'info>(
     drop(user_stats);
     drop(vp);
 
-    ctx.drift_withdraw(withdraw_amount)?;
+    // shares burned at zero vault equity pay out nothing
+    if withdraw_amount > 0 {
+        ctx.drift_withdraw(withdraw_amount)?;
 
-    ctx.token_transfer(withdraw_amount)?;
+        ctx.token_transfer(withdraw_amount)?;
+    }
 
     Ok(())
 }
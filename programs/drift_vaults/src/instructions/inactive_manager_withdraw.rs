@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+use drift::math::safe_math::SafeMath;
+
+use crate::error::ErrorCode;
+use crate::state::{VaultDepositorLoader, VaultManagerActivityProvider};
+use crate::validate;
+
+use super::Withdraw;
+
+/// Withdraws the depositor's pending request without waiting out the redeem period once the manager has been
+/// inactive for the vault's [`VaultManagerActivity`](crate::state::VaultManagerActivity) `inactivity_period`. The
+/// [`VaultManagerActivity`](crate::state::VaultManagerActivity) must be in remaining_accounts after the market
+/// accounts.
+pub fn inactive_manager_withdraw<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, Withdraw<'info>>,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+
+    {
+        let vault = ctx.accounts.vault.load()?;
+        validate!(
+            !vault.withdraw_settlement,
+            ErrorCode::InvalidVaultManagerActivity,
+            "vault settles withdraws, requests must wait for a settlement"
+        )?;
+
+        let vault_manager_activity = ctx
+            .vault_manager_activity(&vault.pubkey)
+            .ok_or(ErrorCode::InvalidVaultManagerActivity)?;
+        vault_manager_activity
+            .load()?
+            .validate_inactive(&*ctx.accounts.drift_user_stats.load()?, now)?;
+
+        let mut vault_depositor = ctx.accounts.vault_depositor.load_mut_migrated()?;
+        validate!(
+            vault_depositor.last_withdraw_request.pending(),
+            ErrorCode::InvalidVaultManagerActivity,
+            "no withdraw request pending"
+        )?;
+
+        msg!("manager inactive, request matures immediately");
+        vault_depositor.last_withdraw_request.ts = now.safe_sub(vault.redeem_period)?;
+    }
+
+    super::withdraw(ctx)
+}
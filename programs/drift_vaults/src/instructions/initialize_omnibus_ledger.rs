@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+
+use crate::constraints::is_authority_for_vault_depositor;
+use crate::state::{
+    OmnibusLedger, VaultDepositor, VaultDepositorBase, VaultDepositorFlag, VaultDepositorLoader,
+};
+use crate::Size;
+
+/// Turns the vault depositor into an omnibus position whose shares the authority allocates to beneficiaries in an
+/// [`OmnibusLedger`]. Existing shares start out unallocated. One way, the depositor stays omnibus.
+pub fn initialize_omnibus_ledger<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, InitializeOmnibusLedger<'info>>,
+) -> Result<()> {
+    let mut vault_depositor = ctx.accounts.vault_depositor.load_mut_migrated()?;
+    vault_depositor.add_flag(VaultDepositorFlag::Omnibus);
+
+    let mut omnibus_ledger = ctx.accounts.omnibus_ledger.load_init()?;
+    omnibus_ledger.vault_depositor = vault_depositor.pubkey;
+    omnibus_ledger.shares_base = vault_depositor.get_vault_shares_base();
+    omnibus_ledger.bump = ctx.bumps.omnibus_ledger;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeOmnibusLedger<'info> {
+    #[account(
+        mut,
+        constraint = is_authority_for_vault_depositor(&vault_depositor, &authority)?
+    )]
+    pub vault_depositor: AccountLoader<'info, VaultDepositor>,
+    #[account(
+        init,
+        seeds = [b"omnibus_ledger", vault_depositor.key().as_ref()],
+        space = OmnibusLedger::SIZE,
+        bump,
+        payer = payer
+    )]
+    pub omnibus_ledger: AccountLoader<'info, OmnibusLedger>,
+    pub authority: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub rent: Sysvar<'info, Rent>,
+    pub system_program: Program<'info, System>,
+}
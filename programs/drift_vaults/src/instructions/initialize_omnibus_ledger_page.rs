@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+
+use crate::constraints::is_authority_for_vault_depositor;
+use crate::error::ErrorCode;
+use crate::state::{
+    OmnibusLedger, OmnibusLedgerPage, VaultDepositor, VaultDepositorBase, VaultDepositorLoader,
+};
+use crate::{validate, Size};
+
+/// Adds the next page of beneficiary entries to the [`OmnibusLedger`], pages are created in order.
+pub fn initialize_omnibus_ledger_page<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, InitializeOmnibusLedgerPage<'info>>,
+    page_index: u16,
+) -> Result<()> {
+    let vault_depositor = ctx.accounts.vault_depositor.load_checked()?;
+    let mut omnibus_ledger = ctx.accounts.omnibus_ledger.load_mut()?;
+    validate!(
+        page_index == omnibus_ledger.page_count,
+        ErrorCode::InvalidOmnibusLedger,
+        "next page index is {}",
+        omnibus_ledger.page_count
+    )?;
+    omnibus_ledger.page_count = omnibus_ledger.page_count.saturating_add(1);
+
+    let mut page = ctx.accounts.omnibus_ledger_page.load_init()?;
+    page.vault_depositor = vault_depositor.pubkey;
+    page.shares_base = vault_depositor.get_vault_shares_base();
+    page.page_index = page_index;
+    page.bump = ctx.bumps.omnibus_ledger_page;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(page_index: u16)]
+pub struct InitializeOmnibusLedgerPage<'info> {
+    #[account(
+        constraint = is_authority_for_vault_depositor(&vault_depositor, &authority)?
+    )]
+    pub vault_depositor: AccountLoader<'info, VaultDepositor>,
+    #[account(
+        mut,
+        seeds = [b"omnibus_ledger", vault_depositor.key().as_ref()],
+        bump = omnibus_ledger.load()?.bump,
+    )]
+    pub omnibus_ledger: AccountLoader<'info, OmnibusLedger>,
+    #[account(
+        init,
+        seeds = [b"omnibus_ledger_page", vault_depositor.key().as_ref(), page_index.to_le_bytes().as_ref()],
+        space = OmnibusLedgerPage::SIZE,
+        bump,
+        payer = payer
+    )]
+    pub omnibus_ledger_page: AccountLoader<'info, OmnibusLedgerPage>,
+    pub authority: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub rent: Sysvar<'info, Rent>,
+    pub system_program: Program<'info, System>,
+}
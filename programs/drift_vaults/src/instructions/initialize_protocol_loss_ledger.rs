@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+
+use crate::constraints::{is_protocol_for_vault, is_vault_protocol_for_vault};
+use crate::state::{ProtocolLossLedger, Vault, VaultProtocol};
+use crate::Size;
+
+/// Opts a depositor authority into cross-vault loss carryforward for the protocol's vaults denominated in
+/// the same spot market as `vault`.
+pub fn initialize_protocol_loss_ledger<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, InitializeProtocolLossLedger<'info>>,
+    authority: Pubkey,
+) -> Result<()> {
+    let vault = ctx.accounts.vault.load()?;
+
+    let mut ledger = ctx.accounts.protocol_loss_ledger.load_init()?;
+    ledger.protocol = ctx.accounts.protocol.key();
+    ledger.authority = authority;
+    ledger.spot_market_index = vault.spot_market_index;
+    ledger.bump = ctx.bumps.protocol_loss_ledger;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(authority: Pubkey)]
+pub struct InitializeProtocolLossLedger<'info> {
+    #[account(
+        constraint = is_protocol_for_vault(&vault, &vault_protocol, &protocol)?
+    )]
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        constraint = is_vault_protocol_for_vault(&vault_protocol, &vault)?
+    )]
+    pub vault_protocol: AccountLoader<'info, VaultProtocol>,
+    #[account(
+        init,
+        seeds = [b"protocol_loss_ledger", protocol.key().as_ref(), authority.as_ref()],
+        space = ProtocolLossLedger::SIZE,
+        bump,
+        payer = payer
+    )]
+    pub protocol_loss_ledger: AccountLoader<'info, ProtocolLossLedger>,
+    pub protocol: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub rent: Sysvar<'info, Rent>,
+    pub system_program: Program<'info, System>,
+}
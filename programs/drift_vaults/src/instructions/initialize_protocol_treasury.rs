@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+
+use crate::state::ProtocolTreasury;
+use crate::Size;
+
+/// Designates the owner of the token accounts `collect_protocol_fees` pays the protocol's fees to.
+pub fn initialize_protocol_treasury<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, InitializeProtocolTreasury<'info>>,
+    treasury: Pubkey,
+) -> Result<()> {
+    let mut protocol_treasury = ctx.accounts.protocol_treasury.load_init()?;
+    protocol_treasury.protocol = ctx.accounts.protocol.key();
+    protocol_treasury.treasury = treasury;
+    protocol_treasury.bump = ctx.bumps.protocol_treasury;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeProtocolTreasury<'info> {
+    #[account(
+        init,
+        seeds = [b"protocol_treasury", protocol.key().as_ref()],
+        space = ProtocolTreasury::SIZE,
+        bump,
+        payer = payer
+    )]
+    pub protocol_treasury: AccountLoader<'info, ProtocolTreasury>,
+    pub protocol: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub rent: Sysvar<'info, Rent>,
+    pub system_program: Program<'info, System>,
+}
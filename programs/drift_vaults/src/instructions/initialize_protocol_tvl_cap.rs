@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+
+use crate::state::ProtocolTvlCap;
+use crate::Size;
+
+/// Caps the combined tvl of the protocol's vaults at `max_tvl`, vaults are added with `add_vault_to_protocol_tvl_cap`.
+pub fn initialize_protocol_tvl_cap<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, InitializeProtocolTvlCap<'info>>,
+    max_tvl: u64,
+) -> Result<()> {
+    let mut protocol_tvl_cap = ctx.accounts.protocol_tvl_cap.load_init()?;
+    protocol_tvl_cap.protocol = ctx.accounts.protocol.key();
+    protocol_tvl_cap.max_tvl = max_tvl;
+    protocol_tvl_cap.bump = ctx.bumps.protocol_tvl_cap;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeProtocolTvlCap<'info> {
+    #[account(
+        init,
+        seeds = [b"protocol_tvl_cap", protocol.key().as_ref()],
+        space = ProtocolTvlCap::SIZE,
+        bump,
+        payer = payer
+    )]
+    pub protocol_tvl_cap: AccountLoader<'info, ProtocolTvlCap>,
+    pub protocol: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub rent: Sysvar<'info, Rent>,
+    pub system_program: Program<'info, System>,
+}
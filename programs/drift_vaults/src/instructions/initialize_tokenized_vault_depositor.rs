@@ -1,5 +1,5 @@
 use crate::constraints::is_manager_for_vault;
-use crate::{Size, TokenizedVaultDepositor, Vault};
+use crate::{Size, TokenizedVaultDepositor, Vault, VaultFeature};
 use anchor_lang::prelude::*;
 use anchor_spl::{
     metadata::{
@@ -14,6 +14,8 @@ pub fn initialize_tokenized_vault_depositor(
     params: InitializeTokenizedVaultDepositorParams,
 ) -> Result<()> {
     let vault = ctx.accounts.vault.load()?;
+    vault.validate_feature_enabled(VaultFeature::Tokenization)?;
+
     let mut tokenized_vault_depositor = ctx.accounts.vault_depositor.load_init()?;
     *tokenized_vault_depositor = TokenizedVaultDepositor::new(
         ctx.accounts.vault.key(),
@@ -2,7 +2,7 @@ use crate::constants::ONE_DAY;
 use crate::drift_cpi::InitializeUserCPI;
 use crate::{error::ErrorCode, validate, Size, Vault};
 use anchor_lang::prelude::*;
-use anchor_spl::token::{Mint, Token, TokenAccount};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 use drift::cpi::accounts::{InitializeUser, InitializeUserStats};
 use drift::math::casting::Cast;
 use drift::math::constants::PERCENTAGE_PRECISION_U64;
@@ -93,7 +93,7 @@ pub struct InitializeVault<'info> {
         token::mint = drift_spot_market_mint,
         token::authority = vault
     )]
-    pub token_account: Box<Account<'info, TokenAccount>>,
+    pub token_account: Box<InterfaceAccount<'info, TokenAccount>>,
     /// CHECK: checked in drift cpi
     #[account(mut)]
     pub drift_user_stats: AccountInfo<'info>,
@@ -110,14 +110,14 @@ pub struct InitializeVault<'info> {
     #[account(
         constraint = drift_spot_market.load()?.mint.eq(&drift_spot_market_mint.key())
     )]
-    pub drift_spot_market_mint: Box<Account<'info, Mint>>,
+    pub drift_spot_market_mint: Box<InterfaceAccount<'info, Mint>>,
     pub manager: Signer<'info>,
     #[account(mut)]
     pub payer: Signer<'info>,
     pub rent: Sysvar<'info, Rent>,
     pub system_program: Program<'info, System>,
     pub drift_program: Program<'info, Drift>,
-    pub token_program: Program<'info, Token>,
+    pub token_program: Interface<'info, TokenInterface>,
 }
 
 impl<'info> InitializeUserCPI for Context<'_, '_, '_, 'info, InitializeVault<'info>> {
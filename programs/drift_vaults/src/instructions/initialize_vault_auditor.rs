@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+
+use crate::constraints::is_manager_for_vault;
+use crate::state::{Vault, VaultAuditor};
+use crate::Size;
+
+/// Creates the vault's [`VaultAuditor`], giving `auditor` the manager's read only rights.
+pub fn initialize_vault_auditor<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, InitializeVaultAuditor<'info>>,
+    auditor: Pubkey,
+) -> Result<()> {
+    let mut vault_auditor = ctx.accounts.vault_auditor.load_init()?;
+    vault_auditor.vault = ctx.accounts.vault.key();
+    vault_auditor.auditor = auditor;
+    vault_auditor.bump = ctx.bumps.vault_auditor;
+
+    msg!("Vault auditor {}", auditor);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeVaultAuditor<'info> {
+    #[account(
+        constraint = is_manager_for_vault(&vault, &manager)?
+    )]
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        init,
+        seeds = [b"vault_auditor", vault.key().as_ref()],
+        space = VaultAuditor::SIZE,
+        bump,
+        payer = payer
+    )]
+    pub vault_auditor: AccountLoader<'info, VaultAuditor>,
+    pub manager: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub rent: Sysvar<'info, Rent>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::constraints::is_manager_for_vault;
+use crate::state::{Vault, VaultBackstop};
+use crate::Size;
+
+/// Creates the vault's [`VaultBackstop`] and its token account. Nothing is contributed until the vault's protocol
+/// sets a max balance with [`update_vault_backstop_max_balance`](crate::instructions::update_vault_backstop_max_balance).
+pub fn initialize_vault_backstop<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, InitializeVaultBackstop<'info>>,
+    contribution_rate: u32,
+    deductible: u32,
+) -> Result<()> {
+    let vault = ctx.accounts.vault.load()?;
+
+    let mut vault_backstop = ctx.accounts.vault_backstop.load_init()?;
+    vault_backstop.update(&vault, contribution_rate, deductible)?;
+    vault_backstop.vault = vault.pubkey;
+    vault_backstop.shares_base = vault.shares_base;
+    vault_backstop.bump = ctx.bumps.vault_backstop;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeVaultBackstop<'info> {
+    #[account(
+        constraint = is_manager_for_vault(&vault, &manager)?
+    )]
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        init,
+        seeds = [b"vault_backstop", vault.key().as_ref()],
+        space = VaultBackstop::SIZE,
+        bump,
+        payer = payer
+    )]
+    pub vault_backstop: AccountLoader<'info, VaultBackstop>,
+    #[account(
+        seeds = [b"vault_token_account".as_ref(), vault.key().as_ref()],
+        bump,
+    )]
+    pub vault_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        init,
+        seeds = [b"vault_backstop_token_account".as_ref(), vault.key().as_ref()],
+        bump,
+        payer = payer,
+        token::mint = mint,
+        token::authority = vault
+    )]
+    pub backstop_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        address = vault_token_account.mint
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+    pub manager: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub rent: Sysvar<'info, Rent>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+use crate::constraints::is_manager_for_vault;
+use crate::state::{Vault, VaultConfig, VaultFlag};
+use crate::Size;
+
+/// Creates the vault's [`VaultConfig`], from then on every instruction reading the vault's fee and profit share
+/// configuration must pass it in remaining accounts.
+pub fn initialize_vault_config<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, InitializeVaultConfig<'info>>,
+) -> Result<()> {
+    let mut vault_config = ctx.accounts.vault_config.load_init()?;
+    vault_config.vault = ctx.accounts.vault.key();
+    vault_config.bump = ctx.bumps.vault_config;
+
+    let mut vault = ctx.accounts.vault.load_mut()?;
+    vault.add_flag(VaultFlag::HasVaultConfig);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeVaultConfig<'info> {
+    #[account(
+        mut,
+        constraint = is_manager_for_vault(&vault, &manager)?
+    )]
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        init,
+        seeds = [b"vault_config", vault.key().as_ref()],
+        space = VaultConfig::SIZE,
+        bump,
+        payer = payer
+    )]
+    pub vault_config: AccountLoader<'info, VaultConfig>,
+    pub manager: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub rent: Sysvar<'info, Rent>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,80 @@
+use anchor_lang::prelude::*;
+use drift::instructions::optional_accounts::AccountMaps;
+use drift::state::user::User;
+
+use crate::constraints::{is_manager_for_vault, is_user_for_vault};
+use crate::state::{
+    record_vault_param_change, Vault, VaultCrystallization, VaultParam, VaultParamChangeLogProvider,
+};
+use crate::{AccountMapProvider, Size};
+
+/// Moves the vault's manager profit share to fund level [`VaultCrystallization`], cranked by
+/// [`crystallize_vault_profit_share`](crate::instructions::crystallize_vault_profit_share). One way and only before
+/// the vault has depositors. Remaining accounts are the oracles and markets for the vault's positions.
+pub fn initialize_vault_crystallization<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, InitializeVaultCrystallization<'info>>,
+    crystallization_period: u32,
+) -> Result<()> {
+    let clock = &Clock::get()?;
+    let mut vault = ctx.accounts.vault.load_mut()?;
+    let param_change_log = ctx.vault_param_change_log();
+    vault.validate_param_change_log(&param_change_log)?;
+    let user = ctx.accounts.drift_user.load()?;
+
+    let AccountMaps {
+        perp_market_map,
+        spot_market_map,
+        mut oracle_map,
+    } = ctx.load_maps(clock.slot, None, false, false)?;
+
+    let vault_equity =
+        vault.calculate_equity(&user, &perp_market_map, &spot_market_map, &mut oracle_map)?;
+
+    record_vault_param_change(
+        &vault,
+        &param_change_log,
+        ctx.accounts.manager.key(),
+        VaultParam::ProfitShare,
+        vault.profit_share,
+        0_u32,
+        clock.unix_timestamp,
+    )?;
+
+    let mut vault_crystallization = ctx.accounts.vault_crystallization.load_init()?;
+    vault_crystallization.initialize(
+        &mut vault,
+        vault_equity,
+        crystallization_period,
+        clock.unix_timestamp,
+    )?;
+    vault_crystallization.bump = ctx.bumps.vault_crystallization;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeVaultCrystallization<'info> {
+    #[account(
+        mut,
+        constraint = is_manager_for_vault(&vault, &manager)?
+    )]
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        init,
+        seeds = [b"vault_crystallization", vault.key().as_ref()],
+        space = VaultCrystallization::SIZE,
+        bump,
+        payer = payer
+    )]
+    pub vault_crystallization: AccountLoader<'info, VaultCrystallization>,
+    #[account(
+        constraint = is_user_for_vault(&vault, &drift_user.key())?
+    )]
+    /// CHECK: checked in drift cpi
+    pub drift_user: AccountLoader<'info, User>,
+    pub manager: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub rent: Sysvar<'info, Rent>,
+    pub system_program: Program<'info, System>,
+}
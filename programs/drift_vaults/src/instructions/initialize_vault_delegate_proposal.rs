@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+
+use crate::constraints::{
+    is_manager_for_vault, is_protocol_for_vault, is_vault_protocol_for_vault,
+};
+use crate::state::{Vault, VaultDelegateProposal, VaultProtocol, VaultProtocolFlag};
+use crate::Size;
+
+/// Co-signed by the manager and the protocol, from here on delegate changes need both, see
+/// [`VaultProtocolFlag::DelegateDualApproval`].
+pub fn initialize_vault_delegate_proposal<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, InitializeVaultDelegateProposal<'info>>,
+) -> Result<()> {
+    let mut proposal = ctx.accounts.vault_delegate_proposal.load_init()?;
+    proposal.vault = ctx.accounts.vault.key();
+    proposal.bump = ctx.bumps.vault_delegate_proposal;
+
+    ctx.accounts
+        .vault_protocol
+        .load_mut()?
+        .add_flag(VaultProtocolFlag::DelegateDualApproval);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeVaultDelegateProposal<'info> {
+    #[account(
+        constraint = is_manager_for_vault(&vault, &manager)?,
+        constraint = is_protocol_for_vault(&vault, &vault_protocol, &protocol)?
+    )]
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        mut,
+        constraint = is_vault_protocol_for_vault(&vault_protocol, &vault)?
+    )]
+    pub vault_protocol: AccountLoader<'info, VaultProtocol>,
+    #[account(
+        init,
+        seeds = [b"vault_delegate_proposal", vault.key().as_ref()],
+        space = VaultDelegateProposal::SIZE,
+        bump,
+        payer = payer
+    )]
+    pub vault_delegate_proposal: AccountLoader<'info, VaultDelegateProposal>,
+    pub manager: Signer<'info>,
+    pub protocol: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub rent: Sysvar<'info, Rent>,
+    pub system_program: Program<'info, System>,
+}
@@ -1,16 +1,20 @@
 use anchor_lang::prelude::*;
+use drift::math::safe_math::SafeMath;
 
 use crate::error::ErrorCode;
-use crate::state::Vault;
+use crate::state::{Vault, VaultDepositorCapProvider, VaultDepositorFlag};
 use crate::{validate, Size, VaultDepositor};
 
-pub fn initialize_vault_depositor(ctx: Context<InitializeVaultDepositor>) -> Result<()> {
+pub fn initialize_vault_depositor<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, InitializeVaultDepositor<'info>>,
+) -> Result<()> {
     let mut vault_depositor = ctx.accounts.vault_depositor.load_init()?;
     vault_depositor.vault = ctx.accounts.vault.key();
     vault_depositor.pubkey = ctx.accounts.vault_depositor.key();
     vault_depositor.authority = *ctx.accounts.authority.key;
+    vault_depositor.add_flag(VaultDepositorFlag::Counted);
 
-    let vault = ctx.accounts.vault.load()?;
+    let mut vault = ctx.accounts.vault.load_mut()?;
     if vault.permissioned {
         validate!(
             vault.manager == *ctx.accounts.payer.key,
@@ -25,11 +29,15 @@ pub fn initialize_vault_depositor(ctx: Context<InitializeVaultDepositor>) -> Res
         )?;
     }
 
+    vault.validate_depositor_cap(&ctx.vault_depositor_cap())?;
+    vault.depositor_count = vault.depositor_count.safe_add(1)?;
+
     Ok(())
 }
 
 #[derive(Accounts)]
 pub struct InitializeVaultDepositor<'info> {
+    #[account(mut)]
     pub vault: AccountLoader<'info, Vault>,
     #[account(
       init,
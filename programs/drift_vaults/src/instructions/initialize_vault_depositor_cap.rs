@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+
+use crate::constraints::is_manager_for_vault;
+use crate::state::{Vault, VaultDepositorCap, VaultFlag};
+use crate::Size;
+
+/// Creates the vault's [`VaultDepositorCap`], from then on `initialize_vault_depositor` must pass it in remaining
+/// accounts.
+pub fn initialize_vault_depositor_cap<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, InitializeVaultDepositorCap<'info>>,
+    max_depositors: u32,
+) -> Result<()> {
+    VaultDepositorCap::validate_max_depositors(max_depositors)?;
+
+    let mut vault_depositor_cap = ctx.accounts.vault_depositor_cap.load_init()?;
+    vault_depositor_cap.vault = ctx.accounts.vault.key();
+    vault_depositor_cap.max_depositors = max_depositors;
+    vault_depositor_cap.bump = ctx.bumps.vault_depositor_cap;
+
+    let mut vault = ctx.accounts.vault.load_mut()?;
+    vault.add_flag(VaultFlag::HasDepositorCap);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeVaultDepositorCap<'info> {
+    #[account(
+        mut,
+        constraint = is_manager_for_vault(&vault, &manager)?
+    )]
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        init,
+        seeds = [b"vault_depositor_cap", vault.key().as_ref()],
+        space = VaultDepositorCap::SIZE,
+        bump,
+        payer = payer
+    )]
+    pub vault_depositor_cap: AccountLoader<'info, VaultDepositorCap>,
+    pub manager: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub rent: Sysvar<'info, Rent>,
+    pub system_program: Program<'info, System>,
+}
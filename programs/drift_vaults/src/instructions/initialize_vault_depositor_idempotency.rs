@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+
+use crate::constraints::{is_authority_for_vault_depositor, is_vault_for_vault_depositor};
+use crate::state::{Vault, VaultDepositor, VaultDepositorIdempotency};
+use crate::Size;
+
+pub fn initialize_vault_depositor_idempotency<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, InitializeVaultDepositorIdempotency<'info>>,
+) -> Result<()> {
+    let mut idempotency = ctx.accounts.vault_depositor_idempotency.load_init()?;
+    idempotency.vault_depositor = ctx.accounts.vault_depositor.key();
+    idempotency.bump = ctx.bumps.vault_depositor_idempotency;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeVaultDepositorIdempotency<'info> {
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        seeds = [b"vault_depositor", vault.key().as_ref(), authority.key().as_ref()],
+        bump,
+        constraint = is_vault_for_vault_depositor(&vault_depositor, &vault)?,
+        constraint = is_authority_for_vault_depositor(&vault_depositor, &authority)?,
+    )]
+    pub vault_depositor: AccountLoader<'info, VaultDepositor>,
+    #[account(
+        init,
+        seeds = [b"vault_depositor_idempotency", vault_depositor.key().as_ref()],
+        space = VaultDepositorIdempotency::SIZE,
+        bump,
+        payer = payer
+    )]
+    pub vault_depositor_idempotency: AccountLoader<'info, VaultDepositorIdempotency>,
+    pub authority: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub rent: Sysvar<'info, Rent>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+
+use crate::constraints::{is_authority_for_vault_depositor, is_vault_for_vault_depositor};
+use crate::state::{Vault, VaultDepositor, VaultDepositorLockup};
+use crate::Size;
+
+pub fn initialize_vault_depositor_lockup<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, InitializeVaultDepositorLockup<'info>>,
+) -> Result<()> {
+    let mut vault_depositor_lockup = ctx.accounts.vault_depositor_lockup.load_init()?;
+    vault_depositor_lockup.vault_depositor = ctx.accounts.vault_depositor.key();
+    vault_depositor_lockup.bump = ctx.bumps.vault_depositor_lockup;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeVaultDepositorLockup<'info> {
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        seeds = [b"vault_depositor", vault.key().as_ref(), authority.key().as_ref()],
+        bump,
+        constraint = is_vault_for_vault_depositor(&vault_depositor, &vault)?,
+        constraint = is_authority_for_vault_depositor(&vault_depositor, &authority)?,
+    )]
+    pub vault_depositor: AccountLoader<'info, VaultDepositor>,
+    #[account(
+        init,
+        seeds = [b"vault_depositor_lockup", vault_depositor.key().as_ref()],
+        space = VaultDepositorLockup::SIZE,
+        bump,
+        payer = payer
+    )]
+    pub vault_depositor_lockup: AccountLoader<'info, VaultDepositorLockup>,
+    pub authority: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub rent: Sysvar<'info, Rent>,
+    pub system_program: Program<'info, System>,
+}
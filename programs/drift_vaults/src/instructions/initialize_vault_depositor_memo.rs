@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+
+use crate::constraints::{is_authority_for_vault_depositor, is_vault_for_vault_depositor};
+use crate::state::{Vault, VaultDepositor, VaultDepositorMemo};
+use crate::Size;
+
+/// Creates the vault depositor's memo, later changes go through `update_vault_depositor_memo`.
+pub fn initialize_vault_depositor_memo<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, InitializeVaultDepositorMemo<'info>>,
+    memo: [u8; 32],
+) -> Result<()> {
+    let clock = &Clock::get()?;
+
+    let mut vault_depositor_memo = ctx.accounts.vault_depositor_memo.load_init()?;
+    vault_depositor_memo.vault_depositor = ctx.accounts.vault_depositor.key();
+    vault_depositor_memo.bump = ctx.bumps.vault_depositor_memo;
+    vault_depositor_memo.update(
+        ctx.accounts.vault.key(),
+        ctx.accounts.authority.key(),
+        memo,
+        clock.unix_timestamp,
+    );
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeVaultDepositorMemo<'info> {
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        constraint = is_vault_for_vault_depositor(&vault_depositor, &vault)?,
+        constraint = is_authority_for_vault_depositor(&vault_depositor, &authority)?,
+    )]
+    pub vault_depositor: AccountLoader<'info, VaultDepositor>,
+    #[account(
+        init,
+        seeds = [b"vault_depositor_memo", vault_depositor.key().as_ref()],
+        space = VaultDepositorMemo::SIZE,
+        bump,
+        payer = payer
+    )]
+    pub vault_depositor_memo: AccountLoader<'info, VaultDepositorMemo>,
+    pub authority: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub rent: Sysvar<'info, Rent>,
+    pub system_program: Program<'info, System>,
+}
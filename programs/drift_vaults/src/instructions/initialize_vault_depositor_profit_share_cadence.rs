@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+use crate::constraints::is_vault_for_vault_depositor;
+use crate::state::{Vault, VaultDepositor, VaultDepositorProfitShareCadence};
+use crate::Size;
+
+/// Creates a depositor's [`VaultDepositorProfitShareCadence`]. Permissionless, so the manager's cranks can create the
+/// ones they need.
+pub fn initialize_vault_depositor_profit_share_cadence<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, InitializeVaultDepositorProfitShareCadence<'info>>,
+) -> Result<()> {
+    let mut vault_depositor_profit_share_cadence = ctx
+        .accounts
+        .vault_depositor_profit_share_cadence
+        .load_init()?;
+    vault_depositor_profit_share_cadence.vault_depositor = ctx.accounts.vault_depositor.key();
+    vault_depositor_profit_share_cadence.bump = ctx.bumps.vault_depositor_profit_share_cadence;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeVaultDepositorProfitShareCadence<'info> {
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        constraint = is_vault_for_vault_depositor(&vault_depositor, &vault)?,
+    )]
+    pub vault_depositor: AccountLoader<'info, VaultDepositor>,
+    #[account(
+        init,
+        seeds = [b"vault_depositor_profit_share_cadence", vault_depositor.key().as_ref()],
+        space = VaultDepositorProfitShareCadence::SIZE,
+        bump,
+        payer = payer
+    )]
+    pub vault_depositor_profit_share_cadence:
+        AccountLoader<'info, VaultDepositorProfitShareCadence>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub rent: Sysvar<'info, Rent>,
+    pub system_program: Program<'info, System>,
+}
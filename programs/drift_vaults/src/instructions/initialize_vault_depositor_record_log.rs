@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+
+use crate::constraints::is_manager_for_vault;
+use crate::state::{Vault, VaultDepositorRecordLog};
+use crate::Size;
+
+/// Creates the vault's [`VaultDepositorRecordLog`]. Depositor flows mirror their records into it whenever it's passed
+/// in remaining accounts.
+pub fn initialize_vault_depositor_record_log<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, InitializeVaultDepositorRecordLog<'info>>,
+) -> Result<()> {
+    let mut log = ctx.accounts.vault_depositor_record_log.load_init()?;
+    log.vault = ctx.accounts.vault.key();
+    log.bump = ctx.bumps.vault_depositor_record_log;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeVaultDepositorRecordLog<'info> {
+    #[account(
+        constraint = is_manager_for_vault(&vault, &manager)?
+    )]
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        init,
+        seeds = [b"vault_depositor_record_log", vault.key().as_ref()],
+        space = VaultDepositorRecordLog::SIZE,
+        bump,
+        payer = payer
+    )]
+    pub vault_depositor_record_log: AccountLoader<'info, VaultDepositorRecordLog>,
+    pub manager: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub rent: Sysvar<'info, Rent>,
+    pub system_program: Program<'info, System>,
+}
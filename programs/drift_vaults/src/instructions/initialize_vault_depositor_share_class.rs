@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+
+use crate::constraints::{is_authority_for_vault_depositor, is_vault_for_vault_depositor};
+use crate::state::{Vault, VaultDepositor, VaultDepositorShareClass};
+use crate::Size;
+
+pub fn initialize_vault_depositor_share_class<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, InitializeVaultDepositorShareClass<'info>>,
+) -> Result<()> {
+    let mut vault_depositor_share_class = ctx.accounts.vault_depositor_share_class.load_init()?;
+    vault_depositor_share_class.pubkey = ctx.accounts.vault_depositor_share_class.key();
+    vault_depositor_share_class.vault_depositor = ctx.accounts.vault_depositor.key();
+    vault_depositor_share_class.authority = ctx.accounts.authority.key();
+    vault_depositor_share_class.vault_shares_base = ctx.accounts.vault.load()?.shares_base;
+    vault_depositor_share_class.bump = ctx.bumps.vault_depositor_share_class;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeVaultDepositorShareClass<'info> {
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        constraint = is_vault_for_vault_depositor(&vault_depositor, &vault)?,
+        constraint = is_authority_for_vault_depositor(&vault_depositor, &authority)?,
+    )]
+    pub vault_depositor: AccountLoader<'info, VaultDepositor>,
+    #[account(
+        init,
+        seeds = [b"vault_depositor_share_class", vault_depositor.key().as_ref()],
+        space = VaultDepositorShareClass::SIZE,
+        bump,
+        payer = payer
+    )]
+    pub vault_depositor_share_class: AccountLoader<'info, VaultDepositorShareClass>,
+    pub authority: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub rent: Sysvar<'info, Rent>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+
+use crate::constraints::is_manager_for_vault;
+use crate::state::{Vault, VaultConfig, VaultConfigFlag, VaultDepositorThrottle};
+use crate::Size;
+
+/// Creates the vault's [`VaultDepositorThrottle`] and sets [`VaultConfigFlag::DepositorThrottle`] on its
+/// [`VaultConfig`], from then on deposits and withdraw requests must pass the throttle in remaining accounts.
+pub fn initialize_vault_depositor_throttle<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, InitializeVaultDepositorThrottle<'info>>,
+    min_deposit_interval: u32,
+    min_request_interval: u32,
+) -> Result<()> {
+    let mut vault_depositor_throttle = ctx.accounts.vault_depositor_throttle.load_init()?;
+    vault_depositor_throttle.update_intervals(min_deposit_interval, min_request_interval)?;
+    vault_depositor_throttle.vault = ctx.accounts.vault.key();
+    vault_depositor_throttle.bump = ctx.bumps.vault_depositor_throttle;
+
+    let mut vault_config = ctx.accounts.vault_config.load_mut()?;
+    vault_config.add_flag(VaultConfigFlag::DepositorThrottle);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeVaultDepositorThrottle<'info> {
+    #[account(
+        constraint = is_manager_for_vault(&vault, &manager)?
+    )]
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        mut,
+        seeds = [b"vault_config", vault.key().as_ref()],
+        bump = vault_config.load()?.bump,
+    )]
+    pub vault_config: AccountLoader<'info, VaultConfig>,
+    #[account(
+        init,
+        seeds = [b"vault_depositor_throttle", vault.key().as_ref()],
+        space = VaultDepositorThrottle::SIZE,
+        bump,
+        payer = payer
+    )]
+    pub vault_depositor_throttle: AccountLoader<'info, VaultDepositorThrottle>,
+    pub manager: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub rent: Sysvar<'info, Rent>,
+    pub system_program: Program<'info, System>,
+}
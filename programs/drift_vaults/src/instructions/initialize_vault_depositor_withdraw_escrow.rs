@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+
+use crate::constraints::{is_authority_for_vault_depositor, is_vault_for_vault_depositor};
+use crate::state::{Vault, VaultDepositor, VaultDepositorWithdrawEscrow};
+use crate::Size;
+
+pub fn initialize_vault_depositor_withdraw_escrow<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, InitializeVaultDepositorWithdrawEscrow<'info>>,
+) -> Result<()> {
+    let mut vault_depositor_withdraw_escrow =
+        ctx.accounts.vault_depositor_withdraw_escrow.load_init()?;
+    vault_depositor_withdraw_escrow.vault_depositor = ctx.accounts.vault_depositor.key();
+    vault_depositor_withdraw_escrow.bump = ctx.bumps.vault_depositor_withdraw_escrow;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeVaultDepositorWithdrawEscrow<'info> {
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        constraint = is_vault_for_vault_depositor(&vault_depositor, &vault)?,
+        constraint = is_authority_for_vault_depositor(&vault_depositor, &authority)?,
+    )]
+    pub vault_depositor: AccountLoader<'info, VaultDepositor>,
+    #[account(
+        init,
+        seeds = [b"vault_depositor_withdraw_escrow", vault_depositor.key().as_ref()],
+        space = VaultDepositorWithdrawEscrow::SIZE,
+        bump,
+        payer = payer
+    )]
+    pub vault_depositor_withdraw_escrow: AccountLoader<'info, VaultDepositorWithdrawEscrow>,
+    pub authority: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub rent: Sysvar<'info, Rent>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+
+use crate::constraints::is_authority_for_vault_depositor;
+use crate::state::{
+    VaultDepositor, VaultDepositorBase, VaultDepositorFlag, VaultDepositorLoader,
+    VaultDepositorWithdrawRequests,
+};
+use crate::Size;
+
+pub fn initialize_vault_depositor_withdraw_requests<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, InitializeVaultDepositorWithdrawRequests<'info>>,
+) -> Result<()> {
+    let mut vault_depositor = ctx.accounts.vault_depositor.load_mut_migrated()?;
+    vault_depositor.add_flag(VaultDepositorFlag::ParkedWithdrawRequests);
+
+    let mut withdraw_requests = ctx.accounts.vault_depositor_withdraw_requests.load_init()?;
+    withdraw_requests.vault_depositor = vault_depositor.pubkey;
+    withdraw_requests.shares_base = vault_depositor.get_vault_shares_base();
+    withdraw_requests.bump = ctx.bumps.vault_depositor_withdraw_requests;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeVaultDepositorWithdrawRequests<'info> {
+    #[account(
+        mut,
+        constraint = is_authority_for_vault_depositor(&vault_depositor, &authority)?
+    )]
+    pub vault_depositor: AccountLoader<'info, VaultDepositor>,
+    #[account(
+        init,
+        seeds = [b"vault_depositor_withdraw_requests", vault_depositor.key().as_ref()],
+        space = VaultDepositorWithdrawRequests::SIZE,
+        bump,
+        payer = payer
+    )]
+    pub vault_depositor_withdraw_requests: AccountLoader<'info, VaultDepositorWithdrawRequests>,
+    pub authority: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub rent: Sysvar<'info, Rent>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+
+use crate::constraints::is_manager_for_vault;
+use crate::state::{Vault, VaultDustWithdraw};
+use crate::Size;
+
+pub fn initialize_vault_dust_withdraw<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, InitializeVaultDustWithdraw<'info>>,
+    threshold: u64,
+    window_max_amount: u64,
+) -> Result<()> {
+    let mut vault_dust_withdraw = ctx.accounts.vault_dust_withdraw.load_init()?;
+    vault_dust_withdraw.vault = ctx.accounts.vault.key();
+    vault_dust_withdraw.threshold = threshold;
+    vault_dust_withdraw.window_max_amount = window_max_amount;
+    vault_dust_withdraw.bump = ctx.bumps.vault_dust_withdraw;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeVaultDustWithdraw<'info> {
+    #[account(
+        constraint = is_manager_for_vault(&vault, &manager)?
+    )]
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        init,
+        seeds = [b"vault_dust_withdraw", vault.key().as_ref()],
+        space = VaultDustWithdraw::SIZE,
+        bump,
+        payer = payer
+    )]
+    pub vault_dust_withdraw: AccountLoader<'info, VaultDustWithdraw>,
+    pub manager: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub rent: Sysvar<'info, Rent>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+
+use crate::constraints::is_manager_for_vault;
+use crate::state::{Vault, VaultFeeHoliday};
+use crate::Size;
+
+pub fn initialize_vault_fee_holiday<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, InitializeVaultFeeHoliday<'info>>,
+) -> Result<()> {
+    let mut vault_fee_holiday = ctx.accounts.vault_fee_holiday.load_init()?;
+    vault_fee_holiday.vault = ctx.accounts.vault.key();
+    vault_fee_holiday.bump = ctx.bumps.vault_fee_holiday;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeVaultFeeHoliday<'info> {
+    #[account(
+        constraint = is_manager_for_vault(&vault, &manager)?
+    )]
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        init,
+        seeds = [b"vault_fee_holiday", vault.key().as_ref()],
+        space = VaultFeeHoliday::SIZE,
+        bump,
+        payer = payer
+    )]
+    pub vault_fee_holiday: AccountLoader<'info, VaultFeeHoliday>,
+    pub manager: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub rent: Sysvar<'info, Rent>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+
+use crate::constraints::is_manager_for_vault;
+use crate::state::{Vault, VaultFeeSplit};
+use crate::Size;
+
+/// Commits `split` of the manager's future profit share to the `recipient` token account, see [`VaultFeeSplit`].
+pub fn initialize_vault_fee_split<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, InitializeVaultFeeSplit<'info>>,
+    split: u32,
+    recipient: Pubkey,
+) -> Result<()> {
+    let vault = ctx.accounts.vault.load()?;
+
+    let mut fee_split = ctx.accounts.vault_fee_split.load_init()?;
+    fee_split.vault = vault.pubkey;
+    fee_split.bump = ctx.bumps.vault_fee_split;
+    fee_split.last_manager_total_profit_share = vault.manager_total_profit_share;
+    fee_split.update(&vault, split, recipient)?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeVaultFeeSplit<'info> {
+    #[account(
+        constraint = is_manager_for_vault(&vault, &manager)?,
+    )]
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        init,
+        seeds = [b"vault_fee_split", vault.key().as_ref()],
+        space = VaultFeeSplit::SIZE,
+        bump,
+        payer = payer
+    )]
+    pub vault_fee_split: AccountLoader<'info, VaultFeeSplit>,
+    pub manager: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub rent: Sysvar<'info, Rent>,
+    pub system_program: Program<'info, System>,
+}
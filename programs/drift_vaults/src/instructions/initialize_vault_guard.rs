@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+
+use crate::constraints::is_manager_for_vault;
+use crate::state::{Vault, VaultConfig, VaultConfigFlag, VaultGuard};
+use crate::Size;
+
+/// Creates the vault's [`VaultGuard`] and sets [`VaultConfigFlag::VaultGuard`] on its [`VaultConfig`], from then on
+/// deposits and withdraws must pass it in remaining accounts.
+pub fn initialize_vault_guard<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, InitializeVaultGuard<'info>>,
+    max_oracle_twap_deviation_bps: u16,
+) -> Result<()> {
+    let mut vault_guard = ctx.accounts.vault_guard.load_init()?;
+    vault_guard.update_max_oracle_twap_deviation(max_oracle_twap_deviation_bps)?;
+    vault_guard.vault = ctx.accounts.vault.key();
+    vault_guard.bump = ctx.bumps.vault_guard;
+
+    let mut vault_config = ctx.accounts.vault_config.load_mut()?;
+    vault_config.add_flag(VaultConfigFlag::VaultGuard);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeVaultGuard<'info> {
+    #[account(
+        constraint = is_manager_for_vault(&vault, &manager)?
+    )]
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        mut,
+        seeds = [b"vault_config", vault.key().as_ref()],
+        bump = vault_config.load()?.bump,
+    )]
+    pub vault_config: AccountLoader<'info, VaultConfig>,
+    #[account(
+        init,
+        seeds = [b"vault_guard", vault.key().as_ref()],
+        space = VaultGuard::SIZE,
+        bump,
+        payer = payer
+    )]
+    pub vault_guard: AccountLoader<'info, VaultGuard>,
+    pub manager: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub rent: Sysvar<'info, Rent>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+
+use crate::constraints::is_manager_for_vault;
+use crate::state::{Vault, VaultFeature, VaultHook};
+use crate::Size;
+
+pub fn initialize_vault_hook<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, InitializeVaultHook<'info>>,
+    hook_program: Pubkey,
+) -> Result<()> {
+    let mut vault = ctx.accounts.vault.load_mut()?;
+    vault.validate_feature_enabled(VaultFeature::Hooks)?;
+
+    let mut vault_hook = ctx.accounts.vault_hook.load_init()?;
+    vault_hook.vault = ctx.accounts.vault.key();
+    vault_hook.bump = ctx.bumps.vault_hook;
+    vault_hook.update_hook_program(&mut vault, hook_program, ctx.remaining_accounts)?;
+
+    msg!("Initialized vault hook program {}", hook_program);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeVaultHook<'info> {
+    #[account(
+        mut,
+        constraint = is_manager_for_vault(&vault, &manager)?
+    )]
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        init,
+        seeds = [b"vault_hook", vault.key().as_ref()],
+        space = VaultHook::SIZE,
+        bump,
+        payer = payer
+    )]
+    pub vault_hook: AccountLoader<'info, VaultHook>,
+    pub manager: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub rent: Sysvar<'info, Rent>,
+    pub system_program: Program<'info, System>,
+}
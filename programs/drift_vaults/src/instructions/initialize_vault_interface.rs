@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+use crate::constraints::is_manager_for_vault;
+use crate::state::{Vault, VaultInterface};
+use crate::Size;
+
+/// Registers a front-end `interface` the manager pays `fee_rate` on attributed deposits and withdraws.
+pub fn initialize_vault_interface<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, InitializeVaultInterface<'info>>,
+    interface: Pubkey,
+    fee_rate: u32,
+) -> Result<()> {
+    let mut vault_interface = ctx.accounts.vault_interface.load_init()?;
+    vault_interface.vault = ctx.accounts.vault.key();
+    vault_interface.interface = interface;
+    vault_interface.set_fee_rate(fee_rate)?;
+    vault_interface.bump = ctx.bumps.vault_interface;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(interface: Pubkey)]
+pub struct InitializeVaultInterface<'info> {
+    #[account(
+        constraint = is_manager_for_vault(&vault, &manager)?
+    )]
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        init,
+        seeds = [b"vault_interface", vault.key().as_ref(), interface.as_ref()],
+        space = VaultInterface::SIZE,
+        bump,
+        payer = payer
+    )]
+    pub vault_interface: AccountLoader<'info, VaultInterface>,
+    pub manager: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub rent: Sysvar<'info, Rent>,
+    pub system_program: Program<'info, System>,
+}
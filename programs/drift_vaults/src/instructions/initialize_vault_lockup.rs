@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+
+use crate::constraints::is_manager_for_vault;
+use crate::state::{GuardFlag, Vault, VaultGuard, VaultLockup};
+use crate::Size;
+
+/// Creates the vault's [`VaultLockup`] and marks it on the vault's [`VaultGuard`], from then on deposits and withdraw
+/// requests must pass it and the depositor's [`VaultDepositorLockup`](crate::state::VaultDepositorLockup) address in
+/// remaining accounts.
+pub fn initialize_vault_lockup<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, InitializeVaultLockup<'info>>,
+    lockup_period: u32,
+) -> Result<()> {
+    let mut vault_lockup = ctx.accounts.vault_lockup.load_init()?;
+    vault_lockup.vault = ctx.accounts.vault.key();
+    vault_lockup.bump = ctx.bumps.vault_lockup;
+    vault_lockup.update_lockup_period(lockup_period)?;
+
+    let mut vault_guard = ctx.accounts.vault_guard.load_mut()?;
+    vault_guard.add_flag(GuardFlag::Lockup);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeVaultLockup<'info> {
+    #[account(
+        constraint = is_manager_for_vault(&vault, &manager)?
+    )]
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        mut,
+        seeds = [b"vault_guard", vault.key().as_ref()],
+        bump = vault_guard.load()?.bump,
+    )]
+    pub vault_guard: AccountLoader<'info, VaultGuard>,
+    #[account(
+        init,
+        seeds = [b"vault_lockup", vault.key().as_ref()],
+        space = VaultLockup::SIZE,
+        bump,
+        payer = payer
+    )]
+    pub vault_lockup: AccountLoader<'info, VaultLockup>,
+    pub manager: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub rent: Sysvar<'info, Rent>,
+    pub system_program: Program<'info, System>,
+}
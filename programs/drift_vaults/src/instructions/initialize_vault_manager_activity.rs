@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+
+use crate::constraints::is_manager_for_vault;
+use crate::state::{Vault, VaultManagerActivity};
+use crate::Size;
+
+/// Creates the vault's [`VaultManagerActivity`], from then on depositors can `inactive_manager_withdraw` once the
+/// manager has been inactive for `inactivity_period`.
+pub fn initialize_vault_manager_activity<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, InitializeVaultManagerActivity<'info>>,
+    inactivity_period: u32,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+
+    let mut vault_manager_activity = ctx.accounts.vault_manager_activity.load_init()?;
+    vault_manager_activity.update_inactivity_period(inactivity_period)?;
+    vault_manager_activity.vault = ctx.accounts.vault.key();
+    vault_manager_activity.bump = ctx.bumps.vault_manager_activity;
+    vault_manager_activity.record_activity(now);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeVaultManagerActivity<'info> {
+    #[account(
+        constraint = is_manager_for_vault(&vault, &manager)?
+    )]
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        init,
+        seeds = [b"vault_manager_activity", vault.key().as_ref()],
+        space = VaultManagerActivity::SIZE,
+        bump,
+        payer = payer
+    )]
+    pub vault_manager_activity: AccountLoader<'info, VaultManagerActivity>,
+    pub manager: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub rent: Sysvar<'info, Rent>,
+    pub system_program: Program<'info, System>,
+}
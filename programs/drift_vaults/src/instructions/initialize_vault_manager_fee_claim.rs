@@ -0,0 +1,90 @@
+use anchor_lang::prelude::*;
+use drift::instructions::optional_accounts::AccountMaps;
+use drift::state::user::User;
+
+use crate::constraints::{is_manager_for_vault, is_user_for_vault};
+use crate::state::{
+    record_vault_param_change, Vault, VaultConfigProvider, VaultManagerFeeClaim, VaultParam,
+    VaultParamChangeLogProvider,
+};
+use crate::{AccountMapProvider, Size, VaultProtocolProvider};
+
+/// Moves the vault's management fee into a [`VaultManagerFeeClaim`], minting at most `claim_cap` of the vault's
+/// shares per claim period from then on. Fees accrued so far are applied first.
+pub fn initialize_vault_manager_fee_claim<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, InitializeVaultManagerFeeClaim<'info>>,
+    claim_cap: u32,
+) -> Result<()> {
+    let clock = &Clock::get()?;
+
+    let mut vault = ctx.accounts.vault.load_mut()?;
+    let param_change_log = ctx.vault_param_change_log();
+    vault.validate_param_change_log(&param_change_log)?;
+
+    let vault_config = ctx.vault_config(&vault)?;
+
+    // backwards compatible: if last rem acct does not deserialize into [`VaultProtocol`] then it's a legacy vault.
+    let mut vp = ctx.vault_protocol();
+    vault.validate_vault_protocol(&vp)?;
+    let mut vp = vp.as_mut().map(|vp| vp.load_mut()).transpose()?;
+
+    let user = ctx.accounts.drift_user.load()?;
+    let spot_market_index = vault.spot_market_index;
+
+    let AccountMaps {
+        perp_market_map,
+        spot_market_map,
+        mut oracle_map,
+    } = ctx.load_maps(clock.slot, Some(spot_market_index), vp.is_some(), false)?;
+
+    let vault_equity =
+        vault.calculate_equity(&user, &perp_market_map, &spot_market_map, &mut oracle_map)?;
+
+    vault.apply_fee(&vault_config, &mut vp, vault_equity, clock.unix_timestamp)?;
+
+    record_vault_param_change(
+        &vault,
+        &param_change_log,
+        ctx.accounts.manager.key(),
+        VaultParam::ManagementFee,
+        vault.management_fee,
+        0_i64,
+        clock.unix_timestamp,
+    )?;
+
+    let mut vault_manager_fee_claim = ctx.accounts.vault_manager_fee_claim.load_init()?;
+    vault_manager_fee_claim.initialize(
+        &mut vault,
+        claim_cap,
+        ctx.bumps.vault_manager_fee_claim,
+        clock.unix_timestamp,
+    )?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeVaultManagerFeeClaim<'info> {
+    #[account(
+        mut,
+        constraint = is_manager_for_vault(&vault, &manager)?
+    )]
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        init,
+        seeds = [b"vault_manager_fee_claim", vault.key().as_ref()],
+        space = VaultManagerFeeClaim::SIZE,
+        bump,
+        payer = payer
+    )]
+    pub vault_manager_fee_claim: AccountLoader<'info, VaultManagerFeeClaim>,
+    #[account(
+        constraint = is_user_for_vault(&vault, &drift_user.key())?
+    )]
+    pub drift_user: AccountLoader<'info, User>,
+    pub manager: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub rent: Sysvar<'info, Rent>,
+    pub system_program: Program<'info, System>,
+}
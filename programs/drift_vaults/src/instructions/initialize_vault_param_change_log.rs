@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+use crate::constraints::is_manager_for_vault;
+use crate::state::{Vault, VaultFlag, VaultParamChangeLog};
+use crate::Size;
+
+/// Creates the vault's [`VaultParamChangeLog`], from then on every parameter update must pass it in remaining
+/// accounts.
+pub fn initialize_vault_param_change_log<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, InitializeVaultParamChangeLog<'info>>,
+) -> Result<()> {
+    let mut log = ctx.accounts.vault_param_change_log.load_init()?;
+    log.vault = ctx.accounts.vault.key();
+    log.bump = ctx.bumps.vault_param_change_log;
+
+    let mut vault = ctx.accounts.vault.load_mut()?;
+    vault.add_flag(VaultFlag::HasParamChangeLog);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeVaultParamChangeLog<'info> {
+    #[account(
+        mut,
+        constraint = is_manager_for_vault(&vault, &manager)?
+    )]
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        init,
+        seeds = [b"vault_param_change_log", vault.key().as_ref()],
+        space = VaultParamChangeLog::SIZE,
+        bump,
+        payer = payer
+    )]
+    pub vault_param_change_log: AccountLoader<'info, VaultParamChangeLog>,
+    pub manager: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub rent: Sysvar<'info, Rent>,
+    pub system_program: Program<'info, System>,
+}
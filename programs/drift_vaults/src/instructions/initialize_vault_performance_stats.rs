@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{Vault, VaultPerformanceStats};
+use crate::Size;
+
+pub fn initialize_vault_performance_stats<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, InitializeVaultPerformanceStats<'info>>,
+) -> Result<()> {
+    let mut stats = ctx.accounts.vault_performance_stats.load_init()?;
+    stats.vault = ctx.accounts.vault.key();
+    stats.bump = ctx.bumps.vault_performance_stats;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeVaultPerformanceStats<'info> {
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        init,
+        seeds = [b"vault_performance_stats", vault.key().as_ref()],
+        space = VaultPerformanceStats::SIZE,
+        bump,
+        payer = payer
+    )]
+    pub vault_performance_stats: AccountLoader<'info, VaultPerformanceStats>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub rent: Sysvar<'info, Rent>,
+    pub system_program: Program<'info, System>,
+}
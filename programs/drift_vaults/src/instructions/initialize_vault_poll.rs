@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{Vault, VaultPoll};
+use crate::Size;
+
+pub fn initialize_vault_poll<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, InitializeVaultPoll<'info>>,
+) -> Result<()> {
+    let mut vault_poll = ctx.accounts.vault_poll.load_init()?;
+    vault_poll.vault = ctx.accounts.vault.key();
+    vault_poll.bump = ctx.bumps.vault_poll;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeVaultPoll<'info> {
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        init,
+        seeds = [b"vault_poll", vault.key().as_ref()],
+        space = VaultPoll::SIZE,
+        bump,
+        payer = payer
+    )]
+    pub vault_poll: AccountLoader<'info, VaultPoll>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub rent: Sysvar<'info, Rent>,
+    pub system_program: Program<'info, System>,
+}
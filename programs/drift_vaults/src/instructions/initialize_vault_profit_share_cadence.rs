@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+
+use crate::constraints::is_manager_for_vault;
+use crate::state::{GuardFlag, Vault, VaultGuard, VaultProfitShareCadence};
+use crate::Size;
+
+/// Creates the vault's [`VaultProfitShareCadence`] and marks it on the vault's [`VaultGuard`], from then on
+/// `apply_profit_share` cranks and `realize_depositor_profits` must pass it and the depositor's
+/// [`VaultDepositorProfitShareCadence`](crate::state::VaultDepositorProfitShareCadence) in remaining accounts.
+pub fn initialize_vault_profit_share_cadence<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, InitializeVaultProfitShareCadence<'info>>,
+    min_profit_share_interval: u32,
+) -> Result<()> {
+    let mut vault_profit_share_cadence = ctx.accounts.vault_profit_share_cadence.load_init()?;
+    vault_profit_share_cadence.vault = ctx.accounts.vault.key();
+    vault_profit_share_cadence.bump = ctx.bumps.vault_profit_share_cadence;
+    vault_profit_share_cadence.update_min_profit_share_interval(min_profit_share_interval)?;
+
+    let mut vault_guard = ctx.accounts.vault_guard.load_mut()?;
+    vault_guard.add_flag(GuardFlag::ProfitShareCadence);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeVaultProfitShareCadence<'info> {
+    #[account(
+        constraint = is_manager_for_vault(&vault, &manager)?
+    )]
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        mut,
+        seeds = [b"vault_guard", vault.key().as_ref()],
+        bump = vault_guard.load()?.bump,
+    )]
+    pub vault_guard: AccountLoader<'info, VaultGuard>,
+    #[account(
+        init,
+        seeds = [b"vault_profit_share_cadence", vault.key().as_ref()],
+        space = VaultProfitShareCadence::SIZE,
+        bump,
+        payer = payer
+    )]
+    pub vault_profit_share_cadence: AccountLoader<'info, VaultProfitShareCadence>,
+    pub manager: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub rent: Sysvar<'info, Rent>,
+    pub system_program: Program<'info, System>,
+}
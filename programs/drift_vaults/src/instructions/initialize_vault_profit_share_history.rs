@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+use crate::constraints::is_manager_for_vault;
+use crate::state::{Vault, VaultProfitShareHistory};
+use crate::Size;
+
+/// Creates the vault's [`VaultProfitShareHistory`], only profit share realized after it can be refunded by
+/// [`apply_fee_reduction_refund`](crate::instructions::apply_fee_reduction_refund).
+pub fn initialize_vault_profit_share_history<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, InitializeVaultProfitShareHistory<'info>>,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let vault = ctx.accounts.vault.load()?;
+
+    let mut history = ctx.accounts.vault_profit_share_history.load_init()?;
+    history.vault = vault.pubkey;
+    history.reset(&vault, now);
+    history.bump = ctx.bumps.vault_profit_share_history;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeVaultProfitShareHistory<'info> {
+    #[account(
+        constraint = is_manager_for_vault(&vault, &manager)?
+    )]
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        init,
+        seeds = [b"vault_profit_share_history", vault.key().as_ref()],
+        space = VaultProfitShareHistory::SIZE,
+        bump,
+        payer = payer
+    )]
+    pub vault_profit_share_history: AccountLoader<'info, VaultProfitShareHistory>,
+    pub manager: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub rent: Sysvar<'info, Rent>,
+    pub system_program: Program<'info, System>,
+}
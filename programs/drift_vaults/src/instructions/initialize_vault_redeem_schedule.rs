@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+
+use crate::constraints::is_manager_for_vault;
+use crate::state::{RedeemPeriodBucket, Vault, VaultRedeemSchedule, VAULT_REDEEM_SCHEDULE_BUCKETS};
+use crate::Size;
+
+/// Lets smaller withdraw requests mature faster than the vault's redeem period, see [`VaultRedeemSchedule`].
+pub fn initialize_vault_redeem_schedule<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, InitializeVaultRedeemSchedule<'info>>,
+    buckets: [RedeemPeriodBucket; VAULT_REDEEM_SCHEDULE_BUCKETS],
+) -> Result<()> {
+    let mut redeem_schedule = ctx.accounts.vault_redeem_schedule.load_init()?;
+    redeem_schedule.vault = ctx.accounts.vault.key();
+    redeem_schedule.bump = ctx.bumps.vault_redeem_schedule;
+    redeem_schedule.update_buckets(buckets)?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeVaultRedeemSchedule<'info> {
+    #[account(
+        constraint = is_manager_for_vault(&vault, &manager)?,
+    )]
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        init,
+        seeds = [b"vault_redeem_schedule", vault.key().as_ref()],
+        space = VaultRedeemSchedule::SIZE,
+        bump,
+        payer = payer
+    )]
+    pub vault_redeem_schedule: AccountLoader<'info, VaultRedeemSchedule>,
+    pub manager: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub rent: Sysvar<'info, Rent>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,29 @@
+use anchor_lang::prelude::*;
+
+use crate::state::VaultRegistry;
+use crate::Size;
+
+pub fn initialize_vault_registry<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, InitializeVaultRegistry<'info>>,
+) -> Result<()> {
+    let mut vault_registry = ctx.accounts.vault_registry.load_init()?;
+    vault_registry.bump = ctx.bumps.vault_registry;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeVaultRegistry<'info> {
+    #[account(
+        init,
+        seeds = [b"vault_registry"],
+        space = VaultRegistry::SIZE,
+        bump,
+        payer = payer
+    )]
+    pub vault_registry: AccountLoader<'info, VaultRegistry>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub rent: Sysvar<'info, Rent>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+
+use crate::error::ErrorCode;
+use crate::state::{VaultRegistry, VaultRegistryPage};
+use crate::{validate, Size};
+
+/// Creates the page the [`VaultRegistry`] appends to next, once the previous page is full.
+pub fn initialize_vault_registry_page<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, InitializeVaultRegistryPage<'info>>,
+    page_index: u32,
+) -> Result<()> {
+    let vault_registry = ctx.accounts.vault_registry.load()?;
+    validate!(
+        page_index == vault_registry.current_page_index()?,
+        ErrorCode::InvalidVaultRegistry,
+        "next vault registry page is {}",
+        vault_registry.current_page_index()?
+    )?;
+
+    let mut page = ctx.accounts.vault_registry_page.load_init()?;
+    page.page_index = page_index;
+    page.bump = ctx.bumps.vault_registry_page;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(page_index: u32)]
+pub struct InitializeVaultRegistryPage<'info> {
+    #[account(
+        seeds = [b"vault_registry"],
+        bump = vault_registry.load()?.bump,
+    )]
+    pub vault_registry: AccountLoader<'info, VaultRegistry>,
+    #[account(
+        init,
+        seeds = [b"vault_registry_page", page_index.to_le_bytes().as_ref()],
+        space = VaultRegistryPage::SIZE,
+        bump,
+        payer = payer
+    )]
+    pub vault_registry_page: AccountLoader<'info, VaultRegistryPage>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub rent: Sysvar<'info, Rent>,
+    pub system_program: Program<'info, System>,
+}
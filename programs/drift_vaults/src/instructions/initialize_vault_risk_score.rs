@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{Vault, VaultRiskScore};
+use crate::Size;
+
+pub fn initialize_vault_risk_score<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, InitializeVaultRiskScore<'info>>,
+) -> Result<()> {
+    let mut risk_score = ctx.accounts.vault_risk_score.load_init()?;
+    risk_score.vault = ctx.accounts.vault.key();
+    risk_score.bump = ctx.bumps.vault_risk_score;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeVaultRiskScore<'info> {
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        init,
+        seeds = [b"vault_risk_score", vault.key().as_ref()],
+        space = VaultRiskScore::SIZE,
+        bump,
+        payer = payer
+    )]
+    pub vault_risk_score: AccountLoader<'info, VaultRiskScore>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub rent: Sysvar<'info, Rent>,
+    pub system_program: Program<'info, System>,
+}
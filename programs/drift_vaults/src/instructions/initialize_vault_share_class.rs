@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+
+use crate::constraints::is_manager_for_vault;
+use crate::state::{Vault, VaultShareClass};
+use crate::Size;
+
+/// Opens the vault's locked [`VaultShareClass`], shares converted into it are locked for `lock_period` seconds and
+/// pay `profit_share_discount` less of the manager's profit share.
+pub fn initialize_vault_share_class<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, InitializeVaultShareClass<'info>>,
+    lock_period: i64,
+    profit_share_discount: u32,
+) -> Result<()> {
+    let vault = ctx.accounts.vault.load()?;
+
+    let mut vault_share_class = ctx.accounts.vault_share_class.load_init()?;
+    vault_share_class.initialize(
+        &vault,
+        lock_period,
+        profit_share_discount,
+        ctx.bumps.vault_share_class,
+    )?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeVaultShareClass<'info> {
+    #[account(
+        constraint = is_manager_for_vault(&vault, &manager)?
+    )]
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        init,
+        seeds = [b"vault_share_class", vault.key().as_ref()],
+        space = VaultShareClass::SIZE,
+        bump,
+        payer = payer
+    )]
+    pub vault_share_class: AccountLoader<'info, VaultShareClass>,
+    pub manager: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub rent: Sysvar<'info, Rent>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+use drift::math::safe_math::SafeMath;
+
+use crate::state::{
+    validate_manager_or_auditor, Vault, VaultAuditorProvider, VaultShareSnapshot,
+    SHARE_SNAPSHOT_WINDOW,
+};
+use crate::Size;
+
+/// Opens a share snapshot for `epoch`. Signed by the manager or the vault's auditor, who then has to pass the
+/// [`VaultAuditor`](crate::state::VaultAuditor) in remaining_accounts.
+pub fn initialize_vault_share_snapshot<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, InitializeVaultShareSnapshot<'info>>,
+    epoch: u32,
+) -> Result<()> {
+    let clock = &Clock::get()?;
+    let vault = ctx.accounts.vault.load()?;
+
+    let vault_auditor = ctx.vault_auditor(&vault.pubkey);
+    let vault_auditor = vault_auditor
+        .as_ref()
+        .map(|vault_auditor| vault_auditor.load())
+        .transpose()?;
+    validate_manager_or_auditor(
+        &vault,
+        vault_auditor.as_deref(),
+        &ctx.accounts.manager.key(),
+    )?;
+
+    let mut snapshot = ctx.accounts.vault_share_snapshot.load_init()?;
+    snapshot.vault = ctx.accounts.vault.key();
+    snapshot.epoch = epoch;
+    snapshot.shares_base = vault.shares_base;
+    snapshot.start_ts = clock.unix_timestamp;
+    snapshot.end_ts = clock.unix_timestamp.safe_add(SHARE_SNAPSHOT_WINDOW)?;
+    snapshot.bump = ctx.bumps.vault_share_snapshot;
+
+    msg!(
+        "Opened share snapshot epoch {} until {}",
+        epoch,
+        snapshot.end_ts
+    );
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(epoch: u32)]
+pub struct InitializeVaultShareSnapshot<'info> {
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        init,
+        seeds = [b"vault_share_snapshot", vault.key().as_ref(), epoch.to_le_bytes().as_ref()],
+        space = VaultShareSnapshot::SIZE,
+        bump,
+        payer = payer
+    )]
+    pub vault_share_snapshot: AccountLoader<'info, VaultShareSnapshot>,
+    /// The manager or the vault's auditor
+    pub manager: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub rent: Sysvar<'info, Rent>,
+    pub system_program: Program<'info, System>,
+}
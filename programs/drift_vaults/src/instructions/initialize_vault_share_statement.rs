@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{VaultShareSnapshot, VaultShareStatement};
+use crate::Size;
+
+/// Permissionless, opens the [`VaultShareStatement`] for a share snapshot epoch.
+pub fn initialize_vault_share_statement<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, InitializeVaultShareStatement<'info>>,
+) -> Result<()> {
+    let snapshot = ctx.accounts.vault_share_snapshot.load()?;
+
+    let mut statement = ctx.accounts.vault_share_statement.load_init()?;
+    statement.share_snapshot = ctx.accounts.vault_share_snapshot.key();
+    statement.epoch = snapshot.epoch;
+    statement.bump = ctx.bumps.vault_share_statement;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeVaultShareStatement<'info> {
+    pub vault_share_snapshot: AccountLoader<'info, VaultShareSnapshot>,
+    #[account(
+        init,
+        seeds = [b"vault_share_statement", vault_share_snapshot.key().as_ref()],
+        space = VaultShareStatement::SIZE,
+        bump,
+        payer = payer
+    )]
+    pub vault_share_statement: AccountLoader<'info, VaultShareStatement>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub rent: Sysvar<'info, Rent>,
+    pub system_program: Program<'info, System>,
+}
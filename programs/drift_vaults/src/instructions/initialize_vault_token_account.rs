@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+use drift::state::spot_market::SpotMarket;
+
+use crate::Vault;
+
+/// Recreates the vault token account for the vault's current spot market mint after
+/// [`migrate_vault_deposit_mint`](super::migrate_vault_deposit_mint). The token account keeps its address, so
+/// `Vault::token_account` is unchanged.
+pub fn initialize_vault_token_account<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, InitializeVaultTokenAccount<'info>>,
+) -> Result<()> {
+    msg!(
+        "Initialized vault token account for mint {}",
+        ctx.accounts.drift_spot_market_mint.key()
+    );
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeVaultTokenAccount<'info> {
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        init,
+        seeds = [b"vault_token_account".as_ref(), vault.key().as_ref()],
+        bump,
+        payer = payer,
+        token::mint = drift_spot_market_mint,
+        token::authority = vault
+    )]
+    pub vault_token_account: Box<Account<'info, TokenAccount>>,
+    #[account(
+        constraint = drift_spot_market.load()?.market_index == vault.load()?.spot_market_index
+    )]
+    pub drift_spot_market: AccountLoader<'info, SpotMarket>,
+    #[account(
+        constraint = drift_spot_market.load()?.mint.eq(&drift_spot_market_mint.key())
+    )]
+    pub drift_spot_market_mint: Box<Account<'info, Mint>>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub rent: Sysvar<'info, Rent>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+}
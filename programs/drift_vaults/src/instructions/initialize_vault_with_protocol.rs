@@ -1,5 +1,5 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{Mint, Token, TokenAccount};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 use drift::cpi::accounts::{InitializeUser, InitializeUserStats};
 use drift::math::casting::Cast;
 use drift::math::constants::PERCENTAGE_PRECISION_U64;
@@ -139,7 +139,7 @@ pub struct InitializeVaultWithProtocol<'info> {
         token::mint = drift_spot_market_mint,
         token::authority = vault
     )]
-    pub token_account: Box<Account<'info, TokenAccount>>,
+    pub token_account: Box<InterfaceAccount<'info, TokenAccount>>,
     /// CHECK: checked in drift cpi
     #[account(mut)]
     pub drift_user_stats: AccountInfo<'info>,
@@ -156,14 +156,14 @@ pub struct InitializeVaultWithProtocol<'info> {
     #[account(
         constraint = drift_spot_market.load()?.mint.eq(&drift_spot_market_mint.key())
     )]
-    pub drift_spot_market_mint: Box<Account<'info, Mint>>,
+    pub drift_spot_market_mint: Box<InterfaceAccount<'info, Mint>>,
     pub manager: Signer<'info>,
     #[account(mut)]
     pub payer: Signer<'info>,
     pub rent: Sysvar<'info, Rent>,
     pub system_program: Program<'info, System>,
     pub drift_program: Program<'info, Drift>,
-    pub token_program: Program<'info, Token>,
+    pub token_program: Interface<'info, TokenInterface>,
 }
 
 impl<'info> InitializeUserCPI for Context<'_, '_, '_, 'info, InitializeVaultWithProtocol<'info>> {
@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+
+use crate::constraints::is_manager_for_vault;
+use crate::state::{GuardFlag, Vault, VaultGuard, VaultWithdrawBlackout};
+use crate::Size;
+
+/// Creates the vault's [`VaultWithdrawBlackout`] and marks it on the vault's [`VaultGuard`], from then on withdraws
+/// must pass it in remaining accounts.
+pub fn initialize_vault_withdraw_blackout<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, InitializeVaultWithdrawBlackout<'info>>,
+) -> Result<()> {
+    let mut vault_withdraw_blackout = ctx.accounts.vault_withdraw_blackout.load_init()?;
+    vault_withdraw_blackout.vault = ctx.accounts.vault.key();
+    vault_withdraw_blackout.bump = ctx.bumps.vault_withdraw_blackout;
+
+    let mut vault_guard = ctx.accounts.vault_guard.load_mut()?;
+    vault_guard.add_flag(GuardFlag::WithdrawBlackout);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeVaultWithdrawBlackout<'info> {
+    #[account(
+        constraint = is_manager_for_vault(&vault, &manager)?
+    )]
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        mut,
+        seeds = [b"vault_guard", vault.key().as_ref()],
+        bump = vault_guard.load()?.bump,
+    )]
+    pub vault_guard: AccountLoader<'info, VaultGuard>,
+    #[account(
+        init,
+        seeds = [b"vault_withdraw_blackout", vault.key().as_ref()],
+        space = VaultWithdrawBlackout::SIZE,
+        bump,
+        payer = payer
+    )]
+    pub vault_withdraw_blackout: AccountLoader<'info, VaultWithdrawBlackout>,
+    pub manager: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub rent: Sysvar<'info, Rent>,
+    pub system_program: Program<'info, System>,
+}
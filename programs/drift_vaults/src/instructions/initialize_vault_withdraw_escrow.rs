@@ -0,0 +1,83 @@
+use anchor_lang::prelude::*;
+use drift::cpi::accounts::InitializeUser;
+use drift::program::Drift;
+
+use crate::constraints::{is_manager_for_vault, is_user_stats_for_vault};
+use crate::drift_cpi::InitializeEscrowUserCPI;
+use crate::state::{Vault, VaultWithdrawEscrow, WITHDRAW_ESCROW_SUB_ACCOUNT_ID};
+use crate::{declare_vault_seeds, Size};
+
+/// Creates the vault's lend-only escrow drift user so depositors can
+/// [escrow](crate::instructions::escrow_withdraw_request) pending withdraw requests.
+pub fn initialize_vault_withdraw_escrow<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, InitializeVaultWithdrawEscrow<'info>>,
+) -> Result<()> {
+    let mut vault_withdraw_escrow = ctx.accounts.vault_withdraw_escrow.load_init()?;
+    vault_withdraw_escrow.vault = ctx.accounts.vault.key();
+    vault_withdraw_escrow.escrow_user = ctx.accounts.escrow_drift_user.key();
+    vault_withdraw_escrow.bump = ctx.bumps.vault_withdraw_escrow;
+    drop(vault_withdraw_escrow);
+
+    ctx.drift_initialize_escrow_user()?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeVaultWithdrawEscrow<'info> {
+    #[account(
+        constraint = is_manager_for_vault(&vault, &manager)?
+    )]
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        init,
+        seeds = [b"vault_withdraw_escrow", vault.key().as_ref()],
+        space = VaultWithdrawEscrow::SIZE,
+        bump,
+        payer = payer
+    )]
+    pub vault_withdraw_escrow: AccountLoader<'info, VaultWithdrawEscrow>,
+    #[account(
+        mut,
+        constraint = is_user_stats_for_vault(&vault, &drift_user_stats.key())?
+    )]
+    /// CHECK: checked in drift cpi
+    pub drift_user_stats: AccountInfo<'info>,
+    /// CHECK: checked in drift cpi
+    #[account(mut)]
+    pub escrow_drift_user: AccountInfo<'info>,
+    /// CHECK: checked in drift cpi
+    #[account(mut)]
+    pub drift_state: AccountInfo<'info>,
+    pub manager: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub rent: Sysvar<'info, Rent>,
+    pub system_program: Program<'info, System>,
+    pub drift_program: Program<'info, Drift>,
+}
+
+impl<'info> InitializeEscrowUserCPI
+    for Context<'_, '_, '_, 'info, InitializeVaultWithdrawEscrow<'info>>
+{
+    fn drift_initialize_escrow_user(&self) -> Result<()> {
+        declare_vault_seeds!(self.accounts.vault, seeds);
+
+        let name = self.accounts.vault.load()?.name;
+
+        let cpi_program = self.accounts.drift_program.to_account_info().clone();
+        let cpi_accounts = InitializeUser {
+            user_stats: self.accounts.drift_user_stats.clone(),
+            user: self.accounts.escrow_drift_user.clone(),
+            state: self.accounts.drift_state.clone(),
+            authority: self.accounts.vault.to_account_info().clone(),
+            payer: self.accounts.payer.to_account_info().clone(),
+            rent: self.accounts.rent.to_account_info().clone(),
+            system_program: self.accounts.system_program.to_account_info().clone(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, seeds);
+        drift::cpi::initialize_user(cpi_ctx, WITHDRAW_ESCROW_SUB_ACCOUNT_ID, name)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+
+use crate::constraints::is_manager_for_vault;
+use crate::state::{Vault, VaultWithdrawPriority};
+use crate::Size;
+
+/// Creates the vault's [`VaultWithdrawPriority`] so depositors can
+/// [boost](crate::instructions::boost_withdraw_request) queued withdraw requests.
+pub fn initialize_vault_withdraw_priority<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, InitializeVaultWithdrawPriority<'info>>,
+    full_boost_fee: u32,
+) -> Result<()> {
+    let mut vault_withdraw_priority = ctx.accounts.vault_withdraw_priority.load_init()?;
+    vault_withdraw_priority.update_full_boost_fee(full_boost_fee)?;
+    vault_withdraw_priority.vault = ctx.accounts.vault.key();
+    vault_withdraw_priority.bump = ctx.bumps.vault_withdraw_priority;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeVaultWithdrawPriority<'info> {
+    #[account(
+        constraint = is_manager_for_vault(&vault, &manager)?
+    )]
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        init,
+        seeds = [b"vault_withdraw_priority", vault.key().as_ref()],
+        space = VaultWithdrawPriority::SIZE,
+        bump,
+        payer = payer
+    )]
+    pub vault_withdraw_priority: AccountLoader<'info, VaultWithdrawPriority>,
+    pub manager: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub rent: Sysvar<'info, Rent>,
+    pub system_program: Program<'info, System>,
+}
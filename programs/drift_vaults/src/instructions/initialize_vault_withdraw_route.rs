@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+
+use crate::constraints::is_manager_for_vault;
+use crate::state::{Vault, VaultWithdrawRoute};
+use crate::Size;
+
+pub fn initialize_vault_withdraw_route<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, InitializeVaultWithdrawRoute<'info>>,
+    market_indexes: Vec<u16>,
+) -> Result<()> {
+    let vault = ctx.accounts.vault.load()?;
+
+    let mut withdraw_route = ctx.accounts.vault_withdraw_route.load_init()?;
+    withdraw_route.vault = ctx.accounts.vault.key();
+    withdraw_route.bump = ctx.bumps.vault_withdraw_route;
+    withdraw_route.update_market_indexes(vault.spot_market_index, &market_indexes)?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeVaultWithdrawRoute<'info> {
+    #[account(
+        constraint = is_manager_for_vault(&vault, &manager)?
+    )]
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        init,
+        seeds = [b"vault_withdraw_route", vault.key().as_ref()],
+        space = VaultWithdrawRoute::SIZE,
+        bump,
+        payer = payer
+    )]
+    pub vault_withdraw_route: AccountLoader<'info, VaultWithdrawRoute>,
+    pub manager: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub rent: Sysvar<'info, Rent>,
+    pub system_program: Program<'info, System>,
+}
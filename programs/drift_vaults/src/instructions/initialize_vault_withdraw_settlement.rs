@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+use crate::constraints::is_manager_for_vault;
+use crate::state::{Vault, VaultWithdrawSettlement};
+use crate::Size;
+
+/// Switches the vault to settled withdraws: matured requests are paid at the share price of the last
+/// [`settle_vault_withdraws`](crate::instructions::settle_vault_withdraws) crank instead of the current share price.
+pub fn initialize_vault_withdraw_settlement<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, InitializeVaultWithdrawSettlement<'info>>,
+) -> Result<()> {
+    let mut settlement = ctx.accounts.vault_withdraw_settlement.load_init()?;
+    settlement.vault = ctx.accounts.vault.key();
+    settlement.bump = ctx.bumps.vault_withdraw_settlement;
+
+    let mut vault = ctx.accounts.vault.load_mut()?;
+    vault.withdraw_settlement = true;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeVaultWithdrawSettlement<'info> {
+    #[account(
+        mut,
+        constraint = is_manager_for_vault(&vault, &manager)?
+    )]
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        init,
+        seeds = [b"vault_withdraw_settlement", vault.key().as_ref()],
+        space = VaultWithdrawSettlement::SIZE,
+        bump,
+        payer = payer
+    )]
+    pub vault_withdraw_settlement: AccountLoader<'info, VaultWithdrawSettlement>,
+    pub manager: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub rent: Sysvar<'info, Rent>,
+    pub system_program: Program<'info, System>,
+}
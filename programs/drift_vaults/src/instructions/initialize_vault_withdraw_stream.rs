@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+
+use crate::constraints::{is_authority_for_vault_depositor, is_vault_for_vault_depositor};
+use crate::state::{Vault, VaultDepositor, VaultWithdrawStream};
+use crate::Size;
+
+pub fn initialize_vault_withdraw_stream<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, InitializeVaultWithdrawStream<'info>>,
+) -> Result<()> {
+    let mut vault_withdraw_stream = ctx.accounts.vault_withdraw_stream.load_init()?;
+    vault_withdraw_stream.vault_depositor = ctx.accounts.vault_depositor.key();
+    vault_withdraw_stream.bump = ctx.bumps.vault_withdraw_stream;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeVaultWithdrawStream<'info> {
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        constraint = is_vault_for_vault_depositor(&vault_depositor, &vault)?,
+        constraint = is_authority_for_vault_depositor(&vault_depositor, &authority)?,
+    )]
+    pub vault_depositor: AccountLoader<'info, VaultDepositor>,
+    #[account(
+        init,
+        seeds = [b"vault_withdraw_stream", vault_depositor.key().as_ref()],
+        space = VaultWithdrawStream::SIZE,
+        bump,
+        payer = payer
+    )]
+    pub vault_withdraw_stream: AccountLoader<'info, VaultWithdrawStream>,
+    pub authority: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub rent: Sysvar<'info, Rent>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+
+use crate::constraints::{is_authority_for_vault_depositor, is_vault_for_vault_depositor};
+use crate::state::{Vault, VaultDepositor, VaultFlag, WithdrawReceipt};
+use crate::Size;
+
+pub fn initialize_withdraw_receipt<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, InitializeWithdrawReceipt<'info>>,
+) -> Result<()> {
+    let mut receipt = ctx.accounts.withdraw_receipt.load_init()?;
+    receipt.vault_depositor = ctx.accounts.vault_depositor.key();
+    receipt.bump = ctx.bumps.withdraw_receipt;
+
+    ctx.accounts
+        .vault
+        .load_mut()?
+        .add_flag(VaultFlag::WithdrawReceipts);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeWithdrawReceipt<'info> {
+    #[account(mut)]
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        seeds = [b"vault_depositor", vault.key().as_ref(), authority.key().as_ref()],
+        bump,
+        constraint = is_vault_for_vault_depositor(&vault_depositor, &vault)?,
+        constraint = is_authority_for_vault_depositor(&vault_depositor, &authority)?,
+    )]
+    pub vault_depositor: AccountLoader<'info, VaultDepositor>,
+    #[account(
+        init,
+        seeds = [b"withdraw_receipt", vault_depositor.key().as_ref()],
+        space = WithdrawReceipt::SIZE,
+        bump,
+        payer = payer
+    )]
+    pub withdraw_receipt: AccountLoader<'info, WithdrawReceipt>,
+    pub authority: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub rent: Sysvar<'info, Rent>,
+    pub system_program: Program<'info, System>,
+}
@@ -7,7 +7,8 @@ use drift::state::user::User;
 use crate::constants::permissioned_liquidator;
 use crate::constraints::{is_user_for_vault, is_user_stats_for_vault};
 use crate::drift_cpi::{UpdateUserDelegateCPI, UpdateUserReduceOnlyCPI};
-use crate::state::{Vault, VaultDepositor};
+use crate::state::events::LiquidationAction;
+use crate::state::{Vault, VaultDepositor, VaultDepositorLoader};
 use crate::{declare_vault_seeds, implement_update_user_delegate_cpi};
 use crate::{implement_update_user_reduce_only_cpi, AccountMapProvider, VaultProtocolProvider};
 
@@ -19,7 +20,7 @@ pub fn liquidate<'c: 'info, 'info>(
 
     let mut user = ctx.accounts.drift_user.load_mut()?;
     let mut vault = ctx.accounts.vault.load_mut()?;
-    let vault_depositor = ctx.accounts.vault_depositor.load()?;
+    let vault_depositor = ctx.accounts.vault_depositor.load_checked()?;
 
     // backwards compatible: if last rem acct does not deserialize into [`VaultProtocol`] then it's a legacy vault.
     let mut vp = ctx.vault_protocol();
@@ -56,6 +57,15 @@ pub fn liquidate<'c: 'info, 'info>(
     vault.check_available_for_liquidation(now)?;
 
     vault.set_liquidation_delegate(permissioned_liquidator::id(), now);
+    vault.emit_liquidation_action_record(
+        &user,
+        &perp_market_map,
+        &spot_market_map,
+        &mut oracle_map,
+        vault_depositor.authority,
+        LiquidationAction::Start,
+        now,
+    )?;
 
     drop(user);
     drop(vault);
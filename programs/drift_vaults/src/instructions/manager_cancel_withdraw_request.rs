@@ -4,7 +4,7 @@ use drift::math::casting::Cast;
 use drift::state::user::User;
 
 use crate::constraints::{is_manager_for_vault, is_user_for_vault, is_user_stats_for_vault};
-use crate::state::{Vault, VaultProtocolProvider};
+use crate::state::{Vault, VaultConfigProvider, VaultProtocolProvider};
 use crate::AccountMapProvider;
 
 pub fn manager_cancel_withdraw_request<'c: 'info, 'info>(
@@ -13,6 +13,8 @@ pub fn manager_cancel_withdraw_request<'c: 'info, 'info>(
     let clock = &Clock::get()?;
     let vault = &mut ctx.accounts.vault.load_mut()?;
 
+    let vault_config = ctx.vault_config(vault)?;
+
     // backwards compatible: if last rem acct does not deserialize into [`VaultProtocol`] then it's a legacy vault.
     let mut vp = ctx.vault_protocol();
     vault.validate_vault_protocol(&vp)?;
@@ -33,6 +35,7 @@ pub fn manager_cancel_withdraw_request<'c: 'info, 'info>(
     let oracle = oracle_map.get_price_data(&spot_market.oracle_id())?;
 
     vault.manager_cancel_withdraw_request(
+        &vault_config,
         &mut vp,
         vault_equity.cast()?,
         clock.unix_timestamp,
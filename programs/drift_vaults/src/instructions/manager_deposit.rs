@@ -1,5 +1,5 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use anchor_spl::token_interface::{TokenAccount, TokenInterface};
 use drift::cpi::accounts::Deposit as DriftDeposit;
 use drift::instructions::optional_accounts::AccountMaps;
 use drift::program::Drift;
@@ -7,8 +7,11 @@ use drift::state::user::User;
 
 use crate::constraints::{is_manager_for_vault, is_user_for_vault, is_user_stats_for_vault};
 use crate::drift_cpi::DepositCPI;
-use crate::state::{Vault, VaultProtocolProvider};
-use crate::token_cpi::TokenTransferCPI;
+use crate::state::{
+    TokenMintProvider, Vault, VaultConfigProvider, VaultManagerActivityProvider,
+    VaultProtocolProvider,
+};
+use crate::token_cpi::{get_amount_after_transfer_fee, transfer_tokens, TokenTransferCPI};
 use crate::{declare_vault_seeds, AccountMapProvider};
 
 pub fn manager_deposit<'c: 'info, 'info>(
@@ -19,6 +22,14 @@ pub fn manager_deposit<'c: 'info, 'info>(
 
     let mut vault = ctx.accounts.vault.load_mut()?;
 
+    if let Some(vault_manager_activity) = ctx.vault_manager_activity(&vault.pubkey) {
+        vault_manager_activity
+            .load_mut()?
+            .record_activity(clock.unix_timestamp);
+    }
+
+    let vault_config = ctx.vault_config(&vault)?;
+
     // backwards compatible: if last rem acct does not deserialize into [`VaultProtocol`] then it's a legacy vault.
     let mut vp = ctx.vault_protocol();
     vault.validate_vault_protocol(&vp)?;
@@ -39,9 +50,14 @@ pub fn manager_deposit<'c: 'info, 'info>(
     let spot_market = spot_market_map.get_ref(&spot_market_index)?;
     let oracle = oracle_map.get_price_data(&spot_market.oracle_id())?;
 
+    let mint = ctx.token_mint(&ctx.accounts.vault_token_account.mint)?;
+    let received_amount = get_amount_after_transfer_fee(mint.as_ref(), amount)?;
+    let deposited_amount = get_amount_after_transfer_fee(mint.as_ref(), received_amount)?;
+
     vault.manager_deposit(
+        &vault_config,
         &mut vp,
-        amount,
+        deposited_amount,
         vault_equity,
         clock.unix_timestamp,
         oracle.price,
@@ -54,7 +70,7 @@ pub fn manager_deposit<'c: 'info, 'info>(
 
     ctx.token_transfer(amount)?;
 
-    ctx.drift_deposit(amount)?;
+    ctx.drift_deposit(received_amount)?;
 
     Ok(())
 }
@@ -72,7 +88,7 @@ pub struct ManagerDeposit<'info> {
         seeds = [b"vault_token_account".as_ref(), vault.key().as_ref()],
         bump,
     )]
-    pub vault_token_account: Box<Account<'info, TokenAccount>>,
+    pub vault_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
     #[account(
         mut,
         constraint = is_user_stats_for_vault(&vault, &drift_user_stats.key())?
@@ -91,30 +107,29 @@ pub struct ManagerDeposit<'info> {
         mut,
         token::mint = vault_token_account.mint
     )]
-    pub drift_spot_market_vault: Box<Account<'info, TokenAccount>>,
+    pub drift_spot_market_vault: Box<InterfaceAccount<'info, TokenAccount>>,
     #[account(
         mut,
         token::authority = manager,
         token::mint = vault_token_account.mint
     )]
-    pub user_token_account: Box<Account<'info, TokenAccount>>,
+    pub user_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
     pub drift_program: Program<'info, Drift>,
-    pub token_program: Program<'info, Token>,
+    pub token_program: Interface<'info, TokenInterface>,
 }
 
-impl<'info> TokenTransferCPI for Context<'_, '_, '_, 'info, ManagerDeposit<'info>> {
+impl<'c: 'info, 'info> TokenTransferCPI for Context<'_, '_, 'c, 'info, ManagerDeposit<'info>> {
     fn token_transfer(&self, amount: u64) -> Result<()> {
-        let cpi_accounts = Transfer {
-            from: self.accounts.user_token_account.to_account_info().clone(),
-            to: self.accounts.vault_token_account.to_account_info().clone(),
-            authority: self.accounts.manager.to_account_info().clone(),
-        };
-        let token_program = self.accounts.token_program.to_account_info().clone();
-        let cpi_context = CpiContext::new(token_program, cpi_accounts);
-
-        token::transfer(cpi_context, amount)?;
-
-        Ok(())
+        transfer_tokens(
+            self.accounts.token_program.to_account_info(),
+            self.accounts.user_token_account.to_account_info(),
+            self.accounts.vault_token_account.to_account_info(),
+            self.accounts.manager.to_account_info(),
+            self.token_mint(&self.accounts.vault_token_account.mint)?
+                .as_ref(),
+            amount,
+            &[],
+        )
     }
 }
 
@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+
+use crate::constraints::{is_delegate_for_vault, is_manager_for_vault};
+use crate::state::{Vault, VaultManagerActivity};
+
+/// Records manager activity without doing anything else, for a manager or their bot (signing as the vault delegate)
+/// to keep depositors' `inactive_manager_withdraw` closed while nothing needs changing.
+pub fn manager_heartbeat<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, ManagerHeartbeat<'info>>,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    ctx.accounts
+        .vault_manager_activity
+        .load_mut()?
+        .record_activity(now);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ManagerHeartbeat<'info> {
+    #[account(
+        constraint = is_manager_for_vault(&vault, &manager)? || is_delegate_for_vault(&vault, &manager)?
+    )]
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        mut,
+        seeds = [b"vault_manager_activity", vault.key().as_ref()],
+        bump = vault_manager_activity.load()?.bump,
+    )]
+    pub vault_manager_activity: AccountLoader<'info, VaultManagerActivity>,
+    pub manager: Signer<'info>,
+}
@@ -3,7 +3,7 @@ use drift::instructions::optional_accounts::AccountMaps;
 use drift::state::user::User;
 
 use crate::constraints::{is_manager_for_vault, is_user_for_vault, is_user_stats_for_vault};
-use crate::state::VaultProtocolProvider;
+use crate::state::{VaultConfigProvider, VaultManagerActivityProvider, VaultProtocolProvider};
 use crate::AccountMapProvider;
 use crate::{Vault, WithdrawUnit};
 
@@ -16,6 +16,12 @@ pub fn manager_request_withdraw<'c: 'info, 'info>(
     let mut vault = ctx.accounts.vault.load_mut()?;
     let now = clock.unix_timestamp;
 
+    if let Some(vault_manager_activity) = ctx.vault_manager_activity(&vault.pubkey) {
+        vault_manager_activity.load_mut()?.record_activity(now);
+    }
+
+    let vault_config = ctx.vault_config(&vault)?;
+
     // backwards compatible: if last rem acct does not deserialize into [`VaultProtocol`] then it's a legacy vault.
     let mut vp = ctx.vault_protocol();
     vault.validate_vault_protocol(&vp)?;
@@ -37,6 +43,7 @@ pub fn manager_request_withdraw<'c: 'info, 'info>(
     let oracle = oracle_map.get_price_data(&spot_market.oracle_id())?;
 
     vault.manager_request_withdraw(
+        &vault_config,
         &mut vp,
         withdraw_amount,
         withdraw_unit,
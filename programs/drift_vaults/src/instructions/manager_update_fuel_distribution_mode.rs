@@ -1,6 +1,7 @@
 use anchor_lang::prelude::*;
 
 use crate::constraints::is_manager_for_vault;
+use crate::state::{record_vault_param_change, VaultParam, VaultParamChangeLogProvider};
 use crate::Vault;
 
 pub fn manager_update_fuel_distribution_mode<'c: 'info, 'info>(
@@ -8,7 +9,19 @@ pub fn manager_update_fuel_distribution_mode<'c: 'info, 'info>(
     fuel_distribution_mode: u8,
 ) -> Result<()> {
     let mut vault = ctx.accounts.vault.load_mut()?;
+    let now = Clock::get()?.unix_timestamp;
+    let param_change_log = ctx.vault_param_change_log();
+    vault.validate_param_change_log(&param_change_log)?;
 
+    record_vault_param_change(
+        &vault,
+        &param_change_log,
+        ctx.accounts.manager.key(),
+        VaultParam::FuelDistributionMode,
+        vault.fuel_distribution_mode,
+        fuel_distribution_mode,
+        now,
+    )?;
     vault.update_fuel_distribution_mode(fuel_distribution_mode);
 
     Ok(())
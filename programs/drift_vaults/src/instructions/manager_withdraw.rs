@@ -1,6 +1,5 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Transfer};
-use anchor_spl::token::{Token, TokenAccount};
+use anchor_spl::token_interface::{TokenAccount, TokenInterface};
 use drift::cpi::accounts::Withdraw as DriftWithdraw;
 use drift::instructions::optional_accounts::AccountMaps;
 use drift::program::Drift;
@@ -8,8 +7,11 @@ use drift::state::user::{FuelOverflowStatus, User, UserStats};
 
 use crate::constraints::{is_manager_for_vault, is_user_for_vault, is_user_stats_for_vault};
 use crate::drift_cpi::WithdrawCPI;
-use crate::state::{FuelOverflowProvider, Vault, VaultProtocolProvider};
-use crate::token_cpi::TokenTransferCPI;
+use crate::state::{
+    FuelOverflowProvider, TokenMintProvider, Vault, VaultConfigProvider,
+    VaultManagerActivityProvider, VaultProtocolProvider,
+};
+use crate::token_cpi::{get_amount_after_transfer_fee, transfer_tokens, TokenTransferCPI};
 use crate::{declare_vault_seeds, AccountMapProvider};
 
 pub fn manager_withdraw<'c: 'info, 'info>(
@@ -19,9 +21,15 @@ pub fn manager_withdraw<'c: 'info, 'info>(
     let mut vault = ctx.accounts.vault.load_mut()?;
     let now = clock.unix_timestamp;
 
+    if let Some(vault_manager_activity) = ctx.vault_manager_activity(&vault.pubkey) {
+        vault_manager_activity.load_mut()?.record_activity(now);
+    }
+
     let user = ctx.accounts.drift_user.load()?;
     let spot_market_index = vault.spot_market_index;
 
+    let vault_config = ctx.vault_config(&vault)?;
+
     // backwards compatible: if last rem acct does not deserialize into [`VaultProtocol`] then it's a legacy vault.
     let mut vp = ctx.vault_protocol();
     vault.validate_vault_protocol(&vp)?;
@@ -50,7 +58,7 @@ pub fn manager_withdraw<'c: 'info, 'info>(
     let oracle = oracle_map.get_price_data(&spot_market.oracle_id())?;
 
     let manager_withdraw_amount =
-        vault.manager_withdraw(&mut vp, vault_equity, now, oracle.price)?;
+        vault.manager_withdraw(&vault_config, &mut vp, vault_equity, now, oracle.price)?;
 
     drop(spot_market);
     drop(vault);
@@ -60,7 +68,19 @@ pub fn manager_withdraw<'c: 'info, 'info>(
 
     ctx.drift_withdraw(manager_withdraw_amount)?;
 
-    ctx.token_transfer(manager_withdraw_amount)?;
+    let mint = ctx.token_mint(&ctx.accounts.vault_token_account.mint)?;
+    let received_amount = get_amount_after_transfer_fee(mint.as_ref(), manager_withdraw_amount)?;
+    ctx.token_transfer(received_amount)?;
+
+    ctx.accounts.vault.load()?.emit_health_record(
+        &*ctx.accounts.drift_user.load()?,
+        &perp_market_map,
+        &spot_market_map,
+        &mut oracle_map,
+        manager_withdraw_amount,
+        ctx.last_manager_activity_ts(&ctx.accounts.vault.key())?,
+        clock.unix_timestamp,
+    )?;
 
     Ok(())
 }
@@ -78,7 +98,7 @@ pub struct ManagerWithdraw<'info> {
       seeds = [b"vault_token_account".as_ref(), vault.key().as_ref()],
       bump,
     )]
-    pub vault_token_account: Box<Account<'info, TokenAccount>>,
+    pub vault_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
     #[account(
         mut,
         constraint = is_user_stats_for_vault(&vault, &drift_user_stats.key())?
@@ -97,7 +117,7 @@ pub struct ManagerWithdraw<'info> {
         mut,
         token::mint = vault_token_account.mint
     )]
-    pub drift_spot_market_vault: Box<Account<'info, TokenAccount>>,
+    pub drift_spot_market_vault: Box<InterfaceAccount<'info, TokenAccount>>,
     /// CHECK: checked in drift cpi
     pub drift_signer: AccountInfo<'info>,
     #[account(
@@ -105,9 +125,9 @@ pub struct ManagerWithdraw<'info> {
         token::authority = manager,
         token::mint = vault_token_account.mint
     )]
-    pub user_token_account: Box<Account<'info, TokenAccount>>,
+    pub user_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
     pub drift_program: Program<'info, Drift>,
-    pub token_program: Program<'info, Token>,
+    pub token_program: Interface<'info, TokenInterface>,
 }
 
 impl<'info> WithdrawCPI for Context<'_, '_, '_, 'info, ManagerWithdraw<'info>> {
@@ -139,20 +159,19 @@ impl<'info> WithdrawCPI for Context<'_, '_, '_, 'info, ManagerWithdraw<'info>> {
     }
 }
 
-impl<'info> TokenTransferCPI for Context<'_, '_, '_, 'info, ManagerWithdraw<'info>> {
+impl<'c: 'info, 'info> TokenTransferCPI for Context<'_, '_, 'c, 'info, ManagerWithdraw<'info>> {
     fn token_transfer(&self, amount: u64) -> Result<()> {
         declare_vault_seeds!(self.accounts.vault, seeds);
 
-        let cpi_accounts = Transfer {
-            from: self.accounts.vault_token_account.to_account_info().clone(),
-            to: self.accounts.user_token_account.to_account_info().clone(),
-            authority: self.accounts.vault.to_account_info().clone(),
-        };
-        let token_program = self.accounts.token_program.to_account_info().clone();
-        let cpi_context = CpiContext::new_with_signer(token_program, cpi_accounts, seeds);
-
-        token::transfer(cpi_context, amount)?;
-
-        Ok(())
+        transfer_tokens(
+            self.accounts.token_program.to_account_info(),
+            self.accounts.vault_token_account.to_account_info(),
+            self.accounts.user_token_account.to_account_info(),
+            self.accounts.vault.to_account_info(),
+            self.token_mint(&self.accounts.vault_token_account.mint)?
+                .as_ref(),
+            amount,
+            seeds,
+        )
     }
 }
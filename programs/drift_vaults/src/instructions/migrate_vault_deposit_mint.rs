@@ -0,0 +1,115 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, CloseAccount, Token, TokenAccount};
+use drift::ids::admin_hot_wallet;
+use drift::state::spot_market::SpotMarket;
+use drift::state::state::State;
+use drift::state::user::User;
+
+use crate::constraints::{is_manager_for_vault, is_user_for_vault};
+use crate::error::ErrorCode;
+use crate::token_cpi::CloseTokenAccountCPI;
+use crate::{declare_vault_seeds, validate, Vault, VaultProtocolProvider};
+
+/// Moves the vault's deposit spot market to a successor market with the same decimals (e.g. USDC to USDC2).
+///
+/// The delegate first converts the drift user's balance with drift's swap, this closes the empty vault token
+/// account and switches `spot_market_index`, then
+/// [`initialize_vault_token_account`](super::initialize_vault_token_account) recreates the token account for the
+/// successor mint. Shares, deposits and withdraw requests are kept as is, they're denominated 1:1 in the successor,
+/// so drift's admin has to sign off on the pair alongside the manager.
+///
+/// Vaults with a [`VaultProtocol`](crate::state::VaultProtocol) also need the protocol's signature, the
+/// [`VaultProtocol`](crate::state::VaultProtocol) is the last remaining account and the protocol signer any other.
+pub fn migrate_vault_deposit_mint<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, MigrateVaultDepositMint<'info>>,
+) -> Result<()> {
+    let mut vault = ctx.accounts.vault.load_mut()?;
+
+    let vp = ctx.vault_protocol();
+    vault.validate_vault_protocol(&vp)?;
+    if let Some(vp) = vp {
+        let protocol = vp.load()?.protocol;
+        validate!(
+            ctx.remaining_accounts
+                .iter()
+                .any(|acct| acct.key() == protocol && acct.is_signer),
+            ErrorCode::InvalidDepositMintMigration,
+            "protocol {} must sign the migration",
+            protocol
+        )?;
+    }
+
+    let successor_spot_market = ctx.accounts.successor_drift_spot_market.load()?;
+    vault.validate_deposit_mint_migration(
+        &*ctx.accounts.drift_spot_market.load()?,
+        &successor_spot_market,
+        &*ctx.accounts.drift_user.load()?,
+    )?;
+    validate!(
+        ctx.accounts.vault_token_account.amount == 0,
+        ErrorCode::InvalidDepositMintMigration,
+        "vault token account not empty"
+    )?;
+
+    msg!(
+        "Migrating vault deposit spot market {} -> {}",
+        vault.spot_market_index,
+        successor_spot_market.market_index
+    );
+    vault.spot_market_index = successor_spot_market.market_index;
+
+    drop(vault);
+
+    ctx.close_token_account()?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct MigrateVaultDepositMint<'info> {
+    #[account(
+        mut,
+        constraint = is_manager_for_vault(&vault, &manager)?,
+    )]
+    pub vault: AccountLoader<'info, Vault>,
+    pub manager: Signer<'info>,
+    #[account(
+        constraint = admin.key() == drift_state.admin || admin.key() == admin_hot_wallet::id()
+    )]
+    pub admin: Signer<'info>,
+    pub drift_state: Box<Account<'info, State>>,
+    #[account(
+        mut,
+        seeds = [b"vault_token_account".as_ref(), vault.key().as_ref()],
+        bump,
+    )]
+    pub vault_token_account: Box<Account<'info, TokenAccount>>,
+    #[account(
+        constraint = is_user_for_vault(&vault, &drift_user.key())?
+    )]
+    pub drift_user: AccountLoader<'info, User>,
+    pub drift_spot_market: AccountLoader<'info, SpotMarket>,
+    pub successor_drift_spot_market: AccountLoader<'info, SpotMarket>,
+    /// CHECK: receives the closed token account's rent
+    #[account(mut)]
+    pub payer: AccountInfo<'info>,
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> CloseTokenAccountCPI for Context<'_, '_, '_, 'info, MigrateVaultDepositMint<'info>> {
+    fn close_token_account(&self) -> Result<()> {
+        declare_vault_seeds!(self.accounts.vault, seeds);
+
+        let cpi_accounts = CloseAccount {
+            account: self.accounts.vault_token_account.to_account_info().clone(),
+            destination: self.accounts.payer.to_account_info().clone(),
+            authority: self.accounts.vault.to_account_info().clone(),
+        };
+        let token_program = self.accounts.token_program.to_account_info().clone();
+        let cpi_context = CpiContext::new_with_signer(token_program, cpi_accounts, seeds);
+
+        token::close_account(cpi_context)?;
+
+        Ok(())
+    }
+}
@@ -1,78 +1,392 @@
+pub use accept_vault_depositor_authority_transfer::*;
 pub use add_insurance_fund_stake::*;
+pub use add_vault_to_protocol_tvl_cap::*;
+pub use append_vault_share_statement::*;
+pub use apply_fee_reduction_refund::*;
+pub use apply_loss_carryforward::*;
 pub use apply_profit_share::*;
+pub use apply_profit_share_batch::*;
 pub use apply_rebase::*;
 pub use apply_rebase_tokenized_depositor::*;
+pub use approve_vault_delegate::*;
+pub use backfill_vault_depositor_counts::*;
+pub use boost_withdraw_request::*;
 pub use cancel_request_remove_insurance_fund_stake::*;
+pub use cancel_vault_depositor_authority_transfer::*;
 pub use cancel_withdraw_request::*;
+pub use check_delegate_self_trade::*;
+pub use check_vault_state_hash::*;
+pub use checkpoint_vault_profit_share::*;
+pub use claim_vault_backstop::*;
+pub use claim_vault_interface_fees::*;
+pub use claim_vault_manager_fee::*;
+pub use claim_withdraw_stream::*;
+pub use collect_protocol_fees::*;
+pub use commit_vault_report::*;
+pub use contribute_vault_backstop::*;
+pub use convert_share_class::*;
+pub use crank_vault_fee_holiday::*;
+pub use crystallize_vault_profit_share::*;
 pub use deposit::*;
+pub use deposit_from_drift_user::*;
+pub use deposit_with_idempotency_key::*;
+pub use deposit_with_interface::*;
+pub use deposit_with_swap::*;
+pub use depositor_action::*;
+pub use distribute_vault_fee_split::*;
+pub use enforce_vault_profile::*;
+pub use escrow_withdraw_request::*;
+pub use execute_queued_withdraw::*;
+pub use execute_vault_poll::*;
 pub use force_withdraw::*;
+pub use inactive_manager_withdraw::*;
 pub use initialize_insurance_fund_stake::*;
+pub use initialize_omnibus_ledger::*;
+pub use initialize_omnibus_ledger_page::*;
+pub use initialize_protocol_loss_ledger::*;
+pub use initialize_protocol_treasury::*;
+pub use initialize_protocol_tvl_cap::*;
 pub use initialize_tokenized_vault_depositor::*;
 pub use initialize_vault::*;
+pub use initialize_vault_auditor::*;
+pub use initialize_vault_backstop::*;
+pub use initialize_vault_config::*;
+pub use initialize_vault_crystallization::*;
+pub use initialize_vault_delegate_proposal::*;
 pub use initialize_vault_depositor::*;
+pub use initialize_vault_depositor_cap::*;
+pub use initialize_vault_depositor_idempotency::*;
+pub use initialize_vault_depositor_lockup::*;
+pub use initialize_vault_depositor_memo::*;
+pub use initialize_vault_depositor_profit_share_cadence::*;
+pub use initialize_vault_depositor_record_log::*;
+pub use initialize_vault_depositor_share_class::*;
+pub use initialize_vault_depositor_throttle::*;
+pub use initialize_vault_depositor_throttle_state::*;
+pub use initialize_vault_depositor_withdraw_escrow::*;
+pub use initialize_vault_depositor_withdraw_requests::*;
+pub use initialize_vault_dust_withdraw::*;
+pub use initialize_vault_fee_holiday::*;
+pub use initialize_vault_fee_split::*;
+pub use initialize_vault_from_template::*;
+pub use initialize_vault_guard::*;
+pub use initialize_vault_hook::*;
+pub use initialize_vault_interface::*;
+pub use initialize_vault_lockup::*;
+pub use initialize_vault_manager_activity::*;
+pub use initialize_vault_manager_fee_claim::*;
+pub use initialize_vault_param_change_log::*;
+pub use initialize_vault_performance_stats::*;
+pub use initialize_vault_poll::*;
+pub use initialize_vault_profit_share_cadence::*;
+pub use initialize_vault_profit_share_history::*;
+pub use initialize_vault_redeem_schedule::*;
+pub use initialize_vault_registry::*;
+pub use initialize_vault_registry_page::*;
+pub use initialize_vault_risk_score::*;
+pub use initialize_vault_share_class::*;
+pub use initialize_vault_share_snapshot::*;
+pub use initialize_vault_share_statement::*;
+pub use initialize_vault_token_account::*;
 pub use initialize_vault_with_protocol::*;
+pub use initialize_vault_withdraw_blackout::*;
+pub use initialize_vault_withdraw_escrow::*;
+pub use initialize_vault_withdraw_priority::*;
+pub use initialize_vault_withdraw_route::*;
+pub use initialize_vault_withdraw_settlement::*;
+pub use initialize_vault_withdraw_stream::*;
+pub use initialize_withdraw_receipt::*;
 pub use liquidate::*;
 pub use manager_cancel_withdraw_request::*;
 pub use manager_deposit::*;
+pub use manager_heartbeat::*;
 pub use manager_request_withdraw::*;
 pub use manager_update_fuel_distribution_mode::*;
 pub use manager_withdraw::*;
+pub use migrate_vault_deposit_mint::*;
+pub use park_withdraw_request::*;
+pub use propose_vault_delegate::*;
+pub use propose_vault_depositor_authority_transfer::*;
 pub use protocol_cancel_withdraw_request::*;
 pub use protocol_request_withdraw::*;
 pub use protocol_withdraw::*;
+pub use queue_withdraw::*;
+pub use realize_depositor_profits::*;
+pub use record_liquidation_action::*;
+pub use record_loss_carryforward::*;
 pub use redeem_tokens::*;
+pub use register_vault::*;
 pub use remove_insurance_fund_stake::*;
+pub use remove_vault_from_protocol_tvl_cap::*;
+pub use renew_withdraw_request::*;
 pub use request_remove_insurance_fund_stake::*;
 pub use request_withdraw::*;
+pub use request_withdraw_with_cancel_deadline::*;
+pub use request_withdraw_with_idempotency_key::*;
 pub use reset_delegate::*;
 pub use reset_fuel_season::*;
 pub use reset_vault_fuel_season::*;
+pub use revoke_vault_depositor_custodian::*;
+pub use schedule_vault_fee_holiday::*;
+pub use schedule_vault_withdraw_blackout::*;
+pub use settle_vault_withdraws::*;
+pub use simulate_withdraw::*;
+pub use snapshot_vault_depositor_shares::*;
+pub use start_vault_poll::*;
+pub use start_withdraw_stream::*;
+pub use sweep_vault_fuel::*;
 pub use tokenize_shares::*;
 pub use update_cumulative_fuel_amount::*;
+pub use update_cumulative_fuel_amount_batch::*;
 pub use update_delegate::*;
 pub use update_margin_trading_enabled::*;
+pub use update_omnibus_ledger_entry::*;
 pub use update_pool_id::*;
+pub use update_protocol_tvl_cap::*;
 pub use update_vault::*;
+pub use update_vault_auditor::*;
+pub use update_vault_backstop::*;
+pub use update_vault_backstop_max_balance::*;
+pub use update_vault_crystallization::*;
+pub use update_vault_delegate_dual_approval::*;
+pub use update_vault_deposit_valuation::*;
+pub use update_vault_depositor_auto_renew_withdraw::*;
+pub use update_vault_depositor_blacklist::*;
+pub use update_vault_depositor_cap::*;
+pub use update_vault_depositor_custodian::*;
+pub use update_vault_depositor_memo::*;
+pub use update_vault_depositor_throttle::*;
+pub use update_vault_disabled_features::*;
+pub use update_vault_dust_withdraw::*;
+pub use update_vault_fee_policy::*;
+pub use update_vault_fee_split::*;
+pub use update_vault_fuel_boost::*;
+pub use update_vault_hook::*;
+pub use update_vault_interface::*;
+pub use update_vault_large_withdraw_notice::*;
+pub use update_vault_lockup::*;
 pub use update_vault_manager::*;
+pub use update_vault_manager_activity::*;
+pub use update_vault_max_oracle_twap_deviation::*;
+pub use update_vault_min_deposit_usd::*;
+pub use update_vault_performance_stats::*;
+pub use update_vault_profile::*;
+pub use update_vault_profit_share_cadence::*;
+pub use update_vault_profit_share_mode::*;
+pub use update_vault_profit_share_realization::*;
 pub use update_vault_protocol::*;
+pub use update_vault_protocol_fee_tiers::*;
+pub use update_vault_protocol_fee_waiver::*;
+pub use update_vault_redeem_schedule::*;
+pub use update_vault_require_settle_crank::*;
+pub use update_vault_risk_score::*;
+pub use update_vault_withdraw_priority::*;
+pub use update_vault_withdraw_route::*;
+pub use view_depositor_portfolio::*;
+pub use view_vault_fee_ledger::*;
+pub use vote_on_vault_poll::*;
 pub use withdraw::*;
+pub use withdraw_and_close::*;
+pub use withdraw_dust::*;
+pub use withdraw_from_escrow::*;
+pub use withdraw_parked_request::*;
+pub use withdraw_routed::*;
+pub use withdraw_to_drift_user::*;
+pub use withdraw_with_interface::*;
 
+mod accept_vault_depositor_authority_transfer;
 mod add_insurance_fund_stake;
+mod add_vault_to_protocol_tvl_cap;
+mod append_vault_share_statement;
+mod apply_fee_reduction_refund;
+mod apply_loss_carryforward;
 mod apply_profit_share;
+mod apply_profit_share_batch;
 mod apply_rebase;
 mod apply_rebase_tokenized_depositor;
+mod approve_vault_delegate;
+mod backfill_vault_depositor_counts;
+mod boost_withdraw_request;
 mod cancel_request_remove_insurance_fund_stake;
+mod cancel_vault_depositor_authority_transfer;
 mod cancel_withdraw_request;
+mod check_delegate_self_trade;
+mod check_vault_state_hash;
+mod checkpoint_vault_profit_share;
+mod claim_vault_backstop;
+mod claim_vault_interface_fees;
+mod claim_vault_manager_fee;
+mod claim_withdraw_stream;
+mod collect_protocol_fees;
+mod commit_vault_report;
 pub mod constraints;
+mod contribute_vault_backstop;
+mod convert_share_class;
+mod crank_vault_fee_holiday;
+mod crystallize_vault_profit_share;
 mod deposit;
+mod deposit_from_drift_user;
+mod deposit_with_idempotency_key;
+mod deposit_with_interface;
+mod deposit_with_swap;
+mod depositor_action;
+mod distribute_vault_fee_split;
+mod enforce_vault_profile;
+mod escrow_withdraw_request;
+mod execute_queued_withdraw;
+mod execute_vault_poll;
 mod force_withdraw;
+mod inactive_manager_withdraw;
 mod initialize_insurance_fund_stake;
+mod initialize_omnibus_ledger;
+mod initialize_omnibus_ledger_page;
+mod initialize_protocol_loss_ledger;
+mod initialize_protocol_treasury;
+mod initialize_protocol_tvl_cap;
 mod initialize_tokenized_vault_depositor;
 mod initialize_vault;
+mod initialize_vault_auditor;
+mod initialize_vault_backstop;
+mod initialize_vault_config;
+mod initialize_vault_crystallization;
+mod initialize_vault_delegate_proposal;
 mod initialize_vault_depositor;
+mod initialize_vault_depositor_cap;
+mod initialize_vault_depositor_idempotency;
+mod initialize_vault_depositor_lockup;
+mod initialize_vault_depositor_memo;
+mod initialize_vault_depositor_profit_share_cadence;
+mod initialize_vault_depositor_record_log;
+mod initialize_vault_depositor_share_class;
+mod initialize_vault_depositor_throttle;
+mod initialize_vault_depositor_throttle_state;
+mod initialize_vault_depositor_withdraw_escrow;
+mod initialize_vault_depositor_withdraw_requests;
+mod initialize_vault_dust_withdraw;
+mod initialize_vault_fee_holiday;
+mod initialize_vault_fee_split;
+mod initialize_vault_from_template;
+mod initialize_vault_guard;
+mod initialize_vault_hook;
+mod initialize_vault_interface;
+mod initialize_vault_lockup;
+mod initialize_vault_manager_activity;
+mod initialize_vault_manager_fee_claim;
+mod initialize_vault_param_change_log;
+mod initialize_vault_performance_stats;
+mod initialize_vault_poll;
+mod initialize_vault_profit_share_cadence;
+mod initialize_vault_profit_share_history;
+mod initialize_vault_redeem_schedule;
+mod initialize_vault_registry;
+mod initialize_vault_registry_page;
+mod initialize_vault_risk_score;
+mod initialize_vault_share_class;
+mod initialize_vault_share_snapshot;
+mod initialize_vault_share_statement;
+mod initialize_vault_token_account;
 mod initialize_vault_with_protocol;
+mod initialize_vault_withdraw_blackout;
+mod initialize_vault_withdraw_escrow;
+mod initialize_vault_withdraw_priority;
+mod initialize_vault_withdraw_route;
+mod initialize_vault_withdraw_settlement;
+mod initialize_vault_withdraw_stream;
+mod initialize_withdraw_receipt;
 mod liquidate;
 mod manager_cancel_withdraw_request;
 mod manager_deposit;
+mod manager_heartbeat;
 mod manager_request_withdraw;
 mod manager_update_fuel_distribution_mode;
 mod manager_withdraw;
+mod migrate_vault_deposit_mint;
+mod park_withdraw_request;
+mod propose_vault_delegate;
+mod propose_vault_depositor_authority_transfer;
 mod protocol_cancel_withdraw_request;
 mod protocol_request_withdraw;
 mod protocol_withdraw;
+mod queue_withdraw;
+mod realize_depositor_profits;
+mod record_liquidation_action;
+mod record_loss_carryforward;
 mod redeem_tokens;
+mod register_vault;
 mod remove_insurance_fund_stake;
+mod remove_vault_from_protocol_tvl_cap;
+mod renew_withdraw_request;
 mod request_remove_insurance_fund_stake;
 mod request_withdraw;
+mod request_withdraw_with_cancel_deadline;
+mod request_withdraw_with_idempotency_key;
 mod reset_delegate;
 mod reset_fuel_season;
 mod reset_vault_fuel_season;
+mod revoke_vault_depositor_custodian;
+mod schedule_vault_fee_holiday;
+mod schedule_vault_withdraw_blackout;
+mod settle_vault_withdraws;
+mod simulate_withdraw;
+mod snapshot_vault_depositor_shares;
+mod start_vault_poll;
+mod start_withdraw_stream;
+mod sweep_vault_fuel;
 mod tokenize_shares;
 mod update_cumulative_fuel_amount;
+mod update_cumulative_fuel_amount_batch;
 mod update_delegate;
 mod update_margin_trading_enabled;
+mod update_omnibus_ledger_entry;
 mod update_pool_id;
+mod update_protocol_tvl_cap;
 mod update_vault;
+mod update_vault_auditor;
+mod update_vault_backstop;
+mod update_vault_backstop_max_balance;
+mod update_vault_crystallization;
+mod update_vault_delegate_dual_approval;
+mod update_vault_deposit_valuation;
+mod update_vault_depositor_auto_renew_withdraw;
+mod update_vault_depositor_blacklist;
+mod update_vault_depositor_cap;
+mod update_vault_depositor_custodian;
+mod update_vault_depositor_memo;
+mod update_vault_depositor_throttle;
+mod update_vault_disabled_features;
+mod update_vault_dust_withdraw;
+mod update_vault_fee_policy;
+mod update_vault_fee_split;
+mod update_vault_fuel_boost;
+mod update_vault_hook;
+mod update_vault_interface;
+mod update_vault_large_withdraw_notice;
+mod update_vault_lockup;
 mod update_vault_manager;
+mod update_vault_manager_activity;
+mod update_vault_max_oracle_twap_deviation;
+mod update_vault_min_deposit_usd;
+mod update_vault_performance_stats;
+mod update_vault_profile;
+mod update_vault_profit_share_cadence;
+mod update_vault_profit_share_mode;
+mod update_vault_profit_share_realization;
 pub mod update_vault_protocol;
+mod update_vault_protocol_fee_tiers;
+mod update_vault_protocol_fee_waiver;
+mod update_vault_redeem_schedule;
+mod update_vault_require_settle_crank;
+mod update_vault_risk_score;
+mod update_vault_withdraw_priority;
+mod update_vault_withdraw_route;
+mod view_depositor_portfolio;
+mod view_vault_fee_ledger;
+mod vote_on_vault_poll;
 mod withdraw;
+mod withdraw_and_close;
+mod withdraw_dust;
+mod withdraw_from_escrow;
+mod withdraw_parked_request;
+mod withdraw_routed;
+mod withdraw_to_drift_user;
+mod withdraw_with_interface;
@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+
+use crate::constraints::{
+    is_authority_or_custodian_for_vault_depositor, is_vault_for_vault_depositor,
+};
+use crate::state::{Vault, VaultDepositor, VaultDepositorLoader, VaultDepositorWithdrawRequests};
+
+/// Parks the depositor's pending withdraw request in its [`VaultDepositorWithdrawRequests`] so another can be
+/// requested. Its redeem period keeps running, it's withdrawn or cancelled by index with
+/// [`withdraw_parked_request`](crate::instructions::withdraw_parked_request) and
+/// [`cancel_parked_withdraw_request`](crate::instructions::cancel_parked_withdraw_request).
+pub fn park_withdraw_request<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, ParkWithdrawRequest<'info>>,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+
+    let mut vault_depositor = ctx.accounts.vault_depositor.load_mut_migrated()?;
+    ctx.accounts
+        .vault_depositor_withdraw_requests
+        .load_mut()?
+        .park(&mut vault_depositor, now)?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ParkWithdrawRequest<'info> {
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        mut,
+        constraint = is_vault_for_vault_depositor(&vault_depositor, &vault)?,
+        constraint = is_authority_or_custodian_for_vault_depositor(&vault_depositor, &authority)?
+    )]
+    pub vault_depositor: AccountLoader<'info, VaultDepositor>,
+    #[account(
+        mut,
+        seeds = [b"vault_depositor_withdraw_requests", vault_depositor.key().as_ref()],
+        bump = vault_depositor_withdraw_requests.load()?.bump,
+    )]
+    pub vault_depositor_withdraw_requests: AccountLoader<'info, VaultDepositorWithdrawRequests>,
+    /// the depositor authority or its custodian
+    pub authority: Signer<'info>,
+}
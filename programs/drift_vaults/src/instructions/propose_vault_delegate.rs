@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+
+use crate::constraints::is_manager_for_vault;
+use crate::state::{Vault, VaultDelegateProposal};
+
+/// Proposes `delegate` for the protocol to approve with
+/// [`approve_vault_delegate`](super::approve_vault_delegate), replacing any pending proposal.
+pub fn propose_vault_delegate<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, ProposeVaultDelegate<'info>>,
+    delegate: Pubkey,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+
+    msg!("Proposing vault delegate {}", delegate);
+    ctx.accounts
+        .vault_delegate_proposal
+        .load_mut()?
+        .propose(delegate, now);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ProposeVaultDelegate<'info> {
+    #[account(
+        constraint = is_manager_for_vault(&vault, &manager)?,
+    )]
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        mut,
+        seeds = [b"vault_delegate_proposal", vault.key().as_ref()],
+        bump = vault_delegate_proposal.load()?.bump,
+    )]
+    pub vault_delegate_proposal: AccountLoader<'info, VaultDelegateProposal>,
+    pub manager: Signer<'info>,
+}
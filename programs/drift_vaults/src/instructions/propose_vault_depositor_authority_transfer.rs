@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+
+use crate::constraints::{is_authority_for_vault_depositor, is_vault_for_vault_depositor};
+use crate::error::ErrorCode;
+use crate::state::{Vault, VaultDepositor, VaultDepositorAuthorityTransfer, VaultFeature};
+use crate::{validate, Size};
+
+/// Proposes moving the vault depositor to `new_authority`, who completes it with
+/// [`accept_vault_depositor_authority_transfer`](crate::instructions::accept_vault_depositor_authority_transfer).
+/// A proposal to the wrong key can be withdrawn with
+/// [`cancel_vault_depositor_authority_transfer`](crate::instructions::cancel_vault_depositor_authority_transfer).
+pub fn propose_vault_depositor_authority_transfer<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, ProposeVaultDepositorAuthorityTransfer<'info>>,
+    new_authority: Pubkey,
+) -> Result<()> {
+    ctx.accounts
+        .vault
+        .load()?
+        .validate_feature_enabled(VaultFeature::Transfers)?;
+
+    validate!(
+        new_authority != Pubkey::default() && new_authority != ctx.accounts.authority.key(),
+        ErrorCode::InvalidVaultDepositorAuthorityTransfer,
+        "new authority must be a different key"
+    )?;
+
+    let mut authority_transfer = ctx
+        .accounts
+        .vault_depositor_authority_transfer
+        .load_init()?;
+    authority_transfer.vault_depositor = ctx.accounts.vault_depositor.key();
+    authority_transfer.new_authority = new_authority;
+    authority_transfer.proposed_ts = Clock::get()?.unix_timestamp;
+    authority_transfer.bump = ctx.bumps.vault_depositor_authority_transfer;
+
+    msg!("Proposing vault depositor authority {}", new_authority);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ProposeVaultDepositorAuthorityTransfer<'info> {
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        seeds = [b"vault_depositor", vault.key().as_ref(), authority.key().as_ref()],
+        bump,
+        constraint = is_vault_for_vault_depositor(&vault_depositor, &vault)?,
+        constraint = is_authority_for_vault_depositor(&vault_depositor, &authority)?,
+    )]
+    pub vault_depositor: AccountLoader<'info, VaultDepositor>,
+    #[account(
+        init,
+        seeds = [b"vault_depositor_authority_transfer", vault_depositor.key().as_ref()],
+        space = VaultDepositorAuthorityTransfer::SIZE,
+        bump,
+        payer = payer
+    )]
+    pub vault_depositor_authority_transfer: AccountLoader<'info, VaultDepositorAuthorityTransfer>,
+    pub authority: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub rent: Sysvar<'info, Rent>,
+    pub system_program: Program<'info, System>,
+}
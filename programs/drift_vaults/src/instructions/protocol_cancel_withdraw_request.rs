@@ -6,7 +6,7 @@ use drift::state::user::User;
 use crate::constraints::{
     is_protocol_for_vault, is_user_for_vault, is_user_stats_for_vault, is_vault_protocol_for_vault,
 };
-use crate::{AccountMapProvider, Vault, VaultProtocol};
+use crate::{AccountMapProvider, Vault, VaultConfigProvider, VaultProtocol};
 
 pub fn protocol_cancel_withdraw_request<'c: 'info, 'info>(
     ctx: Context<'_, '_, 'c, 'info, ProtocolCancelWithdrawRequest<'info>>,
@@ -14,6 +14,8 @@ pub fn protocol_cancel_withdraw_request<'c: 'info, 'info>(
     let clock = &Clock::get()?;
     let vault = &mut ctx.accounts.vault.load_mut()?;
 
+    let vault_config = ctx.vault_config(vault)?;
+
     let mut vp = Some(ctx.accounts.vault_protocol.load_mut()?);
 
     let user = ctx.accounts.drift_user.load()?;
@@ -31,6 +33,7 @@ pub fn protocol_cancel_withdraw_request<'c: 'info, 'info>(
     let oracle = oracle_map.get_price_data(&spot_market.oracle_id())?;
 
     vault.protocol_cancel_withdraw_request(
+        &vault_config,
         &mut vp,
         vault_equity.cast()?,
         clock.unix_timestamp,
@@ -5,7 +5,7 @@ use drift::state::user::User;
 use crate::constraints::{
     is_protocol_for_vault, is_user_for_vault, is_user_stats_for_vault, is_vault_protocol_for_vault,
 };
-use crate::{AccountMapProvider, Vault, VaultProtocol, WithdrawUnit};
+use crate::{AccountMapProvider, Vault, VaultConfigProvider, VaultProtocol, WithdrawUnit};
 
 pub fn protocol_request_withdraw<'c: 'info, 'info>(
     ctx: Context<'_, '_, 'c, 'info, ProtocolRequestWithdraw<'info>>,
@@ -16,6 +16,8 @@ pub fn protocol_request_withdraw<'c: 'info, 'info>(
     let mut vault = ctx.accounts.vault.load_mut()?;
     let now = clock.unix_timestamp;
 
+    let vault_config = ctx.vault_config(&vault)?;
+
     // backwards compatible: if last rem acct does not deserialize into [`VaultProtocol`] then it's a legacy vault.
     let mut vp = Some(ctx.accounts.vault_protocol.load_mut()?);
 
@@ -35,6 +37,7 @@ pub fn protocol_request_withdraw<'c: 'info, 'info>(
     let oracle = oracle_map.get_price_data(&spot_market.oracle_id())?;
 
     vault.protocol_request_withdraw(
+        &vault_config,
         &mut vp,
         withdraw_amount,
         withdraw_unit,
@@ -1,6 +1,5 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Transfer};
-use anchor_spl::token::{Token, TokenAccount};
+use anchor_spl::token_interface::{TokenAccount, TokenInterface};
 use drift::cpi::accounts::Withdraw as DriftWithdraw;
 use drift::instructions::optional_accounts::AccountMaps;
 use drift::program::Drift;
@@ -10,8 +9,10 @@ use crate::constraints::{
     is_protocol_for_vault, is_user_for_vault, is_user_stats_for_vault, is_vault_protocol_for_vault,
 };
 use crate::drift_cpi::WithdrawCPI;
-use crate::state::{Vault, VaultProtocol};
-use crate::token_cpi::TokenTransferCPI;
+use crate::state::{
+    TokenMintProvider, Vault, VaultConfigProvider, VaultManagerActivityProvider, VaultProtocol,
+};
+use crate::token_cpi::{get_amount_after_transfer_fee, transfer_tokens, TokenTransferCPI};
 use crate::{declare_vault_seeds, AccountMapProvider};
 
 pub fn protocol_withdraw<'c: 'info, 'info>(
@@ -24,6 +25,8 @@ pub fn protocol_withdraw<'c: 'info, 'info>(
     let user = ctx.accounts.drift_user.load()?;
     let spot_market_index = vault.spot_market_index;
 
+    let vault_config = ctx.vault_config(&vault)?;
+
     let mut vp = Some(ctx.accounts.vault_protocol.load_mut()?);
 
     let AccountMaps {
@@ -39,7 +42,7 @@ pub fn protocol_withdraw<'c: 'info, 'info>(
     let oracle = oracle_map.get_price_data(&spot_market.oracle_id())?;
 
     let protocol_withdraw_amount =
-        vault.protocol_withdraw(&mut vp, vault_equity, now, oracle.price)?;
+        vault.protocol_withdraw(&vault_config, &mut vp, vault_equity, now, oracle.price)?;
 
     drop(spot_market);
     drop(vault);
@@ -48,7 +51,19 @@ pub fn protocol_withdraw<'c: 'info, 'info>(
 
     ctx.drift_withdraw(protocol_withdraw_amount)?;
 
-    ctx.token_transfer(protocol_withdraw_amount)?;
+    let mint = ctx.token_mint(&ctx.accounts.vault_token_account.mint)?;
+    let received_amount = get_amount_after_transfer_fee(mint.as_ref(), protocol_withdraw_amount)?;
+    ctx.token_transfer(received_amount)?;
+
+    ctx.accounts.vault.load()?.emit_health_record(
+        &*ctx.accounts.drift_user.load()?,
+        &perp_market_map,
+        &spot_market_map,
+        &mut oracle_map,
+        protocol_withdraw_amount,
+        ctx.last_manager_activity_ts(&ctx.accounts.vault.key())?,
+        clock.unix_timestamp,
+    )?;
 
     Ok(())
 }
@@ -71,7 +86,7 @@ pub struct ProtocolWithdraw<'info> {
         seeds = [b"vault_token_account".as_ref(), vault.key().as_ref()],
         bump,
     )]
-    pub vault_token_account: Box<Account<'info, TokenAccount>>,
+    pub vault_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
     #[account(
         mut,
         constraint = is_user_stats_for_vault(&vault, &drift_user_stats.key())?
@@ -90,7 +105,7 @@ pub struct ProtocolWithdraw<'info> {
         mut,
         token::mint = vault_token_account.mint
     )]
-    pub drift_spot_market_vault: Box<Account<'info, TokenAccount>>,
+    pub drift_spot_market_vault: Box<InterfaceAccount<'info, TokenAccount>>,
     /// CHECK: checked in drift cpi
     pub drift_signer: AccountInfo<'info>,
     #[account(
@@ -98,9 +113,9 @@ pub struct ProtocolWithdraw<'info> {
         token::authority = protocol,
         token::mint = vault_token_account.mint
     )]
-    pub user_token_account: Box<Account<'info, TokenAccount>>,
+    pub user_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
     pub drift_program: Program<'info, Drift>,
-    pub token_program: Program<'info, Token>,
+    pub token_program: Interface<'info, TokenInterface>,
 }
 
 impl<'info> WithdrawCPI for Context<'_, '_, '_, 'info, ProtocolWithdraw<'info>> {
@@ -132,20 +147,19 @@ impl<'info> WithdrawCPI for Context<'_, '_, '_, 'info, ProtocolWithdraw<'info>>
     }
 }
 
-impl<'info> TokenTransferCPI for Context<'_, '_, '_, 'info, ProtocolWithdraw<'info>> {
+impl<'c: 'info, 'info> TokenTransferCPI for Context<'_, '_, 'c, 'info, ProtocolWithdraw<'info>> {
     fn token_transfer(&self, amount: u64) -> Result<()> {
         declare_vault_seeds!(self.accounts.vault, seeds);
 
-        let cpi_accounts = Transfer {
-            from: self.accounts.vault_token_account.to_account_info().clone(),
-            to: self.accounts.user_token_account.to_account_info().clone(),
-            authority: self.accounts.vault.to_account_info().clone(),
-        };
-        let token_program = self.accounts.token_program.to_account_info().clone();
-        let cpi_context = CpiContext::new_with_signer(token_program, cpi_accounts, seeds);
-
-        token::transfer(cpi_context, amount)?;
-
-        Ok(())
+        transfer_tokens(
+            self.accounts.token_program.to_account_info(),
+            self.accounts.vault_token_account.to_account_info(),
+            self.accounts.user_token_account.to_account_info(),
+            self.accounts.vault.to_account_info(),
+            self.token_mint(&self.accounts.vault_token_account.mint)?
+                .as_ref(),
+            amount,
+            seeds,
+        )
     }
 }
@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+
+use crate::constraints::{
+    is_authority_or_custodian_for_vault_depositor, is_vault_for_vault_depositor,
+};
+use crate::state::{Vault, VaultDepositor, VaultDepositorLoader};
+
+/// Lets keepers [execute](super::execute_queued_withdraw) the depositor's pending withdraw request once it matures,
+/// for requests that can't be withdrawn while drift pauses withdraws from the vault's spot market.
+pub fn queue_withdraw<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, QueueWithdraw<'info>>,
+) -> Result<()> {
+    ctx.accounts
+        .vault_depositor
+        .load_mut_migrated()?
+        .queue_withdraw()
+}
+
+#[derive(Accounts)]
+pub struct QueueWithdraw<'info> {
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        mut,
+        constraint = is_vault_for_vault_depositor(&vault_depositor, &vault)?,
+        constraint = is_authority_or_custodian_for_vault_depositor(&vault_depositor, &authority)?
+    )]
+    pub vault_depositor: AccountLoader<'info, VaultDepositor>,
+    /// the depositor authority or its custodian
+    pub authority: Signer<'info>,
+}
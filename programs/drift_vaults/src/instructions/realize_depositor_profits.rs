@@ -0,0 +1,119 @@
+use anchor_lang::prelude::*;
+use drift::instructions::optional_accounts::AccountMaps;
+use drift::state::user::{FuelOverflowStatus, User, UserStats};
+
+use crate::constraints::{
+    is_authority_for_vault_depositor, is_user_for_vault, is_user_stats_for_vault,
+};
+use crate::state::account_maps::AccountMapProvider;
+use crate::state::events::VaultDepositorAction;
+use crate::state::{
+    record_vault_depositor_action, FuelOverflowProvider, Vault, VaultConfigProvider,
+    VaultDepositorBase, VaultDepositorLoader, VaultDepositorRecordLogProvider,
+    VaultProfitShareCadenceProvider, VaultProtocolProvider,
+};
+use crate::VaultDepositor;
+
+/// Lets the depositor authority crystallize its own profit share and high-water mark rather than waiting on a
+/// manager [`apply_profit_share`](super::apply_profit_share) crank. Rate limited to once every
+/// [`MIN_DEPOSITOR_REALIZE_PROFITS_INTERVAL`](crate::state::MIN_DEPOSITOR_REALIZE_PROFITS_INTERVAL), and to the
+/// vault's [`VaultProfitShareCadence`](crate::state::VaultProfitShareCadence) if it has one.
+pub fn realize_depositor_profits<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, RealizeDepositorProfits<'info>>,
+) -> Result<()> {
+    let clock = &Clock::get()?;
+
+    let mut vault = ctx.accounts.vault.load_mut()?;
+    let vault_config = ctx.vault_config(&vault)?;
+    vault_config.validate_unrealized_profit_share()?;
+    let mut vault_depositor = ctx.accounts.vault_depositor.load_mut_migrated()?;
+    vault_depositor.validate_realize_profits_interval(clock.unix_timestamp)?;
+
+    if let Some((vault_cadence, vault_depositor_cadence)) =
+        ctx.vault_profit_share_cadence(&vault, &vault_depositor.pubkey)?
+    {
+        vault_cadence.load()?.record_profit_share(
+            &mut *vault_depositor_cadence.load_mut()?,
+            clock.unix_timestamp,
+        )?;
+    }
+
+    // backwards compatible: if last rem acct does not deserialize into [`VaultProtocol`] then it's a legacy vault.
+    let mut vp = ctx.vault_protocol();
+    vault.validate_vault_protocol(&vp)?;
+    let mut vp = vp.as_mut().map(|vp| vp.load_mut()).transpose()?;
+
+    let user = ctx.accounts.drift_user.load()?;
+    let spot_market_index = vault.spot_market_index;
+
+    let user_stats = ctx.accounts.drift_user_stats.load()?;
+    let has_fuel_overflow = FuelOverflowStatus::exists(user_stats.fuel_overflow_status);
+    let fuel_overflow = ctx.fuel_overflow(vp.is_some(), has_fuel_overflow);
+    user_stats.validate_fuel_overflow(&fuel_overflow)?;
+
+    let AccountMaps {
+        perp_market_map,
+        spot_market_map,
+        mut oracle_map,
+    } = ctx.load_maps(
+        clock.slot,
+        Some(spot_market_index),
+        vp.is_some(),
+        has_fuel_overflow,
+    )?;
+
+    let vault_equity =
+        vault.calculate_equity(&user, &perp_market_map, &spot_market_map, &mut oracle_map)?;
+
+    let spot_market = spot_market_map.get_ref(&spot_market_index)?;
+    let oracle = oracle_map.get_price_data(&spot_market.oracle_id())?;
+
+    let vault_shares_before = vault_depositor.get_vault_shares();
+    let profit_share = vault_depositor.realize_profits(
+        vault_equity,
+        &mut vault,
+        &vault_config,
+        &mut vp,
+        clock.unix_timestamp,
+        &user_stats,
+        &fuel_overflow,
+        oracle.price,
+    )?;
+
+    record_vault_depositor_action(
+        &ctx.vault_depositor_record_log(&vault.pubkey)?,
+        &vault,
+        &vault_depositor,
+        VaultDepositorAction::FeePayment,
+        profit_share,
+        vault_shares_before,
+        vault_equity,
+        clock.unix_timestamp,
+    )?;
+
+    msg!("realized profit share: {}", profit_share);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RealizeDepositorProfits<'info> {
+    #[account(mut)]
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        mut,
+        seeds = [b"vault_depositor", vault.key().as_ref(), authority.key().as_ref()],
+        bump,
+        constraint = is_authority_for_vault_depositor(&vault_depositor, &authority)?,
+    )]
+    pub vault_depositor: AccountLoader<'info, VaultDepositor>,
+    pub authority: Signer<'info>,
+    #[account(
+        constraint = is_user_stats_for_vault(&vault, &drift_user_stats.key())?
+    )]
+    pub drift_user_stats: AccountLoader<'info, UserStats>,
+    #[account(
+        constraint = is_user_for_vault(&vault, &drift_user.key())?
+    )]
+    pub drift_user: AccountLoader<'info, User>,
+}
@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+use drift::instructions::optional_accounts::AccountMaps;
+use drift::state::user::User;
+
+use crate::constraints::{
+    is_liquidation_delegate_for_vault, is_user_for_vault, is_vault_for_vault_depositor,
+};
+use crate::state::events::LiquidationAction;
+use crate::state::{Vault, VaultDepositor, VaultDepositorLoader};
+use crate::{AccountMapProvider, VaultProtocolProvider};
+
+/// Records the vault drift user's risk for the depositor the ongoing liquidation is for. The liquidation delegate
+/// brackets its drift fills with this in the same transaction so post-mortems can check it only reduced risk.
+pub fn record_liquidation_action<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, RecordLiquidationAction<'info>>,
+) -> Result<()> {
+    let clock = &Clock::get()?;
+    let vault = ctx.accounts.vault.load()?;
+    let vault_depositor = ctx.accounts.vault_depositor.load_checked()?;
+
+    vault.validate_liquidation_depositor(&vault_depositor.last_withdraw_request)?;
+
+    // backwards compatible: if last rem acct does not deserialize into [`VaultProtocol`] then it's a legacy vault.
+    let vp = ctx.vault_protocol();
+    vault.validate_vault_protocol(&vp)?;
+
+    let user = ctx.accounts.drift_user.load()?;
+
+    let AccountMaps {
+        perp_market_map,
+        spot_market_map,
+        mut oracle_map,
+    } = ctx.load_maps(clock.slot, None, vp.is_some(), false)?;
+
+    vault.emit_liquidation_action_record(
+        &user,
+        &perp_market_map,
+        &spot_market_map,
+        &mut oracle_map,
+        vault_depositor.authority,
+        LiquidationAction::Unwind,
+        clock.unix_timestamp,
+    )?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RecordLiquidationAction<'info> {
+    #[account(
+        constraint = is_liquidation_delegate_for_vault(&vault, &liquidation_delegate)?
+    )]
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        constraint = is_vault_for_vault_depositor(&vault_depositor, &vault)?
+    )]
+    pub vault_depositor: AccountLoader<'info, VaultDepositor>,
+    pub liquidation_delegate: Signer<'info>,
+    #[account(
+        constraint = is_user_for_vault(&vault, &drift_user.key())?
+    )]
+    pub drift_user: AccountLoader<'info, User>,
+}
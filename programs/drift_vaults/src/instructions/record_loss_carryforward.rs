@@ -0,0 +1,87 @@
+use anchor_lang::prelude::*;
+use drift::instructions::optional_accounts::AccountMaps;
+use drift::math::insurance::if_shares_to_vault_amount as depositor_shares_to_vault_amount;
+use drift::state::user::User;
+
+use crate::constraints::{
+    is_authority_for_vault_depositor, is_user_for_vault, is_vault_for_vault_depositor,
+    is_vault_protocol_for_vault,
+};
+use crate::error::ErrorCode;
+use crate::state::{
+    ProtocolLossLedger, Vault, VaultDepositor, VaultDepositorLoader, VaultProtocol,
+};
+use crate::{validate, AccountMapProvider};
+
+pub fn record_loss_carryforward<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, LossCarryforward<'info>>,
+) -> Result<()> {
+    let total_amount = depositor_total_amount(&ctx)?;
+
+    let mut vault_depositor = ctx.accounts.vault_depositor.load_mut_migrated()?;
+    let mut ledger = ctx.accounts.protocol_loss_ledger.load_mut()?;
+    ledger.record_loss(&mut *vault_depositor, total_amount)?;
+
+    Ok(())
+}
+
+/// The depositor's current value in the vault, validates the vault belongs to the ledger's protocol.
+pub fn depositor_total_amount<'c: 'info, 'info>(
+    ctx: &Context<'_, '_, 'c, 'info, LossCarryforward<'info>>,
+) -> Result<u64> {
+    let clock = &Clock::get()?;
+
+    let vault = ctx.accounts.vault.load()?;
+    let vault_protocol = ctx.accounts.vault_protocol.load()?;
+    let ledger = ctx.accounts.protocol_loss_ledger.load()?;
+    validate!(
+        vault_protocol.protocol == ledger.protocol
+            && vault.spot_market_index == ledger.spot_market_index,
+        ErrorCode::InvalidLossCarryforward,
+        "vault is not netted by this ledger"
+    )?;
+
+    let user = ctx.accounts.drift_user.load()?;
+    let AccountMaps {
+        perp_market_map,
+        spot_market_map,
+        mut oracle_map,
+    } = ctx.load_maps(clock.slot, None, false, false)?;
+
+    let vault_equity =
+        vault.calculate_equity(&user, &perp_market_map, &spot_market_map, &mut oracle_map)?;
+
+    let vault_depositor = ctx.accounts.vault_depositor.load_checked()?;
+    Ok(depositor_shares_to_vault_amount(
+        vault_depositor.checked_vault_shares(&vault)?,
+        vault.total_shares,
+        vault_equity,
+    )?)
+}
+
+#[derive(Accounts)]
+pub struct LossCarryforward<'info> {
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        constraint = is_vault_protocol_for_vault(&vault_protocol, &vault)?
+    )]
+    pub vault_protocol: AccountLoader<'info, VaultProtocol>,
+    #[account(
+        mut,
+        constraint = is_vault_for_vault_depositor(&vault_depositor, &vault)?,
+        constraint = is_authority_for_vault_depositor(&vault_depositor, &authority)?,
+    )]
+    pub vault_depositor: AccountLoader<'info, VaultDepositor>,
+    #[account(
+        mut,
+        seeds = [b"protocol_loss_ledger", protocol_loss_ledger.load()?.protocol.as_ref(), authority.key().as_ref()],
+        bump = protocol_loss_ledger.load()?.bump,
+    )]
+    pub protocol_loss_ledger: AccountLoader<'info, ProtocolLossLedger>,
+    pub authority: Signer<'info>,
+    #[account(
+        constraint = is_user_for_vault(&vault, &drift_user.key())?
+    )]
+    /// CHECK: checked in drift cpi
+    pub drift_user: AccountLoader<'info, User>,
+}
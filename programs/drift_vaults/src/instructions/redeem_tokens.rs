@@ -1,17 +1,18 @@
 use crate::constraints::{
     is_ata, is_authority_for_vault_depositor, is_mint_for_tokenized_depositor,
-    is_tokenized_depositor_for_vault, is_user_for_vault,
+    is_tokenized_depositor_for_vault, is_user_for_vault, is_user_stats_for_vault,
 };
 use crate::error::ErrorCode;
 use crate::state::traits::VaultDepositorBase;
+use crate::state::VaultDepositorLoader;
 use crate::token_cpi::{BurnTokensCPI, TokenTransferCPI};
-use crate::{validate, AccountMapProvider};
+use crate::{validate, AccountMapProvider, FuelOverflowProvider, VaultConfigProvider};
 use crate::{TokenizedVaultDepositor, Vault, VaultDepositor, VaultProtocolProvider, WithdrawUnit};
 use anchor_lang::prelude::*;
 use anchor_spl::token::{burn, transfer, Burn, Mint, Token, TokenAccount, Transfer};
 use drift::instructions::optional_accounts::AccountMaps;
 use drift::math::safe_math::SafeMath;
-use drift::state::user::User;
+use drift::state::user::{FuelOverflowStatus, User, UserStats};
 
 pub fn redeem_tokens<'info>(
     ctx: Context<'_, '_, 'info, 'info, RedeemTokens<'info>>,
@@ -23,9 +24,11 @@ pub fn redeem_tokens<'info>(
 
     validate!(!vault.in_liquidation(), ErrorCode::OngoingLiquidation)?;
 
-    let mut vault_depositor = ctx.accounts.vault_depositor.load_mut()?;
+    let mut vault_depositor = ctx.accounts.vault_depositor.load_mut_migrated()?;
     let mut tokenized_vault_depositor = ctx.accounts.tokenized_vault_depositor.load_mut()?;
 
+    let vault_config = ctx.vault_config(&vault)?;
+
     // backwards compatible: if last rem acct does not deserialize into [`VaultProtocol`] then it's a legacy vault.
     let mut vp = ctx.vault_protocol();
     vault.validate_vault_protocol(&vp)?;
@@ -38,12 +41,23 @@ pub fn redeem_tokens<'info>(
         .safe_add(manager_shares_before)?;
 
     let user = ctx.accounts.drift_user.load()?;
+
+    let user_stats = ctx.accounts.drift_user_stats.load()?;
+    let has_fuel_overflow = FuelOverflowStatus::exists(user_stats.fuel_overflow_status);
+    let fuel_overflow = ctx.fuel_overflow(vp.is_some(), has_fuel_overflow);
+    user_stats.validate_fuel_overflow(&fuel_overflow)?;
+
     let spot_market_index = vault.spot_market_index;
     let AccountMaps {
         perp_market_map,
         spot_market_map,
         mut oracle_map,
-    } = ctx.load_maps(clock.slot, Some(spot_market_index), vp.is_some(), false)?;
+    } = ctx.load_maps(
+        clock.slot,
+        Some(spot_market_index),
+        vp.is_some(),
+        has_fuel_overflow,
+    )?;
 
     let vault_equity =
         vault.calculate_equity(&user, &perp_market_map, &spot_market_map, &mut oracle_map)?;
@@ -59,6 +73,7 @@ pub fn redeem_tokens<'info>(
     let oracle = oracle_map.get_price_data(&spot_market.oracle_id())?;
     let (shares_to_transfer, mut vp) = tokenized_vault_depositor.redeem_tokens(
         &mut vault,
+        &vault_config,
         &mut vp,
         total_supply_before,
         vault_equity,
@@ -66,15 +81,25 @@ pub fn redeem_tokens<'info>(
         clock.unix_timestamp,
         oracle.price,
     )?;
-    let (shares_transferred, _) = tokenized_vault_depositor.transfer_shares(
-        &mut *vault_depositor,
-        &mut vault,
-        &mut vp,
-        shares_to_transfer,
-        WithdrawUnit::Shares,
-        vault_equity,
+    let (shares_transferred, _) = vault_depositor.with_fuel_sync(
         clock.unix_timestamp,
-        oracle.price,
+        &mut vault,
+        &vault_config,
+        &user_stats,
+        &fuel_overflow,
+        |vd, vault| {
+            tokenized_vault_depositor.transfer_shares(
+                vd,
+                vault,
+                &vault_config,
+                &mut vp,
+                shares_to_transfer,
+                WithdrawUnit::Shares,
+                vault_equity,
+                clock.unix_timestamp,
+                oracle.price,
+            )
+        },
     )?;
 
     let manager_shares_after = vault.get_manager_shares(&mut vp)?;
@@ -94,6 +119,15 @@ pub fn redeem_tokens<'info>(
         ErrorCode::InvalidVaultSharesDetected
     )?;
 
+    tokenized_vault_depositor.emit_exchange_rate_record(
+        &vault,
+        vault_equity,
+        total_supply_before.safe_sub(tokens_to_burn)?,
+        ctx.accounts.mint.decimals,
+        spot_market.decimals,
+        clock.unix_timestamp,
+    )?;
+
     let vault_name = vault.name;
     let vault_bump = vault.bump;
 
@@ -173,6 +207,10 @@ pub struct RedeemTokens<'info> {
         constraint = is_user_for_vault(&vault, &drift_user.key())?
     )]
     pub drift_user: AccountLoader<'info, User>,
+    #[account(
+        constraint = is_user_stats_for_vault(&vault, &drift_user_stats.key())?
+    )]
+    pub drift_user_stats: AccountLoader<'info, UserStats>,
     pub token_program: Program<'info, Token>,
 }
 
@@ -0,0 +1,73 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+use drift::math::safe_math::SafeMath;
+
+use crate::error::ErrorCode;
+use crate::state::{Vault, VaultFlag, VaultRegistry, VaultRegistryEntry, VaultRegistryPage};
+use crate::{validate, VaultProtocolProvider};
+
+/// Permissionless, appends `vault` to the [`VaultRegistry`]. Clients register new vaults in the transaction that
+/// initializes them, anyone can register older vaults. A vault with a
+/// [`VaultProtocol`](crate::state::VaultProtocol) must pass it as the last remaining account.
+pub fn register_vault<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, RegisterVault<'info>>,
+) -> Result<()> {
+    let mut vault = ctx.accounts.vault.load_mut()?;
+    validate!(
+        !vault.has_flag(VaultFlag::Registered),
+        ErrorCode::InvalidVaultRegistry,
+        "vault already registered"
+    )?;
+
+    let vp = ctx.vault_protocol();
+    vault.validate_vault_protocol(&vp)?;
+    let protocol = match vp {
+        Some(vp) => vp.load()?.protocol,
+        None => Pubkey::default(),
+    };
+
+    let mut vault_registry = ctx.accounts.vault_registry.load_mut()?;
+    let mut page = ctx.accounts.vault_registry_page.load_mut()?;
+    validate!(
+        page.page_index == vault_registry.current_page_index()?,
+        ErrorCode::InvalidVaultRegistry,
+        "vaults are appended to registry page {}",
+        vault_registry.current_page_index()?
+    )?;
+
+    page.push(VaultRegistryEntry {
+        vault: vault.pubkey,
+        mint: ctx.accounts.vault_token_account.mint,
+        manager: vault.manager,
+        protocol,
+    })?;
+    vault_registry.total_vaults = vault_registry.total_vaults.safe_add(1)?;
+    vault.add_flag(VaultFlag::Registered);
+
+    msg!(
+        "Registered vault {} as #{}",
+        vault.pubkey,
+        vault_registry.total_vaults
+    );
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RegisterVault<'info> {
+    #[account(mut)]
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        seeds = [b"vault_token_account".as_ref(), vault.key().as_ref()],
+        bump,
+    )]
+    pub vault_token_account: Box<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        seeds = [b"vault_registry"],
+        bump = vault_registry.load()?.bump,
+    )]
+    pub vault_registry: AccountLoader<'info, VaultRegistry>,
+    #[account(mut)]
+    pub vault_registry_page: AccountLoader<'info, VaultRegistryPage>,
+}
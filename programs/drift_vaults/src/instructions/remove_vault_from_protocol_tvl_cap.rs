@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+
+use crate::constraints::{is_protocol_for_vault, is_vault_protocol_for_vault};
+use crate::state::{ProtocolTvlCap, Vault, VaultProtocol, VaultProtocolFlag};
+
+/// Stops counting the vault towards the protocol's tvl cap, freeing its slot.
+pub fn remove_vault_from_protocol_tvl_cap<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, RemoveVaultFromProtocolTvlCap<'info>>,
+) -> Result<()> {
+    ctx.accounts
+        .protocol_tvl_cap
+        .load_mut()?
+        .remove_vault(&ctx.accounts.vault.key())?;
+    ctx.accounts
+        .vault_protocol
+        .load_mut()?
+        .remove_flag(VaultProtocolFlag::TvlCap);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RemoveVaultFromProtocolTvlCap<'info> {
+    #[account(
+        constraint = is_protocol_for_vault(&vault, &vault_protocol, &protocol)?
+    )]
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        mut,
+        constraint = is_vault_protocol_for_vault(&vault_protocol, &vault)?
+    )]
+    pub vault_protocol: AccountLoader<'info, VaultProtocol>,
+    #[account(
+        mut,
+        seeds = [b"protocol_tvl_cap", protocol.key().as_ref()],
+        bump = protocol_tvl_cap.load()?.bump,
+        has_one = protocol,
+    )]
+    pub protocol_tvl_cap: AccountLoader<'info, ProtocolTvlCap>,
+    pub protocol: Signer<'info>,
+}
@@ -0,0 +1,82 @@
+use anchor_lang::prelude::*;
+use drift::instructions::optional_accounts::AccountMaps;
+use drift::state::user::{FuelOverflowStatus, User, UserStats};
+
+use crate::constraints::{
+    is_user_for_vault, is_user_stats_for_vault, is_vault_for_vault_depositor,
+};
+use crate::state::account_maps::AccountMapProvider;
+use crate::state::{
+    FuelOverflowProvider, InstructionsSysvarProvider, Vault, VaultConfigProvider, VaultDepositor,
+    VaultDepositorLoader, VaultProtocolProvider,
+};
+
+/// Permissionless expiry crank, renews the withdraw request of a depositor that opted in to
+/// [auto renewal](super::update_vault_depositor_auto_renew_withdraw) at current share pricing once it expires
+/// unexecuted, see [`VaultDepositor::renew_withdraw_request`].
+pub fn renew_withdraw_request<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, RenewWithdrawRequest<'info>>,
+) -> Result<()> {
+    let clock = &Clock::get()?;
+    let vault = &mut ctx.accounts.vault.load_mut()?;
+    vault.validate_settle_crank(&ctx.accounts.drift_user.key(), ctx.instructions_sysvar())?;
+    let mut vault_depositor = ctx.accounts.vault_depositor.load_mut_migrated()?;
+
+    let user = ctx.accounts.drift_user.load()?;
+
+    let vault_config = ctx.vault_config(vault)?;
+
+    let mut vp = ctx.vault_protocol();
+    vault.validate_vault_protocol(&vp)?;
+    let mut vp = vp.as_mut().map(|vp| vp.load_mut()).transpose()?;
+
+    let user_stats = ctx.accounts.drift_user_stats.load()?;
+    let has_fuel_overflow = FuelOverflowStatus::exists(user_stats.fuel_overflow_status);
+    let fuel_overflow = ctx.fuel_overflow(vp.is_some(), has_fuel_overflow);
+    user_stats.validate_fuel_overflow(&fuel_overflow)?;
+
+    let AccountMaps {
+        perp_market_map,
+        spot_market_map,
+        mut oracle_map,
+    } = ctx.load_maps(clock.slot, None, vp.is_some(), has_fuel_overflow)?;
+
+    let vault_equity =
+        vault.calculate_equity(&user, &perp_market_map, &spot_market_map, &mut oracle_map)?;
+
+    let spot_market = spot_market_map.get_ref(&vault.spot_market_index)?;
+    let oracle = oracle_map.get_price_data(&spot_market.oracle_id())?;
+
+    vault_depositor.renew_withdraw_request(
+        vault_equity,
+        vault,
+        &vault_config,
+        &mut vp,
+        clock.unix_timestamp,
+        &user_stats,
+        &fuel_overflow,
+        oracle.price,
+    )?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RenewWithdrawRequest<'info> {
+    #[account(mut)]
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        mut,
+        constraint = is_vault_for_vault_depositor(&vault_depositor, &vault)?,
+    )]
+    pub vault_depositor: AccountLoader<'info, VaultDepositor>,
+    pub keeper: Signer<'info>,
+    #[account(
+        constraint = is_user_stats_for_vault(&vault, &drift_user_stats.key())?
+    )]
+    pub drift_user_stats: AccountLoader<'info, UserStats>,
+    #[account(
+        constraint = is_user_for_vault(&vault, &drift_user.key())?
+    )]
+    pub drift_user: AccountLoader<'info, User>,
+}
@@ -4,10 +4,17 @@ use drift::math::casting::Cast;
 use drift::state::user::{FuelOverflowStatus, User, UserStats};
 
 use crate::constraints::{
-    is_authority_for_vault_depositor, is_user_for_vault, is_user_stats_for_vault,
+    is_authority_or_custodian_for_vault_depositor, is_user_for_vault, is_user_stats_for_vault,
 };
 use crate::state::account_maps::AccountMapProvider;
-use crate::state::{FuelOverflowProvider, Vault, VaultProtocolProvider};
+use crate::state::events::VaultDepositorAction;
+use crate::state::{
+    record_vault_depositor_action, ComputeUnitsHint, FuelOverflowProvider,
+    InstructionsSysvarProvider, OmnibusLedgerProvider, Vault, VaultConfigProvider,
+    VaultDepositorBase, VaultDepositorLoader, VaultDepositorRecordLogProvider,
+    VaultDepositorThrottleProvider, VaultDepositorWithdrawRequestsProvider, VaultLockupProvider,
+    VaultProtocolProvider, VaultRedeemScheduleProvider, REQUEST_WITHDRAW_BASE_COMPUTE_UNITS,
+};
 use crate::{VaultDepositor, WithdrawUnit};
 
 pub fn request_withdraw<'c: 'info, 'info>(
@@ -17,10 +24,28 @@ pub fn request_withdraw<'c: 'info, 'info>(
 ) -> Result<()> {
     let clock = &Clock::get()?;
     let vault = &mut ctx.accounts.vault.load_mut()?;
-    let mut vault_depositor = ctx.accounts.vault_depositor.load_mut()?;
+    vault.validate_settle_crank(&ctx.accounts.drift_user.key(), ctx.instructions_sysvar())?;
+    let mut vault_depositor = ctx.accounts.vault_depositor.load_mut_migrated()?;
+
+    if let Some((throttle, _)) = ctx.vault_depositor_throttle(vault, &vault_depositor.pubkey)? {
+        throttle
+            .load()?
+            .validate_request(&vault_depositor, clock.unix_timestamp)?;
+    }
+
+    // no depositor lockup, no deposit since the lockup was enabled
+    if let Some((vault_lockup, Some(vault_depositor_lockup))) =
+        ctx.vault_lockup(vault, &vault_depositor.pubkey)?
+    {
+        vault_lockup
+            .load()?
+            .validate_request(&vault_depositor_lockup.load()?, clock.unix_timestamp)?;
+    }
 
     let user = ctx.accounts.drift_user.load()?;
 
+    let vault_config = ctx.vault_config(vault)?;
+
     let mut vp = ctx.vault_protocol();
     vault.validate_vault_protocol(&vp)?;
     let mut vp = vp.as_mut().map(|vp| vp.load_mut()).transpose()?;
@@ -38,15 +63,22 @@ pub fn request_withdraw<'c: 'info, 'info>(
 
     let vault_equity =
         vault.calculate_equity(&user, &perp_market_map, &spot_market_map, &mut oracle_map)?;
+    let compute_units_hint = ComputeUnitsHint::new(
+        REQUEST_WITHDRAW_BASE_COMPUTE_UNITS,
+        &perp_market_map,
+        &spot_market_map,
+    )?;
 
     let spot_market = spot_market_map.get_ref(&vault.spot_market_index)?;
     let oracle = oracle_map.get_price_data(&spot_market.oracle_id())?;
 
+    let vault_shares_before = vault_depositor.get_vault_shares();
     vault_depositor.request_withdraw(
         withdraw_amount.cast()?,
         withdraw_unit,
         vault_equity,
         vault,
+        &vault_config,
         &mut vp,
         clock.unix_timestamp,
         &user_stats,
@@ -54,6 +86,37 @@ pub fn request_withdraw<'c: 'info, 'info>(
         oracle.price,
     )?;
 
+    if let Some(omnibus_ledger) = ctx.omnibus_ledger(&vault_depositor)? {
+        omnibus_ledger.load()?.validate_request(&vault_depositor)?;
+    }
+
+    if let Some(withdraw_requests) = ctx.vault_depositor_withdraw_requests(&vault_depositor)? {
+        withdraw_requests
+            .load()?
+            .validate_request(&vault_depositor)?;
+    }
+
+    if let Some(redeem_schedule) = ctx.vault_redeem_schedule(&vault.pubkey)? {
+        redeem_schedule.load()?.apply(
+            vault,
+            &mut vault_depositor.last_withdraw_request,
+            vault_equity,
+        )?;
+    }
+
+    record_vault_depositor_action(
+        &ctx.vault_depositor_record_log(&vault.pubkey)?,
+        vault,
+        &vault_depositor,
+        VaultDepositorAction::WithdrawRequest,
+        vault_depositor.last_withdraw_request.value,
+        vault_shares_before,
+        vault_equity,
+        clock.unix_timestamp,
+    )?;
+
+    compute_units_hint.set_return_data()?;
+
     Ok(())
 }
 
@@ -63,11 +126,12 @@ pub struct RequestWithdraw<'info> {
     pub vault: AccountLoader<'info, Vault>,
     #[account(
         mut,
-        seeds = [b"vault_depositor", vault.key().as_ref(), authority.key().as_ref()],
+        seeds = [b"vault_depositor", vault.key().as_ref(), vault_depositor.load()?.authority.as_ref()],
         bump,
-        constraint = is_authority_for_vault_depositor(&vault_depositor, &authority)?,
+        constraint = is_authority_or_custodian_for_vault_depositor(&vault_depositor, &authority)?,
     )]
     pub vault_depositor: AccountLoader<'info, VaultDepositor>,
+    /// the depositor authority or its custodian
     pub authority: Signer<'info>,
     #[account(
         constraint = is_user_stats_for_vault(&vault, &drift_user_stats.key())?
@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+
+use crate::error::ErrorCode;
+use crate::state::WithdrawReceiptProvider;
+use crate::WithdrawUnit;
+
+use super::RequestWithdraw;
+
+/// [`request_withdraw`](super::request_withdraw) that commits the new request to `cancel_deadline_ts`, after which
+/// the depositor can no longer cancel it. The vault depositor's [`WithdrawReceipt`](crate::state::WithdrawReceipt)
+/// must be writable in remaining_accounts, after the market accounts.
+pub fn request_withdraw_with_cancel_deadline<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, RequestWithdraw<'info>>,
+    withdraw_amount: u64,
+    withdraw_unit: WithdrawUnit,
+    cancel_deadline_ts: i64,
+) -> Result<()> {
+    let clock = &Clock::get()?;
+
+    let receipt = {
+        let vault = ctx.accounts.vault.load()?;
+        ctx.withdraw_receipt(&vault, &ctx.accounts.vault_depositor.key())?
+            .ok_or(ErrorCode::InvalidWithdrawReceipt)?
+    };
+    let vault_depositor = ctx.accounts.vault_depositor.clone();
+
+    super::request_withdraw(ctx, withdraw_amount, withdraw_unit)?;
+
+    receipt.load_mut()?.set(
+        &vault_depositor.load()?.last_withdraw_request,
+        cancel_deadline_ts,
+        clock.unix_timestamp,
+    )
+}
@@ -0,0 +1,24 @@
+use anchor_lang::prelude::*;
+
+use crate::state::VaultDepositorIdempotencyProvider;
+use crate::WithdrawUnit;
+
+use super::RequestWithdraw;
+
+/// [`request_withdraw`](super::request_withdraw) that fails if `idempotency_key` was already executed for the vault
+/// depositor. The vault depositor's [`VaultDepositorIdempotency`](crate::state::VaultDepositorIdempotency) must be
+/// writable in remaining_accounts, after the market accounts.
+pub fn request_withdraw_with_idempotency_key<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, RequestWithdraw<'info>>,
+    withdraw_amount: u64,
+    withdraw_unit: WithdrawUnit,
+    idempotency_key: u64,
+) -> Result<()> {
+    let clock = &Clock::get()?;
+
+    ctx.vault_depositor_idempotency(&ctx.accounts.vault_depositor.key())?
+        .load_mut()?
+        .check_and_record(idempotency_key, clock.unix_timestamp)?;
+
+    super::request_withdraw(ctx, withdraw_amount, withdraw_unit)
+}
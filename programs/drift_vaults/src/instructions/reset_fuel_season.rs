@@ -4,7 +4,9 @@ use drift::state::state::State;
 use drift::state::user::{FuelOverflowStatus, UserStats};
 
 use crate::constraints::{is_user_stats_for_vault, is_vault_for_vault_depositor};
-use crate::state::{FuelOverflowProvider, Vault, VaultProtocolProvider};
+use crate::state::{
+    FuelOverflowProvider, Vault, VaultConfigProvider, VaultDepositorLoader, VaultProtocolProvider,
+};
 use crate::VaultDepositor;
 
 pub fn reset_fuel_season<'c: 'info, 'info>(
@@ -13,8 +15,9 @@ pub fn reset_fuel_season<'c: 'info, 'info>(
     let clock = &Clock::get()?;
 
     let mut vault = ctx.accounts.vault.load_mut()?;
-    let mut vault_depositor = ctx.accounts.vault_depositor.load_mut()?;
+    let mut vault_depositor = ctx.accounts.vault_depositor.load_mut_migrated()?;
 
+    let vault_config = ctx.vault_config(&vault)?;
     // backwards compatible: if last rem acct does not deserialize into [`VaultProtocol`] then it's a legacy vault.
     let mut vp = ctx.vault_protocol();
     vault.validate_vault_protocol(&vp)?;
@@ -28,6 +31,7 @@ pub fn reset_fuel_season<'c: 'info, 'info>(
     vault_depositor.update_cumulative_fuel_amount(
         clock.unix_timestamp,
         &mut vault,
+        &vault_config,
         &user_stats,
         &fuel_overflow,
     )?;
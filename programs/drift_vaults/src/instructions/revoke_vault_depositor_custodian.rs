@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+
+use crate::constraints::{
+    is_authority_or_custodian_for_vault_depositor, is_vault_for_vault_depositor,
+};
+use crate::state::VaultDepositorLoader;
+use crate::{Vault, VaultDepositor};
+
+/// Clears the vault depositor's custodian. Can be signed by the authority or by the custodian itself.
+pub fn revoke_vault_depositor_custodian<'info>(
+    ctx: Context<'_, '_, '_, 'info, RevokeVaultDepositorCustodian<'info>>,
+) -> Result<()> {
+    let mut vault_depositor = ctx.accounts.vault_depositor.load_mut_migrated()?;
+    vault_depositor.custodian = Pubkey::default();
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RevokeVaultDepositorCustodian<'info> {
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        mut,
+        seeds = [b"vault_depositor", vault.key().as_ref(), vault_depositor.load()?.authority.as_ref()],
+        bump,
+        constraint = is_vault_for_vault_depositor(&vault_depositor, &vault)?,
+        constraint = is_authority_or_custodian_for_vault_depositor(&vault_depositor, &authority)?,
+    )]
+    pub vault_depositor: AccountLoader<'info, VaultDepositor>,
+    /// the depositor authority or its custodian
+    pub authority: Signer<'info>,
+}
@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+
+use crate::constraints::is_manager_for_vault;
+use crate::state::{FeeHolidayAction, Vault, VaultFeeHoliday};
+
+pub fn schedule_vault_fee_holiday<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, UpdateVaultFeeHoliday<'info>>,
+    start_ts: i64,
+    end_ts: i64,
+    suspend_management_fee: bool,
+    suspend_profit_share: bool,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+
+    let mut vault_fee_holiday = ctx.accounts.vault_fee_holiday.load_mut()?;
+    vault_fee_holiday.schedule(
+        start_ts,
+        end_ts,
+        suspend_management_fee,
+        suspend_profit_share,
+        now,
+    )?;
+    vault_fee_holiday.emit_record(FeeHolidayAction::Schedule, now);
+
+    Ok(())
+}
+
+pub fn cancel_vault_fee_holiday<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, UpdateVaultFeeHoliday<'info>>,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+
+    let mut vault_fee_holiday = ctx.accounts.vault_fee_holiday.load_mut()?;
+    vault_fee_holiday.emit_record(FeeHolidayAction::Cancel, now);
+    vault_fee_holiday.cancel()?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdateVaultFeeHoliday<'info> {
+    #[account(
+        constraint = is_manager_for_vault(&vault, &manager)?
+    )]
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        mut,
+        seeds = [b"vault_fee_holiday", vault.key().as_ref()],
+        bump = vault_fee_holiday.load()?.bump,
+    )]
+    pub vault_fee_holiday: AccountLoader<'info, VaultFeeHoliday>,
+    pub manager: Signer<'info>,
+}
@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+
+use crate::constraints::is_manager_for_vault;
+use crate::state::{Vault, VaultWithdrawBlackout};
+
+/// Publishes a blackout window during which withdraws are deferred to `end_ts`, see [`VaultWithdrawBlackout`] for
+/// the notice and duration limits.
+pub fn schedule_vault_withdraw_blackout<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, ScheduleVaultWithdrawBlackout<'info>>,
+    start_ts: i64,
+    end_ts: i64,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+
+    let mut vault_withdraw_blackout = ctx.accounts.vault_withdraw_blackout.load_mut()?;
+    vault_withdraw_blackout.schedule(start_ts, end_ts, now)?;
+    vault_withdraw_blackout.emit_record(start_ts, end_ts, now)?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ScheduleVaultWithdrawBlackout<'info> {
+    #[account(
+        constraint = is_manager_for_vault(&vault, &manager)?
+    )]
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        mut,
+        seeds = [b"vault_withdraw_blackout", vault.key().as_ref()],
+        bump = vault_withdraw_blackout.load()?.bump,
+    )]
+    pub vault_withdraw_blackout: AccountLoader<'info, VaultWithdrawBlackout>,
+    pub manager: Signer<'info>,
+}
@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+use drift::instructions::optional_accounts::AccountMaps;
+use drift::state::user::User;
+
+use crate::constraints::is_user_for_vault;
+use crate::state::{Vault, VaultWithdrawSettlement};
+use crate::AccountMapProvider;
+
+/// Permissionless crank, marks the vault's share price for every withdraw request matured by now.
+/// Remaining accounts are the oracles and markets for the vault's positions.
+pub fn settle_vault_withdraws<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, SettleVaultWithdraws<'info>>,
+) -> Result<()> {
+    let clock = &Clock::get()?;
+    let vault = ctx.accounts.vault.load()?;
+    let user = ctx.accounts.drift_user.load()?;
+
+    let AccountMaps {
+        perp_market_map,
+        spot_market_map,
+        mut oracle_map,
+    } = ctx.load_maps(clock.slot, None, false, false)?;
+
+    let vault_equity =
+        vault.calculate_equity(&user, &perp_market_map, &spot_market_map, &mut oracle_map)?;
+
+    let mut settlement = ctx.accounts.vault_withdraw_settlement.load_mut()?;
+    settlement.settle(&vault, vault_equity, clock.unix_timestamp)?;
+
+    msg!(
+        "settlement {}: equity={} total_shares={}",
+        settlement.settlement_id,
+        settlement.equity,
+        settlement.total_shares
+    );
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SettleVaultWithdraws<'info> {
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        mut,
+        seeds = [b"vault_withdraw_settlement", vault.key().as_ref()],
+        bump = vault_withdraw_settlement.load()?.bump,
+    )]
+    pub vault_withdraw_settlement: AccountLoader<'info, VaultWithdrawSettlement>,
+    #[account(
+        constraint = is_user_for_vault(&vault, &drift_user.key())?
+    )]
+    /// CHECK: checked in drift cpi
+    pub drift_user: AccountLoader<'info, User>,
+}
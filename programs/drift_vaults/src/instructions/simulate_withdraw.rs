@@ -0,0 +1,101 @@
+use anchor_lang::prelude::*;
+use drift::instructions::optional_accounts::AccountMaps;
+use drift::state::user::User;
+
+use crate::constraints::{is_user_for_vault, is_vault_for_vault_depositor};
+use crate::error::{status_code, STATUS_OK};
+use crate::state::{Vault, VaultDepositor, VaultDepositorLoader, WithdrawBlocker};
+use crate::{AccountMapProvider, VaultProtocolProvider};
+
+/// Read only, returns (as return data) what stops the drift user from paying out the depositor's pending withdraw
+/// request so keepers can pick the remediation. Remaining accounts are the same as `liquidate`, the vault's spot
+/// market must be writable.
+pub fn simulate_withdraw<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, SimulateWithdraw<'info>>,
+) -> Result<WithdrawBlocker> {
+    let withdraw_blocker = get_withdraw_blocker(&ctx)?;
+
+    msg!("withdraw blocker: {:?}", withdraw_blocker);
+
+    Ok(withdraw_blocker)
+}
+
+/// Failure-tolerant [`simulate_withdraw`]: never fails once the accounts validate, instead returning the
+/// [status code](crate::error::status_code) of whatever error stopped the simulation so integrator programs can
+/// branch on it over cpi without parsing error strings.
+pub fn simulate_withdraw_status<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, SimulateWithdraw<'info>>,
+) -> Result<SimulateWithdrawStatus> {
+    let status = match get_withdraw_blocker(&ctx) {
+        Ok(withdraw_blocker) => SimulateWithdrawStatus {
+            status_code: STATUS_OK,
+            withdraw_blocker,
+        },
+        Err(error) => SimulateWithdrawStatus {
+            status_code: status_code(&error),
+            withdraw_blocker: WithdrawBlocker::None,
+        },
+    };
+
+    msg!("simulate withdraw status: {:?}", status);
+
+    Ok(status)
+}
+
+#[derive(Clone, Copy, AnchorSerialize, AnchorDeserialize, PartialEq, Debug, Eq)]
+pub struct SimulateWithdrawStatus {
+    /// [`STATUS_OK`] if the simulation ran, otherwise the status code of the error that stopped it
+    pub status_code: u32,
+    /// Only meaningful when `status_code` is [`STATUS_OK`]
+    pub withdraw_blocker: WithdrawBlocker,
+}
+
+fn get_withdraw_blocker<'c: 'info, 'info>(
+    ctx: &Context<'_, '_, 'c, 'info, SimulateWithdraw<'info>>,
+) -> Result<WithdrawBlocker> {
+    let clock = &Clock::get()?;
+
+    let mut user = *ctx.accounts.drift_user.load()?;
+    let vault = ctx.accounts.vault.load()?;
+    let vault_depositor = ctx.accounts.vault_depositor.load_checked()?;
+
+    let vp = ctx.vault_protocol();
+    vault.validate_vault_protocol(&vp)?;
+
+    let AccountMaps {
+        perp_market_map,
+        spot_market_map,
+        mut oracle_map,
+    } = ctx.load_maps(
+        clock.slot,
+        Some(vault.spot_market_index),
+        vp.is_some(),
+        false,
+    )?;
+
+    let vault_equity =
+        vault.calculate_equity(&user, &perp_market_map, &spot_market_map, &mut oracle_map)?;
+    let withdraw_blocker = vault_depositor.simulate_withdraw(
+        &vault,
+        vault_equity,
+        &mut user,
+        &perp_market_map,
+        &spot_market_map,
+        &mut oracle_map,
+    )?;
+
+    Ok(withdraw_blocker)
+}
+
+#[derive(Accounts)]
+pub struct SimulateWithdraw<'info> {
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        constraint = is_vault_for_vault_depositor(&vault_depositor, &vault)?,
+    )]
+    pub vault_depositor: AccountLoader<'info, VaultDepositor>,
+    #[account(
+        constraint = is_user_for_vault(&vault, &drift_user.key())?
+    )]
+    pub drift_user: AccountLoader<'info, User>,
+}
@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+
+use crate::constraints::is_vault_for_vault_depositor;
+use crate::state::{
+    Vault, VaultDepositor, VaultDepositorLoader, VaultDepositorShareSnapshot, VaultShareSnapshot,
+};
+use crate::Size;
+
+/// Permissionless crank, records the depositor's current shares into the open snapshot epoch.
+pub fn snapshot_vault_depositor_shares<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, SnapshotVaultDepositorShares<'info>>,
+) -> Result<()> {
+    let clock = &Clock::get()?;
+
+    let vault = ctx.accounts.vault.load()?;
+    let vault_depositor = ctx.accounts.vault_depositor.load_checked()?;
+    let shares = vault_depositor.checked_vault_shares(&vault)?;
+
+    let mut snapshot = ctx.accounts.vault_share_snapshot.load_mut()?;
+    snapshot.record(clock.unix_timestamp, shares, vault.shares_base)?;
+
+    let mut depositor_snapshot = ctx.accounts.vault_depositor_share_snapshot.load_init()?;
+    depositor_snapshot.share_snapshot = ctx.accounts.vault_share_snapshot.key();
+    depositor_snapshot.authority = vault_depositor.authority;
+    depositor_snapshot.shares = shares;
+    depositor_snapshot.ts = clock.unix_timestamp;
+    depositor_snapshot.epoch = snapshot.epoch;
+    depositor_snapshot.bump = ctx.bumps.vault_depositor_share_snapshot;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SnapshotVaultDepositorShares<'info> {
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        mut,
+        seeds = [
+            b"vault_share_snapshot",
+            vault.key().as_ref(),
+            vault_share_snapshot.load()?.epoch.to_le_bytes().as_ref()
+        ],
+        bump = vault_share_snapshot.load()?.bump,
+    )]
+    pub vault_share_snapshot: AccountLoader<'info, VaultShareSnapshot>,
+    #[account(
+        constraint = is_vault_for_vault_depositor(&vault_depositor, &vault)?
+    )]
+    pub vault_depositor: AccountLoader<'info, VaultDepositor>,
+    #[account(
+        init,
+        seeds = [
+            b"vault_depositor_share_snapshot",
+            vault_share_snapshot.key().as_ref(),
+            vault_depositor.load()?.authority.as_ref()
+        ],
+        space = VaultDepositorShareSnapshot::SIZE,
+        bump,
+        payer = payer
+    )]
+    pub vault_depositor_share_snapshot: AccountLoader<'info, VaultDepositorShareSnapshot>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub rent: Sysvar<'info, Rent>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+
+use crate::constraints::{is_authority_for_vault_depositor, is_vault_for_vault_depositor};
+use crate::error::ErrorCode;
+use crate::state::{Vault, VaultDepositor, VaultDepositorLoader, VaultPoll};
+use crate::validate;
+
+pub fn start_vault_poll<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, StartVaultPoll<'info>>,
+    proposal: u8,
+    new_manager: Pubkey,
+) -> Result<()> {
+    let clock = &Clock::get()?;
+
+    let vault = ctx.accounts.vault.load()?;
+    let vault_depositor = ctx.accounts.vault_depositor.load_checked()?;
+
+    validate!(
+        vault_depositor.checked_vault_shares(&vault)? > 0,
+        ErrorCode::InvalidVaultPoll,
+        "only depositors with shares can start a poll"
+    )?;
+
+    let mut vault_poll = ctx.accounts.vault_poll.load_mut()?;
+    vault_poll.start(
+        clock.unix_timestamp,
+        proposal,
+        new_manager,
+        vault.user_shares,
+        vault.shares_base,
+    )?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct StartVaultPoll<'info> {
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        mut,
+        seeds = [b"vault_poll", vault.key().as_ref()],
+        bump = vault_poll.load()?.bump,
+    )]
+    pub vault_poll: AccountLoader<'info, VaultPoll>,
+    #[account(
+        constraint = is_vault_for_vault_depositor(&vault_depositor, &vault)?,
+        constraint = is_authority_for_vault_depositor(&vault_depositor, &authority)?,
+    )]
+    pub vault_depositor: AccountLoader<'info, VaultDepositor>,
+    pub authority: Signer<'info>,
+}
@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+
+use crate::constraints::{is_authority_for_vault_depositor, is_vault_for_vault_depositor};
+use crate::error::ErrorCode;
+use crate::state::{
+    Vault, VaultDepositor, VaultDepositorBase, VaultDepositorLoader, VaultFeature,
+    VaultWithdrawStream,
+};
+use crate::validate;
+
+/// Streams the depositor's matured withdraw request out linearly over `duration` seconds through
+/// [`claim_withdraw_stream`](crate::instructions::claim_withdraw_stream).
+pub fn start_withdraw_stream<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, StartWithdrawStream<'info>>,
+    duration: i64,
+) -> Result<()> {
+    let clock = &Clock::get()?;
+    let vault = ctx.accounts.vault.load()?;
+    vault.validate_feature_enabled(VaultFeature::WithdrawStream)?;
+
+    let vault_depositor = ctx.accounts.vault_depositor.load_checked()?;
+
+    let request = &vault_depositor.last_withdraw_request;
+    validate!(
+        request.pending(),
+        ErrorCode::InvalidWithdrawStream,
+        "no withdraw request to stream"
+    )?;
+    request.check_redeem_period_finished(&vault, clock.unix_timestamp)?;
+
+    let mut vault_withdraw_stream = ctx.accounts.vault_withdraw_stream.load_mut()?;
+    vault_withdraw_stream.start(
+        request,
+        vault_depositor.get_vault_shares_base(),
+        duration,
+        clock.unix_timestamp,
+    )?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct StartWithdrawStream<'info> {
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        constraint = is_vault_for_vault_depositor(&vault_depositor, &vault)?,
+        constraint = is_authority_for_vault_depositor(&vault_depositor, &authority)?,
+    )]
+    pub vault_depositor: AccountLoader<'info, VaultDepositor>,
+    #[account(
+        mut,
+        seeds = [b"vault_withdraw_stream", vault_depositor.key().as_ref()],
+        bump = vault_withdraw_stream.load()?.bump,
+    )]
+    pub vault_withdraw_stream: AccountLoader<'info, VaultWithdrawStream>,
+    pub authority: Signer<'info>,
+}
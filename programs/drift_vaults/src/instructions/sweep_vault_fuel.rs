@@ -0,0 +1,141 @@
+use anchor_lang::prelude::*;
+use drift::cpi::accounts::{InitializeFuelOverflow, SweepFuel};
+use drift::program::Drift;
+use drift::state::user::{FuelOverflow, FuelOverflowStatus, UserStats};
+
+use crate::constants::FUEL_OVERFLOW_SWEEP_THRESHOLD;
+use crate::constraints::is_user_stats_for_vault;
+use crate::drift_cpi::FuelOverflowCPI;
+use crate::error::ErrorCode;
+use crate::state::Vault;
+use crate::validate;
+
+/// Permissionless keeper crank for a vault user stats whose fuel is about to overflow. Creates the vault's drift
+/// [`FuelOverflow`] if it doesn't exist yet and sweeps the user stats fuel into it, so fuel keeps accruing. Vault fuel
+/// per share is brought up to date before the sweep and read back from user stats plus overflow after it. Once the
+/// overflow exists depositor instructions must pass it, see
+/// [`FuelOverflowProvider`](crate::state::FuelOverflowProvider).
+pub fn sweep_vault_fuel<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, SweepVaultFuel<'info>>,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+
+    let has_fuel_overflow = {
+        let user_stats = ctx.accounts.drift_user_stats.load()?;
+        validate!(
+            is_fuel_near_overflow(&user_stats),
+            ErrorCode::InvalidFuelOverflowSweep,
+            "vault fuel isn't near overflow"
+        )?;
+
+        let has_fuel_overflow = FuelOverflowStatus::exists(user_stats.fuel_overflow_status);
+        let fuel_overflow = if has_fuel_overflow {
+            Some(AccountLoader::<FuelOverflow>::try_from(
+                &ctx.accounts.fuel_overflow.to_account_info(),
+            )?)
+        } else {
+            None
+        };
+
+        ctx.accounts
+            .vault
+            .load_mut()?
+            .update_cumulative_fuel_per_share(now, &user_stats, &fuel_overflow)?;
+
+        has_fuel_overflow
+    };
+
+    if !has_fuel_overflow {
+        ctx.drift_initialize_fuel_overflow()?;
+        msg!("initialized vault fuel overflow");
+    }
+
+    ctx.drift_sweep_fuel()?;
+
+    let user_stats = ctx.accounts.drift_user_stats.load()?;
+    let fuel_overflow = Some(AccountLoader::<FuelOverflow>::try_from(
+        &ctx.accounts.fuel_overflow.to_account_info(),
+    )?);
+    let cumulative_fuel_per_share = ctx
+        .accounts
+        .vault
+        .load_mut()?
+        .update_cumulative_fuel_per_share(now, &user_stats, &fuel_overflow)?;
+
+    msg!(
+        "swept vault fuel, cumulative_fuel_per_share: {}",
+        cumulative_fuel_per_share
+    );
+
+    Ok(())
+}
+
+fn is_fuel_near_overflow(user_stats: &UserStats) -> bool {
+    [
+        user_stats.fuel_insurance,
+        user_stats.fuel_deposits,
+        user_stats.fuel_borrows,
+        user_stats.fuel_positions,
+        user_stats.fuel_taker,
+        user_stats.fuel_maker,
+    ]
+    .iter()
+    .any(|fuel| *fuel >= FUEL_OVERFLOW_SWEEP_THRESHOLD)
+}
+
+#[derive(Accounts)]
+pub struct SweepVaultFuel<'info> {
+    #[account(mut)]
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        mut,
+        constraint = is_user_stats_for_vault(&vault, &drift_user_stats.key())?
+    )]
+    /// CHECK: checked in drift cpi
+    pub drift_user_stats: AccountLoader<'info, UserStats>,
+    #[account(
+        mut,
+        seeds = [b"fuel_overflow", vault.key().as_ref()],
+        seeds::program = drift_program.key(),
+        bump,
+    )]
+    /// CHECK: created and checked in drift cpi
+    pub fuel_overflow: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub drift_program: Program<'info, Drift>,
+    pub rent: Sysvar<'info, Rent>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> FuelOverflowCPI for Context<'_, '_, '_, 'info, SweepVaultFuel<'info>> {
+    fn drift_initialize_fuel_overflow(&self) -> Result<()> {
+        let cpi_program = self.accounts.drift_program.to_account_info().clone();
+        let cpi_accounts = InitializeFuelOverflow {
+            fuel_overflow: self.accounts.fuel_overflow.to_account_info().clone(),
+            user_stats: self.accounts.drift_user_stats.to_account_info().clone(),
+            authority: self.accounts.vault.to_account_info().clone(),
+            payer: self.accounts.payer.to_account_info().clone(),
+            rent: self.accounts.rent.to_account_info().clone(),
+            system_program: self.accounts.system_program.to_account_info().clone(),
+        };
+        let cpi_context = CpiContext::new(cpi_program, cpi_accounts);
+        drift::cpi::initialize_fuel_overflow(cpi_context)?;
+
+        Ok(())
+    }
+
+    fn drift_sweep_fuel(&self) -> Result<()> {
+        let cpi_program = self.accounts.drift_program.to_account_info().clone();
+        let cpi_accounts = SweepFuel {
+            fuel_overflow: self.accounts.fuel_overflow.to_account_info().clone(),
+            user_stats: self.accounts.drift_user_stats.to_account_info().clone(),
+            authority: self.accounts.vault.to_account_info().clone(),
+            signer: self.accounts.payer.to_account_info().clone(),
+        };
+        let cpi_context = CpiContext::new(cpi_program, cpi_accounts);
+        drift::cpi::sweep_fuel(cpi_context)?;
+
+        Ok(())
+    }
+}
@@ -1,17 +1,23 @@
 use crate::constraints::{
     is_authority_for_vault_depositor, is_mint_for_tokenized_depositor,
-    is_tokenized_depositor_for_vault, is_user_for_vault,
+    is_tokenized_depositor_for_vault, is_user_for_vault, is_user_stats_for_vault,
 };
 use crate::error::ErrorCode;
 use crate::state::traits::VaultDepositorBase;
+use crate::state::VaultDepositorLoader;
 use crate::token_cpi::MintTokensCPI;
-use crate::{validate, AccountMapProvider};
-use crate::{TokenizedVaultDepositor, Vault, VaultDepositor, VaultProtocolProvider, WithdrawUnit};
+use crate::{
+    validate, AccountMapProvider, FuelOverflowProvider, OmnibusLedgerProvider, VaultConfigProvider,
+};
+use crate::{
+    TokenizedVaultDepositor, Vault, VaultDepositor, VaultDepositorWithdrawRequestsProvider,
+    VaultFeature, VaultProtocolProvider, WithdrawUnit,
+};
 use anchor_lang::prelude::*;
 use anchor_spl::token::{mint_to, Mint, MintTo, Token, TokenAccount};
 use drift::instructions::optional_accounts::AccountMaps;
 use drift::math::safe_math::SafeMath;
-use drift::state::user::User;
+use drift::state::user::{FuelOverflowStatus, User, UserStats};
 
 use super::constraints::is_vault_shares_base_for_tokenized_depositor;
 
@@ -23,12 +29,15 @@ pub fn tokenize_shares<'info>(
     let clock = &Clock::get()?;
 
     let mut vault = ctx.accounts.vault.load_mut()?;
+    vault.validate_feature_enabled(VaultFeature::Tokenization)?;
 
     validate!(!vault.in_liquidation(), ErrorCode::OngoingLiquidation)?;
 
-    let mut vault_depositor = ctx.accounts.vault_depositor.load_mut()?;
+    let mut vault_depositor = ctx.accounts.vault_depositor.load_mut_migrated()?;
     let mut tokenized_vault_depositor = ctx.accounts.tokenized_vault_depositor.load_mut()?;
 
+    let vault_config = ctx.vault_config(&vault)?;
+
     // backwards compatible: if last rem acct does not deserialize into [`VaultProtocol`] then it's a legacy vault.
     let mut vp = ctx.vault_protocol();
     vault.validate_vault_protocol(&vp)?;
@@ -37,7 +46,7 @@ pub fn tokenize_shares<'info>(
     validate!(
         vault.shares_base == tokenized_vault_depositor.vault_shares_base,
         ErrorCode::InvalidVaultRebase,
-        "Vault has rebased, can no longer tokenize shares. Only redeem_tokens() is allowed. (shares base: {:?} vs. {:?})",
+        "Vault has rebased, call apply_rebase_tokenized_depositor() before tokenizing shares. (shares base: {:?} vs. {:?})",
         vault.shares_base,
         tokenized_vault_depositor.vault_shares_base
     )?;
@@ -47,12 +56,23 @@ pub fn tokenize_shares<'info>(
         .safe_add(tokenized_vault_depositor.get_vault_shares())?;
 
     let user = ctx.accounts.drift_user.load()?;
+
+    let user_stats = ctx.accounts.drift_user_stats.load()?;
+    let has_fuel_overflow = FuelOverflowStatus::exists(user_stats.fuel_overflow_status);
+    let fuel_overflow = ctx.fuel_overflow(vp.is_some(), has_fuel_overflow);
+    user_stats.validate_fuel_overflow(&fuel_overflow)?;
+
     let spot_market_index = vault.spot_market_index;
     let AccountMaps {
         perp_market_map,
         spot_market_map,
         mut oracle_map,
-    } = ctx.load_maps(clock.slot, Some(spot_market_index), vp.is_some(), false)?;
+    } = ctx.load_maps(
+        clock.slot,
+        Some(spot_market_index),
+        vp.is_some(),
+        has_fuel_overflow,
+    )?;
 
     let vault_equity =
         vault.calculate_equity(&user, &perp_market_map, &spot_market_map, &mut oracle_map)?;
@@ -68,19 +88,30 @@ pub fn tokenize_shares<'info>(
     let spot_market = spot_market_map.get_ref(&spot_market_index)?;
     let oracle = oracle_map.get_price_data(&spot_market.oracle_id())?;
 
-    let (shares_transferred, _) = vault_depositor.transfer_shares(
-        &mut *tokenized_vault_depositor,
-        &mut vault,
-        &mut vp,
-        amount,
-        unit,
-        vault_equity,
+    let (shares_transferred, _) = vault_depositor.with_fuel_sync(
         clock.unix_timestamp,
-        oracle.price,
+        &mut vault,
+        &vault_config,
+        &user_stats,
+        &fuel_overflow,
+        |vd, vault| {
+            vd.transfer_shares(
+                &mut *tokenized_vault_depositor,
+                vault,
+                &vault_config,
+                &mut vp,
+                amount,
+                unit,
+                vault_equity,
+                clock.unix_timestamp,
+                oracle.price,
+            )
+        },
     )?;
 
     let tokens_to_mint = tokenized_vault_depositor.tokenize_shares(
         &mut vault,
+        &vault_config,
         &mut vp,
         total_supply_before,
         vault_equity,
@@ -99,6 +130,25 @@ pub fn tokenize_shares<'info>(
         "Total vault depositor shares before != after"
     )?;
 
+    if let Some(omnibus_ledger) = ctx.omnibus_ledger(&vault_depositor)? {
+        omnibus_ledger.load()?.validate_request(&vault_depositor)?;
+    }
+
+    if let Some(withdraw_requests) = ctx.vault_depositor_withdraw_requests(&vault_depositor)? {
+        withdraw_requests
+            .load()?
+            .validate_request(&vault_depositor)?;
+    }
+
+    tokenized_vault_depositor.emit_exchange_rate_record(
+        &vault,
+        vault_equity,
+        total_supply_before.safe_add(tokens_to_mint)?,
+        ctx.accounts.mint.decimals,
+        spot_market.decimals,
+        clock.unix_timestamp,
+    )?;
+
     let vault_name = vault.name;
     let vault_bump = vault.bump;
 
@@ -154,10 +204,9 @@ pub struct TokenizeShares<'info> {
 		constraint = is_vault_shares_base_for_tokenized_depositor(&vault.load()?.shares_base, &tokenized_vault_depositor)?,
 	)]
     pub tokenized_vault_depositor: AccountLoader<'info, TokenizedVaultDepositor>,
+    /// The mint created at the tokenized depositor's initial shares base, it carries on across rebases
     #[account(
         mut,
-        seeds = [b"mint", vault.key().as_ref(), vault.load()?.shares_base.to_string().as_bytes()],
-        bump,
         mint::authority = vault.key(),
 		constraint = is_mint_for_tokenized_depositor(&mint.key(), &tokenized_vault_depositor)?,
     )]
@@ -173,6 +222,11 @@ pub struct TokenizeShares<'info> {
     )]
     /// CHECK: checked in drift cpi
     pub drift_user: AccountLoader<'info, User>,
+    #[account(
+        constraint = is_user_stats_for_vault(&vault, &drift_user_stats.key())?
+    )]
+    /// CHECK: checked in drift cpi
+    pub drift_user_stats: AccountLoader<'info, UserStats>,
     pub token_program: Program<'info, Token>,
 }
 
@@ -5,7 +5,9 @@ use crate::constraints::{
     is_delegate_for_vault, is_manager_for_vault, is_user_stats_for_vault,
     is_vault_for_vault_depositor,
 };
-use crate::state::{FuelOverflowProvider, Vault, VaultProtocolProvider};
+use crate::state::{
+    FuelOverflowProvider, Vault, VaultConfigProvider, VaultDepositorLoader, VaultProtocolProvider,
+};
 use crate::VaultDepositor;
 
 use super::constraints::is_authority_for_vault_depositor;
@@ -16,8 +18,9 @@ pub fn update_cumulative_fuel_amount<'c: 'info, 'info>(
     let clock = &Clock::get()?;
 
     let mut vault = ctx.accounts.vault.load_mut()?;
-    let mut vault_depositor = ctx.accounts.vault_depositor.load_mut()?;
+    let mut vault_depositor = ctx.accounts.vault_depositor.load_mut_migrated()?;
 
+    let vault_config = ctx.vault_config(&vault)?;
     // backwards compatible: if last rem acct does not deserialize into [`VaultProtocol`] then it's a legacy vault.
     let mut vp = ctx.vault_protocol();
     vault.validate_vault_protocol(&vp)?;
@@ -31,6 +34,7 @@ pub fn update_cumulative_fuel_amount<'c: 'info, 'info>(
     let fuel_amount = vault_depositor.update_cumulative_fuel_amount(
         clock.unix_timestamp,
         &mut vault,
+        &vault_config,
         &user_stats,
         &fuel_overflow,
     )?;
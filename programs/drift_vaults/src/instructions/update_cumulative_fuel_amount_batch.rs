@@ -0,0 +1,91 @@
+use anchor_lang::prelude::*;
+use drift::state::user::{FuelOverflowStatus, UserStats};
+
+use crate::constraints::{is_delegate_for_vault, is_manager_for_vault, is_user_stats_for_vault};
+use crate::error::ErrorCode;
+use crate::state::{
+    settle_fuel_season_batch, FuelOverflowProvider, Vault, VaultConfigProvider, VaultDepositor,
+    VaultDepositorLoader, VaultProtocolProvider,
+};
+use crate::validate;
+
+/// [`update_cumulative_fuel_amount`](super::update_cumulative_fuel_amount) for every writable [`VaultDepositor`] in
+/// remaining_accounts. If drift reset the vault's [`FuelOverflow`](drift::state::user::FuelOverflow) since the last
+/// update, every depositor passed is settled into the old fuel season before the vault starts the new one. Run
+/// ahead of drift's `reset_fuel_season` in the same transaction it also settles the vault's own fuel.
+pub fn update_cumulative_fuel_amount_batch<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, UpdateCumulativeFuelAmountBatch<'info>>,
+) -> Result<()> {
+    let clock = &Clock::get()?;
+
+    let mut vault = ctx.accounts.vault.load_mut()?;
+
+    let vault_depositors: Vec<AccountLoader<'info, VaultDepositor>> = ctx
+        .remaining_accounts
+        .iter()
+        .filter(|acct| acct.is_writable)
+        .filter_map(|acct| AccountLoader::<'info, VaultDepositor>::try_from(acct).ok())
+        .collect();
+
+    validate!(
+        !vault_depositors.is_empty(),
+        ErrorCode::InvalidVaultDepositorBatch,
+        "no vault depositors in remaining accounts"
+    )?;
+
+    let vault_config = ctx.vault_config(&vault)?;
+    // backwards compatible: if last rem acct does not deserialize into [`VaultProtocol`] then it's a legacy vault.
+    let mut vp = ctx.vault_protocol();
+    vault.validate_vault_protocol(&vp)?;
+    let vp = vp.as_mut().map(|vp| vp.load_mut()).transpose()?;
+
+    let user_stats = ctx.accounts.drift_user_stats.load()?;
+    let has_fuel_overflow = FuelOverflowStatus::exists(user_stats.fuel_overflow_status);
+    let fuel_overflow = ctx.fuel_overflow(vp.is_some(), has_fuel_overflow);
+    user_stats.validate_fuel_overflow(&fuel_overflow)?;
+
+    settle_fuel_season_batch(
+        &vault,
+        &vault_config,
+        &vault_depositors,
+        &fuel_overflow,
+        clock.unix_timestamp,
+    )?;
+
+    for vault_depositor in vault_depositors.iter() {
+        let mut vault_depositor = vault_depositor.load_mut_migrated()?;
+        validate!(
+            vault_depositor.vault == vault.pubkey,
+            ErrorCode::InvalidVaultDepositorBatch,
+            "vault depositor {} is not for vault {}",
+            vault_depositor.pubkey,
+            vault.pubkey
+        )?;
+
+        let fuel_amount = vault_depositor.update_cumulative_fuel_amount(
+            clock.unix_timestamp,
+            &mut vault,
+            &vault_config,
+            &user_stats,
+            &fuel_overflow,
+        )?;
+
+        msg!("vd {} fuel_amount: {}", vault_depositor.pubkey, fuel_amount);
+    }
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdateCumulativeFuelAmountBatch<'info> {
+    #[account(
+        mut,
+        constraint = is_manager_for_vault(&vault, &signer)? || is_delegate_for_vault(&vault, &signer)?
+    )]
+    pub vault: AccountLoader<'info, Vault>,
+    pub signer: Signer<'info>,
+    #[account(
+        constraint = is_user_stats_for_vault(&vault, &drift_user_stats.key())?
+    )]
+    pub drift_user_stats: AccountLoader<'info, UserStats>,
+}
@@ -5,22 +5,44 @@ use drift::state::user::User;
 
 use crate::constraints::{is_manager_for_vault, is_user_for_vault};
 use crate::drift_cpi::UpdateUserDelegateCPI;
-use crate::Vault;
+use crate::error::ErrorCode;
+use crate::state::{
+    record_vault_param_change, VaultManagerActivityProvider, VaultParam, VaultParamChangeLog,
+    VaultParamChangeLogProvider, VaultProtocolFlag, VaultProtocolProvider,
+};
 use crate::{declare_vault_seeds, implement_update_user_delegate_cpi};
+use crate::{validate, Vault};
 
-pub fn update_delegate<'info>(
-    ctx: Context<'_, '_, '_, 'info, UpdateDelegate<'info>>,
+pub fn update_delegate<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, UpdateDelegate<'info>>,
     delegate: Pubkey,
 ) -> Result<()> {
     let mut vault = ctx.accounts.vault.load_mut()?;
+    let now = Clock::get()?.unix_timestamp;
+    let param_change_log = ctx.vault_param_change_log();
+    vault.validate_param_change_log(&param_change_log)?;
 
-    if vault.in_liquidation() {
-        let now = Clock::get()?.unix_timestamp;
-        vault.check_can_exit_liquidation(now)?;
-        vault.reset_liquidation_delegate();
+    if let Some(vault_manager_activity) = ctx.vault_manager_activity(&vault.pubkey) {
+        vault_manager_activity.load_mut()?.record_activity(now);
     }
 
-    vault.delegate = delegate;
+    let vp = ctx.vault_protocol();
+    vault.validate_vault_protocol(&vp)?;
+    if let Some(vp) = vp {
+        validate!(
+            !vp.load()?.has_flag(VaultProtocolFlag::DelegateDualApproval),
+            ErrorCode::DelegateApprovalRequired,
+            "delegate changes must be proposed and approved by the protocol"
+        )?;
+    }
+
+    set_vault_delegate(
+        &mut vault,
+        &param_change_log,
+        ctx.accounts.manager.key(),
+        delegate,
+        now,
+    )?;
 
     drop(vault);
 
@@ -29,6 +51,34 @@ pub fn update_delegate<'info>(
     Ok(())
 }
 
+/// Sets the vault's delegate, exiting liquidation if the vault is in one. The caller updates the drift user's
+/// delegate.
+pub fn set_vault_delegate(
+    vault: &mut Vault,
+    param_change_log: &Option<AccountLoader<VaultParamChangeLog>>,
+    signer: Pubkey,
+    delegate: Pubkey,
+    now: i64,
+) -> Result<()> {
+    if vault.in_liquidation() {
+        vault.check_can_exit_liquidation(now)?;
+        vault.reset_liquidation_delegate();
+    }
+
+    record_vault_param_change(
+        vault,
+        param_change_log,
+        signer,
+        VaultParam::Delegate,
+        vault.delegate,
+        delegate,
+        now,
+    )?;
+    vault.delegate = delegate;
+
+    Ok(())
+}
+
 #[derive(Accounts)]
 pub struct UpdateDelegate<'info> {
     #[account(
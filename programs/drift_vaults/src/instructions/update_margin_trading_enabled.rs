@@ -6,6 +6,7 @@ use drift::state::user::User;
 use crate::constraints::{is_manager_for_vault, is_user_for_vault};
 use crate::drift_cpi::UpdateUserMarginTradingEnabledCPI;
 use crate::error::ErrorCode;
+use crate::state::{record_vault_param_change, VaultParam, VaultParamChangeLogProvider};
 use crate::Vault;
 use crate::{declare_vault_seeds, validate};
 
@@ -13,10 +14,23 @@ pub fn update_margin_trading_enabled<'info>(
     ctx: Context<'_, '_, '_, 'info, UpdateMarginTradingEnabled<'info>>,
     enabled: bool,
 ) -> Result<()> {
-    validate!(
-        !ctx.accounts.vault.load()?.in_liquidation(),
-        ErrorCode::OngoingLiquidation
+    let vault = ctx.accounts.vault.load()?;
+    validate!(!vault.in_liquidation(), ErrorCode::OngoingLiquidation)?;
+
+    let now = Clock::get()?.unix_timestamp;
+    let param_change_log = ctx.vault_param_change_log();
+    vault.validate_param_change_log(&param_change_log)?;
+
+    record_vault_param_change(
+        &vault,
+        &param_change_log,
+        ctx.accounts.manager.key(),
+        VaultParam::MarginTradingEnabled,
+        ctx.accounts.drift_user.load()?.is_margin_trading_enabled,
+        enabled,
+        now,
     )?;
+    drop(vault);
 
     ctx.drift_update_user_margin_trading_enabled(enabled)?;
 
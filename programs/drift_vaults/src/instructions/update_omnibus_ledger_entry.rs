@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+
+use crate::constraints::is_authority_for_vault_depositor;
+use crate::state::{OmnibusLedger, OmnibusLedgerPage, VaultDepositor, VaultDepositorLoader};
+
+/// Allocates `shares` of the omnibus depositor's position to `beneficiary`, replacing the page entry. The total
+/// allocated can't exceed the depositor's shares not requested for withdraw.
+pub fn update_omnibus_ledger_entry<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, UpdateOmnibusLedgerEntry<'info>>,
+    entry_index: u8,
+    beneficiary: Pubkey,
+    shares: u128,
+) -> Result<()> {
+    let vault_depositor = ctx.accounts.vault_depositor.load_checked()?;
+    let mut omnibus_ledger = ctx.accounts.omnibus_ledger.load_mut()?;
+    let mut page = ctx.accounts.omnibus_ledger_page.load_mut()?;
+
+    omnibus_ledger.update_entry(
+        &mut page,
+        &vault_depositor,
+        entry_index as usize,
+        beneficiary,
+        shares,
+    )?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdateOmnibusLedgerEntry<'info> {
+    #[account(
+        constraint = is_authority_for_vault_depositor(&vault_depositor, &authority)?
+    )]
+    pub vault_depositor: AccountLoader<'info, VaultDepositor>,
+    #[account(
+        mut,
+        seeds = [b"omnibus_ledger", vault_depositor.key().as_ref()],
+        bump = omnibus_ledger.load()?.bump,
+    )]
+    pub omnibus_ledger: AccountLoader<'info, OmnibusLedger>,
+    #[account(
+        mut,
+        constraint = omnibus_ledger_page.load()?.vault_depositor == vault_depositor.key()
+    )]
+    pub omnibus_ledger_page: AccountLoader<'info, OmnibusLedgerPage>,
+    pub authority: Signer<'info>,
+}
@@ -6,12 +6,29 @@ use drift::state::user::User;
 use crate::constraints::{is_manager_for_vault, is_user_for_vault};
 use crate::declare_vault_seeds;
 use crate::drift_cpi::UpdatePoolIdCPI;
+use crate::state::{record_vault_param_change, VaultParam, VaultParamChangeLogProvider};
 use crate::Vault;
 
 pub fn update_pool_id<'info>(
     ctx: Context<'_, '_, '_, 'info, UpdatePoolId<'info>>,
     pool_id: u8,
 ) -> Result<()> {
+    let vault = ctx.accounts.vault.load()?;
+    let now = Clock::get()?.unix_timestamp;
+    let param_change_log = ctx.vault_param_change_log();
+    vault.validate_param_change_log(&param_change_log)?;
+
+    record_vault_param_change(
+        &vault,
+        &param_change_log,
+        ctx.accounts.manager.key(),
+        VaultParam::PoolId,
+        ctx.accounts.drift_user.load()?.pool_id,
+        pool_id,
+        now,
+    )?;
+    drop(vault);
+
     ctx.drift_update_pool_id(pool_id)?;
 
     Ok(())
@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+
+use crate::state::ProtocolTvlCap;
+
+/// Lowering `max_tvl` below the current total doesn't touch existing deposits, it only blocks new ones.
+pub fn update_protocol_tvl_cap<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, UpdateProtocolTvlCap<'info>>,
+    max_tvl: u64,
+) -> Result<()> {
+    let mut protocol_tvl_cap = ctx.accounts.protocol_tvl_cap.load_mut()?;
+
+    msg!(
+        "protocol tvl cap: {} -> {}",
+        protocol_tvl_cap.max_tvl,
+        max_tvl
+    );
+    protocol_tvl_cap.max_tvl = max_tvl;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdateProtocolTvlCap<'info> {
+    #[account(
+        mut,
+        seeds = [b"protocol_tvl_cap", protocol.key().as_ref()],
+        bump = protocol_tvl_cap.load()?.bump,
+        has_one = protocol,
+    )]
+    pub protocol_tvl_cap: AccountLoader<'info, ProtocolTvlCap>,
+    pub protocol: Signer<'info>,
+}
@@ -1,4 +1,8 @@
 use crate::constraints::is_manager_for_vault;
+use crate::state::{
+    record_vault_param_change, VaultManagerActivityProvider, VaultParam,
+    VaultParamChangeLogProvider,
+};
 use crate::{error::ErrorCode, validate, Vault};
 use anchor_lang::prelude::*;
 
@@ -6,24 +10,60 @@ pub fn update_vault<'info>(
     ctx: Context<'_, '_, '_, 'info, UpdateVault<'info>>,
     params: UpdateVaultParams,
 ) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let manager = ctx.accounts.manager.key();
     let mut vault = ctx.accounts.vault.load_mut()?;
 
     validate!(!vault.in_liquidation(), ErrorCode::OngoingLiquidation)?;
 
+    let param_change_log = ctx.vault_param_change_log();
+    vault.validate_param_change_log(&param_change_log)?;
+
+    if let Some(vault_manager_activity) = ctx.vault_manager_activity(&vault.pubkey) {
+        vault_manager_activity.load_mut()?.record_activity(now);
+    }
+
     if let Some(redeem_period) = params.redeem_period {
         validate!(
             redeem_period < vault.redeem_period,
             ErrorCode::InvalidVaultUpdate,
             "new redeem period must be shorter than existing redeem period"
         )?;
+        record_vault_param_change(
+            &vault,
+            &param_change_log,
+            manager,
+            VaultParam::RedeemPeriod,
+            vault.redeem_period,
+            redeem_period,
+            now,
+        )?;
         vault.redeem_period = redeem_period;
     }
 
     if let Some(max_tokens) = params.max_tokens {
+        record_vault_param_change(
+            &vault,
+            &param_change_log,
+            manager,
+            VaultParam::MaxTokens,
+            vault.max_tokens,
+            max_tokens,
+            now,
+        )?;
         vault.max_tokens = max_tokens;
     }
 
     if let Some(min_deposit_amount) = params.min_deposit_amount {
+        record_vault_param_change(
+            &vault,
+            &param_change_log,
+            manager,
+            VaultParam::MinDepositAmount,
+            vault.min_deposit_amount,
+            min_deposit_amount,
+            now,
+        )?;
         vault.min_deposit_amount = min_deposit_amount;
     }
 
@@ -33,6 +73,15 @@ pub fn update_vault<'info>(
             ErrorCode::InvalidVaultUpdate,
             "new management fee must be less than existing management fee"
         )?;
+        record_vault_param_change(
+            &vault,
+            &param_change_log,
+            manager,
+            VaultParam::ManagementFee,
+            vault.management_fee,
+            management_fee,
+            now,
+        )?;
         vault.management_fee = management_fee;
     }
 
@@ -42,6 +91,15 @@ pub fn update_vault<'info>(
             ErrorCode::InvalidVaultUpdate,
             "new profit share must be less than existing profit share"
         )?;
+        record_vault_param_change(
+            &vault,
+            &param_change_log,
+            manager,
+            VaultParam::ProfitShare,
+            vault.profit_share,
+            profit_share,
+            now,
+        )?;
         vault.profit_share = profit_share;
     }
 
@@ -51,10 +109,28 @@ pub fn update_vault<'info>(
             ErrorCode::InvalidVaultUpdate,
             "new hurdle rate must be greater than existing hurdle rate"
         )?;
+        record_vault_param_change(
+            &vault,
+            &param_change_log,
+            manager,
+            VaultParam::HurdleRate,
+            vault.hurdle_rate,
+            hurdle_rate,
+            now,
+        )?;
         vault.hurdle_rate = hurdle_rate;
     }
 
     if let Some(permissioned) = params.permissioned {
+        record_vault_param_change(
+            &vault,
+            &param_change_log,
+            manager,
+            VaultParam::Permissioned,
+            vault.permissioned,
+            permissioned,
+            now,
+        )?;
         vault.permissioned = permissioned;
     }
 
@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+
+use crate::constraints::is_manager_for_vault;
+use crate::state::{Vault, VaultAuditor};
+
+/// Replaces the vault's auditor, `Pubkey::default()` revokes it.
+pub fn update_vault_auditor<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, UpdateVaultAuditor<'info>>,
+    auditor: Pubkey,
+) -> Result<()> {
+    let mut vault_auditor = ctx.accounts.vault_auditor.load_mut()?;
+
+    msg!(
+        "Updating vault auditor {} -> {}",
+        vault_auditor.auditor,
+        auditor
+    );
+    vault_auditor.auditor = auditor;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdateVaultAuditor<'info> {
+    #[account(
+        constraint = is_manager_for_vault(&vault, &manager)?
+    )]
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        mut,
+        seeds = [b"vault_auditor", vault.key().as_ref()],
+        bump = vault_auditor.load()?.bump,
+    )]
+    pub vault_auditor: AccountLoader<'info, VaultAuditor>,
+    pub manager: Signer<'info>,
+}
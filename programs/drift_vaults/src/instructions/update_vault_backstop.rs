@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+
+use crate::constraints::is_manager_for_vault;
+use crate::state::{Vault, VaultBackstop};
+
+/// Changes the contribution rate and deductible. Fees collected before the change contribute at the old rate.
+pub fn update_vault_backstop<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, UpdateVaultBackstop<'info>>,
+    contribution_rate: u32,
+    deductible: u32,
+) -> Result<()> {
+    let vault = ctx.accounts.vault.load()?;
+    let mut vault_backstop = ctx.accounts.vault_backstop.load_mut()?;
+
+    msg!(
+        "Updating vault backstop contribution_rate {} -> {}, deductible {} -> {}",
+        vault_backstop.contribution_rate,
+        contribution_rate,
+        vault_backstop.deductible,
+        deductible
+    );
+    vault_backstop.update(&vault, contribution_rate, deductible)?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdateVaultBackstop<'info> {
+    #[account(
+        constraint = is_manager_for_vault(&vault, &manager)?
+    )]
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        mut,
+        seeds = [b"vault_backstop", vault.key().as_ref()],
+        bump = vault_backstop.load()?.bump,
+    )]
+    pub vault_backstop: AccountLoader<'info, VaultBackstop>,
+    pub manager: Signer<'info>,
+}
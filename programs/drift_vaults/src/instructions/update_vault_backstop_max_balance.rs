@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+use crate::constraints::{is_protocol_for_vault, is_vault_protocol_for_vault};
+use crate::state::{Vault, VaultBackstop, VaultProtocol};
+
+/// Caps the [`VaultBackstop`] balance. Only the vault's protocol can set it, so vaults without a
+/// [`VaultProtocol`] can't fund a backstop.
+pub fn update_vault_backstop_max_balance<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, UpdateVaultBackstopMaxBalance<'info>>,
+    max_balance: u64,
+) -> Result<()> {
+    let vault = ctx.accounts.vault.load()?;
+    let mut vault_backstop = ctx.accounts.vault_backstop.load_mut()?;
+
+    msg!(
+        "Updating vault backstop max_balance {} -> {}",
+        vault_backstop.max_balance,
+        max_balance
+    );
+    vault_backstop.update_max_balance(&vault, max_balance)?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdateVaultBackstopMaxBalance<'info> {
+    #[account(
+        constraint = is_protocol_for_vault(&vault, &vault_protocol, &protocol)?
+    )]
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        constraint = is_vault_protocol_for_vault(&vault_protocol, &vault)?
+    )]
+    pub vault_protocol: AccountLoader<'info, VaultProtocol>,
+    #[account(
+        mut,
+        seeds = [b"vault_backstop", vault.key().as_ref()],
+        bump = vault_backstop.load()?.bump,
+    )]
+    pub vault_backstop: AccountLoader<'info, VaultBackstop>,
+    pub protocol: Signer<'info>,
+}
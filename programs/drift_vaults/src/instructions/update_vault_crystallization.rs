@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+
+use crate::constraints::is_manager_for_vault;
+use crate::state::{Vault, VaultCrystallization};
+
+/// Lowers the crystallized profit share, like `update_vault` it can't be raised.
+pub fn update_vault_crystallization<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, UpdateVaultCrystallization<'info>>,
+    profit_share: u32,
+) -> Result<()> {
+    let mut vault_crystallization = ctx.accounts.vault_crystallization.load_mut()?;
+
+    msg!(
+        "Updating vault crystallization profit_share {} -> {}",
+        vault_crystallization.profit_share,
+        profit_share
+    );
+    vault_crystallization.update_profit_share(profit_share)?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdateVaultCrystallization<'info> {
+    #[account(
+        constraint = is_manager_for_vault(&vault, &manager)?
+    )]
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        mut,
+        seeds = [b"vault_crystallization", vault.key().as_ref()],
+        bump = vault_crystallization.load()?.bump,
+    )]
+    pub vault_crystallization: AccountLoader<'info, VaultCrystallization>,
+    pub manager: Signer<'info>,
+}
@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+
+use crate::constraints::{
+    is_manager_for_vault, is_protocol_for_vault, is_vault_protocol_for_vault,
+};
+use crate::state::{Vault, VaultDelegateProposal, VaultProtocol, VaultProtocolFlag};
+
+/// Co-signed by the manager and the protocol. The vault's [`VaultDelegateProposal`] must be initialized so delegate
+/// changes can still be approved.
+pub fn update_vault_delegate_dual_approval<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, UpdateVaultDelegateDualApproval<'info>>,
+    enabled: bool,
+) -> Result<()> {
+    let mut vp = ctx.accounts.vault_protocol.load_mut()?;
+
+    msg!(
+        "Updating vault delegate dual approval {} -> {}",
+        vp.has_flag(VaultProtocolFlag::DelegateDualApproval),
+        enabled
+    );
+
+    if enabled {
+        vp.add_flag(VaultProtocolFlag::DelegateDualApproval);
+    } else {
+        vp.remove_flag(VaultProtocolFlag::DelegateDualApproval);
+    }
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdateVaultDelegateDualApproval<'info> {
+    #[account(
+        constraint = is_manager_for_vault(&vault, &manager)?,
+        constraint = is_protocol_for_vault(&vault, &vault_protocol, &protocol)?
+    )]
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        mut,
+        constraint = is_vault_protocol_for_vault(&vault_protocol, &vault)?
+    )]
+    pub vault_protocol: AccountLoader<'info, VaultProtocol>,
+    #[account(
+        seeds = [b"vault_delegate_proposal", vault.key().as_ref()],
+        bump = vault_delegate_proposal.load()?.bump,
+    )]
+    pub vault_delegate_proposal: AccountLoader<'info, VaultDelegateProposal>,
+    pub manager: Signer<'info>,
+    pub protocol: Signer<'info>,
+}
@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{
+    record_vault_param_change, DepositValuation, VaultParam, VaultParamChangeLogProvider,
+};
+
+use super::UpdateVaultConfig;
+
+/// Sets the [`DepositValuation`] deposits are priced at. Withdraws are always priced at full marks.
+pub fn update_vault_deposit_valuation<'info>(
+    ctx: Context<'_, '_, '_, 'info, UpdateVaultConfig<'info>>,
+    deposit_valuation: u8,
+) -> Result<()> {
+    let vault = ctx.accounts.vault.load()?;
+    let mut vault_config = ctx.accounts.vault_config.load_mut()?;
+    let now = Clock::get()?.unix_timestamp;
+    let param_change_log = ctx.vault_param_change_log();
+    vault.validate_param_change_log(&param_change_log)?;
+
+    let new_valuation = DepositValuation::try_from(deposit_valuation)?;
+    let current_valuation = vault_config.deposit_valuation()?;
+
+    msg!(
+        "Updating vault deposit valuation {:?} -> {:?}",
+        current_valuation,
+        new_valuation
+    );
+    record_vault_param_change(
+        &vault,
+        &param_change_log,
+        ctx.accounts.manager.key(),
+        VaultParam::DepositValuation,
+        current_valuation as u8,
+        deposit_valuation,
+        now,
+    )?;
+    vault_config.update_deposit_valuation(new_valuation);
+
+    Ok(())
+}
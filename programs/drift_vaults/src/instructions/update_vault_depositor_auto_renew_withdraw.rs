@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+
+use crate::constraints::{
+    is_authority_or_custodian_for_vault_depositor, is_vault_for_vault_depositor,
+};
+use crate::state::{Vault, VaultDepositor, VaultDepositorFlag, VaultDepositorLoader};
+
+/// Opts the vault depositor in or out of keepers [renewing](super::renew_withdraw_request) its expired withdraw
+/// requests at current share pricing.
+pub fn update_vault_depositor_auto_renew_withdraw<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, UpdateVaultDepositorAutoRenewWithdraw<'info>>,
+    enabled: bool,
+) -> Result<()> {
+    let mut vault_depositor = ctx.accounts.vault_depositor.load_mut_migrated()?;
+
+    if enabled {
+        vault_depositor.add_flag(VaultDepositorFlag::AutoRenewWithdraw);
+    } else {
+        vault_depositor.remove_flag(VaultDepositorFlag::AutoRenewWithdraw);
+    }
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdateVaultDepositorAutoRenewWithdraw<'info> {
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        mut,
+        constraint = is_vault_for_vault_depositor(&vault_depositor, &vault)?,
+        constraint = is_authority_or_custodian_for_vault_depositor(&vault_depositor, &authority)?
+    )]
+    pub vault_depositor: AccountLoader<'info, VaultDepositor>,
+    /// the depositor authority or its custodian
+    pub authority: Signer<'info>,
+}
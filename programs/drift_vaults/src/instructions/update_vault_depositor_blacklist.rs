@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+
+use crate::constraints::{is_manager_for_vault, is_vault_for_vault_depositor};
+use crate::state::{Vault, VaultDepositor, VaultDepositorFlag, VaultDepositorLoader};
+
+/// Blocks or unblocks new deposits from the vault depositor's authority. Blacklisted depositors can still request,
+/// cancel and execute withdraws of their existing shares.
+pub fn update_vault_depositor_blacklist<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, UpdateVaultDepositorBlacklist<'info>>,
+    blacklisted: bool,
+) -> Result<()> {
+    let mut vault_depositor = ctx.accounts.vault_depositor.load_mut_migrated()?;
+
+    if blacklisted {
+        vault_depositor.add_flag(VaultDepositorFlag::Blacklisted);
+    } else {
+        vault_depositor.remove_flag(VaultDepositorFlag::Blacklisted);
+    }
+
+    msg!(
+        "vault depositor {} blacklisted: {}",
+        ctx.accounts.vault_depositor.key(),
+        blacklisted
+    );
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdateVaultDepositorBlacklist<'info> {
+    #[account(
+        constraint = is_manager_for_vault(&vault, &manager)?
+    )]
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        mut,
+        constraint = is_vault_for_vault_depositor(&vault_depositor, &vault)?
+    )]
+    pub vault_depositor: AccountLoader<'info, VaultDepositor>,
+    pub manager: Signer<'info>,
+}
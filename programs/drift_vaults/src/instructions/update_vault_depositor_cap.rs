@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+
+use crate::constraints::is_manager_for_vault;
+use crate::state::{Vault, VaultDepositorCap};
+
+/// Lowering the cap below the current depositor count only blocks new vault depositors.
+pub fn update_vault_depositor_cap<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, UpdateVaultDepositorCap<'info>>,
+    max_depositors: u32,
+) -> Result<()> {
+    VaultDepositorCap::validate_max_depositors(max_depositors)?;
+
+    let mut vault_depositor_cap = ctx.accounts.vault_depositor_cap.load_mut()?;
+    vault_depositor_cap.max_depositors = max_depositors;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdateVaultDepositorCap<'info> {
+    #[account(
+        constraint = is_manager_for_vault(&vault, &manager)?
+    )]
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        mut,
+        seeds = [b"vault_depositor_cap", vault.key().as_ref()],
+        bump = vault_depositor_cap.load()?.bump,
+    )]
+    pub vault_depositor_cap: AccountLoader<'info, VaultDepositorCap>,
+    pub manager: Signer<'info>,
+}
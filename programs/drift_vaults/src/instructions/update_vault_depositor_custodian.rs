@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+
+use crate::constraints::{is_authority_for_vault_depositor, is_vault_for_vault_depositor};
+use crate::error::ErrorCode;
+use crate::state::VaultDepositorLoader;
+use crate::validate;
+use crate::{Vault, VaultDepositor};
+
+/// Authorizes `custodian` to request and cancel withdraws for the vault depositor.
+pub fn update_vault_depositor_custodian<'info>(
+    ctx: Context<'_, '_, '_, 'info, UpdateVaultDepositorCustodian<'info>>,
+    custodian: Pubkey,
+) -> Result<()> {
+    let mut vault_depositor = ctx.accounts.vault_depositor.load_mut_migrated()?;
+
+    validate!(
+        custodian != Pubkey::default() && custodian != vault_depositor.authority,
+        ErrorCode::InvalidVaultDepositorCustodian,
+        "custodian must be set and differ from the authority"
+    )?;
+
+    vault_depositor.custodian = custodian;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdateVaultDepositorCustodian<'info> {
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        mut,
+        seeds = [b"vault_depositor", vault.key().as_ref(), authority.key().as_ref()],
+        bump,
+        constraint = is_vault_for_vault_depositor(&vault_depositor, &vault)?,
+        constraint = is_authority_for_vault_depositor(&vault_depositor, &authority)?,
+    )]
+    pub vault_depositor: AccountLoader<'info, VaultDepositor>,
+    pub authority: Signer<'info>,
+}
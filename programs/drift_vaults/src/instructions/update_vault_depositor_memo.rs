@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+
+use crate::constraints::{is_authority_for_vault_depositor, is_vault_for_vault_depositor};
+use crate::state::{Vault, VaultDepositor, VaultDepositorMemo};
+
+pub fn update_vault_depositor_memo<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, UpdateVaultDepositorMemo<'info>>,
+    memo: [u8; 32],
+) -> Result<()> {
+    let clock = &Clock::get()?;
+
+    let mut vault_depositor_memo = ctx.accounts.vault_depositor_memo.load_mut()?;
+    vault_depositor_memo.update(
+        ctx.accounts.vault.key(),
+        ctx.accounts.authority.key(),
+        memo,
+        clock.unix_timestamp,
+    );
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdateVaultDepositorMemo<'info> {
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        constraint = is_vault_for_vault_depositor(&vault_depositor, &vault)?,
+        constraint = is_authority_for_vault_depositor(&vault_depositor, &authority)?,
+    )]
+    pub vault_depositor: AccountLoader<'info, VaultDepositor>,
+    #[account(
+        mut,
+        seeds = [b"vault_depositor_memo", vault_depositor.key().as_ref()],
+        bump = vault_depositor_memo.load()?.bump,
+    )]
+    pub vault_depositor_memo: AccountLoader<'info, VaultDepositorMemo>,
+    pub authority: Signer<'info>,
+}
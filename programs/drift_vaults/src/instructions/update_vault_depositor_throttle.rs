@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+
+use crate::constraints::is_manager_for_vault;
+use crate::state::{Vault, VaultDepositorThrottle};
+
+/// Zero intervals leave that flow unthrottled, setting both lifts the throttle.
+pub fn update_vault_depositor_throttle<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, UpdateVaultDepositorThrottle<'info>>,
+    min_deposit_interval: u32,
+    min_request_interval: u32,
+) -> Result<()> {
+    let mut vault_depositor_throttle = ctx.accounts.vault_depositor_throttle.load_mut()?;
+
+    msg!(
+        "Updating vault depositor throttle min_deposit_interval {} -> {}, min_request_interval {} -> {}",
+        vault_depositor_throttle.min_deposit_interval,
+        min_deposit_interval,
+        vault_depositor_throttle.min_request_interval,
+        min_request_interval
+    );
+    vault_depositor_throttle.update_intervals(min_deposit_interval, min_request_interval)?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdateVaultDepositorThrottle<'info> {
+    #[account(
+        constraint = is_manager_for_vault(&vault, &manager)?
+    )]
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        mut,
+        seeds = [b"vault_depositor_throttle", vault.key().as_ref()],
+        bump = vault_depositor_throttle.load()?.bump,
+    )]
+    pub vault_depositor_throttle: AccountLoader<'info, VaultDepositorThrottle>,
+    pub manager: Signer<'info>,
+}
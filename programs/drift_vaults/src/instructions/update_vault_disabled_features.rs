@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{
+    record_vault_param_change, VaultFeature, VaultParam, VaultParamChangeLogProvider,
+};
+
+use super::UpdateVault;
+
+/// Sets the bits of the [`VaultFeature`]s switched off for the vault.
+pub fn update_vault_disabled_features<'info>(
+    ctx: Context<'_, '_, '_, 'info, UpdateVault<'info>>,
+    disabled_features: u8,
+) -> Result<()> {
+    let mut vault = ctx.accounts.vault.load_mut()?;
+    let now = Clock::get()?.unix_timestamp;
+    let param_change_log = ctx.vault_param_change_log();
+    vault.validate_param_change_log(&param_change_log)?;
+
+    VaultFeature::validate_flags(disabled_features)?;
+
+    msg!(
+        "Updating vault disabled features {:#010b} -> {:#010b}",
+        vault.disabled_features,
+        disabled_features
+    );
+    record_vault_param_change(
+        &vault,
+        &param_change_log,
+        ctx.accounts.manager.key(),
+        VaultParam::DisabledFeatures,
+        vault.disabled_features,
+        disabled_features,
+        now,
+    )?;
+    vault.disabled_features = disabled_features;
+
+    Ok(())
+}
@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+
+use crate::constraints::is_manager_for_vault;
+use crate::state::{Vault, VaultDustWithdraw};
+
+pub fn update_vault_dust_withdraw<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, UpdateVaultDustWithdraw<'info>>,
+    threshold: u64,
+    window_max_amount: u64,
+) -> Result<()> {
+    let mut vault_dust_withdraw = ctx.accounts.vault_dust_withdraw.load_mut()?;
+    vault_dust_withdraw.threshold = threshold;
+    vault_dust_withdraw.window_max_amount = window_max_amount;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdateVaultDustWithdraw<'info> {
+    #[account(
+        constraint = is_manager_for_vault(&vault, &manager)?
+    )]
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        mut,
+        seeds = [b"vault_dust_withdraw", vault.key().as_ref()],
+        bump = vault_dust_withdraw.load()?.bump,
+    )]
+    pub vault_dust_withdraw: AccountLoader<'info, VaultDustWithdraw>,
+    pub manager: Signer<'info>,
+}
@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{record_vault_param_change, FeePolicy, VaultParam, VaultParamChangeLogProvider};
+
+use super::UpdateVaultConfig;
+
+/// Sets the vault's [`FeePolicy`]. Existing depositors move to the new policy at their next profit share.
+pub fn update_vault_fee_policy<'info>(
+    ctx: Context<'_, '_, '_, 'info, UpdateVaultConfig<'info>>,
+    fee_policy: u8,
+) -> Result<()> {
+    let vault = ctx.accounts.vault.load()?;
+    let mut vault_config = ctx.accounts.vault_config.load_mut()?;
+    let now = Clock::get()?.unix_timestamp;
+    let param_change_log = ctx.vault_param_change_log();
+    vault.validate_param_change_log(&param_change_log)?;
+
+    let new_policy = FeePolicy::try_from(fee_policy)?;
+    let current_policy = vault_config.fee_policy(&vault)?;
+
+    msg!(
+        "Updating vault fee policy {:?} -> {:?}",
+        current_policy,
+        new_policy
+    );
+    record_vault_param_change(
+        &vault,
+        &param_change_log,
+        ctx.accounts.manager.key(),
+        VaultParam::FeePolicy,
+        current_policy as u8,
+        fee_policy,
+        now,
+    )?;
+    vault_config.update_fee_policy(&vault, new_policy)?;
+
+    Ok(())
+}
@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+
+use crate::constraints::is_manager_for_vault;
+use crate::state::{Vault, VaultFeeSplit};
+
+/// Changes the split terms, profit share crystallized before the change accrues at the old split.
+pub fn update_vault_fee_split<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, UpdateVaultFeeSplit<'info>>,
+    split: u32,
+    recipient: Pubkey,
+) -> Result<()> {
+    let vault = ctx.accounts.vault.load()?;
+    let mut fee_split = ctx.accounts.vault_fee_split.load_mut()?;
+
+    msg!(
+        "Updating vault fee split {} -> {}, recipient {} -> {}",
+        fee_split.split,
+        split,
+        fee_split.recipient,
+        recipient
+    );
+    fee_split.update(&vault, split, recipient)?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdateVaultFeeSplit<'info> {
+    #[account(
+        constraint = is_manager_for_vault(&vault, &manager)?,
+    )]
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        mut,
+        seeds = [b"vault_fee_split", vault.key().as_ref()],
+        bump = vault_fee_split.load()?.bump,
+    )]
+    pub vault_fee_split: AccountLoader<'info, VaultFeeSplit>,
+    pub manager: Signer<'info>,
+}
@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{record_vault_param_change, VaultParam, VaultParamChangeLogProvider};
+
+use super::UpdateVaultConfig;
+
+/// Rewards depositors that hold without withdrawing with up to `fuel_boost` * 10% extra fuel, see
+/// [`VaultConfig::get_fuel_boost_multiplier`](crate::state::VaultConfig::get_fuel_boost_multiplier).
+pub fn update_vault_fuel_boost<'info>(
+    ctx: Context<'_, '_, '_, 'info, UpdateVaultConfig<'info>>,
+    fuel_boost: u8,
+) -> Result<()> {
+    let vault = ctx.accounts.vault.load()?;
+    let mut vault_config = ctx.accounts.vault_config.load_mut()?;
+    let now = Clock::get()?.unix_timestamp;
+    let param_change_log = ctx.vault_param_change_log();
+    vault.validate_param_change_log(&param_change_log)?;
+
+    record_vault_param_change(
+        &vault,
+        &param_change_log,
+        ctx.accounts.manager.key(),
+        VaultParam::FuelBoost,
+        vault_config.fuel_boost,
+        fuel_boost,
+        now,
+    )?;
+    vault_config.update_fuel_boost(fuel_boost)?;
+
+    Ok(())
+}
@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+
+use crate::constraints::is_manager_for_vault;
+use crate::state::{Vault, VaultFeature, VaultHook};
+
+pub fn update_vault_hook<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, UpdateVaultHook<'info>>,
+    hook_program: Pubkey,
+) -> Result<()> {
+    let mut vault = ctx.accounts.vault.load_mut()?;
+    vault.validate_feature_enabled(VaultFeature::Hooks)?;
+
+    let mut vault_hook = ctx.accounts.vault_hook.load_mut()?;
+
+    msg!(
+        "Updating vault hook program {} -> {}",
+        vault_hook.hook_program,
+        hook_program
+    );
+    vault_hook.update_hook_program(&mut vault, hook_program, ctx.remaining_accounts)?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdateVaultHook<'info> {
+    #[account(
+        mut,
+        constraint = is_manager_for_vault(&vault, &manager)?
+    )]
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        mut,
+        seeds = [b"vault_hook", vault.key().as_ref()],
+        bump = vault_hook.load()?.bump,
+    )]
+    pub vault_hook: AccountLoader<'info, VaultHook>,
+    pub manager: Signer<'info>,
+}
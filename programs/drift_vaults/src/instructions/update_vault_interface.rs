@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+
+use crate::constraints::is_manager_for_vault;
+use crate::state::{Vault, VaultInterface};
+
+/// Updates the fee paid to the interface on volume attributed from now on, fees already accrued are kept.
+pub fn update_vault_interface<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, UpdateVaultInterface<'info>>,
+    fee_rate: u32,
+) -> Result<()> {
+    let mut vault_interface = ctx.accounts.vault_interface.load_mut()?;
+    vault_interface.set_fee_rate(fee_rate)?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdateVaultInterface<'info> {
+    #[account(
+        constraint = is_manager_for_vault(&vault, &manager)?
+    )]
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        mut,
+        seeds = [b"vault_interface", vault.key().as_ref(), vault_interface.load()?.interface.as_ref()],
+        bump = vault_interface.load()?.bump,
+    )]
+    pub vault_interface: AccountLoader<'info, VaultInterface>,
+    pub manager: Signer<'info>,
+}
@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+use drift::math::casting::Cast;
+use drift::math::constants::PERCENTAGE_PRECISION_U64;
+use drift::math::safe_math::SafeMath;
+
+use crate::constants::ONE_DAY;
+use crate::constraints::is_manager_for_vault;
+use crate::state::{record_vault_param_change, VaultParam, VaultParamChangeLogProvider};
+use crate::{error::ErrorCode, validate, Vault};
+
+use super::UpdateVault;
+
+/// Sets the extra notice period for large withdraw requests. Requests already pending keep the maturity they were
+/// created with.
+pub fn update_vault_large_withdraw_notice<'info>(
+    ctx: Context<'_, '_, '_, 'info, UpdateVault<'info>>,
+    large_withdraw_threshold: u32,
+    large_withdraw_notice_period: i64,
+) -> Result<()> {
+    let mut vault = ctx.accounts.vault.load_mut()?;
+
+    validate!(!vault.in_liquidation(), ErrorCode::OngoingLiquidation)?;
+
+    let now = Clock::get()?.unix_timestamp;
+    let param_change_log = ctx.vault_param_change_log();
+    vault.validate_param_change_log(&param_change_log)?;
+
+    validate!(
+        large_withdraw_threshold as u64 <= PERCENTAGE_PRECISION_U64,
+        ErrorCode::InvalidVaultUpdate,
+        "large withdraw threshold must be <= 100%"
+    )?;
+
+    validate!(
+        large_withdraw_notice_period >= 0
+            && vault.redeem_period.safe_add(large_withdraw_notice_period)? < ONE_DAY * 90,
+        ErrorCode::InvalidVaultUpdate,
+        "redeem period plus large withdraw notice period must be less than 90 days"
+    )?;
+
+    record_vault_param_change(
+        &vault,
+        &param_change_log,
+        ctx.accounts.manager.key(),
+        VaultParam::LargeWithdrawThreshold,
+        vault.large_withdraw_threshold,
+        large_withdraw_threshold,
+        now,
+    )?;
+    vault.large_withdraw_threshold = large_withdraw_threshold;
+
+    let large_withdraw_notice_period: u32 = large_withdraw_notice_period.cast()?;
+    record_vault_param_change(
+        &vault,
+        &param_change_log,
+        ctx.accounts.manager.key(),
+        VaultParam::LargeWithdrawNoticePeriod,
+        vault.large_withdraw_notice_period,
+        large_withdraw_notice_period,
+        now,
+    )?;
+    vault.large_withdraw_notice_period = large_withdraw_notice_period;
+
+    Ok(())
+}
@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+
+use crate::constraints::is_manager_for_vault;
+use crate::error::ErrorCode;
+use crate::state::{Vault, VaultLockup};
+use crate::validate;
+
+/// Shortens the vault's lockup period. Like the redeem period it can only be decreased, depositors joined under the
+/// existing lockup.
+pub fn update_vault_lockup<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, UpdateVaultLockup<'info>>,
+    lockup_period: u32,
+) -> Result<()> {
+    let mut vault_lockup = ctx.accounts.vault_lockup.load_mut()?;
+    validate!(
+        lockup_period < vault_lockup.lockup_period,
+        ErrorCode::InvalidVaultLockup,
+        "new lockup period must be shorter than existing lockup period"
+    )?;
+
+    msg!(
+        "vault lockup period: {} -> {}",
+        vault_lockup.lockup_period,
+        lockup_period
+    );
+    vault_lockup.update_lockup_period(lockup_period)?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdateVaultLockup<'info> {
+    #[account(
+        constraint = is_manager_for_vault(&vault, &manager)?
+    )]
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        mut,
+        seeds = [b"vault_lockup", vault.key().as_ref()],
+        bump = vault_lockup.load()?.bump,
+    )]
+    pub vault_lockup: AccountLoader<'info, VaultLockup>,
+    pub manager: Signer<'info>,
+}
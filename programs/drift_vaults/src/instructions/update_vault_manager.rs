@@ -1,5 +1,7 @@
 use anchor_lang::prelude::*;
 
+use crate::state::{record_vault_param_change, VaultParam, VaultParamChangeLogProvider};
+
 use super::UpdateVault;
 
 pub fn update_vault_manager<'info>(
@@ -7,8 +9,20 @@ pub fn update_vault_manager<'info>(
     manager: Pubkey,
 ) -> Result<()> {
     let mut vault = ctx.accounts.vault.load_mut()?;
+    let now = Clock::get()?.unix_timestamp;
+    let param_change_log = ctx.vault_param_change_log();
+    vault.validate_param_change_log(&param_change_log)?;
 
     msg!("Updating vault manager {} -> {}", vault.manager, manager);
+    record_vault_param_change(
+        &vault,
+        &param_change_log,
+        ctx.accounts.manager.key(),
+        VaultParam::Manager,
+        vault.manager,
+        manager,
+        now,
+    )?;
     vault.manager = manager;
 
     Ok(())
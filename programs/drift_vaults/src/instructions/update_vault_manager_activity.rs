@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+
+use crate::constraints::is_manager_for_vault;
+use crate::state::{Vault, VaultManagerActivity};
+
+/// Lowers the vault's manager inactivity period.
+pub fn update_vault_manager_activity<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, UpdateVaultManagerActivity<'info>>,
+    inactivity_period: u32,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let mut vault_manager_activity = ctx.accounts.vault_manager_activity.load_mut()?;
+
+    msg!(
+        "Updating vault manager inactivity period {} -> {}",
+        vault_manager_activity.inactivity_period,
+        inactivity_period
+    );
+    vault_manager_activity.update_inactivity_period(inactivity_period)?;
+    vault_manager_activity.record_activity(now);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdateVaultManagerActivity<'info> {
+    #[account(
+        constraint = is_manager_for_vault(&vault, &manager)?
+    )]
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        mut,
+        seeds = [b"vault_manager_activity", vault.key().as_ref()],
+        bump = vault_manager_activity.load()?.bump,
+    )]
+    pub vault_manager_activity: AccountLoader<'info, VaultManagerActivity>,
+    pub manager: Signer<'info>,
+}
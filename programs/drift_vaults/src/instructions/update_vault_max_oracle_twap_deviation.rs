@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+
+use crate::constraints::is_manager_for_vault;
+use crate::state::{Vault, VaultGuard};
+
+/// Sets the max oracle price deviation from TWAP, in bps, enforced by the vault's [`VaultGuard`] on deposits and
+/// withdraws. 0 disables the check.
+pub fn update_vault_max_oracle_twap_deviation<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, UpdateVaultGuard<'info>>,
+    max_oracle_twap_deviation_bps: u16,
+) -> Result<()> {
+    let mut vault_guard = ctx.accounts.vault_guard.load_mut()?;
+
+    msg!(
+        "Updating vault max oracle twap deviation {} -> {} bps",
+        vault_guard.max_oracle_twap_deviation_bps,
+        max_oracle_twap_deviation_bps
+    );
+    vault_guard.update_max_oracle_twap_deviation(max_oracle_twap_deviation_bps)?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdateVaultGuard<'info> {
+    #[account(
+        constraint = is_manager_for_vault(&vault, &manager)?
+    )]
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        mut,
+        seeds = [b"vault_guard", vault.key().as_ref()],
+        bump = vault_guard.load()?.bump,
+    )]
+    pub vault_guard: AccountLoader<'info, VaultGuard>,
+    pub manager: Signer<'info>,
+}
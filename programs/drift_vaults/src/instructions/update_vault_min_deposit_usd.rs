@@ -0,0 +1,21 @@
+use anchor_lang::prelude::*;
+
+use super::UpdateVaultGuard;
+
+/// Sets the min deposit value in whole USD, at the deposit oracle price when the deposit executes, enforced by the
+/// vault's [`VaultGuard`](crate::state::VaultGuard) on every deposit. 0 disables it.
+pub fn update_vault_min_deposit_usd<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, UpdateVaultGuard<'info>>,
+    min_deposit_usd: u32,
+) -> Result<()> {
+    let mut vault_guard = ctx.accounts.vault_guard.load_mut()?;
+
+    msg!(
+        "Updating vault min deposit usd {} -> {}",
+        vault_guard.min_deposit_usd,
+        min_deposit_usd
+    );
+    vault_guard.min_deposit_usd = min_deposit_usd;
+
+    Ok(())
+}
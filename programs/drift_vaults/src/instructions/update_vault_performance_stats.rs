@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+use drift::instructions::optional_accounts::AccountMaps;
+use drift::math::casting::Cast;
+use drift::state::user::User;
+
+use crate::constraints::is_user_for_vault;
+use crate::state::{Vault, VaultPerformanceStats};
+use crate::AccountMapProvider;
+
+/// Permissionless crank, closes a performance period at most once every
+/// [`PERFORMANCE_PERIOD`](crate::state::PERFORMANCE_PERIOD) and emits a
+/// [`VaultPerformanceRecord`](crate::state::events::VaultPerformanceRecord) for it, splitting the vault's pnl into
+/// spot market interest and trading pnl. Remaining accounts are the oracles and markets for the vault's positions.
+pub fn update_vault_performance_stats<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, UpdateVaultPerformanceStats<'info>>,
+) -> Result<()> {
+    let clock = &Clock::get()?;
+    let vault = ctx.accounts.vault.load()?;
+    let user = ctx.accounts.drift_user.load()?;
+
+    let AccountMaps {
+        perp_market_map,
+        spot_market_map,
+        mut oracle_map,
+    } = ctx.load_maps(clock.slot, None, false, false)?;
+
+    let vault_equity =
+        vault.calculate_equity(&user, &perp_market_map, &spot_market_map, &mut oracle_map)?;
+    let spot_market = spot_market_map.get_ref(&vault.spot_market_index)?;
+    let spot_token_amount = match user.get_spot_position(vault.spot_market_index) {
+        Ok(spot_position) => spot_position.get_signed_token_amount(&spot_market)?,
+        Err(_) => 0,
+    };
+
+    let mut stats = ctx.accounts.vault_performance_stats.load_mut()?;
+    stats.close_period(
+        &vault,
+        vault_equity,
+        spot_market.cumulative_deposit_interest,
+        spot_market.cumulative_borrow_interest,
+        spot_token_amount.cast()?,
+        clock.unix_timestamp,
+    )?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdateVaultPerformanceStats<'info> {
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        mut,
+        seeds = [b"vault_performance_stats", vault.key().as_ref()],
+        bump = vault_performance_stats.load()?.bump,
+    )]
+    pub vault_performance_stats: AccountLoader<'info, VaultPerformanceStats>,
+    #[account(
+        constraint = is_user_for_vault(&vault, &drift_user.key())?
+    )]
+    /// CHECK: checked in drift cpi
+    pub drift_user: AccountLoader<'info, User>,
+}
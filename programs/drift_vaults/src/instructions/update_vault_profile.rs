@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{
+    record_vault_param_change, VaultParam, VaultParamChangeLogProvider, VaultProfile,
+};
+use crate::{error::ErrorCode, validate};
+
+use super::UpdateVault;
+
+/// Sets the vault's [`VaultProfile`]. Depositors rely on the profile, so it can only be made stricter.
+pub fn update_vault_profile<'info>(
+    ctx: Context<'_, '_, '_, 'info, UpdateVault<'info>>,
+    profile: u8,
+) -> Result<()> {
+    let mut vault = ctx.accounts.vault.load_mut()?;
+    let now = Clock::get()?.unix_timestamp;
+    let param_change_log = ctx.vault_param_change_log();
+    vault.validate_param_change_log(&param_change_log)?;
+
+    let new_profile = VaultProfile::try_from(profile)?;
+    let current_profile = VaultProfile::try_from(vault.profile)?;
+    validate!(
+        new_profile >= current_profile,
+        ErrorCode::InvalidVaultProfile,
+        "vault profile can only be made stricter {:?} -> {:?}",
+        current_profile,
+        new_profile
+    )?;
+
+    msg!(
+        "Updating vault profile {:?} -> {:?}",
+        current_profile,
+        new_profile
+    );
+    record_vault_param_change(
+        &vault,
+        &param_change_log,
+        ctx.accounts.manager.key(),
+        VaultParam::Profile,
+        vault.profile,
+        profile,
+        now,
+    )?;
+    vault.profile = profile;
+
+    Ok(())
+}
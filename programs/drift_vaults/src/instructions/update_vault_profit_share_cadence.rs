@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+
+use crate::constraints::is_manager_for_vault;
+use crate::error::ErrorCode;
+use crate::state::{Vault, VaultProfitShareCadence};
+use crate::validate;
+
+/// Lengthens the vault's min profit share interval. It can only be increased, depositors joined expecting profit
+/// share to be crystallized at most that often.
+pub fn update_vault_profit_share_cadence<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, UpdateVaultProfitShareCadence<'info>>,
+    min_profit_share_interval: u32,
+) -> Result<()> {
+    let mut vault_profit_share_cadence = ctx.accounts.vault_profit_share_cadence.load_mut()?;
+    validate!(
+        min_profit_share_interval > vault_profit_share_cadence.min_profit_share_interval,
+        ErrorCode::InvalidVaultProfitShareCadence,
+        "new min profit share interval must be longer than existing interval"
+    )?;
+
+    msg!(
+        "vault min profit share interval: {} -> {}",
+        vault_profit_share_cadence.min_profit_share_interval,
+        min_profit_share_interval
+    );
+    vault_profit_share_cadence.update_min_profit_share_interval(min_profit_share_interval)?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdateVaultProfitShareCadence<'info> {
+    #[account(
+        constraint = is_manager_for_vault(&vault, &manager)?
+    )]
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        mut,
+        seeds = [b"vault_profit_share_cadence", vault.key().as_ref()],
+        bump = vault_profit_share_cadence.load()?.bump,
+    )]
+    pub vault_profit_share_cadence: AccountLoader<'info, VaultProfitShareCadence>,
+    pub manager: Signer<'info>,
+}
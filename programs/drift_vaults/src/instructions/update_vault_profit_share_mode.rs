@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+
+use crate::constraints::is_manager_for_vault;
+use crate::state::{
+    record_vault_param_change, ProfitShareMode, Vault, VaultConfig, VaultParam,
+    VaultParamChangeLogProvider,
+};
+
+/// Sets the vault's [`ProfitShareMode`]. Existing depositors move to the new mode at their next profit share.
+pub fn update_vault_profit_share_mode<'info>(
+    ctx: Context<'_, '_, '_, 'info, UpdateVaultConfig<'info>>,
+    profit_share_mode: u8,
+) -> Result<()> {
+    let vault = ctx.accounts.vault.load()?;
+    let mut vault_config = ctx.accounts.vault_config.load_mut()?;
+    let now = Clock::get()?.unix_timestamp;
+    let param_change_log = ctx.vault_param_change_log();
+    vault.validate_param_change_log(&param_change_log)?;
+
+    let new_mode = ProfitShareMode::try_from(profit_share_mode)?;
+    let current_mode = vault_config.profit_share_mode()?;
+
+    msg!(
+        "Updating vault profit share mode {:?} -> {:?}",
+        current_mode,
+        new_mode
+    );
+    record_vault_param_change(
+        &vault,
+        &param_change_log,
+        ctx.accounts.manager.key(),
+        VaultParam::ProfitShareMode,
+        current_mode as u8,
+        profit_share_mode,
+        now,
+    )?;
+    vault_config.update_profit_share_mode(&vault, new_mode)?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdateVaultConfig<'info> {
+    #[account(
+        constraint = is_manager_for_vault(&vault, &manager)?,
+    )]
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        mut,
+        seeds = [b"vault_config", vault.key().as_ref()],
+        bump = vault_config.load()?.bump,
+    )]
+    pub vault_config: AccountLoader<'info, VaultConfig>,
+    pub manager: Signer<'info>,
+}
@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{
+    record_vault_param_change, ProfitShareRealization, VaultParam, VaultParamChangeLogProvider,
+};
+
+use super::UpdateVaultConfig;
+
+/// Sets the vault's [`ProfitShareRealization`]. Switching to [`ProfitShareRealization::Unrealized`] is only allowed
+/// before the vault has depositor shares, see
+/// [`VaultConfig::update_profit_share_realization`](crate::state::VaultConfig::update_profit_share_realization).
+pub fn update_vault_profit_share_realization<'info>(
+    ctx: Context<'_, '_, '_, 'info, UpdateVaultConfig<'info>>,
+    profit_share_realization: u8,
+) -> Result<()> {
+    let vault = ctx.accounts.vault.load()?;
+    let mut vault_config = ctx.accounts.vault_config.load_mut()?;
+    let now = Clock::get()?.unix_timestamp;
+    let param_change_log = ctx.vault_param_change_log();
+    vault.validate_param_change_log(&param_change_log)?;
+
+    let new_realization = ProfitShareRealization::try_from(profit_share_realization)?;
+    let current_realization = vault_config.profit_share_realization()?;
+
+    msg!(
+        "Updating vault profit share realization {:?} -> {:?}",
+        current_realization,
+        new_realization
+    );
+    record_vault_param_change(
+        &vault,
+        &param_change_log,
+        ctx.accounts.manager.key(),
+        VaultParam::ProfitShareRealization,
+        current_realization as u8,
+        profit_share_realization,
+        now,
+    )?;
+    vault_config.update_profit_share_realization(&vault, new_realization)?;
+
+    Ok(())
+}
@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+
+use crate::constraints::{is_protocol_for_vault, is_vault_protocol_for_vault};
+use crate::state::{ProtocolFeeTier, Vault, VaultConfig, VaultProtocol};
+use crate::{error::ErrorCode, validate};
+
+/// Sets the protocol fee schedule in the vault's [`VaultConfig`], which the manager must have created.
+pub fn update_vault_protocol_fee_tiers<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, UpdateVaultProtocolFeeTiers<'info>>,
+    protocol_fee_tiers: [ProtocolFeeTier; 4],
+) -> Result<()> {
+    let vault = ctx.accounts.vault.load()?;
+    validate!(!vault.in_liquidation(), ErrorCode::OngoingLiquidation)?;
+
+    let vp = ctx.accounts.vault_protocol.load()?;
+    let mut vault_config = ctx.accounts.vault_config.load_mut()?;
+    vault_config.update_protocol_fee_tiers(vp.protocol_fee, protocol_fee_tiers)?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdateVaultProtocolFeeTiers<'info> {
+    #[account(
+        constraint = is_protocol_for_vault(&vault, &vault_protocol, &protocol)?
+    )]
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        constraint = is_vault_protocol_for_vault(&vault_protocol, &vault)?
+    )]
+    pub vault_protocol: AccountLoader<'info, VaultProtocol>,
+    #[account(
+        mut,
+        seeds = [b"vault_config", vault.key().as_ref()],
+        bump = vault_config.load()?.bump,
+    )]
+    pub vault_config: AccountLoader<'info, VaultConfig>,
+    pub protocol: Signer<'info>,
+}
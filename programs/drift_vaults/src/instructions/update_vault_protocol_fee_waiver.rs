@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+
+use crate::constraints::{is_protocol_for_vault, is_vault_protocol_for_vault};
+use crate::state::{Vault, VaultConfig, VaultProtocol};
+use crate::{error::ErrorCode, validate};
+
+/// Waives the protocol fee for vaults under `tvl_threshold` until they grow back to `exit_tvl_threshold`, a
+/// `tvl_threshold` of 0 turns the waiver off. The thresholds live in the vault's [`VaultConfig`].
+pub fn update_vault_protocol_fee_waiver<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, UpdateVaultProtocolFeeWaiver<'info>>,
+    tvl_threshold: u64,
+    exit_tvl_threshold: u64,
+) -> Result<()> {
+    let vault = ctx.accounts.vault.load()?;
+    validate!(!vault.in_liquidation(), ErrorCode::OngoingLiquidation)?;
+
+    let mut vault_config = ctx.accounts.vault_config.load_mut()?;
+    vault_config.update_protocol_fee_waiver_thresholds(tvl_threshold, exit_tvl_threshold)?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdateVaultProtocolFeeWaiver<'info> {
+    #[account(
+        constraint = is_protocol_for_vault(&vault, &vault_protocol, &protocol)?
+    )]
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        constraint = is_vault_protocol_for_vault(&vault_protocol, &vault)?
+    )]
+    pub vault_protocol: AccountLoader<'info, VaultProtocol>,
+    #[account(
+        mut,
+        seeds = [b"vault_config", vault.key().as_ref()],
+        bump = vault_config.load()?.bump,
+    )]
+    pub vault_config: AccountLoader<'info, VaultConfig>,
+    pub protocol: Signer<'info>,
+}
@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+
+use crate::constraints::is_manager_for_vault;
+use crate::state::{RedeemPeriodBucket, Vault, VaultRedeemSchedule, VAULT_REDEEM_SCHEDULE_BUCKETS};
+
+/// Applies to requests made after the update, pending requests keep their maturity.
+pub fn update_vault_redeem_schedule<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, UpdateVaultRedeemSchedule<'info>>,
+    buckets: [RedeemPeriodBucket; VAULT_REDEEM_SCHEDULE_BUCKETS],
+) -> Result<()> {
+    ctx.accounts
+        .vault_redeem_schedule
+        .load_mut()?
+        .update_buckets(buckets)
+}
+
+#[derive(Accounts)]
+pub struct UpdateVaultRedeemSchedule<'info> {
+    #[account(
+        constraint = is_manager_for_vault(&vault, &manager)?,
+    )]
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        mut,
+        seeds = [b"vault_redeem_schedule", vault.key().as_ref()],
+        bump = vault_redeem_schedule.load()?.bump,
+    )]
+    pub vault_redeem_schedule: AccountLoader<'info, VaultRedeemSchedule>,
+    pub manager: Signer<'info>,
+}
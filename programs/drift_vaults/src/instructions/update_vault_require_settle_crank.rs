@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{record_vault_param_change, VaultFlag, VaultParam, VaultParamChangeLogProvider};
+
+use super::UpdateVault;
+
+/// Requires deposits and withdraws to follow a drift settle pnl of the vault's user in the same transaction, see
+/// [`Vault::validate_settle_crank`](crate::state::Vault::validate_settle_crank).
+pub fn update_vault_require_settle_crank<'info>(
+    ctx: Context<'_, '_, '_, 'info, UpdateVault<'info>>,
+    require_settle_crank: bool,
+) -> Result<()> {
+    let mut vault = ctx.accounts.vault.load_mut()?;
+    let now = Clock::get()?.unix_timestamp;
+    let param_change_log = ctx.vault_param_change_log();
+    vault.validate_param_change_log(&param_change_log)?;
+
+    let current = vault.has_flag(VaultFlag::RequireSettleCrank);
+
+    msg!(
+        "Updating vault require settle crank {} -> {}",
+        current,
+        require_settle_crank
+    );
+    record_vault_param_change(
+        &vault,
+        &param_change_log,
+        ctx.accounts.manager.key(),
+        VaultParam::RequireSettleCrank,
+        current,
+        require_settle_crank,
+        now,
+    )?;
+
+    if require_settle_crank {
+        vault.add_flag(VaultFlag::RequireSettleCrank);
+    } else {
+        vault.remove_flag(VaultFlag::RequireSettleCrank);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,104 @@
+use anchor_lang::prelude::*;
+use drift::instructions::optional_accounts::AccountMaps;
+use drift::math::casting::Cast;
+use drift::math::margin::calculate_user_equity;
+use drift::math::position::calculate_base_asset_value_with_oracle_price;
+use drift::math::safe_math::SafeMath;
+use drift::math::spot_balance::get_token_value;
+use drift::state::user::User;
+
+use crate::constraints::is_user_for_vault;
+use crate::error::ErrorCode;
+use crate::state::{Vault, VaultRiskScore};
+use crate::{validate, AccountMapProvider};
+
+/// Permissionless crank, remaining accounts are the oracles and markets for the vault's positions.
+pub fn update_vault_risk_score<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, UpdateVaultRiskScore<'info>>,
+) -> Result<()> {
+    let clock = &Clock::get()?;
+
+    let vault = ctx.accounts.vault.load()?;
+    let user = ctx.accounts.drift_user.load()?;
+
+    let AccountMaps {
+        perp_market_map,
+        spot_market_map,
+        mut oracle_map,
+    } = ctx.load_maps(clock.slot, None, false, false)?;
+
+    let (equity, all_oracles_valid) =
+        calculate_user_equity(&user, &perp_market_map, &spot_market_map, &mut oracle_map)?;
+    validate!(
+        all_oracles_valid,
+        ErrorCode::InvalidEquityValue,
+        "oracle invalid"
+    )?;
+
+    let mut total_liability: u128 = 0;
+    let mut max_liability: u128 = 0;
+
+    for perp_position in user.perp_positions.iter() {
+        if perp_position.base_asset_amount == 0 {
+            continue;
+        }
+
+        let perp_market = perp_market_map.get_ref(&perp_position.market_index)?;
+        let oracle_price = oracle_map.get_price_data(&perp_market.oracle_id())?.price;
+        let liability = calculate_base_asset_value_with_oracle_price(
+            perp_position.base_asset_amount.cast()?,
+            oracle_price,
+        )?;
+
+        total_liability = total_liability.safe_add(liability)?;
+        max_liability = max_liability.max(liability);
+    }
+
+    for spot_position in user.spot_positions.iter() {
+        if spot_position.is_available() {
+            continue;
+        }
+
+        let spot_market = spot_market_map.get_ref(&spot_position.market_index)?;
+        let token_amount = spot_position.get_signed_token_amount(&spot_market)?;
+        if token_amount >= 0 {
+            continue;
+        }
+
+        let oracle_price = oracle_map.get_price_data(&spot_market.oracle_id())?.price;
+        let liability =
+            get_token_value(token_amount, spot_market.decimals, oracle_price)?.unsigned_abs();
+
+        total_liability = total_liability.safe_add(liability)?;
+        max_liability = max_liability.max(liability);
+    }
+
+    let mut risk_score = ctx.accounts.vault_risk_score.load_mut()?;
+    risk_score.update(
+        clock.unix_timestamp,
+        vault.delegate,
+        equity.max(0).unsigned_abs(),
+        vault.total_shares,
+        vault.shares_base,
+        total_liability,
+        max_liability,
+    )?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdateVaultRiskScore<'info> {
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        mut,
+        seeds = [b"vault_risk_score", vault.key().as_ref()],
+        bump = vault_risk_score.load()?.bump,
+    )]
+    pub vault_risk_score: AccountLoader<'info, VaultRiskScore>,
+    #[account(
+        constraint = is_user_for_vault(&vault, &drift_user.key())?
+    )]
+    /// CHECK: checked in drift cpi
+    pub drift_user: AccountLoader<'info, User>,
+}
@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+
+use crate::constraints::is_manager_for_vault;
+use crate::state::{Vault, VaultWithdrawPriority};
+
+/// Reprices the boost for future priority fees, requests already boosted keep theirs.
+pub fn update_vault_withdraw_priority<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, UpdateVaultWithdrawPriority<'info>>,
+    full_boost_fee: u32,
+) -> Result<()> {
+    let mut vault_withdraw_priority = ctx.accounts.vault_withdraw_priority.load_mut()?;
+
+    msg!(
+        "Updating vault withdraw priority full_boost_fee {} -> {}",
+        vault_withdraw_priority.full_boost_fee,
+        full_boost_fee
+    );
+    vault_withdraw_priority.update_full_boost_fee(full_boost_fee)?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdateVaultWithdrawPriority<'info> {
+    #[account(
+        constraint = is_manager_for_vault(&vault, &manager)?
+    )]
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        mut,
+        seeds = [b"vault_withdraw_priority", vault.key().as_ref()],
+        bump = vault_withdraw_priority.load()?.bump,
+    )]
+    pub vault_withdraw_priority: AccountLoader<'info, VaultWithdrawPriority>,
+    pub manager: Signer<'info>,
+}
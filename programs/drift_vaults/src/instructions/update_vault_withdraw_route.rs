@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+
+use crate::constraints::is_manager_for_vault;
+use crate::state::{Vault, VaultWithdrawRoute};
+
+pub fn update_vault_withdraw_route<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, UpdateVaultWithdrawRoute<'info>>,
+    market_indexes: Vec<u16>,
+) -> Result<()> {
+    let vault = ctx.accounts.vault.load()?;
+    let mut withdraw_route = ctx.accounts.vault_withdraw_route.load_mut()?;
+
+    withdraw_route.update_market_indexes(vault.spot_market_index, &market_indexes)?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdateVaultWithdrawRoute<'info> {
+    #[account(
+        constraint = is_manager_for_vault(&vault, &manager)?
+    )]
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        mut,
+        seeds = [b"vault_withdraw_route", vault.key().as_ref()],
+        bump = vault_withdraw_route.load()?.bump,
+    )]
+    pub vault_withdraw_route: AccountLoader<'info, VaultWithdrawRoute>,
+    pub manager: Signer<'info>,
+}
@@ -0,0 +1,119 @@
+use std::collections::BTreeMap;
+
+use anchor_lang::prelude::*;
+use drift::instructions::optional_accounts::AccountMaps;
+use drift::state::user::User;
+
+use crate::error::ErrorCode;
+use crate::state::{
+    PortfolioPosition, Vault, VaultConfigProvider, VaultDepositor, VaultProtocol,
+    MAX_PORTFOLIO_POSITIONS,
+};
+use crate::{validate, AccountMapProvider};
+
+/// Read only, returns (as return data) the current value and pending liabilities of every [`VaultDepositor`] in
+/// remaining_accounts, so portfolio UIs can price positions across vaults in one simulation. Remaining accounts are
+/// the market accounts for every vault, then the vault depositors, their vaults, the vaults' drift users and
+/// [`VaultProtocol`]s and [`VaultConfig`](crate::state::VaultConfig)s where the vault has one, in any order.
+pub fn view_depositor_portfolio<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, ViewDepositorPortfolio>,
+) -> Result<Vec<PortfolioPosition>> {
+    let clock = &Clock::get()?;
+
+    let vault_depositors: Vec<AccountLoader<'info, VaultDepositor>> = ctx
+        .remaining_accounts
+        .iter()
+        .filter_map(|acct| AccountLoader::<'info, VaultDepositor>::try_from(acct).ok())
+        .collect();
+
+    validate!(
+        !vault_depositors.is_empty() && vault_depositors.len() <= MAX_PORTFOLIO_POSITIONS,
+        ErrorCode::InvalidVaultDepositorBatch,
+        "expected 1 to {} vault depositors in remaining accounts, got {}",
+        MAX_PORTFOLIO_POSITIONS,
+        vault_depositors.len()
+    )?;
+
+    let AccountMaps {
+        perp_market_map,
+        spot_market_map,
+        mut oracle_map,
+    } = ctx.load_maps(clock.slot, None, false, false)?;
+
+    let mut vault_equities = BTreeMap::<Pubkey, u64>::new();
+    let mut positions = Vec::with_capacity(vault_depositors.len());
+
+    for vault_depositor_loader in vault_depositors.iter() {
+        let mut vault_depositor = *vault_depositor_loader.load()?;
+        vault_depositor.migrate()?;
+
+        let vault_loader = AccountLoader::<'info, Vault>::try_from(find_remaining_account(
+            ctx.remaining_accounts,
+            &vault_depositor.vault,
+        )?)?;
+        let vault = vault_loader.load()?;
+        let vault_config = ctx.vault_config(&vault)?;
+
+        let vault_protocol = if vault.vault_protocol {
+            let address = Pubkey::find_program_address(
+                &[b"vault_protocol", vault.pubkey.as_ref()],
+                &crate::id(),
+            )
+            .0;
+            let vault_protocol = AccountLoader::<'info, VaultProtocol>::try_from(
+                find_remaining_account(ctx.remaining_accounts, &address)?,
+            )?;
+            let vault_protocol = *vault_protocol.load()?;
+            Some(vault_protocol)
+        } else {
+            None
+        };
+
+        let vault_equity = match vault_equities.get(&vault.pubkey) {
+            Some(vault_equity) => *vault_equity,
+            None => {
+                let drift_user = AccountLoader::<'info, User>::try_from(find_remaining_account(
+                    ctx.remaining_accounts,
+                    &vault.user,
+                )?)?;
+                let drift_user = drift_user.load()?;
+                let vault_equity = vault.calculate_equity(
+                    &drift_user,
+                    &perp_market_map,
+                    &spot_market_map,
+                    &mut oracle_map,
+                )?;
+                vault_equities.insert(vault.pubkey, vault_equity);
+                vault_equity
+            }
+        };
+
+        positions.push(vault_depositor.portfolio_position(
+            &vault,
+            &vault_config,
+            vault_protocol,
+            vault_equity,
+            clock.unix_timestamp,
+        )?);
+    }
+
+    msg!("portfolio positions: {:?}", positions);
+
+    Ok(positions)
+}
+
+fn find_remaining_account<'c, 'info>(
+    remaining_accounts: &'c [AccountInfo<'info>],
+    key: &Pubkey,
+) -> Result<&'c AccountInfo<'info>> {
+    remaining_accounts
+        .iter()
+        .find(|acct| acct.key == key)
+        .ok_or_else(|| {
+            msg!("{} missing from remaining accounts", key);
+            ErrorCode::InvalidVaultDepositorBatch.into()
+        })
+}
+
+#[derive(Accounts)]
+pub struct ViewDepositorPortfolio {}
@@ -0,0 +1,27 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{Vault, VaultFeeLedger, VaultProtocolProvider};
+
+/// Read only, returns (as return data) the vault's [`VaultFeeLedger`], fees charged and the manager and protocol
+/// positions, for fund administration exports. The vault's [`VaultProtocol`](crate::state::VaultProtocol) must be the
+/// last remaining account if it has one.
+pub fn view_vault_fee_ledger<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, ViewVaultFeeLedger<'info>>,
+) -> Result<VaultFeeLedger> {
+    let vault = ctx.accounts.vault.load()?;
+
+    let vp = ctx.vault_protocol();
+    vault.validate_vault_protocol(&vp)?;
+    let vp = vp.as_ref().map(|vp| vp.load()).transpose()?;
+
+    let fee_ledger = VaultFeeLedger::new(&vault, vp.as_deref())?;
+
+    msg!("fee ledger: {:?}", fee_ledger);
+
+    Ok(fee_ledger)
+}
+
+#[derive(Accounts)]
+pub struct ViewVaultFeeLedger<'info> {
+    pub vault: AccountLoader<'info, Vault>,
+}
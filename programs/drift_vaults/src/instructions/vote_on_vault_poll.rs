@@ -0,0 +1,70 @@
+use anchor_lang::prelude::*;
+
+use crate::constraints::{is_authority_for_vault_depositor, is_vault_for_vault_depositor};
+use crate::state::{Vault, VaultDepositor, VaultDepositorLoader, VaultPoll, VaultPollVote};
+use crate::Size;
+
+pub fn vote_on_vault_poll<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, VoteOnVaultPoll<'info>>,
+) -> Result<()> {
+    let clock = &Clock::get()?;
+
+    let vault = ctx.accounts.vault.load()?;
+    let vault_depositor = ctx.accounts.vault_depositor.load_checked()?;
+    let shares = vault_depositor.checked_vault_shares(&vault)?;
+
+    let mut vault_poll = ctx.accounts.vault_poll.load_mut()?;
+    vault_poll.vote(clock.unix_timestamp, shares, vault.shares_base)?;
+
+    let mut vote = ctx.accounts.vault_poll_vote.load_init()?;
+    vote.vault_poll = ctx.accounts.vault_poll.key();
+    vote.authority = ctx.accounts.authority.key();
+    vote.shares = shares;
+    vote.ts = clock.unix_timestamp;
+    vote.poll_id = vault_poll.poll_id;
+    vote.bump = ctx.bumps.vault_poll_vote;
+
+    msg!(
+        "Voted on poll {} with {} shares, {}/{} shares for",
+        vault_poll.poll_id,
+        shares,
+        vault_poll.votes_for,
+        vault_poll.total_shares
+    );
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct VoteOnVaultPoll<'info> {
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        mut,
+        seeds = [b"vault_poll", vault.key().as_ref()],
+        bump = vault_poll.load()?.bump,
+    )]
+    pub vault_poll: AccountLoader<'info, VaultPoll>,
+    #[account(
+        init,
+        seeds = [
+            b"vault_poll_vote",
+            vault_poll.key().as_ref(),
+            vault_poll.load()?.poll_id.to_le_bytes().as_ref(),
+            authority.key().as_ref()
+        ],
+        space = VaultPollVote::SIZE,
+        bump,
+        payer = payer
+    )]
+    pub vault_poll_vote: AccountLoader<'info, VaultPollVote>,
+    #[account(
+        constraint = is_vault_for_vault_depositor(&vault_depositor, &vault)?,
+        constraint = is_authority_for_vault_depositor(&vault_depositor, &authority)?,
+    )]
+    pub vault_depositor: AccountLoader<'info, VaultDepositor>,
+    pub authority: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub rent: Sysvar<'info, Rent>,
+    pub system_program: Program<'info, System>,
+}
@@ -1,6 +1,5 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Transfer};
-use anchor_spl::token::{Token, TokenAccount};
+use anchor_spl::token_interface::{TokenAccount, TokenInterface};
 use drift::cpi::accounts::{UpdateUser, Withdraw as DriftWithdraw};
 use drift::instructions::optional_accounts::AccountMaps;
 use drift::program::Drift;
@@ -10,72 +9,261 @@ use crate::constraints::{
     is_authority_for_vault_depositor, is_user_for_vault, is_user_stats_for_vault,
 };
 use crate::drift_cpi::{UpdateUserDelegateCPI, UpdateUserReduceOnlyCPI, WithdrawCPI};
-use crate::state::{FuelOverflowProvider, Vault, VaultDepositor, VaultProtocolProvider};
-use crate::token_cpi::TokenTransferCPI;
+use crate::error::ErrorCode;
+use crate::hook_cpi::VaultHookCPI;
+use crate::state::events::VaultDepositorAction;
+use crate::state::{
+    record_vault_depositor_action, validate_withdraw_not_paused, ComputeUnitsHint, FlowPreview,
+    FuelOverflowProvider, InstructionsSysvarProvider, TokenMintProvider, Vault,
+    VaultConfigProvider, VaultDepositor, VaultDepositorBase, VaultDepositorLoader,
+    VaultDepositorRecordLogProvider, VaultGuardProvider, VaultHook, VaultHookAction,
+    VaultHookContext, VaultHookProvider, VaultManagerActivityProvider, VaultProtocolProvider,
+    VaultWithdrawBlackoutProvider, VaultWithdrawSettlementProvider, WITHDRAW_BASE_COMPUTE_UNITS,
+};
+use crate::token_cpi::{get_amount_after_transfer_fee, transfer_tokens, TokenTransferCPI};
 use crate::{
     declare_vault_seeds, implement_update_user_delegate_cpi, implement_update_user_reduce_only_cpi,
-    implement_withdraw, AccountMapProvider,
+    implement_vault_hook_cpi, implement_withdraw, AccountMapProvider,
 };
 
 pub fn withdraw<'c: 'info, 'info>(ctx: Context<'_, '_, 'c, 'info, Withdraw<'info>>) -> Result<()> {
+    process_withdraw(ctx, false)
+}
+
+/// Simulation only [`withdraw`], takes the same accounts and runs the same share, fee and fuel math but skips the
+/// hook, drift and token cpis. Always fails with `SimulationOnly` after writing the projected [`FlowPreview`] to
+/// return data, see [`FlowPreview::set_return_data_and_revert`].
+pub fn preview_withdraw<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, Withdraw<'info>>,
+) -> Result<()> {
+    process_withdraw(ctx, true)
+}
+
+fn process_withdraw<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, Withdraw<'info>>,
+    simulate: bool,
+) -> Result<()> {
     let clock = &Clock::get()?;
-    let mut vault = ctx.accounts.vault.load_mut()?;
-    let mut vault_depositor = ctx.accounts.vault_depositor.load_mut()?;
 
+    // withdraw hooks are opt-in by the depositor so a hook can never lock funds in the vault. Previews skip every cpi
+    let vault_hook_program = ctx
+        .vault_hook_program(&*ctx.accounts.vault.load()?)?
+        .filter(|_| !simulate);
+    if let Some(hook_program) = &vault_hook_program {
+        let requested_amount = ctx
+            .accounts
+            .vault_depositor
+            .load()?
+            .last_withdraw_request
+            .value;
+        ctx.vault_hook_invoke(hook_program, VaultHookAction::PreWithdraw, requested_amount)?;
+    }
+
+    let mut settled_withdraw = settle_withdraw(
+        &ctx,
+        &ctx.accounts.vault,
+        &ctx.accounts.vault_depositor,
+        &ctx.accounts.drift_user,
+        &ctx.accounts.drift_user_stats,
+        clock,
+        simulate,
+    )?;
+    let user_withdraw_amount = settled_withdraw.user_withdraw_amount;
+
+    // shares burned at zero vault equity pay out nothing
+    if user_withdraw_amount > 0 {
+        ctx.drift_withdraw(user_withdraw_amount)?;
+
+        // the drift withdraw's Token-2022 transfer fee is withheld from what reaches the vault token account
+        let mint = ctx.token_mint(&ctx.accounts.vault_token_account.mint)?;
+        let received_amount = get_amount_after_transfer_fee(mint.as_ref(), user_withdraw_amount)?;
+        ctx.token_transfer(received_amount)?;
+    }
+
+    finish_withdraw(
+        &ctx,
+        &ctx.accounts.vault,
+        &ctx.accounts.drift_user,
+        &mut settled_withdraw,
+        clock,
+    )?;
+
+    if let Some(hook_program) = &vault_hook_program {
+        ctx.vault_hook_invoke(
+            hook_program,
+            VaultHookAction::PostWithdraw,
+            user_withdraw_amount,
+        )?;
+    }
+
+    settled_withdraw.compute_units_hint.set_return_data()?;
+
+    Ok(())
+}
+
+/// The result of [`settle_withdraw`], what's left for the instruction to pay out and finish.
+pub(crate) struct SettledWithdraw<'a> {
+    /// Amount of the vault's spot market token owed to the depositor
+    pub user_withdraw_amount: u64,
+    /// The withdraw was the last one needed to end the vault's liquidation, see [`finish_withdraw`]
+    pub finishing_liquidation: bool,
+    /// Oracle price of the vault's spot market the withdraw was valued at
+    pub vault_oracle_price: i64,
+    pub account_maps: AccountMaps<'a>,
+    pub compute_units_hint: ComputeUnitsHint,
+}
+
+/// The share, fee and fuel half of a depositor withdraw, shared by every instruction that pays out a withdraw
+/// request so they only differ in where the payout goes. Redeems the depositor's request and logs it, previews
+/// revert before the log with the projected [`FlowPreview`].
+pub(crate) fn settle_withdraw<'a: 'info, 'info, T: anchor_lang::Bumps>(
+    ctx: &Context<'_, '_, 'a, 'info, T>,
+    vault_loader: &AccountLoader<'info, Vault>,
+    vault_depositor_loader: &AccountLoader<'info, VaultDepositor>,
+    drift_user: &AccountLoader<'info, User>,
+    drift_user_stats: &AccountLoader<'info, UserStats>,
+    clock: &Clock,
+    simulate: bool,
+) -> Result<SettledWithdraw<'a>> {
+    let mut vault = vault_loader.load_mut()?;
+    vault.validate_settle_crank(&drift_user.key(), ctx.instructions_sysvar())?;
+    let mut vault_depositor = vault_depositor_loader.load_mut_migrated()?;
+    let preview_base = simulate.then(|| (*vault, *vault_depositor));
+
+    let vault_config = ctx.vault_config(&vault)?;
     // backwards compatible: if last rem acct does not deserialize into [`VaultProtocol`] then it's a legacy vault.
     let mut vp = ctx.vault_protocol();
     vault.validate_vault_protocol(&vp)?;
     let mut vp = vp.as_mut().map(|vp| vp.load_mut()).transpose()?;
 
-    let user = ctx.accounts.drift_user.load()?;
+    let user = drift_user.load()?;
     let spot_market_index = vault.spot_market_index;
 
-    let user_stats = ctx.accounts.drift_user_stats.load()?;
+    let user_stats = drift_user_stats.load()?;
     let has_fuel_overflow = FuelOverflowStatus::exists(user_stats.fuel_overflow_status);
     let fuel_overflow = ctx.fuel_overflow(vp.is_some(), has_fuel_overflow);
     user_stats.validate_fuel_overflow(&fuel_overflow)?;
 
-    let AccountMaps {
-        perp_market_map,
-        spot_market_map,
-        mut oracle_map,
-    } = ctx.load_maps(
+    let mut account_maps = ctx.load_maps(
         clock.slot,
         Some(spot_market_index),
         vp.is_some(),
         has_fuel_overflow,
     )?;
 
-    let vault_equity =
-        vault.calculate_equity(&user, &perp_market_map, &spot_market_map, &mut oracle_map)?;
+    let vault_equity = vault.calculate_equity(
+        &user,
+        &account_maps.perp_market_map,
+        &account_maps.spot_market_map,
+        &mut account_maps.oracle_map,
+    )?;
+    let compute_units_hint = ComputeUnitsHint::new(
+        WITHDRAW_BASE_COMPUTE_UNITS,
+        &account_maps.perp_market_map,
+        &account_maps.spot_market_map,
+    )?;
+
+    // vaults that settle withdraws pay every matured request at the last settlement's share price
+    let settlement = ctx.vault_withdraw_settlement(&vault)?;
+    let settlement = settlement.as_ref().map(|s| s.load()).transpose()?;
+
+    let spot_market = account_maps.spot_market_map.get_ref(&spot_market_index)?;
+    validate_withdraw_not_paused(&spot_market)?;
+    let oracle_price = account_maps
+        .oracle_map
+        .get_price_data(&spot_market.oracle_id())?
+        .price;
 
-    let spot_market = spot_market_map.get_ref(&spot_market_index)?;
-    let oracle = oracle_map.get_price_data(&spot_market.oracle_id())?;
+    if let Some(vault_guard) = ctx.vault_guard(&vault)? {
+        vault_guard.load()?.validate_oracle_price(
+            oracle_price,
+            spot_market.historical_oracle_data.last_oracle_price_twap,
+        )?;
+    }
+    drop(spot_market);
 
-    let (user_withdraw_amount, finishing_liquidation) = vault_depositor.withdraw(
+    if let Some(blackout) = ctx.vault_withdraw_blackout(&vault)? {
+        blackout.load()?.validate_withdraw(clock.unix_timestamp)?;
+    }
+
+    let vault_shares_before = vault_depositor.get_vault_shares();
+    let (user_withdraw_amount, finishing_liquidation) = vault_depositor.withdraw_with_settlement(
         vault_equity,
+        settlement.as_deref(),
         &mut vault,
+        &vault_config,
         &mut vp,
         clock.unix_timestamp,
         &user_stats,
         &fuel_overflow,
-        oracle.price,
+        oracle_price,
     )?;
 
     msg!("user_withdraw_amount: {}", user_withdraw_amount);
 
-    drop(spot_market);
-    drop(vault);
-    drop(user);
-    drop(user_stats);
-    drop(vp);
+    if let Some((vault_before, vault_depositor_before)) = preview_base {
+        // always reverts with `SimulationOnly`
+        FlowPreview::new(
+            user_withdraw_amount,
+            &vault_before,
+            &vault_depositor_before,
+            &vault,
+            &vault_depositor,
+            compute_units_hint,
+        )?
+        .set_return_data_and_revert()?;
+    }
+
+    record_vault_depositor_action(
+        &ctx.vault_depositor_record_log(&vault.pubkey)?,
+        &vault,
+        &vault_depositor,
+        VaultDepositorAction::Withdraw,
+        user_withdraw_amount,
+        vault_shares_before,
+        vault_equity,
+        clock.unix_timestamp,
+    )?;
+
+    Ok(SettledWithdraw {
+        user_withdraw_amount,
+        finishing_liquidation,
+        vault_oracle_price: oracle_price,
+        account_maps,
+        compute_units_hint,
+    })
+}
 
-    ctx.drift_withdraw(user_withdraw_amount)?;
+/// Emits the vault's health record once a [`settle_withdraw`] has been paid out and, if the withdraw finished the
+/// vault's liquidation, hands the drift user back to the vault's delegate.
+pub(crate) fn finish_withdraw<'x, 'y, 'a: 'info, 'info, T: anchor_lang::Bumps>(
+    ctx: &Context<'x, 'y, 'a, 'info, T>,
+    vault_loader: &AccountLoader<'info, Vault>,
+    drift_user: &AccountLoader<'info, User>,
+    settled_withdraw: &mut SettledWithdraw<'a>,
+    clock: &Clock,
+) -> Result<()>
+where
+    Context<'x, 'y, 'a, 'info, T>: UpdateUserDelegateCPI + UpdateUserReduceOnlyCPI,
+{
+    let AccountMaps {
+        perp_market_map,
+        spot_market_map,
+        oracle_map,
+    } = &mut settled_withdraw.account_maps;
 
-    ctx.token_transfer(user_withdraw_amount)?;
+    vault_loader.load()?.emit_health_record(
+        &*drift_user.load()?,
+        perp_market_map,
+        spot_market_map,
+        oracle_map,
+        settled_withdraw.user_withdraw_amount,
+        ctx.last_manager_activity_ts(&vault_loader.key())?,
+        clock.unix_timestamp,
+    )?;
 
-    if finishing_liquidation {
-        let mut vault = ctx.accounts.vault.load_mut()?;
+    if settled_withdraw.finishing_liquidation {
+        let mut vault = vault_loader.load_mut()?;
         let vault_delegate = vault.delegate;
         vault.reset_liquidation_delegate();
         drop(vault);
@@ -104,7 +292,7 @@ pub struct Withdraw<'info> {
         seeds = [b"vault_token_account".as_ref(), vault.key().as_ref()],
         bump,
     )]
-    pub vault_token_account: Box<Account<'info, TokenAccount>>,
+    pub vault_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
     #[account(
         mut,
         constraint = is_user_stats_for_vault(&vault, &drift_user_stats.key())?
@@ -123,7 +311,7 @@ pub struct Withdraw<'info> {
         mut,
         token::mint = vault_token_account.mint
     )]
-    pub drift_spot_market_vault: Box<Account<'info, TokenAccount>>,
+    pub drift_spot_market_vault: Box<InterfaceAccount<'info, TokenAccount>>,
     /// CHECK: checked in drift cpi
     pub drift_signer: AccountInfo<'info>,
     #[account(
@@ -131,9 +319,9 @@ pub struct Withdraw<'info> {
         token::authority = authority,
         token::mint = vault_token_account.mint
     )]
-    pub user_token_account: Box<Account<'info, TokenAccount>>,
+    pub user_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
     pub drift_program: Program<'info, Drift>,
-    pub token_program: Program<'info, Token>,
+    pub token_program: Interface<'info, TokenInterface>,
 }
 
 impl<'info> WithdrawCPI for Context<'_, '_, '_, 'info, Withdraw<'info>> {
@@ -143,21 +331,20 @@ impl<'info> WithdrawCPI for Context<'_, '_, '_, 'info, Withdraw<'info>> {
     }
 }
 
-impl<'info> TokenTransferCPI for Context<'_, '_, '_, 'info, Withdraw<'info>> {
+impl<'c: 'info, 'info> TokenTransferCPI for Context<'_, '_, 'c, 'info, Withdraw<'info>> {
     fn token_transfer(&self, amount: u64) -> Result<()> {
         declare_vault_seeds!(self.accounts.vault, seeds);
 
-        let cpi_accounts = Transfer {
-            from: self.accounts.vault_token_account.to_account_info().clone(),
-            to: self.accounts.user_token_account.to_account_info().clone(),
-            authority: self.accounts.vault.to_account_info().clone(),
-        };
-        let token_program = self.accounts.token_program.to_account_info().clone();
-        let cpi_context = CpiContext::new_with_signer(token_program, cpi_accounts, seeds);
-
-        token::transfer(cpi_context, amount)?;
-
-        Ok(())
+        transfer_tokens(
+            self.accounts.token_program.to_account_info(),
+            self.accounts.vault_token_account.to_account_info(),
+            self.accounts.user_token_account.to_account_info(),
+            self.accounts.vault.to_account_info(),
+            self.token_mint(&self.accounts.vault_token_account.mint)?
+                .as_ref(),
+            amount,
+            seeds,
+        )
     }
 }
 
@@ -174,3 +361,15 @@ impl<'info> UpdateUserReduceOnlyCPI for Context<'_, '_, '_, 'info, Withdraw<'inf
         Ok(())
     }
 }
+
+impl<'info> VaultHookCPI<'info> for Context<'_, '_, '_, 'info, Withdraw<'info>> {
+    fn vault_hook_invoke(
+        &self,
+        hook_program: &AccountInfo<'info>,
+        action: VaultHookAction,
+        amount: u64,
+    ) -> Result<()> {
+        implement_vault_hook_cpi!(self, hook_program, action, amount);
+        Ok(())
+    }
+}
@@ -0,0 +1,205 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{TokenAccount, TokenInterface};
+use drift::cpi::accounts::{UpdateUser, Withdraw as DriftWithdraw};
+use drift::program::Drift;
+use drift::state::user::{User, UserStats};
+
+use crate::constraints::{
+    is_authority_for_vault_depositor, is_user_for_vault, is_user_stats_for_vault,
+};
+use crate::drift_cpi::{UpdateUserDelegateCPI, UpdateUserReduceOnlyCPI, WithdrawCPI};
+use crate::error::ErrorCode;
+use crate::hook_cpi::VaultHookCPI;
+use crate::instructions::{finish_withdraw, settle_withdraw};
+use crate::state::{
+    TokenMintProvider, Vault, VaultDepositor, VaultDepositorCompanionAccountsProvider,
+    VaultDepositorFlag, VaultDepositorLoader, VaultHook, VaultHookAction, VaultHookContext,
+    VaultHookProvider,
+};
+use crate::token_cpi::{get_amount_after_transfer_fee, transfer_tokens, TokenTransferCPI};
+use crate::{
+    declare_vault_seeds, implement_update_user_delegate_cpi, implement_update_user_reduce_only_cpi,
+    implement_vault_hook_cpi, implement_withdraw,
+};
+
+/// [`withdraw`](super::withdraw) that then closes the vault depositor and returns its rent to the authority, if the
+/// withdraw left it with no shares, see [`VaultDepositor::is_closable`]. Its companion accounts are closed along with
+/// it, see [`VaultDepositorCompanionAccountsProvider`]. Any fuel left on the account is recorded in a
+/// `FuelSeasonRecord` before it's closed.
+pub fn withdraw_and_close<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, WithdrawAndClose<'info>>,
+) -> Result<()> {
+    let clock = &Clock::get()?;
+
+    // withdraw hooks are opt-in by the depositor so a hook can never lock funds in the vault
+    let vault_hook_program = ctx.vault_hook_program(&*ctx.accounts.vault.load()?)?;
+    if let Some(hook_program) = &vault_hook_program {
+        let requested_amount = ctx
+            .accounts
+            .vault_depositor
+            .load()?
+            .last_withdraw_request
+            .value;
+        ctx.vault_hook_invoke(hook_program, VaultHookAction::PreWithdraw, requested_amount)?;
+    }
+
+    let mut settled_withdraw = settle_withdraw(
+        &ctx,
+        &ctx.accounts.vault,
+        &ctx.accounts.vault_depositor,
+        &ctx.accounts.drift_user,
+        &ctx.accounts.drift_user_stats,
+        clock,
+        false,
+    )?;
+    let user_withdraw_amount = settled_withdraw.user_withdraw_amount;
+
+    // shares burned at zero vault equity pay out nothing
+    if user_withdraw_amount > 0 {
+        ctx.drift_withdraw(user_withdraw_amount)?;
+
+        let mint = ctx.token_mint(&ctx.accounts.vault_token_account.mint)?;
+        let received_amount = get_amount_after_transfer_fee(mint.as_ref(), user_withdraw_amount)?;
+        ctx.token_transfer(received_amount)?;
+    }
+
+    finish_withdraw(
+        &ctx,
+        &ctx.accounts.vault,
+        &ctx.accounts.drift_user,
+        &mut settled_withdraw,
+        clock,
+    )?;
+
+    if let Some(hook_program) = &vault_hook_program {
+        ctx.vault_hook_invoke(
+            hook_program,
+            VaultHookAction::PostWithdraw,
+            user_withdraw_amount,
+        )?;
+    }
+
+    let mut vault_depositor = ctx.accounts.vault_depositor.load_mut_migrated()?;
+    if vault_depositor.is_closable() {
+        vault_depositor.reset_fuel_amount(clock.unix_timestamp);
+        let counted = vault_depositor.has_flag(VaultDepositorFlag::Counted);
+        drop(vault_depositor);
+
+        let authority = ctx.accounts.authority.to_account_info();
+        ctx.close_companion_accounts(&ctx.accounts.vault_depositor.key(), &authority)?;
+
+        if counted {
+            let mut vault = ctx.accounts.vault.load_mut()?;
+            vault.depositor_count = vault.depositor_count.saturating_sub(1);
+        }
+
+        ctx.accounts.vault_depositor.close(authority)?;
+    } else {
+        msg!("vault depositor is not closable, see VaultDepositor::is_closable");
+    }
+
+    settled_withdraw.compute_units_hint.set_return_data()?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct WithdrawAndClose<'info> {
+    #[account(mut)]
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        mut,
+        seeds = [b"vault_depositor", vault.key().as_ref(), authority.key().as_ref()],
+        bump,
+        constraint = is_authority_for_vault_depositor(&vault_depositor, &authority)?,
+    )]
+    pub vault_depositor: AccountLoader<'info, VaultDepositor>,
+    /// receives the rent of the vault depositor and its companion accounts
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"vault_token_account".as_ref(), vault.key().as_ref()],
+        bump,
+    )]
+    pub vault_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        constraint = is_user_stats_for_vault(&vault, &drift_user_stats.key())?
+    )]
+    /// CHECK: checked in drift cpi
+    pub drift_user_stats: AccountLoader<'info, UserStats>,
+    #[account(
+        mut,
+        constraint = is_user_for_vault(&vault, &drift_user.key())?
+    )]
+    /// CHECK: checked in drift cpi
+    pub drift_user: AccountLoader<'info, User>,
+    /// CHECK: checked in drift cpi
+    pub drift_state: AccountInfo<'info>,
+    #[account(
+        mut,
+        token::mint = vault_token_account.mint
+    )]
+    pub drift_spot_market_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+    /// CHECK: checked in drift cpi
+    pub drift_signer: AccountInfo<'info>,
+    #[account(
+        mut,
+        token::authority = authority,
+        token::mint = vault_token_account.mint
+    )]
+    pub user_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub drift_program: Program<'info, Drift>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> WithdrawCPI for Context<'_, '_, '_, 'info, WithdrawAndClose<'info>> {
+    fn drift_withdraw(&self, amount: u64) -> Result<()> {
+        implement_withdraw!(self, amount);
+        Ok(())
+    }
+}
+
+impl<'c: 'info, 'info> TokenTransferCPI for Context<'_, '_, 'c, 'info, WithdrawAndClose<'info>> {
+    fn token_transfer(&self, amount: u64) -> Result<()> {
+        declare_vault_seeds!(self.accounts.vault, seeds);
+
+        transfer_tokens(
+            self.accounts.token_program.to_account_info(),
+            self.accounts.vault_token_account.to_account_info(),
+            self.accounts.user_token_account.to_account_info(),
+            self.accounts.vault.to_account_info(),
+            self.token_mint(&self.accounts.vault_token_account.mint)?
+                .as_ref(),
+            amount,
+            seeds,
+        )
+    }
+}
+
+impl<'info> UpdateUserDelegateCPI for Context<'_, '_, '_, 'info, WithdrawAndClose<'info>> {
+    fn drift_update_user_delegate(&self, delegate: Pubkey) -> Result<()> {
+        implement_update_user_delegate_cpi!(self, delegate);
+        Ok(())
+    }
+}
+
+impl<'info> UpdateUserReduceOnlyCPI for Context<'_, '_, '_, 'info, WithdrawAndClose<'info>> {
+    fn drift_update_user_reduce_only(&self, reduce_only: bool) -> Result<()> {
+        implement_update_user_reduce_only_cpi!(self, reduce_only);
+        Ok(())
+    }
+}
+
+impl<'info> VaultHookCPI<'info> for Context<'_, '_, '_, 'info, WithdrawAndClose<'info>> {
+    fn vault_hook_invoke(
+        &self,
+        hook_program: &AccountInfo<'info>,
+        action: VaultHookAction,
+        amount: u64,
+    ) -> Result<()> {
+        implement_vault_hook_cpi!(self, hook_program, action, amount);
+        Ok(())
+    }
+}
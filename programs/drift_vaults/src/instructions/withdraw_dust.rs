@@ -0,0 +1,100 @@
+use anchor_lang::prelude::*;
+use drift::instructions::optional_accounts::AccountMaps;
+use drift::math::safe_math::SafeMath;
+use drift::state::user::FuelOverflowStatus;
+
+use crate::error::ErrorCode;
+use crate::state::{
+    FuelOverflowProvider, VaultConfigProvider, VaultDepositorLoader,
+    VaultDepositorWithdrawRequestsProvider, VaultDustWithdrawProvider, VaultFeature,
+    VaultProtocolProvider, WithdrawUnit,
+};
+use crate::{validate, AccountMapProvider};
+
+use super::Withdraw;
+
+/// Requests and withdraws the depositor's whole balance in one instruction, skipping the redeem period, if the
+/// balance is within the vault's [`VaultDustWithdraw`](crate::state::VaultDustWithdraw) threshold. The
+/// [`VaultDustWithdraw`](crate::state::VaultDustWithdraw) must be writable in remaining_accounts after the market
+/// accounts.
+pub fn withdraw_dust<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, Withdraw<'info>>,
+) -> Result<()> {
+    let clock = &Clock::get()?;
+    let now = clock.unix_timestamp;
+
+    let vault_dust_withdraw = ctx.vault_dust_withdraw(&ctx.accounts.vault.key())?;
+
+    {
+        let mut vault = ctx.accounts.vault.load_mut()?;
+        vault.validate_feature_enabled(VaultFeature::DustWithdraw)?;
+        validate!(
+            !vault.withdraw_settlement,
+            ErrorCode::InvalidDustWithdraw,
+            "vault settles withdraws, dust withdraws must wait for a settlement"
+        )?;
+
+        let mut vault_depositor = ctx.accounts.vault_depositor.load_mut_migrated()?;
+        validate!(
+            !vault_depositor.last_withdraw_request.pending(),
+            ErrorCode::InvalidDustWithdraw,
+            "withdraw request already pending"
+        )?;
+
+        let vault_config = ctx.vault_config(&vault)?;
+
+        let mut vp = ctx.vault_protocol();
+        vault.validate_vault_protocol(&vp)?;
+        let mut vp = vp.as_mut().map(|vp| vp.load_mut()).transpose()?;
+
+        let user = ctx.accounts.drift_user.load()?;
+
+        let user_stats = ctx.accounts.drift_user_stats.load()?;
+        let has_fuel_overflow = FuelOverflowStatus::exists(user_stats.fuel_overflow_status);
+        let fuel_overflow = ctx.fuel_overflow(vp.is_some(), has_fuel_overflow);
+        user_stats.validate_fuel_overflow(&fuel_overflow)?;
+
+        let AccountMaps {
+            perp_market_map,
+            spot_market_map,
+            mut oracle_map,
+        } = ctx.load_maps(clock.slot, None, vp.is_some(), has_fuel_overflow)?;
+
+        let vault_equity =
+            vault.calculate_equity(&user, &perp_market_map, &spot_market_map, &mut oracle_map)?;
+
+        let spot_market = spot_market_map.get_ref(&vault.spot_market_index)?;
+        let oracle = oracle_map.get_price_data(&spot_market.oracle_id())?;
+
+        let last_withdraw_ts = vault_depositor.last_withdraw_request.ts;
+        vault_depositor.request_withdraw(
+            1_000_000, // 100%
+            WithdrawUnit::SharesPercent,
+            vault_equity,
+            &mut vault,
+            &vault_config,
+            &mut vp,
+            now,
+            &user_stats,
+            &fuel_overflow,
+            oracle.price,
+        )?;
+
+        if let Some(withdraw_requests) = ctx.vault_depositor_withdraw_requests(&vault_depositor)? {
+            withdraw_requests
+                .load()?
+                .validate_request(&vault_depositor)?;
+        }
+
+        vault_dust_withdraw.load_mut()?.record(
+            vault_depositor.last_withdraw_request.value,
+            last_withdraw_ts,
+            now,
+        )?;
+
+        // the request matures immediately
+        vault_depositor.last_withdraw_request.ts = now.safe_sub(vault.redeem_period)?;
+    }
+
+    super::withdraw(ctx)
+}
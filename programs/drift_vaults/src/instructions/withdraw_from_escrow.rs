@@ -0,0 +1,170 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Transfer};
+use anchor_spl::token::{Token, TokenAccount};
+use drift::cpi::accounts::Withdraw as DriftWithdraw;
+use drift::instructions::optional_accounts::AccountMaps;
+use drift::program::Drift;
+use drift::state::user::User;
+
+use crate::constraints::{
+    is_authority_for_vault_depositor, is_user_stats_for_vault, is_vault_for_vault_depositor,
+};
+use crate::drift_cpi::WithdrawCPI;
+use crate::state::{
+    validate_withdraw_not_paused, Vault, VaultDepositor, VaultDepositorWithdrawEscrow,
+    VaultWithdrawEscrow,
+};
+use crate::token_cpi::TokenTransferCPI;
+use crate::{declare_vault_seeds, AccountMapProvider};
+
+/// Pays out the depositor's [escrowed](crate::instructions::escrow_withdraw_request) withdraw request, with the lend
+/// interest it accrued, once it has matured.
+pub fn withdraw_from_escrow<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, WithdrawFromEscrow<'info>>,
+) -> Result<()> {
+    let clock = &Clock::get()?;
+
+    let spot_market_index = ctx.accounts.vault.load()?.spot_market_index;
+    let escrow_user = ctx.accounts.escrow_drift_user.load()?;
+
+    let AccountMaps {
+        spot_market_map, ..
+    } = ctx.load_maps(clock.slot, Some(spot_market_index), false, false)?;
+
+    let spot_market = spot_market_map.get_ref(&spot_market_index)?;
+    validate_withdraw_not_paused(&spot_market)?;
+    let escrow_balance = VaultWithdrawEscrow::get_escrow_balance(&escrow_user, &spot_market)?;
+
+    let escrow_shares = ctx
+        .accounts
+        .vault_depositor_withdraw_escrow
+        .load_mut()?
+        .release(clock.unix_timestamp)?;
+    let withdraw_amount = ctx
+        .accounts
+        .vault_withdraw_escrow
+        .load_mut()?
+        .withdraw(escrow_shares, escrow_balance)?;
+
+    msg!(
+        "escrow_shares: {}, withdraw_amount: {}",
+        escrow_shares,
+        withdraw_amount
+    );
+
+    drop(spot_market);
+    drop(escrow_user);
+
+    ctx.drift_withdraw(withdraw_amount)?;
+
+    ctx.token_transfer(withdraw_amount)?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct WithdrawFromEscrow<'info> {
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        constraint = is_vault_for_vault_depositor(&vault_depositor, &vault)?,
+        constraint = is_authority_for_vault_depositor(&vault_depositor, &authority)?,
+    )]
+    pub vault_depositor: AccountLoader<'info, VaultDepositor>,
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"vault_withdraw_escrow", vault.key().as_ref()],
+        bump = vault_withdraw_escrow.load()?.bump,
+    )]
+    pub vault_withdraw_escrow: AccountLoader<'info, VaultWithdrawEscrow>,
+    #[account(
+        mut,
+        seeds = [b"vault_depositor_withdraw_escrow", vault_depositor.key().as_ref()],
+        bump = vault_depositor_withdraw_escrow.load()?.bump,
+    )]
+    pub vault_depositor_withdraw_escrow: AccountLoader<'info, VaultDepositorWithdrawEscrow>,
+    #[account(
+        mut,
+        seeds = [b"vault_token_account".as_ref(), vault.key().as_ref()],
+        bump,
+    )]
+    pub vault_token_account: Box<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        constraint = is_user_stats_for_vault(&vault, &drift_user_stats.key())?
+    )]
+    /// CHECK: checked in drift cpi
+    pub drift_user_stats: AccountInfo<'info>,
+    #[account(
+        mut,
+        constraint = vault_withdraw_escrow.load()?.escrow_user.eq(&escrow_drift_user.key())
+    )]
+    /// CHECK: checked in drift cpi
+    pub escrow_drift_user: AccountLoader<'info, User>,
+    /// CHECK: checked in drift cpi
+    pub drift_state: AccountInfo<'info>,
+    #[account(
+        mut,
+        token::mint = vault_token_account.mint
+    )]
+    pub drift_spot_market_vault: Box<Account<'info, TokenAccount>>,
+    /// CHECK: checked in drift cpi
+    pub drift_signer: AccountInfo<'info>,
+    #[account(
+        mut,
+        token::authority = authority,
+        token::mint = vault_token_account.mint
+    )]
+    pub user_token_account: Box<Account<'info, TokenAccount>>,
+    pub drift_program: Program<'info, Drift>,
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> WithdrawCPI for Context<'_, '_, '_, 'info, WithdrawFromEscrow<'info>> {
+    fn drift_withdraw(&self, amount: u64) -> Result<()> {
+        declare_vault_seeds!(self.accounts.vault, seeds);
+
+        let spot_market_index = self.accounts.vault.load()?.spot_market_index;
+
+        let cpi_accounts = DriftWithdraw {
+            state: self.accounts.drift_state.to_account_info().clone(),
+            user: self.accounts.escrow_drift_user.to_account_info().clone(),
+            user_stats: self.accounts.drift_user_stats.to_account_info().clone(),
+            authority: self.accounts.vault.to_account_info().clone(),
+            spot_market_vault: self
+                .accounts
+                .drift_spot_market_vault
+                .to_account_info()
+                .clone(),
+            drift_signer: self.accounts.drift_signer.to_account_info().clone(),
+            user_token_account: self.accounts.vault_token_account.to_account_info().clone(),
+            token_program: self.accounts.token_program.to_account_info().clone(),
+        };
+
+        let drift_program = self.accounts.drift_program.to_account_info().clone();
+        let cpi_context = CpiContext::new_with_signer(drift_program, cpi_accounts, seeds)
+            .with_remaining_accounts(self.remaining_accounts.into());
+        // reduce only, the escrow user is lend-only and must never borrow
+        drift::cpi::withdraw(cpi_context, spot_market_index, amount, true)?;
+
+        Ok(())
+    }
+}
+
+impl<'info> TokenTransferCPI for Context<'_, '_, '_, 'info, WithdrawFromEscrow<'info>> {
+    fn token_transfer(&self, amount: u64) -> Result<()> {
+        declare_vault_seeds!(self.accounts.vault, seeds);
+
+        let cpi_accounts = Transfer {
+            from: self.accounts.vault_token_account.to_account_info().clone(),
+            to: self.accounts.user_token_account.to_account_info().clone(),
+            authority: self.accounts.vault.to_account_info().clone(),
+        };
+        let token_program = self.accounts.token_program.to_account_info().clone();
+        let cpi_context = CpiContext::new_with_signer(token_program, cpi_accounts, seeds);
+
+        token::transfer(cpi_context, amount)?;
+
+        Ok(())
+    }
+}
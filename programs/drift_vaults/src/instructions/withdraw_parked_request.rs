@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+use crate::error::ErrorCode;
+use crate::state::{VaultDepositorLoader, VaultDepositorWithdrawRequestsProvider};
+
+use super::{CancelWithdrawRequest, Withdraw};
+
+/// Withdraws the depositor's parked withdraw request at `index` once its own redeem period has passed, see
+/// [`park_withdraw_request`](crate::instructions::park_withdraw_request). The depositor can't have a pending request
+/// and its [`VaultDepositorWithdrawRequests`](crate::state::VaultDepositorWithdrawRequests) must be writable in
+/// remaining_accounts after the market accounts.
+pub fn withdraw_parked_request<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, Withdraw<'info>>,
+    index: u8,
+) -> Result<()> {
+    {
+        let mut vault_depositor = ctx.accounts.vault_depositor.load_mut_migrated()?;
+        ctx.vault_depositor_withdraw_requests(&vault_depositor)?
+            .ok_or(ErrorCode::InvalidVaultDepositorWithdrawRequests)?
+            .load_mut()?
+            .unpark(&mut vault_depositor, index as usize)?;
+    }
+
+    super::withdraw(ctx)
+}
+
+/// Cancels the depositor's parked withdraw request at `index` like
+/// [`cancel_withdraw_request`](crate::instructions::cancel_withdraw_request), forfeiting any gain since it was made.
+pub fn cancel_parked_withdraw_request<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, CancelWithdrawRequest<'info>>,
+    index: u8,
+) -> Result<()> {
+    {
+        let mut vault_depositor = ctx.accounts.vault_depositor.load_mut_migrated()?;
+        ctx.vault_depositor_withdraw_requests(&vault_depositor)?
+            .ok_or(ErrorCode::InvalidVaultDepositorWithdrawRequests)?
+            .load_mut()?
+            .unpark(&mut vault_depositor, index as usize)?;
+    }
+
+    super::cancel_withdraw_request(ctx)
+}
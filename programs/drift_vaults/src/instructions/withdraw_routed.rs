@@ -0,0 +1,314 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{TokenAccount, TokenInterface};
+use drift::cpi::accounts::{UpdateUser, Withdraw as DriftWithdraw};
+use drift::math::casting::Cast;
+use drift::math::safe_math::SafeMath;
+use drift::program::Drift;
+use drift::state::user::{User, UserStats};
+
+use crate::constraints::{
+    is_authority_for_vault_depositor, is_user_for_vault, is_user_stats_for_vault,
+};
+use crate::drift_cpi::{
+    UpdateUserDelegateCPI, UpdateUserReduceOnlyCPI, WithdrawCPI, WithdrawRouteCPI,
+};
+use crate::error::ErrorCode;
+use crate::hook_cpi::VaultHookCPI;
+use crate::instructions::{finish_withdraw, settle_withdraw};
+use crate::state::{
+    calculate_routed_withdraw_amount, TokenMintProvider, Vault, VaultDepositor, VaultHook,
+    VaultHookAction, VaultHookContext, VaultHookProvider, VaultWithdrawRoute,
+    WithdrawRouteAccounts, WithdrawRouteAccountsProvider,
+};
+use crate::token_cpi::{
+    get_amount_after_transfer_fee, transfer_tokens, TokenTransferCPI, TokenTransferRouteCPI,
+};
+use crate::{
+    declare_vault_seeds, implement_update_user_delegate_cpi, implement_update_user_reduce_only_cpi,
+    implement_vault_hook_cpi, implement_withdraw,
+};
+
+/// Like [`withdraw`](crate::instructions::withdraw) but draws the payout from the [`VaultWithdrawRoute`] markets in
+/// priority order before falling back to the vault's spot market. Routed markets are paid out in their own token at
+/// oracle price and are skipped if the vault has no deposit in them. Fails if a routed market the vault has a deposit
+/// in is missing its token accounts, so the depositor can't pick the payout asset by leaving accounts out.
+pub fn withdraw_routed<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, WithdrawRouted<'info>>,
+) -> Result<()> {
+    let clock = &Clock::get()?;
+
+    // withdraw hooks are opt-in by the depositor so a hook can never lock funds in the vault
+    let vault_hook_program = ctx.vault_hook_program(&*ctx.accounts.vault.load()?)?;
+    if let Some(hook_program) = &vault_hook_program {
+        let requested_amount = ctx
+            .accounts
+            .vault_depositor
+            .load()?
+            .last_withdraw_request
+            .value;
+        ctx.vault_hook_invoke(hook_program, VaultHookAction::PreWithdraw, requested_amount)?;
+    }
+
+    let mut settled_withdraw = settle_withdraw(
+        &ctx,
+        &ctx.accounts.vault,
+        &ctx.accounts.vault_depositor,
+        &ctx.accounts.drift_user,
+        &ctx.accounts.drift_user_stats,
+        clock,
+        false,
+    )?;
+    let user_withdraw_amount = settled_withdraw.user_withdraw_amount;
+
+    let vault_key = ctx.accounts.vault.key();
+    let authority = ctx.accounts.authority.key();
+    let token_program = ctx.accounts.token_program.to_account_info();
+    let spot_market_index = ctx.accounts.vault.load()?.spot_market_index;
+    let user = ctx.accounts.drift_user.load()?;
+    let withdraw_route = ctx.accounts.vault_withdraw_route.load()?;
+    let spot_market_map = &settled_withdraw.account_maps.spot_market_map;
+    let oracle_map = &mut settled_withdraw.account_maps.oracle_map;
+    let vault_decimals = spot_market_map.get_ref(&spot_market_index)?.decimals;
+
+    let mut remaining_amount = user_withdraw_amount;
+    let mut routes = Vec::with_capacity(withdraw_route.num_markets as usize);
+    for market_index in withdraw_route.markets() {
+        if remaining_amount == 0 {
+            break;
+        }
+
+        let spot_position = match user.get_spot_position(*market_index) {
+            Ok(spot_position) => spot_position,
+            Err(_) => continue,
+        };
+        let route_spot_market = spot_market_map.get_ref(market_index)?;
+        let token_amount = spot_position.get_signed_token_amount(&route_spot_market)?;
+        if token_amount <= 0 {
+            continue;
+        }
+
+        let route_accounts = ctx
+            .withdraw_route_accounts(&route_spot_market, &vault_key, &authority, &token_program)?
+            .ok_or_else(|| {
+                msg!(
+                    "token accounts for routed market {} not passed",
+                    market_index
+                );
+                ErrorCode::InvalidWithdrawRoute
+            })?;
+
+        let route_oracle_price = oracle_map
+            .get_price_data(&route_spot_market.oracle_id())?
+            .price;
+        let (route_amount, vault_amount) = calculate_routed_withdraw_amount(
+            remaining_amount,
+            settled_withdraw.vault_oracle_price,
+            vault_decimals,
+            token_amount.cast()?,
+            route_oracle_price,
+            route_spot_market.decimals,
+        )?;
+        if route_amount == 0 {
+            continue;
+        }
+
+        msg!(
+            "routing {} from market {} ({} of market {})",
+            route_amount,
+            market_index,
+            vault_amount,
+            spot_market_index
+        );
+        remaining_amount = remaining_amount.safe_sub(vault_amount)?;
+        routes.push((*market_index, route_amount, route_accounts));
+    }
+
+    drop(withdraw_route);
+    drop(user);
+
+    for (market_index, route_amount, route_accounts) in routes.iter() {
+        ctx.drift_withdraw_route(*market_index, *route_amount, route_accounts)?;
+        let route_received_amount =
+            get_amount_after_transfer_fee(route_accounts.mint.as_ref(), *route_amount)?;
+        ctx.token_transfer_route(route_received_amount, route_accounts)?;
+    }
+
+    if remaining_amount > 0 {
+        ctx.drift_withdraw(remaining_amount)?;
+        let mint = ctx.token_mint(&ctx.accounts.vault_token_account.mint)?;
+        let received_amount = get_amount_after_transfer_fee(mint.as_ref(), remaining_amount)?;
+        ctx.token_transfer(received_amount)?;
+    }
+
+    finish_withdraw(
+        &ctx,
+        &ctx.accounts.vault,
+        &ctx.accounts.drift_user,
+        &mut settled_withdraw,
+        clock,
+    )?;
+
+    if let Some(hook_program) = &vault_hook_program {
+        ctx.vault_hook_invoke(
+            hook_program,
+            VaultHookAction::PostWithdraw,
+            user_withdraw_amount,
+        )?;
+    }
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct WithdrawRouted<'info> {
+    #[account(mut)]
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        mut,
+        seeds = [b"vault_depositor", vault.key().as_ref(), authority.key().as_ref()],
+        bump,
+        constraint = is_authority_for_vault_depositor(&vault_depositor, &authority)?,
+    )]
+    pub vault_depositor: AccountLoader<'info, VaultDepositor>,
+    #[account(
+        seeds = [b"vault_withdraw_route", vault.key().as_ref()],
+        bump = vault_withdraw_route.load()?.bump,
+    )]
+    pub vault_withdraw_route: AccountLoader<'info, VaultWithdrawRoute>,
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"vault_token_account".as_ref(), vault.key().as_ref()],
+        bump,
+    )]
+    pub vault_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        constraint = is_user_stats_for_vault(&vault, &drift_user_stats.key())?
+    )]
+    /// CHECK: checked in drift cpi
+    pub drift_user_stats: AccountLoader<'info, UserStats>,
+    #[account(
+        mut,
+        constraint = is_user_for_vault(&vault, &drift_user.key())?
+    )]
+    /// CHECK: checked in drift cpi
+    pub drift_user: AccountLoader<'info, User>,
+    /// CHECK: checked in drift cpi
+    pub drift_state: AccountInfo<'info>,
+    #[account(
+        mut,
+        token::mint = vault_token_account.mint
+    )]
+    pub drift_spot_market_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+    /// CHECK: checked in drift cpi
+    pub drift_signer: AccountInfo<'info>,
+    #[account(
+        mut,
+        token::authority = authority,
+        token::mint = vault_token_account.mint
+    )]
+    pub user_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub drift_program: Program<'info, Drift>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> WithdrawCPI for Context<'_, '_, '_, 'info, WithdrawRouted<'info>> {
+    fn drift_withdraw(&self, amount: u64) -> Result<()> {
+        implement_withdraw!(self, amount);
+        Ok(())
+    }
+}
+
+impl<'info> WithdrawRouteCPI<'info> for Context<'_, '_, '_, 'info, WithdrawRouted<'info>> {
+    fn drift_withdraw_route(
+        &self,
+        market_index: u16,
+        amount: u64,
+        route_accounts: &WithdrawRouteAccounts<'info>,
+    ) -> Result<()> {
+        declare_vault_seeds!(self.accounts.vault, seeds);
+
+        let cpi_accounts = DriftWithdraw {
+            state: self.accounts.drift_state.to_account_info().clone(),
+            user: self.accounts.drift_user.to_account_info().clone(),
+            user_stats: self.accounts.drift_user_stats.to_account_info().clone(),
+            authority: self.accounts.vault.to_account_info().clone(),
+            spot_market_vault: route_accounts.spot_market_vault.clone(),
+            drift_signer: self.accounts.drift_signer.to_account_info().clone(),
+            user_token_account: route_accounts.vault_token_account.clone(),
+            token_program: route_accounts.token_program.clone(),
+        };
+
+        let drift_program = self.accounts.drift_program.to_account_info().clone();
+        let cpi_context = CpiContext::new_with_signer(drift_program, cpi_accounts, seeds)
+            .with_remaining_accounts(self.remaining_accounts.into());
+        // reduce only, a routed market can never be borrowed from
+        drift::cpi::withdraw(cpi_context, market_index, amount, true)?;
+
+        Ok(())
+    }
+}
+
+impl<'c: 'info, 'info> TokenTransferCPI for Context<'_, '_, 'c, 'info, WithdrawRouted<'info>> {
+    fn token_transfer(&self, amount: u64) -> Result<()> {
+        declare_vault_seeds!(self.accounts.vault, seeds);
+
+        transfer_tokens(
+            self.accounts.token_program.to_account_info(),
+            self.accounts.vault_token_account.to_account_info(),
+            self.accounts.user_token_account.to_account_info(),
+            self.accounts.vault.to_account_info(),
+            self.token_mint(&self.accounts.vault_token_account.mint)?
+                .as_ref(),
+            amount,
+            seeds,
+        )
+    }
+}
+
+impl<'info> TokenTransferRouteCPI<'info> for Context<'_, '_, '_, 'info, WithdrawRouted<'info>> {
+    fn token_transfer_route(
+        &self,
+        amount: u64,
+        route_accounts: &WithdrawRouteAccounts<'info>,
+    ) -> Result<()> {
+        declare_vault_seeds!(self.accounts.vault, seeds);
+
+        transfer_tokens(
+            route_accounts.token_program.clone(),
+            route_accounts.vault_token_account.clone(),
+            route_accounts.user_token_account.clone(),
+            self.accounts.vault.to_account_info(),
+            route_accounts.mint.as_ref(),
+            amount,
+            seeds,
+        )
+    }
+}
+
+impl<'info> UpdateUserDelegateCPI for Context<'_, '_, '_, 'info, WithdrawRouted<'info>> {
+    fn drift_update_user_delegate(&self, delegate: Pubkey) -> Result<()> {
+        implement_update_user_delegate_cpi!(self, delegate);
+        Ok(())
+    }
+}
+
+impl<'info> UpdateUserReduceOnlyCPI for Context<'_, '_, '_, 'info, WithdrawRouted<'info>> {
+    fn drift_update_user_reduce_only(&self, reduce_only: bool) -> Result<()> {
+        implement_update_user_reduce_only_cpi!(self, reduce_only);
+        Ok(())
+    }
+}
+
+impl<'info> VaultHookCPI<'info> for Context<'_, '_, '_, 'info, WithdrawRouted<'info>> {
+    fn vault_hook_invoke(
+        &self,
+        hook_program: &AccountInfo<'info>,
+        action: VaultHookAction,
+        amount: u64,
+    ) -> Result<()> {
+        implement_vault_hook_cpi!(self, hook_program, action, amount);
+        Ok(())
+    }
+}
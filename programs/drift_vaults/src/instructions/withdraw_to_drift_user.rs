@@ -0,0 +1,226 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Transfer};
+use anchor_spl::token::{Token, TokenAccount};
+use drift::cpi::accounts::{Deposit as DriftDeposit, UpdateUser, Withdraw as DriftWithdraw};
+use drift::program::Drift;
+use drift::state::user::{User, UserStats};
+
+use crate::constraints::{
+    is_authority_for_drift_user, is_authority_for_vault_depositor, is_user_for_vault,
+    is_user_stats_for_vault,
+};
+use crate::drift_cpi::{
+    DepositToUserCPI, UpdateUserDelegateCPI, UpdateUserReduceOnlyCPI, WithdrawCPI,
+};
+use crate::error::ErrorCode;
+use crate::hook_cpi::VaultHookCPI;
+use crate::instructions::{finish_withdraw, settle_withdraw};
+use crate::state::{
+    Vault, VaultDepositor, VaultHook, VaultHookAction, VaultHookContext, VaultHookProvider,
+};
+use crate::token_cpi::TokenTransferCPI;
+use crate::{
+    declare_vault_seeds, implement_update_user_delegate_cpi, implement_update_user_reduce_only_cpi,
+    implement_vault_hook_cpi, implement_withdraw,
+};
+
+/// Like [`withdraw`](crate::instructions::withdraw) but deposits the proceeds into the depositor's own drift user
+/// instead of leaving them in their wallet. The payout passes through `user_token_account` within the instruction.
+pub fn withdraw_to_drift_user<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, WithdrawToDriftUser<'info>>,
+) -> Result<()> {
+    let clock = &Clock::get()?;
+
+    // withdraw hooks are opt-in by the depositor so a hook can never lock funds in the vault
+    let vault_hook_program = ctx.vault_hook_program(&*ctx.accounts.vault.load()?)?;
+    if let Some(hook_program) = &vault_hook_program {
+        let requested_amount = ctx
+            .accounts
+            .vault_depositor
+            .load()?
+            .last_withdraw_request
+            .value;
+        ctx.vault_hook_invoke(hook_program, VaultHookAction::PreWithdraw, requested_amount)?;
+    }
+
+    let mut settled_withdraw = settle_withdraw(
+        &ctx,
+        &ctx.accounts.vault,
+        &ctx.accounts.vault_depositor,
+        &ctx.accounts.drift_user,
+        &ctx.accounts.drift_user_stats,
+        clock,
+        false,
+    )?;
+    let user_withdraw_amount = settled_withdraw.user_withdraw_amount;
+
+    // shares burned at zero vault equity pay out nothing
+    if user_withdraw_amount > 0 {
+        ctx.drift_withdraw(user_withdraw_amount)?;
+
+        ctx.token_transfer(user_withdraw_amount)?;
+
+        ctx.drift_deposit_to_user(user_withdraw_amount)?;
+    }
+
+    finish_withdraw(
+        &ctx,
+        &ctx.accounts.vault,
+        &ctx.accounts.drift_user,
+        &mut settled_withdraw,
+        clock,
+    )?;
+
+    if let Some(hook_program) = &vault_hook_program {
+        ctx.vault_hook_invoke(
+            hook_program,
+            VaultHookAction::PostWithdraw,
+            user_withdraw_amount,
+        )?;
+    }
+
+    settled_withdraw.compute_units_hint.set_return_data()?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct WithdrawToDriftUser<'info> {
+    #[account(mut)]
+    pub vault: AccountLoader<'info, Vault>,
+    #[account(
+        mut,
+        seeds = [b"vault_depositor", vault.key().as_ref(), authority.key().as_ref()],
+        bump,
+        constraint = is_authority_for_vault_depositor(&vault_depositor, &authority)?,
+    )]
+    pub vault_depositor: AccountLoader<'info, VaultDepositor>,
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"vault_token_account".as_ref(), vault.key().as_ref()],
+        bump,
+    )]
+    pub vault_token_account: Box<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        constraint = is_user_stats_for_vault(&vault, &drift_user_stats.key())?
+    )]
+    /// CHECK: checked in drift cpi
+    pub drift_user_stats: AccountLoader<'info, UserStats>,
+    #[account(
+        mut,
+        constraint = is_user_for_vault(&vault, &drift_user.key())?
+    )]
+    /// CHECK: checked in drift cpi
+    pub drift_user: AccountLoader<'info, User>,
+    /// CHECK: checked in drift cpi
+    pub drift_state: AccountInfo<'info>,
+    #[account(
+        mut,
+        token::mint = vault_token_account.mint
+    )]
+    pub drift_spot_market_vault: Box<Account<'info, TokenAccount>>,
+    /// CHECK: checked in drift cpi
+    pub drift_signer: AccountInfo<'info>,
+    #[account(
+        mut,
+        token::authority = authority,
+        token::mint = vault_token_account.mint
+    )]
+    pub user_token_account: Box<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        constraint = is_authority_for_drift_user(&depositor_drift_user, &authority)?
+    )]
+    /// CHECK: checked in drift cpi
+    pub depositor_drift_user: AccountLoader<'info, User>,
+    #[account(mut)]
+    /// CHECK: checked in drift cpi
+    pub depositor_drift_user_stats: AccountLoader<'info, UserStats>,
+    pub drift_program: Program<'info, Drift>,
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> WithdrawCPI for Context<'_, '_, '_, 'info, WithdrawToDriftUser<'info>> {
+    fn drift_withdraw(&self, amount: u64) -> Result<()> {
+        implement_withdraw!(self, amount);
+        Ok(())
+    }
+}
+
+impl<'info> DepositToUserCPI for Context<'_, '_, '_, 'info, WithdrawToDriftUser<'info>> {
+    fn drift_deposit_to_user(&self, amount: u64) -> Result<()> {
+        let spot_market_index = self.accounts.vault.load()?.spot_market_index;
+
+        let cpi_accounts = DriftDeposit {
+            state: self.accounts.drift_state.to_account_info().clone(),
+            user: self.accounts.depositor_drift_user.to_account_info().clone(),
+            user_stats: self
+                .accounts
+                .depositor_drift_user_stats
+                .to_account_info()
+                .clone(),
+            authority: self.accounts.authority.to_account_info().clone(),
+            spot_market_vault: self
+                .accounts
+                .drift_spot_market_vault
+                .to_account_info()
+                .clone(),
+            user_token_account: self.accounts.user_token_account.to_account_info().clone(),
+            token_program: self.accounts.token_program.to_account_info().clone(),
+        };
+
+        let drift_program = self.accounts.drift_program.to_account_info().clone();
+        // signed by the depositor, the vault has no authority over their drift user
+        let cpi_context = CpiContext::new(drift_program, cpi_accounts)
+            .with_remaining_accounts(self.remaining_accounts.into());
+        drift::cpi::deposit(cpi_context, spot_market_index, amount, false)?;
+
+        Ok(())
+    }
+}
+
+impl<'info> TokenTransferCPI for Context<'_, '_, '_, 'info, WithdrawToDriftUser<'info>> {
+    fn token_transfer(&self, amount: u64) -> Result<()> {
+        declare_vault_seeds!(self.accounts.vault, seeds);
+
+        let cpi_accounts = Transfer {
+            from: self.accounts.vault_token_account.to_account_info().clone(),
+            to: self.accounts.user_token_account.to_account_info().clone(),
+            authority: self.accounts.vault.to_account_info().clone(),
+        };
+        let token_program = self.accounts.token_program.to_account_info().clone();
+        let cpi_context = CpiContext::new_with_signer(token_program, cpi_accounts, seeds);
+
+        token::transfer(cpi_context, amount)?;
+
+        Ok(())
+    }
+}
+
+impl<'info> UpdateUserDelegateCPI for Context<'_, '_, '_, 'info, WithdrawToDriftUser<'info>> {
+    fn drift_update_user_delegate(&self, delegate: Pubkey) -> Result<()> {
+        implement_update_user_delegate_cpi!(self, delegate);
+        Ok(())
+    }
+}
+
+impl<'info> UpdateUserReduceOnlyCPI for Context<'_, '_, '_, 'info, WithdrawToDriftUser<'info>> {
+    fn drift_update_user_reduce_only(&self, reduce_only: bool) -> Result<()> {
+        implement_update_user_reduce_only_cpi!(self, reduce_only);
+        Ok(())
+    }
+}
+
+impl<'info> VaultHookCPI<'info> for Context<'_, '_, '_, 'info, WithdrawToDriftUser<'info>> {
+    fn vault_hook_invoke(
+        &self,
+        hook_program: &AccountInfo<'info>,
+        action: VaultHookAction,
+        amount: u64,
+    ) -> Result<()> {
+        implement_vault_hook_cpi!(self, hook_program, action, amount);
+        Ok(())
+    }
+}
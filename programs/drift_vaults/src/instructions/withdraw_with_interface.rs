@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+use drift::math::safe_math::SafeMath;
+
+use crate::events::VaultDepositorAction;
+use crate::state::{record_interface_attribution, VaultFeature, VaultInterfaceProvider};
+
+use super::Withdraw;
+
+/// [`withdraw`](super::withdraw) attributed to the front-end `interface`. If the manager registered a
+/// [`VaultInterface`](crate::state::VaultInterface) for it, it must be writable in remaining_accounts after the market
+/// accounts to accrue the interface's fee.
+pub fn withdraw_with_interface<'c: 'info, 'info>(
+    ctx: Context<'_, '_, 'c, 'info, Withdraw<'info>>,
+    interface: Pubkey,
+) -> Result<()> {
+    let clock = &Clock::get()?;
+
+    ctx.accounts
+        .vault
+        .load()?
+        .validate_feature_enabled(VaultFeature::Interface)?;
+
+    let vault = ctx.accounts.vault.clone();
+    let authority = ctx.accounts.authority.key();
+    let vault_interface = ctx.vault_interface(&vault.key(), &interface);
+
+    let total_withdraws_before = vault.load()?.total_withdraws;
+
+    super::withdraw(ctx)?;
+
+    let vault = vault.load()?;
+    let withdraw_amount = vault.total_withdraws.safe_sub(total_withdraws_before)?;
+
+    record_interface_attribution(
+        &vault,
+        authority,
+        interface,
+        vault_interface,
+        VaultDepositorAction::Withdraw,
+        withdraw_amount,
+        clock.unix_timestamp,
+    )
+}
@@ -0,0 +1,632 @@
+//! Instruction builders for programs and clients calling into drift vaults, behind the `drift-vaults-cpi` feature.
+//!
+//! Each builder takes the instruction's anchor generated [`accounts`](crate::accounts) struct, so the account list
+//! always matches the program it was built against, followed by the remaining accounts the instruction reads (drift
+//! markets and oracles, companion pdas like [`VaultDepositorThrottle`]). The returned [`Instruction`] can be passed
+//! to `invoke_signed` with the matching account infos, or the anchor generated [`cpi`](crate::cpi) functions can be
+//! used directly when the caller already has a [`CpiContext`].
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::InstructionData;
+
+use crate::instructions::*;
+use crate::state::*;
+use crate::{accounts, instruction};
+
+/// Builds a drift vaults instruction from its accounts, data and remaining accounts.
+pub fn build_instruction(
+    accounts: impl ToAccountMetas,
+    data: impl InstructionData,
+    remaining_accounts: Vec<AccountMeta>,
+) -> Instruction {
+    let mut account_metas = accounts.to_account_metas(None);
+    account_metas.extend(remaining_accounts);
+
+    Instruction {
+        program_id: crate::id(),
+        accounts: account_metas,
+        data: data.data(),
+    }
+}
+
+pub fn vault_address(name: &[u8; 32]) -> Pubkey {
+    Pubkey::find_program_address(&[b"vault", name.as_ref()], &crate::id()).0
+}
+
+pub fn vault_depositor_address(vault: &Pubkey, authority: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[b"vault_depositor", vault.as_ref(), authority.as_ref()],
+        &crate::id(),
+    )
+    .0
+}
+
+pub fn vault_token_account_address(vault: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"vault_token_account", vault.as_ref()], &crate::id()).0
+}
+
+macro_rules! instruction_builders {
+    ($($name:ident($accounts:ident, $instruction:ident $(, $arg:ident: $arg_type:ty)*);)*) => {
+        $(
+            #[doc = concat!(
+                "Builds a [`", stringify!($name), "`](crate::drift_vaults::", stringify!($name), ") instruction."
+            )]
+            #[allow(clippy::too_many_arguments)]
+            pub fn $name(
+                accounts: accounts::$accounts,
+                $($arg: $arg_type,)*
+                remaining_accounts: Vec<AccountMeta>,
+            ) -> Instruction {
+                build_instruction(
+                    accounts,
+                    instruction::$instruction { $($arg),* },
+                    remaining_accounts,
+                )
+            }
+        )*
+    };
+}
+
+instruction_builders! {
+    initialize_vault(InitializeVault, InitializeVault, params: VaultParams);
+    initialize_vault_with_protocol(
+        InitializeVaultWithProtocol,
+        InitializeVaultWithProtocol,
+        params: VaultWithProtocolParams
+    );
+    initialize_vault_from_template(
+        InitializeVaultFromTemplate,
+        InitializeVaultFromTemplate,
+        params: VaultFromTemplateParams
+    );
+    update_delegate(UpdateDelegate, UpdateDelegate, delegate: Pubkey);
+    update_margin_trading_enabled(
+        UpdateMarginTradingEnabled,
+        UpdateMarginTradingEnabled,
+        enabled: bool
+    );
+    update_user_pool_id(UpdatePoolId, UpdateUserPoolId, pool_id: u8);
+    update_vault_protocol(
+        UpdateVaultProtocol,
+        UpdateVaultProtocol,
+        params: UpdateVaultProtocolParams
+    );
+    update_vault_protocol_fee_tiers(
+        UpdateVaultProtocolFeeTiers,
+        UpdateVaultProtocolFeeTiers,
+        protocol_fee_tiers: [ProtocolFeeTier; 4]
+    );
+    update_vault(UpdateVault, UpdateVault, params: UpdateVaultParams);
+    update_vault_manager(UpdateVault, UpdateVaultManager, manager: Pubkey);
+    initialize_vault_hook(InitializeVaultHook, InitializeVaultHook, hook_program: Pubkey);
+    update_vault_hook(UpdateVaultHook, UpdateVaultHook, hook_program: Pubkey);
+    initialize_vault_withdraw_route(
+        InitializeVaultWithdrawRoute,
+        InitializeVaultWithdrawRoute,
+        market_indexes: Vec<u16>
+    );
+    update_vault_withdraw_route(
+        UpdateVaultWithdrawRoute,
+        UpdateVaultWithdrawRoute,
+        market_indexes: Vec<u16>
+    );
+    initialize_vault_risk_score(InitializeVaultRiskScore, InitializeVaultRiskScore);
+    update_vault_risk_score(UpdateVaultRiskScore, UpdateVaultRiskScore);
+    update_cumulative_fuel_amount(UpdateCumulativeFuelAmount, UpdateCumulativeFuelAmount);
+    initialize_vault_depositor(InitializeVaultDepositor, InitializeVaultDepositor);
+    initialize_tokenized_vault_depositor(
+        InitializeTokenizedVaultDepositor,
+        InitializeTokenizedVaultDepositor,
+        params: InitializeTokenizedVaultDepositorParams
+    );
+    tokenize_shares(TokenizeShares, TokenizeShares, amount: u64, unit: WithdrawUnit);
+    redeem_tokens(RedeemTokens, RedeemTokens, tokens_to_burn: u64);
+    deposit(Deposit, Deposit, amount: u64);
+    request_withdraw(
+        RequestWithdraw,
+        RequestWithdraw,
+        withdraw_amount: u64,
+        withdraw_unit: WithdrawUnit
+    );
+    cancel_request_withdraw(CancelWithdrawRequest, CancelRequestWithdraw);
+    withdraw(Withdraw, Withdraw);
+    withdraw_routed(WithdrawRouted, WithdrawRouted);
+    liquidate(Liquidate, Liquidate);
+    reset_delegate(ResetDelegate, ResetDelegate);
+    reset_fuel_season(ResetFuelSeason, ResetFuelSeason);
+    reset_vault_fuel_season(ResetVaultFuelSeason, ResetVaultFuelSeason);
+    manager_deposit(ManagerDeposit, ManagerDeposit, amount: u64);
+    manager_request_withdraw(
+        ManagerRequestWithdraw,
+        ManagerRequestWithdraw,
+        withdraw_amount: u64,
+        withdraw_unit: WithdrawUnit
+    );
+    manger_cancel_withdraw_request(ManagerCancelWithdrawRequest, MangerCancelWithdrawRequest);
+    manager_withdraw(ManagerWithdraw, ManagerWithdraw);
+    manager_update_fuel_distribution_mode(
+        ManagerUpdateFuelDistributionMode,
+        ManagerUpdateFuelDistributionMode,
+        fuel_distribution_mode: u8
+    );
+    apply_profit_share(ApplyProfitShare, ApplyProfitShare);
+    apply_rebase(ApplyRebase, ApplyRebase);
+    apply_rebase_tokenized_depositor(ApplyRebaseTokenizedDepositor, ApplyRebaseTokenizedDepositor);
+    force_withdraw(ForceWithdraw, ForceWithdraw);
+    initialize_insurance_fund_stake(
+        InitializeInsuranceFundStake,
+        InitializeInsuranceFundStake,
+        market_index: u16
+    );
+    add_insurance_fund_stake(
+        AddInsuranceFundStake,
+        AddInsuranceFundStake,
+        market_index: u16,
+        amount: u64
+    );
+    request_remove_insurance_fund_stake(
+        RequestRemoveInsuranceFundStake,
+        RequestRemoveInsuranceFundStake,
+        market_index: u16,
+        amount: u64
+    );
+    remove_insurance_fund_stake(
+        RemoveInsuranceFundStake,
+        RemoveInsuranceFundStake,
+        market_index: u16
+    );
+    cancel_request_remove_insurance_fund_stake(
+        RequestRemoveInsuranceFundStake,
+        CancelRequestRemoveInsuranceFundStake,
+        market_index: u16
+    );
+    protocol_request_withdraw(
+        ProtocolRequestWithdraw,
+        ProtocolRequestWithdraw,
+        withdraw_amount: u64,
+        withdraw_unit: WithdrawUnit
+    );
+    protocol_cancel_withdraw_request(ProtocolCancelWithdrawRequest, ProtocolCancelWithdrawRequest);
+    protocol_withdraw(ProtocolWithdraw, ProtocolWithdraw);
+    initialize_vault_poll(InitializeVaultPoll, InitializeVaultPoll);
+    start_vault_poll(StartVaultPoll, StartVaultPoll, proposal: u8, new_manager: Pubkey);
+    vote_on_vault_poll(VoteOnVaultPoll, VoteOnVaultPoll);
+    execute_vault_poll(ExecuteVaultPoll, ExecuteVaultPoll);
+    initialize_vault_share_snapshot(
+        InitializeVaultShareSnapshot,
+        InitializeVaultShareSnapshot,
+        epoch: u32
+    );
+    snapshot_vault_depositor_shares(SnapshotVaultDepositorShares, SnapshotVaultDepositorShares);
+    initialize_protocol_loss_ledger(
+        InitializeProtocolLossLedger,
+        InitializeProtocolLossLedger,
+        authority: Pubkey
+    );
+    record_loss_carryforward(LossCarryforward, RecordLossCarryforward);
+    apply_loss_carryforward(LossCarryforward, ApplyLossCarryforward);
+    update_vault_depositor_custodian(
+        UpdateVaultDepositorCustodian,
+        UpdateVaultDepositorCustodian,
+        custodian: Pubkey
+    );
+    revoke_vault_depositor_custodian(RevokeVaultDepositorCustodian, RevokeVaultDepositorCustodian);
+    initialize_vault_depositor_idempotency(
+        InitializeVaultDepositorIdempotency,
+        InitializeVaultDepositorIdempotency
+    );
+    deposit_with_idempotency_key(
+        Deposit,
+        DepositWithIdempotencyKey,
+        amount: u64,
+        idempotency_key: u64
+    );
+    request_withdraw_with_idempotency_key(
+        RequestWithdraw,
+        RequestWithdrawWithIdempotencyKey,
+        withdraw_amount: u64,
+        withdraw_unit: WithdrawUnit,
+        idempotency_key: u64
+    );
+    update_vault_large_withdraw_notice(
+        UpdateVault,
+        UpdateVaultLargeWithdrawNotice,
+        large_withdraw_threshold: u32,
+        large_withdraw_notice_period: i64
+    );
+    initialize_vault_withdraw_settlement(
+        InitializeVaultWithdrawSettlement,
+        InitializeVaultWithdrawSettlement
+    );
+    settle_vault_withdraws(SettleVaultWithdraws, SettleVaultWithdraws);
+    update_vault_profile(UpdateVault, UpdateVaultProfile, profile: u8);
+    enforce_vault_profile(EnforceVaultProfile, EnforceVaultProfile);
+    initialize_vault_performance_stats(
+        InitializeVaultPerformanceStats,
+        InitializeVaultPerformanceStats
+    );
+    update_vault_performance_stats(UpdateVaultPerformanceStats, UpdateVaultPerformanceStats);
+    initialize_vault_interface(
+        InitializeVaultInterface,
+        InitializeVaultInterface,
+        interface: Pubkey,
+        fee_rate: u32
+    );
+    update_vault_interface(UpdateVaultInterface, UpdateVaultInterface, fee_rate: u32);
+    deposit_with_interface(Deposit, DepositWithInterface, amount: u64, interface: Pubkey);
+    withdraw_with_interface(Withdraw, WithdrawWithInterface, interface: Pubkey);
+    claim_vault_interface_fees(ClaimVaultInterfaceFees, ClaimVaultInterfaceFees);
+    initialize_vault_depositor_memo(
+        InitializeVaultDepositorMemo,
+        InitializeVaultDepositorMemo,
+        memo: [u8; 32]
+    );
+    update_vault_depositor_memo(UpdateVaultDepositorMemo, UpdateVaultDepositorMemo, memo: [u8; 32]);
+    initialize_vault_dust_withdraw(
+        InitializeVaultDustWithdraw,
+        InitializeVaultDustWithdraw,
+        threshold: u64,
+        window_max_amount: u64
+    );
+    update_vault_dust_withdraw(
+        UpdateVaultDustWithdraw,
+        UpdateVaultDustWithdraw,
+        threshold: u64,
+        window_max_amount: u64
+    );
+    withdraw_dust(Withdraw, WithdrawDust);
+    initialize_vault_withdraw_stream(InitializeVaultWithdrawStream, InitializeVaultWithdrawStream);
+    start_withdraw_stream(StartWithdrawStream, StartWithdrawStream, duration: i64);
+    claim_withdraw_stream(Withdraw, ClaimWithdrawStream);
+    simulate_withdraw(SimulateWithdraw, SimulateWithdraw);
+    simulate_withdraw_status(SimulateWithdraw, SimulateWithdrawStatus);
+    update_vault_disabled_features(UpdateVault, UpdateVaultDisabledFeatures, disabled_features: u8);
+    initialize_vault_registry(InitializeVaultRegistry, InitializeVaultRegistry);
+    initialize_vault_registry_page(
+        InitializeVaultRegistryPage,
+        InitializeVaultRegistryPage,
+        page_index: u32
+    );
+    register_vault(RegisterVault, RegisterVault);
+    migrate_vault_deposit_mint(MigrateVaultDepositMint, MigrateVaultDepositMint);
+    initialize_vault_token_account(InitializeVaultTokenAccount, InitializeVaultTokenAccount);
+    deposit_with_cancel_withdraw_request(
+        Deposit,
+        DepositWithCancelWithdrawRequest,
+        amount: u64,
+        cancel_withdraw_request: bool
+    );
+    initialize_vault_param_change_log(InitializeVaultParamChangeLog, InitializeVaultParamChangeLog);
+    initialize_vault_depositor_cap(
+        InitializeVaultDepositorCap,
+        InitializeVaultDepositorCap,
+        max_depositors: u32
+    );
+    update_vault_depositor_cap(
+        UpdateVaultDepositorCap,
+        UpdateVaultDepositorCap,
+        max_depositors: u32
+    );
+    initialize_vault_fee_holiday(InitializeVaultFeeHoliday, InitializeVaultFeeHoliday);
+    schedule_vault_fee_holiday(
+        UpdateVaultFeeHoliday,
+        ScheduleVaultFeeHoliday,
+        start_ts: i64,
+        end_ts: i64,
+        suspend_management_fee: bool,
+        suspend_profit_share: bool
+    );
+    cancel_vault_fee_holiday(UpdateVaultFeeHoliday, CancelVaultFeeHoliday);
+    crank_vault_fee_holiday(CrankVaultFeeHoliday, CrankVaultFeeHoliday);
+    update_vault_profit_share_mode(
+        UpdateVaultConfig,
+        UpdateVaultProfitShareMode,
+        profit_share_mode: u8
+    );
+    initialize_protocol_treasury(
+        InitializeProtocolTreasury,
+        InitializeProtocolTreasury,
+        treasury: Pubkey
+    );
+    collect_protocol_fees(CollectProtocolFees, CollectProtocolFees);
+    initialize_withdraw_receipt(InitializeWithdrawReceipt, InitializeWithdrawReceipt);
+    request_withdraw_with_cancel_deadline(
+        RequestWithdraw,
+        RequestWithdrawWithCancelDeadline,
+        withdraw_amount: u64,
+        withdraw_unit: WithdrawUnit,
+        cancel_deadline_ts: i64
+    );
+    update_vault_require_settle_crank(
+        UpdateVault,
+        UpdateVaultRequireSettleCrank,
+        require_settle_crank: bool
+    );
+    initialize_vault_delegate_proposal(
+        InitializeVaultDelegateProposal,
+        InitializeVaultDelegateProposal
+    );
+    update_vault_delegate_dual_approval(
+        UpdateVaultDelegateDualApproval,
+        UpdateVaultDelegateDualApproval,
+        enabled: bool
+    );
+    propose_vault_delegate(ProposeVaultDelegate, ProposeVaultDelegate, delegate: Pubkey);
+    approve_vault_delegate(ApproveVaultDelegate, ApproveVaultDelegate, delegate: Pubkey);
+    commit_vault_report(CommitVaultReport, CommitVaultReport, epoch: u32, report_hash: [u8; 32]);
+    queue_withdraw(QueueWithdraw, QueueWithdraw);
+    execute_queued_withdraw(ExecuteQueuedWithdraw, ExecuteQueuedWithdraw);
+    initialize_vault_fee_split(
+        InitializeVaultFeeSplit,
+        InitializeVaultFeeSplit,
+        split: u32,
+        recipient: Pubkey
+    );
+    update_vault_fee_split(UpdateVaultFeeSplit, UpdateVaultFeeSplit, split: u32, recipient: Pubkey);
+    distribute_vault_fee_split(DistributeVaultFeeSplit, DistributeVaultFeeSplit);
+    update_vault_depositor_auto_renew_withdraw(
+        UpdateVaultDepositorAutoRenewWithdraw,
+        UpdateVaultDepositorAutoRenewWithdraw,
+        enabled: bool
+    );
+    renew_withdraw_request(RenewWithdrawRequest, RenewWithdrawRequest);
+    initialize_protocol_tvl_cap(InitializeProtocolTvlCap, InitializeProtocolTvlCap, max_tvl: u64);
+    update_protocol_tvl_cap(UpdateProtocolTvlCap, UpdateProtocolTvlCap, max_tvl: u64);
+    add_vault_to_protocol_tvl_cap(AddVaultToProtocolTvlCap, AddVaultToProtocolTvlCap);
+    remove_vault_from_protocol_tvl_cap(
+        RemoveVaultFromProtocolTvlCap,
+        RemoveVaultFromProtocolTvlCap
+    );
+    initialize_vault_redeem_schedule(
+        InitializeVaultRedeemSchedule,
+        InitializeVaultRedeemSchedule,
+        buckets: [RedeemPeriodBucket; 4]
+    );
+    update_vault_redeem_schedule(
+        UpdateVaultRedeemSchedule,
+        UpdateVaultRedeemSchedule,
+        buckets: [RedeemPeriodBucket; 4]
+    );
+    update_vault_fuel_boost(UpdateVaultConfig, UpdateVaultFuelBoost, fuel_boost: u8);
+    apply_profit_share_batch(ApplyProfitShareBatch, ApplyProfitShareBatch, emit_details: bool);
+    update_cumulative_fuel_amount_batch(
+        UpdateCumulativeFuelAmountBatch,
+        UpdateCumulativeFuelAmountBatch
+    );
+    backfill_vault_depositor_counts(BackfillVaultDepositorCounts, BackfillVaultDepositorCounts);
+    update_vault_depositor_blacklist(
+        UpdateVaultDepositorBlacklist,
+        UpdateVaultDepositorBlacklist,
+        blacklisted: bool
+    );
+    withdraw_to_drift_user(WithdrawToDriftUser, WithdrawToDriftUser);
+    deposit_from_drift_user(DepositFromDriftUser, DepositFromDriftUser, amount: u64);
+    initialize_vault_manager_fee_claim(
+        InitializeVaultManagerFeeClaim,
+        InitializeVaultManagerFeeClaim,
+        claim_cap: u32
+    );
+    claim_vault_manager_fee(ClaimVaultManagerFee, ClaimVaultManagerFee);
+    initialize_vault_share_statement(InitializeVaultShareStatement, InitializeVaultShareStatement);
+    append_vault_share_statement(AppendVaultShareStatement, AppendVaultShareStatement);
+    initialize_vault_withdraw_escrow(InitializeVaultWithdrawEscrow, InitializeVaultWithdrawEscrow);
+    initialize_vault_depositor_withdraw_escrow(
+        InitializeVaultDepositorWithdrawEscrow,
+        InitializeVaultDepositorWithdrawEscrow
+    );
+    escrow_withdraw_request(EscrowWithdrawRequest, EscrowWithdrawRequest);
+    withdraw_from_escrow(WithdrawFromEscrow, WithdrawFromEscrow);
+    initialize_vault_share_class(
+        InitializeVaultShareClass,
+        InitializeVaultShareClass,
+        lock_period: i64,
+        profit_share_discount: u32
+    );
+    initialize_vault_depositor_share_class(
+        InitializeVaultDepositorShareClass,
+        InitializeVaultDepositorShareClass
+    );
+    convert_share_class(
+        ConvertShareClass,
+        ConvertShareClass,
+        amount: u64,
+        unit: WithdrawUnit,
+        locked: bool
+    );
+    initialize_vault_depositor_throttle(
+        InitializeVaultDepositorThrottle,
+        InitializeVaultDepositorThrottle,
+        min_deposit_interval: u32,
+        min_request_interval: u32
+    );
+    update_vault_depositor_throttle(
+        UpdateVaultDepositorThrottle,
+        UpdateVaultDepositorThrottle,
+        min_deposit_interval: u32,
+        min_request_interval: u32
+    );
+    initialize_vault_depositor_throttle_state(
+        InitializeVaultDepositorThrottleState,
+        InitializeVaultDepositorThrottleState
+    );
+    depositor_action(Withdraw, DepositorAction, action: DepositorAction);
+    check_delegate_self_trade(CheckDelegateSelfTrade, CheckDelegateSelfTrade);
+    update_vault_protocol_fee_waiver(
+        UpdateVaultProtocolFeeWaiver,
+        UpdateVaultProtocolFeeWaiver,
+        tvl_threshold: u64,
+        exit_tvl_threshold: u64
+    );
+    initialize_vault_withdraw_priority(
+        InitializeVaultWithdrawPriority,
+        InitializeVaultWithdrawPriority,
+        full_boost_fee: u32
+    );
+    update_vault_withdraw_priority(
+        UpdateVaultWithdrawPriority,
+        UpdateVaultWithdrawPriority,
+        full_boost_fee: u32
+    );
+    boost_withdraw_request(BoostWithdrawRequest, BoostWithdrawRequest, priority_fee: u32);
+    record_liquidation_action(RecordLiquidationAction, RecordLiquidationAction);
+    initialize_vault_crystallization(
+        InitializeVaultCrystallization,
+        InitializeVaultCrystallization,
+        crystallization_period: u32
+    );
+    update_vault_crystallization(
+        UpdateVaultCrystallization,
+        UpdateVaultCrystallization,
+        profit_share: u32
+    );
+    crystallize_vault_profit_share(CrystallizeVaultProfitShare, CrystallizeVaultProfitShare);
+    initialize_vault_backstop(
+        InitializeVaultBackstop,
+        InitializeVaultBackstop,
+        contribution_rate: u32,
+        deductible: u32
+    );
+    update_vault_backstop(
+        UpdateVaultBackstop,
+        UpdateVaultBackstop,
+        contribution_rate: u32,
+        deductible: u32
+    );
+    update_vault_backstop_max_balance(
+        UpdateVaultBackstopMaxBalance,
+        UpdateVaultBackstopMaxBalance,
+        max_balance: u64
+    );
+    contribute_vault_backstop(ContributeVaultBackstop, ContributeVaultBackstop);
+    claim_vault_backstop(ClaimVaultBackstop, ClaimVaultBackstop);
+    sweep_vault_fuel(SweepVaultFuel, SweepVaultFuel);
+    initialize_vault_profit_share_history(
+        InitializeVaultProfitShareHistory,
+        InitializeVaultProfitShareHistory
+    );
+    checkpoint_vault_profit_share(CheckpointVaultProfitShare, CheckpointVaultProfitShare);
+    apply_fee_reduction_refund(ApplyFeeReductionRefund, ApplyFeeReductionRefund, profit_share: u32);
+    initialize_omnibus_ledger(InitializeOmnibusLedger, InitializeOmnibusLedger);
+    initialize_omnibus_ledger_page(
+        InitializeOmnibusLedgerPage,
+        InitializeOmnibusLedgerPage,
+        page_index: u16
+    );
+    update_omnibus_ledger_entry(
+        UpdateOmnibusLedgerEntry,
+        UpdateOmnibusLedgerEntry,
+        entry_index: u8,
+        beneficiary: Pubkey,
+        shares: u128
+    );
+    update_vault_max_oracle_twap_deviation(
+        UpdateVaultGuard,
+        UpdateVaultMaxOracleTwapDeviation,
+        max_oracle_twap_deviation_bps: u16
+    );
+    initialize_vault_guard(
+        InitializeVaultGuard,
+        InitializeVaultGuard,
+        max_oracle_twap_deviation_bps: u16
+    );
+    initialize_vault_manager_activity(
+        InitializeVaultManagerActivity,
+        InitializeVaultManagerActivity,
+        inactivity_period: u32
+    );
+    update_vault_manager_activity(
+        UpdateVaultManagerActivity,
+        UpdateVaultManagerActivity,
+        inactivity_period: u32
+    );
+    inactive_manager_withdraw(Withdraw, InactiveManagerWithdraw);
+    manager_heartbeat(ManagerHeartbeat, ManagerHeartbeat);
+    update_vault_fee_policy(UpdateVaultConfig, UpdateVaultFeePolicy, fee_policy: u8);
+    update_vault_min_deposit_usd(
+        UpdateVaultGuard,
+        UpdateVaultMinDepositUsd,
+        min_deposit_usd: u32
+    );
+    view_depositor_portfolio(ViewDepositorPortfolio, ViewDepositorPortfolio);
+    check_vault_state_hash(CheckVaultStateHash, CheckVaultStateHash, expected_state_hash: u64);
+    preview_deposit(Deposit, PreviewDeposit, amount: u64);
+    preview_withdraw(Withdraw, PreviewWithdraw);
+    realize_depositor_profits(RealizeDepositorProfits, RealizeDepositorProfits);
+    initialize_vault_depositor_record_log(
+        InitializeVaultDepositorRecordLog,
+        InitializeVaultDepositorRecordLog
+    );
+    withdraw_and_close(WithdrawAndClose, WithdrawAndClose);
+    initialize_vault_withdraw_blackout(
+        InitializeVaultWithdrawBlackout,
+        InitializeVaultWithdrawBlackout
+    );
+    schedule_vault_withdraw_blackout(
+        ScheduleVaultWithdrawBlackout,
+        ScheduleVaultWithdrawBlackout,
+        start_ts: i64,
+        end_ts: i64
+    );
+    initialize_vault_lockup(InitializeVaultLockup, InitializeVaultLockup, lockup_period: u32);
+    update_vault_lockup(UpdateVaultLockup, UpdateVaultLockup, lockup_period: u32);
+    initialize_vault_depositor_lockup(
+        InitializeVaultDepositorLockup,
+        InitializeVaultDepositorLockup
+    );
+    initialize_vault_depositor_withdraw_requests(
+        InitializeVaultDepositorWithdrawRequests,
+        InitializeVaultDepositorWithdrawRequests
+    );
+    park_withdraw_request(ParkWithdrawRequest, ParkWithdrawRequest);
+    withdraw_parked_request(Withdraw, WithdrawParkedRequest, index: u8);
+    cancel_parked_withdraw_request(CancelWithdrawRequest, CancelParkedWithdrawRequest, index: u8);
+    initialize_vault_auditor(InitializeVaultAuditor, InitializeVaultAuditor, auditor: Pubkey);
+    update_vault_auditor(UpdateVaultAuditor, UpdateVaultAuditor, auditor: Pubkey);
+    view_vault_fee_ledger(ViewVaultFeeLedger, ViewVaultFeeLedger);
+    propose_vault_depositor_authority_transfer(
+        ProposeVaultDepositorAuthorityTransfer,
+        ProposeVaultDepositorAuthorityTransfer,
+        new_authority: Pubkey
+    );
+    accept_vault_depositor_authority_transfer(
+        AcceptVaultDepositorAuthorityTransfer,
+        AcceptVaultDepositorAuthorityTransfer
+    );
+    cancel_vault_depositor_authority_transfer(
+        CancelVaultDepositorAuthorityTransfer,
+        CancelVaultDepositorAuthorityTransfer
+    );
+    initialize_vault_profit_share_cadence(
+        InitializeVaultProfitShareCadence,
+        InitializeVaultProfitShareCadence,
+        min_profit_share_interval: u32
+    );
+    update_vault_profit_share_cadence(
+        UpdateVaultProfitShareCadence,
+        UpdateVaultProfitShareCadence,
+        min_profit_share_interval: u32
+    );
+    initialize_vault_depositor_profit_share_cadence(
+        InitializeVaultDepositorProfitShareCadence,
+        InitializeVaultDepositorProfitShareCadence
+    );
+    deposit_with_swap(
+        Deposit,
+        DepositWithSwap,
+        min_amount_out: u64,
+        swap_accounts_len: u8,
+        swap_data: Vec<u8>
+    );
+    initialize_vault_config(InitializeVaultConfig, InitializeVaultConfig);
+    update_vault_deposit_valuation(
+        UpdateVaultConfig,
+        UpdateVaultDepositValuation,
+        deposit_valuation: u8
+    );
+    update_vault_profit_share_realization(
+        UpdateVaultConfig,
+        UpdateVaultProfitShareRealization,
+        profit_share_realization: u8
+    );
+}
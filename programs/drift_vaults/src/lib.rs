@@ -5,7 +5,10 @@ use state::*;
 mod constants;
 mod drift_cpi;
 mod error;
-mod instructions;
+mod hook_cpi;
+pub mod instructions;
+#[cfg(feature = "drift-vaults-cpi")]
+pub mod interface;
 pub mod macros;
 pub mod state;
 #[cfg(test)]
@@ -34,6 +37,13 @@ pub mod drift_vaults {
         instructions::initialize_vault_with_protocol(ctx, params)
     }
 
+    pub fn initialize_vault_from_template<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, InitializeVaultFromTemplate<'info>>,
+        params: VaultFromTemplateParams,
+    ) -> Result<()> {
+        instructions::initialize_vault_from_template(ctx, params)
+    }
+
     pub fn update_delegate<'c: 'info, 'info>(
         ctx: Context<'_, '_, 'c, 'info, UpdateDelegate<'info>>,
         delegate: Pubkey,
@@ -62,6 +72,13 @@ pub mod drift_vaults {
         instructions::update_vault_protocol(ctx, params)
     }
 
+    pub fn update_vault_protocol_fee_tiers<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, UpdateVaultProtocolFeeTiers<'info>>,
+        protocol_fee_tiers: [ProtocolFeeTier; 4],
+    ) -> Result<()> {
+        instructions::update_vault_protocol_fee_tiers(ctx, protocol_fee_tiers)
+    }
+
     pub fn update_vault<'c: 'info, 'info>(
         ctx: Context<'_, '_, 'c, 'info, UpdateVault<'info>>,
         params: UpdateVaultParams,
@@ -76,13 +93,55 @@ pub mod drift_vaults {
         instructions::update_vault_manager(ctx, manager)
     }
 
+    pub fn initialize_vault_hook<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, InitializeVaultHook<'info>>,
+        hook_program: Pubkey,
+    ) -> Result<()> {
+        instructions::initialize_vault_hook(ctx, hook_program)
+    }
+
+    pub fn update_vault_hook<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, UpdateVaultHook<'info>>,
+        hook_program: Pubkey,
+    ) -> Result<()> {
+        instructions::update_vault_hook(ctx, hook_program)
+    }
+
+    pub fn initialize_vault_withdraw_route<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, InitializeVaultWithdrawRoute<'info>>,
+        market_indexes: Vec<u16>,
+    ) -> Result<()> {
+        instructions::initialize_vault_withdraw_route(ctx, market_indexes)
+    }
+
+    pub fn update_vault_withdraw_route<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, UpdateVaultWithdrawRoute<'info>>,
+        market_indexes: Vec<u16>,
+    ) -> Result<()> {
+        instructions::update_vault_withdraw_route(ctx, market_indexes)
+    }
+
+    pub fn initialize_vault_risk_score<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, InitializeVaultRiskScore<'info>>,
+    ) -> Result<()> {
+        instructions::initialize_vault_risk_score(ctx)
+    }
+
+    pub fn update_vault_risk_score<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, UpdateVaultRiskScore<'info>>,
+    ) -> Result<()> {
+        instructions::update_vault_risk_score(ctx)
+    }
+
     pub fn update_cumulative_fuel_amount<'c: 'info, 'info>(
         ctx: Context<'_, '_, 'c, 'info, UpdateCumulativeFuelAmount<'info>>,
     ) -> Result<()> {
         instructions::update_cumulative_fuel_amount(ctx)
     }
 
-    pub fn initialize_vault_depositor(ctx: Context<InitializeVaultDepositor>) -> Result<()> {
+    pub fn initialize_vault_depositor<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, InitializeVaultDepositor<'info>>,
+    ) -> Result<()> {
         instructions::initialize_vault_depositor(ctx)
     }
 
@@ -135,6 +194,12 @@ pub mod drift_vaults {
         instructions::withdraw(ctx)
     }
 
+    pub fn withdraw_routed<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, WithdrawRouted<'info>>,
+    ) -> Result<()> {
+        instructions::withdraw_routed(ctx)
+    }
+
     pub fn liquidate<'c: 'info, 'info>(
         ctx: Context<'_, '_, 'c, 'info, Liquidate<'info>>,
     ) -> Result<()> {
@@ -273,4 +338,1068 @@ pub mod drift_vaults {
     ) -> Result<()> {
         instructions::protocol_withdraw(ctx)
     }
+
+    pub fn initialize_vault_poll<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, InitializeVaultPoll<'info>>,
+    ) -> Result<()> {
+        instructions::initialize_vault_poll(ctx)
+    }
+
+    pub fn start_vault_poll<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, StartVaultPoll<'info>>,
+        proposal: u8,
+        new_manager: Pubkey,
+    ) -> Result<()> {
+        instructions::start_vault_poll(ctx, proposal, new_manager)
+    }
+
+    pub fn vote_on_vault_poll<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, VoteOnVaultPoll<'info>>,
+    ) -> Result<()> {
+        instructions::vote_on_vault_poll(ctx)
+    }
+
+    pub fn execute_vault_poll<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, ExecuteVaultPoll<'info>>,
+    ) -> Result<()> {
+        instructions::execute_vault_poll(ctx)
+    }
+
+    pub fn initialize_vault_share_snapshot<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, InitializeVaultShareSnapshot<'info>>,
+        epoch: u32,
+    ) -> Result<()> {
+        instructions::initialize_vault_share_snapshot(ctx, epoch)
+    }
+
+    pub fn snapshot_vault_depositor_shares<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, SnapshotVaultDepositorShares<'info>>,
+    ) -> Result<()> {
+        instructions::snapshot_vault_depositor_shares(ctx)
+    }
+
+    pub fn initialize_protocol_loss_ledger<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, InitializeProtocolLossLedger<'info>>,
+        authority: Pubkey,
+    ) -> Result<()> {
+        instructions::initialize_protocol_loss_ledger(ctx, authority)
+    }
+
+    pub fn record_loss_carryforward<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, LossCarryforward<'info>>,
+    ) -> Result<()> {
+        instructions::record_loss_carryforward(ctx)
+    }
+
+    pub fn apply_loss_carryforward<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, LossCarryforward<'info>>,
+    ) -> Result<()> {
+        instructions::apply_loss_carryforward(ctx)
+    }
+
+    pub fn update_vault_depositor_custodian<'info>(
+        ctx: Context<'_, '_, '_, 'info, UpdateVaultDepositorCustodian<'info>>,
+        custodian: Pubkey,
+    ) -> Result<()> {
+        instructions::update_vault_depositor_custodian(ctx, custodian)
+    }
+
+    pub fn revoke_vault_depositor_custodian<'info>(
+        ctx: Context<'_, '_, '_, 'info, RevokeVaultDepositorCustodian<'info>>,
+    ) -> Result<()> {
+        instructions::revoke_vault_depositor_custodian(ctx)
+    }
+
+    pub fn initialize_vault_depositor_idempotency<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, InitializeVaultDepositorIdempotency<'info>>,
+    ) -> Result<()> {
+        instructions::initialize_vault_depositor_idempotency(ctx)
+    }
+
+    pub fn deposit_with_idempotency_key<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, Deposit<'info>>,
+        amount: u64,
+        idempotency_key: u64,
+    ) -> Result<()> {
+        instructions::deposit_with_idempotency_key(ctx, amount, idempotency_key)
+    }
+
+    pub fn request_withdraw_with_idempotency_key<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, RequestWithdraw<'info>>,
+        withdraw_amount: u64,
+        withdraw_unit: WithdrawUnit,
+        idempotency_key: u64,
+    ) -> Result<()> {
+        instructions::request_withdraw_with_idempotency_key(
+            ctx,
+            withdraw_amount,
+            withdraw_unit,
+            idempotency_key,
+        )
+    }
+
+    pub fn update_vault_large_withdraw_notice<'info>(
+        ctx: Context<'_, '_, '_, 'info, UpdateVault<'info>>,
+        large_withdraw_threshold: u32,
+        large_withdraw_notice_period: i64,
+    ) -> Result<()> {
+        instructions::update_vault_large_withdraw_notice(
+            ctx,
+            large_withdraw_threshold,
+            large_withdraw_notice_period,
+        )
+    }
+
+    pub fn initialize_vault_withdraw_settlement<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, InitializeVaultWithdrawSettlement<'info>>,
+    ) -> Result<()> {
+        instructions::initialize_vault_withdraw_settlement(ctx)
+    }
+
+    pub fn settle_vault_withdraws<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, SettleVaultWithdraws<'info>>,
+    ) -> Result<()> {
+        instructions::settle_vault_withdraws(ctx)
+    }
+
+    pub fn update_vault_profile<'info>(
+        ctx: Context<'_, '_, '_, 'info, UpdateVault<'info>>,
+        profile: u8,
+    ) -> Result<()> {
+        instructions::update_vault_profile(ctx, profile)
+    }
+
+    pub fn enforce_vault_profile<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, EnforceVaultProfile<'info>>,
+    ) -> Result<()> {
+        instructions::enforce_vault_profile(ctx)
+    }
+
+    pub fn initialize_vault_performance_stats<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, InitializeVaultPerformanceStats<'info>>,
+    ) -> Result<()> {
+        instructions::initialize_vault_performance_stats(ctx)
+    }
+
+    pub fn update_vault_performance_stats<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, UpdateVaultPerformanceStats<'info>>,
+    ) -> Result<()> {
+        instructions::update_vault_performance_stats(ctx)
+    }
+
+    pub fn initialize_vault_interface<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, InitializeVaultInterface<'info>>,
+        interface: Pubkey,
+        fee_rate: u32,
+    ) -> Result<()> {
+        instructions::initialize_vault_interface(ctx, interface, fee_rate)
+    }
+
+    pub fn update_vault_interface<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, UpdateVaultInterface<'info>>,
+        fee_rate: u32,
+    ) -> Result<()> {
+        instructions::update_vault_interface(ctx, fee_rate)
+    }
+
+    pub fn deposit_with_interface<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, Deposit<'info>>,
+        amount: u64,
+        interface: Pubkey,
+    ) -> Result<()> {
+        instructions::deposit_with_interface(ctx, amount, interface)
+    }
+
+    pub fn withdraw_with_interface<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, Withdraw<'info>>,
+        interface: Pubkey,
+    ) -> Result<()> {
+        instructions::withdraw_with_interface(ctx, interface)
+    }
+
+    pub fn claim_vault_interface_fees<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, ClaimVaultInterfaceFees<'info>>,
+    ) -> Result<()> {
+        instructions::claim_vault_interface_fees(ctx)
+    }
+
+    pub fn initialize_vault_depositor_memo<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, InitializeVaultDepositorMemo<'info>>,
+        memo: [u8; 32],
+    ) -> Result<()> {
+        instructions::initialize_vault_depositor_memo(ctx, memo)
+    }
+
+    pub fn update_vault_depositor_memo<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, UpdateVaultDepositorMemo<'info>>,
+        memo: [u8; 32],
+    ) -> Result<()> {
+        instructions::update_vault_depositor_memo(ctx, memo)
+    }
+
+    pub fn initialize_vault_dust_withdraw<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, InitializeVaultDustWithdraw<'info>>,
+        threshold: u64,
+        window_max_amount: u64,
+    ) -> Result<()> {
+        instructions::initialize_vault_dust_withdraw(ctx, threshold, window_max_amount)
+    }
+
+    pub fn update_vault_dust_withdraw<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, UpdateVaultDustWithdraw<'info>>,
+        threshold: u64,
+        window_max_amount: u64,
+    ) -> Result<()> {
+        instructions::update_vault_dust_withdraw(ctx, threshold, window_max_amount)
+    }
+
+    pub fn withdraw_dust<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, Withdraw<'info>>,
+    ) -> Result<()> {
+        instructions::withdraw_dust(ctx)
+    }
+
+    pub fn initialize_vault_withdraw_stream<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, InitializeVaultWithdrawStream<'info>>,
+    ) -> Result<()> {
+        instructions::initialize_vault_withdraw_stream(ctx)
+    }
+
+    pub fn start_withdraw_stream<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, StartWithdrawStream<'info>>,
+        duration: i64,
+    ) -> Result<()> {
+        instructions::start_withdraw_stream(ctx, duration)
+    }
+
+    pub fn claim_withdraw_stream<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, Withdraw<'info>>,
+    ) -> Result<()> {
+        instructions::claim_withdraw_stream(ctx)
+    }
+
+    pub fn simulate_withdraw<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, SimulateWithdraw<'info>>,
+    ) -> Result<WithdrawBlocker> {
+        instructions::simulate_withdraw(ctx)
+    }
+
+    pub fn simulate_withdraw_status<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, SimulateWithdraw<'info>>,
+    ) -> Result<SimulateWithdrawStatus> {
+        instructions::simulate_withdraw_status(ctx)
+    }
+
+    pub fn update_vault_disabled_features<'info>(
+        ctx: Context<'_, '_, '_, 'info, UpdateVault<'info>>,
+        disabled_features: u8,
+    ) -> Result<()> {
+        instructions::update_vault_disabled_features(ctx, disabled_features)
+    }
+
+    pub fn initialize_vault_registry<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, InitializeVaultRegistry<'info>>,
+    ) -> Result<()> {
+        instructions::initialize_vault_registry(ctx)
+    }
+
+    pub fn initialize_vault_registry_page<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, InitializeVaultRegistryPage<'info>>,
+        page_index: u32,
+    ) -> Result<()> {
+        instructions::initialize_vault_registry_page(ctx, page_index)
+    }
+
+    pub fn register_vault<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, RegisterVault<'info>>,
+    ) -> Result<()> {
+        instructions::register_vault(ctx)
+    }
+
+    pub fn migrate_vault_deposit_mint<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, MigrateVaultDepositMint<'info>>,
+    ) -> Result<()> {
+        instructions::migrate_vault_deposit_mint(ctx)
+    }
+
+    pub fn initialize_vault_token_account<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, InitializeVaultTokenAccount<'info>>,
+    ) -> Result<()> {
+        instructions::initialize_vault_token_account(ctx)
+    }
+
+    pub fn deposit_with_cancel_withdraw_request<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, Deposit<'info>>,
+        amount: u64,
+        cancel_withdraw_request: bool,
+    ) -> Result<()> {
+        instructions::deposit_with_cancel_withdraw_request(ctx, amount, cancel_withdraw_request)
+    }
+
+    pub fn initialize_vault_param_change_log<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, InitializeVaultParamChangeLog<'info>>,
+    ) -> Result<()> {
+        instructions::initialize_vault_param_change_log(ctx)
+    }
+
+    pub fn initialize_vault_depositor_cap<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, InitializeVaultDepositorCap<'info>>,
+        max_depositors: u32,
+    ) -> Result<()> {
+        instructions::initialize_vault_depositor_cap(ctx, max_depositors)
+    }
+
+    pub fn update_vault_depositor_cap<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, UpdateVaultDepositorCap<'info>>,
+        max_depositors: u32,
+    ) -> Result<()> {
+        instructions::update_vault_depositor_cap(ctx, max_depositors)
+    }
+
+    pub fn initialize_vault_fee_holiday<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, InitializeVaultFeeHoliday<'info>>,
+    ) -> Result<()> {
+        instructions::initialize_vault_fee_holiday(ctx)
+    }
+
+    pub fn schedule_vault_fee_holiday<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, UpdateVaultFeeHoliday<'info>>,
+        start_ts: i64,
+        end_ts: i64,
+        suspend_management_fee: bool,
+        suspend_profit_share: bool,
+    ) -> Result<()> {
+        instructions::schedule_vault_fee_holiday(
+            ctx,
+            start_ts,
+            end_ts,
+            suspend_management_fee,
+            suspend_profit_share,
+        )
+    }
+
+    pub fn cancel_vault_fee_holiday<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, UpdateVaultFeeHoliday<'info>>,
+    ) -> Result<()> {
+        instructions::cancel_vault_fee_holiday(ctx)
+    }
+
+    pub fn crank_vault_fee_holiday<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, CrankVaultFeeHoliday<'info>>,
+    ) -> Result<()> {
+        instructions::crank_vault_fee_holiday(ctx)
+    }
+
+    pub fn update_vault_profit_share_mode<'info>(
+        ctx: Context<'_, '_, '_, 'info, UpdateVaultConfig<'info>>,
+        profit_share_mode: u8,
+    ) -> Result<()> {
+        instructions::update_vault_profit_share_mode(ctx, profit_share_mode)
+    }
+
+    pub fn initialize_protocol_treasury<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, InitializeProtocolTreasury<'info>>,
+        treasury: Pubkey,
+    ) -> Result<()> {
+        instructions::initialize_protocol_treasury(ctx, treasury)
+    }
+
+    pub fn collect_protocol_fees<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, CollectProtocolFees<'info>>,
+    ) -> Result<()> {
+        instructions::collect_protocol_fees(ctx)
+    }
+
+    pub fn initialize_withdraw_receipt<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, InitializeWithdrawReceipt<'info>>,
+    ) -> Result<()> {
+        instructions::initialize_withdraw_receipt(ctx)
+    }
+
+    pub fn request_withdraw_with_cancel_deadline<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, RequestWithdraw<'info>>,
+        withdraw_amount: u64,
+        withdraw_unit: WithdrawUnit,
+        cancel_deadline_ts: i64,
+    ) -> Result<()> {
+        instructions::request_withdraw_with_cancel_deadline(
+            ctx,
+            withdraw_amount,
+            withdraw_unit,
+            cancel_deadline_ts,
+        )
+    }
+
+    pub fn update_vault_require_settle_crank<'info>(
+        ctx: Context<'_, '_, '_, 'info, UpdateVault<'info>>,
+        require_settle_crank: bool,
+    ) -> Result<()> {
+        instructions::update_vault_require_settle_crank(ctx, require_settle_crank)
+    }
+
+    pub fn initialize_vault_delegate_proposal<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, InitializeVaultDelegateProposal<'info>>,
+    ) -> Result<()> {
+        instructions::initialize_vault_delegate_proposal(ctx)
+    }
+
+    pub fn update_vault_delegate_dual_approval<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, UpdateVaultDelegateDualApproval<'info>>,
+        enabled: bool,
+    ) -> Result<()> {
+        instructions::update_vault_delegate_dual_approval(ctx, enabled)
+    }
+
+    pub fn propose_vault_delegate<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, ProposeVaultDelegate<'info>>,
+        delegate: Pubkey,
+    ) -> Result<()> {
+        instructions::propose_vault_delegate(ctx, delegate)
+    }
+
+    pub fn approve_vault_delegate<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, ApproveVaultDelegate<'info>>,
+        delegate: Pubkey,
+    ) -> Result<()> {
+        instructions::approve_vault_delegate(ctx, delegate)
+    }
+
+    pub fn commit_vault_report<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, CommitVaultReport<'info>>,
+        epoch: u32,
+        report_hash: [u8; 32],
+    ) -> Result<()> {
+        instructions::commit_vault_report(ctx, epoch, report_hash)
+    }
+
+    pub fn queue_withdraw<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, QueueWithdraw<'info>>,
+    ) -> Result<()> {
+        instructions::queue_withdraw(ctx)
+    }
+
+    pub fn execute_queued_withdraw<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, ExecuteQueuedWithdraw<'info>>,
+    ) -> Result<()> {
+        instructions::execute_queued_withdraw(ctx)
+    }
+
+    pub fn initialize_vault_fee_split<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, InitializeVaultFeeSplit<'info>>,
+        split: u32,
+        recipient: Pubkey,
+    ) -> Result<()> {
+        instructions::initialize_vault_fee_split(ctx, split, recipient)
+    }
+
+    pub fn update_vault_fee_split<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, UpdateVaultFeeSplit<'info>>,
+        split: u32,
+        recipient: Pubkey,
+    ) -> Result<()> {
+        instructions::update_vault_fee_split(ctx, split, recipient)
+    }
+
+    pub fn distribute_vault_fee_split<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, DistributeVaultFeeSplit<'info>>,
+    ) -> Result<()> {
+        instructions::distribute_vault_fee_split(ctx)
+    }
+
+    pub fn update_vault_depositor_auto_renew_withdraw<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, UpdateVaultDepositorAutoRenewWithdraw<'info>>,
+        enabled: bool,
+    ) -> Result<()> {
+        instructions::update_vault_depositor_auto_renew_withdraw(ctx, enabled)
+    }
+
+    pub fn renew_withdraw_request<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, RenewWithdrawRequest<'info>>,
+    ) -> Result<()> {
+        instructions::renew_withdraw_request(ctx)
+    }
+
+    pub fn initialize_protocol_tvl_cap<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, InitializeProtocolTvlCap<'info>>,
+        max_tvl: u64,
+    ) -> Result<()> {
+        instructions::initialize_protocol_tvl_cap(ctx, max_tvl)
+    }
+
+    pub fn update_protocol_tvl_cap<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, UpdateProtocolTvlCap<'info>>,
+        max_tvl: u64,
+    ) -> Result<()> {
+        instructions::update_protocol_tvl_cap(ctx, max_tvl)
+    }
+
+    pub fn add_vault_to_protocol_tvl_cap<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, AddVaultToProtocolTvlCap<'info>>,
+    ) -> Result<()> {
+        instructions::add_vault_to_protocol_tvl_cap(ctx)
+    }
+
+    pub fn remove_vault_from_protocol_tvl_cap<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, RemoveVaultFromProtocolTvlCap<'info>>,
+    ) -> Result<()> {
+        instructions::remove_vault_from_protocol_tvl_cap(ctx)
+    }
+
+    pub fn initialize_vault_redeem_schedule<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, InitializeVaultRedeemSchedule<'info>>,
+        buckets: [RedeemPeriodBucket; 4],
+    ) -> Result<()> {
+        instructions::initialize_vault_redeem_schedule(ctx, buckets)
+    }
+
+    pub fn update_vault_redeem_schedule<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, UpdateVaultRedeemSchedule<'info>>,
+        buckets: [RedeemPeriodBucket; 4],
+    ) -> Result<()> {
+        instructions::update_vault_redeem_schedule(ctx, buckets)
+    }
+
+    pub fn update_vault_fuel_boost<'info>(
+        ctx: Context<'_, '_, '_, 'info, UpdateVaultConfig<'info>>,
+        fuel_boost: u8,
+    ) -> Result<()> {
+        instructions::update_vault_fuel_boost(ctx, fuel_boost)
+    }
+
+    pub fn apply_profit_share_batch<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, ApplyProfitShareBatch<'info>>,
+        emit_details: bool,
+    ) -> Result<()> {
+        instructions::apply_profit_share_batch(ctx, emit_details)
+    }
+
+    pub fn update_cumulative_fuel_amount_batch<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, UpdateCumulativeFuelAmountBatch<'info>>,
+    ) -> Result<()> {
+        instructions::update_cumulative_fuel_amount_batch(ctx)
+    }
+
+    pub fn backfill_vault_depositor_counts<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, BackfillVaultDepositorCounts<'info>>,
+    ) -> Result<()> {
+        instructions::backfill_vault_depositor_counts(ctx)
+    }
+
+    pub fn update_vault_depositor_blacklist<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, UpdateVaultDepositorBlacklist<'info>>,
+        blacklisted: bool,
+    ) -> Result<()> {
+        instructions::update_vault_depositor_blacklist(ctx, blacklisted)
+    }
+
+    pub fn withdraw_to_drift_user<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, WithdrawToDriftUser<'info>>,
+    ) -> Result<()> {
+        instructions::withdraw_to_drift_user(ctx)
+    }
+
+    pub fn deposit_from_drift_user<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, DepositFromDriftUser<'info>>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::deposit_from_drift_user(ctx, amount)
+    }
+
+    pub fn initialize_vault_manager_fee_claim<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, InitializeVaultManagerFeeClaim<'info>>,
+        claim_cap: u32,
+    ) -> Result<()> {
+        instructions::initialize_vault_manager_fee_claim(ctx, claim_cap)
+    }
+
+    pub fn claim_vault_manager_fee<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, ClaimVaultManagerFee<'info>>,
+    ) -> Result<()> {
+        instructions::claim_vault_manager_fee(ctx)
+    }
+
+    pub fn initialize_vault_share_statement<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, InitializeVaultShareStatement<'info>>,
+    ) -> Result<()> {
+        instructions::initialize_vault_share_statement(ctx)
+    }
+
+    pub fn append_vault_share_statement<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, AppendVaultShareStatement<'info>>,
+    ) -> Result<()> {
+        instructions::append_vault_share_statement(ctx)
+    }
+
+    pub fn initialize_vault_withdraw_escrow<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, InitializeVaultWithdrawEscrow<'info>>,
+    ) -> Result<()> {
+        instructions::initialize_vault_withdraw_escrow(ctx)
+    }
+
+    pub fn initialize_vault_depositor_withdraw_escrow<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, InitializeVaultDepositorWithdrawEscrow<'info>>,
+    ) -> Result<()> {
+        instructions::initialize_vault_depositor_withdraw_escrow(ctx)
+    }
+
+    pub fn escrow_withdraw_request<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, EscrowWithdrawRequest<'info>>,
+    ) -> Result<()> {
+        instructions::escrow_withdraw_request(ctx)
+    }
+
+    pub fn withdraw_from_escrow<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, WithdrawFromEscrow<'info>>,
+    ) -> Result<()> {
+        instructions::withdraw_from_escrow(ctx)
+    }
+
+    pub fn initialize_vault_share_class<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, InitializeVaultShareClass<'info>>,
+        lock_period: i64,
+        profit_share_discount: u32,
+    ) -> Result<()> {
+        instructions::initialize_vault_share_class(ctx, lock_period, profit_share_discount)
+    }
+
+    pub fn initialize_vault_depositor_share_class<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, InitializeVaultDepositorShareClass<'info>>,
+    ) -> Result<()> {
+        instructions::initialize_vault_depositor_share_class(ctx)
+    }
+
+    pub fn convert_share_class<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, ConvertShareClass<'info>>,
+        amount: u64,
+        unit: WithdrawUnit,
+        locked: bool,
+    ) -> Result<()> {
+        instructions::convert_share_class(ctx, amount, unit, locked)
+    }
+
+    pub fn initialize_vault_depositor_throttle<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, InitializeVaultDepositorThrottle<'info>>,
+        min_deposit_interval: u32,
+        min_request_interval: u32,
+    ) -> Result<()> {
+        instructions::initialize_vault_depositor_throttle(
+            ctx,
+            min_deposit_interval,
+            min_request_interval,
+        )
+    }
+
+    pub fn update_vault_depositor_throttle<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, UpdateVaultDepositorThrottle<'info>>,
+        min_deposit_interval: u32,
+        min_request_interval: u32,
+    ) -> Result<()> {
+        instructions::update_vault_depositor_throttle(
+            ctx,
+            min_deposit_interval,
+            min_request_interval,
+        )
+    }
+
+    pub fn initialize_vault_depositor_throttle_state<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, InitializeVaultDepositorThrottleState<'info>>,
+    ) -> Result<()> {
+        instructions::initialize_vault_depositor_throttle_state(ctx)
+    }
+
+    pub fn depositor_action<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, Withdraw<'info>>,
+        action: DepositorAction,
+    ) -> Result<()> {
+        instructions::depositor_action(ctx, action)
+    }
+
+    pub fn check_delegate_self_trade<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, CheckDelegateSelfTrade<'info>>,
+    ) -> Result<()> {
+        instructions::check_delegate_self_trade(ctx)
+    }
+
+    pub fn update_vault_protocol_fee_waiver<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, UpdateVaultProtocolFeeWaiver<'info>>,
+        tvl_threshold: u64,
+        exit_tvl_threshold: u64,
+    ) -> Result<()> {
+        instructions::update_vault_protocol_fee_waiver(ctx, tvl_threshold, exit_tvl_threshold)
+    }
+
+    pub fn initialize_vault_withdraw_priority<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, InitializeVaultWithdrawPriority<'info>>,
+        full_boost_fee: u32,
+    ) -> Result<()> {
+        instructions::initialize_vault_withdraw_priority(ctx, full_boost_fee)
+    }
+
+    pub fn update_vault_withdraw_priority<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, UpdateVaultWithdrawPriority<'info>>,
+        full_boost_fee: u32,
+    ) -> Result<()> {
+        instructions::update_vault_withdraw_priority(ctx, full_boost_fee)
+    }
+
+    pub fn boost_withdraw_request<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, BoostWithdrawRequest<'info>>,
+        priority_fee: u32,
+    ) -> Result<()> {
+        instructions::boost_withdraw_request(ctx, priority_fee)
+    }
+
+    pub fn record_liquidation_action<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, RecordLiquidationAction<'info>>,
+    ) -> Result<()> {
+        instructions::record_liquidation_action(ctx)
+    }
+
+    pub fn initialize_vault_crystallization<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, InitializeVaultCrystallization<'info>>,
+        crystallization_period: u32,
+    ) -> Result<()> {
+        instructions::initialize_vault_crystallization(ctx, crystallization_period)
+    }
+
+    pub fn update_vault_crystallization<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, UpdateVaultCrystallization<'info>>,
+        profit_share: u32,
+    ) -> Result<()> {
+        instructions::update_vault_crystallization(ctx, profit_share)
+    }
+
+    pub fn crystallize_vault_profit_share<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, CrystallizeVaultProfitShare<'info>>,
+    ) -> Result<()> {
+        instructions::crystallize_vault_profit_share(ctx)
+    }
+
+    pub fn initialize_vault_backstop<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, InitializeVaultBackstop<'info>>,
+        contribution_rate: u32,
+        deductible: u32,
+    ) -> Result<()> {
+        instructions::initialize_vault_backstop(ctx, contribution_rate, deductible)
+    }
+
+    pub fn update_vault_backstop<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, UpdateVaultBackstop<'info>>,
+        contribution_rate: u32,
+        deductible: u32,
+    ) -> Result<()> {
+        instructions::update_vault_backstop(ctx, contribution_rate, deductible)
+    }
+
+    pub fn update_vault_backstop_max_balance<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, UpdateVaultBackstopMaxBalance<'info>>,
+        max_balance: u64,
+    ) -> Result<()> {
+        instructions::update_vault_backstop_max_balance(ctx, max_balance)
+    }
+
+    pub fn contribute_vault_backstop<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, ContributeVaultBackstop<'info>>,
+    ) -> Result<()> {
+        instructions::contribute_vault_backstop(ctx)
+    }
+
+    pub fn claim_vault_backstop<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, ClaimVaultBackstop<'info>>,
+    ) -> Result<()> {
+        instructions::claim_vault_backstop(ctx)
+    }
+
+    pub fn sweep_vault_fuel<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, SweepVaultFuel<'info>>,
+    ) -> Result<()> {
+        instructions::sweep_vault_fuel(ctx)
+    }
+
+    pub fn initialize_vault_profit_share_history<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, InitializeVaultProfitShareHistory<'info>>,
+    ) -> Result<()> {
+        instructions::initialize_vault_profit_share_history(ctx)
+    }
+
+    pub fn checkpoint_vault_profit_share<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, CheckpointVaultProfitShare<'info>>,
+    ) -> Result<()> {
+        instructions::checkpoint_vault_profit_share(ctx)
+    }
+
+    pub fn apply_fee_reduction_refund<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, ApplyFeeReductionRefund<'info>>,
+        profit_share: u32,
+    ) -> Result<()> {
+        instructions::apply_fee_reduction_refund(ctx, profit_share)
+    }
+
+    pub fn initialize_omnibus_ledger<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, InitializeOmnibusLedger<'info>>,
+    ) -> Result<()> {
+        instructions::initialize_omnibus_ledger(ctx)
+    }
+
+    pub fn initialize_omnibus_ledger_page<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, InitializeOmnibusLedgerPage<'info>>,
+        page_index: u16,
+    ) -> Result<()> {
+        instructions::initialize_omnibus_ledger_page(ctx, page_index)
+    }
+
+    pub fn update_omnibus_ledger_entry<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, UpdateOmnibusLedgerEntry<'info>>,
+        entry_index: u8,
+        beneficiary: Pubkey,
+        shares: u128,
+    ) -> Result<()> {
+        instructions::update_omnibus_ledger_entry(ctx, entry_index, beneficiary, shares)
+    }
+
+    pub fn update_vault_max_oracle_twap_deviation<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, UpdateVaultGuard<'info>>,
+        max_oracle_twap_deviation_bps: u16,
+    ) -> Result<()> {
+        instructions::update_vault_max_oracle_twap_deviation(ctx, max_oracle_twap_deviation_bps)
+    }
+
+    pub fn initialize_vault_guard<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, InitializeVaultGuard<'info>>,
+        max_oracle_twap_deviation_bps: u16,
+    ) -> Result<()> {
+        instructions::initialize_vault_guard(ctx, max_oracle_twap_deviation_bps)
+    }
+
+    pub fn initialize_vault_manager_activity<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, InitializeVaultManagerActivity<'info>>,
+        inactivity_period: u32,
+    ) -> Result<()> {
+        instructions::initialize_vault_manager_activity(ctx, inactivity_period)
+    }
+
+    pub fn update_vault_manager_activity<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, UpdateVaultManagerActivity<'info>>,
+        inactivity_period: u32,
+    ) -> Result<()> {
+        instructions::update_vault_manager_activity(ctx, inactivity_period)
+    }
+
+    pub fn inactive_manager_withdraw<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, Withdraw<'info>>,
+    ) -> Result<()> {
+        instructions::inactive_manager_withdraw(ctx)
+    }
+
+    pub fn manager_heartbeat<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, ManagerHeartbeat<'info>>,
+    ) -> Result<()> {
+        instructions::manager_heartbeat(ctx)
+    }
+
+    pub fn update_vault_fee_policy<'info>(
+        ctx: Context<'_, '_, '_, 'info, UpdateVaultConfig<'info>>,
+        fee_policy: u8,
+    ) -> Result<()> {
+        instructions::update_vault_fee_policy(ctx, fee_policy)
+    }
+
+    pub fn update_vault_min_deposit_usd<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, UpdateVaultGuard<'info>>,
+        min_deposit_usd: u32,
+    ) -> Result<()> {
+        instructions::update_vault_min_deposit_usd(ctx, min_deposit_usd)
+    }
+
+    pub fn view_depositor_portfolio<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, ViewDepositorPortfolio>,
+    ) -> Result<Vec<PortfolioPosition>> {
+        instructions::view_depositor_portfolio(ctx)
+    }
+
+    pub fn check_vault_state_hash(
+        ctx: Context<CheckVaultStateHash>,
+        expected_state_hash: u64,
+    ) -> Result<()> {
+        instructions::check_vault_state_hash(ctx, expected_state_hash)
+    }
+
+    pub fn preview_deposit<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, Deposit<'info>>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::preview_deposit(ctx, amount)
+    }
+
+    pub fn preview_withdraw<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, Withdraw<'info>>,
+    ) -> Result<()> {
+        instructions::preview_withdraw(ctx)
+    }
+
+    pub fn realize_depositor_profits<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, RealizeDepositorProfits<'info>>,
+    ) -> Result<()> {
+        instructions::realize_depositor_profits(ctx)
+    }
+
+    pub fn initialize_vault_depositor_record_log<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, InitializeVaultDepositorRecordLog<'info>>,
+    ) -> Result<()> {
+        instructions::initialize_vault_depositor_record_log(ctx)
+    }
+
+    pub fn withdraw_and_close<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, WithdrawAndClose<'info>>,
+    ) -> Result<()> {
+        instructions::withdraw_and_close(ctx)
+    }
+
+    pub fn initialize_vault_withdraw_blackout<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, InitializeVaultWithdrawBlackout<'info>>,
+    ) -> Result<()> {
+        instructions::initialize_vault_withdraw_blackout(ctx)
+    }
+
+    pub fn schedule_vault_withdraw_blackout<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, ScheduleVaultWithdrawBlackout<'info>>,
+        start_ts: i64,
+        end_ts: i64,
+    ) -> Result<()> {
+        instructions::schedule_vault_withdraw_blackout(ctx, start_ts, end_ts)
+    }
+
+    pub fn initialize_vault_lockup<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, InitializeVaultLockup<'info>>,
+        lockup_period: u32,
+    ) -> Result<()> {
+        instructions::initialize_vault_lockup(ctx, lockup_period)
+    }
+
+    pub fn update_vault_lockup<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, UpdateVaultLockup<'info>>,
+        lockup_period: u32,
+    ) -> Result<()> {
+        instructions::update_vault_lockup(ctx, lockup_period)
+    }
+
+    pub fn initialize_vault_depositor_lockup<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, InitializeVaultDepositorLockup<'info>>,
+    ) -> Result<()> {
+        instructions::initialize_vault_depositor_lockup(ctx)
+    }
+
+    pub fn initialize_vault_depositor_withdraw_requests<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, InitializeVaultDepositorWithdrawRequests<'info>>,
+    ) -> Result<()> {
+        instructions::initialize_vault_depositor_withdraw_requests(ctx)
+    }
+
+    pub fn park_withdraw_request<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, ParkWithdrawRequest<'info>>,
+    ) -> Result<()> {
+        instructions::park_withdraw_request(ctx)
+    }
+
+    pub fn withdraw_parked_request<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, Withdraw<'info>>,
+        index: u8,
+    ) -> Result<()> {
+        instructions::withdraw_parked_request(ctx, index)
+    }
+
+    pub fn cancel_parked_withdraw_request<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, CancelWithdrawRequest<'info>>,
+        index: u8,
+    ) -> Result<()> {
+        instructions::cancel_parked_withdraw_request(ctx, index)
+    }
+
+    pub fn initialize_vault_auditor<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, InitializeVaultAuditor<'info>>,
+        auditor: Pubkey,
+    ) -> Result<()> {
+        instructions::initialize_vault_auditor(ctx, auditor)
+    }
+
+    pub fn update_vault_auditor<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, UpdateVaultAuditor<'info>>,
+        auditor: Pubkey,
+    ) -> Result<()> {
+        instructions::update_vault_auditor(ctx, auditor)
+    }
+
+    pub fn view_vault_fee_ledger<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, ViewVaultFeeLedger<'info>>,
+    ) -> Result<VaultFeeLedger> {
+        instructions::view_vault_fee_ledger(ctx)
+    }
+
+    pub fn propose_vault_depositor_authority_transfer<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, ProposeVaultDepositorAuthorityTransfer<'info>>,
+        new_authority: Pubkey,
+    ) -> Result<()> {
+        instructions::propose_vault_depositor_authority_transfer(ctx, new_authority)
+    }
+
+    pub fn accept_vault_depositor_authority_transfer<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, AcceptVaultDepositorAuthorityTransfer<'info>>,
+    ) -> Result<()> {
+        instructions::accept_vault_depositor_authority_transfer(ctx)
+    }
+
+    pub fn cancel_vault_depositor_authority_transfer<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, CancelVaultDepositorAuthorityTransfer<'info>>,
+    ) -> Result<()> {
+        instructions::cancel_vault_depositor_authority_transfer(ctx)
+    }
+
+    pub fn initialize_vault_profit_share_cadence<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, InitializeVaultProfitShareCadence<'info>>,
+        min_profit_share_interval: u32,
+    ) -> Result<()> {
+        instructions::initialize_vault_profit_share_cadence(ctx, min_profit_share_interval)
+    }
+
+    pub fn update_vault_profit_share_cadence<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, UpdateVaultProfitShareCadence<'info>>,
+        min_profit_share_interval: u32,
+    ) -> Result<()> {
+        instructions::update_vault_profit_share_cadence(ctx, min_profit_share_interval)
+    }
+
+    pub fn initialize_vault_depositor_profit_share_cadence<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, InitializeVaultDepositorProfitShareCadence<'info>>,
+    ) -> Result<()> {
+        instructions::initialize_vault_depositor_profit_share_cadence(ctx)
+    }
+
+    pub fn deposit_with_swap<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, Deposit<'info>>,
+        min_amount_out: u64,
+        swap_accounts_len: u8,
+        swap_data: Vec<u8>,
+    ) -> Result<()> {
+        instructions::deposit_with_swap(ctx, min_amount_out, swap_accounts_len, swap_data)
+    }
+
+    pub fn initialize_vault_config<'c: 'info, 'info>(
+        ctx: Context<'_, '_, 'c, 'info, InitializeVaultConfig<'info>>,
+    ) -> Result<()> {
+        instructions::initialize_vault_config(ctx)
+    }
+
+    pub fn update_vault_deposit_valuation<'info>(
+        ctx: Context<'_, '_, '_, 'info, UpdateVaultConfig<'info>>,
+        deposit_valuation: u8,
+    ) -> Result<()> {
+        instructions::update_vault_deposit_valuation(ctx, deposit_valuation)
+    }
+
+    pub fn update_vault_profit_share_realization<'info>(
+        ctx: Context<'_, '_, '_, 'info, UpdateVaultConfig<'info>>,
+        profit_share_realization: u8,
+    ) -> Result<()> {
+        instructions::update_vault_profit_share_realization(ctx, profit_share_realization)
+    }
 }
@@ -120,6 +120,49 @@ macro_rules! implement_deposit {
     };
 }
 
+/// CPI into a [`VaultHook`] program. Accounts are passed read-only and without signer privileges so the hook
+/// can't act with the vault's or the depositor's authority.
+#[macro_export]
+macro_rules! implement_vault_hook_cpi {
+    ( $self:expr, $hook_program:expr, $action:expr, $amount:expr ) => {
+        let vault = $self.accounts.vault.load()?;
+        let vault_depositor = $self.accounts.vault_depositor.load()?;
+        let context = VaultHookContext {
+            action: $action,
+            vault: vault.pubkey,
+            depositor_authority: vault_depositor.authority,
+            amount: $amount,
+            shares: vault_depositor.unchecked_vault_shares(),
+        };
+        drop(vault_depositor);
+        drop(vault);
+
+        let mut data = VaultHook::discriminator().to_vec();
+        context
+            .serialize(&mut data)
+            .map_err(|_| ErrorCode::InvalidVaultHook)?;
+
+        let ix = anchor_lang::solana_program::instruction::Instruction {
+            program_id: $hook_program.key(),
+            accounts: vec![
+                AccountMeta::new_readonly($self.accounts.vault.key(), false),
+                AccountMeta::new_readonly($self.accounts.vault_depositor.key(), false),
+                AccountMeta::new_readonly($self.accounts.authority.key(), false),
+            ],
+            data,
+        };
+        anchor_lang::solana_program::program::invoke(
+            &ix,
+            &[
+                $self.accounts.vault.to_account_info(),
+                $self.accounts.vault_depositor.to_account_info(),
+                $self.accounts.authority.to_account_info(),
+                $hook_program.clone(),
+            ],
+        )?;
+    };
+}
+
 #[cfg(test)]
 #[macro_export]
 macro_rules! assert_eq_within {
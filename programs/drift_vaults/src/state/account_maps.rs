@@ -1,11 +1,34 @@
+//! Providers for the accounts instructions read from remaining_accounts.
+//!
+//! Drift's markets and oracles come first, in the order drift's `load_maps` expects, and a vault's [`VaultProtocol`]
+//! is always last with its [`FuelOverflow`] right before it. Every other account can be anywhere in between: companion
+//! pdas are matched by address, or by discriminator and the vault or vault depositor they belong to, and token
+//! accounts by key, mint and owner, rather than by index. A provider that returns `None` for an account its vault
+//! requires would let a caller opt out of a check by leaving the account out, so those fail instead.
+
 use anchor_lang::prelude::Context;
+use anchor_spl::token_interface::{Mint, TokenAccount};
 use drift::error::DriftResult;
 use drift::instructions::optional_accounts::{load_maps, AccountMaps};
+use drift::state::spot_market::SpotMarket;
 use drift::state::spot_market_map::get_writable_spot_market_set;
 use drift::state::user::FuelOverflow;
+use std::cell::RefMut;
 use std::collections::BTreeSet;
 
-use crate::state::VaultProtocol;
+use crate::error::ErrorCode;
+use crate::state::{
+    GuardFlag, OmnibusLedger, ProtocolTvlCap, Vault, VaultAuditor, VaultConfig, VaultConfigFlag,
+    VaultDepositor, VaultDepositorAuthorityTransfer, VaultDepositorBase, VaultDepositorCap,
+    VaultDepositorFlag, VaultDepositorIdempotency, VaultDepositorLockup, VaultDepositorMemo,
+    VaultDepositorProfitShareCadence, VaultDepositorRecordLog, VaultDepositorShareClass,
+    VaultDepositorThrottle, VaultDepositorThrottleState, VaultDepositorWithdrawEscrow,
+    VaultDepositorWithdrawRequests, VaultDustWithdraw, VaultFlag, VaultGuard, VaultHook,
+    VaultInterface, VaultLockup, VaultManagerActivity, VaultParamChangeLog,
+    VaultProfitShareCadence, VaultProtocol, VaultProtocolFlag, VaultRedeemSchedule,
+    VaultWithdrawBlackout, VaultWithdrawSettlement, VaultWithdrawStream, WithdrawReceipt,
+};
+use crate::validate;
 use anchor_lang::prelude::*;
 
 pub trait AccountMapProvider<'a> {
@@ -50,7 +73,7 @@ pub trait VaultProtocolProvider<'a> {
     fn vault_protocol(&self) -> Option<AccountLoader<'a, VaultProtocol>>;
 }
 
-/// Provides the last remaining account as a [`VaultProtocol`].
+/// Provides the last remaining account as a [`VaultProtocol`], `None` for legacy vaults that don't have one.
 impl<'a: 'info, 'info, T: anchor_lang::Bumps> VaultProtocolProvider<'a>
     for Context<'_, '_, 'a, 'info, T>
 {
@@ -63,6 +86,33 @@ impl<'a: 'info, 'info, T: anchor_lang::Bumps> VaultProtocolProvider<'a>
     }
 }
 
+pub trait VaultConfigProvider<'info> {
+    fn vault_config(&self, vault: &Vault) -> Result<VaultConfig>;
+}
+
+/// Provides a copy of the vault's [`VaultConfig`] from remaining_accounts, matched by address. Required once the vault
+/// has [`VaultFlag::HasVaultConfig`], so it can't be left out to fall back to the defaults, which are what vaults
+/// without one get.
+impl<'a: 'info, 'info, T: anchor_lang::Bumps> VaultConfigProvider<'info>
+    for Context<'_, '_, 'a, 'info, T>
+{
+    fn vault_config(&self, vault: &Vault) -> Result<VaultConfig> {
+        if !vault.has_flag(VaultFlag::HasVaultConfig) {
+            return Ok(VaultConfig::default());
+        }
+
+        let address = VaultConfig::get_address(&vault.pubkey);
+        let acct = self
+            .remaining_accounts
+            .iter()
+            .find(|acct| acct.key == &address)
+            .ok_or(ErrorCode::InvalidVaultConfig)?;
+
+        let vault_config = *AccountLoader::<'info, VaultConfig>::try_from(acct)?.load()?;
+        Ok(vault_config)
+    }
+}
+
 pub trait FuelOverflowProvider<'a> {
     fn fuel_overflow(
         &self,
@@ -96,3 +146,1021 @@ impl<'a: 'info, 'info, T: anchor_lang::Bumps> FuelOverflowProvider<'a>
         }
     }
 }
+
+pub trait VaultHookProvider<'info> {
+    fn vault_hook_program(&self, vault: &Vault) -> Result<Option<AccountInfo<'info>>>;
+}
+
+/// Provides the [`VaultHook`] program from remaining_accounts, matched by discriminator and key.
+impl<'a: 'info, 'info, T: anchor_lang::Bumps> VaultHookProvider<'info>
+    for Context<'_, '_, 'a, 'info, T>
+{
+    fn vault_hook_program(&self, vault: &Vault) -> Result<Option<AccountInfo<'info>>> {
+        if !vault.vault_hook {
+            return Ok(None);
+        }
+
+        let vault_hook = match self
+            .remaining_accounts
+            .iter()
+            .find_map(|acct| AccountLoader::<'info, VaultHook>::try_from(acct).ok())
+        {
+            Some(vault_hook) => vault_hook,
+            None => return Ok(None),
+        };
+
+        let hook_program = vault_hook
+            .load()?
+            .find_hook_program(vault, self.remaining_accounts)?;
+
+        Ok(hook_program)
+    }
+}
+
+/// Accounts needed to pay a depositor out of a [`VaultWithdrawRoute`](crate::state::VaultWithdrawRoute) market.
+pub struct WithdrawRouteAccounts<'info> {
+    pub spot_market_vault: AccountInfo<'info>,
+    pub vault_token_account: AccountInfo<'info>,
+    pub user_token_account: AccountInfo<'info>,
+    /// The routed mint's token program, which can differ from the vault's
+    pub token_program: AccountInfo<'info>,
+    /// The routed mint, Token-2022 routes need it for `transfer_checked`
+    pub mint: Option<InterfaceAccount<'info, Mint>>,
+}
+
+pub trait WithdrawRouteAccountsProvider<'info> {
+    fn withdraw_route_accounts(
+        &self,
+        spot_market: &SpotMarket,
+        vault: &Pubkey,
+        authority: &Pubkey,
+        token_program: &AccountInfo<'info>,
+    ) -> Result<Option<WithdrawRouteAccounts<'info>>>;
+}
+
+/// Provides the token accounts for a routed spot market from remaining_accounts. The routed mint's token program only
+/// needs to be passed if it isn't the instruction's `token_program`.
+impl<'a: 'info, 'info, T: anchor_lang::Bumps> WithdrawRouteAccountsProvider<'info>
+    for Context<'_, '_, 'a, 'info, T>
+{
+    fn withdraw_route_accounts(
+        &self,
+        spot_market: &SpotMarket,
+        vault: &Pubkey,
+        authority: &Pubkey,
+        token_program: &AccountInfo<'info>,
+    ) -> Result<Option<WithdrawRouteAccounts<'info>>> {
+        let find_token_account = |owner: &Pubkey| {
+            self.remaining_accounts.iter().find(|acct| {
+                acct.is_writable
+                    && InterfaceAccount::<TokenAccount>::try_from(acct).map_or(false, |ta| {
+                        ta.mint == spot_market.mint && ta.owner == *owner
+                    })
+            })
+        };
+
+        let spot_market_vault = self
+            .remaining_accounts
+            .iter()
+            .find(|acct| acct.is_writable && acct.key() == spot_market.vault);
+        let vault_token_account = find_token_account(vault);
+        let user_token_account = find_token_account(authority);
+        let (spot_market_vault, vault_token_account, user_token_account) =
+            match (spot_market_vault, vault_token_account, user_token_account) {
+                (Some(spot_market_vault), Some(vault_token_account), Some(user_token_account)) => {
+                    (spot_market_vault, vault_token_account, user_token_account)
+                }
+                _ => return Ok(None),
+            };
+
+        let route_token_program = if *vault_token_account.owner == token_program.key() {
+            Some(token_program)
+        } else {
+            self.remaining_accounts
+                .iter()
+                .find(|acct| acct.key == vault_token_account.owner)
+        };
+        let route_token_program = match route_token_program {
+            Some(route_token_program) => route_token_program,
+            None => return Ok(None),
+        };
+
+        Ok(Some(WithdrawRouteAccounts {
+            spot_market_vault: spot_market_vault.clone(),
+            vault_token_account: vault_token_account.clone(),
+            user_token_account: user_token_account.clone(),
+            token_program: route_token_program.clone(),
+            mint: self.token_mint(&spot_market.mint)?,
+        }))
+    }
+}
+
+pub trait VaultDepositorIdempotencyProvider<'info> {
+    fn vault_depositor_idempotency(
+        &self,
+        vault_depositor: &Pubkey,
+    ) -> Result<AccountLoader<'info, VaultDepositorIdempotency>>;
+}
+
+/// Provides the [`VaultDepositorIdempotency`] from remaining_accounts.
+impl<'a: 'info, 'info, T: anchor_lang::Bumps> VaultDepositorIdempotencyProvider<'info>
+    for Context<'_, '_, 'a, 'info, T>
+{
+    fn vault_depositor_idempotency(
+        &self,
+        vault_depositor: &Pubkey,
+    ) -> Result<AccountLoader<'info, VaultDepositorIdempotency>> {
+        let idempotency = self
+            .remaining_accounts
+            .iter()
+            .filter(|acct| acct.is_writable)
+            .find_map(|acct| AccountLoader::<'info, VaultDepositorIdempotency>::try_from(acct).ok())
+            .ok_or(ErrorCode::InvalidVaultDepositorIdempotency)?;
+
+        validate!(
+            idempotency.load()?.vault_depositor == *vault_depositor,
+            ErrorCode::InvalidVaultDepositorIdempotency,
+            "VaultDepositorIdempotency is not for this vault depositor"
+        )?;
+
+        Ok(idempotency)
+    }
+}
+
+pub trait VaultWithdrawSettlementProvider<'info> {
+    fn vault_withdraw_settlement(
+        &self,
+        vault: &Vault,
+    ) -> Result<Option<AccountLoader<'info, VaultWithdrawSettlement>>>;
+}
+
+/// Provides the [`VaultWithdrawSettlement`] from remaining_accounts if the vault settles withdraws. Like the
+/// [`VaultHook`] it can be anywhere after the market accounts and is matched by discriminator rather than position.
+impl<'a: 'info, 'info, T: anchor_lang::Bumps> VaultWithdrawSettlementProvider<'info>
+    for Context<'_, '_, 'a, 'info, T>
+{
+    fn vault_withdraw_settlement(
+        &self,
+        vault: &Vault,
+    ) -> Result<Option<AccountLoader<'info, VaultWithdrawSettlement>>> {
+        if !vault.withdraw_settlement {
+            return Ok(None);
+        }
+
+        let settlement = self
+            .remaining_accounts
+            .iter()
+            .find_map(|acct| AccountLoader::<'info, VaultWithdrawSettlement>::try_from(acct).ok())
+            .ok_or(ErrorCode::InvalidWithdrawSettlement)?;
+
+        Ok(Some(settlement))
+    }
+}
+
+pub trait VaultInterfaceProvider<'info> {
+    fn vault_interface(
+        &self,
+        vault: &Pubkey,
+        interface: &Pubkey,
+    ) -> Option<AccountLoader<'info, VaultInterface>>;
+}
+
+/// Provides the [`VaultInterface`] registered for `interface` from remaining_accounts, if any. Like the [`VaultHook`]
+/// it can be anywhere after the market accounts and is matched by discriminator, vault and interface.
+impl<'a: 'info, 'info, T: anchor_lang::Bumps> VaultInterfaceProvider<'info>
+    for Context<'_, '_, 'a, 'info, T>
+{
+    fn vault_interface(
+        &self,
+        vault: &Pubkey,
+        interface: &Pubkey,
+    ) -> Option<AccountLoader<'info, VaultInterface>> {
+        self.remaining_accounts
+            .iter()
+            .filter(|acct| acct.is_writable)
+            .filter_map(|acct| AccountLoader::<'info, VaultInterface>::try_from(acct).ok())
+            .find(|vault_interface| {
+                vault_interface.load().map_or(false, |vault_interface| {
+                    vault_interface.vault == *vault && vault_interface.interface == *interface
+                })
+            })
+    }
+}
+
+pub trait VaultDustWithdrawProvider<'info> {
+    fn vault_dust_withdraw(
+        &self,
+        vault: &Pubkey,
+    ) -> Result<AccountLoader<'info, VaultDustWithdraw>>;
+}
+
+/// Provides the [`VaultDustWithdraw`] from remaining_accounts. Like the [`VaultHook`] it can be anywhere after the
+/// market accounts and is matched by discriminator and vault rather than position.
+impl<'a: 'info, 'info, T: anchor_lang::Bumps> VaultDustWithdrawProvider<'info>
+    for Context<'_, '_, 'a, 'info, T>
+{
+    fn vault_dust_withdraw(
+        &self,
+        vault: &Pubkey,
+    ) -> Result<AccountLoader<'info, VaultDustWithdraw>> {
+        let vault_dust_withdraw = self
+            .remaining_accounts
+            .iter()
+            .filter(|acct| acct.is_writable)
+            .find_map(|acct| AccountLoader::<'info, VaultDustWithdraw>::try_from(acct).ok())
+            .ok_or(ErrorCode::InvalidDustWithdraw)?;
+
+        validate!(
+            vault_dust_withdraw.load()?.vault == *vault,
+            ErrorCode::InvalidDustWithdraw,
+            "VaultDustWithdraw is not for this vault"
+        )?;
+
+        Ok(vault_dust_withdraw)
+    }
+}
+
+pub trait VaultWithdrawStreamProvider<'info> {
+    fn vault_withdraw_stream(
+        &self,
+        vault_depositor: &Pubkey,
+    ) -> Result<AccountLoader<'info, VaultWithdrawStream>>;
+}
+
+/// Provides the [`VaultWithdrawStream`] from remaining_accounts. Like the [`VaultHook`] it can be anywhere after the
+/// market accounts and is matched by discriminator and vault depositor rather than position.
+impl<'a: 'info, 'info, T: anchor_lang::Bumps> VaultWithdrawStreamProvider<'info>
+    for Context<'_, '_, 'a, 'info, T>
+{
+    fn vault_withdraw_stream(
+        &self,
+        vault_depositor: &Pubkey,
+    ) -> Result<AccountLoader<'info, VaultWithdrawStream>> {
+        let vault_withdraw_stream = self
+            .remaining_accounts
+            .iter()
+            .filter(|acct| acct.is_writable)
+            .find_map(|acct| AccountLoader::<'info, VaultWithdrawStream>::try_from(acct).ok())
+            .ok_or(ErrorCode::InvalidWithdrawStream)?;
+
+        validate!(
+            vault_withdraw_stream.load()?.vault_depositor == *vault_depositor,
+            ErrorCode::InvalidWithdrawStream,
+            "VaultWithdrawStream is not for this vault depositor"
+        )?;
+
+        Ok(vault_withdraw_stream)
+    }
+}
+
+pub trait VaultParamChangeLogProvider<'info> {
+    fn vault_param_change_log(&self) -> Option<AccountLoader<'info, VaultParamChangeLog>>;
+}
+
+/// Provides the [`VaultParamChangeLog`] from remaining_accounts, if any, validated by
+/// `Vault::validate_param_change_log`. Like the [`VaultHook`] it can be anywhere after the market accounts and is
+/// matched by discriminator rather than position.
+impl<'info, T: anchor_lang::Bumps> VaultParamChangeLogProvider<'info>
+    for Context<'_, '_, '_, 'info, T>
+{
+    fn vault_param_change_log(&self) -> Option<AccountLoader<'info, VaultParamChangeLog>> {
+        self.remaining_accounts
+            .iter()
+            .filter(|acct| acct.is_writable)
+            .find_map(|acct| AccountLoader::<'info, VaultParamChangeLog>::try_from(acct).ok())
+    }
+}
+
+pub trait VaultDepositorCapProvider<'info> {
+    fn vault_depositor_cap(&self) -> Option<AccountLoader<'info, VaultDepositorCap>>;
+}
+
+/// Provides the [`VaultDepositorCap`] from remaining_accounts, if any, validated by
+/// `Vault::validate_depositor_cap`.
+impl<'info, T: anchor_lang::Bumps> VaultDepositorCapProvider<'info>
+    for Context<'_, '_, '_, 'info, T>
+{
+    fn vault_depositor_cap(&self) -> Option<AccountLoader<'info, VaultDepositorCap>> {
+        self.remaining_accounts
+            .iter()
+            .find_map(|acct| AccountLoader::<'info, VaultDepositorCap>::try_from(acct).ok())
+    }
+}
+
+pub trait WithdrawReceiptProvider<'info> {
+    fn withdraw_receipt(
+        &self,
+        vault: &Vault,
+        vault_depositor: &Pubkey,
+    ) -> Result<Option<AccountLoader<'info, WithdrawReceipt>>>;
+}
+
+/// Provides the vault depositor's [`WithdrawReceipt`] from remaining_accounts, matched by address. Once the vault has
+/// [`VaultFlag::WithdrawReceipts`] the address must be passed even if the depositor never initialized a receipt, so
+/// a receipt can't be left out to get around its cancel deadline.
+impl<'a: 'info, 'info, T: anchor_lang::Bumps> WithdrawReceiptProvider<'info>
+    for Context<'_, '_, 'a, 'info, T>
+{
+    fn withdraw_receipt(
+        &self,
+        vault: &Vault,
+        vault_depositor: &Pubkey,
+    ) -> Result<Option<AccountLoader<'info, WithdrawReceipt>>> {
+        if !vault.has_flag(VaultFlag::WithdrawReceipts) {
+            return Ok(None);
+        }
+
+        let address = WithdrawReceipt::get_address(vault_depositor);
+        let acct = self
+            .remaining_accounts
+            .iter()
+            .find(|acct| acct.key == &address)
+            .ok_or(ErrorCode::InvalidWithdrawReceipt)?;
+
+        if acct.owner != &crate::id() {
+            // the depositor never initialized a receipt
+            return Ok(None);
+        }
+
+        Ok(Some(AccountLoader::<'info, WithdrawReceipt>::try_from(
+            acct,
+        )?))
+    }
+}
+
+pub trait VaultDepositorCompanionAccountsProvider<'info> {
+    fn close_companion_accounts(
+        &self,
+        vault_depositor: &Pubkey,
+        sol_destination: &AccountInfo<'info>,
+    ) -> Result<()>;
+}
+
+/// Closes the companion pdas keyed by the vault depositor's address that act on its position, so closing the vault
+/// depositor doesn't orphan them onto one later reopened at the same address. Each address must be passed in
+/// remaining_accounts, writable if the account exists, since leaving one out can't prove it was never initialized.
+/// Companions still holding value, an unclaimed withdraw stream, an escrowed or parked request or locked share class
+/// shares, block the close.
+impl<'a: 'info, 'info, T: anchor_lang::Bumps> VaultDepositorCompanionAccountsProvider<'info>
+    for Context<'_, '_, 'a, 'info, T>
+{
+    fn close_companion_accounts(
+        &self,
+        vault_depositor: &Pubkey,
+        sol_destination: &AccountInfo<'info>,
+    ) -> Result<()> {
+        let remaining_accounts = self.remaining_accounts;
+
+        if let Some(acct) = find_companion_account(
+            remaining_accounts,
+            "VaultDepositorLockup",
+            VaultDepositorLockup::get_address(vault_depositor),
+        )? {
+            AccountLoader::<'info, VaultDepositorLockup>::try_from(acct)?
+                .close(sol_destination.clone())?;
+        }
+
+        if let Some(acct) = find_companion_account(
+            remaining_accounts,
+            "VaultWithdrawStream",
+            VaultWithdrawStream::get_address(vault_depositor),
+        )? {
+            let stream = AccountLoader::<'info, VaultWithdrawStream>::try_from(acct)?;
+            validate!(
+                !stream.load()?.is_active(),
+                ErrorCode::VaultDepositorHasCompanionAccounts,
+                "claim the withdraw stream before closing the vault depositor"
+            )?;
+            stream.close(sol_destination.clone())?;
+        }
+
+        if let Some(acct) = find_companion_account(
+            remaining_accounts,
+            "VaultDepositorWithdrawEscrow",
+            VaultDepositorWithdrawEscrow::get_address(vault_depositor),
+        )? {
+            let escrow = AccountLoader::<'info, VaultDepositorWithdrawEscrow>::try_from(acct)?;
+            validate!(
+                !escrow.load()?.is_active(),
+                ErrorCode::VaultDepositorHasCompanionAccounts,
+                "withdraw the escrowed request before closing the vault depositor"
+            )?;
+            escrow.close(sol_destination.clone())?;
+        }
+
+        if let Some(acct) = find_companion_account(
+            remaining_accounts,
+            "VaultDepositorShareClass",
+            VaultDepositorShareClass::get_address(vault_depositor),
+        )? {
+            let share_class = AccountLoader::<'info, VaultDepositorShareClass>::try_from(acct)?;
+            validate!(
+                share_class.load()?.get_vault_shares() == 0,
+                ErrorCode::VaultDepositorHasCompanionAccounts,
+                "convert the share class shares back before closing the vault depositor"
+            )?;
+            share_class.close(sol_destination.clone())?;
+        }
+
+        if let Some(acct) = find_companion_account(
+            remaining_accounts,
+            "VaultDepositorWithdrawRequests",
+            VaultDepositorWithdrawRequests::get_address(vault_depositor),
+        )? {
+            let withdraw_requests =
+                AccountLoader::<'info, VaultDepositorWithdrawRequests>::try_from(acct)?;
+            validate!(
+                !withdraw_requests
+                    .load()?
+                    .requests
+                    .iter()
+                    .any(|request| request.pending()),
+                ErrorCode::VaultDepositorHasCompanionAccounts,
+                "withdraw or cancel the parked withdraw requests before closing the vault depositor"
+            )?;
+            withdraw_requests.close(sol_destination.clone())?;
+        }
+
+        if let Some(acct) = find_companion_account(
+            remaining_accounts,
+            "WithdrawReceipt",
+            WithdrawReceipt::get_address(vault_depositor),
+        )? {
+            AccountLoader::<'info, WithdrawReceipt>::try_from(acct)?
+                .close(sol_destination.clone())?;
+        }
+
+        if let Some(acct) = find_companion_account(
+            remaining_accounts,
+            "VaultDepositorProfitShareCadence",
+            VaultDepositorProfitShareCadence::get_address(vault_depositor),
+        )? {
+            AccountLoader::<'info, VaultDepositorProfitShareCadence>::try_from(acct)?
+                .close(sol_destination.clone())?;
+        }
+
+        if let Some(acct) = find_companion_account(
+            remaining_accounts,
+            "VaultDepositorIdempotency",
+            VaultDepositorIdempotency::get_address(vault_depositor),
+        )? {
+            AccountLoader::<'info, VaultDepositorIdempotency>::try_from(acct)?
+                .close(sol_destination.clone())?;
+        }
+
+        if let Some(acct) = find_companion_account(
+            remaining_accounts,
+            "VaultDepositorThrottleState",
+            VaultDepositorThrottleState::get_address(vault_depositor),
+        )? {
+            AccountLoader::<'info, VaultDepositorThrottleState>::try_from(acct)?
+                .close(sol_destination.clone())?;
+        }
+
+        if let Some(acct) = find_companion_account(
+            remaining_accounts,
+            "VaultDepositorMemo",
+            VaultDepositorMemo::get_address(vault_depositor),
+        )? {
+            AccountLoader::<'info, VaultDepositorMemo>::try_from(acct)?
+                .close(sol_destination.clone())?;
+        }
+
+        if let Some(acct) = find_companion_account(
+            remaining_accounts,
+            "VaultDepositorAuthorityTransfer",
+            VaultDepositorAuthorityTransfer::get_address(vault_depositor),
+        )? {
+            AccountLoader::<'info, VaultDepositorAuthorityTransfer>::try_from(acct)?
+                .close(sol_destination.clone())?;
+        }
+
+        Ok(())
+    }
+}
+
+/// The companion account at `address` if it was initialized, `None` if the address was passed but never initialized.
+pub(crate) fn find_companion_account<'a, 'info>(
+    remaining_accounts: &'a [AccountInfo<'info>],
+    name: &str,
+    address: Pubkey,
+) -> Result<Option<&'a AccountInfo<'info>>> {
+    let acct = remaining_accounts
+        .iter()
+        .find(|acct| acct.key == &address)
+        .ok_or_else(|| {
+            msg!(
+                "{} address {} must be passed to close the vault depositor",
+                name,
+                address
+            );
+            ErrorCode::VaultDepositorHasCompanionAccounts
+        })?;
+
+    Ok((acct.owner == &crate::id()).then_some(acct))
+}
+
+pub trait InstructionsSysvarProvider<'info> {
+    fn instructions_sysvar(&self) -> Option<&'info AccountInfo<'info>>;
+}
+
+/// Provides the instructions sysvar from remaining_accounts, matched by address
+impl<'a: 'info, 'info, T: anchor_lang::Bumps> InstructionsSysvarProvider<'info>
+    for Context<'_, '_, 'a, 'info, T>
+{
+    fn instructions_sysvar(&self) -> Option<&'info AccountInfo<'info>> {
+        self.remaining_accounts
+            .iter()
+            .find(|acct| acct.key == &anchor_lang::solana_program::sysvar::instructions::ID)
+    }
+}
+
+pub trait ProtocolTvlCapProvider<'info> {
+    fn protocol_tvl_cap(
+        &self,
+        vault_protocol: &Option<RefMut<VaultProtocol>>,
+    ) -> Result<Option<AccountLoader<'info, ProtocolTvlCap>>>;
+}
+
+/// Provides the protocol's [`ProtocolTvlCap`] from remaining_accounts, matched by address. Required once the
+/// protocol added the vault to its cap, see [`VaultProtocolFlag::TvlCap`].
+impl<'a: 'info, 'info, T: anchor_lang::Bumps> ProtocolTvlCapProvider<'info>
+    for Context<'_, '_, 'a, 'info, T>
+{
+    fn protocol_tvl_cap(
+        &self,
+        vault_protocol: &Option<RefMut<VaultProtocol>>,
+    ) -> Result<Option<AccountLoader<'info, ProtocolTvlCap>>> {
+        let protocol = match vault_protocol {
+            Some(vp) if vp.has_flag(VaultProtocolFlag::TvlCap) => vp.protocol,
+            _ => return Ok(None),
+        };
+
+        let address = ProtocolTvlCap::get_address(&protocol);
+        let acct = self
+            .remaining_accounts
+            .iter()
+            .find(|acct| acct.key == &address)
+            .ok_or(ErrorCode::InvalidProtocolTvlCap)?;
+
+        Ok(Some(AccountLoader::<'info, ProtocolTvlCap>::try_from(
+            acct,
+        )?))
+    }
+}
+
+pub trait VaultRedeemScheduleProvider<'info> {
+    fn vault_redeem_schedule(
+        &self,
+        vault: &Pubkey,
+    ) -> Result<Option<AccountLoader<'info, VaultRedeemSchedule>>>;
+}
+
+/// Provides the vault's [`VaultRedeemSchedule`] from remaining_accounts, matched by address. Optional, without it
+/// requests wait the vault's full redeem period.
+impl<'a: 'info, 'info, T: anchor_lang::Bumps> VaultRedeemScheduleProvider<'info>
+    for Context<'_, '_, 'a, 'info, T>
+{
+    fn vault_redeem_schedule(
+        &self,
+        vault: &Pubkey,
+    ) -> Result<Option<AccountLoader<'info, VaultRedeemSchedule>>> {
+        let address = VaultRedeemSchedule::get_address(vault);
+        self.remaining_accounts
+            .iter()
+            .find(|acct| acct.key == &address)
+            .map(AccountLoader::<'info, VaultRedeemSchedule>::try_from)
+            .transpose()
+    }
+}
+
+pub trait VaultDepositorRecordLogProvider<'info> {
+    fn vault_depositor_record_log(
+        &self,
+        vault: &Pubkey,
+    ) -> Result<Option<AccountLoader<'info, VaultDepositorRecordLog>>>;
+}
+
+/// Provides the vault's [`VaultDepositorRecordLog`] from remaining_accounts, matched by address. Optional, depositor
+/// flows only mirror their records when it's passed.
+impl<'a: 'info, 'info, T: anchor_lang::Bumps> VaultDepositorRecordLogProvider<'info>
+    for Context<'_, '_, 'a, 'info, T>
+{
+    fn vault_depositor_record_log(
+        &self,
+        vault: &Pubkey,
+    ) -> Result<Option<AccountLoader<'info, VaultDepositorRecordLog>>> {
+        let address = VaultDepositorRecordLog::get_address(vault);
+        self.remaining_accounts
+            .iter()
+            .find(|acct| acct.key == &address)
+            .map(AccountLoader::<'info, VaultDepositorRecordLog>::try_from)
+            .transpose()
+    }
+}
+
+pub trait VaultDepositorThrottleProvider<'info> {
+    fn vault_depositor_throttle(
+        &self,
+        vault: &Vault,
+        vault_depositor: &Pubkey,
+    ) -> Result<
+        Option<(
+            AccountLoader<'info, VaultDepositorThrottle>,
+            Option<AccountLoader<'info, VaultDepositorThrottleState>>,
+        )>,
+    >;
+}
+
+/// Provides the vault's [`VaultDepositorThrottle`] and the depositor's [`VaultDepositorThrottleState`] from
+/// remaining_accounts, matched by address. Both addresses are required once the vault's [`VaultConfig`] has
+/// [`VaultConfigFlag::DepositorThrottle`], so it can't be left out to get around the throttle. The depositor's is
+/// `None` if it was never initialized, the depositor hasn't deposited since the throttle was enabled.
+impl<'a: 'info, 'info, T: anchor_lang::Bumps> VaultDepositorThrottleProvider<'info>
+    for Context<'_, '_, 'a, 'info, T>
+{
+    fn vault_depositor_throttle(
+        &self,
+        vault: &Vault,
+        vault_depositor: &Pubkey,
+    ) -> Result<
+        Option<(
+            AccountLoader<'info, VaultDepositorThrottle>,
+            Option<AccountLoader<'info, VaultDepositorThrottleState>>,
+        )>,
+    > {
+        if !self
+            .vault_config(vault)?
+            .has_flag(VaultConfigFlag::DepositorThrottle)
+        {
+            return Ok(None);
+        }
+
+        let vault_depositor_throttle_address = VaultDepositorThrottle::get_address(&vault.pubkey);
+        let vault_depositor_throttle = self
+            .remaining_accounts
+            .iter()
+            .find(|acct| acct.key == &vault_depositor_throttle_address)
+            .ok_or(ErrorCode::InvalidVaultDepositorThrottle)?;
+        let vault_depositor_throttle_state_address =
+            VaultDepositorThrottleState::get_address(vault_depositor);
+        let vault_depositor_throttle_state = self
+            .remaining_accounts
+            .iter()
+            .find(|acct| acct.key == &vault_depositor_throttle_state_address)
+            .ok_or(ErrorCode::InvalidVaultDepositorThrottle)?;
+
+        let vault_depositor_throttle_state = if vault_depositor_throttle_state.owner == &crate::id()
+        {
+            Some(
+                AccountLoader::<'info, VaultDepositorThrottleState>::try_from(
+                    vault_depositor_throttle_state,
+                )?,
+            )
+        } else {
+            None
+        };
+
+        Ok(Some((
+            AccountLoader::<'info, VaultDepositorThrottle>::try_from(vault_depositor_throttle)?,
+            vault_depositor_throttle_state,
+        )))
+    }
+}
+
+pub trait VaultGuardProvider<'info> {
+    fn vault_guard(&self, vault: &Vault) -> Result<Option<AccountLoader<'info, VaultGuard>>>;
+}
+
+/// Provides the vault's [`VaultGuard`] from remaining_accounts, matched by address. Required once the vault has one,
+/// see [`VaultConfigFlag::VaultGuard`], so it can't be left out to get around the guard.
+impl<'a: 'info, 'info, T: anchor_lang::Bumps> VaultGuardProvider<'info>
+    for Context<'_, '_, 'a, 'info, T>
+{
+    fn vault_guard(&self, vault: &Vault) -> Result<Option<AccountLoader<'info, VaultGuard>>> {
+        if !self
+            .vault_config(vault)?
+            .has_flag(VaultConfigFlag::VaultGuard)
+        {
+            return Ok(None);
+        }
+
+        let address = VaultGuard::get_address(&vault.pubkey);
+        let acct = self
+            .remaining_accounts
+            .iter()
+            .find(|acct| acct.key == &address)
+            .ok_or(ErrorCode::InvalidVaultGuard)?;
+
+        Ok(Some(AccountLoader::<'info, VaultGuard>::try_from(acct)?))
+    }
+}
+
+pub trait VaultWithdrawBlackoutProvider<'info> {
+    fn vault_withdraw_blackout(
+        &self,
+        vault: &Vault,
+    ) -> Result<Option<AccountLoader<'info, VaultWithdrawBlackout>>>;
+}
+
+/// Provides the vault's [`VaultWithdrawBlackout`] from remaining_accounts, matched by address. Required once the
+/// vault's [`VaultGuard`] has [`GuardFlag::WithdrawBlackout`], so it can't be left out to withdraw during a blackout.
+impl<'a: 'info, 'info, T: anchor_lang::Bumps> VaultWithdrawBlackoutProvider<'info>
+    for Context<'_, '_, 'a, 'info, T>
+{
+    fn vault_withdraw_blackout(
+        &self,
+        vault: &Vault,
+    ) -> Result<Option<AccountLoader<'info, VaultWithdrawBlackout>>> {
+        let has_withdraw_blackout = match self.vault_guard(vault)? {
+            Some(vault_guard) => vault_guard.load()?.has_flag(GuardFlag::WithdrawBlackout),
+            None => false,
+        };
+        if !has_withdraw_blackout {
+            return Ok(None);
+        }
+
+        let address = VaultWithdrawBlackout::get_address(&vault.pubkey);
+        let acct = self
+            .remaining_accounts
+            .iter()
+            .find(|acct| acct.key == &address)
+            .ok_or(ErrorCode::InvalidVaultWithdrawBlackout)?;
+
+        Ok(Some(
+            AccountLoader::<'info, VaultWithdrawBlackout>::try_from(acct)?,
+        ))
+    }
+}
+
+pub trait VaultLockupProvider<'info> {
+    fn vault_lockup(
+        &self,
+        vault: &Vault,
+        vault_depositor: &Pubkey,
+    ) -> Result<
+        Option<(
+            AccountLoader<'info, VaultLockup>,
+            Option<AccountLoader<'info, VaultDepositorLockup>>,
+        )>,
+    >;
+}
+
+/// Provides the vault's [`VaultLockup`] and the depositor's [`VaultDepositorLockup`] from remaining_accounts, matched
+/// by address. Both addresses are required once the vault's [`VaultGuard`] has [`GuardFlag::Lockup`], so a deposit
+/// can't be left untracked. The depositor's is `None` if it was never initialized, the depositor hasn't deposited
+/// since the lockup was enabled.
+impl<'a: 'info, 'info, T: anchor_lang::Bumps> VaultLockupProvider<'info>
+    for Context<'_, '_, 'a, 'info, T>
+{
+    fn vault_lockup(
+        &self,
+        vault: &Vault,
+        vault_depositor: &Pubkey,
+    ) -> Result<
+        Option<(
+            AccountLoader<'info, VaultLockup>,
+            Option<AccountLoader<'info, VaultDepositorLockup>>,
+        )>,
+    > {
+        let has_lockup = match self.vault_guard(vault)? {
+            Some(vault_guard) => vault_guard.load()?.has_flag(GuardFlag::Lockup),
+            None => false,
+        };
+        if !has_lockup {
+            return Ok(None);
+        }
+
+        let vault_lockup_address = VaultLockup::get_address(&vault.pubkey);
+        let vault_lockup = self
+            .remaining_accounts
+            .iter()
+            .find(|acct| acct.key == &vault_lockup_address)
+            .ok_or(ErrorCode::InvalidVaultLockup)?;
+        let vault_depositor_lockup_address = VaultDepositorLockup::get_address(vault_depositor);
+        let vault_depositor_lockup = self
+            .remaining_accounts
+            .iter()
+            .find(|acct| acct.key == &vault_depositor_lockup_address)
+            .ok_or(ErrorCode::InvalidVaultLockup)?;
+
+        let vault_depositor_lockup = if vault_depositor_lockup.owner == &crate::id() {
+            Some(AccountLoader::<'info, VaultDepositorLockup>::try_from(
+                vault_depositor_lockup,
+            )?)
+        } else {
+            None
+        };
+
+        Ok(Some((
+            AccountLoader::<'info, VaultLockup>::try_from(vault_lockup)?,
+            vault_depositor_lockup,
+        )))
+    }
+}
+
+pub trait VaultProfitShareCadenceProvider<'info> {
+    fn vault_profit_share_cadence(
+        &self,
+        vault: &Vault,
+        vault_depositor: &Pubkey,
+    ) -> Result<
+        Option<(
+            AccountLoader<'info, VaultProfitShareCadence>,
+            AccountLoader<'info, VaultDepositorProfitShareCadence>,
+        )>,
+    >;
+}
+
+/// Provides the vault's [`VaultProfitShareCadence`] and the depositor's [`VaultDepositorProfitShareCadence`] from
+/// remaining_accounts, matched by address. Both are required once the vault's [`VaultGuard`] has
+/// [`GuardFlag::ProfitShareCadence`], so a crank can't leave them out to crystallize early.
+impl<'a: 'info, 'info, T: anchor_lang::Bumps> VaultProfitShareCadenceProvider<'info>
+    for Context<'_, '_, 'a, 'info, T>
+{
+    fn vault_profit_share_cadence(
+        &self,
+        vault: &Vault,
+        vault_depositor: &Pubkey,
+    ) -> Result<
+        Option<(
+            AccountLoader<'info, VaultProfitShareCadence>,
+            AccountLoader<'info, VaultDepositorProfitShareCadence>,
+        )>,
+    > {
+        let has_profit_share_cadence = match self.vault_guard(vault)? {
+            Some(vault_guard) => vault_guard.load()?.has_flag(GuardFlag::ProfitShareCadence),
+            None => false,
+        };
+        if !has_profit_share_cadence {
+            return Ok(None);
+        }
+
+        let vault_cadence_address = VaultProfitShareCadence::get_address(&vault.pubkey);
+        let vault_cadence = self
+            .remaining_accounts
+            .iter()
+            .find(|acct| acct.key == &vault_cadence_address)
+            .ok_or(ErrorCode::InvalidVaultProfitShareCadence)?;
+        let vault_depositor_cadence_address =
+            VaultDepositorProfitShareCadence::get_address(vault_depositor);
+        let vault_depositor_cadence = self
+            .remaining_accounts
+            .iter()
+            .find(|acct| acct.key == &vault_depositor_cadence_address)
+            .ok_or(ErrorCode::InvalidVaultProfitShareCadence)?;
+
+        Ok(Some((
+            AccountLoader::<'info, VaultProfitShareCadence>::try_from(vault_cadence)?,
+            AccountLoader::<'info, VaultDepositorProfitShareCadence>::try_from(
+                vault_depositor_cadence,
+            )?,
+        )))
+    }
+}
+
+pub trait OmnibusLedgerProvider<'info> {
+    fn omnibus_ledger(
+        &self,
+        vault_depositor: &VaultDepositor,
+    ) -> Result<Option<AccountLoader<'info, OmnibusLedger>>>;
+}
+
+/// Provides an omnibus depositor's [`OmnibusLedger`] from remaining_accounts, matched by address. Required once the
+/// depositor has [`VaultDepositorFlag::Omnibus`], so it can't be left out to withdraw allocated shares.
+impl<'a: 'info, 'info, T: anchor_lang::Bumps> OmnibusLedgerProvider<'info>
+    for Context<'_, '_, 'a, 'info, T>
+{
+    fn omnibus_ledger(
+        &self,
+        vault_depositor: &VaultDepositor,
+    ) -> Result<Option<AccountLoader<'info, OmnibusLedger>>> {
+        if !vault_depositor.has_flag(VaultDepositorFlag::Omnibus) {
+            return Ok(None);
+        }
+
+        let address = OmnibusLedger::get_address(&vault_depositor.pubkey);
+        let acct = self
+            .remaining_accounts
+            .iter()
+            .find(|acct| acct.key == &address)
+            .ok_or(ErrorCode::InvalidOmnibusLedger)?;
+
+        Ok(Some(AccountLoader::<'info, OmnibusLedger>::try_from(acct)?))
+    }
+}
+
+pub trait VaultDepositorWithdrawRequestsProvider<'info> {
+    fn vault_depositor_withdraw_requests(
+        &self,
+        vault_depositor: &VaultDepositor,
+    ) -> Result<Option<AccountLoader<'info, VaultDepositorWithdrawRequests>>>;
+}
+
+/// Provides the depositor's [`VaultDepositorWithdrawRequests`] from remaining_accounts, matched by address. Required
+/// once the depositor has [`VaultDepositorFlag::ParkedWithdrawRequests`], so it can't be left out to request parked
+/// shares again.
+impl<'a: 'info, 'info, T: anchor_lang::Bumps> VaultDepositorWithdrawRequestsProvider<'info>
+    for Context<'_, '_, 'a, 'info, T>
+{
+    fn vault_depositor_withdraw_requests(
+        &self,
+        vault_depositor: &VaultDepositor,
+    ) -> Result<Option<AccountLoader<'info, VaultDepositorWithdrawRequests>>> {
+        if !vault_depositor.has_flag(VaultDepositorFlag::ParkedWithdrawRequests) {
+            return Ok(None);
+        }
+
+        let address = VaultDepositorWithdrawRequests::get_address(&vault_depositor.pubkey);
+        let acct = self
+            .remaining_accounts
+            .iter()
+            .find(|acct| acct.key == &address)
+            .ok_or(ErrorCode::InvalidVaultDepositorWithdrawRequests)?;
+
+        Ok(Some(
+            AccountLoader::<'info, VaultDepositorWithdrawRequests>::try_from(acct)?,
+        ))
+    }
+}
+
+pub trait VaultManagerActivityProvider<'info> {
+    fn vault_manager_activity(
+        &self,
+        vault: &Pubkey,
+    ) -> Option<AccountLoader<'info, VaultManagerActivity>>;
+
+    /// For status records, 0 if the vault's [`VaultManagerActivity`] isn't in remaining_accounts
+    fn last_manager_activity_ts(&self, vault: &Pubkey) -> Result<i64> {
+        self.vault_manager_activity(vault)
+            .map_or(Ok(0), |activity| {
+                Ok(activity.load()?.last_manager_activity_ts)
+            })
+    }
+}
+
+/// Provides the vault's [`VaultManagerActivity`] from remaining_accounts, matched by address. Manager instructions
+/// refresh it when it's passed, a manager leaving it out only looks inactive sooner.
+impl<'info, T: anchor_lang::Bumps> VaultManagerActivityProvider<'info>
+    for Context<'_, '_, '_, 'info, T>
+{
+    fn vault_manager_activity(
+        &self,
+        vault: &Pubkey,
+    ) -> Option<AccountLoader<'info, VaultManagerActivity>> {
+        let address = VaultManagerActivity::get_address(vault);
+        self.remaining_accounts
+            .iter()
+            .find(|acct| acct.key == &address)
+            .and_then(|acct| AccountLoader::<'info, VaultManagerActivity>::try_from(acct).ok())
+    }
+}
+
+pub trait VaultAuditorProvider<'info> {
+    fn vault_auditor(&self, vault: &Pubkey) -> Option<AccountLoader<'info, VaultAuditor>>;
+}
+
+/// Provides the vault's [`VaultAuditor`] from remaining_accounts, matched by address. Only needed when the auditor
+/// rather than the manager signs.
+impl<'info, T: anchor_lang::Bumps> VaultAuditorProvider<'info> for Context<'_, '_, '_, 'info, T> {
+    fn vault_auditor(&self, vault: &Pubkey) -> Option<AccountLoader<'info, VaultAuditor>> {
+        let address = VaultAuditor::get_address(vault);
+        self.remaining_accounts
+            .iter()
+            .find(|acct| acct.key == &address)
+            .and_then(|acct| AccountLoader::<'info, VaultAuditor>::try_from(acct).ok())
+    }
+}
+
+pub trait TokenMintProvider<'info> {
+    fn token_mint(&self, mint: &Pubkey) -> Result<Option<InterfaceAccount<'info, Mint>>>;
+}
+
+/// Provides the vault's deposit mint from remaining_accounts, matched by address. Token-2022 vaults need it for
+/// `transfer_checked`, it goes right after the market accounts where drift also reads it.
+impl<'a: 'info, 'info, T: anchor_lang::Bumps> TokenMintProvider<'info>
+    for Context<'_, '_, 'a, 'info, T>
+{
+    fn token_mint(&self, mint: &Pubkey) -> Result<Option<InterfaceAccount<'info, Mint>>> {
+        let acct = match self.remaining_accounts.iter().find(|acct| acct.key == mint) {
+            Some(acct) => acct,
+            None => return Ok(None),
+        };
+
+        Ok(Some(InterfaceAccount::<'info, Mint>::try_from(acct)?))
+    }
+}
+
+pub trait SwapAccountsProvider<'info> {
+    fn swap_accounts(&self, swap_accounts_len: usize) -> Result<SwapAccounts<'info>>;
+}
+
+/// Provides a whitelisted swap program and the `swap_accounts_len` accounts right after it from remaining_accounts.
+/// They go after the market accounts and, like everything else there, before the [`VaultProtocol`].
+impl<'a: 'info, 'info, T: anchor_lang::Bumps> SwapAccountsProvider<'info>
+    for Context<'_, '_, 'a, 'info, T>
+{
+    fn swap_accounts(&self, swap_accounts_len: usize) -> Result<SwapAccounts<'info>> {
+        SwapAccounts::find(self.remaining_accounts, swap_accounts_len)
+    }
+}
@@ -0,0 +1,99 @@
+use std::collections::BTreeSet;
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::set_return_data;
+use drift::math::casting::Cast;
+use drift::math::safe_math::SafeMath;
+use drift::state::perp_market_map::PerpMarketMap;
+use drift::state::spot_market_map::SpotMarketMap;
+
+/// Estimated compute units of a heavy instruction before loading markets, including its drift cpis
+pub const DEPOSIT_BASE_COMPUTE_UNITS: u32 = 60_000;
+pub const REQUEST_WITHDRAW_BASE_COMPUTE_UNITS: u32 = 30_000;
+pub const CANCEL_WITHDRAW_REQUEST_BASE_COMPUTE_UNITS: u32 = 30_000;
+pub const WITHDRAW_BASE_COMPUTE_UNITS: u32 = 80_000;
+
+/// Estimated compute units of loading and pricing a market or oracle passed in remaining accounts
+pub const PERP_MARKET_COMPUTE_UNITS: u32 = 6_000;
+pub const SPOT_MARKET_COMPUTE_UNITS: u32 = 4_000;
+pub const ORACLE_COMPUTE_UNITS: u32 = 3_000;
+
+/// Written to return data by heavy instructions so clients simulating a transaction can set its compute budget
+/// from the markets the vault actually loaded instead of over-reserving.
+#[derive(Clone, Copy, Default, AnchorSerialize, AnchorDeserialize, PartialEq, Debug, Eq)]
+pub struct ComputeUnitsHint {
+    pub perp_markets: u8,
+    pub spot_markets: u8,
+    pub oracles: u8,
+    /// estimated compute units of the instruction
+    pub compute_units: u32,
+}
+
+impl ComputeUnitsHint {
+    pub fn new(
+        base_compute_units: u32,
+        perp_market_map: &PerpMarketMap,
+        spot_market_map: &SpotMarketMap,
+    ) -> Result<Self> {
+        let mut oracles = BTreeSet::new();
+        for perp_market in perp_market_map.0.values() {
+            oracles.insert(perp_market.load()?.amm.oracle);
+        }
+        for spot_market in spot_market_map.0.values() {
+            oracles.insert(spot_market.load()?.oracle);
+        }
+
+        Self::from_counts(
+            base_compute_units,
+            perp_market_map.0.len().cast()?,
+            spot_market_map.0.len().cast()?,
+            oracles.len().cast()?,
+        )
+    }
+
+    pub fn from_counts(
+        base_compute_units: u32,
+        perp_markets: u8,
+        spot_markets: u8,
+        oracles: u8,
+    ) -> Result<Self> {
+        let compute_units = base_compute_units
+            .safe_add(PERP_MARKET_COMPUTE_UNITS.safe_mul(perp_markets.into())?)?
+            .safe_add(SPOT_MARKET_COMPUTE_UNITS.safe_mul(spot_markets.into())?)?
+            .safe_add(ORACLE_COMPUTE_UNITS.safe_mul(oracles.into())?)?;
+
+        Ok(ComputeUnitsHint {
+            perp_markets,
+            spot_markets,
+            oracles,
+            compute_units,
+        })
+    }
+
+    /// Must be the last return data of the instruction, cpis that return data overwrite it
+    pub fn set_return_data(&self) -> Result<()> {
+        set_return_data(&self.try_to_vec()?);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compute_units_hint() {
+        let hint = ComputeUnitsHint::from_counts(WITHDRAW_BASE_COMPUTE_UNITS, 2, 1, 3).unwrap();
+        assert_eq!(
+            hint.compute_units,
+            WITHDRAW_BASE_COMPUTE_UNITS
+                + 2 * PERP_MARKET_COMPUTE_UNITS
+                + SPOT_MARKET_COMPUTE_UNITS
+                + 3 * ORACLE_COMPUTE_UNITS
+        );
+
+        let data = hint.try_to_vec().unwrap();
+        assert_eq!(data.len(), 7);
+        assert_eq!(ComputeUnitsHint::try_from_slice(&data).unwrap(), hint);
+    }
+}
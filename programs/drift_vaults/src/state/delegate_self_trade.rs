@@ -0,0 +1,78 @@
+use drift::state::user::{Order, OrderStatus, User};
+
+use crate::state::Vault;
+
+/// Whether `user` is controlled by the vault's manager or delegate, as its authority or drift delegate
+pub fn is_controlled_by_vault_operator(vault: &Vault, user: &User) -> bool {
+    [vault.manager, vault.delegate]
+        .iter()
+        .any(|operator| user.authority == *operator || user.delegate == *operator)
+}
+
+/// The first open order of `vault_user` that `counterparty` has an open order against: same market, opposite
+/// direction. Drift only keeps a user from filling against itself, so this is as close to self-match prevention as
+/// can be checked from the two users' order books.
+pub fn find_opposing_order<'a>(vault_user: &'a User, counterparty: &User) -> Option<&'a Order> {
+    let is_open = |order: &&Order| order.status == OrderStatus::Open;
+
+    vault_user.orders.iter().filter(is_open).find(|order| {
+        counterparty.orders.iter().filter(is_open).any(|other| {
+            other.market_type == order.market_type
+                && other.market_index == order.market_index
+                && other.direction != order.direction
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use anchor_lang::prelude::Pubkey;
+    use drift::controller::position::PositionDirection;
+    use drift::state::user::{MarketType, Order, OrderStatus, User};
+
+    use crate::state::{find_opposing_order, is_controlled_by_vault_operator, Vault};
+
+    fn open_order(market_index: u16, direction: PositionDirection) -> Order {
+        Order {
+            status: OrderStatus::Open,
+            market_type: MarketType::Perp,
+            market_index,
+            direction,
+            ..Order::default()
+        }
+    }
+
+    #[test]
+    fn test_find_opposing_order() {
+        let vault = Vault {
+            manager: Pubkey::new_unique(),
+            delegate: Pubkey::new_unique(),
+            ..Vault::default()
+        };
+
+        let mut vault_user = User::default();
+        vault_user.orders[0] = open_order(0, PositionDirection::Long);
+
+        let mut counterparty = User {
+            delegate: vault.delegate,
+            ..User::default()
+        };
+        assert!(is_controlled_by_vault_operator(&vault, &counterparty));
+        assert!(!is_controlled_by_vault_operator(&vault, &User::default()));
+
+        // same side, or another market, is fine
+        counterparty.orders[0] = open_order(0, PositionDirection::Long);
+        counterparty.orders[1] = open_order(1, PositionDirection::Short);
+        assert!(find_opposing_order(&vault_user, &counterparty).is_none());
+
+        counterparty.orders[2] = open_order(0, PositionDirection::Short);
+        assert_eq!(
+            find_opposing_order(&vault_user, &counterparty).map(|order| order.market_index),
+            Some(0)
+        );
+
+        // filled and canceled orders don't count
+        counterparty.orders[2].status = OrderStatus::Canceled;
+        assert!(find_opposing_order(&vault_user, &counterparty).is_none());
+    }
+}
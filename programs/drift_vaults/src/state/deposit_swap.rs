@@ -0,0 +1,142 @@
+use anchor_lang::prelude::*;
+use drift::math::safe_math::SafeMath;
+
+use crate::constants::jupiter;
+use crate::error::ErrorCode;
+use crate::validate;
+
+pub struct SwapAccounts<'info> {
+    pub swap_program: AccountInfo<'info>,
+    pub accounts: Vec<AccountInfo<'info>>,
+}
+
+impl<'info> SwapAccounts<'info> {
+    /// The whitelisted swap program in `accounts` and the `swap_accounts_len` accounts right after it.
+    pub fn find(accounts: &[AccountInfo<'info>], swap_accounts_len: usize) -> Result<Self> {
+        let index = accounts
+            .iter()
+            .position(|acct| acct.executable && acct.key == &jupiter::id())
+            .ok_or(ErrorCode::InvalidSwapProgram)?;
+
+        let swap_accounts = accounts
+            .get(index + 1..index + 1 + swap_accounts_len)
+            .ok_or(ErrorCode::InvalidSwapProgram)?;
+
+        Ok(SwapAccounts {
+            swap_program: accounts[index].clone(),
+            accounts: swap_accounts.to_vec(),
+        })
+    }
+}
+
+/// What a swap paid out to the depositor's token account, from its balance around the swap. Fails if it's less than
+/// `min_amount_out`.
+pub fn calculate_swap_amount_out(
+    balance_before: u64,
+    balance_after: u64,
+    min_amount_out: u64,
+) -> Result<u64> {
+    let amount_out = balance_after.safe_sub(balance_before)?;
+
+    validate!(
+        amount_out >= min_amount_out,
+        ErrorCode::SwapOutputBelowMinimum,
+        "swap paid out {} < min_amount_out {}",
+        amount_out,
+        min_amount_out
+    )?;
+
+    Ok(amount_out)
+}
+
+#[cfg(test)]
+mod tests {
+    use anchor_lang::prelude::{AccountInfo, Pubkey};
+
+    use crate::constants::jupiter;
+    use crate::error::ErrorCode;
+    use crate::state::{calculate_swap_amount_out, SwapAccounts};
+
+    #[test]
+    fn test_calculate_swap_amount_out() {
+        assert_eq!(calculate_swap_amount_out(100, 1_100, 1_000).unwrap(), 1_000);
+        assert_eq!(calculate_swap_amount_out(0, 1_500, 1_000).unwrap(), 1_500);
+
+        assert_eq!(
+            calculate_swap_amount_out(100, 1_099, 1_000),
+            Err(ErrorCode::SwapOutputBelowMinimum.into())
+        );
+        // the swap took tokens out of the depositor's token account
+        assert!(calculate_swap_amount_out(1_100, 100, 0).is_err());
+    }
+
+    #[test]
+    fn test_find_swap_accounts() {
+        let swap_program = jupiter::id();
+        let owner = Pubkey::new_unique();
+        let keys = [
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        ];
+
+        let (mut lamports, mut data) = (0, vec![]);
+        let program_info = AccountInfo::new(
+            &swap_program,
+            false,
+            false,
+            &mut lamports,
+            &mut data,
+            &owner,
+            true,
+            0,
+        );
+        let (mut lamports, mut data) = (0, vec![]);
+        let not_executable = AccountInfo::new(
+            &swap_program,
+            false,
+            false,
+            &mut lamports,
+            &mut data,
+            &owner,
+            false,
+            0,
+        );
+        let mut lamports = [0; 3];
+        let mut data: [Vec<u8>; 3] = Default::default();
+        let account_infos: Vec<AccountInfo> = keys
+            .iter()
+            .zip(lamports.iter_mut())
+            .zip(data.iter_mut())
+            .map(|((key, lamports), data)| {
+                AccountInfo::new(key, false, true, lamports, data, &owner, false, 0)
+            })
+            .collect();
+
+        let mut remaining_accounts = vec![account_infos[0].clone(), program_info.clone()];
+        remaining_accounts.extend(account_infos[1..].iter().cloned());
+
+        let swap_accounts = SwapAccounts::find(&remaining_accounts, 2).unwrap();
+        assert_eq!(*swap_accounts.swap_program.key, swap_program);
+        assert_eq!(
+            swap_accounts
+                .accounts
+                .iter()
+                .map(|acct| *acct.key)
+                .collect::<Vec<_>>(),
+            keys[1..].to_vec()
+        );
+
+        let swap_accounts = SwapAccounts::find(&remaining_accounts, 0).unwrap();
+        assert!(swap_accounts.accounts.is_empty());
+
+        // swap_accounts_len past the end of remaining accounts
+        assert!(SwapAccounts::find(&remaining_accounts, 3).is_err());
+
+        // the swap program must be passed and executable
+        assert!(SwapAccounts::find(&account_infos, 0).is_err());
+        let mut remaining_accounts = vec![not_executable.clone()];
+        remaining_accounts.extend(account_infos.iter().cloned());
+        assert!(SwapAccounts::find(&remaining_accounts, 2).is_err());
+    }
+}
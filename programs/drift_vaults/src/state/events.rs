@@ -74,6 +74,158 @@ pub struct VaultDepositorV1Record {
     pub deposit_oracle_price: i64,
 }
 
+/// Lot-level view of a depositor withdraw for tax tooling. Cost basis is the average cost of the depositor's
+/// shares (`net_deposits / vault_shares`), so a withdraw of n shares closes a lot of `n * avg cost`.
+#[event]
+#[derive(Default)]
+pub struct VaultDepositorLotRecord {
+    pub ts: i64,
+    pub vault: Pubkey,
+    pub depositor_authority: Pubkey,
+    pub spot_market_index: u16,
+    pub shares: u128,
+    /// cost basis of the withdrawn shares
+    pub cost_basis: u64,
+    /// proceeds paid to the depositor
+    pub proceeds: u64,
+    /// precision: PRICE_PRECISION
+    pub entry_share_price: u64,
+    /// precision: PRICE_PRECISION
+    pub exit_share_price: u64,
+    /// seconds since the vault depositor was created
+    pub holding_period: i64,
+    /// profit share paid on the withdrawn shares, pro-rata of the depositor's lifetime profit share paid
+    pub profit_share_fee_paid: u64,
+}
+
+/// Attributes a depositor deposit or withdraw to the front-end interface that routed it.
+#[event]
+#[derive(Default)]
+pub struct InterfaceRecord {
+    pub ts: i64,
+    pub vault: Pubkey,
+    pub depositor_authority: Pubkey,
+    pub interface: Pubkey,
+    pub action: VaultDepositorAction,
+    pub amount: u64,
+    pub spot_market_index: u16,
+    /// fee accrued to the interface, paid by the manager
+    pub interface_fee: u64,
+}
+
+#[event]
+#[derive(Default)]
+pub struct VaultDepositorMemoRecord {
+    pub ts: i64,
+    pub vault: Pubkey,
+    pub vault_depositor: Pubkey,
+    pub depositor_authority: Pubkey,
+    pub memo_before: [u8; 32],
+    pub memo_after: [u8; 32],
+}
+
+/// The vault drift user's margin health after a withdraw.
+#[event]
+#[derive(Default)]
+pub struct VaultHealthRecord {
+    pub ts: i64,
+    pub vault: Pubkey,
+    pub withdraw_amount: u64,
+    /// precision: QUOTE_PRECISION
+    pub total_collateral: i128,
+    /// precision: QUOTE_PRECISION
+    pub maintenance_margin_requirement: u128,
+    /// total collateral above the maintenance margin requirement, precision: QUOTE_PRECISION
+    pub free_collateral: u128,
+    /// total collateral / total liabilities, u64::MAX without liabilities, precision: PERCENTAGE_PRECISION
+    pub margin_ratio: u64,
+    pub depositor_count: u32,
+    pub active_withdraw_request_count: u32,
+    /// last manager action, heartbeat or delegate fill, 0 if the vault's
+    /// [`VaultManagerActivity`](crate::state::VaultManagerActivity) wasn't passed
+    pub last_manager_activity_ts: i64,
+}
+
+#[event]
+#[derive(Default)]
+pub struct VaultParamChangeRecord {
+    pub ts: i64,
+    pub vault: Pubkey,
+    pub signer: Pubkey,
+    pub param: crate::state::VaultParam,
+    /// see [`ParamValue`](crate::state::ParamValue)
+    pub old_value: [u8; 32],
+    pub new_value: [u8; 32],
+}
+
+#[event]
+#[derive(Default)]
+pub struct FeeHolidayRecord {
+    pub ts: i64,
+    pub vault: Pubkey,
+    pub action: crate::state::FeeHolidayAction,
+    pub start_ts: i64,
+    pub end_ts: i64,
+    pub suspend_management_fee: bool,
+    pub suspend_profit_share: bool,
+}
+
+#[event]
+#[derive(Default)]
+pub struct FeeSplitRecord {
+    pub ts: i64,
+    pub vault: Pubkey,
+    pub recipient: Pubkey,
+    pub amount: u64,
+    pub shares: u128,
+    pub owed: u64,
+    pub total_paid: u64,
+}
+
+#[event]
+#[derive(Default)]
+pub struct VaultReportRecord {
+    pub ts: i64,
+    pub vault: Pubkey,
+    pub epoch: u32,
+    pub report_hash: [u8; 32],
+}
+
+/// Published root of a [`VaultShareStatement`](crate::state::VaultShareStatement), depositors prove their
+/// (authority, shares) leaf at `epoch` against `root`.
+#[event]
+#[derive(Default)]
+pub struct VaultShareStatementRecord {
+    pub ts: i64,
+    pub vault: Pubkey,
+    pub epoch: u32,
+    pub root: [u8; 32],
+    pub num_depositors: u32,
+    pub total_shares: u128,
+    /// the vault's shares_base the shares are in
+    pub shares_base: u32,
+}
+
+/// One record for a crank over many depositors instead of a [`VaultDepositorRecord`] each, see
+/// [`VaultDepositorDelta`](crate::state::VaultDepositorDelta) for the per depositor changes it commits to.
+#[event]
+#[derive(Default)]
+pub struct VaultDepositorBatchRecord {
+    pub ts: i64,
+    pub vault: Pubkey,
+    pub action: VaultDepositorAction,
+    pub depositor_count: u32,
+    pub vault_equity_before: u64,
+    /// summed over the batch's depositors
+    pub vault_shares_before: u128,
+    pub vault_shares_after: u128,
+    pub total_vault_shares_after: u128,
+    pub manager_profit_share: u64,
+    pub protocol_profit_share: u64,
+    /// merkle root of the depositors' deltas in remaining_accounts order
+    pub deltas_root: [u8; 32],
+}
+
 #[derive(Clone, Copy, BorshSerialize, BorshDeserialize, PartialEq, Eq, Default)]
 pub enum VaultDepositorAction {
     #[default]
@@ -86,6 +238,19 @@ pub enum VaultDepositorAction {
     RedeemTokens,
 }
 
+/// Emitted whenever `Vault::apply_rebase` raises the vault's `shares_base`. All share amounts recorded before `ts`
+/// are in the old base and divide by `rebase_divisor` to compare with later ones.
+#[event]
+#[derive(Default)]
+pub struct VaultRebaseRecord {
+    pub ts: i64,
+    pub vault: Pubkey,
+    pub vault_equity: u64,
+    pub shares_base_before: u32,
+    pub shares_base_after: u32,
+    pub rebase_divisor: u128,
+}
+
 #[event]
 #[derive(Default)]
 pub struct ShareTransferRecord {
@@ -101,6 +266,214 @@ pub struct ShareTransferRecord {
     pub to_depositor_shares_after: u128,
 }
 
+#[event]
+pub struct VaultPerformanceRecord {
+    pub ts: i64,
+    pub vault: Pubkey,
+    pub period_start_ts: i64,
+    /// equity per share at the end of the period, precision: PERCENTAGE_PRECISION
+    pub equity_per_share: u128,
+    /// change in equity per share over the period, precision: PERCENTAGE_PRECISION
+    pub period_return: i64,
+    /// lend interest on the vault's spot market over the period, precision: PERCENTAGE_PRECISION
+    pub benchmark_return: i64,
+    /// management fee and profit share charged by the manager over the period
+    pub fees_charged: u64,
+    /// depositors' share of vault equity at the end of the period
+    pub depositor_equity: u64,
+    /// change in depositor equity less net deposits over the period, precision: PERCENTAGE_PRECISION
+    pub net_depositor_return: i64,
+    /// lend interest earned less borrow interest paid on the vault's spot market balance over the period
+    pub interest_pnl: i64,
+    /// vault pnl over the period other than `interest_pnl`
+    pub trading_pnl: i64,
+}
+
+#[event]
+pub struct ShareClassConversionRecord {
+    pub ts: i64,
+    pub vault: Pubkey,
+    pub depositor_authority: Pubkey,
+    /// true if the shares were converted into the locked class, false if converted back out
+    pub locked: bool,
+    pub shares: u128,
+    pub holder_locked_shares_after: u128,
+    pub class_locked_shares_after: u128,
+    pub unlock_ts: i64,
+    pub profit_share_discount: u32,
+}
+
+#[event]
+pub struct WithdrawPriorityBoostRecord {
+    pub ts: i64,
+    pub vault: Pubkey,
+    pub depositor_authority: Pubkey,
+    /// precision: PERCENTAGE_PRECISION
+    pub priority_fee: u32,
+    pub shares_forfeited: u128,
+    pub value_forfeited: u64,
+    pub request_ts_before: i64,
+    pub request_ts_after: i64,
+}
+
+/// The vault drift user's risk while a liquidation delegate unwinds it for a withdrawing depositor. Fills on the
+/// drift user with order ids below `next_order_id` happened before the record, so consecutive records bracket the
+/// delegate's fills and show whether each one reduced risk.
+#[event]
+#[derive(Default)]
+pub struct LiquidationActionRecord {
+    pub ts: i64,
+    pub vault: Pubkey,
+    pub liquidation_delegate: Pubkey,
+    /// the depositor whose unpaid withdraw request the liquidation is for
+    pub depositor_authority: Pubkey,
+    pub action: LiquidationAction,
+    /// the drift user's next order id
+    pub next_order_id: u32,
+    /// precision: QUOTE_PRECISION
+    pub total_collateral: i128,
+    /// precision: QUOTE_PRECISION
+    pub maintenance_margin_requirement: u128,
+    /// precision: QUOTE_PRECISION
+    pub perp_liability_value: u128,
+    /// precision: QUOTE_PRECISION
+    pub spot_liability_value: u128,
+}
+
+#[derive(Clone, Copy, BorshSerialize, BorshDeserialize, PartialEq, Eq, Default, Debug)]
+pub enum LiquidationAction {
+    /// the depositor handed the vault to the liquidation delegate
+    #[default]
+    Start,
+    /// the liquidation delegate recorded the drift user around its fills
+    Unwind,
+}
+
+/// A fund level profit share crystallization, see [`VaultCrystallization`](crate::state::VaultCrystallization)
+#[event]
+#[derive(Default)]
+pub struct VaultCrystallizationRecord {
+    pub ts: i64,
+    pub vault: Pubkey,
+    pub vault_equity: u64,
+    /// equity per share before the profit share is minted, precision: PRICE_PRECISION
+    pub share_price: u128,
+    /// precision: PRICE_PRECISION
+    pub high_water_mark_before: u128,
+    /// precision: PRICE_PRECISION
+    pub high_water_mark_after: u128,
+    /// depositors' gain above the high water mark, 0 within the hurdle
+    pub depositor_profit: u64,
+    pub profit_share_paid: u64,
+    /// shares minted to the manager for profit_share_paid
+    pub profit_share_shares: u128,
+}
+
+#[event]
+#[derive(Default)]
+pub struct BackstopRecord {
+    pub ts: i64,
+    pub vault: Pubkey,
+    pub action: crate::state::BackstopAction,
+    pub amount: u64,
+    /// manager shares burned by a contribution
+    pub shares: u128,
+    /// precision: PRICE_PRECISION
+    pub share_price: u128,
+    /// precision: PRICE_PRECISION
+    pub high_water_mark: u128,
+    pub balance_after: u64,
+    pub owed_after: u64,
+}
+
+#[event]
+#[derive(Default)]
+pub struct ProfitShareRefundRecord {
+    pub ts: i64,
+    pub vault: Pubkey,
+    /// precision: PERCENTAGE_PRECISION
+    pub profit_share_before: u32,
+    /// precision: PERCENTAGE_PRECISION
+    pub profit_share_after: u32,
+    /// ts of the oldest checkpoint, profit share realized since it is refunded
+    pub lookback_start_ts: i64,
+    pub profit_share_in_lookback: u64,
+    pub amount: u64,
+    /// manager shares burned for the refund
+    pub shares: u128,
+}
+
+/// Emitted with every matured withdraw, depositor, manager or protocol. The payout is the lesser of the value locked
+/// in at request time and the current value of the requested shares, so any difference is either a shortfall the
+/// withdrawer absorbs or gains since the request that stay in the vault.
+#[event]
+#[derive(Default)]
+pub struct WithdrawPayoutRecord {
+    pub ts: i64,
+    pub vault: Pubkey,
+    /// The depositor authority, or the manager / protocol for their own withdraws
+    pub authority: Pubkey,
+    /// `WithdrawRequest::value`
+    pub requested_value: u64,
+    /// Value of the requested shares at withdraw
+    pub current_value: u64,
+    pub payout: u64,
+    /// requested_value - payout, the vault lost value since the request
+    pub shortfall: u64,
+    /// current_value - payout, gains since the request left in the vault
+    pub capped_gains: u64,
+    pub cause: WithdrawPayoutDivergence,
+}
+
+#[derive(Clone, Copy, BorshSerialize, BorshDeserialize, PartialEq, Eq, Default, Debug)]
+pub enum WithdrawPayoutDivergence {
+    /// Paid out the requested value
+    #[default]
+    None,
+    Losses,
+    GainsCapped,
+}
+
+/// Emitted on tokenize and redeem with the share token's exchange rate. The mint's supply never rebases, vault rebases
+/// change the shares backing each token instead.
+#[event]
+#[derive(Default)]
+pub struct TokenizedSharesExchangeRateRecord {
+    pub ts: i64,
+    pub vault: Pubkey,
+    pub mint: Pubkey,
+    pub vault_shares_base: u32,
+    /// shares backing the whole mint supply
+    pub vault_shares: u128,
+    pub mint_supply: u64,
+    /// value of one whole token in whole spot market tokens, precision: PRICE_PRECISION
+    pub token_price: u64,
+}
+
+/// Emitted when the manager schedules a [`VaultWithdrawBlackout`](crate::state::VaultWithdrawBlackout) window
+#[event]
+#[derive(Default)]
+pub struct WithdrawBlackoutRecord {
+    pub ts: i64,
+    pub vault: Pubkey,
+    pub start_ts: i64,
+    pub end_ts: i64,
+    /// Blackout scheduled in the window's period, including it
+    pub period_blackout: i64,
+}
+
+#[event]
+#[derive(Default)]
+pub struct VaultDepositorAuthorityTransferRecord {
+    pub ts: i64,
+    pub vault: Pubkey,
+    pub old_authority: Pubkey,
+    pub new_authority: Pubkey,
+    /// The new authority's vault depositor the position moved to
+    pub vault_depositor: Pubkey,
+    pub vault_shares: u128,
+}
+
 #[event]
 pub struct FuelSeasonRecord {
     pub ts: i64,
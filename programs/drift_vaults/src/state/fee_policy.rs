@@ -0,0 +1,198 @@
+use anchor_lang::prelude::*;
+use borsh::{BorshDeserialize, BorshSerialize};
+use drift::math::casting::Cast;
+use drift::math::constants::PERCENTAGE_PRECISION_I128;
+use drift::math::safe_math::SafeMath;
+
+use crate::error::ErrorCode;
+use crate::validate;
+
+/// How the profit share is charged on gains above a high water mark, the one place fee models differ. The
+/// management fee is charged the same under every policy. Stored in the vault's
+/// [`VaultConfig`](crate::state::VaultConfig), see [`VaultConfig::fee_policy`](crate::state::VaultConfig::fee_policy).
+#[derive(Clone, Copy, BorshSerialize, BorshDeserialize, PartialEq, Debug, Eq)]
+#[repr(u8)]
+pub enum FeePolicy {
+    /// Profit share on the whole gain above the high water mark
+    HighWaterMark = 0,
+    /// Nothing is charged until the gain clears `hurdle_rate` of the high water mark, then the whole gain is
+    Hurdle = 1,
+    /// Only the gain beyond `hurdle_rate` of the high water mark is charged, the hurdle being the benchmark return
+    Benchmark = 2,
+    /// Management fee only, no profit share
+    Flat = 3,
+}
+
+impl TryFrom<u8> for FeePolicy {
+    type Error = ErrorCode;
+
+    fn try_from(value: u8) -> std::result::Result<Self, ErrorCode> {
+        match value {
+            0 => Ok(FeePolicy::HighWaterMark),
+            1 => Ok(FeePolicy::Hurdle),
+            2 => Ok(FeePolicy::Benchmark),
+            3 => Ok(FeePolicy::Flat),
+            _ => Err(ErrorCode::InvalidVaultUpdate),
+        }
+    }
+}
+
+impl FeePolicy {
+    /// Higher charges more of the same gain, depositors can always be moved to a lower policy
+    pub fn fee_rank(&self) -> u8 {
+        match self {
+            FeePolicy::Flat => 0,
+            FeePolicy::Benchmark => 1,
+            FeePolicy::Hurdle => 2,
+            FeePolicy::HighWaterMark => 3,
+        }
+    }
+
+    /// A vault with a hurdle rate and no policy set is [`FeePolicy::Hurdle`], so [`FeePolicy::HighWaterMark`] needs
+    /// it to be 0 and the hurdle policies need one.
+    pub fn validate_hurdle_rate(&self, hurdle_rate: u32) -> Result<()> {
+        let valid = match self {
+            FeePolicy::HighWaterMark => hurdle_rate == 0,
+            FeePolicy::Hurdle | FeePolicy::Benchmark => hurdle_rate > 0,
+            FeePolicy::Flat => true,
+        };
+        validate!(
+            valid,
+            ErrorCode::InvalidVaultUpdate,
+            "fee policy {:?} not allowed with hurdle rate {}",
+            self,
+            hurdle_rate
+        )?;
+
+        Ok(())
+    }
+
+    /// The part of `profit`, the gain above `high_water_mark`, the profit share is charged on
+    pub fn chargeable_profit(
+        &self,
+        profit: i64,
+        high_water_mark: i64,
+        hurdle_rate: u32,
+    ) -> Result<i64> {
+        if profit <= 0 {
+            return Ok(0);
+        }
+
+        let hurdle: i64 = high_water_mark
+            .cast::<i128>()?
+            .safe_mul(hurdle_rate.cast()?)?
+            .safe_div(PERCENTAGE_PRECISION_I128)?
+            .cast()?;
+
+        Ok(match self {
+            FeePolicy::HighWaterMark => profit,
+            FeePolicy::Hurdle => {
+                if profit > hurdle {
+                    profit
+                } else {
+                    0
+                }
+            }
+            FeePolicy::Benchmark => profit.saturating_sub(hurdle).max(0),
+            FeePolicy::Flat => 0,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::state::{FeePolicy, Vault, VaultConfig};
+
+    #[test]
+    fn test_chargeable_profit() {
+        let hwm = 1_000;
+        let hurdle_rate = 100_000; // 10%
+
+        assert_eq!(
+            FeePolicy::HighWaterMark
+                .chargeable_profit(50, hwm, 0)
+                .unwrap(),
+            50
+        );
+        assert_eq!(
+            FeePolicy::HighWaterMark
+                .chargeable_profit(-50, hwm, 0)
+                .unwrap(),
+            0
+        );
+
+        // within the hurdle nothing, beyond it the whole gain
+        assert_eq!(
+            FeePolicy::Hurdle
+                .chargeable_profit(100, hwm, hurdle_rate)
+                .unwrap(),
+            0
+        );
+        assert_eq!(
+            FeePolicy::Hurdle
+                .chargeable_profit(150, hwm, hurdle_rate)
+                .unwrap(),
+            150
+        );
+
+        // only the outperformance
+        assert_eq!(
+            FeePolicy::Benchmark
+                .chargeable_profit(100, hwm, hurdle_rate)
+                .unwrap(),
+            0
+        );
+        assert_eq!(
+            FeePolicy::Benchmark
+                .chargeable_profit(150, hwm, hurdle_rate)
+                .unwrap(),
+            50
+        );
+
+        assert_eq!(
+            FeePolicy::Flat
+                .chargeable_profit(150, hwm, hurdle_rate)
+                .unwrap(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_update_fee_policy() {
+        let mut vault = Vault::default();
+        let mut vault_config = VaultConfig::default();
+        assert_eq!(
+            vault_config.fee_policy(&vault).unwrap(),
+            FeePolicy::HighWaterMark
+        );
+
+        // hurdle policies need a hurdle rate
+        assert!(vault_config
+            .update_fee_policy(&vault, FeePolicy::Benchmark)
+            .is_err());
+        vault.hurdle_rate = 100_000;
+        // a legacy vault with a hurdle rate
+        assert_eq!(vault_config.fee_policy(&vault).unwrap(), FeePolicy::Hurdle);
+        assert!(vault_config
+            .update_fee_policy(&vault, FeePolicy::HighWaterMark)
+            .is_err());
+
+        vault_config
+            .update_fee_policy(&vault, FeePolicy::Benchmark)
+            .unwrap();
+        assert_eq!(
+            vault_config.fee_policy(&vault).unwrap(),
+            FeePolicy::Benchmark
+        );
+
+        // with depositors only towards charging less
+        vault.user_shares = 1;
+        assert!(vault_config
+            .update_fee_policy(&vault, FeePolicy::Hurdle)
+            .is_err());
+        vault_config
+            .update_fee_policy(&vault, FeePolicy::Flat)
+            .unwrap();
+        assert_eq!(vault_config.fee_policy(&vault).unwrap(), FeePolicy::Flat);
+    }
+}
@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::set_return_data;
+use drift::math::safe_math::SafeMath;
+
+use crate::error::ErrorCode;
+use crate::state::{ComputeUnitsHint, Vault, VaultDepositor, VaultDepositorBase};
+
+/// Projected result of a deposit or withdraw, written to return data by
+/// [`preview_deposit`](crate::instructions::preview_deposit) and
+/// [`preview_withdraw`](crate::instructions::preview_withdraw).
+#[derive(Clone, Copy, Default, AnchorSerialize, AnchorDeserialize, PartialEq, Debug, Eq)]
+pub struct FlowPreview {
+    /// tokens deposited or paid out, deposits are capped by the vault's `max_tokens`
+    pub amount: u64,
+    pub vault_shares_before: u128,
+    pub vault_shares_after: u128,
+    /// profit share charged to the depositor
+    pub profit_share: u64,
+    /// management fee the vault charged since its last fee update
+    pub management_fee: i64,
+    /// the depositor's fuel after the flow
+    pub fuel_amount: u128,
+    pub compute_units_hint: ComputeUnitsHint,
+}
+
+impl FlowPreview {
+    pub fn new(
+        amount: u64,
+        vault_before: &Vault,
+        vault_depositor_before: &VaultDepositor,
+        vault: &Vault,
+        vault_depositor: &VaultDepositor,
+        compute_units_hint: ComputeUnitsHint,
+    ) -> Result<Self> {
+        Ok(FlowPreview {
+            amount,
+            vault_shares_before: vault_depositor_before.get_vault_shares(),
+            vault_shares_after: vault_depositor.get_vault_shares(),
+            profit_share: vault_depositor
+                .profit_share_fee_paid
+                .safe_sub(vault_depositor_before.profit_share_fee_paid)?,
+            management_fee: vault
+                .manager_total_fee
+                .safe_sub(vault_before.manager_total_fee)?,
+            fuel_amount: vault_depositor.fuel_amount,
+            compute_units_hint,
+        })
+    }
+
+    /// Sets the preview as return data and fails with `SimulationOnly`, so everything the instruction did is reverted
+    /// and a preview that lands leaves no state or records behind. Clients read the return data of the simulation.
+    pub fn set_return_data_and_revert(&self) -> Result<()> {
+        msg!("flow preview: {:?}", self);
+        set_return_data(&self.try_to_vec()?);
+        Err(ErrorCode::SimulationOnly.into())
+    }
+}
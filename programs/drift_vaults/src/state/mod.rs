@@ -1,17 +1,125 @@
 pub use account_maps::*;
+pub use compute_units_hint::*;
+pub use delegate_self_trade::*;
+pub use deposit_swap::*;
+pub use fee_policy::*;
+pub use flow_preview::*;
+pub use omnibus_ledger::*;
+pub use protocol_loss_ledger::*;
+pub use protocol_treasury::*;
+pub use protocol_tvl_cap::*;
+pub use settle_crank::*;
 pub use tokenized_vault_depositor::*;
 pub use traits::*;
 pub use vault::*;
+pub use vault_auditor::*;
+pub use vault_backstop::*;
+pub use vault_config::*;
+pub use vault_crystallization::*;
+pub use vault_delegate_proposal::*;
 pub use vault_depositor::*;
+pub use vault_depositor_authority_transfer::*;
+pub use vault_depositor_batch::*;
+pub use vault_depositor_cap::*;
+pub use vault_depositor_idempotency::*;
+pub use vault_depositor_memo::*;
+pub use vault_depositor_record_log::*;
+pub use vault_depositor_throttle::*;
+pub use vault_depositor_withdraw_requests::*;
+pub use vault_dust_withdraw::*;
+pub use vault_feature::*;
+pub use vault_fee_holiday::*;
+pub use vault_fee_split::*;
+pub use vault_guard::*;
+pub use vault_hook::*;
+pub use vault_interface::*;
+pub use vault_lockup::*;
+pub use vault_manager_activity::*;
+pub use vault_manager_fee_claim::*;
+pub use vault_param_change_log::*;
+pub use vault_performance_stats::*;
+pub use vault_poll::*;
+pub use vault_profile::*;
+pub use vault_profit_share_cadence::*;
+pub use vault_profit_share_history::*;
 pub use vault_protocol::*;
+pub use vault_redeem_schedule::*;
+pub use vault_registry::*;
+pub use vault_report::*;
+pub use vault_risk_score::*;
+pub use vault_share_class::*;
+pub use vault_share_snapshot::*;
+pub use vault_share_statement::*;
+pub use vault_template::*;
+pub use vault_withdraw_blackout::*;
+pub use vault_withdraw_escrow::*;
+pub use vault_withdraw_priority::*;
+pub use vault_withdraw_route::*;
+pub use vault_withdraw_settlement::*;
+pub use vault_withdraw_stream::*;
+pub use withdraw_receipt::*;
 pub use withdraw_unit::*;
 
 pub mod account_maps;
+pub mod compute_units_hint;
+pub mod delegate_self_trade;
+pub mod deposit_swap;
 pub mod events;
+pub mod fee_policy;
+pub mod flow_preview;
+pub mod omnibus_ledger;
+pub mod protocol_loss_ledger;
+pub mod protocol_treasury;
+pub mod protocol_tvl_cap;
+pub mod settle_crank;
 pub mod tokenized_vault_depositor;
 pub mod traits;
 pub mod vault;
+pub mod vault_auditor;
+pub mod vault_backstop;
+pub mod vault_config;
+pub mod vault_crystallization;
+pub mod vault_delegate_proposal;
 pub mod vault_depositor;
+pub mod vault_depositor_authority_transfer;
+pub mod vault_depositor_batch;
+pub mod vault_depositor_cap;
+pub mod vault_depositor_idempotency;
+pub mod vault_depositor_memo;
+pub mod vault_depositor_record_log;
+pub mod vault_depositor_throttle;
+pub mod vault_depositor_withdraw_requests;
+pub mod vault_dust_withdraw;
+pub mod vault_feature;
+pub mod vault_fee_holiday;
+pub mod vault_fee_split;
+pub mod vault_guard;
+pub mod vault_hook;
+pub mod vault_interface;
+pub mod vault_lockup;
+pub mod vault_manager_activity;
+pub mod vault_manager_fee_claim;
+pub mod vault_param_change_log;
+pub mod vault_performance_stats;
+pub mod vault_poll;
+pub mod vault_profile;
+pub mod vault_profit_share_cadence;
+pub mod vault_profit_share_history;
 pub mod vault_protocol;
+pub mod vault_redeem_schedule;
+pub mod vault_registry;
+pub mod vault_report;
+pub mod vault_risk_score;
+pub mod vault_share_class;
+pub mod vault_share_snapshot;
+pub mod vault_share_statement;
+pub mod vault_template;
+pub mod vault_withdraw_blackout;
+pub mod vault_withdraw_escrow;
+pub mod vault_withdraw_priority;
+pub mod vault_withdraw_route;
+pub mod vault_withdraw_settlement;
+pub mod vault_withdraw_stream;
+pub mod withdraw_receipt;
 pub mod withdraw_request;
 pub mod withdraw_unit;
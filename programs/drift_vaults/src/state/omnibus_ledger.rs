@@ -0,0 +1,243 @@
+use anchor_lang::prelude::*;
+use bytemuck::Zeroable;
+use drift::math::safe_math::SafeMath;
+use drift_macros::assert_no_slop;
+use static_assertions::const_assert_eq;
+
+use crate::error::ErrorCode;
+use crate::state::{Size, VaultDepositor, VaultDepositorBase};
+use crate::validate;
+
+pub const OMNIBUS_LEDGER_PAGE_ENTRIES: usize = 16;
+
+/// Per-beneficiary allocation of an omnibus [`VaultDepositor`]'s shares, see
+/// [`VaultDepositorFlag::Omnibus`](crate::state::VaultDepositorFlag). The depositor authority, usually a DAO
+/// program's PDA, allocates shares in [`OmnibusLedgerPage`]s and the vault keeps the total allocated covered by the
+/// depositor's shares: deposits add unallocated shares, withdraw requests can only take unallocated ones.
+#[assert_no_slop]
+#[account(zero_copy(unsafe))]
+#[derive(Default, Eq, PartialEq, Debug)]
+#[repr(C)]
+pub struct OmnibusLedger {
+    /// The omnibus vault depositor
+    pub vault_depositor: Pubkey,
+    /// Sum of every page's allocations, at shares_base
+    pub total_allocated_shares: u128,
+    /// The vault depositor's shares_base when the total was last updated
+    pub shares_base: u32,
+    pub page_count: u16,
+    pub bump: u8,
+    pub padding: [u8; 9],
+}
+
+impl Size for OmnibusLedger {
+    const SIZE: usize = 64 + 8;
+}
+const_assert_eq!(
+    OmnibusLedger::SIZE,
+    std::mem::size_of::<OmnibusLedger>() + 8
+);
+
+#[assert_no_slop]
+#[derive(
+    Default, AnchorSerialize, AnchorDeserialize, Copy, Clone, Eq, PartialEq, Debug, Zeroable,
+)]
+pub struct OmnibusLedgerEntry {
+    pub beneficiary: Pubkey,
+    pub shares: u128,
+}
+
+#[assert_no_slop]
+#[account(zero_copy(unsafe))]
+#[derive(Default, Eq, PartialEq, Debug)]
+#[repr(C)]
+pub struct OmnibusLedgerPage {
+    /// The omnibus vault depositor
+    pub vault_depositor: Pubkey,
+    pub entries: [OmnibusLedgerEntry; OMNIBUS_LEDGER_PAGE_ENTRIES],
+    /// The vault depositor's shares_base when the entries were last updated
+    pub shares_base: u32,
+    pub page_index: u16,
+    pub bump: u8,
+    pub padding: [u8; 9],
+}
+
+impl Size for OmnibusLedgerPage {
+    const SIZE: usize = 816 + 8;
+}
+const_assert_eq!(
+    OmnibusLedgerPage::SIZE,
+    std::mem::size_of::<OmnibusLedgerPage>() + 8
+);
+
+/// Brings `shares` at `shares_base` to the vault depositor's base, rebases only ever increase it
+fn rebase_shares(shares: u128, shares_base: u32, vault_depositor: &VaultDepositor) -> Result<u128> {
+    let expo_diff = vault_depositor
+        .get_vault_shares_base()
+        .safe_sub(shares_base)?;
+    Ok(shares.safe_div(10_u128.pow(expo_diff))?)
+}
+
+impl OmnibusLedger {
+    pub fn get_address(vault_depositor: &Pubkey) -> Pubkey {
+        Pubkey::find_program_address(&[b"omnibus_ledger", vault_depositor.as_ref()], &crate::id()).0
+    }
+
+    pub fn allocated_shares(&self, vault_depositor: &VaultDepositor) -> Result<u128> {
+        rebase_shares(
+            self.total_allocated_shares,
+            self.shares_base,
+            vault_depositor,
+        )
+    }
+
+    /// Depositor shares not allocated to a beneficiary or already requested for withdraw
+    pub fn unallocated_shares(&self, vault_depositor: &VaultDepositor) -> Result<u128> {
+        Ok(vault_depositor
+            .get_vault_shares()
+            .safe_sub(vault_depositor.last_withdraw_request.shares)?
+            .saturating_sub(self.allocated_shares(vault_depositor)?))
+    }
+
+    /// A withdraw request can't take shares allocated to beneficiaries
+    pub fn validate_request(&self, vault_depositor: &VaultDepositor) -> Result<()> {
+        let allocated_shares = self.allocated_shares(vault_depositor)?;
+        let unrequested_shares = vault_depositor
+            .get_vault_shares()
+            .safe_sub(vault_depositor.last_withdraw_request.shares)?;
+        validate!(
+            allocated_shares <= unrequested_shares,
+            ErrorCode::InvalidOmnibusLedger,
+            "withdraw request takes allocated shares: allocated {}, shares left {}",
+            allocated_shares,
+            unrequested_shares
+        )?;
+
+        Ok(())
+    }
+
+    /// Sets a page entry, keeping the ledger total within the depositor's shares not requested for withdraw
+    pub fn update_entry(
+        &mut self,
+        page: &mut OmnibusLedgerPage,
+        vault_depositor: &VaultDepositor,
+        entry_index: usize,
+        beneficiary: Pubkey,
+        shares: u128,
+    ) -> Result<()> {
+        validate!(
+            entry_index < OMNIBUS_LEDGER_PAGE_ENTRIES,
+            ErrorCode::InvalidOmnibusLedger,
+            "entry index {} out of bounds",
+            entry_index
+        )?;
+
+        self.total_allocated_shares = self.allocated_shares(vault_depositor)?;
+        self.shares_base = vault_depositor.get_vault_shares_base();
+        page.rebase(vault_depositor)?;
+
+        let entry = &mut page.entries[entry_index];
+        // entries round down on a rebase, the total can be a little ahead of their sum
+        let total_allocated_shares = self
+            .total_allocated_shares
+            .saturating_sub(entry.shares)
+            .safe_add(shares)?;
+        let unrequested_shares = vault_depositor
+            .get_vault_shares()
+            .safe_sub(vault_depositor.last_withdraw_request.shares)?;
+        validate!(
+            total_allocated_shares <= unrequested_shares,
+            ErrorCode::InvalidOmnibusLedger,
+            "allocating {} shares, depositor has {} not requested for withdraw",
+            total_allocated_shares,
+            unrequested_shares
+        )?;
+
+        self.total_allocated_shares = total_allocated_shares;
+        entry.beneficiary = beneficiary;
+        entry.shares = shares;
+
+        msg!(
+            "omnibus ledger entry {}:{} {} -> {} shares, total allocated {}",
+            page.page_index,
+            entry_index,
+            beneficiary,
+            shares,
+            self.total_allocated_shares
+        );
+
+        Ok(())
+    }
+}
+
+impl OmnibusLedgerPage {
+    pub fn rebase(&mut self, vault_depositor: &VaultDepositor) -> Result<()> {
+        if self.shares_base == vault_depositor.get_vault_shares_base() {
+            return Ok(());
+        }
+
+        for entry in self.entries.iter_mut() {
+            entry.shares = rebase_shares(entry.shares, self.shares_base, vault_depositor)?;
+        }
+        self.shares_base = vault_depositor.get_vault_shares_base();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anchor_lang::prelude::Pubkey;
+
+    use crate::state::{OmnibusLedger, OmnibusLedgerPage, VaultDepositor, VaultDepositorBase};
+
+    #[test]
+    fn test_omnibus_ledger_allocations() {
+        let mut vault_depositor = VaultDepositor::default();
+        vault_depositor.set_vault_shares(1_000);
+
+        let mut ledger = OmnibusLedger::default();
+        let mut page = OmnibusLedgerPage::default();
+
+        let alice = Pubkey::new_unique();
+        let bob = Pubkey::new_unique();
+        ledger
+            .update_entry(&mut page, &vault_depositor, 0, alice, 600)
+            .unwrap();
+        ledger
+            .update_entry(&mut page, &vault_depositor, 1, bob, 400)
+            .unwrap();
+        assert_eq!(ledger.total_allocated_shares, 1_000);
+        assert_eq!(ledger.unallocated_shares(&vault_depositor).unwrap(), 0);
+
+        // can't allocate more than the depositor holds
+        assert!(ledger
+            .update_entry(&mut page, &vault_depositor, 1, bob, 401)
+            .is_err());
+        assert!(ledger
+            .update_entry(&mut page, &vault_depositor, 16, bob, 0)
+            .is_err());
+
+        // a request can only take unallocated shares
+        vault_depositor.last_withdraw_request.shares = 100;
+        assert!(ledger.validate_request(&vault_depositor).is_err());
+        vault_depositor.last_withdraw_request.shares = 0;
+        ledger
+            .update_entry(&mut page, &vault_depositor, 1, bob, 300)
+            .unwrap();
+        vault_depositor.last_withdraw_request.shares = 100;
+        ledger.validate_request(&vault_depositor).unwrap();
+
+        // a rebase scales the ledger with the depositor's shares
+        vault_depositor.set_vault_shares(100);
+        vault_depositor.last_withdraw_request.shares = 10;
+        vault_depositor.vault_shares_base = 1;
+        assert_eq!(ledger.allocated_shares(&vault_depositor).unwrap(), 90);
+        ledger
+            .update_entry(&mut page, &vault_depositor, 0, alice, 50)
+            .unwrap();
+        assert_eq!(page.entries[1].shares, 30);
+        assert_eq!(ledger.total_allocated_shares, 80);
+        assert_eq!(ledger.unallocated_shares(&vault_depositor).unwrap(), 10);
+    }
+}
@@ -0,0 +1,150 @@
+use anchor_lang::prelude::*;
+use drift::math::casting::Cast;
+use drift::math::safe_math::SafeMath;
+use drift_macros::assert_no_slop;
+use static_assertions::const_assert_eq;
+
+use crate::error::ErrorCode;
+use crate::state::{Size, VaultDepositorBase};
+use crate::validate;
+
+/// Per-authority loss carryforward across the vaults of one [`VaultProtocol`](crate::state::VaultProtocol).
+/// Losses moved out of one vault offset profit share in another vault of the same protocol and spot market.
+#[assert_no_slop]
+#[account(zero_copy(unsafe))]
+#[derive(Default, Eq, PartialEq, Debug)]
+#[repr(C)]
+pub struct ProtocolLossLedger {
+    /// The `VaultProtocol::protocol` the ledger nets across
+    pub protocol: Pubkey,
+    /// The depositor authority
+    pub authority: Pubkey,
+    /// Losses recorded from the protocol's vaults not yet netted against profit, in `spot_market_index` token
+    pub loss_carryforward: u64,
+    /// Total profit that was exempted from profit share by carried losses
+    pub total_loss_netted: u64,
+    pub spot_market_index: u16,
+    pub bump: u8,
+    pub padding: [u8; 5],
+}
+
+impl Size for ProtocolLossLedger {
+    const SIZE: usize = 88 + 8;
+}
+const_assert_eq!(
+    ProtocolLossLedger::SIZE,
+    std::mem::size_of::<ProtocolLossLedger>() + 8
+);
+
+impl ProtocolLossLedger {
+    fn high_water_mark<T: VaultDepositorBase>(depositor: &T) -> Result<i64> {
+        Ok(depositor
+            .get_net_deposits()
+            .safe_add(depositor.get_cumulative_profit_share_amount())?)
+    }
+
+    /// Moves the depositor's unrealized loss below their high water mark into the ledger. The depositor's
+    /// high water mark is lowered by the same amount so a recovery in this vault is profit shared again.
+    pub fn record_loss<T: VaultDepositorBase>(
+        &mut self,
+        depositor: &mut T,
+        total_amount: u64,
+    ) -> Result<u64> {
+        let loss = Self::high_water_mark(depositor)?.safe_sub(total_amount.cast()?)?;
+        validate!(
+            loss > 0,
+            ErrorCode::InvalidLossCarryforward,
+            "depositor has no loss to carry forward"
+        )?;
+
+        depositor.set_cumulative_profit_share_amount(
+            depositor
+                .get_cumulative_profit_share_amount()
+                .safe_sub(loss)?,
+        );
+        self.loss_carryforward = self.loss_carryforward.safe_add(loss.cast()?)?;
+
+        msg!(
+            "recorded loss {}, loss_carryforward: {}",
+            loss,
+            self.loss_carryforward
+        );
+
+        loss.cast()
+    }
+
+    /// Nets carried losses against the depositor's profit above their high water mark, the netted amount
+    /// is treated as already profit shared.
+    pub fn apply_loss<T: VaultDepositorBase>(
+        &mut self,
+        depositor: &mut T,
+        total_amount: u64,
+    ) -> Result<u64> {
+        let profit = total_amount
+            .cast::<i64>()?
+            .safe_sub(Self::high_water_mark(depositor)?)?;
+        validate!(
+            profit > 0 && self.loss_carryforward > 0,
+            ErrorCode::InvalidLossCarryforward,
+            "nothing to net, profit: {}, loss_carryforward: {}",
+            profit,
+            self.loss_carryforward
+        )?;
+
+        let netted = profit.cast::<u64>()?.min(self.loss_carryforward);
+        depositor.set_cumulative_profit_share_amount(
+            depositor
+                .get_cumulative_profit_share_amount()
+                .safe_add(netted.cast()?)?,
+        );
+        self.loss_carryforward = self.loss_carryforward.safe_sub(netted)?;
+        self.total_loss_netted = self.total_loss_netted.saturating_add(netted);
+
+        msg!(
+            "netted {} profit against carried losses, loss_carryforward: {}",
+            netted,
+            self.loss_carryforward
+        );
+
+        Ok(netted)
+    }
+}
+
+#[cfg(test)]
+mod protocol_loss_ledger_tests {
+    use anchor_lang::prelude::Pubkey;
+
+    use crate::state::{ProtocolLossLedger, VaultDepositor, VaultDepositorBase};
+
+    #[test]
+    fn test_loss_carryforward_across_vaults() {
+        let mut ledger = ProtocolLossLedger::default();
+
+        // vault a: deposited 1000, now worth 600
+        let mut vd_a =
+            VaultDepositor::new(Pubkey::default(), Pubkey::default(), Pubkey::default(), 0);
+        vd_a.net_deposits = 1000;
+        assert_eq!(ledger.record_loss(&mut vd_a, 600).unwrap(), 400);
+        assert_eq!(ledger.loss_carryforward, 400);
+        assert_eq!(vd_a.get_cumulative_profit_share_amount(), -400);
+        // loss already moved out
+        assert!(ledger.record_loss(&mut vd_a, 600).is_err());
+
+        // vault b: deposited 1000, now worth 1300
+        let mut vd_b =
+            VaultDepositor::new(Pubkey::default(), Pubkey::default(), Pubkey::default(), 0);
+        vd_b.net_deposits = 1000;
+        assert_eq!(ledger.apply_loss(&mut vd_b, 1300).unwrap(), 300);
+        assert_eq!(ledger.loss_carryforward, 100);
+        assert_eq!(ledger.total_loss_netted, 300);
+        assert_eq!(vd_b.get_cumulative_profit_share_amount(), 300);
+        // no profit left above the high water mark
+        assert!(ledger.apply_loss(&mut vd_b, 1300).is_err());
+
+        // vault b keeps going up, the remaining carryforward is capped at the loss
+        assert_eq!(ledger.apply_loss(&mut vd_b, 1500).unwrap(), 100);
+        assert_eq!(ledger.loss_carryforward, 0);
+        assert_eq!(vd_b.get_cumulative_profit_share_amount(), 400);
+        assert!(ledger.apply_loss(&mut vd_b, 1600).is_err());
+    }
+}
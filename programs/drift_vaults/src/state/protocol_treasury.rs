@@ -0,0 +1,103 @@
+use anchor_lang::prelude::*;
+use drift::math::safe_math::SafeMath;
+use drift_macros::assert_no_slop;
+use static_assertions::const_assert_eq;
+
+use crate::state::{Size, Vault, VaultProtocol};
+
+/// Where `collect_protocol_fees` sends a protocol's fee and profit share shares redeemed across its vaults.
+#[assert_no_slop]
+#[account(zero_copy(unsafe))]
+#[derive(Default, Eq, PartialEq, Debug)]
+#[repr(C)]
+pub struct ProtocolTreasury {
+    /// The `VaultProtocol::protocol` the treasury collects for
+    pub protocol: Pubkey,
+    /// Owner of the token accounts fees are paid to, one per vault spot market
+    pub treasury: Pubkey,
+    /// Total tokens collected across all vaults and spot markets, precision: token mint
+    pub total_collected: u64,
+    pub bump: u8,
+    pub padding: [u8; 7],
+}
+
+impl Size for ProtocolTreasury {
+    const SIZE: usize = 80 + 8;
+}
+const_assert_eq!(
+    ProtocolTreasury::SIZE,
+    std::mem::size_of::<ProtocolTreasury>() + 8
+);
+
+#[derive(Clone, Copy, PartialEq, Debug, Eq)]
+pub enum ProtocolFeeCollectStep {
+    /// Request a withdraw of all protocol shares, starting the vault's redeem period
+    Request,
+    /// The redeem period is over, withdraw to the treasury
+    Withdraw,
+    /// The request is still in its redeem period
+    Wait,
+    /// No protocol shares to collect
+    Skip,
+}
+
+impl ProtocolTreasury {
+    /// Each vault goes through its own request and redeem period, so a keeper can collect a batch of vaults
+    /// every run and each vault advances one step.
+    pub fn next_collect_step(
+        vault: &Vault,
+        vault_protocol: &VaultProtocol,
+        now: i64,
+    ) -> Result<ProtocolFeeCollectStep> {
+        let request = &vault_protocol.last_protocol_withdraw_request;
+        Ok(if request.pending() {
+            if now.safe_sub(request.ts)? >= vault.redeem_period {
+                ProtocolFeeCollectStep::Withdraw
+            } else {
+                ProtocolFeeCollectStep::Wait
+            }
+        } else if vault_protocol.protocol_profit_and_fee_shares > 0 {
+            ProtocolFeeCollectStep::Request
+        } else {
+            ProtocolFeeCollectStep::Skip
+        })
+    }
+}
+
+#[cfg(test)]
+mod protocol_treasury_tests {
+    use super::*;
+
+    #[test]
+    fn test_next_collect_step() {
+        let now = 1_000_000;
+        let vault = Vault {
+            redeem_period: 100,
+            ..Vault::default()
+        };
+        let mut vp = VaultProtocol::default();
+
+        assert_eq!(
+            ProtocolTreasury::next_collect_step(&vault, &vp, now).unwrap(),
+            ProtocolFeeCollectStep::Skip
+        );
+
+        vp.protocol_profit_and_fee_shares = 10;
+        assert_eq!(
+            ProtocolTreasury::next_collect_step(&vault, &vp, now).unwrap(),
+            ProtocolFeeCollectStep::Request
+        );
+
+        vp.last_protocol_withdraw_request.shares = 10;
+        vp.last_protocol_withdraw_request.value = 10;
+        vp.last_protocol_withdraw_request.ts = now;
+        assert_eq!(
+            ProtocolTreasury::next_collect_step(&vault, &vp, now + 99).unwrap(),
+            ProtocolFeeCollectStep::Wait
+        );
+        assert_eq!(
+            ProtocolTreasury::next_collect_step(&vault, &vp, now + 100).unwrap(),
+            ProtocolFeeCollectStep::Withdraw
+        );
+    }
+}
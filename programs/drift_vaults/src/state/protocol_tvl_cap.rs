@@ -0,0 +1,163 @@
+use anchor_lang::prelude::*;
+use bytemuck::Zeroable;
+use drift::math::casting::Cast;
+use drift::math::safe_math::SafeMath;
+use drift::math::spot_balance::get_token_value;
+use drift::state::spot_market::SpotMarket;
+use drift_macros::assert_no_slop;
+use static_assertions::const_assert_eq;
+
+use crate::error::ErrorCode;
+use crate::state::Size;
+use crate::validate;
+
+pub const PROTOCOL_TVL_CAP_MAX_VAULTS: usize = 16;
+
+/// Caps the combined tvl of the vaults a `VaultProtocol::protocol` adds to it, checked on every deposit into one of
+/// them. Each vault's tvl is its live equity as of its last deposit, valued in quote so vaults in different spot
+/// markets can be summed.
+#[assert_no_slop]
+#[account(zero_copy(unsafe))]
+#[derive(Default, Eq, PartialEq, Debug)]
+#[repr(C)]
+pub struct ProtocolTvlCap {
+    /// The `VaultProtocol::protocol` whose vaults are capped
+    pub protocol: Pubkey,
+    /// Max combined tvl of the vaults, 0 for no cap. precision: QUOTE_PRECISION
+    pub max_tvl: u64,
+    /// Sum of the vaults' tvl. precision: QUOTE_PRECISION
+    pub total_tvl: u64,
+    pub bump: u8,
+    pub padding: [u8; 7],
+    /// Vaults counted towards the cap, unused entries are zeroed
+    pub vaults: [ProtocolTvlCapVault; PROTOCOL_TVL_CAP_MAX_VAULTS],
+}
+
+impl Size for ProtocolTvlCap {
+    const SIZE: usize = 696 + 8;
+}
+const_assert_eq!(
+    ProtocolTvlCap::SIZE,
+    std::mem::size_of::<ProtocolTvlCap>() + 8
+);
+
+#[assert_no_slop]
+#[derive(
+    Default, AnchorSerialize, AnchorDeserialize, Copy, Clone, Eq, PartialEq, Debug, Zeroable,
+)]
+pub struct ProtocolTvlCapVault {
+    pub vault: Pubkey,
+    /// Vault equity as of its last deposit. precision: QUOTE_PRECISION
+    pub tvl: u64,
+}
+
+/// Quote value of `vault_equity` tokens of `spot_market`
+pub fn get_vault_tvl(
+    vault_equity: u64,
+    spot_market: &SpotMarket,
+    oracle_price: i64,
+) -> Result<u64> {
+    Ok(get_token_value(vault_equity.cast()?, spot_market.decimals, oracle_price)?.cast()?)
+}
+
+impl ProtocolTvlCap {
+    pub fn get_address(protocol: &Pubkey) -> Pubkey {
+        Pubkey::find_program_address(&[b"protocol_tvl_cap", protocol.as_ref()], &crate::id()).0
+    }
+
+    fn find_vault(&self, vault: &Pubkey) -> Option<usize> {
+        self.vaults.iter().position(|entry| entry.vault == *vault)
+    }
+
+    fn update_total_tvl(&mut self) -> Result<()> {
+        self.total_tvl = self
+            .vaults
+            .iter()
+            .try_fold(0_u64, |total, entry| total.safe_add(entry.tvl))?;
+
+        Ok(())
+    }
+
+    pub fn add_vault(&mut self, vault: Pubkey, tvl: u64) -> Result<()> {
+        validate!(
+            self.find_vault(&vault).is_none(),
+            ErrorCode::InvalidProtocolTvlCap,
+            "vault {} already counts towards the tvl cap",
+            vault
+        )?;
+
+        let index = self
+            .find_vault(&Pubkey::default())
+            .ok_or(ErrorCode::InvalidProtocolTvlCap)?;
+        self.vaults[index] = ProtocolTvlCapVault { vault, tvl };
+
+        self.update_total_tvl()
+    }
+
+    pub fn remove_vault(&mut self, vault: &Pubkey) -> Result<()> {
+        let index = self
+            .find_vault(vault)
+            .ok_or(ErrorCode::InvalidProtocolTvlCap)?;
+        self.vaults[index] = ProtocolTvlCapVault::default();
+
+        self.update_total_tvl()
+    }
+
+    /// Records the vault's tvl after a deposit and fails if it puts the combined tvl over `max_tvl`.
+    pub fn validate_deposit(&mut self, vault: &Pubkey, tvl_after_deposit: u64) -> Result<()> {
+        let index = self
+            .find_vault(vault)
+            .ok_or(ErrorCode::InvalidProtocolTvlCap)?;
+        self.vaults[index].tvl = tvl_after_deposit;
+        self.update_total_tvl()?;
+
+        validate!(
+            self.max_tvl == 0 || self.total_tvl <= self.max_tvl,
+            ErrorCode::ProtocolTvlCapExceeded,
+            "deposit puts protocol tvl {} over cap {}",
+            self.total_tvl,
+            self.max_tvl
+        )?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_deposit() {
+        let vault_a = Pubkey::new_unique();
+        let vault_b = Pubkey::new_unique();
+        let mut cap = ProtocolTvlCap {
+            max_tvl: 1_000,
+            ..ProtocolTvlCap::default()
+        };
+
+        cap.add_vault(vault_a, 400).unwrap();
+        assert!(cap.add_vault(vault_a, 400).is_err());
+        cap.add_vault(vault_b, 300).unwrap();
+        assert_eq!(cap.total_tvl, 700);
+
+        // vault equity is replaced, not added to
+        cap.validate_deposit(&vault_a, 700).unwrap();
+        assert_eq!(cap.total_tvl, 1_000);
+        assert!(cap.validate_deposit(&vault_b, 301).is_err());
+        assert!(cap.validate_deposit(&Pubkey::new_unique(), 0).is_err());
+
+        cap.remove_vault(&vault_b).unwrap();
+        assert_eq!(cap.total_tvl, 700);
+        cap.validate_deposit(&vault_a, 1_000).unwrap();
+
+        // 0 is uncapped
+        cap.max_tvl = 0;
+        cap.validate_deposit(&vault_a, u32::MAX as u64).unwrap();
+
+        for _ in 1..PROTOCOL_TVL_CAP_MAX_VAULTS {
+            cap.add_vault(Pubkey::new_unique(), 0).unwrap();
+        }
+        assert!(cap.add_vault(Pubkey::new_unique(), 0).is_err());
+    }
+}
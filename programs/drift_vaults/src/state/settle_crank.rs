@@ -0,0 +1,107 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::sysvar::instructions::{
+    load_current_index_checked, load_instruction_at_checked,
+};
+use anchor_lang::Discriminator;
+
+use crate::error::ErrorCode;
+use crate::state::{Vault, VaultFlag};
+
+/// Whether `ix` is a drift `settle_pnl` or `settle_multiple_pnls` for `drift_user`
+pub fn is_settle_crank(ix: &Instruction, drift_user: &Pubkey) -> bool {
+    let is_settle_pnl = ix
+        .data
+        .starts_with(&drift::instruction::SettlePnl::DISCRIMINATOR)
+        || ix
+            .data
+            .starts_with(&drift::instruction::SettleMultiplePnls::DISCRIMINATOR);
+
+    // settle pnl accounts are [state, user, authority, spot_market_vault]
+    ix.program_id == drift::id()
+        && is_settle_pnl
+        && ix
+            .accounts
+            .get(1)
+            .map_or(false, |meta| &meta.pubkey == drift_user)
+}
+
+impl Vault {
+    /// Under [`VaultFlag::RequireSettleCrank`], validates that an instruction earlier in the transaction settled the
+    /// pnl of the vault's `drift_user`, so the vault equity used for share prices is fresh.
+    pub fn validate_settle_crank(
+        &self,
+        drift_user: &Pubkey,
+        instructions_sysvar: Option<&AccountInfo>,
+    ) -> Result<()> {
+        if !self.has_flag(VaultFlag::RequireSettleCrank) {
+            return Ok(());
+        }
+
+        let instructions_sysvar = match instructions_sysvar {
+            Some(instructions_sysvar) => instructions_sysvar,
+            None => {
+                msg!("Vault requires the instructions sysvar in remaining accounts");
+                return Err(ErrorCode::SettleCrankMissing.into());
+            }
+        };
+
+        let current_index = load_current_index_checked(instructions_sysvar)?;
+        for index in 0..current_index {
+            let ix = load_instruction_at_checked(index as usize, instructions_sysvar)?;
+            if is_settle_crank(&ix, drift_user) {
+                return Ok(());
+            }
+        }
+
+        msg!(
+            "Vault requires a settle pnl for {} earlier in the transaction",
+            drift_user
+        );
+        Err(ErrorCode::SettleCrankMissing.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anchor_lang::solana_program::instruction::AccountMeta;
+
+    use super::*;
+
+    #[test]
+    fn test_is_settle_crank() {
+        let drift_user = Pubkey::new_unique();
+        let accounts = |user: Pubkey| {
+            vec![
+                AccountMeta::new_readonly(Pubkey::new_unique(), false),
+                AccountMeta::new(user, false),
+                AccountMeta::new_readonly(Pubkey::new_unique(), true),
+                AccountMeta::new_readonly(Pubkey::new_unique(), false),
+            ]
+        };
+        let settle_pnl = Instruction {
+            program_id: drift::id(),
+            accounts: accounts(drift_user),
+            data: drift::instruction::SettlePnl::DISCRIMINATOR.to_vec(),
+        };
+        assert!(is_settle_crank(&settle_pnl, &drift_user));
+
+        let other_user = Instruction {
+            accounts: accounts(Pubkey::new_unique()),
+            ..settle_pnl.clone()
+        };
+        assert!(!is_settle_crank(&other_user, &drift_user));
+
+        let other_program = Instruction {
+            program_id: crate::id(),
+            ..settle_pnl.clone()
+        };
+        assert!(!is_settle_crank(&other_program, &drift_user));
+
+        let other_ix = Instruction {
+            data: drift::instruction::UpdateUserName::DISCRIMINATOR.to_vec(),
+            ..settle_pnl
+        };
+        assert!(!is_settle_crank(&other_ix, &drift_user));
+    }
+}
@@ -1,14 +1,19 @@
 use std::cell::RefMut;
 
 use crate::error::ErrorCode;
-use crate::events::{VaultDepositorAction, VaultDepositorRecord, VaultDepositorV1Record};
+use crate::events::{
+    TokenizedSharesExchangeRateRecord, VaultDepositorAction, VaultDepositorRecord,
+    VaultDepositorV1Record,
+};
 use crate::state::vault::Vault;
+use crate::state::VaultConfig;
 use crate::{validate, VaultFee, VaultProtocol};
 use crate::{Size, VaultDepositorBase};
 use static_assertions::const_assert_eq;
 
 use anchor_lang::prelude::*;
 use drift::math::casting::Cast;
+use drift::math::constants::PRICE_PRECISION;
 use drift::math::insurance::{
     if_shares_to_vault_amount as depositor_shares_to_vault_amount,
     vault_amount_to_if_shares as vault_amount_to_depositor_shares,
@@ -44,9 +49,9 @@ pub struct TokenizedVaultDepositor {
     /// the token amount of gains the vault depositor has paid performance fees on
     pub cumulative_profit_share_amount: i64,
     pub profit_share_fee_paid: u64,
-    /// The exponent for vault_shares decimal places at the time the tokenized vault depositor was initialized.
-    /// If the vault undergoes a rebase, this TokenizedVaultDepositor can no longer issue new tokens, only redeem
-    /// is possible.
+    /// The exponent for vault_shares decimal places, kept in step with the vault by apply_rebase_tokenized_depositor.
+    /// Tokens are issued and redeemed at mint supply / vault_shares, so a rebase only changes the shares backing each
+    /// token and the mint keeps its balances and decimals. Tokenizing is blocked until the rebase is applied.
     pub vault_shares_base: u32,
     /// The bump for the vault pda
     pub bump: u8,
@@ -81,8 +86,9 @@ impl VaultDepositorBase for TokenizedVaultDepositor {
     fn get_vault_shares_base(&self) -> u32 {
         self.vault_shares_base
     }
-    fn set_vault_shares_base(&mut self, base: u32) {
+    fn set_vault_shares_base(&mut self, base: u32) -> Result<()> {
         self.vault_shares_base = base;
+        Ok(())
     }
 
     fn get_net_deposits(&self) -> i64 {
@@ -140,9 +146,10 @@ impl TokenizedVaultDepositor {
         vault: &mut Vault,
         vault_protocol: &mut Option<RefMut<VaultProtocol>>,
         vault_equity: u64,
+        now: i64,
     ) -> Result<Option<u128>> {
         if let Some(rebase_divisor) =
-            VaultDepositorBase::apply_rebase(self, vault, vault_protocol, vault_equity)?
+            VaultDepositorBase::apply_rebase(self, vault, vault_protocol, vault_equity, now)?
         {
             self.last_vault_shares = self.get_vault_shares();
             Ok(Some(rebase_divisor))
@@ -151,10 +158,68 @@ impl TokenizedVaultDepositor {
         }
     }
 
+    /// Value of one whole token (10^mint_decimals) in whole spot market tokens, precision: PRICE_PRECISION. Scaling
+    /// by both decimals keeps the rate comparable to the deposit token whatever decimals the mint was created with.
+    pub fn token_price(
+        &self,
+        vault: &Vault,
+        vault_equity: u64,
+        mint_supply: u64,
+        mint_decimals: u8,
+        spot_market_decimals: u32,
+    ) -> Result<u64> {
+        if mint_supply == 0 {
+            return Ok(0);
+        }
+
+        let tokenized_value =
+            depositor_shares_to_vault_amount(self.vault_shares, vault.total_shares, vault_equity)?;
+
+        Ok(tokenized_value
+            .cast::<u128>()?
+            .safe_mul(PRICE_PRECISION)?
+            .safe_mul(10_u128.pow(mint_decimals.cast()?))?
+            .safe_div(
+                mint_supply
+                    .cast::<u128>()?
+                    .safe_mul(10_u128.pow(spot_market_decimals))?,
+            )?
+            .cast()?)
+    }
+
+    pub fn emit_exchange_rate_record(
+        &self,
+        vault: &Vault,
+        vault_equity: u64,
+        mint_supply: u64,
+        mint_decimals: u8,
+        spot_market_decimals: u32,
+        now: i64,
+    ) -> Result<()> {
+        emit!(TokenizedSharesExchangeRateRecord {
+            ts: now,
+            vault: vault.pubkey,
+            mint: self.mint,
+            vault_shares_base: self.vault_shares_base,
+            vault_shares: self.vault_shares,
+            mint_supply,
+            token_price: self.token_price(
+                vault,
+                vault_equity,
+                mint_supply,
+                mint_decimals,
+                spot_market_decimals,
+            )?,
+        });
+
+        Ok(())
+    }
+
     #[allow(clippy::too_many_arguments)]
     pub fn tokenize_shares(
         self: &mut TokenizedVaultDepositor,
         vault: &mut Vault,
+        vault_config: &VaultConfig,
         vault_protocol: &mut Option<RefMut<VaultProtocol>>,
         mint_supply: u64,
         vault_equity: u64,
@@ -162,7 +227,7 @@ impl TokenizedVaultDepositor {
         now: i64,
         deposit_oracle_price: i64,
     ) -> Result<u64> {
-        let rebase_divisor = self.apply_rebase(vault, vault_protocol, vault_equity)?;
+        let rebase_divisor = self.apply_rebase(vault, vault_protocol, vault_equity, now)?;
         if rebase_divisor.is_some() {
             return Err(ErrorCode::InvalidVaultRebase.into());
         }
@@ -172,9 +237,9 @@ impl TokenizedVaultDepositor {
             management_fee_shares,
             protocol_fee_payment,
             protocol_fee_shares,
-        } = vault.apply_fee(vault_protocol, vault_equity, now)?;
+        } = vault.apply_fee(vault_config, vault_protocol, vault_equity, now)?;
         let (manager_profit_share, protocol_profit_share) =
-            self.apply_profit_share(vault_equity, vault, vault_protocol)?;
+            self.apply_profit_share(vault_equity, vault, vault_config, vault_protocol)?;
 
         let vault_shares_before = self.checked_vault_shares(vault)?;
         let total_vault_shares_before = vault.total_shares;
@@ -266,6 +331,7 @@ impl TokenizedVaultDepositor {
     pub fn redeem_tokens<'a>(
         self: &mut TokenizedVaultDepositor,
         vault: &mut Vault,
+        vault_config: &VaultConfig,
         vault_protocol: &mut Option<RefMut<'a, VaultProtocol>>,
         mint_supply: u64,
         vault_equity: u64,
@@ -273,16 +339,16 @@ impl TokenizedVaultDepositor {
         now: i64,
         deposit_oracle_price: i64,
     ) -> Result<(u64, Option<RefMut<'a, VaultProtocol>>)> {
-        self.apply_rebase(vault, vault_protocol, vault_equity)?;
+        self.apply_rebase(vault, vault_protocol, vault_equity, now)?;
 
         let VaultFee {
             management_fee_payment,
             management_fee_shares,
             protocol_fee_payment,
             protocol_fee_shares,
-        } = vault.apply_fee(vault_protocol, vault_equity, now)?;
+        } = vault.apply_fee(vault_config, vault_protocol, vault_equity, now)?;
         let (manager_profit_share, protocol_profit_share) =
-            self.apply_profit_share(vault_equity, vault, vault_protocol)?;
+            self.apply_profit_share(vault_equity, vault, vault_config, vault_protocol)?;
 
         let vault_shares_before = self.checked_vault_shares(vault)?;
         let total_vault_shares_before = vault.total_shares;
@@ -363,6 +429,7 @@ impl TokenizedVaultDepositor {
 
 #[cfg(test)]
 mod tests {
+    use crate::state::VaultConfig;
     use crate::{TokenizedVaultDepositor, Vault, VaultDepositorBase};
     use anchor_lang::prelude::Pubkey;
     use drift::math::constants::PERCENTAGE_PRECISION;
@@ -390,6 +457,7 @@ mod tests {
         let tokens_issued_1 = tvd
             .tokenize_shares(
                 vault,
+                &VaultConfig::default(),
                 &mut None,
                 total_supply,
                 vault_equity,
@@ -413,6 +481,7 @@ mod tests {
         let tokens_issued_2 = tvd
             .tokenize_shares(
                 vault,
+                &VaultConfig::default(),
                 &mut None,
                 total_supply,
                 vault_equity,
@@ -457,6 +526,7 @@ mod tests {
         let shares_to_transfer = tvd
             .redeem_tokens(
                 vault,
+                &VaultConfig::default(),
                 &mut None,
                 total_supply as u64,
                 vault_equity,
@@ -491,6 +561,7 @@ mod tests {
         let tokens_issued_1 = tvd
             .tokenize_shares(
                 vault,
+                &VaultConfig::default(),
                 &mut None,
                 total_supply,
                 vault_equity,
@@ -516,6 +587,7 @@ mod tests {
         // will trigger rebase
         let tokens_issued_2 = tvd.tokenize_shares(
             vault,
+            &VaultConfig::default(),
             &mut None,
             total_supply,
             vault_equity,
@@ -530,6 +602,69 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_tokenize_shares_after_applied_rebase() {
+        let now = 1337;
+        let vault = &mut Vault {
+            total_shares: 100_000_000,
+            user_shares: 100_000_000,
+            ..Vault::default()
+        };
+        let mut tvd = TokenizedVaultDepositor::new(
+            Pubkey::default(),
+            Pubkey::default(),
+            Pubkey::default(),
+            0,
+            0,
+            now,
+        );
+        tvd.vault_shares = 100_000_000;
+        tvd.last_vault_shares = tvd.vault_shares;
+        let total_supply = 100_000_000;
+
+        // vault down 99.99%, the rebase is applied to the tokenized depositor before tokenizing again
+        let vault_equity = 10_000;
+        let rebase_divisor = tvd
+            .apply_rebase(vault, &mut None, vault_equity, now)
+            .unwrap()
+            .expect("rebase");
+        assert_eq!(tvd.vault_shares_base, vault.shares_base);
+        assert_eq!(tvd.vault_shares, 100_000_000 / rebase_divisor);
+        assert_eq!(tvd.last_vault_shares, tvd.vault_shares);
+
+        // same mint, the token supply didn't rebase and 1% more shares mint 1% more tokens
+        let shares_transferred = tvd.vault_shares / 100;
+        vault.total_shares += shares_transferred;
+        vault.user_shares += shares_transferred;
+        tvd.vault_shares += shares_transferred;
+        let tokens_issued = tvd
+            .tokenize_shares(
+                vault,
+                &VaultConfig::default(),
+                &mut None,
+                total_supply,
+                vault_equity,
+                shares_transferred,
+                now,
+                0,
+            )
+            .unwrap();
+        assert_eq!(tokens_issued, total_supply / 100);
+
+        // 10_000 for 101_000_000 tokens, a 9 decimal mint is quoted 1000x a 6 decimal one
+        let mint_supply = total_supply + tokens_issued;
+        assert_eq!(
+            tvd.token_price(vault, vault_equity, mint_supply, 6, 6)
+                .unwrap(),
+            99
+        );
+        assert_eq!(
+            tvd.token_price(vault, vault_equity, mint_supply, 9, 6)
+                .unwrap(),
+            99_009
+        );
+    }
+
     #[test]
     fn test_tokenize_shares_with_profit_share() {
         let now = 1337;
@@ -561,6 +696,7 @@ mod tests {
         let tokens_issued_1 = tvd
             .tokenize_shares(
                 vault,
+                &VaultConfig::default(),
                 &mut None,
                 total_supply,
                 vault_equity,
@@ -579,7 +715,12 @@ mod tests {
 
         let tvd_shares_before = tvd.get_vault_shares();
         let (manager_profit_share, protocol_profit_share) = tvd
-            .apply_profit_share(vault_equity + profit, vault, &mut None)
+            .apply_profit_share(
+                vault_equity + profit,
+                vault,
+                &VaultConfig::default(),
+                &mut None,
+            )
             .unwrap();
         let tvd_shares_after = tvd.get_vault_shares();
 
@@ -4,18 +4,41 @@ use crate::error::ErrorCode;
 use crate::events::{
     ShareTransferRecord, VaultDepositorAction, VaultDepositorRecord, VaultDepositorV1Record,
 };
-use crate::state::vault::Vault;
+use crate::state::vault::{ProfitShareMode, Vault};
+use crate::state::VaultConfig;
 use crate::{validate, VaultFee, VaultProtocol, WithdrawUnit};
 use anchor_lang::prelude::*;
 
 use drift::math::casting::Cast;
-use drift::math::constants::{PERCENTAGE_PRECISION, PERCENTAGE_PRECISION_I64};
+use drift::math::constants::PERCENTAGE_PRECISION;
 use drift::math::insurance::{
     if_shares_to_vault_amount as depositor_shares_to_vault_amount,
     vault_amount_to_if_shares as vault_amount_to_depositor_shares,
 };
 use drift::math::safe_math::SafeMath;
 
+/// Portion of a depositor's cost basis `amount` attributable to `shares_moved` out of `shares_total`.
+/// Rounds toward zero, leaving the remainder with the source, so source + destination always sum to `amount`.
+pub fn split_cost_basis(amount: i64, shares_moved: u128, shares_total: u128) -> Result<i64> {
+    validate!(
+        shares_moved <= shares_total,
+        ErrorCode::InvalidVaultSharesDetected,
+        "shares_moved {} > shares_total {}",
+        shares_moved,
+        shares_total
+    )?;
+
+    if shares_moved == shares_total {
+        return Ok(amount);
+    }
+
+    amount
+        .cast::<i128>()?
+        .safe_mul(shares_moved.cast::<i128>()?)?
+        .safe_div(shares_total.cast::<i128>()?)?
+        .cast()
+}
+
 pub trait Size {
     const SIZE: usize;
 }
@@ -28,7 +51,7 @@ pub trait VaultDepositorBase {
     fn set_vault_shares(&mut self, shares: u128);
 
     fn get_vault_shares_base(&self) -> u32;
-    fn set_vault_shares_base(&mut self, base: u32);
+    fn set_vault_shares_base(&mut self, base: u32) -> Result<()>;
 
     fn get_net_deposits(&self) -> i64;
     fn set_net_deposits(&mut self, amount: i64);
@@ -39,6 +62,11 @@ pub trait VaultDepositorBase {
     fn get_profit_share_fee_paid(&self) -> u64;
     fn set_profit_share_fee_paid(&mut self, amount: u64);
 
+    /// The manager profit share charged on this depositor's profits, the vault's unless overridden.
+    fn manager_profit_share(&self, vault: &Vault) -> Result<u32> {
+        Ok(vault.manager_profit_share())
+    }
+
     fn validate_base(&self, vault: &Vault) -> Result<()> {
         validate!(
             self.get_vault_shares_base() == vault.shares_base,
@@ -78,12 +106,54 @@ pub trait VaultDepositorBase {
         Ok(())
     }
 
+    /// In [`ProfitShareMode::LossesFirst`] resets the high water mark to net deposits once `total_amount` is below
+    /// them.
+    fn apply_losses_first(&mut self, total_amount: u64, vault_config: &VaultConfig) -> Result<()> {
+        if vault_config.profit_share_mode()? == ProfitShareMode::LossesFirst
+            && total_amount.cast::<i64>()? < self.get_net_deposits()
+            && self.get_cumulative_profit_share_amount() > 0
+        {
+            msg!(
+                "depositor below net deposits, resetting cumulative_profit_share_amount {}",
+                self.get_cumulative_profit_share_amount()
+            );
+            self.set_cumulative_profit_share_amount(0);
+        }
+
+        Ok(())
+    }
+
     fn calculate_profit_share_and_update(
         &mut self,
         total_amount: u64,
         vault: &Vault,
+        vault_config: &VaultConfig,
         vault_protocol: &mut Option<RefMut<VaultProtocol>>,
     ) -> Result<(u128, u128)> {
+        let vault_shares = self.get_vault_shares();
+        self.calculate_realized_profit_share_and_update(
+            total_amount,
+            vault_shares,
+            vault,
+            vault_config,
+            vault_protocol,
+        )
+    }
+
+    /// Like [`Self::calculate_profit_share_and_update`] but only charges the part of the profit attributable to
+    /// `realized_shares` of the depositor's shares. The high water mark only moves up by the charged part, so the
+    /// rest of the profit stays owed by the remaining shares. In [`ProfitShareMode::PeakEquity`] it moves up by the
+    /// realized part even when the [`FeePolicy`](crate::state::FeePolicy) charges nothing on it.
+    fn calculate_realized_profit_share_and_update(
+        &mut self,
+        total_amount: u64,
+        realized_shares: u128,
+        vault: &Vault,
+        vault_config: &VaultConfig,
+        vault_protocol: &mut Option<RefMut<VaultProtocol>>,
+    ) -> Result<(u128, u128)> {
+        self.apply_losses_first(total_amount, vault_config)?;
+
         let cumulative_profit_share_amount = self
             .get_net_deposits()
             .safe_add(self.get_cumulative_profit_share_amount())?;
@@ -92,23 +162,35 @@ pub trait VaultDepositorBase {
             .cast::<i64>()?
             .safe_sub(cumulative_profit_share_amount)?;
 
-        let profit_beyond_hurdle = if vault.hurdle_rate > 0 {
-            cumulative_profit_share_amount
-                .safe_mul(vault.hurdle_rate as i64)?
-                .safe_div(PERCENTAGE_PRECISION_I64)?
-        } else {
-            0
-        };
+        let chargeable_profit = vault_config.fee_policy(vault)?.chargeable_profit(
+            profit,
+            cumulative_profit_share_amount,
+            vault.hurdle_rate,
+        )?;
 
-        if profit > profit_beyond_hurdle {
-            let profit_u128 = profit.cast::<u128>()?;
+        let peak_equity =
+            profit > 0 && vault_config.profit_share_mode()? == ProfitShareMode::PeakEquity;
+        if chargeable_profit > 0 || peak_equity {
+            let vault_shares = self.get_vault_shares();
+            let realized = |amount: i64| -> Result<u128> {
+                if realized_shares < vault_shares {
+                    Ok(amount
+                        .cast::<u128>()?
+                        .safe_mul(realized_shares)?
+                        .safe_div(vault_shares)?)
+                } else {
+                    Ok(amount.cast::<u128>()?)
+                }
+            };
+            let profit_u128 = realized(profit)?;
+            let chargeable_profit_u128 = realized(chargeable_profit)?;
 
-            let manager_profit_share_amount = profit_u128
-                .safe_mul(vault.profit_share.cast()?)?
+            let manager_profit_share_amount = chargeable_profit_u128
+                .safe_mul(self.manager_profit_share(vault)?.cast()?)?
                 .safe_div(PERCENTAGE_PRECISION)?;
             let protocol_profit_share_amount = match vault_protocol {
                 None => 0,
-                Some(vp) => profit_u128
+                Some(vp) => chargeable_profit_u128
                     .safe_mul(vp.protocol_profit_share.cast()?)?
                     .safe_div(PERCENTAGE_PRECISION)?,
             };
@@ -137,7 +219,28 @@ pub trait VaultDepositorBase {
         &mut self,
         vault_equity: u64,
         vault: &mut Vault,
+        vault_config: &VaultConfig,
         vault_protocol: &mut Option<RefMut<VaultProtocol>>,
+    ) -> Result<(u64, u64)> {
+        let vault_shares = self.get_vault_shares();
+        self.apply_realized_profit_share(
+            vault_equity,
+            vault,
+            vault_config,
+            vault_protocol,
+            vault_shares,
+        )
+    }
+
+    /// Like [`Self::apply_profit_share`] but only charges profit share on `realized_shares`, see
+    /// [`Self::calculate_realized_profit_share_and_update`]
+    fn apply_realized_profit_share(
+        &mut self,
+        vault_equity: u64,
+        vault: &mut Vault,
+        vault_config: &VaultConfig,
+        vault_protocol: &mut Option<RefMut<VaultProtocol>>,
+        realized_shares: u128,
     ) -> Result<(u64, u64)> {
         let total_amount = depositor_shares_to_vault_amount(
             self.get_vault_shares(),
@@ -145,8 +248,14 @@ pub trait VaultDepositorBase {
             vault_equity,
         )?;
 
-        let (manager_profit_share, protocol_profit_share) =
-            self.calculate_profit_share_and_update(total_amount, vault, vault_protocol)?;
+        let (manager_profit_share, protocol_profit_share) = self
+            .calculate_realized_profit_share_and_update(
+                total_amount,
+                realized_shares,
+                vault,
+                vault_config,
+                vault_protocol,
+            )?;
         let manager_profit_share: u64 = manager_profit_share.cast()?;
         let protocol_profit_share: u64 = protocol_profit_share.cast()?;
         let profit_share = manager_profit_share
@@ -191,8 +300,9 @@ pub trait VaultDepositorBase {
         vault: &mut Vault,
         vault_protocol: &mut Option<RefMut<VaultProtocol>>,
         vault_equity: u64,
+        now: i64,
     ) -> Result<Option<u128>> {
-        vault.apply_rebase(vault_protocol, vault_equity)?;
+        vault.apply_rebase(vault_protocol, vault_equity, now)?;
 
         let mut rebase_divisor: Option<u128> = None;
 
@@ -213,7 +323,7 @@ pub trait VaultDepositorBase {
                 vault.shares_base,
             );
 
-            self.set_vault_shares_base(vault.shares_base);
+            self.set_vault_shares_base(vault.shares_base)?;
 
             let old_vault_shares = self.unchecked_vault_shares();
             let new_vault_shares =
@@ -239,12 +349,18 @@ pub trait VaultDepositorBase {
 
     /// Transfer shares from `self` to `to`
     ///
+    /// `net_deposits` and `cumulative_profit_share_amount` are split pro-rata to the shares moved (see
+    /// [`split_cost_basis`]), so the profit share owed on the moved shares follows them. Fuel needs no
+    /// split: callers accrue the `VaultDepositor` side with `with_fuel_sync` before the transfer and fuel
+    /// only accrues per share held from then on.
+    ///
     /// Returns the number of shares transferred
     #[allow(clippy::too_many_arguments)]
     fn transfer_shares<'a>(
         &mut self,
         to: &mut dyn VaultDepositorBase,
         vault: &mut Vault,
+        vault_config: &VaultConfig,
         vault_protocol: &mut Option<RefMut<'a, VaultProtocol>>,
         withdraw_amount: u64,
         withdraw_unit: WithdrawUnit,
@@ -252,8 +368,8 @@ pub trait VaultDepositorBase {
         now: i64,
         deposit_oracle_price: i64,
     ) -> Result<(u128, Option<RefMut<'a, VaultProtocol>>)> {
-        let from_rebase_divisor = self.apply_rebase(vault, vault_protocol, vault_equity)?;
-        let to_rebase_divisor = to.apply_rebase(vault, vault_protocol, vault_equity)?;
+        let from_rebase_divisor = self.apply_rebase(vault, vault_protocol, vault_equity, now)?;
+        let to_rebase_divisor = to.apply_rebase(vault, vault_protocol, vault_equity, now)?;
 
         validate!(
             from_rebase_divisor == to_rebase_divisor,
@@ -266,12 +382,12 @@ pub trait VaultDepositorBase {
             management_fee_shares,
             protocol_fee_payment,
             protocol_fee_shares,
-        } = vault.apply_fee(vault_protocol, vault_equity, now)?;
+        } = vault.apply_fee(vault_config, vault_protocol, vault_equity, now)?;
 
         let (from_manager_profit_share, from_protocol_profit_share) =
-            self.apply_profit_share(vault_equity, vault, vault_protocol)?;
+            self.apply_profit_share(vault_equity, vault, vault_config, vault_protocol)?;
         let (to_manager_profit_share, to_protocol_profit_share) =
-            to.apply_profit_share(vault_equity, vault, vault_protocol)?;
+            to.apply_profit_share(vault_equity, vault, vault_config, vault_protocol)?;
 
         let (withdraw_value, n_shares) = withdraw_unit.get_withdraw_value_and_shares(
             withdraw_amount,
@@ -299,8 +415,28 @@ pub trait VaultDepositorBase {
         self.decrease_vault_shares(n_shares, vault)?;
         to.increase_vault_shares(n_shares, vault)?;
 
-        self.set_net_deposits(self.get_net_deposits().safe_sub(withdraw_value.cast()?)?);
-        to.set_net_deposits(to.get_net_deposits().safe_add(withdraw_value.cast()?)?);
+        // move cost basis with the shares so the high water mark per share is the same on both sides
+        let net_deposits_moved = split_cost_basis(
+            self.get_net_deposits(),
+            n_shares,
+            from_depositor_shares_before,
+        )?;
+        let cumulative_profit_share_moved = split_cost_basis(
+            self.get_cumulative_profit_share_amount(),
+            n_shares,
+            from_depositor_shares_before,
+        )?;
+
+        self.set_net_deposits(self.get_net_deposits().safe_sub(net_deposits_moved)?);
+        to.set_net_deposits(to.get_net_deposits().safe_add(net_deposits_moved)?);
+        self.set_cumulative_profit_share_amount(
+            self.get_cumulative_profit_share_amount()
+                .safe_sub(cumulative_profit_share_moved)?,
+        );
+        to.set_cumulative_profit_share_amount(
+            to.get_cumulative_profit_share_amount()
+                .safe_add(cumulative_profit_share_moved)?,
+        );
 
         let from_depositor_shares_after = self.checked_vault_shares(vault)?;
         let to_depositor_shares_after = to.checked_vault_shares(vault)?;
@@ -428,3 +564,86 @@ pub trait VaultDepositorBase {
         Ok((n_shares, vault_protocol.take()))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::split_cost_basis;
+
+    fn profit_share_liability(shares: i128, price: i128, high_water_mark: i128) -> i128 {
+        (shares * price - high_water_mark).max(0)
+    }
+
+    #[test]
+    fn test_split_cost_basis() {
+        assert_eq!(split_cost_basis(1_000, 0, 10).unwrap(), 0);
+        assert_eq!(split_cost_basis(1_000, 10, 10).unwrap(), 1_000);
+        assert_eq!(split_cost_basis(1_000, 3, 10).unwrap(), 300);
+        assert_eq!(split_cost_basis(1_000, 1, 3).unwrap(), 333);
+        assert_eq!(split_cost_basis(-1_000, 1, 3).unwrap(), -333);
+        assert_eq!(split_cost_basis(i64::MAX, 1, 2).unwrap(), i64::MAX / 2);
+        assert!(split_cost_basis(1_000, 11, 10).is_err());
+    }
+
+    #[test]
+    fn test_split_cost_basis_conserves_profit_share_liability() {
+        // deterministic lcg so failures are reproducible
+        let mut seed: u64 = 0x5eed;
+        let mut next = |max: u64| {
+            seed = seed
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            (seed >> 33) % max
+        };
+
+        for _ in 0..10_000 {
+            let shares_total = next(1_000_000_000) as u128 + 1;
+            let shares_moved = next(shares_total as u64 + 1) as u128;
+            let net_deposits = next(2_000_000_000_000) as i64 - 1_000_000_000_000;
+            let cumulative_profit_share_amount = next(1_000_000_000_000) as i64;
+
+            let net_deposits_moved =
+                split_cost_basis(net_deposits, shares_moved, shares_total).unwrap();
+            let cumulative_profit_share_moved =
+                split_cost_basis(cumulative_profit_share_amount, shares_moved, shares_total)
+                    .unwrap();
+
+            // cost basis totals are conserved exactly
+            assert_eq!(
+                (net_deposits - net_deposits_moved) + net_deposits_moved,
+                net_deposits
+            );
+            assert_eq!(
+                (cumulative_profit_share_amount - cumulative_profit_share_moved)
+                    + cumulative_profit_share_moved,
+                cumulative_profit_share_amount
+            );
+            assert!(net_deposits_moved.abs() <= net_deposits.abs());
+            assert!(cumulative_profit_share_moved <= cumulative_profit_share_amount);
+
+            // profit share owed at any later share price is unchanged by the split, up to rounding
+            let high_water_mark = net_deposits as i128 + cumulative_profit_share_amount as i128;
+            let high_water_mark_moved =
+                net_deposits_moved as i128 + cumulative_profit_share_moved as i128;
+            for _ in 0..8 {
+                let price = next(5_000) as i128;
+                let before = profit_share_liability(shares_total as i128, price, high_water_mark);
+                let after =
+                    profit_share_liability(
+                        (shares_total - shares_moved) as i128,
+                        price,
+                        high_water_mark - high_water_mark_moved,
+                    ) + profit_share_liability(shares_moved as i128, price, high_water_mark_moved);
+                assert!(
+                    (after - before).abs() <= 2,
+                    "liability changed {} -> {} (shares {}/{}, hwm {}, price {})",
+                    before,
+                    after,
+                    shares_moved,
+                    shares_total,
+                    high_water_mark,
+                    price
+                );
+            }
+        }
+    }
+}
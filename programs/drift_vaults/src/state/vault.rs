@@ -1,29 +1,40 @@
 use std::cell::RefMut;
 
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hash;
 use borsh::{BorshDeserialize, BorshSerialize};
 use drift::math::casting::Cast;
-use drift::math::constants::{ONE_YEAR, PERCENTAGE_PRECISION, PERCENTAGE_PRECISION_I128};
+use drift::math::constants::{
+    ONE_YEAR, PERCENTAGE_PRECISION, PERCENTAGE_PRECISION_I128, SPOT_WEIGHT_PRECISION,
+};
+use drift::math::funding::calculate_funding_payment;
 use drift::math::insurance::calculate_rebase_info;
 use drift::math::insurance::{
     if_shares_to_vault_amount as depositor_shares_to_vault_amount,
     vault_amount_to_if_shares as vault_amount_to_depositor_shares,
 };
-use drift::math::margin::calculate_user_equity;
+use drift::math::margin::{
+    calculate_margin_requirement_and_total_collateral_and_liability_info, calculate_user_equity,
+};
 use drift::math::safe_math::SafeMath;
+use drift::state::margin_calculation::MarginContext;
 use drift::state::oracle_map::OracleMap;
 use drift::state::perp_market_map::PerpMarketMap;
+use drift::state::spot_market::SpotMarket;
 use drift::state::spot_market_map::SpotMarketMap;
-use drift::state::user::{FuelOverflow, User, UserStats};
+use drift::state::user::{FuelOverflow, MarginRequirementType, User, UserStats};
 use drift_macros::assert_no_slop;
 use static_assertions::const_assert_eq;
 
 use crate::constants::{FUEL_SHARE_PRECISION, TIME_FOR_LIQUIDATION};
 use crate::error::{ErrorCode, VaultResult};
 use crate::events::{VaultDepositorAction, VaultDepositorV1Record};
-use crate::state::events::VaultDepositorRecord;
+use crate::state::events::{
+    LiquidationAction, LiquidationActionRecord, VaultDepositorRecord, VaultHealthRecord,
+    VaultRebaseRecord,
+};
 use crate::state::withdraw_request::WithdrawRequest;
-use crate::state::{VaultFee, VaultProtocol};
+use crate::state::{DepositValuation, VaultConfig, VaultFeature, VaultFee, VaultProtocol};
 use crate::{validate, Size, WithdrawUnit};
 
 #[assert_no_slop]
@@ -104,14 +115,63 @@ pub struct Vault {
     pub vault_protocol: bool,
     /// How fuel distribution should be treated [`FuelDistributionMode`]. Default is `UsersOnly`
     pub fuel_distribution_mode: u8,
-    pub padding1: [u8; 2],
+    /// The optional [`VaultHook`] account.
+    pub vault_hook: bool,
+    /// Set by a [`VaultPoll`](crate::state::VaultPoll) wind down, no new deposits are accepted
+    pub wind_down: bool,
     /// The timestamp cumulative_fuel_per_share was last updated
     pub last_cumulative_fuel_per_share_ts: u32,
     /// The cumulative fuel per share (scaled up by 1e6 to avoid losing precision)
     pub cumulative_fuel_per_share: u128,
     /// The total fuel accumulated
     pub cumulative_fuel: u128,
-    pub padding: [u64; 3],
+    /// The [`FuelOverflow`] `last_reset_ts` of the fuel season the vault is currently accruing
+    pub fuel_season_start_ts: u32,
+    /// Withdraw requests for more than this share of vault equity wait an extra notice period: PERCENTAGE_PRECISION.
+    /// 0 disables the notice period
+    pub large_withdraw_threshold: u32,
+    /// The extra period (in seconds) on top of the redeem period for large withdraw requests
+    pub large_withdraw_notice_period: u32,
+    /// Number of open [`VaultDepositor`](crate::state::VaultDepositor)s of the vault. Depositors initialized
+    /// before the counter was added are counted by `backfill_vault_depositor_counts`
+    pub depositor_count: u32,
+    /// Number of counted depositors with a pending withdraw request
+    pub active_withdraw_request_count: u32,
+    /// The optional [`VaultWithdrawSettlement`](crate::state::VaultWithdrawSettlement) account. Matured withdraw
+    /// requests are paid at the share price of the last settlement crank
+    pub withdraw_settlement: bool,
+    /// Restricts the positions the drift user may hold [`VaultProfile`](crate::state::VaultProfile).
+    /// Default is `Unrestricted`
+    pub profile: u8,
+    /// Bits of the optional [`VaultFeature`](crate::state::VaultFeature)s switched off for this vault. Default is 0,
+    /// everything enabled
+    pub disabled_features: u8,
+    /// Bits of [`VaultFlag`]
+    pub flags: u8,
+}
+
+/// Bits of [`Vault`] `flags`
+#[derive(Clone, Copy, PartialEq, Debug, Eq)]
+pub enum VaultFlag {
+    /// The vault is listed in the [`VaultRegistry`](crate::state::VaultRegistry)
+    Registered = 0b00000001,
+    /// Parameter updates must be recorded in the vault's
+    /// [`VaultParamChangeLog`](crate::state::VaultParamChangeLog)
+    HasParamChangeLog = 0b00000010,
+    /// New vault depositors are limited by the vault's [`VaultDepositorCap`](crate::state::VaultDepositorCap)
+    HasDepositorCap = 0b00000100,
+    /// Management fee is suspended by an active [`VaultFeeHoliday`](crate::state::VaultFeeHoliday)
+    ManagementFeeHoliday = 0b00001000,
+    /// Manager profit share is suspended by an active [`VaultFeeHoliday`](crate::state::VaultFeeHoliday)
+    ProfitShareHoliday = 0b00010000,
+    /// The vault has a [`VaultConfig`](crate::state::VaultConfig), which must be passed in remaining_accounts
+    HasVaultConfig = 0b00100000,
+    /// A depositor has a [`WithdrawReceipt`](crate::state::WithdrawReceipt), cancels must pass the depositor's
+    /// receipt address
+    WithdrawReceipts = 0b01000000,
+    /// Deposits and withdraws must follow a drift settle pnl of the vault's user in the same transaction, see
+    /// [`Vault::validate_settle_crank`]
+    RequireSettleCrank = 0b10000000,
 }
 
 impl Vault {
@@ -119,6 +179,63 @@ impl Vault {
         [b"vault".as_ref(), name, bytemuck::bytes_of(bump)]
     }
 
+    /// First 8 bytes (little endian) of the sha256 of the vault's data after the discriminator. Every instruction that
+    /// updates the vault changes it, so keepers can pin a crank to the state they priced it against, see
+    /// [`check_vault_state_hash`](crate::instructions::check_vault_state_hash)
+    pub fn state_hash(&self) -> u64 {
+        let hash = hash(bytemuck::bytes_of(self)).to_bytes();
+        u64::from_le_bytes(hash[..8].try_into().unwrap_or_default())
+    }
+
+    /// Extra delay before a withdraw request of `withdraw_value` matures, the vault's large withdraw notice period
+    /// if the request exceeds `large_withdraw_threshold` of `vault_equity`.
+    pub fn large_withdraw_notice_delay(
+        &self,
+        withdraw_value: u64,
+        vault_equity: u64,
+    ) -> Result<i64> {
+        if self.large_withdraw_threshold == 0 {
+            return Ok(0);
+        }
+
+        let threshold_value = vault_equity
+            .cast::<u128>()?
+            .safe_mul(self.large_withdraw_threshold.cast()?)?
+            .safe_div(PERCENTAGE_PRECISION)?;
+
+        if withdraw_value.cast::<u128>()? > threshold_value {
+            Ok(self.large_withdraw_notice_period.cast()?)
+        } else {
+            Ok(0)
+        }
+    }
+
+    pub fn has_flag(&self, flag: VaultFlag) -> bool {
+        self.flags & (flag as u8) != 0
+    }
+
+    pub fn add_flag(&mut self, flag: VaultFlag) {
+        self.flags |= flag as u8;
+    }
+
+    pub fn remove_flag(&mut self, flag: VaultFlag) {
+        self.flags &= !(flag as u8);
+    }
+
+    pub fn is_feature_enabled(&self, feature: VaultFeature) -> bool {
+        self.disabled_features & (feature as u8) == 0
+    }
+
+    pub fn validate_feature_enabled(&self, feature: VaultFeature) -> Result<()> {
+        validate!(
+            self.is_feature_enabled(feature),
+            ErrorCode::VaultFeatureDisabled,
+            "vault feature {:?} is disabled",
+            feature
+        )?;
+        Ok(())
+    }
+
     pub fn reset_cumulative_fuel_per_share(&mut self, now: i64) {
         msg!(
             "Resetting vault fuel. now: {:?}, cumulative_fuel_per_share: {:?}, cumulative_fuel: {:?}",
@@ -131,19 +248,84 @@ impl Vault {
         self.last_cumulative_fuel_per_share_ts = now as u32;
     }
 
+    /// The [`FuelOverflow`] reset the vault hasn't started a fuel season for yet, see [`Vault::start_fuel_season`].
+    pub fn pending_fuel_season(
+        &self,
+        fuel_overflow: &Option<AccountLoader<FuelOverflow>>,
+    ) -> Result<Option<u32>> {
+        let overflow_last_reset_ts = match fuel_overflow {
+            Some(overflow) => overflow.load()?.last_reset_ts,
+            None => return Ok(None),
+        };
+
+        Ok(self
+            .is_new_fuel_season(overflow_last_reset_ts)
+            .then_some(overflow_last_reset_ts))
+    }
+
+    /// Resets that happened before the vault last accrued were already settled by the legacy path in
+    /// [`Vault::update_cumulative_fuel_per_share_with_overflow`].
+    fn is_new_fuel_season(&self, overflow_last_reset_ts: u32) -> bool {
+        overflow_last_reset_ts > self.fuel_season_start_ts
+            && overflow_last_reset_ts > self.last_cumulative_fuel_per_share_ts
+    }
+
+    /// Starts a new fuel season at the [`FuelOverflow`] reset boundary, fuel accrued after
+    /// `season_start_ts` is folded in from zero on the next update. Drift resets the fuel the vault accrued since
+    /// its last update along with the season, so depositors are settled into the old season with
+    /// [`VaultDepositor::settle_fuel_season`](crate::state::VaultDepositor::settle_fuel_season) first, and keepers
+    /// run [`update_cumulative_fuel_amount_batch`](crate::instructions::update_cumulative_fuel_amount_batch) ahead of
+    /// drift's `reset_fuel_season` so the vault's own fuel is settled too.
+    pub fn start_fuel_season(&mut self, season_start_ts: u32) {
+        msg!(
+            "Starting vault fuel season at {:?}. previous season start: {:?}, cumulative_fuel_per_share: {:?}, cumulative_fuel: {:?}",
+            season_start_ts,
+            self.fuel_season_start_ts,
+            self.cumulative_fuel_per_share,
+            self.cumulative_fuel
+        );
+        self.cumulative_fuel_per_share = 0;
+        self.cumulative_fuel = 0;
+        self.last_cumulative_fuel_per_share_ts = season_start_ts;
+        self.fuel_season_start_ts = season_start_ts;
+    }
+
     pub fn update_cumulative_fuel_per_share(
         &mut self,
         now: i64,
         user_stats: &UserStats,
         fuel_overflow: &Option<AccountLoader<FuelOverflow>>,
     ) -> Result<u128> {
-        let overflow_total_fuel = if let Some(overflow) = fuel_overflow {
-            overflow.load()?.total_fuel()?
+        let (overflow_total_fuel, overflow_last_reset_ts) = if let Some(overflow) = fuel_overflow {
+            let overflow = overflow.load()?;
+            (overflow.total_fuel()?, overflow.last_reset_ts)
         } else {
-            0
+            (0, 0)
         };
+
+        self.update_cumulative_fuel_per_share_with_overflow(
+            now,
+            user_stats,
+            overflow_total_fuel,
+            overflow_last_reset_ts,
+        )
+    }
+
+    pub fn update_cumulative_fuel_per_share_with_overflow(
+        &mut self,
+        now: i64,
+        user_stats: &UserStats,
+        overflow_total_fuel: u128,
+        overflow_last_reset_ts: u32,
+    ) -> Result<u128> {
         let total_fuel = user_stats.total_fuel()?.safe_add(overflow_total_fuel)?;
 
+        // the FuelOverflow was reset since the last update, everything in total_fuel belongs to the
+        // new season so close out the old one instead of diffing across the boundary.
+        if self.is_new_fuel_season(overflow_last_reset_ts) {
+            self.start_fuel_season(overflow_last_reset_ts);
+        }
+
         if (now as u32) > self.last_cumulative_fuel_per_share_ts {
             if self.cumulative_fuel > total_fuel {
                 // this shouldn't happen under SOP, if it does happen then the UserStats fuel was reset
@@ -193,6 +375,7 @@ const_assert_eq!(Vault::SIZE, std::mem::size_of::<Vault>() + 8);
 impl Vault {
     pub fn apply_fee(
         &mut self,
+        vault_config: &VaultConfig,
         vault_protocol: &mut Option<RefMut<VaultProtocol>>,
         vault_equity: u64,
         now: i64,
@@ -206,12 +389,19 @@ impl Vault {
         let mut protocol_fee_shares: i128 = 0;
         let mut skip_ts_update = false;
 
+        // a fee holiday only suspends the manager's fee, the protocol fee keeps accruing
+        let management_fee = if self.has_flag(VaultFlag::ManagementFeeHoliday) {
+            0
+        } else {
+            self.management_fee
+        };
+
         let mut handle_no_protocol_fee = |vault: &mut Vault| -> Result<()> {
             let since_last = now.safe_sub(vault.last_fee_update_ts)?;
 
             // default behavior in legacy [`Vault`], manager taxes equity - 1 if tax is >= equity
             let management_fee_payment = depositor_equity
-                .safe_mul(vault.management_fee.cast()?)?
+                .safe_mul(management_fee.cast()?)?
                 .safe_div(PERCENTAGE_PRECISION_I128)?
                 .safe_mul(since_last.cast()?)?
                 .safe_div(ONE_YEAR.cast()?)?
@@ -242,22 +432,26 @@ impl Vault {
                 .saturating_add(management_fee_payment.cast()?);
 
             // in case total_shares is pushed to level that warrants a rebase
-            vault.apply_rebase(&mut None, vault_equity)?;
+            vault.apply_rebase(&mut None, vault_equity, now)?;
             Ok(())
         };
 
         match vault_protocol {
             None => {
-                if self.management_fee != 0 && depositor_equity > 0 {
+                if management_fee != 0 && depositor_equity > 0 {
                     handle_no_protocol_fee(self)?;
                 }
             }
             Some(vp) => {
-                if self.management_fee != 0 && vp.protocol_fee != 0 && depositor_equity > 0 {
+                let protocol_fee = if vp.update_protocol_fee_waiver(vault_config, vault_equity) {
+                    0
+                } else {
+                    vault_config.get_protocol_fee(vp.protocol_fee, vault_equity)
+                };
+                if management_fee != 0 && protocol_fee != 0 && depositor_equity > 0 {
                     let since_last = now.safe_sub(self.last_fee_update_ts)?;
-                    let total_fee = self
-                        .management_fee
-                        .safe_add(vp.protocol_fee.cast()?)?
+                    let total_fee = management_fee
+                        .safe_add(protocol_fee.cast()?)?
                         .cast::<i128>()?;
 
                     // if protocol fee is non-zero and total fee would lead to zero equity remaining,
@@ -268,7 +462,7 @@ impl Vault {
                         .safe_mul(since_last.cast()?)?
                         .safe_div(ONE_YEAR.cast()?)?;
                     let management_fee_payment = total_fee_payment
-                        .safe_mul(self.management_fee.cast()?)?
+                        .safe_mul(management_fee.cast()?)?
                         .safe_div(total_fee)?;
                     let protocol_fee_payment = total_fee_payment
                         .min(depositor_equity.saturating_sub(1))
@@ -333,13 +527,13 @@ impl Vault {
                         .safe_add(protocol_fee_shares.cast()?)?;
 
                     // in case total_shares is pushed to level that warrants a rebase
-                    self.apply_rebase(vault_protocol, vault_equity)?;
-                } else if self.management_fee == 0 && vp.protocol_fee != 0 && depositor_equity > 0 {
+                    self.apply_rebase(vault_protocol, vault_equity, now)?;
+                } else if management_fee == 0 && protocol_fee != 0 && depositor_equity > 0 {
                     let since_last = now.safe_sub(self.last_fee_update_ts)?;
 
                     // default behavior in legacy [`Vault`], manager taxes equity - 1 if tax is >= equity
                     let protocol_fee_payment = depositor_equity
-                        .safe_mul(vp.protocol_fee.cast()?)?
+                        .safe_mul(protocol_fee.cast()?)?
                         .safe_div(PERCENTAGE_PRECISION_I128)?
                         .safe_mul(since_last.cast()?)?
                         .safe_div(ONE_YEAR.cast()?)?
@@ -373,8 +567,8 @@ impl Vault {
                         .safe_add(protocol_fee_shares.cast()?)?;
 
                     // in case total_shares is pushed to level that warrants a rebase
-                    self.apply_rebase(vault_protocol, vault_equity)?;
-                } else if self.management_fee != 0 && vp.protocol_fee == 0 && depositor_equity > 0 {
+                    self.apply_rebase(vault_protocol, vault_equity, now)?;
+                } else if management_fee != 0 && protocol_fee == 0 && depositor_equity > 0 {
                     handle_no_protocol_fee(self)?;
                 }
             }
@@ -421,6 +615,15 @@ impl Vault {
         }
     }
 
+    /// The manager's profit share, 0 while a [`VaultFeeHoliday`](crate::state::VaultFeeHoliday) suspends it
+    pub fn manager_profit_share(&self) -> u32 {
+        if self.has_flag(VaultFlag::ProfitShareHoliday) {
+            0
+        } else {
+            self.profit_share
+        }
+    }
+
     pub fn get_profit_share(&self, vault_protocol: &Option<&VaultProtocol>) -> VaultResult<u32> {
         Ok(match vault_protocol {
             None => self.profit_share,
@@ -432,6 +635,7 @@ impl Vault {
         &mut self,
         vault_protocol: &mut Option<RefMut<VaultProtocol>>,
         vault_equity: u64,
+        now: i64,
     ) -> Result<Option<u128>> {
         let mut rebase_divisor = None;
         if vault_equity != 0 && vault_equity.cast::<u128>()? < self.total_shares {
@@ -439,6 +643,7 @@ impl Vault {
                 calculate_rebase_info(self.total_shares, vault_equity)?;
 
             if expo_diff != 0 {
+                let shares_base_before = self.shares_base;
                 self.total_shares = self.total_shares.safe_div(_rebase_divisor)?;
                 self.user_shares = self.user_shares.safe_div(_rebase_divisor)?;
                 self.shares_base = self.shares_base.safe_add(expo_diff)?;
@@ -455,6 +660,14 @@ impl Vault {
                 rebase_divisor = Some(_rebase_divisor);
 
                 msg!("rebasing vault: expo_diff={}", expo_diff);
+                emit!(VaultRebaseRecord {
+                    ts: now,
+                    vault: self.pubkey,
+                    vault_equity,
+                    shares_base_before,
+                    shares_base_after: self.shares_base,
+                    rebase_divisor: _rebase_divisor,
+                });
             }
         }
 
@@ -486,6 +699,92 @@ impl Vault {
             "vault equity negative"
         )?;
 
+        self.quote_value_to_vault_amount(vault_equity, spot_market_map, oracle_map)
+    }
+
+    /// The part of an unsettled perp loss beyond `unrealized_pnl_initial_asset_weight` that deposits don't count,
+    /// 0 for unsettled profits
+    pub fn unsettled_loss_haircut(
+        unsettled_pnl: i128,
+        unrealized_pnl_initial_asset_weight: u32,
+    ) -> VaultResult<i128> {
+        if unsettled_pnl >= 0 {
+            return Ok(0);
+        }
+
+        let excluded_weight = SPOT_WEIGHT_PRECISION
+            .saturating_sub(unrealized_pnl_initial_asset_weight)
+            .cast::<i128>()?;
+
+        Ok(unsettled_pnl
+            .unsigned_abs()
+            .cast::<i128>()?
+            .safe_mul(excluded_weight)?
+            .safe_div(SPOT_WEIGHT_PRECISION.cast()?)?)
+    }
+
+    /// The equity deposits are priced at. This is [`Vault::calculate_equity`] unless the vault's [`VaultConfig`]
+    /// is in [`DepositValuation::HaircutLosses`], then unsettled perp losses and funding only count at each market's
+    /// `unrealized_pnl_initial_asset_weight`. Withdraws always use full marks, so a deposit is never priced below
+    /// what an immediate withdraw request pays out.
+    pub fn calculate_deposit_equity(
+        &self,
+        vault_config: &VaultConfig,
+        user: &User,
+        perp_market_map: &PerpMarketMap,
+        spot_market_map: &SpotMarketMap,
+        oracle_map: &mut OracleMap,
+    ) -> VaultResult<u64> {
+        if vault_config.deposit_valuation()? == DepositValuation::FullMark {
+            return self.calculate_equity(user, perp_market_map, spot_market_map, oracle_map);
+        }
+
+        let (vault_equity, all_oracles_valid) =
+            calculate_user_equity(user, perp_market_map, spot_market_map, oracle_map)?;
+
+        validate!(
+            all_oracles_valid,
+            ErrorCode::InvalidEquityValue,
+            "oracle invalid"
+        )?;
+        validate!(
+            vault_equity >= 0,
+            ErrorCode::InvalidEquityValue,
+            "vault equity negative"
+        )?;
+
+        let mut deposit_equity = vault_equity;
+        for perp_position in user.perp_positions.iter() {
+            if perp_position.is_available() {
+                continue;
+            }
+
+            let perp_market = perp_market_map.get_ref(&perp_position.market_index)?;
+            let oracle_price = oracle_map.get_price_data(&perp_market.oracle_id())?.price;
+            let cumulative_funding_rate = if perp_position.base_asset_amount > 0 {
+                perp_market.amm.cumulative_funding_rate_long
+            } else {
+                perp_market.amm.cumulative_funding_rate_short
+            };
+
+            let unsettled_pnl = perp_position.get_unrealized_pnl(oracle_price)?.safe_add(
+                calculate_funding_payment(cumulative_funding_rate, perp_position)?.cast()?,
+            )?;
+            deposit_equity = deposit_equity.safe_add(Self::unsettled_loss_haircut(
+                unsettled_pnl,
+                perp_market.unrealized_pnl_initial_asset_weight,
+            )?)?;
+        }
+
+        self.quote_value_to_vault_amount(deposit_equity, spot_market_map, oracle_map)
+    }
+
+    fn quote_value_to_vault_amount(
+        &self,
+        quote_value: i128,
+        spot_market_map: &SpotMarketMap,
+        oracle_map: &mut OracleMap,
+    ) -> VaultResult<u64> {
         let spot_market = spot_market_map.get_ref(&self.spot_market_index)?;
         let spot_market_precision = spot_market.get_precision().cast::<i128>()?;
         let oracle_price = oracle_map
@@ -493,27 +792,135 @@ impl Vault {
             .price
             .cast::<i128>()?;
 
-        Ok(vault_equity
+        Ok(quote_value
             .safe_mul(spot_market_precision)?
             .safe_div(oracle_price)?
             .cast::<u64>()?)
     }
 
+    /// Emits the drift user's margin health, called after a withdraw leaves the drift account.
+    pub fn emit_health_record(
+        &self,
+        user: &User,
+        perp_market_map: &PerpMarketMap,
+        spot_market_map: &SpotMarketMap,
+        oracle_map: &mut OracleMap,
+        withdraw_amount: u64,
+        last_manager_activity_ts: i64,
+        now: i64,
+    ) -> Result<()> {
+        let margin_calculation =
+            calculate_margin_requirement_and_total_collateral_and_liability_info(
+                user,
+                perp_market_map,
+                spot_market_map,
+                oracle_map,
+                MarginContext::standard(MarginRequirementType::Maintenance),
+            )?;
+
+        let total_liability = margin_calculation
+            .total_spot_liability_value
+            .safe_add(margin_calculation.total_perp_liability_value)?;
+        let margin_ratio = if total_liability > 0 {
+            margin_calculation
+                .total_collateral
+                .max(0)
+                .unsigned_abs()
+                .safe_mul(PERCENTAGE_PRECISION)?
+                .safe_div(total_liability)?
+                .min(u64::MAX as u128)
+                .cast()?
+        } else {
+            u64::MAX
+        };
+
+        emit!(VaultHealthRecord {
+            ts: now,
+            vault: self.pubkey,
+            withdraw_amount,
+            total_collateral: margin_calculation.total_collateral,
+            maintenance_margin_requirement: margin_calculation.margin_requirement,
+            free_collateral: margin_calculation.get_free_collateral()?,
+            margin_ratio,
+            depositor_count: self.depositor_count,
+            active_withdraw_request_count: self.active_withdraw_request_count,
+            last_manager_activity_ts,
+        });
+
+        Ok(())
+    }
+
+    /// A depositor can only have started the ongoing liquidation if its withdraw request was past the redeem period
+    /// when the liquidation started, see [`crate::instructions::liquidate`].
+    pub fn validate_liquidation_depositor(&self, withdraw_request: &WithdrawRequest) -> Result<()> {
+        validate!(
+            self.in_liquidation(),
+            ErrorCode::InvalidLiquidationAction,
+            "vault not in liquidation"
+        )?;
+        validate!(
+            withdraw_request.pending()
+                && withdraw_request.ts.safe_add(self.redeem_period)? <= self.liquidation_start_ts,
+            ErrorCode::InvalidLiquidationAction,
+            "depositor withdraw request was not redeemable when the liquidation started"
+        )?;
+
+        Ok(())
+    }
+
+    /// Emits the drift user's risk for the liquidation delegate's `action` on behalf of the withdrawing depositor.
+    #[allow(clippy::too_many_arguments)]
+    pub fn emit_liquidation_action_record(
+        &self,
+        user: &User,
+        perp_market_map: &PerpMarketMap,
+        spot_market_map: &SpotMarketMap,
+        oracle_map: &mut OracleMap,
+        depositor_authority: Pubkey,
+        action: LiquidationAction,
+        now: i64,
+    ) -> Result<()> {
+        let margin_calculation =
+            calculate_margin_requirement_and_total_collateral_and_liability_info(
+                user,
+                perp_market_map,
+                spot_market_map,
+                oracle_map,
+                MarginContext::standard(MarginRequirementType::Maintenance),
+            )?;
+
+        emit!(LiquidationActionRecord {
+            ts: now,
+            vault: self.pubkey,
+            liquidation_delegate: self.liquidation_delegate,
+            depositor_authority,
+            action,
+            next_order_id: user.next_order_id,
+            total_collateral: margin_calculation.total_collateral,
+            maintenance_margin_requirement: margin_calculation.margin_requirement,
+            perp_liability_value: margin_calculation.total_perp_liability_value,
+            spot_liability_value: margin_calculation.total_spot_liability_value,
+        });
+
+        Ok(())
+    }
+
     pub fn manager_deposit(
         &mut self,
+        vault_config: &VaultConfig,
         vault_protocol: &mut Option<RefMut<VaultProtocol>>,
         amount: u64,
         vault_equity: u64,
         now: i64,
         deposit_oracle_price: i64,
     ) -> Result<()> {
-        self.apply_rebase(vault_protocol, vault_equity)?;
+        self.apply_rebase(vault_protocol, vault_equity, now)?;
         let VaultFee {
             management_fee_payment,
             management_fee_shares,
             protocol_fee_payment,
             protocol_fee_shares,
-        } = self.apply_fee(vault_protocol, vault_equity, now)?;
+        } = self.apply_fee(vault_config, vault_protocol, vault_equity, now)?;
 
         let user_vault_shares_before = self.user_shares;
         let total_vault_shares_before = self.total_shares;
@@ -523,6 +930,15 @@ impl Vault {
         let n_shares =
             vault_amount_to_depositor_shares(amount, total_vault_shares_before, vault_equity)?;
 
+        validate!(
+            n_shares > 0,
+            ErrorCode::DepositTooSmall,
+            "deposit amount {} mints no shares at vault equity {} and total shares {}",
+            amount,
+            vault_equity,
+            total_vault_shares_before
+        )?;
+
         self.total_deposits = self.total_deposits.saturating_add(amount);
         self.manager_total_deposits = self.manager_total_deposits.saturating_add(amount);
         self.net_deposits = self.net_deposits.safe_add(amount.cast()?)?;
@@ -581,6 +997,7 @@ impl Vault {
 
     pub fn manager_request_withdraw(
         &mut self,
+        vault_config: &VaultConfig,
         vault_protocol: &mut Option<RefMut<VaultProtocol>>,
         withdraw_amount: u64,
         withdraw_unit: WithdrawUnit,
@@ -588,13 +1005,13 @@ impl Vault {
         now: i64,
         deposit_oracle_price: i64,
     ) -> Result<()> {
-        let rebase_divisor = self.apply_rebase(vault_protocol, vault_equity)?;
+        let rebase_divisor = self.apply_rebase(vault_protocol, vault_equity, now)?;
         let VaultFee {
             management_fee_payment,
             management_fee_shares,
             protocol_fee_payment,
             protocol_fee_shares,
-        } = self.apply_fee(vault_protocol, vault_equity, now)?;
+        } = self.apply_fee(vault_config, vault_protocol, vault_equity, now)?;
 
         let vault_shares_before: u128 = self.get_manager_shares(vault_protocol)?;
         let protocol_shares_before = self.get_protocol_shares(vault_protocol);
@@ -666,12 +1083,13 @@ impl Vault {
 
     pub fn manager_cancel_withdraw_request(
         &mut self,
+        vault_config: &VaultConfig,
         vault_protocol: &mut Option<RefMut<VaultProtocol>>,
         vault_equity: u64,
         now: i64,
         deposit_oracle_price: i64,
     ) -> Result<()> {
-        self.apply_rebase(vault_protocol, vault_equity)?;
+        self.apply_rebase(vault_protocol, vault_equity, now)?;
 
         let manager_vault_shares_before: u128 = self.get_manager_shares(vault_protocol)?;
         let total_vault_shares_before = self.total_shares;
@@ -683,11 +1101,11 @@ impl Vault {
             management_fee_shares,
             protocol_fee_payment,
             protocol_fee_shares,
-        } = self.apply_fee(vault_protocol, vault_equity, now)?;
+        } = self.apply_fee(vault_config, vault_protocol, vault_equity, now)?;
 
-        let vault_shares_lost = self
-            .last_manager_withdraw_request
-            .calculate_shares_lost(self, vault_equity)?;
+        let vault_shares_lost =
+            self.last_manager_withdraw_request
+                .calculate_shares_lost(self, vault_equity, None)?;
 
         // only deduct lost shares if manager doesn't own 100% of the vault
         let manager_owns_entire_vault = total_vault_shares_before == manager_vault_shares_before;
@@ -735,6 +1153,7 @@ impl Vault {
 
     pub fn manager_withdraw(
         &mut self,
+        vault_config: &VaultConfig,
         vault_protocol: &mut Option<RefMut<VaultProtocol>>,
         vault_equity: u64,
         now: i64,
@@ -743,14 +1162,14 @@ impl Vault {
         self.last_manager_withdraw_request
             .check_redeem_period_finished(self, now)?;
 
-        self.apply_rebase(vault_protocol, vault_equity)?;
+        self.apply_rebase(vault_protocol, vault_equity, now)?;
 
         let VaultFee {
             management_fee_payment,
             management_fee_shares,
             protocol_fee_payment,
             protocol_fee_shares,
-        } = self.apply_fee(vault_protocol, vault_equity, now)?;
+        } = self.apply_fee(vault_config, vault_protocol, vault_equity, now)?;
 
         let vault_shares_before: u128 = self.get_manager_shares(vault_protocol)?;
         let total_vault_shares_before = self.total_shares;
@@ -823,6 +1242,12 @@ impl Vault {
         self.total_withdraw_requested = self
             .total_withdraw_requested
             .safe_sub(self.last_manager_withdraw_request.value)?;
+        emit!(self.last_manager_withdraw_request.payout_record(
+            self.pubkey,
+            self.manager,
+            amount,
+            now
+        ));
         self.last_manager_withdraw_request.reset(now)?;
 
         Ok(n_tokens)
@@ -854,6 +1279,7 @@ impl Vault {
 
     pub fn protocol_request_withdraw(
         &mut self,
+        vault_config: &VaultConfig,
         vault_protocol: &mut Option<RefMut<VaultProtocol>>,
         withdraw_amount: u64,
         withdraw_unit: WithdrawUnit,
@@ -869,13 +1295,13 @@ impl Vault {
             )?;
         }
 
-        let rebase_divisor = self.apply_rebase(vault_protocol, vault_equity)?;
+        let rebase_divisor = self.apply_rebase(vault_protocol, vault_equity, now)?;
         let VaultFee {
             management_fee_payment,
             management_fee_shares,
             protocol_fee_payment,
             protocol_fee_shares,
-        } = self.apply_fee(vault_protocol, vault_equity, now)?;
+        } = self.apply_fee(vault_config, vault_protocol, vault_equity, now)?;
 
         let vault_shares_before: u128 = self.get_manager_shares(vault_protocol)?;
         let protocol_shares_before = self.get_protocol_shares(vault_protocol);
@@ -952,6 +1378,7 @@ impl Vault {
 
     pub fn protocol_cancel_withdraw_request(
         &mut self,
+        vault_config: &VaultConfig,
         vault_protocol: &mut Option<RefMut<VaultProtocol>>,
         vault_equity: u64,
         now: i64,
@@ -965,7 +1392,7 @@ impl Vault {
             )?;
         }
 
-        self.apply_rebase(vault_protocol, vault_equity)?;
+        self.apply_rebase(vault_protocol, vault_equity, now)?;
 
         let manager_shares_before: u128 = self.get_manager_shares(vault_protocol)?;
         let total_vault_shares_before = self.total_shares;
@@ -977,13 +1404,14 @@ impl Vault {
             management_fee_shares,
             protocol_fee_payment,
             protocol_fee_shares,
-        } = self.apply_fee(vault_protocol, vault_equity, now)?;
+        } = self.apply_fee(vault_config, vault_protocol, vault_equity, now)?;
 
         let vault_shares_lost = match vault_protocol {
             None => 0,
-            Some(vp) => vp
-                .last_protocol_withdraw_request
-                .calculate_shares_lost(self, vault_equity)?,
+            Some(vp) => {
+                vp.last_protocol_withdraw_request
+                    .calculate_shares_lost(self, vault_equity, None)?
+            }
         };
 
         if let Some(vp) = vault_protocol {
@@ -1042,6 +1470,7 @@ impl Vault {
 
     pub fn protocol_withdraw(
         &mut self,
+        vault_config: &VaultConfig,
         vault_protocol: &mut Option<RefMut<VaultProtocol>>,
         vault_equity: u64,
         now: i64,
@@ -1060,14 +1489,14 @@ impl Vault {
                 .check_redeem_period_finished(self, now)?;
         }
 
-        self.apply_rebase(vault_protocol, vault_equity)?;
+        self.apply_rebase(vault_protocol, vault_equity, now)?;
 
         let VaultFee {
             management_fee_payment,
             management_fee_shares,
             protocol_fee_payment,
             protocol_fee_shares,
-        } = self.apply_fee(vault_protocol, vault_equity, now)?;
+        } = self.apply_fee(vault_config, vault_protocol, vault_equity, now)?;
 
         let vault_shares_before: u128 = self.get_manager_shares(vault_protocol)?;
         let total_vault_shares_before = self.total_shares;
@@ -1147,6 +1576,12 @@ impl Vault {
             self.total_withdraw_requested = self
                 .total_withdraw_requested
                 .safe_sub(vp.last_protocol_withdraw_request.value)?;
+            emit!(vp.last_protocol_withdraw_request.payout_record(
+                self.pubkey,
+                vp.protocol,
+                amount,
+                now
+            ));
             vp.last_protocol_withdraw_request.reset(now)?;
         }
 
@@ -1262,6 +1697,46 @@ impl Vault {
         );
         self.fuel_distribution_mode = mode;
     }
+
+    /// Checks the vault can move its deposits from `spot_market` to `successor_spot_market`, a different market with
+    /// the same decimals, once the drift user no longer holds the current one. Shares, deposits and withdraw requests
+    /// carry over 1:1, so the pair itself has to be approved by drift's admin, see
+    /// [`migrate_vault_deposit_mint`](crate::instructions::migrate_vault_deposit_mint).
+    pub fn validate_deposit_mint_migration(
+        &self,
+        spot_market: &SpotMarket,
+        successor_spot_market: &SpotMarket,
+        user: &User,
+    ) -> VaultResult {
+        validate!(!self.in_liquidation(), ErrorCode::OngoingLiquidation)?;
+
+        validate!(
+            spot_market.market_index == self.spot_market_index,
+            ErrorCode::InvalidDepositMintMigration,
+            "drift_spot_market is not the vault's spot market"
+        )?;
+        validate!(
+            successor_spot_market.market_index != self.spot_market_index
+                && successor_spot_market.decimals == spot_market.decimals,
+            ErrorCode::InvalidDepositMintMigration,
+            "successor spot market {} must be a different market with {} decimals",
+            successor_spot_market.market_index,
+            spot_market.decimals
+        )?;
+
+        validate!(
+            !user
+                .spot_positions
+                .iter()
+                .any(|position| position.market_index == self.spot_market_index
+                    && (position.scaled_balance != 0 || position.open_orders != 0)),
+            ErrorCode::InvalidDepositMintMigration,
+            "drift user still holds spot market {}, swap it to the successor first",
+            self.spot_market_index
+        )?;
+
+        Ok(())
+    }
 }
 
 #[derive(Clone, Copy, BorshSerialize, BorshDeserialize, PartialEq, Debug, Eq)]
@@ -1283,6 +1758,45 @@ impl TryFrom<u8> for FuelDistributionMode {
     }
 }
 
+#[derive(Clone, Copy, BorshSerialize, BorshDeserialize, PartialEq, Debug, Eq)]
+#[repr(u8)]
+pub enum ProfitShareMode {
+    /// Profit share is charged on gains above the depositor's high water mark, net deposits plus the gains already
+    /// profit shared
+    HighWaterMark = 0,
+    /// Losses are taken from gains already profit shared first: once a depositor's value falls below their net
+    /// deposits their `cumulative_profit_share_amount` resets and the high water mark is their net deposits again
+    LossesFirst = 1,
+    /// The high water mark rises to every value the depositor's profit share is calculated at, so gains left
+    /// uncharged by the [`FeePolicy`](crate::state::FeePolicy), e.g. within a hurdle, are never charged once lost and
+    /// recovered. Profit share is only charged on gains above the depositor's peak
+    PeakEquity = 2,
+}
+
+impl TryFrom<u8> for ProfitShareMode {
+    type Error = ErrorCode;
+
+    fn try_from(value: u8) -> std::result::Result<Self, ErrorCode> {
+        match value {
+            0 => Ok(ProfitShareMode::HighWaterMark),
+            1 => Ok(ProfitShareMode::LossesFirst),
+            2 => Ok(ProfitShareMode::PeakEquity),
+            _ => Err(ErrorCode::InvalidVaultUpdate),
+        }
+    }
+}
+
+impl ProfitShareMode {
+    /// Higher charges more of the same gains, like [`FeePolicy::fee_rank`](crate::state::FeePolicy::fee_rank)
+    pub fn fee_rank(&self) -> u8 {
+        match self {
+            ProfitShareMode::PeakEquity => 0,
+            ProfitShareMode::HighWaterMark => 1,
+            ProfitShareMode::LossesFirst => 2,
+        }
+    }
+}
+
 impl FuelDistributionMode {
     pub fn is_users_only(mode: u8) -> bool {
         mode & FuelDistributionMode::UsersOnly as u8 != 0
@@ -1374,6 +1888,81 @@ mod vault_fuel_tests {
         assert_eq!(vault.last_cumulative_fuel_per_share_ts, 3);
     }
 
+    #[test]
+    fn test_update_cumulative_fuel_per_share_overflow_rollover() {
+        let mut vault = Vault {
+            user_shares: 1_000_000,
+            ..Vault::default()
+        };
+        let user_stats = UserStats {
+            fuel_deposits: 100_000,
+            ..UserStats::default()
+        };
+
+        // season 1: 100k in UserStats + 300k swept into FuelOverflow
+        vault
+            .update_cumulative_fuel_per_share_with_overflow(10, &user_stats, 300_000, 0)
+            .unwrap();
+        assert_eq!(
+            vault.cumulative_fuel_per_share,
+            FUEL_SHARE_PRECISION * 2 / 5
+        );
+        assert_eq!(vault.cumulative_fuel, 400_000);
+        assert_eq!(vault.fuel_season_start_ts, 0);
+
+        // FuelOverflow reset at 20, 50k accrued in the new season
+        let user_stats = UserStats {
+            fuel_deposits: 50_000,
+            ..UserStats::default()
+        };
+        vault
+            .update_cumulative_fuel_per_share_with_overflow(30, &user_stats, 0, 20)
+            .unwrap();
+        assert_eq!(vault.cumulative_fuel_per_share, FUEL_SHARE_PRECISION / 20); // 50e3/1e6
+        assert_eq!(vault.cumulative_fuel, 50_000);
+        assert_eq!(vault.fuel_season_start_ts, 20);
+        assert_eq!(vault.last_cumulative_fuel_per_share_ts, 30);
+
+        // same season, user shares double and 150k more fuel is split between UserStats and FuelOverflow
+        vault.user_shares = 2_000_000;
+        let user_stats = UserStats {
+            fuel_deposits: 150_000,
+            ..UserStats::default()
+        };
+        vault
+            .update_cumulative_fuel_per_share_with_overflow(40, &user_stats, 50_000, 20)
+            .unwrap();
+        assert_eq!(vault.cumulative_fuel_per_share, FUEL_SHARE_PRECISION / 8); // 50e3/1e6 + 150e3/2e6
+        assert_eq!(vault.cumulative_fuel, 200_000);
+        assert_eq!(vault.fuel_season_start_ts, 20);
+    }
+
+    #[test]
+    fn test_update_cumulative_fuel_per_share_ignores_settled_overflow_reset() {
+        // vault already accrued past the FuelOverflow reset before tracking season boundaries
+        let mut vault = Vault {
+            user_shares: 1_000_000,
+            last_cumulative_fuel_per_share_ts: 50,
+            cumulative_fuel_per_share: FUEL_SHARE_PRECISION / 10,
+            cumulative_fuel: 100_000,
+            ..Vault::default()
+        };
+        let user_stats = UserStats {
+            fuel_deposits: 150_000,
+            ..UserStats::default()
+        };
+
+        vault
+            .update_cumulative_fuel_per_share_with_overflow(60, &user_stats, 0, 20)
+            .unwrap();
+        assert_eq!(
+            vault.cumulative_fuel_per_share,
+            FUEL_SHARE_PRECISION * 3 / 20
+        );
+        assert_eq!(vault.cumulative_fuel, 150_000);
+        assert_eq!(vault.fuel_season_start_ts, 0);
+    }
+
     #[test]
     fn test_fuel_updates_with_larger_user_shares() {
         let test_cases: [u128; 8] = [
@@ -1409,4 +1998,69 @@ mod vault_fuel_tests {
             assert_eq!(vault.last_cumulative_fuel_per_share_ts, 1);
         }
     }
+
+    #[test]
+    fn test_validate_deposit_mint_migration() {
+        let mut vault = Vault::default();
+        let spot_market = SpotMarket {
+            market_index: 0,
+            decimals: 6,
+            ..SpotMarket::default()
+        };
+        let successor_spot_market = SpotMarket {
+            market_index: 1,
+            decimals: 6,
+            ..SpotMarket::default()
+        };
+        let mut user = User::default();
+
+        vault
+            .validate_deposit_mint_migration(&spot_market, &successor_spot_market, &user)
+            .unwrap();
+
+        // the successor must be a different market
+        assert!(vault
+            .validate_deposit_mint_migration(&spot_market, &spot_market, &user)
+            .is_err());
+
+        // with the same decimals
+        let other_decimals = SpotMarket {
+            decimals: 9,
+            ..successor_spot_market
+        };
+        assert!(vault
+            .validate_deposit_mint_migration(&spot_market, &other_decimals, &user)
+            .is_err());
+
+        // and migrate from the vault's own spot market
+        assert!(vault
+            .validate_deposit_mint_migration(&successor_spot_market, &spot_market, &user)
+            .is_err());
+
+        // once the drift user no longer holds it
+        user.spot_positions[0].scaled_balance = 1;
+        assert_eq!(
+            vault.validate_deposit_mint_migration(&spot_market, &successor_spot_market, &user),
+            Err(ErrorCode::InvalidDepositMintMigration)
+        );
+        user.spot_positions[0].scaled_balance = 0;
+
+        vault.liquidation_delegate = Pubkey::new_unique();
+        assert_eq!(
+            vault.validate_deposit_mint_migration(&spot_market, &successor_spot_market, &user),
+            Err(ErrorCode::OngoingLiquidation)
+        );
+    }
+
+    #[test]
+    fn test_state_hash() {
+        let mut vault = Vault::default();
+        let state_hash = vault.state_hash();
+        assert_eq!(vault.state_hash(), state_hash);
+
+        vault.total_withdraw_requested = 1;
+        assert_ne!(vault.state_hash(), state_hash);
+        vault.total_withdraw_requested = 0;
+        assert_eq!(vault.state_hash(), state_hash);
+    }
 }
@@ -0,0 +1,185 @@
+use anchor_lang::prelude::*;
+use borsh::{BorshDeserialize, BorshSerialize};
+use drift::math::safe_math::SafeMath;
+use drift_macros::assert_no_slop;
+use static_assertions::const_assert_eq;
+
+use crate::error::ErrorCode;
+use crate::state::{Size, Vault, VaultProtocol};
+use crate::validate;
+
+/// A read only key the manager hands to a fund administrator or auditor. The auditor can run the manager gated
+/// exports, like opening a [`VaultShareSnapshot`](crate::state::VaultShareSnapshot) to enumerate depositors, but
+/// nothing that changes the vault, its funds or its params. `Pubkey::default()` revokes it.
+#[assert_no_slop]
+#[account(zero_copy(unsafe))]
+#[derive(Default, Eq, PartialEq, Debug)]
+#[repr(C)]
+pub struct VaultAuditor {
+    /// The vault the auditor is for
+    pub vault: Pubkey,
+    pub auditor: Pubkey,
+    pub bump: u8,
+    pub padding: [u8; 7],
+}
+
+impl Size for VaultAuditor {
+    const SIZE: usize = 72 + 8;
+}
+const_assert_eq!(VaultAuditor::SIZE, std::mem::size_of::<VaultAuditor>() + 8);
+
+impl VaultAuditor {
+    pub fn get_address(vault: &Pubkey) -> Pubkey {
+        Pubkey::find_program_address(&[b"vault_auditor", vault.as_ref()], &crate::id()).0
+    }
+
+    pub fn is_auditor(&self, vault: &Vault, signer: &Pubkey) -> bool {
+        self.vault == vault.pubkey && self.auditor != Pubkey::default() && self.auditor == *signer
+    }
+}
+
+/// For manager gated read only instructions, the vault's [`VaultAuditor`] must be passed for its auditor to sign.
+pub fn validate_manager_or_auditor(
+    vault: &Vault,
+    vault_auditor: Option<&VaultAuditor>,
+    signer: &Pubkey,
+) -> Result<()> {
+    validate!(
+        vault.manager == *signer
+            || vault_auditor.map_or(false, |vault_auditor| vault_auditor
+                .is_auditor(vault, signer)),
+        ErrorCode::InvalidVaultAuditor,
+        "{} is neither the manager nor the auditor of the vault",
+        signer
+    )?;
+
+    Ok(())
+}
+
+/// The vault's fee and manager/protocol accounting in one place, see
+/// [`view_vault_fee_ledger`](crate::instructions::view_vault_fee_ledger). Amounts are in the vault's spot market
+/// precision and lifetime totals unless noted.
+#[derive(Clone, Copy, BorshSerialize, BorshDeserialize, PartialEq, Debug, Eq, Default)]
+pub struct VaultFeeLedger {
+    pub vault: Pubkey,
+    pub spot_market_index: u16,
+    /// management fee accrued by the manager
+    pub manager_total_fee: i64,
+    /// profit share accrued by the manager
+    pub manager_total_profit_share: u64,
+    pub manager_net_deposits: i64,
+    pub manager_total_withdraws: u64,
+    /// shares held by the manager, its deposits plus fees not yet withdrawn
+    pub manager_shares: u128,
+    /// management fee accrued by the protocol, 0 without a [`VaultProtocol`]
+    pub protocol_total_fee: u64,
+    /// profit share accrued by the protocol
+    pub protocol_total_profit_share: u64,
+    pub protocol_total_withdraws: u64,
+    /// shares held by the protocol, fees not yet withdrawn
+    pub protocol_shares: u128,
+    /// shares held by depositors
+    pub user_shares: u128,
+    pub total_shares: u128,
+    /// ts the management fee was last charged
+    pub last_fee_update_ts: i64,
+}
+
+impl VaultFeeLedger {
+    pub fn new(vault: &Vault, vault_protocol: Option<&VaultProtocol>) -> Result<Self> {
+        let protocol_shares = vault_protocol.map_or(0, |vp| vp.protocol_profit_and_fee_shares);
+
+        Ok(VaultFeeLedger {
+            vault: vault.pubkey,
+            spot_market_index: vault.spot_market_index,
+            manager_total_fee: vault.manager_total_fee,
+            manager_total_profit_share: vault.manager_total_profit_share,
+            manager_net_deposits: vault.manager_net_deposits,
+            manager_total_withdraws: vault.manager_total_withdraws,
+            manager_shares: vault
+                .total_shares
+                .safe_sub(vault.user_shares)?
+                .safe_sub(protocol_shares)?,
+            protocol_total_fee: vault_protocol.map_or(0, |vp| vp.protocol_total_fee),
+            protocol_total_profit_share: vault_protocol
+                .map_or(0, |vp| vp.protocol_total_profit_share),
+            protocol_total_withdraws: vault_protocol.map_or(0, |vp| vp.protocol_total_withdraws),
+            protocol_shares,
+            user_shares: vault.user_shares,
+            total_shares: vault.total_shares,
+            last_fee_update_ts: vault.last_fee_update_ts,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anchor_lang::prelude::Pubkey;
+
+    use crate::state::{
+        validate_manager_or_auditor, Vault, VaultAuditor, VaultFeeLedger, VaultProtocol,
+    };
+
+    #[test]
+    fn test_validate_manager_or_auditor() {
+        let manager = Pubkey::new_unique();
+        let auditor = Pubkey::new_unique();
+        let vault = Vault {
+            pubkey: Pubkey::new_unique(),
+            manager,
+            ..Vault::default()
+        };
+
+        validate_manager_or_auditor(&vault, None, &manager).unwrap();
+        assert!(validate_manager_or_auditor(&vault, None, &auditor).is_err());
+
+        let mut vault_auditor = VaultAuditor {
+            vault: vault.pubkey,
+            auditor,
+            ..VaultAuditor::default()
+        };
+        validate_manager_or_auditor(&vault, Some(&vault_auditor), &auditor).unwrap();
+        validate_manager_or_auditor(&vault, Some(&vault_auditor), &manager).unwrap();
+
+        // another vault's auditor
+        let other_vault = Vault {
+            pubkey: Pubkey::new_unique(),
+            manager,
+            ..Vault::default()
+        };
+        assert!(validate_manager_or_auditor(&other_vault, Some(&vault_auditor), &auditor).is_err());
+
+        // revoked
+        vault_auditor.auditor = Pubkey::default();
+        assert!(validate_manager_or_auditor(&vault, Some(&vault_auditor), &auditor).is_err());
+        assert!(
+            validate_manager_or_auditor(&vault, Some(&vault_auditor), &Pubkey::default()).is_err()
+        );
+    }
+
+    #[test]
+    fn test_fee_ledger() {
+        let vault = Vault {
+            user_shares: 800,
+            total_shares: 1_000,
+            manager_total_fee: 30,
+            manager_total_profit_share: 20,
+            ..Vault::default()
+        };
+
+        let ledger = VaultFeeLedger::new(&vault, None).unwrap();
+        assert_eq!(ledger.manager_shares, 200);
+        assert_eq!(ledger.protocol_shares, 0);
+
+        let vault_protocol = VaultProtocol {
+            protocol_profit_and_fee_shares: 50,
+            protocol_total_fee: 5,
+            ..VaultProtocol::default()
+        };
+        let ledger = VaultFeeLedger::new(&vault, Some(&vault_protocol)).unwrap();
+        assert_eq!(ledger.manager_shares, 150);
+        assert_eq!(ledger.protocol_shares, 50);
+        assert_eq!(ledger.protocol_total_fee, 5);
+        assert_eq!(ledger.manager_total_fee, 30);
+    }
+}
@@ -0,0 +1,344 @@
+use std::cell::RefMut;
+
+use anchor_lang::prelude::*;
+use borsh::{BorshDeserialize, BorshSerialize};
+use drift::math::casting::Cast;
+use drift::math::constants::{PERCENTAGE_PRECISION, PRICE_PRECISION};
+use drift::math::insurance::{
+    if_shares_to_vault_amount as depositor_shares_to_vault_amount,
+    vault_amount_to_if_shares as vault_amount_to_depositor_shares,
+};
+use drift::math::safe_math::SafeMath;
+use drift_macros::assert_no_slop;
+use static_assertions::const_assert_eq;
+
+use crate::error::ErrorCode;
+use crate::state::events::BackstopRecord;
+use crate::state::{calculate_share_price, Size, Vault, VaultConfig, VaultProtocol};
+use crate::validate;
+
+#[derive(Clone, Copy, BorshSerialize, BorshDeserialize, PartialEq, Debug, Eq, Default)]
+pub enum BackstopAction {
+    /// manager fees moved into the pool
+    #[default]
+    Contribute,
+    /// the pool topped up vault equity after a loss
+    Claim,
+}
+
+/// Insurance pool funded by a slice of the manager's fees that absorbs losses before depositors do. Contributions
+/// are paid out of the manager's shares into the backstop token account, up to a `max_balance` set by the vault's
+/// protocol. When the share price falls more than `deductible` below its high water mark, e.g. after bad debt or a
+/// loss big enough to rebase the vault, the pool deposits into the vault without shares to bring it back up.
+#[assert_no_slop]
+#[account(zero_copy(unsafe))]
+#[derive(Default, Eq, PartialEq, Debug)]
+#[repr(C)]
+pub struct VaultBackstop {
+    /// The vault the pool backs
+    pub vault: Pubkey,
+    /// `manager_total_fee` plus `manager_total_profit_share` contributions have accrued up to
+    pub last_manager_fees: u64,
+    /// Accrued and not yet moved into the pool, precision: token mint
+    pub owed: u64,
+    /// Tokens in the backstop token account, precision: token mint
+    pub balance: u64,
+    /// Max balance, set by the vault's protocol. Contributions accrued beyond it stay with the manager
+    pub max_balance: u64,
+    pub total_contributed: u64,
+    pub total_claimed: u64,
+    /// Highest share price seen by the pool's cranks: PRICE_PRECISION. 0 until the first claim crank
+    pub high_water_mark: u128,
+    /// Slice of manager fees contributed, precision: PERCENTAGE_PRECISION
+    pub contribution_rate: u32,
+    /// Drop from the high water mark depositors absorb before the pool tops up, precision: PERCENTAGE_PRECISION
+    pub deductible: u32,
+    /// The vault's shares_base when high_water_mark was set
+    pub shares_base: u32,
+    pub bump: u8,
+    pub padding: [u8; 3],
+}
+
+impl Size for VaultBackstop {
+    const SIZE: usize = 112 + 8;
+}
+const_assert_eq!(
+    VaultBackstop::SIZE,
+    std::mem::size_of::<VaultBackstop>() + 8
+);
+
+fn manager_fees(vault: &Vault) -> Result<u64> {
+    Ok(vault
+        .manager_total_fee
+        .max(0)
+        .cast::<u64>()?
+        .saturating_add(vault.manager_total_profit_share))
+}
+
+impl VaultBackstop {
+    /// Accrues contributions on the fees collected since the last accrual, then sets the new terms
+    pub fn update(&mut self, vault: &Vault, contribution_rate: u32, deductible: u32) -> Result<()> {
+        validate!(
+            contribution_rate.cast::<u128>()? <= PERCENTAGE_PRECISION
+                && deductible.cast::<u128>()? < PERCENTAGE_PRECISION,
+            ErrorCode::InvalidVaultBackstop,
+            "contribution rate above 100% or deductible not below 100%"
+        )?;
+
+        self.accrue(vault)?;
+        self.contribution_rate = contribution_rate;
+        self.deductible = deductible;
+
+        Ok(())
+    }
+
+    pub fn update_max_balance(&mut self, vault: &Vault, max_balance: u64) -> Result<()> {
+        self.accrue(vault)?;
+        self.max_balance = max_balance;
+        self.owed = self.owed.min(self.room()?);
+
+        Ok(())
+    }
+
+    fn room(&self) -> Result<u64> {
+        Ok(self.max_balance.saturating_sub(self.balance))
+    }
+
+    pub fn accrue(&mut self, vault: &Vault) -> Result<u64> {
+        let manager_fees = manager_fees(vault)?;
+        let accrued = manager_fees
+            .safe_sub(self.last_manager_fees)?
+            .cast::<u128>()?
+            .safe_mul(self.contribution_rate.cast()?)?
+            .safe_div(PERCENTAGE_PRECISION)?
+            .cast::<u64>()?;
+
+        self.owed = self.owed.safe_add(accrued)?.min(self.room()?);
+        self.last_manager_fees = manager_fees;
+
+        Ok(self.owed)
+    }
+
+    /// Burns manager shares worth what is owed, capped at the shares not already requested for withdraw by the
+    /// manager, and returns the token amount to move into the pool. Fees are applied first so the burn comes out of
+    /// the manager's current share count.
+    pub fn contribute(
+        &mut self,
+        vault: &mut Vault,
+        vault_config: &VaultConfig,
+        vault_protocol: &mut Option<RefMut<VaultProtocol>>,
+        vault_equity: u64,
+        now: i64,
+    ) -> Result<u64> {
+        validate!(
+            self.vault == vault.pubkey,
+            ErrorCode::InvalidVaultBackstop,
+            "VaultBackstop is not for this vault"
+        )?;
+
+        vault.apply_rebase(vault_protocol, vault_equity, now)?;
+        vault.apply_fee(vault_config, vault_protocol, vault_equity, now)?;
+
+        let owed = self.accrue(vault)?;
+
+        let available_shares = vault
+            .get_manager_shares(vault_protocol)?
+            .saturating_sub(vault.last_manager_withdraw_request.shares);
+        let shares = vault_amount_to_depositor_shares(owed, vault.total_shares, vault_equity)?
+            .min(available_shares);
+        let amount =
+            depositor_shares_to_vault_amount(shares, vault.total_shares, vault_equity)?.min(owed);
+
+        if amount == 0 {
+            return Ok(0);
+        }
+
+        vault.total_shares = vault.total_shares.safe_sub(shares)?;
+        vault.total_withdraws = vault.total_withdraws.saturating_add(amount);
+        vault.manager_total_withdraws = vault.manager_total_withdraws.saturating_add(amount);
+        vault.net_deposits = vault.net_deposits.safe_sub(amount.cast()?)?;
+        vault.manager_net_deposits = vault.manager_net_deposits.safe_sub(amount.cast()?)?;
+
+        self.owed = owed.safe_sub(amount)?;
+        self.balance = self.balance.safe_add(amount)?;
+        self.total_contributed = self.total_contributed.safe_add(amount)?;
+
+        let share_price =
+            calculate_share_price(vault_equity.safe_sub(amount)?, vault.total_shares)?;
+        self.emit_record(
+            vault,
+            BackstopAction::Contribute,
+            amount,
+            shares,
+            share_price,
+            now,
+        );
+
+        Ok(amount)
+    }
+
+    /// Returns the amount to deposit into the vault to bring the share price back up to `deductible` below the high
+    /// water mark, at most the pool's balance. Share prices above the high water mark raise it.
+    pub fn claim(&mut self, vault: &Vault, vault_equity: u64, now: i64) -> Result<u64> {
+        validate!(
+            self.vault == vault.pubkey,
+            ErrorCode::InvalidVaultBackstop,
+            "VaultBackstop is not for this vault"
+        )?;
+
+        // a rebase divides shares by 10^expo_diff, so share prices are 10^expo_diff larger after it
+        if vault.shares_base != self.shares_base {
+            let expo_diff = vault.shares_base.safe_sub(self.shares_base)?;
+            self.high_water_mark = self.high_water_mark.safe_mul(10_u128.pow(expo_diff))?;
+            self.shares_base = vault.shares_base;
+        }
+
+        let share_price = calculate_share_price(vault_equity, vault.total_shares)?;
+        if share_price >= self.high_water_mark || vault.total_shares == 0 {
+            self.high_water_mark = self.high_water_mark.max(share_price);
+            return Ok(0);
+        }
+
+        let floor = self
+            .high_water_mark
+            .safe_mul(PERCENTAGE_PRECISION.safe_sub(self.deductible.cast()?)?)?
+            .safe_div(PERCENTAGE_PRECISION)?;
+        if share_price >= floor {
+            return Ok(0);
+        }
+
+        let amount = floor
+            .safe_sub(share_price)?
+            .safe_mul(vault.total_shares)?
+            .safe_div(PRICE_PRECISION)?
+            .min(self.balance.cast()?)
+            .cast::<u64>()?;
+
+        if amount == 0 {
+            return Ok(0);
+        }
+
+        self.balance = self.balance.safe_sub(amount)?;
+        self.total_claimed = self.total_claimed.safe_add(amount)?;
+        self.owed = self.owed.min(self.room()?);
+
+        self.emit_record(vault, BackstopAction::Claim, amount, 0, share_price, now);
+
+        Ok(amount)
+    }
+
+    fn emit_record(
+        &self,
+        vault: &Vault,
+        action: BackstopAction,
+        amount: u64,
+        shares: u128,
+        share_price: u128,
+        now: i64,
+    ) {
+        emit!(BackstopRecord {
+            ts: now,
+            vault: vault.pubkey,
+            action,
+            amount,
+            shares,
+            share_price,
+            high_water_mark: self.high_water_mark,
+            balance_after: self.balance,
+            owed_after: self.owed,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anchor_lang::prelude::Pubkey;
+    use drift::math::constants::{PRICE_PRECISION, QUOTE_PRECISION_U64};
+
+    use crate::state::{Vault, VaultBackstop, VaultConfig};
+
+    #[test]
+    fn test_backstop_contribute_and_claim() {
+        let mut vault = Vault {
+            pubkey: Pubkey::new_unique(),
+            total_shares: 1_000 * QUOTE_PRECISION_U64 as u128,
+            user_shares: 900 * QUOTE_PRECISION_U64 as u128,
+            manager_total_profit_share: 50 * QUOTE_PRECISION_U64,
+            ..Vault::default()
+        };
+        let mut vault_equity = 1_000 * QUOTE_PRECISION_U64;
+
+        let mut backstop = VaultBackstop {
+            vault: vault.pubkey,
+            ..VaultBackstop::default()
+        };
+        assert!(backstop.update(&vault, 100_000, 1_000_000).is_err());
+        backstop.update(&vault, 100_000, 100_000).unwrap(); // 10% of fees, 10% deductible
+        backstop
+            .update_max_balance(&vault, 15 * QUOTE_PRECISION_U64)
+            .unwrap();
+        // only fees collected after the pool was configured
+        assert_eq!(backstop.owed, 0);
+
+        // 10% of 200, capped at the max balance
+        vault.manager_total_profit_share += 200 * QUOTE_PRECISION_U64;
+        let amount = backstop
+            .contribute(
+                &mut vault,
+                &VaultConfig::default(),
+                &mut None,
+                vault_equity,
+                0,
+            )
+            .unwrap();
+        assert_eq!(amount, 15 * QUOTE_PRECISION_U64);
+        assert_eq!(backstop.balance, 15 * QUOTE_PRECISION_U64);
+        assert_eq!(backstop.owed, 0);
+        assert_eq!(vault.total_shares, 985 * QUOTE_PRECISION_U64 as u128);
+        vault_equity -= amount;
+
+        // first crank sets the high water mark
+        assert_eq!(backstop.claim(&vault, vault_equity, 0).unwrap(), 0);
+        assert_eq!(backstop.high_water_mark, PRICE_PRECISION);
+
+        // within the deductible
+        assert_eq!(
+            backstop
+                .claim(&vault, 900 * QUOTE_PRECISION_U64, 0)
+                .unwrap(),
+            0
+        );
+
+        // 12% down: 2% of 985 shares is 19.7 to top up, capped at the balance
+        let amount = backstop.claim(&vault, 866_800_000, 0).unwrap();
+        assert_eq!(amount, 15 * QUOTE_PRECISION_U64);
+        assert_eq!(backstop.balance, 0);
+        assert_eq!(backstop.total_claimed, 15 * QUOTE_PRECISION_U64);
+
+        // the room freed by the claim can be refilled
+        vault.manager_total_profit_share += 100 * QUOTE_PRECISION_U64;
+        assert_eq!(backstop.accrue(&vault).unwrap(), 10 * QUOTE_PRECISION_U64);
+    }
+
+    #[test]
+    fn test_backstop_claim_partial() {
+        let vault = Vault {
+            pubkey: Pubkey::new_unique(),
+            total_shares: 100 * QUOTE_PRECISION_U64 as u128,
+            user_shares: 100 * QUOTE_PRECISION_U64 as u128,
+            ..Vault::default()
+        };
+        let mut backstop = VaultBackstop {
+            vault: vault.pubkey,
+            balance: 10 * QUOTE_PRECISION_U64,
+            high_water_mark: PRICE_PRECISION,
+            ..VaultBackstop::default()
+        };
+
+        // no deductible, 5% down tops up 5
+        let amount = backstop.claim(&vault, 95 * QUOTE_PRECISION_U64, 0).unwrap();
+        assert_eq!(amount, 5 * QUOTE_PRECISION_U64);
+        assert_eq!(backstop.balance, 5 * QUOTE_PRECISION_U64);
+        assert_eq!(backstop.high_water_mark, PRICE_PRECISION);
+    }
+}
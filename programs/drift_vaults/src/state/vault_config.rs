@@ -0,0 +1,407 @@
+use anchor_lang::prelude::*;
+use borsh::{BorshDeserialize, BorshSerialize};
+use bytemuck::Zeroable;
+use drift::math::casting::Cast;
+use drift::math::constants::PERCENTAGE_PRECISION;
+use drift::math::safe_math::SafeMath;
+use drift_macros::assert_no_slop;
+use static_assertions::const_assert_eq;
+
+use crate::constants::FUEL_BOOST_RAMP_PERIOD;
+use crate::error::{ErrorCode, VaultResult};
+use crate::state::{FeePolicy, ProfitShareMode, Size, Vault};
+use crate::validate;
+
+/// Per vault configuration that doesn't fit in [`Vault`], created by the manager with `initialize_vault_config`.
+/// Every field defaults to the behavior of vaults without one, so instructions on a vault without
+/// [`VaultFlag::HasVaultConfig`](crate::state::VaultFlag::HasVaultConfig) use [`VaultConfig::default`], see
+/// [`VaultConfigProvider`](crate::state::VaultConfigProvider).
+#[assert_no_slop]
+#[account(zero_copy(unsafe))]
+#[derive(Default, Eq, PartialEq, Debug)]
+#[repr(C)]
+pub struct VaultConfig {
+    /// The vault the config belongs to
+    pub vault: Pubkey,
+    pub bump: u8,
+    /// The [`ProfitShareMode`]. Default is `HighWaterMark`
+    pub profit_share_mode: u8,
+    /// The [`FeePolicy`], see [`VaultConfig::fee_policy`]
+    pub fee_policy: u8,
+    /// Bits of [`VaultConfigFlag`]
+    pub flags: u8,
+    /// The [`DepositValuation`] deposits are priced at. Default is `FullMark`
+    pub deposit_valuation: u8,
+    /// Max extra fuel for long term depositors, in steps of [`FUEL_BOOST_STEP`], see
+    /// [`VaultConfig::get_fuel_boost_multiplier`]
+    pub fuel_boost: u8,
+    /// The [`ProfitShareRealization`]. Default is `Unrealized`
+    pub profit_share_realization: u8,
+    pub padding1: [u8; 1],
+    /// Protocol fee schedule that steps down as vault equity grows, see [`ProtocolFeeTier`].
+    /// Tiers are sorted by ascending `tvl_threshold`, unused tiers are zeroed. Set by the protocol with
+    /// `update_vault_protocol_fee_tiers`
+    pub protocol_fee_tiers: [ProtocolFeeTier; 4],
+    /// Vault equity (in vault spot_market_index) below which the protocol fee is waived, 0 to never waive
+    pub fee_waiver_tvl_threshold: u64,
+    /// Vault equity a waived vault must grow back to before the protocol fee is charged again, at least
+    /// `fee_waiver_tvl_threshold` so vaults hovering around it don't flap in and out of the waiver
+    pub fee_waiver_exit_tvl_threshold: u64,
+    pub padding: [u64; 4],
+}
+
+impl Size for VaultConfig {
+    const SIZE: usize = 152 + 8;
+}
+const_assert_eq!(VaultConfig::SIZE, std::mem::size_of::<VaultConfig>() + 8);
+
+#[assert_no_slop]
+#[derive(
+    Default, AnchorSerialize, AnchorDeserialize, Copy, Clone, Eq, PartialEq, Debug, Zeroable,
+)]
+pub struct ProtocolFeeTier {
+    /// Vault equity (in vault spot_market_index) at or above which this tier's fee applies
+    pub tvl_threshold: u64,
+    /// The annual protocol fee charged while this tier applies: PERCENTAGE_PRECISION
+    pub protocol_fee: u64,
+}
+
+impl ProtocolFeeTier {
+    pub fn is_active(&self) -> bool {
+        self.tvl_threshold != 0
+    }
+}
+
+/// The equity deposits are priced at, see [`Vault::calculate_deposit_equity`]. Withdraws always use full marks.
+#[derive(Clone, Copy, BorshSerialize, BorshDeserialize, PartialEq, Debug, Eq)]
+#[repr(u8)]
+pub enum DepositValuation {
+    /// Deposits are priced at [`Vault::calculate_equity`]
+    FullMark = 0,
+    /// Unsettled perp losses and funding only count at each market's `unrealized_pnl_initial_asset_weight`, so
+    /// deposits can't buy in cheap against a loss the vault hasn't settled yet
+    HaircutLosses = 1,
+}
+
+impl TryFrom<u8> for DepositValuation {
+    type Error = ErrorCode;
+
+    fn try_from(value: u8) -> std::result::Result<Self, ErrorCode> {
+        match value {
+            0 => Ok(DepositValuation::FullMark),
+            1 => Ok(DepositValuation::HaircutLosses),
+            _ => Err(ErrorCode::InvalidVaultUpdate),
+        }
+    }
+}
+
+/// Bits of [`VaultConfig`] `flags`
+#[derive(Clone, Copy, PartialEq, Debug, Eq)]
+pub enum VaultConfigFlag {
+    /// Deposits and withdraw requests are rate limited by the vault's
+    /// [`VaultDepositorThrottle`](crate::state::VaultDepositorThrottle)
+    DepositorThrottle = 0b00000001,
+    /// Deposits and withdraws must pass the vault's [`VaultGuard`](crate::state::VaultGuard)
+    VaultGuard = 0b00000010,
+}
+
+/// When depositors' gains are profit shared
+#[derive(Clone, Copy, BorshSerialize, BorshDeserialize, PartialEq, Debug, Eq)]
+#[repr(u8)]
+pub enum ProfitShareRealization {
+    /// Unrealized gains are profit shared by `apply_profit_share` cranks, deposits and on the whole position at
+    /// `request_withdraw`
+    Unrealized = 0,
+    /// Profit share is only charged on the shares leaving at `request_withdraw`, "no fee until you exit"
+    Realized = 1,
+}
+
+impl TryFrom<u8> for ProfitShareRealization {
+    type Error = ErrorCode;
+
+    fn try_from(value: u8) -> std::result::Result<Self, ErrorCode> {
+        match value {
+            0 => Ok(ProfitShareRealization::Unrealized),
+            1 => Ok(ProfitShareRealization::Realized),
+            _ => Err(ErrorCode::InvalidVaultUpdate),
+        }
+    }
+}
+
+impl ProfitShareRealization {
+    /// Higher charges the same gains earlier, like [`FeePolicy::fee_rank`]
+    pub fn fee_rank(&self) -> u8 {
+        match self {
+            ProfitShareRealization::Realized => 0,
+            ProfitShareRealization::Unrealized => 1,
+        }
+    }
+}
+
+/// Each step of [`VaultConfig::fuel_boost`] adds 10% fuel at the full holding period: PERCENTAGE_PRECISION
+pub const FUEL_BOOST_STEP: u128 = PERCENTAGE_PRECISION / 10;
+pub const MAX_FUEL_BOOST: u8 = 15;
+
+impl VaultConfig {
+    pub fn get_address(vault: &Pubkey) -> Pubkey {
+        Pubkey::find_program_address(&[b"vault_config", vault.as_ref()], &crate::id()).0
+    }
+
+    pub fn has_flag(&self, flag: VaultConfigFlag) -> bool {
+        self.flags & (flag as u8) != 0
+    }
+
+    pub fn add_flag(&mut self, flag: VaultConfigFlag) {
+        self.flags |= flag as u8;
+    }
+
+    /// The protocol fee to charge for the given vault equity. Falls back to `protocol_fee` below the first tier,
+    /// and a tier can never charge more than `protocol_fee`.
+    pub fn get_protocol_fee(&self, protocol_fee: u64, vault_equity: u64) -> u64 {
+        self.protocol_fee_tiers
+            .iter()
+            .take_while(|tier| tier.is_active())
+            .filter(|tier| vault_equity >= tier.tvl_threshold)
+            .last()
+            .map_or(protocol_fee, |tier| tier.protocol_fee.min(protocol_fee))
+    }
+
+    pub fn update_protocol_fee_tiers(
+        &mut self,
+        protocol_fee: u64,
+        tiers: [ProtocolFeeTier; 4],
+    ) -> VaultResult {
+        let mut last_threshold = 0;
+        let mut last_fee = protocol_fee;
+        let mut last_active = true;
+
+        for tier in tiers.iter() {
+            if !tier.is_active() {
+                validate!(
+                    tier.protocol_fee == 0,
+                    ErrorCode::InvalidVaultUpdate,
+                    "unused protocol fee tier must have zero fee"
+                )?;
+                last_active = false;
+                continue;
+            }
+
+            validate!(
+                last_active,
+                ErrorCode::InvalidVaultUpdate,
+                "protocol fee tiers must be contiguous"
+            )?;
+            validate!(
+                tier.tvl_threshold > last_threshold,
+                ErrorCode::InvalidVaultUpdate,
+                "protocol fee tier thresholds must be increasing {} <= {}",
+                tier.tvl_threshold,
+                last_threshold
+            )?;
+            validate!(
+                tier.protocol_fee <= last_fee,
+                ErrorCode::InvalidVaultUpdate,
+                "protocol fee tiers must step down {} > {}",
+                tier.protocol_fee,
+                last_fee
+            )?;
+
+            last_threshold = tier.tvl_threshold;
+            last_fee = tier.protocol_fee;
+        }
+
+        msg!(
+            "Updating protocol fee tiers {:?} -> {:?}",
+            self.protocol_fee_tiers,
+            tiers
+        );
+        self.protocol_fee_tiers = tiers;
+
+        Ok(())
+    }
+
+    pub fn update_protocol_fee_waiver_thresholds(
+        &mut self,
+        tvl_threshold: u64,
+        exit_tvl_threshold: u64,
+    ) -> VaultResult {
+        validate!(
+            exit_tvl_threshold >= tvl_threshold,
+            ErrorCode::InvalidVaultUpdate,
+            "protocol fee waiver exit threshold must be at least the threshold {} < {}",
+            exit_tvl_threshold,
+            tvl_threshold
+        )?;
+
+        msg!(
+            "Updating protocol fee waiver thresholds {}/{} -> {}/{}",
+            self.fee_waiver_tvl_threshold,
+            self.fee_waiver_exit_tvl_threshold,
+            tvl_threshold,
+            exit_tvl_threshold
+        );
+        self.fee_waiver_tvl_threshold = tvl_threshold;
+        self.fee_waiver_exit_tvl_threshold = exit_tvl_threshold;
+
+        Ok(())
+    }
+
+    pub fn profit_share_mode(&self) -> VaultResult<ProfitShareMode> {
+        ProfitShareMode::try_from(self.profit_share_mode)
+    }
+
+    /// Switching to a mode charging more, see [`ProfitShareMode::fee_rank`], changes the terms depositors entered on,
+    /// so it's only allowed before the vault has depositor shares. Switching to one charging less is always allowed.
+    pub fn update_profit_share_mode(&mut self, vault: &Vault, mode: ProfitShareMode) -> Result<()> {
+        validate!(
+            mode.fee_rank() <= self.profit_share_mode()?.fee_rank() || vault.user_shares == 0,
+            ErrorCode::InvalidVaultUpdate,
+            "can only switch to a profit share mode charging more without depositor shares"
+        )?;
+
+        self.profit_share_mode = mode as u8;
+
+        Ok(())
+    }
+
+    /// Vaults from before fee policies with a hurdle rate are [`FeePolicy::Hurdle`]
+    pub fn fee_policy(&self, vault: &Vault) -> VaultResult<FeePolicy> {
+        let fee_policy = FeePolicy::try_from(self.fee_policy)?;
+        if fee_policy == FeePolicy::HighWaterMark && vault.hurdle_rate > 0 {
+            return Ok(FeePolicy::Hurdle);
+        }
+
+        Ok(fee_policy)
+    }
+
+    /// Like [`Self::update_profit_share_mode`], a policy charging more is only allowed before the vault has
+    /// depositor shares.
+    pub fn update_fee_policy(&mut self, vault: &Vault, fee_policy: FeePolicy) -> Result<()> {
+        fee_policy.validate_hurdle_rate(vault.hurdle_rate)?;
+        validate!(
+            fee_policy != FeePolicy::Flat || !vault.vault_protocol,
+            ErrorCode::InvalidVaultUpdate,
+            "flat fee policy would waive the protocol profit share"
+        )?;
+        validate!(
+            fee_policy.fee_rank() <= self.fee_policy(vault)?.fee_rank() || vault.user_shares == 0,
+            ErrorCode::InvalidVaultUpdate,
+            "can only switch to a fee policy charging more without depositor shares"
+        )?;
+
+        self.fee_policy = fee_policy as u8;
+
+        Ok(())
+    }
+
+    pub fn deposit_valuation(&self) -> VaultResult<DepositValuation> {
+        DepositValuation::try_from(self.deposit_valuation)
+    }
+
+    pub fn update_deposit_valuation(&mut self, deposit_valuation: DepositValuation) {
+        self.deposit_valuation = deposit_valuation as u8;
+    }
+
+    pub fn profit_share_realization(&self) -> VaultResult<ProfitShareRealization> {
+        ProfitShareRealization::try_from(self.profit_share_realization)
+    }
+
+    /// Like [`Self::update_profit_share_mode`], realizing gains earlier is only allowed before the vault has
+    /// depositor shares, so depositors that joined under "no fee until you exit" are never charged on a crank.
+    pub fn update_profit_share_realization(
+        &mut self,
+        vault: &Vault,
+        realization: ProfitShareRealization,
+    ) -> Result<()> {
+        validate!(
+            realization.fee_rank() <= self.profit_share_realization()?.fee_rank()
+                || vault.user_shares == 0,
+            ErrorCode::InvalidVaultUpdate,
+            "can only switch to realizing profit share earlier without depositor shares"
+        )?;
+
+        self.profit_share_realization = realization as u8;
+
+        Ok(())
+    }
+
+    /// `apply_profit_share` cranks and `realize_depositor_profits` would charge depositors before they exit
+    pub fn validate_unrealized_profit_share(&self) -> Result<()> {
+        let realization = self.profit_share_realization()?;
+        validate!(
+            realization == ProfitShareRealization::Unrealized,
+            ErrorCode::VaultFeatureDisabled,
+            "vault profit share realization is {:?}",
+            realization
+        )?;
+        Ok(())
+    }
+
+    pub fn update_fuel_boost(&mut self, fuel_boost: u8) -> VaultResult {
+        validate!(
+            fuel_boost <= MAX_FUEL_BOOST,
+            ErrorCode::InvalidVaultUpdate,
+            "fuel boost {} > max {}",
+            fuel_boost,
+            MAX_FUEL_BOOST
+        )?;
+
+        msg!("Updating fuel boost {} -> {}", self.fuel_boost, fuel_boost);
+        self.fuel_boost = fuel_boost;
+
+        Ok(())
+    }
+
+    /// Multiplier on the fuel of a depositor that held its shares for `holding_period` without withdrawing: 1x
+    /// growing linearly to 1 + `fuel_boost` * [`FUEL_BOOST_STEP`] over [`FUEL_BOOST_RAMP_PERIOD`].
+    /// precision: PERCENTAGE_PRECISION
+    pub fn get_fuel_boost_multiplier(&self, holding_period: i64) -> VaultResult<u128> {
+        let max_boost = self.fuel_boost.cast::<u128>()?.safe_mul(FUEL_BOOST_STEP)?;
+        let ramp = holding_period
+            .clamp(0, FUEL_BOOST_RAMP_PERIOD)
+            .cast::<u128>()?;
+
+        PERCENTAGE_PRECISION.safe_add(
+            max_boost
+                .safe_mul(ramp)?
+                .safe_div(FUEL_BOOST_RAMP_PERIOD.cast()?)?,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use drift::math::constants::PERCENTAGE_PRECISION;
+
+    use crate::constants::FUEL_BOOST_RAMP_PERIOD;
+    use crate::state::{VaultConfig, MAX_FUEL_BOOST};
+
+    #[test]
+    fn test_fuel_boost() {
+        let mut vault_config = VaultConfig::default();
+        vault_config.update_fuel_boost(5).unwrap();
+        assert!(vault_config.update_fuel_boost(MAX_FUEL_BOOST + 1).is_err());
+        assert_eq!(vault_config.fuel_boost, 5);
+
+        assert_eq!(
+            vault_config.get_fuel_boost_multiplier(-1).unwrap(),
+            PERCENTAGE_PRECISION
+        );
+        assert_eq!(
+            vault_config.get_fuel_boost_multiplier(0).unwrap(),
+            PERCENTAGE_PRECISION
+        );
+        assert_eq!(
+            vault_config
+                .get_fuel_boost_multiplier(FUEL_BOOST_RAMP_PERIOD / 2)
+                .unwrap(),
+            PERCENTAGE_PRECISION * 5 / 4
+        );
+        assert_eq!(
+            vault_config
+                .get_fuel_boost_multiplier(FUEL_BOOST_RAMP_PERIOD * 2)
+                .unwrap(),
+            PERCENTAGE_PRECISION * 3 / 2
+        );
+    }
+}
@@ -0,0 +1,374 @@
+use anchor_lang::prelude::*;
+use drift::math::casting::Cast;
+use drift::math::constants::{PERCENTAGE_PRECISION, PRICE_PRECISION};
+use drift::math::insurance::vault_amount_to_if_shares as vault_amount_to_depositor_shares;
+use drift::math::safe_math::SafeMath;
+use drift_macros::assert_no_slop;
+use static_assertions::const_assert_eq;
+
+use crate::error::ErrorCode;
+use crate::events::VaultCrystallizationRecord;
+use crate::state::{Size, Vault, VaultConfig, VaultFlag};
+use crate::validate;
+
+/// Fund level profit share. Instead of tracking each depositor's cost basis, the manager's profit share is charged
+/// on the share price's gain above the high water mark and paid by minting manager shares, so one crank covers every
+/// depositor.
+///
+/// Creating it moves the vault's `profit_share` here and zeroes it on the vault, which stops the per depositor
+/// manager profit share for good since `update_vault` can only lower it. The protocol profit share stays per
+/// depositor. Depositors who withdraw between two crystallizations don't pay on the gain since the last one.
+#[assert_no_slop]
+#[account(zero_copy(unsafe))]
+#[derive(Default, Eq, PartialEq, Debug)]
+#[repr(C)]
+pub struct VaultCrystallization {
+    /// The vault profit share is crystallized for
+    pub vault: Pubkey,
+    /// Share price the profit share was last crystallized at, net of it: PRICE_PRECISION
+    pub high_water_mark: u128,
+    /// ts of the last crystallization
+    pub last_crystallization_ts: i64,
+    /// Manager profit share on depositors' share price gains: PERCENTAGE_PRECISION
+    pub profit_share: u32,
+    /// The vault's shares_base when high_water_mark was set
+    pub shares_base: u32,
+    /// Minimum seconds between two crystallizations
+    pub crystallization_period: u32,
+    pub bump: u8,
+    pub padding: [u8; 11],
+}
+
+impl Size for VaultCrystallization {
+    const SIZE: usize = 80 + 8;
+}
+const_assert_eq!(
+    VaultCrystallization::SIZE,
+    std::mem::size_of::<VaultCrystallization>() + 8
+);
+
+/// Equity per share: PRICE_PRECISION
+pub fn calculate_share_price(vault_equity: u64, total_shares: u128) -> Result<u128> {
+    if total_shares == 0 {
+        return Ok(PRICE_PRECISION);
+    }
+
+    vault_equity
+        .cast::<u128>()?
+        .safe_mul(PRICE_PRECISION)?
+        .safe_div(total_shares)
+}
+
+impl VaultCrystallization {
+    /// Moves the vault's manager profit share to fund level crystallization starting from the current share price.
+    /// Only allowed before the vault has depositors, their cost basis would be charged twice otherwise.
+    pub fn initialize(
+        &mut self,
+        vault: &mut Vault,
+        vault_equity: u64,
+        crystallization_period: u32,
+        now: i64,
+    ) -> Result<()> {
+        validate!(
+            vault.user_shares == 0,
+            ErrorCode::InvalidVaultCrystallization,
+            "vault has depositors"
+        )?;
+
+        self.vault = vault.pubkey;
+        self.profit_share = vault.profit_share;
+        self.shares_base = vault.shares_base;
+        self.crystallization_period = crystallization_period;
+        self.high_water_mark = calculate_share_price(vault_equity, vault.total_shares)?;
+        self.last_crystallization_ts = now;
+
+        vault.profit_share = 0;
+
+        Ok(())
+    }
+
+    pub fn update_profit_share(&mut self, profit_share: u32) -> Result<()> {
+        validate!(
+            profit_share < self.profit_share,
+            ErrorCode::InvalidVaultCrystallization,
+            "new profit share must be lower"
+        )?;
+
+        self.profit_share = profit_share;
+
+        Ok(())
+    }
+
+    /// Charges the profit share on the gain of the depositors' shares above the high water mark, as the vault's
+    /// [`FeePolicy`](crate::state::FeePolicy) has it, and mints it to the manager as new shares. `vault_equity` must
+    /// be net of the management fee, see [`Vault::apply_fee`].
+    ///
+    /// With share price `p`, high water mark `h` and `u` user shares the depositors' profit is `(p - h) * u` and the
+    /// fee `f` a share of it. Minting `f * total_shares / (equity - f)` shares leaves the manager with exactly `f` of
+    /// equity, and the share price after it is the new high water mark.
+    pub fn crystallize(
+        &mut self,
+        vault: &mut Vault,
+        vault_config: &VaultConfig,
+        vault_equity: u64,
+        now: i64,
+    ) -> Result<VaultCrystallizationRecord> {
+        validate!(
+            self.vault == vault.pubkey,
+            ErrorCode::InvalidVaultCrystallization,
+            "VaultCrystallization is not for this vault"
+        )?;
+        validate!(
+            now.safe_sub(self.last_crystallization_ts)? >= self.crystallization_period.cast()?,
+            ErrorCode::InvalidVaultCrystallization,
+            "next crystallization at {}",
+            self.last_crystallization_ts
+                .safe_add(self.crystallization_period.cast()?)?
+        )?;
+
+        // a rebase divides shares by 10^expo_diff, so share prices are 10^expo_diff larger after it
+        if vault.shares_base != self.shares_base {
+            let expo_diff = vault.shares_base.safe_sub(self.shares_base)?;
+            self.high_water_mark = self.high_water_mark.safe_mul(10_u128.pow(expo_diff))?;
+            self.shares_base = vault.shares_base;
+        }
+
+        let high_water_mark_before = self.high_water_mark;
+        let share_price = calculate_share_price(vault_equity, vault.total_shares)?;
+
+        let mut depositor_profit = 0_u64;
+        let mut profit_share_paid = 0_u64;
+        let mut profit_share_shares = 0_u128;
+        if vault.user_shares > 0 && share_price > high_water_mark_before {
+            let gain: u64 = share_price
+                .safe_sub(high_water_mark_before)?
+                .safe_mul(vault.user_shares)?
+                .safe_div(PRICE_PRECISION)?
+                .cast()?;
+            let high_water_mark_value: i64 = high_water_mark_before
+                .safe_mul(vault.user_shares)?
+                .safe_div(PRICE_PRECISION)?
+                .cast()?;
+            let chargeable_profit: u64 = vault_config
+                .fee_policy(vault)?
+                .chargeable_profit(gain.cast()?, high_water_mark_value, vault.hurdle_rate)?
+                .cast()?;
+
+            if chargeable_profit > 0 {
+                depositor_profit = gain;
+
+                // a fee holiday suspends crystallization without moving the high water mark
+                let profit_share = if vault.has_flag(VaultFlag::ProfitShareHoliday) {
+                    0
+                } else {
+                    self.profit_share
+                };
+                profit_share_paid = chargeable_profit
+                    .cast::<u128>()?
+                    .safe_mul(profit_share.cast()?)?
+                    .safe_div(PERCENTAGE_PRECISION)?
+                    .cast()?;
+
+                if profit_share_paid > 0 {
+                    profit_share_shares = vault_amount_to_depositor_shares(
+                        profit_share_paid,
+                        vault.total_shares,
+                        vault_equity.safe_sub(profit_share_paid)?,
+                    )?;
+                    vault.total_shares = vault.total_shares.safe_add(profit_share_shares)?;
+                    vault.manager_total_profit_share = vault
+                        .manager_total_profit_share
+                        .safe_add(profit_share_paid)?;
+                }
+
+                if profit_share > 0 {
+                    self.high_water_mark = calculate_share_price(vault_equity, vault.total_shares)?;
+                }
+            }
+        }
+
+        self.last_crystallization_ts = now;
+
+        Ok(VaultCrystallizationRecord {
+            ts: now,
+            vault: vault.pubkey,
+            vault_equity,
+            share_price,
+            high_water_mark_before,
+            high_water_mark_after: self.high_water_mark,
+            depositor_profit,
+            profit_share_paid,
+            profit_share_shares,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anchor_lang::prelude::Pubkey;
+    use drift::math::constants::{PRICE_PRECISION, QUOTE_PRECISION_U64};
+
+    use crate::constants::ONE_DAY;
+    use crate::state::{Vault, VaultConfig, VaultCrystallization, VaultFlag};
+
+    fn crystallized_vault() -> (Vault, VaultCrystallization) {
+        let mut vault = Vault {
+            pubkey: Pubkey::new_unique(),
+            profit_share: 100_000, // 10%
+            ..Vault::default()
+        };
+        let mut crystallization = VaultCrystallization::default();
+        crystallization
+            .initialize(&mut vault, 0, ONE_DAY as u32, 0)
+            .unwrap();
+
+        vault.total_shares = 100 * QUOTE_PRECISION_U64 as u128;
+        vault.user_shares = vault.total_shares;
+
+        (vault, crystallization)
+    }
+
+    #[test]
+    fn test_initialize() {
+        let (vault, crystallization) = crystallized_vault();
+        assert_eq!(vault.profit_share, 0);
+        assert_eq!(crystallization.profit_share, 100_000);
+        assert_eq!(crystallization.high_water_mark, PRICE_PRECISION);
+
+        let mut vault = vault;
+        assert!(VaultCrystallization::default()
+            .initialize(&mut vault, 0, 0, 0)
+            .is_err());
+    }
+
+    #[test]
+    fn test_crystallize() {
+        let (mut vault, mut crystallization) = crystallized_vault();
+
+        // too early
+        assert!(crystallization
+            .crystallize(
+                &mut vault,
+                &VaultConfig::default(),
+                120 * QUOTE_PRECISION_U64,
+                ONE_DAY - 1
+            )
+            .is_err());
+
+        // 20% up: 20 profit, 2 profit share
+        let now = ONE_DAY;
+        let record = crystallization
+            .crystallize(
+                &mut vault,
+                &VaultConfig::default(),
+                120 * QUOTE_PRECISION_U64,
+                now,
+            )
+            .unwrap();
+        assert_eq!(record.share_price, 1_200_000);
+        assert_eq!(record.depositor_profit, 20 * QUOTE_PRECISION_U64);
+        assert_eq!(record.profit_share_paid, 2 * QUOTE_PRECISION_U64);
+        // 2 * 100 / 118
+        assert_eq!(record.profit_share_shares, 1_694_915);
+        assert_eq!(vault.total_shares, 101_694_915);
+        assert_eq!(vault.manager_total_profit_share, 2 * QUOTE_PRECISION_U64);
+        // the manager owns 2 of the 120
+        assert_eq!(
+            record.profit_share_shares * 120 * QUOTE_PRECISION_U64 as u128 / vault.total_shares,
+            1_999_999
+        );
+        assert_eq!(crystallization.high_water_mark, 1_180_000);
+
+        // below the high water mark nothing is charged and it stays put
+        let now = now + ONE_DAY;
+        let record = crystallization
+            .crystallize(
+                &mut vault,
+                &VaultConfig::default(),
+                110 * QUOTE_PRECISION_U64,
+                now,
+            )
+            .unwrap();
+        assert_eq!(record.profit_share_paid, 0);
+        assert_eq!(crystallization.high_water_mark, 1_180_000);
+
+        // recovering to it isn't charged either
+        let now = now + ONE_DAY;
+        let record = crystallization
+            .crystallize(
+                &mut vault,
+                &VaultConfig::default(),
+                120 * QUOTE_PRECISION_U64,
+                now,
+            )
+            .unwrap();
+        assert_eq!(record.share_price, 1_180_000);
+        assert_eq!(record.profit_share_paid, 0);
+    }
+
+    #[test]
+    fn test_crystallize_hurdle_and_holiday() {
+        let (mut vault, mut crystallization) = crystallized_vault();
+        vault.hurdle_rate = 100_000; // 10%
+
+        // within the hurdle
+        let record = crystallization
+            .crystallize(
+                &mut vault,
+                &VaultConfig::default(),
+                110 * QUOTE_PRECISION_U64,
+                ONE_DAY,
+            )
+            .unwrap();
+        assert_eq!(record.profit_share_paid, 0);
+        assert_eq!(crystallization.high_water_mark, PRICE_PRECISION);
+
+        // a holiday charges nothing and keeps the high water mark
+        vault.add_flag(VaultFlag::ProfitShareHoliday);
+        let record = crystallization
+            .crystallize(
+                &mut vault,
+                &VaultConfig::default(),
+                120 * QUOTE_PRECISION_U64,
+                2 * ONE_DAY,
+            )
+            .unwrap();
+        assert_eq!(record.depositor_profit, 20 * QUOTE_PRECISION_U64);
+        assert_eq!(record.profit_share_paid, 0);
+        assert_eq!(crystallization.high_water_mark, PRICE_PRECISION);
+
+        // beyond the hurdle the whole gain is charged, like the per depositor profit share
+        vault.remove_flag(VaultFlag::ProfitShareHoliday);
+        let record = crystallization
+            .crystallize(
+                &mut vault,
+                &VaultConfig::default(),
+                120 * QUOTE_PRECISION_U64,
+                3 * ONE_DAY,
+            )
+            .unwrap();
+        assert_eq!(record.profit_share_paid, 2 * QUOTE_PRECISION_U64);
+    }
+
+    #[test]
+    fn test_crystallize_after_rebase() {
+        let (mut vault, mut crystallization) = crystallized_vault();
+
+        // shares / 10, so a share is worth 10x
+        vault.total_shares /= 10;
+        vault.user_shares /= 10;
+        vault.shares_base = 1;
+
+        let record = crystallization
+            .crystallize(
+                &mut vault,
+                &VaultConfig::default(),
+                100 * QUOTE_PRECISION_U64,
+                ONE_DAY,
+            )
+            .unwrap();
+        assert_eq!(record.high_water_mark_before, 10 * PRICE_PRECISION);
+        assert_eq!(record.profit_share_paid, 0);
+        assert_eq!(crystallization.shares_base, 1);
+    }
+}
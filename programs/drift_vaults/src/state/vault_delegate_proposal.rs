@@ -0,0 +1,79 @@
+use anchor_lang::prelude::*;
+use drift_macros::assert_no_slop;
+use static_assertions::const_assert_eq;
+
+use crate::error::ErrorCode;
+use crate::state::Size;
+use crate::validate;
+
+/// A delegate change proposed by the manager of a vault under
+/// [`VaultProtocolFlag::DelegateDualApproval`](crate::state::VaultProtocolFlag), applied once the protocol approves it.
+#[assert_no_slop]
+#[account(zero_copy(unsafe))]
+#[derive(Default, Eq, PartialEq, Debug)]
+#[repr(C)]
+pub struct VaultDelegateProposal {
+    /// The vault the proposal is for
+    pub vault: Pubkey,
+    /// The proposed delegate
+    pub delegate: Pubkey,
+    /// When the delegate was proposed, 0 if there is no pending proposal
+    pub proposed_ts: i64,
+    pub bump: u8,
+    pub padding: [u8; 7],
+}
+
+impl Size for VaultDelegateProposal {
+    const SIZE: usize = 80 + 8;
+}
+const_assert_eq!(
+    VaultDelegateProposal::SIZE,
+    std::mem::size_of::<VaultDelegateProposal>() + 8
+);
+
+impl VaultDelegateProposal {
+    pub fn pending(&self) -> bool {
+        self.proposed_ts != 0
+    }
+
+    /// Replaces any pending proposal
+    pub fn propose(&mut self, delegate: Pubkey, now: i64) {
+        self.delegate = delegate;
+        self.proposed_ts = now;
+    }
+
+    /// Consumes the pending proposal, `delegate` must match it so the protocol approves what it reviewed
+    pub fn approve(&mut self, delegate: Pubkey) -> Result<()> {
+        validate!(
+            self.pending() && self.delegate == delegate,
+            ErrorCode::InvalidVaultDelegateProposal,
+            "no pending proposal for delegate {}",
+            delegate
+        )?;
+
+        self.delegate = Pubkey::default();
+        self.proposed_ts = 0;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_approve_delegate_proposal() {
+        let mut proposal = VaultDelegateProposal::default();
+        let delegate = Pubkey::new_unique();
+        assert!(proposal.approve(delegate).is_err());
+
+        proposal.propose(Pubkey::new_unique(), 100);
+        proposal.propose(delegate, 200);
+        assert!(proposal.approve(Pubkey::new_unique()).is_err());
+
+        proposal.approve(delegate).unwrap();
+        assert!(!proposal.pending());
+        assert!(proposal.approve(delegate).is_err());
+    }
+}
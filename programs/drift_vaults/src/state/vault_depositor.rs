@@ -1,10 +1,11 @@
-use std::cell::RefMut;
+use std::cell::{Ref, RefCell, RefMut};
 
 use anchor_lang::prelude::*;
+use borsh::{BorshDeserialize, BorshSerialize};
 use drift::controller::spot_balance::update_spot_balances;
 use drift::error::ErrorCode as DriftErrorCode;
 use drift::math::casting::Cast;
-use drift::math::constants::PERCENTAGE_PRECISION;
+use drift::math::constants::{PERCENTAGE_PRECISION, PRICE_PRECISION};
 use drift::math::insurance::{
     if_shares_to_vault_amount as depositor_shares_to_vault_amount,
     vault_amount_to_if_shares as vault_amount_to_depositor_shares,
@@ -13,20 +14,24 @@ use drift::math::margin::{meets_initial_margin_requirement, validate_spot_margin
 use drift::math::safe_math::SafeMath;
 use drift::state::events::FuelSeasonRecord;
 use drift::state::oracle_map::OracleMap;
+use drift::state::paused_operations::SpotOperation;
 use drift::state::perp_market_map::PerpMarketMap;
-use drift::state::spot_market::SpotBalanceType;
+use drift::state::spot_market::{SpotBalanceType, SpotMarket};
 use drift::state::spot_market_map::SpotMarketMap;
 use drift::state::user::{FuelOverflow, User, UserStats};
 use drift_macros::assert_no_slop;
 use static_assertions::const_assert_eq;
 
-use crate::constants::{FUEL_SHARE_PRECISION, MAGIC_FUEL_START_TS};
+use crate::constants::{FUEL_SHARE_PRECISION, MAGIC_FUEL_START_TS, ONE_HOUR};
 use crate::error::ErrorCode;
 use crate::events::VaultDepositorAction;
-use crate::state::events::{VaultDepositorRecord, VaultDepositorV1Record};
+use crate::state::events::{VaultDepositorLotRecord, VaultDepositorRecord, VaultDepositorV1Record};
 use crate::state::withdraw_request::WithdrawRequest;
 use crate::state::withdraw_unit::WithdrawUnit;
-use crate::state::{Vault, VaultDepositorBase, VaultFee, VaultProtocol};
+use crate::state::{
+    ProfitShareRealization, Vault, VaultConfig, VaultDepositorBase, VaultFee, VaultProtocol,
+    VaultWithdrawSettlement,
+};
 use crate::validate;
 use crate::Size;
 
@@ -57,15 +62,25 @@ pub struct VaultDepositor {
     pub cumulative_profit_share_amount: i64,
     pub profit_share_fee_paid: u64,
     /// the exponent for vault_shares decimal places
-    pub vault_shares_base: u32,
+    pub vault_shares_base: u16,
+    /// layout version, see [`VAULT_DEPOSITOR_VERSION`]. Accounts created before versioning read as 0
+    pub version: u8,
+    /// Bits of [`VaultDepositorFlag`]
+    pub flags: u8,
     pub last_fuel_update_ts: u32, // overflows on 2106-02-07 06:28:15 UTC
     /// precision: FUEL_SHARE_PRECISION
     pub cumulative_fuel_per_share_amount: u128,
     /// precision: none
     pub fuel_amount: u128,
-    pub padding: [u64; 4],
+    /// optional key allowed to request and cancel withdraws on behalf of the authority.
+    /// withdraws are still paid to the authority. Pubkey::default() if unset
+    pub custodian: Pubkey,
 }
 
+/// Current [`VaultDepositor`] layout version. Bump it when a field is added or repurposed and initialize the field in
+/// [`VaultDepositor::migrate`], existing accounts are upgraded in place the next time an instruction loads them.
+pub const VAULT_DEPOSITOR_VERSION: u8 = 1;
+
 impl Size for VaultDepositor {
     const SIZE: usize = 264 + 8;
 }
@@ -75,6 +90,110 @@ const_assert_eq!(
     std::mem::size_of::<VaultDepositor>() + 8
 );
 
+/// Why a depositor's pending withdraw request can't be paid out of the drift user, see
+/// [`VaultDepositor::simulate_withdraw`].
+#[derive(Clone, Copy, BorshSerialize, BorshDeserialize, PartialEq, Debug, Eq)]
+#[repr(u8)]
+pub enum WithdrawBlocker {
+    None = 0,
+    /// the withdraw would leave the drift user below its initial margin requirement
+    InsufficientCollateral = 1,
+    /// the withdraw would borrow and the drift user doesn't have margin trading enabled
+    MarginTradingDisabled = 2,
+    /// the withdraw would borrow and the drift user is reduce only
+    ReduceOnly = 3,
+    /// drift paused withdraws from the vault's spot market
+    MarketPaused = 4,
+}
+
+/// Min seconds from a depositor's last fuel sync to realizing its own profits with
+/// [`realize_depositor_profits`](crate::instructions::realize_depositor_profits)
+pub const MIN_DEPOSITOR_REALIZE_PROFITS_INTERVAL: i64 = ONE_HOUR;
+
+/// Most positions [`view_depositor_portfolio`](crate::instructions::view_depositor_portfolio) returns, keeps the
+/// return data under its 1024 byte limit
+pub const MAX_PORTFOLIO_POSITIONS: usize = 12;
+
+/// A depositor's position valued at current vault equity, see [`VaultDepositor::portfolio_position`]. Amounts are in
+/// the vault's spot market precision.
+#[derive(Clone, Copy, BorshSerialize, BorshDeserialize, PartialEq, Debug, Eq, Default)]
+pub struct PortfolioPosition {
+    pub vault_depositor: Pubkey,
+    pub spot_market_index: u16,
+    /// value of the depositor's shares, before any fees
+    pub value: u64,
+    /// the depositor's part of the management and protocol fees accrued since the vault last charged them
+    pub accrued_fees: u64,
+    /// profit share a withdraw of the whole position would be charged now
+    pub profit_share_owed: u64,
+    /// value of the pending withdraw request, 0 if there is none
+    pub withdraw_request_value: u64,
+}
+
+/// Bits of [`VaultDepositor`] `flags`
+#[derive(Clone, Copy, PartialEq, Debug, Eq)]
+pub enum VaultDepositorFlag {
+    /// Anyone can execute the pending withdraw request once it matures, see
+    /// [`execute_queued_withdraw`](crate::instructions::execute_queued_withdraw). Cleared when the request is
+    /// withdrawn or cancelled.
+    QueuedWithdraw = 0b00000001,
+    /// Opt in to anyone [renewing](crate::instructions::renew_withdraw_request) the pending withdraw request at
+    /// current share pricing once it [expires](crate::state::withdraw_request::WithdrawRequest::is_expired) unexecuted.
+    AutoRenewWithdraw = 0b00000010,
+    /// Included in [`Vault::depositor_count`] and, while it has a pending withdraw request,
+    /// [`Vault::active_withdraw_request_count`]. Set on every depositor created since the counters were added, older
+    /// depositors are counted by [`backfill_vault_depositor_counts`](crate::instructions::backfill_vault_depositor_counts).
+    Counted = 0b00100000,
+    /// Set by the manager to block new deposits from the authority. Withdraws of existing shares are unaffected.
+    Blacklisted = 0b00000100,
+    /// Set when the authority creates an [`OmnibusLedger`](crate::state::OmnibusLedger) to hold the position for many
+    /// beneficiaries. Withdraw requests must then pass the ledger and can't take allocated shares.
+    Omnibus = 0b00001000,
+    /// Set when the authority creates a
+    /// [`VaultDepositorWithdrawRequests`](crate::state::VaultDepositorWithdrawRequests) to park withdraw requests.
+    /// Withdraw requests must then pass it and can't take parked shares.
+    ParkedWithdrawRequests = 0b00010000,
+}
+
+/// Whether drift rejects withdraws from `spot_market`
+pub fn is_withdraw_paused(spot_market: &SpotMarket) -> bool {
+    spot_market.is_operation_paused(SpotOperation::Withdraw)
+}
+
+/// Fails with a precise error instead of the drift cpi failure when withdraws from `spot_market` are paused.
+/// Depositors can [`queue_withdraw`](crate::instructions::queue_withdraw) to be paid once the market resumes.
+pub fn validate_withdraw_not_paused(spot_market: &SpotMarket) -> Result<()> {
+    validate!(
+        !is_withdraw_paused(spot_market),
+        ErrorCode::SpotMarketWithdrawPaused,
+        "drift paused withdraws from spot market {}, queue_withdraw to withdraw once it resumes",
+        spot_market.market_index
+    )?;
+
+    Ok(())
+}
+
+pub trait VaultDepositorLoader {
+    /// [`AccountLoader::load_mut`] that first [migrates](VaultDepositor::migrate) the account to the current layout
+    fn load_mut_migrated(&self) -> Result<RefMut<'_, VaultDepositor>>;
+    /// [`AccountLoader::load`] that rejects accounts this program can't [read](VaultDepositor::validate_version)
+    fn load_checked(&self) -> Result<Ref<'_, VaultDepositor>>;
+}
+
+impl<'info> VaultDepositorLoader for AccountLoader<'info, VaultDepositor> {
+    fn load_mut_migrated(&self) -> Result<RefMut<'_, VaultDepositor>> {
+        let mut vault_depositor = self.load_mut()?;
+        vault_depositor.migrate()?;
+        Ok(vault_depositor)
+    }
+
+    fn load_checked(&self) -> Result<Ref<'_, VaultDepositor>> {
+        let vault_depositor = self.load()?;
+        vault_depositor.validate_version()?;
+        Ok(vault_depositor)
+    }
+}
+
 impl VaultDepositorBase for VaultDepositor {
     fn get_authority(&self) -> Pubkey {
         self.authority
@@ -91,10 +210,11 @@ impl VaultDepositorBase for VaultDepositor {
     }
 
     fn get_vault_shares_base(&self) -> u32 {
-        self.vault_shares_base
+        self.vault_shares_base.into()
     }
-    fn set_vault_shares_base(&mut self, base: u32) {
-        self.vault_shares_base = base;
+    fn set_vault_shares_base(&mut self, base: u32) -> Result<()> {
+        self.vault_shares_base = base.cast()?;
+        Ok(())
     }
 
     fn get_net_deposits(&self) -> i64 {
@@ -127,6 +247,8 @@ impl VaultDepositor {
             authority,
             vault_shares: 0,
             vault_shares_base: 0,
+            version: VAULT_DEPOSITOR_VERSION,
+            flags: VaultDepositorFlag::Counted as u8,
             last_withdraw_request: WithdrawRequest::default(),
             last_valid_ts: now,
             net_deposits: 0,
@@ -137,13 +259,255 @@ impl VaultDepositor {
             last_fuel_update_ts: MAGIC_FUEL_START_TS,
             cumulative_fuel_per_share_amount: 0,
             fuel_amount: 0,
-            padding: [0u64; 4],
+            custodian: Pubkey::default(),
+        }
+    }
+
+    pub fn has_flag(&self, flag: VaultDepositorFlag) -> bool {
+        self.flags & (flag as u8) != 0
+    }
+
+    pub fn add_flag(&mut self, flag: VaultDepositorFlag) {
+        self.flags |= flag as u8;
+    }
+
+    pub fn remove_flag(&mut self, flag: VaultDepositorFlag) {
+        self.flags &= !(flag as u8);
+    }
+
+    /// Counts a depositor created before [`Vault::depositor_count`] and [`Vault::active_withdraw_request_count`] were
+    /// tracked. Returns false if it's already counted.
+    pub fn backfill_counts(&mut self, vault: &mut Vault) -> Result<bool> {
+        validate!(
+            self.vault == vault.pubkey,
+            ErrorCode::InvalidVaultDepositorBatch,
+            "vault depositor {} is not for vault {}",
+            self.pubkey,
+            vault.pubkey
+        )?;
+
+        if self.has_flag(VaultDepositorFlag::Counted) {
+            return Ok(false);
+        }
+
+        self.add_flag(VaultDepositorFlag::Counted);
+        vault.depositor_count = vault.depositor_count.safe_add(1)?;
+        if self.last_withdraw_request.pending() {
+            self.increment_active_withdraw_request_count(vault)?;
+        }
+
+        Ok(true)
+    }
+
+    /// Counters only track [`VaultDepositorFlag::Counted`] depositors, so a backfill can't count a request twice
+    pub fn increment_active_withdraw_request_count(&self, vault: &mut Vault) -> Result<()> {
+        if self.has_flag(VaultDepositorFlag::Counted) {
+            vault.active_withdraw_request_count =
+                vault.active_withdraw_request_count.safe_add(1)?;
+        }
+
+        Ok(())
+    }
+
+    pub fn decrement_active_withdraw_request_count(&self, vault: &mut Vault) {
+        if self.has_flag(VaultDepositorFlag::Counted) {
+            vault.active_withdraw_request_count =
+                vault.active_withdraw_request_count.saturating_sub(1);
+        }
+    }
+
+    /// Whether [`withdraw_and_close`](crate::instructions::withdraw_and_close) can close the account: everything is
+    /// withdrawn and nothing is requested. Omnibus depositors and depositors with parked withdraw requests keep the
+    /// account their ledger or requests are tied to, and a custodian must be revoked first.
+    pub fn is_closable(&self) -> bool {
+        self.vault_shares == 0
+            && !self.last_withdraw_request.pending()
+            && !self.has_flag(VaultDepositorFlag::Omnibus)
+            && !self.has_flag(VaultDepositorFlag::ParkedWithdrawRequests)
+            && self.custodian == Pubkey::default()
+    }
+
+    /// Moves the whole position, cost basis, high water mark and fuel to `to`, the new authority's fresh vault
+    /// depositor, leaving this one empty. Blacklisting and the custodian carry over. Positions with a pending withdraw
+    /// request or companion accounts keyed by this vault depositor, an omnibus ledger or parked withdraw requests,
+    /// can't be moved.
+    pub fn transfer_authority(&mut self, to: &mut VaultDepositor) -> Result<()> {
+        validate!(
+            !self.last_withdraw_request.pending(),
+            ErrorCode::InvalidVaultDepositorAuthorityTransfer,
+            "cancel or withdraw the pending withdraw request first"
+        )?;
+        validate!(
+            !self.has_flag(VaultDepositorFlag::Omnibus)
+                && !self.has_flag(VaultDepositorFlag::ParkedWithdrawRequests),
+            ErrorCode::InvalidVaultDepositorAuthorityTransfer,
+            "vault depositor has accounts keyed by its address"
+        )?;
+        validate!(
+            to.vault == self.vault && to.vault_shares == 0 && to.net_deposits == 0,
+            ErrorCode::InvalidVaultDepositorAuthorityTransfer,
+            "can only transfer to a new vault depositor in the same vault"
+        )?;
+
+        to.vault_shares = self.vault_shares;
+        to.vault_shares_base = self.vault_shares_base;
+        to.last_withdraw_request = self.last_withdraw_request;
+        to.last_valid_ts = self.last_valid_ts;
+        to.net_deposits = self.net_deposits;
+        to.total_deposits = self.total_deposits;
+        to.total_withdraws = self.total_withdraws;
+        to.cumulative_profit_share_amount = self.cumulative_profit_share_amount;
+        to.profit_share_fee_paid = self.profit_share_fee_paid;
+        // `to` was counted when it was created
+        to.flags = self.flags | (to.flags & VaultDepositorFlag::Counted as u8);
+        to.last_fuel_update_ts = self.last_fuel_update_ts;
+        to.cumulative_fuel_per_share_amount = self.cumulative_fuel_per_share_amount;
+        to.fuel_amount = self.fuel_amount;
+        to.custodian = self.custodian;
+
+        self.vault_shares = 0;
+        self.net_deposits = 0;
+        self.total_deposits = 0;
+        self.total_withdraws = 0;
+        self.cumulative_profit_share_amount = 0;
+        self.profit_share_fee_paid = 0;
+        self.fuel_amount = 0;
+        self.custodian = Pubkey::default();
+
+        Ok(())
+    }
+
+    /// Queues the pending withdraw request for keepers to execute once it matures and drift allows withdraws from
+    /// the vault's spot market again.
+    pub fn queue_withdraw(&mut self) -> Result<()> {
+        validate!(
+            self.last_withdraw_request.pending(),
+            ErrorCode::WithdrawNotQueued,
+            "no withdraw request to queue"
+        )?;
+
+        self.add_flag(VaultDepositorFlag::QueuedWithdraw);
+
+        Ok(())
+    }
+
+    /// Re-requests the shares of an expired withdraw request at current share pricing. The old request is cancelled
+    /// first, so the depositor forfeits any gain since it was made as with a regular cancel, and the new request
+    /// starts a fresh redeem period.
+    #[allow(clippy::too_many_arguments)]
+    pub fn renew_withdraw_request(
+        &mut self,
+        vault_equity: u64,
+        vault: &mut Vault,
+        vault_config: &VaultConfig,
+        vault_protocol: &mut Option<RefMut<VaultProtocol>>,
+        now: i64,
+        user_stats: &UserStats,
+        fuel_overflow: &Option<AccountLoader<FuelOverflow>>,
+        deposit_oracle_price: i64,
+    ) -> Result<()> {
+        validate!(
+            self.has_flag(VaultDepositorFlag::AutoRenewWithdraw),
+            ErrorCode::AutoRenewWithdrawNotEnabled,
+            "vault depositor did not opt in to auto renewing withdraw requests"
+        )?;
+
+        validate!(
+            self.last_withdraw_request.is_expired(vault, now)?,
+            ErrorCode::WithdrawRequestNotExpired,
+            "withdraw request at ts {} has not expired",
+            self.last_withdraw_request.ts
+        )?;
+
+        let requested_shares = self.last_withdraw_request.shares;
+        let queued = self.has_flag(VaultDepositorFlag::QueuedWithdraw);
+
+        self.cancel_withdraw_request(
+            vault_equity,
+            vault,
+            vault_config,
+            vault_protocol,
+            now,
+            user_stats,
+            fuel_overflow,
+            deposit_oracle_price,
+            // an expired request has been up for settlement for a whole redeem period, renewing always forfeits
+            None,
+        )?;
+
+        // cancelling and profit share can cost shares, never request more than the depositor has left
+        self.realize_profits(
+            vault_equity,
+            vault,
+            vault_config,
+            vault_protocol,
+            now,
+            user_stats,
+            fuel_overflow,
+            deposit_oracle_price,
+        )?;
+        let renewed_shares = requested_shares.min(self.checked_vault_shares(vault)?);
+
+        self.request_withdraw(
+            renewed_shares.cast()?,
+            WithdrawUnit::Shares,
+            vault_equity,
+            vault,
+            vault_config,
+            vault_protocol,
+            now,
+            user_stats,
+            fuel_overflow,
+            deposit_oracle_price,
+        )?;
+
+        if queued {
+            self.add_flag(VaultDepositorFlag::QueuedWithdraw);
+        }
+
+        Ok(())
+    }
+
+    /// Upgrades an account written with an older layout [`version`](Self::version) to
+    /// [`VAULT_DEPOSITOR_VERSION`], see [`VaultDepositorLoader::load_mut_migrated`].
+    pub fn migrate(&mut self) -> Result<()> {
+        self.validate_version()?;
+
+        if self.version == VAULT_DEPOSITOR_VERSION {
+            return Ok(());
         }
+
+        msg!(
+            "Migrating vault depositor {} from version {} to {}",
+            self.pubkey,
+            self.version,
+            VAULT_DEPOSITOR_VERSION
+        );
+
+        // version 0 -> 1: introduced `version`, no fields to initialize
+
+        self.version = VAULT_DEPOSITOR_VERSION;
+
+        Ok(())
+    }
+
+    /// Older layouts read the same as the current one until they're migrated, newer ones were written by a later
+    /// program version and may have repurposed fields.
+    pub fn validate_version(&self) -> Result<()> {
+        validate!(
+            self.version <= VAULT_DEPOSITOR_VERSION,
+            ErrorCode::InvalidVaultDepositorVersion,
+            "vault depositor version {} is newer than program version {}",
+            self.version,
+            VAULT_DEPOSITOR_VERSION
+        )?;
+
+        Ok(())
     }
 
     pub fn validate_base(&self, vault: &Vault) -> Result<()> {
         validate!(
-            self.vault_shares_base == vault.shares_base,
+            self.get_vault_shares_base() == vault.shares_base,
             ErrorCode::InvalidVaultRebase,
             "vault depositor bases mismatch. user base: {} vault base {}",
             self.vault_shares_base,
@@ -158,6 +522,23 @@ impl VaultDepositor {
         Ok(self.vault_shares)
     }
 
+    /// Average cost basis of `shares` out of the depositor's `vault_shares`, and the profit share paid on them.
+    pub fn calculate_lot_cost_basis(&self, shares: u128, vault_shares: u128) -> Result<(u64, u64)> {
+        if vault_shares == 0 {
+            return Ok((0, 0));
+        }
+
+        let net_deposits: u128 = self.net_deposits.max(0).cast()?;
+        let cost_basis = net_deposits.safe_mul(shares)?.safe_div(vault_shares)?;
+        let fee_paid = self
+            .profit_share_fee_paid
+            .cast::<u128>()?
+            .safe_mul(shares)?
+            .safe_div(vault_shares)?;
+
+        Ok((cost_basis.cast()?, fee_paid.cast()?))
+    }
+
     pub fn unchecked_vault_shares(&self) -> u128 {
         self.vault_shares
     }
@@ -186,9 +567,10 @@ impl VaultDepositor {
         vault: &mut Vault,
         vault_protocol: &mut Option<RefMut<VaultProtocol>>,
         vault_equity: u64,
+        now: i64,
     ) -> Result<Option<u128>> {
         if let Some(rebase_divisor) =
-            VaultDepositorBase::apply_rebase(self, vault, vault_protocol, vault_equity)?
+            VaultDepositorBase::apply_rebase(self, vault, vault_protocol, vault_equity, now)?
         {
             self.last_withdraw_request.rebase(rebase_divisor)?;
             Ok(Some(rebase_divisor))
@@ -211,7 +593,7 @@ impl VaultDepositor {
             let profit_u128 = profit.cast::<u128>()?;
 
             let manager_profit_share_amount = profit_u128
-                .safe_mul(vault.profit_share.cast()?)?
+                .safe_mul(vault.manager_profit_share().cast()?)?
                 .safe_div(PERCENTAGE_PRECISION)?;
             let protocol_profit_share_amount = match vault_protocol {
                 None => 0,
@@ -239,12 +621,19 @@ impl VaultDepositor {
         amount: u64,
         vault_equity: u64,
         vault: &mut Vault,
+        vault_config: &VaultConfig,
         vault_protocol: &mut Option<RefMut<VaultProtocol>>,
         now: i64,
         user_stats: &UserStats,
         fuel_overflow: &Option<AccountLoader<FuelOverflow>>,
         deposit_oracle_price: i64,
     ) -> Result<()> {
+        validate!(
+            !vault.wind_down,
+            ErrorCode::VaultWindingDown,
+            "vault is winding down"
+        )?;
+
         validate!(
             vault.max_tokens == 0 || vault.max_tokens >= vault_equity.safe_add(amount)?,
             ErrorCode::VaultIsAtCapacity,
@@ -273,7 +662,7 @@ impl VaultDepositor {
             "withdraw request is in progress"
         )?;
 
-        self.apply_rebase(vault, vault_protocol, vault_equity)?;
+        self.apply_rebase(vault, vault_protocol, vault_equity, now)?;
 
         let vault_shares_before = self.checked_vault_shares(vault)?;
         let total_vault_shares_before = vault.total_shares;
@@ -285,10 +674,11 @@ impl VaultDepositor {
             management_fee_shares,
             protocol_fee_payment,
             protocol_fee_shares,
-        } = vault.apply_fee(vault_protocol, vault_equity, now)?;
+        } = vault.apply_fee(vault_config, vault_protocol, vault_equity, now)?;
         let (manager_profit_share, protocol_profit_share) = self.apply_profit_share(
             vault_equity,
             vault,
+            vault_config,
             vault_protocol,
             now,
             user_stats,
@@ -297,6 +687,16 @@ impl VaultDepositor {
 
         let n_shares = vault_amount_to_depositor_shares(amount, vault.total_shares, vault_equity)?;
 
+        // at high share prices tiny deposits round down to no shares and would be donated to the vault
+        validate!(
+            n_shares > 0,
+            ErrorCode::DepositTooSmall,
+            "deposit amount {} mints no shares at vault equity {} and total shares {}",
+            amount,
+            vault_equity,
+            vault.total_shares
+        )?;
+
         self.total_deposits = self.total_deposits.saturating_add(amount);
         self.net_deposits = self.net_deposits.safe_add(amount.cast()?)?;
 
@@ -371,26 +771,52 @@ impl VaultDepositor {
         withdraw_unit: WithdrawUnit,
         vault_equity: u64,
         vault: &mut Vault,
+        vault_config: &VaultConfig,
         vault_protocol: &mut Option<RefMut<VaultProtocol>>,
         now: i64,
         user_stats: &UserStats,
         fuel_overflow: &Option<AccountLoader<FuelOverflow>>,
         deposit_oracle_price: i64,
     ) -> Result<()> {
-        let rebase_divisor = self.apply_rebase(vault, vault_protocol, vault_equity)?;
+        // a token amount can't be priced in shares once the vault has no equity left, its shares can still be
+        // requested and are withdrawn for nothing
+        validate!(
+            vault_equity > 0 || withdraw_unit != WithdrawUnit::Token,
+            ErrorCode::ZeroVaultEquity,
+            "vault has no equity, request the withdraw in shares instead"
+        )?;
+
+        let rebase_divisor = self.apply_rebase(vault, vault_protocol, vault_equity, now)?;
         let VaultFee {
             management_fee_payment,
             management_fee_shares,
             protocol_fee_payment,
             protocol_fee_shares,
-        } = vault.apply_fee(vault_protocol, vault_equity, now)?;
-        let (manager_profit_share, protocol_profit_share) = self.apply_profit_share(
+        } = vault.apply_fee(vault_config, vault_protocol, vault_equity, now)?;
+
+        // realized only vaults charge profit share on the shares leaving, the rest stays owed by the remaining shares
+        let realized_shares =
+            if vault_config.profit_share_realization()? == ProfitShareRealization::Unrealized {
+                self.get_vault_shares()
+            } else {
+                let (_, n_shares) = withdraw_unit.get_withdraw_value_and_shares(
+                    withdraw_amount,
+                    vault_equity,
+                    self.get_vault_shares(),
+                    vault.total_shares,
+                    rebase_divisor,
+                )?;
+                n_shares.min(self.get_vault_shares())
+            };
+        let (manager_profit_share, protocol_profit_share) = self.apply_realized_profit_share(
             vault_equity,
             vault,
+            vault_config,
             vault_protocol,
             now,
             user_stats,
             fuel_overflow,
+            realized_shares,
         )?;
 
         let (withdraw_value, n_shares) = withdraw_unit.get_withdraw_value_and_shares(
@@ -412,14 +838,17 @@ impl VaultDepositor {
         let user_vault_shares_before = vault.user_shares;
         let protocol_shares_before = vault.get_protocol_shares(vault_protocol);
 
+        // large requests mature after an extra notice period, encoded by pushing the request ts forward
+        let notice_delay = vault.large_withdraw_notice_delay(withdraw_value, vault_equity)?;
         self.last_withdraw_request.set(
             vault_shares_before,
             n_shares,
             withdraw_value,
             vault_equity,
-            now,
+            now.safe_add(notice_delay)?,
         )?;
         vault.total_withdraw_requested = vault.total_withdraw_requested.safe_add(withdraw_value)?;
+        self.increment_active_withdraw_request_count(vault)?;
 
         let vault_shares_after = self.checked_vault_shares(vault)?;
         let protocol_shares_after = vault.get_protocol_shares(vault_protocol);
@@ -482,13 +911,15 @@ impl VaultDepositor {
         &mut self,
         vault_equity: u64,
         vault: &mut Vault,
+        vault_config: &VaultConfig,
         vault_protocol: &mut Option<RefMut<VaultProtocol>>,
         now: i64,
         user_stats: &UserStats,
         fuel_overflow: &Option<AccountLoader<FuelOverflow>>,
         deposit_oracle_price: i64,
+        withdraw_settlement: Option<&VaultWithdrawSettlement>,
     ) -> Result<()> {
-        self.apply_rebase(vault, vault_protocol, vault_equity)?;
+        self.apply_rebase(vault, vault_protocol, vault_equity, now)?;
 
         let vd_vault_shares_before: u128 = self.checked_vault_shares(vault)?;
         let total_vault_shares_before = vault.total_shares;
@@ -500,13 +931,15 @@ impl VaultDepositor {
             management_fee_shares,
             protocol_fee_payment,
             protocol_fee_shares,
-        } = vault.apply_fee(vault_protocol, vault_equity, now)?;
+        } = vault.apply_fee(vault_config, vault_protocol, vault_equity, now)?;
 
-        self.update_cumulative_fuel_amount(now, vault, user_stats, fuel_overflow)?;
+        self.update_cumulative_fuel_amount(now, vault, vault_config, user_stats, fuel_overflow)?;
 
-        let vault_shares_lost = self
-            .last_withdraw_request
-            .calculate_shares_lost(vault, vault_equity)?;
+        let vault_shares_lost = self.last_withdraw_request.calculate_shares_lost(
+            vault,
+            vault_equity,
+            withdraw_settlement,
+        )?;
 
         // only deduct lost shares if user doesn't own 100% of the vault
         let user_owns_entire_vault = total_vault_shares_before == vd_vault_shares_before;
@@ -574,8 +1007,10 @@ impl VaultDepositor {
         vault.total_withdraw_requested = vault
             .total_withdraw_requested
             .safe_sub(self.last_withdraw_request.value)?;
+        self.decrement_active_withdraw_request_count(vault);
 
         self.last_withdraw_request.reset(now)?;
+        self.remove_flag(VaultDepositorFlag::QueuedWithdraw);
 
         Ok(())
     }
@@ -585,6 +1020,36 @@ impl VaultDepositor {
         &mut self,
         vault_equity: u64,
         vault: &mut Vault,
+        vault_config: &VaultConfig,
+        vault_protocol: &mut Option<RefMut<VaultProtocol>>,
+        now: i64,
+        user_stats: &UserStats,
+        fuel_overflow: &Option<AccountLoader<FuelOverflow>>,
+        deposit_oracle_price: i64,
+    ) -> Result<(u64, bool)> {
+        self.withdraw_with_settlement(
+            vault_equity,
+            None,
+            vault,
+            vault_config,
+            vault_protocol,
+            now,
+            user_stats,
+            fuel_overflow,
+            deposit_oracle_price,
+        )
+    }
+
+    /// [`VaultDepositor::withdraw`] for vaults that settle withdraws. The rebase and fees still run at the live
+    /// `vault_equity`, only the payout is valued at the settlement's share price, see
+    /// [`VaultWithdrawSettlement::settled_withdraw_amount`].
+    #[allow(clippy::too_many_arguments)]
+    pub fn withdraw_with_settlement(
+        &mut self,
+        vault_equity: u64,
+        settlement: Option<&VaultWithdrawSettlement>,
+        vault: &mut Vault,
+        vault_config: &VaultConfig,
         vault_protocol: &mut Option<RefMut<VaultProtocol>>,
         now: i64,
         user_stats: &UserStats,
@@ -594,9 +1059,35 @@ impl VaultDepositor {
         self.last_withdraw_request
             .check_redeem_period_finished(vault, now)?;
 
-        self.apply_rebase(vault, vault_protocol, vault_equity)?;
+        self.settle_withdraw_request(
+            vault_equity,
+            vault,
+            vault_config,
+            vault_protocol,
+            now,
+            user_stats,
+            fuel_overflow,
+            deposit_oracle_price,
+        )
+    }
+
+    /// Burns the pending withdraw request's shares at the lesser of the request and current value without checking
+    /// the redeem period, see [`VaultDepositor::withdraw`].
+    #[allow(clippy::too_many_arguments)]
+    pub fn settle_withdraw_request(
+        &mut self,
+        vault_equity: u64,
+        vault: &mut Vault,
+        vault_config: &VaultConfig,
+        vault_protocol: &mut Option<RefMut<VaultProtocol>>,
+        now: i64,
+        user_stats: &UserStats,
+        fuel_overflow: &Option<AccountLoader<FuelOverflow>>,
+        deposit_oracle_price: i64,
+    ) -> Result<(u64, bool)> {
+        self.apply_rebase(vault, vault_protocol, vault_equity, now)?;
 
-        self.update_cumulative_fuel_amount(now, vault, user_stats, fuel_overflow)?;
+        self.update_cumulative_fuel_amount(now, vault, vault_config, user_stats, fuel_overflow)?;
 
         let vault_shares_before: u128 = self.checked_vault_shares(vault)?;
         let total_vault_shares_before = vault.total_shares;
@@ -621,11 +1112,15 @@ impl VaultDepositor {
             management_fee_shares,
             protocol_fee_payment,
             protocol_fee_shares,
-        } = vault.apply_fee(vault_protocol, vault_equity, now)?;
+        } = vault.apply_fee(vault_config, vault_protocol, vault_equity, now)?;
         msg!("after management_fee vault_shares={}", self.vault_shares);
 
-        let amount: u64 =
-            depositor_shares_to_vault_amount(n_shares, vault.total_shares, vault_equity)?;
+        let amount: u64 = match settlement {
+            Some(settlement) => {
+                settlement.settled_withdraw_amount(vault, &self.last_withdraw_request)?
+            }
+            None => depositor_shares_to_vault_amount(n_shares, vault.total_shares, vault_equity)?,
+        };
 
         let withdraw_amount = amount.min(self.last_withdraw_request.value);
         msg!(
@@ -639,6 +1134,9 @@ impl VaultDepositor {
             self.last_withdraw_request.shares
         );
 
+        let (cost_basis, profit_share_fee_paid) =
+            self.calculate_lot_cost_basis(n_shares, vault_shares_before)?;
+
         self.decrease_vault_shares(n_shares, vault)?;
 
         self.total_withdraws = self.total_withdraws.saturating_add(withdraw_amount);
@@ -651,8 +1149,13 @@ impl VaultDepositor {
         vault.total_withdraw_requested = vault
             .total_withdraw_requested
             .safe_sub(self.last_withdraw_request.value)?;
+        self.decrement_active_withdraw_request_count(vault);
 
+        let payout_record =
+            self.last_withdraw_request
+                .payout_record(vault.pubkey, self.authority, amount, now);
         self.last_withdraw_request.reset(now)?;
+        self.remove_flag(VaultDepositorFlag::QueuedWithdraw);
 
         let vault_shares_after = self.checked_vault_shares(vault)?;
         let protocol_shares_after = vault.get_protocol_shares(vault_protocol);
@@ -707,28 +1210,117 @@ impl VaultDepositor {
             }
         }
 
+        emit!(payout_record);
+
+        emit!(VaultDepositorLotRecord {
+            ts: now,
+            vault: vault.pubkey,
+            depositor_authority: self.authority,
+            spot_market_index: vault.spot_market_index,
+            shares: n_shares,
+            cost_basis,
+            proceeds: withdraw_amount,
+            entry_share_price: cost_basis
+                .cast::<u128>()?
+                .safe_mul(PRICE_PRECISION)?
+                .safe_div(n_shares)?
+                .cast()?,
+            exit_share_price: withdraw_amount
+                .cast::<u128>()?
+                .safe_mul(PRICE_PRECISION)?
+                .safe_div(n_shares)?
+                .cast()?,
+            holding_period: now.safe_sub(self.last_valid_ts)?,
+            profit_share_fee_paid,
+        });
+
         let finishing_liquidation = vault.liquidation_delegate == self.authority;
 
         Ok((withdraw_amount, finishing_liquidation))
     }
 
+    /// Charges profit share on the whole position. A no-op, besides syncing fuel, if the vault's [`VaultConfig`] is
+    /// in [`ProfitShareRealization::Realized`].
     #[allow(clippy::too_many_arguments)]
     pub fn apply_profit_share(
         &mut self,
         vault_equity: u64,
         vault: &mut Vault,
+        vault_config: &VaultConfig,
+        vault_protocol: &mut Option<RefMut<VaultProtocol>>,
+        now: i64,
+        user_stats: &UserStats,
+        fuel_overflow: &Option<AccountLoader<FuelOverflow>>,
+    ) -> Result<(u64, u64)> {
+        let realized_shares =
+            if vault_config.profit_share_realization()? == ProfitShareRealization::Unrealized {
+                self.get_vault_shares()
+            } else {
+                0
+            };
+        self.apply_realized_profit_share(
+            vault_equity,
+            vault,
+            vault_config,
+            vault_protocol,
+            now,
+            user_stats,
+            fuel_overflow,
+            realized_shares,
+        )
+    }
+
+    /// Charges profit share on `realized_shares` of the position only, see
+    /// [`VaultDepositorBase::calculate_realized_profit_share_and_update`]
+    #[allow(clippy::too_many_arguments)]
+    pub fn apply_realized_profit_share(
+        &mut self,
+        vault_equity: u64,
+        vault: &mut Vault,
+        vault_config: &VaultConfig,
         vault_protocol: &mut Option<RefMut<VaultProtocol>>,
         now: i64,
         user_stats: &UserStats,
         fuel_overflow: &Option<AccountLoader<FuelOverflow>>,
+        realized_shares: u128,
     ) -> Result<(u64, u64)> {
         validate!(
             !self.last_withdraw_request.pending(),
             ErrorCode::InvalidVaultDeposit,
             "Cannot apply profit share to depositor with pending withdraw request"
         )?;
-        self.update_cumulative_fuel_amount(now, vault, user_stats, fuel_overflow)?;
-        VaultDepositorBase::apply_profit_share(self, vault_equity, vault, vault_protocol)
+        self.with_fuel_sync(
+            now,
+            vault,
+            vault_config,
+            user_stats,
+            fuel_overflow,
+            |vd, vault| {
+                VaultDepositorBase::apply_realized_profit_share(
+                    vd,
+                    vault_equity,
+                    vault,
+                    vault_config,
+                    vault_protocol,
+                    realized_shares,
+                )
+            },
+        )
+    }
+
+    /// Every deposit, withdraw request, cancel, withdraw and profit realization syncs the depositor's fuel, so its last
+    /// fuel update is the last time its position was touched.
+    pub fn validate_realize_profits_interval(&self, now: i64) -> Result<()> {
+        let next_ts =
+            (self.last_fuel_update_ts as i64).safe_add(MIN_DEPOSITOR_REALIZE_PROFITS_INTERVAL)?;
+        validate!(
+            now >= next_ts,
+            ErrorCode::VaultDepositorThrottled,
+            "depositor can realize profits again at {}",
+            next_ts
+        )?;
+
+        Ok(())
     }
 
     #[allow(clippy::too_many_arguments)]
@@ -736,6 +1328,7 @@ impl VaultDepositor {
         &mut self,
         vault_equity: u64,
         vault: &mut Vault,
+        vault_config: &VaultConfig,
         vault_protocol: &mut Option<RefMut<VaultProtocol>>,
         now: i64,
         user_stats: &UserStats,
@@ -747,7 +1340,7 @@ impl VaultDepositor {
             management_fee_shares,
             protocol_fee_payment,
             protocol_fee_shares,
-        } = vault.apply_fee(vault_protocol, vault_equity, now)?;
+        } = vault.apply_fee(vault_config, vault_protocol, vault_equity, now)?;
 
         let vault_shares_before = self.checked_vault_shares(vault)?;
         let total_vault_shares_before = vault.total_shares;
@@ -757,6 +1350,7 @@ impl VaultDepositor {
         let (manager_profit_share, protocol_profit_share) = self.apply_profit_share(
             vault_equity,
             vault,
+            vault_config,
             vault_protocol,
             now,
             user_stats,
@@ -827,6 +1421,39 @@ impl VaultDepositor {
         spot_market_map: &SpotMarketMap,
         oracle_map: &mut OracleMap,
     ) -> Result<()> {
+        let withdraw_blocker = self.simulate_withdraw(
+            vault,
+            vault_equity,
+            drift_user,
+            perp_market_map,
+            spot_market_map,
+            oracle_map,
+        )?;
+
+        if withdraw_blocker == WithdrawBlocker::None {
+            msg!("depositor is able to withdraw");
+            return Err(ErrorCode::DriftError.into());
+        }
+
+        // taking over the vault can't unblock a paused market
+        if withdraw_blocker == WithdrawBlocker::MarketPaused {
+            return Err(ErrorCode::SpotMarketWithdrawPaused.into());
+        }
+
+        Ok(())
+    }
+
+    /// Simulates withdrawing the pending request from the drift user and returns what would block it. The drift
+    /// user and spot market are restored before returning.
+    pub fn simulate_withdraw(
+        &self,
+        vault: &Vault,
+        vault_equity: u64,
+        drift_user: &mut User,
+        perp_market_map: &PerpMarketMap,
+        spot_market_map: &SpotMarketMap,
+        oracle_map: &mut OracleMap,
+    ) -> Result<WithdrawBlocker> {
         let shares_value = depositor_shares_to_vault_amount(
             self.last_withdraw_request.shares,
             vault.total_shares,
@@ -835,19 +1462,25 @@ impl VaultDepositor {
         let withdraw_amount = self.last_withdraw_request.value.min(shares_value);
 
         let mut spot_market = spot_market_map.get_ref_mut(&vault.spot_market_index)?;
+        if is_withdraw_paused(&spot_market) {
+            return Ok(WithdrawBlocker::MarketPaused);
+        }
 
         // Save relevant data before updating balances
         let spot_market_deposit_balance_before = spot_market.deposit_balance;
         let spot_market_borrow_balance_before = spot_market.borrow_balance;
         let user_spot_position_before = drift_user.spot_positions;
 
+        let spot_position = drift_user.force_get_spot_position_mut(vault.spot_market_index)?;
         update_spot_balances(
             withdraw_amount.cast()?,
             &SpotBalanceType::Borrow,
             &mut spot_market,
-            drift_user.force_get_spot_position_mut(vault.spot_market_index)?,
+            spot_position,
             true,
         )?;
+        let creates_borrow = spot_position.balance_type == SpotBalanceType::Borrow
+            && spot_position.scaled_balance > 0;
 
         drop(spot_market);
 
@@ -872,14 +1505,7 @@ impl VaultDepositor {
             }
         };
 
-        if sufficient_collateral && margin_trading_ok {
-            msg!(
-                "depositor is able to withdraw. sufficient collateral = {} margin trading ok = {}",
-                sufficient_collateral,
-                margin_trading_ok
-            );
-            return Err(ErrorCode::DriftError.into());
-        }
+        let reduce_only_ok = !(creates_borrow && drift_user.is_reduce_only());
 
         // Must reset drift accounts afterward else ix will fail
         let mut spot_market = spot_market_map.get_ref_mut(&vault.spot_market_index)?;
@@ -888,19 +1514,164 @@ impl VaultDepositor {
 
         drift_user.spot_positions = user_spot_position_before;
 
-        Ok(())
+        msg!(
+            "sufficient collateral = {} margin trading ok = {} reduce only ok = {}",
+            sufficient_collateral,
+            margin_trading_ok,
+            reduce_only_ok
+        );
+
+        Ok(if !sufficient_collateral {
+            WithdrawBlocker::InsufficientCollateral
+        } else if !margin_trading_ok {
+            WithdrawBlocker::MarginTradingDisabled
+        } else if !reduce_only_ok {
+            WithdrawBlocker::ReduceOnly
+        } else {
+            WithdrawBlocker::None
+        })
+    }
+
+    /// The depositor's position at `vault_equity` and what a full withdraw would be charged now. Runs on copies of
+    /// the accounts, nothing is updated.
+    pub fn portfolio_position(
+        &self,
+        vault: &Vault,
+        vault_config: &VaultConfig,
+        vault_protocol: Option<VaultProtocol>,
+        vault_equity: u64,
+        now: i64,
+    ) -> Result<PortfolioPosition> {
+        let mut vault_depositor = *self;
+        let mut vault = *vault;
+        let vault_protocol = vault_protocol.map(RefCell::new);
+        let mut vp = vault_protocol.as_ref().map(|vp| vp.borrow_mut());
+
+        vault_depositor.apply_rebase(&mut vault, &mut vp, vault_equity, now)?;
+        let value = depositor_shares_to_vault_amount(
+            vault_depositor.checked_vault_shares(&vault)?,
+            vault.total_shares,
+            vault_equity,
+        )?;
+
+        vault.apply_fee(vault_config, &mut vp, vault_equity, now)?;
+        vault_depositor.apply_rebase(&mut vault, &mut vp, vault_equity, now)?;
+        let value_after_fees = depositor_shares_to_vault_amount(
+            vault_depositor.checked_vault_shares(&vault)?,
+            vault.total_shares,
+            vault_equity,
+        )?;
+
+        let (manager_profit_share, protocol_profit_share) =
+            VaultDepositorBase::calculate_profit_share_and_update(
+                &mut vault_depositor,
+                value_after_fees,
+                &vault,
+                vault_config,
+                &mut vp,
+            )?;
+
+        Ok(PortfolioPosition {
+            vault_depositor: self.pubkey,
+            spot_market_index: vault.spot_market_index,
+            value,
+            accrued_fees: value.saturating_sub(value_after_fees),
+            profit_share_owed: manager_profit_share
+                .safe_add(protocol_profit_share)?
+                .cast()?,
+            withdraw_request_value: self.last_withdraw_request.value,
+        })
+    }
+
+    /// Accrues fuel on the depositor's current shares before running `f`, which may change `vault_shares`. Every
+    /// share changing operation should go through this, otherwise fuel accrued since the last update is credited at
+    /// the new share count.
+    pub fn with_fuel_sync<T>(
+        &mut self,
+        now: i64,
+        vault: &mut Vault,
+        vault_config: &VaultConfig,
+        user_stats: &UserStats,
+        fuel_overflow: &Option<AccountLoader<FuelOverflow>>,
+        f: impl FnOnce(&mut Self, &mut Vault) -> Result<T>,
+    ) -> Result<T> {
+        self.update_cumulative_fuel_amount(now, vault, vault_config, user_stats, fuel_overflow)?;
+        f(self, vault)
     }
 
     pub fn update_cumulative_fuel_amount(
         &mut self,
         now: i64,
         vault: &mut Vault,
+        vault_config: &VaultConfig,
         user_stats: &UserStats,
         fuel_overflow: &Option<AccountLoader<FuelOverflow>>,
     ) -> Result<u128> {
+        if let Some(season_start_ts) = vault.pending_fuel_season(fuel_overflow)? {
+            // the vault is about to close its fuel season, settle the vd into it while the vault's fuel per share
+            // is still the old season's
+            self.settle_fuel_season(now, vault, vault_config, season_start_ts)?;
+        }
+
         let cumulative_fuel_per_share =
             vault.update_cumulative_fuel_per_share(now, user_stats, fuel_overflow)?;
 
+        if self.last_fuel_update_ts != MAGIC_FUEL_START_TS
+            && self.last_fuel_update_ts < vault.fuel_season_start_ts
+        {
+            // the vault closed the season the vd last accrued in without it, only the fuel up to the vd's last
+            // update can be recorded. Batch cranks settle every depositor passed before the vault rolls over.
+            msg!(
+                "vd last updated at {} before fuel season start {}. Rolling over the vd.",
+                self.last_fuel_update_ts,
+                vault.fuel_season_start_ts
+            );
+            self.reset_fuel_amount(now);
+            self.last_fuel_update_ts = vault.fuel_season_start_ts;
+        }
+
+        self.accrue_fuel(now, vault, vault_config, cumulative_fuel_per_share)?;
+
+        Ok(self.fuel_amount)
+    }
+
+    /// Settles the vd's fuel into the fuel season the vault is about to close, at the vault's fuel per share as of its
+    /// last update, and starts the vd in the season beginning at `season_start_ts`. Emits the vd's
+    /// `FuelSeasonRecord` for the closed season and returns its fuel in it.
+    pub fn settle_fuel_season(
+        &mut self,
+        now: i64,
+        vault: &Vault,
+        vault_config: &VaultConfig,
+        season_start_ts: u32,
+    ) -> Result<u128> {
+        if self.last_fuel_update_ts == MAGIC_FUEL_START_TS
+            || self.last_fuel_update_ts >= season_start_ts
+        {
+            return Ok(0);
+        }
+
+        self.accrue_fuel(now, vault, vault_config, vault.cumulative_fuel_per_share)?;
+        let season_fuel_amount = self.fuel_amount;
+
+        msg!(
+            "Settling vd fuel season ending at {}: fuel_amount={}",
+            season_start_ts,
+            season_fuel_amount
+        );
+        self.reset_fuel_amount(now);
+        self.last_fuel_update_ts = season_start_ts;
+
+        Ok(season_fuel_amount)
+    }
+
+    fn accrue_fuel(
+        &mut self,
+        now: i64,
+        vault: &Vault,
+        vault_config: &VaultConfig,
+        cumulative_fuel_per_share: u128,
+    ) -> Result<()> {
         if (now as u32) > self.last_fuel_update_ts {
             // self.last_fuel_update_ts == 0:
             //   - VaultDepositors created before fuel distribution update, no fuel applied yet.
@@ -922,109 +1693,1071 @@ impl VaultDepositor {
                         .safe_sub(self.cumulative_fuel_per_share_amount)?;
                     let new_fuel = fuel_per_share_delta
                         .safe_mul(vd_shares)?
-                        .safe_div(FUEL_SHARE_PRECISION)?;
+                        .safe_div(FUEL_SHARE_PRECISION)?
+                        .safe_mul(
+                            vault_config.get_fuel_boost_multiplier(self.get_holding_period())?,
+                        )?
+                        .safe_div(PERCENTAGE_PRECISION)?;
 
                     self.fuel_amount = self.fuel_amount.safe_add(new_fuel)?;
                 }
             }
 
-            self.cumulative_fuel_per_share_amount = cumulative_fuel_per_share;
-            self.last_fuel_update_ts = now as u32;
-        }
+            self.cumulative_fuel_per_share_amount = cumulative_fuel_per_share;
+            self.last_fuel_update_ts = now as u32;
+        }
+
+        Ok(())
+    }
+
+    /// How long the depositor held its shares without withdrawing as of its last fuel update, which is when the
+    /// fuel being accrued started. Withdraws and cancels reset the request ts, and a pending request gets no boost.
+    pub fn get_holding_period(&self) -> i64 {
+        if self.last_withdraw_request.pending() || self.last_fuel_update_ts == MAGIC_FUEL_START_TS {
+            return 0;
+        }
+
+        let holding_since = self.last_withdraw_request.ts.max(self.last_valid_ts);
+        (self.last_fuel_update_ts as i64).saturating_sub(holding_since)
+    }
+
+    pub fn reset_fuel_amount(&mut self, now: i64) {
+        emit!(FuelSeasonRecord {
+            ts: now,
+            authority: self.authority,
+            fuel_insurance: 0,
+            fuel_deposits: 0,
+            fuel_borrows: 0,
+            fuel_positions: 0,
+            fuel_taker: 0,
+            fuel_maker: 0,
+            fuel_total: self.fuel_amount,
+        });
+        self.fuel_amount = 0;
+        self.cumulative_fuel_per_share_amount = 0;
+        self.last_fuel_update_ts = now as u32;
+    }
+}
+
+#[cfg(test)]
+mod vault_v1_tests {
+    use std::cell::RefCell;
+
+    use anchor_lang::prelude::Pubkey;
+    use drift::math::casting::Cast;
+    use drift::math::constants::{
+        ONE_YEAR, PERCENTAGE_PRECISION_U64, QUOTE_PRECISION_I128, QUOTE_PRECISION_U64,
+        SPOT_WEIGHT_PRECISION,
+    };
+    use drift::math::insurance::if_shares_to_vault_amount;
+    use drift::state::user::UserStats;
+
+    use crate::constants::MAGIC_FUEL_START_TS;
+    use crate::error::ErrorCode;
+    use crate::state::{
+        FeePolicy, FuelDistributionMode, ProfitShareMode, ProfitShareRealization, VaultConfig,
+        VaultDepositorBase, VaultDepositorFlag, VaultWithdrawSettlement,
+        MIN_DEPOSITOR_REALIZE_PROFITS_INTERVAL, VAULT_DEPOSITOR_VERSION,
+    };
+    use crate::{assert_eq_within, Vault, VaultDepositor, VaultProtocol, WithdrawUnit};
+
+    #[test]
+    fn base_init() {
+        let now = 1337;
+        let vd = VaultDepositor::new(Pubkey::default(), Pubkey::default(), Pubkey::default(), now);
+        assert_eq!(vd.vault_shares_base, 0);
+        assert_eq!(vd.last_valid_ts, now);
+    }
+
+    #[test]
+    fn test_deposit_equity_then_immediate_withdraw_request() {
+        let now = 1000;
+        let full_equity: u64 = 1000 * QUOTE_PRECISION_U64;
+        let mut vault = Vault {
+            total_shares: full_equity.cast().unwrap(),
+            user_shares: full_equity.cast().unwrap(),
+            ..Vault::default()
+        };
+        let vp = RefCell::new(VaultProtocol::default());
+
+        // $100 of unsettled loss in a market that weighs unsettled pnl at 80%
+        let unsettled_pnl = -100 * QUOTE_PRECISION_I128;
+        assert_eq!(
+            Vault::unsettled_loss_haircut(unsettled_pnl, SPOT_WEIGHT_PRECISION * 4 / 5).unwrap(),
+            20 * QUOTE_PRECISION_I128
+        );
+        assert_eq!(
+            Vault::unsettled_loss_haircut(-unsettled_pnl, SPOT_WEIGHT_PRECISION * 4 / 5).unwrap(),
+            0
+        );
+        let deposit_equity = full_equity + 20 * QUOTE_PRECISION_U64;
+
+        let vd =
+            &mut VaultDepositor::new(Pubkey::default(), Pubkey::default(), Pubkey::default(), now);
+        let amount: u64 = 100 * QUOTE_PRECISION_U64;
+        vd.deposit(
+            amount,
+            deposit_equity,
+            &mut vault,
+            &VaultConfig::default(),
+            &mut Some(vp.borrow_mut()),
+            now,
+            &UserStats::default(),
+            &None,
+            0,
+        )
+        .unwrap();
+
+        // withdraws use full marks
+        vd.request_withdraw(
+            PERCENTAGE_PRECISION_U64,
+            WithdrawUnit::SharesPercent,
+            full_equity + amount,
+            &mut vault,
+            &VaultConfig::default(),
+            &mut Some(vp.borrow_mut()),
+            now,
+            &UserStats::default(),
+            &None,
+            0,
+        )
+        .unwrap();
+        assert!(vd.last_withdraw_request.value <= amount);
+    }
+
+    #[test]
+    fn test_backfill_counts() {
+        let now = 1000;
+        let mut vault = Vault::default();
+        let vp = RefCell::new(VaultProtocol::default());
+
+        // initialized before the counters were added
+        let vd =
+            &mut VaultDepositor::new(Pubkey::default(), Pubkey::default(), Pubkey::default(), now);
+        vd.flags = 0;
+
+        let amount: u64 = 100 * QUOTE_PRECISION_U64;
+        vd.deposit(
+            amount,
+            0,
+            &mut vault,
+            &VaultConfig::default(),
+            &mut Some(vp.borrow_mut()),
+            now,
+            &UserStats::default(),
+            &None,
+            0,
+        )
+        .unwrap();
+
+        vd.request_withdraw(
+            amount / 2,
+            WithdrawUnit::Token,
+            amount,
+            &mut vault,
+            &VaultConfig::default(),
+            &mut Some(vp.borrow_mut()),
+            now,
+            &UserStats::default(),
+            &None,
+            0,
+        )
+        .unwrap();
+        assert_eq!(vault.depositor_count, 0);
+        assert_eq!(vault.active_withdraw_request_count, 0);
+
+        assert!(vd.backfill_counts(&mut vault).unwrap());
+        assert!(vd.has_flag(VaultDepositorFlag::Counted));
+        assert_eq!(vault.depositor_count, 1);
+        assert_eq!(vault.active_withdraw_request_count, 1);
+
+        // counted once
+        assert!(!vd.backfill_counts(&mut vault).unwrap());
+        assert_eq!(vault.depositor_count, 1);
+        assert_eq!(vault.active_withdraw_request_count, 1);
+
+        vd.cancel_withdraw_request(
+            amount,
+            &mut vault,
+            &VaultConfig::default(),
+            &mut Some(vp.borrow_mut()),
+            now,
+            &UserStats::default(),
+            &None,
+            0,
+        )
+        .unwrap();
+        assert_eq!(vault.active_withdraw_request_count, 0);
+
+        // an uncounted depositor never moves the counters
+        let legacy_vd =
+            &mut VaultDepositor::new(Pubkey::default(), Pubkey::default(), Pubkey::default(), now);
+        legacy_vd.flags = 0;
+        legacy_vd
+            .deposit(
+                amount,
+                amount,
+                &mut vault,
+                &VaultConfig::default(),
+                &mut Some(vp.borrow_mut()),
+                now,
+                &UserStats::default(),
+                &None,
+                0,
+            )
+            .unwrap();
+        legacy_vd
+            .request_withdraw(
+                amount / 2,
+                WithdrawUnit::Token,
+                2 * amount,
+                &mut vault,
+                &VaultConfig::default(),
+                &mut Some(vp.borrow_mut()),
+                now,
+                &UserStats::default(),
+                &None,
+                0,
+            )
+            .unwrap();
+        legacy_vd
+            .cancel_withdraw_request(
+                2 * amount,
+                &mut vault,
+                &VaultConfig::default(),
+                &mut Some(vp.borrow_mut()),
+                now,
+                &UserStats::default(),
+                &None,
+                0,
+            )
+            .unwrap();
+        assert_eq!(vault.depositor_count, 1);
+        assert_eq!(vault.active_withdraw_request_count, 0);
+
+        let mut other_vault = Vault {
+            pubkey: Pubkey::new_unique(),
+            ..Vault::default()
+        };
+        assert_eq!(
+            legacy_vd.backfill_counts(&mut other_vault),
+            Err(ErrorCode::InvalidVaultDepositorBatch.into())
+        );
+    }
+
+    #[test]
+    fn test_deposit_withdraw() {
+        let now = 1000;
+        let mut vault = Vault::default();
+        let vp = RefCell::new(VaultProtocol::default());
+
+        let vd =
+            &mut VaultDepositor::new(Pubkey::default(), Pubkey::default(), Pubkey::default(), now);
+
+        let vault_equity: u64 = 100 * QUOTE_PRECISION_U64; // $100 in total equity
+        let amount: u64 = 100 * QUOTE_PRECISION_U64; // $100 of new deposits to add to total equity, for new total of $200
+        vd.deposit(
+            amount,
+            vault_equity,
+            &mut vault,
+            &VaultConfig::default(),
+            &mut Some(vp.borrow_mut()),
+            now + 20,
+            &UserStats::default(),
+            &None,
+            0,
+        )
+        .unwrap();
+
+        let vault_equity: u64 = 200 * QUOTE_PRECISION_U64;
+
+        vd.request_withdraw(
+            amount.cast().unwrap(),
+            WithdrawUnit::Token,
+            vault_equity,
+            &mut vault,
+            &VaultConfig::default(),
+            &mut Some(vp.borrow_mut()),
+            now + 20,
+            &UserStats::default(),
+            &None,
+            0,
+        )
+        .unwrap();
+
+        let (withdraw_amount, _) = vd
+            .withdraw(
+                vault_equity,
+                &mut vault,
+                &VaultConfig::default(),
+                &mut Some(vp.borrow_mut()),
+                now + 20,
+                &UserStats::default(),
+                &None,
+                0,
+            )
+            .unwrap();
+        assert_eq!(vd.vault_shares_base, 0);
+        assert_eq!(withdraw_amount, amount);
+    }
+
+    #[test]
+    fn test_deposit_too_small() {
+        let now = 1000;
+        let mut vault = Vault {
+            total_shares: 1_000,
+            user_shares: 1_000,
+            ..Vault::default()
+        };
+        let vp = RefCell::new(VaultProtocol::default());
+        let vd =
+            &mut VaultDepositor::new(Pubkey::default(), Pubkey::default(), Pubkey::default(), now);
+
+        // a share is worth 1_000, anything less mints nothing
+        let vault_equity = 1_000_000;
+        let result = vd.deposit(
+            999,
+            vault_equity,
+            &mut vault,
+            &VaultConfig::default(),
+            &mut Some(vp.borrow_mut()),
+            now,
+            &UserStats::default(),
+            &None,
+            0,
+        );
+        assert_eq!(result, Err(ErrorCode::DepositTooSmall.into()));
+
+        vd.deposit(
+            1_000,
+            vault_equity,
+            &mut vault,
+            &VaultConfig::default(),
+            &mut Some(vp.borrow_mut()),
+            now,
+            &UserStats::default(),
+            &None,
+            0,
+        )
+        .unwrap();
+        assert_eq!(vd.get_vault_shares(), 1);
+    }
+
+    #[test]
+    fn test_zero_equity_withdraw_lifecycle() {
+        let now = 1000;
+        let mut vault = Vault {
+            management_fee: 20_000,
+            ..Vault::default()
+        };
+        let vp = RefCell::new(VaultProtocol::default());
+
+        let vd =
+            &mut VaultDepositor::new(Pubkey::default(), Pubkey::default(), Pubkey::default(), now);
+
+        let amount: u64 = 100 * QUOTE_PRECISION_U64;
+        vd.deposit(
+            amount,
+            0,
+            &mut vault,
+            &VaultConfig::default(),
+            &mut Some(vp.borrow_mut()),
+            now,
+            &UserStats::default(),
+            &None,
+            0,
+        )
+        .unwrap();
+        let shares = vd.get_vault_shares();
+
+        // the vault lost everything
+        let vault_equity = 0;
+        let now = now + 100;
+
+        assert_eq!(
+            vd.request_withdraw(
+                amount,
+                WithdrawUnit::Token,
+                vault_equity,
+                &mut vault,
+                &VaultConfig::default(),
+                &mut Some(vp.borrow_mut()),
+                now,
+                &UserStats::default(),
+                &None,
+                0,
+            ),
+            Err(ErrorCode::ZeroVaultEquity.into())
+        );
+
+        vd.request_withdraw(
+            shares.cast().unwrap(),
+            WithdrawUnit::Shares,
+            vault_equity,
+            &mut vault,
+            &VaultConfig::default(),
+            &mut Some(vp.borrow_mut()),
+            now,
+            &UserStats::default(),
+            &None,
+            0,
+        )
+        .unwrap();
+        assert_eq!(vd.last_withdraw_request.shares, shares);
+        assert_eq!(vd.last_withdraw_request.value, 0);
+        assert_eq!(vault.active_withdraw_request_count, 1);
+
+        // the zero value request is still pending
+        assert_eq!(
+            vd.request_withdraw(
+                shares.cast().unwrap(),
+                WithdrawUnit::Shares,
+                vault_equity,
+                &mut vault,
+                &VaultConfig::default(),
+                &mut Some(vp.borrow_mut()),
+                now,
+                &UserStats::default(),
+                &None,
+                0,
+            ),
+            Err(ErrorCode::VaultWithdrawRequestInProgress.into())
+        );
+
+        // cancelling forfeits nothing
+        vd.cancel_withdraw_request(
+            vault_equity,
+            &mut vault,
+            &VaultConfig::default(),
+            &mut Some(vp.borrow_mut()),
+            now,
+            &UserStats::default(),
+            &None,
+            0,
+            None,
+        )
+        .unwrap();
+        assert_eq!(vd.get_vault_shares(), shares);
+        assert!(!vd.last_withdraw_request.pending());
+        assert_eq!(vault.active_withdraw_request_count, 0);
+
+        vd.request_withdraw(
+            PERCENTAGE_PRECISION_U64,
+            WithdrawUnit::SharesPercent,
+            vault_equity,
+            &mut vault,
+            &VaultConfig::default(),
+            &mut Some(vp.borrow_mut()),
+            now,
+            &UserStats::default(),
+            &None,
+            0,
+        )
+        .unwrap();
+
+        // the shares are burned for nothing
+        let (withdraw_amount, _) = vd
+            .withdraw(
+                vault_equity,
+                &mut vault,
+                &VaultConfig::default(),
+                &mut Some(vp.borrow_mut()),
+                now,
+                &UserStats::default(),
+                &None,
+                0,
+            )
+            .unwrap();
+        assert_eq!(withdraw_amount, 0);
+        assert_eq!(vd.get_vault_shares(), 0);
+        assert_eq!(vault.user_shares, 0);
+        assert_eq!(vault.total_shares, 0);
+        assert_eq!(vault.active_withdraw_request_count, 0);
+    }
+
+    #[test]
+    fn test_large_withdraw_notice_period() {
+        let now = 1000;
+        let mut vault = Vault {
+            redeem_period: 100,
+            large_withdraw_threshold: PERCENTAGE_PRECISION_U64 as u32 / 4, // 25%
+            large_withdraw_notice_period: 500,
+            ..Vault::default()
+        };
+        let vp = RefCell::new(VaultProtocol::default());
+
+        let vd =
+            &mut VaultDepositor::new(Pubkey::default(), Pubkey::default(), Pubkey::default(), now);
+
+        let amount: u64 = 100 * QUOTE_PRECISION_U64;
+        vd.deposit(
+            amount,
+            0,
+            &mut vault,
+            &VaultConfig::default(),
+            &mut Some(vp.borrow_mut()),
+            now,
+            &UserStats::default(),
+            &None,
+            0,
+        )
+        .unwrap();
+
+        // 20% of equity, standard redeem period
+        vd.request_withdraw(
+            (20 * QUOTE_PRECISION_U64).cast().unwrap(),
+            WithdrawUnit::Token,
+            amount,
+            &mut vault,
+            &VaultConfig::default(),
+            &mut Some(vp.borrow_mut()),
+            now,
+            &UserStats::default(),
+            &None,
+            0,
+        )
+        .unwrap();
+        assert_eq!(vd.last_withdraw_request.ts, now);
+        vd.last_withdraw_request
+            .check_redeem_period_finished(&vault, now + 100)
+            .unwrap();
+
+        vd.cancel_withdraw_request(
+            amount.cast().unwrap(),
+            &mut vault,
+            &VaultConfig::default(),
+            &mut Some(vp.borrow_mut()),
+            now,
+            &UserStats::default(),
+            &None,
+            0,
+            None,
+        )
+        .unwrap();
+
+        // 50% of equity waits the extra notice period
+        vd.request_withdraw(
+            (50 * QUOTE_PRECISION_U64).cast().unwrap(),
+            WithdrawUnit::Token,
+            amount,
+            &mut vault,
+            &VaultConfig::default(),
+            &mut Some(vp.borrow_mut()),
+            now,
+            &UserStats::default(),
+            &None,
+            0,
+        )
+        .unwrap();
+        assert_eq!(vd.last_withdraw_request.ts, now + 500);
+        assert!(vd
+            .last_withdraw_request
+            .check_redeem_period_finished(&vault, now + 100)
+            .is_err());
+        vd.last_withdraw_request
+            .check_redeem_period_finished(&vault, now + 600)
+            .unwrap();
+    }
+
+    #[test]
+    fn test_active_withdraw_request_count() {
+        let now = 1000;
+        let mut vault = Vault {
+            redeem_period: 100,
+            ..Vault::default()
+        };
+        let vp = RefCell::new(VaultProtocol::default());
+
+        let vd =
+            &mut VaultDepositor::new(Pubkey::default(), Pubkey::default(), Pubkey::default(), now);
+
+        let amount: u64 = 100 * QUOTE_PRECISION_U64;
+        vd.deposit(
+            amount,
+            0,
+            &mut vault,
+            &VaultConfig::default(),
+            &mut Some(vp.borrow_mut()),
+            now,
+            &UserStats::default(),
+            &None,
+            0,
+        )
+        .unwrap();
+        assert_eq!(vault.active_withdraw_request_count, 0);
+
+        vd.request_withdraw(
+            (20 * QUOTE_PRECISION_U64).cast().unwrap(),
+            WithdrawUnit::Token,
+            amount,
+            &mut vault,
+            &VaultConfig::default(),
+            &mut Some(vp.borrow_mut()),
+            now,
+            &UserStats::default(),
+            &None,
+            0,
+        )
+        .unwrap();
+        assert_eq!(vault.active_withdraw_request_count, 1);
+
+        vd.cancel_withdraw_request(
+            amount,
+            &mut vault,
+            &VaultConfig::default(),
+            &mut Some(vp.borrow_mut()),
+            now,
+            &UserStats::default(),
+            &None,
+            0,
+            None,
+        )
+        .unwrap();
+        assert_eq!(vault.active_withdraw_request_count, 0);
+
+        vd.request_withdraw(
+            (20 * QUOTE_PRECISION_U64).cast().unwrap(),
+            WithdrawUnit::Token,
+            amount,
+            &mut vault,
+            &VaultConfig::default(),
+            &mut Some(vp.borrow_mut()),
+            now,
+            &UserStats::default(),
+            &None,
+            0,
+        )
+        .unwrap();
+        assert_eq!(vault.active_withdraw_request_count, 1);
 
-        Ok(self.fuel_amount)
+        vd.withdraw(
+            amount,
+            &mut vault,
+            &VaultConfig::default(),
+            &mut Some(vp.borrow_mut()),
+            now + 100,
+            &UserStats::default(),
+            &None,
+            0,
+        )
+        .unwrap();
+        assert_eq!(vault.active_withdraw_request_count, 0);
     }
 
-    pub fn reset_fuel_amount(&mut self, now: i64) {
-        emit!(FuelSeasonRecord {
-            ts: now,
-            authority: self.authority,
-            fuel_insurance: 0,
-            fuel_deposits: 0,
-            fuel_borrows: 0,
-            fuel_positions: 0,
-            fuel_taker: 0,
-            fuel_maker: 0,
-            fuel_total: self.fuel_amount,
-        });
-        self.fuel_amount = 0;
-        self.cumulative_fuel_per_share_amount = 0;
-        self.last_fuel_update_ts = now as u32;
+    #[test]
+    fn test_lot_cost_basis() {
+        let mut vd =
+            VaultDepositor::new(Pubkey::default(), Pubkey::default(), Pubkey::default(), 0);
+        vd.net_deposits = 100 * QUOTE_PRECISION_U64 as i64;
+        vd.profit_share_fee_paid = 10 * QUOTE_PRECISION_U64;
+
+        let (cost_basis, fee_paid) = vd
+            .calculate_lot_cost_basis(
+                25 * QUOTE_PRECISION_U64 as u128,
+                100 * QUOTE_PRECISION_U64 as u128,
+            )
+            .unwrap();
+        assert_eq!(cost_basis, 25 * QUOTE_PRECISION_U64);
+        assert_eq!(fee_paid, 5 * QUOTE_PRECISION_U64 / 2);
+
+        // net withdrawn more than deposited, no cost basis left
+        vd.net_deposits = -(QUOTE_PRECISION_U64 as i64);
+        let (cost_basis, _) = vd.calculate_lot_cost_basis(1, 100).unwrap();
+        assert_eq!(cost_basis, 0);
+
+        assert_eq!(vd.calculate_lot_cost_basis(1, 0).unwrap(), (0, 0));
     }
-}
 
-#[cfg(test)]
-mod vault_v1_tests {
-    use std::cell::RefCell;
+    #[test]
+    fn test_queue_withdraw() {
+        let mut vd =
+            VaultDepositor::new(Pubkey::default(), Pubkey::default(), Pubkey::default(), 0);
+        assert!(vd.queue_withdraw().is_err());
+
+        vd.last_withdraw_request.shares = 100;
+        vd.queue_withdraw().unwrap();
+        assert!(vd.has_flag(VaultDepositorFlag::QueuedWithdraw));
+    }
 
-    use anchor_lang::prelude::Pubkey;
-    use drift::math::casting::Cast;
-    use drift::math::constants::{PERCENTAGE_PRECISION_U64, QUOTE_PRECISION_U64};
-    use drift::math::insurance::if_shares_to_vault_amount;
-    use drift::state::user::UserStats;
+    #[test]
+    fn test_is_closable() {
+        let mut vd =
+            VaultDepositor::new(Pubkey::default(), Pubkey::default(), Pubkey::default(), 0);
+        assert!(vd.is_closable());
+
+        vd.last_withdraw_request.shares = 100;
+        assert!(!vd.is_closable());
+        vd.last_withdraw_request.shares = 0;
+
+        vd.set_vault_shares(1);
+        assert!(!vd.is_closable());
+        vd.set_vault_shares(0);
+
+        vd.add_flag(VaultDepositorFlag::Omnibus);
+        assert!(!vd.is_closable());
+        vd.remove_flag(VaultDepositorFlag::Omnibus);
+
+        vd.add_flag(VaultDepositorFlag::ParkedWithdrawRequests);
+        assert!(!vd.is_closable());
+        vd.remove_flag(VaultDepositorFlag::ParkedWithdrawRequests);
+
+        vd.custodian = Pubkey::new_unique();
+        assert!(!vd.is_closable());
+        vd.custodian = Pubkey::default();
+        assert!(vd.is_closable());
+    }
 
-    use crate::state::FuelDistributionMode;
-    use crate::{assert_eq_within, Vault, VaultDepositor, VaultProtocol, WithdrawUnit};
+    #[test]
+    fn test_transfer_authority() {
+        let vault = Pubkey::new_unique();
+        let mut from = VaultDepositor::new(vault, Pubkey::new_unique(), Pubkey::new_unique(), 0);
+        from.set_vault_shares(1_000);
+        from.net_deposits = 900;
+        from.cumulative_profit_share_amount = 100;
+        from.fuel_amount = 50;
+        from.add_flag(VaultDepositorFlag::Blacklisted);
+        let custodian = Pubkey::new_unique();
+        from.custodian = custodian;
+
+        let mut to = VaultDepositor::new(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            10,
+        );
+        assert!(from.transfer_authority(&mut to).is_err());
+
+        let mut to = VaultDepositor::new(vault, Pubkey::new_unique(), Pubkey::new_unique(), 10);
+        from.last_withdraw_request.shares = 100;
+        assert!(from.transfer_authority(&mut to).is_err());
+        from.last_withdraw_request.shares = 0;
+        from.add_flag(VaultDepositorFlag::Omnibus);
+        assert!(from.transfer_authority(&mut to).is_err());
+        from.remove_flag(VaultDepositorFlag::Omnibus);
+
+        from.transfer_authority(&mut to).unwrap();
+        assert_eq!(to.get_vault_shares(), 1_000);
+        assert_eq!(to.net_deposits, 900);
+        assert_eq!(to.cumulative_profit_share_amount, 100);
+        assert_eq!(to.fuel_amount, 50);
+        assert_eq!(to.last_valid_ts, 0);
+        assert!(to.has_flag(VaultDepositorFlag::Blacklisted));
+        assert_eq!(to.custodian, custodian);
+        assert_eq!(from.get_vault_shares(), 0);
+        assert_eq!(from.net_deposits, 0);
+        assert_eq!(from.fuel_amount, 0);
+    }
 
     #[test]
-    fn base_init() {
-        let now = 1337;
-        let vd = VaultDepositor::new(Pubkey::default(), Pubkey::default(), Pubkey::default(), now);
-        assert_eq!(vd.vault_shares_base, 0);
-        assert_eq!(vd.last_valid_ts, now);
+    fn test_realize_profits_interval() {
+        let now = 10_000;
+        let mut vd =
+            VaultDepositor::new(Pubkey::default(), Pubkey::default(), Pubkey::default(), 0);
+        vd.last_fuel_update_ts = now as u32;
+
+        assert_eq!(
+            vd.validate_realize_profits_interval(now + MIN_DEPOSITOR_REALIZE_PROFITS_INTERVAL - 1),
+            Err(ErrorCode::VaultDepositorThrottled.into())
+        );
+        vd.validate_realize_profits_interval(now + MIN_DEPOSITOR_REALIZE_PROFITS_INTERVAL)
+            .unwrap();
     }
 
     #[test]
-    fn test_deposit_withdraw() {
+    fn test_renew_withdraw_request() {
         let now = 1000;
-        let mut vault = Vault::default();
+        let mut vault = Vault {
+            redeem_period: 100,
+            ..Vault::default()
+        };
         let vp = RefCell::new(VaultProtocol::default());
 
         let vd =
             &mut VaultDepositor::new(Pubkey::default(), Pubkey::default(), Pubkey::default(), now);
 
-        let vault_equity: u64 = 100 * QUOTE_PRECISION_U64; // $100 in total equity
-        let amount: u64 = 100 * QUOTE_PRECISION_U64; // $100 of new deposits to add to total equity, for new total of $200
+        let amount: u64 = 100 * QUOTE_PRECISION_U64;
         vd.deposit(
             amount,
-            vault_equity,
+            0,
             &mut vault,
+            &VaultConfig::default(),
             &mut Some(vp.borrow_mut()),
-            now + 20,
+            now,
             &UserStats::default(),
             &None,
             0,
         )
         .unwrap();
 
-        let vault_equity: u64 = 200 * QUOTE_PRECISION_U64;
-
         vd.request_withdraw(
-            amount.cast().unwrap(),
+            (20 * QUOTE_PRECISION_U64).cast().unwrap(),
             WithdrawUnit::Token,
-            vault_equity,
+            amount,
             &mut vault,
+            &VaultConfig::default(),
             &mut Some(vp.borrow_mut()),
-            now + 20,
+            now,
             &UserStats::default(),
             &None,
             0,
         )
         .unwrap();
+        let requested_shares = vd.last_withdraw_request.shares;
 
-        let (withdraw_amount, _) = vd
-            .withdraw(
+        // matured but not yet expired
+        assert!(!vd
+            .last_withdraw_request
+            .is_expired(&vault, now + 150)
+            .unwrap());
+        assert!(vd
+            .last_withdraw_request
+            .is_expired(&vault, now + 200)
+            .unwrap());
+
+        // equity fell 20% while the request sat unexecuted
+        let vault_equity = 80 * QUOTE_PRECISION_U64;
+        let renew = |vd: &mut VaultDepositor, vault: &mut Vault, now: i64| {
+            vd.renew_withdraw_request(
                 vault_equity,
-                &mut vault,
+                vault,
                 &mut Some(vp.borrow_mut()),
-                now + 20,
+                now,
                 &UserStats::default(),
                 &None,
                 0,
             )
+        };
+
+        assert!(renew(vd, &mut vault, now + 200).is_err());
+
+        vd.add_flag(VaultDepositorFlag::AutoRenewWithdraw);
+        assert!(renew(vd, &mut vault, now + 150).is_err());
+
+        renew(vd, &mut vault, now + 200).unwrap();
+        assert_eq!(vd.last_withdraw_request.shares, requested_shares);
+        assert_eq!(vd.last_withdraw_request.value, 16 * QUOTE_PRECISION_U64);
+        assert_eq!(vd.last_withdraw_request.ts, now + 200);
+        assert_eq!(vault.total_withdraw_requested, 16 * QUOTE_PRECISION_U64);
+        assert_eq!(vault.active_withdraw_request_count, 1);
+        assert!(vd.has_flag(VaultDepositorFlag::AutoRenewWithdraw));
+    }
+
+    #[test]
+    fn test_get_holding_period() {
+        let now = 1_000_000;
+        let mut vd =
+            VaultDepositor::new(Pubkey::default(), Pubkey::default(), Pubkey::default(), now);
+        // no fuel accrued yet
+        assert_eq!(vd.get_holding_period(), 0);
+
+        vd.last_fuel_update_ts = (now + 100) as u32;
+        assert_eq!(vd.get_holding_period(), 100);
+
+        // a pending request forfeits the boost, and withdrawing restarts the clock
+        vd.last_withdraw_request
+            .set(100, 50, 50, 100, now + 100)
             .unwrap();
-        assert_eq!(vd.vault_shares_base, 0);
-        assert_eq!(withdraw_amount, amount);
+        assert_eq!(vd.get_holding_period(), 0);
+        vd.last_withdraw_request.reset(now + 100).unwrap();
+        vd.last_fuel_update_ts = (now + 150) as u32;
+        assert_eq!(vd.get_holding_period(), 50);
+    }
+
+    #[test]
+    fn test_migrate() {
+        let mut vd =
+            VaultDepositor::new(Pubkey::default(), Pubkey::default(), Pubkey::default(), 0);
+        assert_eq!(vd.version, VAULT_DEPOSITOR_VERSION);
+
+        // accounts created before versioning
+        vd.version = 0;
+        vd.migrate().unwrap();
+        assert_eq!(vd.version, VAULT_DEPOSITOR_VERSION);
+
+        vd.version = VAULT_DEPOSITOR_VERSION + 1;
+        assert!(vd.validate_version().is_err());
+        assert!(vd.migrate().is_err());
+    }
+
+    #[test]
+    fn test_losses_first_profit_share() {
+        let mut vault = Vault {
+            profit_share: (PERCENTAGE_PRECISION_U64 / 10) as u32, // 10%
+            ..Vault::default()
+        };
+        let mut vault_config = VaultConfig::default();
+
+        let new_vd = || {
+            let mut vd =
+                VaultDepositor::new(Pubkey::default(), Pubkey::default(), Pubkey::default(), 0);
+            vd.net_deposits = 100 * QUOTE_PRECISION_U64 as i64;
+            // $40 of gains already profit shared
+            vd.cumulative_profit_share_amount = 40 * QUOTE_PRECISION_U64 as i64;
+            vd
+        };
+
+        // high water mark: falling to $80 and recovering to $130 stays below the $140 mark
+        let mut vd = new_vd();
+        for total_amount in [80, 130] {
+            let (manager_profit_share, _) = VaultDepositorBase::calculate_profit_share_and_update(
+                &mut vd,
+                total_amount * QUOTE_PRECISION_U64,
+                &vault,
+                &vault_config,
+                &mut None,
+            )
+            .unwrap();
+            assert_eq!(manager_profit_share, 0);
+        }
+        assert_eq!(
+            vd.cumulative_profit_share_amount,
+            40 * QUOTE_PRECISION_U64 as i64
+        );
+
+        // losses first: falling below net deposits resets the mark to $100, the recovery to $130 is profit shared
+        vault_config
+            .update_profit_share_mode(&vault, ProfitShareMode::LossesFirst)
+            .unwrap();
+        let mut vd = new_vd();
+        let (manager_profit_share, _) = VaultDepositorBase::calculate_profit_share_and_update(
+            &mut vd,
+            80 * QUOTE_PRECISION_U64,
+            &vault,
+            &vault_config,
+            &mut None,
+        )
+        .unwrap();
+        assert_eq!(manager_profit_share, 0);
+        assert_eq!(vd.cumulative_profit_share_amount, 0);
+
+        let (manager_profit_share, _) = VaultDepositorBase::calculate_profit_share_and_update(
+            &mut vd,
+            130 * QUOTE_PRECISION_U64,
+            &vault,
+            &vault_config,
+            &mut None,
+        )
+        .unwrap();
+        assert_eq!(manager_profit_share, 3 * QUOTE_PRECISION_U64 as u128);
+        assert_eq!(
+            vd.cumulative_profit_share_amount,
+            27 * QUOTE_PRECISION_U64 as i64
+        );
+
+        // can't switch a vault with depositor shares to losses first
+        let vault = Vault {
+            user_shares: 1,
+            ..Vault::default()
+        };
+        let mut vault_config = VaultConfig::default();
+        assert!(vault_config
+            .update_profit_share_mode(&vault, ProfitShareMode::LossesFirst)
+            .is_err());
+        vault_config
+            .update_profit_share_mode(&vault, ProfitShareMode::HighWaterMark)
+            .unwrap();
+    }
+
+    #[test]
+    fn test_peak_equity_profit_share() {
+        let mut vault = Vault {
+            profit_share: (PERCENTAGE_PRECISION_U64 / 10) as u32, // 10%
+            hurdle_rate: (PERCENTAGE_PRECISION_U64 / 10) as u32,  // 10%
+            ..Vault::default()
+        };
+        let mut vault_config = VaultConfig::default();
+
+        let new_vd = || {
+            let mut vd =
+                VaultDepositor::new(Pubkey::default(), Pubkey::default(), Pubkey::default(), 0);
+            vd.net_deposits = 100 * QUOTE_PRECISION_U64 as i64;
+            vd
+        };
+        let profit_share = |vd: &mut VaultDepositor,
+                            vault: &Vault,
+                            vault_config: &VaultConfig,
+                            total_amount: u64| {
+            VaultDepositorBase::calculate_profit_share_and_update(
+                vd,
+                total_amount * QUOTE_PRECISION_U64,
+                vault,
+                vault_config,
+                &mut None,
+            )
+            .unwrap()
+            .0
+        };
+
+        // high water mark: the gain within the hurdle doesn't move the mark, after a drawdown the recovery to $112
+        // clears the hurdle and the whole $12 is profit shared
+        let mut vd = new_vd();
+        assert_eq!(profit_share(&mut vd, &vault, &vault_config, 105), 0);
+        assert_eq!(vd.cumulative_profit_share_amount, 0);
+        assert_eq!(profit_share(&mut vd, &vault, &vault_config, 90), 0);
+        assert_eq!(
+            profit_share(&mut vd, &vault, &vault_config, 112),
+            1_200_000 // $1.2
+        );
+
+        // peak equity: the mark follows the $105 peak, recovering to $112 is within the hurdle of the peak
+        vault_config
+            .update_profit_share_mode(&vault, ProfitShareMode::PeakEquity)
+            .unwrap();
+        let mut vd = new_vd();
+        assert_eq!(profit_share(&mut vd, &vault, &vault_config, 105), 0);
+        assert_eq!(
+            vd.cumulative_profit_share_amount,
+            5 * QUOTE_PRECISION_U64 as i64
+        );
+        assert_eq!(profit_share(&mut vd, &vault, &vault_config, 90), 0);
+        assert_eq!(
+            vd.cumulative_profit_share_amount,
+            5 * QUOTE_PRECISION_U64 as i64
+        );
+        assert_eq!(profit_share(&mut vd, &vault, &vault_config, 112), 0);
+        assert_eq!(
+            vd.cumulative_profit_share_amount,
+            12 * QUOTE_PRECISION_U64 as i64
+        );
+
+        // only the gain above the $112 peak is charged once it clears the hurdle
+        assert_eq!(
+            profit_share(&mut vd, &vault, &vault_config, 130),
+            1_800_000 // $1.8
+        );
+        assert_eq!(vd.cumulative_profit_share_amount, 28_200_000);
+
+        // the mode is kept apart from the fee policy
+        assert_eq!(vault_config.fee_policy(&vault).unwrap(), FeePolicy::Hurdle);
+        vault_config
+            .update_fee_policy(&vault, FeePolicy::Benchmark)
+            .unwrap();
+        assert_eq!(
+            vault_config.profit_share_mode().unwrap(),
+            ProfitShareMode::PeakEquity
+        );
+        assert_eq!(
+            vault_config.fee_policy(&vault).unwrap(),
+            FeePolicy::Benchmark
+        );
+
+        // with depositors only towards charging less
+        vault.user_shares = 1;
+        assert!(vault_config
+            .update_profit_share_mode(&vault, ProfitShareMode::HighWaterMark)
+            .is_err());
+        vault.user_shares = 0;
+        vault_config
+            .update_profit_share_mode(&vault, ProfitShareMode::LossesFirst)
+            .unwrap();
+        vault.user_shares = 1;
+        vault_config
+            .update_profit_share_mode(&vault, ProfitShareMode::PeakEquity)
+            .unwrap();
+        assert_eq!(
+            vault_config.profit_share_mode().unwrap(),
+            ProfitShareMode::PeakEquity
+        );
     }
 
     #[test]
@@ -1042,6 +2775,7 @@ mod vault_v1_tests {
             amount,
             vault_equity,
             &mut vault,
+            &VaultConfig::default(),
             &mut Some(vp.borrow_mut()),
             now + 20,
             &UserStats::default(),
@@ -1064,6 +2798,7 @@ mod vault_v1_tests {
             WithdrawUnit::Token,
             vault_equity,
             &mut vault,
+            &VaultConfig::default(),
             &mut Some(vp.borrow_mut()),
             now + 20,
             &UserStats::default(),
@@ -1082,6 +2817,7 @@ mod vault_v1_tests {
             .withdraw(
                 vault_equity,
                 &mut vault,
+                &VaultConfig::default(),
                 &mut Some(vp.borrow_mut()),
                 now + 20,
                 &UserStats::default(),
@@ -1148,6 +2884,7 @@ mod vault_v1_tests {
             amount,
             vault_equity,
             &mut vault,
+            &VaultConfig::default(),
             &mut Some(vp.borrow_mut()),
             now + 20,
             &UserStats::default(),
@@ -1169,6 +2906,7 @@ mod vault_v1_tests {
             WithdrawUnit::Token,
             vault_equity,
             &mut vault,
+            &VaultConfig::default(),
             &mut Some(vp.borrow_mut()),
             now + 20,
             &UserStats::default(),
@@ -1186,6 +2924,7 @@ mod vault_v1_tests {
             .withdraw(
                 vault_equity,
                 &mut vault,
+                &VaultConfig::default(),
                 &mut Some(vp.borrow_mut()),
                 now + 20,
                 &UserStats::default(),
@@ -1222,6 +2961,110 @@ mod vault_v1_tests {
         assert_eq!(protocol_owned_amount, 0); // $100
     }
 
+    #[test]
+    fn test_realized_only_profit_share() {
+        let now = 1000;
+        let mut vault = Vault::default();
+        let mut vault_config = VaultConfig {
+            profit_share_realization: ProfitShareRealization::Realized as u8,
+            ..VaultConfig::default()
+        };
+        let vp = RefCell::new(VaultProtocol::default());
+
+        let vd =
+            &mut VaultDepositor::new(Pubkey::default(), Pubkey::default(), Pubkey::default(), now);
+
+        let mut vault_equity: u64 = 100 * QUOTE_PRECISION_U64;
+        let amount: u64 = 100 * QUOTE_PRECISION_U64;
+        vd.deposit(
+            amount,
+            vault_equity,
+            &mut vault,
+            &vault_config,
+            &mut Some(vp.borrow_mut()),
+            now + 20,
+            &UserStats::default(),
+            &None,
+            0,
+        )
+        .unwrap();
+        assert_eq!(vd.checked_vault_shares(&vault).unwrap(), 100_000_000);
+
+        vault.profit_share = 100_000; // 10% profit share
+        vault_equity = 400 * QUOTE_PRECISION_U64; // depositor's $100 -> $200
+
+        // cranks don't charge unrealized gains
+        let (manager_profit_share, _) = vd
+            .apply_profit_share(
+                vault_equity,
+                &mut vault,
+                &vault_config,
+                &mut Some(vp.borrow_mut()),
+                now + 20,
+                &UserStats::default(),
+                &None,
+            )
+            .unwrap();
+        assert_eq!(manager_profit_share, 0);
+        assert_eq!(vd.checked_vault_shares(&vault).unwrap(), 100_000_000);
+
+        // withdrawing half the position realizes half the $100 profit, $5 profit share
+        vd.request_withdraw(
+            amount,
+            WithdrawUnit::Token,
+            vault_equity,
+            &mut vault,
+            &vault_config,
+            &mut Some(vp.borrow_mut()),
+            now + 20,
+            &UserStats::default(),
+            &None,
+            0,
+        )
+        .unwrap();
+        assert_eq!(vd.profit_share_fee_paid, 5_000_000);
+        assert_eq!(vd.cumulative_profit_share_amount, 45_000_000);
+        assert_eq!(vd.checked_vault_shares(&vault).unwrap(), 97_500_000);
+        assert_eq!(vd.last_withdraw_request.shares, 50_000_000);
+
+        let (withdraw_amount, _) = vd
+            .withdraw(
+                vault_equity,
+                &mut vault,
+                &vault_config,
+                &mut Some(vp.borrow_mut()),
+                now + 20,
+                &UserStats::default(),
+                &None,
+                0,
+            )
+            .unwrap();
+        assert_eq!(withdraw_amount, amount);
+        vault_equity -= withdraw_amount;
+
+        // the other half of the profit is still owed by the remaining shares
+        let remaining_amount = if_shares_to_vault_amount(
+            vd.checked_vault_shares(&vault).unwrap(),
+            vault.total_shares,
+            vault_equity,
+        )
+        .unwrap();
+        assert_eq!(remaining_amount, 95_000_000);
+        assert_eq!(
+            remaining_amount as i64 - vd.net_deposits - vd.cumulative_profit_share_amount,
+            50_000_000
+        );
+
+        // depositors that joined realized only can't be switched to being charged by cranks
+        assert!(vault_config
+            .update_profit_share_realization(&vault, ProfitShareRealization::Unrealized)
+            .is_err());
+        vault.user_shares = 0;
+        vault_config
+            .update_profit_share_realization(&vault, ProfitShareRealization::Unrealized)
+            .unwrap();
+    }
+
     #[test]
     fn test_deposit_full_withdraw_profit_share() {
         let now = 1000;
@@ -1237,6 +3080,7 @@ mod vault_v1_tests {
             amount,
             vault_equity,
             &mut vault,
+            &VaultConfig::default(),
             &mut Some(vp.borrow_mut()),
             now + 20,
             &UserStats::default(),
@@ -1259,6 +3103,7 @@ mod vault_v1_tests {
             WithdrawUnit::Token,
             vault_equity,
             &mut vault,
+            &VaultConfig::default(),
             &mut Some(vp.borrow_mut()),
             now + 20,
             &UserStats::default(),
@@ -1278,6 +3123,7 @@ mod vault_v1_tests {
             .withdraw(
                 vault_equity,
                 &mut vault,
+                &VaultConfig::default(),
                 &mut Some(vp.borrow_mut()),
                 now + 20,
                 &UserStats::default(),
@@ -1361,6 +3207,7 @@ mod vault_v1_tests {
             amount,
             vault_equity,
             &mut vault,
+            &VaultConfig::default(),
             &mut Some(vp.borrow_mut()),
             now + 20,
             &UserStats::default(),
@@ -1382,6 +3229,7 @@ mod vault_v1_tests {
             WithdrawUnit::Token,
             vault_equity,
             &mut vault,
+            &VaultConfig::default(),
             &mut Some(vp.borrow_mut()),
             now + 20,
             &UserStats::default(),
@@ -1401,6 +3249,7 @@ mod vault_v1_tests {
             .withdraw(
                 vault_equity,
                 &mut vault,
+                &VaultConfig::default(),
                 &mut Some(vp.borrow_mut()),
                 now + 20,
                 &UserStats::default(),
@@ -1484,6 +3333,7 @@ mod vault_v1_tests {
             amount,
             vault_equity,
             &mut vault,
+            &VaultConfig::default(),
             &mut Some(vp.borrow_mut()),
             now,
             &UserStats::default(),
@@ -1503,6 +3353,7 @@ mod vault_v1_tests {
         vd.realize_profits(
             vault_equity,
             &mut vault,
+            &VaultConfig::default(),
             &mut Some(vp.borrow_mut()),
             now,
             &UserStats::default(),
@@ -1529,6 +3380,7 @@ mod vault_v1_tests {
             WithdrawUnit::Token,
             vault_equity,
             &mut vault,
+            &VaultConfig::default(),
             &mut Some(vp.borrow_mut()),
             now + 20,
             &UserStats::default(),
@@ -1546,6 +3398,7 @@ mod vault_v1_tests {
             .withdraw(
                 vault_equity,
                 &mut vault,
+                &VaultConfig::default(),
                 &mut Some(vp.borrow_mut()),
                 now + 20,
                 &UserStats::default(),
@@ -1589,6 +3442,7 @@ mod vault_v1_tests {
             amount,
             vault_equity,
             &mut vault,
+            &VaultConfig::default(),
             &mut Some(vp.borrow_mut()),
             now,
             &UserStats::default(),
@@ -1622,6 +3476,7 @@ mod vault_v1_tests {
         vd.realize_profits(
             vault_equity,
             &mut vault,
+            &VaultConfig::default(),
             &mut Some(vp.borrow_mut()),
             now,
             &UserStats::default(),
@@ -1636,6 +3491,7 @@ mod vault_v1_tests {
             WithdrawUnit::SharesPercent,
             vault_equity,
             &mut vault,
+            &VaultConfig::default(),
             &mut Some(vp.borrow_mut()),
             now + 20,
             &UserStats::default(),
@@ -1656,6 +3512,7 @@ mod vault_v1_tests {
             .withdraw(
                 vault_equity,
                 &mut vault,
+                &VaultConfig::default(),
                 &mut Some(vp.borrow_mut()),
                 now + 20 + 3600,
                 &UserStats::default(),
@@ -1699,6 +3556,7 @@ mod vault_v1_tests {
             amount,
             vault_equity,
             &mut vault,
+            &VaultConfig::default(),
             &mut Some(vp.borrow_mut()),
             now,
             &UserStats::default(),
@@ -1732,6 +3590,7 @@ mod vault_v1_tests {
         vd.realize_profits(
             vault_equity,
             &mut vault,
+            &VaultConfig::default(),
             &mut Some(vp.borrow_mut()),
             now,
             &UserStats::default(),
@@ -1746,6 +3605,7 @@ mod vault_v1_tests {
             WithdrawUnit::SharesPercent,
             vault_equity,
             &mut vault,
+            &VaultConfig::default(),
             &mut Some(vp.borrow_mut()),
             now + 20,
             &UserStats::default(),
@@ -1765,6 +3625,7 @@ mod vault_v1_tests {
             .withdraw(
                 vault_equity,
                 &mut vault,
+                &VaultConfig::default(),
                 &mut Some(vp.borrow_mut()),
                 now + 20 + 3600,
                 &UserStats::default(),
@@ -1821,14 +3682,26 @@ mod vault_v1_tests {
         // 1) first crank
         let now = 1000;
         let vd_0_fuel_amount = vd_0
-            .update_cumulative_fuel_amount(now, &mut vault, &vault_user_stats, &None)
+            .update_cumulative_fuel_amount(
+                now,
+                &mut vault,
+                &VaultConfig::default(),
+                &vault_user_stats,
+                &None,
+            )
             .unwrap();
         assert_eq!(vd_0_fuel_amount, 24_000);
         assert_eq!(vd_0.fuel_amount, 24_000);
         assert_eq!(vault.cumulative_fuel, 60_000);
 
         let vd_1_fuel_amount = vd_1
-            .update_cumulative_fuel_amount(now, &mut vault, &vault_user_stats, &None)
+            .update_cumulative_fuel_amount(
+                now,
+                &mut vault,
+                &VaultConfig::default(),
+                &vault_user_stats,
+                &None,
+            )
             .unwrap();
         assert_eq!(vd_1_fuel_amount, 36_000);
         assert_eq!(vd_1.fuel_amount, 36_000);
@@ -1837,14 +3710,26 @@ mod vault_v1_tests {
         // 2) time advances, no new fuel
         let now = 2000;
         let vd_0_fuel_amount = vd_0
-            .update_cumulative_fuel_amount(now, &mut vault, &vault_user_stats, &None)
+            .update_cumulative_fuel_amount(
+                now,
+                &mut vault,
+                &VaultConfig::default(),
+                &vault_user_stats,
+                &None,
+            )
             .unwrap();
         assert_eq!(vd_0_fuel_amount, 24_000);
         assert_eq!(vd_0.fuel_amount, 24_000);
         assert_eq!(vault.cumulative_fuel, 60_000);
 
         let vd_1_fuel_amount = vd_1
-            .update_cumulative_fuel_amount(now, &mut vault, &vault_user_stats, &None)
+            .update_cumulative_fuel_amount(
+                now,
+                &mut vault,
+                &VaultConfig::default(),
+                &vault_user_stats,
+                &None,
+            )
             .unwrap();
         assert_eq!(vd_1_fuel_amount, 36_000);
         assert_eq!(vd_1.fuel_amount, 36_000);
@@ -1854,14 +3739,26 @@ mod vault_v1_tests {
         let now = 3000;
         vault_user_stats.fuel_maker += 10_000; // total = 70k
         let vd_0_fuel_amount = vd_0
-            .update_cumulative_fuel_amount(now, &mut vault, &vault_user_stats, &None)
+            .update_cumulative_fuel_amount(
+                now,
+                &mut vault,
+                &VaultConfig::default(),
+                &vault_user_stats,
+                &None,
+            )
             .unwrap();
         assert_eq!(vd_0_fuel_amount, 28_000);
         assert_eq!(vd_0.fuel_amount, 28_000);
         assert_eq!(vault.cumulative_fuel, 70_000);
 
         let vd_1_fuel_amount = vd_1
-            .update_cumulative_fuel_amount(now, &mut vault, &vault_user_stats, &None)
+            .update_cumulative_fuel_amount(
+                now,
+                &mut vault,
+                &VaultConfig::default(),
+                &vault_user_stats,
+                &None,
+            )
             .unwrap();
         assert_eq!(vd_1_fuel_amount, 42_000);
         assert_eq!(vd_1.fuel_amount, 42_000);
@@ -1871,7 +3768,13 @@ mod vault_v1_tests {
         let now = 4000;
         vault_user_stats.fuel_maker += 10_000; // total = 80k
         let vd_0_fuel_amount = vd_0
-            .update_cumulative_fuel_amount(now, &mut vault, &vault_user_stats, &None)
+            .update_cumulative_fuel_amount(
+                now,
+                &mut vault,
+                &VaultConfig::default(),
+                &vault_user_stats,
+                &None,
+            )
             .unwrap();
         assert_eq!(vd_0_fuel_amount, 32_000);
         assert_eq!(vd_0.fuel_amount, 32_000);
@@ -1881,14 +3784,26 @@ mod vault_v1_tests {
         let now = 5000;
         vault_user_stats.fuel_maker += 10_000; // total = 90k
         let vd_0_fuel_amount = vd_0
-            .update_cumulative_fuel_amount(now, &mut vault, &vault_user_stats, &None)
+            .update_cumulative_fuel_amount(
+                now,
+                &mut vault,
+                &VaultConfig::default(),
+                &vault_user_stats,
+                &None,
+            )
             .unwrap();
         assert_eq!(vd_0_fuel_amount, 36_000);
         assert_eq!(vd_1.fuel_amount, 42_000);
         assert_eq!(vault.cumulative_fuel, 90_000);
 
         let vd_1_fuel_amount = vd_1
-            .update_cumulative_fuel_amount(now, &mut vault, &vault_user_stats, &None)
+            .update_cumulative_fuel_amount(
+                now,
+                &mut vault,
+                &VaultConfig::default(),
+                &vault_user_stats,
+                &None,
+            )
             .unwrap();
         assert_eq!(vd_1_fuel_amount, 54_000);
         assert_eq!(vd_1.fuel_amount, 54_000);
@@ -1929,13 +3844,25 @@ mod vault_v1_tests {
         // 1) first crank
         let now = 1000;
         let vd_0_fuel_amount = vd_0
-            .update_cumulative_fuel_amount(now, &mut vault, &vault_user_stats, &None)
+            .update_cumulative_fuel_amount(
+                now,
+                &mut vault,
+                &VaultConfig::default(),
+                &vault_user_stats,
+                &None,
+            )
             .unwrap();
         assert_eq!(vd_0_fuel_amount, 12_000);
         assert_eq!(vd_0.fuel_amount, 12_000);
 
         let vd_1_fuel_amount = vd_1
-            .update_cumulative_fuel_amount(now, &mut vault, &vault_user_stats, &None)
+            .update_cumulative_fuel_amount(
+                now,
+                &mut vault,
+                &VaultConfig::default(),
+                &vault_user_stats,
+                &None,
+            )
             .unwrap();
         assert_eq!(vd_1_fuel_amount, 18_000);
         assert_eq!(vd_1.fuel_amount, 18_000);
@@ -1943,13 +3870,25 @@ mod vault_v1_tests {
         // 2) time advances, no new fuel
         let now = 2000;
         let vd_0_fuel_amount = vd_0
-            .update_cumulative_fuel_amount(now, &mut vault, &vault_user_stats, &None)
+            .update_cumulative_fuel_amount(
+                now,
+                &mut vault,
+                &VaultConfig::default(),
+                &vault_user_stats,
+                &None,
+            )
             .unwrap();
         assert_eq!(vd_0_fuel_amount, 12_000);
         assert_eq!(vd_0.fuel_amount, 12_000);
 
         let vd_1_fuel_amount = vd_1
-            .update_cumulative_fuel_amount(now, &mut vault, &vault_user_stats, &None)
+            .update_cumulative_fuel_amount(
+                now,
+                &mut vault,
+                &VaultConfig::default(),
+                &vault_user_stats,
+                &None,
+            )
             .unwrap();
         assert_eq!(vd_1_fuel_amount, 18_000);
         assert_eq!(vd_1.fuel_amount, 18_000);
@@ -1958,13 +3897,25 @@ mod vault_v1_tests {
         let now = 3000;
         vault_user_stats.fuel_maker += 10_000; // total = 70k
         let vd_0_fuel_amount = vd_0
-            .update_cumulative_fuel_amount(now, &mut vault, &vault_user_stats, &None)
+            .update_cumulative_fuel_amount(
+                now,
+                &mut vault,
+                &VaultConfig::default(),
+                &vault_user_stats,
+                &None,
+            )
             .unwrap();
         assert_eq!(vd_0_fuel_amount, 14_000);
         assert_eq!(vd_0.fuel_amount, 14_000);
 
         let vd_1_fuel_amount = vd_1
-            .update_cumulative_fuel_amount(now, &mut vault, &vault_user_stats, &None)
+            .update_cumulative_fuel_amount(
+                now,
+                &mut vault,
+                &VaultConfig::default(),
+                &vault_user_stats,
+                &None,
+            )
             .unwrap();
         assert_eq!(vd_1_fuel_amount, 21_000);
         assert_eq!(vd_1.fuel_amount, 21_000);
@@ -1974,7 +3925,13 @@ mod vault_v1_tests {
         let now = 4000;
         vault_user_stats.fuel_maker += 10_000; // total = 80k
         let vd_0_fuel_amount = vd_0
-            .update_cumulative_fuel_amount(now, &mut vault, &vault_user_stats, &None)
+            .update_cumulative_fuel_amount(
+                now,
+                &mut vault,
+                &VaultConfig::default(),
+                &vault_user_stats,
+                &None,
+            )
             .unwrap();
         assert_eq!(vd_0_fuel_amount, 16_000);
         assert_eq!(vd_0.fuel_amount, 16_000);
@@ -1984,14 +3941,26 @@ mod vault_v1_tests {
         let now = 5000;
         vault_user_stats.fuel_maker += 10_000; // total = 90k
         let vd_0_fuel_amount = vd_0
-            .update_cumulative_fuel_amount(now, &mut vault, &vault_user_stats, &None)
+            .update_cumulative_fuel_amount(
+                now,
+                &mut vault,
+                &VaultConfig::default(),
+                &vault_user_stats,
+                &None,
+            )
             .unwrap();
         assert_eq!(vd_0_fuel_amount, 18_000);
         assert_eq!(vd_1.fuel_amount, 21_000);
         assert_eq!(vault.cumulative_fuel, 90_000);
 
         let vd_1_fuel_amount = vd_1
-            .update_cumulative_fuel_amount(now, &mut vault, &vault_user_stats, &None)
+            .update_cumulative_fuel_amount(
+                now,
+                &mut vault,
+                &VaultConfig::default(),
+                &vault_user_stats,
+                &None,
+            )
             .unwrap();
         assert_eq!(vd_1_fuel_amount, 27_000);
         assert_eq!(vd_1.fuel_amount, 27_000);
@@ -2034,13 +4003,25 @@ mod vault_v1_tests {
         // 1) first crank
         let now = 1000;
         let vd_0_fuel_amount = vd_0
-            .update_cumulative_fuel_amount(now, &mut vault, &vault_user_stats, &None)
+            .update_cumulative_fuel_amount(
+                now,
+                &mut vault,
+                &VaultConfig::default(),
+                &vault_user_stats,
+                &None,
+            )
             .unwrap();
         assert_eq!(vd_0_fuel_amount, 24_000);
         assert_eq!(vd_0.fuel_amount, 24_000);
 
         let vd_1_fuel_amount = vd_1
-            .update_cumulative_fuel_amount(now, &mut vault, &vault_user_stats, &None)
+            .update_cumulative_fuel_amount(
+                now,
+                &mut vault,
+                &VaultConfig::default(),
+                &vault_user_stats,
+                &None,
+            )
             .unwrap();
         assert_eq!(vd_1_fuel_amount, 36_000);
         assert_eq!(vd_1.fuel_amount, 36_000);
@@ -2052,14 +4033,26 @@ mod vault_v1_tests {
         vd_0.vault_shares = 400_000;
         vault.user_shares = 700_000;
         let vd_0_fuel_amount = vd_0
-            .update_cumulative_fuel_amount(now, &mut vault, &vault_user_stats, &None)
+            .update_cumulative_fuel_amount(
+                now,
+                &mut vault,
+                &VaultConfig::default(),
+                &vault_user_stats,
+                &None,
+            )
             .unwrap();
         assert_eq!(vd_0_fuel_amount, 29_714); // +5,714.2857142857
         assert_eq!(vd_0.fuel_amount, 29_714);
         assert_eq!(vault.cumulative_fuel, 70_000);
 
         let vd_1_fuel_amount = vd_1
-            .update_cumulative_fuel_amount(now, &mut vault, &vault_user_stats, &None)
+            .update_cumulative_fuel_amount(
+                now,
+                &mut vault,
+                &VaultConfig::default(),
+                &vault_user_stats,
+                &None,
+            )
             .unwrap();
         assert_eq!(vd_1_fuel_amount, 40_285); // +4,285.7142857143
         assert_eq!(vd_1.fuel_amount, 40_285);
@@ -2097,13 +4090,25 @@ mod vault_v1_tests {
         // 1) first crank
         let now = 1000;
         let vd_0_fuel_amount = vd_0
-            .update_cumulative_fuel_amount(now, &mut vault, &vault_user_stats, &None)
+            .update_cumulative_fuel_amount(
+                now,
+                &mut vault,
+                &VaultConfig::default(),
+                &vault_user_stats,
+                &None,
+            )
             .unwrap();
         assert_eq!(vd_0_fuel_amount, 0);
         assert_eq!(vd_0.fuel_amount, 0);
 
         let vd_1_fuel_amount = vd_1
-            .update_cumulative_fuel_amount(now, &mut vault, &vault_user_stats, &None)
+            .update_cumulative_fuel_amount(
+                now,
+                &mut vault,
+                &VaultConfig::default(),
+                &vault_user_stats,
+                &None,
+            )
             .unwrap();
         assert_eq!(vd_1_fuel_amount, 0);
         assert_eq!(vd_1.fuel_amount, 0);
@@ -2146,8 +4151,14 @@ mod vault_v1_tests {
                 vd.last_fuel_update_ts = 0;
                 vd.vault_shares = user_shares * vd_share_of_vault / vd_share_of_vault_denom;
 
-                vd.update_cumulative_fuel_amount(now, &mut vault, &user_stats, &None)
-                    .unwrap();
+                vd.update_cumulative_fuel_amount(
+                    now,
+                    &mut vault,
+                    &VaultConfig::default(),
+                    &user_stats,
+                    &None,
+                )
+                .unwrap();
 
                 let approx_fuel_amount_share = (vd.fuel_amount as f64) / (vault_fuel as f64);
                 let expected_fuel_amount_share =
@@ -2176,4 +4187,358 @@ mod vault_v1_tests {
             }
         }
     }
+
+    #[test]
+    fn test_vault_depositor_fuel_overflow_season_rollover() {
+        let now = 1000;
+        let mut vault = Vault {
+            total_shares: 1_000_000,
+            user_shares: 500_000,
+            ..Vault::default()
+        };
+
+        let vd_0 =
+            &mut VaultDepositor::new(Pubkey::default(), Pubkey::default(), Pubkey::default(), now);
+        vd_0.vault_shares = 200_000;
+        vd_0.last_fuel_update_ts = now as u32 - 1;
+        let vd_1 =
+            &mut VaultDepositor::new(Pubkey::default(), Pubkey::default(), Pubkey::default(), now);
+        vd_1.vault_shares = 300_000;
+        vd_1.last_fuel_update_ts = now as u32 - 1;
+
+        // 1) season 1: 100k fuel over 500k user shares
+        let vault_user_stats = UserStats {
+            fuel_deposits: 100_000,
+            ..UserStats::default()
+        };
+        vault
+            .update_cumulative_fuel_per_share_with_overflow(now, &vault_user_stats, 0, 0)
+            .unwrap();
+        vd_0.update_cumulative_fuel_amount(
+            now,
+            &mut vault,
+            &VaultConfig::default(),
+            &vault_user_stats,
+            &None,
+        )
+        .unwrap();
+        vd_1.update_cumulative_fuel_amount(
+            now,
+            &mut vault,
+            &VaultConfig::default(),
+            &vault_user_stats,
+            &None,
+        )
+        .unwrap();
+        assert_eq!(vd_0.fuel_amount, 40_000);
+        assert_eq!(vd_1.fuel_amount, 60_000);
+
+        // 2) FuelOverflow resets at 1500, 50k new fuel accrues before a new depositor doubles user shares
+        let vault_user_stats = UserStats {
+            fuel_deposits: 50_000,
+            ..UserStats::default()
+        };
+        vault
+            .update_cumulative_fuel_per_share_with_overflow(1600, &vault_user_stats, 0, 1500)
+            .unwrap();
+        assert_eq!(vault.fuel_season_start_ts, 1500);
+        vault.user_shares = 1_000_000;
+
+        // 3) another 100k fuel over 1m user shares
+        let now = 2000;
+        let vault_user_stats = UserStats {
+            fuel_deposits: 150_000,
+            ..UserStats::default()
+        };
+        vault
+            .update_cumulative_fuel_per_share_with_overflow(now, &vault_user_stats, 0, 1500)
+            .unwrap();
+
+        // vds last updated in season 1, only season 2 fuel is accrued: 200k * (0.1 + 0.05)
+        let vd_0_fuel_amount = vd_0
+            .update_cumulative_fuel_amount(
+                now,
+                &mut vault,
+                &VaultConfig::default(),
+                &vault_user_stats,
+                &None,
+            )
+            .unwrap();
+        assert_eq!(vd_0_fuel_amount, 30_000);
+        assert_eq!(vd_0.last_fuel_update_ts, now as u32);
+        assert_eq!(
+            vd_0.cumulative_fuel_per_share_amount,
+            vault.cumulative_fuel_per_share
+        );
+
+        let vd_1_fuel_amount = vd_1
+            .update_cumulative_fuel_amount(
+                now,
+                &mut vault,
+                &VaultConfig::default(),
+                &vault_user_stats,
+                &None,
+            )
+            .unwrap();
+        assert_eq!(vd_1_fuel_amount, 45_000);
+
+        // 4) time advances in the same season, no further rollover
+        let now = 3000;
+        let vd_0_fuel_amount = vd_0
+            .update_cumulative_fuel_amount(
+                now,
+                &mut vault,
+                &VaultConfig::default(),
+                &vault_user_stats,
+                &None,
+            )
+            .unwrap();
+        assert_eq!(vd_0_fuel_amount, 30_000);
+    }
+
+    #[test]
+    fn test_vault_depositor_settle_fuel_season() {
+        let now = 1000;
+        let mut vault = Vault {
+            total_shares: 1_000_000,
+            user_shares: 500_000,
+            ..Vault::default()
+        };
+
+        let vd_0 =
+            &mut VaultDepositor::new(Pubkey::default(), Pubkey::default(), Pubkey::default(), now);
+        vd_0.vault_shares = 200_000;
+        vd_0.last_fuel_update_ts = now as u32 - 1;
+        let vd_1 =
+            &mut VaultDepositor::new(Pubkey::default(), Pubkey::default(), Pubkey::default(), now);
+        vd_1.vault_shares = 300_000;
+        vd_1.last_fuel_update_ts = now as u32 - 1;
+
+        // 1) season 1: 100k fuel over 500k user shares
+        let vault_user_stats = UserStats {
+            fuel_deposits: 100_000,
+            ..UserStats::default()
+        };
+        vd_0.update_cumulative_fuel_amount(
+            now,
+            &mut vault,
+            &VaultConfig::default(),
+            &vault_user_stats,
+            &None,
+        )
+        .unwrap();
+        vd_1.update_cumulative_fuel_amount(
+            now,
+            &mut vault,
+            &VaultConfig::default(),
+            &vault_user_stats,
+            &None,
+        )
+        .unwrap();
+        assert_eq!(vd_0.fuel_amount, 40_000);
+        assert_eq!(vd_1.fuel_amount, 60_000);
+
+        // 2) another 50k accrues in season 1, only vd_1 is updated
+        let vault_user_stats = UserStats {
+            fuel_deposits: 150_000,
+            ..UserStats::default()
+        };
+        vd_1.update_cumulative_fuel_amount(
+            1200,
+            &mut vault,
+            &VaultConfig::default(),
+            &vault_user_stats,
+            &None,
+        )
+        .unwrap();
+        assert_eq!(vd_1.fuel_amount, 90_000);
+
+        // 3) the FuelOverflow resets at 1500, both vds are settled into season 1 before the vault rolls over,
+        // vd_0 with the fuel it hadn't accrued yet
+        assert_eq!(
+            vd_0.settle_fuel_season(1600, &vault, &VaultConfig::default(), 1500)
+                .unwrap(),
+            60_000
+        );
+        assert_eq!(
+            vd_1.settle_fuel_season(1600, &vault, &VaultConfig::default(), 1500)
+                .unwrap(),
+            90_000
+        );
+        assert_eq!(vd_0.fuel_amount, 0);
+        assert_eq!(vd_0.last_fuel_update_ts, 1500);
+        // already settled
+        assert_eq!(
+            vd_0.settle_fuel_season(1600, &vault, &VaultConfig::default(), 1500)
+                .unwrap(),
+            0
+        );
+
+        // a vd that hasn't accrued yet has nothing to settle
+        let vd_2 =
+            &mut VaultDepositor::new(Pubkey::default(), Pubkey::default(), Pubkey::default(), now);
+        assert_eq!(
+            vd_2.settle_fuel_season(1600, &vault, &VaultConfig::default(), 1500)
+                .unwrap(),
+            0
+        );
+        assert_eq!(vd_2.last_fuel_update_ts, MAGIC_FUEL_START_TS);
+
+        // 4) season 2: 50k fuel over 500k user shares, accrued from the season start
+        let vault_user_stats = UserStats {
+            fuel_deposits: 50_000,
+            ..UserStats::default()
+        };
+        vault
+            .update_cumulative_fuel_per_share_with_overflow(1600, &vault_user_stats, 0, 1500)
+            .unwrap();
+        assert_eq!(vault.fuel_season_start_ts, 1500);
+        let vd_0_fuel_amount = vd_0
+            .update_cumulative_fuel_amount(
+                2000,
+                &mut vault,
+                &VaultConfig::default(),
+                &vault_user_stats,
+                &None,
+            )
+            .unwrap();
+        assert_eq!(vd_0_fuel_amount, 20_000);
+        let vd_1_fuel_amount = vd_1
+            .update_cumulative_fuel_amount(
+                2000,
+                &mut vault,
+                &VaultConfig::default(),
+                &vault_user_stats,
+                &None,
+            )
+            .unwrap();
+        assert_eq!(vd_1_fuel_amount, 30_000);
+    }
+
+    #[test]
+    fn test_withdraw_with_settlement_charges_fees_at_live_equity() {
+        let now = 1000;
+        let mut vault = Vault {
+            management_fee: (PERCENTAGE_PRECISION_U64 / 10).cast().unwrap(), // 10%
+            redeem_period: 100,
+            ..Vault::default()
+        };
+        let vd =
+            &mut VaultDepositor::new(Pubkey::default(), Pubkey::default(), Pubkey::default(), now);
+
+        let amount: u64 = 100 * QUOTE_PRECISION_U64;
+        vd.deposit(
+            amount,
+            0,
+            &mut vault,
+            &VaultConfig::default(),
+            &mut None,
+            now,
+            &UserStats::default(),
+            &None,
+            0,
+        )
+        .unwrap();
+        vd.request_withdraw(
+            (amount / 2).cast().unwrap(),
+            WithdrawUnit::Token,
+            amount,
+            &mut vault,
+            &VaultConfig::default(),
+            &mut None,
+            now,
+            &UserStats::default(),
+            &None,
+            0,
+        )
+        .unwrap();
+        let request_shares = vd.last_withdraw_request.shares;
+
+        // the vault is marked down 20% at the settlement but has recovered by the withdraw
+        let withdraw_ts = now + ONE_YEAR as i64;
+        let mut settlement = VaultWithdrawSettlement::default();
+        settlement
+            .settle(&vault, 80 * QUOTE_PRECISION_U64, withdraw_ts)
+            .unwrap();
+
+        let mut vault_live = vault;
+        let mut vd_live = *vd;
+        vd_live
+            .withdraw(
+                amount,
+                &mut vault_live,
+                &mut None,
+                withdraw_ts,
+                &UserStats::default(),
+                &None,
+                0,
+            )
+            .unwrap();
+
+        let (withdraw_amount, _) = vd
+            .withdraw_with_settlement(
+                amount,
+                Some(&settlement),
+                &mut vault,
+                &VaultConfig::default(),
+                &mut None,
+                withdraw_ts,
+                &UserStats::default(),
+                &None,
+                0,
+            )
+            .unwrap();
+
+        // only the payout is valued at the settled share price
+        assert_eq!(
+            withdraw_amount,
+            (request_shares * 80 * QUOTE_PRECISION_U64 as u128 / settlement.total_shares)
+                .cast::<u64>()
+                .unwrap()
+        );
+        assert!(vault.manager_total_fee > 0);
+        assert_eq!(vault.manager_total_fee, vault_live.manager_total_fee);
+        assert_eq!(vault.total_shares, vault_live.total_shares);
+    }
+
+    #[test]
+    fn test_portfolio_position() {
+        let now = ONE_YEAR as i64;
+        let mut vault = Vault {
+            total_shares: 100 * QUOTE_PRECISION_U64 as u128,
+            user_shares: 100 * QUOTE_PRECISION_U64 as u128,
+            profit_share: (PERCENTAGE_PRECISION_U64 / 5) as u32, // 20%
+            last_fee_update_ts: now,
+            ..Vault::default()
+        };
+        let mut vd =
+            VaultDepositor::new(Pubkey::default(), Pubkey::default(), Pubkey::default(), 0);
+        vd.set_vault_shares(100 * QUOTE_PRECISION_U64 as u128);
+        vd.net_deposits = 100 * QUOTE_PRECISION_U64 as i64;
+        vd.last_withdraw_request.value = 20 * QUOTE_PRECISION_U64;
+        let vault_equity = 150 * QUOTE_PRECISION_U64;
+
+        // 20% of the $50 profit
+        let position = vd
+            .portfolio_position(&vault, None, vault_equity, now)
+            .unwrap();
+        assert_eq!(position.value, 150 * QUOTE_PRECISION_U64);
+        assert_eq!(position.accrued_fees, 0);
+        assert_eq!(position.profit_share_owed, 10 * QUOTE_PRECISION_U64);
+        assert_eq!(position.withdraw_request_value, 20 * QUOTE_PRECISION_U64);
+
+        // a year of 10% management fee comes off the value before the profit share
+        vault.management_fee = (PERCENTAGE_PRECISION_U64 / 10) as i64;
+        vault.last_fee_update_ts = 0;
+        let position = vd
+            .portfolio_position(&vault, None, vault_equity, now)
+            .unwrap();
+        assert_eq!(position.value, 150 * QUOTE_PRECISION_U64);
+        assert_eq_within!(position.accrued_fees, 15 * QUOTE_PRECISION_U64, 100);
+        assert_eq_within!(position.profit_share_owed, 7 * QUOTE_PRECISION_U64, 100);
+
+        // nothing was updated
+        assert_eq!(vault.total_shares, 100 * QUOTE_PRECISION_U64 as u128);
+        assert_eq!(vd.cumulative_profit_share_amount, 0);
+    }
 }
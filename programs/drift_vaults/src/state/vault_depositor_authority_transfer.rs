@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+use drift_macros::assert_no_slop;
+use static_assertions::const_assert_eq;
+
+use crate::state::Size;
+
+/// A [`VaultDepositor`](crate::state::VaultDepositor)'s pending move to `new_authority`, proposed by its authority
+/// and accepted by the new one. Vault depositor pdas are derived from the authority, so accepting moves the position
+/// into the new authority's vault depositor rather than rewriting `authority` in place, see
+/// [`VaultDepositor::transfer_authority`](crate::state::VaultDepositor::transfer_authority).
+#[assert_no_slop]
+#[account(zero_copy(unsafe))]
+#[derive(Default, Eq, PartialEq, Debug)]
+#[repr(C)]
+pub struct VaultDepositorAuthorityTransfer {
+    /// The vault depositor being moved
+    pub vault_depositor: Pubkey,
+    /// The authority that has to accept the transfer
+    pub new_authority: Pubkey,
+    pub proposed_ts: i64,
+    pub bump: u8,
+    pub padding: [u8; 7],
+}
+
+impl Size for VaultDepositorAuthorityTransfer {
+    const SIZE: usize = 80 + 8;
+}
+const_assert_eq!(
+    VaultDepositorAuthorityTransfer::SIZE,
+    std::mem::size_of::<VaultDepositorAuthorityTransfer>() + 8
+);
+
+impl VaultDepositorAuthorityTransfer {
+    pub fn get_address(vault_depositor: &Pubkey) -> Pubkey {
+        Pubkey::find_program_address(
+            &[
+                b"vault_depositor_authority_transfer",
+                vault_depositor.as_ref(),
+            ],
+            &crate::id(),
+        )
+        .0
+    }
+}
@@ -0,0 +1,183 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
+use drift::state::user::FuelOverflow;
+
+use crate::error::ErrorCode;
+use crate::state::{Vault, VaultConfig, VaultDepositor, VaultDepositorLoader};
+use crate::validate;
+
+/// Batches at most this size can also emit a record per depositor
+pub const MAX_DETAILED_BATCH_SIZE: usize = 8;
+
+pub(crate) const LEAF_PREFIX: &[u8] = &[0];
+const NODE_PREFIX: &[u8] = &[1];
+
+/// A depositor's change in a batch crank, a leaf of
+/// [`VaultDepositorBatchRecord::deltas_root`](crate::state::events::VaultDepositorBatchRecord).
+/// Indexers recompute the leaves from the depositor accounts to check the root.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub struct VaultDepositorDelta {
+    pub authority: Pubkey,
+    pub vault_shares_before: u128,
+    pub vault_shares_after: u128,
+    /// action specific, e.g. the profit share paid
+    pub amount: u64,
+}
+
+impl VaultDepositorDelta {
+    pub fn leaf(&self) -> [u8; 32] {
+        hashv(&[
+            LEAF_PREFIX,
+            self.authority.as_ref(),
+            &self.vault_shares_before.to_le_bytes(),
+            &self.vault_shares_after.to_le_bytes(),
+            &self.amount.to_le_bytes(),
+        ])
+        .to_bytes()
+    }
+}
+
+/// Root of the sha256 merkle tree over `leaves` in order. An odd node out is promoted to the next level as is, and
+/// leaves and nodes are hashed with distinct prefixes. Zeroed when there are no leaves.
+pub fn merkle_root(leaves: &[[u8; 32]]) -> [u8; 32] {
+    if leaves.is_empty() {
+        return [0; 32];
+    }
+
+    let mut level = leaves.to_vec();
+    while level.len() > 1 {
+        level = level
+            .chunks(2)
+            .map(|pair| match pair {
+                [left, right] => hashv(&[NODE_PREFIX, left, right]).to_bytes(),
+                [node] => *node,
+                _ => unreachable!(),
+            })
+            .collect();
+    }
+
+    level[0]
+}
+
+/// Settles every depositor of a batch crank into the fuel season the vault is about to close, before the first of
+/// them rolls the vault over, see [`VaultDepositor::settle_fuel_season`].
+pub fn settle_fuel_season_batch(
+    vault: &Vault,
+    vault_config: &VaultConfig,
+    vault_depositors: &[AccountLoader<VaultDepositor>],
+    fuel_overflow: &Option<AccountLoader<FuelOverflow>>,
+    now: i64,
+) -> Result<()> {
+    let season_start_ts = match vault.pending_fuel_season(fuel_overflow)? {
+        Some(season_start_ts) => season_start_ts,
+        None => return Ok(()),
+    };
+
+    for vault_depositor in vault_depositors.iter() {
+        let mut vault_depositor = vault_depositor.load_mut_migrated()?;
+        validate!(
+            vault_depositor.vault == vault.pubkey,
+            ErrorCode::InvalidVaultDepositorBatch,
+            "vault depositor {} is not for vault {}",
+            vault_depositor.pubkey,
+            vault.pubkey
+        )?;
+        vault_depositor.settle_fuel_season(now, vault, vault_config, season_start_ts)?;
+    }
+
+    Ok(())
+}
+
+/// Levels of a [`merkle_root`] tree kept while building it incrementally, enough for `u32::MAX` leaves
+pub const MERKLE_FRONTIER_DEPTH: usize = 32;
+
+/// Appends `leaf` as leaf number `num_leaves` to a merkle tree built incrementally across transactions.
+/// `frontier[i]` holds the root of the pending complete subtree of 2^i leaves, if bit i of `num_leaves` is set.
+pub fn merkle_frontier_append(
+    frontier: &mut [[u8; 32]; MERKLE_FRONTIER_DEPTH],
+    num_leaves: u32,
+    leaf: [u8; 32],
+) {
+    let mut node = leaf;
+    let mut level = 0;
+    while (num_leaves >> level) & 1 == 1 {
+        node = hashv(&[NODE_PREFIX, &frontier[level], &node]).to_bytes();
+        level += 1;
+    }
+    frontier[level] = node;
+}
+
+/// Root of a tree built with [`merkle_frontier_append`], equal to [`merkle_root`] over the same leaves.
+pub fn merkle_frontier_root(
+    frontier: &[[u8; 32]; MERKLE_FRONTIER_DEPTH],
+    num_leaves: u32,
+) -> [u8; 32] {
+    let mut root: Option<[u8; 32]> = None;
+    for (level, node) in frontier.iter().enumerate() {
+        if (num_leaves >> level) & 1 == 1 {
+            root = Some(match root {
+                None => *node,
+                Some(right) => hashv(&[NODE_PREFIX, node, &right]).to_bytes(),
+            });
+        }
+    }
+
+    root.unwrap_or([0; 32])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn node(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+        hashv(&[NODE_PREFIX, left, right]).to_bytes()
+    }
+
+    #[test]
+    fn test_merkle_root() {
+        let leaves: Vec<[u8; 32]> = (0..5)
+            .map(|i| {
+                VaultDepositorDelta {
+                    authority: Pubkey::new_unique(),
+                    vault_shares_before: i,
+                    vault_shares_after: i + 1,
+                    amount: i as u64,
+                }
+                .leaf()
+            })
+            .collect();
+
+        assert_eq!(merkle_root(&[]), [0; 32]);
+        assert_eq!(merkle_root(&leaves[..1]), leaves[0]);
+        assert_eq!(merkle_root(&leaves[..2]), node(&leaves[0], &leaves[1]));
+        assert_eq!(
+            merkle_root(&leaves),
+            node(
+                &node(&node(&leaves[0], &leaves[1]), &node(&leaves[2], &leaves[3])),
+                &leaves[4]
+            )
+        );
+
+        // order matters
+        let mut swapped = leaves.clone();
+        swapped.swap(0, 1);
+        assert_ne!(merkle_root(&swapped), merkle_root(&leaves));
+    }
+
+    #[test]
+    fn test_merkle_frontier_root() {
+        let leaves: Vec<[u8; 32]> = (0..40)
+            .map(|i| hashv(&[LEAF_PREFIX, &[i as u8]]).to_bytes())
+            .collect();
+
+        let mut frontier = [[0; 32]; MERKLE_FRONTIER_DEPTH];
+        assert_eq!(merkle_frontier_root(&frontier, 0), merkle_root(&[]));
+        for (i, leaf) in leaves.iter().enumerate() {
+            merkle_frontier_append(&mut frontier, i as u32, *leaf);
+            assert_eq!(
+                merkle_frontier_root(&frontier, i as u32 + 1),
+                merkle_root(&leaves[..=i])
+            );
+        }
+    }
+}
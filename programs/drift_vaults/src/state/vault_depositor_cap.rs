@@ -0,0 +1,88 @@
+use anchor_lang::prelude::*;
+use drift_macros::assert_no_slop;
+use static_assertions::const_assert_eq;
+
+use crate::error::ErrorCode;
+use crate::state::{Size, Vault, VaultFlag};
+use crate::validate;
+
+#[assert_no_slop]
+#[account(zero_copy(unsafe))]
+#[derive(Default, Eq, PartialEq, Debug)]
+#[repr(C)]
+pub struct VaultDepositorCap {
+    /// The vault the cap is enforced for
+    pub vault: Pubkey,
+    /// Max number of vault depositors that can be created, checked against `Vault::depositor_count`
+    pub max_depositors: u32,
+    pub bump: u8,
+    pub padding: [u8; 3],
+}
+
+impl Size for VaultDepositorCap {
+    const SIZE: usize = 40 + 8;
+}
+const_assert_eq!(
+    VaultDepositorCap::SIZE,
+    std::mem::size_of::<VaultDepositorCap>() + 8
+);
+
+impl VaultDepositorCap {
+    pub fn validate_max_depositors(max_depositors: u32) -> Result<()> {
+        validate!(
+            max_depositors > 0,
+            ErrorCode::InvalidVaultDepositorCap,
+            "max_depositors must be greater than 0"
+        )?;
+
+        Ok(())
+    }
+}
+
+impl Vault {
+    /// Validates a new vault depositor fits under the vault's [`VaultDepositorCap`], which must be provided once the
+    /// vault has one.
+    pub fn validate_depositor_cap(
+        &self,
+        cap: &Option<AccountLoader<VaultDepositorCap>>,
+    ) -> Result<()> {
+        match cap {
+            Some(cap) => {
+                let cap = cap.load()?;
+                validate!(
+                    cap.vault == self.pubkey,
+                    ErrorCode::InvalidVaultDepositorCap,
+                    "VaultDepositorCap is not for this vault"
+                )?;
+                validate!(
+                    self.depositor_count < cap.max_depositors,
+                    ErrorCode::VaultDepositorCapReached,
+                    "vault has {} depositors, max {}",
+                    self.depositor_count,
+                    cap.max_depositors
+                )?;
+            }
+            None => validate!(
+                !self.has_flag(VaultFlag::HasDepositorCap),
+                ErrorCode::InvalidVaultDepositorCap,
+                "VaultDepositorCap missing in remaining accounts"
+            )?,
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod vault_depositor_cap_tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_depositor_cap_missing() {
+        let mut vault = Vault::default();
+        vault.validate_depositor_cap(&None).unwrap();
+
+        vault.add_flag(VaultFlag::HasDepositorCap);
+        assert!(vault.validate_depositor_cap(&None).is_err());
+    }
+}
@@ -0,0 +1,95 @@
+use anchor_lang::prelude::*;
+use drift::math::safe_math::SafeMath;
+use drift_macros::assert_no_slop;
+use static_assertions::const_assert_eq;
+
+use crate::constants::ONE_HOUR;
+use crate::error::ErrorCode;
+use crate::state::Size;
+use crate::validate;
+
+/// How long an idempotency key is remembered. Retries of a timed out transaction land well within this window.
+pub const IDEMPOTENCY_KEY_WINDOW: i64 = ONE_HOUR;
+
+#[assert_no_slop]
+#[account(zero_copy(unsafe))]
+#[derive(Default, Eq, PartialEq, Debug)]
+#[repr(C)]
+pub struct VaultDepositorIdempotency {
+    /// The vault depositor the keys are tracked for
+    pub vault_depositor: Pubkey,
+    /// Last client supplied key that was executed
+    pub last_key: u64,
+    /// ts the last key was executed
+    pub last_key_ts: i64,
+    pub bump: u8,
+    pub padding: [u8; 7],
+}
+
+impl Size for VaultDepositorIdempotency {
+    const SIZE: usize = 56 + 8;
+}
+const_assert_eq!(
+    VaultDepositorIdempotency::SIZE,
+    std::mem::size_of::<VaultDepositorIdempotency>() + 8
+);
+
+impl VaultDepositorIdempotency {
+    pub fn get_address(vault_depositor: &Pubkey) -> Pubkey {
+        Pubkey::find_program_address(
+            &[b"vault_depositor_idempotency", vault_depositor.as_ref()],
+            &crate::id(),
+        )
+        .0
+    }
+
+    /// Fails if `key` was already executed within [`IDEMPOTENCY_KEY_WINDOW`], otherwise remembers it.
+    pub fn check_and_record(&mut self, key: u64, now: i64) -> Result<()> {
+        validate!(
+            key != 0,
+            ErrorCode::DuplicateIdempotencyKey,
+            "idempotency key must be non-zero"
+        )?;
+
+        validate!(
+            key != self.last_key || now.safe_sub(self.last_key_ts)? >= IDEMPOTENCY_KEY_WINDOW,
+            ErrorCode::DuplicateIdempotencyKey,
+            "idempotency key {} already executed at {}",
+            key,
+            self.last_key_ts
+        )?;
+
+        self.last_key = key;
+        self.last_key_ts = now;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod vault_depositor_idempotency_tests {
+    use super::*;
+
+    #[test]
+    fn test_check_and_record() {
+        let mut idempotency = VaultDepositorIdempotency::default();
+        let now = 1_000_000;
+
+        assert!(idempotency.check_and_record(0, now).is_err());
+
+        idempotency.check_and_record(1, now).unwrap();
+        assert_eq!(idempotency.last_key, 1);
+        assert_eq!(idempotency.last_key_ts, now);
+
+        // retry of the same instruction is rejected
+        assert!(idempotency.check_and_record(1, now + 10).is_err());
+
+        // a new key goes through
+        idempotency.check_and_record(2, now + 10).unwrap();
+
+        // an old key can be reused once it expires
+        idempotency
+            .check_and_record(2, now + 10 + IDEMPOTENCY_KEY_WINDOW)
+            .unwrap();
+    }
+}
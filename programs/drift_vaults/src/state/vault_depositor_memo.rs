@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+use drift_macros::assert_no_slop;
+use static_assertions::const_assert_eq;
+
+use crate::events::VaultDepositorMemoRecord;
+use crate::state::Size;
+
+#[assert_no_slop]
+#[account(zero_copy(unsafe))]
+#[derive(Default, Eq, PartialEq, Debug)]
+#[repr(C)]
+pub struct VaultDepositorMemo {
+    /// The vault depositor the memo tags
+    pub vault_depositor: Pubkey,
+    /// Free form tag set by the depositor authority, e.g. the hash of an internal reference
+    pub memo: [u8; 32],
+    /// ts the memo was last updated
+    pub last_update_ts: i64,
+    pub bump: u8,
+    pub padding: [u8; 7],
+}
+
+impl Size for VaultDepositorMemo {
+    const SIZE: usize = 80 + 8;
+}
+const_assert_eq!(
+    VaultDepositorMemo::SIZE,
+    std::mem::size_of::<VaultDepositorMemo>() + 8
+);
+
+impl VaultDepositorMemo {
+    pub fn get_address(vault_depositor: &Pubkey) -> Pubkey {
+        Pubkey::find_program_address(
+            &[b"vault_depositor_memo", vault_depositor.as_ref()],
+            &crate::id(),
+        )
+        .0
+    }
+
+    pub fn update(&mut self, vault: Pubkey, authority: Pubkey, memo: [u8; 32], now: i64) {
+        emit!(VaultDepositorMemoRecord {
+            ts: now,
+            vault,
+            vault_depositor: self.vault_depositor,
+            depositor_authority: authority,
+            memo_before: self.memo,
+            memo_after: memo,
+        });
+
+        self.memo = memo;
+        self.last_update_ts = now;
+    }
+}
@@ -0,0 +1,139 @@
+use anchor_lang::prelude::*;
+use bytemuck::Zeroable;
+use drift::math::casting::Cast;
+use drift::math::safe_math::SafeMath;
+use drift_macros::assert_no_slop;
+use static_assertions::const_assert_eq;
+
+use crate::state::events::VaultDepositorAction;
+use crate::state::{Size, Vault, VaultDepositor};
+
+/// Depositor records kept in a [`VaultDepositorRecordLog`]
+pub const VAULT_DEPOSITOR_RECORD_LOG_ENTRIES: usize = 32;
+
+/// The parts of a [`VaultDepositorRecord`](crate::state::events::VaultDepositorRecord) an indexer needs to rebuild a
+/// depositor's position. Share amounts after are in the vault's shares base at `ts`, `vault_shares_before` is in the
+/// depositor's base before the action and lags it if the action applied a
+/// [rebase](crate::state::events::VaultRebaseRecord).
+#[assert_no_slop]
+#[derive(
+    Default, AnchorSerialize, AnchorDeserialize, Copy, Clone, Eq, PartialEq, Debug, Zeroable,
+)]
+pub struct VaultDepositorRecordLogEntry {
+    pub depositor_authority: Pubkey,
+    pub vault_shares_before: u128,
+    pub vault_shares_after: u128,
+    pub total_vault_shares_after: u128,
+    pub ts: i64,
+    /// deposited, requested, withdrawn or profit share paid, precision: token mint
+    pub amount: u64,
+    pub vault_equity_before: u64,
+    /// [`VaultDepositorAction`]
+    pub action: u8,
+    pub padding: [u8; 7],
+}
+
+/// Opt-in on-chain mirror of a vault's latest depositor records, so indexers on RPCs that truncate logs can backfill
+/// missed events by reading the account. Holds the latest [`VAULT_DEPOSITOR_RECORD_LOG_ENTRIES`] deposits, withdraw
+/// requests, cancels, withdraws and profit share payments, record `n` is in entry
+/// `n % VAULT_DEPOSITOR_RECORD_LOG_ENTRIES`. Only written when passed in remaining accounts, a gap in the depositor's
+/// share chain means a record was left out.
+#[assert_no_slop]
+#[account(zero_copy(unsafe))]
+#[derive(Default, Eq, PartialEq, Debug)]
+#[repr(C)]
+pub struct VaultDepositorRecordLog {
+    /// The vault whose depositor records are logged
+    pub vault: Pubkey,
+    pub entries: [VaultDepositorRecordLogEntry; VAULT_DEPOSITOR_RECORD_LOG_ENTRIES],
+    /// Records logged since the log was created
+    pub count: u64,
+    pub bump: u8,
+    pub padding: [u8; 7],
+}
+
+impl Size for VaultDepositorRecordLog {
+    const SIZE: usize = 3632 + 8;
+}
+const_assert_eq!(
+    VaultDepositorRecordLog::SIZE,
+    std::mem::size_of::<VaultDepositorRecordLog>() + 8
+);
+
+impl VaultDepositorRecordLog {
+    pub fn get_address(vault: &Pubkey) -> Pubkey {
+        Pubkey::find_program_address(
+            &[b"vault_depositor_record_log", vault.as_ref()],
+            &crate::id(),
+        )
+        .0
+    }
+
+    pub fn push(&mut self, entry: VaultDepositorRecordLogEntry) -> Result<()> {
+        let index = self
+            .count
+            .safe_rem(VAULT_DEPOSITOR_RECORD_LOG_ENTRIES.cast()?)?
+            .cast::<usize>()?;
+        self.entries[index] = entry;
+        self.count = self.count.safe_add(1)?;
+
+        Ok(())
+    }
+}
+
+/// Appends the depositor's `action` to `log`, if the vault's [`VaultDepositorRecordLog`] was passed.
+#[allow(clippy::too_many_arguments)]
+pub fn record_vault_depositor_action(
+    log: &Option<AccountLoader<VaultDepositorRecordLog>>,
+    vault: &Vault,
+    vault_depositor: &VaultDepositor,
+    action: VaultDepositorAction,
+    amount: u64,
+    vault_shares_before: u128,
+    vault_equity_before: u64,
+    now: i64,
+) -> Result<()> {
+    if let Some(log) = log {
+        log.load_mut()?.push(VaultDepositorRecordLogEntry {
+            depositor_authority: vault_depositor.authority,
+            vault_shares_before,
+            vault_shares_after: vault_depositor.checked_vault_shares(vault)?,
+            total_vault_shares_after: vault.total_shares,
+            ts: now,
+            amount,
+            vault_equity_before,
+            action: action as u8,
+            padding: [0; 7],
+        })?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::state::{
+        VaultDepositorRecordLog, VaultDepositorRecordLogEntry, VAULT_DEPOSITOR_RECORD_LOG_ENTRIES,
+    };
+
+    #[test]
+    fn test_vault_depositor_record_log_wraps() {
+        let mut log = VaultDepositorRecordLog::default();
+
+        for i in 0..VAULT_DEPOSITOR_RECORD_LOG_ENTRIES as i64 + 2 {
+            log.push(VaultDepositorRecordLogEntry {
+                ts: i,
+                ..VaultDepositorRecordLogEntry::default()
+            })
+            .unwrap();
+        }
+
+        assert_eq!(log.count, VAULT_DEPOSITOR_RECORD_LOG_ENTRIES as u64 + 2);
+        assert_eq!(log.entries[0].ts, VAULT_DEPOSITOR_RECORD_LOG_ENTRIES as i64);
+        assert_eq!(
+            log.entries[1].ts,
+            VAULT_DEPOSITOR_RECORD_LOG_ENTRIES as i64 + 1
+        );
+        assert_eq!(log.entries[2].ts, 2);
+    }
+}
@@ -0,0 +1,176 @@
+use anchor_lang::prelude::*;
+use drift::math::safe_math::SafeMath;
+use drift_macros::assert_no_slop;
+use static_assertions::const_assert_eq;
+
+use crate::constants::ONE_DAY;
+use crate::error::ErrorCode;
+use crate::state::{Size, VaultDepositor};
+use crate::validate;
+
+pub const MAX_DEPOSITOR_THROTTLE_INTERVAL: u32 = ONE_DAY as u32;
+
+/// Per-depositor rate limits on deposits and withdraw requests, to deter probing the share price and sandwiching
+/// oracle updates. Enforced once the vault's [`VaultConfig`](crate::state::VaultConfig) has
+/// [`VaultConfigFlag::DepositorThrottle`](crate::state::VaultConfigFlag::DepositorThrottle). Depositors track their
+/// last deposit in a [`VaultDepositorThrottleState`], withdraw requests are checked against the [`VaultDepositor`].
+#[assert_no_slop]
+#[account(zero_copy(unsafe))]
+#[derive(Default, Eq, PartialEq, Debug)]
+#[repr(C)]
+pub struct VaultDepositorThrottle {
+    /// The vault the throttle is enforced for
+    pub vault: Pubkey,
+    /// Min seconds from the depositor's last deposit to its next deposit
+    pub min_deposit_interval: u32,
+    /// Min seconds from the depositor's last withdraw request, cancel or withdraw to its next withdraw request
+    pub min_request_interval: u32,
+    pub bump: u8,
+    pub padding: [u8; 7],
+}
+
+impl Size for VaultDepositorThrottle {
+    const SIZE: usize = 48 + 8;
+}
+const_assert_eq!(
+    VaultDepositorThrottle::SIZE,
+    std::mem::size_of::<VaultDepositorThrottle>() + 8
+);
+
+/// A depositor's last deposit into a vault with a [`VaultDepositorThrottle`]. Deposits into such a vault require
+/// it, depositors that never created one haven't deposited since the throttle was enabled.
+#[assert_no_slop]
+#[account(zero_copy(unsafe))]
+#[derive(Default, Eq, PartialEq, Debug)]
+#[repr(C)]
+pub struct VaultDepositorThrottleState {
+    /// The vault depositor whose deposits are tracked
+    pub vault_depositor: Pubkey,
+    pub last_deposit_ts: i64,
+    pub bump: u8,
+    pub padding: [u8; 7],
+}
+
+impl Size for VaultDepositorThrottleState {
+    const SIZE: usize = 48 + 8;
+}
+const_assert_eq!(
+    VaultDepositorThrottleState::SIZE,
+    std::mem::size_of::<VaultDepositorThrottleState>() + 8
+);
+
+impl VaultDepositorThrottle {
+    pub fn get_address(vault: &Pubkey) -> Pubkey {
+        Pubkey::find_program_address(&[b"vault_depositor_throttle", vault.as_ref()], &crate::id()).0
+    }
+
+    pub fn update_intervals(
+        &mut self,
+        min_deposit_interval: u32,
+        min_request_interval: u32,
+    ) -> Result<()> {
+        validate!(
+            min_deposit_interval <= MAX_DEPOSITOR_THROTTLE_INTERVAL
+                && min_request_interval <= MAX_DEPOSITOR_THROTTLE_INTERVAL,
+            ErrorCode::InvalidVaultDepositorThrottle,
+            "throttle intervals must be at most {}",
+            MAX_DEPOSITOR_THROTTLE_INTERVAL
+        )?;
+
+        self.min_deposit_interval = min_deposit_interval;
+        self.min_request_interval = min_request_interval;
+
+        Ok(())
+    }
+
+    pub fn validate_deposit(
+        &self,
+        vault_depositor_throttle_state: &VaultDepositorThrottleState,
+        now: i64,
+    ) -> Result<()> {
+        let next_ts = vault_depositor_throttle_state
+            .last_deposit_ts
+            .safe_add(self.min_deposit_interval as i64)?;
+        validate!(
+            now >= next_ts,
+            ErrorCode::VaultDepositorThrottled,
+            "depositor can deposit again at {}",
+            next_ts
+        )?;
+
+        Ok(())
+    }
+
+    /// Withdraw requests, cancels and withdraws all set the depositor's withdraw request ts.
+    pub fn validate_request(&self, vault_depositor: &VaultDepositor, now: i64) -> Result<()> {
+        let last_ts = vault_depositor.last_withdraw_request.ts;
+        let next_ts = last_ts.safe_add(self.min_request_interval as i64)?;
+        validate!(
+            now >= next_ts,
+            ErrorCode::VaultDepositorThrottled,
+            "depositor can request a withdraw again at {}",
+            next_ts
+        )?;
+
+        Ok(())
+    }
+}
+
+impl VaultDepositorThrottleState {
+    pub fn get_address(vault_depositor: &Pubkey) -> Pubkey {
+        Pubkey::find_program_address(
+            &[b"vault_depositor_throttle_state", vault_depositor.as_ref()],
+            &crate::id(),
+        )
+        .0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anchor_lang::prelude::Pubkey;
+
+    use crate::error::ErrorCode;
+    use crate::state::{
+        VaultDepositor, VaultDepositorThrottle, VaultDepositorThrottleState,
+        MAX_DEPOSITOR_THROTTLE_INTERVAL,
+    };
+
+    #[test]
+    fn test_throttle() {
+        let now = 10_000;
+        let mut throttle = VaultDepositorThrottle::default();
+        assert!(throttle
+            .update_intervals(MAX_DEPOSITOR_THROTTLE_INTERVAL + 1, 0)
+            .is_err());
+        throttle.update_intervals(60, 300).unwrap();
+
+        // never deposited since the throttle was enabled
+        let mut throttle_state = VaultDepositorThrottleState::default();
+        throttle.validate_deposit(&throttle_state, 0).unwrap();
+
+        throttle_state.last_deposit_ts = now;
+        let mut vd =
+            VaultDepositor::new(Pubkey::default(), Pubkey::default(), Pubkey::default(), 0);
+        vd.last_withdraw_request.ts = now;
+
+        assert_eq!(
+            throttle.validate_deposit(&throttle_state, now + 59),
+            Err(ErrorCode::VaultDepositorThrottled.into())
+        );
+        throttle
+            .validate_deposit(&throttle_state, now + 60)
+            .unwrap();
+
+        assert_eq!(
+            throttle.validate_request(&vd, now + 299),
+            Err(ErrorCode::VaultDepositorThrottled.into())
+        );
+        throttle.validate_request(&vd, now + 300).unwrap();
+
+        // zero intervals don't throttle
+        throttle.update_intervals(0, 0).unwrap();
+        throttle.validate_deposit(&throttle_state, now).unwrap();
+        throttle.validate_request(&vd, now).unwrap();
+    }
+}
@@ -0,0 +1,220 @@
+use anchor_lang::prelude::*;
+use drift::math::safe_math::SafeMath;
+use drift_macros::assert_no_slop;
+use static_assertions::const_assert_eq;
+
+use crate::error::ErrorCode;
+use crate::state::withdraw_request::WithdrawRequest;
+use crate::state::{Size, VaultDepositor, VaultDepositorBase, VaultDepositorFlag};
+use crate::validate;
+
+pub const MAX_PARKED_WITHDRAW_REQUESTS: usize = 4;
+
+/// Withdraw requests a depositor has parked out of `last_withdraw_request`, so it can hold several partial requests
+/// with their own redeem timers. A parked request keeps its shares, value and ts and still counts towards the vault's
+/// requested totals, it's moved back into `last_withdraw_request` to be withdrawn or cancelled. Once created every
+/// withdraw request must pass it, see [`VaultDepositorFlag::ParkedWithdrawRequests`], so parked and pending requests
+/// never take more than the depositor's shares.
+#[assert_no_slop]
+#[account(zero_copy(unsafe))]
+#[derive(Default, Eq, PartialEq, Debug)]
+#[repr(C)]
+pub struct VaultDepositorWithdrawRequests {
+    /// The vault depositor the requests belong to
+    pub vault_depositor: Pubkey,
+    pub requests: [WithdrawRequest; MAX_PARKED_WITHDRAW_REQUESTS],
+    /// The vault depositor's shares_base when the requests were last updated
+    pub shares_base: u32,
+    pub bump: u8,
+    pub padding: [u8; 11],
+}
+
+impl Size for VaultDepositorWithdrawRequests {
+    const SIZE: usize = 176 + 8;
+}
+const_assert_eq!(
+    VaultDepositorWithdrawRequests::SIZE,
+    std::mem::size_of::<VaultDepositorWithdrawRequests>() + 8
+);
+
+impl VaultDepositorWithdrawRequests {
+    pub fn get_address(vault_depositor: &Pubkey) -> Pubkey {
+        Pubkey::find_program_address(
+            &[
+                b"vault_depositor_withdraw_requests",
+                vault_depositor.as_ref(),
+            ],
+            &crate::id(),
+        )
+        .0
+    }
+
+    fn rebase_divisor(&self, vault_depositor: &VaultDepositor) -> Result<u128> {
+        let expo_diff = vault_depositor
+            .get_vault_shares_base()
+            .safe_sub(self.shares_base)?;
+        Ok(10_u128.pow(expo_diff))
+    }
+
+    /// Brings the parked requests to the vault depositor's shares base, rebases only ever increase it
+    pub fn rebase(&mut self, vault_depositor: &VaultDepositor) -> Result<()> {
+        let rebase_divisor = self.rebase_divisor(vault_depositor)?;
+        if rebase_divisor != 1 {
+            for request in self.requests.iter_mut() {
+                request.rebase(rebase_divisor)?;
+            }
+            self.shares_base = vault_depositor.get_vault_shares_base();
+        }
+
+        Ok(())
+    }
+
+    /// Shares of every parked request, at the vault depositor's shares base
+    pub fn parked_shares(&self, vault_depositor: &VaultDepositor) -> Result<u128> {
+        let rebase_divisor = self.rebase_divisor(vault_depositor)?;
+        self.requests.iter().try_fold(0_u128, |total, request| {
+            Ok(total.safe_add(request.shares.safe_div(rebase_divisor)?)?)
+        })
+    }
+
+    /// The pending withdraw request can't take shares already parked
+    pub fn validate_request(&self, vault_depositor: &VaultDepositor) -> Result<()> {
+        let parked_shares = self.parked_shares(vault_depositor)?;
+        let requested_shares =
+            parked_shares.safe_add(vault_depositor.last_withdraw_request.shares)?;
+        validate!(
+            requested_shares <= vault_depositor.get_vault_shares(),
+            ErrorCode::InvalidVaultWithdrawSize,
+            "withdraw request takes parked shares: parked {}, pending {}, vault shares {}",
+            parked_shares,
+            vault_depositor.last_withdraw_request.shares,
+            vault_depositor.get_vault_shares()
+        )?;
+
+        Ok(())
+    }
+
+    /// Moves the pending withdraw request into a free slot so another can be requested, returns the slot index.
+    pub fn park(&mut self, vault_depositor: &mut VaultDepositor, now: i64) -> Result<usize> {
+        validate!(
+            vault_depositor.last_withdraw_request.pending(),
+            ErrorCode::InvalidVaultDepositorWithdrawRequests,
+            "no withdraw request to park"
+        )?;
+        // keepers and renewals only act on the pending request
+        validate!(
+            !vault_depositor.has_flag(VaultDepositorFlag::QueuedWithdraw),
+            ErrorCode::InvalidVaultDepositorWithdrawRequests,
+            "can't park a queued withdraw request"
+        )?;
+
+        self.rebase(vault_depositor)?;
+        let index = self
+            .requests
+            .iter()
+            .position(|request| !request.pending())
+            .ok_or(ErrorCode::InvalidVaultDepositorWithdrawRequests)?;
+
+        self.requests[index] = vault_depositor.last_withdraw_request;
+        vault_depositor.last_withdraw_request.reset(now)?;
+
+        msg!(
+            "parked withdraw request {}: shares={} value={} ts={}",
+            index,
+            self.requests[index].shares,
+            self.requests[index].value,
+            self.requests[index].ts
+        );
+
+        Ok(index)
+    }
+
+    /// Moves the parked request at `index` back into the vault depositor's `last_withdraw_request`.
+    pub fn unpark(&mut self, vault_depositor: &mut VaultDepositor, index: usize) -> Result<()> {
+        validate!(
+            index < MAX_PARKED_WITHDRAW_REQUESTS && self.requests[index].pending(),
+            ErrorCode::InvalidVaultDepositorWithdrawRequests,
+            "no parked withdraw request at {}",
+            index
+        )?;
+        validate!(
+            !vault_depositor.last_withdraw_request.pending(),
+            ErrorCode::VaultWithdrawRequestInProgress,
+            "park the pending withdraw request first"
+        )?;
+
+        self.rebase(vault_depositor)?;
+        vault_depositor.last_withdraw_request = self.requests[index];
+        self.requests[index] = WithdrawRequest::default();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::state::{
+        Vault, VaultDepositor, VaultDepositorBase, VaultDepositorFlag,
+        VaultDepositorWithdrawRequests, MAX_PARKED_WITHDRAW_REQUESTS,
+    };
+
+    #[test]
+    fn test_park_withdraw_requests() {
+        let vault = Vault {
+            redeem_period: 100,
+            ..Vault::default()
+        };
+        let mut vault_depositor = VaultDepositor::default();
+        vault_depositor.set_vault_shares(1_000);
+        let mut parked = VaultDepositorWithdrawRequests::default();
+
+        assert!(parked.park(&mut vault_depositor, 0).is_err());
+
+        // two requests with their own timers
+        vault_depositor
+            .last_withdraw_request
+            .set(1_000, 300, 30, 100, 10)
+            .unwrap();
+        assert_eq!(parked.park(&mut vault_depositor, 10).unwrap(), 0);
+        assert!(!vault_depositor.last_withdraw_request.pending());
+        vault_depositor
+            .last_withdraw_request
+            .set(1_000, 700, 70, 100, 50)
+            .unwrap();
+        parked.validate_request(&vault_depositor).unwrap();
+
+        // the parked shares can't be requested again
+        vault_depositor.last_withdraw_request.shares = 701;
+        assert!(parked.validate_request(&vault_depositor).is_err());
+        vault_depositor.last_withdraw_request.shares = 700;
+
+        vault_depositor.add_flag(VaultDepositorFlag::QueuedWithdraw);
+        assert!(parked.park(&mut vault_depositor, 50).is_err());
+        vault_depositor.remove_flag(VaultDepositorFlag::QueuedWithdraw);
+        assert_eq!(parked.park(&mut vault_depositor, 50).unwrap(), 1);
+        assert_eq!(parked.parked_shares(&vault_depositor).unwrap(), 1_000);
+
+        // the first request matures on its own
+        assert!(parked.unpark(&mut vault_depositor, 2).is_err());
+        assert!(parked
+            .unpark(&mut vault_depositor, MAX_PARKED_WITHDRAW_REQUESTS)
+            .is_err());
+        parked.unpark(&mut vault_depositor, 0).unwrap();
+        assert_eq!(vault_depositor.last_withdraw_request.shares, 300);
+        vault_depositor
+            .last_withdraw_request
+            .check_redeem_period_finished(&vault, 110)
+            .unwrap();
+        assert!(parked.unpark(&mut vault_depositor, 1).is_err());
+
+        // a rebase scales the parked requests with the depositor's shares
+        vault_depositor.last_withdraw_request.reset(110).unwrap();
+        vault_depositor.set_vault_shares(70);
+        vault_depositor.vault_shares_base = 1;
+        assert_eq!(parked.parked_shares(&vault_depositor).unwrap(), 70);
+        parked.unpark(&mut vault_depositor, 1).unwrap();
+        assert_eq!(vault_depositor.last_withdraw_request.shares, 70);
+        assert_eq!(vault_depositor.last_withdraw_request.value, 70);
+        assert_eq!(parked.shares_base, 1);
+    }
+}
@@ -0,0 +1,111 @@
+use anchor_lang::prelude::*;
+use drift::math::safe_math::SafeMath;
+use drift_macros::assert_no_slop;
+use static_assertions::const_assert_eq;
+
+use crate::constants::ONE_DAY;
+use crate::error::ErrorCode;
+use crate::state::Size;
+use crate::validate;
+
+/// Window the vault wide dust withdraw budget resets over
+pub const DUST_WITHDRAW_WINDOW: i64 = ONE_DAY;
+/// A depositor can only dust withdraw this long after its last withdraw request or withdraw
+pub const DUST_WITHDRAW_COOLDOWN: i64 = ONE_DAY;
+
+#[assert_no_slop]
+#[account(zero_copy(unsafe))]
+#[derive(Default, Eq, PartialEq, Debug)]
+#[repr(C)]
+pub struct VaultDustWithdraw {
+    /// The vault dust withdraws are enabled for
+    pub vault: Pubkey,
+    /// Depositors whose whole balance is at or below this amount can withdraw without a redeem period
+    pub threshold: u64,
+    /// Max total dust withdrawn per [`DUST_WITHDRAW_WINDOW`], so splitting a balance across depositors can't drain
+    /// the vault around the redeem period
+    pub window_max_amount: u64,
+    /// Dust withdrawn in the current window
+    pub window_amount: u64,
+    pub window_start_ts: i64,
+    pub bump: u8,
+    pub padding: [u8; 7],
+}
+
+impl Size for VaultDustWithdraw {
+    const SIZE: usize = 72 + 8;
+}
+const_assert_eq!(
+    VaultDustWithdraw::SIZE,
+    std::mem::size_of::<VaultDustWithdraw>() + 8
+);
+
+impl VaultDustWithdraw {
+    /// Validates a dust withdraw of the depositor's whole `balance` and counts it against the window budget.
+    /// `last_withdraw_ts` is the depositor's last withdraw request or withdraw.
+    pub fn record(&mut self, balance: u64, last_withdraw_ts: i64, now: i64) -> Result<()> {
+        validate!(
+            balance <= self.threshold,
+            ErrorCode::InvalidDustWithdraw,
+            "balance {} above dust threshold {}",
+            balance,
+            self.threshold
+        )?;
+
+        validate!(
+            now.safe_sub(last_withdraw_ts)? >= DUST_WITHDRAW_COOLDOWN,
+            ErrorCode::InvalidDustWithdraw,
+            "dust withdraw available at {}",
+            last_withdraw_ts.safe_add(DUST_WITHDRAW_COOLDOWN)?
+        )?;
+
+        if now.safe_sub(self.window_start_ts)? >= DUST_WITHDRAW_WINDOW {
+            self.window_start_ts = now;
+            self.window_amount = 0;
+        }
+
+        let window_amount = self.window_amount.safe_add(balance)?;
+        validate!(
+            window_amount <= self.window_max_amount,
+            ErrorCode::InvalidDustWithdraw,
+            "dust withdraw budget {} exhausted until {}",
+            self.window_max_amount,
+            self.window_start_ts.safe_add(DUST_WITHDRAW_WINDOW)?
+        )?;
+        self.window_amount = window_amount;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod vault_dust_withdraw_tests {
+    use super::*;
+
+    #[test]
+    fn test_record() {
+        let now = 1_000_000;
+        let mut dust = VaultDustWithdraw {
+            threshold: 10,
+            window_max_amount: 25,
+            ..VaultDustWithdraw::default()
+        };
+
+        // above threshold
+        assert!(dust.record(11, 0, now).is_err());
+        // cooling down after a withdraw
+        assert!(dust.record(10, now - 1, now).is_err());
+
+        dust.record(10, 0, now).unwrap();
+        dust.record(10, 0, now + 1).unwrap();
+        assert_eq!(dust.window_amount, 20);
+        // budget exhausted
+        assert!(dust.record(10, 0, now + 2).is_err());
+        dust.record(5, 0, now + 2).unwrap();
+
+        // next window
+        dust.record(10, 0, now + DUST_WITHDRAW_WINDOW).unwrap();
+        assert_eq!(dust.window_amount, 10);
+        assert_eq!(dust.window_start_ts, now + DUST_WITHDRAW_WINDOW);
+    }
+}
@@ -0,0 +1,39 @@
+use crate::error::ErrorCode;
+
+/// Optional vault code paths the manager can switch off, stored as bits of [`Vault`](crate::state::Vault)
+/// `disabled_features`. All features are enabled by default. Exits from a feature already in use (e.g.
+/// `redeem_tokens`, `claim_withdraw_stream`) are never disabled.
+#[derive(Clone, Copy, PartialEq, Debug, Eq)]
+pub enum VaultFeature {
+    /// `initialize_tokenized_vault_depositor`, `tokenize_shares`
+    Tokenization = 0b00000001,
+    /// `initialize_vault_hook`, `update_vault_hook`
+    Hooks = 0b00000010,
+    /// `withdraw_dust`, instant withdraws skipping the redeem period
+    DustWithdraw = 0b00000100,
+    /// `start_withdraw_stream`
+    WithdrawStream = 0b00001000,
+    /// `deposit_with_interface`, `withdraw_with_interface`
+    Interface = 0b00010000,
+    /// `deposit_with_swap`
+    Swaps = 0b00100000,
+    /// `propose_vault_depositor_authority_transfer`
+    Transfers = 0b01000000,
+}
+
+impl VaultFeature {
+    pub const ALL: u8 = VaultFeature::Tokenization as u8
+        | VaultFeature::Hooks as u8
+        | VaultFeature::DustWithdraw as u8
+        | VaultFeature::WithdrawStream as u8
+        | VaultFeature::Interface as u8
+        | VaultFeature::Swaps as u8
+        | VaultFeature::Transfers as u8;
+
+    pub fn validate_flags(flags: u8) -> std::result::Result<(), ErrorCode> {
+        if flags & !VaultFeature::ALL != 0 {
+            return Err(ErrorCode::InvalidVaultFeatureFlags);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,222 @@
+use anchor_lang::prelude::*;
+use borsh::{BorshDeserialize, BorshSerialize};
+use drift::math::safe_math::SafeMath;
+use drift_macros::assert_no_slop;
+use static_assertions::const_assert_eq;
+
+use crate::constants::ONE_DAY;
+use crate::error::ErrorCode;
+use crate::state::events::FeeHolidayRecord;
+use crate::state::{Size, Vault, VaultFlag};
+use crate::validate;
+
+/// Longest fee holiday a manager can schedule
+pub const MAX_FEE_HOLIDAY_DURATION: i64 = 90 * ONE_DAY;
+
+#[derive(Clone, Copy, BorshSerialize, BorshDeserialize, PartialEq, Debug, Eq, Default)]
+pub enum FeeHolidayAction {
+    #[default]
+    Schedule,
+    Cancel,
+    Start,
+    End,
+}
+
+/// A window during which the manager's management fee and/or profit share is suspended, e.g. to make depositors
+/// whole after an incident.
+///
+/// The window is applied by `crank_vault_fee_holiday`, which accrues fees up to the crank and then sets or clears
+/// the holiday [`VaultFlag`]s that `Vault::apply_fee` and the profit share math check. Anyone can crank, so
+/// depositors can start a holiday on time and the manager can end it on time.
+#[assert_no_slop]
+#[account(zero_copy(unsafe))]
+#[derive(Default, Eq, PartialEq, Debug)]
+#[repr(C)]
+pub struct VaultFeeHoliday {
+    /// The vault the fee holiday is for
+    pub vault: Pubkey,
+    /// When the scheduled holiday starts, 0 if none is scheduled
+    pub start_ts: i64,
+    /// When the scheduled holiday ends
+    pub end_ts: i64,
+    pub suspend_management_fee: bool,
+    pub suspend_profit_share: bool,
+    /// Whether the holiday has been started by a crank and not yet ended
+    pub active: bool,
+    pub bump: u8,
+    pub padding: [u8; 4],
+}
+
+impl Size for VaultFeeHoliday {
+    const SIZE: usize = 56 + 8;
+}
+const_assert_eq!(
+    VaultFeeHoliday::SIZE,
+    std::mem::size_of::<VaultFeeHoliday>() + 8
+);
+
+impl VaultFeeHoliday {
+    pub fn is_scheduled(&self) -> bool {
+        self.start_ts != 0
+    }
+
+    pub fn schedule(
+        &mut self,
+        start_ts: i64,
+        end_ts: i64,
+        suspend_management_fee: bool,
+        suspend_profit_share: bool,
+        now: i64,
+    ) -> Result<()> {
+        validate!(
+            !self.active,
+            ErrorCode::InvalidFeeHoliday,
+            "fee holiday already active until {}",
+            self.end_ts
+        )?;
+        validate!(
+            start_ts >= now && end_ts > start_ts,
+            ErrorCode::InvalidFeeHoliday,
+            "invalid fee holiday window {} -> {}",
+            start_ts,
+            end_ts
+        )?;
+        validate!(
+            end_ts.safe_sub(start_ts)? <= MAX_FEE_HOLIDAY_DURATION,
+            ErrorCode::InvalidFeeHoliday,
+            "fee holiday longer than {}",
+            MAX_FEE_HOLIDAY_DURATION
+        )?;
+        validate!(
+            suspend_management_fee || suspend_profit_share,
+            ErrorCode::InvalidFeeHoliday,
+            "fee holiday must suspend a fee"
+        )?;
+
+        self.start_ts = start_ts;
+        self.end_ts = end_ts;
+        self.suspend_management_fee = suspend_management_fee;
+        self.suspend_profit_share = suspend_profit_share;
+
+        Ok(())
+    }
+
+    /// Only a holiday that hasn't started can be cancelled.
+    pub fn cancel(&mut self) -> Result<()> {
+        validate!(
+            self.is_scheduled() && !self.active,
+            ErrorCode::InvalidFeeHoliday,
+            "no pending fee holiday to cancel"
+        )?;
+
+        self.clear();
+
+        Ok(())
+    }
+
+    /// Starts or ends the holiday depending on `now`. The caller must accrue fees with `Vault::apply_fee` first,
+    /// so the period before the crank is charged at the flags in effect during it.
+    pub fn crank(&mut self, vault: &mut Vault, now: i64) -> Result<FeeHolidayAction> {
+        validate!(
+            self.is_scheduled() && now >= self.start_ts,
+            ErrorCode::InvalidFeeHoliday,
+            "no fee holiday to start"
+        )?;
+
+        if !self.active && now < self.end_ts {
+            if self.suspend_management_fee {
+                vault.add_flag(VaultFlag::ManagementFeeHoliday);
+            }
+            if self.suspend_profit_share {
+                vault.add_flag(VaultFlag::ProfitShareHoliday);
+            }
+            self.active = true;
+
+            Ok(FeeHolidayAction::Start)
+        } else {
+            validate!(
+                now >= self.end_ts,
+                ErrorCode::InvalidFeeHoliday,
+                "fee holiday active until {}",
+                self.end_ts
+            )?;
+
+            vault.remove_flag(VaultFlag::ManagementFeeHoliday);
+            vault.remove_flag(VaultFlag::ProfitShareHoliday);
+            self.clear();
+
+            Ok(FeeHolidayAction::End)
+        }
+    }
+
+    pub fn emit_record(&self, action: FeeHolidayAction, now: i64) {
+        emit!(FeeHolidayRecord {
+            ts: now,
+            vault: self.vault,
+            action,
+            start_ts: self.start_ts,
+            end_ts: self.end_ts,
+            suspend_management_fee: self.suspend_management_fee,
+            suspend_profit_share: self.suspend_profit_share,
+        });
+    }
+
+    fn clear(&mut self) {
+        self.start_ts = 0;
+        self.end_ts = 0;
+        self.suspend_management_fee = false;
+        self.suspend_profit_share = false;
+        self.active = false;
+    }
+}
+
+#[cfg(test)]
+mod vault_fee_holiday_tests {
+    use super::*;
+
+    #[test]
+    fn test_fee_holiday_crank() {
+        let now = 1_000_000;
+        let mut vault = Vault {
+            profit_share: 100_000,
+            ..Vault::default()
+        };
+        let mut holiday = VaultFeeHoliday::default();
+
+        assert!(holiday
+            .schedule(now - 1, now + 10, true, true, now)
+            .is_err());
+        assert!(holiday.schedule(now, now, true, true, now).is_err());
+        assert!(holiday.schedule(now, now + 10, false, false, now).is_err());
+        assert!(holiday
+            .schedule(now, now + MAX_FEE_HOLIDAY_DURATION + 1, true, false, now)
+            .is_err());
+
+        holiday
+            .schedule(now + 5, now + 10, false, true, now)
+            .unwrap();
+        // not started yet
+        assert!(holiday.crank(&mut vault, now + 4).is_err());
+
+        assert_eq!(
+            holiday.crank(&mut vault, now + 5).unwrap(),
+            FeeHolidayAction::Start
+        );
+        assert!(!vault.has_flag(VaultFlag::ManagementFeeHoliday));
+        assert_eq!(vault.manager_profit_share(), 0);
+        // can't reschedule, cancel or end early while active
+        assert!(holiday
+            .schedule(now + 20, now + 30, true, true, now + 6)
+            .is_err());
+        assert!(holiday.cancel().is_err());
+        assert!(holiday.crank(&mut vault, now + 9).is_err());
+
+        assert_eq!(
+            holiday.crank(&mut vault, now + 10).unwrap(),
+            FeeHolidayAction::End
+        );
+        assert_eq!(vault.manager_profit_share(), 100_000);
+        assert!(!holiday.is_scheduled());
+        assert!(holiday.crank(&mut vault, now + 11).is_err());
+    }
+}
@@ -0,0 +1,175 @@
+use std::cell::RefMut;
+
+use anchor_lang::prelude::*;
+use drift::math::casting::Cast;
+use drift::math::constants::PERCENTAGE_PRECISION;
+use drift::math::insurance::{
+    if_shares_to_vault_amount as depositor_shares_to_vault_amount,
+    vault_amount_to_if_shares as vault_amount_to_depositor_shares,
+};
+use drift::math::safe_math::SafeMath;
+use drift_macros::assert_no_slop;
+use static_assertions::const_assert_eq;
+
+use crate::error::ErrorCode;
+use crate::state::events::FeeSplitRecord;
+use crate::state::{Size, Vault, VaultProtocol};
+use crate::validate;
+
+/// A slice of the manager's profit share the manager committed to an external token account, e.g. a charity or a
+/// burn address. Every profit share crystallized after the split is configured accrues `split` of it as owed, which
+/// `distribute_vault_fee_split` pays out of the manager's shares.
+#[assert_no_slop]
+#[account(zero_copy(unsafe))]
+#[derive(Default, Eq, PartialEq, Debug)]
+#[repr(C)]
+pub struct VaultFeeSplit {
+    /// The vault the split is for
+    pub vault: Pubkey,
+    /// Token account the split is paid to
+    pub recipient: Pubkey,
+    /// `Vault::manager_total_profit_share` the split has accrued up to
+    pub last_manager_total_profit_share: u64,
+    /// Accrued and not yet paid, precision: token mint
+    pub owed: u64,
+    /// Total paid to the recipient, precision: token mint
+    pub total_paid: u64,
+    /// Slice of manager profit share, precision: PERCENTAGE_PRECISION
+    pub split: u32,
+    pub bump: u8,
+    pub padding: [u8; 3],
+}
+
+impl Size for VaultFeeSplit {
+    const SIZE: usize = 96 + 8;
+}
+const_assert_eq!(
+    VaultFeeSplit::SIZE,
+    std::mem::size_of::<VaultFeeSplit>() + 8
+);
+
+impl VaultFeeSplit {
+    /// Accrues the split of the profit share crystallized since the last accrual, then sets the new terms
+    pub fn update(&mut self, vault: &Vault, split: u32, recipient: Pubkey) -> Result<()> {
+        validate!(
+            split.cast::<u128>()? <= PERCENTAGE_PRECISION,
+            ErrorCode::InvalidVaultFeeSplit,
+            "split {} above 100%",
+            split
+        )?;
+
+        self.accrue(vault)?;
+        self.split = split;
+        self.recipient = recipient;
+
+        Ok(())
+    }
+
+    pub fn accrue(&mut self, vault: &Vault) -> Result<u64> {
+        let new_profit_share = vault
+            .manager_total_profit_share
+            .safe_sub(self.last_manager_total_profit_share)?;
+        let accrued = new_profit_share
+            .cast::<u128>()?
+            .safe_mul(self.split.cast()?)?
+            .safe_div(PERCENTAGE_PRECISION)?
+            .cast::<u64>()?;
+
+        self.owed = self.owed.safe_add(accrued)?;
+        self.last_manager_total_profit_share = vault.manager_total_profit_share;
+
+        Ok(self.owed)
+    }
+
+    /// Burns manager shares worth what is owed, capped at the shares not already requested for withdraw by the
+    /// manager, and returns the token amount to pay the recipient.
+    pub fn distribute(
+        &mut self,
+        vault: &mut Vault,
+        vault_protocol: &mut Option<RefMut<VaultProtocol>>,
+        vault_equity: u64,
+        now: i64,
+    ) -> Result<u64> {
+        let owed = self.accrue(vault)?;
+
+        let available_shares = vault
+            .get_manager_shares(vault_protocol)?
+            .saturating_sub(vault.last_manager_withdraw_request.shares);
+        let shares = vault_amount_to_depositor_shares(owed, vault.total_shares, vault_equity)?
+            .min(available_shares);
+        let amount =
+            depositor_shares_to_vault_amount(shares, vault.total_shares, vault_equity)?.min(owed);
+
+        if amount == 0 {
+            return Ok(0);
+        }
+
+        vault.total_shares = vault.total_shares.safe_sub(shares)?;
+        vault.total_withdraws = vault.total_withdraws.saturating_add(amount);
+        vault.manager_total_withdraws = vault.manager_total_withdraws.saturating_add(amount);
+        vault.net_deposits = vault.net_deposits.safe_sub(amount.cast()?)?;
+        vault.manager_net_deposits = vault.manager_net_deposits.safe_sub(amount.cast()?)?;
+
+        self.owed = owed.safe_sub(amount)?;
+        self.total_paid = self.total_paid.safe_add(amount)?;
+
+        emit!(FeeSplitRecord {
+            ts: now,
+            vault: vault.pubkey,
+            recipient: self.recipient,
+            amount,
+            shares,
+            owed: self.owed,
+            total_paid: self.total_paid,
+        });
+
+        Ok(amount)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use drift::math::constants::QUOTE_PRECISION_U64;
+
+    use super::*;
+
+    #[test]
+    fn test_fee_split_distribute() {
+        let mut vault = Vault {
+            total_shares: 1_000 * QUOTE_PRECISION_U64 as u128,
+            user_shares: 900 * QUOTE_PRECISION_U64 as u128,
+            manager_total_profit_share: 50 * QUOTE_PRECISION_U64,
+            ..Vault::default()
+        };
+        let vault_equity = 1_000 * QUOTE_PRECISION_U64;
+
+        let mut fee_split = VaultFeeSplit::default();
+        fee_split
+            .update(
+                &vault,
+                PERCENTAGE_PRECISION as u32 / 10,
+                Pubkey::new_unique(),
+            )
+            .unwrap();
+        // only profit share crystallized after the split was configured
+        assert_eq!(fee_split.owed, 0);
+
+        vault.manager_total_profit_share += 100 * QUOTE_PRECISION_U64;
+        let amount = fee_split
+            .distribute(&mut vault, &mut None, vault_equity, 0)
+            .unwrap();
+        assert_eq!(amount, 10 * QUOTE_PRECISION_U64);
+        assert_eq!(fee_split.owed, 0);
+        assert_eq!(fee_split.total_paid, amount);
+        assert_eq!(vault.total_shares, 990 * QUOTE_PRECISION_U64 as u128);
+
+        // capped at manager shares, the rest stays owed
+        vault.manager_total_profit_share += 1_000 * QUOTE_PRECISION_U64;
+        vault.last_manager_withdraw_request.shares = 85 * QUOTE_PRECISION_U64 as u128;
+        let amount = fee_split
+            .distribute(&mut vault, &mut None, vault_equity - amount, 0)
+            .unwrap();
+        assert_eq!(amount, 5 * QUOTE_PRECISION_U64);
+        assert_eq!(fee_split.owed, 95 * QUOTE_PRECISION_U64);
+    }
+}
@@ -0,0 +1,194 @@
+use anchor_lang::prelude::*;
+use drift::math::casting::Cast;
+use drift::math::constants::QUOTE_PRECISION_I128;
+use drift::math::safe_math::SafeMath;
+use drift::math::spot_balance::get_token_value;
+use drift_macros::assert_no_slop;
+use static_assertions::const_assert_eq;
+
+use crate::error::ErrorCode;
+use crate::state::Size;
+use crate::validate;
+
+pub const MAX_ORACLE_TWAP_DEVIATION_BPS: u16 = 10_000;
+
+/// Safeguards on depositor flows the manager opts into, enforced on every deposit and payout once the vault has one,
+/// see [`VaultConfigFlag::VaultGuard`](crate::state::VaultConfigFlag::VaultGuard), independently of the
+/// [`VaultDepositorThrottle`](crate::state::VaultDepositorThrottle). Deposits and withdraws are rejected while
+/// the deposit asset's oracle price is too far from its TWAP, so they can't be priced off an oracle spike, and must
+/// pass the companion accounts marked in its [`GuardFlag`]s. Deposits worth less than the USD minimum are rejected.
+#[assert_no_slop]
+#[account(zero_copy(unsafe))]
+#[derive(Default, Eq, PartialEq, Debug)]
+#[repr(C)]
+pub struct VaultGuard {
+    /// The vault the guard is enforced for
+    pub vault: Pubkey,
+    /// Max deviation of the deposit asset's oracle price from its TWAP for deposits and withdraws, in bps. 0 disables
+    /// the check
+    pub max_oracle_twap_deviation_bps: u16,
+    pub bump: u8,
+    /// Bits of [`GuardFlag`]
+    pub flags: u8,
+    /// Min deposit value in whole USD at the deposit oracle price, on top of the vault's `min_deposit_amount`. 0
+    /// disables it
+    pub min_deposit_usd: u32,
+    pub padding: [u8; 8],
+}
+
+/// Bits of [`VaultGuard`] `flags`, companion accounts every depositor flow must pass once the manager creates them
+#[derive(Clone, Copy, PartialEq, Debug, Eq)]
+pub enum GuardFlag {
+    /// Withdraws must pass the vault's [`VaultWithdrawBlackout`](crate::state::VaultWithdrawBlackout)
+    WithdrawBlackout = 0b00000001,
+    /// Deposits and withdraw requests must pass the vault's [`VaultLockup`](crate::state::VaultLockup) and the
+    /// depositor's [`VaultDepositorLockup`](crate::state::VaultDepositorLockup) address
+    Lockup = 0b00000010,
+    /// `apply_profit_share` cranks and `realize_depositor_profits` must pass the vault's
+    /// [`VaultProfitShareCadence`](crate::state::VaultProfitShareCadence) and the depositor's
+    /// [`VaultDepositorProfitShareCadence`](crate::state::VaultDepositorProfitShareCadence)
+    ProfitShareCadence = 0b00000100,
+}
+
+impl Size for VaultGuard {
+    const SIZE: usize = 48 + 8;
+}
+const_assert_eq!(VaultGuard::SIZE, std::mem::size_of::<VaultGuard>() + 8);
+
+impl VaultGuard {
+    pub fn get_address(vault: &Pubkey) -> Pubkey {
+        Pubkey::find_program_address(&[b"vault_guard", vault.as_ref()], &crate::id()).0
+    }
+
+    pub fn has_flag(&self, flag: GuardFlag) -> bool {
+        self.flags & (flag as u8) != 0
+    }
+
+    pub fn add_flag(&mut self, flag: GuardFlag) {
+        self.flags |= flag as u8;
+    }
+
+    pub fn update_max_oracle_twap_deviation(
+        &mut self,
+        max_oracle_twap_deviation_bps: u16,
+    ) -> Result<()> {
+        validate!(
+            max_oracle_twap_deviation_bps <= MAX_ORACLE_TWAP_DEVIATION_BPS,
+            ErrorCode::InvalidVaultGuard,
+            "max oracle twap deviation must be at most {} bps",
+            MAX_ORACLE_TWAP_DEVIATION_BPS
+        )?;
+
+        self.max_oracle_twap_deviation_bps = max_oracle_twap_deviation_bps;
+
+        Ok(())
+    }
+
+    /// `oracle_twap` is the spot market's `last_oracle_price_twap`, same precision as `oracle_price`
+    pub fn validate_oracle_price(&self, oracle_price: i64, oracle_twap: i64) -> Result<()> {
+        if self.max_oracle_twap_deviation_bps == 0 {
+            return Ok(());
+        }
+
+        validate!(
+            oracle_twap > 0,
+            ErrorCode::OracleTwapDeviationTooLarge,
+            "invalid oracle twap {}",
+            oracle_twap
+        )?;
+
+        let deviation_bps = oracle_price
+            .safe_sub(oracle_twap)?
+            .unsigned_abs()
+            .cast::<u128>()?
+            .safe_mul(10_000)?
+            .safe_div(oracle_twap.cast()?)?;
+        validate!(
+            deviation_bps <= self.max_oracle_twap_deviation_bps.cast()?,
+            ErrorCode::OracleTwapDeviationTooLarge,
+            "oracle price {} is {} bps from twap {}, max {}",
+            oracle_price,
+            deviation_bps,
+            oracle_twap,
+            self.max_oracle_twap_deviation_bps
+        )?;
+
+        Ok(())
+    }
+
+    pub fn validate_deposit_value(
+        &self,
+        amount: u64,
+        spot_market_decimals: u32,
+        oracle_price: i64,
+    ) -> Result<()> {
+        if self.min_deposit_usd == 0 {
+            return Ok(());
+        }
+
+        let deposit_value = get_token_value(amount.cast()?, spot_market_decimals, oracle_price)?;
+        let min_deposit_value = self
+            .min_deposit_usd
+            .cast::<i128>()?
+            .safe_mul(QUOTE_PRECISION_I128)?;
+        validate!(
+            deposit_value >= min_deposit_value,
+            ErrorCode::InvalidVaultDeposit,
+            "deposit value {} is below vault min deposit value {}",
+            deposit_value,
+            min_deposit_value
+        )?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use drift::math::constants::PRICE_PRECISION_I64;
+
+    use crate::error::ErrorCode;
+    use crate::state::{VaultGuard, MAX_ORACLE_TWAP_DEVIATION_BPS};
+
+    #[test]
+    fn test_oracle_twap_deviation() {
+        let mut guard = VaultGuard::default();
+        assert!(guard
+            .update_max_oracle_twap_deviation(MAX_ORACLE_TWAP_DEVIATION_BPS + 1)
+            .is_err());
+
+        // disabled by default
+        guard.validate_oracle_price(200, 100).unwrap();
+
+        guard.update_max_oracle_twap_deviation(250).unwrap();
+        guard.validate_oracle_price(102_500, 100_000).unwrap();
+        guard.validate_oracle_price(97_500, 100_000).unwrap();
+        assert_eq!(
+            guard.validate_oracle_price(102_600, 100_000),
+            Err(ErrorCode::OracleTwapDeviationTooLarge.into())
+        );
+        assert_eq!(
+            guard.validate_oracle_price(97_400, 100_000),
+            Err(ErrorCode::OracleTwapDeviationTooLarge.into())
+        );
+        assert!(guard.validate_oracle_price(100_000, 0).is_err());
+    }
+
+    #[test]
+    fn test_min_deposit_usd() {
+        let mut guard = VaultGuard::default();
+        let sol_price = 100 * PRICE_PRECISION_I64;
+
+        // disabled by default
+        guard.validate_deposit_value(1, 9, sol_price).unwrap();
+
+        guard.min_deposit_usd = 150;
+        assert_eq!(
+            guard.validate_deposit_value(1_000_000_000, 9, sol_price),
+            Err(ErrorCode::InvalidVaultDeposit.into())
+        );
+        guard
+            .validate_deposit_value(1_500_000_000, 9, sol_price)
+            .unwrap();
+    }
+}
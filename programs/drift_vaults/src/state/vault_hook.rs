@@ -0,0 +1,222 @@
+use anchor_lang::prelude::*;
+use borsh::{BorshDeserialize, BorshSerialize};
+use drift_macros::assert_no_slop;
+use static_assertions::const_assert_eq;
+
+use crate::error::ErrorCode;
+use crate::state::{Size, Vault};
+use crate::validate;
+
+#[assert_no_slop]
+#[account(zero_copy(unsafe))]
+#[derive(Default, Eq, PartialEq, Debug)]
+#[repr(C)]
+pub struct VaultHook {
+    /// The vault this hook is configured for
+    pub vault: Pubkey,
+    /// The program CPI'd before and after depositor deposits and withdrawals.
+    /// Pubkey::default() disables the hook.
+    pub hook_program: Pubkey,
+    pub bump: u8,
+    pub padding: [u8; 7],
+}
+
+impl Size for VaultHook {
+    const SIZE: usize = 72 + 8;
+}
+const_assert_eq!(VaultHook::SIZE, std::mem::size_of::<VaultHook>() + 8);
+
+impl VaultHook {
+    /// Instruction discriminator the hook program must handle, anchor sighash of `global:on_vault_action`
+    pub fn discriminator() -> [u8; 8] {
+        let mut discriminator = [0u8; 8];
+        discriminator.copy_from_slice(
+            &anchor_lang::solana_program::hash::hash(b"global:on_vault_action").to_bytes()[..8],
+        );
+        discriminator
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.hook_program != Pubkey::default()
+    }
+
+    /// Sets the hook program and keeps the vault's `vault_hook` flag in sync with it, so deposits only require the
+    /// hook while it's enabled. An enabled program must be passed in `accounts` and be executable.
+    pub fn update_hook_program(
+        &mut self,
+        vault: &mut Vault,
+        hook_program: Pubkey,
+        accounts: &[AccountInfo],
+    ) -> Result<()> {
+        validate!(
+            self.vault == vault.pubkey,
+            ErrorCode::InvalidVaultHook,
+            "VaultHook is not for this vault"
+        )?;
+        if hook_program != Pubkey::default() {
+            validate!(
+                accounts
+                    .iter()
+                    .any(|acct| acct.key == &hook_program && acct.executable),
+                ErrorCode::InvalidVaultHook,
+                "hook program {} must be an executable account in remaining accounts",
+                hook_program
+            )?;
+        }
+
+        self.hook_program = hook_program;
+        vault.vault_hook = self.is_enabled();
+
+        Ok(())
+    }
+
+    /// The hook program to cpi from `accounts`, `None` if the hook is disabled or its program wasn't passed.
+    pub fn find_hook_program<'info>(
+        &self,
+        vault: &Vault,
+        accounts: &[AccountInfo<'info>],
+    ) -> Result<Option<AccountInfo<'info>>> {
+        validate!(
+            self.vault == vault.pubkey,
+            ErrorCode::InvalidVaultHook,
+            "VaultHook is not for this vault"
+        )?;
+
+        if !self.is_enabled() {
+            return Ok(None);
+        }
+
+        Ok(accounts
+            .iter()
+            .find(|acct| acct.key == &self.hook_program && acct.executable)
+            .cloned())
+    }
+}
+
+/// Deposits can't skip an enabled hook, withdraw hooks are opt-in by the depositor so a hook can never lock funds.
+pub fn validate_deposit_hook(vault: &Vault, hook_program: Option<&AccountInfo>) -> Result<()> {
+    validate!(
+        !vault.vault_hook || hook_program.is_some(),
+        ErrorCode::VaultHookMissing,
+        "VaultHook and its program must be in remaining accounts"
+    )?;
+
+    Ok(())
+}
+
+#[derive(Clone, Copy, BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug)]
+pub enum VaultHookAction {
+    PreDeposit,
+    PostDeposit,
+    PreWithdraw,
+    PostWithdraw,
+}
+
+/// Instruction data passed to the hook program after the discriminator.
+#[derive(Clone, Copy, BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug)]
+pub struct VaultHookContext {
+    pub action: VaultHookAction,
+    pub vault: Pubkey,
+    pub depositor_authority: Pubkey,
+    /// token amount deposited or withdrawn
+    pub amount: u64,
+    /// the depositor's vault shares at the time of the hook
+    pub shares: u128,
+}
+
+#[cfg(test)]
+mod tests {
+    use anchor_lang::prelude::{AccountInfo, Pubkey};
+
+    use crate::state::{validate_deposit_hook, Vault, VaultHook};
+
+    #[test]
+    fn test_enable_disable_and_missing_hook() {
+        let mut vault = Vault {
+            pubkey: Pubkey::new_unique(),
+            ..Vault::default()
+        };
+        let mut vault_hook = VaultHook {
+            vault: vault.pubkey,
+            ..VaultHook::default()
+        };
+
+        let hook_program = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+        let (mut lamports, mut data) = (0, vec![]);
+        let program_info = AccountInfo::new(
+            &hook_program,
+            false,
+            false,
+            &mut lamports,
+            &mut data,
+            &owner,
+            true,
+            0,
+        );
+        let (mut lamports, mut data) = (0, vec![]);
+        let not_executable = AccountInfo::new(
+            &hook_program,
+            false,
+            false,
+            &mut lamports,
+            &mut data,
+            &owner,
+            false,
+            0,
+        );
+
+        // enable, the program must be passed and executable
+        assert!(vault_hook
+            .update_hook_program(&mut vault, hook_program, &[])
+            .is_err());
+        assert!(vault_hook
+            .update_hook_program(&mut vault, hook_program, &[not_executable.clone()])
+            .is_err());
+        assert!(!vault.vault_hook);
+        vault_hook
+            .update_hook_program(&mut vault, hook_program, &[program_info.clone()])
+            .unwrap();
+        assert!(vault.vault_hook);
+
+        let found = vault_hook
+            .find_hook_program(&vault, &[not_executable.clone(), program_info.clone()])
+            .unwrap();
+        assert_eq!(found.map(|acct| *acct.key), Some(hook_program));
+        validate_deposit_hook(&vault, Some(&program_info)).unwrap();
+
+        // missing, deposits fail while the hook is enabled
+        let found = vault_hook
+            .find_hook_program(&vault, &[not_executable.clone()])
+            .unwrap();
+        assert!(found.is_none());
+        assert!(validate_deposit_hook(&vault, None).is_err());
+
+        // another vault's hook
+        let other_vault = Vault {
+            pubkey: Pubkey::new_unique(),
+            vault_hook: true,
+            ..Vault::default()
+        };
+        assert!(vault_hook
+            .find_hook_program(&other_vault, &[program_info.clone()])
+            .is_err());
+
+        // disable, deposits go through without the hook
+        vault_hook
+            .update_hook_program(&mut vault, Pubkey::default(), &[])
+            .unwrap();
+        assert!(!vault.vault_hook);
+        let found = vault_hook
+            .find_hook_program(&vault, &[program_info.clone()])
+            .unwrap();
+        assert!(found.is_none());
+        validate_deposit_hook(&vault, None).unwrap();
+
+        // and re-enable
+        vault_hook
+            .update_hook_program(&mut vault, hook_program, &[program_info])
+            .unwrap();
+        assert!(vault.vault_hook);
+    }
+}
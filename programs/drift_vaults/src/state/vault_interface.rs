@@ -0,0 +1,151 @@
+use anchor_lang::prelude::*;
+use drift::math::casting::Cast;
+use drift::math::constants::PERCENTAGE_PRECISION;
+use drift::math::safe_math::SafeMath;
+use drift_macros::assert_no_slop;
+use static_assertions::const_assert_eq;
+
+use crate::error::ErrorCode;
+use crate::events::{InterfaceRecord, VaultDepositorAction};
+use crate::state::{Size, Vault};
+use crate::validate;
+
+/// Max fee a manager can pay an interface on attributed volume, 1%
+pub const MAX_INTERFACE_FEE_RATE: u32 = 10_000;
+
+#[assert_no_slop]
+#[account(zero_copy(unsafe))]
+#[derive(Default, Eq, PartialEq, Debug)]
+#[repr(C)]
+pub struct VaultInterface {
+    /// The vault the interface routes deposits and withdraws to
+    pub vault: Pubkey,
+    /// The front-end operator's key, passed as `interface` on deposits and withdraws
+    pub interface: Pubkey,
+    /// lifetime deposits attributed to the interface
+    pub attributed_deposits: u64,
+    /// lifetime withdraws attributed to the interface
+    pub attributed_withdraws: u64,
+    /// fees owed to the interface, paid out of the manager's shares on claim
+    pub accrued_fee: u64,
+    /// lifetime fees paid to the interface
+    pub total_fee_paid: u64,
+    /// fee the manager pays on attributed volume, precision: PERCENTAGE_PRECISION
+    pub fee_rate: u32,
+    pub bump: u8,
+    pub padding: [u8; 3],
+}
+
+impl Size for VaultInterface {
+    const SIZE: usize = 104 + 8;
+}
+const_assert_eq!(
+    VaultInterface::SIZE,
+    std::mem::size_of::<VaultInterface>() + 8
+);
+
+impl VaultInterface {
+    pub fn set_fee_rate(&mut self, fee_rate: u32) -> Result<()> {
+        validate!(
+            fee_rate <= MAX_INTERFACE_FEE_RATE,
+            ErrorCode::InvalidVaultInterface,
+            "interface fee rate {} above max {}",
+            fee_rate,
+            MAX_INTERFACE_FEE_RATE
+        )?;
+        self.fee_rate = fee_rate;
+        Ok(())
+    }
+
+    /// Attributes `amount` of volume to the interface, returns the fee accrued on it.
+    pub fn record_volume(&mut self, action: VaultDepositorAction, amount: u64) -> Result<u64> {
+        match action {
+            VaultDepositorAction::Deposit => {
+                self.attributed_deposits = self.attributed_deposits.saturating_add(amount);
+            }
+            VaultDepositorAction::Withdraw => {
+                self.attributed_withdraws = self.attributed_withdraws.saturating_add(amount);
+            }
+            _ => return Err(ErrorCode::InvalidVaultInterface.into()),
+        }
+
+        let fee: u64 = amount
+            .cast::<u128>()?
+            .safe_mul(self.fee_rate.cast()?)?
+            .safe_div(PERCENTAGE_PRECISION)?
+            .cast()?;
+        self.accrued_fee = self.accrued_fee.safe_add(fee)?;
+
+        Ok(fee)
+    }
+
+    pub fn claim(&mut self, fee_paid: u64) -> Result<()> {
+        self.accrued_fee = self.accrued_fee.safe_sub(fee_paid)?;
+        self.total_fee_paid = self.total_fee_paid.saturating_add(fee_paid);
+        Ok(())
+    }
+}
+
+/// Emits the attribution of a depositor's deposit or withdraw to `interface`, accruing its fee if the manager
+/// registered a [`VaultInterface`] for it.
+pub fn record_interface_attribution(
+    vault: &Vault,
+    depositor_authority: Pubkey,
+    interface: Pubkey,
+    vault_interface: Option<AccountLoader<VaultInterface>>,
+    action: VaultDepositorAction,
+    amount: u64,
+    now: i64,
+) -> Result<()> {
+    let interface_fee = match vault_interface {
+        Some(vault_interface) => vault_interface.load_mut()?.record_volume(action, amount)?,
+        None => 0,
+    };
+
+    emit!(InterfaceRecord {
+        ts: now,
+        vault: vault.pubkey,
+        depositor_authority,
+        interface,
+        action,
+        amount,
+        spot_market_index: vault.spot_market_index,
+        interface_fee,
+    });
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod vault_interface_tests {
+    use super::*;
+
+    #[test]
+    fn test_record_volume_and_claim() {
+        let mut vault_interface = VaultInterface::default();
+        assert!(vault_interface
+            .set_fee_rate(MAX_INTERFACE_FEE_RATE + 1)
+            .is_err());
+        vault_interface.set_fee_rate(1_000).unwrap(); // 10 bps
+
+        let fee = vault_interface
+            .record_volume(VaultDepositorAction::Deposit, 1_000_000)
+            .unwrap();
+        assert_eq!(fee, 1_000);
+        vault_interface
+            .record_volume(VaultDepositorAction::Withdraw, 500_000)
+            .unwrap();
+        assert_eq!(vault_interface.attributed_deposits, 1_000_000);
+        assert_eq!(vault_interface.attributed_withdraws, 500_000);
+        assert_eq!(vault_interface.accrued_fee, 1_500);
+
+        assert!(vault_interface
+            .record_volume(VaultDepositorAction::FeePayment, 1)
+            .is_err());
+
+        vault_interface.claim(1_000).unwrap();
+        assert_eq!(vault_interface.accrued_fee, 500);
+        assert_eq!(vault_interface.total_fee_paid, 1_000);
+        assert!(vault_interface.claim(501).is_err());
+    }
+}
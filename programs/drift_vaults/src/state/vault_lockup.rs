@@ -0,0 +1,131 @@
+use anchor_lang::prelude::*;
+use drift::math::safe_math::SafeMath;
+use drift_macros::assert_no_slop;
+use static_assertions::const_assert_eq;
+
+use crate::constants::ONE_DAY;
+use crate::error::ErrorCode;
+use crate::state::Size;
+use crate::validate;
+
+/// Longest lockup a manager can set
+pub const MAX_LOCKUP_PERIOD: u32 = 365 * ONE_DAY as u32;
+
+/// Min time from a depositor's last deposit to its next withdraw request, for strategies with a longer horizon than
+/// the redeem period, which only starts at the request. Depositors track their last deposit in a
+/// [`VaultDepositorLockup`]. Enforced through the vault's [`VaultGuard`](crate::state::VaultGuard), regardless of the
+/// [`VaultDepositorThrottle`](crate::state::VaultDepositorThrottle).
+#[assert_no_slop]
+#[account(zero_copy(unsafe))]
+#[derive(Default, Eq, PartialEq, Debug)]
+#[repr(C)]
+pub struct VaultLockup {
+    /// The vault the lockup is enforced for
+    pub vault: Pubkey,
+    /// Min seconds from a depositor's last deposit to its next withdraw request
+    pub lockup_period: u32,
+    pub bump: u8,
+    pub padding: [u8; 3],
+}
+
+impl Size for VaultLockup {
+    const SIZE: usize = 40 + 8;
+}
+const_assert_eq!(VaultLockup::SIZE, std::mem::size_of::<VaultLockup>() + 8);
+
+/// A depositor's last deposit into a vault with a [`VaultLockup`]. Deposits into such a vault require it, deposits
+/// made before the depositor created it aren't locked up, nor are depositors that never created one.
+#[assert_no_slop]
+#[account(zero_copy(unsafe))]
+#[derive(Default, Eq, PartialEq, Debug)]
+#[repr(C)]
+pub struct VaultDepositorLockup {
+    /// The vault depositor whose deposits are tracked
+    pub vault_depositor: Pubkey,
+    pub last_deposit_ts: i64,
+    pub bump: u8,
+    pub padding: [u8; 7],
+}
+
+impl Size for VaultDepositorLockup {
+    const SIZE: usize = 48 + 8;
+}
+const_assert_eq!(
+    VaultDepositorLockup::SIZE,
+    std::mem::size_of::<VaultDepositorLockup>() + 8
+);
+
+impl VaultLockup {
+    pub fn get_address(vault: &Pubkey) -> Pubkey {
+        Pubkey::find_program_address(&[b"vault_lockup", vault.as_ref()], &crate::id()).0
+    }
+
+    pub fn update_lockup_period(&mut self, lockup_period: u32) -> Result<()> {
+        validate!(
+            lockup_period <= MAX_LOCKUP_PERIOD,
+            ErrorCode::InvalidVaultLockup,
+            "lockup period must be at most {}",
+            MAX_LOCKUP_PERIOD
+        )?;
+
+        self.lockup_period = lockup_period;
+
+        Ok(())
+    }
+
+    pub fn validate_request(
+        &self,
+        vault_depositor_lockup: &VaultDepositorLockup,
+        now: i64,
+    ) -> Result<()> {
+        let unlock_ts = vault_depositor_lockup
+            .last_deposit_ts
+            .safe_add(self.lockup_period as i64)?;
+        validate!(
+            now >= unlock_ts,
+            ErrorCode::VaultDepositorLockedUp,
+            "depositor can request a withdraw at {}",
+            unlock_ts
+        )?;
+
+        Ok(())
+    }
+}
+
+impl VaultDepositorLockup {
+    pub fn get_address(vault_depositor: &Pubkey) -> Pubkey {
+        Pubkey::find_program_address(
+            &[b"vault_depositor_lockup", vault_depositor.as_ref()],
+            &crate::id(),
+        )
+        .0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::constants::ONE_DAY;
+    use crate::error::ErrorCode;
+    use crate::state::{VaultDepositorLockup, VaultLockup, MAX_LOCKUP_PERIOD};
+
+    #[test]
+    fn test_lockup() {
+        let mut lockup = VaultLockup::default();
+        assert!(lockup.update_lockup_period(MAX_LOCKUP_PERIOD + 1).is_err());
+        lockup.update_lockup_period(30 * ONE_DAY as u32).unwrap();
+
+        // never deposited since tracking started
+        let mut depositor_lockup = VaultDepositorLockup::default();
+        lockup.validate_request(&depositor_lockup, 0).unwrap();
+
+        let now = 100 * ONE_DAY;
+        depositor_lockup.last_deposit_ts = now;
+        assert_eq!(
+            lockup.validate_request(&depositor_lockup, now + 30 * ONE_DAY - 1),
+            Err(ErrorCode::VaultDepositorLockedUp.into())
+        );
+        lockup
+            .validate_request(&depositor_lockup, now + 30 * ONE_DAY)
+            .unwrap();
+    }
+}
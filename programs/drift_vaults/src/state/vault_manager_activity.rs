@@ -0,0 +1,140 @@
+use anchor_lang::prelude::*;
+use drift::math::casting::Cast;
+use drift::math::safe_math::SafeMath;
+use drift::state::user::UserStats;
+use drift_macros::assert_no_slop;
+use static_assertions::const_assert_eq;
+
+use crate::constants::ONE_DAY;
+use crate::error::ErrorCode;
+use crate::state::Size;
+use crate::validate;
+
+pub const MIN_MANAGER_INACTIVITY_PERIOD: u32 = (ONE_DAY * 7) as u32;
+pub const MAX_MANAGER_INACTIVITY_PERIOD: u32 = (ONE_DAY * 365) as u32;
+
+/// Tracks when the manager was last active so depositors can exit with `inactive_manager_withdraw`, skipping the
+/// redeem period, once the manager has gone quiet for `inactivity_period`. `manager_heartbeat` and manager signed
+/// instructions passing it in remaining_accounts refresh it, and fills by the vault's delegate count as activity
+/// through the drift user stats.
+#[assert_no_slop]
+#[account(zero_copy(unsafe))]
+#[derive(Default, Eq, PartialEq, Debug)]
+#[repr(C)]
+pub struct VaultManagerActivity {
+    /// The vault the manager activity is tracked for
+    pub vault: Pubkey,
+    /// ts of the last manager signed action or heartbeat
+    pub last_manager_activity_ts: i64,
+    /// Seconds without manager activity or delegate fills before depositors can exit early
+    pub inactivity_period: u32,
+    pub bump: u8,
+    pub padding: [u8; 3],
+}
+
+impl Size for VaultManagerActivity {
+    const SIZE: usize = 48 + 8;
+}
+const_assert_eq!(
+    VaultManagerActivity::SIZE,
+    std::mem::size_of::<VaultManagerActivity>() + 8
+);
+
+impl VaultManagerActivity {
+    pub fn get_address(vault: &Pubkey) -> Pubkey {
+        Pubkey::find_program_address(&[b"vault_manager_activity", vault.as_ref()], &crate::id()).0
+    }
+
+    /// Only lowers the period once set, depositors can count on the exit not moving further out
+    pub fn update_inactivity_period(&mut self, inactivity_period: u32) -> Result<()> {
+        validate!(
+            (MIN_MANAGER_INACTIVITY_PERIOD..=MAX_MANAGER_INACTIVITY_PERIOD)
+                .contains(&inactivity_period),
+            ErrorCode::InvalidVaultManagerActivity,
+            "inactivity period must be between {} and {}",
+            MIN_MANAGER_INACTIVITY_PERIOD,
+            MAX_MANAGER_INACTIVITY_PERIOD
+        )?;
+        validate!(
+            self.inactivity_period == 0 || inactivity_period < self.inactivity_period,
+            ErrorCode::InvalidVaultManagerActivity,
+            "new inactivity period must be lower"
+        )?;
+
+        self.inactivity_period = inactivity_period;
+
+        Ok(())
+    }
+
+    pub fn record_activity(&mut self, now: i64) {
+        self.last_manager_activity_ts = self.last_manager_activity_ts.max(now);
+    }
+
+    /// Latest of the last manager action and the delegate's last maker or taker fill
+    pub fn last_activity_ts(&self, user_stats: &UserStats) -> i64 {
+        self.last_manager_activity_ts
+            .max(user_stats.last_taker_volume_30d_ts)
+            .max(user_stats.last_maker_volume_30d_ts)
+    }
+
+    pub fn validate_inactive(&self, user_stats: &UserStats, now: i64) -> Result<()> {
+        let inactive_ts = self
+            .last_activity_ts(user_stats)
+            .safe_add(self.inactivity_period.cast()?)?;
+        validate!(
+            now >= inactive_ts,
+            ErrorCode::InvalidVaultManagerActivity,
+            "manager is active, inactive from {}",
+            inactive_ts
+        )?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use drift::state::user::UserStats;
+
+    use crate::constants::ONE_DAY;
+    use crate::state::{VaultManagerActivity, MIN_MANAGER_INACTIVITY_PERIOD};
+
+    #[test]
+    fn test_manager_inactivity() {
+        let mut activity = VaultManagerActivity::default();
+        assert!(activity.update_inactivity_period(0).is_err());
+        activity
+            .update_inactivity_period(MIN_MANAGER_INACTIVITY_PERIOD * 2)
+            .unwrap();
+        // can only be lowered
+        assert!(activity
+            .update_inactivity_period(MIN_MANAGER_INACTIVITY_PERIOD * 3)
+            .is_err());
+        activity
+            .update_inactivity_period(MIN_MANAGER_INACTIVITY_PERIOD)
+            .unwrap();
+
+        let period = MIN_MANAGER_INACTIVITY_PERIOD as i64;
+        let mut user_stats = UserStats::default();
+        activity.record_activity(ONE_DAY);
+        assert!(activity
+            .validate_inactive(&user_stats, ONE_DAY + period - 1)
+            .is_err());
+        activity
+            .validate_inactive(&user_stats, ONE_DAY + period)
+            .unwrap();
+
+        // a delegate fill counts as activity
+        user_stats.last_taker_volume_30d_ts = 2 * ONE_DAY;
+        assert!(activity
+            .validate_inactive(&user_stats, ONE_DAY + period)
+            .is_err());
+        activity
+            .validate_inactive(&user_stats, 2 * ONE_DAY + period)
+            .unwrap();
+
+        // activity never moves back
+        activity.record_activity(0);
+        assert_eq!(activity.last_manager_activity_ts, ONE_DAY);
+    }
+}
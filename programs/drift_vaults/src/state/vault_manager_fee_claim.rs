@@ -0,0 +1,223 @@
+use anchor_lang::prelude::*;
+use drift::math::casting::Cast;
+use drift::math::constants::{ONE_YEAR, PERCENTAGE_PRECISION, PERCENTAGE_PRECISION_I128};
+use drift::math::insurance::if_shares_to_vault_amount as depositor_shares_to_vault_amount;
+use drift::math::safe_math::SafeMath;
+use drift_macros::assert_no_slop;
+use static_assertions::const_assert_eq;
+
+use crate::constants::ONE_DAY;
+use crate::error::ErrorCode;
+use crate::state::{Size, Vault, VaultFlag};
+use crate::validate;
+
+/// Period over which at most `claim_cap` of the vault's shares can be minted as management fee
+pub const MANAGER_FEE_CLAIM_PERIOD: i64 = 90 * ONE_DAY;
+
+/// Defers the vault's management fee into a claimable share balance so it's minted in bounded steps instead of
+/// all at once.
+///
+/// Once initialized the vault's `management_fee` is moved here and zeroed on the [`Vault`], so `Vault::apply_fee`
+/// stops minting it. `claim_vault_manager_fee` accrues the fee since the last claim as shares, at the same rate
+/// `Vault::apply_fee` would have minted them, and mints at most `claim_cap` of the vault's total shares per
+/// [`MANAGER_FEE_CLAIM_PERIOD`]. Anything over the cap rolls forward to the next period.
+#[assert_no_slop]
+#[account(zero_copy(unsafe))]
+#[derive(Default, Eq, PartialEq, Debug)]
+#[repr(C)]
+pub struct VaultManagerFeeClaim {
+    /// The vault the fee claim is for
+    pub vault: Pubkey,
+    /// Management fee shares accrued and not yet minted
+    pub accrued_shares: u128,
+    /// Shares minted in the current claim period
+    pub period_claimed_shares: u128,
+    /// When the current claim period started
+    pub period_start_ts: i64,
+    /// When the management fee was last accrued
+    pub last_accrue_ts: i64,
+    /// The annual management fee moved off the vault: PERCENTAGE_PRECISION
+    pub management_fee: i64,
+    /// Most of the vault's total shares that can be minted per claim period: PERCENTAGE_PRECISION
+    pub claim_cap: u32,
+    /// The vault `shares_base` the share balances are denominated in
+    pub shares_base: u32,
+    pub bump: u8,
+    pub padding: [u8; 15],
+}
+
+impl Size for VaultManagerFeeClaim {
+    const SIZE: usize = 112 + 8;
+}
+const_assert_eq!(
+    VaultManagerFeeClaim::SIZE,
+    std::mem::size_of::<VaultManagerFeeClaim>() + 8
+);
+
+impl VaultManagerFeeClaim {
+    pub fn validate_claim_cap(claim_cap: u32) -> Result<()> {
+        validate!(
+            claim_cap > 0 && claim_cap.cast::<u128>()? <= PERCENTAGE_PRECISION,
+            ErrorCode::InvalidVaultManagerFeeClaim,
+            "claim cap {} must be in (0, {}]",
+            claim_cap,
+            PERCENTAGE_PRECISION
+        )?;
+        Ok(())
+    }
+
+    /// Takes over the vault's management fee. Fees up to `now` must already be applied to the vault.
+    pub fn initialize(
+        &mut self,
+        vault: &mut Vault,
+        claim_cap: u32,
+        bump: u8,
+        now: i64,
+    ) -> Result<()> {
+        Self::validate_claim_cap(claim_cap)?;
+
+        self.vault = vault.pubkey;
+        self.management_fee = vault.management_fee;
+        self.claim_cap = claim_cap;
+        self.shares_base = vault.shares_base;
+        self.period_start_ts = now;
+        self.last_accrue_ts = now;
+        self.bump = bump;
+
+        vault.management_fee = 0;
+
+        Ok(())
+    }
+
+    fn rebase(&mut self, vault: &Vault) -> Result<()> {
+        if vault.shares_base != self.shares_base {
+            validate!(
+                vault.shares_base > self.shares_base,
+                ErrorCode::InvalidVaultRebase,
+                "Rebase expo out of bounds"
+            )?;
+
+            let rebase_divisor = 10_u128.pow(vault.shares_base.safe_sub(self.shares_base)?);
+            self.accrued_shares = self.accrued_shares.safe_div(rebase_divisor)?;
+            self.period_claimed_shares = self.period_claimed_shares.safe_div(rebase_divisor)?;
+            self.shares_base = vault.shares_base;
+        }
+
+        Ok(())
+    }
+
+    /// Accrues the management fee since the last accrual as shares, as if the accrued shares were already minted.
+    /// Returns the shares accrued.
+    pub fn accrue(&mut self, vault: &mut Vault, vault_equity: u64, now: i64) -> Result<u128> {
+        self.rebase(vault)?;
+
+        let total_shares = vault.total_shares.safe_add(self.accrued_shares)?;
+        let depositor_equity =
+            depositor_shares_to_vault_amount(vault.user_shares, total_shares, vault_equity)?
+                .cast::<i128>()?;
+
+        if self.management_fee == 0
+            || vault.has_flag(VaultFlag::ManagementFeeHoliday)
+            || depositor_equity == 0
+        {
+            self.last_accrue_ts = now;
+            return Ok(0);
+        }
+
+        let since_last = now.safe_sub(self.last_accrue_ts)?;
+        let management_fee_payment = depositor_equity
+            .safe_mul(self.management_fee.cast()?)?
+            .safe_div(PERCENTAGE_PRECISION_I128)?
+            .safe_mul(since_last.cast()?)?
+            .safe_div(ONE_YEAR.cast()?)?
+            .min(depositor_equity.saturating_sub(1));
+
+        let new_total_shares = total_shares
+            .safe_mul(depositor_equity.cast()?)?
+            .safe_div(depositor_equity.safe_sub(management_fee_payment)?.cast()?)?;
+        let accrued_shares = new_total_shares.safe_sub(total_shares)?;
+
+        // leave the ts so a fee too small to be worth a share keeps accruing
+        if accrued_shares == 0 {
+            return Ok(0);
+        }
+
+        self.accrued_shares = self.accrued_shares.safe_add(accrued_shares)?;
+        self.last_accrue_ts = now;
+        vault.manager_total_fee = vault
+            .manager_total_fee
+            .saturating_add(management_fee_payment.cast()?);
+
+        Ok(accrued_shares)
+    }
+
+    /// Mints accrued shares up to what's left of the period's cap. Returns the shares minted.
+    pub fn claim(&mut self, vault: &mut Vault, now: i64) -> Result<u128> {
+        self.rebase(vault)?;
+
+        if now >= self.period_start_ts.safe_add(MANAGER_FEE_CLAIM_PERIOD)? {
+            self.period_start_ts = now;
+            self.period_claimed_shares = 0;
+        }
+
+        let period_cap = vault
+            .total_shares
+            .safe_mul(self.claim_cap.cast()?)?
+            .safe_div(PERCENTAGE_PRECISION)?;
+        let claimed_shares = self
+            .accrued_shares
+            .min(period_cap.saturating_sub(self.period_claimed_shares));
+
+        vault.total_shares = vault.total_shares.safe_add(claimed_shares)?;
+        self.accrued_shares = self.accrued_shares.safe_sub(claimed_shares)?;
+        self.period_claimed_shares = self.period_claimed_shares.safe_add(claimed_shares)?;
+
+        Ok(claimed_shares)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anchor_lang::prelude::Pubkey;
+    use drift::math::constants::{ONE_YEAR, QUOTE_PRECISION_U64};
+
+    use crate::state::{Vault, VaultManagerFeeClaim, MANAGER_FEE_CLAIM_PERIOD};
+
+    #[test]
+    fn test_accrue_and_claim() {
+        let now = 1000;
+        let mut vault = Vault {
+            pubkey: Pubkey::new_unique(),
+            management_fee: 20_000, // 2%
+            total_shares: 100 * QUOTE_PRECISION_U64 as u128,
+            user_shares: 100 * QUOTE_PRECISION_U64 as u128,
+            ..Vault::default()
+        };
+        let vault_equity = 100 * QUOTE_PRECISION_U64;
+
+        let mut fee_claim = VaultManagerFeeClaim::default();
+        fee_claim.initialize(&mut vault, 5_000, 255, now).unwrap(); // 0.5% per period
+        assert_eq!(vault.management_fee, 0);
+        assert_eq!(fee_claim.management_fee, 20_000);
+
+        // a year of 2% fee accrues as shares without minting any
+        let accrued = fee_claim
+            .accrue(&mut vault, vault_equity, now + ONE_YEAR as i64)
+            .unwrap();
+        assert_eq!(accrued, 2_040_816);
+        assert_eq!(vault.total_shares, 100_000_000);
+        assert_eq!(vault.manager_total_fee, 2_000_000);
+
+        // only 0.5% of total shares is minted, the rest rolls forward
+        let now = now + ONE_YEAR as i64;
+        assert_eq!(fee_claim.claim(&mut vault, now).unwrap(), 500_000);
+        assert_eq!(fee_claim.claim(&mut vault, now).unwrap(), 0);
+        assert_eq!(fee_claim.accrued_shares, 1_540_816);
+        assert_eq!(vault.total_shares, 100_500_000);
+
+        let now = now + MANAGER_FEE_CLAIM_PERIOD;
+        assert_eq!(fee_claim.claim(&mut vault, now).unwrap(), 502_500);
+        assert_eq!(fee_claim.accrued_shares, 1_038_316);
+        assert_eq!(vault.total_shares, 101_002_500);
+    }
+}
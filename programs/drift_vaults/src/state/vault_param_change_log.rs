@@ -0,0 +1,224 @@
+use anchor_lang::prelude::*;
+use borsh::{BorshDeserialize, BorshSerialize};
+use bytemuck::Zeroable;
+use drift::math::casting::Cast;
+use drift::math::safe_math::SafeMath;
+use drift_macros::assert_no_slop;
+use static_assertions::const_assert_eq;
+
+use crate::error::ErrorCode;
+use crate::state::events::VaultParamChangeRecord;
+use crate::state::{Size, Vault, VaultFlag};
+use crate::validate;
+
+/// Changes kept in a [`VaultParamChangeLog`]
+pub const VAULT_PARAM_CHANGE_LOG_ENTRIES: usize = 32;
+
+#[derive(Clone, Copy, BorshSerialize, BorshDeserialize, PartialEq, Debug, Eq, Default)]
+#[repr(u8)]
+pub enum VaultParam {
+    #[default]
+    RedeemPeriod = 0,
+    MaxTokens = 1,
+    ManagementFee = 2,
+    MinDepositAmount = 3,
+    ProfitShare = 4,
+    HurdleRate = 5,
+    Permissioned = 6,
+    Manager = 7,
+    Delegate = 8,
+    FuelDistributionMode = 9,
+    LargeWithdrawThreshold = 10,
+    LargeWithdrawNoticePeriod = 11,
+    Profile = 12,
+    DisabledFeatures = 13,
+    MarginTradingEnabled = 14,
+    PoolId = 15,
+    ProfitShareMode = 16,
+    RequireSettleCrank = 17,
+    FuelBoost = 18,
+    FeePolicy = 19,
+    DepositValuation = 20,
+    ProfitShareRealization = 21,
+}
+
+/// Little endian integers and bools or pubkey bytes, zero padded to 32 bytes
+pub trait ParamValue {
+    fn to_param_bytes(&self) -> [u8; 32];
+}
+
+macro_rules! impl_param_value {
+    ( $($t:ty),* ) => {
+        $(
+            impl ParamValue for $t {
+                fn to_param_bytes(&self) -> [u8; 32] {
+                    let le_bytes = self.to_le_bytes();
+                    let mut bytes = [0u8; 32];
+                    bytes[..le_bytes.len()].copy_from_slice(&le_bytes);
+                    bytes
+                }
+            }
+        )*
+    };
+}
+
+impl_param_value!(u8, u32, u64, i64);
+
+impl ParamValue for bool {
+    fn to_param_bytes(&self) -> [u8; 32] {
+        (*self as u8).to_param_bytes()
+    }
+}
+
+impl ParamValue for Pubkey {
+    fn to_param_bytes(&self) -> [u8; 32] {
+        self.to_bytes()
+    }
+}
+
+#[assert_no_slop]
+#[derive(
+    Default, AnchorSerialize, AnchorDeserialize, Copy, Clone, Eq, PartialEq, Debug, Zeroable,
+)]
+pub struct VaultParamChange {
+    /// Who signed the change
+    pub signer: Pubkey,
+    /// See [`ParamValue`]
+    pub old_value: [u8; 32],
+    /// See [`ParamValue`]
+    pub new_value: [u8; 32],
+    pub ts: i64,
+    /// [`VaultParam`]
+    pub param: u8,
+    pub padding: [u8; 7],
+}
+
+/// On-chain history of a vault's parameter updates, kept alongside the [`VaultParamChangeRecord`] events since RPC
+/// providers truncate old transaction logs. Holds the latest [`VAULT_PARAM_CHANGE_LOG_ENTRIES`] changes, change `n`
+/// is in entry `n % VAULT_PARAM_CHANGE_LOG_ENTRIES`.
+#[assert_no_slop]
+#[account(zero_copy(unsafe))]
+#[derive(Default, Eq, PartialEq, Debug)]
+#[repr(C)]
+pub struct VaultParamChangeLog {
+    /// The vault whose parameter changes are logged
+    pub vault: Pubkey,
+    /// Changes recorded since the log was created
+    pub count: u64,
+    pub entries: [VaultParamChange; VAULT_PARAM_CHANGE_LOG_ENTRIES],
+    pub bump: u8,
+    pub padding: [u8; 7],
+}
+
+impl Size for VaultParamChangeLog {
+    const SIZE: usize = 3632 + 8;
+}
+const_assert_eq!(
+    VaultParamChangeLog::SIZE,
+    std::mem::size_of::<VaultParamChangeLog>() + 8
+);
+
+impl VaultParamChangeLog {
+    pub fn push(&mut self, change: VaultParamChange) -> Result<()> {
+        let index = self
+            .count
+            .safe_rem(VAULT_PARAM_CHANGE_LOG_ENTRIES.cast()?)?
+            .cast::<usize>()?;
+        self.entries[index] = change;
+        self.count = self.count.safe_add(1)?;
+
+        Ok(())
+    }
+}
+
+impl Vault {
+    /// The [`VaultParamChangeLog`] must be provided once the vault has one.
+    pub fn validate_param_change_log(
+        &self,
+        log: &Option<AccountLoader<VaultParamChangeLog>>,
+    ) -> Result<()> {
+        match log {
+            Some(log) => validate!(
+                log.load()?.vault == self.pubkey,
+                ErrorCode::InvalidVaultParamChangeLog,
+                "VaultParamChangeLog is not for this vault"
+            )?,
+            None => validate!(
+                !self.has_flag(VaultFlag::HasParamChangeLog),
+                ErrorCode::InvalidVaultParamChangeLog,
+                "VaultParamChangeLog missing in remaining accounts"
+            )?,
+        }
+
+        Ok(())
+    }
+}
+
+/// Emits a [`VaultParamChangeRecord`] and appends the change to `log`, if the vault has one.
+pub fn record_vault_param_change(
+    vault: &Vault,
+    log: &Option<AccountLoader<VaultParamChangeLog>>,
+    signer: Pubkey,
+    param: VaultParam,
+    old_value: impl ParamValue,
+    new_value: impl ParamValue,
+    now: i64,
+) -> Result<()> {
+    let change = VaultParamChange {
+        signer,
+        old_value: old_value.to_param_bytes(),
+        new_value: new_value.to_param_bytes(),
+        ts: now,
+        param: param as u8,
+        padding: [0; 7],
+    };
+
+    emit!(VaultParamChangeRecord {
+        ts: now,
+        vault: vault.pubkey,
+        signer,
+        param,
+        old_value: change.old_value,
+        new_value: change.new_value,
+    });
+
+    if let Some(log) = log {
+        log.load_mut()?.push(change)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod vault_param_change_log_tests {
+    use super::*;
+
+    #[test]
+    fn test_param_change_log_wraps() {
+        let mut log = VaultParamChangeLog::default();
+
+        for i in 0..VAULT_PARAM_CHANGE_LOG_ENTRIES as i64 + 2 {
+            log.push(VaultParamChange {
+                ts: i,
+                ..VaultParamChange::default()
+            })
+            .unwrap();
+        }
+
+        assert_eq!(log.count, VAULT_PARAM_CHANGE_LOG_ENTRIES as u64 + 2);
+        assert_eq!(log.entries[0].ts, VAULT_PARAM_CHANGE_LOG_ENTRIES as i64);
+        assert_eq!(log.entries[1].ts, VAULT_PARAM_CHANGE_LOG_ENTRIES as i64 + 1);
+        assert_eq!(log.entries[2].ts, 2);
+    }
+
+    #[test]
+    fn test_param_value_bytes() {
+        let mut expected = [0u8; 32];
+        expected[..8].copy_from_slice(&(-5_i64).to_le_bytes());
+        assert_eq!((-5_i64).to_param_bytes(), expected);
+
+        let mut expected = [0u8; 32];
+        expected[0] = 1;
+        assert_eq!(true.to_param_bytes(), expected);
+    }
+}
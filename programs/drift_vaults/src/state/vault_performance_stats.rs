@@ -0,0 +1,403 @@
+use anchor_lang::prelude::*;
+use drift::math::casting::Cast;
+use drift::math::constants::PERCENTAGE_PRECISION;
+use drift::math::insurance::if_shares_to_vault_amount as depositor_shares_to_vault_amount;
+use drift::math::safe_math::SafeMath;
+use drift_macros::assert_no_slop;
+use static_assertions::const_assert_eq;
+
+use crate::constants::ONE_DAY;
+use crate::error::ErrorCode;
+use crate::events::VaultPerformanceRecord;
+use crate::state::{Size, Vault};
+use crate::validate;
+
+/// Minimum time between two recorded performance periods
+pub const PERFORMANCE_PERIOD: i64 = ONE_DAY * 7;
+
+#[assert_no_slop]
+#[account(zero_copy(unsafe))]
+#[derive(Default, Eq, PartialEq, Debug)]
+#[repr(C)]
+pub struct VaultPerformanceStats {
+    /// The vault these stats are for
+    pub vault: Pubkey,
+    /// Equity per share at the end of the last period, precision: PERCENTAGE_PRECISION
+    pub last_equity_per_share: u128,
+    /// ts the last period ended, 0 before the first crank
+    pub last_period_ts: i64,
+    /// Consecutive periods equity per share increased
+    pub current_streak: u32,
+    /// Longest run of consecutive periods equity per share increased
+    pub longest_streak: u32,
+    /// Periods recorded
+    pub total_periods: u32,
+    /// Periods equity per share increased
+    pub positive_periods: u32,
+    /// The vault's shares_base when last_equity_per_share was set
+    pub shares_base: u32,
+    pub bump: u8,
+    pub padding: [u8; 19],
+    /// The vault's spot market `cumulative_deposit_interest` at the end of the last period
+    pub last_cumulative_deposit_interest: u128,
+    /// Depositors' share of vault equity at the end of the last period
+    pub last_depositor_equity: u64,
+    /// The vault's `net_deposits` at the end of the last period
+    pub last_net_deposits: i64,
+    /// The vault's `manager_total_fee` plus `manager_total_profit_share` at the end of the last period
+    pub last_manager_fees: u64,
+    /// The drift user's signed token amount in the vault's spot market at the end of the last period
+    pub last_spot_token_amount: i64,
+    /// The vault's spot market `cumulative_borrow_interest` at the end of the last period
+    pub last_cumulative_borrow_interest: u128,
+    /// Vault equity at the end of the last period
+    pub last_vault_equity: u64,
+    /// The vault's `manager_net_deposits` at the end of the last period
+    pub last_manager_net_deposits: i64,
+    /// Lend interest earned, less borrow interest paid, on the vault's spot market balance over the last period
+    pub period_interest_pnl: i64,
+    /// Vault pnl over the last period that isn't [`Self::period_interest_pnl`]
+    pub period_trading_pnl: i64,
+    /// Sum of [`Self::period_interest_pnl`] over every attributed period
+    pub cumulative_interest_pnl: i64,
+    /// Sum of [`Self::period_trading_pnl`] over every attributed period
+    pub cumulative_trading_pnl: i64,
+    pub padding1: [u8; 16],
+}
+
+impl Size for VaultPerformanceStats {
+    const SIZE: usize = 224 + 8;
+}
+const_assert_eq!(
+    VaultPerformanceStats::SIZE,
+    std::mem::size_of::<VaultPerformanceStats>() + 8
+);
+
+impl VaultPerformanceStats {
+    /// Closes the current period if at least [`PERFORMANCE_PERIOD`] passed. `equity` is in the vault's spot market.
+    pub fn record_period(
+        &mut self,
+        now: i64,
+        equity: u64,
+        total_shares: u128,
+        shares_base: u32,
+    ) -> Result<()> {
+        validate!(
+            self.last_period_ts == 0 || now.safe_sub(self.last_period_ts)? >= PERFORMANCE_PERIOD,
+            ErrorCode::InvalidPerformancePeriod,
+            "performance period ends at {}",
+            self.last_period_ts.safe_add(PERFORMANCE_PERIOD)?
+        )?;
+
+        let equity_per_share = if total_shares > 0 {
+            (equity as u128)
+                .safe_mul(PERCENTAGE_PRECISION)?
+                .safe_div(total_shares)?
+        } else {
+            0
+        };
+
+        // the first crank and a rebase only set the baseline, share prices across a rebase aren't comparable
+        if self.last_period_ts != 0 && shares_base == self.shares_base {
+            self.total_periods = self.total_periods.safe_add(1)?;
+            if equity_per_share > self.last_equity_per_share {
+                self.positive_periods = self.positive_periods.safe_add(1)?;
+                self.current_streak = self.current_streak.safe_add(1)?;
+                self.longest_streak = self.longest_streak.max(self.current_streak);
+            } else {
+                self.current_streak = 0;
+            }
+        }
+
+        self.last_equity_per_share = equity_per_share;
+        self.shares_base = shares_base;
+        self.last_period_ts = now;
+
+        msg!(
+            "vault performance: streak {} (longest {}), {}/{} positive periods",
+            self.current_streak,
+            self.longest_streak,
+            self.positive_periods,
+            self.total_periods
+        );
+
+        Ok(())
+    }
+
+    /// Closes the current period like [`Self::record_period`] and emits a [`VaultPerformanceRecord`] for it, unless
+    /// it's the first period or the vault rebased.
+    ///
+    /// Returns are PERCENTAGE_PRECISION. `period_return` is the change in equity per share, so it's net of the
+    /// management fee but not profit share, `benchmark_return` is the lend interest on the vault's spot market and
+    /// `net_depositor_return` is the change in the depositors' share of equity less net deposits over the period.
+    ///
+    /// The vault's pnl over the period, the change in equity less depositor and manager net deposits, is split into
+    /// interest on `spot_token_amount` as of the start of the period and trading pnl, see [`Self::attribute_pnl`].
+    #[allow(clippy::too_many_arguments)]
+    pub fn close_period(
+        &mut self,
+        vault: &Vault,
+        vault_equity: u64,
+        cumulative_deposit_interest: u128,
+        cumulative_borrow_interest: u128,
+        spot_token_amount: i64,
+        now: i64,
+    ) -> Result<Option<VaultPerformanceRecord>> {
+        let period_start_ts = self.last_period_ts;
+        let last_equity_per_share = self.last_equity_per_share;
+        let comparable = period_start_ts != 0 && vault.shares_base == self.shares_base;
+
+        // unlike returns the pnl split doesn't depend on share prices, so it carries across rebases
+        if period_start_ts != 0 {
+            self.attribute_pnl(
+                vault,
+                vault_equity,
+                cumulative_deposit_interest,
+                cumulative_borrow_interest,
+            )?;
+        }
+
+        self.record_period(now, vault_equity, vault.total_shares, vault.shares_base)?;
+
+        let depositor_equity =
+            depositor_shares_to_vault_amount(vault.user_shares, vault.total_shares, vault_equity)?;
+        let manager_fees = vault
+            .manager_total_fee
+            .max(0)
+            .cast::<u64>()?
+            .saturating_add(vault.manager_total_profit_share);
+
+        let mut record = None;
+        if comparable {
+            let net_deposits = vault.net_deposits.safe_sub(self.last_net_deposits)?;
+            let depositor_pnl = depositor_equity
+                .cast::<i64>()?
+                .safe_sub(net_deposits)?
+                .safe_sub(self.last_depositor_equity.cast()?)?;
+
+            let performance_record = VaultPerformanceRecord {
+                ts: now,
+                vault: vault.pubkey,
+                period_start_ts,
+                equity_per_share: self.last_equity_per_share,
+                period_return: calculate_return(
+                    self.last_equity_per_share
+                        .cast::<i128>()?
+                        .safe_sub(last_equity_per_share.cast()?)?,
+                    last_equity_per_share,
+                )?,
+                benchmark_return: calculate_return(
+                    cumulative_deposit_interest
+                        .cast::<i128>()?
+                        .safe_sub(self.last_cumulative_deposit_interest.cast()?)?,
+                    self.last_cumulative_deposit_interest,
+                )?,
+                fees_charged: manager_fees.saturating_sub(self.last_manager_fees),
+                depositor_equity,
+                net_depositor_return: calculate_return(
+                    depositor_pnl.cast()?,
+                    self.last_depositor_equity.cast()?,
+                )?,
+                interest_pnl: self.period_interest_pnl,
+                trading_pnl: self.period_trading_pnl,
+            };
+            emit!(performance_record);
+            record = Some(performance_record);
+        }
+
+        self.last_cumulative_deposit_interest = cumulative_deposit_interest;
+        self.last_depositor_equity = depositor_equity;
+        self.last_net_deposits = vault.net_deposits;
+        self.last_manager_fees = manager_fees;
+        self.last_spot_token_amount = spot_token_amount;
+        self.last_cumulative_borrow_interest = cumulative_borrow_interest;
+        self.last_vault_equity = vault_equity;
+        self.last_manager_net_deposits = vault.manager_net_deposits;
+
+        Ok(record)
+    }
+
+    /// Splits the vault's pnl since the last period into the interest its spot market balance at the start of the
+    /// period earned, or paid if it was a borrow, and trading pnl. Flows during the period are credited to trading.
+    fn attribute_pnl(
+        &mut self,
+        vault: &Vault,
+        vault_equity: u64,
+        cumulative_deposit_interest: u128,
+        cumulative_borrow_interest: u128,
+    ) -> Result<()> {
+        let (cumulative_interest, last_cumulative_interest) = if self.last_spot_token_amount >= 0 {
+            (
+                cumulative_deposit_interest,
+                self.last_cumulative_deposit_interest,
+            )
+        } else {
+            (
+                cumulative_borrow_interest,
+                self.last_cumulative_borrow_interest,
+            )
+        };
+        let interest_pnl: i64 = self
+            .last_spot_token_amount
+            .cast::<i128>()?
+            .safe_mul(
+                cumulative_interest
+                    .cast::<i128>()?
+                    .safe_sub(last_cumulative_interest.cast()?)?,
+            )?
+            .safe_div(last_cumulative_interest.cast()?)?
+            .cast()?;
+
+        let net_deposits = vault
+            .net_deposits
+            .safe_sub(self.last_net_deposits)?
+            .safe_add(
+                vault
+                    .manager_net_deposits
+                    .safe_sub(self.last_manager_net_deposits)?,
+            )?;
+        let vault_pnl = vault_equity
+            .cast::<i64>()?
+            .safe_sub(self.last_vault_equity.cast()?)?
+            .safe_sub(net_deposits)?;
+        let trading_pnl = vault_pnl.safe_sub(interest_pnl)?;
+
+        self.period_interest_pnl = interest_pnl;
+        self.period_trading_pnl = trading_pnl;
+        self.cumulative_interest_pnl = self.cumulative_interest_pnl.safe_add(interest_pnl)?;
+        self.cumulative_trading_pnl = self.cumulative_trading_pnl.safe_add(trading_pnl)?;
+
+        msg!(
+            "vault pnl {}: interest {} trading {}",
+            vault_pnl,
+            interest_pnl,
+            trading_pnl
+        );
+
+        Ok(())
+    }
+}
+
+/// `change` over `base`, PERCENTAGE_PRECISION. 0 if there's no base.
+fn calculate_return(change: i128, base: u128) -> Result<i64> {
+    if base == 0 {
+        return Ok(0);
+    }
+
+    change
+        .safe_mul(PERCENTAGE_PRECISION.cast()?)?
+        .safe_div(base.cast()?)?
+        .cast()
+}
+
+#[cfg(test)]
+mod vault_performance_stats_tests {
+    use super::*;
+
+    #[test]
+    fn test_record_period() {
+        let now = 1_000_000;
+        let mut stats = VaultPerformanceStats::default();
+
+        // baseline
+        stats.record_period(now, 1_000, 1_000, 0).unwrap();
+        assert_eq!(stats.total_periods, 0);
+        assert_eq!(stats.last_equity_per_share, PERCENTAGE_PRECISION);
+
+        // too early
+        assert!(stats.record_period(now + 1, 1_100, 1_000, 0).is_err());
+
+        let mut now = now + PERFORMANCE_PERIOD;
+        stats.record_period(now, 1_100, 1_000, 0).unwrap();
+        now += PERFORMANCE_PERIOD;
+        stats.record_period(now, 1_200, 1_000, 0).unwrap();
+        assert_eq!(stats.current_streak, 2);
+        assert_eq!(stats.longest_streak, 2);
+
+        // flat period breaks the streak
+        now += PERFORMANCE_PERIOD;
+        stats.record_period(now, 1_200, 1_000, 0).unwrap();
+        assert_eq!(stats.current_streak, 0);
+        assert_eq!(stats.longest_streak, 2);
+        assert_eq!(stats.positive_periods, 2);
+        assert_eq!(stats.total_periods, 3);
+
+        // a rebase isn't counted
+        now += PERFORMANCE_PERIOD;
+        stats.record_period(now, 1_300, 100, 1).unwrap();
+        assert_eq!(stats.total_periods, 3);
+        assert_eq!(stats.shares_base, 1);
+    }
+
+    #[test]
+    fn test_close_period() {
+        let now = 1_000_000;
+        let mut vault = Vault {
+            total_shares: 1_000,
+            user_shares: 900,
+            net_deposits: 900,
+            ..Vault::default()
+        };
+        let cumulative_deposit_interest = 10_000_000_000;
+
+        let cumulative_borrow_interest = 20_000_000_000;
+
+        let mut stats = VaultPerformanceStats::default();
+        assert!(stats
+            .close_period(
+                &vault,
+                1_000,
+                cumulative_deposit_interest,
+                cumulative_borrow_interest,
+                1_000,
+                now
+            )
+            .unwrap()
+            .is_none());
+        assert_eq!(stats.last_depositor_equity, 900);
+
+        // share price up 10%, 100 deposited at the starting share price, 9 shares of profit share moved to the
+        // manager and the spot market paid 1% lend interest
+        let now = now + PERFORMANCE_PERIOD;
+        vault.total_shares = 1_100;
+        vault.user_shares = 991;
+        vault.net_deposits = 1_000;
+        vault.manager_total_profit_share = 9;
+        let record = stats
+            .close_period(
+                &vault,
+                1_210,
+                10_100_000_000,
+                cumulative_borrow_interest,
+                -500,
+                now,
+            )
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(record.period_start_ts, now - PERFORMANCE_PERIOD);
+        assert_eq!(record.equity_per_share, 1_100_000);
+        assert_eq!(record.period_return, 100_000);
+        assert_eq!(record.benchmark_return, 10_000);
+        assert_eq!(record.fees_charged, 9);
+        assert_eq!(record.depositor_equity, 1_090);
+        assert_eq!(record.net_depositor_return, 100_000);
+        assert_eq!(stats.total_periods, 1);
+
+        // of the 110 pnl 10 is lend interest on the 1_000 deposited in the spot market
+        assert_eq!(record.interest_pnl, 10);
+        assert_eq!(record.trading_pnl, 100);
+
+        // a 2% borrow rate on the 500 borrowed since
+        let now = now + PERFORMANCE_PERIOD;
+        let record = stats
+            .close_period(&vault, 1_300, 10_200_000_000, 20_400_000_000, -500, now)
+            .unwrap()
+            .unwrap();
+        assert_eq!(record.interest_pnl, -10);
+        assert_eq!(record.trading_pnl, 100);
+        assert_eq!(stats.period_trading_pnl, 100);
+        assert_eq!(stats.cumulative_interest_pnl, 0);
+        assert_eq!(stats.cumulative_trading_pnl, 200);
+    }
+}
@@ -0,0 +1,255 @@
+use anchor_lang::prelude::*;
+use borsh::{BorshDeserialize, BorshSerialize};
+use drift::math::constants::PERCENTAGE_PRECISION;
+use drift::math::safe_math::SafeMath;
+use drift_macros::assert_no_slop;
+use static_assertions::const_assert_eq;
+
+use crate::constants::ONE_DAY;
+use crate::error::{ErrorCode, VaultResult};
+use crate::state::Size;
+use crate::validate;
+
+pub const VAULT_POLL_DURATION: i64 = ONE_DAY * 7;
+/// Share of user shares that must vote for a poll before the protocol can execute it, precision: PERCENTAGE_PRECISION
+pub const VAULT_POLL_QUORUM: u128 = PERCENTAGE_PRECISION / 2;
+
+#[derive(Clone, Copy, BorshSerialize, BorshDeserialize, PartialEq, Debug, Eq)]
+#[repr(u8)]
+pub enum VaultPollProposal {
+    /// Replace the manager with [`VaultPoll::new_manager`]
+    ReplaceManager = 0,
+    /// Stop depositor deposits and put the drift user in reduce only
+    WindDown = 1,
+}
+
+impl TryFrom<u8> for VaultPollProposal {
+    type Error = ErrorCode;
+
+    fn try_from(value: u8) -> std::result::Result<Self, ErrorCode> {
+        match value {
+            0 => Ok(VaultPollProposal::ReplaceManager),
+            1 => Ok(VaultPollProposal::WindDown),
+            _ => Err(ErrorCode::InvalidVaultPoll),
+        }
+    }
+}
+
+#[assert_no_slop]
+#[account(zero_copy(unsafe))]
+#[derive(Default, Eq, PartialEq, Debug)]
+#[repr(C)]
+pub struct VaultPoll {
+    /// The vault being voted on
+    pub vault: Pubkey,
+    /// The manager proposed by a [`VaultPollProposal::ReplaceManager`] poll
+    pub new_manager: Pubkey,
+    /// Depositor shares voting for the proposal, snapshotted at each vote
+    pub votes_for: u128,
+    /// The vault's user shares when the poll started, quorum is measured against this
+    pub total_shares: u128,
+    pub start_ts: i64,
+    pub end_ts: i64,
+    /// Incremented every poll, 0 if no poll has been started
+    pub poll_id: u32,
+    /// The vault's shares_base when the poll started
+    pub shares_base: u32,
+    /// [`VaultPollProposal`]
+    pub proposal: u8,
+    pub executed: bool,
+    pub bump: u8,
+    pub padding: [u8; 5],
+}
+
+impl Size for VaultPoll {
+    const SIZE: usize = 128 + 8;
+}
+const_assert_eq!(VaultPoll::SIZE, std::mem::size_of::<VaultPoll>() + 8);
+
+/// A depositor's vote on a [`VaultPoll`], one per depositor per poll.
+#[assert_no_slop]
+#[account(zero_copy(unsafe))]
+#[derive(Default, Eq, PartialEq, Debug)]
+#[repr(C)]
+pub struct VaultPollVote {
+    pub vault_poll: Pubkey,
+    pub authority: Pubkey,
+    /// The depositor's shares at vote time
+    pub shares: u128,
+    pub ts: i64,
+    pub poll_id: u32,
+    pub bump: u8,
+    pub padding: [u8; 3],
+}
+
+impl Size for VaultPollVote {
+    const SIZE: usize = 96 + 8;
+}
+const_assert_eq!(
+    VaultPollVote::SIZE,
+    std::mem::size_of::<VaultPollVote>() + 8
+);
+
+impl VaultPoll {
+    pub fn is_active(&self, now: i64) -> bool {
+        self.poll_id != 0 && !self.executed && now < self.end_ts
+    }
+
+    pub fn has_quorum(&self) -> VaultResult<bool> {
+        Ok(self.total_shares > 0
+            && self.votes_for.safe_mul(PERCENTAGE_PRECISION)?
+                >= self.total_shares.safe_mul(VAULT_POLL_QUORUM)?)
+    }
+
+    pub fn start(
+        &mut self,
+        now: i64,
+        proposal: u8,
+        new_manager: Pubkey,
+        total_shares: u128,
+        shares_base: u32,
+    ) -> VaultResult {
+        validate!(
+            !self.is_active(now),
+            ErrorCode::InvalidVaultPoll,
+            "poll {} is still active until {}",
+            self.poll_id,
+            self.end_ts
+        )?;
+        let proposal_type = VaultPollProposal::try_from(proposal)?;
+        validate!(
+            proposal_type != VaultPollProposal::ReplaceManager || new_manager != Pubkey::default(),
+            ErrorCode::InvalidVaultPoll,
+            "ReplaceManager poll needs a new manager"
+        )?;
+
+        self.poll_id = self.poll_id.safe_add(1)?;
+        self.proposal = proposal;
+        self.new_manager = new_manager;
+        self.votes_for = 0;
+        self.total_shares = total_shares;
+        self.shares_base = shares_base;
+        self.start_ts = now;
+        self.end_ts = now.safe_add(VAULT_POLL_DURATION)?;
+        self.executed = false;
+
+        msg!(
+            "Started vault poll {}: {:?}, ends {}",
+            self.poll_id,
+            proposal_type,
+            self.end_ts
+        );
+
+        Ok(())
+    }
+
+    pub fn vote(&mut self, now: i64, shares: u128, shares_base: u32) -> VaultResult {
+        validate!(
+            self.is_active(now),
+            ErrorCode::InvalidVaultPoll,
+            "poll {} is not active",
+            self.poll_id
+        )?;
+        validate!(
+            self.shares_base == shares_base,
+            ErrorCode::InvalidVaultPoll,
+            "vault rebased during poll {}",
+            self.poll_id
+        )?;
+        validate!(shares > 0, ErrorCode::InvalidVaultPoll, "no shares to vote")?;
+
+        self.votes_for = self.votes_for.safe_add(shares)?;
+
+        Ok(())
+    }
+
+    pub fn execute(&mut self) -> VaultResult<VaultPollProposal> {
+        validate!(
+            self.poll_id != 0 && !self.executed,
+            ErrorCode::InvalidVaultPoll,
+            "no poll to execute"
+        )?;
+        validate!(
+            self.has_quorum()?,
+            ErrorCode::InvalidVaultPoll,
+            "poll {} has {}/{} shares, below quorum",
+            self.poll_id,
+            self.votes_for,
+            self.total_shares
+        )?;
+
+        self.executed = true;
+
+        VaultPollProposal::try_from(self.proposal)
+    }
+}
+
+#[cfg(test)]
+mod vault_poll_tests {
+    use super::*;
+
+    #[test]
+    fn test_vault_poll() {
+        let now = 1_000;
+        let new_manager = Pubkey::new_unique();
+        let mut poll = VaultPoll::default();
+
+        assert!(poll.vote(now, 100, 0).is_err());
+        assert!(poll
+            .start(
+                now,
+                VaultPollProposal::ReplaceManager as u8,
+                Pubkey::default(),
+                1_000,
+                0
+            )
+            .is_err());
+        assert!(poll.start(now, 2, new_manager, 1_000, 0).is_err());
+
+        poll.start(
+            now,
+            VaultPollProposal::ReplaceManager as u8,
+            new_manager,
+            1_000,
+            0,
+        )
+        .unwrap();
+        assert_eq!(poll.poll_id, 1);
+        assert!(poll.is_active(now));
+        // only one poll at a time
+        assert!(poll
+            .start(
+                now,
+                VaultPollProposal::WindDown as u8,
+                new_manager,
+                1_000,
+                0
+            )
+            .is_err());
+
+        poll.vote(now + 1, 300, 0).unwrap();
+        assert!(!poll.has_quorum().unwrap());
+        assert!(poll.execute().is_err());
+        // rebased shares can't be mixed with the snapshot
+        assert!(poll.vote(now + 1, 300, 1).is_err());
+
+        poll.vote(now + 2, 200, 0).unwrap();
+        assert!(poll.has_quorum().unwrap());
+        assert_eq!(poll.execute().unwrap(), VaultPollProposal::ReplaceManager);
+        assert!(poll.execute().is_err());
+        assert!(!poll.is_active(now + 3));
+
+        // expired polls can be replaced
+        poll.start(
+            now + 3,
+            VaultPollProposal::WindDown as u8,
+            Pubkey::default(),
+            1_000,
+            0,
+        )
+        .unwrap();
+        assert_eq!(poll.poll_id, 2);
+        assert_eq!(poll.votes_for, 0);
+        assert!(poll.vote(now + 3 + VAULT_POLL_DURATION, 500, 0).is_err());
+    }
+}
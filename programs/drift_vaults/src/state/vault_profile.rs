@@ -0,0 +1,141 @@
+use anchor_lang::prelude::*;
+use borsh::{BorshDeserialize, BorshSerialize};
+use drift::state::perp_market::ContractType;
+use drift::state::perp_market_map::PerpMarketMap;
+use drift::state::spot_market::SpotBalanceType;
+use drift::state::user::User;
+
+use crate::error::ErrorCode;
+
+/// Positions a vault promises its depositors the drift user will hold. Ordered from least to most strict, a vault
+/// can only move to a stricter profile.
+#[derive(Clone, Copy, BorshSerialize, BorshDeserialize, PartialEq, Debug, Eq, PartialOrd, Ord)]
+#[repr(u8)]
+pub enum VaultProfile {
+    Unrestricted = 0,
+    /// spot positions and prediction market perp positions only
+    SpotAndPrediction = 1,
+    /// no perp positions or orders
+    SpotOnly = 2,
+    /// no perp positions or orders and no borrows
+    YieldOnly = 3,
+}
+
+impl TryFrom<u8> for VaultProfile {
+    type Error = ErrorCode;
+
+    fn try_from(value: u8) -> std::result::Result<Self, ErrorCode> {
+        match value {
+            0 => Ok(VaultProfile::Unrestricted),
+            1 => Ok(VaultProfile::SpotAndPrediction),
+            2 => Ok(VaultProfile::SpotOnly),
+            3 => Ok(VaultProfile::YieldOnly),
+            _ => Err(ErrorCode::InvalidVaultProfile),
+        }
+    }
+}
+
+impl VaultProfile {
+    /// Whether the drift user's positions satisfy the profile. `perp_market_map` only needs the markets of the
+    /// user's perp positions for `SpotAndPrediction`.
+    pub fn is_satisfied_by(&self, user: &User, perp_market_map: &PerpMarketMap) -> Result<bool> {
+        let allows_perp = |market_index: u16| -> Result<bool> {
+            match self {
+                VaultProfile::Unrestricted => Ok(true),
+                VaultProfile::SpotAndPrediction => {
+                    Ok(perp_market_map.get_ref(&market_index)?.contract_type
+                        == ContractType::Prediction)
+                }
+                VaultProfile::SpotOnly | VaultProfile::YieldOnly => Ok(false),
+            }
+        };
+
+        for perp_position in user.perp_positions.iter() {
+            if perp_position.is_available() {
+                continue;
+            }
+
+            if !allows_perp(perp_position.market_index)? {
+                msg!(
+                    "perp market {} not allowed by vault profile {:?}",
+                    perp_position.market_index,
+                    self
+                );
+                return Ok(false);
+            }
+        }
+
+        if *self == VaultProfile::YieldOnly {
+            for spot_position in user.spot_positions.iter() {
+                if spot_position.scaled_balance > 0
+                    && spot_position.balance_type == SpotBalanceType::Borrow
+                {
+                    msg!(
+                        "spot market {} borrow not allowed by vault profile {:?}",
+                        spot_position.market_index,
+                        self
+                    );
+                    return Ok(false);
+                }
+            }
+        }
+
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod vault_profile_tests {
+    use drift::state::perp_market_map::PerpMarketMap;
+    use drift::state::spot_market::SpotBalanceType;
+    use drift::state::user::{PerpPosition, SpotPosition, User};
+
+    use super::VaultProfile;
+
+    #[test]
+    fn test_profile_strictness() {
+        assert!(VaultProfile::Unrestricted < VaultProfile::SpotAndPrediction);
+        assert!(VaultProfile::SpotAndPrediction < VaultProfile::SpotOnly);
+        assert!(VaultProfile::SpotOnly < VaultProfile::YieldOnly);
+        assert!(VaultProfile::try_from(4).is_err());
+    }
+
+    #[test]
+    fn test_is_satisfied_by() {
+        let perp_market_map = PerpMarketMap::empty();
+        let mut user = User::default();
+
+        for profile in [
+            VaultProfile::Unrestricted,
+            VaultProfile::SpotOnly,
+            VaultProfile::YieldOnly,
+        ] {
+            assert!(profile.is_satisfied_by(&user, &perp_market_map).unwrap());
+        }
+
+        user.spot_positions[0] = SpotPosition {
+            market_index: 1,
+            scaled_balance: 1,
+            balance_type: SpotBalanceType::Borrow,
+            ..SpotPosition::default()
+        };
+        assert!(VaultProfile::SpotOnly
+            .is_satisfied_by(&user, &perp_market_map)
+            .unwrap());
+        assert!(!VaultProfile::YieldOnly
+            .is_satisfied_by(&user, &perp_market_map)
+            .unwrap());
+
+        user.perp_positions[0] = PerpPosition {
+            market_index: 0,
+            base_asset_amount: 1,
+            ..PerpPosition::default()
+        };
+        assert!(VaultProfile::Unrestricted
+            .is_satisfied_by(&user, &perp_market_map)
+            .unwrap());
+        assert!(!VaultProfile::SpotOnly
+            .is_satisfied_by(&user, &perp_market_map)
+            .unwrap());
+    }
+}
@@ -0,0 +1,159 @@
+use anchor_lang::prelude::*;
+use drift::math::safe_math::SafeMath;
+use drift_macros::assert_no_slop;
+use static_assertions::const_assert_eq;
+
+use crate::constants::ONE_DAY;
+use crate::error::ErrorCode;
+use crate::state::Size;
+use crate::validate;
+
+/// Longest profit share cadence a manager can set
+pub const MAX_PROFIT_SHARE_INTERVAL: u32 = 365 * ONE_DAY as u32;
+
+/// Min time between two profit share crystallizations of a depositor's position by `apply_profit_share` cranks and
+/// `realize_depositor_profits`, so profit share isn't locked in on every transient spike. Depositors track their last
+/// one in a [`VaultDepositorProfitShareCadence`]. Deposits and withdraw requests still charge profit share as they
+/// need to. Enforced through the vault's [`VaultGuard`](crate::state::VaultGuard), regardless of the
+/// [`VaultDepositorThrottle`](crate::state::VaultDepositorThrottle).
+#[assert_no_slop]
+#[account(zero_copy(unsafe))]
+#[derive(Default, Eq, PartialEq, Debug)]
+#[repr(C)]
+pub struct VaultProfitShareCadence {
+    /// The vault the cadence is enforced for
+    pub vault: Pubkey,
+    /// Min seconds between two crystallizations of a depositor's profit share
+    pub min_profit_share_interval: u32,
+    pub bump: u8,
+    pub padding: [u8; 3],
+}
+
+impl Size for VaultProfitShareCadence {
+    const SIZE: usize = 40 + 8;
+}
+const_assert_eq!(
+    VaultProfitShareCadence::SIZE,
+    std::mem::size_of::<VaultProfitShareCadence>() + 8
+);
+
+/// A depositor's last profit share crystallization in a vault with a [`VaultProfitShareCadence`]. Anyone can create
+/// it, so cranks aren't blocked on the depositor.
+#[assert_no_slop]
+#[account(zero_copy(unsafe))]
+#[derive(Default, Eq, PartialEq, Debug)]
+#[repr(C)]
+pub struct VaultDepositorProfitShareCadence {
+    /// The vault depositor whose crystallizations are tracked
+    pub vault_depositor: Pubkey,
+    pub last_profit_share_ts: i64,
+    pub bump: u8,
+    pub padding: [u8; 7],
+}
+
+impl Size for VaultDepositorProfitShareCadence {
+    const SIZE: usize = 48 + 8;
+}
+const_assert_eq!(
+    VaultDepositorProfitShareCadence::SIZE,
+    std::mem::size_of::<VaultDepositorProfitShareCadence>() + 8
+);
+
+impl VaultProfitShareCadence {
+    pub fn get_address(vault: &Pubkey) -> Pubkey {
+        Pubkey::find_program_address(
+            &[b"vault_profit_share_cadence", vault.as_ref()],
+            &crate::id(),
+        )
+        .0
+    }
+
+    pub fn update_min_profit_share_interval(
+        &mut self,
+        min_profit_share_interval: u32,
+    ) -> Result<()> {
+        validate!(
+            min_profit_share_interval <= MAX_PROFIT_SHARE_INTERVAL,
+            ErrorCode::InvalidVaultProfitShareCadence,
+            "min profit share interval must be at most {}",
+            MAX_PROFIT_SHARE_INTERVAL
+        )?;
+
+        self.min_profit_share_interval = min_profit_share_interval;
+
+        Ok(())
+    }
+
+    /// Fails if the depositor's profit share was crystallized less than `min_profit_share_interval` ago, otherwise
+    /// records `now` as its last crystallization.
+    pub fn record_profit_share(
+        &self,
+        vault_depositor_cadence: &mut VaultDepositorProfitShareCadence,
+        now: i64,
+    ) -> Result<()> {
+        let next_ts = vault_depositor_cadence
+            .last_profit_share_ts
+            .safe_add(self.min_profit_share_interval as i64)?;
+        validate!(
+            now >= next_ts,
+            ErrorCode::ProfitShareCadenceNotElapsed,
+            "depositor profit share can be crystallized again at {}",
+            next_ts
+        )?;
+
+        vault_depositor_cadence.last_profit_share_ts = now;
+
+        Ok(())
+    }
+}
+
+impl VaultDepositorProfitShareCadence {
+    pub fn get_address(vault_depositor: &Pubkey) -> Pubkey {
+        Pubkey::find_program_address(
+            &[
+                b"vault_depositor_profit_share_cadence",
+                vault_depositor.as_ref(),
+            ],
+            &crate::id(),
+        )
+        .0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::constants::ONE_DAY;
+    use crate::error::ErrorCode;
+    use crate::state::{
+        VaultDepositorProfitShareCadence, VaultProfitShareCadence, MAX_PROFIT_SHARE_INTERVAL,
+    };
+
+    #[test]
+    fn test_profit_share_cadence() {
+        let mut cadence = VaultProfitShareCadence::default();
+        assert!(cadence
+            .update_min_profit_share_interval(MAX_PROFIT_SHARE_INTERVAL + 1)
+            .is_err());
+        cadence
+            .update_min_profit_share_interval(30 * ONE_DAY as u32)
+            .unwrap();
+
+        // never crystallized since tracking started
+        let mut depositor_cadence = VaultDepositorProfitShareCadence::default();
+        let now = 100 * ONE_DAY;
+        cadence
+            .record_profit_share(&mut depositor_cadence, now)
+            .unwrap();
+        assert_eq!(depositor_cadence.last_profit_share_ts, now);
+
+        assert_eq!(
+            cadence.record_profit_share(&mut depositor_cadence, now + 30 * ONE_DAY - 1),
+            Err(ErrorCode::ProfitShareCadenceNotElapsed.into())
+        );
+        assert_eq!(depositor_cadence.last_profit_share_ts, now);
+        cadence
+            .record_profit_share(&mut depositor_cadence, now + 30 * ONE_DAY)
+            .unwrap();
+        assert_eq!(depositor_cadence.last_profit_share_ts, now + 30 * ONE_DAY);
+    }
+}
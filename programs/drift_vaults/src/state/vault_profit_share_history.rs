@@ -0,0 +1,243 @@
+use std::cell::RefMut;
+
+use anchor_lang::prelude::*;
+use bytemuck::Zeroable;
+use drift::math::casting::Cast;
+use drift::math::insurance::{
+    if_shares_to_vault_amount as depositor_shares_to_vault_amount,
+    vault_amount_to_if_shares as vault_amount_to_depositor_shares,
+};
+use drift::math::safe_math::SafeMath;
+use drift_macros::assert_no_slop;
+use static_assertions::const_assert_eq;
+
+use crate::constants::ONE_DAY;
+use crate::error::ErrorCode;
+use crate::events::ProfitShareRefundRecord;
+use crate::state::{Size, Vault, VaultConfig, VaultProtocol};
+use crate::validate;
+
+/// Minimum time between two [`ProfitShareCheckpoint`]s
+pub const PROFIT_SHARE_CHECKPOINT_PERIOD: i64 = ONE_DAY * 7;
+
+#[assert_no_slop]
+#[derive(
+    Default, AnchorSerialize, AnchorDeserialize, Copy, Clone, Eq, PartialEq, Debug, Zeroable,
+)]
+pub struct ProfitShareCheckpoint {
+    pub ts: i64,
+    /// `Vault::manager_total_profit_share` at ts
+    pub manager_total_profit_share: u64,
+}
+
+/// Rolling checkpoints of the manager's realized profit share, so a manager lowering `profit_share` can refund the
+/// difference on what was realized since the oldest checkpoint, between three and four
+/// [`PROFIT_SHARE_CHECKPOINT_PERIOD`]s back. The refund burns manager shares, so it accrues to everyone holding
+/// shares when it's applied rather than to the depositors who paid the profit share.
+#[assert_no_slop]
+#[account(zero_copy(unsafe))]
+#[derive(Default, Eq, PartialEq, Debug)]
+#[repr(C)]
+pub struct VaultProfitShareHistory {
+    /// The vault the checkpoints are for
+    pub vault: Pubkey,
+    /// Oldest first
+    pub checkpoints: [ProfitShareCheckpoint; 4],
+    /// Total refunded to shareholders, precision: token mint
+    pub total_refunded: u64,
+    pub bump: u8,
+    pub padding: [u8; 7],
+}
+
+impl Size for VaultProfitShareHistory {
+    const SIZE: usize = 112 + 8;
+}
+const_assert_eq!(
+    VaultProfitShareHistory::SIZE,
+    std::mem::size_of::<VaultProfitShareHistory>() + 8
+);
+
+impl VaultProfitShareHistory {
+    /// Sets every checkpoint to now, nothing realized before it can be refunded
+    pub fn reset(&mut self, vault: &Vault, now: i64) {
+        self.checkpoints = [ProfitShareCheckpoint {
+            ts: now,
+            manager_total_profit_share: vault.manager_total_profit_share,
+        }; 4];
+    }
+
+    pub fn checkpoint(&mut self, vault: &Vault, now: i64) -> Result<()> {
+        let newest = self.checkpoints[self.checkpoints.len() - 1];
+        validate!(
+            now.safe_sub(newest.ts)? >= PROFIT_SHARE_CHECKPOINT_PERIOD,
+            ErrorCode::InvalidProfitShareRefund,
+            "next checkpoint at {}",
+            newest.ts.safe_add(PROFIT_SHARE_CHECKPOINT_PERIOD)?
+        )?;
+
+        self.checkpoints.rotate_left(1);
+        self.checkpoints[self.checkpoints.len() - 1] = ProfitShareCheckpoint {
+            ts: now,
+            manager_total_profit_share: vault.manager_total_profit_share,
+        };
+
+        Ok(())
+    }
+
+    /// Profit share realized since the oldest checkpoint
+    pub fn profit_share_in_lookback(&self, vault: &Vault) -> Result<u64> {
+        Ok(vault
+            .manager_total_profit_share
+            .safe_sub(self.checkpoints[0].manager_total_profit_share)?)
+    }
+
+    /// Lowers the vault's `profit_share` and burns manager shares worth the difference in profit share on what was
+    /// realized in the lookback, capped at the shares not already requested for withdraw by the manager. The
+    /// checkpoints are reset so the same profits aren't refunded twice. Returns the amount refunded.
+    pub fn apply_fee_reduction_refund(
+        &mut self,
+        vault: &mut Vault,
+        vault_config: &VaultConfig,
+        vault_protocol: &mut Option<RefMut<VaultProtocol>>,
+        profit_share: u32,
+        vault_equity: u64,
+        now: i64,
+    ) -> Result<u64> {
+        validate!(
+            self.vault == vault.pubkey,
+            ErrorCode::InvalidProfitShareRefund,
+            "VaultProfitShareHistory is not for this vault"
+        )?;
+        validate!(
+            profit_share < vault.profit_share,
+            ErrorCode::InvalidVaultUpdate,
+            "new profit share must be less than existing profit share"
+        )?;
+
+        vault.apply_rebase(vault_protocol, vault_equity, now)?;
+        vault.apply_fee(vault_config, vault_protocol, vault_equity, now)?;
+
+        let profit_share_before = vault.profit_share;
+        let profit_share_in_lookback = self.profit_share_in_lookback(vault)?;
+        let owed = profit_share_in_lookback
+            .cast::<u128>()?
+            .safe_mul(profit_share_before.safe_sub(profit_share)?.cast()?)?
+            .safe_div(profit_share_before.cast()?)?
+            .cast::<u64>()?;
+
+        let available_shares = vault
+            .get_manager_shares(vault_protocol)?
+            .saturating_sub(vault.last_manager_withdraw_request.shares);
+        let shares = vault_amount_to_depositor_shares(owed, vault.total_shares, vault_equity)?
+            .min(available_shares);
+        let amount =
+            depositor_shares_to_vault_amount(shares, vault.total_shares, vault_equity)?.min(owed);
+
+        vault.total_shares = vault.total_shares.safe_sub(shares)?;
+        vault.profit_share = profit_share;
+        self.total_refunded = self.total_refunded.safe_add(amount)?;
+
+        emit!(ProfitShareRefundRecord {
+            ts: now,
+            vault: vault.pubkey,
+            profit_share_before,
+            profit_share_after: profit_share,
+            lookback_start_ts: self.checkpoints[0].ts,
+            profit_share_in_lookback,
+            amount,
+            shares,
+        });
+
+        self.reset(vault, now);
+
+        Ok(amount)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anchor_lang::prelude::Pubkey;
+    use drift::math::constants::QUOTE_PRECISION_U64;
+
+    use crate::state::{
+        Vault, VaultConfig, VaultProfitShareHistory, PROFIT_SHARE_CHECKPOINT_PERIOD,
+    };
+
+    #[test]
+    fn test_apply_fee_reduction_refund() {
+        let mut vault = Vault {
+            pubkey: Pubkey::new_unique(),
+            total_shares: 1_000 * QUOTE_PRECISION_U64 as u128,
+            user_shares: 900 * QUOTE_PRECISION_U64 as u128,
+            profit_share: 200_000, // 20%
+            manager_total_profit_share: 50 * QUOTE_PRECISION_U64,
+            ..Vault::default()
+        };
+        let vault_equity = 1_000 * QUOTE_PRECISION_U64;
+
+        let mut history = VaultProfitShareHistory {
+            vault: vault.pubkey,
+            ..VaultProfitShareHistory::default()
+        };
+        history.reset(&vault, 0);
+        assert!(history.checkpoint(&vault, 1).is_err());
+
+        // 10 realized every period, the lookback starts at the oldest of the 4 checkpoints
+        let mut now = 0;
+        for _ in 0..5 {
+            now += PROFIT_SHARE_CHECKPOINT_PERIOD;
+            vault.manager_total_profit_share += 10 * QUOTE_PRECISION_U64;
+            history.checkpoint(&vault, now).unwrap();
+        }
+        vault.manager_total_profit_share += 10 * QUOTE_PRECISION_U64;
+        assert_eq!(
+            history.checkpoints[0].ts,
+            2 * PROFIT_SHARE_CHECKPOINT_PERIOD
+        );
+        assert_eq!(
+            history.profit_share_in_lookback(&vault).unwrap(),
+            40 * QUOTE_PRECISION_U64
+        );
+
+        assert!(history
+            .apply_fee_reduction_refund(
+                &mut vault,
+                &VaultConfig::default(),
+                &mut None,
+                200_000,
+                vault_equity,
+                now
+            )
+            .is_err());
+
+        // 20% -> 15% refunds a quarter of the 40
+        let amount = history
+            .apply_fee_reduction_refund(
+                &mut vault,
+                &VaultConfig::default(),
+                &mut None,
+                150_000,
+                vault_equity,
+                now,
+            )
+            .unwrap();
+        assert_eq!(amount, 10 * QUOTE_PRECISION_U64);
+        assert_eq!(vault.profit_share, 150_000);
+        assert_eq!(vault.total_shares, 990 * QUOTE_PRECISION_U64 as u128);
+        assert_eq!(history.total_refunded, amount);
+
+        // one shot, a further reduction has nothing left to refund
+        let amount = history
+            .apply_fee_reduction_refund(
+                &mut vault,
+                &VaultConfig::default(),
+                &mut None,
+                100_000,
+                vault_equity,
+                now,
+            )
+            .unwrap();
+        assert_eq!(amount, 0);
+        assert_eq!(vault.profit_share, 100_000);
+    }
+}
@@ -3,7 +3,7 @@ use drift_macros::assert_no_slop;
 use static_assertions::const_assert_eq;
 
 use crate::state::withdraw_request::WithdrawRequest;
-use crate::state::Size;
+use crate::state::{Size, VaultConfig};
 
 pub struct VaultFee {
     pub management_fee_payment: i64,
@@ -37,7 +37,9 @@ pub struct VaultProtocol {
     pub protocol_profit_share: u32,
     pub bump: u8,
     pub version: u8,
-    pub padding: [u8; 2],
+    /// Bits of [`VaultProtocolFlag`]
+    pub flags: u8,
+    pub padding: [u8; 1],
 }
 
 impl Size for VaultProtocol {
@@ -48,8 +50,67 @@ const_assert_eq!(
     std::mem::size_of::<VaultProtocol>() + 8
 );
 
+/// Bits of [`VaultProtocol`] `flags`
+#[derive(Clone, Copy, PartialEq, Debug, Eq)]
+pub enum VaultProtocolFlag {
+    /// Delegate changes are proposed by the manager and approved by the protocol, see
+    /// [`VaultDelegateProposal`](crate::state::VaultDelegateProposal)
+    DelegateDualApproval = 0b00000001,
+    /// Deposits count towards the protocol's [`ProtocolTvlCap`](crate::state::ProtocolTvlCap), which must be passed
+    /// in remaining_accounts
+    TvlCap = 0b00000010,
+    /// The protocol fee is currently waived, see [`VaultProtocol::update_protocol_fee_waiver`]
+    ProtocolFeeWaived = 0b00000100,
+}
+
 impl VaultProtocol {
+    pub fn has_flag(&self, flag: VaultProtocolFlag) -> bool {
+        self.flags & (flag as u8) != 0
+    }
+
+    pub fn add_flag(&mut self, flag: VaultProtocolFlag) {
+        self.flags |= flag as u8;
+    }
+
+    pub fn remove_flag(&mut self, flag: VaultProtocolFlag) {
+        self.flags &= !(flag as u8);
+    }
+
     pub fn get_vault_protocol_seeds<'a>(vault: &'a [u8], bump: &'a u8) -> [&'a [u8]; 3] {
         [b"vault_protocol".as_ref(), vault, bytemuck::bytes_of(bump)]
     }
+
+    /// Moves the vault in or out of the protocol fee waiver of its [`VaultConfig`] for the given vault equity and
+    /// returns whether the fee is waived. Called at each `apply_fee`.
+    pub fn update_protocol_fee_waiver(
+        &mut self,
+        vault_config: &VaultConfig,
+        vault_equity: u64,
+    ) -> bool {
+        let waived = self.has_flag(VaultProtocolFlag::ProtocolFeeWaived);
+
+        let waive = if vault_config.fee_waiver_tvl_threshold == 0 {
+            false
+        } else if waived {
+            vault_equity < vault_config.fee_waiver_exit_tvl_threshold
+        } else {
+            vault_equity < vault_config.fee_waiver_tvl_threshold
+        };
+
+        if waive != waived {
+            msg!(
+                "Protocol fee waiver {} -> {} at vault equity {}",
+                waived,
+                waive,
+                vault_equity
+            );
+            if waive {
+                self.add_flag(VaultProtocolFlag::ProtocolFeeWaived);
+            } else {
+                self.remove_flag(VaultProtocolFlag::ProtocolFeeWaived);
+            }
+        }
+
+        waive
+    }
 }
@@ -0,0 +1,230 @@
+use anchor_lang::prelude::*;
+use bytemuck::Zeroable;
+use drift::math::casting::Cast;
+use drift::math::constants::PERCENTAGE_PRECISION;
+use drift::math::safe_math::SafeMath;
+use drift_macros::assert_no_slop;
+use static_assertions::const_assert_eq;
+
+use crate::error::ErrorCode;
+use crate::state::withdraw_request::WithdrawRequest;
+use crate::state::{Size, Vault};
+use crate::validate;
+
+pub const VAULT_REDEEM_SCHEDULE_BUCKETS: usize = 4;
+
+/// Shorter redeem periods for smaller withdraw requests. Requests larger than every bucket, or made without the
+/// schedule in remaining_accounts, wait the vault's full `redeem_period`, so leaving it out never helps a depositor.
+#[assert_no_slop]
+#[account(zero_copy(unsafe))]
+#[derive(Default, Eq, PartialEq, Debug)]
+#[repr(C)]
+pub struct VaultRedeemSchedule {
+    pub vault: Pubkey,
+    /// Sorted by ascending `max_withdraw_share`, unused buckets are zeroed
+    pub buckets: [RedeemPeriodBucket; VAULT_REDEEM_SCHEDULE_BUCKETS],
+    pub bump: u8,
+    pub padding: [u8; 7],
+}
+
+impl Size for VaultRedeemSchedule {
+    const SIZE: usize = 72 + 8;
+}
+const_assert_eq!(
+    VaultRedeemSchedule::SIZE,
+    std::mem::size_of::<VaultRedeemSchedule>() + 8
+);
+
+#[assert_no_slop]
+#[derive(
+    Default, AnchorSerialize, AnchorDeserialize, Copy, Clone, Eq, PartialEq, Debug, Zeroable,
+)]
+pub struct RedeemPeriodBucket {
+    /// Requests for at most this share of vault equity use this bucket: PERCENTAGE_PRECISION
+    pub max_withdraw_share: u32,
+    /// The redeem period (in seconds) of requests in this bucket, capped at the vault's `redeem_period`
+    pub redeem_period: u32,
+}
+
+impl RedeemPeriodBucket {
+    pub fn is_active(&self) -> bool {
+        self.max_withdraw_share != 0
+    }
+}
+
+impl VaultRedeemSchedule {
+    pub fn get_address(vault: &Pubkey) -> Pubkey {
+        Pubkey::find_program_address(&[b"vault_redeem_schedule", vault.as_ref()], &crate::id()).0
+    }
+
+    pub fn update_buckets(
+        &mut self,
+        buckets: [RedeemPeriodBucket; VAULT_REDEEM_SCHEDULE_BUCKETS],
+    ) -> Result<()> {
+        let mut last_share = 0;
+        let mut last_redeem_period = 0;
+        let mut last_active = true;
+
+        for bucket in buckets.iter() {
+            if !bucket.is_active() {
+                validate!(
+                    bucket.redeem_period == 0,
+                    ErrorCode::InvalidVaultRedeemSchedule,
+                    "unused redeem period bucket must have zero redeem period"
+                )?;
+                last_active = false;
+                continue;
+            }
+
+            validate!(
+                last_active,
+                ErrorCode::InvalidVaultRedeemSchedule,
+                "redeem period buckets must be contiguous"
+            )?;
+            validate!(
+                bucket.max_withdraw_share > last_share
+                    && bucket.max_withdraw_share.cast::<u128>()? <= PERCENTAGE_PRECISION,
+                ErrorCode::InvalidVaultRedeemSchedule,
+                "redeem period bucket shares must be increasing {} <= {}",
+                bucket.max_withdraw_share,
+                last_share
+            )?;
+            validate!(
+                bucket.redeem_period >= last_redeem_period,
+                ErrorCode::InvalidVaultRedeemSchedule,
+                "redeem periods must not shrink for larger buckets {} < {}",
+                bucket.redeem_period,
+                last_redeem_period
+            )?;
+
+            last_share = bucket.max_withdraw_share;
+            last_redeem_period = bucket.redeem_period;
+        }
+
+        msg!(
+            "Updating redeem period buckets {:?} -> {:?}",
+            self.buckets,
+            buckets
+        );
+        self.buckets = buckets;
+
+        Ok(())
+    }
+
+    /// The redeem period of a request for `withdraw_value` out of `vault_equity`
+    pub fn get_redeem_period(
+        &self,
+        vault: &Vault,
+        withdraw_value: u64,
+        vault_equity: u64,
+    ) -> Result<i64> {
+        for bucket in self.buckets.iter().take_while(|bucket| bucket.is_active()) {
+            let max_withdraw_value = vault_equity
+                .cast::<u128>()?
+                .safe_mul(bucket.max_withdraw_share.cast()?)?
+                .safe_div(PERCENTAGE_PRECISION)?;
+
+            if withdraw_value.cast::<u128>()? <= max_withdraw_value {
+                return Ok(bucket.redeem_period.cast::<i64>()?.min(vault.redeem_period));
+            }
+        }
+
+        Ok(vault.redeem_period)
+    }
+
+    /// Requests mature at `ts + vault.redeem_period`, a shorter bucket period is encoded by moving the new
+    /// request's ts back.
+    pub fn apply(
+        &self,
+        vault: &Vault,
+        withdraw_request: &mut WithdrawRequest,
+        vault_equity: u64,
+    ) -> Result<()> {
+        let redeem_period = self.get_redeem_period(vault, withdraw_request.value, vault_equity)?;
+        withdraw_request.ts = withdraw_request
+            .ts
+            .safe_sub(vault.redeem_period.safe_sub(redeem_period)?)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bucket(max_withdraw_share: u32, redeem_period: u32) -> RedeemPeriodBucket {
+        RedeemPeriodBucket {
+            max_withdraw_share,
+            redeem_period,
+        }
+    }
+
+    #[test]
+    fn test_update_buckets() {
+        let mut schedule = VaultRedeemSchedule::default();
+        let unused = RedeemPeriodBucket::default();
+
+        schedule
+            .update_buckets([bucket(10_000, 3600), bucket(100_000, 86400), unused, unused])
+            .unwrap();
+
+        // not increasing
+        assert!(schedule
+            .update_buckets([bucket(100_000, 3600), bucket(10_000, 86400), unused, unused])
+            .is_err());
+        // larger bucket redeems faster
+        assert!(schedule
+            .update_buckets([bucket(10_000, 86400), bucket(100_000, 3600), unused, unused])
+            .is_err());
+        // gap
+        assert!(schedule
+            .update_buckets([bucket(10_000, 3600), unused, bucket(100_000, 86400), unused])
+            .is_err());
+        // over 100%
+        assert!(schedule
+            .update_buckets([bucket(1_000_001, 3600), unused, unused, unused])
+            .is_err());
+    }
+
+    #[test]
+    fn test_apply() {
+        let now = 1_000_000;
+        let vault = Vault {
+            redeem_period: 7 * 86400,
+            ..Vault::default()
+        };
+        let mut schedule = VaultRedeemSchedule::default();
+        schedule
+            .update_buckets([
+                bucket(10_000, 3600),        // <= 1%
+                bucket(100_000, 86400),      // <= 10%
+                bucket(500_000, 30 * 86400), // capped at the vault's redeem period
+                RedeemPeriodBucket::default(),
+            ])
+            .unwrap();
+
+        let vault_equity = 1_000_000;
+        for (value, redeem_period) in [
+            (10_000, 3600),
+            (10_001, 86400),
+            (100_000, 86400),
+            (400_000, 7 * 86400),
+            (900_000, 7 * 86400),
+        ] {
+            let mut request = WithdrawRequest {
+                shares: value as u128,
+                value,
+                ts: now,
+            };
+            schedule.apply(&vault, &mut request, vault_equity).unwrap();
+
+            assert!(request
+                .check_redeem_period_finished(&vault, now + redeem_period - 1)
+                .is_err());
+            request
+                .check_redeem_period_finished(&vault, now + redeem_period)
+                .unwrap();
+        }
+    }
+}
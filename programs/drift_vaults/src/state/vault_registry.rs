@@ -0,0 +1,120 @@
+use anchor_lang::prelude::*;
+use bytemuck::Zeroable;
+use drift::math::casting::Cast;
+use drift::math::safe_math::SafeMath;
+use drift_macros::assert_no_slop;
+use static_assertions::const_assert_eq;
+
+use crate::error::ErrorCode;
+use crate::state::Size;
+use crate::validate;
+
+/// Vaults per [`VaultRegistryPage`]
+pub const VAULT_REGISTRY_PAGE_ENTRIES: usize = 32;
+
+/// Program wide count of registered vaults. Vaults are listed in order in [`VaultRegistryPage`]s, vault `n` is
+/// entry `n % VAULT_REGISTRY_PAGE_ENTRIES` of page `n / VAULT_REGISTRY_PAGE_ENTRIES`.
+#[assert_no_slop]
+#[account(zero_copy(unsafe))]
+#[derive(Default, Eq, PartialEq, Debug)]
+#[repr(C)]
+pub struct VaultRegistry {
+    pub total_vaults: u64,
+    pub bump: u8,
+    pub padding: [u8; 7],
+}
+
+impl Size for VaultRegistry {
+    const SIZE: usize = 16 + 8;
+}
+const_assert_eq!(
+    VaultRegistry::SIZE,
+    std::mem::size_of::<VaultRegistry>() + 8
+);
+
+impl VaultRegistry {
+    /// The page the next registered vault is appended to
+    pub fn current_page_index(&self) -> Result<u32> {
+        Ok(self
+            .total_vaults
+            .safe_div(VAULT_REGISTRY_PAGE_ENTRIES.cast()?)?
+            .cast()?)
+    }
+}
+
+#[assert_no_slop]
+#[derive(
+    Default, AnchorSerialize, AnchorDeserialize, Copy, Clone, Eq, PartialEq, Debug, Zeroable,
+)]
+pub struct VaultRegistryEntry {
+    pub vault: Pubkey,
+    /// The mint of the vault's spot market
+    pub mint: Pubkey,
+    pub manager: Pubkey,
+    /// The vault's `VaultProtocol::protocol`, Pubkey::default() if it has none
+    pub protocol: Pubkey,
+}
+
+#[assert_no_slop]
+#[account(zero_copy(unsafe))]
+#[derive(Default, Eq, PartialEq, Debug)]
+#[repr(C)]
+pub struct VaultRegistryPage {
+    pub entries: [VaultRegistryEntry; VAULT_REGISTRY_PAGE_ENTRIES],
+    pub page_index: u32,
+    /// Number of `entries` filled
+    pub len: u32,
+    pub bump: u8,
+    pub padding: [u8; 3],
+}
+
+impl Size for VaultRegistryPage {
+    const SIZE: usize = 4108 + 8;
+}
+const_assert_eq!(
+    VaultRegistryPage::SIZE,
+    std::mem::size_of::<VaultRegistryPage>() + 8
+);
+
+impl VaultRegistryPage {
+    pub fn push(&mut self, entry: VaultRegistryEntry) -> Result<()> {
+        let len = self.len.cast::<usize>()?;
+        validate!(
+            len < VAULT_REGISTRY_PAGE_ENTRIES,
+            ErrorCode::InvalidVaultRegistry,
+            "vault registry page {} is full",
+            self.page_index
+        )?;
+
+        self.entries[len] = entry;
+        self.len = self.len.safe_add(1)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod vault_registry_tests {
+    use super::*;
+
+    #[test]
+    fn test_registry_page_push() {
+        let mut registry = VaultRegistry::default();
+        let mut page = VaultRegistryPage::default();
+
+        for i in 0..VAULT_REGISTRY_PAGE_ENTRIES {
+            assert_eq!(registry.current_page_index().unwrap(), 0);
+            let entry = VaultRegistryEntry {
+                vault: Pubkey::new_unique(),
+                ..VaultRegistryEntry::default()
+            };
+            page.push(entry).unwrap();
+            registry.total_vaults += 1;
+            assert_eq!(page.entries[i], entry);
+        }
+
+        assert_eq!(page.len as usize, VAULT_REGISTRY_PAGE_ENTRIES);
+        assert!(page.push(VaultRegistryEntry::default()).is_err());
+        assert_eq!(registry.current_page_index().unwrap(), 1);
+    }
+}
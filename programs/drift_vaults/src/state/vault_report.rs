@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+use drift_macros::assert_no_slop;
+use static_assertions::const_assert_eq;
+
+use crate::state::events::VaultReportRecord;
+use crate::state::Size;
+
+/// The manager's commitment to the hash of their off-chain report for one accounting `epoch`, so depositors can
+/// check a published report matches. One account per vault and epoch, a commitment can't be changed.
+#[assert_no_slop]
+#[account(zero_copy(unsafe))]
+#[derive(Default, Eq, PartialEq, Debug)]
+#[repr(C)]
+pub struct VaultReport {
+    /// The vault the report is for
+    pub vault: Pubkey,
+    /// Hash of the report document, e.g. sha256 of the published pdf
+    pub report_hash: [u8; 32],
+    /// When the hash was committed
+    pub committed_ts: i64,
+    /// Manager chosen accounting period, e.g. 202610 for october 2026
+    pub epoch: u32,
+    pub bump: u8,
+    pub padding: [u8; 3],
+}
+
+impl Size for VaultReport {
+    const SIZE: usize = 80 + 8;
+}
+const_assert_eq!(VaultReport::SIZE, std::mem::size_of::<VaultReport>() + 8);
+
+impl VaultReport {
+    pub fn commit(&mut self, vault: Pubkey, epoch: u32, report_hash: [u8; 32], now: i64) {
+        self.vault = vault;
+        self.epoch = epoch;
+        self.report_hash = report_hash;
+        self.committed_ts = now;
+
+        emit!(VaultReportRecord {
+            ts: now,
+            vault,
+            epoch,
+            report_hash,
+        });
+    }
+}
@@ -0,0 +1,246 @@
+use anchor_lang::prelude::*;
+use borsh::{BorshDeserialize, BorshSerialize};
+use drift::math::casting::Cast;
+use drift::math::constants::{PERCENTAGE_PRECISION, PERCENTAGE_PRECISION_U64};
+use drift::math::safe_math::SafeMath;
+use drift_macros::assert_no_slop;
+use static_assertions::const_assert_eq;
+
+use crate::constants::ONE_DAY;
+use crate::error::VaultResult;
+use crate::state::Size;
+
+/// Delegate changes older than this no longer count towards turnover
+pub const DELEGATE_TURNOVER_WINDOW: i64 = ONE_DAY * 30;
+
+#[assert_no_slop]
+#[account(zero_copy(unsafe))]
+#[derive(Default, Eq, PartialEq, Debug)]
+#[repr(C)]
+pub struct VaultRiskScore {
+    /// The vault this risk score is for
+    pub vault: Pubkey,
+    /// The vault delegate at the last update, used to track delegate turnover
+    pub last_delegate: Pubkey,
+    /// Highest equity per share seen, precision: PERCENTAGE_PRECISION
+    pub peak_equity_per_share: u128,
+    pub last_update_ts: i64,
+    pub last_delegate_change_ts: i64,
+    /// Total liabilities / equity, precision: PERCENTAGE_PRECISION
+    pub leverage: u64,
+    /// Largest single position liability / total liabilities, precision: PERCENTAGE_PRECISION
+    pub concentration: u32,
+    /// Drop in equity per share from the peak, precision: PERCENTAGE_PRECISION
+    pub drawdown: u32,
+    /// The vault's shares_base when peak_equity_per_share was set
+    pub shares_base: u32,
+    /// Delegate changes within [`DELEGATE_TURNOVER_WINDOW`] of the last change
+    pub delegate_changes: u16,
+    /// 0 (lowest risk) to 100
+    pub risk_score: u8,
+    /// [`RiskBand`]
+    pub risk_band: u8,
+    pub bump: u8,
+    pub padding: [u8; 7],
+}
+
+impl Size for VaultRiskScore {
+    const SIZE: usize = 128 + 8;
+}
+const_assert_eq!(
+    VaultRiskScore::SIZE,
+    std::mem::size_of::<VaultRiskScore>() + 8
+);
+
+#[derive(Clone, Copy, BorshSerialize, BorshDeserialize, PartialEq, Debug, Eq)]
+#[repr(u8)]
+pub enum RiskBand {
+    Low = 0,
+    Medium = 1,
+    High = 2,
+    Extreme = 3,
+}
+
+impl RiskBand {
+    pub fn from_score(risk_score: u8) -> Self {
+        match risk_score {
+            0..=24 => RiskBand::Low,
+            25..=49 => RiskBand::Medium,
+            50..=74 => RiskBand::High,
+            _ => RiskBand::Extreme,
+        }
+    }
+}
+
+impl VaultRiskScore {
+    /// Updates the risk score from the vault's current state. `equity` and liabilities are in quote precision.
+    #[allow(clippy::too_many_arguments)]
+    pub fn update(
+        &mut self,
+        now: i64,
+        delegate: Pubkey,
+        equity: u128,
+        total_shares: u128,
+        shares_base: u32,
+        total_liability: u128,
+        max_liability: u128,
+    ) -> VaultResult {
+        self.leverage = if equity > 0 {
+            total_liability
+                .safe_mul(PERCENTAGE_PRECISION)?
+                .safe_div(equity)?
+                .min(u64::MAX as u128)
+                .cast()?
+        } else if total_liability > 0 {
+            u64::MAX
+        } else {
+            0
+        };
+
+        self.concentration = if total_liability > 0 {
+            max_liability
+                .safe_mul(PERCENTAGE_PRECISION)?
+                .safe_div(total_liability)?
+                .cast()?
+        } else {
+            0
+        };
+
+        let equity_per_share = if total_shares > 0 {
+            equity
+                .safe_mul(PERCENTAGE_PRECISION)?
+                .safe_div(total_shares)?
+        } else {
+            0
+        };
+        // a rebase changes the share price, start tracking the peak again
+        if shares_base != self.shares_base || equity_per_share > self.peak_equity_per_share {
+            self.peak_equity_per_share = equity_per_share;
+            self.shares_base = shares_base;
+        }
+        self.drawdown = if self.peak_equity_per_share > 0 {
+            self.peak_equity_per_share
+                .safe_sub(equity_per_share)?
+                .safe_mul(PERCENTAGE_PRECISION)?
+                .safe_div(self.peak_equity_per_share)?
+                .cast()?
+        } else {
+            0
+        };
+
+        if delegate != self.last_delegate {
+            if self.last_delegate != Pubkey::default() {
+                if now.safe_sub(self.last_delegate_change_ts)? > DELEGATE_TURNOVER_WINDOW {
+                    self.delegate_changes = 0;
+                }
+                self.delegate_changes = self.delegate_changes.saturating_add(1);
+                self.last_delegate_change_ts = now;
+            }
+            self.last_delegate = delegate;
+        } else if now.safe_sub(self.last_delegate_change_ts)? > DELEGATE_TURNOVER_WINDOW {
+            self.delegate_changes = 0;
+        }
+
+        self.risk_score = self.calculate_risk_score()?;
+        self.risk_band = RiskBand::from_score(self.risk_score) as u8;
+        self.last_update_ts = now;
+
+        msg!(
+            "vault risk score: {} (leverage: {}, concentration: {}, drawdown: {}, delegate changes: {})",
+            self.risk_score,
+            self.leverage,
+            self.concentration,
+            self.drawdown,
+            self.delegate_changes
+        );
+
+        Ok(())
+    }
+
+    /// Weighted 0-100 score: leverage 40 (maxed at 5x), drawdown 30 (maxed at 50%), concentration 20,
+    /// delegate turnover 10 (maxed at 4 changes).
+    fn calculate_risk_score(&self) -> VaultResult<u8> {
+        let leverage_score = (self.leverage / (PERCENTAGE_PRECISION_U64 * 5 / 100)).min(100);
+        let drawdown_score =
+            (self.drawdown.cast::<u64>()? / (PERCENTAGE_PRECISION_U64 / 200)).min(100);
+        let concentration_score =
+            self.concentration.cast::<u64>()? / (PERCENTAGE_PRECISION_U64 / 100);
+        let delegate_score = (self.delegate_changes as u64 * 25).min(100);
+
+        let risk_score = leverage_score
+            .safe_mul(40)?
+            .safe_add(drawdown_score.safe_mul(30)?)?
+            .safe_add(concentration_score.safe_mul(20)?)?
+            .safe_add(delegate_score.safe_mul(10)?)?
+            .safe_div(100)?;
+
+        risk_score.min(100).cast()
+    }
+}
+
+#[cfg(test)]
+mod vault_risk_score_tests {
+    use super::*;
+
+    #[test]
+    fn test_risk_score_update() {
+        let now = 1_000_000;
+        let delegate = Pubkey::new_unique();
+        let mut risk_score = VaultRiskScore::default();
+
+        // $1000 equity, $2000 liabilities split 1500/500
+        risk_score
+            .update(
+                now,
+                delegate,
+                1_000_000_000,
+                1_000_000_000,
+                0,
+                2_000_000_000,
+                1_500_000_000,
+            )
+            .unwrap();
+        assert_eq!(risk_score.leverage, 2 * PERCENTAGE_PRECISION_U64);
+        assert_eq!(risk_score.concentration, 750_000);
+        assert_eq!(risk_score.drawdown, 0);
+        assert_eq!(risk_score.delegate_changes, 0);
+        // 40 * 40% + 75 * 20% = 31
+        assert_eq!(risk_score.risk_score, 31);
+        assert_eq!(risk_score.risk_band, RiskBand::Medium as u8);
+
+        // equity down 20% with no positions and a new delegate
+        risk_score
+            .update(
+                now + 1,
+                Pubkey::new_unique(),
+                800_000_000,
+                1_000_000_000,
+                0,
+                0,
+                0,
+            )
+            .unwrap();
+        assert_eq!(risk_score.leverage, 0);
+        assert_eq!(risk_score.drawdown, 200_000);
+        assert_eq!(risk_score.delegate_changes, 1);
+        // 40 * 30% + 25 * 10% = 14
+        assert_eq!(risk_score.risk_score, 14);
+        assert_eq!(risk_score.risk_band, RiskBand::Low as u8);
+
+        // a rebase resets the peak, delegate turnover decays after the window
+        risk_score
+            .update(
+                now + 1 + DELEGATE_TURNOVER_WINDOW + 1,
+                risk_score.last_delegate,
+                800_000_000,
+                10_000_000_000,
+                1,
+                0,
+                0,
+            )
+            .unwrap();
+        assert_eq!(risk_score.drawdown, 0);
+        assert_eq!(risk_score.delegate_changes, 0);
+        assert_eq!(risk_score.risk_score, 0);
+    }
+}
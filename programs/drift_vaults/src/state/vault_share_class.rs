@@ -0,0 +1,375 @@
+use anchor_lang::prelude::*;
+use drift::math::casting::Cast;
+use drift::math::constants::PERCENTAGE_PRECISION;
+use drift::math::safe_math::SafeMath;
+use drift_macros::assert_no_slop;
+use static_assertions::const_assert_eq;
+
+use crate::constants::ONE_DAY;
+use crate::error::ErrorCode;
+use crate::events::ShareClassConversionRecord;
+use crate::state::{Size, Vault, VaultDepositorBase};
+use crate::validate;
+
+pub const MAX_SHARE_CLASS_LOCK_PERIOD: i64 = 365 * ONE_DAY;
+
+/// The vault's locked share class. Depositors can convert liquid shares into the class for a discount on the
+/// manager's profit share, in exchange for not being able to convert them back, or withdraw them, until
+/// `lock_period` after their last conversion in.
+#[assert_no_slop]
+#[account(zero_copy(unsafe))]
+#[derive(Default, Eq, PartialEq, Debug)]
+#[repr(C)]
+pub struct VaultShareClass {
+    /// The vault the share class is for
+    pub vault: Pubkey,
+    /// Shares held in the locked class across all depositors
+    pub locked_shares: u128,
+    /// How long shares converted into the class are locked for
+    pub lock_period: i64,
+    /// Discount on the manager profit share charged on locked shares: PERCENTAGE_PRECISION
+    pub profit_share_discount: u32,
+    /// The vault `shares_base` `locked_shares` is denominated in
+    pub shares_base: u32,
+    pub bump: u8,
+    pub padding: [u8; 15],
+}
+
+impl Size for VaultShareClass {
+    const SIZE: usize = 80 + 8;
+}
+const_assert_eq!(
+    VaultShareClass::SIZE,
+    std::mem::size_of::<VaultShareClass>() + 8
+);
+
+impl VaultShareClass {
+    pub fn initialize(
+        &mut self,
+        vault: &Vault,
+        lock_period: i64,
+        profit_share_discount: u32,
+        bump: u8,
+    ) -> Result<()> {
+        validate!(
+            lock_period > 0 && lock_period <= MAX_SHARE_CLASS_LOCK_PERIOD,
+            ErrorCode::InvalidVaultShareClass,
+            "lock period must be in (0, {}]",
+            MAX_SHARE_CLASS_LOCK_PERIOD
+        )?;
+
+        validate!(
+            profit_share_discount.cast::<u128>()? <= PERCENTAGE_PRECISION,
+            ErrorCode::InvalidVaultShareClass,
+            "profit share discount {} > {}",
+            profit_share_discount,
+            PERCENTAGE_PRECISION
+        )?;
+
+        self.vault = vault.pubkey;
+        self.lock_period = lock_period;
+        self.profit_share_discount = profit_share_discount;
+        self.shares_base = vault.shares_base;
+        self.bump = bump;
+
+        Ok(())
+    }
+
+    fn rebase(&mut self, vault: &Vault) -> Result<()> {
+        if vault.shares_base != self.shares_base {
+            validate!(
+                vault.shares_base > self.shares_base,
+                ErrorCode::InvalidVaultRebase,
+                "Rebase expo out of bounds"
+            )?;
+
+            let rebase_divisor = 10_u128.pow(vault.shares_base.safe_sub(self.shares_base)?);
+            self.locked_shares = self.locked_shares.safe_div(rebase_divisor)?;
+            self.shares_base = vault.shares_base;
+        }
+
+        Ok(())
+    }
+
+    /// Records `shares` converted into the class, restarting the holder's lock and moving it onto the class's
+    /// current terms. Profit share up to now must already be charged at the holder's previous terms.
+    pub fn record_lock(
+        &mut self,
+        holder: &mut VaultDepositorShareClass,
+        vault: &Vault,
+        shares: u128,
+        now: i64,
+    ) -> Result<()> {
+        self.rebase(vault)?;
+
+        self.locked_shares = self.locked_shares.safe_add(shares)?;
+        holder.unlock_ts = holder.unlock_ts.max(now.safe_add(self.lock_period)?);
+        holder.profit_share_discount = self.profit_share_discount;
+
+        self.emit_conversion(holder, vault, true, shares, now);
+
+        Ok(())
+    }
+
+    /// Records `shares` converted out of the class, see [`VaultDepositorShareClass::validate_unlocked`].
+    pub fn record_unlock(
+        &mut self,
+        holder: &VaultDepositorShareClass,
+        vault: &Vault,
+        shares: u128,
+        now: i64,
+    ) -> Result<()> {
+        self.rebase(vault)?;
+
+        // rebasing rounds each holder down separately, so the class total can sit slightly above their sum
+        self.locked_shares = self.locked_shares.saturating_sub(shares);
+
+        self.emit_conversion(holder, vault, false, shares, now);
+
+        Ok(())
+    }
+
+    fn emit_conversion(
+        &self,
+        holder: &VaultDepositorShareClass,
+        vault: &Vault,
+        locked: bool,
+        shares: u128,
+        now: i64,
+    ) {
+        emit!(ShareClassConversionRecord {
+            ts: now,
+            vault: vault.pubkey,
+            depositor_authority: holder.authority,
+            locked,
+            shares,
+            holder_locked_shares_after: holder.vault_shares,
+            class_locked_shares_after: self.locked_shares,
+            unlock_ts: holder.unlock_ts,
+            profit_share_discount: holder.profit_share_discount,
+        });
+    }
+}
+
+/// A vault depositor's shares in the vault's locked [`VaultShareClass`]. Holds the shares, and the cost basis moved
+/// with them, outside the [`VaultDepositor`](crate::state::VaultDepositor) so they can't be requested for withdraw
+/// while locked.
+#[assert_no_slop]
+#[account(zero_copy(unsafe))]
+#[derive(Default, Eq, PartialEq, Debug)]
+#[repr(C)]
+pub struct VaultDepositorShareClass {
+    /// The share class holder's pubkey, a pda of the vault depositor
+    pub pubkey: Pubkey,
+    /// The vault depositor the locked shares belong to
+    pub vault_depositor: Pubkey,
+    /// The vault depositor's authority
+    pub authority: Pubkey,
+    /// Locked shares held
+    vault_shares: u128,
+    /// Cost basis of the locked shares, moved in from the vault depositor
+    pub net_deposits: i64,
+    pub cumulative_profit_share_amount: i64,
+    pub profit_share_fee_paid: u64,
+    /// When the locked shares can be converted back
+    pub unlock_ts: i64,
+    /// The exponent for vault_shares decimal places
+    pub vault_shares_base: u32,
+    /// The class's profit share discount as of the last conversion in: PERCENTAGE_PRECISION
+    pub profit_share_discount: u32,
+    pub bump: u8,
+    pub padding: [u8; 7],
+}
+
+impl Size for VaultDepositorShareClass {
+    const SIZE: usize = 160 + 8;
+}
+const_assert_eq!(
+    VaultDepositorShareClass::SIZE,
+    std::mem::size_of::<VaultDepositorShareClass>() + 8
+);
+
+impl VaultDepositorBase for VaultDepositorShareClass {
+    fn get_authority(&self) -> Pubkey {
+        self.authority
+    }
+    fn get_pubkey(&self) -> Pubkey {
+        self.pubkey
+    }
+
+    fn get_vault_shares(&self) -> u128 {
+        self.vault_shares
+    }
+    fn set_vault_shares(&mut self, shares: u128) {
+        self.vault_shares = shares;
+    }
+
+    fn get_vault_shares_base(&self) -> u32 {
+        self.vault_shares_base
+    }
+    fn set_vault_shares_base(&mut self, base: u32) -> Result<()> {
+        self.vault_shares_base = base;
+        Ok(())
+    }
+
+    fn get_net_deposits(&self) -> i64 {
+        self.net_deposits
+    }
+    fn set_net_deposits(&mut self, amount: i64) {
+        self.net_deposits = amount;
+    }
+
+    fn get_cumulative_profit_share_amount(&self) -> i64 {
+        self.cumulative_profit_share_amount
+    }
+    fn set_cumulative_profit_share_amount(&mut self, amount: i64) {
+        self.cumulative_profit_share_amount = amount;
+    }
+
+    fn get_profit_share_fee_paid(&self) -> u64 {
+        self.profit_share_fee_paid
+    }
+    fn set_profit_share_fee_paid(&mut self, amount: u64) {
+        self.profit_share_fee_paid = amount;
+    }
+
+    fn manager_profit_share(&self, vault: &Vault) -> Result<u32> {
+        Ok(vault
+            .manager_profit_share()
+            .cast::<u128>()?
+            .safe_mul(PERCENTAGE_PRECISION.safe_sub(self.profit_share_discount.cast()?)?)?
+            .safe_div(PERCENTAGE_PRECISION)?
+            .cast()?)
+    }
+}
+
+impl VaultDepositorShareClass {
+    pub fn get_address(vault_depositor: &Pubkey) -> Pubkey {
+        Pubkey::find_program_address(
+            &[b"vault_depositor_share_class", vault_depositor.as_ref()],
+            &crate::id(),
+        )
+        .0
+    }
+
+    /// Moves the locked shares, their cost basis and lock to `to`, the new authority's fresh share class holder,
+    /// leaving this one empty, see
+    /// [`VaultDepositor::transfer_authority`](crate::state::VaultDepositor::transfer_authority).
+    pub fn transfer_authority(&mut self, to: &mut VaultDepositorShareClass) -> Result<()> {
+        validate!(
+            to.vault_shares == 0 && to.net_deposits == 0,
+            ErrorCode::InvalidVaultShareClass,
+            "can only transfer to a new share class holder"
+        )?;
+
+        to.vault_shares = self.vault_shares;
+        to.vault_shares_base = self.vault_shares_base;
+        to.net_deposits = self.net_deposits;
+        to.cumulative_profit_share_amount = self.cumulative_profit_share_amount;
+        to.profit_share_fee_paid = self.profit_share_fee_paid;
+        to.unlock_ts = self.unlock_ts;
+        to.profit_share_discount = self.profit_share_discount;
+
+        self.vault_shares = 0;
+        self.net_deposits = 0;
+        self.cumulative_profit_share_amount = 0;
+        self.profit_share_fee_paid = 0;
+
+        Ok(())
+    }
+
+    pub fn validate_unlocked(&self, now: i64) -> Result<()> {
+        validate!(
+            now >= self.unlock_ts,
+            ErrorCode::VaultShareClassLocked,
+            "locked shares unlock at {}",
+            self.unlock_ts
+        )?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anchor_lang::prelude::Pubkey;
+
+    use crate::state::{Vault, VaultDepositorBase, VaultDepositorShareClass, VaultShareClass};
+
+    #[test]
+    fn test_lock_and_unlock() {
+        let now = 1000;
+        let vault = Vault {
+            pubkey: Pubkey::new_unique(),
+            profit_share: 200_000, // 20%
+            ..Vault::default()
+        };
+
+        let mut share_class = VaultShareClass::default();
+        assert!(share_class.initialize(&vault, 0, 500_000, 255).is_err());
+        assert!(share_class.initialize(&vault, 100, 1_000_001, 255).is_err());
+        share_class.initialize(&vault, 100, 500_000, 255).unwrap();
+
+        let mut holder = VaultDepositorShareClass::default();
+        assert_eq!(holder.manager_profit_share(&vault).unwrap(), 200_000);
+
+        holder.set_vault_shares(1_000);
+        share_class
+            .record_lock(&mut holder, &vault, 1_000, now)
+            .unwrap();
+        assert_eq!(share_class.locked_shares, 1_000);
+        assert_eq!(holder.unlock_ts, now + 100);
+        assert_eq!(holder.manager_profit_share(&vault).unwrap(), 100_000);
+
+        // converting more in restarts the lock
+        holder.set_vault_shares(1_500);
+        share_class
+            .record_lock(&mut holder, &vault, 500, now + 50)
+            .unwrap();
+        assert_eq!(share_class.locked_shares, 1_500);
+        assert_eq!(holder.unlock_ts, now + 150);
+
+        assert!(holder.validate_unlocked(now + 149).is_err());
+        holder.validate_unlocked(now + 150).unwrap();
+
+        holder.set_vault_shares(0);
+        share_class
+            .record_unlock(&holder, &vault, 1_500, now + 150)
+            .unwrap();
+        assert_eq!(share_class.locked_shares, 0);
+    }
+
+    #[test]
+    fn test_transfer_authority() {
+        let mut holder = VaultDepositorShareClass {
+            net_deposits: 1_000,
+            cumulative_profit_share_amount: 100,
+            profit_share_fee_paid: 20,
+            unlock_ts: 2_000,
+            vault_shares_base: 1,
+            profit_share_discount: 500_000,
+            ..VaultDepositorShareClass::default()
+        };
+        holder.set_vault_shares(1_500);
+
+        let mut new_holder = VaultDepositorShareClass::default();
+        holder.transfer_authority(&mut new_holder).unwrap();
+        assert_eq!(new_holder.get_vault_shares(), 1_500);
+        assert_eq!(new_holder.net_deposits, 1_000);
+        assert_eq!(new_holder.cumulative_profit_share_amount, 100);
+        assert_eq!(new_holder.profit_share_fee_paid, 20);
+        assert_eq!(new_holder.unlock_ts, 2_000);
+        assert_eq!(new_holder.vault_shares_base, 1);
+        assert_eq!(new_holder.profit_share_discount, 500_000);
+        assert_eq!(holder.get_vault_shares(), 0);
+        assert_eq!(holder.net_deposits, 0);
+
+        // only into an empty holder
+        assert!(new_holder
+            .transfer_authority(&mut VaultDepositorShareClass {
+                net_deposits: 1,
+                ..VaultDepositorShareClass::default()
+            })
+            .is_err());
+    }
+}
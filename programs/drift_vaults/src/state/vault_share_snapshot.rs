@@ -0,0 +1,112 @@
+use anchor_lang::prelude::*;
+use drift::math::safe_math::SafeMath;
+use drift_macros::assert_no_slop;
+use static_assertions::const_assert_eq;
+
+use crate::constants::ONE_DAY;
+use crate::error::{ErrorCode, VaultResult};
+use crate::state::Size;
+use crate::validate;
+
+/// How long depositors can be snapshotted after a snapshot epoch is opened
+pub const SHARE_SNAPSHOT_WINDOW: i64 = ONE_DAY;
+
+/// Per-epoch share snapshot of a vault, external governance reads each depositor's
+/// [`VaultDepositorShareSnapshot`] as voting weight.
+#[assert_no_slop]
+#[account(zero_copy(unsafe))]
+#[derive(Default, Eq, PartialEq, Debug)]
+#[repr(C)]
+pub struct VaultShareSnapshot {
+    pub vault: Pubkey,
+    /// Sum of the snapshotted depositor shares
+    pub total_shares: u128,
+    pub start_ts: i64,
+    pub end_ts: i64,
+    pub epoch: u32,
+    /// The vault's shares_base when the epoch was opened
+    pub shares_base: u32,
+    pub num_depositors: u32,
+    pub bump: u8,
+    pub padding: [u8; 3],
+}
+
+impl Size for VaultShareSnapshot {
+    const SIZE: usize = 80 + 8;
+}
+const_assert_eq!(
+    VaultShareSnapshot::SIZE,
+    std::mem::size_of::<VaultShareSnapshot>() + 8
+);
+
+#[assert_no_slop]
+#[account(zero_copy(unsafe))]
+#[derive(Default, Eq, PartialEq, Debug)]
+#[repr(C)]
+pub struct VaultDepositorShareSnapshot {
+    pub share_snapshot: Pubkey,
+    pub authority: Pubkey,
+    pub shares: u128,
+    pub ts: i64,
+    pub epoch: u32,
+    pub bump: u8,
+    pub padding: [u8; 3],
+}
+
+impl Size for VaultDepositorShareSnapshot {
+    const SIZE: usize = 96 + 8;
+}
+const_assert_eq!(
+    VaultDepositorShareSnapshot::SIZE,
+    std::mem::size_of::<VaultDepositorShareSnapshot>() + 8
+);
+
+impl VaultShareSnapshot {
+    pub fn record(&mut self, now: i64, shares: u128, shares_base: u32) -> VaultResult {
+        validate!(
+            now < self.end_ts,
+            ErrorCode::InvalidShareSnapshot,
+            "snapshot epoch {} closed at {}",
+            self.epoch,
+            self.end_ts
+        )?;
+        validate!(
+            self.shares_base == shares_base,
+            ErrorCode::InvalidShareSnapshot,
+            "vault rebased during snapshot epoch {}",
+            self.epoch
+        )?;
+
+        self.total_shares = self.total_shares.safe_add(shares)?;
+        self.num_depositors = self.num_depositors.safe_add(1)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod vault_share_snapshot_tests {
+    use super::*;
+
+    #[test]
+    fn test_record_share_snapshot() {
+        let now = 1_000;
+        let mut snapshot = VaultShareSnapshot {
+            start_ts: now,
+            end_ts: now + SHARE_SNAPSHOT_WINDOW,
+            epoch: 1,
+            ..VaultShareSnapshot::default()
+        };
+
+        snapshot.record(now, 100, 0).unwrap();
+        snapshot.record(now + 1, 250, 0).unwrap();
+        assert_eq!(snapshot.total_shares, 350);
+        assert_eq!(snapshot.num_depositors, 2);
+
+        assert!(snapshot.record(now + 2, 100, 1).is_err());
+        assert!(snapshot
+            .record(now + SHARE_SNAPSHOT_WINDOW, 100, 0)
+            .is_err());
+        assert_eq!(snapshot.total_shares, 350);
+    }
+}
@@ -0,0 +1,213 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
+use drift::math::safe_math::SafeMath;
+use drift_macros::assert_no_slop;
+use static_assertions::const_assert_eq;
+
+use crate::error::ErrorCode;
+use crate::state::events::VaultShareStatementRecord;
+use crate::state::vault_depositor_batch::LEAF_PREFIX;
+use crate::state::{
+    merkle_frontier_append, merkle_frontier_root, Size, VaultDepositorShareSnapshot,
+    VaultShareSnapshot, MERKLE_FRONTIER_DEPTH,
+};
+use crate::validate;
+
+/// Merkle root over every depositor's (authority, shares) in a closed [`VaultShareSnapshot`] epoch, so a depositor
+/// can prove their balance at that epoch against the root alone.
+///
+/// After the snapshot window closes, `append_vault_share_statement` folds the epoch's
+/// [`VaultDepositorShareSnapshot`]s into the tree in ascending authority order over as many transactions as needed.
+/// The ordering rules out duplicates, so once the leaf count matches the snapshot's depositor count every depositor
+/// is in and the root is published.
+#[assert_no_slop]
+#[account(zero_copy(unsafe))]
+#[derive(Default, Eq, PartialEq, Debug)]
+#[repr(C)]
+pub struct VaultShareStatement {
+    pub share_snapshot: Pubkey,
+    /// Authority of the last appended leaf, the next one must be greater
+    pub last_authority: Pubkey,
+    /// Zeroed until published
+    pub root: [u8; 32],
+    pub frontier: [[u8; 32]; MERKLE_FRONTIER_DEPTH],
+    /// Sum of the appended shares
+    pub total_shares: u128,
+    /// When the root was published, 0 until then
+    pub published_ts: i64,
+    pub num_leaves: u32,
+    pub epoch: u32,
+    pub bump: u8,
+    pub padding: [u8; 15],
+}
+
+impl Size for VaultShareStatement {
+    const SIZE: usize = 1168 + 8;
+}
+const_assert_eq!(
+    VaultShareStatement::SIZE,
+    std::mem::size_of::<VaultShareStatement>() + 8
+);
+
+pub fn share_statement_leaf(authority: &Pubkey, shares: u128) -> [u8; 32] {
+    hashv(&[LEAF_PREFIX, authority.as_ref(), &shares.to_le_bytes()]).to_bytes()
+}
+
+impl VaultShareStatement {
+    pub fn is_published(&self) -> bool {
+        self.published_ts != 0
+    }
+
+    pub fn append(
+        &mut self,
+        snapshot: &VaultShareSnapshot,
+        depositor_snapshot: &VaultDepositorShareSnapshot,
+        now: i64,
+    ) -> Result<()> {
+        validate!(
+            now >= snapshot.end_ts,
+            ErrorCode::InvalidShareSnapshot,
+            "snapshot epoch {} open until {}",
+            snapshot.epoch,
+            snapshot.end_ts
+        )?;
+        validate!(
+            !self.is_published(),
+            ErrorCode::InvalidShareSnapshot,
+            "statement for epoch {} already published",
+            self.epoch
+        )?;
+
+        // depositor snapshots are pdas of the snapshot and authority, so this is the depositor's only one
+        validate!(
+            depositor_snapshot.share_snapshot == self.share_snapshot,
+            ErrorCode::InvalidShareSnapshot,
+            "depositor snapshot for {} is not for snapshot {}",
+            depositor_snapshot.authority,
+            self.share_snapshot
+        )?;
+        validate!(
+            self.num_leaves == 0 || depositor_snapshot.authority > self.last_authority,
+            ErrorCode::InvalidShareSnapshot,
+            "depositor snapshots must be appended in ascending authority order"
+        )?;
+
+        merkle_frontier_append(
+            &mut self.frontier,
+            self.num_leaves,
+            share_statement_leaf(&depositor_snapshot.authority, depositor_snapshot.shares),
+        );
+        self.num_leaves = self.num_leaves.safe_add(1)?;
+        self.last_authority = depositor_snapshot.authority;
+        self.total_shares = self.total_shares.safe_add(depositor_snapshot.shares)?;
+
+        Ok(())
+    }
+
+    /// Publishes the root once every depositor in the snapshot is appended. Returns whether it was published.
+    pub fn try_publish(&mut self, snapshot: &VaultShareSnapshot, now: i64) -> Result<bool> {
+        if self.is_published()
+            || now < snapshot.end_ts
+            || self.num_leaves != snapshot.num_depositors
+        {
+            return Ok(false);
+        }
+
+        validate!(
+            self.total_shares == snapshot.total_shares,
+            ErrorCode::InvalidShareSnapshot,
+            "statement shares {} != snapshot shares {}",
+            self.total_shares,
+            snapshot.total_shares
+        )?;
+
+        self.root = merkle_frontier_root(&self.frontier, self.num_leaves);
+        self.published_ts = now;
+
+        emit!(VaultShareStatementRecord {
+            ts: now,
+            vault: snapshot.vault,
+            epoch: self.epoch,
+            root: self.root,
+            num_depositors: self.num_leaves,
+            total_shares: self.total_shares,
+            shares_base: snapshot.shares_base,
+        });
+
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::{merkle_root, SHARE_SNAPSHOT_WINDOW};
+
+    #[test]
+    fn test_share_statement() {
+        let now = 1_000;
+        let share_snapshot_key = Pubkey::new_unique();
+        let mut snapshot = VaultShareSnapshot {
+            start_ts: now,
+            end_ts: now + SHARE_SNAPSHOT_WINDOW,
+            epoch: 1,
+            ..VaultShareSnapshot::default()
+        };
+
+        let mut depositor_snapshots: Vec<VaultDepositorShareSnapshot> = (0..3)
+            .map(|i| {
+                snapshot.record(now, 100 + i, 0).unwrap();
+                VaultDepositorShareSnapshot {
+                    share_snapshot: share_snapshot_key,
+                    authority: Pubkey::new_unique(),
+                    shares: 100 + i,
+                    ..VaultDepositorShareSnapshot::default()
+                }
+            })
+            .collect();
+        depositor_snapshots.sort_by_key(|s| s.authority);
+        let [first, middle, last] = depositor_snapshots.as_slice() else {
+            unreachable!()
+        };
+
+        let mut statement = VaultShareStatement {
+            share_snapshot: share_snapshot_key,
+            epoch: 1,
+            ..VaultShareStatement::default()
+        };
+
+        // window still open
+        assert!(statement.append(&snapshot, first, now).is_err());
+
+        let now = now + SHARE_SNAPSHOT_WINDOW;
+        statement.append(&snapshot, first, now).unwrap();
+        assert!(!statement.try_publish(&snapshot, now).unwrap());
+
+        // duplicates and out of order appends are rejected
+        assert!(statement.append(&snapshot, first, now).is_err());
+        statement.append(&snapshot, last, now).unwrap();
+        assert!(statement.append(&snapshot, middle, now).is_err());
+
+        let mut statement = VaultShareStatement {
+            share_snapshot: share_snapshot_key,
+            epoch: 1,
+            ..VaultShareStatement::default()
+        };
+        for depositor_snapshot in depositor_snapshots.iter() {
+            statement
+                .append(&snapshot, depositor_snapshot, now)
+                .unwrap();
+        }
+        assert!(statement.try_publish(&snapshot, now).unwrap());
+
+        let leaves: Vec<[u8; 32]> = depositor_snapshots
+            .iter()
+            .map(|s| share_statement_leaf(&s.authority, s.shares))
+            .collect();
+        assert_eq!(statement.root, merkle_root(&leaves));
+        assert_eq!(statement.total_shares, 303);
+
+        // published statements are final
+        assert!(statement.append(&snapshot, last, now).is_err());
+    }
+}
@@ -0,0 +1,125 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use drift::math::constants::PERCENTAGE_PRECISION_U64;
+
+use crate::constants::ONE_DAY;
+use crate::error::{ErrorCode, VaultResult};
+use crate::validate;
+
+/// Protocol-curated vault presets for [`initialize_vault_from_template`](crate::instructions::initialize_vault_from_template).
+#[derive(Clone, Copy, BorshSerialize, BorshDeserialize, PartialEq, Debug, Eq)]
+#[repr(u8)]
+pub enum VaultTemplate {
+    Conservative = 0,
+    MarketMaking = 1,
+    Leveraged = 2,
+}
+
+impl TryFrom<u8> for VaultTemplate {
+    type Error = ErrorCode;
+
+    fn try_from(value: u8) -> std::result::Result<Self, ErrorCode> {
+        match value {
+            0 => Ok(VaultTemplate::Conservative),
+            1 => Ok(VaultTemplate::MarketMaking),
+            2 => Ok(VaultTemplate::Leveraged),
+            _ => Err(ErrorCode::InvalidVaultTemplate),
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Debug, Eq)]
+pub struct VaultTemplatePreset {
+    pub redeem_period: i64,
+    /// precision: PERCENTAGE_PRECISION
+    pub max_management_fee: i64,
+    /// precision: PERCENTAGE_PRECISION
+    pub max_profit_share: u32,
+    /// drift user custom margin ratio, caps leverage at MARGIN_PRECISION / margin_ratio
+    pub margin_ratio: u32,
+    /// spot markets the vault can be denominated in
+    pub spot_market_indexes: &'static [u16],
+}
+
+impl VaultTemplate {
+    pub fn preset(&self) -> VaultTemplatePreset {
+        match self {
+            VaultTemplate::Conservative => VaultTemplatePreset {
+                redeem_period: ONE_DAY * 7,
+                max_management_fee: PERCENTAGE_PRECISION_U64 as i64 / 50, // 2%
+                max_profit_share: PERCENTAGE_PRECISION_U64 as u32 / 5,    // 20%
+                margin_ratio: 5_000,                                      // 2x
+                spot_market_indexes: &[0],
+            },
+            VaultTemplate::MarketMaking => VaultTemplatePreset {
+                redeem_period: ONE_DAY * 3,
+                max_management_fee: PERCENTAGE_PRECISION_U64 as i64 / 50, // 2%
+                max_profit_share: PERCENTAGE_PRECISION_U64 as u32 * 3 / 10, // 30%
+                margin_ratio: 2_000,                                      // 5x
+                spot_market_indexes: &[0, 1],
+            },
+            VaultTemplate::Leveraged => VaultTemplatePreset {
+                redeem_period: ONE_DAY,
+                max_management_fee: PERCENTAGE_PRECISION_U64 as i64 * 3 / 100, // 3%
+                max_profit_share: PERCENTAGE_PRECISION_U64 as u32 * 2 / 5,     // 40%
+                margin_ratio: 1_000,                                           // 10x
+                spot_market_indexes: &[0, 1],
+            },
+        }
+    }
+}
+
+impl VaultTemplatePreset {
+    pub fn validate(
+        &self,
+        spot_market_index: u16,
+        management_fee: i64,
+        profit_share: u32,
+    ) -> VaultResult {
+        validate!(
+            self.spot_market_indexes.contains(&spot_market_index),
+            ErrorCode::InvalidVaultTemplate,
+            "spot market {} not allowed, must be one of {:?}",
+            spot_market_index,
+            self.spot_market_indexes
+        )?;
+        validate!(
+            (0..=self.max_management_fee).contains(&management_fee),
+            ErrorCode::InvalidVaultTemplate,
+            "management fee {} must be between 0 and {}",
+            management_fee,
+            self.max_management_fee
+        )?;
+        validate!(
+            profit_share <= self.max_profit_share,
+            ErrorCode::InvalidVaultTemplate,
+            "profit share {} must be <= {}",
+            profit_share,
+            self.max_profit_share
+        )?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod vault_template_tests {
+    use super::*;
+
+    #[test]
+    fn test_template_preset_validate() {
+        let preset = VaultTemplate::try_from(0).unwrap().preset();
+        assert_eq!(preset.redeem_period, ONE_DAY * 7);
+
+        assert!(preset.validate(0, 20_000, 200_000).is_ok());
+        // sol isn't allowed for conservative vaults
+        assert!(preset.validate(1, 20_000, 200_000).is_err());
+        assert!(preset.validate(0, 20_001, 200_000).is_err());
+        assert!(preset.validate(0, -1, 200_000).is_err());
+        assert!(preset.validate(0, 20_000, 200_001).is_err());
+
+        let preset = VaultTemplate::try_from(2).unwrap().preset();
+        assert!(preset.validate(1, 30_000, 400_000).is_ok());
+
+        assert!(VaultTemplate::try_from(3).is_err());
+    }
+}
@@ -0,0 +1,226 @@
+use anchor_lang::prelude::*;
+use bytemuck::Zeroable;
+use drift::math::safe_math::SafeMath;
+use drift_macros::assert_no_slop;
+use static_assertions::const_assert_eq;
+
+use crate::constants::{ONE_DAY, ONE_HOUR};
+use crate::error::ErrorCode;
+use crate::state::events::WithdrawBlackoutRecord;
+use crate::state::Size;
+use crate::validate;
+
+/// Windows kept in a [`VaultWithdrawBlackout`]
+pub const VAULT_WITHDRAW_BLACKOUT_WINDOWS: usize = 8;
+/// Longest single blackout window
+pub const MAX_WITHDRAW_BLACKOUT_DURATION: i64 = 12 * ONE_HOUR;
+/// Min time between scheduling a blackout window and its start
+pub const MIN_WITHDRAW_BLACKOUT_NOTICE: i64 = ONE_DAY;
+/// Blackout windows are budgeted per period of this length since the unix epoch, by their start
+pub const WITHDRAW_BLACKOUT_PERIOD: i64 = 30 * ONE_DAY;
+/// Most blackout time a vault can schedule in one [`WITHDRAW_BLACKOUT_PERIOD`]
+pub const MAX_WITHDRAW_BLACKOUT_PER_PERIOD: i64 = ONE_DAY;
+
+#[assert_no_slop]
+#[derive(
+    Default, AnchorSerialize, AnchorDeserialize, Copy, Clone, Eq, PartialEq, Debug, Zeroable,
+)]
+pub struct WithdrawBlackoutWindow {
+    pub start_ts: i64,
+    /// 0 if the slot was never used
+    pub end_ts: i64,
+}
+
+impl WithdrawBlackoutWindow {
+    fn period(&self) -> i64 {
+        self.start_ts.div_euclid(WITHDRAW_BLACKOUT_PERIOD)
+    }
+
+    fn duration(&self) -> i64 {
+        self.end_ts.saturating_sub(self.start_ts)
+    }
+}
+
+/// Short manager scheduled windows, e.g. around major economic releases, during which depositor withdraws are
+/// deferred to the end of the window. Windows are published here at least [`MIN_WITHDRAW_BLACKOUT_NOTICE`] in advance
+/// and the total per [`WITHDRAW_BLACKOUT_PERIOD`] is capped at [`MAX_WITHDRAW_BLACKOUT_PER_PERIOD`]. Enforced
+/// through the vault's [`VaultGuard`](crate::state::VaultGuard), regardless of the
+/// [`VaultDepositorThrottle`](crate::state::VaultDepositorThrottle).
+#[assert_no_slop]
+#[account(zero_copy(unsafe))]
+#[derive(Default, Eq, PartialEq, Debug)]
+#[repr(C)]
+pub struct VaultWithdrawBlackout {
+    /// The vault the blackout windows are for
+    pub vault: Pubkey,
+    pub windows: [WithdrawBlackoutWindow; VAULT_WITHDRAW_BLACKOUT_WINDOWS],
+    pub bump: u8,
+    pub padding: [u8; 7],
+}
+
+impl Size for VaultWithdrawBlackout {
+    const SIZE: usize = 168 + 8;
+}
+const_assert_eq!(
+    VaultWithdrawBlackout::SIZE,
+    std::mem::size_of::<VaultWithdrawBlackout>() + 8
+);
+
+impl VaultWithdrawBlackout {
+    pub fn get_address(vault: &Pubkey) -> Pubkey {
+        Pubkey::find_program_address(&[b"vault_withdraw_blackout", vault.as_ref()], &crate::id()).0
+    }
+
+    /// Adds a window, reusing the slot of one that ended in another period so the period's total stays accounted
+    pub fn schedule(&mut self, start_ts: i64, end_ts: i64, now: i64) -> Result<()> {
+        let window = WithdrawBlackoutWindow { start_ts, end_ts };
+        validate!(
+            start_ts >= now.safe_add(MIN_WITHDRAW_BLACKOUT_NOTICE)?,
+            ErrorCode::InvalidVaultWithdrawBlackout,
+            "blackout must start at least {} seconds from now",
+            MIN_WITHDRAW_BLACKOUT_NOTICE
+        )?;
+        validate!(
+            end_ts > start_ts && window.duration() <= MAX_WITHDRAW_BLACKOUT_DURATION,
+            ErrorCode::InvalidVaultWithdrawBlackout,
+            "invalid blackout window {} -> {}, max duration {}",
+            start_ts,
+            end_ts,
+            MAX_WITHDRAW_BLACKOUT_DURATION
+        )?;
+        validate!(
+            self.windows
+                .iter()
+                .all(|w| w.end_ts <= start_ts || w.start_ts >= end_ts),
+            ErrorCode::InvalidVaultWithdrawBlackout,
+            "blackout window {} -> {} overlaps a scheduled one",
+            start_ts,
+            end_ts
+        )?;
+
+        let period_blackout = self
+            .period_blackout(window.period())?
+            .safe_add(window.duration())?;
+        validate!(
+            period_blackout <= MAX_WITHDRAW_BLACKOUT_PER_PERIOD,
+            ErrorCode::InvalidVaultWithdrawBlackout,
+            "blackout of {} seconds in the period, max {}",
+            period_blackout,
+            MAX_WITHDRAW_BLACKOUT_PER_PERIOD
+        )?;
+
+        let slot = self
+            .windows
+            .iter_mut()
+            .find(|w| w.end_ts <= now && w.period() != window.period())
+            .ok_or(ErrorCode::InvalidVaultWithdrawBlackout)?;
+        *slot = window;
+
+        Ok(())
+    }
+
+    /// Blackout time scheduled in `period`
+    pub fn period_blackout(&self, period: i64) -> Result<i64> {
+        self.windows
+            .iter()
+            .filter(|w| w.end_ts != 0 && w.period() == period)
+            .try_fold(0_i64, |total, w| Ok(total.safe_add(w.duration())?))
+    }
+
+    /// The end of the window `now` is in, if any
+    pub fn active_window_end(&self, now: i64) -> Option<i64> {
+        self.windows
+            .iter()
+            .find(|w| w.start_ts <= now && now < w.end_ts)
+            .map(|w| w.end_ts)
+    }
+
+    pub fn validate_withdraw(&self, now: i64) -> Result<()> {
+        let end_ts = self.active_window_end(now);
+        validate!(
+            end_ts.is_none(),
+            ErrorCode::WithdrawBlackout,
+            "withdraws are deferred until the blackout ends at {}",
+            end_ts.unwrap_or_default()
+        )?;
+
+        Ok(())
+    }
+
+    pub fn emit_record(&self, start_ts: i64, end_ts: i64, now: i64) -> Result<()> {
+        let period = WithdrawBlackoutWindow { start_ts, end_ts }.period();
+        emit!(WithdrawBlackoutRecord {
+            ts: now,
+            vault: self.vault,
+            start_ts,
+            end_ts,
+            period_blackout: self.period_blackout(period)?,
+        });
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::constants::{ONE_DAY, ONE_HOUR};
+    use crate::error::ErrorCode;
+    use crate::state::{
+        VaultWithdrawBlackout, VAULT_WITHDRAW_BLACKOUT_WINDOWS, WITHDRAW_BLACKOUT_PERIOD,
+    };
+
+    #[test]
+    fn test_withdraw_blackout() {
+        let now = 100 * WITHDRAW_BLACKOUT_PERIOD;
+        let mut blackout = VaultWithdrawBlackout::default();
+
+        // needs notice, a window no longer than the max and no overlap
+        assert!(blackout
+            .schedule(now + ONE_HOUR, now + 2 * ONE_HOUR, now)
+            .is_err());
+        let start = now + ONE_DAY;
+        assert!(blackout
+            .schedule(start, start + 13 * ONE_HOUR, now)
+            .is_err());
+        assert!(blackout.schedule(start, start, now).is_err());
+        blackout.schedule(start, start + 8 * ONE_HOUR, now).unwrap();
+        assert!(blackout
+            .schedule(start + ONE_HOUR, start + 2 * ONE_HOUR, now)
+            .is_err());
+
+        // one day of blackout per period
+        let start = now + 2 * ONE_DAY;
+        blackout
+            .schedule(start, start + 12 * ONE_HOUR, now)
+            .unwrap();
+        let start = now + 3 * ONE_DAY;
+        assert!(blackout.schedule(start, start + 5 * ONE_HOUR, now).is_err());
+        blackout.schedule(start, start + 4 * ONE_HOUR, now).unwrap();
+        assert_eq!(blackout.period_blackout(100).unwrap(), ONE_DAY);
+
+        // withdraws wait out the window
+        let end = now + ONE_DAY + 8 * ONE_HOUR;
+        blackout.validate_withdraw(now + ONE_DAY - 1).unwrap();
+        assert_eq!(
+            blackout.validate_withdraw(now + ONE_DAY),
+            Err(ErrorCode::WithdrawBlackout.into())
+        );
+        assert_eq!(blackout.active_window_end(end - 1), Some(end));
+        blackout.validate_withdraw(end).unwrap();
+
+        // ended windows only free their slot once the next period starts
+        let now = now + WITHDRAW_BLACKOUT_PERIOD;
+        for i in 0..VAULT_WITHDRAW_BLACKOUT_WINDOWS as i64 {
+            let start = now + ONE_DAY + i * ONE_HOUR;
+            blackout.schedule(start, start + ONE_HOUR, now).unwrap();
+        }
+        let now = now + 3 * ONE_DAY;
+        assert!(blackout
+            .schedule(now + ONE_DAY, now + ONE_DAY + ONE_HOUR, now)
+            .is_err());
+        let now = now + WITHDRAW_BLACKOUT_PERIOD;
+        blackout
+            .schedule(now + ONE_DAY, now + ONE_DAY + 12 * ONE_HOUR, now)
+            .unwrap();
+    }
+}
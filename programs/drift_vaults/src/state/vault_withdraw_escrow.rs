@@ -0,0 +1,202 @@
+use anchor_lang::prelude::*;
+use drift::math::casting::Cast;
+use drift::math::insurance::{
+    if_shares_to_vault_amount as depositor_shares_to_vault_amount,
+    vault_amount_to_if_shares as vault_amount_to_depositor_shares,
+};
+use drift::math::safe_math::SafeMath;
+use drift::state::spot_market::SpotMarket;
+use drift::state::user::User;
+use drift_macros::assert_no_slop;
+use static_assertions::const_assert_eq;
+
+use crate::error::ErrorCode;
+use crate::state::Size;
+use crate::validate;
+
+/// Drift sub account the vault's withdraw escrow user is created at, the vault's trading user is sub account 0.
+pub const WITHDRAW_ESCROW_SUB_ACCOUNT_ID: u16 = 1;
+
+/// A lend-only drift user, owned by the vault, that pending withdraw requests can be moved into so they keep earning
+/// lend interest without taking on the strategy's PnL.
+///
+/// The vault delegate can't trade the escrow user and its balance isn't part of the vault's equity. Escrowed amounts
+/// are tracked as escrow shares so lend interest accrues pro rata to every escrowed request.
+#[assert_no_slop]
+#[account(zero_copy(unsafe))]
+#[derive(Default, Eq, PartialEq, Debug)]
+#[repr(C)]
+pub struct VaultWithdrawEscrow {
+    /// The vault the escrow is for
+    pub vault: Pubkey,
+    /// The vault's escrow drift user, sub account [`WITHDRAW_ESCROW_SUB_ACCOUNT_ID`]
+    pub escrow_user: Pubkey,
+    /// Escrow shares outstanding across all escrowed requests
+    pub total_shares: u128,
+    /// Lifetime amount escrowed
+    pub total_deposits: u64,
+    /// Lifetime amount paid out of the escrow, including interest
+    pub total_withdraws: u64,
+    pub bump: u8,
+    pub padding: [u8; 15],
+}
+
+impl Size for VaultWithdrawEscrow {
+    const SIZE: usize = 112 + 8;
+}
+const_assert_eq!(
+    VaultWithdrawEscrow::SIZE,
+    std::mem::size_of::<VaultWithdrawEscrow>() + 8
+);
+
+impl VaultWithdrawEscrow {
+    /// The escrow user's deposit balance in the vault's spot market
+    pub fn get_escrow_balance(escrow_user: &User, spot_market: &SpotMarket) -> Result<u64> {
+        let token_amount = match escrow_user.get_spot_position(spot_market.market_index) {
+            Ok(spot_position) => spot_position.get_signed_token_amount(spot_market)?,
+            Err(_) => 0,
+        };
+
+        Ok(token_amount.max(0).cast()?)
+    }
+
+    /// Records `amount` moved into an escrow user holding `escrow_balance`, returns the escrow shares issued for it.
+    pub fn deposit(&mut self, amount: u64, escrow_balance: u64) -> Result<u128> {
+        // interest left over once every request is paid out goes to the next request escrowed
+        let shares = if self.total_shares == 0 {
+            amount.cast::<u128>()?
+        } else {
+            vault_amount_to_depositor_shares(amount, self.total_shares, escrow_balance)?
+        };
+
+        validate!(
+            shares > 0,
+            ErrorCode::InvalidVaultWithdrawEscrow,
+            "escrowed amount {} too small to issue shares",
+            amount
+        )?;
+
+        self.total_shares = self.total_shares.safe_add(shares)?;
+        self.total_deposits = self.total_deposits.saturating_add(amount);
+
+        Ok(shares)
+    }
+
+    /// Redeems `shares` against an escrow user holding `escrow_balance`, returns the amount to pay out.
+    pub fn withdraw(&mut self, shares: u128, escrow_balance: u64) -> Result<u64> {
+        let amount = depositor_shares_to_vault_amount(shares, self.total_shares, escrow_balance)?;
+
+        self.total_shares = self.total_shares.safe_sub(shares)?;
+        self.total_withdraws = self.total_withdraws.saturating_add(amount);
+
+        Ok(amount)
+    }
+}
+
+/// A vault depositor's withdraw request held in the vault's [`VaultWithdrawEscrow`] until it matures.
+#[assert_no_slop]
+#[account(zero_copy(unsafe))]
+#[derive(Default, Eq, PartialEq, Debug)]
+#[repr(C)]
+pub struct VaultDepositorWithdrawEscrow {
+    /// The vault depositor the escrowed request belongs to
+    pub vault_depositor: Pubkey,
+    /// Escrow shares of the escrowed request
+    pub shares: u128,
+    /// Amount the request paid into the escrow
+    pub amount: u64,
+    /// When the escrowed request matures and can be withdrawn
+    pub unlock_ts: i64,
+    pub bump: u8,
+    pub padding: [u8; 15],
+}
+
+impl Size for VaultDepositorWithdrawEscrow {
+    const SIZE: usize = 80 + 8;
+}
+const_assert_eq!(
+    VaultDepositorWithdrawEscrow::SIZE,
+    std::mem::size_of::<VaultDepositorWithdrawEscrow>() + 8
+);
+
+impl VaultDepositorWithdrawEscrow {
+    pub fn get_address(vault_depositor: &Pubkey) -> Pubkey {
+        Pubkey::find_program_address(
+            &[b"vault_depositor_withdraw_escrow", vault_depositor.as_ref()],
+            &crate::id(),
+        )
+        .0
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.shares > 0
+    }
+
+    pub fn escrow(&mut self, shares: u128, amount: u64, unlock_ts: i64) -> Result<()> {
+        validate!(
+            !self.is_active(),
+            ErrorCode::InvalidVaultWithdrawEscrow,
+            "withdraw request already escrowed"
+        )?;
+
+        self.shares = shares;
+        self.amount = amount;
+        self.unlock_ts = unlock_ts;
+
+        Ok(())
+    }
+
+    /// Clears the escrowed request once it has matured, returns its escrow shares.
+    pub fn release(&mut self, now: i64) -> Result<u128> {
+        validate!(
+            self.is_active(),
+            ErrorCode::InvalidVaultWithdrawEscrow,
+            "no escrowed withdraw request"
+        )?;
+
+        validate!(
+            now >= self.unlock_ts,
+            ErrorCode::CannotWithdrawBeforeRedeemPeriodEnd,
+            "escrowed withdraw request unlocks at {}",
+            self.unlock_ts
+        )?;
+
+        let shares = self.shares;
+        self.shares = 0;
+        self.amount = 0;
+
+        Ok(shares)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::state::{VaultDepositorWithdrawEscrow, VaultWithdrawEscrow};
+
+    #[test]
+    fn test_escrow_accrues_interest_pro_rata() {
+        let mut escrow = VaultWithdrawEscrow::default();
+
+        let mut first = VaultDepositorWithdrawEscrow::default();
+        let shares = escrow.deposit(1_000_000, 0).unwrap();
+        first.escrow(shares, 1_000_000, 100).unwrap();
+        assert!(first.escrow(shares, 1_000_000, 100).is_err());
+
+        // 10% lend interest accrues before the second request is escrowed
+        let mut second = VaultDepositorWithdrawEscrow::default();
+        let shares = escrow.deposit(1_100_000, 1_100_000).unwrap();
+        second.escrow(shares, 1_100_000, 200).unwrap();
+        assert_eq!(first.shares, second.shares);
+
+        assert!(first.release(99).is_err());
+        let shares = first.release(100).unwrap();
+        assert!(!first.is_active());
+        assert_eq!(escrow.withdraw(shares, 2_200_000).unwrap(), 1_100_000);
+
+        let shares = second.release(200).unwrap();
+        assert_eq!(escrow.withdraw(shares, 1_100_000).unwrap(), 1_100_000);
+        assert_eq!(escrow.total_shares, 0);
+        assert_eq!(escrow.total_deposits, 2_100_000);
+        assert_eq!(escrow.total_withdraws, 2_200_000);
+    }
+}
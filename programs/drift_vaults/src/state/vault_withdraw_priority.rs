@@ -0,0 +1,264 @@
+use std::cell::RefMut;
+
+use anchor_lang::prelude::*;
+use drift::math::casting::Cast;
+use drift::math::constants::PERCENTAGE_PRECISION;
+use drift::math::safe_math::SafeMath;
+use drift::state::user::{FuelOverflow, UserStats};
+use drift_macros::assert_no_slop;
+use static_assertions::const_assert_eq;
+
+use crate::error::ErrorCode;
+use crate::events::WithdrawPriorityBoostRecord;
+use crate::state::{Size, Vault, VaultConfig, VaultDepositor, VaultDepositorFlag, VaultProtocol};
+use crate::validate;
+
+/// Lets depositors in queue mode pay a priority fee, forfeiting part of their queued
+/// [`WithdrawRequest`](crate::state::withdraw_request::WithdrawRequest) to the remaining shareholders, to have it
+/// mature sooner.
+#[assert_no_slop]
+#[account(zero_copy(unsafe))]
+#[derive(Default, Eq, PartialEq, Debug)]
+#[repr(C)]
+pub struct VaultWithdrawPriority {
+    /// The vault priority fees are paid to
+    pub vault: Pubkey,
+    /// Priority fee, as a share of the request, that skips the whole redeem period. Smaller fees skip a
+    /// proportional part of it: PERCENTAGE_PRECISION
+    pub full_boost_fee: u32,
+    pub bump: u8,
+    pub padding: [u8; 3],
+}
+
+impl Size for VaultWithdrawPriority {
+    const SIZE: usize = 40 + 8;
+}
+const_assert_eq!(
+    VaultWithdrawPriority::SIZE,
+    std::mem::size_of::<VaultWithdrawPriority>() + 8
+);
+
+impl VaultWithdrawPriority {
+    pub fn update_full_boost_fee(&mut self, full_boost_fee: u32) -> Result<()> {
+        validate!(
+            full_boost_fee > 0 && full_boost_fee.cast::<u128>()? <= PERCENTAGE_PRECISION,
+            ErrorCode::InvalidVaultWithdrawPriority,
+            "full boost fee must be in (0, {}]",
+            PERCENTAGE_PRECISION
+        )?;
+
+        self.full_boost_fee = full_boost_fee;
+
+        Ok(())
+    }
+
+    /// Seconds of the vault's redeem period a `priority_fee` skips
+    pub fn calculate_boost(&self, vault: &Vault, priority_fee: u32) -> Result<i64> {
+        validate!(
+            priority_fee > 0 && priority_fee <= self.full_boost_fee,
+            ErrorCode::InvalidVaultWithdrawPriority,
+            "priority fee must be in (0, {}]",
+            self.full_boost_fee
+        )?;
+
+        vault
+            .redeem_period
+            .cast::<i128>()?
+            .safe_mul(priority_fee.cast()?)?
+            .safe_div(self.full_boost_fee.cast()?)?
+            .cast()
+    }
+}
+
+impl VaultDepositor {
+    /// Forfeits `priority_fee` of the queued withdraw request's shares and value to the remaining shareholders and
+    /// moves the request's ts back by the boost it buys, see [`VaultWithdrawPriority::calculate_boost`].
+    #[allow(clippy::too_many_arguments)]
+    pub fn boost_withdraw_request(
+        &mut self,
+        priority: &VaultWithdrawPriority,
+        priority_fee: u32,
+        vault_equity: u64,
+        vault: &mut Vault,
+        vault_config: &VaultConfig,
+        vault_protocol: &mut Option<RefMut<VaultProtocol>>,
+        now: i64,
+        user_stats: &UserStats,
+        fuel_overflow: &Option<AccountLoader<FuelOverflow>>,
+    ) -> Result<()> {
+        validate!(
+            priority.vault == vault.pubkey,
+            ErrorCode::InvalidVaultWithdrawPriority,
+            "VaultWithdrawPriority is not for this vault"
+        )?;
+        validate!(
+            self.has_flag(VaultDepositorFlag::QueuedWithdraw),
+            ErrorCode::WithdrawNotQueued,
+            "only queued withdraw requests can be boosted"
+        )?;
+
+        let boost = priority.calculate_boost(vault, priority_fee)?;
+
+        self.apply_rebase(vault, vault_protocol, vault_equity, now)?;
+        vault.apply_fee(vault_config, vault_protocol, vault_equity, now)?;
+        self.update_cumulative_fuel_amount(now, vault, vault_config, user_stats, fuel_overflow)?;
+
+        // the fee would go straight back to a depositor that owns the entire vault
+        validate!(
+            self.checked_vault_shares(vault)? < vault.total_shares,
+            ErrorCode::InvalidVaultWithdrawPriority,
+            "depositor owns the entire vault"
+        )?;
+
+        let request = &mut self.last_withdraw_request;
+        let shares_forfeited = request
+            .shares
+            .safe_mul(priority_fee.cast()?)?
+            .safe_div(PERCENTAGE_PRECISION)?;
+        let value_forfeited = request
+            .value
+            .cast::<u128>()?
+            .safe_mul(priority_fee.cast()?)?
+            .safe_div(PERCENTAGE_PRECISION)?
+            .cast::<u64>()?;
+        let request_ts_before = request.ts;
+
+        request.shares = request.shares.safe_sub(shares_forfeited)?;
+        request.value = request.value.safe_sub(value_forfeited)?;
+        request.ts = request.ts.safe_sub(boost)?;
+        let request_ts_after = request.ts;
+
+        self.decrease_vault_shares(shares_forfeited, vault)?;
+        vault.total_shares = vault.total_shares.safe_sub(shares_forfeited)?;
+        vault.user_shares = vault.user_shares.safe_sub(shares_forfeited)?;
+        vault.total_withdraw_requested =
+            vault.total_withdraw_requested.safe_sub(value_forfeited)?;
+
+        emit!(WithdrawPriorityBoostRecord {
+            ts: now,
+            vault: vault.pubkey,
+            depositor_authority: self.authority,
+            priority_fee,
+            shares_forfeited,
+            value_forfeited,
+            request_ts_before,
+            request_ts_after,
+        });
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anchor_lang::prelude::Pubkey;
+    use drift::math::constants::QUOTE_PRECISION_U64;
+    use drift::state::user::UserStats;
+
+    use crate::constants::ONE_DAY;
+    use crate::state::{Vault, VaultConfig, VaultDepositor, VaultWithdrawPriority};
+    use crate::WithdrawUnit;
+
+    #[test]
+    fn test_boost_withdraw_request() {
+        let now = 1000;
+        let mut vault = Vault {
+            redeem_period: ONE_DAY,
+            ..Vault::default()
+        };
+
+        let mut priority = VaultWithdrawPriority::default();
+        assert!(priority.update_full_boost_fee(0).is_err());
+        priority.update_full_boost_fee(10_000).unwrap(); // 1% skips the whole redeem period
+
+        let mut vault_equity = 0;
+        let mut vds = vec![];
+        for _ in 0..2 {
+            let mut vd = VaultDepositor::new(
+                Pubkey::default(),
+                Pubkey::new_unique(),
+                Pubkey::default(),
+                0,
+            );
+            vd.deposit(
+                100 * QUOTE_PRECISION_U64,
+                vault_equity,
+                &mut vault,
+                &VaultConfig::default(),
+                &mut None,
+                now,
+                &UserStats::default(),
+                &None,
+                0,
+            )
+            .unwrap();
+            vault_equity += 100 * QUOTE_PRECISION_U64;
+            vds.push(vd);
+        }
+
+        let vd = &mut vds[0];
+        vd.request_withdraw(
+            100 * QUOTE_PRECISION_U64,
+            WithdrawUnit::Token,
+            vault_equity,
+            &mut vault,
+            &VaultConfig::default(),
+            &mut None,
+            now,
+            &UserStats::default(),
+            &None,
+            0,
+        )
+        .unwrap();
+
+        // only queued requests can be boosted
+        assert!(vd
+            .boost_withdraw_request(
+                &priority,
+                5_000,
+                vault_equity,
+                &mut vault,
+                &VaultConfig::default(),
+                &mut None,
+                now,
+                &UserStats::default(),
+                &None,
+            )
+            .is_err());
+        vd.queue_withdraw().unwrap();
+
+        assert!(vd
+            .boost_withdraw_request(
+                &priority,
+                10_001,
+                vault_equity,
+                &mut vault,
+                &VaultConfig::default(),
+                &mut None,
+                now,
+                &UserStats::default(),
+                &None,
+            )
+            .is_err());
+
+        let total_shares_before = vault.total_shares;
+        vd.boost_withdraw_request(
+            &priority,
+            5_000, // 0.5%
+            vault_equity,
+            &mut vault,
+            &VaultConfig::default(),
+            &mut None,
+            now,
+            &UserStats::default(),
+            &None,
+        )
+        .unwrap();
+
+        assert_eq!(vd.last_withdraw_request.ts, now - ONE_DAY / 2);
+        assert_eq!(vd.last_withdraw_request.value, 99_500_000);
+        assert_eq!(vd.last_withdraw_request.shares, 99_500_000);
+        assert_eq!(vault.total_shares, total_shares_before - 500_000);
+        assert_eq!(vault.total_withdraw_requested, 99_500_000);
+    }
+}
@@ -0,0 +1,183 @@
+use anchor_lang::prelude::*;
+use drift::math::casting::Cast;
+use drift::math::safe_math::SafeMath;
+use drift_macros::assert_no_slop;
+use static_assertions::const_assert_eq;
+
+use crate::error::{ErrorCode, VaultResult};
+use crate::state::Size;
+use crate::validate;
+
+#[assert_no_slop]
+#[account(zero_copy(unsafe))]
+#[derive(Default, Eq, PartialEq, Debug)]
+#[repr(C)]
+pub struct VaultWithdrawRoute {
+    /// The vault this route is configured for
+    pub vault: Pubkey,
+    /// Spot markets depositor payouts are drawn from before the vault's spot market, highest priority first
+    pub market_indexes: [u16; 4],
+    /// Number of entries in `market_indexes` that are in use
+    pub num_markets: u8,
+    pub bump: u8,
+    pub padding: [u8; 6],
+}
+
+impl Size for VaultWithdrawRoute {
+    const SIZE: usize = 48 + 8;
+}
+const_assert_eq!(
+    VaultWithdrawRoute::SIZE,
+    std::mem::size_of::<VaultWithdrawRoute>() + 8
+);
+
+impl VaultWithdrawRoute {
+    pub fn markets(&self) -> &[u16] {
+        &self.market_indexes[..self.num_markets as usize]
+    }
+
+    pub fn update_market_indexes(
+        &mut self,
+        vault_spot_market_index: u16,
+        market_indexes: &[u16],
+    ) -> VaultResult {
+        validate!(
+            market_indexes.len() <= self.market_indexes.len(),
+            ErrorCode::InvalidWithdrawRoute,
+            "at most {} markets can be routed",
+            self.market_indexes.len()
+        )?;
+
+        for (i, market_index) in market_indexes.iter().enumerate() {
+            validate!(
+                *market_index != vault_spot_market_index,
+                ErrorCode::InvalidWithdrawRoute,
+                "vault spot market {} is always the last route",
+                vault_spot_market_index
+            )?;
+            validate!(
+                !market_indexes[..i].contains(market_index),
+                ErrorCode::InvalidWithdrawRoute,
+                "duplicate market {} in route",
+                market_index
+            )?;
+        }
+
+        msg!(
+            "Updating withdraw route {:?} -> {:?}",
+            self.markets(),
+            market_indexes
+        );
+
+        self.market_indexes = [0; 4];
+        self.market_indexes[..market_indexes.len()].copy_from_slice(market_indexes);
+        self.num_markets = market_indexes.len().cast()?;
+
+        Ok(())
+    }
+}
+
+/// Splits `remaining_amount` (in the vault's spot market token) against the balance available in a routed market.
+/// Returns the amount to withdraw from the routed market and the equivalent amount in the vault's spot market token.
+pub fn calculate_routed_withdraw_amount(
+    remaining_amount: u64,
+    vault_oracle_price: i64,
+    vault_decimals: u32,
+    available_route_amount: u64,
+    route_oracle_price: i64,
+    route_decimals: u32,
+) -> VaultResult<(u64, u64)> {
+    validate!(
+        vault_oracle_price > 0 && route_oracle_price > 0,
+        ErrorCode::InvalidWithdrawRoute,
+        "invalid oracle price, vault: {}, route: {}",
+        vault_oracle_price,
+        route_oracle_price
+    )?;
+
+    // token_a * price_a / 10^decimals_a = token_b * price_b / 10^decimals_b
+    let vault_precision = 10_u128.pow(vault_decimals);
+    let route_precision = 10_u128.pow(route_decimals);
+    let vault_oracle_price = vault_oracle_price.cast::<u128>()?;
+    let route_oracle_price = route_oracle_price.cast::<u128>()?;
+
+    let available_amount: u64 = available_route_amount
+        .cast::<u128>()?
+        .safe_mul(route_oracle_price)?
+        .safe_mul(vault_precision)?
+        .safe_div(route_precision.safe_mul(vault_oracle_price)?)?
+        .cast()?;
+
+    if available_amount <= remaining_amount {
+        return Ok((available_route_amount, available_amount));
+    }
+
+    let route_amount: u64 = remaining_amount
+        .cast::<u128>()?
+        .safe_mul(vault_oracle_price)?
+        .safe_mul(route_precision)?
+        .safe_div(vault_precision.safe_mul(route_oracle_price)?)?
+        .cast()?;
+
+    Ok((route_amount, remaining_amount))
+}
+
+#[cfg(test)]
+mod vault_withdraw_route_tests {
+    use super::*;
+    use drift::math::constants::PRICE_PRECISION_I64;
+
+    #[test]
+    fn test_update_market_indexes() {
+        let mut route = VaultWithdrawRoute::default();
+
+        route.update_market_indexes(0, &[1, 6]).unwrap();
+        assert_eq!(route.markets(), &[1, 6]);
+
+        assert!(route.update_market_indexes(0, &[1, 0]).is_err());
+        assert!(route.update_market_indexes(0, &[1, 1]).is_err());
+        assert!(route.update_market_indexes(0, &[1, 2, 3, 4, 5]).is_err());
+        assert_eq!(route.markets(), &[1, 6]);
+
+        route.update_market_indexes(0, &[]).unwrap();
+        assert_eq!(route.markets(), &[] as &[u16]);
+    }
+
+    #[test]
+    fn test_calculate_routed_withdraw_amount() {
+        // usdc vault, routed market is sol (9 decimals) at $100
+        let vault_price = PRICE_PRECISION_I64;
+        let sol_price = 100 * PRICE_PRECISION_I64;
+
+        // 2 sol available covers a $150 payout with 1.5 sol
+        let (route_amount, vault_amount) = calculate_routed_withdraw_amount(
+            150_000_000,
+            vault_price,
+            6,
+            2_000_000_000,
+            sol_price,
+            9,
+        )
+        .unwrap();
+        assert_eq!(route_amount, 1_500_000_000);
+        assert_eq!(vault_amount, 150_000_000);
+
+        // 1 sol available only covers $100 of a $150 payout
+        let (route_amount, vault_amount) = calculate_routed_withdraw_amount(
+            150_000_000,
+            vault_price,
+            6,
+            1_000_000_000,
+            sol_price,
+            9,
+        )
+        .unwrap();
+        assert_eq!(route_amount, 1_000_000_000);
+        assert_eq!(vault_amount, 100_000_000);
+
+        assert!(
+            calculate_routed_withdraw_amount(150_000_000, 0, 6, 1_000_000_000, sol_price, 9)
+                .is_err()
+        );
+    }
+}
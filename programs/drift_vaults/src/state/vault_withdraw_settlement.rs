@@ -0,0 +1,209 @@
+use anchor_lang::prelude::*;
+use drift::math::casting::Cast;
+use drift::math::safe_math::SafeMath;
+use drift_macros::assert_no_slop;
+use static_assertions::const_assert_eq;
+
+use crate::error::{ErrorCode, VaultResult};
+use crate::state::withdraw_request::WithdrawRequest;
+use crate::state::{Size, Vault};
+use crate::validate;
+
+#[assert_no_slop]
+#[account(zero_copy(unsafe))]
+#[derive(Default, Eq, PartialEq, Debug)]
+#[repr(C)]
+pub struct VaultWithdrawSettlement {
+    /// The vault whose withdraw requests are settled
+    pub vault: Pubkey,
+    /// vault.total_shares at the last settlement
+    pub total_shares: u128,
+    /// vault equity at the last settlement
+    pub equity: u64,
+    /// ts of the last settlement
+    pub ts: i64,
+    /// number of settlements cranked
+    pub settlement_id: u32,
+    /// vault.shares_base at the last settlement
+    pub shares_base: u32,
+    pub bump: u8,
+    pub padding: [u8; 15],
+}
+
+impl Size for VaultWithdrawSettlement {
+    const SIZE: usize = 96 + 8;
+}
+const_assert_eq!(
+    VaultWithdrawSettlement::SIZE,
+    std::mem::size_of::<VaultWithdrawSettlement>() + 8
+);
+
+impl VaultWithdrawSettlement {
+    pub fn settle(&mut self, vault: &Vault, vault_equity: u64, now: i64) -> Result<()> {
+        self.total_shares = vault.total_shares;
+        self.equity = vault_equity;
+        self.shares_base = vault.shares_base;
+        self.ts = now;
+        self.settlement_id = self.settlement_id.safe_add(1)?;
+
+        Ok(())
+    }
+
+    /// Whether a settlement has run since `request` was made. Until then the manager has no settled epoch to unwind
+    /// for it, so cancelling it is penalty free, see [`WithdrawRequest::calculate_shares_lost`].
+    pub fn is_request_processed(
+        &self,
+        vault: &Vault,
+        request: &WithdrawRequest,
+    ) -> VaultResult<bool> {
+        validate!(
+            self.vault == vault.pubkey,
+            ErrorCode::InvalidWithdrawSettlement,
+            "VaultWithdrawSettlement is not for this vault"
+        )?;
+
+        Ok(self.settlement_id > 0 && self.ts >= request.ts)
+    }
+
+    /// Amount to pay out for a matured withdraw request, its shares at the last settlement's share price. Every
+    /// request that matured before the settlement gets the same share price.
+    pub fn settled_withdraw_amount(&self, vault: &Vault, request: &WithdrawRequest) -> Result<u64> {
+        validate!(
+            self.vault == vault.pubkey,
+            ErrorCode::InvalidWithdrawSettlement,
+            "VaultWithdrawSettlement is not for this vault"
+        )?;
+
+        validate!(
+            self.settlement_id > 0 && self.shares_base == vault.shares_base,
+            ErrorCode::InvalidWithdrawSettlement,
+            "vault must be settled since its last rebase"
+        )?;
+
+        validate!(
+            request.ts.safe_add(vault.redeem_period)? <= self.ts,
+            ErrorCode::CannotWithdrawBeforeRedeemPeriodEnd,
+            "withdraw request matures after the last settlement at {}",
+            self.ts
+        )?;
+
+        if self.total_shares == 0 {
+            return Ok(0);
+        }
+
+        Ok(request
+            .shares
+            .safe_mul(self.equity.cast()?)?
+            .safe_div(self.total_shares)?
+            .cast()?)
+    }
+}
+
+#[cfg(test)]
+mod vault_withdraw_settlement_tests {
+    use super::*;
+
+    #[test]
+    fn test_settled_withdraw_amount() {
+        let mut vault = Vault {
+            redeem_period: 100,
+            total_shares: 1000,
+            ..Vault::default()
+        };
+        let mut settlement = VaultWithdrawSettlement::default();
+        let request = WithdrawRequest {
+            shares: 100,
+            value: 100,
+            ts: 1000,
+        };
+
+        // never settled
+        assert!(settlement
+            .settled_withdraw_amount(&vault, &request)
+            .is_err());
+
+        // settled before the request matured
+        settlement.settle(&vault, 800, 1050).unwrap();
+        assert!(settlement
+            .settled_withdraw_amount(&vault, &request)
+            .is_err());
+
+        // 20% drawdown marked at settlement
+        settlement.settle(&vault, 800, 1100).unwrap();
+        assert_eq!(settlement.settlement_id, 2);
+        assert_eq!(
+            settlement
+                .settled_withdraw_amount(&vault, &request)
+                .unwrap(),
+            80
+        );
+
+        // fee shares minted since the settlement keep the same share price
+        vault.total_shares = 1100;
+        assert_eq!(
+            settlement
+                .settled_withdraw_amount(&vault, &request)
+                .unwrap(),
+            80
+        );
+
+        // a rebase requires a new settlement
+        vault.shares_base = 1;
+        assert!(settlement
+            .settled_withdraw_amount(&vault, &request)
+            .is_err());
+    }
+
+    #[test]
+    fn test_cancel_before_settlement_is_penalty_free() {
+        let vault = Vault {
+            redeem_period: 100,
+            total_shares: 1000,
+            ..Vault::default()
+        };
+        let mut settlement = VaultWithdrawSettlement::default();
+        let request = WithdrawRequest {
+            shares: 100,
+            value: 100,
+            ts: 1000,
+        };
+
+        // the vault is up 50% since the request
+        assert_eq!(
+            request.calculate_shares_lost(&vault, 1500, None).unwrap(),
+            36
+        );
+        assert_eq!(
+            request
+                .calculate_shares_lost(&vault, 1500, Some(&settlement))
+                .unwrap(),
+            0
+        );
+
+        // a settlement before the request doesn't count
+        settlement.settle(&vault, 1000, 999).unwrap();
+        assert!(!settlement.is_request_processed(&vault, &request).unwrap());
+        assert_eq!(
+            request
+                .calculate_shares_lost(&vault, 1500, Some(&settlement))
+                .unwrap(),
+            0
+        );
+
+        settlement.settle(&vault, 1200, 1050).unwrap();
+        assert_eq!(
+            request
+                .calculate_shares_lost(&vault, 1500, Some(&settlement))
+                .unwrap(),
+            36
+        );
+
+        let other_vault = Vault {
+            pubkey: Pubkey::new_unique(),
+            ..vault
+        };
+        assert!(request
+            .calculate_shares_lost(&other_vault, 1500, Some(&settlement))
+            .is_err());
+    }
+}
@@ -0,0 +1,205 @@
+use anchor_lang::prelude::*;
+use drift::math::casting::Cast;
+use drift::math::safe_math::SafeMath;
+use drift_macros::assert_no_slop;
+use static_assertions::const_assert_eq;
+
+use crate::constants::{ONE_DAY, ONE_HOUR};
+use crate::error::ErrorCode;
+use crate::state::withdraw_request::WithdrawRequest;
+use crate::state::Size;
+use crate::validate;
+
+pub const MIN_WITHDRAW_STREAM_DURATION: i64 = ONE_HOUR;
+pub const MAX_WITHDRAW_STREAM_DURATION: i64 = ONE_DAY * 30;
+
+/// Releases a matured withdraw request linearly over `duration` instead of as a lump sum.
+#[assert_no_slop]
+#[account(zero_copy(unsafe))]
+#[derive(Default, Eq, PartialEq, Debug)]
+#[repr(C)]
+pub struct VaultWithdrawStream {
+    /// The vault depositor streaming its withdraw request
+    pub vault_depositor: Pubkey,
+    /// shares of the withdraw request being streamed
+    pub shares: u128,
+    /// shares already claimed
+    pub claimed_shares: u128,
+    /// value of the withdraw request being streamed
+    pub value: u64,
+    /// value already claimed
+    pub claimed_value: u64,
+    /// ts of the streamed withdraw request
+    pub request_ts: i64,
+    pub start_ts: i64,
+    pub duration: i64,
+    /// the vault depositor's shares base when the stream started
+    pub shares_base: u32,
+    pub bump: u8,
+    pub padding: [u8; 3],
+}
+
+impl Size for VaultWithdrawStream {
+    const SIZE: usize = 112 + 8;
+}
+const_assert_eq!(
+    VaultWithdrawStream::SIZE,
+    std::mem::size_of::<VaultWithdrawStream>() + 8
+);
+
+impl VaultWithdrawStream {
+    pub fn get_address(vault_depositor: &Pubkey) -> Pubkey {
+        Pubkey::find_program_address(
+            &[b"vault_withdraw_stream", vault_depositor.as_ref()],
+            &crate::id(),
+        )
+        .0
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.claimed_shares < self.shares
+    }
+
+    pub fn start(
+        &mut self,
+        request: &WithdrawRequest,
+        shares_base: u32,
+        duration: i64,
+        now: i64,
+    ) -> Result<()> {
+        validate!(
+            !self.is_active(),
+            ErrorCode::InvalidWithdrawStream,
+            "withdraw stream already active"
+        )?;
+
+        validate!(
+            (MIN_WITHDRAW_STREAM_DURATION..=MAX_WITHDRAW_STREAM_DURATION).contains(&duration),
+            ErrorCode::InvalidWithdrawStream,
+            "stream duration must be between {} and {}",
+            MIN_WITHDRAW_STREAM_DURATION,
+            MAX_WITHDRAW_STREAM_DURATION
+        )?;
+
+        self.shares = request.shares;
+        self.value = request.value;
+        self.claimed_shares = 0;
+        self.claimed_value = 0;
+        self.request_ts = request.ts;
+        self.shares_base = shares_base;
+        self.start_ts = now;
+        self.duration = duration;
+
+        Ok(())
+    }
+
+    /// The part of the stream released since the last claim as a withdraw request, the vault depositor's pending
+    /// request must be what's left of the stream.
+    pub fn next_tranche(
+        &self,
+        request: &WithdrawRequest,
+        shares_base: u32,
+        now: i64,
+    ) -> Result<WithdrawRequest> {
+        validate!(
+            self.is_active()
+                && shares_base == self.shares_base
+                && request.shares == self.shares.safe_sub(self.claimed_shares)?
+                && request.value == self.value.safe_sub(self.claimed_value)?,
+            ErrorCode::InvalidWithdrawStream,
+            "withdraw request no longer matches the stream"
+        )?;
+
+        let elapsed = now.safe_sub(self.start_ts)?.clamp(0, self.duration);
+        let vested_shares = self
+            .shares
+            .safe_mul(elapsed.cast()?)?
+            .safe_div(self.duration.cast()?)?;
+        let vested_value: u64 = self
+            .value
+            .cast::<u128>()?
+            .safe_mul(elapsed.cast()?)?
+            .safe_div(self.duration.cast()?)?
+            .cast()?;
+
+        let tranche = WithdrawRequest {
+            shares: vested_shares.safe_sub(self.claimed_shares)?,
+            value: vested_value.safe_sub(self.claimed_value)?,
+            ts: self.request_ts,
+        };
+
+        validate!(
+            tranche.shares > 0,
+            ErrorCode::InvalidWithdrawStream,
+            "nothing released yet"
+        )?;
+
+        Ok(tranche)
+    }
+
+    /// Records a claimed tranche, returns the withdraw request left to stream.
+    pub fn claim(&mut self, tranche: &WithdrawRequest) -> Result<WithdrawRequest> {
+        self.claimed_shares = self.claimed_shares.safe_add(tranche.shares)?;
+        self.claimed_value = self.claimed_value.safe_add(tranche.value)?;
+
+        Ok(WithdrawRequest {
+            shares: self.shares.safe_sub(self.claimed_shares)?,
+            value: self.value.safe_sub(self.claimed_value)?,
+            ts: self.request_ts,
+        })
+    }
+}
+
+#[cfg(test)]
+mod vault_withdraw_stream_tests {
+    use super::*;
+
+    #[test]
+    fn test_stream() {
+        let now = 1_000_000;
+        let mut stream = VaultWithdrawStream::default();
+        let request = WithdrawRequest {
+            shares: 1_000,
+            value: 500,
+            ts: now - ONE_DAY,
+        };
+
+        assert!(stream.start(&request, 0, 60, now).is_err());
+        stream.start(&request, 0, ONE_DAY, now).unwrap();
+        assert!(stream.is_active());
+        assert!(stream.start(&request, 0, ONE_DAY, now).is_err());
+
+        // nothing vested yet
+        assert!(stream.next_tranche(&request, 0, now).is_err());
+
+        let tranche = stream.next_tranche(&request, 0, now + ONE_DAY / 4).unwrap();
+        assert_eq!(tranche.shares, 250);
+        assert_eq!(tranche.value, 125);
+        assert_eq!(tranche.ts, request.ts);
+
+        let remaining = stream.claim(&tranche).unwrap();
+        assert_eq!(remaining.shares, 750);
+        assert_eq!(remaining.value, 375);
+
+        // stale request or rebase
+        assert!(stream.next_tranche(&request, 0, now + ONE_DAY).is_err());
+        assert!(stream.next_tranche(&remaining, 1, now + ONE_DAY).is_err());
+
+        // past the end everything left is released
+        let tranche = stream
+            .next_tranche(&remaining, 0, now + ONE_DAY * 2)
+            .unwrap();
+        assert_eq!(tranche.shares, 750);
+        assert_eq!(tranche.value, 375);
+        let remaining = stream.claim(&tranche).unwrap();
+        assert_eq!(
+            remaining,
+            WithdrawRequest {
+                shares: 0,
+                value: 0,
+                ts: request.ts
+            }
+        );
+        assert!(!stream.is_active());
+    }
+}
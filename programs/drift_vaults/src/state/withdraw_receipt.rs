@@ -0,0 +1,111 @@
+use anchor_lang::prelude::*;
+use drift_macros::assert_no_slop;
+use static_assertions::const_assert_eq;
+
+use crate::error::ErrorCode;
+use crate::state::withdraw_request::WithdrawRequest;
+use crate::state::Size;
+use crate::validate;
+
+/// Commits a vault depositor's withdraw request to a cancellation deadline, after which the request can't be
+/// cancelled, so the manager can start unwinding positions for a large exit.
+///
+/// The receipt only applies to the request it was set for, matched by the request's `ts` and `shares`. Once any
+/// depositor of a vault has a receipt the vault has [`VaultFlag::WithdrawReceipts`](crate::state::VaultFlag) set
+/// and cancels must pass the depositor's receipt address in remaining accounts, initialized or not.
+#[assert_no_slop]
+#[account(zero_copy(unsafe))]
+#[derive(Default, Eq, PartialEq, Debug)]
+#[repr(C)]
+pub struct WithdrawReceipt {
+    /// The vault depositor the receipt is for
+    pub vault_depositor: Pubkey,
+    /// `shares` of the withdraw request the deadline applies to
+    pub request_shares: u128,
+    /// `ts` of the withdraw request the deadline applies to
+    pub request_ts: i64,
+    /// The request can't be cancelled from this ts on
+    pub cancel_deadline_ts: i64,
+    pub bump: u8,
+    pub padding: [u8; 7],
+}
+
+impl Size for WithdrawReceipt {
+    const SIZE: usize = 72 + 8;
+}
+const_assert_eq!(
+    WithdrawReceipt::SIZE,
+    std::mem::size_of::<WithdrawReceipt>() + 8
+);
+
+impl WithdrawReceipt {
+    pub fn get_address(vault_depositor: &Pubkey) -> Pubkey {
+        Pubkey::find_program_address(
+            &[b"withdraw_receipt", vault_depositor.as_ref()],
+            &crate::id(),
+        )
+        .0
+    }
+
+    pub fn set(
+        &mut self,
+        request: &WithdrawRequest,
+        cancel_deadline_ts: i64,
+        now: i64,
+    ) -> Result<()> {
+        validate!(
+            cancel_deadline_ts >= now,
+            ErrorCode::InvalidWithdrawReceipt,
+            "cancel deadline {} already passed",
+            cancel_deadline_ts
+        )?;
+
+        self.request_shares = request.shares;
+        self.request_ts = request.ts;
+        self.cancel_deadline_ts = cancel_deadline_ts;
+
+        Ok(())
+    }
+
+    pub fn validate_cancel(&self, request: &WithdrawRequest, now: i64) -> Result<()> {
+        let is_for_request = self.request_ts == request.ts && self.request_shares == request.shares;
+
+        validate!(
+            !is_for_request || now < self.cancel_deadline_ts,
+            ErrorCode::WithdrawCancelDeadlinePassed,
+            "withdraw request can't be cancelled after {}",
+            self.cancel_deadline_ts
+        )?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod withdraw_receipt_tests {
+    use super::*;
+
+    #[test]
+    fn test_withdraw_receipt_cancel_deadline() {
+        let now = 1_000_000;
+        let request = WithdrawRequest {
+            shares: 100,
+            value: 100,
+            ts: now,
+        };
+        let mut receipt = WithdrawReceipt::default();
+
+        assert!(receipt.set(&request, now - 1, now).is_err());
+        receipt.set(&request, now + 10, now).unwrap();
+
+        receipt.validate_cancel(&request, now + 9).unwrap();
+        assert!(receipt.validate_cancel(&request, now + 10).is_err());
+
+        // a later request isn't bound by the deadline
+        let next_request = WithdrawRequest {
+            ts: now + 20,
+            ..request
+        };
+        receipt.validate_cancel(&next_request, now + 20).unwrap();
+    }
+}
@@ -1,5 +1,7 @@
 use crate::error::ErrorCode;
 use crate::error::VaultResult;
+use crate::events::{WithdrawPayoutDivergence, WithdrawPayoutRecord};
+use crate::state::VaultWithdrawSettlement;
 use crate::{validate, Vault};
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program;
@@ -22,7 +24,8 @@ pub struct WithdrawRequest {
     pub shares: u128,
     /// requested value (in vault spot_market_index) of shares for withdraw
     pub value: u64,
-    /// request ts of vault withdraw
+    /// request ts of vault withdraw. For depositor requests over the vault's large withdraw threshold this is
+    /// pushed forward by the large withdraw notice period, so the request matures at ts + redeem_period
     pub ts: i64,
 }
 
@@ -36,7 +39,55 @@ impl WithdrawRequest {
         Ok(())
     }
 
-    pub fn calculate_shares_lost(&self, vault: &Vault, vault_equity: u64) -> VaultResult<u128> {
+    /// Payout of the request when its shares are currently worth `current_value`, call before `reset`
+    pub fn payout_record(
+        &self,
+        vault: Pubkey,
+        authority: Pubkey,
+        current_value: u64,
+        now: i64,
+    ) -> WithdrawPayoutRecord {
+        let payout = current_value.min(self.value);
+        let cause = if current_value < self.value {
+            WithdrawPayoutDivergence::Losses
+        } else if current_value > self.value {
+            WithdrawPayoutDivergence::GainsCapped
+        } else {
+            WithdrawPayoutDivergence::None
+        };
+
+        WithdrawPayoutRecord {
+            ts: now,
+            vault,
+            authority,
+            requested_value: self.value,
+            current_value,
+            payout,
+            shortfall: self.value.saturating_sub(payout),
+            capped_gains: current_value.saturating_sub(payout),
+            cause,
+        }
+    }
+
+    /// Shares forfeited on cancel, the gain since the request was made. On vaults that settle withdraws the
+    /// `withdraw_settlement` must be passed, cancelling is penalty free until a settlement has run since the request.
+    pub fn calculate_shares_lost(
+        &self,
+        vault: &Vault,
+        vault_equity: u64,
+        withdraw_settlement: Option<&VaultWithdrawSettlement>,
+    ) -> VaultResult<u128> {
+        // nothing to forfeit when the vault has no equity to price the request against
+        if vault_equity == 0 {
+            return Ok(0);
+        }
+
+        if let Some(withdraw_settlement) = withdraw_settlement {
+            if !withdraw_settlement.is_request_processed(vault, self)? {
+                return Ok(0);
+            }
+        }
+
         let n_shares = self.shares;
 
         let amount = depositor_shares_to_vault_amount(n_shares, vault.total_shares, vault_equity)?;
@@ -72,8 +123,9 @@ impl WithdrawRequest {
         vault_equity: u64,
         now: i64,
     ) -> VaultResult {
+        // a request made at zero vault equity has no value but is still pending
         validate!(
-            self.value == 0,
+            !self.pending(),
             ErrorCode::VaultWithdrawRequestInProgress,
             "withdraw request is already in progress"
         )?;
@@ -112,6 +164,18 @@ impl WithdrawRequest {
         Ok(())
     }
 
+    /// A matured request expires once it has gone unexecuted for another full redeem period, i.e. at
+    /// `ts + 2 * redeem_period`. Requests never expire on vaults without a redeem period.
+    pub fn is_expired(&self, vault: &Vault, now: i64) -> VaultResult<bool> {
+        if !self.pending() || vault.redeem_period == 0 {
+            return Ok(false);
+        }
+
+        let expiry_ts = self.ts.safe_add(vault.redeem_period.safe_mul(2)?)?;
+
+        Ok(now >= expiry_ts)
+    }
+
     pub fn check_redeem_period_finished(&self, vault: &Vault, now: i64) -> VaultResult {
         let time_since_withdraw_request = now.safe_sub(self.ts)?;
 
@@ -3,6 +3,7 @@ mod vault_fcn {
     use std::str::FromStr;
 
     use crate::state::traits::VaultDepositorBase;
+    use crate::state::VaultConfig;
     use crate::test_utils::create_account_info;
     use crate::withdraw_request::WithdrawRequest;
     use crate::{assert_eq_within, Vault, VaultDepositor, WithdrawUnit};
@@ -24,7 +25,14 @@ mod vault_fcn {
         let mut vault_equity = 0;
         let amount = 100_000_000; // $100
         vault
-            .manager_deposit(&mut vp, amount, vault_equity, now, 0)
+            .manager_deposit(
+                &VaultConfig::default(),
+                &mut vp,
+                amount,
+                vault_equity,
+                now,
+                0,
+            )
             .unwrap();
         vault_equity += amount;
         vault_equity -= 1;
@@ -37,6 +45,7 @@ mod vault_fcn {
 
         vault
             .manager_request_withdraw(
+                &VaultConfig::default(),
                 &mut vp,
                 amount - 1,
                 WithdrawUnit::Token,
@@ -53,12 +62,12 @@ mod vault_fcn {
         assert_eq!(vault.manager_total_withdraws, 0);
 
         let err = vault
-            .manager_withdraw(&mut vp, vault_equity, now + 50, 0)
+            .manager_withdraw(&VaultConfig::default(), &mut vp, vault_equity, now + 50, 0)
             .is_err();
         assert!(err);
 
         let withdraw = vault
-            .manager_withdraw(&mut vp, vault_equity, now + 60, 0)
+            .manager_withdraw(&VaultConfig::default(), &mut vp, vault_equity, now + 60, 0)
             .unwrap();
         assert_eq!(vault.user_shares, 0);
         assert_eq!(vault.total_shares, 0);
@@ -86,6 +95,7 @@ mod vault_fcn {
             amount,
             vault_equity,
             &mut vault,
+            &VaultConfig::default(),
             &mut vp,
             now,
             &UserStats::default(),
@@ -104,7 +114,12 @@ mod vault_fcn {
         assert_eq!(user_eq_before, 100000000);
 
         vault
-            .apply_fee(&mut vp, vault_equity, now + ONE_YEAR as i64)
+            .apply_fee(
+                &VaultConfig::default(),
+                &mut vp,
+                vault_equity,
+                now + ONE_YEAR as i64,
+            )
             .unwrap();
         assert_eq!(vault.user_shares, 100000000);
         assert_eq!(vault.total_shares, 200200200);
@@ -136,6 +151,7 @@ mod vault_fcn {
             amount,
             vault_equity,
             &mut vault,
+            &VaultConfig::default(),
             &mut vp,
             now,
             &UserStats::default(),
@@ -150,7 +166,12 @@ mod vault_fcn {
         vault_equity += amount;
 
         vault
-            .apply_fee(&mut vp, vault_equity, now + ONE_YEAR as i64)
+            .apply_fee(
+                &VaultConfig::default(),
+                &mut vp,
+                vault_equity,
+                now + ONE_YEAR as i64,
+            )
             .unwrap();
         assert_eq!(vault.user_shares, 10);
         assert_eq!(vault.total_shares, 2000000000);
@@ -183,6 +204,7 @@ mod vault_fcn {
             amount,
             vault_equity,
             &mut vault,
+            &VaultConfig::default(),
             &mut vp,
             now,
             &UserStats::default(),
@@ -197,10 +219,14 @@ mod vault_fcn {
         vault_equity += amount;
 
         while now < ONE_YEAR as i64 {
-            vault.apply_fee(&mut vp, vault_equity, now).unwrap();
+            vault
+                .apply_fee(&VaultConfig::default(), &mut vp, vault_equity, now)
+                .unwrap();
             now += 60 * 60 * 24 * 7; // every week
         }
-        vault.apply_fee(&mut vp, vault_equity, now).unwrap();
+        vault
+            .apply_fee(&VaultConfig::default(), &mut vp, vault_equity, now)
+            .unwrap();
 
         let vd_amount_left =
             depositor_shares_to_vault_amount(vault.user_shares, vault.total_shares, vault_equity)
@@ -219,7 +245,14 @@ mod vault_fcn {
         let mut vault_equity: u64 = 0;
         let amount: u64 = 100 * QUOTE_PRECISION_U64;
         vault
-            .manager_deposit(&mut vp, amount, vault_equity, now, 0)
+            .manager_deposit(
+                &VaultConfig::default(),
+                &mut vp,
+                amount,
+                vault_equity,
+                now,
+                0,
+            )
             .unwrap();
         vault_equity += amount;
 
@@ -237,11 +270,19 @@ mod vault_fcn {
         assert_eq!(vault_manager_amount, 100000000);
 
         vault
-            .manager_request_withdraw(&mut vp, amount, WithdrawUnit::Token, vault_equity, now, 0)
+            .manager_request_withdraw(
+                &VaultConfig::default(),
+                &mut vp,
+                amount,
+                WithdrawUnit::Token,
+                vault_equity,
+                now,
+                0,
+            )
             .unwrap();
 
         let withdrew = vault
-            .manager_withdraw(&mut vp, vault_equity, now, 0)
+            .manager_withdraw(&VaultConfig::default(), &mut vp, vault_equity, now, 0)
             .unwrap();
         assert_eq!(amount, withdrew);
         assert_eq!(vault.user_shares, 0);
@@ -275,6 +316,7 @@ mod vault_fcn {
             amount,
             vault_equity,
             &mut vault,
+            &VaultConfig::default(),
             &mut vp,
             now,
             &UserStats::default(),
@@ -293,7 +335,9 @@ mod vault_fcn {
         assert_eq!(user_eq_before, 100000000);
 
         // one second since inception
-        vault.apply_fee(&mut vp, vault_equity, now + 1_i64).unwrap();
+        vault
+            .apply_fee(&VaultConfig::default(), &mut vp, vault_equity, now + 1_i64)
+            .unwrap();
         assert_eq!(vault.user_shares, 100000000);
         assert_eq!(vault.total_shares, 199986200);
 
@@ -304,7 +348,7 @@ mod vault_fcn {
 
         // one minute since inception
         vault
-            .apply_fee(&mut vp, vault_equity, now + 60_i64)
+            .apply_fee(&VaultConfig::default(), &mut vp, vault_equity, now + 60_i64)
             .unwrap();
         assert_eq!(vault.user_shares, 100000000);
         assert_eq!(vault.total_shares, 199185855);
@@ -316,7 +360,12 @@ mod vault_fcn {
 
         // one year since inception
         vault
-            .apply_fee(&mut vp, vault_equity, now + ONE_YEAR as i64)
+            .apply_fee(
+                &VaultConfig::default(),
+                &mut vp,
+                vault_equity,
+                now + ONE_YEAR as i64,
+            )
             .unwrap();
         assert_eq!(vault.user_shares, 100000000);
         assert_eq!(vault.total_shares, 100000000);
@@ -342,7 +391,14 @@ mod vault_fcn {
         let amount: u64 = 100 * QUOTE_PRECISION_U64;
         now += 100000;
         vault
-            .manager_deposit(&mut vp, amount, vault_equity, now, 0)
+            .manager_deposit(
+                &VaultConfig::default(),
+                &mut vp,
+                amount,
+                vault_equity,
+                now,
+                0,
+            )
             .unwrap();
 
         assert_eq!(vault.user_shares, 0);
@@ -352,10 +408,18 @@ mod vault_fcn {
 
         now += 100000;
         vault
-            .manager_request_withdraw(&mut vp, amount, WithdrawUnit::Token, vault_equity, now, 0)
+            .manager_request_withdraw(
+                &VaultConfig::default(),
+                &mut vp,
+                amount,
+                WithdrawUnit::Token,
+                vault_equity,
+                now,
+                0,
+            )
             .unwrap();
         let withdrew = vault
-            .manager_withdraw(&mut vp, vault_equity, now, 0)
+            .manager_withdraw(&VaultConfig::default(), &mut vp, vault_equity, now, 0)
             .unwrap();
         assert_eq!(withdrew, amount);
     }
@@ -372,7 +436,14 @@ mod vault_fcn {
         let mut vault_equity: u64 = 0;
         let amount: u64 = 100 * QUOTE_PRECISION_U64;
         vault
-            .manager_deposit(&mut vp, amount, vault_equity, now, 0)
+            .manager_deposit(
+                &VaultConfig::default(),
+                &mut vp,
+                amount,
+                vault_equity,
+                now,
+                0,
+            )
             .unwrap();
         vault_equity += amount;
 
@@ -386,6 +457,7 @@ mod vault_fcn {
             amount * 20,
             vault_equity,
             &mut vault,
+            &VaultConfig::default(),
             &mut vp,
             now,
             &UserStats::default(),
@@ -403,13 +475,16 @@ mod vault_fcn {
         vd.apply_profit_share(
             vault_equity,
             &mut vault,
+            &VaultConfig::default(),
             &mut vp,
             now,
             &UserStats::default(),
             &None,
         )
         .unwrap();
-        vault.apply_fee(&mut vp, vault_equity, now).unwrap();
+        vault
+            .apply_fee(&VaultConfig::default(), &mut vp, vault_equity, now)
+            .unwrap();
 
         let vault_manager_amount = depositor_shares_to_vault_amount(
             vault.total_shares - vault.user_shares,
@@ -420,11 +495,19 @@ mod vault_fcn {
 
         assert_eq!(vault_manager_amount, 100000000);
         vault
-            .manager_request_withdraw(&mut vp, amount, WithdrawUnit::Token, vault_equity, now, 0)
+            .manager_request_withdraw(
+                &VaultConfig::default(),
+                &mut vp,
+                amount,
+                WithdrawUnit::Token,
+                vault_equity,
+                now,
+                0,
+            )
             .unwrap();
 
         let withdrew = vault
-            .manager_withdraw(&mut vp, vault_equity, now, 0)
+            .manager_withdraw(&VaultConfig::default(), &mut vp, vault_equity, now, 0)
             .unwrap();
         assert_eq!(amount, withdrew);
         assert_eq!(vault.user_shares, 2000000000);
@@ -452,7 +535,14 @@ mod vault_fcn {
         let mut vault_equity: u64 = 0;
         let amount: u64 = 100 * QUOTE_PRECISION_U64;
         vault
-            .manager_deposit(&mut vp, amount, vault_equity, now, 0)
+            .manager_deposit(
+                &VaultConfig::default(),
+                &mut vp,
+                amount,
+                vault_equity,
+                now,
+                0,
+            )
             .unwrap();
         vault_equity += amount;
 
@@ -466,6 +556,7 @@ mod vault_fcn {
             amount * 20,
             vault_equity,
             &mut vault,
+            &VaultConfig::default(),
             &mut vp,
             now,
             &UserStats::default(),
@@ -485,13 +576,16 @@ mod vault_fcn {
             vd.apply_profit_share(
                 vault_equity,
                 &mut vault,
+                &VaultConfig::default(),
                 &mut vp,
                 now,
                 &UserStats::default(),
                 &None,
             )
             .unwrap();
-            vault.apply_fee(&mut vp, vault_equity, now).unwrap();
+            vault
+                .apply_fee(&VaultConfig::default(), &mut vp, vault_equity, now)
+                .unwrap();
             // crate::msg!("vault last ts: {} vs {}", vault.last_fee_update_ts, now);
             cnt += 1;
         }
@@ -507,11 +601,19 @@ mod vault_fcn {
 
         assert_eq!(vault_manager_amount, 100001999);
         vault
-            .manager_request_withdraw(&mut vp, amount, WithdrawUnit::Token, vault_equity, now, 0)
+            .manager_request_withdraw(
+                &VaultConfig::default(),
+                &mut vp,
+                amount,
+                WithdrawUnit::Token,
+                vault_equity,
+                now,
+                0,
+            )
             .unwrap();
 
         let withdrew = vault
-            .manager_withdraw(&mut vp, vault_equity, now, 0)
+            .manager_withdraw(&VaultConfig::default(), &mut vp, vault_equity, now, 0)
             .unwrap();
         assert_eq!(amount, withdrew);
         assert_eq!(vault.user_shares, 2000000000);
@@ -550,7 +652,14 @@ mod vault_fcn {
         let mut vault_equity: u64 = 0;
         let amount: u64 = 100 * QUOTE_PRECISION_U64;
         vault
-            .manager_deposit(&mut vp, amount, vault_equity, now, 0)
+            .manager_deposit(
+                &VaultConfig::default(),
+                &mut vp,
+                amount,
+                vault_equity,
+                now,
+                0,
+            )
             .unwrap();
         vault_equity += amount;
 
@@ -564,6 +673,7 @@ mod vault_fcn {
             amount * 20,
             vault_equity,
             &mut vault,
+            &VaultConfig::default(),
             &mut vp,
             now,
             &UserStats::default(),
@@ -589,13 +699,16 @@ mod vault_fcn {
             vd.apply_profit_share(
                 vault_equity,
                 &mut vault,
+                &VaultConfig::default(),
                 &mut vp,
                 now,
                 &UserStats::default(),
                 &None,
             )
             .unwrap();
-            vault.apply_fee(&mut vp, vault_equity, now).unwrap();
+            vault
+                .apply_fee(&VaultConfig::default(), &mut vp, vault_equity, now)
+                .unwrap();
             // crate::msg!("vault last ts: {} vs {}", vault.last_fee_update_ts, now);
             cnt += 1;
         }
@@ -617,12 +730,20 @@ mod vault_fcn {
         assert_eq!(vault_manager_amount, 300002849); //$300??
 
         vault
-            .manager_request_withdraw(&mut vp, amount, WithdrawUnit::Token, vault_equity, now, 0)
+            .manager_request_withdraw(
+                &VaultConfig::default(),
+                &mut vp,
+                amount,
+                WithdrawUnit::Token,
+                vault_equity,
+                now,
+                0,
+            )
             .unwrap();
         assert_eq!(amount, vault.last_manager_withdraw_request.value);
 
         let withdrew = vault
-            .manager_withdraw(&mut vp, vault_equity, now, 0)
+            .manager_withdraw(&VaultConfig::default(), &mut vp, vault_equity, now, 0)
             .unwrap();
         assert_eq!(amount - 1, withdrew); // todo: slight round out of favor
         assert_eq!(vault.user_shares, 1900000000);
@@ -671,6 +792,7 @@ mod vault_fcn {
             deposit_amount,
             vault_equity,
             vault,
+            &VaultConfig::default(),
             &mut None,
             now,
             &UserStats::default(),
@@ -696,6 +818,7 @@ mod vault_fcn {
             WithdrawUnit::Shares,
             vault_equity,
             vault,
+            &VaultConfig::default(),
             &mut None,
             now,
             &UserStats::default(),
@@ -721,6 +844,7 @@ mod vault_fcn {
             .withdraw(
                 vault_equity,
                 vault,
+                &VaultConfig::default(),
                 &mut None,
                 now,
                 &UserStats::default(),
@@ -755,6 +879,7 @@ mod vault_fcn {
             deposit_amount,
             vault_equity,
             vault,
+            &VaultConfig::default(),
             &mut vp,
             now,
             &UserStats::default(),
@@ -768,7 +893,7 @@ mod vault_fcn {
         assert_eq!(vault.total_shares, deposit_amount as u128);
         assert_eq!(vault.shares_base, 0);
         assert_eq!(vd.checked_vault_shares(vault).unwrap(), vault.user_shares);
-        assert_eq!(vd.vault_shares_base, vault.shares_base);
+        assert_eq!(vd.get_vault_shares_base(), vault.shares_base);
         vault_equity += deposit_amount;
 
         // down 99.9%
@@ -781,6 +906,7 @@ mod vault_fcn {
             WithdrawUnit::Shares,
             vault_equity,
             vault,
+            &VaultConfig::default(),
             &mut vp,
             now,
             &UserStats::default(),
@@ -811,6 +937,7 @@ mod vault_fcn {
             .withdraw(
                 vault_equity,
                 vault,
+                &VaultConfig::default(),
                 &mut vp,
                 now,
                 &UserStats::default(),
@@ -849,6 +976,7 @@ mod vault_fcn {
             deposit_amount,
             vault_equity,
             vault,
+            &VaultConfig::default(),
             &mut vp,
             now,
             &UserStats::default(),
@@ -862,7 +990,7 @@ mod vault_fcn {
         assert_eq!(vault.total_shares, deposit_amount as u128);
         assert_eq!(vault.shares_base, 0);
         assert_eq!(vd.checked_vault_shares(vault).unwrap(), vault.user_shares);
-        assert_eq!(vd.vault_shares_base, vault.shares_base);
+        assert_eq!(vd.get_vault_shares_base(), vault.shares_base);
         vault_equity += deposit_amount;
 
         vd.request_withdraw(
@@ -870,6 +998,7 @@ mod vault_fcn {
             WithdrawUnit::Shares,
             vault_equity,
             vault,
+            &VaultConfig::default(),
             &mut vp,
             now,
             &UserStats::default(),
@@ -900,6 +1029,7 @@ mod vault_fcn {
             .withdraw(
                 vault_equity,
                 vault,
+                &VaultConfig::default(),
                 &mut None,
                 now,
                 &UserStats::default(),
@@ -933,6 +1063,7 @@ mod vault_fcn {
             deposit_amount,
             vault_equity,
             &mut vault,
+            &VaultConfig::default(),
             &mut vp,
             now,
             &UserStats::default(),
@@ -957,13 +1088,16 @@ mod vault_fcn {
         vd.apply_profit_share(
             vault_equity,
             &mut vault,
+            &VaultConfig::default(),
             &mut vp,
             now,
             &UserStats::default(),
             &None,
         )
         .unwrap();
-        vault.apply_fee(&mut vp, vault_equity, now).unwrap();
+        vault
+            .apply_fee(&VaultConfig::default(), &mut vp, vault_equity, now)
+            .unwrap();
 
         let depositor_amount_in_profit = depositor_shares_to_vault_amount(
             vd.checked_vault_shares(&vault).unwrap(),
@@ -984,13 +1118,16 @@ mod vault_fcn {
         vd.apply_profit_share(
             vault_equity,
             &mut vault,
+            &VaultConfig::default(),
             &mut vp,
             now,
             &UserStats::default(),
             &None,
         )
         .unwrap();
-        vault.apply_fee(&mut vp, vault_equity, now).unwrap();
+        vault
+            .apply_fee(&VaultConfig::default(), &mut vp, vault_equity, now)
+            .unwrap();
 
         let depositor_amount_in_drawdown = depositor_shares_to_vault_amount(
             vd.checked_vault_shares(&vault).unwrap(),
@@ -1012,13 +1149,16 @@ mod vault_fcn {
         vd.apply_profit_share(
             vault_equity,
             &mut vault,
+            &VaultConfig::default(),
             &mut vp,
             now,
             &UserStats::default(),
             &None,
         )
         .unwrap();
-        vault.apply_fee(&mut vp, vault_equity, now).unwrap();
+        vault
+            .apply_fee(&VaultConfig::default(), &mut vp, vault_equity, now)
+            .unwrap();
 
         let depositor_amount_in_profit = depositor_shares_to_vault_amount(
             vd.checked_vault_shares(&vault).unwrap(),
@@ -1084,6 +1224,7 @@ mod vault_fcn {
             .apply_profit_share(
                 vault_equity,
                 &mut vault,
+                &VaultConfig::default(),
                 &mut vp,
                 0,
                 &UserStats::default(),
@@ -1117,6 +1258,31 @@ mod vault_fcn {
             manager_total_profit_share_before + manager_profit_share
         );
     }
+
+    #[test]
+    fn test_validate_liquidation_depositor() {
+        let mut vault = Vault {
+            redeem_period: 60,
+            ..Vault::default()
+        };
+        let request = WithdrawRequest {
+            shares: 100,
+            value: 100,
+            ts: 1_000,
+        };
+
+        // not in liquidation
+        assert!(vault.validate_liquidation_depositor(&request).is_err());
+
+        vault.set_liquidation_delegate(Pubkey::new_unique(), 1_059);
+        assert!(vault.validate_liquidation_depositor(&request).is_err());
+
+        vault.set_liquidation_delegate(Pubkey::new_unique(), 1_060);
+        vault.validate_liquidation_depositor(&request).unwrap();
+        assert!(vault
+            .validate_liquidation_depositor(&WithdrawRequest::default())
+            .is_err());
+    }
 }
 
 #[cfg(test)]
@@ -1128,7 +1294,9 @@ mod vault_v1_fcn {
     use drift::math::insurance::if_shares_to_vault_amount as depositor_shares_to_vault_amount;
     use drift::state::user::UserStats;
 
-    use crate::state::{Vault, VaultDepositorBase, VaultProtocol};
+    use crate::state::{
+        ProtocolFeeTier, Vault, VaultConfig, VaultDepositorBase, VaultProtocol, VaultProtocolFlag,
+    };
     use crate::{VaultDepositor, WithdrawUnit};
 
     const USER_SHARES_AFTER_1500_BPS_FEE: u64 = 99_850_025;
@@ -1145,7 +1313,14 @@ mod vault_v1_fcn {
         let mut vault_equity = 0;
         let amount = 100_000_000; // $100
         vault
-            .manager_deposit(&mut Some(vp.borrow_mut()), amount, vault_equity, now, 0)
+            .manager_deposit(
+                &VaultConfig::default(),
+                &mut Some(vp.borrow_mut()),
+                amount,
+                vault_equity,
+                now,
+                0,
+            )
             .unwrap();
         vault_equity += amount;
         vault_equity -= 1;
@@ -1158,6 +1333,7 @@ mod vault_v1_fcn {
 
         vault
             .manager_request_withdraw(
+                &VaultConfig::default(),
                 &mut Some(vp.borrow_mut()),
                 amount - 1,
                 WithdrawUnit::Token,
@@ -1174,12 +1350,24 @@ mod vault_v1_fcn {
         assert_eq!(vault.manager_total_withdraws, 0);
 
         let err = vault
-            .manager_withdraw(&mut Some(vp.borrow_mut()), vault_equity, now + 50, 0)
+            .manager_withdraw(
+                &VaultConfig::default(),
+                &mut Some(vp.borrow_mut()),
+                vault_equity,
+                now + 50,
+                0,
+            )
             .is_err();
         assert!(err);
 
         let withdraw = vault
-            .manager_withdraw(&mut Some(vp.borrow_mut()), vault_equity, now + 60, 0)
+            .manager_withdraw(
+                &VaultConfig::default(),
+                &mut Some(vp.borrow_mut()),
+                vault_equity,
+                now + 60,
+                0,
+            )
             .unwrap();
         assert_eq!(vault.user_shares, 0);
         assert_eq!(vault.total_shares, 0);
@@ -1208,6 +1396,7 @@ mod vault_v1_fcn {
             amount,
             vault_equity,
             &mut vault,
+            &VaultConfig::default(),
             &mut Some(vp.borrow_mut()),
             now,
             &UserStats::default(),
@@ -1227,6 +1416,7 @@ mod vault_v1_fcn {
 
         vault
             .apply_fee(
+                &VaultConfig::default(),
                 &mut Some(vp.borrow_mut()),
                 vault_equity,
                 now + ONE_YEAR as i64,
@@ -1257,6 +1447,186 @@ mod vault_v1_fcn {
         assert_eq!(vault.last_fee_update_ts, now + ONE_YEAR as i64);
     }
 
+    #[test]
+    fn test_protocol_fee_tiers_v1() {
+        let now = 0;
+        let mut vault = Vault::default();
+        let vp = RefCell::new(VaultProtocol::default());
+        vault.management_fee = 1000; // 10 bps
+        vp.borrow_mut().protocol_fee = 500; // 5 bps
+        let mut vault_config = VaultConfig::default();
+
+        let mut tiers = [ProtocolFeeTier::default(); 4];
+        tiers[0] = ProtocolFeeTier {
+            tvl_threshold: 150 * QUOTE_PRECISION_U64,
+            protocol_fee: 250,
+        };
+        tiers[1] = ProtocolFeeTier {
+            tvl_threshold: 200 * QUOTE_PRECISION_U64,
+            protocol_fee: 0,
+        };
+        vault_config.update_protocol_fee_tiers(500, tiers).unwrap();
+
+        assert_eq!(
+            vault_config.get_protocol_fee(500, 100 * QUOTE_PRECISION_U64),
+            500
+        );
+        assert_eq!(
+            vault_config.get_protocol_fee(500, 150 * QUOTE_PRECISION_U64),
+            250
+        );
+        assert_eq!(
+            vault_config.get_protocol_fee(500, 199 * QUOTE_PRECISION_U64),
+            250
+        );
+        assert_eq!(
+            vault_config.get_protocol_fee(500, 1000 * QUOTE_PRECISION_U64),
+            0
+        );
+
+        let vd =
+            &mut VaultDepositor::new(Pubkey::default(), Pubkey::default(), Pubkey::default(), now);
+
+        let mut vault_equity: u64 = 100 * QUOTE_PRECISION_U64;
+        let amount: u64 = 100 * QUOTE_PRECISION_U64;
+        vd.deposit(
+            amount,
+            vault_equity,
+            &mut vault,
+            &vault_config,
+            &mut Some(vp.borrow_mut()),
+            now,
+            &UserStats::default(),
+            &None,
+            0,
+        )
+        .unwrap();
+        assert_eq!(vault.user_shares, 100000000);
+        assert_eq!(vault.total_shares, 200000000);
+        vault_equity += amount;
+
+        // $200 of equity is in the top tier, protocol fee is waived
+        vault
+            .apply_fee(
+                &vault_config,
+                &mut Some(vp.borrow_mut()),
+                vault_equity,
+                now + ONE_YEAR as i64,
+            )
+            .unwrap();
+        assert_eq!(vault.user_shares, 100_000_000);
+        assert_eq!(vault.get_protocol_shares(&mut Some(vp.borrow_mut())), 0);
+        assert_eq!(vault.total_shares, 200_200_200);
+        assert_eq!(vault.last_fee_update_ts, now + ONE_YEAR as i64);
+    }
+
+    #[test]
+    fn test_protocol_fee_waiver_v1() {
+        let now = 0;
+        let mut vault = Vault::default();
+        let vp = RefCell::new(VaultProtocol::default());
+        vault.management_fee = 1000; // 10 bps
+        vp.borrow_mut().protocol_fee = 500; // 5 bps
+        let mut vault_config = VaultConfig::default();
+
+        assert!(vault_config
+            .update_protocol_fee_waiver_thresholds(200 * QUOTE_PRECISION_U64, 100)
+            .is_err());
+        vault_config
+            .update_protocol_fee_waiver_thresholds(
+                150 * QUOTE_PRECISION_U64,
+                250 * QUOTE_PRECISION_U64,
+            )
+            .unwrap();
+
+        let vd =
+            &mut VaultDepositor::new(Pubkey::default(), Pubkey::default(), Pubkey::default(), now);
+
+        let mut vault_equity: u64 = 100 * QUOTE_PRECISION_U64;
+        let amount: u64 = 100 * QUOTE_PRECISION_U64;
+        vd.deposit(
+            amount,
+            vault_equity,
+            &mut vault,
+            &vault_config,
+            &mut Some(vp.borrow_mut()),
+            now,
+            &UserStats::default(),
+            &None,
+            0,
+        )
+        .unwrap();
+        vault_equity += amount;
+
+        // $100 of equity on deposit entered the waiver, $200 is still under the exit threshold
+        assert!(vp.borrow().has_flag(VaultProtocolFlag::ProtocolFeeWaived));
+        vault
+            .apply_fee(
+                &vault_config,
+                &mut Some(vp.borrow_mut()),
+                vault_equity,
+                now + ONE_YEAR as i64,
+            )
+            .unwrap();
+        assert_eq!(vault.get_protocol_shares(&mut Some(vp.borrow_mut())), 0);
+        assert_eq!(vault.total_shares, 200_200_200);
+        assert!(vp.borrow().has_flag(VaultProtocolFlag::ProtocolFeeWaived));
+
+        // the fee is charged again once equity reaches the exit threshold
+        vault
+            .apply_fee(
+                &vault_config,
+                &mut Some(vp.borrow_mut()),
+                250 * QUOTE_PRECISION_U64,
+                now + 2 * ONE_YEAR as i64,
+            )
+            .unwrap();
+        assert!(!vp.borrow().has_flag(VaultProtocolFlag::ProtocolFeeWaived));
+        assert!(vault.get_protocol_shares(&mut Some(vp.borrow_mut())) > 0);
+
+        // and stays charged above the entry threshold
+        assert!(!vp
+            .borrow_mut()
+            .update_protocol_fee_waiver(&vault_config, 200 * QUOTE_PRECISION_U64));
+        assert!(vp
+            .borrow_mut()
+            .update_protocol_fee_waiver(&vault_config, 149 * QUOTE_PRECISION_U64));
+    }
+
+    #[test]
+    fn test_invalid_protocol_fee_tiers_v1() {
+        let mut vault_config = VaultConfig::default();
+
+        // fee can't step up
+        let mut tiers = [ProtocolFeeTier::default(); 4];
+        tiers[0] = ProtocolFeeTier {
+            tvl_threshold: 100,
+            protocol_fee: 600,
+        };
+        assert!(vault_config.update_protocol_fee_tiers(500, tiers).is_err());
+
+        // thresholds must increase
+        tiers[0].protocol_fee = 400;
+        tiers[1] = ProtocolFeeTier {
+            tvl_threshold: 100,
+            protocol_fee: 300,
+        };
+        assert!(vault_config.update_protocol_fee_tiers(500, tiers).is_err());
+
+        // tiers must be contiguous
+        tiers[1] = ProtocolFeeTier::default();
+        tiers[2] = ProtocolFeeTier {
+            tvl_threshold: 200,
+            protocol_fee: 300,
+        };
+        assert!(vault_config.update_protocol_fee_tiers(500, tiers).is_err());
+
+        tiers[1] = tiers[2];
+        tiers[2] = ProtocolFeeTier::default();
+        assert!(vault_config.update_protocol_fee_tiers(500, tiers).is_ok());
+        assert_eq!(vault_config.protocol_fee_tiers, tiers);
+    }
+
     #[test]
     fn test_odd_management_and_protocol_fee_v1() {
         let now = 0;
@@ -1276,6 +1646,7 @@ mod vault_v1_fcn {
             amount,
             vault_equity,
             &mut vault,
+            &VaultConfig::default(),
             &mut Some(vp.borrow_mut()),
             now,
             &UserStats::default(),
@@ -1295,6 +1666,7 @@ mod vault_v1_fcn {
 
         vault
             .apply_fee(
+                &VaultConfig::default(),
                 &mut Some(vp.borrow_mut()),
                 vault_equity,
                 now + ONE_YEAR as i64,
@@ -1352,6 +1724,7 @@ mod vault_v1_fcn {
             amount,
             vault_equity,
             &mut vault,
+            &VaultConfig::default(),
             &mut Some(vp.borrow_mut()),
             now,
             &UserStats::default(),
@@ -1371,6 +1744,7 @@ mod vault_v1_fcn {
 
         vault
             .apply_fee(
+                &VaultConfig::default(),
                 &mut Some(vp.borrow_mut()),
                 vault_equity,
                 now + ONE_YEAR as i64,
@@ -1419,6 +1793,7 @@ mod vault_v1_fcn {
             amount,
             vault_equity,
             &mut vault,
+            &VaultConfig::default(),
             &mut Some(vp.borrow_mut()),
             now,
             &UserStats::default(),
@@ -1434,6 +1809,7 @@ mod vault_v1_fcn {
 
         vault
             .apply_fee(
+                &VaultConfig::default(),
                 &mut Some(vp.borrow_mut()),
                 vault_equity,
                 now + ONE_YEAR as i64,
@@ -1472,6 +1848,7 @@ mod vault_v1_fcn {
             amount,
             vault_equity,
             &mut vault,
+            &VaultConfig::default(),
             &mut Some(vp.borrow_mut()),
             now,
             &UserStats::default(),
@@ -1487,12 +1864,22 @@ mod vault_v1_fcn {
 
         while now < ONE_YEAR as i64 {
             vault
-                .apply_fee(&mut Some(vp.borrow_mut()), vault_equity, now)
+                .apply_fee(
+                    &VaultConfig::default(),
+                    &mut Some(vp.borrow_mut()),
+                    vault_equity,
+                    now,
+                )
                 .unwrap();
             now += 60 * 60 * 24 * 7; // every week
         }
         vault
-            .apply_fee(&mut Some(vp.borrow_mut()), vault_equity, now)
+            .apply_fee(
+                &VaultConfig::default(),
+                &mut Some(vp.borrow_mut()),
+                vault_equity,
+                now,
+            )
             .unwrap();
 
         let vd_amount_left =
@@ -1512,7 +1899,14 @@ mod vault_v1_fcn {
         let mut vault_equity: u64 = 0;
         let amount: u64 = 100 * QUOTE_PRECISION_U64;
         vault
-            .manager_deposit(&mut Some(vp.borrow_mut()), amount, vault_equity, now, 0)
+            .manager_deposit(
+                &VaultConfig::default(),
+                &mut Some(vp.borrow_mut()),
+                amount,
+                vault_equity,
+                now,
+                0,
+            )
             .unwrap();
         vault_equity += amount;
 
@@ -1531,6 +1925,7 @@ mod vault_v1_fcn {
 
         vault
             .manager_request_withdraw(
+                &VaultConfig::default(),
                 &mut Some(vp.borrow_mut()),
                 amount,
                 WithdrawUnit::Token,
@@ -1541,7 +1936,13 @@ mod vault_v1_fcn {
             .unwrap();
 
         let withdrew = vault
-            .manager_withdraw(&mut Some(vp.borrow_mut()), vault_equity, now, 0)
+            .manager_withdraw(
+                &VaultConfig::default(),
+                &mut Some(vp.borrow_mut()),
+                vault_equity,
+                now,
+                0,
+            )
             .unwrap();
         assert_eq!(amount, withdrew);
         assert_eq!(vault.user_shares, 0);
@@ -1575,6 +1976,7 @@ mod vault_v1_fcn {
             amount,
             vault_equity,
             &mut vault,
+            &VaultConfig::default(),
             &mut Some(vp.borrow_mut()),
             now,
             &UserStats::default(),
@@ -1594,7 +1996,12 @@ mod vault_v1_fcn {
 
         // one second since inception
         vault
-            .apply_fee(&mut Some(vp.borrow_mut()), vault_equity, now + 1_i64)
+            .apply_fee(
+                &VaultConfig::default(),
+                &mut Some(vp.borrow_mut()),
+                vault_equity,
+                now + 1_i64,
+            )
             .unwrap();
         assert_eq!(vault.user_shares, 100000000);
         assert_eq!(vault.total_shares, 199986200);
@@ -1606,7 +2013,12 @@ mod vault_v1_fcn {
 
         // one minute since inception
         vault
-            .apply_fee(&mut Some(vp.borrow_mut()), vault_equity, now + 60_i64)
+            .apply_fee(
+                &VaultConfig::default(),
+                &mut Some(vp.borrow_mut()),
+                vault_equity,
+                now + 60_i64,
+            )
             .unwrap();
         assert_eq!(vault.user_shares, 100000000);
         assert_eq!(vault.total_shares, 199185855);
@@ -1619,6 +2031,7 @@ mod vault_v1_fcn {
         // one year since inception
         vault
             .apply_fee(
+                &VaultConfig::default(),
                 &mut Some(vp.borrow_mut()),
                 vault_equity,
                 now + ONE_YEAR as i64,
@@ -1648,7 +2061,14 @@ mod vault_v1_fcn {
         let amount: u64 = 100 * QUOTE_PRECISION_U64;
         now += 100000;
         vault
-            .manager_deposit(&mut Some(vp.borrow_mut()), amount, vault_equity, now, 0)
+            .manager_deposit(
+                &VaultConfig::default(),
+                &mut Some(vp.borrow_mut()),
+                amount,
+                vault_equity,
+                now,
+                0,
+            )
             .unwrap();
 
         assert_eq!(vault.user_shares, 0);
@@ -1659,6 +2079,7 @@ mod vault_v1_fcn {
         now += 100000;
         vault
             .manager_request_withdraw(
+                &VaultConfig::default(),
                 &mut Some(vp.borrow_mut()),
                 amount,
                 WithdrawUnit::Token,
@@ -1668,7 +2089,13 @@ mod vault_v1_fcn {
             )
             .unwrap();
         let withdrew = vault
-            .manager_withdraw(&mut Some(vp.borrow_mut()), vault_equity, now, 0)
+            .manager_withdraw(
+                &VaultConfig::default(),
+                &mut Some(vp.borrow_mut()),
+                vault_equity,
+                now,
+                0,
+            )
             .unwrap();
         assert_eq!(withdrew, amount);
     }
@@ -1685,7 +2112,14 @@ mod vault_v1_fcn {
         let mut vault_equity: u64 = 0;
         let amount: u64 = 100 * QUOTE_PRECISION_U64;
         vault
-            .manager_deposit(&mut Some(vp.borrow_mut()), amount, vault_equity, now, 0)
+            .manager_deposit(
+                &VaultConfig::default(),
+                &mut Some(vp.borrow_mut()),
+                amount,
+                vault_equity,
+                now,
+                0,
+            )
             .unwrap();
         vault_equity += amount;
 
@@ -1699,6 +2133,7 @@ mod vault_v1_fcn {
             amount * 20,
             vault_equity,
             &mut vault,
+            &VaultConfig::default(),
             &mut Some(vp.borrow_mut()),
             now,
             &UserStats::default(),
@@ -1716,6 +2151,7 @@ mod vault_v1_fcn {
         vd.apply_profit_share(
             vault_equity,
             &mut vault,
+            &VaultConfig::default(),
             &mut Some(vp.borrow_mut()),
             now,
             &UserStats::default(),
@@ -1723,7 +2159,12 @@ mod vault_v1_fcn {
         )
         .unwrap();
         vault
-            .apply_fee(&mut Some(vp.borrow_mut()), vault_equity, now)
+            .apply_fee(
+                &VaultConfig::default(),
+                &mut Some(vp.borrow_mut()),
+                vault_equity,
+                now,
+            )
             .unwrap();
 
         let vault_manager_amount = depositor_shares_to_vault_amount(
@@ -1736,6 +2177,7 @@ mod vault_v1_fcn {
         assert_eq!(vault_manager_amount, 100000000);
         vault
             .manager_request_withdraw(
+                &VaultConfig::default(),
                 &mut Some(vp.borrow_mut()),
                 amount,
                 WithdrawUnit::Token,
@@ -1746,7 +2188,13 @@ mod vault_v1_fcn {
             .unwrap();
 
         let withdrew = vault
-            .manager_withdraw(&mut Some(vp.borrow_mut()), vault_equity, now, 0)
+            .manager_withdraw(
+                &VaultConfig::default(),
+                &mut Some(vp.borrow_mut()),
+                vault_equity,
+                now,
+                0,
+            )
             .unwrap();
         assert_eq!(amount, withdrew);
         assert_eq!(vault.user_shares, 2000000000);
@@ -1774,7 +2222,14 @@ mod vault_v1_fcn {
         let mut vault_equity: u64 = 0;
         let amount: u64 = 100 * QUOTE_PRECISION_U64;
         vault
-            .manager_deposit(&mut Some(vp.borrow_mut()), amount, vault_equity, now, 0)
+            .manager_deposit(
+                &VaultConfig::default(),
+                &mut Some(vp.borrow_mut()),
+                amount,
+                vault_equity,
+                now,
+                0,
+            )
             .unwrap();
         vault_equity += amount;
 
@@ -1788,6 +2243,7 @@ mod vault_v1_fcn {
             amount * 20,
             vault_equity,
             &mut vault,
+            &VaultConfig::default(),
             &mut Some(vp.borrow_mut()),
             now,
             &UserStats::default(),
@@ -1807,6 +2263,7 @@ mod vault_v1_fcn {
             vd.apply_profit_share(
                 vault_equity,
                 &mut vault,
+                &VaultConfig::default(),
                 &mut Some(vp.borrow_mut()),
                 now,
                 &UserStats::default(),
@@ -1814,7 +2271,12 @@ mod vault_v1_fcn {
             )
             .unwrap();
             vault
-                .apply_fee(&mut Some(vp.borrow_mut()), vault_equity, now)
+                .apply_fee(
+                    &VaultConfig::default(),
+                    &mut Some(vp.borrow_mut()),
+                    vault_equity,
+                    now,
+                )
                 .unwrap();
             // crate::msg!("vault last ts: {} vs {}", vault.last_fee_update_ts, now);
             cnt += 1;
@@ -1832,6 +2294,7 @@ mod vault_v1_fcn {
         assert_eq!(vault_manager_amount, 100001999);
         vault
             .manager_request_withdraw(
+                &VaultConfig::default(),
                 &mut Some(vp.borrow_mut()),
                 amount,
                 WithdrawUnit::Token,
@@ -1842,7 +2305,13 @@ mod vault_v1_fcn {
             .unwrap();
 
         let withdrew = vault
-            .manager_withdraw(&mut Some(vp.borrow_mut()), vault_equity, now, 0)
+            .manager_withdraw(
+                &VaultConfig::default(),
+                &mut Some(vp.borrow_mut()),
+                vault_equity,
+                now,
+                0,
+            )
             .unwrap();
         assert_eq!(amount, withdrew);
         assert_eq!(vault.user_shares, 2000000000);
@@ -1881,7 +2350,14 @@ mod vault_v1_fcn {
         let mut vault_equity: u64 = 0;
         let amount: u64 = 100 * QUOTE_PRECISION_U64;
         vault
-            .manager_deposit(&mut Some(vp.borrow_mut()), amount, vault_equity, now, 0)
+            .manager_deposit(
+                &VaultConfig::default(),
+                &mut Some(vp.borrow_mut()),
+                amount,
+                vault_equity,
+                now,
+                0,
+            )
             .unwrap();
         vault_equity += amount;
 
@@ -1895,6 +2371,7 @@ mod vault_v1_fcn {
             amount * 20,
             vault_equity,
             &mut vault,
+            &VaultConfig::default(),
             &mut Some(vp.borrow_mut()),
             now,
             &UserStats::default(),
@@ -1920,6 +2397,7 @@ mod vault_v1_fcn {
             vd.apply_profit_share(
                 vault_equity,
                 &mut vault,
+                &VaultConfig::default(),
                 &mut Some(vp.borrow_mut()),
                 now,
                 &UserStats::default(),
@@ -1927,7 +2405,12 @@ mod vault_v1_fcn {
             )
             .unwrap();
             vault
-                .apply_fee(&mut Some(vp.borrow_mut()), vault_equity, now)
+                .apply_fee(
+                    &VaultConfig::default(),
+                    &mut Some(vp.borrow_mut()),
+                    vault_equity,
+                    now,
+                )
                 .unwrap();
             cnt += 1;
         }
@@ -1950,6 +2433,7 @@ mod vault_v1_fcn {
 
         vault
             .manager_request_withdraw(
+                &VaultConfig::default(),
                 &mut Some(vp.borrow_mut()),
                 amount,
                 WithdrawUnit::Token,
@@ -1961,7 +2445,13 @@ mod vault_v1_fcn {
         assert_eq!(amount, vault.last_manager_withdraw_request.value);
 
         let withdrew = vault
-            .manager_withdraw(&mut Some(vp.borrow_mut()), vault_equity, now, 0)
+            .manager_withdraw(
+                &VaultConfig::default(),
+                &mut Some(vp.borrow_mut()),
+                vault_equity,
+                now,
+                0,
+            )
             .unwrap();
         assert_eq!(amount - 1, withdrew); // todo: slight round out of favor
         assert_eq!(vault.user_shares, 1900000000);
@@ -2000,7 +2490,14 @@ mod vault_v1_fcn {
         let mut vault_equity: u64 = 0;
         let amount: u64 = 100 * QUOTE_PRECISION_U64;
         vault
-            .manager_deposit(&mut Some(vp.borrow_mut()), amount, vault_equity, now, 0)
+            .manager_deposit(
+                &VaultConfig::default(),
+                &mut Some(vp.borrow_mut()),
+                amount,
+                vault_equity,
+                now,
+                0,
+            )
             .unwrap();
         vault_equity += amount;
 
@@ -2014,6 +2511,7 @@ mod vault_v1_fcn {
             amount * 20,
             vault_equity,
             &mut vault,
+            &VaultConfig::default(),
             &mut Some(vp.borrow_mut()),
             now,
             &UserStats::default(),
@@ -2039,6 +2537,7 @@ mod vault_v1_fcn {
             vd.apply_profit_share(
                 vault_equity,
                 &mut vault,
+                &VaultConfig::default(),
                 &mut Some(vp.borrow_mut()),
                 now,
                 &UserStats::default(),
@@ -2046,7 +2545,12 @@ mod vault_v1_fcn {
             )
             .unwrap();
             vault
-                .apply_fee(&mut Some(vp.borrow_mut()), vault_equity, now)
+                .apply_fee(
+                    &VaultConfig::default(),
+                    &mut Some(vp.borrow_mut()),
+                    vault_equity,
+                    now,
+                )
                 .unwrap();
             cnt += 1;
         }
@@ -2069,6 +2573,7 @@ mod vault_v1_fcn {
 
         vault
             .protocol_request_withdraw(
+                &VaultConfig::default(),
                 &mut Some(vp.borrow_mut()),
                 amount,
                 WithdrawUnit::Token,
@@ -2080,7 +2585,13 @@ mod vault_v1_fcn {
         assert_eq!(amount, vp.borrow().last_protocol_withdraw_request.value);
 
         let withdrew = vault
-            .protocol_withdraw(&mut Some(vp.borrow_mut()), vault_equity, now, 0)
+            .protocol_withdraw(
+                &VaultConfig::default(),
+                &mut Some(vp.borrow_mut()),
+                vault_equity,
+                now,
+                0,
+            )
             .unwrap();
         assert_eq!(amount - 1, withdrew); // todo: slight round out of favor
         assert_eq!(vault.user_shares, 1900000000);
@@ -2137,6 +2648,7 @@ mod vault_v1_fcn {
             amount,
             vault_equity,
             &mut vault,
+            &VaultConfig::default(),
             &mut Some(vp.borrow_mut()),
             now,
             &UserStats::default(),
@@ -2155,6 +2667,7 @@ mod vault_v1_fcn {
         vd.apply_profit_share(
             vault_equity,
             &mut vault,
+            &VaultConfig::default(),
             &mut Some(vp.borrow_mut()),
             now,
             &UserStats::default(),
@@ -2168,6 +2681,7 @@ mod vault_v1_fcn {
         vd.apply_profit_share(
             vault_equity * 105 / 100,
             &mut vault,
+            &VaultConfig::default(),
             &mut Some(vp.borrow_mut()),
             now,
             &UserStats::default(),
@@ -2181,6 +2695,7 @@ mod vault_v1_fcn {
         vd.apply_profit_share(
             vault_equity * 110 / 100,
             &mut vault,
+            &VaultConfig::default(),
             &mut Some(vp.borrow_mut()),
             now,
             &UserStats::default(),
@@ -2196,6 +2711,7 @@ mod vault_v1_fcn {
         vd.apply_profit_share(
             vault_equity_profit_share,
             &mut vault,
+            &VaultConfig::default(),
             &mut Some(vp.borrow_mut()),
             now,
             &UserStats::default(),
@@ -2218,6 +2734,7 @@ mod vault_v1_fcn {
         vd.apply_profit_share(
             vault_equity_final,
             &mut vault,
+            &VaultConfig::default(),
             &mut Some(vp.borrow_mut()),
             now,
             &UserStats::default(),
@@ -2239,6 +2756,7 @@ mod vault_v1_fcn {
         vd.apply_profit_share(
             vault_equity_final,
             &mut vault,
+            &VaultConfig::default(),
             &mut Some(vp.borrow_mut()),
             now,
             &UserStats::default(),
@@ -2269,7 +2787,7 @@ mod request_withdraw_cancel_tests {
     use super::EntityType;
     use crate::{
         assert_eq_within,
-        state::{vault::Vault, VaultDepositor, VaultProtocol},
+        state::{vault::Vault, VaultConfig, VaultDepositor, VaultProtocol},
         WithdrawUnit,
     };
     use drift::{
@@ -2336,6 +2854,7 @@ mod request_withdraw_cancel_tests {
             EntityType::Manager => {
                 vault
                     .manager_request_withdraw(
+                        &VaultConfig::default(),
                         &mut Some(vp.borrow_mut()),
                         withdraw_shares_pct,
                         WithdrawUnit::SharesPercent,
@@ -2348,6 +2867,7 @@ mod request_withdraw_cancel_tests {
             EntityType::Protocol => {
                 vault
                     .protocol_request_withdraw(
+                        &VaultConfig::default(),
                         &mut Some(vp.borrow_mut()),
                         withdraw_shares_pct,
                         WithdrawUnit::SharesPercent,
@@ -2363,6 +2883,7 @@ mod request_withdraw_cancel_tests {
                     WithdrawUnit::SharesPercent,
                     vault_equity_initial,
                     &mut vault,
+                    &VaultConfig::default(),
                     &mut None,
                     now,
                     &UserStats::default(),
@@ -2381,6 +2902,7 @@ mod request_withdraw_cancel_tests {
                 EntityType::Manager => {
                     vault
                         .manager_deposit(
+                            &VaultConfig::default(),
                             &mut Some(vp.borrow_mut()),
                             action.amount,
                             vault_equity_initial,
@@ -2397,6 +2919,7 @@ mod request_withdraw_cancel_tests {
                         action.amount,
                         vault_equity_initial,
                         &mut vault,
+                        &VaultConfig::default(),
                         &mut Some(vp.borrow_mut()),
                         now,
                         &UserStats::default(),
@@ -2415,6 +2938,7 @@ mod request_withdraw_cancel_tests {
             EntityType::Manager => {
                 vault
                     .manager_cancel_withdraw_request(
+                        &VaultConfig::default(),
                         &mut Some(vp.borrow_mut()),
                         vault_equity_final,
                         now + 1000,
@@ -2425,6 +2949,7 @@ mod request_withdraw_cancel_tests {
             EntityType::Protocol => {
                 vault
                     .protocol_cancel_withdraw_request(
+                        &VaultConfig::default(),
                         &mut Some(vp.borrow_mut()),
                         vault_equity_final,
                         now + 1000,
@@ -2436,11 +2961,13 @@ mod request_withdraw_cancel_tests {
                 vd.cancel_withdraw_request(
                     vault_equity_final,
                     &mut vault,
+                    &VaultConfig::default(),
                     &mut Some(vp.borrow_mut()),
                     now + 1000,
                     &UserStats::default(),
                     &None,
                     0,
+                    None,
                 )
                 .expect("can cancel withdraw request");
             }
@@ -3458,7 +3985,7 @@ mod full_vault_withdraw_tests {
 
     use super::EntityType;
     use crate::{
-        state::{vault::Vault, VaultDepositor, VaultProtocol},
+        state::{vault::Vault, VaultConfig, VaultDepositor, VaultProtocol},
         WithdrawUnit,
     };
     use drift::{
@@ -3512,6 +4039,7 @@ mod full_vault_withdraw_tests {
                 EntityType::Manager => {
                     vault
                         .manager_request_withdraw(
+                            &VaultConfig::default(),
                             &mut Some(vp.borrow_mut()),
                             param.shares_pct,
                             WithdrawUnit::SharesPercent,
@@ -3524,6 +4052,7 @@ mod full_vault_withdraw_tests {
                 EntityType::Protocol => {
                     vault
                         .protocol_request_withdraw(
+                            &VaultConfig::default(),
                             &mut Some(vp.borrow_mut()),
                             param.shares_pct,
                             WithdrawUnit::SharesPercent,
@@ -3539,6 +4068,7 @@ mod full_vault_withdraw_tests {
                         WithdrawUnit::SharesPercent,
                         vault_equity_initial,
                         &mut vault,
+                        &VaultConfig::default(),
                         &mut None,
                         now,
                         &UserStats::default(),
@@ -3557,18 +4087,31 @@ mod full_vault_withdraw_tests {
             match param.entity_type {
                 EntityType::Manager => {
                     vault
-                        .manager_withdraw(&mut Some(vp.borrow_mut()), vault_equity_final, now, 0)
+                        .manager_withdraw(
+                            &VaultConfig::default(),
+                            &mut Some(vp.borrow_mut()),
+                            vault_equity_final,
+                            now,
+                            0,
+                        )
                         .expect("manager can withdraw");
                 }
                 EntityType::Protocol => {
                     vault
-                        .protocol_withdraw(&mut Some(vp.borrow_mut()), vault_equity_final, now, 0)
+                        .protocol_withdraw(
+                            &VaultConfig::default(),
+                            &mut Some(vp.borrow_mut()),
+                            vault_equity_final,
+                            now,
+                            0,
+                        )
                         .expect("protocol can withdraw");
                 }
                 EntityType::VaultDepositor => {
                     vd.withdraw(
                         vault_equity_final,
                         &mut vault,
+                        &VaultConfig::default(),
                         &mut Some(vp.borrow_mut()),
                         now,
                         &UserStats::default(),
@@ -1,4 +1,17 @@
 use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Transfer};
+use anchor_spl::token_2022::spl_token_2022;
+use anchor_spl::token_2022::spl_token_2022::extension::transfer_fee::TransferFeeConfig;
+use anchor_spl::token_2022::spl_token_2022::extension::{
+    BaseStateWithExtensions, StateWithExtensions,
+};
+use anchor_spl::token_interface::{self, Mint, TransferChecked};
+use drift::error::ErrorCode as DriftErrorCode;
+use drift::math::safe_math::SafeMath;
+
+use crate::error::ErrorCode;
+use crate::state::WithdrawRouteAccounts;
+use crate::validate;
 
 pub trait MintTokensCPI {
     fn mint(&self, vault_name: [u8; 32], vault_bump: u8, amount: u64) -> Result<()>;
@@ -11,3 +24,211 @@ pub trait BurnTokensCPI {
 pub trait TokenTransferCPI {
     fn token_transfer(&self, amount: u64) -> Result<()>;
 }
+
+pub trait CloseTokenAccountCPI {
+    fn close_token_account(&self) -> Result<()>;
+}
+
+pub trait TokenTransferRouteCPI<'info> {
+    fn token_transfer_route(
+        &self,
+        amount: u64,
+        route_accounts: &WithdrawRouteAccounts<'info>,
+    ) -> Result<()>;
+}
+
+/// Moves `amount` between token accounts of either token program. With the mint, see
+/// [`TokenMintProvider`](crate::state::TokenMintProvider), it's a `transfer_checked`, which Token-2022 requires.
+/// Without it only spl token transfers are allowed, so clients that don't pass the mint keep working.
+pub fn transfer_tokens<'info>(
+    token_program: AccountInfo<'info>,
+    from: AccountInfo<'info>,
+    to: AccountInfo<'info>,
+    authority: AccountInfo<'info>,
+    mint: Option<&InterfaceAccount<'info, Mint>>,
+    amount: u64,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    match mint {
+        Some(mint) => {
+            let cpi_accounts = TransferChecked {
+                from,
+                mint: mint.to_account_info(),
+                to,
+                authority,
+            };
+            let cpi_context =
+                CpiContext::new_with_signer(token_program, cpi_accounts, signer_seeds);
+
+            token_interface::transfer_checked(cpi_context, amount, mint.decimals)?;
+        }
+        None => {
+            validate!(
+                token_program.key() == token::ID,
+                ErrorCode::TokenMintMissing,
+                "Token-2022 transfers need the mint in remaining accounts"
+            )?;
+
+            let cpi_accounts = Transfer {
+                from,
+                to,
+                authority,
+            };
+            let cpi_context =
+                CpiContext::new_with_signer(token_program, cpi_accounts, signer_seeds);
+
+            token::transfer(cpi_context, amount)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// The Token-2022 transfer fee withheld from a transfer of `amount`, 0 for mints without a transfer fee.
+pub fn get_transfer_fee(mint: &InterfaceAccount<Mint>, amount: u64) -> Result<u64> {
+    let mint_info = mint.to_account_info();
+    let mint_data = mint_info.try_borrow_data()?;
+
+    calculate_transfer_fee(mint_info.owner, &mint_data, Clock::get()?.epoch, amount)
+}
+
+/// What's left of `amount` for the receiver of a transfer of it, after the Token-2022 transfer fee is withheld.
+/// Without the mint only spl token transfers are allowed, see [`transfer_tokens`], so nothing is withheld.
+pub fn get_amount_after_transfer_fee(
+    mint: Option<&InterfaceAccount<Mint>>,
+    amount: u64,
+) -> Result<u64> {
+    let transfer_fee = match mint {
+        Some(mint) => get_transfer_fee(mint, amount)?,
+        None => 0,
+    };
+
+    Ok(amount.safe_sub(transfer_fee)?)
+}
+
+pub fn calculate_transfer_fee(
+    mint_owner: &Pubkey,
+    mint_data: &[u8],
+    epoch: u64,
+    amount: u64,
+) -> Result<u64> {
+    if *mint_owner != spl_token_2022::ID {
+        return Ok(0);
+    }
+
+    let mint = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(mint_data)?;
+    let transfer_fee = match mint.get_extension::<TransferFeeConfig>() {
+        Ok(transfer_fee_config) => transfer_fee_config
+            .calculate_epoch_fee(epoch, amount)
+            .ok_or(DriftErrorCode::MathError)?,
+        Err(_) => 0,
+    };
+
+    Ok(transfer_fee)
+}
+
+#[cfg(test)]
+mod tests {
+    use anchor_lang::prelude::Pubkey;
+    use anchor_spl::token::ID as TOKEN_PROGRAM_ID;
+    use anchor_spl::token_2022::spl_token_2022;
+    use anchor_spl::token_2022::spl_token_2022::extension::transfer_fee::{
+        TransferFee, TransferFeeConfig,
+    };
+    use anchor_spl::token_2022::spl_token_2022::extension::{
+        ExtensionType, StateWithExtensionsMut,
+    };
+    use anchor_spl::token_2022::spl_token_2022::state::Mint;
+
+    use crate::token_cpi::calculate_transfer_fee;
+
+    fn transfer_fee(epoch: u64, transfer_fee_basis_points: u16, maximum_fee: u64) -> TransferFee {
+        TransferFee {
+            epoch: epoch.into(),
+            maximum_fee: maximum_fee.into(),
+            transfer_fee_basis_points: transfer_fee_basis_points.into(),
+        }
+    }
+
+    fn mint_data(transfer_fee_config: Option<TransferFeeConfig>) -> Vec<u8> {
+        let extensions = match transfer_fee_config {
+            Some(_) => vec![ExtensionType::TransferFeeConfig],
+            None => vec![],
+        };
+        let len = ExtensionType::try_calculate_account_len::<Mint>(&extensions).unwrap();
+        let mut data = vec![0; len];
+
+        let mut mint = StateWithExtensionsMut::<Mint>::unpack_uninitialized(&mut data).unwrap();
+        mint.base = Mint {
+            decimals: 6,
+            is_initialized: true,
+            ..Mint::default()
+        };
+        mint.pack_base();
+        if let Some(transfer_fee_config) = transfer_fee_config {
+            *mint.init_extension::<TransferFeeConfig>(true).unwrap() = transfer_fee_config;
+        }
+        mint.init_account_type().unwrap();
+
+        data
+    }
+
+    #[test]
+    fn test_calculate_transfer_fee() {
+        let data = mint_data(Some(TransferFeeConfig {
+            older_transfer_fee: transfer_fee(0, 100, 5_000), // 1%
+            newer_transfer_fee: transfer_fee(10, 200, 50_000), // 2% from epoch 10
+            ..TransferFeeConfig::default()
+        }));
+
+        // fees round up
+        assert_eq!(
+            calculate_transfer_fee(&spl_token_2022::ID, &data, 0, 101).unwrap(),
+            2
+        );
+        assert_eq!(
+            calculate_transfer_fee(&spl_token_2022::ID, &data, 0, 100_000).unwrap(),
+            1_000
+        );
+        // capped at the maximum fee
+        assert_eq!(
+            calculate_transfer_fee(&spl_token_2022::ID, &data, 0, 1_000_000).unwrap(),
+            5_000
+        );
+        assert_eq!(
+            calculate_transfer_fee(&spl_token_2022::ID, &data, 10, 1_000_000).unwrap(),
+            20_000
+        );
+        assert_eq!(
+            calculate_transfer_fee(&spl_token_2022::ID, &data, 10, 0).unwrap(),
+            0
+        );
+
+        // a deposit pays the fee on the transfer into the vault and again on the transfer into drift
+        let received_amount =
+            1_000_000 - calculate_transfer_fee(&spl_token_2022::ID, &data, 0, 1_000_000).unwrap();
+        let deposited_amount = received_amount
+            - calculate_transfer_fee(&spl_token_2022::ID, &data, 0, received_amount).unwrap();
+        assert_eq!(received_amount, 995_000);
+        assert_eq!(deposited_amount, 990_000);
+    }
+
+    #[test]
+    fn test_calculate_transfer_fee_without_extension() {
+        let data = mint_data(None);
+        assert_eq!(
+            calculate_transfer_fee(&spl_token_2022::ID, &data, 0, 1_000_000).unwrap(),
+            0
+        );
+
+        // spl token mints are never unpacked
+        assert_eq!(
+            calculate_transfer_fee(&TOKEN_PROGRAM_ID, &[], 0, 1_000_000).unwrap(),
+            0
+        );
+        assert_eq!(
+            calculate_transfer_fee(&Pubkey::new_unique(), &[], 0, 1_000_000).unwrap(),
+            0
+        );
+    }
+}
@@ -0,0 +1,308 @@
+//! End to end flows through the vaults program and its CPIs into drift, see [`harness`].
+
+mod harness;
+
+use drift::controller::position::PositionDirection;
+use drift::math::constants::ONE_YEAR;
+use drift::state::user::{User, UserStatus};
+use drift_vaults::instructions::VaultParams;
+use drift_vaults::state::{Vault, VaultDepositor, VaultDepositorBase, WithdrawUnit};
+
+use harness::{percent, sol, usdc, vault_depositor, vault_params, TestHarness};
+
+#[tokio::test]
+async fn test_deposit_trade_profit_share_liquidate_withdraw() {
+    let mut harness = TestHarness::start().await;
+    let vault = harness
+        .initialize_vault("flows", vault_params(percent(10)))
+        .await;
+    let depositor = harness.create_depositor(&vault, usdc(1_000)).await;
+    let depositor_vd = vault_depositor(&vault.pubkey, &depositor.authority.pubkey());
+
+    // deposit
+    harness
+        .deposit(&vault, &depositor, usdc(1_000))
+        .await
+        .unwrap();
+    assert_eq!(harness.token_balance(depositor.token_account).await, 0);
+    let vault_account: Vault = harness.load(vault.pubkey).await;
+    assert_eq!(vault_account.net_deposits, usdc(1_000) as i64);
+
+    // the delegate opens a 3x long
+    harness
+        .delegate_place_and_take(&vault, PositionDirection::Long, sol(30), false)
+        .await
+        .unwrap();
+    let user: User = harness.load(vault.drift_user).await;
+    assert_eq!(user.perp_positions[0].base_asset_amount, sol(30) as i64);
+
+    // the long is up, crank the depositor's profit share to the manager
+    harness.set_sol_price(105).await;
+    harness
+        .apply_profit_share(&vault, &depositor)
+        .await
+        .unwrap();
+    let vault_account: Vault = harness.load(vault.pubkey).await;
+    let vd: VaultDepositor = harness.load(depositor_vd).await;
+    assert!(vault_account.manager_total_profit_share > 0);
+    assert_eq!(
+        vd.profit_share_fee_paid,
+        vault_account.manager_total_profit_share
+    );
+    assert!(vd.get_vault_shares() < vault_account.total_shares);
+
+    // request everything, the open long keeps it from being withdrawn once the redeem period is over
+    harness
+        .request_withdraw(
+            &vault,
+            &depositor,
+            vd.get_vault_shares() as u64,
+            WithdrawUnit::Shares,
+        )
+        .await
+        .unwrap();
+    harness
+        .move_time_forward(vault_account.redeem_period + 1)
+        .await;
+    assert!(harness.withdraw(&vault, &depositor).await.is_err());
+
+    // so the depositor can hand the drift user to the liquidator, reduce only
+    harness.liquidate(&vault, &depositor).await.unwrap();
+    let vault_account: Vault = harness.load(vault.pubkey).await;
+    let user: User = harness.load(vault.drift_user).await;
+    assert!(vault_account.in_liquidation());
+    assert_eq!(user.delegate, vault_account.liquidation_delegate);
+    assert_ne!(user.status & UserStatus::ReduceOnly as u8, 0);
+
+    // the liquidation times out and the delegate takes back over to unwind
+    assert!(harness.reset_delegate(&vault).await.is_err());
+    harness.move_time_forward(60 * 60 + 1).await;
+    harness.reset_delegate(&vault).await.unwrap();
+    let user: User = harness.load(vault.drift_user).await;
+    assert_eq!(user.delegate, vault.delegate.pubkey());
+    assert_eq!(user.status & UserStatus::ReduceOnly as u8, 0);
+
+    harness
+        .delegate_place_and_take(&vault, PositionDirection::Short, sol(30), true)
+        .await
+        .unwrap();
+    harness.delegate_settle_pnl(&vault).await.unwrap();
+    let user: User = harness.load(vault.drift_user).await;
+    assert_eq!(user.perp_positions[0].base_asset_amount, 0);
+
+    // and the depositor's request can be paid out
+    harness.withdraw(&vault, &depositor).await.unwrap();
+    let vault_account: Vault = harness.load(vault.pubkey).await;
+    let vd: VaultDepositor = harness.load(depositor_vd).await;
+    assert_eq!(vd.get_vault_shares(), 0);
+    assert_eq!(vd.last_withdraw_request.shares, 0);
+    assert_eq!(vault_account.total_withdraw_requested, 0);
+    assert_eq!(
+        harness.token_balance(depositor.token_account).await,
+        vd.total_withdraws
+    );
+    assert!(vd.total_withdraws > 0);
+}
+
+#[tokio::test]
+async fn test_withdraw_before_redeem_period_fails() {
+    let mut harness = TestHarness::start().await;
+    let vault = harness.initialize_vault("early", vault_params(0)).await;
+    let depositor = harness.create_depositor(&vault, usdc(100)).await;
+
+    harness
+        .deposit(&vault, &depositor, usdc(100))
+        .await
+        .unwrap();
+    harness
+        .request_withdraw(&vault, &depositor, usdc(100), WithdrawUnit::Token)
+        .await
+        .unwrap();
+    assert!(harness.withdraw(&vault, &depositor).await.is_err());
+
+    harness.move_time_forward(60 * 60).await;
+    harness.withdraw(&vault, &depositor).await.unwrap();
+    assert_eq!(
+        harness.token_balance(depositor.token_account).await,
+        usdc(100)
+    );
+    assert_eq!(harness.token_balance(vault.token_account).await, 0);
+}
+
+#[tokio::test]
+async fn test_cancel_withdraw_request() {
+    let mut harness = TestHarness::start().await;
+    let vault = harness.initialize_vault("cancel", vault_params(0)).await;
+    let depositor = harness.create_depositor(&vault, usdc(100)).await;
+    let depositor_vd = vault_depositor(&vault.pubkey, &depositor.authority.pubkey());
+
+    harness
+        .deposit(&vault, &depositor, usdc(100))
+        .await
+        .unwrap();
+    let vd: VaultDepositor = harness.load(depositor_vd).await;
+    let shares = vd.get_vault_shares();
+
+    harness
+        .request_withdraw(&vault, &depositor, usdc(40), WithdrawUnit::Token)
+        .await
+        .unwrap();
+    let vault_account: Vault = harness.load(vault.pubkey).await;
+    assert_eq!(vault_account.total_withdraw_requested, usdc(40));
+
+    // cancelling keeps the shares and clears the request, so there's nothing left to withdraw
+    harness
+        .cancel_request_withdraw(&vault, &depositor)
+        .await
+        .unwrap();
+    let vault_account: Vault = harness.load(vault.pubkey).await;
+    let vd: VaultDepositor = harness.load(depositor_vd).await;
+    assert_eq!(vd.get_vault_shares(), shares);
+    assert_eq!(vd.last_withdraw_request.shares, 0);
+    assert_eq!(vault_account.total_withdraw_requested, 0);
+
+    harness.move_time_forward(60 * 60).await;
+    assert!(harness.withdraw(&vault, &depositor).await.is_err());
+    assert_eq!(harness.token_balance(depositor.token_account).await, 0);
+}
+
+#[tokio::test]
+async fn test_management_fee_withdrawn_by_manager() {
+    let mut harness = TestHarness::start().await;
+    let vault = harness
+        .initialize_vault(
+            "fees",
+            VaultParams {
+                management_fee: percent(10) as i64,
+                ..vault_params(0)
+            },
+        )
+        .await;
+    let depositor = harness.create_depositor(&vault, usdc(1_000)).await;
+    let depositor_vd = vault_depositor(&vault.pubkey, &depositor.authority.pubkey());
+    let manager_token_account = harness.create_token_account(&vault.manager.pubkey()).await;
+
+    harness
+        .deposit(&vault, &depositor, usdc(1_000))
+        .await
+        .unwrap();
+
+    // half a year of a 10% management fee, charged in shares once the depositor next touches the vault
+    harness.move_time_forward(ONE_YEAR as i64 / 2).await;
+    let vd: VaultDepositor = harness.load(depositor_vd).await;
+    harness
+        .request_withdraw(
+            &vault,
+            &depositor,
+            vd.get_vault_shares() as u64,
+            WithdrawUnit::Shares,
+        )
+        .await
+        .unwrap();
+    let vault_account: Vault = harness.load(vault.pubkey).await;
+    assert!(
+        vault_account.manager_total_fee > usdc(49) as i64
+            && vault_account.manager_total_fee <= usdc(50) as i64
+    );
+    let manager_shares = vault_account.total_shares - vault_account.user_shares;
+    assert!(manager_shares > 0);
+
+    harness
+        .manager_request_withdraw(&vault, manager_shares as u64, WithdrawUnit::Shares)
+        .await
+        .unwrap();
+    assert!(harness
+        .manager_withdraw(&vault, manager_token_account)
+        .await
+        .is_err());
+
+    harness
+        .move_time_forward(vault_account.redeem_period + 1)
+        .await;
+    harness.withdraw(&vault, &depositor).await.unwrap();
+    harness
+        .manager_withdraw(&vault, manager_token_account)
+        .await
+        .unwrap();
+
+    // the depositor paid the fee out of their share of equity, up to rounding
+    let depositor_balance = harness.token_balance(depositor.token_account).await;
+    let manager_balance = harness.token_balance(manager_token_account).await;
+    assert!(depositor_balance >= usdc(949) && depositor_balance <= usdc(951));
+    assert!(manager_balance >= usdc(49) && manager_balance <= usdc(51));
+    assert!(depositor_balance + manager_balance <= usdc(1_000));
+    let vault_account: Vault = harness.load(vault.pubkey).await;
+    assert_eq!(vault_account.manager_total_withdraws, manager_balance);
+    assert_eq!(vault_account.total_withdraw_requested, 0);
+}
+
+#[tokio::test]
+async fn test_liquidate_requires_stuck_withdraw() {
+    let mut harness = TestHarness::start().await;
+    let vault = harness.initialize_vault("liquidate", vault_params(0)).await;
+    let depositor = harness.create_depositor(&vault, usdc(1_000)).await;
+    let other_depositor = harness.create_depositor(&vault, usdc(100)).await;
+
+    harness
+        .deposit(&vault, &depositor, usdc(1_000))
+        .await
+        .unwrap();
+
+    // nothing requested
+    assert!(harness.liquidate(&vault, &depositor).await.is_err());
+
+    harness
+        .delegate_place_and_take(&vault, PositionDirection::Long, sol(30), false)
+        .await
+        .unwrap();
+    harness
+        .request_withdraw(&vault, &depositor, usdc(1_000), WithdrawUnit::Token)
+        .await
+        .unwrap();
+
+    // the redeem period has to be over first
+    assert!(harness.liquidate(&vault, &depositor).await.is_err());
+
+    let vault_account: Vault = harness.load(vault.pubkey).await;
+    harness
+        .move_time_forward(vault_account.redeem_period + 1)
+        .await;
+    harness.liquidate(&vault, &depositor).await.unwrap();
+    let vault_account: Vault = harness.load(vault.pubkey).await;
+    assert!(vault_account.in_liquidation());
+
+    // no deposits or second liquidation while it's ongoing
+    assert!(harness
+        .deposit(&vault, &other_depositor, usdc(100))
+        .await
+        .is_err());
+    assert!(harness.liquidate(&vault, &depositor).await.is_err());
+}
+
+#[tokio::test]
+async fn test_liquidate_fails_when_withdraw_can_be_paid() {
+    let mut harness = TestHarness::start().await;
+    let vault = harness.initialize_vault("solvent", vault_params(0)).await;
+    let depositor = harness.create_depositor(&vault, usdc(100)).await;
+
+    harness
+        .deposit(&vault, &depositor, usdc(100))
+        .await
+        .unwrap();
+    harness
+        .request_withdraw(&vault, &depositor, usdc(100), WithdrawUnit::Token)
+        .await
+        .unwrap();
+    harness.move_time_forward(60 * 60 + 1).await;
+
+    // the vault holds only usdc so the request can be paid out, the depositor has to withdraw instead
+    assert!(harness.liquidate(&vault, &depositor).await.is_err());
+    let vault_account: Vault = harness.load(vault.pubkey).await;
+    assert!(!vault_account.in_liquidation());
+
+    harness.withdraw(&vault, &depositor).await.unwrap();
+    assert_eq!(
+        harness.token_balance(depositor.token_account).await,
+        usdc(100)
+    );
+}
@@ -0,0 +1,962 @@
+//! Runs drift and the vaults program in a [`ProgramTest`] bank so full instruction flows, CPIs into drift
+//! included, can be tested without the ts suite.
+//!
+//! drift and the mock pyth program are loaded from the same `tests/fixtures` the ts suite deploys through
+//! `Anchor.toml`'s `test.genesis`. The `.so` files aren't tracked, build them once from protocol-v2 at the `drift`
+//! rev pinned in `Cargo.toml`, pyth being its mock oracle program rather than the mainnet one at the same address:
+//!
+//! ```shell
+//! git clone --depth 1 --branch v2.109.0 https://github.com/drift-labs/protocol-v2.git
+//! (cd protocol-v2 && anchor build -p drift && anchor build -p pyth)
+//! cp protocol-v2/target/deploy/{drift,pyth}.so tests/fixtures/
+//! ```
+//!
+//! The vaults program runs natively so the tests always exercise the code in this tree, no `anchor build` needed.
+//!
+//! The bank's clock is pinned to [`START_TS`] and only moves through [`TestHarness::move_time_forward`], which
+//! like the ts `BankrunContextWrapper::moveTimeForward` leaves the slot alone so oracles never go stale.
+#![allow(dead_code)]
+
+use anchor_lang::prelude::{AccountMeta, Clock, Pubkey};
+use anchor_lang::solana_program::hash::hash;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::program_pack::Pack;
+use anchor_lang::solana_program::{system_program, sysvar};
+use anchor_lang::{AnchorSerialize, InstructionData, ToAccountMetas};
+use anchor_spl::token::spl_token;
+use drift::controller::position::PositionDirection;
+use drift::math::constants::{
+    BASE_PRECISION_U64, PEG_PRECISION, PERCENTAGE_PRECISION_U64, PRICE_PRECISION_U64,
+    QUOTE_PRECISION_U64, SPOT_RATE_PRECISION_U32, SPOT_WEIGHT_PRECISION,
+};
+use drift::state::oracle::OracleSource;
+use drift::state::order_params::OrderParams;
+use drift::state::perp_market::ContractTier;
+use drift::state::spot_market::AssetTier;
+use drift::state::user::{MarketType, OrderType};
+use drift_vaults::instructions::VaultParams;
+use drift_vaults::state::WithdrawUnit;
+use solana_program_test::{processor, BanksClientError, ProgramTest, ProgramTestContext};
+use solana_sdk::account::Account;
+use solana_sdk::pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+use solana_sdk::system_instruction;
+use solana_sdk::transaction::Transaction;
+
+pub const START_TS: i64 = 1_700_000_000;
+
+pub const QUOTE_SPOT_MARKET_INDEX: u16 = 0;
+pub const SOL_PERP_MARKET_INDEX: u16 = 0;
+pub const INITIAL_SOL_PRICE: i64 = 100;
+
+const PYTH_PROGRAM_ID: Pubkey = pubkey!("FsJ3A3u2vn5cTVofAjvy6y5kwABJAqYWpe4975bi2epH");
+const PYTH_PRICE_ACCOUNT_SIZE: usize = 3312;
+const PYTH_EXPO: i32 = -7;
+
+const FIXTURES_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../../tests/fixtures");
+const FIXTURE_PROGRAMS: [&str; 2] = ["drift", "pyth"];
+
+/// `ProgramTest` wants a `fn(&Pubkey, &[AccountInfo], &[u8])` but anchor's entry ties the account infos to the
+/// slice's lifetime, so hand it a copy that lives long enough.
+fn process_vaults_instruction(
+    program_id: &Pubkey,
+    accounts: &[anchor_lang::prelude::AccountInfo],
+    data: &[u8],
+) -> anchor_lang::solana_program::entrypoint::ProgramResult {
+    let accounts = Box::leak(Box::new(accounts.to_vec()));
+    drift_vaults::entry(program_id, accounts, data)
+}
+
+pub fn drift_state() -> Pubkey {
+    Pubkey::find_program_address(&[b"drift_state"], &drift::id()).0
+}
+
+pub fn drift_signer() -> Pubkey {
+    Pubkey::find_program_address(&[b"drift_signer"], &drift::id()).0
+}
+
+pub fn spot_market(market_index: u16) -> Pubkey {
+    Pubkey::find_program_address(
+        &[b"spot_market", market_index.to_le_bytes().as_ref()],
+        &drift::id(),
+    )
+    .0
+}
+
+pub fn spot_market_vault(market_index: u16) -> Pubkey {
+    Pubkey::find_program_address(
+        &[b"spot_market_vault", market_index.to_le_bytes().as_ref()],
+        &drift::id(),
+    )
+    .0
+}
+
+pub fn insurance_fund_vault(market_index: u16) -> Pubkey {
+    Pubkey::find_program_address(
+        &[b"insurance_fund_vault", market_index.to_le_bytes().as_ref()],
+        &drift::id(),
+    )
+    .0
+}
+
+pub fn perp_market(market_index: u16) -> Pubkey {
+    Pubkey::find_program_address(
+        &[b"perp_market", market_index.to_le_bytes().as_ref()],
+        &drift::id(),
+    )
+    .0
+}
+
+pub fn drift_user(authority: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[b"user", authority.as_ref(), 0_u16.to_le_bytes().as_ref()],
+        &drift::id(),
+    )
+    .0
+}
+
+pub fn drift_user_stats(authority: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"user_stats", authority.as_ref()], &drift::id()).0
+}
+
+pub fn vault_address(name: &[u8; 32]) -> Pubkey {
+    Pubkey::find_program_address(&[b"vault", name.as_ref()], &drift_vaults::id()).0
+}
+
+pub fn vault_token_account(vault: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[b"vault_token_account", vault.as_ref()],
+        &drift_vaults::id(),
+    )
+    .0
+}
+
+pub fn vault_depositor(vault: &Pubkey, authority: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[b"vault_depositor", vault.as_ref(), authority.as_ref()],
+        &drift_vaults::id(),
+    )
+    .0
+}
+
+/// Anchor instruction data for the mock pyth program, which has no rust crate to build it with
+fn pyth_instruction_data(name: &str, args: impl AnchorSerialize) -> Vec<u8> {
+    let mut data = hash(format!("global:{}", name).as_bytes()).to_bytes()[..8].to_vec();
+    args.serialize(&mut data).unwrap();
+    data
+}
+
+/// A vault, its manager and delegate, see [`TestHarness::initialize_vault`]
+pub struct TestVault {
+    pub pubkey: Pubkey,
+    pub manager: Keypair,
+    pub delegate: Keypair,
+    pub drift_user: Pubkey,
+    pub drift_user_stats: Pubkey,
+    pub token_account: Pubkey,
+}
+
+/// A depositor with a funded token account, see [`TestHarness::create_depositor`]
+pub struct TestDepositor {
+    pub authority: Keypair,
+    pub token_account: Pubkey,
+}
+
+pub struct TestHarness {
+    pub context: ProgramTestContext,
+    pub usdc_mint: Keypair,
+    pub sol_oracle: Keypair,
+}
+
+impl TestHarness {
+    /// Starts a bank with drift initialized: a usdc spot market and a sol perp market priced by a mock pyth oracle
+    /// at [`INITIAL_SOL_PRICE`], mirroring the ts suite's setup.
+    pub async fn start() -> Self {
+        for name in FIXTURE_PROGRAMS {
+            let path = std::path::Path::new(FIXTURES_DIR).join(format!("{}.so", name));
+            assert!(
+                path.exists(),
+                "missing {}, build it from protocol-v2 as described in the harness docs",
+                path.display()
+            );
+        }
+        std::env::set_var("SBF_OUT_DIR", FIXTURES_DIR);
+
+        let mut program_test = ProgramTest::new(
+            "drift_vaults",
+            drift_vaults::id(),
+            processor!(process_vaults_instruction),
+        );
+        program_test.prefer_bpf(false);
+        program_test.add_program("drift", drift::id(), None);
+        program_test.add_program("pyth", PYTH_PROGRAM_ID, None);
+        program_test.set_compute_max_units(1_400_000);
+
+        let mut harness = Self {
+            context: program_test.start_with_context().await,
+            usdc_mint: Keypair::new(),
+            sol_oracle: Keypair::new(),
+        };
+
+        let mut clock = harness.clock().await;
+        clock.unix_timestamp = START_TS;
+        harness.context.set_sysvar(&clock);
+
+        harness.initialize_drift().await;
+
+        harness
+    }
+
+    pub fn admin(&self) -> Pubkey {
+        self.context.payer.pubkey()
+    }
+
+    /// Signs with the payer and `signers` over a fresh blockhash, so repeating an instruction isn't rejected as a
+    /// duplicate transaction.
+    pub async fn process(
+        &mut self,
+        instructions: &[Instruction],
+        signers: &[&Keypair],
+    ) -> Result<(), BanksClientError> {
+        let blockhash = self.context.get_new_latest_blockhash().await.unwrap();
+        let transaction = {
+            let mut all_signers = vec![&self.context.payer];
+            all_signers.extend(signers);
+            Transaction::new_signed_with_payer(
+                instructions,
+                Some(&self.context.payer.pubkey()),
+                &all_signers,
+                blockhash,
+            )
+        };
+
+        self.context
+            .banks_client
+            .process_transaction(transaction)
+            .await
+    }
+
+    pub async fn get_account(&mut self, address: Pubkey) -> Account {
+        self.context
+            .banks_client
+            .get_account(address)
+            .await
+            .unwrap()
+            .unwrap_or_else(|| panic!("account {} not found", address))
+    }
+
+    /// Reads a zero copy account, e.g. a [`Vault`](drift_vaults::state::Vault) or drift
+    /// [`User`](drift::state::user::User)
+    pub async fn load<T: bytemuck::Pod>(&mut self, address: Pubkey) -> T {
+        let account = self.get_account(address).await;
+        bytemuck::pod_read_unaligned(&account.data[8..8 + std::mem::size_of::<T>()])
+    }
+
+    pub async fn token_balance(&mut self, token_account: Pubkey) -> u64 {
+        let account = self.get_account(token_account).await;
+        spl_token::state::Account::unpack(&account.data)
+            .unwrap()
+            .amount
+    }
+
+    pub async fn clock(&mut self) -> Clock {
+        self.context
+            .banks_client
+            .get_sysvar::<Clock>()
+            .await
+            .unwrap()
+    }
+
+    pub async fn move_time_forward(&mut self, seconds: i64) {
+        let mut clock = self.clock().await;
+        clock.unix_timestamp += seconds;
+        self.context.set_sysvar(&clock);
+    }
+
+    pub async fn create_token_account(&mut self, owner: &Pubkey) -> Pubkey {
+        let token_account = Keypair::new();
+        let rent = self.context.banks_client.get_rent().await.unwrap();
+
+        self.process(
+            &[
+                system_instruction::create_account(
+                    &self.admin(),
+                    &token_account.pubkey(),
+                    rent.minimum_balance(spl_token::state::Account::LEN),
+                    spl_token::state::Account::LEN as u64,
+                    &spl_token::id(),
+                ),
+                spl_token::instruction::initialize_account(
+                    &spl_token::id(),
+                    &token_account.pubkey(),
+                    &self.usdc_mint.pubkey(),
+                    owner,
+                )
+                .unwrap(),
+            ],
+            &[&token_account],
+        )
+        .await
+        .unwrap();
+
+        token_account.pubkey()
+    }
+
+    pub async fn mint_usdc(&mut self, token_account: &Pubkey, amount: u64) {
+        let mint_to = spl_token::instruction::mint_to(
+            &spl_token::id(),
+            &self.usdc_mint.pubkey(),
+            token_account,
+            &self.admin(),
+            &[],
+            amount,
+        )
+        .unwrap();
+
+        self.process(&[mint_to], &[]).await.unwrap();
+    }
+
+    /// Sets the sol oracle to `price` dollars
+    pub async fn set_sol_price(&mut self, price: i64) {
+        let set_price = Instruction {
+            program_id: PYTH_PROGRAM_ID,
+            accounts: vec![AccountMeta::new(self.sol_oracle.pubkey(), false)],
+            data: pyth_instruction_data("set_price", price * 10_i64.pow(-PYTH_EXPO as u32)),
+        };
+
+        self.process(&[set_price], &[]).await.unwrap();
+    }
+
+    /// Markets in the order drift's `load_maps` reads them: oracles, spot markets, perp markets
+    pub fn market_accounts(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new_readonly(self.sol_oracle.pubkey(), false),
+            AccountMeta::new(spot_market(QUOTE_SPOT_MARKET_INDEX), false),
+            AccountMeta::new(perp_market(SOL_PERP_MARKET_INDEX), false),
+        ]
+    }
+
+    async fn initialize_drift(&mut self) {
+        let admin = self.admin();
+        let rent = self.context.banks_client.get_rent().await.unwrap();
+        let usdc_mint = self.usdc_mint.insecure_clone();
+        let sol_oracle = self.sol_oracle.insecure_clone();
+
+        let create_usdc_mint = [
+            system_instruction::create_account(
+                &admin,
+                &usdc_mint.pubkey(),
+                rent.minimum_balance(spl_token::state::Mint::LEN),
+                spl_token::state::Mint::LEN as u64,
+                &spl_token::id(),
+            ),
+            spl_token::instruction::initialize_mint(
+                &spl_token::id(),
+                &usdc_mint.pubkey(),
+                &admin,
+                None,
+                6,
+            )
+            .unwrap(),
+        ];
+        self.process(&create_usdc_mint, &[&usdc_mint])
+            .await
+            .unwrap();
+
+        let create_sol_oracle = [
+            system_instruction::create_account(
+                &admin,
+                &sol_oracle.pubkey(),
+                rent.minimum_balance(PYTH_PRICE_ACCOUNT_SIZE),
+                PYTH_PRICE_ACCOUNT_SIZE as u64,
+                &PYTH_PROGRAM_ID,
+            ),
+            Instruction {
+                program_id: PYTH_PROGRAM_ID,
+                accounts: vec![AccountMeta::new(sol_oracle.pubkey(), false)],
+                data: pyth_instruction_data(
+                    "initialize",
+                    (
+                        INITIAL_SOL_PRICE * 10_i64.pow(-PYTH_EXPO as u32),
+                        PYTH_EXPO,
+                        0_u64,
+                    ),
+                ),
+            },
+        ];
+        self.process(&create_sol_oracle, &[&sol_oracle])
+            .await
+            .unwrap();
+
+        let initialize = Instruction {
+            program_id: drift::id(),
+            accounts: drift::accounts::Initialize {
+                admin,
+                state: drift_state(),
+                quote_asset_mint: usdc_mint.pubkey(),
+                drift_signer: drift_signer(),
+                rent: sysvar::rent::id(),
+                system_program: system_program::id(),
+                token_program: spl_token::id(),
+            }
+            .to_account_metas(None),
+            data: drift::instruction::Initialize {}.data(),
+        };
+
+        let initialize_quote_spot_market = Instruction {
+            program_id: drift::id(),
+            accounts: drift::accounts::InitializeSpotMarket {
+                spot_market: spot_market(QUOTE_SPOT_MARKET_INDEX),
+                spot_market_mint: usdc_mint.pubkey(),
+                spot_market_vault: spot_market_vault(QUOTE_SPOT_MARKET_INDEX),
+                insurance_fund_vault: insurance_fund_vault(QUOTE_SPOT_MARKET_INDEX),
+                drift_signer: drift_signer(),
+                state: drift_state(),
+                oracle: Pubkey::default(),
+                admin,
+                rent: sysvar::rent::id(),
+                system_program: system_program::id(),
+                token_program: spl_token::id(),
+            }
+            .to_account_metas(None),
+            data: drift::instruction::InitializeSpotMarket {
+                optimal_utilization: SPOT_RATE_PRECISION_U32 / 2,
+                optimal_borrow_rate: SPOT_RATE_PRECISION_U32,
+                max_borrow_rate: SPOT_RATE_PRECISION_U32,
+                oracle_source: OracleSource::QuoteAsset,
+                initial_asset_weight: SPOT_WEIGHT_PRECISION,
+                maintenance_asset_weight: SPOT_WEIGHT_PRECISION,
+                initial_liability_weight: SPOT_WEIGHT_PRECISION,
+                maintenance_liability_weight: SPOT_WEIGHT_PRECISION,
+                imf_factor: 0,
+                liquidator_fee: 0,
+                if_liquidation_fee: 0,
+                active_status: true,
+                asset_tier: AssetTier::Collateral,
+                scale_initial_asset_weight_start: 0,
+                withdraw_guard_threshold: 0,
+                order_tick_size: 1,
+                order_step_size: 1,
+                if_total_factor: 0,
+                name: [0; 32],
+            }
+            .data(),
+        };
+
+        let update_withdraw_guard_threshold = Instruction {
+            program_id: drift::id(),
+            accounts: drift::accounts::AdminUpdateSpotMarket {
+                admin,
+                state: drift_state(),
+                spot_market: spot_market(QUOTE_SPOT_MARKET_INDEX),
+            }
+            .to_account_metas(None),
+            data: drift::instruction::UpdateWithdrawGuardThreshold {
+                withdraw_guard_threshold: 10_u64.pow(10) * QUOTE_PRECISION_U64,
+            }
+            .data(),
+        };
+
+        self.process(
+            &[
+                initialize,
+                initialize_quote_spot_market,
+                update_withdraw_guard_threshold,
+            ],
+            &[],
+        )
+        .await
+        .unwrap();
+
+        // same amm as the ts suite: 5 * 10^13 * sqrt(PRICE_PRECISION) reserves pegged at the oracle price
+        let amm_reserves = 5 * 10_u128.pow(13) * 1_000;
+        let initialize_sol_perp_market = Instruction {
+            program_id: drift::id(),
+            accounts: drift::accounts::InitializePerpMarket {
+                admin,
+                state: drift_state(),
+                perp_market: perp_market(SOL_PERP_MARKET_INDEX),
+                oracle: sol_oracle.pubkey(),
+                rent: sysvar::rent::id(),
+                system_program: system_program::id(),
+            }
+            .to_account_metas(None),
+            data: drift::instruction::InitializePerpMarket {
+                market_index: SOL_PERP_MARKET_INDEX,
+                amm_base_asset_reserve: amm_reserves,
+                amm_quote_asset_reserve: amm_reserves,
+                amm_periodicity: 0,
+                amm_peg_multiplier: INITIAL_SOL_PRICE as u128 * PEG_PRECISION,
+                oracle_source: OracleSource::Pyth,
+                contract_tier: ContractTier::Speculative,
+                margin_ratio_initial: 2_000,
+                margin_ratio_maintenance: 500,
+                liquidator_fee: 0,
+                if_liquidation_fee: 10_000,
+                imf_factor: 0,
+                active_status: true,
+                base_spread: 0,
+                max_spread: 142_500,
+                max_open_interest: 0,
+                max_revenue_withdraw_per_period: 0,
+                quote_max_insurance: 0,
+                order_step_size: BASE_PRECISION_U64 / 10_000,
+                order_tick_size: PRICE_PRECISION_U64 / 100_000,
+                min_order_size: BASE_PRECISION_U64 / 10_000,
+                concentration_coef_scale: 1,
+                curve_update_intensity: 100,
+                amm_jit_intensity: 0,
+                name: [0; 32],
+            }
+            .data(),
+        };
+
+        // fill market orders against the amm right away
+        let update_perp_auction_duration = Instruction {
+            program_id: drift::id(),
+            accounts: drift::accounts::AdminUpdateState {
+                admin,
+                state: drift_state(),
+            }
+            .to_account_metas(None),
+            data: drift::instruction::UpdatePerpAuctionDuration {
+                min_perp_auction_duration: 0,
+            }
+            .data(),
+        };
+
+        self.process(
+            &[initialize_sol_perp_market, update_perp_auction_duration],
+            &[],
+        )
+        .await
+        .unwrap();
+    }
+
+    /// Creates a usdc vault and sets its delegate
+    pub async fn initialize_vault(&mut self, name: &str, params: VaultParams) -> TestVault {
+        let mut vault_name = [0; 32];
+        vault_name[..name.len()].copy_from_slice(name.as_bytes());
+        let params = VaultParams {
+            name: vault_name,
+            spot_market_index: QUOTE_SPOT_MARKET_INDEX,
+            ..params
+        };
+
+        let pubkey = vault_address(&vault_name);
+        let vault = TestVault {
+            pubkey,
+            manager: Keypair::new(),
+            delegate: Keypair::new(),
+            drift_user: drift_user(&pubkey),
+            drift_user_stats: drift_user_stats(&pubkey),
+            token_account: vault_token_account(&pubkey),
+        };
+
+        let initialize_vault = Instruction {
+            program_id: drift_vaults::id(),
+            accounts: drift_vaults::accounts::InitializeVault {
+                vault: vault.pubkey,
+                token_account: vault.token_account,
+                drift_user_stats: vault.drift_user_stats,
+                drift_user: vault.drift_user,
+                drift_state: drift_state(),
+                drift_spot_market: spot_market(QUOTE_SPOT_MARKET_INDEX),
+                drift_spot_market_mint: self.usdc_mint.pubkey(),
+                manager: vault.manager.pubkey(),
+                payer: self.admin(),
+                rent: sysvar::rent::id(),
+                system_program: system_program::id(),
+                drift_program: drift::id(),
+                token_program: spl_token::id(),
+            }
+            .to_account_metas(None),
+            data: drift_vaults::instruction::InitializeVault { params }.data(),
+        };
+
+        let update_delegate = Instruction {
+            program_id: drift_vaults::id(),
+            accounts: drift_vaults::accounts::UpdateDelegate {
+                vault: vault.pubkey,
+                manager: vault.manager.pubkey(),
+                drift_user: vault.drift_user,
+                drift_program: drift::id(),
+            }
+            .to_account_metas(None),
+            data: drift_vaults::instruction::UpdateDelegate {
+                delegate: vault.delegate.pubkey(),
+            }
+            .data(),
+        };
+
+        self.process(&[initialize_vault, update_delegate], &[&vault.manager])
+            .await
+            .unwrap();
+
+        vault
+    }
+
+    /// Creates a depositor for `vault` with `amount` usdc in its token account
+    pub async fn create_depositor(&mut self, vault: &TestVault, amount: u64) -> TestDepositor {
+        let authority = Keypair::new();
+        let token_account = self.create_token_account(&authority.pubkey()).await;
+        self.mint_usdc(&token_account, amount).await;
+
+        let initialize_vault_depositor = Instruction {
+            program_id: drift_vaults::id(),
+            accounts: drift_vaults::accounts::InitializeVaultDepositor {
+                vault: vault.pubkey,
+                vault_depositor: vault_depositor(&vault.pubkey, &authority.pubkey()),
+                authority: authority.pubkey(),
+                payer: self.admin(),
+                rent: sysvar::rent::id(),
+                system_program: system_program::id(),
+            }
+            .to_account_metas(None),
+            data: drift_vaults::instruction::InitializeVaultDepositor {}.data(),
+        };
+
+        self.process(&[initialize_vault_depositor], &[])
+            .await
+            .unwrap();
+
+        TestDepositor {
+            authority,
+            token_account,
+        }
+    }
+
+    pub async fn deposit(
+        &mut self,
+        vault: &TestVault,
+        depositor: &TestDepositor,
+        amount: u64,
+    ) -> Result<(), BanksClientError> {
+        let mut accounts = drift_vaults::accounts::Deposit {
+            vault: vault.pubkey,
+            vault_depositor: vault_depositor(&vault.pubkey, &depositor.authority.pubkey()),
+            authority: depositor.authority.pubkey(),
+            vault_token_account: vault.token_account,
+            drift_user_stats: vault.drift_user_stats,
+            drift_user: vault.drift_user,
+            drift_state: drift_state(),
+            drift_spot_market_vault: spot_market_vault(QUOTE_SPOT_MARKET_INDEX),
+            user_token_account: depositor.token_account,
+            drift_program: drift::id(),
+            token_program: spl_token::id(),
+        }
+        .to_account_metas(None);
+        accounts.extend(self.market_accounts());
+
+        let deposit = Instruction {
+            program_id: drift_vaults::id(),
+            accounts,
+            data: drift_vaults::instruction::Deposit { amount }.data(),
+        };
+
+        self.process(&[deposit], &[&depositor.authority]).await
+    }
+
+    pub async fn request_withdraw(
+        &mut self,
+        vault: &TestVault,
+        depositor: &TestDepositor,
+        withdraw_amount: u64,
+        withdraw_unit: WithdrawUnit,
+    ) -> Result<(), BanksClientError> {
+        let mut accounts = drift_vaults::accounts::RequestWithdraw {
+            vault: vault.pubkey,
+            vault_depositor: vault_depositor(&vault.pubkey, &depositor.authority.pubkey()),
+            authority: depositor.authority.pubkey(),
+            drift_user_stats: vault.drift_user_stats,
+            drift_user: vault.drift_user,
+        }
+        .to_account_metas(None);
+        accounts.extend(self.market_accounts());
+
+        let request_withdraw = Instruction {
+            program_id: drift_vaults::id(),
+            accounts,
+            data: drift_vaults::instruction::RequestWithdraw {
+                withdraw_amount,
+                withdraw_unit,
+            }
+            .data(),
+        };
+
+        self.process(&[request_withdraw], &[&depositor.authority])
+            .await
+    }
+
+    pub async fn withdraw(
+        &mut self,
+        vault: &TestVault,
+        depositor: &TestDepositor,
+    ) -> Result<(), BanksClientError> {
+        let mut accounts = drift_vaults::accounts::Withdraw {
+            vault: vault.pubkey,
+            vault_depositor: vault_depositor(&vault.pubkey, &depositor.authority.pubkey()),
+            authority: depositor.authority.pubkey(),
+            vault_token_account: vault.token_account,
+            drift_user_stats: vault.drift_user_stats,
+            drift_user: vault.drift_user,
+            drift_state: drift_state(),
+            drift_spot_market_vault: spot_market_vault(QUOTE_SPOT_MARKET_INDEX),
+            drift_signer: drift_signer(),
+            user_token_account: depositor.token_account,
+            drift_program: drift::id(),
+            token_program: spl_token::id(),
+        }
+        .to_account_metas(None);
+        accounts.extend(self.market_accounts());
+
+        let withdraw = Instruction {
+            program_id: drift_vaults::id(),
+            accounts,
+            data: drift_vaults::instruction::Withdraw {}.data(),
+        };
+
+        self.process(&[withdraw], &[&depositor.authority]).await
+    }
+
+    pub async fn cancel_request_withdraw(
+        &mut self,
+        vault: &TestVault,
+        depositor: &TestDepositor,
+    ) -> Result<(), BanksClientError> {
+        let mut accounts = drift_vaults::accounts::CancelWithdrawRequest {
+            vault: vault.pubkey,
+            vault_depositor: vault_depositor(&vault.pubkey, &depositor.authority.pubkey()),
+            authority: depositor.authority.pubkey(),
+            drift_user_stats: vault.drift_user_stats,
+            drift_user: vault.drift_user,
+        }
+        .to_account_metas(None);
+        accounts.extend(self.market_accounts());
+
+        let cancel_request_withdraw = Instruction {
+            program_id: drift_vaults::id(),
+            accounts,
+            data: drift_vaults::instruction::CancelRequestWithdraw {}.data(),
+        };
+
+        self.process(&[cancel_request_withdraw], &[&depositor.authority])
+            .await
+    }
+
+    pub async fn manager_request_withdraw(
+        &mut self,
+        vault: &TestVault,
+        withdraw_amount: u64,
+        withdraw_unit: WithdrawUnit,
+    ) -> Result<(), BanksClientError> {
+        let mut accounts = drift_vaults::accounts::ManagerRequestWithdraw {
+            vault: vault.pubkey,
+            manager: vault.manager.pubkey(),
+            drift_user_stats: vault.drift_user_stats,
+            drift_user: vault.drift_user,
+        }
+        .to_account_metas(None);
+        accounts.extend(self.market_accounts());
+
+        let manager_request_withdraw = Instruction {
+            program_id: drift_vaults::id(),
+            accounts,
+            data: drift_vaults::instruction::ManagerRequestWithdraw {
+                withdraw_amount,
+                withdraw_unit,
+            }
+            .data(),
+        };
+
+        self.process(&[manager_request_withdraw], &[&vault.manager])
+            .await
+    }
+
+    /// Pays the manager's matured withdraw request out to `token_account`, which the manager must own
+    pub async fn manager_withdraw(
+        &mut self,
+        vault: &TestVault,
+        token_account: Pubkey,
+    ) -> Result<(), BanksClientError> {
+        let mut accounts = drift_vaults::accounts::ManagerWithdraw {
+            vault: vault.pubkey,
+            manager: vault.manager.pubkey(),
+            vault_token_account: vault.token_account,
+            drift_user_stats: vault.drift_user_stats,
+            drift_user: vault.drift_user,
+            drift_state: drift_state(),
+            drift_spot_market_vault: spot_market_vault(QUOTE_SPOT_MARKET_INDEX),
+            drift_signer: drift_signer(),
+            user_token_account: token_account,
+            drift_program: drift::id(),
+            token_program: spl_token::id(),
+        }
+        .to_account_metas(None);
+        accounts.extend(self.market_accounts());
+
+        let manager_withdraw = Instruction {
+            program_id: drift_vaults::id(),
+            accounts,
+            data: drift_vaults::instruction::ManagerWithdraw {}.data(),
+        };
+
+        self.process(&[manager_withdraw], &[&vault.manager]).await
+    }
+
+    /// The manager cranks the depositor's profit share
+    pub async fn apply_profit_share(
+        &mut self,
+        vault: &TestVault,
+        depositor: &TestDepositor,
+    ) -> Result<(), BanksClientError> {
+        let mut accounts = drift_vaults::accounts::ApplyProfitShare {
+            vault: vault.pubkey,
+            vault_depositor: vault_depositor(&vault.pubkey, &depositor.authority.pubkey()),
+            manager: vault.manager.pubkey(),
+            drift_user_stats: vault.drift_user_stats,
+            drift_user: vault.drift_user,
+            drift_state: drift_state(),
+            drift_signer: drift_signer(),
+            drift_program: drift::id(),
+        }
+        .to_account_metas(None);
+        accounts.extend(self.market_accounts());
+
+        let apply_profit_share = Instruction {
+            program_id: drift_vaults::id(),
+            accounts,
+            data: drift_vaults::instruction::ApplyProfitShare {}.data(),
+        };
+
+        self.process(&[apply_profit_share], &[&vault.manager]).await
+    }
+
+    pub async fn liquidate(
+        &mut self,
+        vault: &TestVault,
+        depositor: &TestDepositor,
+    ) -> Result<(), BanksClientError> {
+        let mut accounts = drift_vaults::accounts::Liquidate {
+            vault: vault.pubkey,
+            vault_depositor: vault_depositor(&vault.pubkey, &depositor.authority.pubkey()),
+            authority: depositor.authority.pubkey(),
+            drift_user_stats: vault.drift_user_stats,
+            drift_user: vault.drift_user,
+            drift_program: drift::id(),
+        }
+        .to_account_metas(None);
+        accounts.extend(self.market_accounts());
+
+        let liquidate = Instruction {
+            program_id: drift_vaults::id(),
+            accounts,
+            data: drift_vaults::instruction::Liquidate {}.data(),
+        };
+
+        self.process(&[liquidate], &[&depositor.authority]).await
+    }
+
+    pub async fn reset_delegate(&mut self, vault: &TestVault) -> Result<(), BanksClientError> {
+        let reset_delegate = Instruction {
+            program_id: drift_vaults::id(),
+            accounts: drift_vaults::accounts::ResetDelegate {
+                vault: vault.pubkey,
+                drift_user: vault.drift_user,
+                drift_program: drift::id(),
+            }
+            .to_account_metas(None),
+            data: drift_vaults::instruction::ResetDelegate {}.data(),
+        };
+
+        self.process(&[reset_delegate], &[]).await
+    }
+
+    /// The vault's delegate fills a sol perp market order against the amm
+    pub async fn delegate_place_and_take(
+        &mut self,
+        vault: &TestVault,
+        direction: PositionDirection,
+        base_asset_amount: u64,
+        reduce_only: bool,
+    ) -> Result<(), BanksClientError> {
+        let mut accounts = drift::accounts::PlaceAndTake {
+            state: drift_state(),
+            user: vault.drift_user,
+            user_stats: vault.drift_user_stats,
+            authority: vault.delegate.pubkey(),
+        }
+        .to_account_metas(None);
+        accounts.extend(self.market_accounts());
+
+        let place_and_take = Instruction {
+            program_id: drift::id(),
+            accounts,
+            data: drift::instruction::PlaceAndTakePerpOrder {
+                params: OrderParams {
+                    order_type: OrderType::Market,
+                    market_type: MarketType::Perp,
+                    direction,
+                    base_asset_amount,
+                    market_index: SOL_PERP_MARKET_INDEX,
+                    reduce_only,
+                    ..OrderParams::default()
+                },
+                success_condition: None,
+            }
+            .data(),
+        };
+
+        self.process(&[place_and_take], &[&vault.delegate]).await
+    }
+
+    /// The vault's delegate settles the sol perp pnl into the vault's usdc balance
+    pub async fn delegate_settle_pnl(&mut self, vault: &TestVault) -> Result<(), BanksClientError> {
+        let mut accounts = drift::accounts::SettlePNL {
+            state: drift_state(),
+            user: vault.drift_user,
+            authority: vault.delegate.pubkey(),
+            spot_market_vault: spot_market_vault(QUOTE_SPOT_MARKET_INDEX),
+        }
+        .to_account_metas(None);
+        accounts.extend(self.market_accounts());
+
+        let settle_pnl = Instruction {
+            program_id: drift::id(),
+            accounts,
+            data: drift::instruction::SettlePnl {
+                market_index: SOL_PERP_MARKET_INDEX,
+            }
+            .data(),
+        };
+
+        self.process(&[settle_pnl], &[&vault.delegate]).await
+    }
+}
+
+/// A vault with no fees beyond `profit_share` and a one hour redeem period
+pub fn vault_params(profit_share: u32) -> VaultParams {
+    VaultParams {
+        name: [0; 32],
+        redeem_period: 60 * 60,
+        max_tokens: 0,
+        management_fee: 0,
+        min_deposit_amount: 0,
+        profit_share,
+        hurdle_rate: 0,
+        spot_market_index: QUOTE_SPOT_MARKET_INDEX,
+        permissioned: false,
+    }
+}
+
+pub fn usdc(amount: u64) -> u64 {
+    amount * QUOTE_PRECISION_U64
+}
+
+pub fn sol(amount: u64) -> u64 {
+    amount * BASE_PRECISION_U64
+}
+
+pub fn percent(amount: u32) -> u32 {
+    amount * (PERCENTAGE_PRECISION_U64 / 100) as u32
+}
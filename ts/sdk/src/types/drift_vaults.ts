@@ -163,6 +163,84 @@ export type DriftVaults = {
 				}
 			];
 		},
+		{
+			name: 'initializeVaultFromTemplate';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'tokenAccount';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftUserStats';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftUser';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftState';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftSpotMarket';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'driftSpotMarketMint';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'manager';
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: 'payer';
+					isMut: true;
+					isSigner: true;
+				},
+				{
+					name: 'rent';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'systemProgram';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'driftProgram';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'tokenProgram';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [
+				{
+					name: 'params';
+					type: {
+						defined: 'VaultFromTemplateParams';
+					};
+				}
+			];
+		},
 		{
 			name: 'updateDelegate';
 			accounts: [
@@ -285,30 +363,45 @@ export type DriftVaults = {
 			];
 		},
 		{
-			name: 'updateVault';
+			name: 'updateVaultProtocolFeeTiers';
 			accounts: [
 				{
 					name: 'vault';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'vaultProtocol';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'vaultConfig';
 					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: 'manager';
+					name: 'protocol';
 					isMut: false;
 					isSigner: true;
 				}
 			];
 			args: [
 				{
-					name: 'params';
+					name: 'protocolFeeTiers';
 					type: {
-						defined: 'UpdateVaultParams';
+						array: [
+							{
+								defined: 'ProtocolFeeTier';
+							},
+							4
+						];
 					};
 				}
 			];
 		},
 		{
-			name: 'updateVaultManager';
+			name: 'updateVault';
 			accounts: [
 				{
 					name: 'vault';
@@ -323,13 +416,15 @@ export type DriftVaults = {
 			];
 			args: [
 				{
-					name: 'manager';
-					type: 'publicKey';
+					name: 'params';
+					type: {
+						defined: 'UpdateVaultParams';
+					};
 				}
 			];
 		},
 		{
-			name: 'updateCumulativeFuelAmount';
+			name: 'updateVaultManager';
 			accounts: [
 				{
 					name: 'vault';
@@ -337,40 +432,35 @@ export type DriftVaults = {
 					isSigner: false;
 				},
 				{
-					name: 'vaultDepositor';
-					isMut: true;
-					isSigner: false;
-				},
-				{
-					name: 'signer';
+					name: 'manager';
 					isMut: false;
 					isSigner: true;
-				},
+				}
+			];
+			args: [
 				{
-					name: 'driftUserStats';
-					isMut: true;
-					isSigner: false;
+					name: 'manager';
+					type: 'publicKey';
 				}
 			];
-			args: [];
 		},
 		{
-			name: 'initializeVaultDepositor';
+			name: 'initializeVaultHook';
 			accounts: [
 				{
 					name: 'vault';
-					isMut: false;
+					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: 'vaultDepositor';
+					name: 'vaultHook';
 					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: 'authority';
+					name: 'manager';
 					isMut: false;
-					isSigner: false;
+					isSigner: true;
 				},
 				{
 					name: 'payer';
@@ -388,45 +478,61 @@ export type DriftVaults = {
 					isSigner: false;
 				}
 			];
-			args: [];
+			args: [
+				{
+					name: 'hookProgram';
+					type: 'publicKey';
+				}
+			];
 		},
 		{
-			name: 'initializeTokenizedVaultDepositor';
+			name: 'updateVaultHook';
 			accounts: [
 				{
 					name: 'vault';
-					isMut: false;
-					isSigner: false;
-				},
-				{
-					name: 'vaultDepositor';
 					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: 'mintAccount';
+					name: 'vaultHook';
 					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: 'metadataAccount';
-					isMut: true;
+					name: 'manager';
+					isMut: false;
+					isSigner: true;
+				}
+			];
+			args: [
+				{
+					name: 'hookProgram';
+					type: 'publicKey';
+				}
+			];
+		},
+		{
+			name: 'initializeVaultWithdrawRoute';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: false;
 					isSigner: false;
 				},
 				{
-					name: 'payer';
+					name: 'vaultWithdrawRoute';
 					isMut: true;
-					isSigner: true;
+					isSigner: false;
 				},
 				{
-					name: 'tokenProgram';
+					name: 'manager';
 					isMut: false;
-					isSigner: false;
+					isSigner: true;
 				},
 				{
-					name: 'tokenMetadataProgram';
-					isMut: false;
-					isSigner: false;
+					name: 'payer';
+					isMut: true;
+					isSigner: true;
 				},
 				{
 					name: 'rent';
@@ -441,128 +547,95 @@ export type DriftVaults = {
 			];
 			args: [
 				{
-					name: 'params';
+					name: 'marketIndexes';
 					type: {
-						defined: 'InitializeTokenizedVaultDepositorParams';
+						vec: 'u16';
 					};
 				}
 			];
 		},
 		{
-			name: 'tokenizeShares';
+			name: 'updateVaultWithdrawRoute';
 			accounts: [
 				{
 					name: 'vault';
-					isMut: true;
-					isSigner: false;
-				},
-				{
-					name: 'vaultDepositor';
-					isMut: true;
-					isSigner: false;
-				},
-				{
-					name: 'authority';
-					isMut: false;
-					isSigner: true;
-				},
-				{
-					name: 'tokenizedVaultDepositor';
-					isMut: true;
-					isSigner: false;
-				},
-				{
-					name: 'mint';
-					isMut: true;
-					isSigner: false;
-				},
-				{
-					name: 'userTokenAccount';
 					isMut: false;
 					isSigner: false;
 				},
 				{
-					name: 'driftUser';
+					name: 'vaultWithdrawRoute';
 					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: 'tokenProgram';
+					name: 'manager';
 					isMut: false;
-					isSigner: false;
+					isSigner: true;
 				}
 			];
 			args: [
 				{
-					name: 'amount';
-					type: 'u64';
-				},
-				{
-					name: 'unit';
+					name: 'marketIndexes';
 					type: {
-						defined: 'WithdrawUnit';
+						vec: 'u16';
 					};
 				}
 			];
 		},
 		{
-			name: 'redeemTokens';
+			name: 'initializeVaultRiskScore';
 			accounts: [
 				{
 					name: 'vault';
-					isMut: true;
+					isMut: false;
 					isSigner: false;
 				},
 				{
-					name: 'vaultDepositor';
+					name: 'vaultRiskScore';
 					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: 'authority';
-					isMut: false;
+					name: 'payer';
+					isMut: true;
 					isSigner: true;
 				},
 				{
-					name: 'tokenizedVaultDepositor';
-					isMut: true;
+					name: 'rent';
+					isMut: false;
 					isSigner: false;
 				},
 				{
-					name: 'mint';
-					isMut: true;
+					name: 'systemProgram';
+					isMut: false;
 					isSigner: false;
-				},
+				}
+			];
+			args: [];
+		},
+		{
+			name: 'updateVaultRiskScore';
+			accounts: [
 				{
-					name: 'userTokenAccount';
-					isMut: true;
+					name: 'vault';
+					isMut: false;
 					isSigner: false;
 				},
 				{
-					name: 'vaultTokenAccount';
+					name: 'vaultRiskScore';
 					isMut: true;
 					isSigner: false;
 				},
 				{
 					name: 'driftUser';
-					isMut: true;
-					isSigner: false;
-				},
-				{
-					name: 'tokenProgram';
 					isMut: false;
 					isSigner: false;
 				}
 			];
-			args: [
-				{
-					name: 'tokensToBurn';
-					type: 'u64';
-				}
-			];
+			args: [];
 		},
 		{
-			name: 'deposit';
+			name: 'updateCumulativeFuelAmount';
 			accounts: [
 				{
 					name: 'vault';
@@ -575,64 +648,60 @@ export type DriftVaults = {
 					isSigner: false;
 				},
 				{
-					name: 'authority';
+					name: 'signer';
 					isMut: false;
 					isSigner: true;
 				},
 				{
-					name: 'vaultTokenAccount';
+					name: 'driftUserStats';
 					isMut: true;
 					isSigner: false;
-				},
+				}
+			];
+			args: [];
+		},
+		{
+			name: 'initializeVaultDepositor';
+			accounts: [
 				{
-					name: 'driftUserStats';
+					name: 'vault';
 					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: 'driftUser';
+					name: 'vaultDepositor';
 					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: 'driftState';
+					name: 'authority';
 					isMut: false;
 					isSigner: false;
 				},
 				{
-					name: 'driftSpotMarketVault';
-					isMut: true;
-					isSigner: false;
-				},
-				{
-					name: 'userTokenAccount';
+					name: 'payer';
 					isMut: true;
-					isSigner: false;
+					isSigner: true;
 				},
 				{
-					name: 'driftProgram';
+					name: 'rent';
 					isMut: false;
 					isSigner: false;
 				},
 				{
-					name: 'tokenProgram';
+					name: 'systemProgram';
 					isMut: false;
 					isSigner: false;
 				}
 			];
-			args: [
-				{
-					name: 'amount';
-					type: 'u64';
-				}
-			];
+			args: [];
 		},
 		{
-			name: 'requestWithdraw';
+			name: 'initializeTokenizedVaultDepositor';
 			accounts: [
 				{
 					name: 'vault';
-					isMut: true;
+					isMut: false;
 					isSigner: false;
 				},
 				{
@@ -641,67 +710,52 @@ export type DriftVaults = {
 					isSigner: false;
 				},
 				{
-					name: 'authority';
-					isMut: false;
-					isSigner: true;
-				},
-				{
-					name: 'driftUserStats';
-					isMut: false;
+					name: 'mintAccount';
+					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: 'driftUser';
-					isMut: false;
+					name: 'metadataAccount';
+					isMut: true;
 					isSigner: false;
-				}
-			];
-			args: [
-				{
-					name: 'withdrawAmount';
-					type: 'u64';
 				},
 				{
-					name: 'withdrawUnit';
-					type: {
-						defined: 'WithdrawUnit';
-					};
-				}
-			];
-		},
-		{
-			name: 'cancelRequestWithdraw';
-			accounts: [
-				{
-					name: 'vault';
+					name: 'payer';
 					isMut: true;
-					isSigner: false;
+					isSigner: true;
 				},
 				{
-					name: 'vaultDepositor';
-					isMut: true;
+					name: 'tokenProgram';
+					isMut: false;
 					isSigner: false;
 				},
 				{
-					name: 'authority';
+					name: 'tokenMetadataProgram';
 					isMut: false;
-					isSigner: true;
+					isSigner: false;
 				},
 				{
-					name: 'driftUserStats';
+					name: 'rent';
 					isMut: false;
 					isSigner: false;
 				},
 				{
-					name: 'driftUser';
+					name: 'systemProgram';
 					isMut: false;
 					isSigner: false;
 				}
 			];
-			args: [];
+			args: [
+				{
+					name: 'params';
+					type: {
+						defined: 'InitializeTokenizedVaultDepositorParams';
+					};
+				}
+			];
 		},
 		{
-			name: 'withdraw';
+			name: 'tokenizeShares';
 			accounts: [
 				{
 					name: 'vault';
@@ -719,55 +773,54 @@ export type DriftVaults = {
 					isSigner: true;
 				},
 				{
-					name: 'vaultTokenAccount';
-					isMut: true;
-					isSigner: false;
-				},
-				{
-					name: 'driftUserStats';
+					name: 'tokenizedVaultDepositor';
 					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: 'driftUser';
+					name: 'mint';
 					isMut: true;
 					isSigner: false;
+					docs: [
+						"The mint created at the tokenized depositor's initial shares base, it carries on across rebases"
+					];
 				},
 				{
-					name: 'driftState';
+					name: 'userTokenAccount';
 					isMut: false;
 					isSigner: false;
 				},
 				{
-					name: 'driftSpotMarketVault';
+					name: 'driftUser';
 					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: 'driftSigner';
+					name: 'driftUserStats';
 					isMut: false;
 					isSigner: false;
 				},
 				{
-					name: 'userTokenAccount';
-					isMut: true;
-					isSigner: false;
-				},
-				{
-					name: 'driftProgram';
+					name: 'tokenProgram';
 					isMut: false;
 					isSigner: false;
+				}
+			];
+			args: [
+				{
+					name: 'amount';
+					type: 'u64';
 				},
 				{
-					name: 'tokenProgram';
-					isMut: false;
-					isSigner: false;
+					name: 'unit';
+					type: {
+						defined: 'WithdrawUnit';
+					};
 				}
 			];
-			args: [];
 		},
 		{
-			name: 'liquidate';
+			name: 'redeemTokens';
 			accounts: [
 				{
 					name: 'vault';
@@ -785,77 +838,50 @@ export type DriftVaults = {
 					isSigner: true;
 				},
 				{
-					name: 'driftUserStats';
-					isMut: true;
-					isSigner: false;
-				},
-				{
-					name: 'driftUser';
+					name: 'tokenizedVaultDepositor';
 					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: 'driftProgram';
-					isMut: false;
-					isSigner: false;
-				}
-			];
-			args: [];
-		},
-		{
-			name: 'resetDelegate';
-			accounts: [
-				{
-					name: 'vault';
+					name: 'mint';
 					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: 'driftUser';
+					name: 'userTokenAccount';
 					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: 'driftProgram';
-					isMut: false;
-					isSigner: false;
-				}
-			];
-			args: [];
-		},
-		{
-			name: 'resetFuelSeason';
-			accounts: [
-				{
-					name: 'vault';
+					name: 'vaultTokenAccount';
 					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: 'vaultDepositor';
+					name: 'driftUser';
 					isMut: true;
 					isSigner: false;
 				},
-				{
-					name: 'admin';
-					isMut: false;
-					isSigner: true;
-				},
 				{
 					name: 'driftUserStats';
-					isMut: true;
+					isMut: false;
 					isSigner: false;
 				},
 				{
-					name: 'driftState';
+					name: 'tokenProgram';
 					isMut: false;
 					isSigner: false;
 				}
 			];
-			args: [];
+			args: [
+				{
+					name: 'tokensToBurn';
+					type: 'u64';
+				}
+			];
 		},
 		{
-			name: 'resetVaultFuelSeason';
+			name: 'deposit';
 			accounts: [
 				{
 					name: 'vault';
@@ -863,28 +889,12 @@ export type DriftVaults = {
 					isSigner: false;
 				},
 				{
-					name: 'admin';
-					isMut: false;
-					isSigner: true;
-				},
-				{
-					name: 'driftState';
-					isMut: false;
-					isSigner: false;
-				}
-			];
-			args: [];
-		},
-		{
-			name: 'managerDeposit';
-			accounts: [
-				{
-					name: 'vault';
+					name: 'vaultDepositor';
 					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: 'manager';
+					name: 'authority';
 					isMut: false;
 					isSigner: true;
 				},
@@ -937,7 +947,7 @@ export type DriftVaults = {
 			];
 		},
 		{
-			name: 'managerRequestWithdraw';
+			name: 'requestWithdraw';
 			accounts: [
 				{
 					name: 'vault';
@@ -945,9 +955,15 @@ export type DriftVaults = {
 					isSigner: false;
 				},
 				{
-					name: 'manager';
+					name: 'vaultDepositor';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'authority';
 					isMut: false;
 					isSigner: true;
+					docs: ['the depositor authority or its custodian'];
 				},
 				{
 					name: 'driftUserStats';
@@ -974,7 +990,7 @@ export type DriftVaults = {
 			];
 		},
 		{
-			name: 'mangerCancelWithdrawRequest';
+			name: 'cancelRequestWithdraw';
 			accounts: [
 				{
 					name: 'vault';
@@ -982,9 +998,15 @@ export type DriftVaults = {
 					isSigner: false;
 				},
 				{
-					name: 'manager';
+					name: 'vaultDepositor';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'authority';
 					isMut: false;
 					isSigner: true;
+					docs: ['the depositor authority or its custodian'];
 				},
 				{
 					name: 'driftUserStats';
@@ -1000,7 +1022,7 @@ export type DriftVaults = {
 			args: [];
 		},
 		{
-			name: 'managerWithdraw';
+			name: 'withdraw';
 			accounts: [
 				{
 					name: 'vault';
@@ -1008,7 +1030,12 @@ export type DriftVaults = {
 					isSigner: false;
 				},
 				{
-					name: 'manager';
+					name: 'vaultDepositor';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'authority';
 					isMut: false;
 					isSigner: true;
 				},
@@ -1061,7 +1088,7 @@ export type DriftVaults = {
 			args: [];
 		},
 		{
-			name: 'managerUpdateFuelDistributionMode';
+			name: 'withdrawRouted';
 			accounts: [
 				{
 					name: 'vault';
@@ -1069,36 +1096,25 @@ export type DriftVaults = {
 					isSigner: false;
 				},
 				{
-					name: 'manager';
-					isMut: false;
-					isSigner: true;
-				}
-			];
-			args: [
-				{
-					name: 'fuelDistributionMode';
-					type: 'u8';
-				}
-			];
-		},
-		{
-			name: 'applyProfitShare';
-			accounts: [
-				{
-					name: 'vault';
+					name: 'vaultDepositor';
 					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: 'vaultDepositor';
-					isMut: true;
+					name: 'vaultWithdrawRoute';
+					isMut: false;
 					isSigner: false;
 				},
 				{
-					name: 'manager';
+					name: 'authority';
 					isMut: false;
 					isSigner: true;
 				},
+				{
+					name: 'vaultTokenAccount';
+					isMut: true;
+					isSigner: false;
+				},
 				{
 					name: 'driftUserStats';
 					isMut: true;
@@ -1114,21 +1130,36 @@ export type DriftVaults = {
 					isMut: false;
 					isSigner: false;
 				},
+				{
+					name: 'driftSpotMarketVault';
+					isMut: true;
+					isSigner: false;
+				},
 				{
 					name: 'driftSigner';
 					isMut: false;
 					isSigner: false;
 				},
+				{
+					name: 'userTokenAccount';
+					isMut: true;
+					isSigner: false;
+				},
 				{
 					name: 'driftProgram';
 					isMut: false;
 					isSigner: false;
+				},
+				{
+					name: 'tokenProgram';
+					isMut: false;
+					isSigner: false;
 				}
 			];
 			args: [];
 		},
 		{
-			name: 'applyRebase';
+			name: 'liquidate';
 			accounts: [
 				{
 					name: 'vault';
@@ -1140,16 +1171,31 @@ export type DriftVaults = {
 					isMut: true;
 					isSigner: false;
 				},
+				{
+					name: 'authority';
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: 'driftUserStats';
+					isMut: true;
+					isSigner: false;
+				},
 				{
 					name: 'driftUser';
 					isMut: true;
 					isSigner: false;
+				},
+				{
+					name: 'driftProgram';
+					isMut: false;
+					isSigner: false;
 				}
 			];
 			args: [];
 		},
 		{
-			name: 'applyRebaseTokenizedDepositor';
+			name: 'resetDelegate';
 			accounts: [
 				{
 					name: 'vault';
@@ -1157,78 +1203,64 @@ export type DriftVaults = {
 					isSigner: false;
 				},
 				{
-					name: 'tokenizedVaultDepositor';
+					name: 'driftUser';
 					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: 'driftUser';
-					isMut: true;
+					name: 'driftProgram';
+					isMut: false;
 					isSigner: false;
 				}
 			];
 			args: [];
 		},
 		{
-			name: 'forceWithdraw';
+			name: 'resetFuelSeason';
 			accounts: [
 				{
 					name: 'vault';
 					isMut: true;
 					isSigner: false;
 				},
-				{
-					name: 'manager';
-					isMut: false;
-					isSigner: true;
-				},
 				{
 					name: 'vaultDepositor';
 					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: 'vaultTokenAccount';
-					isMut: true;
-					isSigner: false;
+					name: 'admin';
+					isMut: false;
+					isSigner: true;
 				},
 				{
 					name: 'driftUserStats';
 					isMut: true;
 					isSigner: false;
 				},
-				{
-					name: 'driftUser';
-					isMut: true;
-					isSigner: false;
-				},
 				{
 					name: 'driftState';
 					isMut: false;
 					isSigner: false;
-				},
-				{
-					name: 'driftSpotMarketVault';
-					isMut: true;
-					isSigner: false;
-				},
-				{
-					name: 'driftSigner';
-					isMut: false;
-					isSigner: false;
-				},
+				}
+			];
+			args: [];
+		},
+		{
+			name: 'resetVaultFuelSeason';
+			accounts: [
 				{
-					name: 'userTokenAccount';
+					name: 'vault';
 					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: 'driftProgram';
+					name: 'admin';
 					isMut: false;
-					isSigner: false;
+					isSigner: true;
 				},
 				{
-					name: 'tokenProgram';
+					name: 'driftState';
 					isMut: false;
 					isSigner: false;
 				}
@@ -1236,7 +1268,7 @@ export type DriftVaults = {
 			args: [];
 		},
 		{
-			name: 'initializeInsuranceFundStake';
+			name: 'managerDeposit';
 			accounts: [
 				{
 					name: 'vault';
@@ -1249,70 +1281,92 @@ export type DriftVaults = {
 					isSigner: true;
 				},
 				{
-					name: 'payer';
+					name: 'vaultTokenAccount';
 					isMut: true;
-					isSigner: true;
+					isSigner: false;
 				},
 				{
-					name: 'rent';
-					isMut: false;
+					name: 'driftUserStats';
+					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: 'driftSpotMarket';
+					name: 'driftUser';
 					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: 'driftSpotMarketMint';
+					name: 'driftState';
 					isMut: false;
 					isSigner: false;
 				},
 				{
-					name: 'vaultTokenAccount';
+					name: 'driftSpotMarketVault';
 					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: 'insuranceFundStake';
+					name: 'userTokenAccount';
 					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: 'driftUserStats';
-					isMut: true;
+					name: 'driftProgram';
+					isMut: false;
 					isSigner: false;
 				},
 				{
-					name: 'driftState';
+					name: 'tokenProgram';
 					isMut: false;
 					isSigner: false;
+				}
+			];
+			args: [
+				{
+					name: 'amount';
+					type: 'u64';
+				}
+			];
+		},
+		{
+			name: 'managerRequestWithdraw';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'driftProgram';
+					name: 'manager';
 					isMut: false;
-					isSigner: false;
+					isSigner: true;
 				},
 				{
-					name: 'tokenProgram';
+					name: 'driftUserStats';
 					isMut: false;
 					isSigner: false;
 				},
 				{
-					name: 'systemProgram';
+					name: 'driftUser';
 					isMut: false;
 					isSigner: false;
 				}
 			];
 			args: [
 				{
-					name: 'marketIndex';
-					type: 'u16';
+					name: 'withdrawAmount';
+					type: 'u64';
+				},
+				{
+					name: 'withdrawUnit';
+					type: {
+						defined: 'WithdrawUnit';
+					};
 				}
 			];
 		},
 		{
-			name: 'addInsuranceFundStake';
+			name: 'mangerCancelWithdrawRequest';
 			accounts: [
 				{
 					name: 'vault';
@@ -1321,41 +1375,47 @@ export type DriftVaults = {
 				},
 				{
 					name: 'manager';
-					isMut: true;
+					isMut: false;
 					isSigner: true;
 				},
 				{
-					name: 'driftSpotMarket';
-					isMut: true;
+					name: 'driftUserStats';
+					isMut: false;
 					isSigner: false;
 				},
 				{
-					name: 'driftSpotMarketVault';
-					isMut: true;
+					name: 'driftUser';
+					isMut: false;
 					isSigner: false;
-				},
+				}
+			];
+			args: [];
+		},
+		{
+			name: 'managerWithdraw';
+			accounts: [
 				{
-					name: 'insuranceFundStake';
+					name: 'vault';
 					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: 'insuranceFundVault';
-					isMut: true;
-					isSigner: false;
+					name: 'manager';
+					isMut: false;
+					isSigner: true;
 				},
 				{
-					name: 'managerTokenAccount';
+					name: 'vaultTokenAccount';
 					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: 'vaultIfTokenAccount';
+					name: 'driftUserStats';
 					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: 'driftUserStats';
+					name: 'driftUser';
 					isMut: true;
 					isSigner: false;
 				},
@@ -1364,11 +1424,21 @@ export type DriftVaults = {
 					isMut: false;
 					isSigner: false;
 				},
+				{
+					name: 'driftSpotMarketVault';
+					isMut: true;
+					isSigner: false;
+				},
 				{
 					name: 'driftSigner';
 					isMut: false;
 					isSigner: false;
 				},
+				{
+					name: 'userTokenAccount';
+					isMut: true;
+					isSigner: false;
+				},
 				{
 					name: 'driftProgram';
 					isMut: false;
@@ -1380,48 +1450,65 @@ export type DriftVaults = {
 					isSigner: false;
 				}
 			];
-			args: [
+			args: [];
+		},
+		{
+			name: 'managerUpdateFuelDistributionMode';
+			accounts: [
 				{
-					name: 'marketIndex';
-					type: 'u16';
+					name: 'vault';
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: 'amount';
-					type: 'u64';
+					name: 'manager';
+					isMut: false;
+					isSigner: true;
+				}
+			];
+			args: [
+				{
+					name: 'fuelDistributionMode';
+					type: 'u8';
 				}
 			];
 		},
 		{
-			name: 'requestRemoveInsuranceFundStake';
+			name: 'applyProfitShare';
 			accounts: [
 				{
 					name: 'vault';
 					isMut: true;
 					isSigner: false;
 				},
+				{
+					name: 'vaultDepositor';
+					isMut: true;
+					isSigner: false;
+				},
 				{
 					name: 'manager';
 					isMut: false;
 					isSigner: true;
 				},
 				{
-					name: 'driftSpotMarket';
+					name: 'driftUserStats';
 					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: 'insuranceFundStake';
+					name: 'driftUser';
 					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: 'insuranceFundVault';
-					isMut: true;
+					name: 'driftState';
+					isMut: false;
 					isSigner: false;
 				},
 				{
-					name: 'driftUserStats';
-					isMut: true;
+					name: 'driftSigner';
+					isMut: false;
 					isSigner: false;
 				},
 				{
@@ -1430,19 +1517,10 @@ export type DriftVaults = {
 					isSigner: false;
 				}
 			];
-			args: [
-				{
-					name: 'marketIndex';
-					type: 'u16';
-				},
-				{
-					name: 'amount';
-					type: 'u64';
-				}
-			];
+			args: [];
 		},
 		{
-			name: 'removeInsuranceFundStake';
+			name: 'applyRebase';
 			accounts: [
 				{
 					name: 'vault';
@@ -1450,32 +1528,59 @@ export type DriftVaults = {
 					isSigner: false;
 				},
 				{
-					name: 'manager';
-					isMut: false;
-					isSigner: true;
-				},
-				{
-					name: 'driftSpotMarket';
+					name: 'vaultDepositor';
 					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: 'insuranceFundStake';
+					name: 'driftUser';
+					isMut: true;
+					isSigner: false;
+				}
+			];
+			args: [];
+		},
+		{
+			name: 'applyRebaseTokenizedDepositor';
+			accounts: [
+				{
+					name: 'vault';
 					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: 'insuranceFundVault';
+					name: 'tokenizedVaultDepositor';
 					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: 'managerTokenAccount';
+					name: 'driftUser';
+					isMut: true;
+					isSigner: false;
+				}
+			];
+			args: [];
+		},
+		{
+			name: 'forceWithdraw';
+			accounts: [
+				{
+					name: 'vault';
 					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: 'vaultIfTokenAccount';
+					name: 'manager';
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: 'vaultDepositor';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'vaultTokenAccount';
 					isMut: true;
 					isSigner: false;
 				},
@@ -1484,16 +1589,31 @@ export type DriftVaults = {
 					isMut: true;
 					isSigner: false;
 				},
+				{
+					name: 'driftUser';
+					isMut: true;
+					isSigner: false;
+				},
 				{
 					name: 'driftState';
 					isMut: false;
 					isSigner: false;
 				},
+				{
+					name: 'driftSpotMarketVault';
+					isMut: true;
+					isSigner: false;
+				},
 				{
 					name: 'driftSigner';
 					isMut: false;
 					isSigner: false;
 				},
+				{
+					name: 'userTokenAccount';
+					isMut: true;
+					isSigner: false;
+				},
 				{
 					name: 'driftProgram';
 					isMut: false;
@@ -1505,15 +1625,10 @@ export type DriftVaults = {
 					isSigner: false;
 				}
 			];
-			args: [
-				{
-					name: 'marketIndex';
-					type: 'u16';
-				}
-			];
+			args: [];
 		},
 		{
-			name: 'cancelRequestRemoveInsuranceFundStake';
+			name: 'initializeInsuranceFundStake';
 			accounts: [
 				{
 					name: 'vault';
@@ -1525,18 +1640,33 @@ export type DriftVaults = {
 					isMut: false;
 					isSigner: true;
 				},
+				{
+					name: 'payer';
+					isMut: true;
+					isSigner: true;
+				},
+				{
+					name: 'rent';
+					isMut: false;
+					isSigner: false;
+				},
 				{
 					name: 'driftSpotMarket';
 					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: 'insuranceFundStake';
+					name: 'driftSpotMarketMint';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'vaultTokenAccount';
 					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: 'insuranceFundVault';
+					name: 'insuranceFundStake';
 					isMut: true;
 					isSigner: false;
 				},
@@ -1545,10 +1675,25 @@ export type DriftVaults = {
 					isMut: true;
 					isSigner: false;
 				},
+				{
+					name: 'driftState';
+					isMut: false;
+					isSigner: false;
+				},
 				{
 					name: 'driftProgram';
 					isMut: false;
 					isSigner: false;
+				},
+				{
+					name: 'tokenProgram';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'systemProgram';
+					isMut: false;
+					isSigner: false;
 				}
 			];
 			args: [
@@ -1559,7 +1704,7 @@ export type DriftVaults = {
 			];
 		},
 		{
-			name: 'protocolRequestWithdraw';
+			name: 'addInsuranceFundStake';
 			accounts: [
 				{
 					name: 'vault';
@@ -1567,41 +1712,79 @@ export type DriftVaults = {
 					isSigner: false;
 				},
 				{
-					name: 'vaultProtocol';
+					name: 'manager';
+					isMut: true;
+					isSigner: true;
+				},
+				{
+					name: 'driftSpotMarket';
 					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: 'protocol';
-					isMut: false;
-					isSigner: true;
+					name: 'driftSpotMarketVault';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'insuranceFundStake';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'insuranceFundVault';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'managerTokenAccount';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'vaultIfTokenAccount';
+					isMut: true;
+					isSigner: false;
 				},
 				{
 					name: 'driftUserStats';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftState';
 					isMut: false;
 					isSigner: false;
 				},
 				{
-					name: 'driftUser';
+					name: 'driftSigner';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'driftProgram';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'tokenProgram';
 					isMut: false;
 					isSigner: false;
 				}
 			];
 			args: [
 				{
-					name: 'withdrawAmount';
-					type: 'u64';
+					name: 'marketIndex';
+					type: 'u16';
 				},
 				{
-					name: 'withdrawUnit';
-					type: {
-						defined: 'WithdrawUnit';
-					};
+					name: 'amount';
+					type: 'u64';
 				}
 			];
 		},
 		{
-			name: 'protocolCancelWithdrawRequest';
+			name: 'requestRemoveInsuranceFundStake';
 			accounts: [
 				{
 					name: 'vault';
@@ -1609,30 +1792,49 @@ export type DriftVaults = {
 					isSigner: false;
 				},
 				{
-					name: 'vaultProtocol';
+					name: 'manager';
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: 'driftSpotMarket';
 					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: 'protocol';
-					isMut: false;
-					isSigner: true;
+					name: 'insuranceFundStake';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'insuranceFundVault';
+					isMut: true;
+					isSigner: false;
 				},
 				{
 					name: 'driftUserStats';
-					isMut: false;
+					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: 'driftUser';
+					name: 'driftProgram';
 					isMut: false;
 					isSigner: false;
 				}
 			];
-			args: [];
+			args: [
+				{
+					name: 'marketIndex';
+					type: 'u16';
+				},
+				{
+					name: 'amount';
+					type: 'u64';
+				}
+			];
 		},
 		{
-			name: 'protocolWithdraw';
+			name: 'removeInsuranceFundStake';
 			accounts: [
 				{
 					name: 'vault';
@@ -1640,48 +1842,48 @@ export type DriftVaults = {
 					isSigner: false;
 				},
 				{
-					name: 'vaultProtocol';
-					isMut: true;
-					isSigner: false;
-				},
-				{
-					name: 'protocol';
+					name: 'manager';
 					isMut: false;
 					isSigner: true;
 				},
 				{
-					name: 'vaultTokenAccount';
+					name: 'driftSpotMarket';
 					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: 'driftUserStats';
+					name: 'insuranceFundStake';
 					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: 'driftUser';
+					name: 'insuranceFundVault';
 					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: 'driftState';
-					isMut: false;
+					name: 'managerTokenAccount';
+					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: 'driftSpotMarketVault';
+					name: 'vaultIfTokenAccount';
 					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: 'driftSigner';
+					name: 'driftUserStats';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftState';
 					isMut: false;
 					isSigner: false;
 				},
 				{
-					name: 'userTokenAccount';
-					isMut: true;
+					name: 'driftSigner';
+					isMut: false;
 					isSigner: false;
 				},
 				{
@@ -1695,3821 +1897,25943 @@ export type DriftVaults = {
 					isSigner: false;
 				}
 			];
-			args: [];
-		}
-	];
-	accounts: [
+			args: [
+				{
+					name: 'marketIndex';
+					type: 'u16';
+				}
+			];
+		},
 		{
-			name: 'tokenizedVaultDepositor';
-			type: {
-				kind: 'struct';
-				fields: [
-					{
-						name: 'vault';
-						docs: ['The vault deposited into'];
-						type: 'publicKey';
-					},
-					{
-						name: 'pubkey';
-						docs: [
-							"The vault depositor account's pubkey. It is a pda of vault"
-						];
-						type: 'publicKey';
-					},
-					{
-						name: 'mint';
-						docs: [
-							'The token mint for tokenized shares owned by this VaultDepositor'
-						];
-						type: 'publicKey';
-					},
-					{
-						name: 'vaultShares';
-						docs: [
-							"share of vault owned by this depositor. vault_shares / vault.total_shares is depositor's ownership of vault_equity"
-						];
-						type: 'u128';
-					},
-					{
-						name: 'lastVaultShares';
-						docs: [
-							'stores the vault_shares from the most recent liquidity event (redeem or issuance) before a spl token',
-							'CPI is done, used to track invariants'
-						];
-						type: 'u128';
-					},
-					{
-						name: 'lastValidTs';
-						docs: ['creation ts of vault depositor'];
-						type: 'i64';
-					},
-					{
-						name: 'netDeposits';
-						docs: ['lifetime net deposits of vault depositor for the vault'];
-						type: 'i64';
-					},
-					{
-						name: 'totalDeposits';
-						docs: ['lifetime total deposits'];
-						type: 'u64';
-					},
-					{
-						name: 'totalWithdraws';
-						docs: ['lifetime total withdraws'];
-						type: 'u64';
-					},
-					{
-						name: 'cumulativeProfitShareAmount';
-						docs: [
-							'the token amount of gains the vault depositor has paid performance fees on'
-						];
-						type: 'i64';
-					},
-					{
-						name: 'profitShareFeePaid';
-						type: 'u64';
-					},
-					{
-						name: 'vaultSharesBase';
-						docs: [
-							'The exponent for vault_shares decimal places at the time the tokenized vault depositor was initialized.',
-							'If the vault undergoes a rebase, this TokenizedVaultDepositor can no longer issue new tokens, only redeem',
-							'is possible.'
-						];
-						type: 'u32';
-					},
-					{
-						name: 'bump';
-						docs: ['The bump for the vault pda'];
-						type: 'u8';
-					},
-					{
-						name: 'padding1';
-						type: {
-							array: ['u8', 3];
-						};
-					},
-					{
-						name: 'padding';
-						type: {
-							array: ['u64', 10];
-						};
-					}
-				];
-			};
+			name: 'cancelRequestRemoveInsuranceFundStake';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'manager';
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: 'driftSpotMarket';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'insuranceFundStake';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'insuranceFundVault';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftUserStats';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftProgram';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [
+				{
+					name: 'marketIndex';
+					type: 'u16';
+				}
+			];
 		},
 		{
-			name: 'vaultDepositor';
-			type: {
-				kind: 'struct';
-				fields: [
-					{
-						name: 'vault';
-						docs: ['The vault deposited into'];
-						type: 'publicKey';
-					},
-					{
-						name: 'pubkey';
-						docs: [
-							"The vault depositor account's pubkey. It is a pda of vault and authority"
-						];
-						type: 'publicKey';
-					},
-					{
-						name: 'authority';
-						docs: [
-							'The authority is the address w permission to deposit/withdraw'
-						];
-						type: 'publicKey';
-					},
-					{
-						name: 'vaultShares';
-						docs: [
-							"share of vault owned by this depositor. vault_shares / vault.total_shares is depositor's ownership of vault_equity"
-						];
-						type: 'u128';
-					},
-					{
-						name: 'lastWithdrawRequest';
-						docs: ['last withdraw request'];
-						type: {
-							defined: 'WithdrawRequest';
-						};
-					},
-					{
-						name: 'lastValidTs';
-						docs: ['creation ts of vault depositor'];
-						type: 'i64';
-					},
-					{
-						name: 'netDeposits';
-						docs: ['lifetime net deposits of vault depositor for the vault'];
-						type: 'i64';
-					},
-					{
-						name: 'totalDeposits';
-						docs: ['lifetime total deposits'];
-						type: 'u64';
-					},
-					{
-						name: 'totalWithdraws';
-						docs: ['lifetime total withdraws'];
-						type: 'u64';
-					},
-					{
-						name: 'cumulativeProfitShareAmount';
-						docs: [
-							'the token amount of gains the vault depositor has paid performance fees on'
-						];
-						type: 'i64';
-					},
-					{
-						name: 'profitShareFeePaid';
-						type: 'u64';
-					},
-					{
-						name: 'vaultSharesBase';
-						docs: ['the exponent for vault_shares decimal places'];
-						type: 'u32';
-					},
-					{
-						name: 'lastFuelUpdateTs';
-						type: 'u32';
-					},
-					{
-						name: 'cumulativeFuelPerShareAmount';
-						docs: ['precision: FUEL_SHARE_PRECISION'];
-						type: 'u128';
-					},
-					{
-						name: 'fuelAmount';
-						docs: ['precision: none'];
-						type: 'u128';
-					},
-					{
-						name: 'padding';
-						type: {
-							array: ['u64', 4];
-						};
-					}
-				];
-			};
+			name: 'protocolRequestWithdraw';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'vaultProtocol';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'protocol';
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: 'driftUserStats';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'driftUser';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [
+				{
+					name: 'withdrawAmount';
+					type: 'u64';
+				},
+				{
+					name: 'withdrawUnit';
+					type: {
+						defined: 'WithdrawUnit';
+					};
+				}
+			];
 		},
 		{
-			name: 'vaultProtocol';
-			type: {
-				kind: 'struct';
-				fields: [
-					{
-						name: 'protocol';
-						docs: [
-							'The protocol, company, or entity that services the product using this vault.',
-							'The protocol is not allowed to deposit into the vault but can profit share and collect annual fees just like the manager.'
-						];
-						type: 'publicKey';
-					},
-					{
-						name: 'protocolProfitAndFeeShares';
-						docs: [
-							'The shares from profit share and annual fee unclaimed by the protocol.'
-						];
-						type: 'u128';
-					},
-					{
-						name: 'protocolFee';
-						docs: [
-							'The annual fee charged on deposits by the protocol (traditional hedge funds typically charge 2% per year on assets under management).',
-							"Unlike the management fee this can't be negative."
-						];
-						type: 'u64';
-					},
-					{
-						name: 'protocolTotalWithdraws';
-						docs: ['Total withdraws for the protocol'];
-						type: 'u64';
-					},
-					{
-						name: 'protocolTotalFee';
-						docs: [
-							'Total fee charged by the protocol (annual management fee + profit share).',
-							"Unlike the management fee this can't be negative."
-						];
-						type: 'u64';
-					},
-					{
-						name: 'protocolTotalProfitShare';
-						docs: ['Total profit share charged by the protocol'];
-						type: 'u64';
-					},
-					{
-						name: 'lastProtocolWithdrawRequest';
-						type: {
-							defined: 'WithdrawRequest';
-						};
-					},
-					{
-						name: 'protocolProfitShare';
-						docs: [
-							'Percentage the protocol charges on all profits realized by depositors: PERCENTAGE_PRECISION'
-						];
-						type: 'u32';
-					},
-					{
-						name: 'bump';
-						type: 'u8';
-					},
-					{
-						name: 'version';
-						type: 'u8';
-					},
-					{
-						name: 'padding';
-						type: {
-							array: ['u8', 2];
-						};
-					}
-				];
-			};
+			name: 'protocolCancelWithdrawRequest';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'vaultProtocol';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'protocol';
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: 'driftUserStats';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'driftUser';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [];
 		},
 		{
-			name: 'vault';
-			type: {
-				kind: 'struct';
-				fields: [
-					{
-						name: 'name';
-						docs: [
-							'The name of the vault. Vault pubkey is derived from this name.'
-						];
-						type: {
-							array: ['u8', 32];
-						};
-					},
-					{
-						name: 'pubkey';
-						docs: [
-							"The vault's pubkey. It is a pda of name and also used as the authority for drift user"
-						];
-						type: 'publicKey';
-					},
-					{
-						name: 'manager';
-						docs: [
-							'The manager of the vault who has ability to update vault params'
-						];
-						type: 'publicKey';
-					},
-					{
-						name: 'tokenAccount';
-						docs: [
-							'The vaults token account. Used to receive tokens between deposits and withdrawals'
-						];
-						type: 'publicKey';
-					},
-					{
-						name: 'userStats';
-						docs: ['The drift user stats account for the vault'];
-						type: 'publicKey';
-					},
-					{
-						name: 'user';
-						docs: ['The drift user account for the vault'];
-						type: 'publicKey';
-					},
-					{
-						name: 'delegate';
-						docs: [
-							'The vaults designated delegate for drift user account',
-							'can differ from actual user delegate if vault is in liquidation'
-						];
-						type: 'publicKey';
-					},
-					{
-						name: 'liquidationDelegate';
-						docs: ['The delegate handling liquidation for depositor'];
-						type: 'publicKey';
-					},
-					{
-						name: 'userShares';
-						docs: [
-							'The sum of all shares held by the users (vault depositors)'
-						];
-						type: 'u128';
-					},
-					{
-						name: 'totalShares';
-						docs: [
-							'The sum of all shares: deposits from users, manager deposits, manager profit/fee, and protocol profit/fee.',
-							'The manager deposits are total_shares - user_shares - protocol_profit_and_fee_shares.'
-						];
-						type: 'u128';
-					},
-					{
-						name: 'lastFeeUpdateTs';
-						docs: ['Last fee update unix timestamp'];
-						type: 'i64';
-					},
-					{
-						name: 'liquidationStartTs';
-						docs: ['When the liquidation starts'];
-						type: 'i64';
-					},
-					{
-						name: 'redeemPeriod';
-						docs: [
-							'The period (in seconds) that a vault depositor must wait after requesting a withdrawal to finalize withdrawal.',
-							'Currently, the maximum is 90 days.'
-						];
-						type: 'i64';
-					},
-					{
-						name: 'totalWithdrawRequested';
-						docs: ['The sum of all outstanding withdraw requests'];
-						type: 'u64';
-					},
-					{
-						name: 'maxTokens';
-						docs: [
-							'Max token capacity, once hit/passed vault will reject new deposits (updatable)'
-						];
-						type: 'u64';
-					},
-					{
-						name: 'managementFee';
-						docs: [
-							'The annual fee charged on deposits by the manager.',
-							'Traditional funds typically charge 2% per year on assets under management.'
-						];
-						type: 'i64';
-					},
-					{
-						name: 'initTs';
-						docs: ['Timestamp vault initialized'];
-						type: 'i64';
-					},
-					{
-						name: 'netDeposits';
-						docs: ['The net deposits for the vault'];
-						type: 'i64';
-					},
-					{
-						name: 'managerNetDeposits';
-						docs: ['The net deposits for the manager'];
-						type: 'i64';
-					},
-					{
-						name: 'totalDeposits';
-						docs: ['Total deposits'];
-						type: 'u64';
-					},
-					{
-						name: 'totalWithdraws';
-						docs: ['Total withdraws'];
-						type: 'u64';
-					},
-					{
-						name: 'managerTotalDeposits';
-						docs: ['Total deposits for the manager'];
-						type: 'u64';
-					},
-					{
-						name: 'managerTotalWithdraws';
-						docs: ['Total withdraws for the manager'];
-						type: 'u64';
-					},
-					{
-						name: 'managerTotalFee';
-						docs: ['Total management fee accrued by the manager'];
-						type: 'i64';
-					},
-					{
-						name: 'managerTotalProfitShare';
-						docs: ['Total profit share accrued by the manager'];
-						type: 'u64';
-					},
-					{
-						name: 'minDepositAmount';
+			name: 'protocolWithdraw';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'vaultProtocol';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'protocol';
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: 'vaultTokenAccount';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftUserStats';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftUser';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftState';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'driftSpotMarketVault';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftSigner';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'userTokenAccount';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftProgram';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'tokenProgram';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [];
+		},
+		{
+			name: 'initializeVaultPoll';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'vaultPoll';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'payer';
+					isMut: true;
+					isSigner: true;
+				},
+				{
+					name: 'rent';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'systemProgram';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [];
+		},
+		{
+			name: 'startVaultPoll';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'vaultPoll';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'vaultDepositor';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'authority';
+					isMut: false;
+					isSigner: true;
+				}
+			];
+			args: [
+				{
+					name: 'proposal';
+					type: 'u8';
+				},
+				{
+					name: 'newManager';
+					type: 'publicKey';
+				}
+			];
+		},
+		{
+			name: 'voteOnVaultPoll';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'vaultPoll';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'vaultPollVote';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'vaultDepositor';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'authority';
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: 'payer';
+					isMut: true;
+					isSigner: true;
+				},
+				{
+					name: 'rent';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'systemProgram';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [];
+		},
+		{
+			name: 'executeVaultPoll';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'vaultProtocol';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'vaultPoll';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'protocol';
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: 'driftUser';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftProgram';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [];
+		},
+		{
+			name: 'initializeVaultShareSnapshot';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'vaultShareSnapshot';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'manager';
+					isMut: false;
+					isSigner: true;
+					docs: ["The manager or the vault's auditor"];
+				},
+				{
+					name: 'payer';
+					isMut: true;
+					isSigner: true;
+				},
+				{
+					name: 'rent';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'systemProgram';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [
+				{
+					name: 'epoch';
+					type: 'u32';
+				}
+			];
+		},
+		{
+			name: 'snapshotVaultDepositorShares';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'vaultShareSnapshot';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'vaultDepositor';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'vaultDepositorShareSnapshot';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'payer';
+					isMut: true;
+					isSigner: true;
+				},
+				{
+					name: 'rent';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'systemProgram';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [];
+		},
+		{
+			name: 'initializeProtocolLossLedger';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'vaultProtocol';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'protocolLossLedger';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'protocol';
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: 'payer';
+					isMut: true;
+					isSigner: true;
+				},
+				{
+					name: 'rent';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'systemProgram';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [
+				{
+					name: 'authority';
+					type: 'publicKey';
+				}
+			];
+		},
+		{
+			name: 'recordLossCarryforward';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'vaultProtocol';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'vaultDepositor';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'protocolLossLedger';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'authority';
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: 'driftUser';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [];
+		},
+		{
+			name: 'applyLossCarryforward';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'vaultProtocol';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'vaultDepositor';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'protocolLossLedger';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'authority';
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: 'driftUser';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [];
+		},
+		{
+			name: 'updateVaultDepositorCustodian';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'vaultDepositor';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'authority';
+					isMut: false;
+					isSigner: true;
+				}
+			];
+			args: [
+				{
+					name: 'custodian';
+					type: 'publicKey';
+				}
+			];
+		},
+		{
+			name: 'revokeVaultDepositorCustodian';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'vaultDepositor';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'authority';
+					isMut: false;
+					isSigner: true;
+					docs: ['the depositor authority or its custodian'];
+				}
+			];
+			args: [];
+		},
+		{
+			name: 'initializeVaultDepositorIdempotency';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'vaultDepositor';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'vaultDepositorIdempotency';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'authority';
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: 'payer';
+					isMut: true;
+					isSigner: true;
+				},
+				{
+					name: 'rent';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'systemProgram';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [];
+		},
+		{
+			name: 'depositWithIdempotencyKey';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'vaultDepositor';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'authority';
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: 'vaultTokenAccount';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftUserStats';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftUser';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftState';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'driftSpotMarketVault';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'userTokenAccount';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftProgram';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'tokenProgram';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [
+				{
+					name: 'amount';
+					type: 'u64';
+				},
+				{
+					name: 'idempotencyKey';
+					type: 'u64';
+				}
+			];
+		},
+		{
+			name: 'requestWithdrawWithIdempotencyKey';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'vaultDepositor';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'authority';
+					isMut: false;
+					isSigner: true;
+					docs: ['the depositor authority or its custodian'];
+				},
+				{
+					name: 'driftUserStats';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'driftUser';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [
+				{
+					name: 'withdrawAmount';
+					type: 'u64';
+				},
+				{
+					name: 'withdrawUnit';
+					type: {
+						defined: 'WithdrawUnit';
+					};
+				},
+				{
+					name: 'idempotencyKey';
+					type: 'u64';
+				}
+			];
+		},
+		{
+			name: 'updateVaultLargeWithdrawNotice';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'manager';
+					isMut: false;
+					isSigner: true;
+				}
+			];
+			args: [
+				{
+					name: 'largeWithdrawThreshold';
+					type: 'u32';
+				},
+				{
+					name: 'largeWithdrawNoticePeriod';
+					type: 'i64';
+				}
+			];
+		},
+		{
+			name: 'initializeVaultWithdrawSettlement';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'vaultWithdrawSettlement';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'manager';
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: 'payer';
+					isMut: true;
+					isSigner: true;
+				},
+				{
+					name: 'rent';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'systemProgram';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [];
+		},
+		{
+			name: 'settleVaultWithdraws';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'vaultWithdrawSettlement';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftUser';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [];
+		},
+		{
+			name: 'updateVaultProfile';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'manager';
+					isMut: false;
+					isSigner: true;
+				}
+			];
+			args: [
+				{
+					name: 'profile';
+					type: 'u8';
+				}
+			];
+		},
+		{
+			name: 'enforceVaultProfile';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'driftUser';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftProgram';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [];
+		},
+		{
+			name: 'initializeVaultPerformanceStats';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'vaultPerformanceStats';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'payer';
+					isMut: true;
+					isSigner: true;
+				},
+				{
+					name: 'rent';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'systemProgram';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [];
+		},
+		{
+			name: 'updateVaultPerformanceStats';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'vaultPerformanceStats';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftUser';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [];
+		},
+		{
+			name: 'initializeVaultInterface';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'vaultInterface';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'manager';
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: 'payer';
+					isMut: true;
+					isSigner: true;
+				},
+				{
+					name: 'rent';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'systemProgram';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [
+				{
+					name: 'interface';
+					type: 'publicKey';
+				},
+				{
+					name: 'feeRate';
+					type: 'u32';
+				}
+			];
+		},
+		{
+			name: 'updateVaultInterface';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'vaultInterface';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'manager';
+					isMut: false;
+					isSigner: true;
+				}
+			];
+			args: [
+				{
+					name: 'feeRate';
+					type: 'u32';
+				}
+			];
+		},
+		{
+			name: 'depositWithInterface';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'vaultDepositor';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'authority';
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: 'vaultTokenAccount';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftUserStats';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftUser';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftState';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'driftSpotMarketVault';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'userTokenAccount';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftProgram';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'tokenProgram';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [
+				{
+					name: 'amount';
+					type: 'u64';
+				},
+				{
+					name: 'interface';
+					type: 'publicKey';
+				}
+			];
+		},
+		{
+			name: 'withdrawWithInterface';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'vaultDepositor';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'authority';
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: 'vaultTokenAccount';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftUserStats';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftUser';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftState';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'driftSpotMarketVault';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftSigner';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'userTokenAccount';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftProgram';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'tokenProgram';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [
+				{
+					name: 'interface';
+					type: 'publicKey';
+				}
+			];
+		},
+		{
+			name: 'claimVaultInterfaceFees';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'vaultInterface';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'vaultDepositor';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'interface';
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: 'driftUserStats';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'driftUser';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [];
+		},
+		{
+			name: 'initializeVaultDepositorMemo';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'vaultDepositor';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'vaultDepositorMemo';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'authority';
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: 'payer';
+					isMut: true;
+					isSigner: true;
+				},
+				{
+					name: 'rent';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'systemProgram';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [
+				{
+					name: 'memo';
+					type: {
+						array: ['u8', 32];
+					};
+				}
+			];
+		},
+		{
+			name: 'updateVaultDepositorMemo';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'vaultDepositor';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'vaultDepositorMemo';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'authority';
+					isMut: false;
+					isSigner: true;
+				}
+			];
+			args: [
+				{
+					name: 'memo';
+					type: {
+						array: ['u8', 32];
+					};
+				}
+			];
+		},
+		{
+			name: 'initializeVaultDustWithdraw';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'vaultDustWithdraw';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'manager';
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: 'payer';
+					isMut: true;
+					isSigner: true;
+				},
+				{
+					name: 'rent';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'systemProgram';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [
+				{
+					name: 'threshold';
+					type: 'u64';
+				},
+				{
+					name: 'windowMaxAmount';
+					type: 'u64';
+				}
+			];
+		},
+		{
+			name: 'updateVaultDustWithdraw';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'vaultDustWithdraw';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'manager';
+					isMut: false;
+					isSigner: true;
+				}
+			];
+			args: [
+				{
+					name: 'threshold';
+					type: 'u64';
+				},
+				{
+					name: 'windowMaxAmount';
+					type: 'u64';
+				}
+			];
+		},
+		{
+			name: 'withdrawDust';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'vaultDepositor';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'authority';
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: 'vaultTokenAccount';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftUserStats';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftUser';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftState';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'driftSpotMarketVault';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftSigner';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'userTokenAccount';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftProgram';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'tokenProgram';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [];
+		},
+		{
+			name: 'initializeVaultWithdrawStream';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'vaultDepositor';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'vaultWithdrawStream';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'authority';
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: 'payer';
+					isMut: true;
+					isSigner: true;
+				},
+				{
+					name: 'rent';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'systemProgram';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [];
+		},
+		{
+			name: 'startWithdrawStream';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'vaultDepositor';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'vaultWithdrawStream';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'authority';
+					isMut: false;
+					isSigner: true;
+				}
+			];
+			args: [
+				{
+					name: 'duration';
+					type: 'i64';
+				}
+			];
+		},
+		{
+			name: 'claimWithdrawStream';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'vaultDepositor';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'authority';
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: 'vaultTokenAccount';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftUserStats';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftUser';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftState';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'driftSpotMarketVault';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftSigner';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'userTokenAccount';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftProgram';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'tokenProgram';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [];
+		},
+		{
+			name: 'simulateWithdraw';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'vaultDepositor';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'driftUser';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [];
+			returns: {
+				defined: 'WithdrawBlocker';
+			};
+		},
+		{
+			name: 'simulateWithdrawStatus';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'vaultDepositor';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'driftUser';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [];
+			returns: {
+				defined: 'SimulateWithdrawStatus';
+			};
+		},
+		{
+			name: 'updateVaultDisabledFeatures';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'manager';
+					isMut: false;
+					isSigner: true;
+				}
+			];
+			args: [
+				{
+					name: 'disabledFeatures';
+					type: 'u8';
+				}
+			];
+		},
+		{
+			name: 'initializeVaultRegistry';
+			accounts: [
+				{
+					name: 'vaultRegistry';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'payer';
+					isMut: true;
+					isSigner: true;
+				},
+				{
+					name: 'rent';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'systemProgram';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [];
+		},
+		{
+			name: 'initializeVaultRegistryPage';
+			accounts: [
+				{
+					name: 'vaultRegistry';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'vaultRegistryPage';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'payer';
+					isMut: true;
+					isSigner: true;
+				},
+				{
+					name: 'rent';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'systemProgram';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [
+				{
+					name: 'pageIndex';
+					type: 'u32';
+				}
+			];
+		},
+		{
+			name: 'registerVault';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'vaultTokenAccount';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'vaultRegistry';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'vaultRegistryPage';
+					isMut: true;
+					isSigner: false;
+				}
+			];
+			args: [];
+		},
+		{
+			name: 'migrateVaultDepositMint';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'manager';
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: 'admin';
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: 'driftState';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'vaultTokenAccount';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftUser';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'driftSpotMarket';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'successorDriftSpotMarket';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'payer';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'tokenProgram';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [];
+		},
+		{
+			name: 'initializeVaultTokenAccount';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'vaultTokenAccount';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftSpotMarket';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'driftSpotMarketMint';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'payer';
+					isMut: true;
+					isSigner: true;
+				},
+				{
+					name: 'rent';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'systemProgram';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'tokenProgram';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [];
+		},
+		{
+			name: 'depositWithCancelWithdrawRequest';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'vaultDepositor';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'authority';
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: 'vaultTokenAccount';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftUserStats';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftUser';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftState';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'driftSpotMarketVault';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'userTokenAccount';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftProgram';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'tokenProgram';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [
+				{
+					name: 'amount';
+					type: 'u64';
+				},
+				{
+					name: 'cancelWithdrawRequest';
+					type: 'bool';
+				}
+			];
+		},
+		{
+			name: 'initializeVaultParamChangeLog';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'vaultParamChangeLog';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'manager';
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: 'payer';
+					isMut: true;
+					isSigner: true;
+				},
+				{
+					name: 'rent';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'systemProgram';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [];
+		},
+		{
+			name: 'initializeVaultDepositorCap';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'vaultDepositorCap';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'manager';
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: 'payer';
+					isMut: true;
+					isSigner: true;
+				},
+				{
+					name: 'rent';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'systemProgram';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [
+				{
+					name: 'maxDepositors';
+					type: 'u32';
+				}
+			];
+		},
+		{
+			name: 'updateVaultDepositorCap';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'vaultDepositorCap';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'manager';
+					isMut: false;
+					isSigner: true;
+				}
+			];
+			args: [
+				{
+					name: 'maxDepositors';
+					type: 'u32';
+				}
+			];
+		},
+		{
+			name: 'initializeVaultFeeHoliday';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'vaultFeeHoliday';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'manager';
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: 'payer';
+					isMut: true;
+					isSigner: true;
+				},
+				{
+					name: 'rent';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'systemProgram';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [];
+		},
+		{
+			name: 'scheduleVaultFeeHoliday';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'vaultFeeHoliday';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'manager';
+					isMut: false;
+					isSigner: true;
+				}
+			];
+			args: [
+				{
+					name: 'startTs';
+					type: 'i64';
+				},
+				{
+					name: 'endTs';
+					type: 'i64';
+				},
+				{
+					name: 'suspendManagementFee';
+					type: 'bool';
+				},
+				{
+					name: 'suspendProfitShare';
+					type: 'bool';
+				}
+			];
+		},
+		{
+			name: 'cancelVaultFeeHoliday';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'vaultFeeHoliday';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'manager';
+					isMut: false;
+					isSigner: true;
+				}
+			];
+			args: [];
+		},
+		{
+			name: 'crankVaultFeeHoliday';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'vaultFeeHoliday';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftUser';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [];
+		},
+		{
+			name: 'updateVaultProfitShareMode';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'vaultConfig';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'manager';
+					isMut: false;
+					isSigner: true;
+				}
+			];
+			args: [
+				{
+					name: 'profitShareMode';
+					type: 'u8';
+				}
+			];
+		},
+		{
+			name: 'initializeProtocolTreasury';
+			accounts: [
+				{
+					name: 'protocolTreasury';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'protocol';
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: 'payer';
+					isMut: true;
+					isSigner: true;
+				},
+				{
+					name: 'rent';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'systemProgram';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [
+				{
+					name: 'treasury';
+					type: 'publicKey';
+				}
+			];
+		},
+		{
+			name: 'collectProtocolFees';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'vaultProtocol';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'protocolTreasury';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'vaultTokenAccount';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftUserStats';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftUser';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftState';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'driftSpotMarketVault';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftSigner';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'treasuryTokenAccount';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftProgram';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'tokenProgram';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [];
+		},
+		{
+			name: 'initializeWithdrawReceipt';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'vaultDepositor';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'withdrawReceipt';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'authority';
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: 'payer';
+					isMut: true;
+					isSigner: true;
+				},
+				{
+					name: 'rent';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'systemProgram';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [];
+		},
+		{
+			name: 'requestWithdrawWithCancelDeadline';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'vaultDepositor';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'authority';
+					isMut: false;
+					isSigner: true;
+					docs: ['the depositor authority or its custodian'];
+				},
+				{
+					name: 'driftUserStats';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'driftUser';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [
+				{
+					name: 'withdrawAmount';
+					type: 'u64';
+				},
+				{
+					name: 'withdrawUnit';
+					type: {
+						defined: 'WithdrawUnit';
+					};
+				},
+				{
+					name: 'cancelDeadlineTs';
+					type: 'i64';
+				}
+			];
+		},
+		{
+			name: 'updateVaultRequireSettleCrank';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'manager';
+					isMut: false;
+					isSigner: true;
+				}
+			];
+			args: [
+				{
+					name: 'requireSettleCrank';
+					type: 'bool';
+				}
+			];
+		},
+		{
+			name: 'initializeVaultDelegateProposal';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'vaultProtocol';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'vaultDelegateProposal';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'manager';
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: 'protocol';
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: 'payer';
+					isMut: true;
+					isSigner: true;
+				},
+				{
+					name: 'rent';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'systemProgram';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [];
+		},
+		{
+			name: 'updateVaultDelegateDualApproval';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'vaultProtocol';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'vaultDelegateProposal';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'manager';
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: 'protocol';
+					isMut: false;
+					isSigner: true;
+				}
+			];
+			args: [
+				{
+					name: 'enabled';
+					type: 'bool';
+				}
+			];
+		},
+		{
+			name: 'proposeVaultDelegate';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'vaultDelegateProposal';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'manager';
+					isMut: false;
+					isSigner: true;
+				}
+			];
+			args: [
+				{
+					name: 'delegate';
+					type: 'publicKey';
+				}
+			];
+		},
+		{
+			name: 'approveVaultDelegate';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'vaultProtocol';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'vaultDelegateProposal';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'protocol';
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: 'driftUser';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftProgram';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [
+				{
+					name: 'delegate';
+					type: 'publicKey';
+				}
+			];
+		},
+		{
+			name: 'commitVaultReport';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'vaultReport';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'manager';
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: 'payer';
+					isMut: true;
+					isSigner: true;
+				},
+				{
+					name: 'rent';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'systemProgram';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [
+				{
+					name: 'epoch';
+					type: 'u32';
+				},
+				{
+					name: 'reportHash';
+					type: {
+						array: ['u8', 32];
+					};
+				}
+			];
+		},
+		{
+			name: 'queueWithdraw';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'vaultDepositor';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'authority';
+					isMut: false;
+					isSigner: true;
+					docs: ['the depositor authority or its custodian'];
+				}
+			];
+			args: [];
+		},
+		{
+			name: 'executeQueuedWithdraw';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'keeper';
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: 'vaultDepositor';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'vaultTokenAccount';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftUserStats';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftUser';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftState';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'driftSpotMarketVault';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftSigner';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'userTokenAccount';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftProgram';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'tokenProgram';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [];
+		},
+		{
+			name: 'initializeVaultFeeSplit';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'vaultFeeSplit';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'manager';
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: 'payer';
+					isMut: true;
+					isSigner: true;
+				},
+				{
+					name: 'rent';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'systemProgram';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [
+				{
+					name: 'split';
+					type: 'u32';
+				},
+				{
+					name: 'recipient';
+					type: 'publicKey';
+				}
+			];
+		},
+		{
+			name: 'updateVaultFeeSplit';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'vaultFeeSplit';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'manager';
+					isMut: false;
+					isSigner: true;
+				}
+			];
+			args: [
+				{
+					name: 'split';
+					type: 'u32';
+				},
+				{
+					name: 'recipient';
+					type: 'publicKey';
+				}
+			];
+		},
+		{
+			name: 'distributeVaultFeeSplit';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'vaultFeeSplit';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'vaultTokenAccount';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftUserStats';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftUser';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftState';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'driftSpotMarketVault';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftSigner';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'recipientTokenAccount';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftProgram';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'tokenProgram';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [];
+		},
+		{
+			name: 'updateVaultDepositorAutoRenewWithdraw';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'vaultDepositor';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'authority';
+					isMut: false;
+					isSigner: true;
+					docs: ['the depositor authority or its custodian'];
+				}
+			];
+			args: [
+				{
+					name: 'enabled';
+					type: 'bool';
+				}
+			];
+		},
+		{
+			name: 'renewWithdrawRequest';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'vaultDepositor';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'keeper';
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: 'driftUserStats';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'driftUser';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [];
+		},
+		{
+			name: 'initializeProtocolTvlCap';
+			accounts: [
+				{
+					name: 'protocolTvlCap';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'protocol';
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: 'payer';
+					isMut: true;
+					isSigner: true;
+				},
+				{
+					name: 'rent';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'systemProgram';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [
+				{
+					name: 'maxTvl';
+					type: 'u64';
+				}
+			];
+		},
+		{
+			name: 'updateProtocolTvlCap';
+			accounts: [
+				{
+					name: 'protocolTvlCap';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'protocol';
+					isMut: false;
+					isSigner: true;
+				}
+			];
+			args: [
+				{
+					name: 'maxTvl';
+					type: 'u64';
+				}
+			];
+		},
+		{
+			name: 'addVaultToProtocolTvlCap';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'vaultProtocol';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'protocolTvlCap';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'protocol';
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: 'driftUser';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [];
+		},
+		{
+			name: 'removeVaultFromProtocolTvlCap';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'vaultProtocol';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'protocolTvlCap';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'protocol';
+					isMut: false;
+					isSigner: true;
+				}
+			];
+			args: [];
+		},
+		{
+			name: 'initializeVaultRedeemSchedule';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'vaultRedeemSchedule';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'manager';
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: 'payer';
+					isMut: true;
+					isSigner: true;
+				},
+				{
+					name: 'rent';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'systemProgram';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [
+				{
+					name: 'buckets';
+					type: {
+						array: [
+							{
+								defined: 'RedeemPeriodBucket';
+							},
+							4
+						];
+					};
+				}
+			];
+		},
+		{
+			name: 'updateVaultRedeemSchedule';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'vaultRedeemSchedule';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'manager';
+					isMut: false;
+					isSigner: true;
+				}
+			];
+			args: [
+				{
+					name: 'buckets';
+					type: {
+						array: [
+							{
+								defined: 'RedeemPeriodBucket';
+							},
+							4
+						];
+					};
+				}
+			];
+		},
+		{
+			name: 'updateVaultFuelBoost';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'vaultConfig';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'manager';
+					isMut: false;
+					isSigner: true;
+				}
+			];
+			args: [
+				{
+					name: 'fuelBoost';
+					type: 'u8';
+				}
+			];
+		},
+		{
+			name: 'applyProfitShareBatch';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'manager';
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: 'driftUserStats';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'driftUser';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [
+				{
+					name: 'emitDetails';
+					type: 'bool';
+				}
+			];
+		},
+		{
+			name: 'updateCumulativeFuelAmountBatch';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'signer';
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: 'driftUserStats';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [];
+		},
+		{
+			name: 'backfillVaultDepositorCounts';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: true;
+					isSigner: false;
+				}
+			];
+			args: [];
+		},
+		{
+			name: 'updateVaultDepositorBlacklist';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'vaultDepositor';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'manager';
+					isMut: false;
+					isSigner: true;
+				}
+			];
+			args: [
+				{
+					name: 'blacklisted';
+					type: 'bool';
+				}
+			];
+		},
+		{
+			name: 'withdrawToDriftUser';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'vaultDepositor';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'authority';
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: 'vaultTokenAccount';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftUserStats';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftUser';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftState';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'driftSpotMarketVault';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftSigner';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'userTokenAccount';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'depositorDriftUser';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'depositorDriftUserStats';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftProgram';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'tokenProgram';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [];
+		},
+		{
+			name: 'depositFromDriftUser';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'vaultDepositor';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'authority';
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: 'vaultTokenAccount';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftUserStats';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftUser';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftState';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'driftSpotMarketVault';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'userTokenAccount';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'depositorDriftUser';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'depositorDriftUserStats';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftSigner';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'driftProgram';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'tokenProgram';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [
+				{
+					name: 'amount';
+					type: 'u64';
+				}
+			];
+		},
+		{
+			name: 'initializeVaultManagerFeeClaim';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'vaultManagerFeeClaim';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftUser';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'manager';
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: 'payer';
+					isMut: true;
+					isSigner: true;
+				},
+				{
+					name: 'rent';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'systemProgram';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [
+				{
+					name: 'claimCap';
+					type: 'u32';
+				}
+			];
+		},
+		{
+			name: 'claimVaultManagerFee';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'vaultManagerFeeClaim';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftUser';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'manager';
+					isMut: false;
+					isSigner: true;
+				}
+			];
+			args: [];
+		},
+		{
+			name: 'initializeVaultShareStatement';
+			accounts: [
+				{
+					name: 'vaultShareSnapshot';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'vaultShareStatement';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'payer';
+					isMut: true;
+					isSigner: true;
+				},
+				{
+					name: 'rent';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'systemProgram';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [];
+		},
+		{
+			name: 'appendVaultShareStatement';
+			accounts: [
+				{
+					name: 'vaultShareSnapshot';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'vaultShareStatement';
+					isMut: true;
+					isSigner: false;
+				}
+			];
+			args: [];
+		},
+		{
+			name: 'initializeVaultWithdrawEscrow';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'vaultWithdrawEscrow';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftUserStats';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'escrowDriftUser';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftState';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'manager';
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: 'payer';
+					isMut: true;
+					isSigner: true;
+				},
+				{
+					name: 'rent';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'systemProgram';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'driftProgram';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [];
+		},
+		{
+			name: 'initializeVaultDepositorWithdrawEscrow';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'vaultDepositor';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'vaultDepositorWithdrawEscrow';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'authority';
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: 'payer';
+					isMut: true;
+					isSigner: true;
+				},
+				{
+					name: 'rent';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'systemProgram';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [];
+		},
+		{
+			name: 'escrowWithdrawRequest';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'vaultDepositor';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'authority';
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: 'vaultWithdrawEscrow';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'vaultDepositorWithdrawEscrow';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftUserStats';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftUser';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'escrowDriftUser';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftState';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'driftSpotMarketVault';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'driftProgram';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [];
+		},
+		{
+			name: 'withdrawFromEscrow';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'vaultDepositor';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'authority';
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: 'vaultWithdrawEscrow';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'vaultDepositorWithdrawEscrow';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'vaultTokenAccount';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftUserStats';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'escrowDriftUser';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftState';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'driftSpotMarketVault';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftSigner';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'userTokenAccount';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftProgram';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'tokenProgram';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [];
+		},
+		{
+			name: 'initializeVaultShareClass';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'vaultShareClass';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'manager';
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: 'payer';
+					isMut: true;
+					isSigner: true;
+				},
+				{
+					name: 'rent';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'systemProgram';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [
+				{
+					name: 'lockPeriod';
+					type: 'i64';
+				},
+				{
+					name: 'profitShareDiscount';
+					type: 'u32';
+				}
+			];
+		},
+		{
+			name: 'initializeVaultDepositorShareClass';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'vaultDepositor';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'vaultDepositorShareClass';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'authority';
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: 'payer';
+					isMut: true;
+					isSigner: true;
+				},
+				{
+					name: 'rent';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'systemProgram';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [];
+		},
+		{
+			name: 'convertShareClass';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'vaultDepositor';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'authority';
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: 'vaultShareClass';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'vaultDepositorShareClass';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftUser';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'driftUserStats';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [
+				{
+					name: 'amount';
+					type: 'u64';
+				},
+				{
+					name: 'unit';
+					type: {
+						defined: 'WithdrawUnit';
+					};
+				},
+				{
+					name: 'locked';
+					type: 'bool';
+				}
+			];
+		},
+		{
+			name: 'initializeVaultDepositorThrottle';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'vaultConfig';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'vaultDepositorThrottle';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'manager';
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: 'payer';
+					isMut: true;
+					isSigner: true;
+				},
+				{
+					name: 'rent';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'systemProgram';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [
+				{
+					name: 'minDepositInterval';
+					type: 'u32';
+				},
+				{
+					name: 'minRequestInterval';
+					type: 'u32';
+				}
+			];
+		},
+		{
+			name: 'updateVaultDepositorThrottle';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'vaultDepositorThrottle';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'manager';
+					isMut: false;
+					isSigner: true;
+				}
+			];
+			args: [
+				{
+					name: 'minDepositInterval';
+					type: 'u32';
+				},
+				{
+					name: 'minRequestInterval';
+					type: 'u32';
+				}
+			];
+		},
+		{
+			name: 'initializeVaultDepositorThrottleState';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'vaultDepositor';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'vaultDepositorThrottleState';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'authority';
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: 'payer';
+					isMut: true;
+					isSigner: true;
+				},
+				{
+					name: 'rent';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'systemProgram';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [];
+		},
+		{
+			name: 'depositorAction';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'vaultDepositor';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'authority';
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: 'vaultTokenAccount';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftUserStats';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftUser';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftState';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'driftSpotMarketVault';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftSigner';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'userTokenAccount';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftProgram';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'tokenProgram';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [
+				{
+					name: 'action';
+					type: {
+						defined: 'DepositorAction';
+					};
+				}
+			];
+		},
+		{
+			name: 'checkDelegateSelfTrade';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'driftUser';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [];
+		},
+		{
+			name: 'updateVaultProtocolFeeWaiver';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'vaultProtocol';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'vaultConfig';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'protocol';
+					isMut: false;
+					isSigner: true;
+				}
+			];
+			args: [
+				{
+					name: 'tvlThreshold';
+					type: 'u64';
+				},
+				{
+					name: 'exitTvlThreshold';
+					type: 'u64';
+				}
+			];
+		},
+		{
+			name: 'initializeVaultWithdrawPriority';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'vaultWithdrawPriority';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'manager';
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: 'payer';
+					isMut: true;
+					isSigner: true;
+				},
+				{
+					name: 'rent';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'systemProgram';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [
+				{
+					name: 'fullBoostFee';
+					type: 'u32';
+				}
+			];
+		},
+		{
+			name: 'updateVaultWithdrawPriority';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'vaultWithdrawPriority';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'manager';
+					isMut: false;
+					isSigner: true;
+				}
+			];
+			args: [
+				{
+					name: 'fullBoostFee';
+					type: 'u32';
+				}
+			];
+		},
+		{
+			name: 'boostWithdrawRequest';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'vaultDepositor';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'authority';
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: 'vaultWithdrawPriority';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'driftUserStats';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'driftUser';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [
+				{
+					name: 'priorityFee';
+					type: 'u32';
+				}
+			];
+		},
+		{
+			name: 'recordLiquidationAction';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'vaultDepositor';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'liquidationDelegate';
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: 'driftUser';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [];
+		},
+		{
+			name: 'initializeVaultCrystallization';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'vaultCrystallization';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftUser';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'manager';
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: 'payer';
+					isMut: true;
+					isSigner: true;
+				},
+				{
+					name: 'rent';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'systemProgram';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [
+				{
+					name: 'crystallizationPeriod';
+					type: 'u32';
+				}
+			];
+		},
+		{
+			name: 'updateVaultCrystallization';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'vaultCrystallization';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'manager';
+					isMut: false;
+					isSigner: true;
+				}
+			];
+			args: [
+				{
+					name: 'profitShare';
+					type: 'u32';
+				}
+			];
+		},
+		{
+			name: 'crystallizeVaultProfitShare';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'vaultCrystallization';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftUser';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [];
+		},
+		{
+			name: 'initializeVaultBackstop';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'vaultBackstop';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'vaultTokenAccount';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'backstopTokenAccount';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'mint';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'manager';
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: 'payer';
+					isMut: true;
+					isSigner: true;
+				},
+				{
+					name: 'rent';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'systemProgram';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'tokenProgram';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [
+				{
+					name: 'contributionRate';
+					type: 'u32';
+				},
+				{
+					name: 'deductible';
+					type: 'u32';
+				}
+			];
+		},
+		{
+			name: 'updateVaultBackstop';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'vaultBackstop';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'manager';
+					isMut: false;
+					isSigner: true;
+				}
+			];
+			args: [
+				{
+					name: 'contributionRate';
+					type: 'u32';
+				},
+				{
+					name: 'deductible';
+					type: 'u32';
+				}
+			];
+		},
+		{
+			name: 'updateVaultBackstopMaxBalance';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'vaultProtocol';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'vaultBackstop';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'protocol';
+					isMut: false;
+					isSigner: true;
+				}
+			];
+			args: [
+				{
+					name: 'maxBalance';
+					type: 'u64';
+				}
+			];
+		},
+		{
+			name: 'contributeVaultBackstop';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'vaultBackstop';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'vaultTokenAccount';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftUserStats';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftUser';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftState';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'driftSpotMarketVault';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftSigner';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'backstopTokenAccount';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftProgram';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'tokenProgram';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [];
+		},
+		{
+			name: 'claimVaultBackstop';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'vaultBackstop';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'backstopTokenAccount';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'vaultTokenAccount';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftUserStats';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftUser';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftState';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'driftSpotMarketVault';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftProgram';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'tokenProgram';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [];
+		},
+		{
+			name: 'sweepVaultFuel';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftUserStats';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'fuelOverflow';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'payer';
+					isMut: true;
+					isSigner: true;
+				},
+				{
+					name: 'driftProgram';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'rent';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'systemProgram';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [];
+		},
+		{
+			name: 'initializeVaultProfitShareHistory';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'vaultProfitShareHistory';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'manager';
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: 'payer';
+					isMut: true;
+					isSigner: true;
+				},
+				{
+					name: 'rent';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'systemProgram';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [];
+		},
+		{
+			name: 'checkpointVaultProfitShare';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'vaultProfitShareHistory';
+					isMut: true;
+					isSigner: false;
+				}
+			];
+			args: [];
+		},
+		{
+			name: 'applyFeeReductionRefund';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'vaultProfitShareHistory';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftUser';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'manager';
+					isMut: false;
+					isSigner: true;
+				}
+			];
+			args: [
+				{
+					name: 'profitShare';
+					type: 'u32';
+				}
+			];
+		},
+		{
+			name: 'initializeOmnibusLedger';
+			accounts: [
+				{
+					name: 'vaultDepositor';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'omnibusLedger';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'authority';
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: 'payer';
+					isMut: true;
+					isSigner: true;
+				},
+				{
+					name: 'rent';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'systemProgram';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [];
+		},
+		{
+			name: 'initializeOmnibusLedgerPage';
+			accounts: [
+				{
+					name: 'vaultDepositor';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'omnibusLedger';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'omnibusLedgerPage';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'authority';
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: 'payer';
+					isMut: true;
+					isSigner: true;
+				},
+				{
+					name: 'rent';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'systemProgram';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [
+				{
+					name: 'pageIndex';
+					type: 'u16';
+				}
+			];
+		},
+		{
+			name: 'updateOmnibusLedgerEntry';
+			accounts: [
+				{
+					name: 'vaultDepositor';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'omnibusLedger';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'omnibusLedgerPage';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'authority';
+					isMut: false;
+					isSigner: true;
+				}
+			];
+			args: [
+				{
+					name: 'entryIndex';
+					type: 'u8';
+				},
+				{
+					name: 'beneficiary';
+					type: 'publicKey';
+				},
+				{
+					name: 'shares';
+					type: 'u128';
+				}
+			];
+		},
+		{
+			name: 'updateVaultMaxOracleTwapDeviation';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'vaultGuard';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'manager';
+					isMut: false;
+					isSigner: true;
+				}
+			];
+			args: [
+				{
+					name: 'maxOracleTwapDeviationBps';
+					type: 'u16';
+				}
+			];
+		},
+		{
+			name: 'initializeVaultGuard';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'vaultConfig';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'vaultGuard';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'manager';
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: 'payer';
+					isMut: true;
+					isSigner: true;
+				},
+				{
+					name: 'rent';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'systemProgram';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [
+				{
+					name: 'maxOracleTwapDeviationBps';
+					type: 'u16';
+				}
+			];
+		},
+		{
+			name: 'initializeVaultManagerActivity';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'vaultManagerActivity';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'manager';
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: 'payer';
+					isMut: true;
+					isSigner: true;
+				},
+				{
+					name: 'rent';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'systemProgram';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [
+				{
+					name: 'inactivityPeriod';
+					type: 'u32';
+				}
+			];
+		},
+		{
+			name: 'updateVaultManagerActivity';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'vaultManagerActivity';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'manager';
+					isMut: false;
+					isSigner: true;
+				}
+			];
+			args: [
+				{
+					name: 'inactivityPeriod';
+					type: 'u32';
+				}
+			];
+		},
+		{
+			name: 'inactiveManagerWithdraw';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'vaultDepositor';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'authority';
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: 'vaultTokenAccount';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftUserStats';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftUser';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftState';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'driftSpotMarketVault';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftSigner';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'userTokenAccount';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftProgram';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'tokenProgram';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [];
+		},
+		{
+			name: 'managerHeartbeat';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'vaultManagerActivity';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'manager';
+					isMut: false;
+					isSigner: true;
+				}
+			];
+			args: [];
+		},
+		{
+			name: 'updateVaultFeePolicy';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'vaultConfig';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'manager';
+					isMut: false;
+					isSigner: true;
+				}
+			];
+			args: [
+				{
+					name: 'feePolicy';
+					type: 'u8';
+				}
+			];
+		},
+		{
+			name: 'updateVaultMinDepositUsd';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'vaultGuard';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'manager';
+					isMut: false;
+					isSigner: true;
+				}
+			];
+			args: [
+				{
+					name: 'minDepositUsd';
+					type: 'u32';
+				}
+			];
+		},
+		{
+			name: 'viewDepositorPortfolio';
+			accounts: [];
+			args: [];
+			returns: {
+				vec: {
+					defined: 'PortfolioPosition';
+				};
+			};
+		},
+		{
+			name: 'checkVaultStateHash';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [
+				{
+					name: 'expectedStateHash';
+					type: 'u64';
+				}
+			];
+		},
+		{
+			name: 'previewDeposit';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'vaultDepositor';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'authority';
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: 'vaultTokenAccount';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftUserStats';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftUser';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftState';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'driftSpotMarketVault';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'userTokenAccount';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftProgram';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'tokenProgram';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [
+				{
+					name: 'amount';
+					type: 'u64';
+				}
+			];
+		},
+		{
+			name: 'previewWithdraw';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'vaultDepositor';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'authority';
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: 'vaultTokenAccount';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftUserStats';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftUser';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftState';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'driftSpotMarketVault';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftSigner';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'userTokenAccount';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftProgram';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'tokenProgram';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [];
+		},
+		{
+			name: 'realizeDepositorProfits';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'vaultDepositor';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'authority';
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: 'driftUserStats';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'driftUser';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [];
+		},
+		{
+			name: 'initializeVaultDepositorRecordLog';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'vaultDepositorRecordLog';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'manager';
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: 'payer';
+					isMut: true;
+					isSigner: true;
+				},
+				{
+					name: 'rent';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'systemProgram';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [];
+		},
+		{
+			name: 'withdrawAndClose';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'vaultDepositor';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'authority';
+					isMut: true;
+					isSigner: true;
+					docs: [
+						'receives the rent of the vault depositor and its companion accounts'
+					];
+				},
+				{
+					name: 'vaultTokenAccount';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftUserStats';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftUser';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftState';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'driftSpotMarketVault';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftSigner';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'userTokenAccount';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftProgram';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'tokenProgram';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [];
+		},
+		{
+			name: 'initializeVaultWithdrawBlackout';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'vaultGuard';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'vaultWithdrawBlackout';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'manager';
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: 'payer';
+					isMut: true;
+					isSigner: true;
+				},
+				{
+					name: 'rent';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'systemProgram';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [];
+		},
+		{
+			name: 'scheduleVaultWithdrawBlackout';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'vaultWithdrawBlackout';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'manager';
+					isMut: false;
+					isSigner: true;
+				}
+			];
+			args: [
+				{
+					name: 'startTs';
+					type: 'i64';
+				},
+				{
+					name: 'endTs';
+					type: 'i64';
+				}
+			];
+		},
+		{
+			name: 'initializeVaultLockup';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'vaultGuard';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'vaultLockup';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'manager';
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: 'payer';
+					isMut: true;
+					isSigner: true;
+				},
+				{
+					name: 'rent';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'systemProgram';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [
+				{
+					name: 'lockupPeriod';
+					type: 'u32';
+				}
+			];
+		},
+		{
+			name: 'updateVaultLockup';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'vaultLockup';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'manager';
+					isMut: false;
+					isSigner: true;
+				}
+			];
+			args: [
+				{
+					name: 'lockupPeriod';
+					type: 'u32';
+				}
+			];
+		},
+		{
+			name: 'initializeVaultDepositorLockup';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'vaultDepositor';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'vaultDepositorLockup';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'authority';
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: 'payer';
+					isMut: true;
+					isSigner: true;
+				},
+				{
+					name: 'rent';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'systemProgram';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [];
+		},
+		{
+			name: 'initializeVaultDepositorWithdrawRequests';
+			accounts: [
+				{
+					name: 'vaultDepositor';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'vaultDepositorWithdrawRequests';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'authority';
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: 'payer';
+					isMut: true;
+					isSigner: true;
+				},
+				{
+					name: 'rent';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'systemProgram';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [];
+		},
+		{
+			name: 'parkWithdrawRequest';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'vaultDepositor';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'vaultDepositorWithdrawRequests';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'authority';
+					isMut: false;
+					isSigner: true;
+					docs: ['the depositor authority or its custodian'];
+				}
+			];
+			args: [];
+		},
+		{
+			name: 'withdrawParkedRequest';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'vaultDepositor';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'authority';
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: 'vaultTokenAccount';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftUserStats';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftUser';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftState';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'driftSpotMarketVault';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftSigner';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'userTokenAccount';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftProgram';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'tokenProgram';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [
+				{
+					name: 'index';
+					type: 'u8';
+				}
+			];
+		},
+		{
+			name: 'cancelParkedWithdrawRequest';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'vaultDepositor';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'authority';
+					isMut: false;
+					isSigner: true;
+					docs: ['the depositor authority or its custodian'];
+				},
+				{
+					name: 'driftUserStats';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'driftUser';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [
+				{
+					name: 'index';
+					type: 'u8';
+				}
+			];
+		},
+		{
+			name: 'initializeVaultAuditor';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'vaultAuditor';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'manager';
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: 'payer';
+					isMut: true;
+					isSigner: true;
+				},
+				{
+					name: 'rent';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'systemProgram';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [
+				{
+					name: 'auditor';
+					type: 'publicKey';
+				}
+			];
+		},
+		{
+			name: 'updateVaultAuditor';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'vaultAuditor';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'manager';
+					isMut: false;
+					isSigner: true;
+				}
+			];
+			args: [
+				{
+					name: 'auditor';
+					type: 'publicKey';
+				}
+			];
+		},
+		{
+			name: 'viewVaultFeeLedger';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [];
+			returns: {
+				defined: 'VaultFeeLedger';
+			};
+		},
+		{
+			name: 'proposeVaultDepositorAuthorityTransfer';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'vaultDepositor';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'vaultDepositorAuthorityTransfer';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'authority';
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: 'payer';
+					isMut: true;
+					isSigner: true;
+				},
+				{
+					name: 'rent';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'systemProgram';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [
+				{
+					name: 'newAuthority';
+					type: 'publicKey';
+				}
+			];
+		},
+		{
+			name: 'acceptVaultDepositorAuthorityTransfer';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'vaultDepositor';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'authority';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'vaultDepositorAuthorityTransfer';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'newVaultDepositor';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'newVaultDepositorLockup';
+					isMut: true;
+					isSigner: false;
+					isOptional: true;
+					docs: [
+						'required if the old vault depositor has a lockup on a vault with a [`VaultLockup`](crate::state::VaultLockup)'
+					];
+				},
+				{
+					name: 'newVaultDepositorThrottleState';
+					isMut: true;
+					isSigner: false;
+					isOptional: true;
+					docs: [
+						'required if the old vault depositor has a throttle state on a vault with a',
+						'[`VaultDepositorThrottle`](crate::state::VaultDepositorThrottle)'
+					];
+				},
+				{
+					name: 'newVaultDepositorShareClass';
+					isMut: true;
+					isSigner: false;
+					isOptional: true;
+					docs: [
+						'required if the old vault depositor holds locked [`VaultShareClass`](crate::state::VaultShareClass) shares'
+					];
+				},
+				{
+					name: 'newAuthority';
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: 'payer';
+					isMut: true;
+					isSigner: true;
+				},
+				{
+					name: 'rent';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'systemProgram';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [];
+		},
+		{
+			name: 'cancelVaultDepositorAuthorityTransfer';
+			accounts: [
+				{
+					name: 'vaultDepositor';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'vaultDepositorAuthorityTransfer';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'authority';
+					isMut: true;
+					isSigner: true;
+				}
+			];
+			args: [];
+		},
+		{
+			name: 'initializeVaultProfitShareCadence';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'vaultGuard';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'vaultProfitShareCadence';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'manager';
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: 'payer';
+					isMut: true;
+					isSigner: true;
+				},
+				{
+					name: 'rent';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'systemProgram';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [
+				{
+					name: 'minProfitShareInterval';
+					type: 'u32';
+				}
+			];
+		},
+		{
+			name: 'updateVaultProfitShareCadence';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'vaultProfitShareCadence';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'manager';
+					isMut: false;
+					isSigner: true;
+				}
+			];
+			args: [
+				{
+					name: 'minProfitShareInterval';
+					type: 'u32';
+				}
+			];
+		},
+		{
+			name: 'initializeVaultDepositorProfitShareCadence';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'vaultDepositor';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'vaultDepositorProfitShareCadence';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'payer';
+					isMut: true;
+					isSigner: true;
+				},
+				{
+					name: 'rent';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'systemProgram';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [];
+		},
+		{
+			name: 'depositWithSwap';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'vaultDepositor';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'authority';
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: 'vaultTokenAccount';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftUserStats';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftUser';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftState';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'driftSpotMarketVault';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'userTokenAccount';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'driftProgram';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'tokenProgram';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [
+				{
+					name: 'minAmountOut';
+					type: 'u64';
+				},
+				{
+					name: 'swapAccountsLen';
+					type: 'u8';
+				},
+				{
+					name: 'swapData';
+					type: 'bytes';
+				}
+			];
+		},
+		{
+			name: 'initializeVaultConfig';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'vaultConfig';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'manager';
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: 'payer';
+					isMut: true;
+					isSigner: true;
+				},
+				{
+					name: 'rent';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'systemProgram';
+					isMut: false;
+					isSigner: false;
+				}
+			];
+			args: [];
+		},
+		{
+			name: 'updateVaultDepositValuation';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'vaultConfig';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'manager';
+					isMut: false;
+					isSigner: true;
+				}
+			];
+			args: [
+				{
+					name: 'depositValuation';
+					type: 'u8';
+				}
+			];
+		},
+		{
+			name: 'updateVaultProfitShareRealization';
+			accounts: [
+				{
+					name: 'vault';
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: 'vaultConfig';
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: 'manager';
+					isMut: false;
+					isSigner: true;
+				}
+			];
+			args: [
+				{
+					name: 'profitShareRealization';
+					type: 'u8';
+				}
+			];
+		}
+	];
+	accounts: [
+		{
+			name: 'omnibusLedger';
+			docs: [
+				"Per-beneficiary allocation of an omnibus [`VaultDepositor`]'s shares, see",
+				'[`VaultDepositorFlag::Omnibus`](crate::state::VaultDepositorFlag). The depositor authority, usually a DAO',
+				"program's PDA, allocates shares in [`OmnibusLedgerPage`]s and the vault keeps the total allocated covered by the",
+				"depositor's shares: deposits add unallocated shares, withdraw requests can only take unallocated ones."
+			];
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'vaultDepositor';
+						docs: ['The omnibus vault depositor'];
+						type: 'publicKey';
+					},
+					{
+						name: 'totalAllocatedShares';
+						docs: ["Sum of every page's allocations, at shares_base"];
+						type: 'u128';
+					},
+					{
+						name: 'sharesBase';
+						docs: [
+							"The vault depositor's shares_base when the total was last updated"
+						];
+						type: 'u32';
+					},
+					{
+						name: 'pageCount';
+						type: 'u16';
+					},
+					{
+						name: 'bump';
+						type: 'u8';
+					},
+					{
+						name: 'padding';
+						type: {
+							array: ['u8', 9];
+						};
+					}
+				];
+			};
+		},
+		{
+			name: 'omnibusLedgerPage';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'vaultDepositor';
+						docs: ['The omnibus vault depositor'];
+						type: 'publicKey';
+					},
+					{
+						name: 'entries';
+						type: {
+							array: [
+								{
+									defined: 'OmnibusLedgerEntry';
+								},
+								16
+							];
+						};
+					},
+					{
+						name: 'sharesBase';
+						docs: [
+							"The vault depositor's shares_base when the entries were last updated"
+						];
+						type: 'u32';
+					},
+					{
+						name: 'pageIndex';
+						type: 'u16';
+					},
+					{
+						name: 'bump';
+						type: 'u8';
+					},
+					{
+						name: 'padding';
+						type: {
+							array: ['u8', 9];
+						};
+					}
+				];
+			};
+		},
+		{
+			name: 'protocolLossLedger';
+			docs: [
+				'Per-authority loss carryforward across the vaults of one [`VaultProtocol`](crate::state::VaultProtocol).',
+				'Losses moved out of one vault offset profit share in another vault of the same protocol and spot market.'
+			];
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'protocol';
+						docs: ['The `VaultProtocol::protocol` the ledger nets across'];
+						type: 'publicKey';
+					},
+					{
+						name: 'authority';
+						docs: ['The depositor authority'];
+						type: 'publicKey';
+					},
+					{
+						name: 'lossCarryforward';
+						docs: [
+							"Losses recorded from the protocol's vaults not yet netted against profit, in `spot_market_index` token"
+						];
+						type: 'u64';
+					},
+					{
+						name: 'totalLossNetted';
+						docs: [
+							'Total profit that was exempted from profit share by carried losses'
+						];
+						type: 'u64';
+					},
+					{
+						name: 'spotMarketIndex';
+						type: 'u16';
+					},
+					{
+						name: 'bump';
+						type: 'u8';
+					},
+					{
+						name: 'padding';
+						type: {
+							array: ['u8', 5];
+						};
+					}
+				];
+			};
+		},
+		{
+			name: 'protocolTreasury';
+			docs: [
+				"Where `collect_protocol_fees` sends a protocol's fee and profit share shares redeemed across its vaults."
+			];
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'protocol';
+						docs: ['The `VaultProtocol::protocol` the treasury collects for'];
+						type: 'publicKey';
+					},
+					{
+						name: 'treasury';
+						docs: [
+							'Owner of the token accounts fees are paid to, one per vault spot market'
+						];
+						type: 'publicKey';
+					},
+					{
+						name: 'totalCollected';
+						docs: [
+							'Total tokens collected across all vaults and spot markets, precision: token mint'
+						];
+						type: 'u64';
+					},
+					{
+						name: 'bump';
+						type: 'u8';
+					},
+					{
+						name: 'padding';
+						type: {
+							array: ['u8', 7];
+						};
+					}
+				];
+			};
+		},
+		{
+			name: 'protocolTvlCap';
+			docs: [
+				'Caps the combined tvl of the vaults a `VaultProtocol::protocol` adds to it, checked on every deposit into one of',
+				"them. Each vault's tvl is its live equity as of its last deposit, valued in quote so vaults in different spot",
+				'markets can be summed.'
+			];
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'protocol';
+						docs: ['The `VaultProtocol::protocol` whose vaults are capped'];
+						type: 'publicKey';
+					},
+					{
+						name: 'maxTvl';
+						docs: [
+							'Max combined tvl of the vaults, 0 for no cap. precision: QUOTE_PRECISION'
+						];
+						type: 'u64';
+					},
+					{
+						name: 'totalTvl';
+						docs: ["Sum of the vaults' tvl. precision: QUOTE_PRECISION"];
+						type: 'u64';
+					},
+					{
+						name: 'bump';
+						type: 'u8';
+					},
+					{
+						name: 'padding';
+						type: {
+							array: ['u8', 7];
+						};
+					},
+					{
+						name: 'vaults';
+						docs: ['Vaults counted towards the cap, unused entries are zeroed'];
+						type: {
+							array: [
+								{
+									defined: 'ProtocolTvlCapVault';
+								},
+								16
+							];
+						};
+					}
+				];
+			};
+		},
+		{
+			name: 'tokenizedVaultDepositor';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'vault';
+						docs: ['The vault deposited into'];
+						type: 'publicKey';
+					},
+					{
+						name: 'pubkey';
+						docs: [
+							"The vault depositor account's pubkey. It is a pda of vault"
+						];
+						type: 'publicKey';
+					},
+					{
+						name: 'mint';
+						docs: [
+							'The token mint for tokenized shares owned by this VaultDepositor'
+						];
+						type: 'publicKey';
+					},
+					{
+						name: 'vaultShares';
+						docs: [
+							"share of vault owned by this depositor. vault_shares / vault.total_shares is depositor's ownership of vault_equity"
+						];
+						type: 'u128';
+					},
+					{
+						name: 'lastVaultShares';
+						docs: [
+							'stores the vault_shares from the most recent liquidity event (redeem or issuance) before a spl token',
+							'CPI is done, used to track invariants'
+						];
+						type: 'u128';
+					},
+					{
+						name: 'lastValidTs';
+						docs: ['creation ts of vault depositor'];
+						type: 'i64';
+					},
+					{
+						name: 'netDeposits';
+						docs: ['lifetime net deposits of vault depositor for the vault'];
+						type: 'i64';
+					},
+					{
+						name: 'totalDeposits';
+						docs: ['lifetime total deposits'];
+						type: 'u64';
+					},
+					{
+						name: 'totalWithdraws';
+						docs: ['lifetime total withdraws'];
+						type: 'u64';
+					},
+					{
+						name: 'cumulativeProfitShareAmount';
+						docs: [
+							'the token amount of gains the vault depositor has paid performance fees on'
+						];
+						type: 'i64';
+					},
+					{
+						name: 'profitShareFeePaid';
+						type: 'u64';
+					},
+					{
+						name: 'vaultSharesBase';
+						docs: [
+							'The exponent for vault_shares decimal places, kept in step with the vault by apply_rebase_tokenized_depositor.',
+							'Tokens are issued and redeemed at mint supply / vault_shares, so a rebase only changes the shares backing each',
+							'token and the mint keeps its balances and decimals. Tokenizing is blocked until the rebase is applied.'
+						];
+						type: 'u32';
+					},
+					{
+						name: 'bump';
+						docs: ['The bump for the vault pda'];
+						type: 'u8';
+					},
+					{
+						name: 'padding1';
+						type: {
+							array: ['u8', 3];
+						};
+					},
+					{
+						name: 'padding';
+						type: {
+							array: ['u64', 10];
+						};
+					}
+				];
+			};
+		},
+		{
+			name: 'vaultAuditor';
+			docs: [
+				'A read only key the manager hands to a fund administrator or auditor. The auditor can run the manager gated',
+				'exports, like opening a [`VaultShareSnapshot`](crate::state::VaultShareSnapshot) to enumerate depositors, but',
+				'nothing that changes the vault, its funds or its params. `Pubkey::default()` revokes it.'
+			];
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'vault';
+						docs: ['The vault the auditor is for'];
+						type: 'publicKey';
+					},
+					{
+						name: 'auditor';
+						type: 'publicKey';
+					},
+					{
+						name: 'bump';
+						type: 'u8';
+					},
+					{
+						name: 'padding';
+						type: {
+							array: ['u8', 7];
+						};
+					}
+				];
+			};
+		},
+		{
+			name: 'vaultBackstop';
+			docs: [
+				"Insurance pool funded by a slice of the manager's fees that absorbs losses before depositors do. Contributions",
+				"are paid out of the manager's shares into the backstop token account, up to a `max_balance` set by the vault's",
+				'protocol. When the share price falls more than `deductible` below its high water mark, e.g. after bad debt or a',
+				'loss big enough to rebase the vault, the pool deposits into the vault without shares to bring it back up.'
+			];
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'vault';
+						docs: ['The vault the pool backs'];
+						type: 'publicKey';
+					},
+					{
+						name: 'lastManagerFees';
+						docs: [
+							'`manager_total_fee` plus `manager_total_profit_share` contributions have accrued up to'
+						];
+						type: 'u64';
+					},
+					{
+						name: 'owed';
+						docs: [
+							'Accrued and not yet moved into the pool, precision: token mint'
+						];
+						type: 'u64';
+					},
+					{
+						name: 'balance';
+						docs: [
+							'Tokens in the backstop token account, precision: token mint'
+						];
+						type: 'u64';
+					},
+					{
+						name: 'maxBalance';
+						docs: [
+							"Max balance, set by the vault's protocol. Contributions accrued beyond it stay with the manager"
+						];
+						type: 'u64';
+					},
+					{
+						name: 'totalContributed';
+						type: 'u64';
+					},
+					{
+						name: 'totalClaimed';
+						type: 'u64';
+					},
+					{
+						name: 'highWaterMark';
+						docs: [
+							"Highest share price seen by the pool's cranks: PRICE_PRECISION. 0 until the first claim crank"
+						];
+						type: 'u128';
+					},
+					{
+						name: 'contributionRate';
+						docs: [
+							'Slice of manager fees contributed, precision: PERCENTAGE_PRECISION'
+						];
+						type: 'u32';
+					},
+					{
+						name: 'deductible';
+						docs: [
+							'Drop from the high water mark depositors absorb before the pool tops up, precision: PERCENTAGE_PRECISION'
+						];
+						type: 'u32';
+					},
+					{
+						name: 'sharesBase';
+						docs: ["The vault's shares_base when high_water_mark was set"];
+						type: 'u32';
+					},
+					{
+						name: 'bump';
+						type: 'u8';
+					},
+					{
+						name: 'padding';
+						type: {
+							array: ['u8', 3];
+						};
+					}
+				];
+			};
+		},
+		{
+			name: 'vaultConfig';
+			docs: [
+				"Per vault configuration that doesn't fit in [`Vault`], created by the manager with `initialize_vault_config`.",
+				'Every field defaults to the behavior of vaults without one, so instructions on a vault without',
+				'[`VaultFlag::HasVaultConfig`](crate::state::VaultFlag::HasVaultConfig) use [`VaultConfig::default`], see',
+				'[`VaultConfigProvider`](crate::state::VaultConfigProvider).'
+			];
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'vault';
+						docs: ['The vault the config belongs to'];
+						type: 'publicKey';
+					},
+					{
+						name: 'bump';
+						type: 'u8';
+					},
+					{
+						name: 'profitShareMode';
+						docs: ['The [`ProfitShareMode`]. Default is `HighWaterMark`'];
+						type: 'u8';
+					},
+					{
+						name: 'feePolicy';
+						docs: ['The [`FeePolicy`], see [`VaultConfig::fee_policy`]'];
+						type: 'u8';
+					},
+					{
+						name: 'flags';
+						docs: ['Bits of [`VaultConfigFlag`]'];
+						type: 'u8';
+					},
+					{
+						name: 'depositValuation';
+						docs: [
+							'The [`DepositValuation`] deposits are priced at. Default is `FullMark`'
+						];
+						type: 'u8';
+					},
+					{
+						name: 'fuelBoost';
+						docs: [
+							'Max extra fuel for long term depositors, in steps of [`FUEL_BOOST_STEP`], see',
+							'[`VaultConfig::get_fuel_boost_multiplier`]'
+						];
+						type: 'u8';
+					},
+					{
+						name: 'profitShareRealization';
+						docs: ['The [`ProfitShareRealization`]. Default is `Unrealized`'];
+						type: 'u8';
+					},
+					{
+						name: 'padding1';
+						type: {
+							array: ['u8', 1];
+						};
+					},
+					{
+						name: 'protocolFeeTiers';
+						docs: [
+							'Protocol fee schedule that steps down as vault equity grows, see [`ProtocolFeeTier`].',
+							'Tiers are sorted by ascending `tvl_threshold`, unused tiers are zeroed. Set by the protocol with',
+							'`update_vault_protocol_fee_tiers`'
+						];
+						type: {
+							array: [
+								{
+									defined: 'ProtocolFeeTier';
+								},
+								4
+							];
+						};
+					},
+					{
+						name: 'feeWaiverTvlThreshold';
+						docs: [
+							'Vault equity (in vault spot_market_index) below which the protocol fee is waived, 0 to never waive'
+						];
+						type: 'u64';
+					},
+					{
+						name: 'feeWaiverExitTvlThreshold';
+						docs: [
+							'Vault equity a waived vault must grow back to before the protocol fee is charged again, at least',
+							"`fee_waiver_tvl_threshold` so vaults hovering around it don't flap in and out of the waiver"
+						];
+						type: 'u64';
+					},
+					{
+						name: 'padding';
+						type: {
+							array: ['u64', 4];
+						};
+					}
+				];
+			};
+		},
+		{
+			name: 'vaultCrystallization';
+			docs: [
+				"Fund level profit share. Instead of tracking each depositor's cost basis, the manager's profit share is charged",
+				"on the share price's gain above the high water mark and paid by minting manager shares, so one crank covers every",
+				'depositor.',
+				'',
+				"Creating it moves the vault's `profit_share` here and zeroes it on the vault, which stops the per depositor",
+				'manager profit share for good since `update_vault` can only lower it. The protocol profit share stays per',
+				"depositor. Depositors who withdraw between two crystallizations don't pay on the gain since the last one."
+			];
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'vault';
+						docs: ['The vault profit share is crystallized for'];
+						type: 'publicKey';
+					},
+					{
+						name: 'highWaterMark';
+						docs: [
+							'Share price the profit share was last crystallized at, net of it: PRICE_PRECISION'
+						];
+						type: 'u128';
+					},
+					{
+						name: 'lastCrystallizationTs';
+						docs: ['ts of the last crystallization'];
+						type: 'i64';
+					},
+					{
+						name: 'profitShare';
+						docs: [
+							"Manager profit share on depositors' share price gains: PERCENTAGE_PRECISION"
+						];
+						type: 'u32';
+					},
+					{
+						name: 'sharesBase';
+						docs: ["The vault's shares_base when high_water_mark was set"];
+						type: 'u32';
+					},
+					{
+						name: 'crystallizationPeriod';
+						docs: ['Minimum seconds between two crystallizations'];
+						type: 'u32';
+					},
+					{
+						name: 'bump';
+						type: 'u8';
+					},
+					{
+						name: 'padding';
+						type: {
+							array: ['u8', 11];
+						};
+					}
+				];
+			};
+		},
+		{
+			name: 'vaultDelegateProposal';
+			docs: [
+				'A delegate change proposed by the manager of a vault under',
+				'[`VaultProtocolFlag::DelegateDualApproval`](crate::state::VaultProtocolFlag), applied once the protocol approves it.'
+			];
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'vault';
+						docs: ['The vault the proposal is for'];
+						type: 'publicKey';
+					},
+					{
+						name: 'delegate';
+						docs: ['The proposed delegate'];
+						type: 'publicKey';
+					},
+					{
+						name: 'proposedTs';
+						docs: [
+							'When the delegate was proposed, 0 if there is no pending proposal'
+						];
+						type: 'i64';
+					},
+					{
+						name: 'bump';
+						type: 'u8';
+					},
+					{
+						name: 'padding';
+						type: {
+							array: ['u8', 7];
+						};
+					}
+				];
+			};
+		},
+		{
+			name: 'vaultDepositorAuthorityTransfer';
+			docs: [
+				"A [`VaultDepositor`](crate::state::VaultDepositor)'s pending move to `new_authority`, proposed by its authority",
+				'and accepted by the new one. Vault depositor pdas are derived from the authority, so accepting moves the position',
+				"into the new authority's vault depositor rather than rewriting `authority` in place, see",
+				'[`VaultDepositor::transfer_authority`](crate::state::VaultDepositor::transfer_authority).'
+			];
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'vaultDepositor';
+						docs: ['The vault depositor being moved'];
+						type: 'publicKey';
+					},
+					{
+						name: 'newAuthority';
+						docs: ['The authority that has to accept the transfer'];
+						type: 'publicKey';
+					},
+					{
+						name: 'proposedTs';
+						type: 'i64';
+					},
+					{
+						name: 'bump';
+						type: 'u8';
+					},
+					{
+						name: 'padding';
+						type: {
+							array: ['u8', 7];
+						};
+					}
+				];
+			};
+		},
+		{
+			name: 'vaultDepositorCap';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'vault';
+						docs: ['The vault the cap is enforced for'];
+						type: 'publicKey';
+					},
+					{
+						name: 'maxDepositors';
+						docs: [
+							'Max number of vault depositors that can be created, checked against `Vault::depositor_count`'
+						];
+						type: 'u32';
+					},
+					{
+						name: 'bump';
+						type: 'u8';
+					},
+					{
+						name: 'padding';
+						type: {
+							array: ['u8', 3];
+						};
+					}
+				];
+			};
+		},
+		{
+			name: 'vaultDepositorIdempotency';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'vaultDepositor';
+						docs: ['The vault depositor the keys are tracked for'];
+						type: 'publicKey';
+					},
+					{
+						name: 'lastKey';
+						docs: ['Last client supplied key that was executed'];
+						type: 'u64';
+					},
+					{
+						name: 'lastKeyTs';
+						docs: ['ts the last key was executed'];
+						type: 'i64';
+					},
+					{
+						name: 'bump';
+						type: 'u8';
+					},
+					{
+						name: 'padding';
+						type: {
+							array: ['u8', 7];
+						};
+					}
+				];
+			};
+		},
+		{
+			name: 'vaultDepositorMemo';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'vaultDepositor';
+						docs: ['The vault depositor the memo tags'];
+						type: 'publicKey';
+					},
+					{
+						name: 'memo';
+						docs: [
+							'Free form tag set by the depositor authority, e.g. the hash of an internal reference'
+						];
+						type: {
+							array: ['u8', 32];
+						};
+					},
+					{
+						name: 'lastUpdateTs';
+						docs: ['ts the memo was last updated'];
+						type: 'i64';
+					},
+					{
+						name: 'bump';
+						type: 'u8';
+					},
+					{
+						name: 'padding';
+						type: {
+							array: ['u8', 7];
+						};
+					}
+				];
+			};
+		},
+		{
+			name: 'vaultDepositorRecordLog';
+			docs: [
+				"Opt-in on-chain mirror of a vault's latest depositor records, so indexers on RPCs that truncate logs can backfill",
+				'missed events by reading the account. Holds the latest [`VAULT_DEPOSITOR_RECORD_LOG_ENTRIES`] deposits, withdraw',
+				'requests, cancels, withdraws and profit share payments, record `n` is in entry',
+				"`n % VAULT_DEPOSITOR_RECORD_LOG_ENTRIES`. Only written when passed in remaining accounts, a gap in the depositor's",
+				'share chain means a record was left out.'
+			];
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'vault';
+						docs: ['The vault whose depositor records are logged'];
+						type: 'publicKey';
+					},
+					{
+						name: 'entries';
+						type: {
+							array: [
+								{
+									defined: 'VaultDepositorRecordLogEntry';
+								},
+								32
+							];
+						};
+					},
+					{
+						name: 'count';
+						docs: ['Records logged since the log was created'];
+						type: 'u64';
+					},
+					{
+						name: 'bump';
+						type: 'u8';
+					},
+					{
+						name: 'padding';
+						type: {
+							array: ['u8', 7];
+						};
+					}
+				];
+			};
+		},
+		{
+			name: 'vaultDepositorThrottle';
+			docs: [
+				'Per-depositor rate limits on deposits and withdraw requests, to deter probing the share price and sandwiching',
+				"oracle updates. Enforced once the vault's [`VaultConfig`](crate::state::VaultConfig) has",
+				'[`VaultConfigFlag::DepositorThrottle`](crate::state::VaultConfigFlag::DepositorThrottle). Depositors track their',
+				'last deposit in a [`VaultDepositorThrottleState`], withdraw requests are checked against the [`VaultDepositor`].'
+			];
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'vault';
+						docs: ['The vault the throttle is enforced for'];
+						type: 'publicKey';
+					},
+					{
+						name: 'minDepositInterval';
+						docs: [
+							"Min seconds from the depositor's last deposit to its next deposit"
+						];
+						type: 'u32';
+					},
+					{
+						name: 'minRequestInterval';
+						docs: [
+							"Min seconds from the depositor's last withdraw request, cancel or withdraw to its next withdraw request"
+						];
+						type: 'u32';
+					},
+					{
+						name: 'bump';
+						type: 'u8';
+					},
+					{
+						name: 'padding';
+						type: {
+							array: ['u8', 7];
+						};
+					}
+				];
+			};
+		},
+		{
+			name: 'vaultDepositorThrottleState';
+			docs: [
+				"A depositor's last deposit into a vault with a [`VaultDepositorThrottle`]. Deposits into such a vault require",
+				"it, depositors that never created one haven't deposited since the throttle was enabled."
+			];
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'vaultDepositor';
+						docs: ['The vault depositor whose deposits are tracked'];
+						type: 'publicKey';
+					},
+					{
+						name: 'lastDepositTs';
+						type: 'i64';
+					},
+					{
+						name: 'bump';
+						type: 'u8';
+					},
+					{
+						name: 'padding';
+						type: {
+							array: ['u8', 7];
+						};
+					}
+				];
+			};
+		},
+		{
+			name: 'vaultDepositorWithdrawRequests';
+			docs: [
+				'Withdraw requests a depositor has parked out of `last_withdraw_request`, so it can hold several partial requests',
+				"with their own redeem timers. A parked request keeps its shares, value and ts and still counts towards the vault's",
+				"requested totals, it's moved back into `last_withdraw_request` to be withdrawn or cancelled. Once created every",
+				'withdraw request must pass it, see [`VaultDepositorFlag::ParkedWithdrawRequests`], so parked and pending requests',
+				"never take more than the depositor's shares."
+			];
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'vaultDepositor';
+						docs: ['The vault depositor the requests belong to'];
+						type: 'publicKey';
+					},
+					{
+						name: 'requests';
+						type: {
+							array: [
+								{
+									defined: 'WithdrawRequest';
+								},
+								4
+							];
+						};
+					},
+					{
+						name: 'sharesBase';
+						docs: [
+							"The vault depositor's shares_base when the requests were last updated"
+						];
+						type: 'u32';
+					},
+					{
+						name: 'bump';
+						type: 'u8';
+					},
+					{
+						name: 'padding';
+						type: {
+							array: ['u8', 11];
+						};
+					}
+				];
+			};
+		},
+		{
+			name: 'vaultDepositor';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'vault';
+						docs: ['The vault deposited into'];
+						type: 'publicKey';
+					},
+					{
+						name: 'pubkey';
+						docs: [
+							"The vault depositor account's pubkey. It is a pda of vault and authority"
+						];
+						type: 'publicKey';
+					},
+					{
+						name: 'authority';
+						docs: [
+							'The authority is the address w permission to deposit/withdraw'
+						];
+						type: 'publicKey';
+					},
+					{
+						name: 'vaultShares';
+						docs: [
+							"share of vault owned by this depositor. vault_shares / vault.total_shares is depositor's ownership of vault_equity"
+						];
+						type: 'u128';
+					},
+					{
+						name: 'lastWithdrawRequest';
+						docs: ['last withdraw request'];
+						type: {
+							defined: 'WithdrawRequest';
+						};
+					},
+					{
+						name: 'lastValidTs';
+						docs: ['creation ts of vault depositor'];
+						type: 'i64';
+					},
+					{
+						name: 'netDeposits';
+						docs: ['lifetime net deposits of vault depositor for the vault'];
+						type: 'i64';
+					},
+					{
+						name: 'totalDeposits';
+						docs: ['lifetime total deposits'];
+						type: 'u64';
+					},
+					{
+						name: 'totalWithdraws';
+						docs: ['lifetime total withdraws'];
+						type: 'u64';
+					},
+					{
+						name: 'cumulativeProfitShareAmount';
+						docs: [
+							'the token amount of gains the vault depositor has paid performance fees on'
+						];
+						type: 'i64';
+					},
+					{
+						name: 'profitShareFeePaid';
+						type: 'u64';
+					},
+					{
+						name: 'vaultSharesBase';
+						docs: ['the exponent for vault_shares decimal places'];
+						type: 'u16';
+					},
+					{
+						name: 'version';
+						docs: [
+							'layout version, see [`VAULT_DEPOSITOR_VERSION`]. Accounts created before versioning read as 0'
+						];
+						type: 'u8';
+					},
+					{
+						name: 'flags';
+						docs: ['Bits of [`VaultDepositorFlag`]'];
+						type: 'u8';
+					},
+					{
+						name: 'lastFuelUpdateTs';
+						type: 'u32';
+					},
+					{
+						name: 'cumulativeFuelPerShareAmount';
+						docs: ['precision: FUEL_SHARE_PRECISION'];
+						type: 'u128';
+					},
+					{
+						name: 'fuelAmount';
+						docs: ['precision: none'];
+						type: 'u128';
+					},
+					{
+						name: 'custodian';
+						docs: [
+							'optional key allowed to request and cancel withdraws on behalf of the authority.',
+							'withdraws are still paid to the authority. Pubkey::default() if unset'
+						];
+						type: 'publicKey';
+					}
+				];
+			};
+		},
+		{
+			name: 'vaultDustWithdraw';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'vault';
+						docs: ['The vault dust withdraws are enabled for'];
+						type: 'publicKey';
+					},
+					{
+						name: 'threshold';
+						docs: [
+							'Depositors whose whole balance is at or below this amount can withdraw without a redeem period'
+						];
+						type: 'u64';
+					},
+					{
+						name: 'windowMaxAmount';
+						docs: [
+							"Max total dust withdrawn per [`DUST_WITHDRAW_WINDOW`], so splitting a balance across depositors can't drain",
+							'the vault around the redeem period'
+						];
+						type: 'u64';
+					},
+					{
+						name: 'windowAmount';
+						docs: ['Dust withdrawn in the current window'];
+						type: 'u64';
+					},
+					{
+						name: 'windowStartTs';
+						type: 'i64';
+					},
+					{
+						name: 'bump';
+						type: 'u8';
+					},
+					{
+						name: 'padding';
+						type: {
+							array: ['u8', 7];
+						};
+					}
+				];
+			};
+		},
+		{
+			name: 'vaultFeeHoliday';
+			docs: [
+				"A window during which the manager's management fee and/or profit share is suspended, e.g. to make depositors",
+				'whole after an incident.',
+				'',
+				'The window is applied by `crank_vault_fee_holiday`, which accrues fees up to the crank and then sets or clears',
+				'the holiday [`VaultFlag`]s that `Vault::apply_fee` and the profit share math check. Anyone can crank, so',
+				'depositors can start a holiday on time and the manager can end it on time.'
+			];
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'vault';
+						docs: ['The vault the fee holiday is for'];
+						type: 'publicKey';
+					},
+					{
+						name: 'startTs';
+						docs: ['When the scheduled holiday starts, 0 if none is scheduled'];
+						type: 'i64';
+					},
+					{
+						name: 'endTs';
+						docs: ['When the scheduled holiday ends'];
+						type: 'i64';
+					},
+					{
+						name: 'suspendManagementFee';
+						type: 'bool';
+					},
+					{
+						name: 'suspendProfitShare';
+						type: 'bool';
+					},
+					{
+						name: 'active';
+						docs: [
+							'Whether the holiday has been started by a crank and not yet ended'
+						];
+						type: 'bool';
+					},
+					{
+						name: 'bump';
+						type: 'u8';
+					},
+					{
+						name: 'padding';
+						type: {
+							array: ['u8', 4];
+						};
+					}
+				];
+			};
+		},
+		{
+			name: 'vaultFeeSplit';
+			docs: [
+				"A slice of the manager's profit share the manager committed to an external token account, e.g. a charity or a",
+				'burn address. Every profit share crystallized after the split is configured accrues `split` of it as owed, which',
+				"`distribute_vault_fee_split` pays out of the manager's shares."
+			];
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'vault';
+						docs: ['The vault the split is for'];
+						type: 'publicKey';
+					},
+					{
+						name: 'recipient';
+						docs: ['Token account the split is paid to'];
+						type: 'publicKey';
+					},
+					{
+						name: 'lastManagerTotalProfitShare';
+						docs: [
+							'`Vault::manager_total_profit_share` the split has accrued up to'
+						];
+						type: 'u64';
+					},
+					{
+						name: 'owed';
+						docs: ['Accrued and not yet paid, precision: token mint'];
+						type: 'u64';
+					},
+					{
+						name: 'totalPaid';
+						docs: ['Total paid to the recipient, precision: token mint'];
+						type: 'u64';
+					},
+					{
+						name: 'split';
+						docs: [
+							'Slice of manager profit share, precision: PERCENTAGE_PRECISION'
+						];
+						type: 'u32';
+					},
+					{
+						name: 'bump';
+						type: 'u8';
+					},
+					{
+						name: 'padding';
+						type: {
+							array: ['u8', 3];
+						};
+					}
+				];
+			};
+		},
+		{
+			name: 'vaultGuard';
+			docs: [
+				'Safeguards on depositor flows the manager opts into, enforced on every deposit and payout once the vault has one,',
+				'see [`VaultConfigFlag::VaultGuard`](crate::state::VaultConfigFlag::VaultGuard), independently of the',
+				'[`VaultDepositorThrottle`](crate::state::VaultDepositorThrottle). Deposits and withdraws are rejected while',
+				"the deposit asset's oracle price is too far from its TWAP, so they can't be priced off an oracle spike, and must",
+				'pass the companion accounts marked in its [`GuardFlag`]s. Deposits worth less than the USD minimum are rejected.'
+			];
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'vault';
+						docs: ['The vault the guard is enforced for'];
+						type: 'publicKey';
+					},
+					{
+						name: 'maxOracleTwapDeviationBps';
+						docs: [
+							"Max deviation of the deposit asset's oracle price from its TWAP for deposits and withdraws, in bps. 0 disables",
+							'the check'
+						];
+						type: 'u16';
+					},
+					{
+						name: 'bump';
+						type: 'u8';
+					},
+					{
+						name: 'flags';
+						docs: ['Bits of [`GuardFlag`]'];
+						type: 'u8';
+					},
+					{
+						name: 'minDepositUsd';
+						docs: [
+							"Min deposit value in whole USD at the deposit oracle price, on top of the vault's `min_deposit_amount`. 0",
+							'disables it'
+						];
+						type: 'u32';
+					},
+					{
+						name: 'padding';
+						type: {
+							array: ['u8', 8];
+						};
+					}
+				];
+			};
+		},
+		{
+			name: 'vaultHook';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'vault';
+						docs: ['The vault this hook is configured for'];
+						type: 'publicKey';
+					},
+					{
+						name: 'hookProgram';
+						docs: [
+							"The program CPI'd before and after depositor deposits and withdrawals.",
+							'Pubkey::default() disables the hook.'
+						];
+						type: 'publicKey';
+					},
+					{
+						name: 'bump';
+						type: 'u8';
+					},
+					{
+						name: 'padding';
+						type: {
+							array: ['u8', 7];
+						};
+					}
+				];
+			};
+		},
+		{
+			name: 'vaultInterface';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'vault';
+						docs: ['The vault the interface routes deposits and withdraws to'];
+						type: 'publicKey';
+					},
+					{
+						name: 'interface';
+						docs: [
+							"The front-end operator's key, passed as `interface` on deposits and withdraws"
+						];
+						type: 'publicKey';
+					},
+					{
+						name: 'attributedDeposits';
+						docs: ['lifetime deposits attributed to the interface'];
+						type: 'u64';
+					},
+					{
+						name: 'attributedWithdraws';
+						docs: ['lifetime withdraws attributed to the interface'];
+						type: 'u64';
+					},
+					{
+						name: 'accruedFee';
+						docs: [
+							"fees owed to the interface, paid out of the manager's shares on claim"
+						];
+						type: 'u64';
+					},
+					{
+						name: 'totalFeePaid';
+						docs: ['lifetime fees paid to the interface'];
+						type: 'u64';
+					},
+					{
+						name: 'feeRate';
+						docs: [
+							'fee the manager pays on attributed volume, precision: PERCENTAGE_PRECISION'
+						];
+						type: 'u32';
+					},
+					{
+						name: 'bump';
+						type: 'u8';
+					},
+					{
+						name: 'padding';
+						type: {
+							array: ['u8', 3];
+						};
+					}
+				];
+			};
+		},
+		{
+			name: 'vaultLockup';
+			docs: [
+				"Min time from a depositor's last deposit to its next withdraw request, for strategies with a longer horizon than",
+				'the redeem period, which only starts at the request. Depositors track their last deposit in a',
+				"[`VaultDepositorLockup`]. Enforced through the vault's [`VaultGuard`](crate::state::VaultGuard), regardless of the",
+				'[`VaultDepositorThrottle`](crate::state::VaultDepositorThrottle).'
+			];
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'vault';
+						docs: ['The vault the lockup is enforced for'];
+						type: 'publicKey';
+					},
+					{
+						name: 'lockupPeriod';
+						docs: [
+							"Min seconds from a depositor's last deposit to its next withdraw request"
+						];
+						type: 'u32';
+					},
+					{
+						name: 'bump';
+						type: 'u8';
+					},
+					{
+						name: 'padding';
+						type: {
+							array: ['u8', 3];
+						};
+					}
+				];
+			};
+		},
+		{
+			name: 'vaultDepositorLockup';
+			docs: [
+				"A depositor's last deposit into a vault with a [`VaultLockup`]. Deposits into such a vault require it, deposits",
+				"made before the depositor created it aren't locked up, nor are depositors that never created one."
+			];
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'vaultDepositor';
+						docs: ['The vault depositor whose deposits are tracked'];
+						type: 'publicKey';
+					},
+					{
+						name: 'lastDepositTs';
+						type: 'i64';
+					},
+					{
+						name: 'bump';
+						type: 'u8';
+					},
+					{
+						name: 'padding';
+						type: {
+							array: ['u8', 7];
+						};
+					}
+				];
+			};
+		},
+		{
+			name: 'vaultManagerActivity';
+			docs: [
+				'Tracks when the manager was last active so depositors can exit with `inactive_manager_withdraw`, skipping the',
+				'redeem period, once the manager has gone quiet for `inactivity_period`. `manager_heartbeat` and manager signed',
+				"instructions passing it in remaining_accounts refresh it, and fills by the vault's delegate count as activity",
+				'through the drift user stats.'
+			];
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'vault';
+						docs: ['The vault the manager activity is tracked for'];
+						type: 'publicKey';
+					},
+					{
+						name: 'lastManagerActivityTs';
+						docs: ['ts of the last manager signed action or heartbeat'];
+						type: 'i64';
+					},
+					{
+						name: 'inactivityPeriod';
+						docs: [
+							'Seconds without manager activity or delegate fills before depositors can exit early'
+						];
+						type: 'u32';
+					},
+					{
+						name: 'bump';
+						type: 'u8';
+					},
+					{
+						name: 'padding';
+						type: {
+							array: ['u8', 3];
+						};
+					}
+				];
+			};
+		},
+		{
+			name: 'vaultManagerFeeClaim';
+			docs: [
+				"Defers the vault's management fee into a claimable share balance so it's minted in bounded steps instead of",
+				'all at once.',
+				'',
+				"Once initialized the vault's `management_fee` is moved here and zeroed on the [`Vault`], so `Vault::apply_fee`",
+				'stops minting it. `claim_vault_manager_fee` accrues the fee since the last claim as shares, at the same rate',
+				"`Vault::apply_fee` would have minted them, and mints at most `claim_cap` of the vault's total shares per",
+				'[`MANAGER_FEE_CLAIM_PERIOD`]. Anything over the cap rolls forward to the next period.'
+			];
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'vault';
+						docs: ['The vault the fee claim is for'];
+						type: 'publicKey';
+					},
+					{
+						name: 'accruedShares';
+						docs: ['Management fee shares accrued and not yet minted'];
+						type: 'u128';
+					},
+					{
+						name: 'periodClaimedShares';
+						docs: ['Shares minted in the current claim period'];
+						type: 'u128';
+					},
+					{
+						name: 'periodStartTs';
+						docs: ['When the current claim period started'];
+						type: 'i64';
+					},
+					{
+						name: 'lastAccrueTs';
+						docs: ['When the management fee was last accrued'];
+						type: 'i64';
+					},
+					{
+						name: 'managementFee';
+						docs: [
+							'The annual management fee moved off the vault: PERCENTAGE_PRECISION'
+						];
+						type: 'i64';
+					},
+					{
+						name: 'claimCap';
+						docs: [
+							"Most of the vault's total shares that can be minted per claim period: PERCENTAGE_PRECISION"
+						];
+						type: 'u32';
+					},
+					{
+						name: 'sharesBase';
+						docs: [
+							'The vault `shares_base` the share balances are denominated in'
+						];
+						type: 'u32';
+					},
+					{
+						name: 'bump';
+						type: 'u8';
+					},
+					{
+						name: 'padding';
+						type: {
+							array: ['u8', 15];
+						};
+					}
+				];
+			};
+		},
+		{
+			name: 'vaultParamChangeLog';
+			docs: [
+				"On-chain history of a vault's parameter updates, kept alongside the [`VaultParamChangeRecord`] events since RPC",
+				'providers truncate old transaction logs. Holds the latest [`VAULT_PARAM_CHANGE_LOG_ENTRIES`] changes, change `n`',
+				'is in entry `n % VAULT_PARAM_CHANGE_LOG_ENTRIES`.'
+			];
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'vault';
+						docs: ['The vault whose parameter changes are logged'];
+						type: 'publicKey';
+					},
+					{
+						name: 'count';
+						docs: ['Changes recorded since the log was created'];
+						type: 'u64';
+					},
+					{
+						name: 'entries';
+						type: {
+							array: [
+								{
+									defined: 'VaultParamChange';
+								},
+								32
+							];
+						};
+					},
+					{
+						name: 'bump';
+						type: 'u8';
+					},
+					{
+						name: 'padding';
+						type: {
+							array: ['u8', 7];
+						};
+					}
+				];
+			};
+		},
+		{
+			name: 'vaultPerformanceStats';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'vault';
+						docs: ['The vault these stats are for'];
+						type: 'publicKey';
+					},
+					{
+						name: 'lastEquityPerShare';
+						docs: [
+							'Equity per share at the end of the last period, precision: PERCENTAGE_PRECISION'
+						];
+						type: 'u128';
+					},
+					{
+						name: 'lastPeriodTs';
+						docs: ['ts the last period ended, 0 before the first crank'];
+						type: 'i64';
+					},
+					{
+						name: 'currentStreak';
+						docs: ['Consecutive periods equity per share increased'];
+						type: 'u32';
+					},
+					{
+						name: 'longestStreak';
+						docs: [
+							'Longest run of consecutive periods equity per share increased'
+						];
+						type: 'u32';
+					},
+					{
+						name: 'totalPeriods';
+						docs: ['Periods recorded'];
+						type: 'u32';
+					},
+					{
+						name: 'positivePeriods';
+						docs: ['Periods equity per share increased'];
+						type: 'u32';
+					},
+					{
+						name: 'sharesBase';
+						docs: [
+							"The vault's shares_base when last_equity_per_share was set"
+						];
+						type: 'u32';
+					},
+					{
+						name: 'bump';
+						type: 'u8';
+					},
+					{
+						name: 'padding';
+						type: {
+							array: ['u8', 19];
+						};
+					},
+					{
+						name: 'lastCumulativeDepositInterest';
+						docs: [
+							"The vault's spot market `cumulative_deposit_interest` at the end of the last period"
+						];
+						type: 'u128';
+					},
+					{
+						name: 'lastDepositorEquity';
+						docs: [
+							"Depositors' share of vault equity at the end of the last period"
+						];
+						type: 'u64';
+					},
+					{
+						name: 'lastNetDeposits';
+						docs: ["The vault's `net_deposits` at the end of the last period"];
+						type: 'i64';
+					},
+					{
+						name: 'lastManagerFees';
+						docs: [
+							"The vault's `manager_total_fee` plus `manager_total_profit_share` at the end of the last period"
+						];
+						type: 'u64';
+					},
+					{
+						name: 'lastSpotTokenAmount';
+						docs: [
+							"The drift user's signed token amount in the vault's spot market at the end of the last period"
+						];
+						type: 'i64';
+					},
+					{
+						name: 'lastCumulativeBorrowInterest';
+						docs: [
+							"The vault's spot market `cumulative_borrow_interest` at the end of the last period"
+						];
+						type: 'u128';
+					},
+					{
+						name: 'lastVaultEquity';
+						docs: ['Vault equity at the end of the last period'];
+						type: 'u64';
+					},
+					{
+						name: 'lastManagerNetDeposits';
+						docs: [
+							"The vault's `manager_net_deposits` at the end of the last period"
+						];
+						type: 'i64';
+					},
+					{
+						name: 'periodInterestPnl';
+						docs: [
+							"Lend interest earned, less borrow interest paid, on the vault's spot market balance over the last period"
+						];
+						type: 'i64';
+					},
+					{
+						name: 'periodTradingPnl';
+						docs: [
+							"Vault pnl over the last period that isn't [`Self::period_interest_pnl`]"
+						];
+						type: 'i64';
+					},
+					{
+						name: 'cumulativeInterestPnl';
+						docs: [
+							'Sum of [`Self::period_interest_pnl`] over every attributed period'
+						];
+						type: 'i64';
+					},
+					{
+						name: 'cumulativeTradingPnl';
+						docs: [
+							'Sum of [`Self::period_trading_pnl`] over every attributed period'
+						];
+						type: 'i64';
+					},
+					{
+						name: 'padding1';
+						type: {
+							array: ['u8', 16];
+						};
+					}
+				];
+			};
+		},
+		{
+			name: 'vaultPoll';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'vault';
+						docs: ['The vault being voted on'];
+						type: 'publicKey';
+					},
+					{
+						name: 'newManager';
+						docs: [
+							'The manager proposed by a [`VaultPollProposal::ReplaceManager`] poll'
+						];
+						type: 'publicKey';
+					},
+					{
+						name: 'votesFor';
+						docs: [
+							'Depositor shares voting for the proposal, snapshotted at each vote'
+						];
+						type: 'u128';
+					},
+					{
+						name: 'totalShares';
+						docs: [
+							"The vault's user shares when the poll started, quorum is measured against this"
+						];
+						type: 'u128';
+					},
+					{
+						name: 'startTs';
+						type: 'i64';
+					},
+					{
+						name: 'endTs';
+						type: 'i64';
+					},
+					{
+						name: 'pollId';
+						docs: ['Incremented every poll, 0 if no poll has been started'];
+						type: 'u32';
+					},
+					{
+						name: 'sharesBase';
+						docs: ["The vault's shares_base when the poll started"];
+						type: 'u32';
+					},
+					{
+						name: 'proposal';
+						docs: ['[`VaultPollProposal`]'];
+						type: 'u8';
+					},
+					{
+						name: 'executed';
+						type: 'bool';
+					},
+					{
+						name: 'bump';
+						type: 'u8';
+					},
+					{
+						name: 'padding';
+						type: {
+							array: ['u8', 5];
+						};
+					}
+				];
+			};
+		},
+		{
+			name: 'vaultPollVote';
+			docs: [
+				"A depositor's vote on a [`VaultPoll`], one per depositor per poll."
+			];
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'vaultPoll';
+						type: 'publicKey';
+					},
+					{
+						name: 'authority';
+						type: 'publicKey';
+					},
+					{
+						name: 'shares';
+						docs: ["The depositor's shares at vote time"];
+						type: 'u128';
+					},
+					{
+						name: 'ts';
+						type: 'i64';
+					},
+					{
+						name: 'pollId';
+						type: 'u32';
+					},
+					{
+						name: 'bump';
+						type: 'u8';
+					},
+					{
+						name: 'padding';
+						type: {
+							array: ['u8', 3];
+						};
+					}
+				];
+			};
+		},
+		{
+			name: 'vaultProfitShareCadence';
+			docs: [
+				"Min time between two profit share crystallizations of a depositor's position by `apply_profit_share` cranks and",
+				"`realize_depositor_profits`, so profit share isn't locked in on every transient spike. Depositors track their last",
+				'one in a [`VaultDepositorProfitShareCadence`]. Deposits and withdraw requests still charge profit share as they',
+				"need to. Enforced through the vault's [`VaultGuard`](crate::state::VaultGuard), regardless of the",
+				'[`VaultDepositorThrottle`](crate::state::VaultDepositorThrottle).'
+			];
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'vault';
+						docs: ['The vault the cadence is enforced for'];
+						type: 'publicKey';
+					},
+					{
+						name: 'minProfitShareInterval';
+						docs: [
+							"Min seconds between two crystallizations of a depositor's profit share"
+						];
+						type: 'u32';
+					},
+					{
+						name: 'bump';
+						type: 'u8';
+					},
+					{
+						name: 'padding';
+						type: {
+							array: ['u8', 3];
+						};
+					}
+				];
+			};
+		},
+		{
+			name: 'vaultDepositorProfitShareCadence';
+			docs: [
+				"A depositor's last profit share crystallization in a vault with a [`VaultProfitShareCadence`]. Anyone can create",
+				"it, so cranks aren't blocked on the depositor."
+			];
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'vaultDepositor';
+						docs: ['The vault depositor whose crystallizations are tracked'];
+						type: 'publicKey';
+					},
+					{
+						name: 'lastProfitShareTs';
+						type: 'i64';
+					},
+					{
+						name: 'bump';
+						type: 'u8';
+					},
+					{
+						name: 'padding';
+						type: {
+							array: ['u8', 7];
+						};
+					}
+				];
+			};
+		},
+		{
+			name: 'vaultProfitShareHistory';
+			docs: [
+				"Rolling checkpoints of the manager's realized profit share, so a manager lowering `profit_share` can refund the",
+				'difference on what was realized since the oldest checkpoint, between three and four',
+				'[`PROFIT_SHARE_CHECKPOINT_PERIOD`]s back. The refund burns manager shares, so it accrues to everyone holding',
+				"shares when it's applied rather than to the depositors who paid the profit share."
+			];
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'vault';
+						docs: ['The vault the checkpoints are for'];
+						type: 'publicKey';
+					},
+					{
+						name: 'checkpoints';
+						docs: ['Oldest first'];
+						type: {
+							array: [
+								{
+									defined: 'ProfitShareCheckpoint';
+								},
+								4
+							];
+						};
+					},
+					{
+						name: 'totalRefunded';
+						docs: ['Total refunded to shareholders, precision: token mint'];
+						type: 'u64';
+					},
+					{
+						name: 'bump';
+						type: 'u8';
+					},
+					{
+						name: 'padding';
+						type: {
+							array: ['u8', 7];
+						};
+					}
+				];
+			};
+		},
+		{
+			name: 'vaultProtocol';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'protocol';
+						docs: [
+							'The protocol, company, or entity that services the product using this vault.',
+							'The protocol is not allowed to deposit into the vault but can profit share and collect annual fees just like the manager.'
+						];
+						type: 'publicKey';
+					},
+					{
+						name: 'protocolProfitAndFeeShares';
+						docs: [
+							'The shares from profit share and annual fee unclaimed by the protocol.'
+						];
+						type: 'u128';
+					},
+					{
+						name: 'protocolFee';
+						docs: [
+							'The annual fee charged on deposits by the protocol (traditional hedge funds typically charge 2% per year on assets under management).',
+							"Unlike the management fee this can't be negative."
+						];
+						type: 'u64';
+					},
+					{
+						name: 'protocolTotalWithdraws';
+						docs: ['Total withdraws for the protocol'];
+						type: 'u64';
+					},
+					{
+						name: 'protocolTotalFee';
+						docs: [
+							'Total fee charged by the protocol (annual management fee + profit share).',
+							"Unlike the management fee this can't be negative."
+						];
+						type: 'u64';
+					},
+					{
+						name: 'protocolTotalProfitShare';
+						docs: ['Total profit share charged by the protocol'];
+						type: 'u64';
+					},
+					{
+						name: 'lastProtocolWithdrawRequest';
+						type: {
+							defined: 'WithdrawRequest';
+						};
+					},
+					{
+						name: 'protocolProfitShare';
+						docs: [
+							'Percentage the protocol charges on all profits realized by depositors: PERCENTAGE_PRECISION'
+						];
+						type: 'u32';
+					},
+					{
+						name: 'bump';
+						type: 'u8';
+					},
+					{
+						name: 'version';
+						type: 'u8';
+					},
+					{
+						name: 'flags';
+						docs: ['Bits of [`VaultProtocolFlag`]'];
+						type: 'u8';
+					},
+					{
+						name: 'padding';
+						type: {
+							array: ['u8', 1];
+						};
+					}
+				];
+			};
+		},
+		{
+			name: 'vaultRedeemSchedule';
+			docs: [
+				'Shorter redeem periods for smaller withdraw requests. Requests larger than every bucket, or made without the',
+				"schedule in remaining_accounts, wait the vault's full `redeem_period`, so leaving it out never helps a depositor."
+			];
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'vault';
+						type: 'publicKey';
+					},
+					{
+						name: 'buckets';
+						docs: [
+							'Sorted by ascending `max_withdraw_share`, unused buckets are zeroed'
+						];
+						type: {
+							array: [
+								{
+									defined: 'RedeemPeriodBucket';
+								},
+								4
+							];
+						};
+					},
+					{
+						name: 'bump';
+						type: 'u8';
+					},
+					{
+						name: 'padding';
+						type: {
+							array: ['u8', 7];
+						};
+					}
+				];
+			};
+		},
+		{
+			name: 'vaultRegistry';
+			docs: [
+				'Program wide count of registered vaults. Vaults are listed in order in [`VaultRegistryPage`]s, vault `n` is',
+				'entry `n % VAULT_REGISTRY_PAGE_ENTRIES` of page `n / VAULT_REGISTRY_PAGE_ENTRIES`.'
+			];
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'totalVaults';
+						type: 'u64';
+					},
+					{
+						name: 'bump';
+						type: 'u8';
+					},
+					{
+						name: 'padding';
+						type: {
+							array: ['u8', 7];
+						};
+					}
+				];
+			};
+		},
+		{
+			name: 'vaultRegistryPage';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'entries';
+						type: {
+							array: [
+								{
+									defined: 'VaultRegistryEntry';
+								},
+								32
+							];
+						};
+					},
+					{
+						name: 'pageIndex';
+						type: 'u32';
+					},
+					{
+						name: 'len';
+						docs: ['Number of `entries` filled'];
+						type: 'u32';
+					},
+					{
+						name: 'bump';
+						type: 'u8';
+					},
+					{
+						name: 'padding';
+						type: {
+							array: ['u8', 3];
+						};
+					}
+				];
+			};
+		},
+		{
+			name: 'vaultReport';
+			docs: [
+				"The manager's commitment to the hash of their off-chain report for one accounting `epoch`, so depositors can",
+				"check a published report matches. One account per vault and epoch, a commitment can't be changed."
+			];
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'vault';
+						docs: ['The vault the report is for'];
+						type: 'publicKey';
+					},
+					{
+						name: 'reportHash';
+						docs: [
+							'Hash of the report document, e.g. sha256 of the published pdf'
+						];
+						type: {
+							array: ['u8', 32];
+						};
+					},
+					{
+						name: 'committedTs';
+						docs: ['When the hash was committed'];
+						type: 'i64';
+					},
+					{
+						name: 'epoch';
+						docs: [
+							'Manager chosen accounting period, e.g. 202610 for october 2026'
+						];
+						type: 'u32';
+					},
+					{
+						name: 'bump';
+						type: 'u8';
+					},
+					{
+						name: 'padding';
+						type: {
+							array: ['u8', 3];
+						};
+					}
+				];
+			};
+		},
+		{
+			name: 'vaultRiskScore';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'vault';
+						docs: ['The vault this risk score is for'];
+						type: 'publicKey';
+					},
+					{
+						name: 'lastDelegate';
+						docs: [
+							'The vault delegate at the last update, used to track delegate turnover'
+						];
+						type: 'publicKey';
+					},
+					{
+						name: 'peakEquityPerShare';
+						docs: [
+							'Highest equity per share seen, precision: PERCENTAGE_PRECISION'
+						];
+						type: 'u128';
+					},
+					{
+						name: 'lastUpdateTs';
+						type: 'i64';
+					},
+					{
+						name: 'lastDelegateChangeTs';
+						type: 'i64';
+					},
+					{
+						name: 'leverage';
+						docs: [
+							'Total liabilities / equity, precision: PERCENTAGE_PRECISION'
+						];
+						type: 'u64';
+					},
+					{
+						name: 'concentration';
+						docs: [
+							'Largest single position liability / total liabilities, precision: PERCENTAGE_PRECISION'
+						];
+						type: 'u32';
+					},
+					{
+						name: 'drawdown';
+						docs: [
+							'Drop in equity per share from the peak, precision: PERCENTAGE_PRECISION'
+						];
+						type: 'u32';
+					},
+					{
+						name: 'sharesBase';
+						docs: [
+							"The vault's shares_base when peak_equity_per_share was set"
+						];
+						type: 'u32';
+					},
+					{
+						name: 'delegateChanges';
+						docs: [
+							'Delegate changes within [`DELEGATE_TURNOVER_WINDOW`] of the last change'
+						];
+						type: 'u16';
+					},
+					{
+						name: 'riskScore';
+						docs: ['0 (lowest risk) to 100'];
+						type: 'u8';
+					},
+					{
+						name: 'riskBand';
+						docs: ['[`RiskBand`]'];
+						type: 'u8';
+					},
+					{
+						name: 'bump';
+						type: 'u8';
+					},
+					{
+						name: 'padding';
+						type: {
+							array: ['u8', 7];
+						};
+					}
+				];
+			};
+		},
+		{
+			name: 'vaultShareClass';
+			docs: [
+				"The vault's locked share class. Depositors can convert liquid shares into the class for a discount on the",
+				"manager's profit share, in exchange for not being able to convert them back, or withdraw them, until",
+				'`lock_period` after their last conversion in.'
+			];
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'vault';
+						docs: ['The vault the share class is for'];
+						type: 'publicKey';
+					},
+					{
+						name: 'lockedShares';
+						docs: ['Shares held in the locked class across all depositors'];
+						type: 'u128';
+					},
+					{
+						name: 'lockPeriod';
+						docs: ['How long shares converted into the class are locked for'];
+						type: 'i64';
+					},
+					{
+						name: 'profitShareDiscount';
+						docs: [
+							'Discount on the manager profit share charged on locked shares: PERCENTAGE_PRECISION'
+						];
+						type: 'u32';
+					},
+					{
+						name: 'sharesBase';
+						docs: ['The vault `shares_base` `locked_shares` is denominated in'];
+						type: 'u32';
+					},
+					{
+						name: 'bump';
+						type: 'u8';
+					},
+					{
+						name: 'padding';
+						type: {
+							array: ['u8', 15];
+						};
+					}
+				];
+			};
+		},
+		{
+			name: 'vaultDepositorShareClass';
+			docs: [
+				"A vault depositor's shares in the vault's locked [`VaultShareClass`]. Holds the shares, and the cost basis moved",
+				"with them, outside the [`VaultDepositor`](crate::state::VaultDepositor) so they can't be requested for withdraw",
+				'while locked.'
+			];
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'pubkey';
+						docs: [
+							"The share class holder's pubkey, a pda of the vault depositor"
+						];
+						type: 'publicKey';
+					},
+					{
+						name: 'vaultDepositor';
+						docs: ['The vault depositor the locked shares belong to'];
+						type: 'publicKey';
+					},
+					{
+						name: 'authority';
+						docs: ["The vault depositor's authority"];
+						type: 'publicKey';
+					},
+					{
+						name: 'vaultShares';
+						docs: ['Locked shares held'];
+						type: 'u128';
+					},
+					{
+						name: 'netDeposits';
+						docs: [
+							'Cost basis of the locked shares, moved in from the vault depositor'
+						];
+						type: 'i64';
+					},
+					{
+						name: 'cumulativeProfitShareAmount';
+						type: 'i64';
+					},
+					{
+						name: 'profitShareFeePaid';
+						type: 'u64';
+					},
+					{
+						name: 'unlockTs';
+						docs: ['When the locked shares can be converted back'];
+						type: 'i64';
+					},
+					{
+						name: 'vaultSharesBase';
+						docs: ['The exponent for vault_shares decimal places'];
+						type: 'u32';
+					},
+					{
+						name: 'profitShareDiscount';
+						docs: [
+							"The class's profit share discount as of the last conversion in: PERCENTAGE_PRECISION"
+						];
+						type: 'u32';
+					},
+					{
+						name: 'bump';
+						type: 'u8';
+					},
+					{
+						name: 'padding';
+						type: {
+							array: ['u8', 7];
+						};
+					}
+				];
+			};
+		},
+		{
+			name: 'vaultShareSnapshot';
+			docs: [
+				"Per-epoch share snapshot of a vault, external governance reads each depositor's",
+				'[`VaultDepositorShareSnapshot`] as voting weight.'
+			];
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'vault';
+						type: 'publicKey';
+					},
+					{
+						name: 'totalShares';
+						docs: ['Sum of the snapshotted depositor shares'];
+						type: 'u128';
+					},
+					{
+						name: 'startTs';
+						type: 'i64';
+					},
+					{
+						name: 'endTs';
+						type: 'i64';
+					},
+					{
+						name: 'epoch';
+						type: 'u32';
+					},
+					{
+						name: 'sharesBase';
+						docs: ["The vault's shares_base when the epoch was opened"];
+						type: 'u32';
+					},
+					{
+						name: 'numDepositors';
+						type: 'u32';
+					},
+					{
+						name: 'bump';
+						type: 'u8';
+					},
+					{
+						name: 'padding';
+						type: {
+							array: ['u8', 3];
+						};
+					}
+				];
+			};
+		},
+		{
+			name: 'vaultDepositorShareSnapshot';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'shareSnapshot';
+						type: 'publicKey';
+					},
+					{
+						name: 'authority';
+						type: 'publicKey';
+					},
+					{
+						name: 'shares';
+						type: 'u128';
+					},
+					{
+						name: 'ts';
+						type: 'i64';
+					},
+					{
+						name: 'epoch';
+						type: 'u32';
+					},
+					{
+						name: 'bump';
+						type: 'u8';
+					},
+					{
+						name: 'padding';
+						type: {
+							array: ['u8', 3];
+						};
+					}
+				];
+			};
+		},
+		{
+			name: 'vaultShareStatement';
+			docs: [
+				"Merkle root over every depositor's (authority, shares) in a closed [`VaultShareSnapshot`] epoch, so a depositor",
+				'can prove their balance at that epoch against the root alone.',
+				'',
+				"After the snapshot window closes, `append_vault_share_statement` folds the epoch's",
+				'[`VaultDepositorShareSnapshot`]s into the tree in ascending authority order over as many transactions as needed.',
+				"The ordering rules out duplicates, so once the leaf count matches the snapshot's depositor count every depositor",
+				'is in and the root is published.'
+			];
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'shareSnapshot';
+						type: 'publicKey';
+					},
+					{
+						name: 'lastAuthority';
+						docs: [
+							'Authority of the last appended leaf, the next one must be greater'
+						];
+						type: 'publicKey';
+					},
+					{
+						name: 'root';
+						docs: ['Zeroed until published'];
+						type: {
+							array: ['u8', 32];
+						};
+					},
+					{
+						name: 'frontier';
+						type: {
+							array: [
+								{
+									array: ['u8', 32];
+								},
+								32
+							];
+						};
+					},
+					{
+						name: 'totalShares';
+						docs: ['Sum of the appended shares'];
+						type: 'u128';
+					},
+					{
+						name: 'publishedTs';
+						docs: ['When the root was published, 0 until then'];
+						type: 'i64';
+					},
+					{
+						name: 'numLeaves';
+						type: 'u32';
+					},
+					{
+						name: 'epoch';
+						type: 'u32';
+					},
+					{
+						name: 'bump';
+						type: 'u8';
+					},
+					{
+						name: 'padding';
+						type: {
+							array: ['u8', 15];
+						};
+					}
+				];
+			};
+		},
+		{
+			name: 'vaultWithdrawBlackout';
+			docs: [
+				'Short manager scheduled windows, e.g. around major economic releases, during which depositor withdraws are',
+				'deferred to the end of the window. Windows are published here at least [`MIN_WITHDRAW_BLACKOUT_NOTICE`] in advance',
+				'and the total per [`WITHDRAW_BLACKOUT_PERIOD`] is capped at [`MAX_WITHDRAW_BLACKOUT_PER_PERIOD`]. Enforced',
+				"through the vault's [`VaultGuard`](crate::state::VaultGuard), regardless of the",
+				'[`VaultDepositorThrottle`](crate::state::VaultDepositorThrottle).'
+			];
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'vault';
+						docs: ['The vault the blackout windows are for'];
+						type: 'publicKey';
+					},
+					{
+						name: 'windows';
+						type: {
+							array: [
+								{
+									defined: 'WithdrawBlackoutWindow';
+								},
+								8
+							];
+						};
+					},
+					{
+						name: 'bump';
+						type: 'u8';
+					},
+					{
+						name: 'padding';
+						type: {
+							array: ['u8', 7];
+						};
+					}
+				];
+			};
+		},
+		{
+			name: 'vaultWithdrawEscrow';
+			docs: [
+				'A lend-only drift user, owned by the vault, that pending withdraw requests can be moved into so they keep earning',
+				"lend interest without taking on the strategy's PnL.",
+				'',
+				"The vault delegate can't trade the escrow user and its balance isn't part of the vault's equity. Escrowed amounts",
+				'are tracked as escrow shares so lend interest accrues pro rata to every escrowed request.'
+			];
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'vault';
+						docs: ['The vault the escrow is for'];
+						type: 'publicKey';
+					},
+					{
+						name: 'escrowUser';
+						docs: [
+							"The vault's escrow drift user, sub account [`WITHDRAW_ESCROW_SUB_ACCOUNT_ID`]"
+						];
+						type: 'publicKey';
+					},
+					{
+						name: 'totalShares';
+						docs: ['Escrow shares outstanding across all escrowed requests'];
+						type: 'u128';
+					},
+					{
+						name: 'totalDeposits';
+						docs: ['Lifetime amount escrowed'];
+						type: 'u64';
+					},
+					{
+						name: 'totalWithdraws';
+						docs: [
+							'Lifetime amount paid out of the escrow, including interest'
+						];
+						type: 'u64';
+					},
+					{
+						name: 'bump';
+						type: 'u8';
+					},
+					{
+						name: 'padding';
+						type: {
+							array: ['u8', 15];
+						};
+					}
+				];
+			};
+		},
+		{
+			name: 'vaultDepositorWithdrawEscrow';
+			docs: [
+				"A vault depositor's withdraw request held in the vault's [`VaultWithdrawEscrow`] until it matures."
+			];
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'vaultDepositor';
+						docs: ['The vault depositor the escrowed request belongs to'];
+						type: 'publicKey';
+					},
+					{
+						name: 'shares';
+						docs: ['Escrow shares of the escrowed request'];
+						type: 'u128';
+					},
+					{
+						name: 'amount';
+						docs: ['Amount the request paid into the escrow'];
+						type: 'u64';
+					},
+					{
+						name: 'unlockTs';
+						docs: ['When the escrowed request matures and can be withdrawn'];
+						type: 'i64';
+					},
+					{
+						name: 'bump';
+						type: 'u8';
+					},
+					{
+						name: 'padding';
+						type: {
+							array: ['u8', 15];
+						};
+					}
+				];
+			};
+		},
+		{
+			name: 'vaultWithdrawPriority';
+			docs: [
+				'Lets depositors in queue mode pay a priority fee, forfeiting part of their queued',
+				'[`WithdrawRequest`](crate::state::withdraw_request::WithdrawRequest) to the remaining shareholders, to have it',
+				'mature sooner.'
+			];
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'vault';
+						docs: ['The vault priority fees are paid to'];
+						type: 'publicKey';
+					},
+					{
+						name: 'fullBoostFee';
+						docs: [
+							'Priority fee, as a share of the request, that skips the whole redeem period. Smaller fees skip a',
+							'proportional part of it: PERCENTAGE_PRECISION'
+						];
+						type: 'u32';
+					},
+					{
+						name: 'bump';
+						type: 'u8';
+					},
+					{
+						name: 'padding';
+						type: {
+							array: ['u8', 3];
+						};
+					}
+				];
+			};
+		},
+		{
+			name: 'vaultWithdrawRoute';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'vault';
+						docs: ['The vault this route is configured for'];
+						type: 'publicKey';
+					},
+					{
+						name: 'marketIndexes';
+						docs: [
+							"Spot markets depositor payouts are drawn from before the vault's spot market, highest priority first"
+						];
+						type: {
+							array: ['u16', 4];
+						};
+					},
+					{
+						name: 'numMarkets';
+						docs: ['Number of entries in `market_indexes` that are in use'];
+						type: 'u8';
+					},
+					{
+						name: 'bump';
+						type: 'u8';
+					},
+					{
+						name: 'padding';
+						type: {
+							array: ['u8', 6];
+						};
+					}
+				];
+			};
+		},
+		{
+			name: 'vaultWithdrawSettlement';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'vault';
+						docs: ['The vault whose withdraw requests are settled'];
+						type: 'publicKey';
+					},
+					{
+						name: 'totalShares';
+						docs: ['vault.total_shares at the last settlement'];
+						type: 'u128';
+					},
+					{
+						name: 'equity';
+						docs: ['vault equity at the last settlement'];
+						type: 'u64';
+					},
+					{
+						name: 'ts';
+						docs: ['ts of the last settlement'];
+						type: 'i64';
+					},
+					{
+						name: 'settlementId';
+						docs: ['number of settlements cranked'];
+						type: 'u32';
+					},
+					{
+						name: 'sharesBase';
+						docs: ['vault.shares_base at the last settlement'];
+						type: 'u32';
+					},
+					{
+						name: 'bump';
+						type: 'u8';
+					},
+					{
+						name: 'padding';
+						type: {
+							array: ['u8', 15];
+						};
+					}
+				];
+			};
+		},
+		{
+			name: 'vaultWithdrawStream';
+			docs: [
+				'Releases a matured withdraw request linearly over `duration` instead of as a lump sum.'
+			];
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'vaultDepositor';
+						docs: ['The vault depositor streaming its withdraw request'];
+						type: 'publicKey';
+					},
+					{
+						name: 'shares';
+						docs: ['shares of the withdraw request being streamed'];
+						type: 'u128';
+					},
+					{
+						name: 'claimedShares';
+						docs: ['shares already claimed'];
+						type: 'u128';
+					},
+					{
+						name: 'value';
+						docs: ['value of the withdraw request being streamed'];
+						type: 'u64';
+					},
+					{
+						name: 'claimedValue';
+						docs: ['value already claimed'];
+						type: 'u64';
+					},
+					{
+						name: 'requestTs';
+						docs: ['ts of the streamed withdraw request'];
+						type: 'i64';
+					},
+					{
+						name: 'startTs';
+						type: 'i64';
+					},
+					{
+						name: 'duration';
+						type: 'i64';
+					},
+					{
+						name: 'sharesBase';
+						docs: ["the vault depositor's shares base when the stream started"];
+						type: 'u32';
+					},
+					{
+						name: 'bump';
+						type: 'u8';
+					},
+					{
+						name: 'padding';
+						type: {
+							array: ['u8', 3];
+						};
+					}
+				];
+			};
+		},
+		{
+			name: 'vault';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'name';
+						docs: [
+							'The name of the vault. Vault pubkey is derived from this name.'
+						];
+						type: {
+							array: ['u8', 32];
+						};
+					},
+					{
+						name: 'pubkey';
+						docs: [
+							"The vault's pubkey. It is a pda of name and also used as the authority for drift user"
+						];
+						type: 'publicKey';
+					},
+					{
+						name: 'manager';
+						docs: [
+							'The manager of the vault who has ability to update vault params'
+						];
+						type: 'publicKey';
+					},
+					{
+						name: 'tokenAccount';
+						docs: [
+							'The vaults token account. Used to receive tokens between deposits and withdrawals'
+						];
+						type: 'publicKey';
+					},
+					{
+						name: 'userStats';
+						docs: ['The drift user stats account for the vault'];
+						type: 'publicKey';
+					},
+					{
+						name: 'user';
+						docs: ['The drift user account for the vault'];
+						type: 'publicKey';
+					},
+					{
+						name: 'delegate';
+						docs: [
+							'The vaults designated delegate for drift user account',
+							'can differ from actual user delegate if vault is in liquidation'
+						];
+						type: 'publicKey';
+					},
+					{
+						name: 'liquidationDelegate';
+						docs: ['The delegate handling liquidation for depositor'];
+						type: 'publicKey';
+					},
+					{
+						name: 'userShares';
+						docs: [
+							'The sum of all shares held by the users (vault depositors)'
+						];
+						type: 'u128';
+					},
+					{
+						name: 'totalShares';
+						docs: [
+							'The sum of all shares: deposits from users, manager deposits, manager profit/fee, and protocol profit/fee.',
+							'The manager deposits are total_shares - user_shares - protocol_profit_and_fee_shares.'
+						];
+						type: 'u128';
+					},
+					{
+						name: 'lastFeeUpdateTs';
+						docs: ['Last fee update unix timestamp'];
+						type: 'i64';
+					},
+					{
+						name: 'liquidationStartTs';
+						docs: ['When the liquidation starts'];
+						type: 'i64';
+					},
+					{
+						name: 'redeemPeriod';
+						docs: [
+							'The period (in seconds) that a vault depositor must wait after requesting a withdrawal to finalize withdrawal.',
+							'Currently, the maximum is 90 days.'
+						];
+						type: 'i64';
+					},
+					{
+						name: 'totalWithdrawRequested';
+						docs: ['The sum of all outstanding withdraw requests'];
+						type: 'u64';
+					},
+					{
+						name: 'maxTokens';
+						docs: [
+							'Max token capacity, once hit/passed vault will reject new deposits (updatable)'
+						];
+						type: 'u64';
+					},
+					{
+						name: 'managementFee';
+						docs: [
+							'The annual fee charged on deposits by the manager.',
+							'Traditional funds typically charge 2% per year on assets under management.'
+						];
+						type: 'i64';
+					},
+					{
+						name: 'initTs';
+						docs: ['Timestamp vault initialized'];
+						type: 'i64';
+					},
+					{
+						name: 'netDeposits';
+						docs: ['The net deposits for the vault'];
+						type: 'i64';
+					},
+					{
+						name: 'managerNetDeposits';
+						docs: ['The net deposits for the manager'];
+						type: 'i64';
+					},
+					{
+						name: 'totalDeposits';
+						docs: ['Total deposits'];
+						type: 'u64';
+					},
+					{
+						name: 'totalWithdraws';
+						docs: ['Total withdraws'];
+						type: 'u64';
+					},
+					{
+						name: 'managerTotalDeposits';
+						docs: ['Total deposits for the manager'];
+						type: 'u64';
+					},
+					{
+						name: 'managerTotalWithdraws';
+						docs: ['Total withdraws for the manager'];
+						type: 'u64';
+					},
+					{
+						name: 'managerTotalFee';
+						docs: ['Total management fee accrued by the manager'];
+						type: 'i64';
+					},
+					{
+						name: 'managerTotalProfitShare';
+						docs: ['Total profit share accrued by the manager'];
+						type: 'u64';
+					},
+					{
+						name: 'minDepositAmount';
 						docs: ['The minimum deposit amount'];
 						type: 'u64';
 					},
-					{
-						name: 'lastManagerWithdrawRequest';
-						type: {
-							defined: 'WithdrawRequest';
-						};
+					{
+						name: 'lastManagerWithdrawRequest';
+						type: {
+							defined: 'WithdrawRequest';
+						};
+					},
+					{
+						name: 'sharesBase';
+						docs: [
+							'The base 10 exponent of the shares (given massive share inflation can occur at near zero vault equity)'
+						];
+						type: 'u32';
+					},
+					{
+						name: 'profitShare';
+						docs: [
+							'Percentage the manager charges on all profits realized by depositors: PERCENTAGE_PRECISION'
+						];
+						type: 'u32';
+					},
+					{
+						name: 'hurdleRate';
+						docs: [
+							'Vault manager only collect incentive fees during periods when returns are higher than this amount: PERCENTAGE_PRECISION'
+						];
+						type: 'u32';
+					},
+					{
+						name: 'spotMarketIndex';
+						docs: [
+							'The spot market index the vault deposits into/withdraws from'
+						];
+						type: 'u16';
+					},
+					{
+						name: 'bump';
+						docs: ['The bump for the vault pda'];
+						type: 'u8';
+					},
+					{
+						name: 'permissioned';
+						docs: ['Whether anybody can be a depositor'];
+						type: 'bool';
+					},
+					{
+						name: 'vaultProtocol';
+						docs: ['The optional [`VaultProtocol`] account.'];
+						type: 'bool';
+					},
+					{
+						name: 'fuelDistributionMode';
+						docs: [
+							'How fuel distribution should be treated [`FuelDistributionMode`]. Default is `UsersOnly`'
+						];
+						type: 'u8';
+					},
+					{
+						name: 'vaultHook';
+						docs: ['The optional [`VaultHook`] account.'];
+						type: 'bool';
+					},
+					{
+						name: 'windDown';
+						docs: [
+							'Set by a [`VaultPoll`](crate::state::VaultPoll) wind down, no new deposits are accepted'
+						];
+						type: 'bool';
+					},
+					{
+						name: 'lastCumulativeFuelPerShareTs';
+						docs: ['The timestamp cumulative_fuel_per_share was last updated'];
+						type: 'u32';
+					},
+					{
+						name: 'cumulativeFuelPerShare';
+						docs: [
+							'The cumulative fuel per share (scaled up by 1e6 to avoid losing precision)'
+						];
+						type: 'u128';
+					},
+					{
+						name: 'cumulativeFuel';
+						docs: ['The total fuel accumulated'];
+						type: 'u128';
+					},
+					{
+						name: 'fuelSeasonStartTs';
+						docs: [
+							'The [`FuelOverflow`] `last_reset_ts` of the fuel season the vault is currently accruing'
+						];
+						type: 'u32';
+					},
+					{
+						name: 'largeWithdrawThreshold';
+						docs: [
+							'Withdraw requests for more than this share of vault equity wait an extra notice period: PERCENTAGE_PRECISION.',
+							'0 disables the notice period'
+						];
+						type: 'u32';
+					},
+					{
+						name: 'largeWithdrawNoticePeriod';
+						docs: [
+							'The extra period (in seconds) on top of the redeem period for large withdraw requests'
+						];
+						type: 'u32';
+					},
+					{
+						name: 'depositorCount';
+						docs: [
+							'Number of open [`VaultDepositor`](crate::state::VaultDepositor)s of the vault. Depositors initialized',
+							'before the counter was added are counted by `backfill_vault_depositor_counts`'
+						];
+						type: 'u32';
+					},
+					{
+						name: 'activeWithdrawRequestCount';
+						docs: [
+							'Number of counted depositors with a pending withdraw request'
+						];
+						type: 'u32';
+					},
+					{
+						name: 'withdrawSettlement';
+						docs: [
+							'The optional [`VaultWithdrawSettlement`](crate::state::VaultWithdrawSettlement) account. Matured withdraw',
+							'requests are paid at the share price of the last settlement crank'
+						];
+						type: 'bool';
+					},
+					{
+						name: 'profile';
+						docs: [
+							'Restricts the positions the drift user may hold [`VaultProfile`](crate::state::VaultProfile).',
+							'Default is `Unrestricted`'
+						];
+						type: 'u8';
+					},
+					{
+						name: 'disabledFeatures';
+						docs: [
+							'Bits of the optional [`VaultFeature`](crate::state::VaultFeature)s switched off for this vault. Default is 0,',
+							'everything enabled'
+						];
+						type: 'u8';
+					},
+					{
+						name: 'flags';
+						docs: ['Bits of [`VaultFlag`]'];
+						type: 'u8';
+					}
+				];
+			};
+		},
+		{
+			name: 'withdrawReceipt';
+			docs: [
+				"Commits a vault depositor's withdraw request to a cancellation deadline, after which the request can't be",
+				'cancelled, so the manager can start unwinding positions for a large exit.',
+				'',
+				"The receipt only applies to the request it was set for, matched by the request's `ts` and `shares`. Once any",
+				'depositor of a vault has a receipt the vault has [`VaultFlag::WithdrawReceipts`](crate::state::VaultFlag) set',
+				"and cancels must pass the depositor's receipt address in remaining accounts, initialized or not."
+			];
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'vaultDepositor';
+						docs: ['The vault depositor the receipt is for'];
+						type: 'publicKey';
+					},
+					{
+						name: 'requestShares';
+						docs: ['`shares` of the withdraw request the deadline applies to'];
+						type: 'u128';
+					},
+					{
+						name: 'requestTs';
+						docs: ['`ts` of the withdraw request the deadline applies to'];
+						type: 'i64';
+					},
+					{
+						name: 'cancelDeadlineTs';
+						docs: ["The request can't be cancelled from this ts on"];
+						type: 'i64';
+					},
+					{
+						name: 'bump';
+						type: 'u8';
+					},
+					{
+						name: 'padding';
+						type: {
+							array: ['u8', 7];
+						};
+					}
+				];
+			};
+		}
+	];
+	types: [
+		{
+			name: 'InitializeTokenizedVaultDepositorParams';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'tokenName';
+						type: 'string';
+					},
+					{
+						name: 'tokenSymbol';
+						type: 'string';
+					},
+					{
+						name: 'tokenUri';
+						type: 'string';
+					},
+					{
+						name: 'decimals';
+						type: 'u8';
+					}
+				];
+			};
+		},
+		{
+			name: 'VaultFromTemplateParams';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'name';
+						type: {
+							array: ['u8', 32];
+						};
+					},
+					{
+						name: 'template';
+						docs: [
+							'[`VaultTemplate`] id, sets the redeem period, fee bounds, leverage cap and allowed spot markets'
+						];
+						type: 'u8';
+					},
+					{
+						name: 'maxTokens';
+						type: 'u64';
+					},
+					{
+						name: 'managementFee';
+						type: 'i64';
+					},
+					{
+						name: 'minDepositAmount';
+						type: 'u64';
+					},
+					{
+						name: 'profitShare';
+						type: 'u32';
+					},
+					{
+						name: 'spotMarketIndex';
+						type: 'u16';
+					},
+					{
+						name: 'permissioned';
+						type: 'bool';
+					}
+				];
+			};
+		},
+		{
+			name: 'VaultWithProtocolParams';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'name';
+						type: {
+							array: ['u8', 32];
+						};
+					},
+					{
+						name: 'redeemPeriod';
+						type: 'i64';
+					},
+					{
+						name: 'maxTokens';
+						type: 'u64';
+					},
+					{
+						name: 'managementFee';
+						type: 'i64';
+					},
+					{
+						name: 'minDepositAmount';
+						type: 'u64';
+					},
+					{
+						name: 'profitShare';
+						type: 'u32';
+					},
+					{
+						name: 'hurdleRate';
+						type: 'u32';
+					},
+					{
+						name: 'spotMarketIndex';
+						type: 'u16';
+					},
+					{
+						name: 'permissioned';
+						type: 'bool';
+					},
+					{
+						name: 'vaultProtocol';
+						type: {
+							defined: 'VaultProtocolParams';
+						};
+					}
+				];
+			};
+		},
+		{
+			name: 'VaultProtocolParams';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'protocol';
+						type: 'publicKey';
+					},
+					{
+						name: 'protocolFee';
+						type: 'u64';
+					},
+					{
+						name: 'protocolProfitShare';
+						type: 'u32';
+					}
+				];
+			};
+		},
+		{
+			name: 'VaultParams';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'name';
+						type: {
+							array: ['u8', 32];
+						};
+					},
+					{
+						name: 'redeemPeriod';
+						type: 'i64';
+					},
+					{
+						name: 'maxTokens';
+						type: 'u64';
+					},
+					{
+						name: 'managementFee';
+						type: 'i64';
+					},
+					{
+						name: 'minDepositAmount';
+						type: 'u64';
+					},
+					{
+						name: 'profitShare';
+						type: 'u32';
+					},
+					{
+						name: 'hurdleRate';
+						type: 'u32';
+					},
+					{
+						name: 'spotMarketIndex';
+						type: 'u16';
+					},
+					{
+						name: 'permissioned';
+						type: 'bool';
+					}
+				];
+			};
+		},
+		{
+			name: 'SimulateWithdrawStatus';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'statusCode';
+						docs: [
+							'[`STATUS_OK`] if the simulation ran, otherwise the status code of the error that stopped it'
+						];
+						type: 'u32';
+					},
+					{
+						name: 'withdrawBlocker';
+						docs: ['Only meaningful when `status_code` is [`STATUS_OK`]'];
+						type: {
+							defined: 'WithdrawBlocker';
+						};
+					}
+				];
+			};
+		},
+		{
+			name: 'UpdateVaultProtocolParams';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'protocolFee';
+						type: {
+							option: 'u64';
+						};
+					},
+					{
+						name: 'protocolProfitShare';
+						type: {
+							option: 'u32';
+						};
+					}
+				];
+			};
+		},
+		{
+			name: 'UpdateVaultParams';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'redeemPeriod';
+						type: {
+							option: 'i64';
+						};
+					},
+					{
+						name: 'maxTokens';
+						type: {
+							option: 'u64';
+						};
+					},
+					{
+						name: 'managementFee';
+						type: {
+							option: 'i64';
+						};
+					},
+					{
+						name: 'minDepositAmount';
+						type: {
+							option: 'u64';
+						};
+					},
+					{
+						name: 'profitShare';
+						type: {
+							option: 'u32';
+						};
+					},
+					{
+						name: 'hurdleRate';
+						type: {
+							option: 'u32';
+						};
+					},
+					{
+						name: 'permissioned';
+						type: {
+							option: 'bool';
+						};
+					}
+				];
+			};
+		},
+		{
+			name: 'ComputeUnitsHint';
+			docs: [
+				'Written to return data by heavy instructions so clients simulating a transaction can set its compute budget',
+				'from the markets the vault actually loaded instead of over-reserving.'
+			];
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'perpMarkets';
+						type: 'u8';
+					},
+					{
+						name: 'spotMarkets';
+						type: 'u8';
+					},
+					{
+						name: 'oracles';
+						type: 'u8';
+					},
+					{
+						name: 'computeUnits';
+						docs: ['estimated compute units of the instruction'];
+						type: 'u32';
+					}
+				];
+			};
+		},
+		{
+			name: 'FlowPreview';
+			docs: [
+				'Projected result of a deposit or withdraw, written to return data by',
+				'[`preview_deposit`](crate::instructions::preview_deposit) and',
+				'[`preview_withdraw`](crate::instructions::preview_withdraw).'
+			];
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'amount';
+						docs: [
+							"tokens deposited or paid out, deposits are capped by the vault's `max_tokens`"
+						];
+						type: 'u64';
+					},
+					{
+						name: 'vaultSharesBefore';
+						type: 'u128';
+					},
+					{
+						name: 'vaultSharesAfter';
+						type: 'u128';
+					},
+					{
+						name: 'profitShare';
+						docs: ['profit share charged to the depositor'];
+						type: 'u64';
+					},
+					{
+						name: 'managementFee';
+						docs: [
+							'management fee the vault charged since its last fee update'
+						];
+						type: 'i64';
+					},
+					{
+						name: 'fuelAmount';
+						docs: ["the depositor's fuel after the flow"];
+						type: 'u128';
+					},
+					{
+						name: 'computeUnitsHint';
+						type: {
+							defined: 'ComputeUnitsHint';
+						};
+					}
+				];
+			};
+		},
+		{
+			name: 'OmnibusLedgerEntry';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'beneficiary';
+						type: 'publicKey';
+					},
+					{
+						name: 'shares';
+						type: 'u128';
+					}
+				];
+			};
+		},
+		{
+			name: 'ProtocolTvlCapVault';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'vault';
+						type: 'publicKey';
+					},
+					{
+						name: 'tvl';
+						docs: [
+							'Vault equity as of its last deposit. precision: QUOTE_PRECISION'
+						];
+						type: 'u64';
+					}
+				];
+			};
+		},
+		{
+			name: 'ProtocolFeeTier';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'tvlThreshold';
+						docs: [
+							"Vault equity (in vault spot_market_index) at or above which this tier's fee applies"
+						];
+						type: 'u64';
+					},
+					{
+						name: 'protocolFee';
+						docs: [
+							'The annual protocol fee charged while this tier applies: PERCENTAGE_PRECISION'
+						];
+						type: 'u64';
+					}
+				];
+			};
+		},
+		{
+			name: 'VaultDepositorRecordLogEntry';
+			docs: [
+				'The parts of a [`VaultDepositorRecord`](crate::state::events::VaultDepositorRecord) an indexer needs to rebuild a',
+				"depositor's position. Share amounts after are in the vault's shares base at `ts`, `vault_shares_before` is in the",
+				"depositor's base before the action and lags it if the action applied a",
+				'[rebase](crate::state::events::VaultRebaseRecord).'
+			];
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'depositorAuthority';
+						type: 'publicKey';
+					},
+					{
+						name: 'vaultSharesBefore';
+						type: 'u128';
+					},
+					{
+						name: 'vaultSharesAfter';
+						type: 'u128';
+					},
+					{
+						name: 'totalVaultSharesAfter';
+						type: 'u128';
+					},
+					{
+						name: 'ts';
+						type: 'i64';
+					},
+					{
+						name: 'amount';
+						docs: [
+							'deposited, requested, withdrawn or profit share paid, precision: token mint'
+						];
+						type: 'u64';
+					},
+					{
+						name: 'vaultEquityBefore';
+						type: 'u64';
+					},
+					{
+						name: 'action';
+						docs: ['[`VaultDepositorAction`]'];
+						type: 'u8';
+					},
+					{
+						name: 'padding';
+						type: {
+							array: ['u8', 7];
+						};
+					}
+				];
+			};
+		},
+		{
+			name: 'VaultParamChange';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'signer';
+						docs: ['Who signed the change'];
+						type: 'publicKey';
+					},
+					{
+						name: 'oldValue';
+						docs: ['See [`ParamValue`]'];
+						type: {
+							array: ['u8', 32];
+						};
+					},
+					{
+						name: 'newValue';
+						docs: ['See [`ParamValue`]'];
+						type: {
+							array: ['u8', 32];
+						};
+					},
+					{
+						name: 'ts';
+						type: 'i64';
+					},
+					{
+						name: 'param';
+						docs: ['[`VaultParam`]'];
+						type: 'u8';
+					},
+					{
+						name: 'padding';
+						type: {
+							array: ['u8', 7];
+						};
+					}
+				];
+			};
+		},
+		{
+			name: 'ProfitShareCheckpoint';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'ts';
+						type: 'i64';
+					},
+					{
+						name: 'managerTotalProfitShare';
+						docs: ['`Vault::manager_total_profit_share` at ts'];
+						type: 'u64';
+					}
+				];
+			};
+		},
+		{
+			name: 'RedeemPeriodBucket';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'maxWithdrawShare';
+						docs: [
+							'Requests for at most this share of vault equity use this bucket: PERCENTAGE_PRECISION'
+						];
+						type: 'u32';
+					},
+					{
+						name: 'redeemPeriod';
+						docs: [
+							"The redeem period (in seconds) of requests in this bucket, capped at the vault's `redeem_period`"
+						];
+						type: 'u32';
+					}
+				];
+			};
+		},
+		{
+			name: 'VaultRegistryEntry';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'vault';
+						type: 'publicKey';
+					},
+					{
+						name: 'mint';
+						docs: ["The mint of the vault's spot market"];
+						type: 'publicKey';
+					},
+					{
+						name: 'manager';
+						type: 'publicKey';
+					},
+					{
+						name: 'protocol';
+						docs: [
+							"The vault's `VaultProtocol::protocol`, Pubkey::default() if it has none"
+						];
+						type: 'publicKey';
+					}
+				];
+			};
+		},
+		{
+			name: 'WithdrawBlackoutWindow';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'startTs';
+						type: 'i64';
+					},
+					{
+						name: 'endTs';
+						docs: ['0 if the slot was never used'];
+						type: 'i64';
+					}
+				];
+			};
+		},
+		{
+			name: 'WithdrawRequest';
+			type: {
+				kind: 'struct';
+				fields: [
+					{
+						name: 'shares';
+						docs: ['request shares of vault withdraw'];
+						type: 'u128';
+					},
+					{
+						name: 'value';
+						docs: [
+							'requested value (in vault spot_market_index) of shares for withdraw'
+						];
+						type: 'u64';
+					},
+					{
+						name: 'ts';
+						docs: [
+							"request ts of vault withdraw. For depositor requests over the vault's large withdraw threshold this is",
+							'pushed forward by the large withdraw notice period, so the request matures at ts + redeem_period'
+						];
+						type: 'i64';
+					}
+				];
+			};
+		},
+		{
+			name: 'DepositorAction';
+			type: {
+				kind: 'enum';
+				variants: [
+					{
+						name: 'Deposit';
+						fields: [
+							{
+								name: 'amount';
+								type: 'u64';
+							}
+						];
+					},
+					{
+						name: 'Request';
+						fields: [
+							{
+								name: 'withdrawAmount';
+								type: 'u64';
+							},
+							{
+								name: 'withdrawUnit';
+								type: {
+									defined: 'WithdrawUnit';
+								};
+							}
+						];
+					},
+					{
+						name: 'Cancel';
+					},
+					{
+						name: 'Withdraw';
+					},
+					{
+						name: 'Fuel';
+					}
+				];
+			};
+		},
+		{
+			name: 'VaultDepositorAction';
+			type: {
+				kind: 'enum';
+				variants: [
+					{
+						name: 'Deposit';
+					},
+					{
+						name: 'WithdrawRequest';
+					},
+					{
+						name: 'CancelWithdrawRequest';
+					},
+					{
+						name: 'Withdraw';
+					},
+					{
+						name: 'FeePayment';
+					},
+					{
+						name: 'TokenizeShares';
+					},
+					{
+						name: 'RedeemTokens';
+					}
+				];
+			};
+		},
+		{
+			name: 'LiquidationAction';
+			type: {
+				kind: 'enum';
+				variants: [
+					{
+						name: 'Start';
+					},
+					{
+						name: 'Unwind';
+					}
+				];
+			};
+		},
+		{
+			name: 'WithdrawPayoutDivergence';
+			type: {
+				kind: 'enum';
+				variants: [
+					{
+						name: 'None';
+					},
+					{
+						name: 'Losses';
+					},
+					{
+						name: 'GainsCapped';
+					}
+				];
+			};
+		},
+		{
+			name: 'FeePolicy';
+			docs: [
+				'How the profit share is charged on gains above a high water mark, the one place fee models differ. The',
+				"management fee is charged the same under every policy. Stored in the vault's",
+				'[`VaultConfig`](crate::state::VaultConfig), see [`VaultConfig::fee_policy`](crate::state::VaultConfig::fee_policy).'
+			];
+			type: {
+				kind: 'enum';
+				variants: [
+					{
+						name: 'HighWaterMark';
+					},
+					{
+						name: 'Hurdle';
+					},
+					{
+						name: 'Benchmark';
+					},
+					{
+						name: 'Flat';
+					}
+				];
+			};
+		},
+		{
+			name: 'ProtocolFeeCollectStep';
+			type: {
+				kind: 'enum';
+				variants: [
+					{
+						name: 'Request';
+					},
+					{
+						name: 'Withdraw';
+					},
+					{
+						name: 'Wait';
+					},
+					{
+						name: 'Skip';
+					}
+				];
+			};
+		},
+		{
+			name: 'BackstopAction';
+			type: {
+				kind: 'enum';
+				variants: [
+					{
+						name: 'Contribute';
+					},
+					{
+						name: 'Claim';
+					}
+				];
+			};
+		},
+		{
+			name: 'DepositValuation';
+			docs: [
+				'The equity deposits are priced at, see [`Vault::calculate_deposit_equity`]. Withdraws always use full marks.'
+			];
+			type: {
+				kind: 'enum';
+				variants: [
+					{
+						name: 'FullMark';
+					},
+					{
+						name: 'HaircutLosses';
+					}
+				];
+			};
+		},
+		{
+			name: 'VaultConfigFlag';
+			docs: ['Bits of [`VaultConfig`] `flags`'];
+			type: {
+				kind: 'enum';
+				variants: [
+					{
+						name: 'DepositorThrottle';
+					},
+					{
+						name: 'VaultGuard';
+					}
+				];
+			};
+		},
+		{
+			name: 'ProfitShareRealization';
+			docs: ["When depositors' gains are profit shared"];
+			type: {
+				kind: 'enum';
+				variants: [
+					{
+						name: 'Unrealized';
+					},
+					{
+						name: 'Realized';
+					}
+				];
+			};
+		},
+		{
+			name: 'WithdrawBlocker';
+			docs: [
+				"Why a depositor's pending withdraw request can't be paid out of the drift user, see",
+				'[`VaultDepositor::simulate_withdraw`].'
+			];
+			type: {
+				kind: 'enum';
+				variants: [
+					{
+						name: 'None';
+					},
+					{
+						name: 'InsufficientCollateral';
+					},
+					{
+						name: 'MarginTradingDisabled';
+					},
+					{
+						name: 'ReduceOnly';
+					},
+					{
+						name: 'MarketPaused';
+					}
+				];
+			};
+		},
+		{
+			name: 'VaultDepositorFlag';
+			docs: ['Bits of [`VaultDepositor`] `flags`'];
+			type: {
+				kind: 'enum';
+				variants: [
+					{
+						name: 'QueuedWithdraw';
+					},
+					{
+						name: 'AutoRenewWithdraw';
+					},
+					{
+						name: 'Counted';
+					},
+					{
+						name: 'Blacklisted';
+					},
+					{
+						name: 'Omnibus';
+					},
+					{
+						name: 'ParkedWithdrawRequests';
+					}
+				];
+			};
+		},
+		{
+			name: 'VaultFeature';
+			docs: [
+				'Optional vault code paths the manager can switch off, stored as bits of [`Vault`](crate::state::Vault)',
+				'`disabled_features`. All features are enabled by default. Exits from a feature already in use (e.g.',
+				'`redeem_tokens`, `claim_withdraw_stream`) are never disabled.'
+			];
+			type: {
+				kind: 'enum';
+				variants: [
+					{
+						name: 'Tokenization';
+					},
+					{
+						name: 'Hooks';
+					},
+					{
+						name: 'DustWithdraw';
+					},
+					{
+						name: 'WithdrawStream';
+					},
+					{
+						name: 'Interface';
+					},
+					{
+						name: 'Swaps';
+					},
+					{
+						name: 'Transfers';
+					}
+				];
+			};
+		},
+		{
+			name: 'FeeHolidayAction';
+			type: {
+				kind: 'enum';
+				variants: [
+					{
+						name: 'Schedule';
+					},
+					{
+						name: 'Cancel';
+					},
+					{
+						name: 'Start';
+					},
+					{
+						name: 'End';
+					}
+				];
+			};
+		},
+		{
+			name: 'GuardFlag';
+			docs: [
+				'Bits of [`VaultGuard`] `flags`, companion accounts every depositor flow must pass once the manager creates them'
+			];
+			type: {
+				kind: 'enum';
+				variants: [
+					{
+						name: 'WithdrawBlackout';
+					},
+					{
+						name: 'Lockup';
+					},
+					{
+						name: 'ProfitShareCadence';
+					}
+				];
+			};
+		},
+		{
+			name: 'VaultHookAction';
+			type: {
+				kind: 'enum';
+				variants: [
+					{
+						name: 'PreDeposit';
+					},
+					{
+						name: 'PostDeposit';
+					},
+					{
+						name: 'PreWithdraw';
+					},
+					{
+						name: 'PostWithdraw';
+					}
+				];
+			};
+		},
+		{
+			name: 'VaultParam';
+			type: {
+				kind: 'enum';
+				variants: [
+					{
+						name: 'RedeemPeriod';
+					},
+					{
+						name: 'MaxTokens';
+					},
+					{
+						name: 'ManagementFee';
+					},
+					{
+						name: 'MinDepositAmount';
+					},
+					{
+						name: 'ProfitShare';
+					},
+					{
+						name: 'HurdleRate';
+					},
+					{
+						name: 'Permissioned';
+					},
+					{
+						name: 'Manager';
+					},
+					{
+						name: 'Delegate';
+					},
+					{
+						name: 'FuelDistributionMode';
+					},
+					{
+						name: 'LargeWithdrawThreshold';
+					},
+					{
+						name: 'LargeWithdrawNoticePeriod';
+					},
+					{
+						name: 'Profile';
+					},
+					{
+						name: 'DisabledFeatures';
+					},
+					{
+						name: 'MarginTradingEnabled';
+					},
+					{
+						name: 'PoolId';
+					},
+					{
+						name: 'ProfitShareMode';
+					},
+					{
+						name: 'RequireSettleCrank';
+					},
+					{
+						name: 'FuelBoost';
+					},
+					{
+						name: 'FeePolicy';
+					},
+					{
+						name: 'DepositValuation';
+					},
+					{
+						name: 'ProfitShareRealization';
+					}
+				];
+			};
+		},
+		{
+			name: 'VaultPollProposal';
+			type: {
+				kind: 'enum';
+				variants: [
+					{
+						name: 'ReplaceManager';
+					},
+					{
+						name: 'WindDown';
+					}
+				];
+			};
+		},
+		{
+			name: 'VaultProfile';
+			docs: [
+				'Positions a vault promises its depositors the drift user will hold. Ordered from least to most strict, a vault',
+				'can only move to a stricter profile.'
+			];
+			type: {
+				kind: 'enum';
+				variants: [
+					{
+						name: 'Unrestricted';
+					},
+					{
+						name: 'SpotAndPrediction';
+					},
+					{
+						name: 'SpotOnly';
+					},
+					{
+						name: 'YieldOnly';
+					}
+				];
+			};
+		},
+		{
+			name: 'VaultProtocolFlag';
+			docs: ['Bits of [`VaultProtocol`] `flags`'];
+			type: {
+				kind: 'enum';
+				variants: [
+					{
+						name: 'DelegateDualApproval';
+					},
+					{
+						name: 'TvlCap';
+					},
+					{
+						name: 'ProtocolFeeWaived';
+					}
+				];
+			};
+		},
+		{
+			name: 'RiskBand';
+			type: {
+				kind: 'enum';
+				variants: [
+					{
+						name: 'Low';
+					},
+					{
+						name: 'Medium';
+					},
+					{
+						name: 'High';
+					},
+					{
+						name: 'Extreme';
+					}
+				];
+			};
+		},
+		{
+			name: 'VaultTemplate';
+			docs: [
+				'Protocol-curated vault presets for [`initialize_vault_from_template`](crate::instructions::initialize_vault_from_template).'
+			];
+			type: {
+				kind: 'enum';
+				variants: [
+					{
+						name: 'Conservative';
+					},
+					{
+						name: 'MarketMaking';
+					},
+					{
+						name: 'Leveraged';
+					}
+				];
+			};
+		},
+		{
+			name: 'VaultFlag';
+			docs: ['Bits of [`Vault`] `flags`'];
+			type: {
+				kind: 'enum';
+				variants: [
+					{
+						name: 'Registered';
+					},
+					{
+						name: 'HasParamChangeLog';
+					},
+					{
+						name: 'HasDepositorCap';
+					},
+					{
+						name: 'ManagementFeeHoliday';
+					},
+					{
+						name: 'ProfitShareHoliday';
+					},
+					{
+						name: 'HasVaultConfig';
+					},
+					{
+						name: 'WithdrawReceipts';
+					},
+					{
+						name: 'RequireSettleCrank';
+					}
+				];
+			};
+		},
+		{
+			name: 'FuelDistributionMode';
+			type: {
+				kind: 'enum';
+				variants: [
+					{
+						name: 'UsersOnly';
+					},
+					{
+						name: 'UsersAndManager';
+					}
+				];
+			};
+		},
+		{
+			name: 'ProfitShareMode';
+			type: {
+				kind: 'enum';
+				variants: [
+					{
+						name: 'HighWaterMark';
+					},
+					{
+						name: 'LossesFirst';
+					},
+					{
+						name: 'PeakEquity';
+					}
+				];
+			};
+		},
+		{
+			name: 'WithdrawUnit';
+			type: {
+				kind: 'enum';
+				variants: [
+					{
+						name: 'Shares';
+					},
+					{
+						name: 'Token';
+					},
+					{
+						name: 'SharesPercent';
+					}
+				];
+			};
+		}
+	];
+	events: [
+		{
+			name: 'VaultRecord';
+			fields: [
+				{
+					name: 'ts';
+					type: 'i64';
+					index: false;
+				},
+				{
+					name: 'spotMarketIndex';
+					type: 'u16';
+					index: false;
+				},
+				{
+					name: 'vaultEquityBefore';
+					type: 'u64';
+					index: false;
+				}
+			];
+		},
+		{
+			name: 'VaultDepositorRecord';
+			fields: [
+				{
+					name: 'ts';
+					type: 'i64';
+					index: false;
+				},
+				{
+					name: 'vault';
+					type: 'publicKey';
+					index: false;
+				},
+				{
+					name: 'depositorAuthority';
+					type: 'publicKey';
+					index: false;
+				},
+				{
+					name: 'action';
+					type: {
+						defined: 'VaultDepositorAction';
+					};
+					index: false;
+				},
+				{
+					name: 'amount';
+					type: 'u64';
+					index: false;
+				},
+				{
+					name: 'spotMarketIndex';
+					type: 'u16';
+					index: false;
+				},
+				{
+					name: 'vaultSharesBefore';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'vaultSharesAfter';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'vaultEquityBefore';
+					type: 'u64';
+					index: false;
+				},
+				{
+					name: 'userVaultSharesBefore';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'totalVaultSharesBefore';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'userVaultSharesAfter';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'totalVaultSharesAfter';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'profitShare';
+					type: 'u64';
+					index: false;
+				},
+				{
+					name: 'managementFee';
+					type: 'i64';
+					index: false;
+				},
+				{
+					name: 'managementFeeShares';
+					type: 'i64';
+					index: false;
+				},
+				{
+					name: 'depositOraclePrice';
+					type: 'i64';
+					index: false;
+				}
+			];
+		},
+		{
+			name: 'VaultDepositorV1Record';
+			fields: [
+				{
+					name: 'ts';
+					type: 'i64';
+					index: false;
+				},
+				{
+					name: 'vault';
+					type: 'publicKey';
+					index: false;
+				},
+				{
+					name: 'depositorAuthority';
+					type: 'publicKey';
+					index: false;
+				},
+				{
+					name: 'action';
+					type: {
+						defined: 'VaultDepositorAction';
+					};
+					index: false;
+				},
+				{
+					name: 'amount';
+					type: 'u64';
+					index: false;
+				},
+				{
+					name: 'spotMarketIndex';
+					type: 'u16';
+					index: false;
+				},
+				{
+					name: 'vaultSharesBefore';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'vaultSharesAfter';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'vaultEquityBefore';
+					type: 'u64';
+					index: false;
+				},
+				{
+					name: 'userVaultSharesBefore';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'totalVaultSharesBefore';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'userVaultSharesAfter';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'totalVaultSharesAfter';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'protocolSharesBefore';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'protocolSharesAfter';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'protocolProfitShare';
+					type: 'u64';
+					index: false;
+				},
+				{
+					name: 'protocolFee';
+					type: 'i64';
+					index: false;
+				},
+				{
+					name: 'protocolFeeShares';
+					type: 'i64';
+					index: false;
+				},
+				{
+					name: 'managerProfitShare';
+					type: 'u64';
+					index: false;
+				},
+				{
+					name: 'managementFee';
+					type: 'i64';
+					index: false;
+				},
+				{
+					name: 'managementFeeShares';
+					type: 'i64';
+					index: false;
+				},
+				{
+					name: 'depositOraclePrice';
+					type: 'i64';
+					index: false;
+				}
+			];
+		},
+		{
+			name: 'VaultDepositorLotRecord';
+			fields: [
+				{
+					name: 'ts';
+					type: 'i64';
+					index: false;
+				},
+				{
+					name: 'vault';
+					type: 'publicKey';
+					index: false;
+				},
+				{
+					name: 'depositorAuthority';
+					type: 'publicKey';
+					index: false;
+				},
+				{
+					name: 'spotMarketIndex';
+					type: 'u16';
+					index: false;
+				},
+				{
+					name: 'shares';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'costBasis';
+					type: 'u64';
+					index: false;
+				},
+				{
+					name: 'proceeds';
+					type: 'u64';
+					index: false;
+				},
+				{
+					name: 'entrySharePrice';
+					type: 'u64';
+					index: false;
+				},
+				{
+					name: 'exitSharePrice';
+					type: 'u64';
+					index: false;
+				},
+				{
+					name: 'holdingPeriod';
+					type: 'i64';
+					index: false;
+				},
+				{
+					name: 'profitShareFeePaid';
+					type: 'u64';
+					index: false;
+				}
+			];
+		},
+		{
+			name: 'InterfaceRecord';
+			fields: [
+				{
+					name: 'ts';
+					type: 'i64';
+					index: false;
+				},
+				{
+					name: 'vault';
+					type: 'publicKey';
+					index: false;
+				},
+				{
+					name: 'depositorAuthority';
+					type: 'publicKey';
+					index: false;
+				},
+				{
+					name: 'interface';
+					type: 'publicKey';
+					index: false;
+				},
+				{
+					name: 'action';
+					type: {
+						defined: 'VaultDepositorAction';
+					};
+					index: false;
+				},
+				{
+					name: 'amount';
+					type: 'u64';
+					index: false;
+				},
+				{
+					name: 'spotMarketIndex';
+					type: 'u16';
+					index: false;
+				},
+				{
+					name: 'interfaceFee';
+					type: 'u64';
+					index: false;
+				}
+			];
+		},
+		{
+			name: 'VaultDepositorMemoRecord';
+			fields: [
+				{
+					name: 'ts';
+					type: 'i64';
+					index: false;
+				},
+				{
+					name: 'vault';
+					type: 'publicKey';
+					index: false;
+				},
+				{
+					name: 'vaultDepositor';
+					type: 'publicKey';
+					index: false;
+				},
+				{
+					name: 'depositorAuthority';
+					type: 'publicKey';
+					index: false;
+				},
+				{
+					name: 'memoBefore';
+					type: {
+						array: ['u8', 32];
+					};
+					index: false;
+				},
+				{
+					name: 'memoAfter';
+					type: {
+						array: ['u8', 32];
+					};
+					index: false;
+				}
+			];
+		},
+		{
+			name: 'VaultHealthRecord';
+			fields: [
+				{
+					name: 'ts';
+					type: 'i64';
+					index: false;
+				},
+				{
+					name: 'vault';
+					type: 'publicKey';
+					index: false;
+				},
+				{
+					name: 'withdrawAmount';
+					type: 'u64';
+					index: false;
+				},
+				{
+					name: 'totalCollateral';
+					type: 'i128';
+					index: false;
+				},
+				{
+					name: 'maintenanceMarginRequirement';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'freeCollateral';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'marginRatio';
+					type: 'u64';
+					index: false;
+				},
+				{
+					name: 'depositorCount';
+					type: 'u32';
+					index: false;
+				},
+				{
+					name: 'activeWithdrawRequestCount';
+					type: 'u32';
+					index: false;
+				},
+				{
+					name: 'lastManagerActivityTs';
+					type: 'i64';
+					index: false;
+				}
+			];
+		},
+		{
+			name: 'VaultParamChangeRecord';
+			fields: [
+				{
+					name: 'ts';
+					type: 'i64';
+					index: false;
+				},
+				{
+					name: 'vault';
+					type: 'publicKey';
+					index: false;
+				},
+				{
+					name: 'signer';
+					type: 'publicKey';
+					index: false;
+				},
+				{
+					name: 'param';
+					type: {
+						defined: 'crate::state::VaultParam';
+					};
+					index: false;
+				},
+				{
+					name: 'oldValue';
+					type: {
+						array: ['u8', 32];
+					};
+					index: false;
+				},
+				{
+					name: 'newValue';
+					type: {
+						array: ['u8', 32];
+					};
+					index: false;
+				}
+			];
+		},
+		{
+			name: 'FeeHolidayRecord';
+			fields: [
+				{
+					name: 'ts';
+					type: 'i64';
+					index: false;
+				},
+				{
+					name: 'vault';
+					type: 'publicKey';
+					index: false;
+				},
+				{
+					name: 'action';
+					type: {
+						defined: 'crate::state::FeeHolidayAction';
+					};
+					index: false;
+				},
+				{
+					name: 'startTs';
+					type: 'i64';
+					index: false;
+				},
+				{
+					name: 'endTs';
+					type: 'i64';
+					index: false;
+				},
+				{
+					name: 'suspendManagementFee';
+					type: 'bool';
+					index: false;
+				},
+				{
+					name: 'suspendProfitShare';
+					type: 'bool';
+					index: false;
+				}
+			];
+		},
+		{
+			name: 'FeeSplitRecord';
+			fields: [
+				{
+					name: 'ts';
+					type: 'i64';
+					index: false;
+				},
+				{
+					name: 'vault';
+					type: 'publicKey';
+					index: false;
+				},
+				{
+					name: 'recipient';
+					type: 'publicKey';
+					index: false;
+				},
+				{
+					name: 'amount';
+					type: 'u64';
+					index: false;
+				},
+				{
+					name: 'shares';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'owed';
+					type: 'u64';
+					index: false;
+				},
+				{
+					name: 'totalPaid';
+					type: 'u64';
+					index: false;
+				}
+			];
+		},
+		{
+			name: 'VaultReportRecord';
+			fields: [
+				{
+					name: 'ts';
+					type: 'i64';
+					index: false;
+				},
+				{
+					name: 'vault';
+					type: 'publicKey';
+					index: false;
+				},
+				{
+					name: 'epoch';
+					type: 'u32';
+					index: false;
+				},
+				{
+					name: 'reportHash';
+					type: {
+						array: ['u8', 32];
+					};
+					index: false;
+				}
+			];
+		},
+		{
+			name: 'VaultShareStatementRecord';
+			fields: [
+				{
+					name: 'ts';
+					type: 'i64';
+					index: false;
+				},
+				{
+					name: 'vault';
+					type: 'publicKey';
+					index: false;
+				},
+				{
+					name: 'epoch';
+					type: 'u32';
+					index: false;
+				},
+				{
+					name: 'root';
+					type: {
+						array: ['u8', 32];
+					};
+					index: false;
+				},
+				{
+					name: 'numDepositors';
+					type: 'u32';
+					index: false;
+				},
+				{
+					name: 'totalShares';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'sharesBase';
+					type: 'u32';
+					index: false;
+				}
+			];
+		},
+		{
+			name: 'VaultDepositorBatchRecord';
+			fields: [
+				{
+					name: 'ts';
+					type: 'i64';
+					index: false;
+				},
+				{
+					name: 'vault';
+					type: 'publicKey';
+					index: false;
+				},
+				{
+					name: 'action';
+					type: {
+						defined: 'VaultDepositorAction';
+					};
+					index: false;
+				},
+				{
+					name: 'depositorCount';
+					type: 'u32';
+					index: false;
+				},
+				{
+					name: 'vaultEquityBefore';
+					type: 'u64';
+					index: false;
+				},
+				{
+					name: 'vaultSharesBefore';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'vaultSharesAfter';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'totalVaultSharesAfter';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'managerProfitShare';
+					type: 'u64';
+					index: false;
+				},
+				{
+					name: 'protocolProfitShare';
+					type: 'u64';
+					index: false;
+				},
+				{
+					name: 'deltasRoot';
+					type: {
+						array: ['u8', 32];
+					};
+					index: false;
+				}
+			];
+		},
+		{
+			name: 'VaultRebaseRecord';
+			fields: [
+				{
+					name: 'ts';
+					type: 'i64';
+					index: false;
+				},
+				{
+					name: 'vault';
+					type: 'publicKey';
+					index: false;
+				},
+				{
+					name: 'vaultEquity';
+					type: 'u64';
+					index: false;
+				},
+				{
+					name: 'sharesBaseBefore';
+					type: 'u32';
+					index: false;
+				},
+				{
+					name: 'sharesBaseAfter';
+					type: 'u32';
+					index: false;
+				},
+				{
+					name: 'rebaseDivisor';
+					type: 'u128';
+					index: false;
+				}
+			];
+		},
+		{
+			name: 'ShareTransferRecord';
+			fields: [
+				{
+					name: 'ts';
+					type: 'i64';
+					index: false;
+				},
+				{
+					name: 'vault';
+					type: 'publicKey';
+					index: false;
+				},
+				{
+					name: 'fromVaultDepositor';
+					type: 'publicKey';
+					index: false;
+				},
+				{
+					name: 'toVaultDepositor';
+					type: 'publicKey';
+					index: false;
+				},
+				{
+					name: 'shares';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'value';
+					type: 'u64';
+					index: false;
+				},
+				{
+					name: 'fromDepositorSharesBefore';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'fromDepositorSharesAfter';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'toDepositorSharesBefore';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'toDepositorSharesAfter';
+					type: 'u128';
+					index: false;
+				}
+			];
+		},
+		{
+			name: 'VaultPerformanceRecord';
+			fields: [
+				{
+					name: 'ts';
+					type: 'i64';
+					index: false;
+				},
+				{
+					name: 'vault';
+					type: 'publicKey';
+					index: false;
+				},
+				{
+					name: 'periodStartTs';
+					type: 'i64';
+					index: false;
+				},
+				{
+					name: 'equityPerShare';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'periodReturn';
+					type: 'i64';
+					index: false;
+				},
+				{
+					name: 'benchmarkReturn';
+					type: 'i64';
+					index: false;
+				},
+				{
+					name: 'feesCharged';
+					type: 'u64';
+					index: false;
+				},
+				{
+					name: 'depositorEquity';
+					type: 'u64';
+					index: false;
+				},
+				{
+					name: 'netDepositorReturn';
+					type: 'i64';
+					index: false;
+				},
+				{
+					name: 'interestPnl';
+					type: 'i64';
+					index: false;
+				},
+				{
+					name: 'tradingPnl';
+					type: 'i64';
+					index: false;
+				}
+			];
+		},
+		{
+			name: 'ShareClassConversionRecord';
+			fields: [
+				{
+					name: 'ts';
+					type: 'i64';
+					index: false;
+				},
+				{
+					name: 'vault';
+					type: 'publicKey';
+					index: false;
+				},
+				{
+					name: 'depositorAuthority';
+					type: 'publicKey';
+					index: false;
+				},
+				{
+					name: 'locked';
+					type: 'bool';
+					index: false;
+				},
+				{
+					name: 'shares';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'holderLockedSharesAfter';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'classLockedSharesAfter';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'unlockTs';
+					type: 'i64';
+					index: false;
+				},
+				{
+					name: 'profitShareDiscount';
+					type: 'u32';
+					index: false;
+				}
+			];
+		},
+		{
+			name: 'WithdrawPriorityBoostRecord';
+			fields: [
+				{
+					name: 'ts';
+					type: 'i64';
+					index: false;
+				},
+				{
+					name: 'vault';
+					type: 'publicKey';
+					index: false;
+				},
+				{
+					name: 'depositorAuthority';
+					type: 'publicKey';
+					index: false;
+				},
+				{
+					name: 'priorityFee';
+					type: 'u32';
+					index: false;
+				},
+				{
+					name: 'sharesForfeited';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'valueForfeited';
+					type: 'u64';
+					index: false;
+				},
+				{
+					name: 'requestTsBefore';
+					type: 'i64';
+					index: false;
+				},
+				{
+					name: 'requestTsAfter';
+					type: 'i64';
+					index: false;
+				}
+			];
+		},
+		{
+			name: 'LiquidationActionRecord';
+			fields: [
+				{
+					name: 'ts';
+					type: 'i64';
+					index: false;
+				},
+				{
+					name: 'vault';
+					type: 'publicKey';
+					index: false;
+				},
+				{
+					name: 'liquidationDelegate';
+					type: 'publicKey';
+					index: false;
+				},
+				{
+					name: 'depositorAuthority';
+					type: 'publicKey';
+					index: false;
+				},
+				{
+					name: 'action';
+					type: {
+						defined: 'LiquidationAction';
+					};
+					index: false;
+				},
+				{
+					name: 'nextOrderId';
+					type: 'u32';
+					index: false;
+				},
+				{
+					name: 'totalCollateral';
+					type: 'i128';
+					index: false;
+				},
+				{
+					name: 'maintenanceMarginRequirement';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'perpLiabilityValue';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'spotLiabilityValue';
+					type: 'u128';
+					index: false;
+				}
+			];
+		},
+		{
+			name: 'VaultCrystallizationRecord';
+			fields: [
+				{
+					name: 'ts';
+					type: 'i64';
+					index: false;
+				},
+				{
+					name: 'vault';
+					type: 'publicKey';
+					index: false;
+				},
+				{
+					name: 'vaultEquity';
+					type: 'u64';
+					index: false;
+				},
+				{
+					name: 'sharePrice';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'highWaterMarkBefore';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'highWaterMarkAfter';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'depositorProfit';
+					type: 'u64';
+					index: false;
+				},
+				{
+					name: 'profitSharePaid';
+					type: 'u64';
+					index: false;
+				},
+				{
+					name: 'profitShareShares';
+					type: 'u128';
+					index: false;
+				}
+			];
+		},
+		{
+			name: 'BackstopRecord';
+			fields: [
+				{
+					name: 'ts';
+					type: 'i64';
+					index: false;
+				},
+				{
+					name: 'vault';
+					type: 'publicKey';
+					index: false;
+				},
+				{
+					name: 'action';
+					type: {
+						defined: 'crate::state::BackstopAction';
+					};
+					index: false;
+				},
+				{
+					name: 'amount';
+					type: 'u64';
+					index: false;
+				},
+				{
+					name: 'shares';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'sharePrice';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'highWaterMark';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'balanceAfter';
+					type: 'u64';
+					index: false;
+				},
+				{
+					name: 'owedAfter';
+					type: 'u64';
+					index: false;
+				}
+			];
+		},
+		{
+			name: 'ProfitShareRefundRecord';
+			fields: [
+				{
+					name: 'ts';
+					type: 'i64';
+					index: false;
+				},
+				{
+					name: 'vault';
+					type: 'publicKey';
+					index: false;
+				},
+				{
+					name: 'profitShareBefore';
+					type: 'u32';
+					index: false;
+				},
+				{
+					name: 'profitShareAfter';
+					type: 'u32';
+					index: false;
+				},
+				{
+					name: 'lookbackStartTs';
+					type: 'i64';
+					index: false;
+				},
+				{
+					name: 'profitShareInLookback';
+					type: 'u64';
+					index: false;
+				},
+				{
+					name: 'amount';
+					type: 'u64';
+					index: false;
+				},
+				{
+					name: 'shares';
+					type: 'u128';
+					index: false;
+				}
+			];
+		},
+		{
+			name: 'WithdrawPayoutRecord';
+			fields: [
+				{
+					name: 'ts';
+					type: 'i64';
+					index: false;
+				},
+				{
+					name: 'vault';
+					type: 'publicKey';
+					index: false;
+				},
+				{
+					name: 'authority';
+					type: 'publicKey';
+					index: false;
+				},
+				{
+					name: 'requestedValue';
+					type: 'u64';
+					index: false;
+				},
+				{
+					name: 'currentValue';
+					type: 'u64';
+					index: false;
+				},
+				{
+					name: 'payout';
+					type: 'u64';
+					index: false;
+				},
+				{
+					name: 'shortfall';
+					type: 'u64';
+					index: false;
+				},
+				{
+					name: 'cappedGains';
+					type: 'u64';
+					index: false;
+				},
+				{
+					name: 'cause';
+					type: {
+						defined: 'WithdrawPayoutDivergence';
+					};
+					index: false;
+				}
+			];
+		},
+		{
+			name: 'TokenizedSharesExchangeRateRecord';
+			fields: [
+				{
+					name: 'ts';
+					type: 'i64';
+					index: false;
+				},
+				{
+					name: 'vault';
+					type: 'publicKey';
+					index: false;
+				},
+				{
+					name: 'mint';
+					type: 'publicKey';
+					index: false;
+				},
+				{
+					name: 'vaultSharesBase';
+					type: 'u32';
+					index: false;
+				},
+				{
+					name: 'vaultShares';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'mintSupply';
+					type: 'u64';
+					index: false;
+				},
+				{
+					name: 'tokenPrice';
+					type: 'u64';
+					index: false;
+				}
+			];
+		},
+		{
+			name: 'WithdrawBlackoutRecord';
+			fields: [
+				{
+					name: 'ts';
+					type: 'i64';
+					index: false;
+				},
+				{
+					name: 'vault';
+					type: 'publicKey';
+					index: false;
+				},
+				{
+					name: 'startTs';
+					type: 'i64';
+					index: false;
+				},
+				{
+					name: 'endTs';
+					type: 'i64';
+					index: false;
+				},
+				{
+					name: 'periodBlackout';
+					type: 'i64';
+					index: false;
+				}
+			];
+		},
+		{
+			name: 'VaultDepositorAuthorityTransferRecord';
+			fields: [
+				{
+					name: 'ts';
+					type: 'i64';
+					index: false;
+				},
+				{
+					name: 'vault';
+					type: 'publicKey';
+					index: false;
+				},
+				{
+					name: 'oldAuthority';
+					type: 'publicKey';
+					index: false;
+				},
+				{
+					name: 'newAuthority';
+					type: 'publicKey';
+					index: false;
+				},
+				{
+					name: 'vaultDepositor';
+					type: 'publicKey';
+					index: false;
+				},
+				{
+					name: 'vaultShares';
+					type: 'u128';
+					index: false;
+				}
+			];
+		},
+		{
+			name: 'FuelSeasonRecord';
+			fields: [
+				{
+					name: 'ts';
+					type: 'i64';
+					index: false;
+				},
+				{
+					name: 'authority';
+					type: 'publicKey';
+					index: false;
+				},
+				{
+					name: 'fuelInsurance';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'fuelDeposits';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'fuelBorrows';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'fuelPositions';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'fuelTaker';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'fuelMaker';
+					type: 'u128';
+					index: false;
+				},
+				{
+					name: 'fuelTotal';
+					type: 'u128';
+					index: false;
+				}
+			];
+		}
+	];
+	errors: [
+		{
+			code: 6000;
+			name: 'Default';
+			msg: 'Default';
+		},
+		{
+			code: 6001;
+			name: 'InvalidVaultRebase';
+			msg: 'InvalidVaultRebase';
+		},
+		{
+			code: 6002;
+			name: 'InvalidVaultSharesDetected';
+			msg: 'InvalidVaultSharesDetected';
+		},
+		{
+			code: 6003;
+			name: 'CannotWithdrawBeforeRedeemPeriodEnd';
+			msg: 'CannotWithdrawBeforeRedeemPeriodEnd';
+		},
+		{
+			code: 6004;
+			name: 'InvalidVaultWithdraw';
+			msg: 'InvalidVaultWithdraw';
+		},
+		{
+			code: 6005;
+			name: 'InsufficientVaultShares';
+			msg: 'InsufficientVaultShares';
+		},
+		{
+			code: 6006;
+			name: 'InvalidVaultWithdrawSize';
+			msg: 'InvalidVaultWithdrawSize';
+		},
+		{
+			code: 6007;
+			name: 'InvalidVaultForNewDepositors';
+			msg: 'InvalidVaultForNewDepositors';
+		},
+		{
+			code: 6008;
+			name: 'VaultWithdrawRequestInProgress';
+			msg: 'VaultWithdrawRequestInProgress';
+		},
+		{
+			code: 6009;
+			name: 'VaultIsAtCapacity';
+			msg: 'VaultIsAtCapacity';
+		},
+		{
+			code: 6010;
+			name: 'InvalidVaultDepositorInitialization';
+			msg: 'InvalidVaultDepositorInitialization';
+		},
+		{
+			code: 6011;
+			name: 'DelegateNotAvailableForLiquidation';
+			msg: 'DelegateNotAvailableForLiquidation';
+		},
+		{
+			code: 6012;
+			name: 'InvalidEquityValue';
+			msg: 'InvalidEquityValue';
+		},
+		{
+			code: 6013;
+			name: 'VaultInLiquidation';
+			msg: 'VaultInLiquidation';
+		},
+		{
+			code: 6014;
+			name: 'DriftError';
+			msg: 'DriftError';
+		},
+		{
+			code: 6015;
+			name: 'InvalidVaultInitialization';
+			msg: 'InvalidVaultInitialization';
+		},
+		{
+			code: 6016;
+			name: 'InvalidVaultUpdate';
+			msg: 'InvalidVaultUpdate';
+		},
+		{
+			code: 6017;
+			name: 'PermissionedVault';
+			msg: 'PermissionedVault';
+		},
+		{
+			code: 6018;
+			name: 'WithdrawInProgress';
+			msg: 'WithdrawInProgress';
+		},
+		{
+			code: 6019;
+			name: 'SharesPercentTooLarge';
+			msg: 'SharesPercentTooLarge';
+		},
+		{
+			code: 6020;
+			name: 'InvalidVaultDeposit';
+			msg: 'InvalidVaultDeposit';
+		},
+		{
+			code: 6021;
+			name: 'OngoingLiquidation';
+			msg: 'OngoingLiquidation';
+		},
+		{
+			code: 6022;
+			name: 'VaultProtocolMissing';
+			msg: 'VaultProtocolMissing';
+		},
+		{
+			code: 6023;
+			name: 'InvalidTokenization';
+			msg: 'InvalidTokenization';
+		},
+		{
+			code: 6024;
+			name: 'InvalidFuelDistributionMode';
+			msg: 'InvalidFuelDistributionMode';
+		},
+		{
+			code: 6025;
+			name: 'VaultHookMissing';
+			msg: 'VaultHookMissing';
+		},
+		{
+			code: 6026;
+			name: 'InvalidVaultHook';
+			msg: 'InvalidVaultHook';
+		},
+		{
+			code: 6027;
+			name: 'InvalidWithdrawRoute';
+			msg: 'InvalidWithdrawRoute';
+		},
+		{
+			code: 6028;
+			name: 'InvalidVaultTemplate';
+			msg: 'InvalidVaultTemplate';
+		},
+		{
+			code: 6029;
+			name: 'InvalidVaultPoll';
+			msg: 'InvalidVaultPoll';
+		},
+		{
+			code: 6030;
+			name: 'VaultWindingDown';
+			msg: 'VaultWindingDown';
+		},
+		{
+			code: 6031;
+			name: 'InvalidShareSnapshot';
+			msg: 'InvalidShareSnapshot';
+		},
+		{
+			code: 6032;
+			name: 'InvalidLossCarryforward';
+			msg: 'InvalidLossCarryforward';
+		},
+		{
+			code: 6033;
+			name: 'InvalidVaultDepositorCustodian';
+			msg: 'InvalidVaultDepositorCustodian';
+		},
+		{
+			code: 6034;
+			name: 'DuplicateIdempotencyKey';
+			msg: 'DuplicateIdempotencyKey';
+		},
+		{
+			code: 6035;
+			name: 'InvalidVaultDepositorIdempotency';
+			msg: 'InvalidVaultDepositorIdempotency';
+		},
+		{
+			code: 6036;
+			name: 'InvalidWithdrawSettlement';
+			msg: 'InvalidWithdrawSettlement';
+		},
+		{
+			code: 6037;
+			name: 'InvalidVaultProfile';
+			msg: 'InvalidVaultProfile';
+		},
+		{
+			code: 6038;
+			name: 'InvalidPerformancePeriod';
+			msg: 'InvalidPerformancePeriod';
+		},
+		{
+			code: 6039;
+			name: 'InvalidVaultInterface';
+			msg: 'InvalidVaultInterface';
+		},
+		{
+			code: 6040;
+			name: 'InvalidDustWithdraw';
+			msg: 'InvalidDustWithdraw';
+		},
+		{
+			code: 6041;
+			name: 'InvalidWithdrawStream';
+			msg: 'InvalidWithdrawStream';
+		},
+		{
+			code: 6042;
+			name: 'InvalidVaultFeatureFlags';
+			msg: 'InvalidVaultFeatureFlags';
+		},
+		{
+			code: 6043;
+			name: 'VaultFeatureDisabled';
+			msg: 'VaultFeatureDisabled';
+		},
+		{
+			code: 6044;
+			name: 'InvalidVaultRegistry';
+			msg: 'InvalidVaultRegistry';
+		},
+		{
+			code: 6045;
+			name: 'InvalidDepositMintMigration';
+			msg: 'InvalidDepositMintMigration';
+		},
+		{
+			code: 6046;
+			name: 'InvalidVaultParamChangeLog';
+			msg: 'InvalidVaultParamChangeLog';
+		},
+		{
+			code: 6047;
+			name: 'InvalidVaultDepositorCap';
+			msg: 'InvalidVaultDepositorCap';
+		},
+		{
+			code: 6048;
+			name: 'VaultDepositorCapReached';
+			msg: 'VaultDepositorCapReached';
+		},
+		{
+			code: 6049;
+			name: 'InvalidFeeHoliday';
+			msg: 'InvalidFeeHoliday';
+		},
+		{
+			code: 6050;
+			name: 'InvalidWithdrawReceipt';
+			msg: 'InvalidWithdrawReceipt';
+		},
+		{
+			code: 6051;
+			name: 'WithdrawCancelDeadlinePassed';
+			msg: 'WithdrawCancelDeadlinePassed';
+		},
+		{
+			code: 6052;
+			name: 'SettleCrankMissing';
+			msg: 'SettleCrankMissing';
+		},
+		{
+			code: 6053;
+			name: 'InvalidVaultDepositorVersion';
+			msg: 'InvalidVaultDepositorVersion';
+		},
+		{
+			code: 6054;
+			name: 'InvalidVaultDelegateProposal';
+			msg: 'InvalidVaultDelegateProposal';
+		},
+		{
+			code: 6055;
+			name: 'DelegateApprovalRequired';
+			msg: 'DelegateApprovalRequired';
+		},
+		{
+			code: 6056;
+			name: 'SpotMarketWithdrawPaused';
+			msg: 'SpotMarketWithdrawPaused';
+		},
+		{
+			code: 6057;
+			name: 'WithdrawNotQueued';
+			msg: 'WithdrawNotQueued';
+		},
+		{
+			code: 6058;
+			name: 'InvalidVaultFeeSplit';
+			msg: 'InvalidVaultFeeSplit';
+		},
+		{
+			code: 6059;
+			name: 'AutoRenewWithdrawNotEnabled';
+			msg: 'AutoRenewWithdrawNotEnabled';
+		},
+		{
+			code: 6060;
+			name: 'WithdrawRequestNotExpired';
+			msg: 'WithdrawRequestNotExpired';
+		},
+		{
+			code: 6061;
+			name: 'InvalidProtocolTvlCap';
+			msg: 'InvalidProtocolTvlCap';
+		},
+		{
+			code: 6062;
+			name: 'ProtocolTvlCapExceeded';
+			msg: 'ProtocolTvlCapExceeded';
+		},
+		{
+			code: 6063;
+			name: 'InvalidVaultRedeemSchedule';
+			msg: 'InvalidVaultRedeemSchedule';
+		},
+		{
+			code: 6064;
+			name: 'InvalidVaultDepositorBatch';
+			msg: 'InvalidVaultDepositorBatch';
+		},
+		{
+			code: 6065;
+			name: 'VaultDepositorBlacklisted';
+			msg: 'VaultDepositorBlacklisted';
+		},
+		{
+			code: 6066;
+			name: 'InvalidVaultManagerFeeClaim';
+			msg: 'InvalidVaultManagerFeeClaim';
+		},
+		{
+			code: 6067;
+			name: 'InvalidVaultWithdrawEscrow';
+			msg: 'InvalidVaultWithdrawEscrow';
+		},
+		{
+			code: 6068;
+			name: 'ZeroVaultEquity';
+			msg: 'ZeroVaultEquity';
+		},
+		{
+			code: 6069;
+			name: 'InvalidVaultShareClass';
+			msg: 'InvalidVaultShareClass';
+		},
+		{
+			code: 6070;
+			name: 'VaultShareClassLocked';
+			msg: 'VaultShareClassLocked';
+		},
+		{
+			code: 6071;
+			name: 'InvalidVaultDepositorThrottle';
+			msg: 'InvalidVaultDepositorThrottle';
+		},
+		{
+			code: 6072;
+			name: 'VaultDepositorThrottled';
+			msg: 'VaultDepositorThrottled';
+		},
+		{
+			code: 6073;
+			name: 'DelegateSelfTrade';
+			msg: 'DelegateSelfTrade';
+		},
+		{
+			code: 6074;
+			name: 'InvalidVaultWithdrawPriority';
+			msg: 'InvalidVaultWithdrawPriority';
+		},
+		{
+			code: 6075;
+			name: 'InvalidLiquidationAction';
+			msg: 'InvalidLiquidationAction';
+		},
+		{
+			code: 6076;
+			name: 'InvalidVaultCrystallization';
+			msg: 'InvalidVaultCrystallization';
+		},
+		{
+			code: 6077;
+			name: 'InvalidVaultBackstop';
+			msg: 'InvalidVaultBackstop';
+		},
+		{
+			code: 6078;
+			name: 'InvalidFuelOverflowSweep';
+			msg: 'InvalidFuelOverflowSweep';
+		},
+		{
+			code: 6079;
+			name: 'InvalidProfitShareRefund';
+			msg: 'InvalidProfitShareRefund';
+		},
+		{
+			code: 6080;
+			name: 'InvalidOmnibusLedger';
+			msg: 'InvalidOmnibusLedger';
+		},
+		{
+			code: 6081;
+			name: 'OracleTwapDeviationTooLarge';
+			msg: 'OracleTwapDeviationTooLarge';
+		},
+		{
+			code: 6082;
+			name: 'InvalidVaultGuard';
+			msg: 'InvalidVaultGuard';
+		},
+		{
+			code: 6083;
+			name: 'InvalidVaultManagerActivity';
+			msg: 'InvalidVaultManagerActivity';
+		},
+		{
+			code: 6084;
+			name: 'VaultStateChanged';
+			msg: 'VaultStateChanged';
+		},
+		{
+			code: 6085;
+			name: 'SimulationOnly';
+			msg: 'SimulationOnly';
+		},
+		{
+			code: 6086;
+			name: 'VaultDepositorHasCompanionAccounts';
+			msg: 'VaultDepositorHasCompanionAccounts';
+		},
+		{
+			code: 6087;
+			name: 'InvalidVaultWithdrawBlackout';
+			msg: 'InvalidVaultWithdrawBlackout';
+		},
+		{
+			code: 6088;
+			name: 'WithdrawBlackout';
+			msg: 'WithdrawBlackout';
+		},
+		{
+			code: 6089;
+			name: 'InvalidVaultLockup';
+			msg: 'InvalidVaultLockup';
+		},
+		{
+			code: 6090;
+			name: 'VaultDepositorLockedUp';
+			msg: 'VaultDepositorLockedUp';
+		},
+		{
+			code: 6091;
+			name: 'InvalidVaultDepositorWithdrawRequests';
+			msg: 'InvalidVaultDepositorWithdrawRequests';
+		},
+		{
+			code: 6092;
+			name: 'InvalidVaultAuditor';
+			msg: 'InvalidVaultAuditor';
+		},
+		{
+			code: 6093;
+			name: 'DepositTooSmall';
+			msg: 'DepositTooSmall';
+		},
+		{
+			code: 6094;
+			name: 'InvalidVaultDepositorAuthorityTransfer';
+			msg: 'InvalidVaultDepositorAuthorityTransfer';
+		},
+		{
+			code: 6095;
+			name: 'TokenMintMissing';
+			msg: 'TokenMintMissing';
+		},
+		{
+			code: 6096;
+			name: 'InvalidVaultProfitShareCadence';
+			msg: 'InvalidVaultProfitShareCadence';
+		},
+		{
+			code: 6097;
+			name: 'ProfitShareCadenceNotElapsed';
+			msg: 'ProfitShareCadenceNotElapsed';
+		},
+		{
+			code: 6098;
+			name: 'InvalidSwapProgram';
+			msg: 'InvalidSwapProgram';
+		},
+		{
+			code: 6099;
+			name: 'SwapOutputBelowMinimum';
+			msg: 'SwapOutputBelowMinimum';
+		},
+		{
+			code: 6100;
+			name: 'InvalidVaultConfig';
+			msg: 'InvalidVaultConfig';
+		}
+	];
+};
+
+export const IDL: DriftVaults = {
+	version: '0.6.0',
+	name: 'drift_vaults',
+	instructions: [
+		{
+			name: 'initializeVault',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'tokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUserStats',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUser',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftState',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftSpotMarket',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftSpotMarketMint',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'manager',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'payer',
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: 'rent',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'systemProgram',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftProgram',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'tokenProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'params',
+					type: {
+						defined: 'VaultParams',
+					},
+				},
+			],
+		},
+		{
+			name: 'initializeVaultWithProtocol',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultProtocol',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'tokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUserStats',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUser',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftState',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftSpotMarket',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftSpotMarketMint',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'manager',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'payer',
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: 'rent',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'systemProgram',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftProgram',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'tokenProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'params',
+					type: {
+						defined: 'VaultWithProtocolParams',
+					},
+				},
+			],
+		},
+		{
+			name: 'initializeVaultFromTemplate',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'tokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUserStats',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUser',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftState',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftSpotMarket',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftSpotMarketMint',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'manager',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'payer',
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: 'rent',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'systemProgram',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftProgram',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'tokenProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'params',
+					type: {
+						defined: 'VaultFromTemplateParams',
+					},
+				},
+			],
+		},
+		{
+			name: 'updateDelegate',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'manager',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'driftUser',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'delegate',
+					type: 'publicKey',
+				},
+			],
+		},
+		{
+			name: 'updateMarginTradingEnabled',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'manager',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'driftUser',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'enabled',
+					type: 'bool',
+				},
+			],
+		},
+		{
+			name: 'updateUserPoolId',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'manager',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'driftUser',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'poolId',
+					type: 'u8',
+				},
+			],
+		},
+		{
+			name: 'updateVaultProtocol',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'protocol',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'vaultProtocol',
+					isMut: true,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'params',
+					type: {
+						defined: 'UpdateVaultProtocolParams',
+					},
+				},
+			],
+		},
+		{
+			name: 'updateVaultProtocolFeeTiers',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'vaultProtocol',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'vaultConfig',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'protocol',
+					isMut: false,
+					isSigner: true,
+				},
+			],
+			args: [
+				{
+					name: 'protocolFeeTiers',
+					type: {
+						array: [
+							{
+								defined: 'ProtocolFeeTier',
+							},
+							4,
+						],
+					},
+				},
+			],
+		},
+		{
+			name: 'updateVault',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'manager',
+					isMut: false,
+					isSigner: true,
+				},
+			],
+			args: [
+				{
+					name: 'params',
+					type: {
+						defined: 'UpdateVaultParams',
+					},
+				},
+			],
+		},
+		{
+			name: 'updateVaultManager',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'manager',
+					isMut: false,
+					isSigner: true,
+				},
+			],
+			args: [
+				{
+					name: 'manager',
+					type: 'publicKey',
+				},
+			],
+		},
+		{
+			name: 'initializeVaultHook',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultHook',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'manager',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'payer',
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: 'rent',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'systemProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'hookProgram',
+					type: 'publicKey',
+				},
+			],
+		},
+		{
+			name: 'updateVaultHook',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultHook',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'manager',
+					isMut: false,
+					isSigner: true,
+				},
+			],
+			args: [
+				{
+					name: 'hookProgram',
+					type: 'publicKey',
+				},
+			],
+		},
+		{
+			name: 'initializeVaultWithdrawRoute',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'vaultWithdrawRoute',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'manager',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'payer',
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: 'rent',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'systemProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'marketIndexes',
+					type: {
+						vec: 'u16',
+					},
+				},
+			],
+		},
+		{
+			name: 'updateVaultWithdrawRoute',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'vaultWithdrawRoute',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'manager',
+					isMut: false,
+					isSigner: true,
+				},
+			],
+			args: [
+				{
+					name: 'marketIndexes',
+					type: {
+						vec: 'u16',
+					},
+				},
+			],
+		},
+		{
+			name: 'initializeVaultRiskScore',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'vaultRiskScore',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'payer',
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: 'rent',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'systemProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'updateVaultRiskScore',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'vaultRiskScore',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUser',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'updateCumulativeFuelAmount',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultDepositor',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'signer',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'driftUserStats',
+					isMut: true,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'initializeVaultDepositor',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultDepositor',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'authority',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'payer',
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: 'rent',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'systemProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'initializeTokenizedVaultDepositor',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'vaultDepositor',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'mintAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'metadataAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'payer',
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: 'tokenProgram',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'tokenMetadataProgram',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'rent',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'systemProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'params',
+					type: {
+						defined: 'InitializeTokenizedVaultDepositorParams',
+					},
+				},
+			],
+		},
+		{
+			name: 'tokenizeShares',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultDepositor',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'authority',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'tokenizedVaultDepositor',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'mint',
+					isMut: true,
+					isSigner: false,
+					docs: [
+						"The mint created at the tokenized depositor's initial shares base, it carries on across rebases",
+					],
+				},
+				{
+					name: 'userTokenAccount',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftUser',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUserStats',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'tokenProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'amount',
+					type: 'u64',
+				},
+				{
+					name: 'unit',
+					type: {
+						defined: 'WithdrawUnit',
 					},
-					{
-						name: 'sharesBase';
-						docs: [
-							'The base 10 exponent of the shares (given massive share inflation can occur at near zero vault equity)'
-						];
-						type: 'u32';
+				},
+			],
+		},
+		{
+			name: 'redeemTokens',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultDepositor',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'authority',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'tokenizedVaultDepositor',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'mint',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'userTokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultTokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUser',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUserStats',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'tokenProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'tokensToBurn',
+					type: 'u64',
+				},
+			],
+		},
+		{
+			name: 'deposit',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultDepositor',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'authority',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'vaultTokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUserStats',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUser',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftState',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftSpotMarketVault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'userTokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftProgram',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'tokenProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'amount',
+					type: 'u64',
+				},
+			],
+		},
+		{
+			name: 'requestWithdraw',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultDepositor',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'authority',
+					isMut: false,
+					isSigner: true,
+					docs: ['the depositor authority or its custodian'],
+				},
+				{
+					name: 'driftUserStats',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftUser',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'withdrawAmount',
+					type: 'u64',
+				},
+				{
+					name: 'withdrawUnit',
+					type: {
+						defined: 'WithdrawUnit',
 					},
-					{
-						name: 'profitShare';
-						docs: [
-							'Percentage the manager charges on all profits realized by depositors: PERCENTAGE_PRECISION'
-						];
-						type: 'u32';
+				},
+			],
+		},
+		{
+			name: 'cancelRequestWithdraw',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultDepositor',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'authority',
+					isMut: false,
+					isSigner: true,
+					docs: ['the depositor authority or its custodian'],
+				},
+				{
+					name: 'driftUserStats',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftUser',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'withdraw',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultDepositor',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'authority',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'vaultTokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUserStats',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUser',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftState',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftSpotMarketVault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftSigner',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'userTokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftProgram',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'tokenProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'withdrawRouted',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultDepositor',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultWithdrawRoute',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'authority',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'vaultTokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUserStats',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUser',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftState',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftSpotMarketVault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftSigner',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'userTokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftProgram',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'tokenProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'liquidate',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultDepositor',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'authority',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'driftUserStats',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUser',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'resetDelegate',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUser',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'resetFuelSeason',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultDepositor',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'admin',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'driftUserStats',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftState',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'resetVaultFuelSeason',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'admin',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'driftState',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'managerDeposit',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'manager',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'vaultTokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUserStats',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUser',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftState',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftSpotMarketVault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'userTokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftProgram',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'tokenProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'amount',
+					type: 'u64',
+				},
+			],
+		},
+		{
+			name: 'managerRequestWithdraw',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'manager',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'driftUserStats',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftUser',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'withdrawAmount',
+					type: 'u64',
+				},
+				{
+					name: 'withdrawUnit',
+					type: {
+						defined: 'WithdrawUnit',
 					},
-					{
-						name: 'hurdleRate';
-						docs: [
-							'Vault manager only collect incentive fees during periods when returns are higher than this amount: PERCENTAGE_PRECISION'
-						];
-						type: 'u32';
+				},
+			],
+		},
+		{
+			name: 'mangerCancelWithdrawRequest',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'manager',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'driftUserStats',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftUser',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'managerWithdraw',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'manager',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'vaultTokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUserStats',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUser',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftState',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftSpotMarketVault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftSigner',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'userTokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftProgram',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'tokenProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'managerUpdateFuelDistributionMode',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'manager',
+					isMut: false,
+					isSigner: true,
+				},
+			],
+			args: [
+				{
+					name: 'fuelDistributionMode',
+					type: 'u8',
+				},
+			],
+		},
+		{
+			name: 'applyProfitShare',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultDepositor',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'manager',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'driftUserStats',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUser',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftState',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftSigner',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'applyRebase',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultDepositor',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUser',
+					isMut: true,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'applyRebaseTokenizedDepositor',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'tokenizedVaultDepositor',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUser',
+					isMut: true,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'forceWithdraw',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'manager',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'vaultDepositor',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultTokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUserStats',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUser',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftState',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftSpotMarketVault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftSigner',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'userTokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftProgram',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'tokenProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'initializeInsuranceFundStake',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'manager',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'payer',
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: 'rent',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftSpotMarket',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftSpotMarketMint',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'vaultTokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'insuranceFundStake',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUserStats',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftState',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftProgram',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'tokenProgram',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'systemProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'marketIndex',
+					type: 'u16',
+				},
+			],
+		},
+		{
+			name: 'addInsuranceFundStake',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'manager',
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: 'driftSpotMarket',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftSpotMarketVault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'insuranceFundStake',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'insuranceFundVault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'managerTokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultIfTokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUserStats',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftState',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftSigner',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftProgram',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'tokenProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'marketIndex',
+					type: 'u16',
+				},
+				{
+					name: 'amount',
+					type: 'u64',
+				},
+			],
+		},
+		{
+			name: 'requestRemoveInsuranceFundStake',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'manager',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'driftSpotMarket',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'insuranceFundStake',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'insuranceFundVault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUserStats',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'marketIndex',
+					type: 'u16',
+				},
+				{
+					name: 'amount',
+					type: 'u64',
+				},
+			],
+		},
+		{
+			name: 'removeInsuranceFundStake',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'manager',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'driftSpotMarket',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'insuranceFundStake',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'insuranceFundVault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'managerTokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultIfTokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUserStats',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftState',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftSigner',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftProgram',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'tokenProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'marketIndex',
+					type: 'u16',
+				},
+			],
+		},
+		{
+			name: 'cancelRequestRemoveInsuranceFundStake',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'manager',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'driftSpotMarket',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'insuranceFundStake',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'insuranceFundVault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUserStats',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'marketIndex',
+					type: 'u16',
+				},
+			],
+		},
+		{
+			name: 'protocolRequestWithdraw',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultProtocol',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'protocol',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'driftUserStats',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftUser',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'withdrawAmount',
+					type: 'u64',
+				},
+				{
+					name: 'withdrawUnit',
+					type: {
+						defined: 'WithdrawUnit',
 					},
-					{
-						name: 'spotMarketIndex';
-						docs: [
-							'The spot market index the vault deposits into/withdraws from'
-						];
-						type: 'u16';
+				},
+			],
+		},
+		{
+			name: 'protocolCancelWithdrawRequest',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultProtocol',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'protocol',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'driftUserStats',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftUser',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'protocolWithdraw',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultProtocol',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'protocol',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'vaultTokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUserStats',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUser',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftState',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftSpotMarketVault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftSigner',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'userTokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftProgram',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'tokenProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'initializeVaultPoll',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'vaultPoll',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'payer',
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: 'rent',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'systemProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'startVaultPoll',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'vaultPoll',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultDepositor',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'authority',
+					isMut: false,
+					isSigner: true,
+				},
+			],
+			args: [
+				{
+					name: 'proposal',
+					type: 'u8',
+				},
+				{
+					name: 'newManager',
+					type: 'publicKey',
+				},
+			],
+		},
+		{
+			name: 'voteOnVaultPoll',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'vaultPoll',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultPollVote',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultDepositor',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'authority',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'payer',
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: 'rent',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'systemProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'executeVaultPoll',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultProtocol',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'vaultPoll',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'protocol',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'driftUser',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'initializeVaultShareSnapshot',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'vaultShareSnapshot',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'manager',
+					isMut: false,
+					isSigner: true,
+					docs: ["The manager or the vault's auditor"],
+				},
+				{
+					name: 'payer',
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: 'rent',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'systemProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'epoch',
+					type: 'u32',
+				},
+			],
+		},
+		{
+			name: 'snapshotVaultDepositorShares',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'vaultShareSnapshot',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultDepositor',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'vaultDepositorShareSnapshot',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'payer',
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: 'rent',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'systemProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'initializeProtocolLossLedger',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'vaultProtocol',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'protocolLossLedger',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'protocol',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'payer',
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: 'rent',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'systemProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'authority',
+					type: 'publicKey',
+				},
+			],
+		},
+		{
+			name: 'recordLossCarryforward',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'vaultProtocol',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'vaultDepositor',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'protocolLossLedger',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'authority',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'driftUser',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'applyLossCarryforward',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'vaultProtocol',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'vaultDepositor',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'protocolLossLedger',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'authority',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'driftUser',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'updateVaultDepositorCustodian',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'vaultDepositor',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'authority',
+					isMut: false,
+					isSigner: true,
+				},
+			],
+			args: [
+				{
+					name: 'custodian',
+					type: 'publicKey',
+				},
+			],
+		},
+		{
+			name: 'revokeVaultDepositorCustodian',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'vaultDepositor',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'authority',
+					isMut: false,
+					isSigner: true,
+					docs: ['the depositor authority or its custodian'],
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'initializeVaultDepositorIdempotency',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'vaultDepositor',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'vaultDepositorIdempotency',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'authority',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'payer',
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: 'rent',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'systemProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'depositWithIdempotencyKey',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultDepositor',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'authority',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'vaultTokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUserStats',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUser',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftState',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftSpotMarketVault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'userTokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftProgram',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'tokenProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'amount',
+					type: 'u64',
+				},
+				{
+					name: 'idempotencyKey',
+					type: 'u64',
+				},
+			],
+		},
+		{
+			name: 'requestWithdrawWithIdempotencyKey',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultDepositor',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'authority',
+					isMut: false,
+					isSigner: true,
+					docs: ['the depositor authority or its custodian'],
+				},
+				{
+					name: 'driftUserStats',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftUser',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'withdrawAmount',
+					type: 'u64',
+				},
+				{
+					name: 'withdrawUnit',
+					type: {
+						defined: 'WithdrawUnit',
 					},
-					{
-						name: 'bump';
-						docs: ['The bump for the vault pda'];
-						type: 'u8';
+				},
+				{
+					name: 'idempotencyKey',
+					type: 'u64',
+				},
+			],
+		},
+		{
+			name: 'updateVaultLargeWithdrawNotice',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'manager',
+					isMut: false,
+					isSigner: true,
+				},
+			],
+			args: [
+				{
+					name: 'largeWithdrawThreshold',
+					type: 'u32',
+				},
+				{
+					name: 'largeWithdrawNoticePeriod',
+					type: 'i64',
+				},
+			],
+		},
+		{
+			name: 'initializeVaultWithdrawSettlement',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultWithdrawSettlement',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'manager',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'payer',
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: 'rent',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'systemProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'settleVaultWithdraws',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'vaultWithdrawSettlement',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUser',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'updateVaultProfile',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'manager',
+					isMut: false,
+					isSigner: true,
+				},
+			],
+			args: [
+				{
+					name: 'profile',
+					type: 'u8',
+				},
+			],
+		},
+		{
+			name: 'enforceVaultProfile',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftUser',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'initializeVaultPerformanceStats',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'vaultPerformanceStats',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'payer',
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: 'rent',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'systemProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'updateVaultPerformanceStats',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'vaultPerformanceStats',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUser',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'initializeVaultInterface',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'vaultInterface',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'manager',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'payer',
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: 'rent',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'systemProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'interface',
+					type: 'publicKey',
+				},
+				{
+					name: 'feeRate',
+					type: 'u32',
+				},
+			],
+		},
+		{
+			name: 'updateVaultInterface',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'vaultInterface',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'manager',
+					isMut: false,
+					isSigner: true,
+				},
+			],
+			args: [
+				{
+					name: 'feeRate',
+					type: 'u32',
+				},
+			],
+		},
+		{
+			name: 'depositWithInterface',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultDepositor',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'authority',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'vaultTokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUserStats',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUser',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftState',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftSpotMarketVault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'userTokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftProgram',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'tokenProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'amount',
+					type: 'u64',
+				},
+				{
+					name: 'interface',
+					type: 'publicKey',
+				},
+			],
+		},
+		{
+			name: 'withdrawWithInterface',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultDepositor',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'authority',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'vaultTokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUserStats',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUser',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftState',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftSpotMarketVault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftSigner',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'userTokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftProgram',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'tokenProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'interface',
+					type: 'publicKey',
+				},
+			],
+		},
+		{
+			name: 'claimVaultInterfaceFees',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultInterface',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultDepositor',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'interface',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'driftUserStats',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftUser',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'initializeVaultDepositorMemo',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'vaultDepositor',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'vaultDepositorMemo',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'authority',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'payer',
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: 'rent',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'systemProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'memo',
+					type: {
+						array: ['u8', 32],
 					},
-					{
-						name: 'permissioned';
-						docs: ['Whether anybody can be a depositor'];
-						type: 'bool';
+				},
+			],
+		},
+		{
+			name: 'updateVaultDepositorMemo',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'vaultDepositor',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'vaultDepositorMemo',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'authority',
+					isMut: false,
+					isSigner: true,
+				},
+			],
+			args: [
+				{
+					name: 'memo',
+					type: {
+						array: ['u8', 32],
 					},
-					{
-						name: 'vaultProtocol';
-						docs: ['The optional [`VaultProtocol`] account.'];
-						type: 'bool';
+				},
+			],
+		},
+		{
+			name: 'initializeVaultDustWithdraw',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'vaultDustWithdraw',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'manager',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'payer',
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: 'rent',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'systemProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'threshold',
+					type: 'u64',
+				},
+				{
+					name: 'windowMaxAmount',
+					type: 'u64',
+				},
+			],
+		},
+		{
+			name: 'updateVaultDustWithdraw',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'vaultDustWithdraw',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'manager',
+					isMut: false,
+					isSigner: true,
+				},
+			],
+			args: [
+				{
+					name: 'threshold',
+					type: 'u64',
+				},
+				{
+					name: 'windowMaxAmount',
+					type: 'u64',
+				},
+			],
+		},
+		{
+			name: 'withdrawDust',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultDepositor',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'authority',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'vaultTokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUserStats',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUser',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftState',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftSpotMarketVault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftSigner',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'userTokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftProgram',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'tokenProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'initializeVaultWithdrawStream',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'vaultDepositor',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'vaultWithdrawStream',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'authority',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'payer',
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: 'rent',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'systemProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'startWithdrawStream',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'vaultDepositor',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'vaultWithdrawStream',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'authority',
+					isMut: false,
+					isSigner: true,
+				},
+			],
+			args: [
+				{
+					name: 'duration',
+					type: 'i64',
+				},
+			],
+		},
+		{
+			name: 'claimWithdrawStream',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultDepositor',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'authority',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'vaultTokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUserStats',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUser',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftState',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftSpotMarketVault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftSigner',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'userTokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftProgram',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'tokenProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'simulateWithdraw',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'vaultDepositor',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftUser',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
+			returns: {
+				defined: 'WithdrawBlocker',
+			},
+		},
+		{
+			name: 'simulateWithdrawStatus',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'vaultDepositor',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftUser',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
+			returns: {
+				defined: 'SimulateWithdrawStatus',
+			},
+		},
+		{
+			name: 'updateVaultDisabledFeatures',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'manager',
+					isMut: false,
+					isSigner: true,
+				},
+			],
+			args: [
+				{
+					name: 'disabledFeatures',
+					type: 'u8',
+				},
+			],
+		},
+		{
+			name: 'initializeVaultRegistry',
+			accounts: [
+				{
+					name: 'vaultRegistry',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'payer',
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: 'rent',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'systemProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'initializeVaultRegistryPage',
+			accounts: [
+				{
+					name: 'vaultRegistry',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'vaultRegistryPage',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'payer',
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: 'rent',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'systemProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'pageIndex',
+					type: 'u32',
+				},
+			],
+		},
+		{
+			name: 'registerVault',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultTokenAccount',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'vaultRegistry',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultRegistryPage',
+					isMut: true,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'migrateVaultDepositMint',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'manager',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'admin',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'driftState',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'vaultTokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUser',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftSpotMarket',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'successorDriftSpotMarket',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'payer',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'tokenProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'initializeVaultTokenAccount',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'vaultTokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftSpotMarket',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftSpotMarketMint',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'payer',
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: 'rent',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'systemProgram',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'tokenProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'depositWithCancelWithdrawRequest',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultDepositor',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'authority',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'vaultTokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUserStats',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUser',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftState',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftSpotMarketVault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'userTokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftProgram',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'tokenProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'amount',
+					type: 'u64',
+				},
+				{
+					name: 'cancelWithdrawRequest',
+					type: 'bool',
+				},
+			],
+		},
+		{
+			name: 'initializeVaultParamChangeLog',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultParamChangeLog',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'manager',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'payer',
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: 'rent',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'systemProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'initializeVaultDepositorCap',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultDepositorCap',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'manager',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'payer',
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: 'rent',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'systemProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'maxDepositors',
+					type: 'u32',
+				},
+			],
+		},
+		{
+			name: 'updateVaultDepositorCap',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'vaultDepositorCap',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'manager',
+					isMut: false,
+					isSigner: true,
+				},
+			],
+			args: [
+				{
+					name: 'maxDepositors',
+					type: 'u32',
+				},
+			],
+		},
+		{
+			name: 'initializeVaultFeeHoliday',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'vaultFeeHoliday',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'manager',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'payer',
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: 'rent',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'systemProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'scheduleVaultFeeHoliday',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'vaultFeeHoliday',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'manager',
+					isMut: false,
+					isSigner: true,
+				},
+			],
+			args: [
+				{
+					name: 'startTs',
+					type: 'i64',
+				},
+				{
+					name: 'endTs',
+					type: 'i64',
+				},
+				{
+					name: 'suspendManagementFee',
+					type: 'bool',
+				},
+				{
+					name: 'suspendProfitShare',
+					type: 'bool',
+				},
+			],
+		},
+		{
+			name: 'cancelVaultFeeHoliday',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'vaultFeeHoliday',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'manager',
+					isMut: false,
+					isSigner: true,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'crankVaultFeeHoliday',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultFeeHoliday',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUser',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'updateVaultProfitShareMode',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'vaultConfig',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'manager',
+					isMut: false,
+					isSigner: true,
+				},
+			],
+			args: [
+				{
+					name: 'profitShareMode',
+					type: 'u8',
+				},
+			],
+		},
+		{
+			name: 'initializeProtocolTreasury',
+			accounts: [
+				{
+					name: 'protocolTreasury',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'protocol',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'payer',
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: 'rent',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'systemProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'treasury',
+					type: 'publicKey',
+				},
+			],
+		},
+		{
+			name: 'collectProtocolFees',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultProtocol',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'protocolTreasury',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultTokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUserStats',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUser',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftState',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftSpotMarketVault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftSigner',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'treasuryTokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftProgram',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'tokenProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'initializeWithdrawReceipt',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultDepositor',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'withdrawReceipt',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'authority',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'payer',
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: 'rent',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'systemProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'requestWithdrawWithCancelDeadline',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultDepositor',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'authority',
+					isMut: false,
+					isSigner: true,
+					docs: ['the depositor authority or its custodian'],
+				},
+				{
+					name: 'driftUserStats',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftUser',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'withdrawAmount',
+					type: 'u64',
+				},
+				{
+					name: 'withdrawUnit',
+					type: {
+						defined: 'WithdrawUnit',
 					},
-					{
-						name: 'fuelDistributionMode';
-						docs: [
-							'How fuel distribution should be treated [`FuelDistributionMode`]. Default is `UsersOnly`'
-						];
-						type: 'u8';
+				},
+				{
+					name: 'cancelDeadlineTs',
+					type: 'i64',
+				},
+			],
+		},
+		{
+			name: 'updateVaultRequireSettleCrank',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'manager',
+					isMut: false,
+					isSigner: true,
+				},
+			],
+			args: [
+				{
+					name: 'requireSettleCrank',
+					type: 'bool',
+				},
+			],
+		},
+		{
+			name: 'initializeVaultDelegateProposal',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'vaultProtocol',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultDelegateProposal',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'manager',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'protocol',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'payer',
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: 'rent',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'systemProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'updateVaultDelegateDualApproval',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'vaultProtocol',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultDelegateProposal',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'manager',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'protocol',
+					isMut: false,
+					isSigner: true,
+				},
+			],
+			args: [
+				{
+					name: 'enabled',
+					type: 'bool',
+				},
+			],
+		},
+		{
+			name: 'proposeVaultDelegate',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'vaultDelegateProposal',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'manager',
+					isMut: false,
+					isSigner: true,
+				},
+			],
+			args: [
+				{
+					name: 'delegate',
+					type: 'publicKey',
+				},
+			],
+		},
+		{
+			name: 'approveVaultDelegate',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultProtocol',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'vaultDelegateProposal',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'protocol',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'driftUser',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'delegate',
+					type: 'publicKey',
+				},
+			],
+		},
+		{
+			name: 'commitVaultReport',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'vaultReport',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'manager',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'payer',
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: 'rent',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'systemProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'epoch',
+					type: 'u32',
+				},
+				{
+					name: 'reportHash',
+					type: {
+						array: ['u8', 32],
 					},
-					{
-						name: 'padding1';
-						type: {
-							array: ['u8', 2];
-						};
+				},
+			],
+		},
+		{
+			name: 'queueWithdraw',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'vaultDepositor',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'authority',
+					isMut: false,
+					isSigner: true,
+					docs: ['the depositor authority or its custodian'],
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'executeQueuedWithdraw',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'keeper',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'vaultDepositor',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultTokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUserStats',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUser',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftState',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftSpotMarketVault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftSigner',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'userTokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftProgram',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'tokenProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'initializeVaultFeeSplit',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'vaultFeeSplit',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'manager',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'payer',
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: 'rent',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'systemProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'split',
+					type: 'u32',
+				},
+				{
+					name: 'recipient',
+					type: 'publicKey',
+				},
+			],
+		},
+		{
+			name: 'updateVaultFeeSplit',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'vaultFeeSplit',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'manager',
+					isMut: false,
+					isSigner: true,
+				},
+			],
+			args: [
+				{
+					name: 'split',
+					type: 'u32',
+				},
+				{
+					name: 'recipient',
+					type: 'publicKey',
+				},
+			],
+		},
+		{
+			name: 'distributeVaultFeeSplit',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultFeeSplit',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultTokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUserStats',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUser',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftState',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftSpotMarketVault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftSigner',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'recipientTokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftProgram',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'tokenProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'updateVaultDepositorAutoRenewWithdraw',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'vaultDepositor',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'authority',
+					isMut: false,
+					isSigner: true,
+					docs: ['the depositor authority or its custodian'],
+				},
+			],
+			args: [
+				{
+					name: 'enabled',
+					type: 'bool',
+				},
+			],
+		},
+		{
+			name: 'renewWithdrawRequest',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultDepositor',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'keeper',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'driftUserStats',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftUser',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'initializeProtocolTvlCap',
+			accounts: [
+				{
+					name: 'protocolTvlCap',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'protocol',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'payer',
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: 'rent',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'systemProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'maxTvl',
+					type: 'u64',
+				},
+			],
+		},
+		{
+			name: 'updateProtocolTvlCap',
+			accounts: [
+				{
+					name: 'protocolTvlCap',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'protocol',
+					isMut: false,
+					isSigner: true,
+				},
+			],
+			args: [
+				{
+					name: 'maxTvl',
+					type: 'u64',
+				},
+			],
+		},
+		{
+			name: 'addVaultToProtocolTvlCap',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'vaultProtocol',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'protocolTvlCap',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'protocol',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'driftUser',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'removeVaultFromProtocolTvlCap',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'vaultProtocol',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'protocolTvlCap',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'protocol',
+					isMut: false,
+					isSigner: true,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'initializeVaultRedeemSchedule',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'vaultRedeemSchedule',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'manager',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'payer',
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: 'rent',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'systemProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'buckets',
+					type: {
+						array: [
+							{
+								defined: 'RedeemPeriodBucket',
+							},
+							4,
+						],
 					},
-					{
-						name: 'lastCumulativeFuelPerShareTs';
-						docs: ['The timestamp cumulative_fuel_per_share was last updated'];
-						type: 'u32';
+				},
+			],
+		},
+		{
+			name: 'updateVaultRedeemSchedule',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'vaultRedeemSchedule',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'manager',
+					isMut: false,
+					isSigner: true,
+				},
+			],
+			args: [
+				{
+					name: 'buckets',
+					type: {
+						array: [
+							{
+								defined: 'RedeemPeriodBucket',
+							},
+							4,
+						],
 					},
-					{
-						name: 'cumulativeFuelPerShare';
-						docs: [
-							'The cumulative fuel per share (scaled up by 1e6 to avoid losing precision)'
-						];
-						type: 'u128';
+				},
+			],
+		},
+		{
+			name: 'updateVaultFuelBoost',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'vaultConfig',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'manager',
+					isMut: false,
+					isSigner: true,
+				},
+			],
+			args: [
+				{
+					name: 'fuelBoost',
+					type: 'u8',
+				},
+			],
+		},
+		{
+			name: 'applyProfitShareBatch',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'manager',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'driftUserStats',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftUser',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'emitDetails',
+					type: 'bool',
+				},
+			],
+		},
+		{
+			name: 'updateCumulativeFuelAmountBatch',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'signer',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'driftUserStats',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'backfillVaultDepositorCounts',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'updateVaultDepositorBlacklist',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'vaultDepositor',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'manager',
+					isMut: false,
+					isSigner: true,
+				},
+			],
+			args: [
+				{
+					name: 'blacklisted',
+					type: 'bool',
+				},
+			],
+		},
+		{
+			name: 'withdrawToDriftUser',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultDepositor',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'authority',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'vaultTokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUserStats',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUser',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftState',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftSpotMarketVault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftSigner',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'userTokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'depositorDriftUser',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'depositorDriftUserStats',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftProgram',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'tokenProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'depositFromDriftUser',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultDepositor',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'authority',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'vaultTokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUserStats',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUser',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftState',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftSpotMarketVault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'userTokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'depositorDriftUser',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'depositorDriftUserStats',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftSigner',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftProgram',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'tokenProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'amount',
+					type: 'u64',
+				},
+			],
+		},
+		{
+			name: 'initializeVaultManagerFeeClaim',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultManagerFeeClaim',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUser',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'manager',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'payer',
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: 'rent',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'systemProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'claimCap',
+					type: 'u32',
+				},
+			],
+		},
+		{
+			name: 'claimVaultManagerFee',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultManagerFeeClaim',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUser',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'manager',
+					isMut: false,
+					isSigner: true,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'initializeVaultShareStatement',
+			accounts: [
+				{
+					name: 'vaultShareSnapshot',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'vaultShareStatement',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'payer',
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: 'rent',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'systemProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'appendVaultShareStatement',
+			accounts: [
+				{
+					name: 'vaultShareSnapshot',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'vaultShareStatement',
+					isMut: true,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'initializeVaultWithdrawEscrow',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'vaultWithdrawEscrow',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUserStats',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'escrowDriftUser',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftState',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'manager',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'payer',
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: 'rent',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'systemProgram',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'initializeVaultDepositorWithdrawEscrow',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'vaultDepositor',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'vaultDepositorWithdrawEscrow',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'authority',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'payer',
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: 'rent',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'systemProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'escrowWithdrawRequest',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultDepositor',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'authority',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'vaultWithdrawEscrow',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultDepositorWithdrawEscrow',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUserStats',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUser',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'escrowDriftUser',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftState',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftSpotMarketVault',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'withdrawFromEscrow',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'vaultDepositor',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'authority',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'vaultWithdrawEscrow',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultDepositorWithdrawEscrow',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultTokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUserStats',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'escrowDriftUser',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftState',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftSpotMarketVault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftSigner',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'userTokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftProgram',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'tokenProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'initializeVaultShareClass',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'vaultShareClass',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'manager',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'payer',
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: 'rent',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'systemProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'lockPeriod',
+					type: 'i64',
+				},
+				{
+					name: 'profitShareDiscount',
+					type: 'u32',
+				},
+			],
+		},
+		{
+			name: 'initializeVaultDepositorShareClass',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'vaultDepositor',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'vaultDepositorShareClass',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'authority',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'payer',
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: 'rent',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'systemProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'convertShareClass',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultDepositor',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'authority',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'vaultShareClass',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultDepositorShareClass',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUser',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftUserStats',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'amount',
+					type: 'u64',
+				},
+				{
+					name: 'unit',
+					type: {
+						defined: 'WithdrawUnit',
 					},
-					{
-						name: 'cumulativeFuel';
-						docs: ['The total fuel accumulated'];
-						type: 'u128';
+				},
+				{
+					name: 'locked',
+					type: 'bool',
+				},
+			],
+		},
+		{
+			name: 'initializeVaultDepositorThrottle',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'vaultConfig',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultDepositorThrottle',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'manager',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'payer',
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: 'rent',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'systemProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'minDepositInterval',
+					type: 'u32',
+				},
+				{
+					name: 'minRequestInterval',
+					type: 'u32',
+				},
+			],
+		},
+		{
+			name: 'updateVaultDepositorThrottle',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'vaultDepositorThrottle',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'manager',
+					isMut: false,
+					isSigner: true,
+				},
+			],
+			args: [
+				{
+					name: 'minDepositInterval',
+					type: 'u32',
+				},
+				{
+					name: 'minRequestInterval',
+					type: 'u32',
+				},
+			],
+		},
+		{
+			name: 'initializeVaultDepositorThrottleState',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'vaultDepositor',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'vaultDepositorThrottleState',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'authority',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'payer',
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: 'rent',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'systemProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'depositorAction',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultDepositor',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'authority',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'vaultTokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUserStats',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUser',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftState',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftSpotMarketVault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftSigner',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'userTokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftProgram',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'tokenProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'action',
+					type: {
+						defined: 'DepositorAction',
 					},
-					{
-						name: 'padding';
-						type: {
-							array: ['u64', 3];
-						};
-					}
-				];
-			};
-		}
-	];
-	types: [
+				},
+			],
+		},
+		{
+			name: 'checkDelegateSelfTrade',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftUser',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'updateVaultProtocolFeeWaiver',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'vaultProtocol',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'vaultConfig',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'protocol',
+					isMut: false,
+					isSigner: true,
+				},
+			],
+			args: [
+				{
+					name: 'tvlThreshold',
+					type: 'u64',
+				},
+				{
+					name: 'exitTvlThreshold',
+					type: 'u64',
+				},
+			],
+		},
+		{
+			name: 'initializeVaultWithdrawPriority',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'vaultWithdrawPriority',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'manager',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'payer',
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: 'rent',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'systemProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'fullBoostFee',
+					type: 'u32',
+				},
+			],
+		},
+		{
+			name: 'updateVaultWithdrawPriority',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'vaultWithdrawPriority',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'manager',
+					isMut: false,
+					isSigner: true,
+				},
+			],
+			args: [
+				{
+					name: 'fullBoostFee',
+					type: 'u32',
+				},
+			],
+		},
+		{
+			name: 'boostWithdrawRequest',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultDepositor',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'authority',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'vaultWithdrawPriority',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftUserStats',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftUser',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'priorityFee',
+					type: 'u32',
+				},
+			],
+		},
+		{
+			name: 'recordLiquidationAction',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'vaultDepositor',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'liquidationDelegate',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'driftUser',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'initializeVaultCrystallization',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultCrystallization',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUser',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'manager',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'payer',
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: 'rent',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'systemProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'crystallizationPeriod',
+					type: 'u32',
+				},
+			],
+		},
+		{
+			name: 'updateVaultCrystallization',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'vaultCrystallization',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'manager',
+					isMut: false,
+					isSigner: true,
+				},
+			],
+			args: [
+				{
+					name: 'profitShare',
+					type: 'u32',
+				},
+			],
+		},
+		{
+			name: 'crystallizeVaultProfitShare',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultCrystallization',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUser',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'initializeVaultBackstop',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'vaultBackstop',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultTokenAccount',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'backstopTokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'mint',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'manager',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'payer',
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: 'rent',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'systemProgram',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'tokenProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'contributionRate',
+					type: 'u32',
+				},
+				{
+					name: 'deductible',
+					type: 'u32',
+				},
+			],
+		},
+		{
+			name: 'updateVaultBackstop',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'vaultBackstop',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'manager',
+					isMut: false,
+					isSigner: true,
+				},
+			],
+			args: [
+				{
+					name: 'contributionRate',
+					type: 'u32',
+				},
+				{
+					name: 'deductible',
+					type: 'u32',
+				},
+			],
+		},
+		{
+			name: 'updateVaultBackstopMaxBalance',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'vaultProtocol',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'vaultBackstop',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'protocol',
+					isMut: false,
+					isSigner: true,
+				},
+			],
+			args: [
+				{
+					name: 'maxBalance',
+					type: 'u64',
+				},
+			],
+		},
+		{
+			name: 'contributeVaultBackstop',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultBackstop',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultTokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUserStats',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUser',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftState',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftSpotMarketVault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftSigner',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'backstopTokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftProgram',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'tokenProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'claimVaultBackstop',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'vaultBackstop',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'backstopTokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultTokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUserStats',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUser',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftState',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftSpotMarketVault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftProgram',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'tokenProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'sweepVaultFuel',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUserStats',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'fuelOverflow',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'payer',
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: 'driftProgram',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'rent',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'systemProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'initializeVaultProfitShareHistory',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'vaultProfitShareHistory',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'manager',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'payer',
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: 'rent',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'systemProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'checkpointVaultProfitShare',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'vaultProfitShareHistory',
+					isMut: true,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'applyFeeReductionRefund',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultProfitShareHistory',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUser',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'manager',
+					isMut: false,
+					isSigner: true,
+				},
+			],
+			args: [
+				{
+					name: 'profitShare',
+					type: 'u32',
+				},
+			],
+		},
+		{
+			name: 'initializeOmnibusLedger',
+			accounts: [
+				{
+					name: 'vaultDepositor',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'omnibusLedger',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'authority',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'payer',
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: 'rent',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'systemProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'initializeOmnibusLedgerPage',
+			accounts: [
+				{
+					name: 'vaultDepositor',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'omnibusLedger',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'omnibusLedgerPage',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'authority',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'payer',
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: 'rent',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'systemProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'pageIndex',
+					type: 'u16',
+				},
+			],
+		},
+		{
+			name: 'updateOmnibusLedgerEntry',
+			accounts: [
+				{
+					name: 'vaultDepositor',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'omnibusLedger',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'omnibusLedgerPage',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'authority',
+					isMut: false,
+					isSigner: true,
+				},
+			],
+			args: [
+				{
+					name: 'entryIndex',
+					type: 'u8',
+				},
+				{
+					name: 'beneficiary',
+					type: 'publicKey',
+				},
+				{
+					name: 'shares',
+					type: 'u128',
+				},
+			],
+		},
+		{
+			name: 'updateVaultMaxOracleTwapDeviation',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'vaultGuard',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'manager',
+					isMut: false,
+					isSigner: true,
+				},
+			],
+			args: [
+				{
+					name: 'maxOracleTwapDeviationBps',
+					type: 'u16',
+				},
+			],
+		},
+		{
+			name: 'initializeVaultGuard',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'vaultConfig',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultGuard',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'manager',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'payer',
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: 'rent',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'systemProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'maxOracleTwapDeviationBps',
+					type: 'u16',
+				},
+			],
+		},
+		{
+			name: 'initializeVaultManagerActivity',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'vaultManagerActivity',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'manager',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'payer',
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: 'rent',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'systemProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'inactivityPeriod',
+					type: 'u32',
+				},
+			],
+		},
+		{
+			name: 'updateVaultManagerActivity',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'vaultManagerActivity',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'manager',
+					isMut: false,
+					isSigner: true,
+				},
+			],
+			args: [
+				{
+					name: 'inactivityPeriod',
+					type: 'u32',
+				},
+			],
+		},
+		{
+			name: 'inactiveManagerWithdraw',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultDepositor',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'authority',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'vaultTokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUserStats',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUser',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftState',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftSpotMarketVault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftSigner',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'userTokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftProgram',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'tokenProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'managerHeartbeat',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'vaultManagerActivity',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'manager',
+					isMut: false,
+					isSigner: true,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'updateVaultFeePolicy',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'vaultConfig',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'manager',
+					isMut: false,
+					isSigner: true,
+				},
+			],
+			args: [
+				{
+					name: 'feePolicy',
+					type: 'u8',
+				},
+			],
+		},
+		{
+			name: 'updateVaultMinDepositUsd',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'vaultGuard',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'manager',
+					isMut: false,
+					isSigner: true,
+				},
+			],
+			args: [
+				{
+					name: 'minDepositUsd',
+					type: 'u32',
+				},
+			],
+		},
+		{
+			name: 'viewDepositorPortfolio',
+			accounts: [],
+			args: [],
+			returns: {
+				vec: {
+					defined: 'PortfolioPosition',
+				},
+			},
+		},
+		{
+			name: 'checkVaultStateHash',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'expectedStateHash',
+					type: 'u64',
+				},
+			],
+		},
+		{
+			name: 'previewDeposit',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultDepositor',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'authority',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'vaultTokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUserStats',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUser',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftState',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftSpotMarketVault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'userTokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftProgram',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'tokenProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'amount',
+					type: 'u64',
+				},
+			],
+		},
+		{
+			name: 'previewWithdraw',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultDepositor',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'authority',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'vaultTokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUserStats',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUser',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftState',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftSpotMarketVault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftSigner',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'userTokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftProgram',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'tokenProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'realizeDepositorProfits',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultDepositor',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'authority',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'driftUserStats',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftUser',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'initializeVaultDepositorRecordLog',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'vaultDepositorRecordLog',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'manager',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'payer',
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: 'rent',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'systemProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'withdrawAndClose',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultDepositor',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'authority',
+					isMut: true,
+					isSigner: true,
+					docs: [
+						'receives the rent of the vault depositor and its companion accounts',
+					],
+				},
+				{
+					name: 'vaultTokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUserStats',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUser',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftState',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftSpotMarketVault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftSigner',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'userTokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftProgram',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'tokenProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'initializeVaultWithdrawBlackout',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'vaultGuard',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultWithdrawBlackout',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'manager',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'payer',
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: 'rent',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'systemProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
 		{
-			name: 'InitializeTokenizedVaultDepositorParams';
-			type: {
-				kind: 'struct';
-				fields: [
-					{
-						name: 'tokenName';
-						type: 'string';
-					},
-					{
-						name: 'tokenSymbol';
-						type: 'string';
-					},
-					{
-						name: 'tokenUri';
-						type: 'string';
-					},
-					{
-						name: 'decimals';
-						type: 'u8';
-					}
-				];
-			};
+			name: 'scheduleVaultWithdrawBlackout',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'vaultWithdrawBlackout',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'manager',
+					isMut: false,
+					isSigner: true,
+				},
+			],
+			args: [
+				{
+					name: 'startTs',
+					type: 'i64',
+				},
+				{
+					name: 'endTs',
+					type: 'i64',
+				},
+			],
 		},
 		{
-			name: 'VaultWithProtocolParams';
-			type: {
-				kind: 'struct';
-				fields: [
-					{
-						name: 'name';
-						type: {
-							array: ['u8', 32];
-						};
-					},
-					{
-						name: 'redeemPeriod';
-						type: 'i64';
-					},
-					{
-						name: 'maxTokens';
-						type: 'u64';
-					},
-					{
-						name: 'managementFee';
-						type: 'i64';
-					},
-					{
-						name: 'minDepositAmount';
-						type: 'u64';
-					},
-					{
-						name: 'profitShare';
-						type: 'u32';
-					},
-					{
-						name: 'hurdleRate';
-						type: 'u32';
-					},
-					{
-						name: 'spotMarketIndex';
-						type: 'u16';
-					},
-					{
-						name: 'permissioned';
-						type: 'bool';
-					},
-					{
-						name: 'vaultProtocol';
-						type: {
-							defined: 'VaultProtocolParams';
-						};
-					}
-				];
-			};
+			name: 'initializeVaultLockup',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'vaultGuard',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultLockup',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'manager',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'payer',
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: 'rent',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'systemProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'lockupPeriod',
+					type: 'u32',
+				},
+			],
 		},
 		{
-			name: 'VaultProtocolParams';
-			type: {
-				kind: 'struct';
-				fields: [
-					{
-						name: 'protocol';
-						type: 'publicKey';
-					},
-					{
-						name: 'protocolFee';
-						type: 'u64';
-					},
-					{
-						name: 'protocolProfitShare';
-						type: 'u32';
-					}
-				];
-			};
+			name: 'updateVaultLockup',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'vaultLockup',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'manager',
+					isMut: false,
+					isSigner: true,
+				},
+			],
+			args: [
+				{
+					name: 'lockupPeriod',
+					type: 'u32',
+				},
+			],
 		},
 		{
-			name: 'VaultParams';
-			type: {
-				kind: 'struct';
-				fields: [
-					{
-						name: 'name';
-						type: {
-							array: ['u8', 32];
-						};
-					},
-					{
-						name: 'redeemPeriod';
-						type: 'i64';
-					},
-					{
-						name: 'maxTokens';
-						type: 'u64';
-					},
-					{
-						name: 'managementFee';
-						type: 'i64';
-					},
-					{
-						name: 'minDepositAmount';
-						type: 'u64';
-					},
-					{
-						name: 'profitShare';
-						type: 'u32';
-					},
-					{
-						name: 'hurdleRate';
-						type: 'u32';
-					},
-					{
-						name: 'spotMarketIndex';
-						type: 'u16';
-					},
-					{
-						name: 'permissioned';
-						type: 'bool';
-					}
-				];
-			};
+			name: 'initializeVaultDepositorLockup',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'vaultDepositor',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'vaultDepositorLockup',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'authority',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'payer',
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: 'rent',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'systemProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'initializeVaultDepositorWithdrawRequests',
+			accounts: [
+				{
+					name: 'vaultDepositor',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultDepositorWithdrawRequests',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'authority',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'payer',
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: 'rent',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'systemProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
 		},
 		{
-			name: 'UpdateVaultProtocolParams';
-			type: {
-				kind: 'struct';
-				fields: [
-					{
-						name: 'protocolFee';
-						type: {
-							option: 'u64';
-						};
-					},
-					{
-						name: 'protocolProfitShare';
-						type: {
-							option: 'u32';
-						};
-					}
-				];
-			};
+			name: 'parkWithdrawRequest',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'vaultDepositor',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultDepositorWithdrawRequests',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'authority',
+					isMut: false,
+					isSigner: true,
+					docs: ['the depositor authority or its custodian'],
+				},
+			],
+			args: [],
 		},
 		{
-			name: 'UpdateVaultParams';
-			type: {
-				kind: 'struct';
-				fields: [
-					{
-						name: 'redeemPeriod';
-						type: {
-							option: 'i64';
-						};
-					},
-					{
-						name: 'maxTokens';
-						type: {
-							option: 'u64';
-						};
-					},
-					{
-						name: 'managementFee';
-						type: {
-							option: 'i64';
-						};
-					},
-					{
-						name: 'minDepositAmount';
-						type: {
-							option: 'u64';
-						};
-					},
-					{
-						name: 'profitShare';
-						type: {
-							option: 'u32';
-						};
-					},
-					{
-						name: 'hurdleRate';
-						type: {
-							option: 'u32';
-						};
-					},
-					{
-						name: 'permissioned';
-						type: {
-							option: 'bool';
-						};
-					}
-				];
-			};
+			name: 'withdrawParkedRequest',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultDepositor',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'authority',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'vaultTokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUserStats',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftUser',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftState',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftSpotMarketVault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftSigner',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'userTokenAccount',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'driftProgram',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'tokenProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'index',
+					type: 'u8',
+				},
+			],
 		},
 		{
-			name: 'WithdrawRequest';
-			type: {
-				kind: 'struct';
-				fields: [
-					{
-						name: 'shares';
-						docs: ['request shares of vault withdraw'];
-						type: 'u128';
-					},
-					{
-						name: 'value';
-						docs: [
-							'requested value (in vault spot_market_index) of shares for withdraw'
-						];
-						type: 'u64';
-					},
-					{
-						name: 'ts';
-						docs: ['request ts of vault withdraw'];
-						type: 'i64';
-					}
-				];
-			};
+			name: 'cancelParkedWithdrawRequest',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'vaultDepositor',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'authority',
+					isMut: false,
+					isSigner: true,
+					docs: ['the depositor authority or its custodian'],
+				},
+				{
+					name: 'driftUserStats',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'driftUser',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'index',
+					type: 'u8',
+				},
+			],
+		},
+		{
+			name: 'initializeVaultAuditor',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'vaultAuditor',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'manager',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'payer',
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: 'rent',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'systemProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'auditor',
+					type: 'publicKey',
+				},
+			],
 		},
 		{
-			name: 'VaultDepositorAction';
-			type: {
-				kind: 'enum';
-				variants: [
-					{
-						name: 'Deposit';
-					},
-					{
-						name: 'WithdrawRequest';
-					},
-					{
-						name: 'CancelWithdrawRequest';
-					},
-					{
-						name: 'Withdraw';
-					},
-					{
-						name: 'FeePayment';
-					},
-					{
-						name: 'TokenizeShares';
-					},
-					{
-						name: 'RedeemTokens';
-					}
-				];
-			};
+			name: 'updateVaultAuditor',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'vaultAuditor',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'manager',
+					isMut: false,
+					isSigner: true,
+				},
+			],
+			args: [
+				{
+					name: 'auditor',
+					type: 'publicKey',
+				},
+			],
 		},
 		{
-			name: 'FuelDistributionMode';
-			type: {
-				kind: 'enum';
-				variants: [
-					{
-						name: 'UsersOnly';
-					},
-					{
-						name: 'UsersAndManager';
-					}
-				];
-			};
+			name: 'viewVaultFeeLedger',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
+			returns: {
+				defined: 'VaultFeeLedger',
+			},
 		},
 		{
-			name: 'WithdrawUnit';
-			type: {
-				kind: 'enum';
-				variants: [
-					{
-						name: 'Shares';
-					},
-					{
-						name: 'Token';
-					},
-					{
-						name: 'SharesPercent';
-					}
-				];
-			};
-		}
-	];
-	events: [
-		{
-			name: 'VaultRecord';
-			fields: [
+			name: 'proposeVaultDepositorAuthorityTransfer',
+			accounts: [
 				{
-					name: 'ts';
-					type: 'i64';
-					index: false;
+					name: 'vault',
+					isMut: false,
+					isSigner: false,
 				},
 				{
-					name: 'spotMarketIndex';
-					type: 'u16';
-					index: false;
+					name: 'vaultDepositor',
+					isMut: false,
+					isSigner: false,
 				},
 				{
-					name: 'vaultEquityBefore';
-					type: 'u64';
-					index: false;
-				}
-			];
+					name: 'vaultDepositorAuthorityTransfer',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'authority',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'payer',
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: 'rent',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'systemProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: 'newAuthority',
+					type: 'publicKey',
+				},
+			],
 		},
 		{
-			name: 'VaultDepositorRecord';
-			fields: [
+			name: 'acceptVaultDepositorAuthorityTransfer',
+			accounts: [
 				{
-					name: 'ts';
-					type: 'i64';
-					index: false;
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
 				},
 				{
-					name: 'vault';
-					type: 'publicKey';
-					index: false;
+					name: 'vaultDepositor',
+					isMut: true,
+					isSigner: false,
 				},
 				{
-					name: 'depositorAuthority';
-					type: 'publicKey';
-					index: false;
+					name: 'authority',
+					isMut: true,
+					isSigner: false,
 				},
 				{
-					name: 'action';
-					type: {
-						defined: 'VaultDepositorAction';
-					};
-					index: false;
+					name: 'vaultDepositorAuthorityTransfer',
+					isMut: true,
+					isSigner: false,
 				},
 				{
-					name: 'amount';
-					type: 'u64';
-					index: false;
+					name: 'newVaultDepositor',
+					isMut: true,
+					isSigner: false,
 				},
 				{
-					name: 'spotMarketIndex';
-					type: 'u16';
-					index: false;
+					name: 'newVaultDepositorLockup',
+					isMut: true,
+					isSigner: false,
+					isOptional: true,
+					docs: [
+						'required if the old vault depositor has a lockup on a vault with a [`VaultLockup`](crate::state::VaultLockup)',
+					],
 				},
 				{
-					name: 'vaultSharesBefore';
-					type: 'u128';
-					index: false;
+					name: 'newVaultDepositorThrottleState',
+					isMut: true,
+					isSigner: false,
+					isOptional: true,
+					docs: [
+						'required if the old vault depositor has a throttle state on a vault with a',
+						'[`VaultDepositorThrottle`](crate::state::VaultDepositorThrottle)',
+					],
 				},
 				{
-					name: 'vaultSharesAfter';
-					type: 'u128';
-					index: false;
+					name: 'newVaultDepositorShareClass',
+					isMut: true,
+					isSigner: false,
+					isOptional: true,
+					docs: [
+						'required if the old vault depositor holds locked [`VaultShareClass`](crate::state::VaultShareClass) shares',
+					],
 				},
 				{
-					name: 'vaultEquityBefore';
-					type: 'u64';
-					index: false;
+					name: 'newAuthority',
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: 'payer',
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: 'rent',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'systemProgram',
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'cancelVaultDepositorAuthorityTransfer',
+			accounts: [
+				{
+					name: 'vaultDepositor',
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: 'vaultDepositorAuthorityTransfer',
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: 'authority',
+					isMut: true,
+					isSigner: true,
+				},
+			],
+			args: [],
+		},
+		{
+			name: 'initializeVaultProfitShareCadence',
+			accounts: [
+				{
+					name: 'vault',
+					isMut: false,
+					isSigner: false,
 				},
 				{
-					name: 'userVaultSharesBefore';
-					type: 'u128';
-					index: false;
+					name: 'vaultGuard',
+					isMut: true,
+					isSigner: false,
 				},
 				{
-					name: 'totalVaultSharesBefore';
-					type: 'u128';
-					index: false;
+					name: 'vaultProfitShareCadence',
+					isMut: true,
+					isSigner: false,
 				},
 				{
-					name: 'userVaultSharesAfter';
-					type: 'u128';
-					index: false;
+					name: 'manager',
+					isMut: false,
+					isSigner: true,
 				},
 				{
-					name: 'totalVaultSharesAfter';
-					type: 'u128';
-					index: false;
+					name: 'payer',
+					isMut: true,
+					isSigner: true,
 				},
 				{
-					name: 'profitShare';
-					type: 'u64';
-					index: false;
+					name: 'rent',
+					isMut: false,
+					isSigner: false,
 				},
 				{
-					name: 'managementFee';
-					type: 'i64';
-					index: false;
+					name: 'systemProgram',
+					isMut: false,
+					isSigner: false,
 				},
+			],
+			args: [
 				{
-					name: 'managementFeeShares';
-					type: 'i64';
-					index: false;
+					name: 'minProfitShareInterval',
+					type: 'u32',
 				},
-				{
-					name: 'depositOraclePrice';
-					type: 'i64';
-					index: false;
-				}
-			];
+			],
 		},
 		{
-			name: 'VaultDepositorV1Record';
-			fields: [
+			name: 'updateVaultProfitShareCadence',
+			accounts: [
 				{
-					name: 'ts';
-					type: 'i64';
-					index: false;
+					name: 'vault',
+					isMut: false,
+					isSigner: false,
 				},
 				{
-					name: 'vault';
-					type: 'publicKey';
-					index: false;
+					name: 'vaultProfitShareCadence',
+					isMut: true,
+					isSigner: false,
 				},
 				{
-					name: 'depositorAuthority';
-					type: 'publicKey';
-					index: false;
+					name: 'manager',
+					isMut: false,
+					isSigner: true,
 				},
+			],
+			args: [
 				{
-					name: 'action';
-					type: {
-						defined: 'VaultDepositorAction';
-					};
-					index: false;
+					name: 'minProfitShareInterval',
+					type: 'u32',
 				},
+			],
+		},
+		{
+			name: 'initializeVaultDepositorProfitShareCadence',
+			accounts: [
 				{
-					name: 'amount';
-					type: 'u64';
-					index: false;
+					name: 'vault',
+					isMut: false,
+					isSigner: false,
 				},
 				{
-					name: 'spotMarketIndex';
-					type: 'u16';
-					index: false;
+					name: 'vaultDepositor',
+					isMut: false,
+					isSigner: false,
 				},
 				{
-					name: 'vaultSharesBefore';
-					type: 'u128';
-					index: false;
+					name: 'vaultDepositorProfitShareCadence',
+					isMut: true,
+					isSigner: false,
 				},
 				{
-					name: 'vaultSharesAfter';
-					type: 'u128';
-					index: false;
+					name: 'payer',
+					isMut: true,
+					isSigner: true,
 				},
 				{
-					name: 'vaultEquityBefore';
-					type: 'u64';
-					index: false;
+					name: 'rent',
+					isMut: false,
+					isSigner: false,
 				},
 				{
-					name: 'userVaultSharesBefore';
-					type: 'u128';
-					index: false;
+					name: 'systemProgram',
+					isMut: false,
+					isSigner: false,
 				},
+			],
+			args: [],
+		},
+		{
+			name: 'depositWithSwap',
+			accounts: [
 				{
-					name: 'totalVaultSharesBefore';
-					type: 'u128';
-					index: false;
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
 				},
 				{
-					name: 'userVaultSharesAfter';
-					type: 'u128';
-					index: false;
+					name: 'vaultDepositor',
+					isMut: true,
+					isSigner: false,
 				},
 				{
-					name: 'totalVaultSharesAfter';
-					type: 'u128';
-					index: false;
+					name: 'authority',
+					isMut: false,
+					isSigner: true,
 				},
 				{
-					name: 'protocolSharesBefore';
-					type: 'u128';
-					index: false;
+					name: 'vaultTokenAccount',
+					isMut: true,
+					isSigner: false,
 				},
 				{
-					name: 'protocolSharesAfter';
-					type: 'u128';
-					index: false;
+					name: 'driftUserStats',
+					isMut: true,
+					isSigner: false,
 				},
 				{
-					name: 'protocolProfitShare';
-					type: 'u64';
-					index: false;
+					name: 'driftUser',
+					isMut: true,
+					isSigner: false,
 				},
 				{
-					name: 'protocolFee';
-					type: 'i64';
-					index: false;
+					name: 'driftState',
+					isMut: false,
+					isSigner: false,
 				},
 				{
-					name: 'protocolFeeShares';
-					type: 'i64';
-					index: false;
+					name: 'driftSpotMarketVault',
+					isMut: true,
+					isSigner: false,
 				},
 				{
-					name: 'managerProfitShare';
-					type: 'u64';
-					index: false;
+					name: 'userTokenAccount',
+					isMut: true,
+					isSigner: false,
 				},
 				{
-					name: 'managementFee';
-					type: 'i64';
-					index: false;
+					name: 'driftProgram',
+					isMut: false,
+					isSigner: false,
 				},
 				{
-					name: 'managementFeeShares';
-					type: 'i64';
-					index: false;
+					name: 'tokenProgram',
+					isMut: false,
+					isSigner: false,
 				},
+			],
+			args: [
 				{
-					name: 'depositOraclePrice';
-					type: 'i64';
-					index: false;
-				}
-			];
-		},
-		{
-			name: 'ShareTransferRecord';
-			fields: [
-				{
-					name: 'ts';
-					type: 'i64';
-					index: false;
+					name: 'minAmountOut',
+					type: 'u64',
 				},
 				{
-					name: 'vault';
-					type: 'publicKey';
-					index: false;
+					name: 'swapAccountsLen',
+					type: 'u8',
 				},
 				{
-					name: 'fromVaultDepositor';
-					type: 'publicKey';
-					index: false;
+					name: 'swapData',
+					type: 'bytes',
 				},
+			],
+		},
+		{
+			name: 'initializeVaultConfig',
+			accounts: [
 				{
-					name: 'toVaultDepositor';
-					type: 'publicKey';
-					index: false;
+					name: 'vault',
+					isMut: true,
+					isSigner: false,
 				},
 				{
-					name: 'shares';
-					type: 'u128';
-					index: false;
+					name: 'vaultConfig',
+					isMut: true,
+					isSigner: false,
 				},
 				{
-					name: 'value';
-					type: 'u64';
-					index: false;
+					name: 'manager',
+					isMut: false,
+					isSigner: true,
 				},
 				{
-					name: 'fromDepositorSharesBefore';
-					type: 'u128';
-					index: false;
+					name: 'payer',
+					isMut: true,
+					isSigner: true,
 				},
 				{
-					name: 'fromDepositorSharesAfter';
-					type: 'u128';
-					index: false;
+					name: 'rent',
+					isMut: false,
+					isSigner: false,
 				},
 				{
-					name: 'toDepositorSharesBefore';
-					type: 'u128';
-					index: false;
+					name: 'systemProgram',
+					isMut: false,
+					isSigner: false,
 				},
-				{
-					name: 'toDepositorSharesAfter';
-					type: 'u128';
-					index: false;
-				}
-			];
+			],
+			args: [],
 		},
 		{
-			name: 'FuelSeasonRecord';
-			fields: [
+			name: 'updateVaultDepositValuation',
+			accounts: [
 				{
-					name: 'ts';
-					type: 'i64';
-					index: false;
+					name: 'vault',
+					isMut: false,
+					isSigner: false,
 				},
 				{
-					name: 'authority';
-					type: 'publicKey';
-					index: false;
+					name: 'vaultConfig',
+					isMut: true,
+					isSigner: false,
 				},
 				{
-					name: 'fuelInsurance';
-					type: 'u128';
-					index: false;
+					name: 'manager',
+					isMut: false,
+					isSigner: true,
 				},
+			],
+			args: [
 				{
-					name: 'fuelDeposits';
-					type: 'u128';
-					index: false;
+					name: 'depositValuation',
+					type: 'u8',
 				},
+			],
+		},
+		{
+			name: 'updateVaultProfitShareRealization',
+			accounts: [
 				{
-					name: 'fuelBorrows';
-					type: 'u128';
-					index: false;
+					name: 'vault',
+					isMut: false,
+					isSigner: false,
 				},
 				{
-					name: 'fuelPositions';
-					type: 'u128';
-					index: false;
+					name: 'vaultConfig',
+					isMut: true,
+					isSigner: false,
 				},
 				{
-					name: 'fuelTaker';
-					type: 'u128';
-					index: false;
+					name: 'manager',
+					isMut: false,
+					isSigner: true,
 				},
+			],
+			args: [
 				{
-					name: 'fuelMaker';
-					type: 'u128';
-					index: false;
+					name: 'profitShareRealization',
+					type: 'u8',
 				},
-				{
-					name: 'fuelTotal';
-					type: 'u128';
-					index: false;
-				}
-			];
-		}
-	];
-	errors: [
+			],
+		},
+	],
+	accounts: [
 		{
-			code: 6000;
-			name: 'Default';
-			msg: 'Default';
+			name: 'omnibusLedger',
+			docs: [
+				"Per-beneficiary allocation of an omnibus [`VaultDepositor`]'s shares, see",
+				'[`VaultDepositorFlag::Omnibus`](crate::state::VaultDepositorFlag). The depositor authority, usually a DAO',
+				"program's PDA, allocates shares in [`OmnibusLedgerPage`]s and the vault keeps the total allocated covered by the",
+				"depositor's shares: deposits add unallocated shares, withdraw requests can only take unallocated ones.",
+			],
+			type: {
+				kind: 'struct',
+				fields: [
+					{
+						name: 'vaultDepositor',
+						docs: ['The omnibus vault depositor'],
+						type: 'publicKey',
+					},
+					{
+						name: 'totalAllocatedShares',
+						docs: ["Sum of every page's allocations, at shares_base"],
+						type: 'u128',
+					},
+					{
+						name: 'sharesBase',
+						docs: [
+							"The vault depositor's shares_base when the total was last updated",
+						],
+						type: 'u32',
+					},
+					{
+						name: 'pageCount',
+						type: 'u16',
+					},
+					{
+						name: 'bump',
+						type: 'u8',
+					},
+					{
+						name: 'padding',
+						type: {
+							array: ['u8', 9],
+						},
+					},
+				],
+			},
 		},
 		{
-			code: 6001;
-			name: 'InvalidVaultRebase';
-			msg: 'InvalidVaultRebase';
+			name: 'omnibusLedgerPage',
+			type: {
+				kind: 'struct',
+				fields: [
+					{
+						name: 'vaultDepositor',
+						docs: ['The omnibus vault depositor'],
+						type: 'publicKey',
+					},
+					{
+						name: 'entries',
+						type: {
+							array: [
+								{
+									defined: 'OmnibusLedgerEntry',
+								},
+								16,
+							],
+						},
+					},
+					{
+						name: 'sharesBase',
+						docs: [
+							"The vault depositor's shares_base when the entries were last updated",
+						],
+						type: 'u32',
+					},
+					{
+						name: 'pageIndex',
+						type: 'u16',
+					},
+					{
+						name: 'bump',
+						type: 'u8',
+					},
+					{
+						name: 'padding',
+						type: {
+							array: ['u8', 9],
+						},
+					},
+				],
+			},
+		},
+		{
+			name: 'protocolLossLedger',
+			docs: [
+				'Per-authority loss carryforward across the vaults of one [`VaultProtocol`](crate::state::VaultProtocol).',
+				'Losses moved out of one vault offset profit share in another vault of the same protocol and spot market.',
+			],
+			type: {
+				kind: 'struct',
+				fields: [
+					{
+						name: 'protocol',
+						docs: ['The `VaultProtocol::protocol` the ledger nets across'],
+						type: 'publicKey',
+					},
+					{
+						name: 'authority',
+						docs: ['The depositor authority'],
+						type: 'publicKey',
+					},
+					{
+						name: 'lossCarryforward',
+						docs: [
+							"Losses recorded from the protocol's vaults not yet netted against profit, in `spot_market_index` token",
+						],
+						type: 'u64',
+					},
+					{
+						name: 'totalLossNetted',
+						docs: [
+							'Total profit that was exempted from profit share by carried losses',
+						],
+						type: 'u64',
+					},
+					{
+						name: 'spotMarketIndex',
+						type: 'u16',
+					},
+					{
+						name: 'bump',
+						type: 'u8',
+					},
+					{
+						name: 'padding',
+						type: {
+							array: ['u8', 5],
+						},
+					},
+				],
+			},
+		},
+		{
+			name: 'protocolTreasury',
+			docs: [
+				"Where `collect_protocol_fees` sends a protocol's fee and profit share shares redeemed across its vaults.",
+			],
+			type: {
+				kind: 'struct',
+				fields: [
+					{
+						name: 'protocol',
+						docs: ['The `VaultProtocol::protocol` the treasury collects for'],
+						type: 'publicKey',
+					},
+					{
+						name: 'treasury',
+						docs: [
+							'Owner of the token accounts fees are paid to, one per vault spot market',
+						],
+						type: 'publicKey',
+					},
+					{
+						name: 'totalCollected',
+						docs: [
+							'Total tokens collected across all vaults and spot markets, precision: token mint',
+						],
+						type: 'u64',
+					},
+					{
+						name: 'bump',
+						type: 'u8',
+					},
+					{
+						name: 'padding',
+						type: {
+							array: ['u8', 7],
+						},
+					},
+				],
+			},
+		},
+		{
+			name: 'protocolTvlCap',
+			docs: [
+				'Caps the combined tvl of the vaults a `VaultProtocol::protocol` adds to it, checked on every deposit into one of',
+				"them. Each vault's tvl is its live equity as of its last deposit, valued in quote so vaults in different spot",
+				'markets can be summed.',
+			],
+			type: {
+				kind: 'struct',
+				fields: [
+					{
+						name: 'protocol',
+						docs: ['The `VaultProtocol::protocol` whose vaults are capped'],
+						type: 'publicKey',
+					},
+					{
+						name: 'maxTvl',
+						docs: [
+							'Max combined tvl of the vaults, 0 for no cap. precision: QUOTE_PRECISION',
+						],
+						type: 'u64',
+					},
+					{
+						name: 'totalTvl',
+						docs: ["Sum of the vaults' tvl. precision: QUOTE_PRECISION"],
+						type: 'u64',
+					},
+					{
+						name: 'bump',
+						type: 'u8',
+					},
+					{
+						name: 'padding',
+						type: {
+							array: ['u8', 7],
+						},
+					},
+					{
+						name: 'vaults',
+						docs: ['Vaults counted towards the cap, unused entries are zeroed'],
+						type: {
+							array: [
+								{
+									defined: 'ProtocolTvlCapVault',
+								},
+								16,
+							],
+						},
+					},
+				],
+			},
+		},
+		{
+			name: 'tokenizedVaultDepositor',
+			type: {
+				kind: 'struct',
+				fields: [
+					{
+						name: 'vault',
+						docs: ['The vault deposited into'],
+						type: 'publicKey',
+					},
+					{
+						name: 'pubkey',
+						docs: [
+							"The vault depositor account's pubkey. It is a pda of vault",
+						],
+						type: 'publicKey',
+					},
+					{
+						name: 'mint',
+						docs: [
+							'The token mint for tokenized shares owned by this VaultDepositor',
+						],
+						type: 'publicKey',
+					},
+					{
+						name: 'vaultShares',
+						docs: [
+							"share of vault owned by this depositor. vault_shares / vault.total_shares is depositor's ownership of vault_equity",
+						],
+						type: 'u128',
+					},
+					{
+						name: 'lastVaultShares',
+						docs: [
+							'stores the vault_shares from the most recent liquidity event (redeem or issuance) before a spl token',
+							'CPI is done, used to track invariants',
+						],
+						type: 'u128',
+					},
+					{
+						name: 'lastValidTs',
+						docs: ['creation ts of vault depositor'],
+						type: 'i64',
+					},
+					{
+						name: 'netDeposits',
+						docs: ['lifetime net deposits of vault depositor for the vault'],
+						type: 'i64',
+					},
+					{
+						name: 'totalDeposits',
+						docs: ['lifetime total deposits'],
+						type: 'u64',
+					},
+					{
+						name: 'totalWithdraws',
+						docs: ['lifetime total withdraws'],
+						type: 'u64',
+					},
+					{
+						name: 'cumulativeProfitShareAmount',
+						docs: [
+							'the token amount of gains the vault depositor has paid performance fees on',
+						],
+						type: 'i64',
+					},
+					{
+						name: 'profitShareFeePaid',
+						type: 'u64',
+					},
+					{
+						name: 'vaultSharesBase',
+						docs: [
+							'The exponent for vault_shares decimal places, kept in step with the vault by apply_rebase_tokenized_depositor.',
+							'Tokens are issued and redeemed at mint supply / vault_shares, so a rebase only changes the shares backing each',
+							'token and the mint keeps its balances and decimals. Tokenizing is blocked until the rebase is applied.',
+						],
+						type: 'u32',
+					},
+					{
+						name: 'bump',
+						docs: ['The bump for the vault pda'],
+						type: 'u8',
+					},
+					{
+						name: 'padding1',
+						type: {
+							array: ['u8', 3],
+						},
+					},
+					{
+						name: 'padding',
+						type: {
+							array: ['u64', 10],
+						},
+					},
+				],
+			},
 		},
 		{
-			code: 6002;
-			name: 'InvalidVaultSharesDetected';
-			msg: 'InvalidVaultSharesDetected';
+			name: 'vaultAuditor',
+			docs: [
+				'A read only key the manager hands to a fund administrator or auditor. The auditor can run the manager gated',
+				'exports, like opening a [`VaultShareSnapshot`](crate::state::VaultShareSnapshot) to enumerate depositors, but',
+				'nothing that changes the vault, its funds or its params. `Pubkey::default()` revokes it.',
+			],
+			type: {
+				kind: 'struct',
+				fields: [
+					{
+						name: 'vault',
+						docs: ['The vault the auditor is for'],
+						type: 'publicKey',
+					},
+					{
+						name: 'auditor',
+						type: 'publicKey',
+					},
+					{
+						name: 'bump',
+						type: 'u8',
+					},
+					{
+						name: 'padding',
+						type: {
+							array: ['u8', 7],
+						},
+					},
+				],
+			},
 		},
 		{
-			code: 6003;
-			name: 'CannotWithdrawBeforeRedeemPeriodEnd';
-			msg: 'CannotWithdrawBeforeRedeemPeriodEnd';
+			name: 'vaultBackstop',
+			docs: [
+				"Insurance pool funded by a slice of the manager's fees that absorbs losses before depositors do. Contributions",
+				"are paid out of the manager's shares into the backstop token account, up to a `max_balance` set by the vault's",
+				'protocol. When the share price falls more than `deductible` below its high water mark, e.g. after bad debt or a',
+				'loss big enough to rebase the vault, the pool deposits into the vault without shares to bring it back up.',
+			],
+			type: {
+				kind: 'struct',
+				fields: [
+					{
+						name: 'vault',
+						docs: ['The vault the pool backs'],
+						type: 'publicKey',
+					},
+					{
+						name: 'lastManagerFees',
+						docs: [
+							'`manager_total_fee` plus `manager_total_profit_share` contributions have accrued up to',
+						],
+						type: 'u64',
+					},
+					{
+						name: 'owed',
+						docs: [
+							'Accrued and not yet moved into the pool, precision: token mint',
+						],
+						type: 'u64',
+					},
+					{
+						name: 'balance',
+						docs: [
+							'Tokens in the backstop token account, precision: token mint',
+						],
+						type: 'u64',
+					},
+					{
+						name: 'maxBalance',
+						docs: [
+							"Max balance, set by the vault's protocol. Contributions accrued beyond it stay with the manager",
+						],
+						type: 'u64',
+					},
+					{
+						name: 'totalContributed',
+						type: 'u64',
+					},
+					{
+						name: 'totalClaimed',
+						type: 'u64',
+					},
+					{
+						name: 'highWaterMark',
+						docs: [
+							"Highest share price seen by the pool's cranks: PRICE_PRECISION. 0 until the first claim crank",
+						],
+						type: 'u128',
+					},
+					{
+						name: 'contributionRate',
+						docs: [
+							'Slice of manager fees contributed, precision: PERCENTAGE_PRECISION',
+						],
+						type: 'u32',
+					},
+					{
+						name: 'deductible',
+						docs: [
+							'Drop from the high water mark depositors absorb before the pool tops up, precision: PERCENTAGE_PRECISION',
+						],
+						type: 'u32',
+					},
+					{
+						name: 'sharesBase',
+						docs: ["The vault's shares_base when high_water_mark was set"],
+						type: 'u32',
+					},
+					{
+						name: 'bump',
+						type: 'u8',
+					},
+					{
+						name: 'padding',
+						type: {
+							array: ['u8', 3],
+						},
+					},
+				],
+			},
 		},
 		{
-			code: 6004;
-			name: 'InvalidVaultWithdraw';
-			msg: 'InvalidVaultWithdraw';
+			name: 'vaultConfig',
+			docs: [
+				"Per vault configuration that doesn't fit in [`Vault`], created by the manager with `initialize_vault_config`.",
+				'Every field defaults to the behavior of vaults without one, so instructions on a vault without',
+				'[`VaultFlag::HasVaultConfig`](crate::state::VaultFlag::HasVaultConfig) use [`VaultConfig::default`], see',
+				'[`VaultConfigProvider`](crate::state::VaultConfigProvider).',
+			],
+			type: {
+				kind: 'struct',
+				fields: [
+					{
+						name: 'vault',
+						docs: ['The vault the config belongs to'],
+						type: 'publicKey',
+					},
+					{
+						name: 'bump',
+						type: 'u8',
+					},
+					{
+						name: 'profitShareMode',
+						docs: ['The [`ProfitShareMode`]. Default is `HighWaterMark`'],
+						type: 'u8',
+					},
+					{
+						name: 'feePolicy',
+						docs: ['The [`FeePolicy`], see [`VaultConfig::fee_policy`]'],
+						type: 'u8',
+					},
+					{
+						name: 'flags',
+						docs: ['Bits of [`VaultConfigFlag`]'],
+						type: 'u8',
+					},
+					{
+						name: 'depositValuation',
+						docs: [
+							'The [`DepositValuation`] deposits are priced at. Default is `FullMark`',
+						],
+						type: 'u8',
+					},
+					{
+						name: 'fuelBoost',
+						docs: [
+							'Max extra fuel for long term depositors, in steps of [`FUEL_BOOST_STEP`], see',
+							'[`VaultConfig::get_fuel_boost_multiplier`]',
+						],
+						type: 'u8',
+					},
+					{
+						name: 'profitShareRealization',
+						docs: ['The [`ProfitShareRealization`]. Default is `Unrealized`'],
+						type: 'u8',
+					},
+					{
+						name: 'padding1',
+						type: {
+							array: ['u8', 1],
+						},
+					},
+					{
+						name: 'protocolFeeTiers',
+						docs: [
+							'Protocol fee schedule that steps down as vault equity grows, see [`ProtocolFeeTier`].',
+							'Tiers are sorted by ascending `tvl_threshold`, unused tiers are zeroed. Set by the protocol with',
+							'`update_vault_protocol_fee_tiers`',
+						],
+						type: {
+							array: [
+								{
+									defined: 'ProtocolFeeTier',
+								},
+								4,
+							],
+						},
+					},
+					{
+						name: 'feeWaiverTvlThreshold',
+						docs: [
+							'Vault equity (in vault spot_market_index) below which the protocol fee is waived, 0 to never waive',
+						],
+						type: 'u64',
+					},
+					{
+						name: 'feeWaiverExitTvlThreshold',
+						docs: [
+							'Vault equity a waived vault must grow back to before the protocol fee is charged again, at least',
+							"`fee_waiver_tvl_threshold` so vaults hovering around it don't flap in and out of the waiver",
+						],
+						type: 'u64',
+					},
+					{
+						name: 'padding',
+						type: {
+							array: ['u64', 4],
+						},
+					},
+				],
+			},
 		},
 		{
-			code: 6005;
-			name: 'InsufficientVaultShares';
-			msg: 'InsufficientVaultShares';
+			name: 'vaultCrystallization',
+			docs: [
+				"Fund level profit share. Instead of tracking each depositor's cost basis, the manager's profit share is charged",
+				"on the share price's gain above the high water mark and paid by minting manager shares, so one crank covers every",
+				'depositor.',
+				'',
+				"Creating it moves the vault's `profit_share` here and zeroes it on the vault, which stops the per depositor",
+				'manager profit share for good since `update_vault` can only lower it. The protocol profit share stays per',
+				"depositor. Depositors who withdraw between two crystallizations don't pay on the gain since the last one.",
+			],
+			type: {
+				kind: 'struct',
+				fields: [
+					{
+						name: 'vault',
+						docs: ['The vault profit share is crystallized for'],
+						type: 'publicKey',
+					},
+					{
+						name: 'highWaterMark',
+						docs: [
+							'Share price the profit share was last crystallized at, net of it: PRICE_PRECISION',
+						],
+						type: 'u128',
+					},
+					{
+						name: 'lastCrystallizationTs',
+						docs: ['ts of the last crystallization'],
+						type: 'i64',
+					},
+					{
+						name: 'profitShare',
+						docs: [
+							"Manager profit share on depositors' share price gains: PERCENTAGE_PRECISION",
+						],
+						type: 'u32',
+					},
+					{
+						name: 'sharesBase',
+						docs: ["The vault's shares_base when high_water_mark was set"],
+						type: 'u32',
+					},
+					{
+						name: 'crystallizationPeriod',
+						docs: ['Minimum seconds between two crystallizations'],
+						type: 'u32',
+					},
+					{
+						name: 'bump',
+						type: 'u8',
+					},
+					{
+						name: 'padding',
+						type: {
+							array: ['u8', 11],
+						},
+					},
+				],
+			},
 		},
 		{
-			code: 6006;
-			name: 'InvalidVaultWithdrawSize';
-			msg: 'InvalidVaultWithdrawSize';
+			name: 'vaultDelegateProposal',
+			docs: [
+				'A delegate change proposed by the manager of a vault under',
+				'[`VaultProtocolFlag::DelegateDualApproval`](crate::state::VaultProtocolFlag), applied once the protocol approves it.',
+			],
+			type: {
+				kind: 'struct',
+				fields: [
+					{
+						name: 'vault',
+						docs: ['The vault the proposal is for'],
+						type: 'publicKey',
+					},
+					{
+						name: 'delegate',
+						docs: ['The proposed delegate'],
+						type: 'publicKey',
+					},
+					{
+						name: 'proposedTs',
+						docs: [
+							'When the delegate was proposed, 0 if there is no pending proposal',
+						],
+						type: 'i64',
+					},
+					{
+						name: 'bump',
+						type: 'u8',
+					},
+					{
+						name: 'padding',
+						type: {
+							array: ['u8', 7],
+						},
+					},
+				],
+			},
 		},
 		{
-			code: 6007;
-			name: 'InvalidVaultForNewDepositors';
-			msg: 'InvalidVaultForNewDepositors';
+			name: 'vaultDepositorAuthorityTransfer',
+			docs: [
+				"A [`VaultDepositor`](crate::state::VaultDepositor)'s pending move to `new_authority`, proposed by its authority",
+				'and accepted by the new one. Vault depositor pdas are derived from the authority, so accepting moves the position',
+				"into the new authority's vault depositor rather than rewriting `authority` in place, see",
+				'[`VaultDepositor::transfer_authority`](crate::state::VaultDepositor::transfer_authority).',
+			],
+			type: {
+				kind: 'struct',
+				fields: [
+					{
+						name: 'vaultDepositor',
+						docs: ['The vault depositor being moved'],
+						type: 'publicKey',
+					},
+					{
+						name: 'newAuthority',
+						docs: ['The authority that has to accept the transfer'],
+						type: 'publicKey',
+					},
+					{
+						name: 'proposedTs',
+						type: 'i64',
+					},
+					{
+						name: 'bump',
+						type: 'u8',
+					},
+					{
+						name: 'padding',
+						type: {
+							array: ['u8', 7],
+						},
+					},
+				],
+			},
 		},
 		{
-			code: 6008;
-			name: 'VaultWithdrawRequestInProgress';
-			msg: 'VaultWithdrawRequestInProgress';
+			name: 'vaultDepositorCap',
+			type: {
+				kind: 'struct',
+				fields: [
+					{
+						name: 'vault',
+						docs: ['The vault the cap is enforced for'],
+						type: 'publicKey',
+					},
+					{
+						name: 'maxDepositors',
+						docs: [
+							'Max number of vault depositors that can be created, checked against `Vault::depositor_count`',
+						],
+						type: 'u32',
+					},
+					{
+						name: 'bump',
+						type: 'u8',
+					},
+					{
+						name: 'padding',
+						type: {
+							array: ['u8', 3],
+						},
+					},
+				],
+			},
 		},
 		{
-			code: 6009;
-			name: 'VaultIsAtCapacity';
-			msg: 'VaultIsAtCapacity';
+			name: 'vaultDepositorIdempotency',
+			type: {
+				kind: 'struct',
+				fields: [
+					{
+						name: 'vaultDepositor',
+						docs: ['The vault depositor the keys are tracked for'],
+						type: 'publicKey',
+					},
+					{
+						name: 'lastKey',
+						docs: ['Last client supplied key that was executed'],
+						type: 'u64',
+					},
+					{
+						name: 'lastKeyTs',
+						docs: ['ts the last key was executed'],
+						type: 'i64',
+					},
+					{
+						name: 'bump',
+						type: 'u8',
+					},
+					{
+						name: 'padding',
+						type: {
+							array: ['u8', 7],
+						},
+					},
+				],
+			},
 		},
 		{
-			code: 6010;
-			name: 'InvalidVaultDepositorInitialization';
-			msg: 'InvalidVaultDepositorInitialization';
+			name: 'vaultDepositorMemo',
+			type: {
+				kind: 'struct',
+				fields: [
+					{
+						name: 'vaultDepositor',
+						docs: ['The vault depositor the memo tags'],
+						type: 'publicKey',
+					},
+					{
+						name: 'memo',
+						docs: [
+							'Free form tag set by the depositor authority, e.g. the hash of an internal reference',
+						],
+						type: {
+							array: ['u8', 32],
+						},
+					},
+					{
+						name: 'lastUpdateTs',
+						docs: ['ts the memo was last updated'],
+						type: 'i64',
+					},
+					{
+						name: 'bump',
+						type: 'u8',
+					},
+					{
+						name: 'padding',
+						type: {
+							array: ['u8', 7],
+						},
+					},
+				],
+			},
 		},
 		{
-			code: 6011;
-			name: 'DelegateNotAvailableForLiquidation';
-			msg: 'DelegateNotAvailableForLiquidation';
+			name: 'vaultDepositorRecordLog',
+			docs: [
+				"Opt-in on-chain mirror of a vault's latest depositor records, so indexers on RPCs that truncate logs can backfill",
+				'missed events by reading the account. Holds the latest [`VAULT_DEPOSITOR_RECORD_LOG_ENTRIES`] deposits, withdraw',
+				'requests, cancels, withdraws and profit share payments, record `n` is in entry',
+				"`n % VAULT_DEPOSITOR_RECORD_LOG_ENTRIES`. Only written when passed in remaining accounts, a gap in the depositor's",
+				'share chain means a record was left out.',
+			],
+			type: {
+				kind: 'struct',
+				fields: [
+					{
+						name: 'vault',
+						docs: ['The vault whose depositor records are logged'],
+						type: 'publicKey',
+					},
+					{
+						name: 'entries',
+						type: {
+							array: [
+								{
+									defined: 'VaultDepositorRecordLogEntry',
+								},
+								32,
+							],
+						},
+					},
+					{
+						name: 'count',
+						docs: ['Records logged since the log was created'],
+						type: 'u64',
+					},
+					{
+						name: 'bump',
+						type: 'u8',
+					},
+					{
+						name: 'padding',
+						type: {
+							array: ['u8', 7],
+						},
+					},
+				],
+			},
 		},
 		{
-			code: 6012;
-			name: 'InvalidEquityValue';
-			msg: 'InvalidEquityValue';
+			name: 'vaultDepositorThrottle',
+			docs: [
+				'Per-depositor rate limits on deposits and withdraw requests, to deter probing the share price and sandwiching',
+				"oracle updates. Enforced once the vault's [`VaultConfig`](crate::state::VaultConfig) has",
+				'[`VaultConfigFlag::DepositorThrottle`](crate::state::VaultConfigFlag::DepositorThrottle). Depositors track their',
+				'last deposit in a [`VaultDepositorThrottleState`], withdraw requests are checked against the [`VaultDepositor`].',
+			],
+			type: {
+				kind: 'struct',
+				fields: [
+					{
+						name: 'vault',
+						docs: ['The vault the throttle is enforced for'],
+						type: 'publicKey',
+					},
+					{
+						name: 'minDepositInterval',
+						docs: [
+							"Min seconds from the depositor's last deposit to its next deposit",
+						],
+						type: 'u32',
+					},
+					{
+						name: 'minRequestInterval',
+						docs: [
+							"Min seconds from the depositor's last withdraw request, cancel or withdraw to its next withdraw request",
+						],
+						type: 'u32',
+					},
+					{
+						name: 'bump',
+						type: 'u8',
+					},
+					{
+						name: 'padding',
+						type: {
+							array: ['u8', 7],
+						},
+					},
+				],
+			},
 		},
 		{
-			code: 6013;
-			name: 'VaultInLiquidation';
-			msg: 'VaultInLiquidation';
+			name: 'vaultDepositorThrottleState',
+			docs: [
+				"A depositor's last deposit into a vault with a [`VaultDepositorThrottle`]. Deposits into such a vault require",
+				"it, depositors that never created one haven't deposited since the throttle was enabled.",
+			],
+			type: {
+				kind: 'struct',
+				fields: [
+					{
+						name: 'vaultDepositor',
+						docs: ['The vault depositor whose deposits are tracked'],
+						type: 'publicKey',
+					},
+					{
+						name: 'lastDepositTs',
+						type: 'i64',
+					},
+					{
+						name: 'bump',
+						type: 'u8',
+					},
+					{
+						name: 'padding',
+						type: {
+							array: ['u8', 7],
+						},
+					},
+				],
+			},
 		},
 		{
-			code: 6014;
-			name: 'DriftError';
-			msg: 'DriftError';
+			name: 'vaultDepositorWithdrawRequests',
+			docs: [
+				'Withdraw requests a depositor has parked out of `last_withdraw_request`, so it can hold several partial requests',
+				"with their own redeem timers. A parked request keeps its shares, value and ts and still counts towards the vault's",
+				"requested totals, it's moved back into `last_withdraw_request` to be withdrawn or cancelled. Once created every",
+				'withdraw request must pass it, see [`VaultDepositorFlag::ParkedWithdrawRequests`], so parked and pending requests',
+				"never take more than the depositor's shares.",
+			],
+			type: {
+				kind: 'struct',
+				fields: [
+					{
+						name: 'vaultDepositor',
+						docs: ['The vault depositor the requests belong to'],
+						type: 'publicKey',
+					},
+					{
+						name: 'requests',
+						type: {
+							array: [
+								{
+									defined: 'WithdrawRequest',
+								},
+								4,
+							],
+						},
+					},
+					{
+						name: 'sharesBase',
+						docs: [
+							"The vault depositor's shares_base when the requests were last updated",
+						],
+						type: 'u32',
+					},
+					{
+						name: 'bump',
+						type: 'u8',
+					},
+					{
+						name: 'padding',
+						type: {
+							array: ['u8', 11],
+						},
+					},
+				],
+			},
 		},
 		{
-			code: 6015;
-			name: 'InvalidVaultInitialization';
-			msg: 'InvalidVaultInitialization';
+			name: 'vaultDepositor',
+			type: {
+				kind: 'struct',
+				fields: [
+					{
+						name: 'vault',
+						docs: ['The vault deposited into'],
+						type: 'publicKey',
+					},
+					{
+						name: 'pubkey',
+						docs: [
+							"The vault depositor account's pubkey. It is a pda of vault and authority",
+						],
+						type: 'publicKey',
+					},
+					{
+						name: 'authority',
+						docs: [
+							'The authority is the address w permission to deposit/withdraw',
+						],
+						type: 'publicKey',
+					},
+					{
+						name: 'vaultShares',
+						docs: [
+							"share of vault owned by this depositor. vault_shares / vault.total_shares is depositor's ownership of vault_equity",
+						],
+						type: 'u128',
+					},
+					{
+						name: 'lastWithdrawRequest',
+						docs: ['last withdraw request'],
+						type: {
+							defined: 'WithdrawRequest',
+						},
+					},
+					{
+						name: 'lastValidTs',
+						docs: ['creation ts of vault depositor'],
+						type: 'i64',
+					},
+					{
+						name: 'netDeposits',
+						docs: ['lifetime net deposits of vault depositor for the vault'],
+						type: 'i64',
+					},
+					{
+						name: 'totalDeposits',
+						docs: ['lifetime total deposits'],
+						type: 'u64',
+					},
+					{
+						name: 'totalWithdraws',
+						docs: ['lifetime total withdraws'],
+						type: 'u64',
+					},
+					{
+						name: 'cumulativeProfitShareAmount',
+						docs: [
+							'the token amount of gains the vault depositor has paid performance fees on',
+						],
+						type: 'i64',
+					},
+					{
+						name: 'profitShareFeePaid',
+						type: 'u64',
+					},
+					{
+						name: 'vaultSharesBase',
+						docs: ['the exponent for vault_shares decimal places'],
+						type: 'u16',
+					},
+					{
+						name: 'version',
+						docs: [
+							'layout version, see [`VAULT_DEPOSITOR_VERSION`]. Accounts created before versioning read as 0',
+						],
+						type: 'u8',
+					},
+					{
+						name: 'flags',
+						docs: ['Bits of [`VaultDepositorFlag`]'],
+						type: 'u8',
+					},
+					{
+						name: 'lastFuelUpdateTs',
+						type: 'u32',
+					},
+					{
+						name: 'cumulativeFuelPerShareAmount',
+						docs: ['precision: FUEL_SHARE_PRECISION'],
+						type: 'u128',
+					},
+					{
+						name: 'fuelAmount',
+						docs: ['precision: none'],
+						type: 'u128',
+					},
+					{
+						name: 'custodian',
+						docs: [
+							'optional key allowed to request and cancel withdraws on behalf of the authority.',
+							'withdraws are still paid to the authority. Pubkey::default() if unset',
+						],
+						type: 'publicKey',
+					},
+				],
+			},
 		},
 		{
-			code: 6016;
-			name: 'InvalidVaultUpdate';
-			msg: 'InvalidVaultUpdate';
+			name: 'vaultDustWithdraw',
+			type: {
+				kind: 'struct',
+				fields: [
+					{
+						name: 'vault',
+						docs: ['The vault dust withdraws are enabled for'],
+						type: 'publicKey',
+					},
+					{
+						name: 'threshold',
+						docs: [
+							'Depositors whose whole balance is at or below this amount can withdraw without a redeem period',
+						],
+						type: 'u64',
+					},
+					{
+						name: 'windowMaxAmount',
+						docs: [
+							"Max total dust withdrawn per [`DUST_WITHDRAW_WINDOW`], so splitting a balance across depositors can't drain",
+							'the vault around the redeem period',
+						],
+						type: 'u64',
+					},
+					{
+						name: 'windowAmount',
+						docs: ['Dust withdrawn in the current window'],
+						type: 'u64',
+					},
+					{
+						name: 'windowStartTs',
+						type: 'i64',
+					},
+					{
+						name: 'bump',
+						type: 'u8',
+					},
+					{
+						name: 'padding',
+						type: {
+							array: ['u8', 7],
+						},
+					},
+				],
+			},
 		},
 		{
-			code: 6017;
-			name: 'PermissionedVault';
-			msg: 'PermissionedVault';
+			name: 'vaultFeeHoliday',
+			docs: [
+				"A window during which the manager's management fee and/or profit share is suspended, e.g. to make depositors",
+				'whole after an incident.',
+				'',
+				'The window is applied by `crank_vault_fee_holiday`, which accrues fees up to the crank and then sets or clears',
+				'the holiday [`VaultFlag`]s that `Vault::apply_fee` and the profit share math check. Anyone can crank, so',
+				'depositors can start a holiday on time and the manager can end it on time.',
+			],
+			type: {
+				kind: 'struct',
+				fields: [
+					{
+						name: 'vault',
+						docs: ['The vault the fee holiday is for'],
+						type: 'publicKey',
+					},
+					{
+						name: 'startTs',
+						docs: ['When the scheduled holiday starts, 0 if none is scheduled'],
+						type: 'i64',
+					},
+					{
+						name: 'endTs',
+						docs: ['When the scheduled holiday ends'],
+						type: 'i64',
+					},
+					{
+						name: 'suspendManagementFee',
+						type: 'bool',
+					},
+					{
+						name: 'suspendProfitShare',
+						type: 'bool',
+					},
+					{
+						name: 'active',
+						docs: [
+							'Whether the holiday has been started by a crank and not yet ended',
+						],
+						type: 'bool',
+					},
+					{
+						name: 'bump',
+						type: 'u8',
+					},
+					{
+						name: 'padding',
+						type: {
+							array: ['u8', 4],
+						},
+					},
+				],
+			},
 		},
 		{
-			code: 6018;
-			name: 'WithdrawInProgress';
-			msg: 'WithdrawInProgress';
+			name: 'vaultFeeSplit',
+			docs: [
+				"A slice of the manager's profit share the manager committed to an external token account, e.g. a charity or a",
+				'burn address. Every profit share crystallized after the split is configured accrues `split` of it as owed, which',
+				"`distribute_vault_fee_split` pays out of the manager's shares.",
+			],
+			type: {
+				kind: 'struct',
+				fields: [
+					{
+						name: 'vault',
+						docs: ['The vault the split is for'],
+						type: 'publicKey',
+					},
+					{
+						name: 'recipient',
+						docs: ['Token account the split is paid to'],
+						type: 'publicKey',
+					},
+					{
+						name: 'lastManagerTotalProfitShare',
+						docs: [
+							'`Vault::manager_total_profit_share` the split has accrued up to',
+						],
+						type: 'u64',
+					},
+					{
+						name: 'owed',
+						docs: ['Accrued and not yet paid, precision: token mint'],
+						type: 'u64',
+					},
+					{
+						name: 'totalPaid',
+						docs: ['Total paid to the recipient, precision: token mint'],
+						type: 'u64',
+					},
+					{
+						name: 'split',
+						docs: [
+							'Slice of manager profit share, precision: PERCENTAGE_PRECISION',
+						],
+						type: 'u32',
+					},
+					{
+						name: 'bump',
+						type: 'u8',
+					},
+					{
+						name: 'padding',
+						type: {
+							array: ['u8', 3],
+						},
+					},
+				],
+			},
 		},
 		{
-			code: 6019;
-			name: 'SharesPercentTooLarge';
-			msg: 'SharesPercentTooLarge';
+			name: 'vaultGuard',
+			docs: [
+				'Safeguards on depositor flows the manager opts into, enforced on every deposit and payout once the vault has one,',
+				'see [`VaultConfigFlag::VaultGuard`](crate::state::VaultConfigFlag::VaultGuard), independently of the',
+				'[`VaultDepositorThrottle`](crate::state::VaultDepositorThrottle). Deposits and withdraws are rejected while',
+				"the deposit asset's oracle price is too far from its TWAP, so they can't be priced off an oracle spike, and must",
+				'pass the companion accounts marked in its [`GuardFlag`]s. Deposits worth less than the USD minimum are rejected.',
+			],
+			type: {
+				kind: 'struct',
+				fields: [
+					{
+						name: 'vault',
+						docs: ['The vault the guard is enforced for'],
+						type: 'publicKey',
+					},
+					{
+						name: 'maxOracleTwapDeviationBps',
+						docs: [
+							"Max deviation of the deposit asset's oracle price from its TWAP for deposits and withdraws, in bps. 0 disables",
+							'the check',
+						],
+						type: 'u16',
+					},
+					{
+						name: 'bump',
+						type: 'u8',
+					},
+					{
+						name: 'flags',
+						docs: ['Bits of [`GuardFlag`]'],
+						type: 'u8',
+					},
+					{
+						name: 'minDepositUsd',
+						docs: [
+							"Min deposit value in whole USD at the deposit oracle price, on top of the vault's `min_deposit_amount`. 0",
+							'disables it',
+						],
+						type: 'u32',
+					},
+					{
+						name: 'padding',
+						type: {
+							array: ['u8', 8],
+						},
+					},
+				],
+			},
 		},
 		{
-			code: 6020;
-			name: 'InvalidVaultDeposit';
-			msg: 'InvalidVaultDeposit';
+			name: 'vaultHook',
+			type: {
+				kind: 'struct',
+				fields: [
+					{
+						name: 'vault',
+						docs: ['The vault this hook is configured for'],
+						type: 'publicKey',
+					},
+					{
+						name: 'hookProgram',
+						docs: [
+							"The program CPI'd before and after depositor deposits and withdrawals.",
+							'Pubkey::default() disables the hook.',
+						],
+						type: 'publicKey',
+					},
+					{
+						name: 'bump',
+						type: 'u8',
+					},
+					{
+						name: 'padding',
+						type: {
+							array: ['u8', 7],
+						},
+					},
+				],
+			},
 		},
 		{
-			code: 6021;
-			name: 'OngoingLiquidation';
-			msg: 'OngoingLiquidation';
+			name: 'vaultInterface',
+			type: {
+				kind: 'struct',
+				fields: [
+					{
+						name: 'vault',
+						docs: ['The vault the interface routes deposits and withdraws to'],
+						type: 'publicKey',
+					},
+					{
+						name: 'interface',
+						docs: [
+							"The front-end operator's key, passed as `interface` on deposits and withdraws",
+						],
+						type: 'publicKey',
+					},
+					{
+						name: 'attributedDeposits',
+						docs: ['lifetime deposits attributed to the interface'],
+						type: 'u64',
+					},
+					{
+						name: 'attributedWithdraws',
+						docs: ['lifetime withdraws attributed to the interface'],
+						type: 'u64',
+					},
+					{
+						name: 'accruedFee',
+						docs: [
+							"fees owed to the interface, paid out of the manager's shares on claim",
+						],
+						type: 'u64',
+					},
+					{
+						name: 'totalFeePaid',
+						docs: ['lifetime fees paid to the interface'],
+						type: 'u64',
+					},
+					{
+						name: 'feeRate',
+						docs: [
+							'fee the manager pays on attributed volume, precision: PERCENTAGE_PRECISION',
+						],
+						type: 'u32',
+					},
+					{
+						name: 'bump',
+						type: 'u8',
+					},
+					{
+						name: 'padding',
+						type: {
+							array: ['u8', 3],
+						},
+					},
+				],
+			},
 		},
 		{
-			code: 6022;
-			name: 'VaultProtocolMissing';
-			msg: 'VaultProtocolMissing';
+			name: 'vaultLockup',
+			docs: [
+				"Min time from a depositor's last deposit to its next withdraw request, for strategies with a longer horizon than",
+				'the redeem period, which only starts at the request. Depositors track their last deposit in a',
+				"[`VaultDepositorLockup`]. Enforced through the vault's [`VaultGuard`](crate::state::VaultGuard), regardless of the",
+				'[`VaultDepositorThrottle`](crate::state::VaultDepositorThrottle).',
+			],
+			type: {
+				kind: 'struct',
+				fields: [
+					{
+						name: 'vault',
+						docs: ['The vault the lockup is enforced for'],
+						type: 'publicKey',
+					},
+					{
+						name: 'lockupPeriod',
+						docs: [
+							"Min seconds from a depositor's last deposit to its next withdraw request",
+						],
+						type: 'u32',
+					},
+					{
+						name: 'bump',
+						type: 'u8',
+					},
+					{
+						name: 'padding',
+						type: {
+							array: ['u8', 3],
+						},
+					},
+				],
+			},
 		},
 		{
-			code: 6023;
-			name: 'InvalidTokenization';
-			msg: 'InvalidTokenization';
+			name: 'vaultDepositorLockup',
+			docs: [
+				"A depositor's last deposit into a vault with a [`VaultLockup`]. Deposits into such a vault require it, deposits",
+				"made before the depositor created it aren't locked up, nor are depositors that never created one.",
+			],
+			type: {
+				kind: 'struct',
+				fields: [
+					{
+						name: 'vaultDepositor',
+						docs: ['The vault depositor whose deposits are tracked'],
+						type: 'publicKey',
+					},
+					{
+						name: 'lastDepositTs',
+						type: 'i64',
+					},
+					{
+						name: 'bump',
+						type: 'u8',
+					},
+					{
+						name: 'padding',
+						type: {
+							array: ['u8', 7],
+						},
+					},
+				],
+			},
 		},
 		{
-			code: 6024;
-			name: 'InvalidFuelDistributionMode';
-			msg: 'InvalidFuelDistributionMode';
-		}
-	];
-};
-
-export const IDL: DriftVaults = {
-	version: '0.6.0',
-	name: 'drift_vaults',
-	instructions: [
-		{
-			name: 'initializeVault',
-			accounts: [
-				{
-					name: 'vault',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'tokenAccount',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftUserStats',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftUser',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftState',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftSpotMarket',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'driftSpotMarketMint',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'manager',
-					isMut: false,
-					isSigner: true,
-				},
-				{
-					name: 'payer',
-					isMut: true,
-					isSigner: true,
-				},
-				{
-					name: 'rent',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'systemProgram',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'driftProgram',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'tokenProgram',
-					isMut: false,
-					isSigner: false,
-				},
+			name: 'vaultManagerActivity',
+			docs: [
+				'Tracks when the manager was last active so depositors can exit with `inactive_manager_withdraw`, skipping the',
+				'redeem period, once the manager has gone quiet for `inactivity_period`. `manager_heartbeat` and manager signed',
+				"instructions passing it in remaining_accounts refresh it, and fills by the vault's delegate count as activity",
+				'through the drift user stats.',
 			],
-			args: [
-				{
-					name: 'params',
-					type: {
-						defined: 'VaultParams',
+			type: {
+				kind: 'struct',
+				fields: [
+					{
+						name: 'vault',
+						docs: ['The vault the manager activity is tracked for'],
+						type: 'publicKey',
 					},
-				},
+					{
+						name: 'lastManagerActivityTs',
+						docs: ['ts of the last manager signed action or heartbeat'],
+						type: 'i64',
+					},
+					{
+						name: 'inactivityPeriod',
+						docs: [
+							'Seconds without manager activity or delegate fills before depositors can exit early',
+						],
+						type: 'u32',
+					},
+					{
+						name: 'bump',
+						type: 'u8',
+					},
+					{
+						name: 'padding',
+						type: {
+							array: ['u8', 3],
+						},
+					},
+				],
+			},
+		},
+		{
+			name: 'vaultManagerFeeClaim',
+			docs: [
+				"Defers the vault's management fee into a claimable share balance so it's minted in bounded steps instead of",
+				'all at once.',
+				'',
+				"Once initialized the vault's `management_fee` is moved here and zeroed on the [`Vault`], so `Vault::apply_fee`",
+				'stops minting it. `claim_vault_manager_fee` accrues the fee since the last claim as shares, at the same rate',
+				"`Vault::apply_fee` would have minted them, and mints at most `claim_cap` of the vault's total shares per",
+				'[`MANAGER_FEE_CLAIM_PERIOD`]. Anything over the cap rolls forward to the next period.',
 			],
+			type: {
+				kind: 'struct',
+				fields: [
+					{
+						name: 'vault',
+						docs: ['The vault the fee claim is for'],
+						type: 'publicKey',
+					},
+					{
+						name: 'accruedShares',
+						docs: ['Management fee shares accrued and not yet minted'],
+						type: 'u128',
+					},
+					{
+						name: 'periodClaimedShares',
+						docs: ['Shares minted in the current claim period'],
+						type: 'u128',
+					},
+					{
+						name: 'periodStartTs',
+						docs: ['When the current claim period started'],
+						type: 'i64',
+					},
+					{
+						name: 'lastAccrueTs',
+						docs: ['When the management fee was last accrued'],
+						type: 'i64',
+					},
+					{
+						name: 'managementFee',
+						docs: [
+							'The annual management fee moved off the vault: PERCENTAGE_PRECISION',
+						],
+						type: 'i64',
+					},
+					{
+						name: 'claimCap',
+						docs: [
+							"Most of the vault's total shares that can be minted per claim period: PERCENTAGE_PRECISION",
+						],
+						type: 'u32',
+					},
+					{
+						name: 'sharesBase',
+						docs: [
+							'The vault `shares_base` the share balances are denominated in',
+						],
+						type: 'u32',
+					},
+					{
+						name: 'bump',
+						type: 'u8',
+					},
+					{
+						name: 'padding',
+						type: {
+							array: ['u8', 15],
+						},
+					},
+				],
+			},
 		},
 		{
-			name: 'initializeVaultWithProtocol',
-			accounts: [
-				{
-					name: 'vault',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'vaultProtocol',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'tokenAccount',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftUserStats',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftUser',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftState',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftSpotMarket',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'driftSpotMarketMint',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'manager',
-					isMut: false,
-					isSigner: true,
-				},
-				{
-					name: 'payer',
-					isMut: true,
-					isSigner: true,
-				},
-				{
-					name: 'rent',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'systemProgram',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'driftProgram',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'tokenProgram',
-					isMut: false,
-					isSigner: false,
-				},
+			name: 'vaultParamChangeLog',
+			docs: [
+				"On-chain history of a vault's parameter updates, kept alongside the [`VaultParamChangeRecord`] events since RPC",
+				'providers truncate old transaction logs. Holds the latest [`VAULT_PARAM_CHANGE_LOG_ENTRIES`] changes, change `n`',
+				'is in entry `n % VAULT_PARAM_CHANGE_LOG_ENTRIES`.',
 			],
-			args: [
-				{
-					name: 'params',
-					type: {
-						defined: 'VaultWithProtocolParams',
+			type: {
+				kind: 'struct',
+				fields: [
+					{
+						name: 'vault',
+						docs: ['The vault whose parameter changes are logged'],
+						type: 'publicKey',
+					},
+					{
+						name: 'count',
+						docs: ['Changes recorded since the log was created'],
+						type: 'u64',
+					},
+					{
+						name: 'entries',
+						type: {
+							array: [
+								{
+									defined: 'VaultParamChange',
+								},
+								32,
+							],
+						},
+					},
+					{
+						name: 'bump',
+						type: 'u8',
+					},
+					{
+						name: 'padding',
+						type: {
+							array: ['u8', 7],
+						},
+					},
+				],
+			},
+		},
+		{
+			name: 'vaultPerformanceStats',
+			type: {
+				kind: 'struct',
+				fields: [
+					{
+						name: 'vault',
+						docs: ['The vault these stats are for'],
+						type: 'publicKey',
+					},
+					{
+						name: 'lastEquityPerShare',
+						docs: [
+							'Equity per share at the end of the last period, precision: PERCENTAGE_PRECISION',
+						],
+						type: 'u128',
+					},
+					{
+						name: 'lastPeriodTs',
+						docs: ['ts the last period ended, 0 before the first crank'],
+						type: 'i64',
+					},
+					{
+						name: 'currentStreak',
+						docs: ['Consecutive periods equity per share increased'],
+						type: 'u32',
+					},
+					{
+						name: 'longestStreak',
+						docs: [
+							'Longest run of consecutive periods equity per share increased',
+						],
+						type: 'u32',
+					},
+					{
+						name: 'totalPeriods',
+						docs: ['Periods recorded'],
+						type: 'u32',
+					},
+					{
+						name: 'positivePeriods',
+						docs: ['Periods equity per share increased'],
+						type: 'u32',
+					},
+					{
+						name: 'sharesBase',
+						docs: [
+							"The vault's shares_base when last_equity_per_share was set",
+						],
+						type: 'u32',
+					},
+					{
+						name: 'bump',
+						type: 'u8',
+					},
+					{
+						name: 'padding',
+						type: {
+							array: ['u8', 19],
+						},
+					},
+					{
+						name: 'lastCumulativeDepositInterest',
+						docs: [
+							"The vault's spot market `cumulative_deposit_interest` at the end of the last period",
+						],
+						type: 'u128',
+					},
+					{
+						name: 'lastDepositorEquity',
+						docs: [
+							"Depositors' share of vault equity at the end of the last period",
+						],
+						type: 'u64',
+					},
+					{
+						name: 'lastNetDeposits',
+						docs: ["The vault's `net_deposits` at the end of the last period"],
+						type: 'i64',
+					},
+					{
+						name: 'lastManagerFees',
+						docs: [
+							"The vault's `manager_total_fee` plus `manager_total_profit_share` at the end of the last period",
+						],
+						type: 'u64',
+					},
+					{
+						name: 'lastSpotTokenAmount',
+						docs: [
+							"The drift user's signed token amount in the vault's spot market at the end of the last period",
+						],
+						type: 'i64',
+					},
+					{
+						name: 'lastCumulativeBorrowInterest',
+						docs: [
+							"The vault's spot market `cumulative_borrow_interest` at the end of the last period",
+						],
+						type: 'u128',
+					},
+					{
+						name: 'lastVaultEquity',
+						docs: ['Vault equity at the end of the last period'],
+						type: 'u64',
+					},
+					{
+						name: 'lastManagerNetDeposits',
+						docs: [
+							"The vault's `manager_net_deposits` at the end of the last period",
+						],
+						type: 'i64',
+					},
+					{
+						name: 'periodInterestPnl',
+						docs: [
+							"Lend interest earned, less borrow interest paid, on the vault's spot market balance over the last period",
+						],
+						type: 'i64',
+					},
+					{
+						name: 'periodTradingPnl',
+						docs: [
+							"Vault pnl over the last period that isn't [`Self::period_interest_pnl`]",
+						],
+						type: 'i64',
+					},
+					{
+						name: 'cumulativeInterestPnl',
+						docs: [
+							'Sum of [`Self::period_interest_pnl`] over every attributed period',
+						],
+						type: 'i64',
+					},
+					{
+						name: 'cumulativeTradingPnl',
+						docs: [
+							'Sum of [`Self::period_trading_pnl`] over every attributed period',
+						],
+						type: 'i64',
+					},
+					{
+						name: 'padding1',
+						type: {
+							array: ['u8', 16],
+						},
 					},
-				},
-			],
-		},
-		{
-			name: 'updateDelegate',
-			accounts: [
-				{
-					name: 'vault',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'manager',
-					isMut: false,
-					isSigner: true,
-				},
-				{
-					name: 'driftUser',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftProgram',
-					isMut: false,
-					isSigner: false,
-				},
-			],
-			args: [
-				{
-					name: 'delegate',
-					type: 'publicKey',
-				},
-			],
-		},
-		{
-			name: 'updateMarginTradingEnabled',
-			accounts: [
-				{
-					name: 'vault',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'manager',
-					isMut: false,
-					isSigner: true,
-				},
-				{
-					name: 'driftUser',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftProgram',
-					isMut: false,
-					isSigner: false,
-				},
-			],
-			args: [
-				{
-					name: 'enabled',
-					type: 'bool',
-				},
-			],
-		},
-		{
-			name: 'updateUserPoolId',
-			accounts: [
-				{
-					name: 'vault',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'manager',
-					isMut: false,
-					isSigner: true,
-				},
-				{
-					name: 'driftUser',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftProgram',
-					isMut: false,
-					isSigner: false,
-				},
-			],
-			args: [
-				{
-					name: 'poolId',
-					type: 'u8',
-				},
-			],
+				],
+			},
 		},
 		{
-			name: 'updateVaultProtocol',
-			accounts: [
-				{
-					name: 'vault',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'protocol',
-					isMut: false,
-					isSigner: true,
-				},
-				{
-					name: 'vaultProtocol',
-					isMut: true,
-					isSigner: false,
-				},
-			],
-			args: [
-				{
-					name: 'params',
-					type: {
-						defined: 'UpdateVaultProtocolParams',
+			name: 'vaultPoll',
+			type: {
+				kind: 'struct',
+				fields: [
+					{
+						name: 'vault',
+						docs: ['The vault being voted on'],
+						type: 'publicKey',
 					},
-				},
-			],
+					{
+						name: 'newManager',
+						docs: [
+							'The manager proposed by a [`VaultPollProposal::ReplaceManager`] poll',
+						],
+						type: 'publicKey',
+					},
+					{
+						name: 'votesFor',
+						docs: [
+							'Depositor shares voting for the proposal, snapshotted at each vote',
+						],
+						type: 'u128',
+					},
+					{
+						name: 'totalShares',
+						docs: [
+							"The vault's user shares when the poll started, quorum is measured against this",
+						],
+						type: 'u128',
+					},
+					{
+						name: 'startTs',
+						type: 'i64',
+					},
+					{
+						name: 'endTs',
+						type: 'i64',
+					},
+					{
+						name: 'pollId',
+						docs: ['Incremented every poll, 0 if no poll has been started'],
+						type: 'u32',
+					},
+					{
+						name: 'sharesBase',
+						docs: ["The vault's shares_base when the poll started"],
+						type: 'u32',
+					},
+					{
+						name: 'proposal',
+						docs: ['[`VaultPollProposal`]'],
+						type: 'u8',
+					},
+					{
+						name: 'executed',
+						type: 'bool',
+					},
+					{
+						name: 'bump',
+						type: 'u8',
+					},
+					{
+						name: 'padding',
+						type: {
+							array: ['u8', 5],
+						},
+					},
+				],
+			},
 		},
 		{
-			name: 'updateVault',
-			accounts: [
-				{
-					name: 'vault',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'manager',
-					isMut: false,
-					isSigner: true,
-				},
+			name: 'vaultPollVote',
+			docs: [
+				"A depositor's vote on a [`VaultPoll`], one per depositor per poll.",
 			],
-			args: [
-				{
-					name: 'params',
-					type: {
-						defined: 'UpdateVaultParams',
+			type: {
+				kind: 'struct',
+				fields: [
+					{
+						name: 'vaultPoll',
+						type: 'publicKey',
 					},
-				},
-			],
+					{
+						name: 'authority',
+						type: 'publicKey',
+					},
+					{
+						name: 'shares',
+						docs: ["The depositor's shares at vote time"],
+						type: 'u128',
+					},
+					{
+						name: 'ts',
+						type: 'i64',
+					},
+					{
+						name: 'pollId',
+						type: 'u32',
+					},
+					{
+						name: 'bump',
+						type: 'u8',
+					},
+					{
+						name: 'padding',
+						type: {
+							array: ['u8', 3],
+						},
+					},
+				],
+			},
 		},
 		{
-			name: 'updateVaultManager',
-			accounts: [
-				{
-					name: 'vault',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'manager',
-					isMut: false,
-					isSigner: true,
-				},
-			],
-			args: [
-				{
-					name: 'manager',
-					type: 'publicKey',
-				},
+			name: 'vaultProfitShareCadence',
+			docs: [
+				"Min time between two profit share crystallizations of a depositor's position by `apply_profit_share` cranks and",
+				"`realize_depositor_profits`, so profit share isn't locked in on every transient spike. Depositors track their last",
+				'one in a [`VaultDepositorProfitShareCadence`]. Deposits and withdraw requests still charge profit share as they',
+				"need to. Enforced through the vault's [`VaultGuard`](crate::state::VaultGuard), regardless of the",
+				'[`VaultDepositorThrottle`](crate::state::VaultDepositorThrottle).',
 			],
+			type: {
+				kind: 'struct',
+				fields: [
+					{
+						name: 'vault',
+						docs: ['The vault the cadence is enforced for'],
+						type: 'publicKey',
+					},
+					{
+						name: 'minProfitShareInterval',
+						docs: [
+							"Min seconds between two crystallizations of a depositor's profit share",
+						],
+						type: 'u32',
+					},
+					{
+						name: 'bump',
+						type: 'u8',
+					},
+					{
+						name: 'padding',
+						type: {
+							array: ['u8', 3],
+						},
+					},
+				],
+			},
 		},
 		{
-			name: 'updateCumulativeFuelAmount',
-			accounts: [
-				{
-					name: 'vault',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'vaultDepositor',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'signer',
-					isMut: false,
-					isSigner: true,
-				},
-				{
-					name: 'driftUserStats',
-					isMut: true,
-					isSigner: false,
-				},
+			name: 'vaultDepositorProfitShareCadence',
+			docs: [
+				"A depositor's last profit share crystallization in a vault with a [`VaultProfitShareCadence`]. Anyone can create",
+				"it, so cranks aren't blocked on the depositor.",
 			],
-			args: [],
+			type: {
+				kind: 'struct',
+				fields: [
+					{
+						name: 'vaultDepositor',
+						docs: ['The vault depositor whose crystallizations are tracked'],
+						type: 'publicKey',
+					},
+					{
+						name: 'lastProfitShareTs',
+						type: 'i64',
+					},
+					{
+						name: 'bump',
+						type: 'u8',
+					},
+					{
+						name: 'padding',
+						type: {
+							array: ['u8', 7],
+						},
+					},
+				],
+			},
 		},
 		{
-			name: 'initializeVaultDepositor',
-			accounts: [
-				{
-					name: 'vault',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'vaultDepositor',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'authority',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'payer',
-					isMut: true,
-					isSigner: true,
-				},
-				{
-					name: 'rent',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'systemProgram',
-					isMut: false,
-					isSigner: false,
-				},
+			name: 'vaultProfitShareHistory',
+			docs: [
+				"Rolling checkpoints of the manager's realized profit share, so a manager lowering `profit_share` can refund the",
+				'difference on what was realized since the oldest checkpoint, between three and four',
+				'[`PROFIT_SHARE_CHECKPOINT_PERIOD`]s back. The refund burns manager shares, so it accrues to everyone holding',
+				"shares when it's applied rather than to the depositors who paid the profit share.",
 			],
-			args: [],
+			type: {
+				kind: 'struct',
+				fields: [
+					{
+						name: 'vault',
+						docs: ['The vault the checkpoints are for'],
+						type: 'publicKey',
+					},
+					{
+						name: 'checkpoints',
+						docs: ['Oldest first'],
+						type: {
+							array: [
+								{
+									defined: 'ProfitShareCheckpoint',
+								},
+								4,
+							],
+						},
+					},
+					{
+						name: 'totalRefunded',
+						docs: ['Total refunded to shareholders, precision: token mint'],
+						type: 'u64',
+					},
+					{
+						name: 'bump',
+						type: 'u8',
+					},
+					{
+						name: 'padding',
+						type: {
+							array: ['u8', 7],
+						},
+					},
+				],
+			},
 		},
 		{
-			name: 'initializeTokenizedVaultDepositor',
-			accounts: [
-				{
-					name: 'vault',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'vaultDepositor',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'mintAccount',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'metadataAccount',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'payer',
-					isMut: true,
-					isSigner: true,
-				},
-				{
-					name: 'tokenProgram',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'tokenMetadataProgram',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'rent',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'systemProgram',
-					isMut: false,
-					isSigner: false,
-				},
+			name: 'vaultProtocol',
+			type: {
+				kind: 'struct',
+				fields: [
+					{
+						name: 'protocol',
+						docs: [
+							'The protocol, company, or entity that services the product using this vault.',
+							'The protocol is not allowed to deposit into the vault but can profit share and collect annual fees just like the manager.',
+						],
+						type: 'publicKey',
+					},
+					{
+						name: 'protocolProfitAndFeeShares',
+						docs: [
+							'The shares from profit share and annual fee unclaimed by the protocol.',
+						],
+						type: 'u128',
+					},
+					{
+						name: 'protocolFee',
+						docs: [
+							'The annual fee charged on deposits by the protocol (traditional hedge funds typically charge 2% per year on assets under management).',
+							"Unlike the management fee this can't be negative.",
+						],
+						type: 'u64',
+					},
+					{
+						name: 'protocolTotalWithdraws',
+						docs: ['Total withdraws for the protocol'],
+						type: 'u64',
+					},
+					{
+						name: 'protocolTotalFee',
+						docs: [
+							'Total fee charged by the protocol (annual management fee + profit share).',
+							"Unlike the management fee this can't be negative.",
+						],
+						type: 'u64',
+					},
+					{
+						name: 'protocolTotalProfitShare',
+						docs: ['Total profit share charged by the protocol'],
+						type: 'u64',
+					},
+					{
+						name: 'lastProtocolWithdrawRequest',
+						type: {
+							defined: 'WithdrawRequest',
+						},
+					},
+					{
+						name: 'protocolProfitShare',
+						docs: [
+							'Percentage the protocol charges on all profits realized by depositors: PERCENTAGE_PRECISION',
+						],
+						type: 'u32',
+					},
+					{
+						name: 'bump',
+						type: 'u8',
+					},
+					{
+						name: 'version',
+						type: 'u8',
+					},
+					{
+						name: 'flags',
+						docs: ['Bits of [`VaultProtocolFlag`]'],
+						type: 'u8',
+					},
+					{
+						name: 'padding',
+						type: {
+							array: ['u8', 1],
+						},
+					},
+				],
+			},
+		},
+		{
+			name: 'vaultRedeemSchedule',
+			docs: [
+				'Shorter redeem periods for smaller withdraw requests. Requests larger than every bucket, or made without the',
+				"schedule in remaining_accounts, wait the vault's full `redeem_period`, so leaving it out never helps a depositor.",
 			],
-			args: [
-				{
-					name: 'params',
-					type: {
-						defined: 'InitializeTokenizedVaultDepositorParams',
+			type: {
+				kind: 'struct',
+				fields: [
+					{
+						name: 'vault',
+						type: 'publicKey',
+					},
+					{
+						name: 'buckets',
+						docs: [
+							'Sorted by ascending `max_withdraw_share`, unused buckets are zeroed',
+						],
+						type: {
+							array: [
+								{
+									defined: 'RedeemPeriodBucket',
+								},
+								4,
+							],
+						},
 					},
-				},
-			],
+					{
+						name: 'bump',
+						type: 'u8',
+					},
+					{
+						name: 'padding',
+						type: {
+							array: ['u8', 7],
+						},
+					},
+				],
+			},
 		},
 		{
-			name: 'tokenizeShares',
-			accounts: [
-				{
-					name: 'vault',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'vaultDepositor',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'authority',
-					isMut: false,
-					isSigner: true,
-				},
-				{
-					name: 'tokenizedVaultDepositor',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'mint',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'userTokenAccount',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'driftUser',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'tokenProgram',
-					isMut: false,
-					isSigner: false,
-				},
+			name: 'vaultRegistry',
+			docs: [
+				'Program wide count of registered vaults. Vaults are listed in order in [`VaultRegistryPage`]s, vault `n` is',
+				'entry `n % VAULT_REGISTRY_PAGE_ENTRIES` of page `n / VAULT_REGISTRY_PAGE_ENTRIES`.',
 			],
-			args: [
-				{
-					name: 'amount',
-					type: 'u64',
-				},
-				{
-					name: 'unit',
-					type: {
-						defined: 'WithdrawUnit',
+			type: {
+				kind: 'struct',
+				fields: [
+					{
+						name: 'totalVaults',
+						type: 'u64',
 					},
-				},
-			],
+					{
+						name: 'bump',
+						type: 'u8',
+					},
+					{
+						name: 'padding',
+						type: {
+							array: ['u8', 7],
+						},
+					},
+				],
+			},
 		},
 		{
-			name: 'redeemTokens',
-			accounts: [
-				{
-					name: 'vault',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'vaultDepositor',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'authority',
-					isMut: false,
-					isSigner: true,
-				},
-				{
-					name: 'tokenizedVaultDepositor',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'mint',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'userTokenAccount',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'vaultTokenAccount',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftUser',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'tokenProgram',
-					isMut: false,
-					isSigner: false,
-				},
-			],
-			args: [
-				{
-					name: 'tokensToBurn',
-					type: 'u64',
-				},
+			name: 'vaultRegistryPage',
+			type: {
+				kind: 'struct',
+				fields: [
+					{
+						name: 'entries',
+						type: {
+							array: [
+								{
+									defined: 'VaultRegistryEntry',
+								},
+								32,
+							],
+						},
+					},
+					{
+						name: 'pageIndex',
+						type: 'u32',
+					},
+					{
+						name: 'len',
+						docs: ['Number of `entries` filled'],
+						type: 'u32',
+					},
+					{
+						name: 'bump',
+						type: 'u8',
+					},
+					{
+						name: 'padding',
+						type: {
+							array: ['u8', 3],
+						},
+					},
+				],
+			},
+		},
+		{
+			name: 'vaultReport',
+			docs: [
+				"The manager's commitment to the hash of their off-chain report for one accounting `epoch`, so depositors can",
+				"check a published report matches. One account per vault and epoch, a commitment can't be changed.",
 			],
+			type: {
+				kind: 'struct',
+				fields: [
+					{
+						name: 'vault',
+						docs: ['The vault the report is for'],
+						type: 'publicKey',
+					},
+					{
+						name: 'reportHash',
+						docs: [
+							'Hash of the report document, e.g. sha256 of the published pdf',
+						],
+						type: {
+							array: ['u8', 32],
+						},
+					},
+					{
+						name: 'committedTs',
+						docs: ['When the hash was committed'],
+						type: 'i64',
+					},
+					{
+						name: 'epoch',
+						docs: [
+							'Manager chosen accounting period, e.g. 202610 for october 2026',
+						],
+						type: 'u32',
+					},
+					{
+						name: 'bump',
+						type: 'u8',
+					},
+					{
+						name: 'padding',
+						type: {
+							array: ['u8', 3],
+						},
+					},
+				],
+			},
+		},
+		{
+			name: 'vaultRiskScore',
+			type: {
+				kind: 'struct',
+				fields: [
+					{
+						name: 'vault',
+						docs: ['The vault this risk score is for'],
+						type: 'publicKey',
+					},
+					{
+						name: 'lastDelegate',
+						docs: [
+							'The vault delegate at the last update, used to track delegate turnover',
+						],
+						type: 'publicKey',
+					},
+					{
+						name: 'peakEquityPerShare',
+						docs: [
+							'Highest equity per share seen, precision: PERCENTAGE_PRECISION',
+						],
+						type: 'u128',
+					},
+					{
+						name: 'lastUpdateTs',
+						type: 'i64',
+					},
+					{
+						name: 'lastDelegateChangeTs',
+						type: 'i64',
+					},
+					{
+						name: 'leverage',
+						docs: [
+							'Total liabilities / equity, precision: PERCENTAGE_PRECISION',
+						],
+						type: 'u64',
+					},
+					{
+						name: 'concentration',
+						docs: [
+							'Largest single position liability / total liabilities, precision: PERCENTAGE_PRECISION',
+						],
+						type: 'u32',
+					},
+					{
+						name: 'drawdown',
+						docs: [
+							'Drop in equity per share from the peak, precision: PERCENTAGE_PRECISION',
+						],
+						type: 'u32',
+					},
+					{
+						name: 'sharesBase',
+						docs: [
+							"The vault's shares_base when peak_equity_per_share was set",
+						],
+						type: 'u32',
+					},
+					{
+						name: 'delegateChanges',
+						docs: [
+							'Delegate changes within [`DELEGATE_TURNOVER_WINDOW`] of the last change',
+						],
+						type: 'u16',
+					},
+					{
+						name: 'riskScore',
+						docs: ['0 (lowest risk) to 100'],
+						type: 'u8',
+					},
+					{
+						name: 'riskBand',
+						docs: ['[`RiskBand`]'],
+						type: 'u8',
+					},
+					{
+						name: 'bump',
+						type: 'u8',
+					},
+					{
+						name: 'padding',
+						type: {
+							array: ['u8', 7],
+						},
+					},
+				],
+			},
 		},
 		{
-			name: 'deposit',
-			accounts: [
-				{
-					name: 'vault',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'vaultDepositor',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'authority',
-					isMut: false,
-					isSigner: true,
-				},
-				{
-					name: 'vaultTokenAccount',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftUserStats',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftUser',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftState',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'driftSpotMarketVault',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'userTokenAccount',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftProgram',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'tokenProgram',
-					isMut: false,
-					isSigner: false,
-				},
+			name: 'vaultShareClass',
+			docs: [
+				"The vault's locked share class. Depositors can convert liquid shares into the class for a discount on the",
+				"manager's profit share, in exchange for not being able to convert them back, or withdraw them, until",
+				'`lock_period` after their last conversion in.',
 			],
-			args: [
-				{
-					name: 'amount',
-					type: 'u64',
-				},
+			type: {
+				kind: 'struct',
+				fields: [
+					{
+						name: 'vault',
+						docs: ['The vault the share class is for'],
+						type: 'publicKey',
+					},
+					{
+						name: 'lockedShares',
+						docs: ['Shares held in the locked class across all depositors'],
+						type: 'u128',
+					},
+					{
+						name: 'lockPeriod',
+						docs: ['How long shares converted into the class are locked for'],
+						type: 'i64',
+					},
+					{
+						name: 'profitShareDiscount',
+						docs: [
+							'Discount on the manager profit share charged on locked shares: PERCENTAGE_PRECISION',
+						],
+						type: 'u32',
+					},
+					{
+						name: 'sharesBase',
+						docs: ['The vault `shares_base` `locked_shares` is denominated in'],
+						type: 'u32',
+					},
+					{
+						name: 'bump',
+						type: 'u8',
+					},
+					{
+						name: 'padding',
+						type: {
+							array: ['u8', 15],
+						},
+					},
+				],
+			},
+		},
+		{
+			name: 'vaultDepositorShareClass',
+			docs: [
+				"A vault depositor's shares in the vault's locked [`VaultShareClass`]. Holds the shares, and the cost basis moved",
+				"with them, outside the [`VaultDepositor`](crate::state::VaultDepositor) so they can't be requested for withdraw",
+				'while locked.',
 			],
+			type: {
+				kind: 'struct',
+				fields: [
+					{
+						name: 'pubkey',
+						docs: [
+							"The share class holder's pubkey, a pda of the vault depositor",
+						],
+						type: 'publicKey',
+					},
+					{
+						name: 'vaultDepositor',
+						docs: ['The vault depositor the locked shares belong to'],
+						type: 'publicKey',
+					},
+					{
+						name: 'authority',
+						docs: ["The vault depositor's authority"],
+						type: 'publicKey',
+					},
+					{
+						name: 'vaultShares',
+						docs: ['Locked shares held'],
+						type: 'u128',
+					},
+					{
+						name: 'netDeposits',
+						docs: [
+							'Cost basis of the locked shares, moved in from the vault depositor',
+						],
+						type: 'i64',
+					},
+					{
+						name: 'cumulativeProfitShareAmount',
+						type: 'i64',
+					},
+					{
+						name: 'profitShareFeePaid',
+						type: 'u64',
+					},
+					{
+						name: 'unlockTs',
+						docs: ['When the locked shares can be converted back'],
+						type: 'i64',
+					},
+					{
+						name: 'vaultSharesBase',
+						docs: ['The exponent for vault_shares decimal places'],
+						type: 'u32',
+					},
+					{
+						name: 'profitShareDiscount',
+						docs: [
+							"The class's profit share discount as of the last conversion in: PERCENTAGE_PRECISION",
+						],
+						type: 'u32',
+					},
+					{
+						name: 'bump',
+						type: 'u8',
+					},
+					{
+						name: 'padding',
+						type: {
+							array: ['u8', 7],
+						},
+					},
+				],
+			},
 		},
 		{
-			name: 'requestWithdraw',
-			accounts: [
-				{
-					name: 'vault',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'vaultDepositor',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'authority',
-					isMut: false,
-					isSigner: true,
-				},
-				{
-					name: 'driftUserStats',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'driftUser',
-					isMut: false,
-					isSigner: false,
-				},
+			name: 'vaultShareSnapshot',
+			docs: [
+				"Per-epoch share snapshot of a vault, external governance reads each depositor's",
+				'[`VaultDepositorShareSnapshot`] as voting weight.',
 			],
-			args: [
-				{
-					name: 'withdrawAmount',
-					type: 'u64',
-				},
-				{
-					name: 'withdrawUnit',
-					type: {
-						defined: 'WithdrawUnit',
+			type: {
+				kind: 'struct',
+				fields: [
+					{
+						name: 'vault',
+						type: 'publicKey',
+					},
+					{
+						name: 'totalShares',
+						docs: ['Sum of the snapshotted depositor shares'],
+						type: 'u128',
+					},
+					{
+						name: 'startTs',
+						type: 'i64',
+					},
+					{
+						name: 'endTs',
+						type: 'i64',
+					},
+					{
+						name: 'epoch',
+						type: 'u32',
+					},
+					{
+						name: 'sharesBase',
+						docs: ["The vault's shares_base when the epoch was opened"],
+						type: 'u32',
+					},
+					{
+						name: 'numDepositors',
+						type: 'u32',
+					},
+					{
+						name: 'bump',
+						type: 'u8',
+					},
+					{
+						name: 'padding',
+						type: {
+							array: ['u8', 3],
+						},
+					},
+				],
+			},
+		},
+		{
+			name: 'vaultDepositorShareSnapshot',
+			type: {
+				kind: 'struct',
+				fields: [
+					{
+						name: 'shareSnapshot',
+						type: 'publicKey',
+					},
+					{
+						name: 'authority',
+						type: 'publicKey',
+					},
+					{
+						name: 'shares',
+						type: 'u128',
+					},
+					{
+						name: 'ts',
+						type: 'i64',
 					},
-				},
+					{
+						name: 'epoch',
+						type: 'u32',
+					},
+					{
+						name: 'bump',
+						type: 'u8',
+					},
+					{
+						name: 'padding',
+						type: {
+							array: ['u8', 3],
+						},
+					},
+				],
+			},
+		},
+		{
+			name: 'vaultShareStatement',
+			docs: [
+				"Merkle root over every depositor's (authority, shares) in a closed [`VaultShareSnapshot`] epoch, so a depositor",
+				'can prove their balance at that epoch against the root alone.',
+				'',
+				"After the snapshot window closes, `append_vault_share_statement` folds the epoch's",
+				'[`VaultDepositorShareSnapshot`]s into the tree in ascending authority order over as many transactions as needed.',
+				"The ordering rules out duplicates, so once the leaf count matches the snapshot's depositor count every depositor",
+				'is in and the root is published.',
 			],
+			type: {
+				kind: 'struct',
+				fields: [
+					{
+						name: 'shareSnapshot',
+						type: 'publicKey',
+					},
+					{
+						name: 'lastAuthority',
+						docs: [
+							'Authority of the last appended leaf, the next one must be greater',
+						],
+						type: 'publicKey',
+					},
+					{
+						name: 'root',
+						docs: ['Zeroed until published'],
+						type: {
+							array: ['u8', 32],
+						},
+					},
+					{
+						name: 'frontier',
+						type: {
+							array: [
+								{
+									array: ['u8', 32],
+								},
+								32,
+							],
+						},
+					},
+					{
+						name: 'totalShares',
+						docs: ['Sum of the appended shares'],
+						type: 'u128',
+					},
+					{
+						name: 'publishedTs',
+						docs: ['When the root was published, 0 until then'],
+						type: 'i64',
+					},
+					{
+						name: 'numLeaves',
+						type: 'u32',
+					},
+					{
+						name: 'epoch',
+						type: 'u32',
+					},
+					{
+						name: 'bump',
+						type: 'u8',
+					},
+					{
+						name: 'padding',
+						type: {
+							array: ['u8', 15],
+						},
+					},
+				],
+			},
 		},
 		{
-			name: 'cancelRequestWithdraw',
-			accounts: [
-				{
-					name: 'vault',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'vaultDepositor',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'authority',
-					isMut: false,
-					isSigner: true,
-				},
-				{
-					name: 'driftUserStats',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'driftUser',
-					isMut: false,
-					isSigner: false,
-				},
+			name: 'vaultWithdrawBlackout',
+			docs: [
+				'Short manager scheduled windows, e.g. around major economic releases, during which depositor withdraws are',
+				'deferred to the end of the window. Windows are published here at least [`MIN_WITHDRAW_BLACKOUT_NOTICE`] in advance',
+				'and the total per [`WITHDRAW_BLACKOUT_PERIOD`] is capped at [`MAX_WITHDRAW_BLACKOUT_PER_PERIOD`]. Enforced',
+				"through the vault's [`VaultGuard`](crate::state::VaultGuard), regardless of the",
+				'[`VaultDepositorThrottle`](crate::state::VaultDepositorThrottle).',
 			],
-			args: [],
+			type: {
+				kind: 'struct',
+				fields: [
+					{
+						name: 'vault',
+						docs: ['The vault the blackout windows are for'],
+						type: 'publicKey',
+					},
+					{
+						name: 'windows',
+						type: {
+							array: [
+								{
+									defined: 'WithdrawBlackoutWindow',
+								},
+								8,
+							],
+						},
+					},
+					{
+						name: 'bump',
+						type: 'u8',
+					},
+					{
+						name: 'padding',
+						type: {
+							array: ['u8', 7],
+						},
+					},
+				],
+			},
 		},
 		{
-			name: 'withdraw',
-			accounts: [
-				{
-					name: 'vault',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'vaultDepositor',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'authority',
-					isMut: false,
-					isSigner: true,
-				},
-				{
-					name: 'vaultTokenAccount',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftUserStats',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftUser',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftState',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'driftSpotMarketVault',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftSigner',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'userTokenAccount',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftProgram',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'tokenProgram',
-					isMut: false,
-					isSigner: false,
-				},
+			name: 'vaultWithdrawEscrow',
+			docs: [
+				'A lend-only drift user, owned by the vault, that pending withdraw requests can be moved into so they keep earning',
+				"lend interest without taking on the strategy's PnL.",
+				'',
+				"The vault delegate can't trade the escrow user and its balance isn't part of the vault's equity. Escrowed amounts",
+				'are tracked as escrow shares so lend interest accrues pro rata to every escrowed request.',
 			],
-			args: [],
+			type: {
+				kind: 'struct',
+				fields: [
+					{
+						name: 'vault',
+						docs: ['The vault the escrow is for'],
+						type: 'publicKey',
+					},
+					{
+						name: 'escrowUser',
+						docs: [
+							"The vault's escrow drift user, sub account [`WITHDRAW_ESCROW_SUB_ACCOUNT_ID`]",
+						],
+						type: 'publicKey',
+					},
+					{
+						name: 'totalShares',
+						docs: ['Escrow shares outstanding across all escrowed requests'],
+						type: 'u128',
+					},
+					{
+						name: 'totalDeposits',
+						docs: ['Lifetime amount escrowed'],
+						type: 'u64',
+					},
+					{
+						name: 'totalWithdraws',
+						docs: [
+							'Lifetime amount paid out of the escrow, including interest',
+						],
+						type: 'u64',
+					},
+					{
+						name: 'bump',
+						type: 'u8',
+					},
+					{
+						name: 'padding',
+						type: {
+							array: ['u8', 15],
+						},
+					},
+				],
+			},
 		},
 		{
-			name: 'liquidate',
-			accounts: [
-				{
-					name: 'vault',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'vaultDepositor',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'authority',
-					isMut: false,
-					isSigner: true,
-				},
-				{
-					name: 'driftUserStats',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftUser',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftProgram',
-					isMut: false,
-					isSigner: false,
-				},
+			name: 'vaultDepositorWithdrawEscrow',
+			docs: [
+				"A vault depositor's withdraw request held in the vault's [`VaultWithdrawEscrow`] until it matures.",
 			],
-			args: [],
+			type: {
+				kind: 'struct',
+				fields: [
+					{
+						name: 'vaultDepositor',
+						docs: ['The vault depositor the escrowed request belongs to'],
+						type: 'publicKey',
+					},
+					{
+						name: 'shares',
+						docs: ['Escrow shares of the escrowed request'],
+						type: 'u128',
+					},
+					{
+						name: 'amount',
+						docs: ['Amount the request paid into the escrow'],
+						type: 'u64',
+					},
+					{
+						name: 'unlockTs',
+						docs: ['When the escrowed request matures and can be withdrawn'],
+						type: 'i64',
+					},
+					{
+						name: 'bump',
+						type: 'u8',
+					},
+					{
+						name: 'padding',
+						type: {
+							array: ['u8', 15],
+						},
+					},
+				],
+			},
 		},
 		{
-			name: 'resetDelegate',
-			accounts: [
-				{
-					name: 'vault',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftUser',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftProgram',
-					isMut: false,
-					isSigner: false,
-				},
+			name: 'vaultWithdrawPriority',
+			docs: [
+				'Lets depositors in queue mode pay a priority fee, forfeiting part of their queued',
+				'[`WithdrawRequest`](crate::state::withdraw_request::WithdrawRequest) to the remaining shareholders, to have it',
+				'mature sooner.',
 			],
-			args: [],
+			type: {
+				kind: 'struct',
+				fields: [
+					{
+						name: 'vault',
+						docs: ['The vault priority fees are paid to'],
+						type: 'publicKey',
+					},
+					{
+						name: 'fullBoostFee',
+						docs: [
+							'Priority fee, as a share of the request, that skips the whole redeem period. Smaller fees skip a',
+							'proportional part of it: PERCENTAGE_PRECISION',
+						],
+						type: 'u32',
+					},
+					{
+						name: 'bump',
+						type: 'u8',
+					},
+					{
+						name: 'padding',
+						type: {
+							array: ['u8', 3],
+						},
+					},
+				],
+			},
 		},
 		{
-			name: 'resetFuelSeason',
-			accounts: [
-				{
-					name: 'vault',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'vaultDepositor',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'admin',
-					isMut: false,
-					isSigner: true,
-				},
-				{
-					name: 'driftUserStats',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftState',
-					isMut: false,
-					isSigner: false,
-				},
-			],
-			args: [],
+			name: 'vaultWithdrawRoute',
+			type: {
+				kind: 'struct',
+				fields: [
+					{
+						name: 'vault',
+						docs: ['The vault this route is configured for'],
+						type: 'publicKey',
+					},
+					{
+						name: 'marketIndexes',
+						docs: [
+							"Spot markets depositor payouts are drawn from before the vault's spot market, highest priority first",
+						],
+						type: {
+							array: ['u16', 4],
+						},
+					},
+					{
+						name: 'numMarkets',
+						docs: ['Number of entries in `market_indexes` that are in use'],
+						type: 'u8',
+					},
+					{
+						name: 'bump',
+						type: 'u8',
+					},
+					{
+						name: 'padding',
+						type: {
+							array: ['u8', 6],
+						},
+					},
+				],
+			},
 		},
 		{
-			name: 'resetVaultFuelSeason',
-			accounts: [
-				{
-					name: 'vault',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'admin',
-					isMut: false,
-					isSigner: true,
-				},
-				{
-					name: 'driftState',
-					isMut: false,
-					isSigner: false,
-				},
-			],
-			args: [],
+			name: 'vaultWithdrawSettlement',
+			type: {
+				kind: 'struct',
+				fields: [
+					{
+						name: 'vault',
+						docs: ['The vault whose withdraw requests are settled'],
+						type: 'publicKey',
+					},
+					{
+						name: 'totalShares',
+						docs: ['vault.total_shares at the last settlement'],
+						type: 'u128',
+					},
+					{
+						name: 'equity',
+						docs: ['vault equity at the last settlement'],
+						type: 'u64',
+					},
+					{
+						name: 'ts',
+						docs: ['ts of the last settlement'],
+						type: 'i64',
+					},
+					{
+						name: 'settlementId',
+						docs: ['number of settlements cranked'],
+						type: 'u32',
+					},
+					{
+						name: 'sharesBase',
+						docs: ['vault.shares_base at the last settlement'],
+						type: 'u32',
+					},
+					{
+						name: 'bump',
+						type: 'u8',
+					},
+					{
+						name: 'padding',
+						type: {
+							array: ['u8', 15],
+						},
+					},
+				],
+			},
 		},
 		{
-			name: 'managerDeposit',
-			accounts: [
-				{
-					name: 'vault',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'manager',
-					isMut: false,
-					isSigner: true,
-				},
-				{
-					name: 'vaultTokenAccount',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftUserStats',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftUser',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftState',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'driftSpotMarketVault',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'userTokenAccount',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftProgram',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'tokenProgram',
-					isMut: false,
-					isSigner: false,
-				},
-			],
-			args: [
-				{
-					name: 'amount',
-					type: 'u64',
-				},
+			name: 'vaultWithdrawStream',
+			docs: [
+				'Releases a matured withdraw request linearly over `duration` instead of as a lump sum.',
 			],
+			type: {
+				kind: 'struct',
+				fields: [
+					{
+						name: 'vaultDepositor',
+						docs: ['The vault depositor streaming its withdraw request'],
+						type: 'publicKey',
+					},
+					{
+						name: 'shares',
+						docs: ['shares of the withdraw request being streamed'],
+						type: 'u128',
+					},
+					{
+						name: 'claimedShares',
+						docs: ['shares already claimed'],
+						type: 'u128',
+					},
+					{
+						name: 'value',
+						docs: ['value of the withdraw request being streamed'],
+						type: 'u64',
+					},
+					{
+						name: 'claimedValue',
+						docs: ['value already claimed'],
+						type: 'u64',
+					},
+					{
+						name: 'requestTs',
+						docs: ['ts of the streamed withdraw request'],
+						type: 'i64',
+					},
+					{
+						name: 'startTs',
+						type: 'i64',
+					},
+					{
+						name: 'duration',
+						type: 'i64',
+					},
+					{
+						name: 'sharesBase',
+						docs: ["the vault depositor's shares base when the stream started"],
+						type: 'u32',
+					},
+					{
+						name: 'bump',
+						type: 'u8',
+					},
+					{
+						name: 'padding',
+						type: {
+							array: ['u8', 3],
+						},
+					},
+				],
+			},
 		},
 		{
-			name: 'managerRequestWithdraw',
-			accounts: [
-				{
-					name: 'vault',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'manager',
-					isMut: false,
-					isSigner: true,
-				},
-				{
-					name: 'driftUserStats',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'driftUser',
-					isMut: false,
-					isSigner: false,
-				},
-			],
-			args: [
-				{
-					name: 'withdrawAmount',
-					type: 'u64',
-				},
-				{
-					name: 'withdrawUnit',
-					type: {
-						defined: 'WithdrawUnit',
+			name: 'vault',
+			type: {
+				kind: 'struct',
+				fields: [
+					{
+						name: 'name',
+						docs: [
+							'The name of the vault. Vault pubkey is derived from this name.',
+						],
+						type: {
+							array: ['u8', 32],
+						},
+					},
+					{
+						name: 'pubkey',
+						docs: [
+							"The vault's pubkey. It is a pda of name and also used as the authority for drift user",
+						],
+						type: 'publicKey',
+					},
+					{
+						name: 'manager',
+						docs: [
+							'The manager of the vault who has ability to update vault params',
+						],
+						type: 'publicKey',
+					},
+					{
+						name: 'tokenAccount',
+						docs: [
+							'The vaults token account. Used to receive tokens between deposits and withdrawals',
+						],
+						type: 'publicKey',
+					},
+					{
+						name: 'userStats',
+						docs: ['The drift user stats account for the vault'],
+						type: 'publicKey',
+					},
+					{
+						name: 'user',
+						docs: ['The drift user account for the vault'],
+						type: 'publicKey',
+					},
+					{
+						name: 'delegate',
+						docs: [
+							'The vaults designated delegate for drift user account',
+							'can differ from actual user delegate if vault is in liquidation',
+						],
+						type: 'publicKey',
+					},
+					{
+						name: 'liquidationDelegate',
+						docs: ['The delegate handling liquidation for depositor'],
+						type: 'publicKey',
+					},
+					{
+						name: 'userShares',
+						docs: [
+							'The sum of all shares held by the users (vault depositors)',
+						],
+						type: 'u128',
+					},
+					{
+						name: 'totalShares',
+						docs: [
+							'The sum of all shares: deposits from users, manager deposits, manager profit/fee, and protocol profit/fee.',
+							'The manager deposits are total_shares - user_shares - protocol_profit_and_fee_shares.',
+						],
+						type: 'u128',
+					},
+					{
+						name: 'lastFeeUpdateTs',
+						docs: ['Last fee update unix timestamp'],
+						type: 'i64',
+					},
+					{
+						name: 'liquidationStartTs',
+						docs: ['When the liquidation starts'],
+						type: 'i64',
+					},
+					{
+						name: 'redeemPeriod',
+						docs: [
+							'The period (in seconds) that a vault depositor must wait after requesting a withdrawal to finalize withdrawal.',
+							'Currently, the maximum is 90 days.',
+						],
+						type: 'i64',
+					},
+					{
+						name: 'totalWithdrawRequested',
+						docs: ['The sum of all outstanding withdraw requests'],
+						type: 'u64',
+					},
+					{
+						name: 'maxTokens',
+						docs: [
+							'Max token capacity, once hit/passed vault will reject new deposits (updatable)',
+						],
+						type: 'u64',
+					},
+					{
+						name: 'managementFee',
+						docs: [
+							'The annual fee charged on deposits by the manager.',
+							'Traditional funds typically charge 2% per year on assets under management.',
+						],
+						type: 'i64',
+					},
+					{
+						name: 'initTs',
+						docs: ['Timestamp vault initialized'],
+						type: 'i64',
+					},
+					{
+						name: 'netDeposits',
+						docs: ['The net deposits for the vault'],
+						type: 'i64',
+					},
+					{
+						name: 'managerNetDeposits',
+						docs: ['The net deposits for the manager'],
+						type: 'i64',
+					},
+					{
+						name: 'totalDeposits',
+						docs: ['Total deposits'],
+						type: 'u64',
+					},
+					{
+						name: 'totalWithdraws',
+						docs: ['Total withdraws'],
+						type: 'u64',
+					},
+					{
+						name: 'managerTotalDeposits',
+						docs: ['Total deposits for the manager'],
+						type: 'u64',
+					},
+					{
+						name: 'managerTotalWithdraws',
+						docs: ['Total withdraws for the manager'],
+						type: 'u64',
+					},
+					{
+						name: 'managerTotalFee',
+						docs: ['Total management fee accrued by the manager'],
+						type: 'i64',
+					},
+					{
+						name: 'managerTotalProfitShare',
+						docs: ['Total profit share accrued by the manager'],
+						type: 'u64',
+					},
+					{
+						name: 'minDepositAmount',
+						docs: ['The minimum deposit amount'],
+						type: 'u64',
+					},
+					{
+						name: 'lastManagerWithdrawRequest',
+						type: {
+							defined: 'WithdrawRequest',
+						},
+					},
+					{
+						name: 'sharesBase',
+						docs: [
+							'The base 10 exponent of the shares (given massive share inflation can occur at near zero vault equity)',
+						],
+						type: 'u32',
+					},
+					{
+						name: 'profitShare',
+						docs: [
+							'Percentage the manager charges on all profits realized by depositors: PERCENTAGE_PRECISION',
+						],
+						type: 'u32',
+					},
+					{
+						name: 'hurdleRate',
+						docs: [
+							'Vault manager only collect incentive fees during periods when returns are higher than this amount: PERCENTAGE_PRECISION',
+						],
+						type: 'u32',
+					},
+					{
+						name: 'spotMarketIndex',
+						docs: [
+							'The spot market index the vault deposits into/withdraws from',
+						],
+						type: 'u16',
+					},
+					{
+						name: 'bump',
+						docs: ['The bump for the vault pda'],
+						type: 'u8',
+					},
+					{
+						name: 'permissioned',
+						docs: ['Whether anybody can be a depositor'],
+						type: 'bool',
+					},
+					{
+						name: 'vaultProtocol',
+						docs: ['The optional [`VaultProtocol`] account.'],
+						type: 'bool',
 					},
-				},
-			],
-		},
-		{
-			name: 'mangerCancelWithdrawRequest',
-			accounts: [
-				{
-					name: 'vault',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'manager',
-					isMut: false,
-					isSigner: true,
-				},
-				{
-					name: 'driftUserStats',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'driftUser',
-					isMut: false,
-					isSigner: false,
-				},
-			],
-			args: [],
-		},
-		{
-			name: 'managerWithdraw',
-			accounts: [
-				{
-					name: 'vault',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'manager',
-					isMut: false,
-					isSigner: true,
-				},
-				{
-					name: 'vaultTokenAccount',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftUserStats',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftUser',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftState',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'driftSpotMarketVault',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftSigner',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'userTokenAccount',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftProgram',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'tokenProgram',
-					isMut: false,
-					isSigner: false,
-				},
-			],
-			args: [],
+					{
+						name: 'fuelDistributionMode',
+						docs: [
+							'How fuel distribution should be treated [`FuelDistributionMode`]. Default is `UsersOnly`',
+						],
+						type: 'u8',
+					},
+					{
+						name: 'vaultHook',
+						docs: ['The optional [`VaultHook`] account.'],
+						type: 'bool',
+					},
+					{
+						name: 'windDown',
+						docs: [
+							'Set by a [`VaultPoll`](crate::state::VaultPoll) wind down, no new deposits are accepted',
+						],
+						type: 'bool',
+					},
+					{
+						name: 'lastCumulativeFuelPerShareTs',
+						docs: ['The timestamp cumulative_fuel_per_share was last updated'],
+						type: 'u32',
+					},
+					{
+						name: 'cumulativeFuelPerShare',
+						docs: [
+							'The cumulative fuel per share (scaled up by 1e6 to avoid losing precision)',
+						],
+						type: 'u128',
+					},
+					{
+						name: 'cumulativeFuel',
+						docs: ['The total fuel accumulated'],
+						type: 'u128',
+					},
+					{
+						name: 'fuelSeasonStartTs',
+						docs: [
+							'The [`FuelOverflow`] `last_reset_ts` of the fuel season the vault is currently accruing',
+						],
+						type: 'u32',
+					},
+					{
+						name: 'largeWithdrawThreshold',
+						docs: [
+							'Withdraw requests for more than this share of vault equity wait an extra notice period: PERCENTAGE_PRECISION.',
+							'0 disables the notice period',
+						],
+						type: 'u32',
+					},
+					{
+						name: 'largeWithdrawNoticePeriod',
+						docs: [
+							'The extra period (in seconds) on top of the redeem period for large withdraw requests',
+						],
+						type: 'u32',
+					},
+					{
+						name: 'depositorCount',
+						docs: [
+							'Number of open [`VaultDepositor`](crate::state::VaultDepositor)s of the vault. Depositors initialized',
+							'before the counter was added are counted by `backfill_vault_depositor_counts`',
+						],
+						type: 'u32',
+					},
+					{
+						name: 'activeWithdrawRequestCount',
+						docs: [
+							'Number of counted depositors with a pending withdraw request',
+						],
+						type: 'u32',
+					},
+					{
+						name: 'withdrawSettlement',
+						docs: [
+							'The optional [`VaultWithdrawSettlement`](crate::state::VaultWithdrawSettlement) account. Matured withdraw',
+							'requests are paid at the share price of the last settlement crank',
+						],
+						type: 'bool',
+					},
+					{
+						name: 'profile',
+						docs: [
+							'Restricts the positions the drift user may hold [`VaultProfile`](crate::state::VaultProfile).',
+							'Default is `Unrestricted`',
+						],
+						type: 'u8',
+					},
+					{
+						name: 'disabledFeatures',
+						docs: [
+							'Bits of the optional [`VaultFeature`](crate::state::VaultFeature)s switched off for this vault. Default is 0,',
+							'everything enabled',
+						],
+						type: 'u8',
+					},
+					{
+						name: 'flags',
+						docs: ['Bits of [`VaultFlag`]'],
+						type: 'u8',
+					},
+				],
+			},
 		},
 		{
-			name: 'managerUpdateFuelDistributionMode',
-			accounts: [
-				{
-					name: 'vault',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'manager',
-					isMut: false,
-					isSigner: true,
-				},
-			],
-			args: [
-				{
-					name: 'fuelDistributionMode',
-					type: 'u8',
-				},
+			name: 'withdrawReceipt',
+			docs: [
+				"Commits a vault depositor's withdraw request to a cancellation deadline, after which the request can't be",
+				'cancelled, so the manager can start unwinding positions for a large exit.',
+				'',
+				"The receipt only applies to the request it was set for, matched by the request's `ts` and `shares`. Once any",
+				'depositor of a vault has a receipt the vault has [`VaultFlag::WithdrawReceipts`](crate::state::VaultFlag) set',
+				"and cancels must pass the depositor's receipt address in remaining accounts, initialized or not.",
 			],
+			type: {
+				kind: 'struct',
+				fields: [
+					{
+						name: 'vaultDepositor',
+						docs: ['The vault depositor the receipt is for'],
+						type: 'publicKey',
+					},
+					{
+						name: 'requestShares',
+						docs: ['`shares` of the withdraw request the deadline applies to'],
+						type: 'u128',
+					},
+					{
+						name: 'requestTs',
+						docs: ['`ts` of the withdraw request the deadline applies to'],
+						type: 'i64',
+					},
+					{
+						name: 'cancelDeadlineTs',
+						docs: ["The request can't be cancelled from this ts on"],
+						type: 'i64',
+					},
+					{
+						name: 'bump',
+						type: 'u8',
+					},
+					{
+						name: 'padding',
+						type: {
+							array: ['u8', 7],
+						},
+					},
+				],
+			},
 		},
-		{
-			name: 'applyProfitShare',
-			accounts: [
-				{
-					name: 'vault',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'vaultDepositor',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'manager',
-					isMut: false,
-					isSigner: true,
-				},
-				{
-					name: 'driftUserStats',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftUser',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftState',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'driftSigner',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'driftProgram',
-					isMut: false,
-					isSigner: false,
-				},
-			],
-			args: [],
+	],
+	types: [
+		{
+			name: 'InitializeTokenizedVaultDepositorParams',
+			type: {
+				kind: 'struct',
+				fields: [
+					{
+						name: 'tokenName',
+						type: 'string',
+					},
+					{
+						name: 'tokenSymbol',
+						type: 'string',
+					},
+					{
+						name: 'tokenUri',
+						type: 'string',
+					},
+					{
+						name: 'decimals',
+						type: 'u8',
+					},
+				],
+			},
 		},
 		{
-			name: 'applyRebase',
-			accounts: [
-				{
-					name: 'vault',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'vaultDepositor',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftUser',
-					isMut: true,
-					isSigner: false,
-				},
-			],
-			args: [],
+			name: 'VaultFromTemplateParams',
+			type: {
+				kind: 'struct',
+				fields: [
+					{
+						name: 'name',
+						type: {
+							array: ['u8', 32],
+						},
+					},
+					{
+						name: 'template',
+						docs: [
+							'[`VaultTemplate`] id, sets the redeem period, fee bounds, leverage cap and allowed spot markets',
+						],
+						type: 'u8',
+					},
+					{
+						name: 'maxTokens',
+						type: 'u64',
+					},
+					{
+						name: 'managementFee',
+						type: 'i64',
+					},
+					{
+						name: 'minDepositAmount',
+						type: 'u64',
+					},
+					{
+						name: 'profitShare',
+						type: 'u32',
+					},
+					{
+						name: 'spotMarketIndex',
+						type: 'u16',
+					},
+					{
+						name: 'permissioned',
+						type: 'bool',
+					},
+				],
+			},
 		},
 		{
-			name: 'applyRebaseTokenizedDepositor',
-			accounts: [
-				{
-					name: 'vault',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'tokenizedVaultDepositor',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftUser',
-					isMut: true,
-					isSigner: false,
-				},
-			],
-			args: [],
+			name: 'VaultWithProtocolParams',
+			type: {
+				kind: 'struct',
+				fields: [
+					{
+						name: 'name',
+						type: {
+							array: ['u8', 32],
+						},
+					},
+					{
+						name: 'redeemPeriod',
+						type: 'i64',
+					},
+					{
+						name: 'maxTokens',
+						type: 'u64',
+					},
+					{
+						name: 'managementFee',
+						type: 'i64',
+					},
+					{
+						name: 'minDepositAmount',
+						type: 'u64',
+					},
+					{
+						name: 'profitShare',
+						type: 'u32',
+					},
+					{
+						name: 'hurdleRate',
+						type: 'u32',
+					},
+					{
+						name: 'spotMarketIndex',
+						type: 'u16',
+					},
+					{
+						name: 'permissioned',
+						type: 'bool',
+					},
+					{
+						name: 'vaultProtocol',
+						type: {
+							defined: 'VaultProtocolParams',
+						},
+					},
+				],
+			},
 		},
 		{
-			name: 'forceWithdraw',
-			accounts: [
-				{
-					name: 'vault',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'manager',
-					isMut: false,
-					isSigner: true,
-				},
-				{
-					name: 'vaultDepositor',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'vaultTokenAccount',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftUserStats',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftUser',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftState',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'driftSpotMarketVault',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftSigner',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'userTokenAccount',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftProgram',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'tokenProgram',
-					isMut: false,
-					isSigner: false,
-				},
-			],
-			args: [],
+			name: 'VaultProtocolParams',
+			type: {
+				kind: 'struct',
+				fields: [
+					{
+						name: 'protocol',
+						type: 'publicKey',
+					},
+					{
+						name: 'protocolFee',
+						type: 'u64',
+					},
+					{
+						name: 'protocolProfitShare',
+						type: 'u32',
+					},
+				],
+			},
+		},
+		{
+			name: 'VaultParams',
+			type: {
+				kind: 'struct',
+				fields: [
+					{
+						name: 'name',
+						type: {
+							array: ['u8', 32],
+						},
+					},
+					{
+						name: 'redeemPeriod',
+						type: 'i64',
+					},
+					{
+						name: 'maxTokens',
+						type: 'u64',
+					},
+					{
+						name: 'managementFee',
+						type: 'i64',
+					},
+					{
+						name: 'minDepositAmount',
+						type: 'u64',
+					},
+					{
+						name: 'profitShare',
+						type: 'u32',
+					},
+					{
+						name: 'hurdleRate',
+						type: 'u32',
+					},
+					{
+						name: 'spotMarketIndex',
+						type: 'u16',
+					},
+					{
+						name: 'permissioned',
+						type: 'bool',
+					},
+				],
+			},
 		},
 		{
-			name: 'initializeInsuranceFundStake',
-			accounts: [
-				{
-					name: 'vault',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'manager',
-					isMut: false,
-					isSigner: true,
-				},
-				{
-					name: 'payer',
-					isMut: true,
-					isSigner: true,
-				},
-				{
-					name: 'rent',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'driftSpotMarket',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftSpotMarketMint',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'vaultTokenAccount',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'insuranceFundStake',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftUserStats',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftState',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'driftProgram',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'tokenProgram',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'systemProgram',
-					isMut: false,
-					isSigner: false,
-				},
-			],
-			args: [
-				{
-					name: 'marketIndex',
-					type: 'u16',
-				},
-			],
+			name: 'SimulateWithdrawStatus',
+			type: {
+				kind: 'struct',
+				fields: [
+					{
+						name: 'statusCode',
+						docs: [
+							'[`STATUS_OK`] if the simulation ran, otherwise the status code of the error that stopped it',
+						],
+						type: 'u32',
+					},
+					{
+						name: 'withdrawBlocker',
+						docs: ['Only meaningful when `status_code` is [`STATUS_OK`]'],
+						type: {
+							defined: 'WithdrawBlocker',
+						},
+					},
+				],
+			},
 		},
 		{
-			name: 'addInsuranceFundStake',
-			accounts: [
-				{
-					name: 'vault',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'manager',
-					isMut: true,
-					isSigner: true,
-				},
-				{
-					name: 'driftSpotMarket',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftSpotMarketVault',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'insuranceFundStake',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'insuranceFundVault',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'managerTokenAccount',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'vaultIfTokenAccount',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftUserStats',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftState',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'driftSigner',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'driftProgram',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'tokenProgram',
-					isMut: false,
-					isSigner: false,
-				},
-			],
-			args: [
-				{
-					name: 'marketIndex',
-					type: 'u16',
-				},
-				{
-					name: 'amount',
-					type: 'u64',
-				},
-			],
+			name: 'UpdateVaultProtocolParams',
+			type: {
+				kind: 'struct',
+				fields: [
+					{
+						name: 'protocolFee',
+						type: {
+							option: 'u64',
+						},
+					},
+					{
+						name: 'protocolProfitShare',
+						type: {
+							option: 'u32',
+						},
+					},
+				],
+			},
+		},
+		{
+			name: 'UpdateVaultParams',
+			type: {
+				kind: 'struct',
+				fields: [
+					{
+						name: 'redeemPeriod',
+						type: {
+							option: 'i64',
+						},
+					},
+					{
+						name: 'maxTokens',
+						type: {
+							option: 'u64',
+						},
+					},
+					{
+						name: 'managementFee',
+						type: {
+							option: 'i64',
+						},
+					},
+					{
+						name: 'minDepositAmount',
+						type: {
+							option: 'u64',
+						},
+					},
+					{
+						name: 'profitShare',
+						type: {
+							option: 'u32',
+						},
+					},
+					{
+						name: 'hurdleRate',
+						type: {
+							option: 'u32',
+						},
+					},
+					{
+						name: 'permissioned',
+						type: {
+							option: 'bool',
+						},
+					},
+				],
+			},
 		},
 		{
-			name: 'requestRemoveInsuranceFundStake',
-			accounts: [
-				{
-					name: 'vault',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'manager',
-					isMut: false,
-					isSigner: true,
-				},
-				{
-					name: 'driftSpotMarket',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'insuranceFundStake',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'insuranceFundVault',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftUserStats',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftProgram',
-					isMut: false,
-					isSigner: false,
-				},
-			],
-			args: [
-				{
-					name: 'marketIndex',
-					type: 'u16',
-				},
-				{
-					name: 'amount',
-					type: 'u64',
-				},
+			name: 'ComputeUnitsHint',
+			docs: [
+				'Written to return data by heavy instructions so clients simulating a transaction can set its compute budget',
+				'from the markets the vault actually loaded instead of over-reserving.',
 			],
+			type: {
+				kind: 'struct',
+				fields: [
+					{
+						name: 'perpMarkets',
+						type: 'u8',
+					},
+					{
+						name: 'spotMarkets',
+						type: 'u8',
+					},
+					{
+						name: 'oracles',
+						type: 'u8',
+					},
+					{
+						name: 'computeUnits',
+						docs: ['estimated compute units of the instruction'],
+						type: 'u32',
+					},
+				],
+			},
 		},
 		{
-			name: 'removeInsuranceFundStake',
-			accounts: [
-				{
-					name: 'vault',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'manager',
-					isMut: false,
-					isSigner: true,
-				},
-				{
-					name: 'driftSpotMarket',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'insuranceFundStake',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'insuranceFundVault',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'managerTokenAccount',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'vaultIfTokenAccount',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftUserStats',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftState',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'driftSigner',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'driftProgram',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'tokenProgram',
-					isMut: false,
-					isSigner: false,
-				},
-			],
-			args: [
-				{
-					name: 'marketIndex',
-					type: 'u16',
-				},
+			name: 'FlowPreview',
+			docs: [
+				'Projected result of a deposit or withdraw, written to return data by',
+				'[`preview_deposit`](crate::instructions::preview_deposit) and',
+				'[`preview_withdraw`](crate::instructions::preview_withdraw).',
 			],
+			type: {
+				kind: 'struct',
+				fields: [
+					{
+						name: 'amount',
+						docs: [
+							"tokens deposited or paid out, deposits are capped by the vault's `max_tokens`",
+						],
+						type: 'u64',
+					},
+					{
+						name: 'vaultSharesBefore',
+						type: 'u128',
+					},
+					{
+						name: 'vaultSharesAfter',
+						type: 'u128',
+					},
+					{
+						name: 'profitShare',
+						docs: ['profit share charged to the depositor'],
+						type: 'u64',
+					},
+					{
+						name: 'managementFee',
+						docs: [
+							'management fee the vault charged since its last fee update',
+						],
+						type: 'i64',
+					},
+					{
+						name: 'fuelAmount',
+						docs: ["the depositor's fuel after the flow"],
+						type: 'u128',
+					},
+					{
+						name: 'computeUnitsHint',
+						type: {
+							defined: 'ComputeUnitsHint',
+						},
+					},
+				],
+			},
 		},
 		{
-			name: 'cancelRequestRemoveInsuranceFundStake',
-			accounts: [
-				{
-					name: 'vault',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'manager',
-					isMut: false,
-					isSigner: true,
-				},
-				{
-					name: 'driftSpotMarket',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'insuranceFundStake',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'insuranceFundVault',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftUserStats',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftProgram',
-					isMut: false,
-					isSigner: false,
-				},
-			],
-			args: [
-				{
-					name: 'marketIndex',
-					type: 'u16',
-				},
-			],
+			name: 'OmnibusLedgerEntry',
+			type: {
+				kind: 'struct',
+				fields: [
+					{
+						name: 'beneficiary',
+						type: 'publicKey',
+					},
+					{
+						name: 'shares',
+						type: 'u128',
+					},
+				],
+			},
+		},
+		{
+			name: 'ProtocolTvlCapVault',
+			type: {
+				kind: 'struct',
+				fields: [
+					{
+						name: 'vault',
+						type: 'publicKey',
+					},
+					{
+						name: 'tvl',
+						docs: [
+							'Vault equity as of its last deposit. precision: QUOTE_PRECISION',
+						],
+						type: 'u64',
+					},
+				],
+			},
+		},
+		{
+			name: 'ProtocolFeeTier',
+			type: {
+				kind: 'struct',
+				fields: [
+					{
+						name: 'tvlThreshold',
+						docs: [
+							"Vault equity (in vault spot_market_index) at or above which this tier's fee applies",
+						],
+						type: 'u64',
+					},
+					{
+						name: 'protocolFee',
+						docs: [
+							'The annual protocol fee charged while this tier applies: PERCENTAGE_PRECISION',
+						],
+						type: 'u64',
+					},
+				],
+			},
 		},
 		{
-			name: 'protocolRequestWithdraw',
-			accounts: [
-				{
-					name: 'vault',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'vaultProtocol',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'protocol',
-					isMut: false,
-					isSigner: true,
-				},
-				{
-					name: 'driftUserStats',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'driftUser',
-					isMut: false,
-					isSigner: false,
-				},
+			name: 'VaultDepositorRecordLogEntry',
+			docs: [
+				'The parts of a [`VaultDepositorRecord`](crate::state::events::VaultDepositorRecord) an indexer needs to rebuild a',
+				"depositor's position. Share amounts after are in the vault's shares base at `ts`, `vault_shares_before` is in the",
+				"depositor's base before the action and lags it if the action applied a",
+				'[rebase](crate::state::events::VaultRebaseRecord).',
 			],
-			args: [
-				{
-					name: 'withdrawAmount',
-					type: 'u64',
-				},
-				{
-					name: 'withdrawUnit',
-					type: {
-						defined: 'WithdrawUnit',
+			type: {
+				kind: 'struct',
+				fields: [
+					{
+						name: 'depositorAuthority',
+						type: 'publicKey',
 					},
-				},
-			],
+					{
+						name: 'vaultSharesBefore',
+						type: 'u128',
+					},
+					{
+						name: 'vaultSharesAfter',
+						type: 'u128',
+					},
+					{
+						name: 'totalVaultSharesAfter',
+						type: 'u128',
+					},
+					{
+						name: 'ts',
+						type: 'i64',
+					},
+					{
+						name: 'amount',
+						docs: [
+							'deposited, requested, withdrawn or profit share paid, precision: token mint',
+						],
+						type: 'u64',
+					},
+					{
+						name: 'vaultEquityBefore',
+						type: 'u64',
+					},
+					{
+						name: 'action',
+						docs: ['[`VaultDepositorAction`]'],
+						type: 'u8',
+					},
+					{
+						name: 'padding',
+						type: {
+							array: ['u8', 7],
+						},
+					},
+				],
+			},
 		},
 		{
-			name: 'protocolCancelWithdrawRequest',
-			accounts: [
-				{
-					name: 'vault',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'vaultProtocol',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'protocol',
-					isMut: false,
-					isSigner: true,
-				},
-				{
-					name: 'driftUserStats',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'driftUser',
-					isMut: false,
-					isSigner: false,
-				},
-			],
-			args: [],
+			name: 'VaultParamChange',
+			type: {
+				kind: 'struct',
+				fields: [
+					{
+						name: 'signer',
+						docs: ['Who signed the change'],
+						type: 'publicKey',
+					},
+					{
+						name: 'oldValue',
+						docs: ['See [`ParamValue`]'],
+						type: {
+							array: ['u8', 32],
+						},
+					},
+					{
+						name: 'newValue',
+						docs: ['See [`ParamValue`]'],
+						type: {
+							array: ['u8', 32],
+						},
+					},
+					{
+						name: 'ts',
+						type: 'i64',
+					},
+					{
+						name: 'param',
+						docs: ['[`VaultParam`]'],
+						type: 'u8',
+					},
+					{
+						name: 'padding',
+						type: {
+							array: ['u8', 7],
+						},
+					},
+				],
+			},
 		},
 		{
-			name: 'protocolWithdraw',
-			accounts: [
-				{
-					name: 'vault',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'vaultProtocol',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'protocol',
-					isMut: false,
-					isSigner: true,
-				},
-				{
-					name: 'vaultTokenAccount',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftUserStats',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftUser',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftState',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'driftSpotMarketVault',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftSigner',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'userTokenAccount',
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: 'driftProgram',
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: 'tokenProgram',
-					isMut: false,
-					isSigner: false,
-				},
-			],
-			args: [],
+			name: 'ProfitShareCheckpoint',
+			type: {
+				kind: 'struct',
+				fields: [
+					{
+						name: 'ts',
+						type: 'i64',
+					},
+					{
+						name: 'managerTotalProfitShare',
+						docs: ['`Vault::manager_total_profit_share` at ts'],
+						type: 'u64',
+					},
+				],
+			},
+		},
+		{
+			name: 'RedeemPeriodBucket',
+			type: {
+				kind: 'struct',
+				fields: [
+					{
+						name: 'maxWithdrawShare',
+						docs: [
+							'Requests for at most this share of vault equity use this bucket: PERCENTAGE_PRECISION',
+						],
+						type: 'u32',
+					},
+					{
+						name: 'redeemPeriod',
+						docs: [
+							"The redeem period (in seconds) of requests in this bucket, capped at the vault's `redeem_period`",
+						],
+						type: 'u32',
+					},
+				],
+			},
+		},
+		{
+			name: 'VaultRegistryEntry',
+			type: {
+				kind: 'struct',
+				fields: [
+					{
+						name: 'vault',
+						type: 'publicKey',
+					},
+					{
+						name: 'mint',
+						docs: ["The mint of the vault's spot market"],
+						type: 'publicKey',
+					},
+					{
+						name: 'manager',
+						type: 'publicKey',
+					},
+					{
+						name: 'protocol',
+						docs: [
+							"The vault's `VaultProtocol::protocol`, Pubkey::default() if it has none",
+						],
+						type: 'publicKey',
+					},
+				],
+			},
 		},
-	],
-	accounts: [
 		{
-			name: 'tokenizedVaultDepositor',
+			name: 'WithdrawBlackoutWindow',
 			type: {
 				kind: 'struct',
 				fields: [
 					{
-						name: 'vault',
-						docs: ['The vault deposited into'],
-						type: 'publicKey',
+						name: 'startTs',
+						type: 'i64',
 					},
 					{
-						name: 'pubkey',
+						name: 'endTs',
+						docs: ['0 if the slot was never used'],
+						type: 'i64',
+					},
+				],
+			},
+		},
+		{
+			name: 'WithdrawRequest',
+			type: {
+				kind: 'struct',
+				fields: [
+					{
+						name: 'shares',
+						docs: ['request shares of vault withdraw'],
+						type: 'u128',
+					},
+					{
+						name: 'value',
 						docs: [
-							"The vault depositor account's pubkey. It is a pda of vault",
+							'requested value (in vault spot_market_index) of shares for withdraw',
 						],
-						type: 'publicKey',
+						type: 'u64',
 					},
 					{
-						name: 'mint',
+						name: 'ts',
 						docs: [
-							'The token mint for tokenized shares owned by this VaultDepositor',
+							"request ts of vault withdraw. For depositor requests over the vault's large withdraw threshold this is",
+							'pushed forward by the large withdraw notice period, so the request matures at ts + redeem_period',
 						],
-						type: 'publicKey',
+						type: 'i64',
 					},
+				],
+			},
+		},
+		{
+			name: 'DepositorAction',
+			type: {
+				kind: 'enum',
+				variants: [
 					{
-						name: 'vaultShares',
-						docs: [
-							"share of vault owned by this depositor. vault_shares / vault.total_shares is depositor's ownership of vault_equity",
+						name: 'Deposit',
+						fields: [
+							{
+								name: 'amount',
+								type: 'u64',
+							},
 						],
-						type: 'u128',
 					},
 					{
-						name: 'lastVaultShares',
-						docs: [
-							'stores the vault_shares from the most recent liquidity event (redeem or issuance) before a spl token',
-							'CPI is done, used to track invariants',
+						name: 'Request',
+						fields: [
+							{
+								name: 'withdrawAmount',
+								type: 'u64',
+							},
+							{
+								name: 'withdrawUnit',
+								type: {
+									defined: 'WithdrawUnit',
+								},
+							},
 						],
-						type: 'u128',
 					},
 					{
-						name: 'lastValidTs',
-						docs: ['creation ts of vault depositor'],
-						type: 'i64',
+						name: 'Cancel',
 					},
 					{
-						name: 'netDeposits',
-						docs: ['lifetime net deposits of vault depositor for the vault'],
-						type: 'i64',
+						name: 'Withdraw',
 					},
 					{
-						name: 'totalDeposits',
-						docs: ['lifetime total deposits'],
-						type: 'u64',
+						name: 'Fuel',
+					},
+				],
+			},
+		},
+		{
+			name: 'VaultDepositorAction',
+			type: {
+				kind: 'enum',
+				variants: [
+					{
+						name: 'Deposit',
 					},
 					{
-						name: 'totalWithdraws',
-						docs: ['lifetime total withdraws'],
-						type: 'u64',
+						name: 'WithdrawRequest',
 					},
 					{
-						name: 'cumulativeProfitShareAmount',
-						docs: [
-							'the token amount of gains the vault depositor has paid performance fees on',
-						],
-						type: 'i64',
+						name: 'CancelWithdrawRequest',
 					},
 					{
-						name: 'profitShareFeePaid',
-						type: 'u64',
+						name: 'Withdraw',
 					},
 					{
-						name: 'vaultSharesBase',
-						docs: [
-							'The exponent for vault_shares decimal places at the time the tokenized vault depositor was initialized.',
-							'If the vault undergoes a rebase, this TokenizedVaultDepositor can no longer issue new tokens, only redeem',
-							'is possible.',
-						],
-						type: 'u32',
+						name: 'FeePayment',
 					},
 					{
-						name: 'bump',
-						docs: ['The bump for the vault pda'],
-						type: 'u8',
+						name: 'TokenizeShares',
 					},
 					{
-						name: 'padding1',
-						type: {
-							array: ['u8', 3],
-						},
+						name: 'RedeemTokens',
+					},
+				],
+			},
+		},
+		{
+			name: 'LiquidationAction',
+			type: {
+				kind: 'enum',
+				variants: [
+					{
+						name: 'Start',
 					},
 					{
-						name: 'padding',
-						type: {
-							array: ['u64', 10],
-						},
+						name: 'Unwind',
 					},
 				],
 			},
 		},
 		{
-			name: 'vaultDepositor',
+			name: 'WithdrawPayoutDivergence',
 			type: {
-				kind: 'struct',
-				fields: [
+				kind: 'enum',
+				variants: [
 					{
-						name: 'vault',
-						docs: ['The vault deposited into'],
-						type: 'publicKey',
+						name: 'None',
 					},
 					{
-						name: 'pubkey',
-						docs: [
-							"The vault depositor account's pubkey. It is a pda of vault and authority",
-						],
-						type: 'publicKey',
+						name: 'Losses',
+					},
+					{
+						name: 'GainsCapped',
+					},
+				],
+			},
+		},
+		{
+			name: 'FeePolicy',
+			docs: [
+				'How the profit share is charged on gains above a high water mark, the one place fee models differ. The',
+				"management fee is charged the same under every policy. Stored in the vault's",
+				'[`VaultConfig`](crate::state::VaultConfig), see [`VaultConfig::fee_policy`](crate::state::VaultConfig::fee_policy).',
+			],
+			type: {
+				kind: 'enum',
+				variants: [
+					{
+						name: 'HighWaterMark',
+					},
+					{
+						name: 'Hurdle',
+					},
+					{
+						name: 'Benchmark',
+					},
+					{
+						name: 'Flat',
+					},
+				],
+			},
+		},
+		{
+			name: 'ProtocolFeeCollectStep',
+			type: {
+				kind: 'enum',
+				variants: [
+					{
+						name: 'Request',
+					},
+					{
+						name: 'Withdraw',
+					},
+					{
+						name: 'Wait',
+					},
+					{
+						name: 'Skip',
+					},
+				],
+			},
+		},
+		{
+			name: 'BackstopAction',
+			type: {
+				kind: 'enum',
+				variants: [
+					{
+						name: 'Contribute',
+					},
+					{
+						name: 'Claim',
+					},
+				],
+			},
+		},
+		{
+			name: 'DepositValuation',
+			docs: [
+				'The equity deposits are priced at, see [`Vault::calculate_deposit_equity`]. Withdraws always use full marks.',
+			],
+			type: {
+				kind: 'enum',
+				variants: [
+					{
+						name: 'FullMark',
+					},
+					{
+						name: 'HaircutLosses',
+					},
+				],
+			},
+		},
+		{
+			name: 'VaultConfigFlag',
+			docs: ['Bits of [`VaultConfig`] `flags`'],
+			type: {
+				kind: 'enum',
+				variants: [
+					{
+						name: 'DepositorThrottle',
+					},
+					{
+						name: 'VaultGuard',
+					},
+				],
+			},
+		},
+		{
+			name: 'ProfitShareRealization',
+			docs: ["When depositors' gains are profit shared"],
+			type: {
+				kind: 'enum',
+				variants: [
+					{
+						name: 'Unrealized',
+					},
+					{
+						name: 'Realized',
+					},
+				],
+			},
+		},
+		{
+			name: 'WithdrawBlocker',
+			docs: [
+				"Why a depositor's pending withdraw request can't be paid out of the drift user, see",
+				'[`VaultDepositor::simulate_withdraw`].',
+			],
+			type: {
+				kind: 'enum',
+				variants: [
+					{
+						name: 'None',
+					},
+					{
+						name: 'InsufficientCollateral',
+					},
+					{
+						name: 'MarginTradingDisabled',
+					},
+					{
+						name: 'ReduceOnly',
+					},
+					{
+						name: 'MarketPaused',
+					},
+				],
+			},
+		},
+		{
+			name: 'VaultDepositorFlag',
+			docs: ['Bits of [`VaultDepositor`] `flags`'],
+			type: {
+				kind: 'enum',
+				variants: [
+					{
+						name: 'QueuedWithdraw',
+					},
+					{
+						name: 'AutoRenewWithdraw',
+					},
+					{
+						name: 'Counted',
+					},
+					{
+						name: 'Blacklisted',
+					},
+					{
+						name: 'Omnibus',
+					},
+					{
+						name: 'ParkedWithdrawRequests',
+					},
+				],
+			},
+		},
+		{
+			name: 'VaultFeature',
+			docs: [
+				'Optional vault code paths the manager can switch off, stored as bits of [`Vault`](crate::state::Vault)',
+				'`disabled_features`. All features are enabled by default. Exits from a feature already in use (e.g.',
+				'`redeem_tokens`, `claim_withdraw_stream`) are never disabled.',
+			],
+			type: {
+				kind: 'enum',
+				variants: [
+					{
+						name: 'Tokenization',
+					},
+					{
+						name: 'Hooks',
+					},
+					{
+						name: 'DustWithdraw',
+					},
+					{
+						name: 'WithdrawStream',
 					},
 					{
-						name: 'authority',
-						docs: [
-							'The authority is the address w permission to deposit/withdraw',
-						],
-						type: 'publicKey',
+						name: 'Interface',
 					},
 					{
-						name: 'vaultShares',
-						docs: [
-							"share of vault owned by this depositor. vault_shares / vault.total_shares is depositor's ownership of vault_equity",
-						],
-						type: 'u128',
+						name: 'Swaps',
 					},
 					{
-						name: 'lastWithdrawRequest',
-						docs: ['last withdraw request'],
-						type: {
-							defined: 'WithdrawRequest',
-						},
+						name: 'Transfers',
 					},
+				],
+			},
+		},
+		{
+			name: 'FeeHolidayAction',
+			type: {
+				kind: 'enum',
+				variants: [
 					{
-						name: 'lastValidTs',
-						docs: ['creation ts of vault depositor'],
-						type: 'i64',
+						name: 'Schedule',
 					},
 					{
-						name: 'netDeposits',
-						docs: ['lifetime net deposits of vault depositor for the vault'],
-						type: 'i64',
+						name: 'Cancel',
 					},
 					{
-						name: 'totalDeposits',
-						docs: ['lifetime total deposits'],
-						type: 'u64',
+						name: 'Start',
 					},
 					{
-						name: 'totalWithdraws',
-						docs: ['lifetime total withdraws'],
-						type: 'u64',
+						name: 'End',
 					},
+				],
+			},
+		},
+		{
+			name: 'GuardFlag',
+			docs: [
+				'Bits of [`VaultGuard`] `flags`, companion accounts every depositor flow must pass once the manager creates them',
+			],
+			type: {
+				kind: 'enum',
+				variants: [
 					{
-						name: 'cumulativeProfitShareAmount',
-						docs: [
-							'the token amount of gains the vault depositor has paid performance fees on',
-						],
-						type: 'i64',
+						name: 'WithdrawBlackout',
 					},
 					{
-						name: 'profitShareFeePaid',
-						type: 'u64',
+						name: 'Lockup',
 					},
 					{
-						name: 'vaultSharesBase',
-						docs: ['the exponent for vault_shares decimal places'],
-						type: 'u32',
+						name: 'ProfitShareCadence',
 					},
+				],
+			},
+		},
+		{
+			name: 'VaultHookAction',
+			type: {
+				kind: 'enum',
+				variants: [
 					{
-						name: 'lastFuelUpdateTs',
-						type: 'u32',
+						name: 'PreDeposit',
 					},
 					{
-						name: 'cumulativeFuelPerShareAmount',
-						docs: ['precision: FUEL_SHARE_PRECISION'],
-						type: 'u128',
+						name: 'PostDeposit',
 					},
 					{
-						name: 'fuelAmount',
-						docs: ['precision: none'],
-						type: 'u128',
+						name: 'PreWithdraw',
 					},
 					{
-						name: 'padding',
-						type: {
-							array: ['u64', 4],
-						},
+						name: 'PostWithdraw',
 					},
 				],
 			},
 		},
 		{
-			name: 'vaultProtocol',
+			name: 'VaultParam',
 			type: {
-				kind: 'struct',
-				fields: [
+				kind: 'enum',
+				variants: [
 					{
-						name: 'protocol',
-						docs: [
-							'The protocol, company, or entity that services the product using this vault.',
-							'The protocol is not allowed to deposit into the vault but can profit share and collect annual fees just like the manager.',
-						],
-						type: 'publicKey',
+						name: 'RedeemPeriod',
 					},
 					{
-						name: 'protocolProfitAndFeeShares',
-						docs: [
-							'The shares from profit share and annual fee unclaimed by the protocol.',
-						],
-						type: 'u128',
+						name: 'MaxTokens',
 					},
 					{
-						name: 'protocolFee',
-						docs: [
-							'The annual fee charged on deposits by the protocol (traditional hedge funds typically charge 2% per year on assets under management).',
-							"Unlike the management fee this can't be negative.",
-						],
-						type: 'u64',
+						name: 'ManagementFee',
 					},
 					{
-						name: 'protocolTotalWithdraws',
-						docs: ['Total withdraws for the protocol'],
-						type: 'u64',
+						name: 'MinDepositAmount',
 					},
 					{
-						name: 'protocolTotalFee',
-						docs: [
-							'Total fee charged by the protocol (annual management fee + profit share).',
-							"Unlike the management fee this can't be negative.",
-						],
-						type: 'u64',
+						name: 'ProfitShare',
 					},
 					{
-						name: 'protocolTotalProfitShare',
-						docs: ['Total profit share charged by the protocol'],
-						type: 'u64',
+						name: 'HurdleRate',
 					},
 					{
-						name: 'lastProtocolWithdrawRequest',
-						type: {
-							defined: 'WithdrawRequest',
-						},
+						name: 'Permissioned',
 					},
 					{
-						name: 'protocolProfitShare',
-						docs: [
-							'Percentage the protocol charges on all profits realized by depositors: PERCENTAGE_PRECISION',
-						],
-						type: 'u32',
+						name: 'Manager',
 					},
 					{
-						name: 'bump',
-						type: 'u8',
+						name: 'Delegate',
 					},
 					{
-						name: 'version',
-						type: 'u8',
+						name: 'FuelDistributionMode',
 					},
 					{
-						name: 'padding',
-						type: {
-							array: ['u8', 2],
-						},
+						name: 'LargeWithdrawThreshold',
 					},
-				],
-			},
-		},
-		{
-			name: 'vault',
-			type: {
-				kind: 'struct',
-				fields: [
 					{
-						name: 'name',
-						docs: [
-							'The name of the vault. Vault pubkey is derived from this name.',
-						],
-						type: {
-							array: ['u8', 32],
-						},
+						name: 'LargeWithdrawNoticePeriod',
 					},
 					{
-						name: 'pubkey',
-						docs: [
-							"The vault's pubkey. It is a pda of name and also used as the authority for drift user",
-						],
-						type: 'publicKey',
+						name: 'Profile',
 					},
 					{
-						name: 'manager',
-						docs: [
-							'The manager of the vault who has ability to update vault params',
-						],
-						type: 'publicKey',
+						name: 'DisabledFeatures',
 					},
 					{
-						name: 'tokenAccount',
-						docs: [
-							'The vaults token account. Used to receive tokens between deposits and withdrawals',
-						],
-						type: 'publicKey',
+						name: 'MarginTradingEnabled',
 					},
 					{
-						name: 'userStats',
-						docs: ['The drift user stats account for the vault'],
-						type: 'publicKey',
+						name: 'PoolId',
 					},
 					{
-						name: 'user',
-						docs: ['The drift user account for the vault'],
-						type: 'publicKey',
+						name: 'ProfitShareMode',
 					},
 					{
-						name: 'delegate',
-						docs: [
-							'The vaults designated delegate for drift user account',
-							'can differ from actual user delegate if vault is in liquidation',
-						],
-						type: 'publicKey',
+						name: 'RequireSettleCrank',
 					},
 					{
-						name: 'liquidationDelegate',
-						docs: ['The delegate handling liquidation for depositor'],
-						type: 'publicKey',
+						name: 'FuelBoost',
 					},
 					{
-						name: 'userShares',
-						docs: [
-							'The sum of all shares held by the users (vault depositors)',
-						],
-						type: 'u128',
+						name: 'FeePolicy',
 					},
 					{
-						name: 'totalShares',
-						docs: [
-							'The sum of all shares: deposits from users, manager deposits, manager profit/fee, and protocol profit/fee.',
-							'The manager deposits are total_shares - user_shares - protocol_profit_and_fee_shares.',
-						],
-						type: 'u128',
+						name: 'DepositValuation',
 					},
 					{
-						name: 'lastFeeUpdateTs',
-						docs: ['Last fee update unix timestamp'],
-						type: 'i64',
+						name: 'ProfitShareRealization',
 					},
+				],
+			},
+		},
+		{
+			name: 'VaultPollProposal',
+			type: {
+				kind: 'enum',
+				variants: [
 					{
-						name: 'liquidationStartTs',
-						docs: ['When the liquidation starts'],
-						type: 'i64',
+						name: 'ReplaceManager',
 					},
 					{
-						name: 'redeemPeriod',
-						docs: [
-							'The period (in seconds) that a vault depositor must wait after requesting a withdrawal to finalize withdrawal.',
-							'Currently, the maximum is 90 days.',
-						],
-						type: 'i64',
+						name: 'WindDown',
 					},
+				],
+			},
+		},
+		{
+			name: 'VaultProfile',
+			docs: [
+				'Positions a vault promises its depositors the drift user will hold. Ordered from least to most strict, a vault',
+				'can only move to a stricter profile.',
+			],
+			type: {
+				kind: 'enum',
+				variants: [
 					{
-						name: 'totalWithdrawRequested',
-						docs: ['The sum of all outstanding withdraw requests'],
-						type: 'u64',
+						name: 'Unrestricted',
 					},
 					{
-						name: 'maxTokens',
-						docs: [
-							'Max token capacity, once hit/passed vault will reject new deposits (updatable)',
-						],
-						type: 'u64',
+						name: 'SpotAndPrediction',
 					},
 					{
-						name: 'managementFee',
-						docs: [
-							'The annual fee charged on deposits by the manager.',
-							'Traditional funds typically charge 2% per year on assets under management.',
-						],
-						type: 'i64',
+						name: 'SpotOnly',
 					},
 					{
-						name: 'initTs',
-						docs: ['Timestamp vault initialized'],
-						type: 'i64',
+						name: 'YieldOnly',
+					},
+				],
+			},
+		},
+		{
+			name: 'VaultProtocolFlag',
+			docs: ['Bits of [`VaultProtocol`] `flags`'],
+			type: {
+				kind: 'enum',
+				variants: [
+					{
+						name: 'DelegateDualApproval',
 					},
 					{
-						name: 'netDeposits',
-						docs: ['The net deposits for the vault'],
-						type: 'i64',
+						name: 'TvlCap',
 					},
 					{
-						name: 'managerNetDeposits',
-						docs: ['The net deposits for the manager'],
-						type: 'i64',
+						name: 'ProtocolFeeWaived',
+					},
+				],
+			},
+		},
+		{
+			name: 'RiskBand',
+			type: {
+				kind: 'enum',
+				variants: [
+					{
+						name: 'Low',
 					},
 					{
-						name: 'totalDeposits',
-						docs: ['Total deposits'],
-						type: 'u64',
+						name: 'Medium',
 					},
 					{
-						name: 'totalWithdraws',
-						docs: ['Total withdraws'],
-						type: 'u64',
+						name: 'High',
 					},
 					{
-						name: 'managerTotalDeposits',
-						docs: ['Total deposits for the manager'],
-						type: 'u64',
+						name: 'Extreme',
+					},
+				],
+			},
+		},
+		{
+			name: 'VaultTemplate',
+			docs: [
+				'Protocol-curated vault presets for [`initialize_vault_from_template`](crate::instructions::initialize_vault_from_template).',
+			],
+			type: {
+				kind: 'enum',
+				variants: [
+					{
+						name: 'Conservative',
 					},
 					{
-						name: 'managerTotalWithdraws',
-						docs: ['Total withdraws for the manager'],
-						type: 'u64',
+						name: 'MarketMaking',
 					},
 					{
-						name: 'managerTotalFee',
-						docs: ['Total management fee accrued by the manager'],
-						type: 'i64',
+						name: 'Leveraged',
 					},
+				],
+			},
+		},
+		{
+			name: 'VaultFlag',
+			docs: ['Bits of [`Vault`] `flags`'],
+			type: {
+				kind: 'enum',
+				variants: [
 					{
-						name: 'managerTotalProfitShare',
-						docs: ['Total profit share accrued by the manager'],
-						type: 'u64',
+						name: 'Registered',
 					},
 					{
-						name: 'minDepositAmount',
-						docs: ['The minimum deposit amount'],
-						type: 'u64',
+						name: 'HasParamChangeLog',
 					},
 					{
-						name: 'lastManagerWithdrawRequest',
-						type: {
-							defined: 'WithdrawRequest',
-						},
+						name: 'HasDepositorCap',
 					},
 					{
-						name: 'sharesBase',
-						docs: [
-							'The base 10 exponent of the shares (given massive share inflation can occur at near zero vault equity)',
-						],
-						type: 'u32',
+						name: 'ManagementFeeHoliday',
 					},
 					{
-						name: 'profitShare',
-						docs: [
-							'Percentage the manager charges on all profits realized by depositors: PERCENTAGE_PRECISION',
-						],
-						type: 'u32',
+						name: 'ProfitShareHoliday',
 					},
 					{
-						name: 'hurdleRate',
-						docs: [
-							'Vault manager only collect incentive fees during periods when returns are higher than this amount: PERCENTAGE_PRECISION',
-						],
-						type: 'u32',
+						name: 'HasVaultConfig',
 					},
 					{
-						name: 'spotMarketIndex',
-						docs: [
-							'The spot market index the vault deposits into/withdraws from',
-						],
-						type: 'u16',
+						name: 'WithdrawReceipts',
 					},
 					{
-						name: 'bump',
-						docs: ['The bump for the vault pda'],
-						type: 'u8',
+						name: 'RequireSettleCrank',
 					},
+				],
+			},
+		},
+		{
+			name: 'FuelDistributionMode',
+			type: {
+				kind: 'enum',
+				variants: [
 					{
-						name: 'permissioned',
-						docs: ['Whether anybody can be a depositor'],
-						type: 'bool',
+						name: 'UsersOnly',
 					},
 					{
-						name: 'vaultProtocol',
-						docs: ['The optional [`VaultProtocol`] account.'],
-						type: 'bool',
+						name: 'UsersAndManager',
 					},
+				],
+			},
+		},
+		{
+			name: 'ProfitShareMode',
+			type: {
+				kind: 'enum',
+				variants: [
 					{
-						name: 'fuelDistributionMode',
-						docs: [
-							'How fuel distribution should be treated [`FuelDistributionMode`]. Default is `UsersOnly`',
-						],
-						type: 'u8',
+						name: 'HighWaterMark',
 					},
 					{
-						name: 'padding1',
-						type: {
-							array: ['u8', 2],
-						},
+						name: 'LossesFirst',
 					},
 					{
-						name: 'lastCumulativeFuelPerShareTs',
-						docs: ['The timestamp cumulative_fuel_per_share was last updated'],
-						type: 'u32',
+						name: 'PeakEquity',
 					},
+				],
+			},
+		},
+		{
+			name: 'WithdrawUnit',
+			type: {
+				kind: 'enum',
+				variants: [
 					{
-						name: 'cumulativeFuelPerShare',
-						docs: [
-							'The cumulative fuel per share (scaled up by 1e6 to avoid losing precision)',
-						],
-						type: 'u128',
+						name: 'Shares',
 					},
 					{
-						name: 'cumulativeFuel',
-						docs: ['The total fuel accumulated'],
-						type: 'u128',
+						name: 'Token',
 					},
 					{
-						name: 'padding',
-						type: {
-							array: ['u64', 3],
-						},
+						name: 'SharesPercent',
 					},
 				],
 			},
 		},
-	],
-	types: [
+	],
+	events: [
+		{
+			name: 'VaultRecord',
+			fields: [
+				{
+					name: 'ts',
+					type: 'i64',
+					index: false,
+				},
+				{
+					name: 'spotMarketIndex',
+					type: 'u16',
+					index: false,
+				},
+				{
+					name: 'vaultEquityBefore',
+					type: 'u64',
+					index: false,
+				},
+			],
+		},
+		{
+			name: 'VaultDepositorRecord',
+			fields: [
+				{
+					name: 'ts',
+					type: 'i64',
+					index: false,
+				},
+				{
+					name: 'vault',
+					type: 'publicKey',
+					index: false,
+				},
+				{
+					name: 'depositorAuthority',
+					type: 'publicKey',
+					index: false,
+				},
+				{
+					name: 'action',
+					type: {
+						defined: 'VaultDepositorAction',
+					},
+					index: false,
+				},
+				{
+					name: 'amount',
+					type: 'u64',
+					index: false,
+				},
+				{
+					name: 'spotMarketIndex',
+					type: 'u16',
+					index: false,
+				},
+				{
+					name: 'vaultSharesBefore',
+					type: 'u128',
+					index: false,
+				},
+				{
+					name: 'vaultSharesAfter',
+					type: 'u128',
+					index: false,
+				},
+				{
+					name: 'vaultEquityBefore',
+					type: 'u64',
+					index: false,
+				},
+				{
+					name: 'userVaultSharesBefore',
+					type: 'u128',
+					index: false,
+				},
+				{
+					name: 'totalVaultSharesBefore',
+					type: 'u128',
+					index: false,
+				},
+				{
+					name: 'userVaultSharesAfter',
+					type: 'u128',
+					index: false,
+				},
+				{
+					name: 'totalVaultSharesAfter',
+					type: 'u128',
+					index: false,
+				},
+				{
+					name: 'profitShare',
+					type: 'u64',
+					index: false,
+				},
+				{
+					name: 'managementFee',
+					type: 'i64',
+					index: false,
+				},
+				{
+					name: 'managementFeeShares',
+					type: 'i64',
+					index: false,
+				},
+				{
+					name: 'depositOraclePrice',
+					type: 'i64',
+					index: false,
+				},
+			],
+		},
+		{
+			name: 'VaultDepositorV1Record',
+			fields: [
+				{
+					name: 'ts',
+					type: 'i64',
+					index: false,
+				},
+				{
+					name: 'vault',
+					type: 'publicKey',
+					index: false,
+				},
+				{
+					name: 'depositorAuthority',
+					type: 'publicKey',
+					index: false,
+				},
+				{
+					name: 'action',
+					type: {
+						defined: 'VaultDepositorAction',
+					},
+					index: false,
+				},
+				{
+					name: 'amount',
+					type: 'u64',
+					index: false,
+				},
+				{
+					name: 'spotMarketIndex',
+					type: 'u16',
+					index: false,
+				},
+				{
+					name: 'vaultSharesBefore',
+					type: 'u128',
+					index: false,
+				},
+				{
+					name: 'vaultSharesAfter',
+					type: 'u128',
+					index: false,
+				},
+				{
+					name: 'vaultEquityBefore',
+					type: 'u64',
+					index: false,
+				},
+				{
+					name: 'userVaultSharesBefore',
+					type: 'u128',
+					index: false,
+				},
+				{
+					name: 'totalVaultSharesBefore',
+					type: 'u128',
+					index: false,
+				},
+				{
+					name: 'userVaultSharesAfter',
+					type: 'u128',
+					index: false,
+				},
+				{
+					name: 'totalVaultSharesAfter',
+					type: 'u128',
+					index: false,
+				},
+				{
+					name: 'protocolSharesBefore',
+					type: 'u128',
+					index: false,
+				},
+				{
+					name: 'protocolSharesAfter',
+					type: 'u128',
+					index: false,
+				},
+				{
+					name: 'protocolProfitShare',
+					type: 'u64',
+					index: false,
+				},
+				{
+					name: 'protocolFee',
+					type: 'i64',
+					index: false,
+				},
+				{
+					name: 'protocolFeeShares',
+					type: 'i64',
+					index: false,
+				},
+				{
+					name: 'managerProfitShare',
+					type: 'u64',
+					index: false,
+				},
+				{
+					name: 'managementFee',
+					type: 'i64',
+					index: false,
+				},
+				{
+					name: 'managementFeeShares',
+					type: 'i64',
+					index: false,
+				},
+				{
+					name: 'depositOraclePrice',
+					type: 'i64',
+					index: false,
+				},
+			],
+		},
 		{
-			name: 'InitializeTokenizedVaultDepositorParams',
-			type: {
-				kind: 'struct',
-				fields: [
-					{
-						name: 'tokenName',
-						type: 'string',
-					},
-					{
-						name: 'tokenSymbol',
-						type: 'string',
-					},
-					{
-						name: 'tokenUri',
-						type: 'string',
-					},
-					{
-						name: 'decimals',
-						type: 'u8',
-					},
-				],
-			},
+			name: 'VaultDepositorLotRecord',
+			fields: [
+				{
+					name: 'ts',
+					type: 'i64',
+					index: false,
+				},
+				{
+					name: 'vault',
+					type: 'publicKey',
+					index: false,
+				},
+				{
+					name: 'depositorAuthority',
+					type: 'publicKey',
+					index: false,
+				},
+				{
+					name: 'spotMarketIndex',
+					type: 'u16',
+					index: false,
+				},
+				{
+					name: 'shares',
+					type: 'u128',
+					index: false,
+				},
+				{
+					name: 'costBasis',
+					type: 'u64',
+					index: false,
+				},
+				{
+					name: 'proceeds',
+					type: 'u64',
+					index: false,
+				},
+				{
+					name: 'entrySharePrice',
+					type: 'u64',
+					index: false,
+				},
+				{
+					name: 'exitSharePrice',
+					type: 'u64',
+					index: false,
+				},
+				{
+					name: 'holdingPeriod',
+					type: 'i64',
+					index: false,
+				},
+				{
+					name: 'profitShareFeePaid',
+					type: 'u64',
+					index: false,
+				},
+			],
 		},
 		{
-			name: 'VaultWithProtocolParams',
-			type: {
-				kind: 'struct',
-				fields: [
-					{
-						name: 'name',
-						type: {
-							array: ['u8', 32],
-						},
-					},
-					{
-						name: 'redeemPeriod',
-						type: 'i64',
-					},
-					{
-						name: 'maxTokens',
-						type: 'u64',
-					},
-					{
-						name: 'managementFee',
-						type: 'i64',
-					},
-					{
-						name: 'minDepositAmount',
-						type: 'u64',
-					},
-					{
-						name: 'profitShare',
-						type: 'u32',
-					},
-					{
-						name: 'hurdleRate',
-						type: 'u32',
-					},
-					{
-						name: 'spotMarketIndex',
-						type: 'u16',
+			name: 'InterfaceRecord',
+			fields: [
+				{
+					name: 'ts',
+					type: 'i64',
+					index: false,
+				},
+				{
+					name: 'vault',
+					type: 'publicKey',
+					index: false,
+				},
+				{
+					name: 'depositorAuthority',
+					type: 'publicKey',
+					index: false,
+				},
+				{
+					name: 'interface',
+					type: 'publicKey',
+					index: false,
+				},
+				{
+					name: 'action',
+					type: {
+						defined: 'VaultDepositorAction',
 					},
-					{
-						name: 'permissioned',
-						type: 'bool',
+					index: false,
+				},
+				{
+					name: 'amount',
+					type: 'u64',
+					index: false,
+				},
+				{
+					name: 'spotMarketIndex',
+					type: 'u16',
+					index: false,
+				},
+				{
+					name: 'interfaceFee',
+					type: 'u64',
+					index: false,
+				},
+			],
+		},
+		{
+			name: 'VaultDepositorMemoRecord',
+			fields: [
+				{
+					name: 'ts',
+					type: 'i64',
+					index: false,
+				},
+				{
+					name: 'vault',
+					type: 'publicKey',
+					index: false,
+				},
+				{
+					name: 'vaultDepositor',
+					type: 'publicKey',
+					index: false,
+				},
+				{
+					name: 'depositorAuthority',
+					type: 'publicKey',
+					index: false,
+				},
+				{
+					name: 'memoBefore',
+					type: {
+						array: ['u8', 32],
 					},
-					{
-						name: 'vaultProtocol',
-						type: {
-							defined: 'VaultProtocolParams',
-						},
+					index: false,
+				},
+				{
+					name: 'memoAfter',
+					type: {
+						array: ['u8', 32],
 					},
-				],
-			},
+					index: false,
+				},
+			],
 		},
 		{
-			name: 'VaultProtocolParams',
-			type: {
-				kind: 'struct',
-				fields: [
-					{
-						name: 'protocol',
-						type: 'publicKey',
-					},
-					{
-						name: 'protocolFee',
-						type: 'u64',
-					},
-					{
-						name: 'protocolProfitShare',
-						type: 'u32',
-					},
-				],
-			},
+			name: 'VaultHealthRecord',
+			fields: [
+				{
+					name: 'ts',
+					type: 'i64',
+					index: false,
+				},
+				{
+					name: 'vault',
+					type: 'publicKey',
+					index: false,
+				},
+				{
+					name: 'withdrawAmount',
+					type: 'u64',
+					index: false,
+				},
+				{
+					name: 'totalCollateral',
+					type: 'i128',
+					index: false,
+				},
+				{
+					name: 'maintenanceMarginRequirement',
+					type: 'u128',
+					index: false,
+				},
+				{
+					name: 'freeCollateral',
+					type: 'u128',
+					index: false,
+				},
+				{
+					name: 'marginRatio',
+					type: 'u64',
+					index: false,
+				},
+				{
+					name: 'depositorCount',
+					type: 'u32',
+					index: false,
+				},
+				{
+					name: 'activeWithdrawRequestCount',
+					type: 'u32',
+					index: false,
+				},
+				{
+					name: 'lastManagerActivityTs',
+					type: 'i64',
+					index: false,
+				},
+			],
 		},
 		{
-			name: 'VaultParams',
-			type: {
-				kind: 'struct',
-				fields: [
-					{
-						name: 'name',
-						type: {
-							array: ['u8', 32],
-						},
-					},
-					{
-						name: 'redeemPeriod',
-						type: 'i64',
-					},
-					{
-						name: 'maxTokens',
-						type: 'u64',
-					},
-					{
-						name: 'managementFee',
-						type: 'i64',
-					},
-					{
-						name: 'minDepositAmount',
-						type: 'u64',
-					},
-					{
-						name: 'profitShare',
-						type: 'u32',
-					},
-					{
-						name: 'hurdleRate',
-						type: 'u32',
+			name: 'VaultParamChangeRecord',
+			fields: [
+				{
+					name: 'ts',
+					type: 'i64',
+					index: false,
+				},
+				{
+					name: 'vault',
+					type: 'publicKey',
+					index: false,
+				},
+				{
+					name: 'signer',
+					type: 'publicKey',
+					index: false,
+				},
+				{
+					name: 'param',
+					type: {
+						defined: 'crate::state::VaultParam',
 					},
-					{
-						name: 'spotMarketIndex',
-						type: 'u16',
+					index: false,
+				},
+				{
+					name: 'oldValue',
+					type: {
+						array: ['u8', 32],
 					},
-					{
-						name: 'permissioned',
-						type: 'bool',
+					index: false,
+				},
+				{
+					name: 'newValue',
+					type: {
+						array: ['u8', 32],
 					},
-				],
-			},
+					index: false,
+				},
+			],
 		},
 		{
-			name: 'UpdateVaultProtocolParams',
-			type: {
-				kind: 'struct',
-				fields: [
-					{
-						name: 'protocolFee',
-						type: {
-							option: 'u64',
-						},
+			name: 'FeeHolidayRecord',
+			fields: [
+				{
+					name: 'ts',
+					type: 'i64',
+					index: false,
+				},
+				{
+					name: 'vault',
+					type: 'publicKey',
+					index: false,
+				},
+				{
+					name: 'action',
+					type: {
+						defined: 'crate::state::FeeHolidayAction',
 					},
-					{
-						name: 'protocolProfitShare',
-						type: {
-							option: 'u32',
-						},
+					index: false,
+				},
+				{
+					name: 'startTs',
+					type: 'i64',
+					index: false,
+				},
+				{
+					name: 'endTs',
+					type: 'i64',
+					index: false,
+				},
+				{
+					name: 'suspendManagementFee',
+					type: 'bool',
+					index: false,
+				},
+				{
+					name: 'suspendProfitShare',
+					type: 'bool',
+					index: false,
+				},
+			],
+		},
+		{
+			name: 'FeeSplitRecord',
+			fields: [
+				{
+					name: 'ts',
+					type: 'i64',
+					index: false,
+				},
+				{
+					name: 'vault',
+					type: 'publicKey',
+					index: false,
+				},
+				{
+					name: 'recipient',
+					type: 'publicKey',
+					index: false,
+				},
+				{
+					name: 'amount',
+					type: 'u64',
+					index: false,
+				},
+				{
+					name: 'shares',
+					type: 'u128',
+					index: false,
+				},
+				{
+					name: 'owed',
+					type: 'u64',
+					index: false,
+				},
+				{
+					name: 'totalPaid',
+					type: 'u64',
+					index: false,
+				},
+			],
+		},
+		{
+			name: 'VaultReportRecord',
+			fields: [
+				{
+					name: 'ts',
+					type: 'i64',
+					index: false,
+				},
+				{
+					name: 'vault',
+					type: 'publicKey',
+					index: false,
+				},
+				{
+					name: 'epoch',
+					type: 'u32',
+					index: false,
+				},
+				{
+					name: 'reportHash',
+					type: {
+						array: ['u8', 32],
 					},
-				],
-			},
+					index: false,
+				},
+			],
 		},
 		{
-			name: 'UpdateVaultParams',
-			type: {
-				kind: 'struct',
-				fields: [
-					{
-						name: 'redeemPeriod',
-						type: {
-							option: 'i64',
-						},
-					},
-					{
-						name: 'maxTokens',
-						type: {
-							option: 'u64',
-						},
-					},
-					{
-						name: 'managementFee',
-						type: {
-							option: 'i64',
-						},
-					},
-					{
-						name: 'minDepositAmount',
-						type: {
-							option: 'u64',
-						},
-					},
-					{
-						name: 'profitShare',
-						type: {
-							option: 'u32',
-						},
-					},
-					{
-						name: 'hurdleRate',
-						type: {
-							option: 'u32',
-						},
-					},
-					{
-						name: 'permissioned',
-						type: {
-							option: 'bool',
-						},
+			name: 'VaultShareStatementRecord',
+			fields: [
+				{
+					name: 'ts',
+					type: 'i64',
+					index: false,
+				},
+				{
+					name: 'vault',
+					type: 'publicKey',
+					index: false,
+				},
+				{
+					name: 'epoch',
+					type: 'u32',
+					index: false,
+				},
+				{
+					name: 'root',
+					type: {
+						array: ['u8', 32],
 					},
-				],
-			},
+					index: false,
+				},
+				{
+					name: 'numDepositors',
+					type: 'u32',
+					index: false,
+				},
+				{
+					name: 'totalShares',
+					type: 'u128',
+					index: false,
+				},
+				{
+					name: 'sharesBase',
+					type: 'u32',
+					index: false,
+				},
+			],
 		},
 		{
-			name: 'WithdrawRequest',
-			type: {
-				kind: 'struct',
-				fields: [
-					{
-						name: 'shares',
-						docs: ['request shares of vault withdraw'],
-						type: 'u128',
-					},
-					{
-						name: 'value',
-						docs: [
-							'requested value (in vault spot_market_index) of shares for withdraw',
-						],
-						type: 'u64',
+			name: 'VaultDepositorBatchRecord',
+			fields: [
+				{
+					name: 'ts',
+					type: 'i64',
+					index: false,
+				},
+				{
+					name: 'vault',
+					type: 'publicKey',
+					index: false,
+				},
+				{
+					name: 'action',
+					type: {
+						defined: 'VaultDepositorAction',
 					},
-					{
-						name: 'ts',
-						docs: ['request ts of vault withdraw'],
-						type: 'i64',
+					index: false,
+				},
+				{
+					name: 'depositorCount',
+					type: 'u32',
+					index: false,
+				},
+				{
+					name: 'vaultEquityBefore',
+					type: 'u64',
+					index: false,
+				},
+				{
+					name: 'vaultSharesBefore',
+					type: 'u128',
+					index: false,
+				},
+				{
+					name: 'vaultSharesAfter',
+					type: 'u128',
+					index: false,
+				},
+				{
+					name: 'totalVaultSharesAfter',
+					type: 'u128',
+					index: false,
+				},
+				{
+					name: 'managerProfitShare',
+					type: 'u64',
+					index: false,
+				},
+				{
+					name: 'protocolProfitShare',
+					type: 'u64',
+					index: false,
+				},
+				{
+					name: 'deltasRoot',
+					type: {
+						array: ['u8', 32],
 					},
-				],
-			},
+					index: false,
+				},
+			],
 		},
 		{
-			name: 'VaultDepositorAction',
-			type: {
-				kind: 'enum',
-				variants: [
-					{
-						name: 'Deposit',
-					},
-					{
-						name: 'WithdrawRequest',
-					},
-					{
-						name: 'CancelWithdrawRequest',
-					},
-					{
-						name: 'Withdraw',
-					},
-					{
-						name: 'FeePayment',
-					},
-					{
-						name: 'TokenizeShares',
-					},
-					{
-						name: 'RedeemTokens',
-					},
-				],
-			},
+			name: 'VaultRebaseRecord',
+			fields: [
+				{
+					name: 'ts',
+					type: 'i64',
+					index: false,
+				},
+				{
+					name: 'vault',
+					type: 'publicKey',
+					index: false,
+				},
+				{
+					name: 'vaultEquity',
+					type: 'u64',
+					index: false,
+				},
+				{
+					name: 'sharesBaseBefore',
+					type: 'u32',
+					index: false,
+				},
+				{
+					name: 'sharesBaseAfter',
+					type: 'u32',
+					index: false,
+				},
+				{
+					name: 'rebaseDivisor',
+					type: 'u128',
+					index: false,
+				},
+			],
+		},
+		{
+			name: 'ShareTransferRecord',
+			fields: [
+				{
+					name: 'ts',
+					type: 'i64',
+					index: false,
+				},
+				{
+					name: 'vault',
+					type: 'publicKey',
+					index: false,
+				},
+				{
+					name: 'fromVaultDepositor',
+					type: 'publicKey',
+					index: false,
+				},
+				{
+					name: 'toVaultDepositor',
+					type: 'publicKey',
+					index: false,
+				},
+				{
+					name: 'shares',
+					type: 'u128',
+					index: false,
+				},
+				{
+					name: 'value',
+					type: 'u64',
+					index: false,
+				},
+				{
+					name: 'fromDepositorSharesBefore',
+					type: 'u128',
+					index: false,
+				},
+				{
+					name: 'fromDepositorSharesAfter',
+					type: 'u128',
+					index: false,
+				},
+				{
+					name: 'toDepositorSharesBefore',
+					type: 'u128',
+					index: false,
+				},
+				{
+					name: 'toDepositorSharesAfter',
+					type: 'u128',
+					index: false,
+				},
+			],
 		},
 		{
-			name: 'FuelDistributionMode',
-			type: {
-				kind: 'enum',
-				variants: [
-					{
-						name: 'UsersOnly',
-					},
-					{
-						name: 'UsersAndManager',
-					},
-				],
-			},
+			name: 'VaultPerformanceRecord',
+			fields: [
+				{
+					name: 'ts',
+					type: 'i64',
+					index: false,
+				},
+				{
+					name: 'vault',
+					type: 'publicKey',
+					index: false,
+				},
+				{
+					name: 'periodStartTs',
+					type: 'i64',
+					index: false,
+				},
+				{
+					name: 'equityPerShare',
+					type: 'u128',
+					index: false,
+				},
+				{
+					name: 'periodReturn',
+					type: 'i64',
+					index: false,
+				},
+				{
+					name: 'benchmarkReturn',
+					type: 'i64',
+					index: false,
+				},
+				{
+					name: 'feesCharged',
+					type: 'u64',
+					index: false,
+				},
+				{
+					name: 'depositorEquity',
+					type: 'u64',
+					index: false,
+				},
+				{
+					name: 'netDepositorReturn',
+					type: 'i64',
+					index: false,
+				},
+				{
+					name: 'interestPnl',
+					type: 'i64',
+					index: false,
+				},
+				{
+					name: 'tradingPnl',
+					type: 'i64',
+					index: false,
+				},
+			],
 		},
 		{
-			name: 'WithdrawUnit',
-			type: {
-				kind: 'enum',
-				variants: [
-					{
-						name: 'Shares',
-					},
-					{
-						name: 'Token',
-					},
-					{
-						name: 'SharesPercent',
-					},
-				],
-			},
+			name: 'ShareClassConversionRecord',
+			fields: [
+				{
+					name: 'ts',
+					type: 'i64',
+					index: false,
+				},
+				{
+					name: 'vault',
+					type: 'publicKey',
+					index: false,
+				},
+				{
+					name: 'depositorAuthority',
+					type: 'publicKey',
+					index: false,
+				},
+				{
+					name: 'locked',
+					type: 'bool',
+					index: false,
+				},
+				{
+					name: 'shares',
+					type: 'u128',
+					index: false,
+				},
+				{
+					name: 'holderLockedSharesAfter',
+					type: 'u128',
+					index: false,
+				},
+				{
+					name: 'classLockedSharesAfter',
+					type: 'u128',
+					index: false,
+				},
+				{
+					name: 'unlockTs',
+					type: 'i64',
+					index: false,
+				},
+				{
+					name: 'profitShareDiscount',
+					type: 'u32',
+					index: false,
+				},
+			],
 		},
-	],
-	events: [
 		{
-			name: 'VaultRecord',
+			name: 'WithdrawPriorityBoostRecord',
 			fields: [
 				{
 					name: 'ts',
@@ -5517,19 +27841,44 @@ export const IDL: DriftVaults = {
 					index: false,
 				},
 				{
-					name: 'spotMarketIndex',
-					type: 'u16',
+					name: 'vault',
+					type: 'publicKey',
 					index: false,
 				},
 				{
-					name: 'vaultEquityBefore',
+					name: 'depositorAuthority',
+					type: 'publicKey',
+					index: false,
+				},
+				{
+					name: 'priorityFee',
+					type: 'u32',
+					index: false,
+				},
+				{
+					name: 'sharesForfeited',
+					type: 'u128',
+					index: false,
+				},
+				{
+					name: 'valueForfeited',
 					type: 'u64',
 					index: false,
 				},
+				{
+					name: 'requestTsBefore',
+					type: 'i64',
+					index: false,
+				},
+				{
+					name: 'requestTsAfter',
+					type: 'i64',
+					index: false,
+				},
 			],
 		},
 		{
-			name: 'VaultDepositorRecord',
+			name: 'LiquidationActionRecord',
 			fields: [
 				{
 					name: 'ts',
@@ -5541,6 +27890,11 @@ export const IDL: DriftVaults = {
 					type: 'publicKey',
 					index: false,
 				},
+				{
+					name: 'liquidationDelegate',
+					type: 'publicKey',
+					index: false,
+				},
 				{
 					name: 'depositorAuthority',
 					type: 'publicKey',
@@ -5549,79 +27903,186 @@ export const IDL: DriftVaults = {
 				{
 					name: 'action',
 					type: {
-						defined: 'VaultDepositorAction',
+						defined: 'LiquidationAction',
 					},
 					index: false,
 				},
 				{
-					name: 'amount',
+					name: 'nextOrderId',
+					type: 'u32',
+					index: false,
+				},
+				{
+					name: 'totalCollateral',
+					type: 'i128',
+					index: false,
+				},
+				{
+					name: 'maintenanceMarginRequirement',
+					type: 'u128',
+					index: false,
+				},
+				{
+					name: 'perpLiabilityValue',
+					type: 'u128',
+					index: false,
+				},
+				{
+					name: 'spotLiabilityValue',
+					type: 'u128',
+					index: false,
+				},
+			],
+		},
+		{
+			name: 'VaultCrystallizationRecord',
+			fields: [
+				{
+					name: 'ts',
+					type: 'i64',
+					index: false,
+				},
+				{
+					name: 'vault',
+					type: 'publicKey',
+					index: false,
+				},
+				{
+					name: 'vaultEquity',
 					type: 'u64',
 					index: false,
 				},
 				{
-					name: 'spotMarketIndex',
-					type: 'u16',
+					name: 'sharePrice',
+					type: 'u128',
 					index: false,
 				},
 				{
-					name: 'vaultSharesBefore',
+					name: 'highWaterMarkBefore',
 					type: 'u128',
 					index: false,
 				},
 				{
-					name: 'vaultSharesAfter',
+					name: 'highWaterMarkAfter',
 					type: 'u128',
 					index: false,
 				},
 				{
-					name: 'vaultEquityBefore',
+					name: 'depositorProfit',
 					type: 'u64',
 					index: false,
 				},
 				{
-					name: 'userVaultSharesBefore',
+					name: 'profitSharePaid',
+					type: 'u64',
+					index: false,
+				},
+				{
+					name: 'profitShareShares',
 					type: 'u128',
 					index: false,
 				},
+			],
+		},
+		{
+			name: 'BackstopRecord',
+			fields: [
 				{
-					name: 'totalVaultSharesBefore',
+					name: 'ts',
+					type: 'i64',
+					index: false,
+				},
+				{
+					name: 'vault',
+					type: 'publicKey',
+					index: false,
+				},
+				{
+					name: 'action',
+					type: {
+						defined: 'crate::state::BackstopAction',
+					},
+					index: false,
+				},
+				{
+					name: 'amount',
+					type: 'u64',
+					index: false,
+				},
+				{
+					name: 'shares',
 					type: 'u128',
 					index: false,
 				},
 				{
-					name: 'userVaultSharesAfter',
+					name: 'sharePrice',
 					type: 'u128',
 					index: false,
 				},
 				{
-					name: 'totalVaultSharesAfter',
+					name: 'highWaterMark',
 					type: 'u128',
 					index: false,
 				},
 				{
-					name: 'profitShare',
-					type: 'u64',
+					name: 'balanceAfter',
+					type: 'u64',
+					index: false,
+				},
+				{
+					name: 'owedAfter',
+					type: 'u64',
+					index: false,
+				},
+			],
+		},
+		{
+			name: 'ProfitShareRefundRecord',
+			fields: [
+				{
+					name: 'ts',
+					type: 'i64',
+					index: false,
+				},
+				{
+					name: 'vault',
+					type: 'publicKey',
+					index: false,
+				},
+				{
+					name: 'profitShareBefore',
+					type: 'u32',
+					index: false,
+				},
+				{
+					name: 'profitShareAfter',
+					type: 'u32',
 					index: false,
 				},
 				{
-					name: 'managementFee',
+					name: 'lookbackStartTs',
 					type: 'i64',
 					index: false,
 				},
 				{
-					name: 'managementFeeShares',
-					type: 'i64',
+					name: 'profitShareInLookback',
+					type: 'u64',
 					index: false,
 				},
 				{
-					name: 'depositOraclePrice',
-					type: 'i64',
+					name: 'amount',
+					type: 'u64',
+					index: false,
+				},
+				{
+					name: 'shares',
+					type: 'u128',
 					index: false,
 				},
 			],
 		},
 		{
-			name: 'VaultDepositorV1Record',
+			name: 'WithdrawPayoutRecord',
 			fields: [
 				{
 					name: 'ts',
@@ -5634,111 +28095,116 @@ export const IDL: DriftVaults = {
 					index: false,
 				},
 				{
-					name: 'depositorAuthority',
+					name: 'authority',
 					type: 'publicKey',
 					index: false,
 				},
 				{
-					name: 'action',
-					type: {
-						defined: 'VaultDepositorAction',
-					},
-					index: false,
-				},
-				{
-					name: 'amount',
+					name: 'requestedValue',
 					type: 'u64',
 					index: false,
 				},
 				{
-					name: 'spotMarketIndex',
-					type: 'u16',
+					name: 'currentValue',
+					type: 'u64',
 					index: false,
 				},
 				{
-					name: 'vaultSharesBefore',
-					type: 'u128',
+					name: 'payout',
+					type: 'u64',
 					index: false,
 				},
 				{
-					name: 'vaultSharesAfter',
-					type: 'u128',
+					name: 'shortfall',
+					type: 'u64',
 					index: false,
 				},
 				{
-					name: 'vaultEquityBefore',
+					name: 'cappedGains',
 					type: 'u64',
 					index: false,
 				},
 				{
-					name: 'userVaultSharesBefore',
-					type: 'u128',
+					name: 'cause',
+					type: {
+						defined: 'WithdrawPayoutDivergence',
+					},
 					index: false,
 				},
+			],
+		},
+		{
+			name: 'TokenizedSharesExchangeRateRecord',
+			fields: [
 				{
-					name: 'totalVaultSharesBefore',
-					type: 'u128',
+					name: 'ts',
+					type: 'i64',
 					index: false,
 				},
 				{
-					name: 'userVaultSharesAfter',
-					type: 'u128',
+					name: 'vault',
+					type: 'publicKey',
 					index: false,
 				},
 				{
-					name: 'totalVaultSharesAfter',
-					type: 'u128',
+					name: 'mint',
+					type: 'publicKey',
 					index: false,
 				},
 				{
-					name: 'protocolSharesBefore',
-					type: 'u128',
+					name: 'vaultSharesBase',
+					type: 'u32',
 					index: false,
 				},
 				{
-					name: 'protocolSharesAfter',
+					name: 'vaultShares',
 					type: 'u128',
 					index: false,
 				},
 				{
-					name: 'protocolProfitShare',
+					name: 'mintSupply',
 					type: 'u64',
 					index: false,
 				},
 				{
-					name: 'protocolFee',
-					type: 'i64',
+					name: 'tokenPrice',
+					type: 'u64',
 					index: false,
 				},
+			],
+		},
+		{
+			name: 'WithdrawBlackoutRecord',
+			fields: [
 				{
-					name: 'protocolFeeShares',
+					name: 'ts',
 					type: 'i64',
 					index: false,
 				},
 				{
-					name: 'managerProfitShare',
-					type: 'u64',
+					name: 'vault',
+					type: 'publicKey',
 					index: false,
 				},
 				{
-					name: 'managementFee',
+					name: 'startTs',
 					type: 'i64',
 					index: false,
 				},
 				{
-					name: 'managementFeeShares',
+					name: 'endTs',
 					type: 'i64',
 					index: false,
 				},
 				{
-					name: 'depositOraclePrice',
+					name: 'periodBlackout',
 					type: 'i64',
 					index: false,
 				},
 			],
 		},
 		{
-			name: 'ShareTransferRecord',
+			name: 'VaultDepositorAuthorityTransferRecord',
 			fields: [
 				{
 					name: 'ts',
@@ -5751,42 +28217,22 @@ export const IDL: DriftVaults = {
 					index: false,
 				},
 				{
-					name: 'fromVaultDepositor',
+					name: 'oldAuthority',
 					type: 'publicKey',
 					index: false,
 				},
 				{
-					name: 'toVaultDepositor',
+					name: 'newAuthority',
 					type: 'publicKey',
 					index: false,
 				},
 				{
-					name: 'shares',
-					type: 'u128',
-					index: false,
-				},
-				{
-					name: 'value',
-					type: 'u64',
-					index: false,
-				},
-				{
-					name: 'fromDepositorSharesBefore',
-					type: 'u128',
-					index: false,
-				},
-				{
-					name: 'fromDepositorSharesAfter',
-					type: 'u128',
-					index: false,
-				},
-				{
-					name: 'toDepositorSharesBefore',
-					type: 'u128',
+					name: 'vaultDepositor',
+					type: 'publicKey',
 					index: false,
 				},
 				{
-					name: 'toDepositorSharesAfter',
+					name: 'vaultShares',
 					type: 'u128',
 					index: false,
 				},
@@ -5969,5 +28415,385 @@ export const IDL: DriftVaults = {
 			name: 'InvalidFuelDistributionMode',
 			msg: 'InvalidFuelDistributionMode',
 		},
+		{
+			code: 6025,
+			name: 'VaultHookMissing',
+			msg: 'VaultHookMissing',
+		},
+		{
+			code: 6026,
+			name: 'InvalidVaultHook',
+			msg: 'InvalidVaultHook',
+		},
+		{
+			code: 6027,
+			name: 'InvalidWithdrawRoute',
+			msg: 'InvalidWithdrawRoute',
+		},
+		{
+			code: 6028,
+			name: 'InvalidVaultTemplate',
+			msg: 'InvalidVaultTemplate',
+		},
+		{
+			code: 6029,
+			name: 'InvalidVaultPoll',
+			msg: 'InvalidVaultPoll',
+		},
+		{
+			code: 6030,
+			name: 'VaultWindingDown',
+			msg: 'VaultWindingDown',
+		},
+		{
+			code: 6031,
+			name: 'InvalidShareSnapshot',
+			msg: 'InvalidShareSnapshot',
+		},
+		{
+			code: 6032,
+			name: 'InvalidLossCarryforward',
+			msg: 'InvalidLossCarryforward',
+		},
+		{
+			code: 6033,
+			name: 'InvalidVaultDepositorCustodian',
+			msg: 'InvalidVaultDepositorCustodian',
+		},
+		{
+			code: 6034,
+			name: 'DuplicateIdempotencyKey',
+			msg: 'DuplicateIdempotencyKey',
+		},
+		{
+			code: 6035,
+			name: 'InvalidVaultDepositorIdempotency',
+			msg: 'InvalidVaultDepositorIdempotency',
+		},
+		{
+			code: 6036,
+			name: 'InvalidWithdrawSettlement',
+			msg: 'InvalidWithdrawSettlement',
+		},
+		{
+			code: 6037,
+			name: 'InvalidVaultProfile',
+			msg: 'InvalidVaultProfile',
+		},
+		{
+			code: 6038,
+			name: 'InvalidPerformancePeriod',
+			msg: 'InvalidPerformancePeriod',
+		},
+		{
+			code: 6039,
+			name: 'InvalidVaultInterface',
+			msg: 'InvalidVaultInterface',
+		},
+		{
+			code: 6040,
+			name: 'InvalidDustWithdraw',
+			msg: 'InvalidDustWithdraw',
+		},
+		{
+			code: 6041,
+			name: 'InvalidWithdrawStream',
+			msg: 'InvalidWithdrawStream',
+		},
+		{
+			code: 6042,
+			name: 'InvalidVaultFeatureFlags',
+			msg: 'InvalidVaultFeatureFlags',
+		},
+		{
+			code: 6043,
+			name: 'VaultFeatureDisabled',
+			msg: 'VaultFeatureDisabled',
+		},
+		{
+			code: 6044,
+			name: 'InvalidVaultRegistry',
+			msg: 'InvalidVaultRegistry',
+		},
+		{
+			code: 6045,
+			name: 'InvalidDepositMintMigration',
+			msg: 'InvalidDepositMintMigration',
+		},
+		{
+			code: 6046,
+			name: 'InvalidVaultParamChangeLog',
+			msg: 'InvalidVaultParamChangeLog',
+		},
+		{
+			code: 6047,
+			name: 'InvalidVaultDepositorCap',
+			msg: 'InvalidVaultDepositorCap',
+		},
+		{
+			code: 6048,
+			name: 'VaultDepositorCapReached',
+			msg: 'VaultDepositorCapReached',
+		},
+		{
+			code: 6049,
+			name: 'InvalidFeeHoliday',
+			msg: 'InvalidFeeHoliday',
+		},
+		{
+			code: 6050,
+			name: 'InvalidWithdrawReceipt',
+			msg: 'InvalidWithdrawReceipt',
+		},
+		{
+			code: 6051,
+			name: 'WithdrawCancelDeadlinePassed',
+			msg: 'WithdrawCancelDeadlinePassed',
+		},
+		{
+			code: 6052,
+			name: 'SettleCrankMissing',
+			msg: 'SettleCrankMissing',
+		},
+		{
+			code: 6053,
+			name: 'InvalidVaultDepositorVersion',
+			msg: 'InvalidVaultDepositorVersion',
+		},
+		{
+			code: 6054,
+			name: 'InvalidVaultDelegateProposal',
+			msg: 'InvalidVaultDelegateProposal',
+		},
+		{
+			code: 6055,
+			name: 'DelegateApprovalRequired',
+			msg: 'DelegateApprovalRequired',
+		},
+		{
+			code: 6056,
+			name: 'SpotMarketWithdrawPaused',
+			msg: 'SpotMarketWithdrawPaused',
+		},
+		{
+			code: 6057,
+			name: 'WithdrawNotQueued',
+			msg: 'WithdrawNotQueued',
+		},
+		{
+			code: 6058,
+			name: 'InvalidVaultFeeSplit',
+			msg: 'InvalidVaultFeeSplit',
+		},
+		{
+			code: 6059,
+			name: 'AutoRenewWithdrawNotEnabled',
+			msg: 'AutoRenewWithdrawNotEnabled',
+		},
+		{
+			code: 6060,
+			name: 'WithdrawRequestNotExpired',
+			msg: 'WithdrawRequestNotExpired',
+		},
+		{
+			code: 6061,
+			name: 'InvalidProtocolTvlCap',
+			msg: 'InvalidProtocolTvlCap',
+		},
+		{
+			code: 6062,
+			name: 'ProtocolTvlCapExceeded',
+			msg: 'ProtocolTvlCapExceeded',
+		},
+		{
+			code: 6063,
+			name: 'InvalidVaultRedeemSchedule',
+			msg: 'InvalidVaultRedeemSchedule',
+		},
+		{
+			code: 6064,
+			name: 'InvalidVaultDepositorBatch',
+			msg: 'InvalidVaultDepositorBatch',
+		},
+		{
+			code: 6065,
+			name: 'VaultDepositorBlacklisted',
+			msg: 'VaultDepositorBlacklisted',
+		},
+		{
+			code: 6066,
+			name: 'InvalidVaultManagerFeeClaim',
+			msg: 'InvalidVaultManagerFeeClaim',
+		},
+		{
+			code: 6067,
+			name: 'InvalidVaultWithdrawEscrow',
+			msg: 'InvalidVaultWithdrawEscrow',
+		},
+		{
+			code: 6068,
+			name: 'ZeroVaultEquity',
+			msg: 'ZeroVaultEquity',
+		},
+		{
+			code: 6069,
+			name: 'InvalidVaultShareClass',
+			msg: 'InvalidVaultShareClass',
+		},
+		{
+			code: 6070,
+			name: 'VaultShareClassLocked',
+			msg: 'VaultShareClassLocked',
+		},
+		{
+			code: 6071,
+			name: 'InvalidVaultDepositorThrottle',
+			msg: 'InvalidVaultDepositorThrottle',
+		},
+		{
+			code: 6072,
+			name: 'VaultDepositorThrottled',
+			msg: 'VaultDepositorThrottled',
+		},
+		{
+			code: 6073,
+			name: 'DelegateSelfTrade',
+			msg: 'DelegateSelfTrade',
+		},
+		{
+			code: 6074,
+			name: 'InvalidVaultWithdrawPriority',
+			msg: 'InvalidVaultWithdrawPriority',
+		},
+		{
+			code: 6075,
+			name: 'InvalidLiquidationAction',
+			msg: 'InvalidLiquidationAction',
+		},
+		{
+			code: 6076,
+			name: 'InvalidVaultCrystallization',
+			msg: 'InvalidVaultCrystallization',
+		},
+		{
+			code: 6077,
+			name: 'InvalidVaultBackstop',
+			msg: 'InvalidVaultBackstop',
+		},
+		{
+			code: 6078,
+			name: 'InvalidFuelOverflowSweep',
+			msg: 'InvalidFuelOverflowSweep',
+		},
+		{
+			code: 6079,
+			name: 'InvalidProfitShareRefund',
+			msg: 'InvalidProfitShareRefund',
+		},
+		{
+			code: 6080,
+			name: 'InvalidOmnibusLedger',
+			msg: 'InvalidOmnibusLedger',
+		},
+		{
+			code: 6081,
+			name: 'OracleTwapDeviationTooLarge',
+			msg: 'OracleTwapDeviationTooLarge',
+		},
+		{
+			code: 6082,
+			name: 'InvalidVaultGuard',
+			msg: 'InvalidVaultGuard',
+		},
+		{
+			code: 6083,
+			name: 'InvalidVaultManagerActivity',
+			msg: 'InvalidVaultManagerActivity',
+		},
+		{
+			code: 6084,
+			name: 'VaultStateChanged',
+			msg: 'VaultStateChanged',
+		},
+		{
+			code: 6085,
+			name: 'SimulationOnly',
+			msg: 'SimulationOnly',
+		},
+		{
+			code: 6086,
+			name: 'VaultDepositorHasCompanionAccounts',
+			msg: 'VaultDepositorHasCompanionAccounts',
+		},
+		{
+			code: 6087,
+			name: 'InvalidVaultWithdrawBlackout',
+			msg: 'InvalidVaultWithdrawBlackout',
+		},
+		{
+			code: 6088,
+			name: 'WithdrawBlackout',
+			msg: 'WithdrawBlackout',
+		},
+		{
+			code: 6089,
+			name: 'InvalidVaultLockup',
+			msg: 'InvalidVaultLockup',
+		},
+		{
+			code: 6090,
+			name: 'VaultDepositorLockedUp',
+			msg: 'VaultDepositorLockedUp',
+		},
+		{
+			code: 6091,
+			name: 'InvalidVaultDepositorWithdrawRequests',
+			msg: 'InvalidVaultDepositorWithdrawRequests',
+		},
+		{
+			code: 6092,
+			name: 'InvalidVaultAuditor',
+			msg: 'InvalidVaultAuditor',
+		},
+		{
+			code: 6093,
+			name: 'DepositTooSmall',
+			msg: 'DepositTooSmall',
+		},
+		{
+			code: 6094,
+			name: 'InvalidVaultDepositorAuthorityTransfer',
+			msg: 'InvalidVaultDepositorAuthorityTransfer',
+		},
+		{
+			code: 6095,
+			name: 'TokenMintMissing',
+			msg: 'TokenMintMissing',
+		},
+		{
+			code: 6096,
+			name: 'InvalidVaultProfitShareCadence',
+			msg: 'InvalidVaultProfitShareCadence',
+		},
+		{
+			code: 6097,
+			name: 'ProfitShareCadenceNotElapsed',
+			msg: 'ProfitShareCadenceNotElapsed',
+		},
+		{
+			code: 6098,
+			name: 'InvalidSwapProgram',
+			msg: 'InvalidSwapProgram',
+		},
+		{
+			code: 6099,
+			name: 'SwapOutputBelowMinimum',
+			msg: 'SwapOutputBelowMinimum',
+		},
+		{
+			code: 6100,
+			name: 'InvalidVaultConfig',
+			msg: 'InvalidVaultConfig',
+		},
 	],
 };